@@ -65,12 +65,26 @@ class _SettingsPageState extends State<SettingsPage> {
         }
       }
 
+      // 本页只编辑以下几项，其余设置沿用 Rust 端当前的值
+      final current = await rust_api.getSettings();
       final settings = AppSettings(
         apiKey: apiKey.isEmpty ? null : apiKey,
         defaultModel: _chatModel,
         enableThinkingByDefault: _enableThinkingByDefault,
         chatModel: _chatModel,
         thinkingModel: _thinkingModel,
+        enableWebSearch: current.enableWebSearch,
+        contentIntensity: current.contentIntensity,
+        provider: current.provider,
+        providerBaseUrl: current.providerBaseUrl,
+        providerModel: current.providerModel,
+        providerApiKey: current.providerApiKey,
+        shadowEvalRate: current.shadowEvalRate,
+        enableTts: current.enableTts,
+        proxy: current.proxy,
+        retry: current.retry,
+        timeAwareness: current.timeAwareness,
+        fastDraft: current.fastDraft,
       );
 
       await rust_api.saveSettings(settings: settings);
@@ -5,9 +5,10 @@
 
 import '../frb_generated.dart';
 import 'data_models.dart';
+import 'knowledge_store.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-// These functions are ignored because they are not marked as `pub`: `get_config_manager`, `get_conversation_store`, `get_data_path`, `resolve_chat_model`, `resolve_thinking_model`
+// These functions are ignored because they are not marked as `pub`: `after_memory_summarized`, `build_online_engine`, `claim_turn`, `get_config_manager`, `get_conversation_store`, `get_data_path`, `install_persisted_config`, `persist_edited_memories`, `replay_outbox`, `resolve_chat_model`, `resolve_thinking_model`, `run_background_task`, `run_check_in`, `run_offline`, `run_regeneration`, `run_send`, `send_now`, `still_offline`, `sync_edited_memory`, `sync_timeline_memory`, `update_organization`
 
 Future<void> initApp({required String dataPath}) =>
     RustLib.instance.api.crateApiChatApiInitApp(dataPath: dataPath);
@@ -15,15 +16,75 @@ Future<void> initApp({required String dataPath}) =>
 Future<Conversation> createConversation() =>
     RustLib.instance.api.crateApiChatApiCreateConversation();
 
+/// 创建沙盒试聊对话：仅存在于内存，不提取事实、不生成摘要
+Future<Conversation?> createSandboxConversation({
+  required CharacterCard card,
+}) => RustLib.instance.api.crateApiChatApiCreateSandboxConversation(card: card);
+
+/// 关闭沙盒对话并丢弃其全部内容
+Future<bool> closeSandboxConversation({required String id}) =>
+    RustLib.instance.api.crateApiChatApiCloseSandboxConversation(id: id);
+
 Future<List<ConversationSummary>> getConversationList() =>
     RustLib.instance.api.crateApiChatApiGetConversationList();
 
+/// 按文件夹 / 标签 / 归档状态 / 标题筛选对话，置顶的排在最前
+Future<List<ConversationSummary>> getFilteredConversationList({
+  required ConversationFilter filter,
+}) => RustLib.instance.api.crateApiChatApiGetFilteredConversationList(
+  filter: filter,
+);
+
+/// 全部对话用到的文件夹名
+Future<List<String>> listConversationFolders() =>
+    RustLib.instance.api.crateApiChatApiListConversationFolders();
+
+/// 全部对话用到的标签
+Future<List<String>> listConversationTags() =>
+    RustLib.instance.api.crateApiChatApiListConversationTags();
+
+/// 设置对话的标签、文件夹、置顶与归档，返回整理后的结果（去掉空白与重复标签）
+Future<ConversationOrganization?> setConversationOrganization({
+  required String conversationId,
+  required ConversationOrganization organization,
+}) => RustLib.instance.api.crateApiChatApiSetConversationOrganization(
+  conversationId: conversationId,
+  organization: organization,
+);
+
+/// 置顶 / 取消置顶对话
+Future<bool> setConversationPinned({
+  required String conversationId,
+  required bool pinned,
+}) => RustLib.instance.api.crateApiChatApiSetConversationPinned(
+  conversationId: conversationId,
+  pinned: pinned,
+);
+
+/// 归档 / 取消归档对话
+Future<bool> setConversationArchived({
+  required String conversationId,
+  required bool archived,
+}) => RustLib.instance.api.crateApiChatApiSetConversationArchived(
+  conversationId: conversationId,
+  archived: archived,
+);
+
 Future<Conversation?> getConversation({required String id}) =>
     RustLib.instance.api.crateApiChatApiGetConversation(id: id);
 
 Future<bool> deleteConversation({required String id}) =>
     RustLib.instance.api.crateApiChatApiDeleteConversation(id: id);
 
+/// 单条回复的生成信息（消息详情页）：结束原因、用量与降级；无记录时返回 None
+Future<GenerationMetadata?> getGenerationMetadata({
+  required String conversationId,
+  required String messageId,
+}) => RustLib.instance.api.crateApiChatApiGetGenerationMetadata(
+  conversationId: conversationId,
+  messageId: messageId,
+);
+
 Future<bool> deleteMessage({
   required String conversationId,
   required String messageId,
@@ -32,6 +93,7 @@ Future<bool> deleteMessage({
   messageId: messageId,
 );
 
+/// 编辑消息；旧版本保留在 edit_history 中，覆盖该轮的记忆摘要随之作废
 Future<bool> editMessage({
   required String conversationId,
   required String messageId,
@@ -42,6 +104,15 @@ Future<bool> editMessage({
   newContent: newContent,
 );
 
+/// 撤销消息最近一次编辑，返回恢复后的消息；没有可撤销的编辑时为 None
+Future<Message?> undoMessageEdit({
+  required String conversationId,
+  required String messageId,
+}) => RustLib.instance.api.crateApiChatApiUndoMessageEdit(
+  conversationId: conversationId,
+  messageId: messageId,
+);
+
 Future<List<String>> rollbackToMessage({
   required String conversationId,
   required String messageId,
@@ -50,6 +121,32 @@ Future<List<String>> rollbackToMessage({
   messageId: messageId,
 );
 
+/// 从指定消息分叉出新时间线并切换过去，返回新分支ID
+Future<String?> createBranch({
+  required String conversationId,
+  required String fromMessageId,
+  String? name,
+}) => RustLib.instance.api.crateApiChatApiCreateBranch(
+  conversationId: conversationId,
+  fromMessageId: fromMessageId,
+  name: name,
+);
+
+Future<List<BranchInfo>> listBranches({
+  required String conversationId,
+}) => RustLib.instance.api.crateApiChatApiListBranches(
+  conversationId: conversationId,
+);
+
+/// 切换到指定分支；之后的对话只基于该分支的历史与记忆
+Future<bool> switchBranch({
+  required String conversationId,
+  required String branchId,
+}) => RustLib.instance.api.crateApiChatApiSwitchBranch(
+  conversationId: conversationId,
+  branchId: branchId,
+);
+
 Future<bool> addSystemMessage({
   required String conversationId,
   required String content,
@@ -66,11 +163,153 @@ Future<bool> addAssistantMessage({
   content: content,
 );
 
+/// 操偶模式：用户亲自替角色写一条回复，返回新消息ID。
+/// 这条回复会进入后续上下文，但不计入回复风格统计（反套路检测只看模型自己的回复）
+Future<String?> addUserAuthoredReply({
+  required String conversationId,
+  required String content,
+}) => RustLib.instance.api.crateApiChatApiAddUserAuthoredReply(
+  conversationId: conversationId,
+  content: content,
+);
+
+/// 操偶模式：用户改写一条角色回复，改写后同样视为用户撰写
+Future<bool> rewriteReply({
+  required String conversationId,
+  required String messageId,
+  required String newContent,
+}) => RustLib.instance.api.crateApiChatApiRewriteReply(
+  conversationId: conversationId,
+  messageId: messageId,
+  newContent: newContent,
+);
+
+/// 收束故事：角色写下尾声，补做摘要与事实提取，生成最终回顾与 Markdown 归档，
+/// 之后对话只读（仍可浏览）。model 为空时使用设置中的对话模型
+Future<ConversationClosure> closeConversation({
+  required String conversationId,
+  required String model,
+}) => RustLib.instance.api.crateApiChatApiCloseConversation(
+  conversationId: conversationId,
+  model: model,
+);
+
 Future<bool> restartStory({required String conversationId}) => RustLib
     .instance
     .api
     .crateApiChatApiRestartStory(conversationId: conversationId);
 
+/// 作废对话的蒸馏缓存（下一次上下文超长时重新蒸馏）
+Future<bool> invalidateDistilledState({
+  required String conversationId,
+}) => RustLib.instance.api.crateApiChatApiInvalidateDistilledState(
+  conversationId: conversationId,
+);
+
+/// 立即重新蒸馏对话的核心状态并返回；蒸馏失败或没有结果时返回 None（旧缓存已作废）
+Future<DistilledSystemState?> refreshDistilledState({
+  required String conversationId,
+}) => RustLib.instance.api.crateApiChatApiRefreshDistilledState(
+  conversationId: conversationId,
+);
+
+/// 角色当前的心情（已按离开的时间回落）；还没有互动过时返回 None
+Future<MoodState?> getCharacterMood({required String conversationId}) => RustLib
+    .instance
+    .api
+    .crateApiChatApiGetCharacterMood(conversationId: conversationId);
+
+/// 发送前预检：分析草稿语气与对关系的预期影响，只给建议不拦截。
+/// 对话不存在时返回 None
+Future<PreflightAdvisory?> preflightMessage({
+  required String conversationId,
+  required String draft,
+}) => RustLib.instance.api.crateApiChatApiPreflightMessage(
+  conversationId: conversationId,
+  draft: draft,
+);
+
+/// 对话当前的认知分析与短期记忆（与下一轮上下文增强看到的一致）
+Future<ConversationInsights?> analyzeConversation({
+  required String conversationId,
+}) => RustLib.instance.api.crateApiChatApiAnalyzeConversation(
+  conversationId: conversationId,
+);
+
+/// 一条事实与当前对话的相关性明细（用于解释某条记忆为何被/未被注入）
+Future<RelevanceInsight?> explainRelevance({
+  required String conversationId,
+  required String fact,
+  required String userContent,
+}) => RustLib.instance.api.crateApiChatApiExplainRelevance(
+  conversationId: conversationId,
+  fact: fact,
+  userContent: userContent,
+);
+
+/// 角色从用户纠正中学到的回避话题（含未生效的候选，按 active 区分）
+Future<List<BlockedTopic>> getBlockedTopics({
+  required String conversationId,
+}) => RustLib.instance.api.crateApiChatApiGetBlockedTopics(
+  conversationId: conversationId,
+);
+
+/// 移除一个学到的回避话题
+Future<bool> removeBlockedTopic({
+  required String conversationId,
+  required String topicId,
+}) => RustLib.instance.api.crateApiChatApiRemoveBlockedTopic(
+  conversationId: conversationId,
+  topicId: topicId,
+);
+
+/// 世界设定条目；conversation_id 为 None 时取所有对话共享的全局设定
+Future<List<LoreEntry>> getLoreEntries({String? conversationId}) => RustLib
+    .instance
+    .api
+    .crateApiChatApiGetLoreEntries(conversationId: conversationId);
+
+/// 新增或更新世界设定条目（id 为空时新建）；缺少触发词或内容时返回 None
+Future<LoreEntry?> saveLoreEntry({
+  String? conversationId,
+  required LoreEntry entry,
+}) => RustLib.instance.api.crateApiChatApiSaveLoreEntry(
+  conversationId: conversationId,
+  entry: entry,
+);
+
+Future<bool> removeLoreEntry({
+  String? conversationId,
+  required String entryId,
+}) => RustLib.instance.api.crateApiChatApiRemoveLoreEntry(
+  conversationId: conversationId,
+  entryId: entryId,
+);
+
+/// 设为多角色群聊或更新角色名单（至少两位，名字不能重复）；失败时返回 None。
+/// 群聊对话照常用 send_message / regenerate_response，引擎会自动挑选发言角色
+Future<GroupChat?> setGroupChat({
+  required String conversationId,
+  required List<GroupCharacter> characters,
+  required TurnPolicy turnPolicy,
+}) => RustLib.instance.api.crateApiChatApiSetGroupChat(
+  conversationId: conversationId,
+  characters: characters,
+  turnPolicy: turnPolicy,
+);
+
+/// 对话的群聊配置；不是群聊时返回 None
+Future<GroupChat?> getGroupChat({required String conversationId}) => RustLib
+    .instance
+    .api
+    .crateApiChatApiGetGroupChat(conversationId: conversationId);
+
+/// 解散群聊，恢复为单角色对话（各角色的记忆与知识保留，重新组群时沿用）
+Future<bool> dissolveGroupChat({required String conversationId}) => RustLib
+    .instance
+    .api
+    .crateApiChatApiDissolveGroupChat(conversationId: conversationId);
+
 Future<bool> setDialogueStyle({
   required String conversationId,
   required DialogueStyle style,
@@ -79,19 +318,183 @@ Future<bool> setDialogueStyle({
   style: style,
 );
 
+/// 切换对话模式（聊天 / 长文共写）
+Future<bool> setConversationMode({
+  required String conversationId,
+  required ConversationMode mode,
+}) => RustLib.instance.api.crateApiChatApiSetConversationMode(
+  conversationId: conversationId,
+  mode: mode,
+);
+
+/// 设置对话的叙述视角（第一人称 / 第三人称 / 不限）
+Future<bool> setNarrationPerspective({
+  required String conversationId,
+  required NarrationPerspective perspective,
+}) => RustLib.instance.api.crateApiChatApiSetNarrationPerspective(
+  conversationId: conversationId,
+  perspective: perspective,
+);
+
+/// 逐层开关对话的上下文增强（短期记忆 / 认知快照 / 多样性提示 / 拟人化提示 / 知识注入）
+Future<bool> setContextLayers({
+  required String conversationId,
+  required ContextLayers layers,
+}) => RustLib.instance.api.crateApiChatApiSetContextLayers(
+  conversationId: conversationId,
+  layers: layers,
+);
+
+/// 设置对话的回复风格偏好（回复长度 / 正式程度 / 表情 / 动作描写频率，Auto 为自动判断）
+Future<bool> setResponseStyle({
+  required String conversationId,
+  required ResponseStyle style,
+}) => RustLib.instance.api.crateApiChatApiSetResponseStyle(
+  conversationId: conversationId,
+  style: style,
+);
+
+/// 用户人设列表（数据目录下 personas.json，按创建时间排序）
+Future<List<UserPersona>> listPersonas() =>
+    RustLib.instance.api.crateApiChatApiListPersonas();
+
+/// 新建（id 留空）或更新人设，返回保存后的人设；名称为空或过长时报错
+Future<UserPersona> savePersona({required UserPersona persona}) =>
+    RustLib.instance.api.crateApiChatApiSavePersona(persona: persona);
+
+/// 删除人设及其分区下的用户档案事实；选用它的对话此后按未选人设处理
+Future<bool> deletePersona({required String personaId}) =>
+    RustLib.instance.api.crateApiChatApiDeletePersona(personaId: personaId);
+
+/// 为对话选用人设（None 为不使用人设）；人设不存在时返回 false
+Future<bool> setConversationPersona({
+  required String conversationId,
+  String? personaId,
+}) => RustLib.instance.api.crateApiChatApiSetConversationPersona(
+  conversationId: conversationId,
+  personaId: personaId,
+);
+
+/// 设置对话的思考内容保留策略
+Future<bool> setThinkingRetention({
+  required String conversationId,
+  required ThinkingRetention policy,
+}) => RustLib.instance.api.crateApiChatApiSetThinkingRetention(
+  conversationId: conversationId,
+  policy: policy,
+);
+
+/// 维护任务：对所有对话执行思考内容保留策略，返回清除的思考内容条数
+Future<int> runThinkingRetention() =>
+    RustLib.instance.api.crateApiChatApiRunThinkingRetention();
+
 Future<MessageType> detectMessageType({required String content}) =>
     RustLib.instance.api.crateApiChatApiDetectMessageType(content: content);
 
+/// 按对话的自定义 Say/Do 规则检测消息类型（未配置规则时同 detect_message_type）
+Future<MessageType> detectMessageTypeIn({
+  required String conversationId,
+  required String content,
+}) => RustLib.instance.api.crateApiChatApiDetectMessageTypeIn(
+  conversationId: conversationId,
+  content: content,
+);
+
+/// 对话的自定义 Say/Do 识别规则（未配置时为空，即内置识别）
+Future<SayDoRuleSet> getSaydoRules({required String conversationId}) => RustLib
+    .instance
+    .api
+    .crateApiChatApiGetSaydoRules(conversationId: conversationId);
+
+/// 设置对话的 Say/Do 识别规则；正则无效时不保存，传空规则恢复内置识别
+Future<void> setSaydoRules({
+  required String conversationId,
+  required SayDoRuleSet rules,
+}) => RustLib.instance.api.crateApiChatApiSetSaydoRules(
+  conversationId: conversationId,
+  rules: rules,
+);
+
 Future<int> getTurnCount({required String conversationId}) => RustLib
     .instance
     .api
     .crateApiChatApiGetTurnCount(conversationId: conversationId);
 
+/// 交叉校验对话、记忆索引与知识库的一致性（只读）
+Future<IntegrityReport?> verifyIntegrity({
+  required String conversationId,
+}) => RustLib.instance.api.crateApiChatApiVerifyIntegrity(
+  conversationId: conversationId,
+);
+
+/// 校验并自动修复不一致项，返回修复前检测到的问题
+Future<IntegrityReport?> repairIntegrity({
+  required String conversationId,
+}) => RustLib.instance.api.crateApiChatApiRepairIntegrity(
+  conversationId: conversationId,
+);
+
+/// 最近的降级/回退决策记录（按时间先后），用于解释回复质量波动
+Future<List<DegradationRecord>> getDegradationHistory({
+  required String conversationId,
+}) => RustLib.instance.api.crateApiChatApiGetDegradationHistory(
+  conversationId: conversationId,
+);
+
+/// 记忆保真度审计：抽样历史检查点中的事实，核对当前摘要是否仍能推出
+Future<MemoryFidelityReport?> auditMemoryFidelity({
+  required String conversationId,
+}) => RustLib.instance.api.crateApiChatApiAuditMemoryFidelity(
+  conversationId: conversationId,
+);
+
+/// 对所有对话执行保真度审计，只返回需要告警的结果
+Future<List<MemoryFidelityReport>> auditAllMemoryFidelity() =>
+    RustLib.instance.api.crateApiChatApiAuditAllMemoryFidelity();
+
+/// 历次保真度审计结果（按时间先后），用于观察压缩代数增长时的记忆流失
+Future<List<MemoryFidelityReport>> getMemoryFidelityHistory({
+  required String conversationId,
+}) => RustLib.instance.api.crateApiChatApiGetMemoryFidelityHistory(
+  conversationId: conversationId,
+);
+
+/// 知识注入影子评估的原始记录（按时间先后）
+Future<List<ShadowEvalRecord>> getShadowEvalRecords({
+  required String conversationId,
+}) => RustLib.instance.api.crateApiChatApiGetShadowEvalRecords(
+  conversationId: conversationId,
+);
+
+/// 影子评估汇总；conversation_id 为 None 时汇总所有对话
+Future<ShadowEvalSummary> getShadowEvalSummary({
+  String? conversationId,
+}) => RustLib.instance.api.crateApiChatApiGetShadowEvalSummary(
+  conversationId: conversationId,
+);
+
 Future<bool> shouldSummarizeMemory({required String conversationId}) => RustLib
     .instance
     .api
     .crateApiChatApiShouldSummarizeMemory(conversationId: conversationId);
 
+/// 对话的记忆摘要节奏（间隔轮数、读取的消息条数）
+Future<SummarizationConfig> getSummarizationConfig({
+  required String conversationId,
+}) => RustLib.instance.api.crateApiChatApiGetSummarizationConfig(
+  conversationId: conversationId,
+);
+
+/// 设置对话的记忆摘要节奏：重度角色扮演可以每 30 轮摘要一次，轻量闲聊每 5 轮；
+/// config 为 None 时恢复默认（每 10 轮、最近 20 条消息）
+Future<void> setSummarizationConfig({
+  required String conversationId,
+  SummarizationConfig? config,
+}) => RustLib.instance.api.crateApiChatApiSetSummarizationConfig(
+  conversationId: conversationId,
+  config: config,
+);
+
 Future<List<MemorySearchResult>> searchMemories({
   required String conversationId,
   required String query,
@@ -102,18 +505,432 @@ Future<List<MemorySearchResult>> searchMemories({
   topK: topK,
 );
 
+/// 记忆溯源：某条摘要由知识库中哪些事实支撑
+Future<MemoryExplanation?> explainMemory({
+  required String conversationId,
+  required String summaryId,
+}) => RustLib.instance.api.crateApiChatApiExplainMemory(
+  conversationId: conversationId,
+  summaryId: summaryId,
+);
+
+/// 知识库中的事实；conversation_id 为 None 时为跨对话共享的用户档案
+Future<List<Fact>> listFacts({String? conversationId}) => RustLib
+    .instance
+    .api
+    .crateApiChatApiListFacts(conversationId: conversationId);
+
+/// 手动添加事实（confidence 缺省为 1.0）；内容为空或置信度越界时返回 None
+Future<Fact?> addManualFact({
+  String? conversationId,
+  required String content,
+  required FactCategory category,
+  double? confidence,
+}) => RustLib.instance.api.crateApiChatApiAddManualFact(
+  conversationId: conversationId,
+  content: content,
+  category: category,
+  confidence: confidence,
+);
+
+/// 修改事实；修改后的事实不再被自动提取覆盖
+Future<bool> updateFact({
+  String? conversationId,
+  required String factId,
+  required String content,
+  required FactCategory category,
+  required double confidence,
+}) => RustLib.instance.api.crateApiChatApiUpdateFact(
+  conversationId: conversationId,
+  factId: factId,
+  content: content,
+  category: category,
+  confidence: confidence,
+);
+
+Future<bool> deleteFact({
+  String? conversationId,
+  required String factId,
+}) => RustLib.instance.api.crateApiChatApiDeleteFact(
+  conversationId: conversationId,
+  factId: factId,
+);
+
+/// 对话与角色层 / 世界层的绑定
+Future<KnowledgeBinding> getKnowledgeBinding({
+  required String conversationId,
+}) => RustLib.instance.api.crateApiChatApiGetKnowledgeBinding(
+  conversationId: conversationId,
+);
+
+/// 绑定角色层 / 世界层（各项均为空时解除绑定）；之后提取的事实按规则晋升，
+/// 检索时一并合并。ID 只能由字母、数字、- 和 _ 组成
+Future<void> setKnowledgeBinding({
+  required String conversationId,
+  required KnowledgeBinding binding,
+}) => RustLib.instance.api.crateApiChatApiSetKnowledgeBinding(
+  conversationId: conversationId,
+  binding: binding,
+);
+
+/// 对话（或群聊角色视角 `{对话ID}@{角色ID}`）某一层的事实；未绑定该层时为空
+Future<List<Fact>> listLayerFacts({
+  required String conversationId,
+  required KnowledgeLayer layer,
+}) => RustLib.instance.api.crateApiChatApiListLayerFacts(
+  conversationId: conversationId,
+  layer: layer,
+);
+
+/// 手动把对话中的一条事实晋升到角色层 / 世界层（群聊角色视角也可晋升到对话共享层）；
+/// 事实不存在时返回 false
+Future<bool> promoteFact({
+  required String conversationId,
+  required String factId,
+  required KnowledgeLayer layer,
+}) => RustLib.instance.api.crateApiChatApiPromoteFact(
+  conversationId: conversationId,
+  factId: factId,
+  layer: layer,
+);
+
+/// 知识图谱：实体为节点、事实为边；conversation_id 为 None 时为用户档案
+Future<KnowledgeGraphView> getKnowledgeGraph({
+  String? conversationId,
+}) => RustLib.instance.api.crateApiChatApiGetKnowledgeGraph(
+  conversationId: conversationId,
+);
+
+/// 与某个实体相距 depth 跳以内的子图（「和妹妹有关的都有什么」）
+Future<KnowledgeGraphView> queryGraphNeighbors({
+  String? conversationId,
+  required String entity,
+  required int depth,
+}) => RustLib.instance.api.crateApiChatApiQueryGraphNeighbors(
+  conversationId: conversationId,
+  entity: entity,
+  depth: depth,
+);
+
+/// 两个实体之间最短的关系链；不连通时为空
+Future<List<GraphEdge>> findGraphPath({
+  String? conversationId,
+  required String from,
+  required String to,
+}) => RustLib.instance.api.crateApiChatApiFindGraphPath(
+  conversationId: conversationId,
+  from: from,
+  to: to,
+);
+
+/// 导出知识图谱（JSON 或 GraphML 文本）
+Future<String> exportKnowledgeGraph({
+  String? conversationId,
+  required GraphExportFormat format,
+}) => RustLib.instance.api.crateApiChatApiExportKnowledgeGraph(
+  conversationId: conversationId,
+  format: format,
+);
+
+/// 对话中角色之间的关系（有向，每对角色每个方向一条当前关系及其历史）
+Future<List<CharacterRelation>> getCharacterRelations({
+  required String conversationId,
+}) => RustLib.instance.api.crateApiChatApiGetCharacterRelations(
+  conversationId: conversationId,
+);
+
+/// 两个角色对彼此的看法：a→b 与 b→a 两个方向（没有记录的方向缺省）
+Future<List<CharacterRelation>> queryCharacterRelation({
+  required String conversationId,
+  required String a,
+  required String b,
+}) => RustLib.instance.api.crateApiChatApiQueryCharacterRelation(
+  conversationId: conversationId,
+  a: a,
+  b: b,
+);
+
+/// 故事时间线：记忆摘要（章节）与关键事件按时间排列；对话不存在时为空
+Future<List<TimelineEntry>> getStoryTimeline({
+  required String conversationId,
+}) => RustLib.instance.api.crateApiChatApiGetStoryTimeline(
+  conversationId: conversationId,
+);
+
+/// 事实冲突记录：哪些旧事实被新事实取代（新的在前）
+Future<List<FactConflict>> getFactConflicts({
+  required String conversationId,
+}) => RustLib.instance.api.crateApiChatApiGetFactConflicts(
+  conversationId: conversationId,
+);
+
+/// 置顶 / 取消置顶记忆摘要（fact 为 None）或其中一条核心事实；
+/// 置顶内容在分级合并中不会被丢弃、合并或改写
+Future<bool> pinMemory({
+  required String conversationId,
+  required String summaryId,
+  String? fact,
+  required bool pinned,
+}) => RustLib.instance.api.crateApiChatApiPinMemory(
+  conversationId: conversationId,
+  summaryId: summaryId,
+  fact: fact,
+  pinned: pinned,
+);
+
+/// 记忆浏览：对话的全部记忆摘要（按轮次排列，含核心事实与排级）
+Future<List<MemorySummary>> listMemories({
+  required String conversationId,
+}) => RustLib.instance.api.crateApiChatApiListMemories(
+  conversationId: conversationId,
+);
+
+/// 修改记忆摘要的正文与核心事实（传入完整的事实列表）；摘要为空或不存在时返回 false
+Future<bool> editMemory({
+  required String conversationId,
+  required String summaryId,
+  required String summary,
+  required List<String> coreFacts,
+}) => RustLib.instance.api.crateApiChatApiEditMemory(
+  conversationId: conversationId,
+  summaryId: summaryId,
+  summary: summary,
+  coreFacts: coreFacts,
+);
+
+/// 删除一条记忆摘要
+Future<bool> deleteMemory({
+  required String conversationId,
+  required String summaryId,
+}) => RustLib.instance.api.crateApiChatApiDeleteMemory(
+  conversationId: conversationId,
+  summaryId: summaryId,
+);
+
+/// 调整记忆摘要中一条核心事实的排级（Identity / CriticalEvent 在分级合并中永不压缩）
+Future<bool> setMemoryFactTier({
+  required String conversationId,
+  required String summaryId,
+  required String fact,
+  required MemoryTier tier,
+}) => RustLib.instance.api.crateApiChatApiSetMemoryFactTier(
+  conversationId: conversationId,
+  summaryId: summaryId,
+  fact: fact,
+  tier: tier,
+);
+
+/// 检索算法升级后，后台重建所有对话中过期的索引，每完成一个对话推送一次进度
+Stream<ReindexProgress> reindexAll({required IndexScope scope}) =>
+    RustLib.instance.api.crateApiChatApiReindexAll(scope: scope);
+
+/// CPU 密集任务的线程池运行统计（用于确认检索计算没有拖慢流式输出）
+Future<BlockingStats> getBlockingStats() =>
+    RustLib.instance.api.crateApiChatApiGetBlockingStats();
+
+/// 是否处于弱网非流式模式（回复整段一次性返回，界面可提示「网络较差」）
+Future<bool> isStreamingDegraded() =>
+    RustLib.instance.api.crateApiChatApiIsStreamingDegraded();
+
+/// 跨全部对话按内容搜索消息，返回带片段的命中（最多 search_index::MAX_SEARCH_HITS 条）
+Future<List<SearchHit>> searchConversations({required String query}) =>
+    RustLib.instance.api.crateApiChatApiSearchConversations(query: query);
+
+/// 就地升级数据目录到当前布局版本，并刷新布局清单
+Future<LayoutVerification?> upgradeDataLayout() =>
+    RustLib.instance.api.crateApiChatApiUpgradeDataLayout();
+
+/// 将数据目录与布局清单快照比对
+Future<LayoutVerification?> verifyDataLayout() =>
+    RustLib.instance.api.crateApiChatApiVerifyDataLayout();
+
+/// 将整个数据目录迁移到新路径（换设备/换存储位置），完成后在目标端校验
+Future<LayoutVerification?> migrateDataLayout({
+  required String targetPath,
+}) => RustLib.instance.api.crateApiChatApiMigrateDataLayout(
+  targetPath: targetPath,
+);
+
+/// 创建一份增量备份（archive_path 为 None 时写入数据目录下的默认归档），按默认保留策略清理旧快照
+Future<BackupSnapshotInfo?> createBackup({String? archivePath}) =>
+    RustLib.instance.api.crateApiChatApiCreateBackup(archivePath: archivePath);
+
+/// 归档中的全部快照（从旧到新）
+Future<List<BackupSnapshotInfo>> listBackups({String? archivePath}) =>
+    RustLib.instance.api.crateApiChatApiListBackups(archivePath: archivePath);
+
+/// 按范围回滚到指定快照（snapshot_id 为 None 时用最新一份），返回写回的文件数
+Future<int> restoreBackup({
+  String? archivePath,
+  String? snapshotId,
+  required BackupScope scope,
+}) => RustLib.instance.api.crateApiChatApiRestoreBackup(
+  archivePath: archivePath,
+  snapshotId: snapshotId,
+  scope: scope,
+);
+
+/// 按保留策略清理旧快照，返回删除的快照数
+Future<int> pruneBackups({
+  String? archivePath,
+  required BackupRetention retention,
+}) => RustLib.instance.api.crateApiChatApiPruneBackups(
+  archivePath: archivePath,
+  retention: retention,
+);
+
+/// 把整个数据目录导出为单个 tar 归档（带清单与校验和），用于换设备
+Future<DataExportInfo> exportAllData({required String targetPath}) =>
+    RustLib.instance.api.crateApiChatApiExportAllData(targetPath: targetPath);
+
+/// 用导出归档整体替换当前数据（全部校验通过才生效），完成后重新载入设置；
+/// 数据启用了静态加密时，之后需用原凭据解锁
+Future<DataExportInfo> importAllData({required String archivePath}) =>
+    RustLib.instance.api.crateApiChatApiImportAllData(archivePath: archivePath);
+
+/// 云同步设置（数据目录下 sync.json；未填写地址时不同步）
+Future<SyncSettings> getSyncSettings() =>
+    RustLib.instance.api.crateApiChatApiGetSyncSettings();
+
+Future<void> setSyncSettings({required SyncSettings settings}) =>
+    RustLib.instance.api.crateApiChatApiSetSyncSettings(settings: settings);
+
+/// 与远端同步一次对话、记忆索引与知识库；宿主在启动、回到前台或定时调用
+Future<SyncReport> syncNow() => RustLib.instance.api.crateApiChatApiSyncNow();
+
+/// 对话 / 记忆 / 事实文件的加密状态（启用后每次启动需先解锁）
+Future<EncryptionStatus> getEncryptionStatus() =>
+    RustLib.instance.api.crateApiChatApiGetEncryptionStatus();
+
+/// 启用静态加密并把现有文件迁移为密文，返回迁移的文件数
+Future<int> enableEncryption({required EncryptionSecret secret}) =>
+    RustLib.instance.api.crateApiChatApiEnableEncryption(secret: secret);
+
+/// 启动后解锁加密存储（口令或平台密钥库中的密钥）
+Future<void> unlockEncryption({required EncryptionSecret secret}) =>
+    RustLib.instance.api.crateApiChatApiUnlockEncryption(secret: secret);
+
+/// 停用静态加密并把文件迁移回明文，返回迁移的文件数
+Future<int> disableEncryption({required EncryptionSecret secret}) =>
+    RustLib.instance.api.crateApiChatApiDisableEncryption(secret: secret);
+
 Future<AppSettings> getSettings() =>
     RustLib.instance.api.crateApiChatApiGetSettings();
 
 Future<bool> saveSettings({required AppSettings settings}) =>
     RustLib.instance.api.crateApiChatApiSaveSettings(settings: settings);
 
+/// 设置角色音色（群聊填角色ID，单聊填对话ID；None 恢复默认音色）
+Future<bool> setCharacterVoice({
+  required String characterKey,
+  String? voice,
+}) => RustLib.instance.api.crateApiChatApiSetCharacterVoice(
+  characterKey: characterKey,
+  voice: voice,
+);
+
+/// 已配置的角色音色
+Future<Map<String, String>> getCharacterVoices() =>
+    RustLib.instance.api.crateApiChatApiGetCharacterVoices();
+
 Future<void> setApiKey({required String apiKey}) =>
     RustLib.instance.api.crateApiChatApiSetApiKey(apiKey: apiKey);
 
 Future<bool> validateApiKey({required String apiKey}) =>
     RustLib.instance.api.crateApiChatApiValidateApiKey(apiKey: apiKey);
 
+/// 切换对话提供方。OpenAI 兼容端点 / Anthropic 需要接口地址、模型名与 Key，
+/// 本地模型只需模型名（地址默认 Ollama），智谱沿用 set_api_key 保存的 Key
+Future<void> setChatProvider({
+  required ProviderKind provider,
+  String? baseUrl,
+  String? model,
+  String? apiKey,
+}) => RustLib.instance.api.crateApiChatApiSetChatProvider(
+  provider: provider,
+  baseUrl: baseUrl,
+  model: model,
+  apiKey: apiKey,
+);
+
+/// 设置网络代理（HTTP / HTTPS / SOCKS5，可带认证与直连列表）；None 取消代理配置
+Future<void> setProxy({ProxySettings? proxy}) =>
+    RustLib.instance.api.crateApiChatApiSetProxy(proxy: proxy);
+
+/// 设置请求重试策略（次数、退避方式、可重试的错误类别、Retry-After 上限）
+Future<void> setRetryPolicy({required RetryPolicy policy}) =>
+    RustLib.instance.api.crateApiChatApiSetRetryPolicy(policy: policy);
+
+/// 开关现实时间感知：每轮告诉角色现在几点、星期几、距上一条消息多久
+Future<void> setTimeAwareness({required bool enabled}) =>
+    RustLib.instance.api.crateApiChatApiSetTimeAwareness(enabled: enabled);
+
+/// 开关快速草稿：深度推理期间先流式给出 glm-4.7-flash 的简短回复（DraftDelta），
+/// 完整回复到达后发送 Refined 替换草稿
+Future<void> setFastDraft({required bool enabled}) =>
+    RustLib.instance.api.crateApiChatApiSetFastDraft(enabled: enabled);
+
+/// 自定义情感词条（数据目录下 emotion_lexicon.json）
+Future<List<LexiconEntry>> getEmotionLexicon() =>
+    RustLib.instance.api.crateApiChatApiGetEmotionLexicon();
+
+/// 整体替换自定义情感词条，保存后立即参与情感分析；有空词或强度越界时不生效
+Future<void> saveEmotionLexicon({required List<LexiconEntry> entries}) =>
+    RustLib.instance.api.crateApiChatApiSaveEmotionLexicon(entries: entries);
+
+/// 内容安全过滤设置（数据目录下 safety.json；默认关闭）
+Future<SafetyPolicy> getSafetyPolicy() =>
+    RustLib.instance.api.crateApiChatApiGetSafetyPolicy();
+
+/// 保存内容安全过滤设置，下一轮对话起生效
+Future<void> setSafetyPolicy({required SafetyPolicy policy}) =>
+    RustLib.instance.api.crateApiChatApiSetSafetyPolicy(policy: policy);
+
+/// 数据保留策略（数据目录下 retention.json；默认全部关闭）
+Future<RetentionPolicy> getRetentionPolicy() =>
+    RustLib.instance.api.crateApiChatApiGetRetentionPolicy();
+
+Future<void> setRetentionPolicy({required RetentionPolicy policy}) =>
+    RustLib.instance.api.crateApiChatApiSetRetentionPolicy(policy: policy);
+
+/// 按保留策略执行一次数据维护（自动归档、冷存储、孤立文件清理）；
+/// 宿主在启动或空闲时调用
+Future<HousekeepingReport> runHousekeeping() =>
+    RustLib.instance.api.crateApiChatApiRunHousekeeping();
+
+/// 对话已转入冷存储的早期消息（旧 → 新）
+Future<List<Message>> loadColdStorageMessages({
+  required String conversationId,
+}) => RustLib.instance.api.crateApiChatApiLoadColdStorageMessages(
+  conversationId: conversationId,
+);
+
+/// 可覆盖的提示词模板（数据目录下 prompts/{name}.txt）及其变量
+Future<List<PromptTemplateInfo>> listPromptTemplates() =>
+    RustLib.instance.api.crateApiChatApiListPromptTemplates();
+
+/// 用户覆盖的模板原文；未覆盖时为 None（使用内置提示）
+Future<String?> getPromptTemplate({required String name}) =>
+    RustLib.instance.api.crateApiChatApiGetPromptTemplate(name: name);
+
+/// 保存覆盖模板，下一轮对话起生效；模板语法错误时不保存，内容为空等同于恢复内置
+Future<void> savePromptTemplate({
+  required String name,
+  required String content,
+}) => RustLib.instance.api.crateApiChatApiSavePromptTemplate(
+  name: name,
+  content: content,
+);
+
+/// 删除覆盖模板，恢复内置提示
+Future<void> resetPromptTemplate({required String name}) =>
+    RustLib.instance.api.crateApiChatApiResetPromptTemplate(name: name);
+
+/// 重新读取 prompts 目录（在应用外手动编辑模板文件后调用），返回生效的覆盖数
+Future<int> reloadPromptTemplates() =>
+    RustLib.instance.api.crateApiChatApiReloadPromptTemplates();
+
+/// 模型选择列表（内置声明 + 数据目录下 models.json 中的声明）
 Future<List<ModelInfo>> getAvailableModels() =>
     RustLib.instance.api.crateApiChatApiGetAvailableModels();
 
@@ -122,13 +939,116 @@ Stream<ChatStreamEvent> sendMessage({
   required String content,
   required String model,
   required bool enableThinking,
+  AmbientContext? ambient,
 }) => RustLib.instance.api.crateApiChatApiSendMessage(
   conversationId: conversationId,
   content: content,
   model: model,
   enableThinking: enableThinking,
+  ambient: ambient,
+);
+
+/// 发送带图片的消息：images 的 url 为 data URL（本地图片）或 http(s) 地址。
+/// 本轮由视觉模型识图并回复；离线模式下忽略图片
+Stream<ChatStreamEvent> sendMessageWithImages({
+  required String conversationId,
+  required String content,
+  required List<MessageAttachment> images,
+  required String model,
+  required bool enableThinking,
+  AmbientContext? ambient,
+}) => RustLib.instance.api.crateApiChatApiSendMessageWithImages(
+  conversationId: conversationId,
+  content: content,
+  images: images,
+  model: model,
+  enableThinking: enableThinking,
+  ambient: ambient,
+);
+
+/// 监听离线发件箱：网络恢复（请求成功、探测成功或 set_network_available(true)）后
+/// 按入队顺序重放待发消息。每条重放先推送 Outbox(状态 Sending)，随后是与
+/// send_message 相同的流事件直到 Done；失败时推送 Outbox(状态 Failed)。
+/// App 启动后调用一次，流保持打开；再次调用会取代之前的监听
+Stream<ChatStreamEvent> watchOutbox() =>
+    RustLib.instance.api.crateApiChatApiWatchOutbox();
+
+/// 发件箱中的全部条目（按入队先后）
+Future<List<OutboxEntry>> getOutbox() =>
+    RustLib.instance.api.crateApiChatApiGetOutbox();
+
+/// 放弃一条待发 / 失败的离线消息
+Future<bool> discardOutboxEntry({required String entryId}) =>
+    RustLib.instance.api.crateApiChatApiDiscardOutboxEntry(entryId: entryId);
+
+/// 宿主 App 上报系统网络状态：可用时立即触发发件箱重放，不可用时新消息直接入队
+Future<void> setNetworkAvailable({required bool available}) => RustLib
+    .instance
+    .api
+    .crateApiChatApiSetNetworkAvailable(available: available);
+
+/// 对话的主动联系设置；未设置时为 None
+Future<CheckInSchedule?> getCheckInSchedule({
+  required String conversationId,
+}) => RustLib.instance.api.crateApiChatApiGetCheckInSchedule(
+  conversationId: conversationId,
+);
+
+/// 开启 / 修改对话的主动联系：对方沉默 silence_hours 小时后角色主动发一条消息。
+/// 群聊、沙盒对话与 0 小时无效，返回 false
+Future<bool> setCheckInSchedule({required CheckInSchedule schedule}) =>
+    RustLib.instance.api.crateApiChatApiSetCheckInSchedule(schedule: schedule);
+
+/// 监听角色主动联系：开启了主动联系的对话到期时（见 check_ins），角色不等用户
+/// 消息直接发一条。每条先推送 CheckIn(对话 ID)，随后是该条回复的正常流事件直到 Done。
+/// 离线或离线回声模式下不生成，等恢复后再发。App 启动后调用一次，流保持打开；
+/// 再次调用会取代之前的监听
+Stream<ChatStreamEvent> watchCheckIns() =>
+    RustLib.instance.api.crateApiChatApiWatchCheckIns();
+
+/// 切换回复显示的版本（重新生成的每一版都会保留在 Message::alternatives 中）；
+/// 返回切换后的消息，消息不存在或序号越界时返回 None
+Future<Message?> selectAlternative({
+  required String conversationId,
+  required String messageId,
+  required int index,
+}) => RustLib.instance.api.crateApiChatApiSelectAlternative(
+  conversationId: conversationId,
+  messageId: messageId,
+  index: index,
+);
+
+/// 收藏 / 取消收藏一条消息（收藏的时刻在长期记忆检索中加权）；消息不存在时返回 None
+Future<Message?> setMessageStarred({
+  required String conversationId,
+  required String messageId,
+  required bool starred,
+}) => RustLib.instance.api.crateApiChatApiSetMessageStarred(
+  conversationId: conversationId,
+  messageId: messageId,
+  starred: starred,
+);
+
+/// 替换一条消息的表情回应（传空列表清除）
+Future<Message> setMessageReactions({
+  required String conversationId,
+  required String messageId,
+  required List<String> reactions,
+}) => RustLib.instance.api.crateApiChatApiSetMessageReactions(
+  conversationId: conversationId,
+  messageId: messageId,
+  reactions: reactions,
 );
 
+/// 对话中收藏的消息（按对话顺序）
+Future<List<Message>> listStarredMessages({
+  required String conversationId,
+}) => RustLib.instance.api.crateApiChatApiListStarredMessages(
+  conversationId: conversationId,
+);
+
+/// 重新生成最后一轮回复：末尾已有回复时新回复作为它的另一个版本追加
+/// （旧版本可用 select_alternative 切回），否则作为新回复追加
 Stream<ChatStreamEvent> regenerateResponse({
   required String conversationId,
   required String model,
@@ -139,8 +1059,120 @@ Stream<ChatStreamEvent> regenerateResponse({
   enableThinking: enableThinking,
 );
 
+/// 从任意消息重新生成：截断（as_branch=false）或分叉（as_branch=true）到
+/// 该消息所属的用户输入，然后基于截断后的历史与记忆重跑完整管线
+Stream<ChatStreamEvent> regenerateFrom({
+  required String conversationId,
+  required String messageId,
+  required bool asBranch,
+  required String model,
+  required bool enableThinking,
+}) => RustLib.instance.api.crateApiChatApiRegenerateFrom(
+  conversationId: conversationId,
+  messageId: messageId,
+  asBranch: asBranch,
+  model: model,
+  enableThinking: enableThinking,
+);
+
+/// 为当前场景生成插画（同 /draw）：hint 为可选的画面补充描述。
+/// 成功时推送 Illustration 事件（插画消息已写入对话），失败时以 SystemNotice 提示
+Stream<ChatStreamEvent> illustrateScene({
+  required String conversationId,
+  String? hint,
+  required String model,
+}) => RustLib.instance.api.crateApiChatApiIllustrateScene(
+  conversationId: conversationId,
+  hint: hint,
+  model: model,
+);
+
+/// 每日心声：过去 24 小时内活跃的每个角色以自己的口吻写一句话，汇总返回。
+/// 并发与花费均有上限；未配置在线提供方时返回 None
+Future<DailyDigest?> generateDailyDigest() =>
+    RustLib.instance.api.crateApiChatApiGenerateDailyDigest();
+
+/// 久别重逢：对话沉寂超过一周时，返回前情提要与建议的角色开场白。
+/// 每个对话只生成一次，直到有新消息才重新生成；最近还在聊时返回 None
+Future<ReengagementBrief?> prepareReengagement({
+  required String conversationId,
+}) => RustLib.instance.api.crateApiChatApiPrepareReengagement(
+  conversationId: conversationId,
+);
+
+/// 宿主 App 上报设备状态（电量、充电、省电模式、计费网络），
+/// 后台任务据此决定是否延后；状态变化时调用即可
+Future<void> setDeviceConditions({
+  required DeviceConditions conditions,
+}) => RustLib.instance.api.crateApiChatApiSetDeviceConditions(
+  conditions: conditions,
+);
+
+/// 调试面板：最近各轮回复的首字延迟、分阶段耗时与重试次数
+Future<MetricsSnapshot> getMetrics() =>
+    RustLib.instance.api.crateApiChatApiGetMetrics();
+
+/// 清空性能指标记录
+Future<void> clearMetrics() =>
+    RustLib.instance.api.crateApiChatApiClearMetrics();
+
+/// 导出最近的日志（纯文本，每行一条），供用户反馈问题时附上；limit 为 0 时导出全部
+Future<String> exportLogs({required int limit}) =>
+    RustLib.instance.api.crateApiChatApiExportLogs(limit: limit);
+
+/// 清空日志缓冲区
+Future<void> clearLogs() => RustLib.instance.api.crateApiChatApiClearLogs();
+
+/// 后台任务调度统计（执行中、超时、延后与待补跑的任务）
+Future<JobSchedulerStats> getJobSchedulerStats() =>
+    RustLib.instance.api.crateApiChatApiGetJobSchedulerStats();
+
+/// 订阅后台任务（事实提取、记忆摘要）的状态变化；send_message 在回复落盘后即返回，
+/// 这些任务随后在后台执行
+Stream<BackgroundTaskEvent> watchBackgroundTasks() =>
+    RustLib.instance.api.crateApiChatApiWatchBackgroundTasks();
+
+/// 排队或执行中的后台任务
+Future<List<BackgroundTaskEvent>> listBackgroundTasks() =>
+    RustLib.instance.api.crateApiChatApiListBackgroundTasks();
+
+/// 补跑因设备压力或配额被延后的后台任务（建议在开始充电或回到前台时调用）；
+/// 当前条件下仍不能执行的继续等待。返回补跑成功的任务数
+Future<int> runDeferredJobs() =>
+    RustLib.instance.api.crateApiChatApiRunDeferredJobs();
+
 Stream<ChatStreamEvent> triggerMemorySummarize({
   required String conversationId,
 }) => RustLib.instance.api.crateApiChatApiTriggerMemorySummarize(
   conversationId: conversationId,
 );
+
+/// 手动摘要：立即总结最近的对话，以 SummaryPreview 事件返回草稿（其后为 Done）；
+/// 草稿不会写入记忆，需调用 approve_memory_summary 确认，或 discard_memory_summary 丢弃
+Stream<ChatStreamEvent> previewMemorySummary({
+  required String conversationId,
+}) => RustLib.instance.api.crateApiChatApiPreviewMemorySummary(
+  conversationId: conversationId,
+);
+
+/// 对话当前待确认的手动摘要草稿
+Future<MemorySummary?> getPendingMemorySummary({
+  required String conversationId,
+}) => RustLib.instance.api.crateApiChatApiGetPendingMemorySummary(
+  conversationId: conversationId,
+);
+
+/// 确认手动摘要的草稿并写入长期记忆，返回写入后的摘要
+Future<MemorySummary> approveMemorySummary({
+  required String conversationId,
+  required String summaryId,
+}) => RustLib.instance.api.crateApiChatApiApproveMemorySummary(
+  conversationId: conversationId,
+  summaryId: summaryId,
+);
+
+/// 丢弃手动摘要的草稿；没有草稿时返回 false
+Future<bool> discardMemorySummary({required String conversationId}) => RustLib
+    .instance
+    .api
+    .crateApiChatApiDiscardMemorySummary(conversationId: conversationId);
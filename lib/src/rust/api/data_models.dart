@@ -8,9 +8,59 @@ import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 part 'data_models.freezed.dart';
 
-// These functions are ignored because they are not marked as `pub`: `default_chat_model`, `default_thinking_model`
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CompressionImpactLevel`, `DistilledSystemState`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored because they are not marked as `pub`: `default_branch_id`, `default_chat_model`, `default_thinking_model`, `default_time_awareness`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CompressionImpactLevel`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `hash`, `hash`
+
+/// 动作描写频率偏好
+enum ActionFrequency {
+  auto,
+  never,
+  occasional,
+  frequent;
+
+  static Future<ActionFrequency> default_() =>
+      RustLib.instance.api.crateApiDataModelsActionFrequencyDefault();
+}
+
+/// 宿主 App 提供的环境上下文（全部可选，缺省即不注入）
+class AmbientContext {
+  /// 天气描述，如「小雨 12°C」
+  final String? weather;
+  /// 今日步数
+  final int? stepCount;
+  /// 正在播放的歌曲
+  final String? nowPlaying;
+  /// 日程忙闲：true=忙碌中，false=空闲
+  final bool? calendarBusy;
+
+  const AmbientContext({
+    this.weather,
+    this.stepCount,
+    this.nowPlaying,
+    this.calendarBusy,
+  });
+
+  static Future<AmbientContext> default_() =>
+      RustLib.instance.api.crateApiDataModelsAmbientContextDefault();
+
+  @override
+  int get hashCode =>
+      weather.hashCode ^
+      stepCount.hashCode ^
+      nowPlaying.hashCode ^
+      calendarBusy.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AmbientContext &&
+          runtimeType == other.runtimeType &&
+          weather == other.weather &&
+          stepCount == other.stepCount &&
+          nowPlaying == other.nowPlaying &&
+          calendarBusy == other.calendarBusy;
+}
 
 class AppSettings {
   final String? apiKey;
@@ -18,6 +68,29 @@ class AppSettings {
   final bool enableThinkingByDefault;
   final String chatModel;
   final String thinkingModel;
+  /// 允许对现实信息类提问联网搜索
+  final bool enableWebSearch;
+  final ContentIntensity contentIntensity;
+  final ProviderKind provider;
+  /// 非智谱提供方的接口地址（OpenAI 兼容端点必填，Anthropic / 本地模型留空则用默认地址）
+  final String? providerBaseUrl;
+  /// 非智谱提供方的模型名（GLM 模型名无法沿用，所有角色统一映射到此模型）
+  final String? providerModel;
+  /// 非智谱提供方的 API Key（与智谱 id.secret 格式不同，单独保存）
+  final String? providerApiKey;
+  /// 知识注入影子评估的抽样比例（0.0 关闭，1.0 每轮都评估）
+  final double shadowEvalRate;
+  /// 回复落盘后合成语音（音色按角色配置，见 ConfigManager::load_voices）
+  final bool enableTts;
+  /// 网络代理（None 时沿用系统代理环境变量）
+  final ProxySettings? proxy;
+  /// 请求失败的重试策略
+  final RetryPolicy retry;
+  /// 每轮注入现实时间（本地时间、星期、距上一条消息多久），见 time_awareness
+  final bool timeAwareness;
+  /// 快速草稿：推理管线运行期间先由 glm-4.7-flash 流式给出简短回复（DraftDelta），
+  /// 完整回复到达后以 Refined 替换（仅思考模式下生效）
+  final bool fastDraft;
 
   const AppSettings({
     this.apiKey,
@@ -25,6 +98,18 @@ class AppSettings {
     required this.enableThinkingByDefault,
     required this.chatModel,
     required this.thinkingModel,
+    required this.enableWebSearch,
+    required this.contentIntensity,
+    required this.provider,
+    this.providerBaseUrl,
+    this.providerModel,
+    this.providerApiKey,
+    required this.shadowEvalRate,
+    required this.enableTts,
+    this.proxy,
+    required this.retry,
+    required this.timeAwareness,
+    required this.fastDraft,
   });
 
   static Future<AppSettings> default_() =>
@@ -36,7 +121,19 @@ class AppSettings {
       defaultModel.hashCode ^
       enableThinkingByDefault.hashCode ^
       chatModel.hashCode ^
-      thinkingModel.hashCode;
+      thinkingModel.hashCode ^
+      enableWebSearch.hashCode ^
+      contentIntensity.hashCode ^
+      provider.hashCode ^
+      providerBaseUrl.hashCode ^
+      providerModel.hashCode ^
+      providerApiKey.hashCode ^
+      shadowEvalRate.hashCode ^
+      enableTts.hashCode ^
+      proxy.hashCode ^
+      retry.hashCode ^
+      timeAwareness.hashCode ^
+      fastDraft.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -47,327 +144,3885 @@ class AppSettings {
           defaultModel == other.defaultModel &&
           enableThinkingByDefault == other.enableThinkingByDefault &&
           chatModel == other.chatModel &&
-          thinkingModel == other.thinkingModel;
+          thinkingModel == other.thinkingModel &&
+          enableWebSearch == other.enableWebSearch &&
+          contentIntensity == other.contentIntensity &&
+          provider == other.provider &&
+          providerBaseUrl == other.providerBaseUrl &&
+          providerModel == other.providerModel &&
+          providerApiKey == other.providerApiKey &&
+          shadowEvalRate == other.shadowEvalRate &&
+          enableTts == other.enableTts &&
+          proxy == other.proxy &&
+          retry == other.retry &&
+          timeAwareness == other.timeAwareness &&
+          fastDraft == other.fastDraft;
 }
 
-@freezed
-sealed class ChatStreamEvent with _$ChatStreamEvent {
-  const ChatStreamEvent._();
-
-  const factory ChatStreamEvent.contentDelta(String field0) =
-      ChatStreamEvent_ContentDelta;
-  const factory ChatStreamEvent.thinkingDelta(String field0) =
-      ChatStreamEvent_ThinkingDelta;
-  const factory ChatStreamEvent.done() = ChatStreamEvent_Done;
-  const factory ChatStreamEvent.error(String field0) = ChatStreamEvent_Error;
-}
-
-/// 对话
-class Conversation {
-  final String id;
-  final String title;
-  final List<Message> messages;
-  final String model;
-  final PlatformInt64 createdAt;
-  final PlatformInt64 updatedAt;
-  final DialogueStyle dialogueStyle;
-  final int turnCount;
-  final List<MemorySummary> memorySummaries;
+/// 一条回复的语音合成完成
+class AudioReadyEvent {
+  final String messageId;
+  /// 本地音频文件路径
+  final String audioPath;
 
-  const Conversation({
-    required this.id,
-    required this.title,
-    required this.messages,
-    required this.model,
-    required this.createdAt,
-    required this.updatedAt,
-    required this.dialogueStyle,
-    required this.turnCount,
-    required this.memorySummaries,
-  });
+  const AudioReadyEvent({required this.messageId, required this.audioPath});
 
   @override
-  int get hashCode =>
-      id.hashCode ^
-      title.hashCode ^
-      messages.hashCode ^
-      model.hashCode ^
-      createdAt.hashCode ^
-      updatedAt.hashCode ^
-      dialogueStyle.hashCode ^
-      turnCount.hashCode ^
-      memorySummaries.hashCode;
+  int get hashCode => messageId.hashCode ^ audioPath.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is Conversation &&
+      other is AudioReadyEvent &&
           runtimeType == other.runtimeType &&
-          id == other.id &&
-          title == other.title &&
-          messages == other.messages &&
-          model == other.model &&
-          createdAt == other.createdAt &&
-          updatedAt == other.updatedAt &&
-          dialogueStyle == other.dialogueStyle &&
-          turnCount == other.turnCount &&
-          memorySummaries == other.memorySummaries;
+          messageId == other.messageId &&
+          audioPath == other.audioPath;
 }
 
-/// 对话摘要（用于列表展示）
-class ConversationSummary {
-  final String id;
-  final String title;
-  final String lastMessagePreview;
-  final String model;
+/// 后台任务类型
+enum BackgroundJobKind {
+  /// 回复后的事实提取
+  factExtraction,
+  /// 记忆摘要
+  summarization,
+  /// 长上下文蒸馏（回复管线内，不可延后）
+  distillation,
+  /// 知识注入影子评估
+  shadowEval,
+}
+
+/// 后台任务的状态变化（见 watch_background_tasks）
+class BackgroundTaskEvent {
+  final String taskId;
+  final BackgroundJobKind kind;
+  final String conversationId;
+  final BackgroundTaskStatus status;
   final PlatformInt64 updatedAt;
 
-  const ConversationSummary({
-    required this.id,
-    required this.title,
-    required this.lastMessagePreview,
-    required this.model,
+  const BackgroundTaskEvent({
+    required this.taskId,
+    required this.kind,
+    required this.conversationId,
+    required this.status,
     required this.updatedAt,
   });
 
   @override
   int get hashCode =>
-      id.hashCode ^
-      title.hashCode ^
-      lastMessagePreview.hashCode ^
-      model.hashCode ^
+      taskId.hashCode ^
+      kind.hashCode ^
+      conversationId.hashCode ^
+      status.hashCode ^
       updatedAt.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is ConversationSummary &&
+      other is BackgroundTaskEvent &&
           runtimeType == other.runtimeType &&
-          id == other.id &&
-          title == other.title &&
-          lastMessagePreview == other.lastMessagePreview &&
-          model == other.model &&
+          taskId == other.taskId &&
+          kind == other.kind &&
+          conversationId == other.conversationId &&
+          status == other.status &&
           updatedAt == other.updatedAt;
 }
 
-enum DialogueStyle {
-  free,
-  sayOnly,
-  doOnly,
-  mixed;
+/// 后台任务队列中任务的状态
+enum BackgroundTaskStatus {
+  queued,
+  running,
+  completed,
+  /// 执行失败，或被调度器延后（稍后由 run_deferred_jobs 补跑）
+  failed,
+}
 
-  static Future<DialogueStyle> default_() =>
-      RustLib.instance.api.crateApiDataModelsDialogueStyleDefault();
+/// 重试间隔的增长方式
+enum BackoffStrategy {
+  /// 每次等待 initial_delay_ms
+  fixed,
+  /// 每次翻倍
+  exponential,
+  /// 翻倍后在 [一半, 全部] 之间随机取值，避免多个请求同时重试
+  exponentialJitter;
+
+  static Future<BackoffStrategy> default_() =>
+      RustLib.instance.api.crateApiDataModelsBackoffStrategyDefault();
 }
 
-class MemoryContextCard {
-  final String sourceRange;
-  final List<String> topicTags;
-  final List<String> keyEntities;
-  final String emotionalTone;
-  final List<String> causalLinks;
+/// 备份保留策略：保留最近 keep_last 份，另外每天保留最新一份（最近 keep_daily 天）
+class BackupRetention {
+  final int keepLast;
+  final int keepDaily;
 
-  const MemoryContextCard({
-    required this.sourceRange,
-    required this.topicTags,
-    required this.keyEntities,
-    required this.emotionalTone,
-    required this.causalLinks,
-  });
+  const BackupRetention({required this.keepLast, required this.keepDaily});
+
+  static Future<BackupRetention> default_() =>
+      RustLib.instance.api.crateApiDataModelsBackupRetentionDefault();
 
   @override
-  int get hashCode =>
-      sourceRange.hashCode ^
-      topicTags.hashCode ^
-      keyEntities.hashCode ^
-      emotionalTone.hashCode ^
-      causalLinks.hashCode;
+  int get hashCode => keepLast.hashCode ^ keepDaily.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is MemoryContextCard &&
+      other is BackupRetention &&
           runtimeType == other.runtimeType &&
-          sourceRange == other.sourceRange &&
-          topicTags == other.topicTags &&
-          keyEntities == other.keyEntities &&
-          emotionalTone == other.emotionalTone &&
-          causalLinks == other.causalLinks;
+          keepLast == other.keepLast &&
+          keepDaily == other.keepDaily;
 }
 
-class MemorySearchResult {
-  final String summary;
-  final List<String> coreFacts;
-  final double relevanceScore;
+/// 备份恢复的范围
+enum BackupScope {
+  all,
+  /// 对话、群聊及其附件、语音
+  conversations,
+  /// 记忆摘要、向量、审计与决策日志
+  memory,
+  /// 知识库、世界设定、屏蔽话题
+  knowledge,
+  /// 根目录下的设置文件与自定义提示词模板
+  config;
 
-  const MemorySearchResult({
-    required this.summary,
-    required this.coreFacts,
-    required this.relevanceScore,
+  static Future<BackupScope> default_() =>
+      RustLib.instance.api.crateApiDataModelsBackupScopeDefault();
+}
+
+/// 一份备份快照的概要
+class BackupSnapshotInfo {
+  final String id;
+  final PlatformInt64 createdAt;
+  final int fileCount;
+  /// 快照内文件的总大小
+  final BigInt totalBytes;
+  /// 创建时新写入归档的字节数（其余内容与已有快照去重）
+  final BigInt addedBytes;
+
+  const BackupSnapshotInfo({
+    required this.id,
+    required this.createdAt,
+    required this.fileCount,
+    required this.totalBytes,
+    required this.addedBytes,
   });
 
   @override
   int get hashCode =>
-      summary.hashCode ^ coreFacts.hashCode ^ relevanceScore.hashCode;
+      id.hashCode ^
+      createdAt.hashCode ^
+      fileCount.hashCode ^
+      totalBytes.hashCode ^
+      addedBytes.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is MemorySearchResult &&
+      other is BackupSnapshotInfo &&
           runtimeType == other.runtimeType &&
-          summary == other.summary &&
-          coreFacts == other.coreFacts &&
-          relevanceScore == other.relevanceScore;
+          id == other.id &&
+          createdAt == other.createdAt &&
+          fileCount == other.fileCount &&
+          totalBytes == other.totalBytes &&
+          addedBytes == other.addedBytes;
 }
 
-class MemorySummary {
+/// 从用户纠正中学到的回避话题（按对话，即按角色）
+class BlockedTopic {
   final String id;
-  final String summary;
-  final List<String> coreFacts;
-  final int turnRangeStart;
-  final int turnRangeEnd;
-  final PlatformInt64 createdAt;
+  /// 话题描述（用户点名的话题，或被回避的问题）
+  final String topic;
+  /// 话题关键词（多次回避按关键词归并）
   final List<String> keywords;
-  final int compressionGeneration;
-  final MemoryContextCard? contextCard;
-  final List<MemoryTier> factTiers;
+  /// 用户原话（最近几次）
+  final List<String> evidence;
+  /// 回避次数
+  final int occurrences;
+  /// 用户明确说过「别聊这个」之类的话
+  final bool explicit;
+  /// 已生效：明确叫停一次，或敷衍累计多次
+  final bool active;
+  final PlatformInt64 firstSeen;
+  final PlatformInt64 lastSeen;
 
-  const MemorySummary({
+  const BlockedTopic({
     required this.id,
-    required this.summary,
-    required this.coreFacts,
-    required this.turnRangeStart,
-    required this.turnRangeEnd,
-    required this.createdAt,
+    required this.topic,
     required this.keywords,
-    required this.compressionGeneration,
-    this.contextCard,
-    required this.factTiers,
+    required this.evidence,
+    required this.occurrences,
+    required this.explicit,
+    required this.active,
+    required this.firstSeen,
+    required this.lastSeen,
   });
 
   @override
   int get hashCode =>
       id.hashCode ^
-      summary.hashCode ^
-      coreFacts.hashCode ^
-      turnRangeStart.hashCode ^
-      turnRangeEnd.hashCode ^
-      createdAt.hashCode ^
+      topic.hashCode ^
       keywords.hashCode ^
-      compressionGeneration.hashCode ^
-      contextCard.hashCode ^
-      factTiers.hashCode;
+      evidence.hashCode ^
+      occurrences.hashCode ^
+      explicit.hashCode ^
+      active.hashCode ^
+      firstSeen.hashCode ^
+      lastSeen.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is MemorySummary &&
+      other is BlockedTopic &&
           runtimeType == other.runtimeType &&
           id == other.id &&
-          summary == other.summary &&
-          coreFacts == other.coreFacts &&
-          turnRangeStart == other.turnRangeStart &&
-          turnRangeEnd == other.turnRangeEnd &&
-          createdAt == other.createdAt &&
+          topic == other.topic &&
           keywords == other.keywords &&
-          compressionGeneration == other.compressionGeneration &&
-          contextCard == other.contextCard &&
-          factTiers == other.factTiers;
-}
-
-enum MemoryTier {
-  identity,
-  criticalEvent,
-  relationshipDynamic,
-  currentState,
-  sceneDetail,
+          evidence == other.evidence &&
+          occurrences == other.occurrences &&
+          explicit == other.explicit &&
+          active == other.active &&
+          firstSeen == other.firstSeen &&
+          lastSeen == other.lastSeen;
 }
 
-class Message {
-  final String id;
-  final MessageRole role;
-  final String content;
-  final String? thinkingContent;
-  final String model;
-  final PlatformInt64 timestamp;
-  final MessageType messageType;
+/// CPU 密集任务（检索/指纹分析）在 blocking 线程池上的运行统计
+class BlockingStats {
+  final BigInt tasks;
+  final BigInt totalRunMs;
+  final BigInt maxRunMs;
+  /// 提交到开始执行的最长等待（线程池饱和程度）
+  final BigInt maxQueueMs;
+  /// 最慢一次任务的标签
+  final String slowestLabel;
 
-  const Message({
-    required this.id,
-    required this.role,
-    required this.content,
-    this.thinkingContent,
-    required this.model,
-    required this.timestamp,
-    required this.messageType,
+  const BlockingStats({
+    required this.tasks,
+    required this.totalRunMs,
+    required this.maxRunMs,
+    required this.maxQueueMs,
+    required this.slowestLabel,
   });
 
+  static Future<BlockingStats> default_() =>
+      RustLib.instance.api.crateApiDataModelsBlockingStatsDefault();
+
   @override
   int get hashCode =>
-      id.hashCode ^
-      role.hashCode ^
-      content.hashCode ^
-      thinkingContent.hashCode ^
-      model.hashCode ^
-      timestamp.hashCode ^
-      messageType.hashCode;
+      tasks.hashCode ^
+      totalRunMs.hashCode ^
+      maxRunMs.hashCode ^
+      maxQueueMs.hashCode ^
+      slowestLabel.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is Message &&
+      other is BlockingStats &&
           runtimeType == other.runtimeType &&
-          id == other.id &&
-          role == other.role &&
-          content == other.content &&
-          thinkingContent == other.thinkingContent &&
-          model == other.model &&
-          timestamp == other.timestamp &&
-          messageType == other.messageType;
-}
-
-enum MessageRole { user, assistant, system }
-
-enum MessageType {
-  say,
-  do_,
-  mixed;
-
-  static Future<MessageType> default_() =>
-      RustLib.instance.api.crateApiDataModelsMessageTypeDefault();
+          tasks == other.tasks &&
+          totalRunMs == other.totalRunMs &&
+          maxRunMs == other.maxRunMs &&
+          maxQueueMs == other.maxQueueMs &&
+          slowestLabel == other.slowestLabel;
 }
 
-class ModelInfo {
+/// 分支列表项（不含消息正文）
+class BranchInfo {
   final String id;
   final String name;
-  final BigInt contextTokens;
-  final BigInt maxOutputTokens;
-  final bool supportsThinking;
+  final String? parentMessageId;
+  final int messageCount;
+  final String lastMessagePreview;
+  final PlatformInt64 createdAt;
+  final bool isActive;
 
-  const ModelInfo({
+  const BranchInfo({
     required this.id,
     required this.name,
-    required this.contextTokens,
-    required this.maxOutputTokens,
-    required this.supportsThinking,
+    this.parentMessageId,
+    required this.messageCount,
+    required this.lastMessagePreview,
+    required this.createdAt,
+    required this.isActive,
   });
 
   @override
   int get hashCode =>
       id.hashCode ^
       name.hashCode ^
-      contextTokens.hashCode ^
-      maxOutputTokens.hashCode ^
-      supportsThinking.hashCode;
+      parentMessageId.hashCode ^
+      messageCount.hashCode ^
+      lastMessagePreview.hashCode ^
+      createdAt.hashCode ^
+      isActive.hashCode;
 
   @override
   bool operator ==(Object other) =>
       identical(this, other) ||
-      other is ModelInfo &&
+      other is BranchInfo &&
           runtimeType == other.runtimeType &&
           id == other.id &&
           name == other.name &&
-          contextTokens == other.contextTokens &&
-          maxOutputTokens == other.maxOutputTokens &&
-          supportsThinking == other.supportsThinking;
+          parentMessageId == other.parentMessageId &&
+          messageCount == other.messageCount &&
+          lastMessagePreview == other.lastMessagePreview &&
+          createdAt == other.createdAt &&
+          isActive == other.isActive;
+}
+
+/// 角色卡（用于沙盒试聊）
+class CharacterCard {
+  final String name;
+  /// 角色系统提示词
+  final String systemPrompt;
+  /// 开场白（可为空）
+  final String greeting;
+
+  const CharacterCard({
+    required this.name,
+    required this.systemPrompt,
+    required this.greeting,
+  });
+
+  @override
+  int get hashCode => name.hashCode ^ systemPrompt.hashCode ^ greeting.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CharacterCard &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          systemPrompt == other.systemPrompt &&
+          greeting == other.greeting;
+}
+
+/// 一个角色对另一个角色的当前关系（有向：from 如何看待 to）
+class CharacterRelation {
+  final String from;
+  final String to;
+  final String relation;
+  final RelationSentiment sentiment;
+  /// 记录当前关系的事实
+  final String factId;
+  final int sourceTurn;
+  /// 较早的关系，按时间先后排列
+  final List<RelationChange> history;
+
+  const CharacterRelation({
+    required this.from,
+    required this.to,
+    required this.relation,
+    required this.sentiment,
+    required this.factId,
+    required this.sourceTurn,
+    required this.history,
+  });
+
+  @override
+  int get hashCode =>
+      from.hashCode ^
+      to.hashCode ^
+      relation.hashCode ^
+      sentiment.hashCode ^
+      factId.hashCode ^
+      sourceTurn.hashCode ^
+      history.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CharacterRelation &&
+          runtimeType == other.runtimeType &&
+          from == other.from &&
+          to == other.to &&
+          relation == other.relation &&
+          sentiment == other.sentiment &&
+          factId == other.factId &&
+          sourceTurn == other.sourceTurn &&
+          history == other.history;
+}
+
+@freezed
+sealed class ChatStreamEvent with _$ChatStreamEvent {
+  const ChatStreamEvent._();
+
+  const factory ChatStreamEvent.contentDelta(String field0) =
+      ChatStreamEvent_ContentDelta;
+  const factory ChatStreamEvent.thinkingDelta(String field0) =
+      ChatStreamEvent_ThinkingDelta;
+  const factory ChatStreamEvent.done() = ChatStreamEvent_Done;
+  const factory ChatStreamEvent.error(String field0) = ChatStreamEvent_Error;
+  /// 本轮开始前采用了新的设置（设置热更新生效）
+  const factory ChatStreamEvent.configChanged() = ChatStreamEvent_ConfigChanged;
+  /// 用户消息落盘后、正式回复之前的即时反应
+  const factory ChatStreamEvent.reaction(ReactionEvent field0) =
+      ChatStreamEvent_Reaction;
+  /// 快捷命令的执行结果（系统提示样式展示，不写入对话）
+  const factory ChatStreamEvent.systemNotice(String field0) =
+      ChatStreamEvent_SystemNotice;
+  /// 场景插画已生成：已写入对话的插画消息（图片在 attachments 中）
+  const factory ChatStreamEvent.illustration(Message field0) =
+      ChatStreamEvent_Illustration;
+  /// 回复的语音已合成（回复落盘之后）
+  const factory ChatStreamEvent.audioReady(AudioReadyEvent field0) =
+      ChatStreamEvent_AudioReady;
+  /// 回复已落盘，附带生成统计（紧接在 Done 之前；回复未能生成时不发送）
+  const factory ChatStreamEvent.completed(GenerationCompletedEvent field0) =
+      ChatStreamEvent_Completed;
+  /// 离线发件箱状态变化：离线时消息入队（Queued），网络恢复后开始重放（Sending，
+  /// 其后是该条发送的正常流事件直到 Done），重放失败（Failed）
+  const factory ChatStreamEvent.outbox(OutboxEntry field0) =
+      ChatStreamEvent_Outbox;
+  /// 内容安全过滤命中（拦截时其后紧跟 Error）
+  const factory ChatStreamEvent.safety(SafetyEvent field0) =
+      ChatStreamEvent_Safety;
+  /// 角色主动联系（值为对话 ID）：其后是该对话这条消息的正常流事件直到 Done
+  const factory ChatStreamEvent.checkIn(String field0) =
+      ChatStreamEvent_CheckIn;
+  /// 手动摘要的草稿（尚未写入记忆，确认后才保存，见 approve_memory_summary）
+  const factory ChatStreamEvent.summaryPreview(MemorySummary field0) =
+      ChatStreamEvent_SummaryPreview;
+  /// 快速草稿的增量（AppSettings::fast_draft 开启时，完整管线的回复到达前先行展示）
+  const factory ChatStreamEvent.draftDelta(String field0) =
+      ChatStreamEvent_DraftDelta;
+  /// 完整管线的回复（整段替换快速草稿；其后是 Completed、Done）
+  const factory ChatStreamEvent.refined(String field0) =
+      ChatStreamEvent_Refined;
+}
+
+/// 角色主动联系的设置（每个对话一份，见 watch_check_ins）
+class CheckInSchedule {
+  final String conversationId;
+  final bool enabled;
+  /// 对方沉默多少小时后由角色主动发一条消息
+  final int silenceHours;
+  /// 上次主动联系的时间；对方回复之前不会再次主动联系
+  final PlatformInt64? lastSentAt;
+
+  const CheckInSchedule({
+    required this.conversationId,
+    required this.enabled,
+    required this.silenceHours,
+    this.lastSentAt,
+  });
+
+  @override
+  int get hashCode =>
+      conversationId.hashCode ^
+      enabled.hashCode ^
+      silenceHours.hashCode ^
+      lastSentAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CheckInSchedule &&
+          runtimeType == other.runtimeType &&
+          conversationId == other.conversationId &&
+          enabled == other.enabled &&
+          silenceHours == other.silenceHours &&
+          lastSentAt == other.lastSentAt;
+}
+
+/// 认知分析结果（镜像 cognitive_engine::CognitiveAnalysis）
+class CognitiveInsight {
+  final EmotionInsight emotion;
+  final IntentKind intent;
+  final RelationshipInsight relationship;
+  final EmpathyKind empathyStrategy;
+  final List<LanguagePatternKind> detectedPatterns;
+  /// 注入给模型的认知提示
+  final String cognitivePrompt;
+
+  const CognitiveInsight({
+    required this.emotion,
+    required this.intent,
+    required this.relationship,
+    required this.empathyStrategy,
+    required this.detectedPatterns,
+    required this.cognitivePrompt,
+  });
+
+  @override
+  int get hashCode =>
+      emotion.hashCode ^
+      intent.hashCode ^
+      relationship.hashCode ^
+      empathyStrategy.hashCode ^
+      detectedPatterns.hashCode ^
+      cognitivePrompt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CognitiveInsight &&
+          runtimeType == other.runtimeType &&
+          emotion == other.emotion &&
+          intent == other.intent &&
+          relationship == other.relationship &&
+          empathyStrategy == other.empathyStrategy &&
+          detectedPatterns == other.detectedPatterns &&
+          cognitivePrompt == other.cognitivePrompt;
+}
+
+/// 生成内容强度：情绪升级、冲突与粗口的尺度
+enum ContentIntensity {
+  /// 温和：不升级冲突，不说粗口
+  mild,
+  normal,
+  /// 戏剧化：允许激烈冲突与符合人设的粗口
+  dramatic;
+
+  static Future<ContentIntensity> default_() =>
+      RustLib.instance.api.crateApiDataModelsContentIntensityDefault();
+}
+
+/// 上下文增强层开关：某些角色被个别提示层带偏时，可逐层关闭（默认全部开启）
+class ContextLayers {
+  /// 短期记忆（情绪轨迹、未展开线索）
+  final bool shortTermMemory;
+  /// 认知快照（意图、共情策略、情绪与关系数值）
+  final bool cognitiveSnapshot;
+  /// 回复多样性提示
+  final bool diversityHint;
+  /// 拟人化提示
+  final bool humanizationHint;
+  /// 知识库事实注入
+  final bool knowledgeInjection;
+
+  const ContextLayers({
+    required this.shortTermMemory,
+    required this.cognitiveSnapshot,
+    required this.diversityHint,
+    required this.humanizationHint,
+    required this.knowledgeInjection,
+  });
+
+  static Future<ContextLayers> default_() =>
+      RustLib.instance.api.crateApiDataModelsContextLayersDefault();
+
+  @override
+  int get hashCode =>
+      shortTermMemory.hashCode ^
+      cognitiveSnapshot.hashCode ^
+      diversityHint.hashCode ^
+      humanizationHint.hashCode ^
+      knowledgeInjection.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ContextLayers &&
+          runtimeType == other.runtimeType &&
+          shortTermMemory == other.shortTermMemory &&
+          cognitiveSnapshot == other.cognitiveSnapshot &&
+          diversityHint == other.diversityHint &&
+          humanizationHint == other.humanizationHint &&
+          knowledgeInjection == other.knowledgeInjection;
+}
+
+/// 对话
+class Conversation {
+  final String id;
+  final String title;
+  final List<Message> messages;
+  final String model;
+  final PlatformInt64 createdAt;
+  final PlatformInt64 updatedAt;
+  final DialogueStyle dialogueStyle;
+  final int turnCount;
+  final List<MemorySummary> memorySummaries;
+  final ConversationMode mode;
+  final ThinkingRetention thinkingRetention;
+  /// 当前所在分支（时间线）
+  final String branchId;
+  /// 当前分支的分叉点消息ID；主线为 None
+  final String? parentMessageId;
+  /// 全部分支的记录；首次分叉前为空（只有隐含的主线）。
+  /// 当前分支的消息就是 messages，其记录中的 messages/memory_summaries 留空
+  final List<ConversationBranch> branches;
+  /// 叙述视角（第一人称 / 第三人称约束）
+  final NarrationPerspective narration;
+  /// 上下文增强各层的开关
+  final ContextLayers contextLayers;
+  /// 故事收束的时间；非空时对话只读（仍可浏览）
+  final PlatformInt64? closedAt;
+  /// 回复风格偏好（长度、正式程度、表情、动作描写）
+  final ResponseStyle responseStyle;
+  /// 本对话中用户使用的人设 ID（见 PersonaStore）；None 时不注入人设
+  final String? personaId;
+  /// 标签、文件夹、置顶与归档（只影响对话列表的整理）
+  final ConversationOrganization organization;
+  /// 已移入冷存储的用户轮数：实际轮数 = cold_turns + messages 中的用户消息数
+  final int coldTurns;
+
+  const Conversation({
+    required this.id,
+    required this.title,
+    required this.messages,
+    required this.model,
+    required this.createdAt,
+    required this.updatedAt,
+    required this.dialogueStyle,
+    required this.turnCount,
+    required this.memorySummaries,
+    required this.mode,
+    required this.thinkingRetention,
+    required this.branchId,
+    this.parentMessageId,
+    required this.branches,
+    required this.narration,
+    required this.contextLayers,
+    this.closedAt,
+    required this.responseStyle,
+    this.personaId,
+    required this.organization,
+    required this.coldTurns,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      title.hashCode ^
+      messages.hashCode ^
+      model.hashCode ^
+      createdAt.hashCode ^
+      updatedAt.hashCode ^
+      dialogueStyle.hashCode ^
+      turnCount.hashCode ^
+      memorySummaries.hashCode ^
+      mode.hashCode ^
+      thinkingRetention.hashCode ^
+      branchId.hashCode ^
+      parentMessageId.hashCode ^
+      branches.hashCode ^
+      narration.hashCode ^
+      contextLayers.hashCode ^
+      closedAt.hashCode ^
+      responseStyle.hashCode ^
+      personaId.hashCode ^
+      organization.hashCode ^
+      coldTurns.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Conversation &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          title == other.title &&
+          messages == other.messages &&
+          model == other.model &&
+          createdAt == other.createdAt &&
+          updatedAt == other.updatedAt &&
+          dialogueStyle == other.dialogueStyle &&
+          turnCount == other.turnCount &&
+          memorySummaries == other.memorySummaries &&
+          mode == other.mode &&
+          thinkingRetention == other.thinkingRetention &&
+          branchId == other.branchId &&
+          parentMessageId == other.parentMessageId &&
+          branches == other.branches &&
+          narration == other.narration &&
+          contextLayers == other.contextLayers &&
+          closedAt == other.closedAt &&
+          responseStyle == other.responseStyle &&
+          personaId == other.personaId &&
+          organization == other.organization &&
+          coldTurns == other.coldTurns;
+}
+
+/// 对话分支：从某条消息分叉出的另一条时间线
+/// 未激活时保存该分支的完整历史与记忆，切换时整体换入
+class ConversationBranch {
+  final String id;
+  final String name;
+  /// 分叉点消息ID；主线为 None
+  final String? parentMessageId;
+  final List<Message> messages;
+  final int turnCount;
+  final List<MemorySummary> memorySummaries;
+  final PlatformInt64 createdAt;
+  /// 该分支已移入冷存储的用户轮数（见 Conversation::cold_turns）
+  final int coldTurns;
+
+  const ConversationBranch({
+    required this.id,
+    required this.name,
+    this.parentMessageId,
+    required this.messages,
+    required this.turnCount,
+    required this.memorySummaries,
+    required this.createdAt,
+    required this.coldTurns,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      name.hashCode ^
+      parentMessageId.hashCode ^
+      messages.hashCode ^
+      turnCount.hashCode ^
+      memorySummaries.hashCode ^
+      createdAt.hashCode ^
+      coldTurns.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ConversationBranch &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          name == other.name &&
+          parentMessageId == other.parentMessageId &&
+          messages == other.messages &&
+          turnCount == other.turnCount &&
+          memorySummaries == other.memorySummaries &&
+          createdAt == other.createdAt &&
+          coldTurns == other.coldTurns;
+}
+
+/// 故事收束的结果
+class ConversationClosure {
+  /// 角色的尾声（已作为最后一条回复写入对话）
+  final String epilogue;
+  /// 整段故事的最终回顾
+  final String recap;
+  /// 导出的 Markdown 归档路径
+  final String archivePath;
+  final PlatformInt64 closedAt;
+
+  const ConversationClosure({
+    required this.epilogue,
+    required this.recap,
+    required this.archivePath,
+    required this.closedAt,
+  });
+
+  @override
+  int get hashCode =>
+      epilogue.hashCode ^
+      recap.hashCode ^
+      archivePath.hashCode ^
+      closedAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ConversationClosure &&
+          runtimeType == other.runtimeType &&
+          epilogue == other.epilogue &&
+          recap == other.recap &&
+          archivePath == other.archivePath &&
+          closedAt == other.closedAt;
+}
+
+/// 对话列表的筛选条件（各项同时满足）
+class ConversationFilter {
+  /// 只列出该文件夹中的对话；None 为不限
+  final String? folder;
+  /// 必须同时带有的标签
+  final List<String> tags;
+  /// true 只列出已归档的对话，false 只列出未归档的
+  final bool archived;
+  /// 标题中包含的文字（不区分大小写）；空为不限
+  final String query;
+
+  const ConversationFilter({
+    this.folder,
+    required this.tags,
+    required this.archived,
+    required this.query,
+  });
+
+  static Future<ConversationFilter> default_() =>
+      RustLib.instance.api.crateApiDataModelsConversationFilterDefault();
+
+  @override
+  int get hashCode =>
+      folder.hashCode ^ tags.hashCode ^ archived.hashCode ^ query.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ConversationFilter &&
+          runtimeType == other.runtimeType &&
+          folder == other.folder &&
+          tags == other.tags &&
+          archived == other.archived &&
+          query == other.query;
+}
+
+/// 一段对话的管线分析快照
+class ConversationInsights {
+  /// 非 system 消息少于两条时为 None（与上下文增强的门槛一致）
+  final CognitiveInsight? cognitive;
+  final ShortTermInsight shortTerm;
+
+  const ConversationInsights({this.cognitive, required this.shortTerm});
+
+  @override
+  int get hashCode => cognitive.hashCode ^ shortTerm.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ConversationInsights &&
+          runtimeType == other.runtimeType &&
+          cognitive == other.cognitive &&
+          shortTerm == other.shortTerm;
+}
+
+/// 对话模式：聊天伙伴 / 长文共写
+enum ConversationMode {
+  chat,
+  coAuthor;
+
+  static Future<ConversationMode> default_() =>
+      RustLib.instance.api.crateApiDataModelsConversationModeDefault();
+}
+
+/// 对话的整理信息：标签、文件夹、置顶、归档
+class ConversationOrganization {
+  /// 标签（去重，按添加顺序）
+  final List<String> tags;
+  /// 所在文件夹；None 为未归类
+  final String? folder;
+  /// 置顶：筛选列表中排在最前
+  final bool pinned;
+  /// 归档：不出现在未归档的筛选列表中
+  final bool archived;
+
+  const ConversationOrganization({
+    required this.tags,
+    this.folder,
+    required this.pinned,
+    required this.archived,
+  });
+
+  static Future<ConversationOrganization> default_() =>
+      RustLib.instance.api.crateApiDataModelsConversationOrganizationDefault();
+
+  @override
+  int get hashCode =>
+      tags.hashCode ^ folder.hashCode ^ pinned.hashCode ^ archived.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ConversationOrganization &&
+          runtimeType == other.runtimeType &&
+          tags == other.tags &&
+          folder == other.folder &&
+          pinned == other.pinned &&
+          archived == other.archived;
+}
+
+/// 对话摘要（用于列表展示）
+class ConversationSummary {
+  final String id;
+  final String title;
+  final String lastMessagePreview;
+  final String model;
+  final PlatformInt64 updatedAt;
+  final ConversationOrganization organization;
+
+  const ConversationSummary({
+    required this.id,
+    required this.title,
+    required this.lastMessagePreview,
+    required this.model,
+    required this.updatedAt,
+    required this.organization,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      title.hashCode ^
+      lastMessagePreview.hashCode ^
+      model.hashCode ^
+      updatedAt.hashCode ^
+      organization.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ConversationSummary &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          title == other.title &&
+          lastMessagePreview == other.lastMessagePreview &&
+          model == other.model &&
+          updatedAt == other.updatedAt &&
+          organization == other.organization;
+}
+
+/// 每日心声：过去 24 小时内活跃的角色各自的一句话
+class DailyDigest {
+  final PlatformInt64 generatedAt;
+  /// 按对话活跃时间倒序
+  final List<DigestEntry> entries;
+  /// 因数量/成本上限或生成失败而跳过的对话数
+  final int skipped;
+  /// 本次生成预估消耗的 token 数
+  final int estimatedTokens;
+
+  const DailyDigest({
+    required this.generatedAt,
+    required this.entries,
+    required this.skipped,
+    required this.estimatedTokens,
+  });
+
+  @override
+  int get hashCode =>
+      generatedAt.hashCode ^
+      entries.hashCode ^
+      skipped.hashCode ^
+      estimatedTokens.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DailyDigest &&
+          runtimeType == other.runtimeType &&
+          generatedAt == other.generatedAt &&
+          entries == other.entries &&
+          skipped == other.skipped &&
+          estimatedTokens == other.estimatedTokens;
+}
+
+/// 整库导出归档的概要（导出 / 导入后返回）
+class DataExportInfo {
+  /// 归档格式版本
+  final int formatVersion;
+  /// 归档内数据的布局版本
+  final int layoutVersion;
+  final PlatformInt64 createdAt;
+  final int fileCount;
+  final BigInt totalBytes;
+
+  const DataExportInfo({
+    required this.formatVersion,
+    required this.layoutVersion,
+    required this.createdAt,
+    required this.fileCount,
+    required this.totalBytes,
+  });
+
+  @override
+  int get hashCode =>
+      formatVersion.hashCode ^
+      layoutVersion.hashCode ^
+      createdAt.hashCode ^
+      fileCount.hashCode ^
+      totalBytes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DataExportInfo &&
+          runtimeType == other.runtimeType &&
+          formatVersion == other.formatVersion &&
+          layoutVersion == other.layoutVersion &&
+          createdAt == other.createdAt &&
+          fileCount == other.fileCount &&
+          totalBytes == other.totalBytes;
+}
+
+/// 因设备压力或配额被延后的后台任务
+class DeferredJob {
+  final BackgroundJobKind kind;
+  final String conversationId;
+  final PlatformInt64 deferredAt;
+  /// 延后原因
+  final String reason;
+
+  const DeferredJob({
+    required this.kind,
+    required this.conversationId,
+    required this.deferredAt,
+    required this.reason,
+  });
+
+  @override
+  int get hashCode =>
+      kind.hashCode ^
+      conversationId.hashCode ^
+      deferredAt.hashCode ^
+      reason.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DeferredJob &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          conversationId == other.conversationId &&
+          deferredAt == other.deferredAt &&
+          reason == other.reason;
+}
+
+/// 降级/回退决策类型
+enum DegradationKind {
+  /// 思考模式只返回了思考内容，关闭思考重试
+  thinkingDropped,
+  /// 压缩上下文后重试
+  compactRetry,
+  /// 回退到快速模型
+  modelFallback,
+  /// 推理阶段超时被跳过
+  reasoningTimeout,
+  /// 长上下文蒸馏超时被跳过
+  distillationTimeout,
+  /// 历史消息因 token 预算被截断
+  contextTruncated,
+  /// 事实提取超时
+  factExtractionTimeout,
+}
+
+/// 单条降级决策记录（按轮次持久化）
+class DegradationRecord {
+  final int turn;
+  final DegradationKind kind;
+  final String model;
+  final String detail;
+  final PlatformInt64 timestamp;
+
+  const DegradationRecord({
+    required this.turn,
+    required this.kind,
+    required this.model,
+    required this.detail,
+    required this.timestamp,
+  });
+
+  @override
+  int get hashCode =>
+      turn.hashCode ^
+      kind.hashCode ^
+      model.hashCode ^
+      detail.hashCode ^
+      timestamp.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DegradationRecord &&
+          runtimeType == other.runtimeType &&
+          turn == other.turn &&
+          kind == other.kind &&
+          model == other.model &&
+          detail == other.detail &&
+          timestamp == other.timestamp;
+}
+
+/// 宿主 App 上报的设备状态，用于后台任务调度（见 job_scheduler）
+class DeviceConditions {
+  /// 电量 0.0-1.0；None 表示未知（桌面端等）
+  final double? batteryLevel;
+  final bool isCharging;
+  /// 系统省电模式
+  final bool lowPowerMode;
+  /// 按流量计费的网络（蜂窝数据等）
+  final bool meteredNetwork;
+
+  const DeviceConditions({
+    this.batteryLevel,
+    required this.isCharging,
+    required this.lowPowerMode,
+    required this.meteredNetwork,
+  });
+
+  static Future<DeviceConditions> default_() =>
+      RustLib.instance.api.crateApiDataModelsDeviceConditionsDefault();
+
+  @override
+  int get hashCode =>
+      batteryLevel.hashCode ^
+      isCharging.hashCode ^
+      lowPowerMode.hashCode ^
+      meteredNetwork.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DeviceConditions &&
+          runtimeType == other.runtimeType &&
+          batteryLevel == other.batteryLevel &&
+          isCharging == other.isCharging &&
+          lowPowerMode == other.lowPowerMode &&
+          meteredNetwork == other.meteredNetwork;
+}
+
+enum DialogueStyle {
+  free,
+  sayOnly,
+  doOnly,
+  mixed;
+
+  static Future<DialogueStyle> default_() =>
+      RustLib.instance.api.crateApiDataModelsDialogueStyleDefault();
+}
+
+/// 每日心声中的一条：某个角色今天想对用户说的一句话
+class DigestEntry {
+  final String conversationId;
+  final String title;
+  /// 角色口吻的一句话
+  final String note;
+  /// 对话最后活跃时间
+  final PlatformInt64 lastActiveAt;
+
+  const DigestEntry({
+    required this.conversationId,
+    required this.title,
+    required this.note,
+    required this.lastActiveAt,
+  });
+
+  @override
+  int get hashCode =>
+      conversationId.hashCode ^
+      title.hashCode ^
+      note.hashCode ^
+      lastActiveAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DigestEntry &&
+          runtimeType == other.runtimeType &&
+          conversationId == other.conversationId &&
+          title == other.title &&
+          note == other.note &&
+          lastActiveAt == other.lastActiveAt;
+}
+
+/// 长上下文蒸馏的持久化缓存（见 MemoryEngine::load_fresh_distilled_state）
+class DistilledSystemState {
+  final String corePrompt;
+  final BigInt lastMemoryCount;
+  final int lastMaxCompressionGen;
+  final BigInt characterPromptHash;
+  final int lastTurnCount;
+  final PlatformInt64 distilledAt;
+  final List<String> coreFactsSnapshot;
+
+  const DistilledSystemState({
+    required this.corePrompt,
+    required this.lastMemoryCount,
+    required this.lastMaxCompressionGen,
+    required this.characterPromptHash,
+    required this.lastTurnCount,
+    required this.distilledAt,
+    required this.coreFactsSnapshot,
+  });
+
+  @override
+  int get hashCode =>
+      corePrompt.hashCode ^
+      lastMemoryCount.hashCode ^
+      lastMaxCompressionGen.hashCode ^
+      characterPromptHash.hashCode ^
+      lastTurnCount.hashCode ^
+      distilledAt.hashCode ^
+      coreFactsSnapshot.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DistilledSystemState &&
+          runtimeType == other.runtimeType &&
+          corePrompt == other.corePrompt &&
+          lastMemoryCount == other.lastMemoryCount &&
+          lastMaxCompressionGen == other.lastMaxCompressionGen &&
+          characterPromptHash == other.characterPromptHash &&
+          lastTurnCount == other.lastTurnCount &&
+          distilledAt == other.distilledAt &&
+          coreFactsSnapshot == other.coreFactsSnapshot;
+}
+
+/// 草稿语气（发送前预检）
+enum DraftTone { calm, warm, playful, sad, anxious, sarcastic, hostile }
+
+/// 表情 / 颜文字使用偏好
+enum EmojiUsage {
+  auto,
+  never,
+  occasional,
+  frequent;
+
+  static Future<EmojiUsage> default_() =>
+      RustLib.instance.api.crateApiDataModelsEmojiUsageDefault();
+}
+
+/// 情感维度（对应 CognitiveEngine 感知层的八个维度）
+enum EmotionDimension {
+  joy,
+  sadness,
+  anger,
+  fear,
+  surprise,
+  intimacy,
+  trust,
+  anticipation,
+}
+
+/// 情感维度得分（镜像 cognitive_engine::EmotionVector）
+class EmotionInsight {
+  final double joy;
+  final double sadness;
+  final double anger;
+  final double fear;
+  final double surprise;
+  final double intimacy;
+  final double trust;
+  final double anticipation;
+  /// 综合效价：正=积极，负=消极
+  final double valence;
+  /// 唤醒度：0=平静，1=激动
+  final double arousal;
+
+  const EmotionInsight({
+    required this.joy,
+    required this.sadness,
+    required this.anger,
+    required this.fear,
+    required this.surprise,
+    required this.intimacy,
+    required this.trust,
+    required this.anticipation,
+    required this.valence,
+    required this.arousal,
+  });
+
+  @override
+  int get hashCode =>
+      joy.hashCode ^
+      sadness.hashCode ^
+      anger.hashCode ^
+      fear.hashCode ^
+      surprise.hashCode ^
+      intimacy.hashCode ^
+      trust.hashCode ^
+      anticipation.hashCode ^
+      valence.hashCode ^
+      arousal.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EmotionInsight &&
+          runtimeType == other.runtimeType &&
+          joy == other.joy &&
+          sadness == other.sadness &&
+          anger == other.anger &&
+          fear == other.fear &&
+          surprise == other.surprise &&
+          intimacy == other.intimacy &&
+          trust == other.trust &&
+          anticipation == other.anticipation &&
+          valence == other.valence &&
+          arousal == other.arousal;
+}
+
+/// 某一轮的情绪（镜像 memory_engine::EmotionalSnapshot）
+class EmotionalTurnInsight {
+  final int turn;
+  final double valence;
+  final double arousal;
+  final String dominantEmotion;
+
+  const EmotionalTurnInsight({
+    required this.turn,
+    required this.valence,
+    required this.arousal,
+    required this.dominantEmotion,
+  });
+
+  @override
+  int get hashCode =>
+      turn.hashCode ^
+      valence.hashCode ^
+      arousal.hashCode ^
+      dominantEmotion.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EmotionalTurnInsight &&
+          runtimeType == other.runtimeType &&
+          turn == other.turn &&
+          valence == other.valence &&
+          arousal == other.arousal &&
+          dominantEmotion == other.dominantEmotion;
+}
+
+/// 共情策略（镜像 cognitive_engine::EmpathyStrategy）
+enum EmpathyKind {
+  mirror,
+  accompany,
+  distract,
+  responsive,
+  playfulCounter,
+  gentleFirm,
+  proactiveCare,
+  naturalFlow,
+  giveSpace,
+  escalate,
+}
+
+/// 静态加密的密钥来源
+enum EncryptionKeySource {
+  /// 由用户口令派生
+  passphrase,
+  /// 平台密钥库（Keychain / Keystore）中保存的 32 字节密钥
+  keystore,
+}
+
+/// 启用 / 解锁 / 停用静态加密时提供的凭据
+@freezed
+sealed class EncryptionSecret with _$EncryptionSecret {
+  const EncryptionSecret._();
+
+  const factory EncryptionSecret.passphrase(String field0) =
+      EncryptionSecret_Passphrase;
+  const factory EncryptionSecret.keystore(Uint8List field0) =
+      EncryptionSecret_Keystore;
+}
+
+/// 静态加密状态
+class EncryptionStatus {
+  final bool enabled;
+  /// 本次启动后是否已解锁（未启用时恒为 true）
+  final bool unlocked;
+  final EncryptionKeySource? source;
+
+  const EncryptionStatus({
+    required this.enabled,
+    required this.unlocked,
+    this.source,
+  });
+
+  @override
+  int get hashCode => enabled.hashCode ^ unlocked.hashCode ^ source.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EncryptionStatus &&
+          runtimeType == other.runtimeType &&
+          enabled == other.enabled &&
+          unlocked == other.unlocked &&
+          source == other.source;
+}
+
+/// 事实冲突：同一主体、同一关系出现了不同客体，新事实生效、旧事实归档
+class FactConflict {
+  final String supersededFactId;
+  final String supersededContent;
+  final String activeFactId;
+  /// 取代它的事实内容（该事实之后被删除时为空）
+  final String activeContent;
+  final PlatformInt64 detectedAt;
+
+  const FactConflict({
+    required this.supersededFactId,
+    required this.supersededContent,
+    required this.activeFactId,
+    required this.activeContent,
+    required this.detectedAt,
+  });
+
+  @override
+  int get hashCode =>
+      supersededFactId.hashCode ^
+      supersededContent.hashCode ^
+      activeFactId.hashCode ^
+      activeContent.hashCode ^
+      detectedAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FactConflict &&
+          runtimeType == other.runtimeType &&
+          supersededFactId == other.supersededFactId &&
+          supersededContent == other.supersededContent &&
+          activeFactId == other.activeFactId &&
+          activeContent == other.activeContent &&
+          detectedAt == other.detectedAt;
+}
+
+/// 回复结构指纹（镜像 memory_engine::ResponseFingerprint）
+class FingerprintInsight {
+  final String openingChars;
+  final int paragraphCount;
+  final double avgSentenceLen;
+  final String endingChars;
+  final bool endsWithQuestion;
+  final int totalLength;
+  final bool hasActionMarker;
+  final bool hasListFormat;
+  /// warm / neutral / cold / playful / concerned
+  final String emotionalTone;
+
+  const FingerprintInsight({
+    required this.openingChars,
+    required this.paragraphCount,
+    required this.avgSentenceLen,
+    required this.endingChars,
+    required this.endsWithQuestion,
+    required this.totalLength,
+    required this.hasActionMarker,
+    required this.hasListFormat,
+    required this.emotionalTone,
+  });
+
+  @override
+  int get hashCode =>
+      openingChars.hashCode ^
+      paragraphCount.hashCode ^
+      avgSentenceLen.hashCode ^
+      endingChars.hashCode ^
+      endsWithQuestion.hashCode ^
+      totalLength.hashCode ^
+      hasActionMarker.hashCode ^
+      hasListFormat.hashCode ^
+      emotionalTone.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FingerprintInsight &&
+          runtimeType == other.runtimeType &&
+          openingChars == other.openingChars &&
+          paragraphCount == other.paragraphCount &&
+          avgSentenceLen == other.avgSentenceLen &&
+          endingChars == other.endingChars &&
+          endsWithQuestion == other.endsWithQuestion &&
+          totalLength == other.totalLength &&
+          hasActionMarker == other.hasActionMarker &&
+          hasListFormat == other.hasListFormat &&
+          emotionalTone == other.emotionalTone;
+}
+
+/// 用语正式程度偏好
+enum Formality {
+  auto,
+  casual,
+  neutral,
+  formal;
+
+  static Future<Formality> default_() =>
+      RustLib.instance.api.crateApiDataModelsFormalityDefault();
+}
+
+/// 一条回复的生成统计：结束原因、用量、回退后实际使用的模型与耗时
+class GenerationCompletedEvent {
+  final String messageId;
+  final GenerationMetadata metadata;
+
+  const GenerationCompletedEvent({
+    required this.messageId,
+    required this.metadata,
+  });
+
+  @override
+  int get hashCode => messageId.hashCode ^ metadata.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GenerationCompletedEvent &&
+          runtimeType == other.runtimeType &&
+          messageId == other.messageId &&
+          metadata == other.metadata;
+}
+
+/// 单条回复的生成信息，供消息详情页展示
+class GenerationMetadata {
+  /// 实际生成回复的模型（优先取服务端返回的名称）
+  final String model;
+  /// 结束原因（stop / length / sensitive / end_turn …），服务端未给出时为 None
+  final String? finishReason;
+  /// token 用量；服务端未返回用量时均为 0
+  final int promptTokens;
+  final int completionTokens;
+  final int totalTokens;
+  /// 输入中命中提供方上下文缓存的 token 数（服务端未返回时为 0）
+  final int cachedTokens;
+  /// false 表示弱网模式下以非流式请求完成
+  final bool streamed;
+  /// 流在中途断开，回复只保留了已收到的部分
+  final bool interrupted;
+  /// 本轮生成中触发的降级与回退（重试、压缩、换模型等的说明）
+  final List<String> fallbacks;
+  /// 从开始生成到回复落盘的耗时（毫秒）
+  final PlatformInt64 latencyMs;
+
+  const GenerationMetadata({
+    required this.model,
+    this.finishReason,
+    required this.promptTokens,
+    required this.completionTokens,
+    required this.totalTokens,
+    required this.cachedTokens,
+    required this.streamed,
+    required this.interrupted,
+    required this.fallbacks,
+    required this.latencyMs,
+  });
+
+  static Future<GenerationMetadata> default_() =>
+      RustLib.instance.api.crateApiDataModelsGenerationMetadataDefault();
+
+  @override
+  int get hashCode =>
+      model.hashCode ^
+      finishReason.hashCode ^
+      promptTokens.hashCode ^
+      completionTokens.hashCode ^
+      totalTokens.hashCode ^
+      cachedTokens.hashCode ^
+      streamed.hashCode ^
+      interrupted.hashCode ^
+      fallbacks.hashCode ^
+      latencyMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GenerationMetadata &&
+          runtimeType == other.runtimeType &&
+          model == other.model &&
+          finishReason == other.finishReason &&
+          promptTokens == other.promptTokens &&
+          completionTokens == other.completionTokens &&
+          totalTokens == other.totalTokens &&
+          cachedTokens == other.cachedTokens &&
+          streamed == other.streamed &&
+          interrupted == other.interrupted &&
+          fallbacks == other.fallbacks &&
+          latencyMs == other.latencyMs;
+}
+
+/// 知识图谱的边：一条事实，由主体指向客体
+class GraphEdge {
+  final String factId;
+  final String source;
+  final String target;
+  final String relation;
+  /// 事实分类标签（身份 / 关系 / 偏好……）
+  final String category;
+
+  const GraphEdge({
+    required this.factId,
+    required this.source,
+    required this.target,
+    required this.relation,
+    required this.category,
+  });
+
+  @override
+  int get hashCode =>
+      factId.hashCode ^
+      source.hashCode ^
+      target.hashCode ^
+      relation.hashCode ^
+      category.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GraphEdge &&
+          runtimeType == other.runtimeType &&
+          factId == other.factId &&
+          source == other.source &&
+          target == other.target &&
+          relation == other.relation &&
+          category == other.category;
+}
+
+/// 知识图谱导出格式
+enum GraphExportFormat { json, graphMl }
+
+/// 知识图谱节点：事实三元组中的主体 / 客体
+class GraphNode {
+  /// 归一化后的实体名（去空白、小写），用作节点 ID
+  final String id;
+  /// 首次出现时的原文
+  final String label;
+  /// 相连的边数
+  final int degree;
+
+  const GraphNode({
+    required this.id,
+    required this.label,
+    required this.degree,
+  });
+
+  @override
+  int get hashCode => id.hashCode ^ label.hashCode ^ degree.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GraphNode &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          label == other.label &&
+          degree == other.degree;
+}
+
+/// 群聊中的一个角色：独立的人设与知识/记忆命名空间
+class GroupCharacter {
+  /// 为空时由存储层生成
+  final String id;
+  final String name;
+  /// 该角色的人设（system prompt）
+  final String systemPrompt;
+
+  const GroupCharacter({
+    required this.id,
+    required this.name,
+    required this.systemPrompt,
+  });
+
+  @override
+  int get hashCode => id.hashCode ^ name.hashCode ^ systemPrompt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GroupCharacter &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          name == other.name &&
+          systemPrompt == other.systemPrompt;
+}
+
+/// 多角色群聊配置（按对话存放）
+class GroupChat {
+  final String conversationId;
+  final List<GroupCharacter> characters;
+  final TurnPolicy turnPolicy;
+
+  const GroupChat({
+    required this.conversationId,
+    required this.characters,
+    required this.turnPolicy,
+  });
+
+  @override
+  int get hashCode =>
+      conversationId.hashCode ^ characters.hashCode ^ turnPolicy.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GroupChat &&
+          runtimeType == other.runtimeType &&
+          conversationId == other.conversationId &&
+          characters == other.characters &&
+          turnPolicy == other.turnPolicy;
+}
+
+/// 一次数据维护的结果
+class HousekeepingReport {
+  /// 本次自动归档的对话ID
+  final List<String> archived;
+  /// 转入冷存储的消息条数
+  final int rolledOverMessages;
+  /// 删除的孤立文件数
+  final int purgedFiles;
+
+  const HousekeepingReport({
+    required this.archived,
+    required this.rolledOverMessages,
+    required this.purgedFiles,
+  });
+
+  static Future<HousekeepingReport> default_() =>
+      RustLib.instance.api.crateApiDataModelsHousekeepingReportDefault();
+
+  @override
+  int get hashCode =>
+      archived.hashCode ^ rolledOverMessages.hashCode ^ purgedFiles.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is HousekeepingReport &&
+          runtimeType == other.runtimeType &&
+          archived == other.archived &&
+          rolledOverMessages == other.rolledOverMessages &&
+          purgedFiles == other.purgedFiles;
+}
+
+/// 重建索引的范围
+enum IndexScope {
+  all,
+  /// 知识库：事实关键词 + 倒排索引
+  knowledge,
+  /// 记忆摘要关键词
+  memory;
+
+  static Future<IndexScope> default_() =>
+      RustLib.instance.api.crateApiDataModelsIndexScopeDefault();
+}
+
+class IntegrityIssue {
+  final IntegrityIssueKind kind;
+  final String detail;
+  final bool repaired;
+
+  const IntegrityIssue({
+    required this.kind,
+    required this.detail,
+    required this.repaired,
+  });
+
+  @override
+  int get hashCode => kind.hashCode ^ detail.hashCode ^ repaired.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is IntegrityIssue &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          detail == other.detail &&
+          repaired == other.repaired;
+}
+
+/// 完整性问题类型
+enum IntegrityIssueKind {
+  /// turn_count 与实际用户消息轮数不一致
+  turnCountMismatch,
+  /// 记忆摘要的轮次范围越界或倒置
+  summaryRangeInvalid,
+  /// 记忆索引文件与对话内嵌摘要不同步
+  summaryIndexDesync,
+  /// 事实的来源轮次不存在
+  orphanFact,
+  /// 倒排索引指向不存在的事实，或事实未被索引
+  danglingIndexEntry,
+}
+
+/// 对话完整性检查报告
+class IntegrityReport {
+  final String conversationId;
+  /// 按用户消息统计的实际轮数
+  final int actualTurns;
+  /// 对话记录中的 turn_count
+  final int recordedTurnCount;
+  final List<IntegrityIssue> issues;
+  final PlatformInt64 checkedAt;
+
+  const IntegrityReport({
+    required this.conversationId,
+    required this.actualTurns,
+    required this.recordedTurnCount,
+    required this.issues,
+    required this.checkedAt,
+  });
+
+  @override
+  int get hashCode =>
+      conversationId.hashCode ^
+      actualTurns.hashCode ^
+      recordedTurnCount.hashCode ^
+      issues.hashCode ^
+      checkedAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is IntegrityReport &&
+          runtimeType == other.runtimeType &&
+          conversationId == other.conversationId &&
+          actualTurns == other.actualTurns &&
+          recordedTurnCount == other.recordedTurnCount &&
+          issues == other.issues &&
+          checkedAt == other.checkedAt;
+}
+
+/// 对话意图（镜像 cognitive_engine::DialogueIntent）
+enum IntentKind {
+  seekingComfort,
+  expressingAffection,
+  expressingDispleasure,
+  testingBoundary,
+  sharingDaily,
+  seekingResponse,
+  emotionalVenting,
+  playful,
+  reconciling,
+  farewell,
+  withdrawn,
+  deepSharing,
+}
+
+/// 后台任务调度统计
+class JobSchedulerStats {
+  /// 正在执行的后台任务数
+  final int running;
+  final BigInt completed;
+  /// 超出单次预算（超时）被中止的任务数
+  final BigInt timedOut;
+  /// 累计被延后的次数
+  final BigInt deferredTotal;
+  /// 等待补跑的任务
+  final List<DeferredJob> pending;
+
+  const JobSchedulerStats({
+    required this.running,
+    required this.completed,
+    required this.timedOut,
+    required this.deferredTotal,
+    required this.pending,
+  });
+
+  static Future<JobSchedulerStats> default_() =>
+      RustLib.instance.api.crateApiDataModelsJobSchedulerStatsDefault();
+
+  @override
+  int get hashCode =>
+      running.hashCode ^
+      completed.hashCode ^
+      timedOut.hashCode ^
+      deferredTotal.hashCode ^
+      pending.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is JobSchedulerStats &&
+          runtimeType == other.runtimeType &&
+          running == other.running &&
+          completed == other.completed &&
+          timedOut == other.timedOut &&
+          deferredTotal == other.deferredTotal &&
+          pending == other.pending;
+}
+
+/// 对话与角色层 / 世界层的绑定（未绑定的层不参与检索与晋升）
+class KnowledgeBinding {
+  /// 对话中 AI 扮演的角色；群聊角色直接使用 GroupCharacter::id
+  final String? characterId;
+  /// 角色名：主体为该名字的事实才晋升到角色层（群聊取 GroupCharacter::name）
+  final String? characterName;
+  final String? worldId;
+
+  const KnowledgeBinding({this.characterId, this.characterName, this.worldId});
+
+  static Future<KnowledgeBinding> default_() =>
+      RustLib.instance.api.crateApiDataModelsKnowledgeBindingDefault();
+
+  @override
+  int get hashCode =>
+      characterId.hashCode ^ characterName.hashCode ^ worldId.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is KnowledgeBinding &&
+          runtimeType == other.runtimeType &&
+          characterId == other.characterId &&
+          characterName == other.characterName &&
+          worldId == other.worldId;
+}
+
+/// 知识图谱（或其子图）
+class KnowledgeGraphView {
+  final List<GraphNode> nodes;
+  final List<GraphEdge> edges;
+
+  const KnowledgeGraphView({required this.nodes, required this.edges});
+
+  static Future<KnowledgeGraphView> default_() =>
+      RustLib.instance.api.crateApiDataModelsKnowledgeGraphViewDefault();
+
+  @override
+  int get hashCode => nodes.hashCode ^ edges.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is KnowledgeGraphView &&
+          runtimeType == other.runtimeType &&
+          nodes == other.nodes &&
+          edges == other.edges;
+}
+
+/// 知识层：检索时从具体到宽泛依次合并，内容冲突时具体的一层为准
+enum KnowledgeLayer {
+  /// 单个对话（群聊中还包括某个角色在该对话里的视角）
+  conversation,
+  /// 某个角色：同一角色的群聊、番外对话共享
+  character,
+  /// 世界观：绑定同一世界的所有对话共享
+  world,
+}
+
+/// 语言模式（镜像 cognitive_engine::LanguagePattern）
+enum LanguagePatternKind {
+  negation,
+  sarcasm,
+  hesitation,
+  repetition,
+  urgent,
+  dragging,
+  contradictory,
+  probing,
+  coquettish,
+  defensive,
+  suppressed,
+  topicAvoidance,
+}
+
+/// 数据目录布局校验结果（与清单快照比对）
+class LayoutVerification {
+  final int version;
+  final int checkedFiles;
+  /// 清单中有、磁盘上缺失的文件
+  final List<String> missing;
+  /// 内容校验和不一致的文件
+  final List<String> corrupted;
+  /// 磁盘上有、清单中没有的文件
+  final List<String> unexpected;
+
+  const LayoutVerification({
+    required this.version,
+    required this.checkedFiles,
+    required this.missing,
+    required this.corrupted,
+    required this.unexpected,
+  });
+
+  @override
+  int get hashCode =>
+      version.hashCode ^
+      checkedFiles.hashCode ^
+      missing.hashCode ^
+      corrupted.hashCode ^
+      unexpected.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LayoutVerification &&
+          runtimeType == other.runtimeType &&
+          version == other.version &&
+          checkedFiles == other.checkedFiles &&
+          missing == other.missing &&
+          corrupted == other.corrupted &&
+          unexpected == other.unexpected;
+}
+
+/// 用户自定义的情感词条（emotion_lexicon.json），与内置词典合并后参与情感感知
+class LexiconEntry {
+  final String word;
+  final EmotionDimension dimension;
+  /// 强度 0 ~ 1，与内置词条同一量纲
+  final double weight;
+
+  const LexiconEntry({
+    required this.word,
+    required this.dimension,
+    required this.weight,
+  });
+
+  @override
+  int get hashCode => word.hashCode ^ dimension.hashCode ^ weight.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LexiconEntry &&
+          runtimeType == other.runtimeType &&
+          word == other.word &&
+          dimension == other.dimension &&
+          weight == other.weight;
+}
+
+/// 摘要链接到的一条知识库事实
+class LinkedFact {
+  final String factId;
+  final String content;
+  final int sourceTurn;
+  /// 同样链接到该事实的摘要 ID（含当前摘要）
+  final List<String> summaryIds;
+
+  const LinkedFact({
+    required this.factId,
+    required this.content,
+    required this.sourceTurn,
+    required this.summaryIds,
+  });
+
+  @override
+  int get hashCode =>
+      factId.hashCode ^
+      content.hashCode ^
+      sourceTurn.hashCode ^
+      summaryIds.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LinkedFact &&
+          runtimeType == other.runtimeType &&
+          factId == other.factId &&
+          content == other.content &&
+          sourceTurn == other.sourceTurn &&
+          summaryIds == other.summaryIds;
+}
+
+/// 用户编写的世界设定条目（Lorebook）：最近对话命中触发词时注入上下文
+class LoreEntry {
+  /// 为空时由存储层生成
+  final String id;
+  final String title;
+  final String content;
+  /// 触发词（不区分大小写，命中任意一个即触发）
+  final List<String> keywords;
+  /// 插入深度：条目之后保留的历史消息条数（0 = 放在全部历史之后）
+  final int insertionDepth;
+  /// 优先级：预算不足时高优先级先入选
+  final int priority;
+  final bool enabled;
+
+  const LoreEntry({
+    required this.id,
+    required this.title,
+    required this.content,
+    required this.keywords,
+    required this.insertionDepth,
+    required this.priority,
+    required this.enabled,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      title.hashCode ^
+      content.hashCode ^
+      keywords.hashCode ^
+      insertionDepth.hashCode ^
+      priority.hashCode ^
+      enabled.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is LoreEntry &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          title == other.title &&
+          content == other.content &&
+          keywords == other.keywords &&
+          insertionDepth == other.insertionDepth &&
+          priority == other.priority &&
+          enabled == other.enabled;
+}
+
+class MemoryContextCard {
+  final String sourceRange;
+  final List<String> topicTags;
+  final List<String> keyEntities;
+  final String emotionalTone;
+  final List<String> causalLinks;
+
+  const MemoryContextCard({
+    required this.sourceRange,
+    required this.topicTags,
+    required this.keyEntities,
+    required this.emotionalTone,
+    required this.causalLinks,
+  });
+
+  @override
+  int get hashCode =>
+      sourceRange.hashCode ^
+      topicTags.hashCode ^
+      keyEntities.hashCode ^
+      emotionalTone.hashCode ^
+      causalLinks.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MemoryContextCard &&
+          runtimeType == other.runtimeType &&
+          sourceRange == other.sourceRange &&
+          topicTags == other.topicTags &&
+          keyEntities == other.keyEntities &&
+          emotionalTone == other.emotionalTone &&
+          causalLinks == other.causalLinks;
+}
+
+/// 记忆溯源：一条摘要及其链接的事实
+class MemoryExplanation {
+  final String summaryId;
+  final String summary;
+  final int turnRangeStart;
+  final int turnRangeEnd;
+  final int compressionGeneration;
+  final List<LinkedFact> linkedFacts;
+
+  const MemoryExplanation({
+    required this.summaryId,
+    required this.summary,
+    required this.turnRangeStart,
+    required this.turnRangeEnd,
+    required this.compressionGeneration,
+    required this.linkedFacts,
+  });
+
+  @override
+  int get hashCode =>
+      summaryId.hashCode ^
+      summary.hashCode ^
+      turnRangeStart.hashCode ^
+      turnRangeEnd.hashCode ^
+      compressionGeneration.hashCode ^
+      linkedFacts.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MemoryExplanation &&
+          runtimeType == other.runtimeType &&
+          summaryId == other.summaryId &&
+          summary == other.summary &&
+          turnRangeStart == other.turnRangeStart &&
+          turnRangeEnd == other.turnRangeEnd &&
+          compressionGeneration == other.compressionGeneration &&
+          linkedFacts == other.linkedFacts;
+}
+
+/// 记忆保真度审计结果：历史检查点中的事实有多少仍能从当前摘要推出
+class MemoryFidelityReport {
+  final String conversationId;
+  /// 保真度 0.0-1.0（抽样事实中仍可推出的比例）
+  final double score;
+  final int sampledFacts;
+  /// 抽样中已无法从当前摘要推出的事实
+  final List<String> lostFacts;
+  /// 上一次审计的保真度
+  final double? previousScore;
+  /// 保真度过低或较上次明显下降
+  final bool alert;
+  final PlatformInt64 auditedAt;
+
+  const MemoryFidelityReport({
+    required this.conversationId,
+    required this.score,
+    required this.sampledFacts,
+    required this.lostFacts,
+    this.previousScore,
+    required this.alert,
+    required this.auditedAt,
+  });
+
+  @override
+  int get hashCode =>
+      conversationId.hashCode ^
+      score.hashCode ^
+      sampledFacts.hashCode ^
+      lostFacts.hashCode ^
+      previousScore.hashCode ^
+      alert.hashCode ^
+      auditedAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MemoryFidelityReport &&
+          runtimeType == other.runtimeType &&
+          conversationId == other.conversationId &&
+          score == other.score &&
+          sampledFacts == other.sampledFacts &&
+          lostFacts == other.lostFacts &&
+          previousScore == other.previousScore &&
+          alert == other.alert &&
+          auditedAt == other.auditedAt;
+}
+
+class MemorySearchResult {
+  final String summary;
+  final List<String> coreFacts;
+  final double relevanceScore;
+
+  const MemorySearchResult({
+    required this.summary,
+    required this.coreFacts,
+    required this.relevanceScore,
+  });
+
+  @override
+  int get hashCode =>
+      summary.hashCode ^ coreFacts.hashCode ^ relevanceScore.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MemorySearchResult &&
+          runtimeType == other.runtimeType &&
+          summary == other.summary &&
+          coreFacts == other.coreFacts &&
+          relevanceScore == other.relevanceScore;
+}
+
+class MemorySummary {
+  final String id;
+  final String summary;
+  final List<String> coreFacts;
+  final int turnRangeStart;
+  final int turnRangeEnd;
+  final PlatformInt64 createdAt;
+  final List<String> keywords;
+  final int compressionGeneration;
+  final MemoryContextCard? contextCard;
+  final List<MemoryTier> factTiers;
+  /// 知识库中描述同一内容的事实 ID（与 Fact::summary_ids 互为反向链接）
+  final List<String> linkedFactIds;
+  /// 用户置顶：整条摘要不参与分级合并，原样保留
+  final bool pinned;
+  /// 用户置顶的核心事实（core_facts 中的原文）：合并时一字不改地带入合并结果
+  final List<String> pinnedFacts;
+  /// 覆盖轮次内的消息被编辑过：内容已过时，等待后台按编辑后的原文重新总结
+  final bool stale;
+
+  const MemorySummary({
+    required this.id,
+    required this.summary,
+    required this.coreFacts,
+    required this.turnRangeStart,
+    required this.turnRangeEnd,
+    required this.createdAt,
+    required this.keywords,
+    required this.compressionGeneration,
+    this.contextCard,
+    required this.factTiers,
+    required this.linkedFactIds,
+    required this.pinned,
+    required this.pinnedFacts,
+    required this.stale,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      summary.hashCode ^
+      coreFacts.hashCode ^
+      turnRangeStart.hashCode ^
+      turnRangeEnd.hashCode ^
+      createdAt.hashCode ^
+      keywords.hashCode ^
+      compressionGeneration.hashCode ^
+      contextCard.hashCode ^
+      factTiers.hashCode ^
+      linkedFactIds.hashCode ^
+      pinned.hashCode ^
+      pinnedFacts.hashCode ^
+      stale.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MemorySummary &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          summary == other.summary &&
+          coreFacts == other.coreFacts &&
+          turnRangeStart == other.turnRangeStart &&
+          turnRangeEnd == other.turnRangeEnd &&
+          createdAt == other.createdAt &&
+          keywords == other.keywords &&
+          compressionGeneration == other.compressionGeneration &&
+          contextCard == other.contextCard &&
+          factTiers == other.factTiers &&
+          linkedFactIds == other.linkedFactIds &&
+          pinned == other.pinned &&
+          pinnedFacts == other.pinnedFacts &&
+          stale == other.stale;
+}
+
+enum MemoryTier {
+  identity,
+  criticalEvent,
+  relationshipDynamic,
+  currentState,
+  sceneDetail,
+}
+
+class Message {
+  final String id;
+  final MessageRole role;
+  final String content;
+  final String? thinkingContent;
+  final String model;
+  final PlatformInt64 timestamp;
+  final MessageType messageType;
+  /// 回复的生成信息（结束原因、用量、降级）；用户消息与旧数据为 None
+  final GenerationMetadata? generationMetadata;
+  /// 群聊中发言角色的ID（GroupCharacter::id）；单角色对话与用户消息为 None
+  final String? characterId;
+  /// 用户附带的图片；图片数据单独存放，这里只保留元信息
+  final List<MessageAttachment> attachments;
+  /// 回复语音的本地文件路径（见 tts）；未合成时为 None
+  final String? audioPath;
+  /// 同一轮生成过的全部版本（含当前显示的这一版）；从未重新生成过时为空
+  final List<MessageAlternative> alternatives;
+  /// 当前显示的是 alternatives 中的第几版
+  final int selectedAlternative;
+  /// 用户收藏：收藏过的时刻在长期记忆检索中加权（见 MemoryEngine::starred_turns）
+  final bool starred;
+  /// 用户对这条消息的表情回应（去重，按添加顺序）
+  final List<String> reactions;
+  /// 被编辑替换掉的旧版本（旧的在前）；撤销编辑时从末尾恢复
+  final List<MessageEdit> editHistory;
+
+  const Message({
+    required this.id,
+    required this.role,
+    required this.content,
+    this.thinkingContent,
+    required this.model,
+    required this.timestamp,
+    required this.messageType,
+    this.generationMetadata,
+    this.characterId,
+    required this.attachments,
+    this.audioPath,
+    required this.alternatives,
+    required this.selectedAlternative,
+    required this.starred,
+    required this.reactions,
+    required this.editHistory,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      role.hashCode ^
+      content.hashCode ^
+      thinkingContent.hashCode ^
+      model.hashCode ^
+      timestamp.hashCode ^
+      messageType.hashCode ^
+      generationMetadata.hashCode ^
+      characterId.hashCode ^
+      attachments.hashCode ^
+      audioPath.hashCode ^
+      alternatives.hashCode ^
+      selectedAlternative.hashCode ^
+      starred.hashCode ^
+      reactions.hashCode ^
+      editHistory.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Message &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          role == other.role &&
+          content == other.content &&
+          thinkingContent == other.thinkingContent &&
+          model == other.model &&
+          timestamp == other.timestamp &&
+          messageType == other.messageType &&
+          generationMetadata == other.generationMetadata &&
+          characterId == other.characterId &&
+          attachments == other.attachments &&
+          audioPath == other.audioPath &&
+          alternatives == other.alternatives &&
+          selectedAlternative == other.selectedAlternative &&
+          starred == other.starred &&
+          reactions == other.reactions &&
+          editHistory == other.editHistory;
+}
+
+/// 同一轮回复的一个版本（重新生成时追加，可左右切换）
+class MessageAlternative {
+  final String content;
+  final String? thinkingContent;
+  final String model;
+  final PlatformInt64 timestamp;
+  final GenerationMetadata? generationMetadata;
+  final String? audioPath;
+
+  const MessageAlternative({
+    required this.content,
+    this.thinkingContent,
+    required this.model,
+    required this.timestamp,
+    this.generationMetadata,
+    this.audioPath,
+  });
+
+  @override
+  int get hashCode =>
+      content.hashCode ^
+      thinkingContent.hashCode ^
+      model.hashCode ^
+      timestamp.hashCode ^
+      generationMetadata.hashCode ^
+      audioPath.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MessageAlternative &&
+          runtimeType == other.runtimeType &&
+          content == other.content &&
+          thinkingContent == other.thinkingContent &&
+          model == other.model &&
+          timestamp == other.timestamp &&
+          generationMetadata == other.generationMetadata &&
+          audioPath == other.audioPath;
+}
+
+/// 消息附件（图片）
+class MessageAttachment {
+  final String id;
+  final String mimeType;
+  /// 远程图片地址；发送时也可传 data URL，落盘后本地图片为 None
+  final String? url;
+  /// 视觉模型看到的内容描述（识图后写回，供后续轮次与知识库使用）
+  final String? caption;
+
+  const MessageAttachment({
+    required this.id,
+    required this.mimeType,
+    this.url,
+    this.caption,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^ mimeType.hashCode ^ url.hashCode ^ caption.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MessageAttachment &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          mimeType == other.mimeType &&
+          url == other.url &&
+          caption == other.caption;
+}
+
+/// 消息被编辑前的一个版本
+class MessageEdit {
+  final String content;
+  /// 这一版原本的时间戳
+  final PlatformInt64 timestamp;
+  /// 被替换的时刻
+  final PlatformInt64 editedAt;
+
+  const MessageEdit({
+    required this.content,
+    required this.timestamp,
+    required this.editedAt,
+  });
+
+  @override
+  int get hashCode => content.hashCode ^ timestamp.hashCode ^ editedAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MessageEdit &&
+          runtimeType == other.runtimeType &&
+          content == other.content &&
+          timestamp == other.timestamp &&
+          editedAt == other.editedAt;
+}
+
+enum MessageRole { user, assistant, system }
+
+enum MessageType {
+  say,
+  do_,
+  mixed,
+  /// 共写模式产出的正文片段（与聊天消息并存于同一对话）
+  document,
+  /// 第三人称的旁白 / 场景描写（如「【旁白】夜幕降临……」）
+  narration,
+  /// 跳出角色的场外交流（如「(OOC: 这段剧情是不是太快了)」）
+  outOfCharacter;
+
+  static Future<MessageType> default_() =>
+      RustLib.instance.api.crateApiDataModelsMessageTypeDefault();
+}
+
+/// 一轮回复中计时的阶段
+enum MetricPhase {
+  /// 知识库 / 向量检索
+  retrieval,
+  /// 长上下文蒸馏
+  distillation,
+  /// 推理模型分析
+  reasoning,
+  /// 对话模型生成（含回退重试）
+  chat,
+}
+
+/// 调试面板的性能指标：最近轮次（新的在前）与平均值
+class MetricsSnapshot {
+  final List<TurnMetrics> turns;
+  final PlatformInt64? avgFirstTokenMs;
+  /// 各阶段在执行过的轮次中的平均耗时
+  final List<PhaseDuration> avgPhaseMs;
+  final int totalRetries;
+
+  const MetricsSnapshot({
+    required this.turns,
+    this.avgFirstTokenMs,
+    required this.avgPhaseMs,
+    required this.totalRetries,
+  });
+
+  static Future<MetricsSnapshot> default_() =>
+      RustLib.instance.api.crateApiDataModelsMetricsSnapshotDefault();
+
+  @override
+  int get hashCode =>
+      turns.hashCode ^
+      avgFirstTokenMs.hashCode ^
+      avgPhaseMs.hashCode ^
+      totalRetries.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MetricsSnapshot &&
+          runtimeType == other.runtimeType &&
+          turns == other.turns &&
+          avgFirstTokenMs == other.avgFirstTokenMs &&
+          avgPhaseMs == other.avgPhaseMs &&
+          totalRetries == other.totalRetries;
+}
+
+class ModelInfo {
+  final String id;
+  final String name;
+  final BigInt contextTokens;
+  final BigInt maxOutputTokens;
+  final bool supportsThinking;
+
+  const ModelInfo({
+    required this.id,
+    required this.name,
+    required this.contextTokens,
+    required this.maxOutputTokens,
+    required this.supportsThinking,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      name.hashCode ^
+      contextTokens.hashCode ^
+      maxOutputTokens.hashCode ^
+      supportsThinking.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ModelInfo &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          name == other.name &&
+          contextTokens == other.contextTokens &&
+          maxOutputTokens == other.maxOutputTokens &&
+          supportsThinking == other.supportsThinking;
+}
+
+/// 角色跨会话延续的心情（见 mood 模块）
+class MoodState {
+  /// 效价：-1 消极 ~ 1 积极
+  final double valence;
+  /// 唤醒度：0 平静 ~ 1 激动
+  final double arousal;
+  /// 心情名称（兴奋 / 愉快 / 平静 / 恼火……）
+  final String mood;
+  /// 最后一次互动的时间（毫秒）
+  final PlatformInt64 updatedAt;
+
+  const MoodState({
+    required this.valence,
+    required this.arousal,
+    required this.mood,
+    required this.updatedAt,
+  });
+
+  @override
+  int get hashCode =>
+      valence.hashCode ^ arousal.hashCode ^ mood.hashCode ^ updatedAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MoodState &&
+          runtimeType == other.runtimeType &&
+          valence == other.valence &&
+          arousal == other.arousal &&
+          mood == other.mood &&
+          updatedAt == other.updatedAt;
+}
+
+/// 叙述视角：不限 / 第一人称角色口吻 / 第三人称小说旁白
+enum NarrationPerspective {
+  free,
+  firstPerson,
+  thirdPerson;
+
+  static Future<NarrationPerspective> default_() =>
+      RustLib.instance.api.crateApiDataModelsNarrationPerspectiveDefault();
+}
+
+/// 离线时暂存的一次发送：网络恢复后按入队顺序重放
+class OutboxEntry {
+  final String id;
+  final String conversationId;
+  final String content;
+  final List<MessageAttachment> attachments;
+  final String model;
+  final bool enableThinking;
+  final OutboxStatus status;
+  final PlatformInt64 queuedAt;
+  /// 最近一次重放失败的原因
+  final String? lastError;
+
+  const OutboxEntry({
+    required this.id,
+    required this.conversationId,
+    required this.content,
+    required this.attachments,
+    required this.model,
+    required this.enableThinking,
+    required this.status,
+    required this.queuedAt,
+    this.lastError,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      conversationId.hashCode ^
+      content.hashCode ^
+      attachments.hashCode ^
+      model.hashCode ^
+      enableThinking.hashCode ^
+      status.hashCode ^
+      queuedAt.hashCode ^
+      lastError.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is OutboxEntry &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          conversationId == other.conversationId &&
+          content == other.content &&
+          attachments == other.attachments &&
+          model == other.model &&
+          enableThinking == other.enableThinking &&
+          status == other.status &&
+          queuedAt == other.queuedAt &&
+          lastError == other.lastError;
+}
+
+/// 发件箱条目的状态
+enum OutboxStatus {
+  /// 等待网络恢复
+  queued,
+  /// 正在重放
+  sending,
+  /// 重放失败（用户消息可能已写入对话，不再自动重试）
+  failed;
+
+  static Future<OutboxStatus> default_() =>
+      RustLib.instance.api.crateApiDataModelsOutboxStatusDefault();
+}
+
+class PhaseDuration {
+  final MetricPhase phase;
+  final PlatformInt64 durationMs;
+
+  const PhaseDuration({required this.phase, required this.durationMs});
+
+  @override
+  int get hashCode => phase.hashCode ^ durationMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PhaseDuration &&
+          runtimeType == other.runtimeType &&
+          phase == other.phase &&
+          durationMs == other.durationMs;
+}
+
+/// 发送前预检建议：只提示，不拦截发送
+class PreflightAdvisory {
+  final DraftTone tone;
+  /// 后悔风险 0.0-1.0
+  final double regretRisk;
+  /// 发送后关系张力的预测变化（正 = 更紧张）
+  final double tensionDelta;
+  /// 发送后亲密度的预测变化（负 = 更疏远）
+  final double closenessDelta;
+  /// 深夜（0-5 点）发送
+  final bool lateNight;
+  /// 风险超过阈值，建议缓一缓再发
+  final bool suggestPause;
+  /// 面向用户的提示语
+  final List<String> notes;
+
+  const PreflightAdvisory({
+    required this.tone,
+    required this.regretRisk,
+    required this.tensionDelta,
+    required this.closenessDelta,
+    required this.lateNight,
+    required this.suggestPause,
+    required this.notes,
+  });
+
+  @override
+  int get hashCode =>
+      tone.hashCode ^
+      regretRisk.hashCode ^
+      tensionDelta.hashCode ^
+      closenessDelta.hashCode ^
+      lateNight.hashCode ^
+      suggestPause.hashCode ^
+      notes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PreflightAdvisory &&
+          runtimeType == other.runtimeType &&
+          tone == other.tone &&
+          regretRisk == other.regretRisk &&
+          tensionDelta == other.tensionDelta &&
+          closenessDelta == other.closenessDelta &&
+          lateNight == other.lateNight &&
+          suggestPause == other.suggestPause &&
+          notes == other.notes;
+}
+
+/// 可覆盖的提示词模板（prompts/{name}.txt）
+class PromptTemplateInfo {
+  final String name;
+  final String description;
+  /// 模板中可用的变量名（写作 {{name}}）
+  final List<String> variables;
+  /// 是否已被用户模板覆盖
+  final bool customized;
+
+  const PromptTemplateInfo({
+    required this.name,
+    required this.description,
+    required this.variables,
+    required this.customized,
+  });
+
+  @override
+  int get hashCode =>
+      name.hashCode ^
+      description.hashCode ^
+      variables.hashCode ^
+      customized.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PromptTemplateInfo &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          description == other.description &&
+          variables == other.variables &&
+          customized == other.customized;
+}
+
+/// 回复提供方：智谱在线模型 / 本地离线回声（演示、测试、无 API Key 时使用）
+/// / OpenAI 兼容端点 / Anthropic / 本地大模型（Ollama、llama.cpp，协议适配见 chat_provider）
+enum ProviderKind {
+  zhipu,
+  localEcho,
+  openAiCompatible,
+  anthropic,
+  localLlm;
+
+  static Future<ProviderKind> default_() =>
+      RustLib.instance.api.crateApiDataModelsProviderKindDefault();
+}
+
+/// 网络代理：http:// / https:// / socks5:// / socks5h:// 地址
+class ProxySettings {
+  final String url;
+  final String? username;
+  final String? password;
+  /// 不走代理的主机（域名、IP 或 CIDR；本机地址始终直连）
+  final List<String> bypass;
+
+  const ProxySettings({
+    required this.url,
+    this.username,
+    this.password,
+    required this.bypass,
+  });
+
+  @override
+  int get hashCode =>
+      url.hashCode ^ username.hashCode ^ password.hashCode ^ bypass.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProxySettings &&
+          runtimeType == other.runtimeType &&
+          url == other.url &&
+          username == other.username &&
+          password == other.password &&
+          bypass == other.bypass;
+}
+
+/// 角色对用户消息的即时反应（表情 + 简短标签）
+class ReactionEvent {
+  final String emoji;
+  final String label;
+
+  const ReactionEvent({required this.emoji, required this.label});
+
+  @override
+  int get hashCode => emoji.hashCode ^ label.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReactionEvent &&
+          runtimeType == other.runtimeType &&
+          emoji == other.emoji &&
+          label == other.label;
+}
+
+/// 久别重逢：重新打开沉寂已久的对话时的前情提要与开场建议
+class ReengagementBrief {
+  final String conversationId;
+  /// 距上一条消息过去的天数
+  final int elapsedDays;
+  /// 「前情提要」：长期记忆与最后几句对话的浓缩
+  final String recap;
+  /// 建议的角色开场白（提及分别的时间与没聊完的话题）
+  final String opener;
+  /// 上次没聊完的线索
+  final List<String> pendingThreads;
+  final PlatformInt64 generatedAt;
+  /// 生成时对话的最后一条消息ID；对话有新消息后缓存失效
+  final String lastMessageId;
+
+  const ReengagementBrief({
+    required this.conversationId,
+    required this.elapsedDays,
+    required this.recap,
+    required this.opener,
+    required this.pendingThreads,
+    required this.generatedAt,
+    required this.lastMessageId,
+  });
+
+  @override
+  int get hashCode =>
+      conversationId.hashCode ^
+      elapsedDays.hashCode ^
+      recap.hashCode ^
+      opener.hashCode ^
+      pendingThreads.hashCode ^
+      generatedAt.hashCode ^
+      lastMessageId.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReengagementBrief &&
+          runtimeType == other.runtimeType &&
+          conversationId == other.conversationId &&
+          elapsedDays == other.elapsedDays &&
+          recap == other.recap &&
+          opener == other.opener &&
+          pendingThreads == other.pendingThreads &&
+          generatedAt == other.generatedAt &&
+          lastMessageId == other.lastMessageId;
+}
+
+/// 批量重建索引的进度
+class ReindexProgress {
+  final String conversationId;
+  final int completed;
+  final int total;
+  /// 本对话重建的条目数（事实 + 摘要）；已是最新版本时为 0
+  final int rebuiltEntries;
+  final String? error;
+
+  const ReindexProgress({
+    required this.conversationId,
+    required this.completed,
+    required this.total,
+    required this.rebuiltEntries,
+    this.error,
+  });
+
+  @override
+  int get hashCode =>
+      conversationId.hashCode ^
+      completed.hashCode ^
+      total.hashCode ^
+      rebuiltEntries.hashCode ^
+      error.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReindexProgress &&
+          runtimeType == other.runtimeType &&
+          conversationId == other.conversationId &&
+          completed == other.completed &&
+          total == other.total &&
+          rebuiltEntries == other.rebuiltEntries &&
+          error == other.error;
+}
+
+/// 同一对角色之间较早的关系（已被新关系取代）
+class RelationChange {
+  final String relation;
+  final String factId;
+  final int sourceTurn;
+
+  const RelationChange({
+    required this.relation,
+    required this.factId,
+    required this.sourceTurn,
+  });
+
+  @override
+  int get hashCode => relation.hashCode ^ factId.hashCode ^ sourceTurn.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RelationChange &&
+          runtimeType == other.runtimeType &&
+          relation == other.relation &&
+          factId == other.factId &&
+          sourceTurn == other.sourceTurn;
+}
+
+/// 角色之间关系的情感倾向（按关系词粗分）
+enum RelationSentiment {
+  /// 喜欢、信任、暗恋……
+  positive,
+  /// 讨厌、嫉妒、敌视……
+  negative,
+  /// 同事、兄妹等不带感情色彩的关系
+  neutral,
+}
+
+/// 关系动态（镜像 cognitive_engine::RelationshipDynamics）
+class RelationshipInsight {
+  final double closeness;
+  final double trustLevel;
+  final double tension;
+  /// -1.0（对方主导）到 1.0（AI 主导）
+  final double powerBalance;
+  /// 正=升温，负=降温
+  final double trend;
+
+  const RelationshipInsight({
+    required this.closeness,
+    required this.trustLevel,
+    required this.tension,
+    required this.powerBalance,
+    required this.trend,
+  });
+
+  @override
+  int get hashCode =>
+      closeness.hashCode ^
+      trustLevel.hashCode ^
+      tension.hashCode ^
+      powerBalance.hashCode ^
+      trend.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RelationshipInsight &&
+          runtimeType == other.runtimeType &&
+          closeness == other.closeness &&
+          trustLevel == other.trustLevel &&
+          tension == other.tension &&
+          powerBalance == other.powerBalance &&
+          trend == other.trend;
+}
+
+/// 相关性评分明细（镜像 memory_engine::RelevanceScore）
+class RelevanceInsight {
+  final double tfidfScore;
+  final double keywordOverlap;
+  /// 事实关键词直接出现在用户消息中
+  final double topicMatch;
+  final double finalScore;
+
+  const RelevanceInsight({
+    required this.tfidfScore,
+    required this.keywordOverlap,
+    required this.topicMatch,
+    required this.finalScore,
+  });
+
+  @override
+  int get hashCode =>
+      tfidfScore.hashCode ^
+      keywordOverlap.hashCode ^
+      topicMatch.hashCode ^
+      finalScore.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RelevanceInsight &&
+          runtimeType == other.runtimeType &&
+          tfidfScore == other.tfidfScore &&
+          keywordOverlap == other.keywordOverlap &&
+          topicMatch == other.topicMatch &&
+          finalScore == other.finalScore;
+}
+
+/// 回复长度偏好
+enum ReplyLength {
+  /// 按对方消息与场景自动判断
+  auto,
+  short,
+  medium,
+  long;
+
+  static Future<ReplyLength> default_() =>
+      RustLib.instance.api.crateApiDataModelsReplyLengthDefault();
+}
+
+/// 一条回复的质量信号（影子评估用）
+class ReplySignals {
+  final int lengthChars;
+  /// 回复中能推出的本轮注入事实数
+  final int groundedFacts;
+  /// 与用户本轮输入的关键词重合度 0.0-1.0
+  final double userOverlap;
+  /// 与上一条 AI 回复的相似度 0.0-1.0（越高越像在复读）
+  final double repetition;
+
+  const ReplySignals({
+    required this.lengthChars,
+    required this.groundedFacts,
+    required this.userOverlap,
+    required this.repetition,
+  });
+
+  static Future<ReplySignals> default_() =>
+      RustLib.instance.api.crateApiDataModelsReplySignalsDefault();
+
+  @override
+  int get hashCode =>
+      lengthChars.hashCode ^
+      groundedFacts.hashCode ^
+      userOverlap.hashCode ^
+      repetition.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ReplySignals &&
+          runtimeType == other.runtimeType &&
+          lengthChars == other.lengthChars &&
+          groundedFacts == other.groundedFacts &&
+          userOverlap == other.userOverlap &&
+          repetition == other.repetition;
+}
+
+/// 对话的回复风格偏好：Auto 项沿用拟人化提示的启发式判断
+class ResponseStyle {
+  final ReplyLength replyLength;
+  final Formality formality;
+  final EmojiUsage emojiUsage;
+  final ActionFrequency actionFrequency;
+
+  const ResponseStyle({
+    required this.replyLength,
+    required this.formality,
+    required this.emojiUsage,
+    required this.actionFrequency,
+  });
+
+  static Future<ResponseStyle> default_() =>
+      RustLib.instance.api.crateApiDataModelsResponseStyleDefault();
+
+  @override
+  int get hashCode =>
+      replyLength.hashCode ^
+      formality.hashCode ^
+      emojiUsage.hashCode ^
+      actionFrequency.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ResponseStyle &&
+          runtimeType == other.runtimeType &&
+          replyLength == other.replyLength &&
+          formality == other.formality &&
+          emojiUsage == other.emojiUsage &&
+          actionFrequency == other.actionFrequency;
+}
+
+/// 数据保留策略（retention.json）；各项为 0 / false 时不启用
+class RetentionPolicy {
+  /// 闲置超过多少天的对话自动归档
+  final int archiveAfterIdleDays;
+  /// 每个对话主线保留的消息上限；更早的消息转入冷存储
+  final int maxMessagesPerConversation;
+  /// 清理对话已不存在的记忆 / 知识文件
+  final bool purgeOrphans;
+
+  const RetentionPolicy({
+    required this.archiveAfterIdleDays,
+    required this.maxMessagesPerConversation,
+    required this.purgeOrphans,
+  });
+
+  static Future<RetentionPolicy> default_() =>
+      RustLib.instance.api.crateApiDataModelsRetentionPolicyDefault();
+
+  @override
+  int get hashCode =>
+      archiveAfterIdleDays.hashCode ^
+      maxMessagesPerConversation.hashCode ^
+      purgeOrphans.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RetentionPolicy &&
+          runtimeType == other.runtimeType &&
+          archiveAfterIdleDays == other.archiveAfterIdleDays &&
+          maxMessagesPerConversation == other.maxMessagesPerConversation &&
+          purgeOrphans == other.purgeOrphans;
+}
+
+/// 请求失败的重试策略（见 error_handler::RetryHandler）
+class RetryPolicy {
+  /// 首次失败后最多再试几次
+  final int maxRetries;
+  final BigInt initialDelayMs;
+  /// 单次退避等待的上限
+  final BigInt maxDelayMs;
+  final BackoffStrategy backoff;
+  /// 网络错误（连接失败、超时）
+  final bool retryNetworkErrors;
+  /// 流中断 / 响应格式错误
+  final bool retryStreamErrors;
+  /// 服务端 5xx
+  final bool retryServerErrors;
+  /// 429 / 并发与频率限制
+  final bool retryRateLimited;
+  /// 服务端要求等待（Retry-After）超过该秒数时不再重试
+  final BigInt maxRetryAfterSecs;
+
+  const RetryPolicy({
+    required this.maxRetries,
+    required this.initialDelayMs,
+    required this.maxDelayMs,
+    required this.backoff,
+    required this.retryNetworkErrors,
+    required this.retryStreamErrors,
+    required this.retryServerErrors,
+    required this.retryRateLimited,
+    required this.maxRetryAfterSecs,
+  });
+
+  static Future<RetryPolicy> default_() =>
+      RustLib.instance.api.crateApiDataModelsRetryPolicyDefault();
+
+  @override
+  int get hashCode =>
+      maxRetries.hashCode ^
+      initialDelayMs.hashCode ^
+      maxDelayMs.hashCode ^
+      backoff.hashCode ^
+      retryNetworkErrors.hashCode ^
+      retryStreamErrors.hashCode ^
+      retryServerErrors.hashCode ^
+      retryRateLimited.hashCode ^
+      maxRetryAfterSecs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RetryPolicy &&
+          runtimeType == other.runtimeType &&
+          maxRetries == other.maxRetries &&
+          initialDelayMs == other.initialDelayMs &&
+          maxDelayMs == other.maxDelayMs &&
+          backoff == other.backoff &&
+          retryNetworkErrors == other.retryNetworkErrors &&
+          retryStreamErrors == other.retryStreamErrors &&
+          retryServerErrors == other.retryServerErrors &&
+          retryRateLimited == other.retryRateLimited &&
+          maxRetryAfterSecs == other.maxRetryAfterSecs;
+}
+
+/// 命中某一分类后的处理方式
+enum SafetyAction {
+  /// 拦截：输入不发送、回复不落盘
+  block,
+  /// 缓和：输入时提示模型谨慎处理，回复中的命中词语遮蔽后落盘
+  soften,
+  /// 仅通知：发送 Safety 事件，内容不变
+  warn,
+}
+
+/// 内容安全分类
+enum SafetyCategory {
+  /// 自伤、自杀
+  selfHarm,
+  /// 暴力伤害的具体方法
+  violence,
+  /// 露骨色情
+  sexual,
+  /// 仇恨、歧视
+  hate,
+  /// 违法犯罪的具体方法（毒品、武器、诈骗等）
+  illegal,
+}
+
+/// 安全过滤的一次命中
+class SafetyEvent {
+  final SafetyStage stage;
+  final SafetyCategory category;
+  final SafetyAction action;
+  /// 命中的词语（按出现顺序，去重）
+  final List<String> matched;
+
+  const SafetyEvent({
+    required this.stage,
+    required this.category,
+    required this.action,
+    required this.matched,
+  });
+
+  @override
+  int get hashCode =>
+      stage.hashCode ^ category.hashCode ^ action.hashCode ^ matched.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SafetyEvent &&
+          runtimeType == other.runtimeType &&
+          stage == other.stage &&
+          category == other.category &&
+          action == other.action &&
+          matched == other.matched;
+}
+
+/// 内容安全过滤设置（safety.json）；没有规则的分类不检查
+class SafetyPolicy {
+  final bool enabled;
+  /// 检查用户消息
+  final bool checkInput;
+  /// 检查生成的回复
+  final bool checkOutput;
+  final List<SafetyRule> rules;
+
+  const SafetyPolicy({
+    required this.enabled,
+    required this.checkInput,
+    required this.checkOutput,
+    required this.rules,
+  });
+
+  static Future<SafetyPolicy> default_() =>
+      RustLib.instance.api.crateApiDataModelsSafetyPolicyDefault();
+
+  @override
+  int get hashCode =>
+      enabled.hashCode ^
+      checkInput.hashCode ^
+      checkOutput.hashCode ^
+      rules.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SafetyPolicy &&
+          runtimeType == other.runtimeType &&
+          enabled == other.enabled &&
+          checkInput == other.checkInput &&
+          checkOutput == other.checkOutput &&
+          rules == other.rules;
+}
+
+/// 一个分类的处理规则
+class SafetyRule {
+  final SafetyCategory category;
+  final SafetyAction action;
+  /// 在内置词表之外追加的词语（英文不区分大小写）
+  final List<String> extraTerms;
+
+  const SafetyRule({
+    required this.category,
+    required this.action,
+    required this.extraTerms,
+  });
+
+  @override
+  int get hashCode => category.hashCode ^ action.hashCode ^ extraTerms.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SafetyRule &&
+          runtimeType == other.runtimeType &&
+          category == other.category &&
+          action == other.action &&
+          extraTerms == other.extraTerms;
+}
+
+/// 检查发生的阶段
+enum SafetyStage {
+  /// 用户消息发送前
+  input,
+  /// 回复生成后
+  output,
+}
+
+/// 自定义识别规则标记的内容类型
+enum SayDoMark { say, do_ }
+
+/// 自定义 Say/Do 识别规则：正则命中的片段按 mark 计为对白或动作
+class SayDoRule {
+  final String pattern;
+  final SayDoMark mark;
+  /// 优先级高的规则先认领文本，已被认领的片段不再参与后续规则
+  final int priority;
+
+  const SayDoRule({
+    required this.pattern,
+    required this.mark,
+    required this.priority,
+  });
+
+  @override
+  int get hashCode => pattern.hashCode ^ mark.hashCode ^ priority.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SayDoRule &&
+          runtimeType == other.runtimeType &&
+          pattern == other.pattern &&
+          mark == other.mark &&
+          priority == other.priority;
+}
+
+/// 对话的 Say/Do 识别规则
+class SayDoRuleSet {
+  final List<SayDoRule> rules;
+  /// 没有被任何规则命中的文字算什么；None 时交给内置识别（括号 / 星号为动作，其余为对白）
+  final SayDoMark? unmatched;
+
+  const SayDoRuleSet({required this.rules, this.unmatched});
+
+  static Future<SayDoRuleSet> default_() =>
+      RustLib.instance.api.crateApiDataModelsSayDoRuleSetDefault();
+
+  @override
+  int get hashCode => rules.hashCode ^ unmatched.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SayDoRuleSet &&
+          runtimeType == other.runtimeType &&
+          rules == other.rules &&
+          unmatched == other.unmatched;
+}
+
+/// 全文搜索的一条命中
+class SearchHit {
+  final String conversationId;
+  final String conversationTitle;
+  final String messageId;
+  final MessageRole role;
+  /// 命中处前后的片段（截断处以 … 表示，换行替换为空格）
+  final String snippet;
+  /// 第一个命中词在 snippet 中的字符区间 [start, end)，供高亮
+  final int highlightStart;
+  final int highlightEnd;
+  final PlatformInt64 timestamp;
+
+  const SearchHit({
+    required this.conversationId,
+    required this.conversationTitle,
+    required this.messageId,
+    required this.role,
+    required this.snippet,
+    required this.highlightStart,
+    required this.highlightEnd,
+    required this.timestamp,
+  });
+
+  @override
+  int get hashCode =>
+      conversationId.hashCode ^
+      conversationTitle.hashCode ^
+      messageId.hashCode ^
+      role.hashCode ^
+      snippet.hashCode ^
+      highlightStart.hashCode ^
+      highlightEnd.hashCode ^
+      timestamp.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SearchHit &&
+          runtimeType == other.runtimeType &&
+          conversationId == other.conversationId &&
+          conversationTitle == other.conversationTitle &&
+          messageId == other.messageId &&
+          role == other.role &&
+          snippet == other.snippet &&
+          highlightStart == other.highlightStart &&
+          highlightEnd == other.highlightEnd &&
+          timestamp == other.timestamp;
+}
+
+/// 一次影子评估：正式回复（有记忆/知识注入）与无注入的影子回复对比
+class ShadowEvalRecord {
+  final int turn;
+  /// 本轮注入的事实数（检索到的知识 + 记忆核心事实）
+  final int injectedFacts;
+  final String mainModel;
+  final String shadowModel;
+  final ReplySignals main;
+  final ReplySignals shadow;
+  final PlatformInt64 createdAt;
+
+  const ShadowEvalRecord({
+    required this.turn,
+    required this.injectedFacts,
+    required this.mainModel,
+    required this.shadowModel,
+    required this.main,
+    required this.shadow,
+    required this.createdAt,
+  });
+
+  @override
+  int get hashCode =>
+      turn.hashCode ^
+      injectedFacts.hashCode ^
+      mainModel.hashCode ^
+      shadowModel.hashCode ^
+      main.hashCode ^
+      shadow.hashCode ^
+      createdAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ShadowEvalRecord &&
+          runtimeType == other.runtimeType &&
+          turn == other.turn &&
+          injectedFacts == other.injectedFacts &&
+          mainModel == other.mainModel &&
+          shadowModel == other.shadowModel &&
+          main == other.main &&
+          shadow == other.shadow &&
+          createdAt == other.createdAt;
+}
+
+/// 影子评估的汇总：注入带来的平均差值（正式 − 影子）
+class ShadowEvalSummary {
+  final int samples;
+  final double avgGroundedGain;
+  final double avgOverlapGain;
+  /// 负值表示注入后更少复读
+  final double avgRepetitionDelta;
+  /// 正式回复引用的注入事实多于影子回复的样本比例
+  final double groundedWinRate;
+
+  const ShadowEvalSummary({
+    required this.samples,
+    required this.avgGroundedGain,
+    required this.avgOverlapGain,
+    required this.avgRepetitionDelta,
+    required this.groundedWinRate,
+  });
+
+  static Future<ShadowEvalSummary> default_() =>
+      RustLib.instance.api.crateApiDataModelsShadowEvalSummaryDefault();
+
+  @override
+  int get hashCode =>
+      samples.hashCode ^
+      avgGroundedGain.hashCode ^
+      avgOverlapGain.hashCode ^
+      avgRepetitionDelta.hashCode ^
+      groundedWinRate.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ShadowEvalSummary &&
+          runtimeType == other.runtimeType &&
+          samples == other.samples &&
+          avgGroundedGain == other.avgGroundedGain &&
+          avgOverlapGain == other.avgOverlapGain &&
+          avgRepetitionDelta == other.avgRepetitionDelta &&
+          groundedWinRate == other.groundedWinRate;
+}
+
+/// 短期记忆（镜像 memory_engine::ShortTermContext）
+class ShortTermInsight {
+  final List<String> activeTopics;
+  final List<EmotionalTurnInsight> emotionalArc;
+  final List<String> pendingThreads;
+  final List<FingerprintInsight> responseFingerprints;
+
+  const ShortTermInsight({
+    required this.activeTopics,
+    required this.emotionalArc,
+    required this.pendingThreads,
+    required this.responseFingerprints,
+  });
+
+  @override
+  int get hashCode =>
+      activeTopics.hashCode ^
+      emotionalArc.hashCode ^
+      pendingThreads.hashCode ^
+      responseFingerprints.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ShortTermInsight &&
+          runtimeType == other.runtimeType &&
+          activeTopics == other.activeTopics &&
+          emotionalArc == other.emotionalArc &&
+          pendingThreads == other.pendingThreads &&
+          responseFingerprints == other.responseFingerprints;
+}
+
+/// 对话的记忆摘要节奏（summarization.json，见 ConfigManager::load_summarization_config）
+class SummarizationConfig {
+  /// 每多少轮生成一次摘要
+  final int intervalTurns;
+  /// 每次摘要读取的最近消息条数
+  final int windowMessages;
+
+  const SummarizationConfig({
+    required this.intervalTurns,
+    required this.windowMessages,
+  });
+
+  static Future<SummarizationConfig> default_() =>
+      RustLib.instance.api.crateApiDataModelsSummarizationConfigDefault();
+
+  @override
+  int get hashCode => intervalTurns.hashCode ^ windowMessages.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SummarizationConfig &&
+          runtimeType == other.runtimeType &&
+          intervalTurns == other.intervalTurns &&
+          windowMessages == other.windowMessages;
+}
+
+/// 云同步的远端类型
+enum SyncBackendKind {
+  webDav,
+  /// S3 及兼容的对象存储（MinIO、R2……），路径风格访问
+  s3;
+
+  static Future<SyncBackendKind> default_() =>
+      RustLib.instance.api.crateApiDataModelsSyncBackendKindDefault();
+}
+
+/// 一次云同步的结果
+class SyncReport {
+  /// 上传的文件数
+  final int pushed;
+  /// 下载的文件数
+  final int pulled;
+  /// 按另一端的删除而删掉的文件数（本地与远端合计）
+  final int deleted;
+  /// 两端都改过的文件（相对路径）：保留本地版本，远端版本另存为冲突副本
+  final List<String> conflicts;
+
+  const SyncReport({
+    required this.pushed,
+    required this.pulled,
+    required this.deleted,
+    required this.conflicts,
+  });
+
+  static Future<SyncReport> default_() =>
+      RustLib.instance.api.crateApiDataModelsSyncReportDefault();
+
+  @override
+  int get hashCode =>
+      pushed.hashCode ^ pulled.hashCode ^ deleted.hashCode ^ conflicts.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SyncReport &&
+          runtimeType == other.runtimeType &&
+          pushed == other.pushed &&
+          pulled == other.pulled &&
+          deleted == other.deleted &&
+          conflicts == other.conflicts;
+}
+
+/// 云同步设置（sync.json）；endpoint 为空时不同步
+class SyncSettings {
+  final SyncBackendKind backend;
+  /// WebDAV 根地址，或 S3 端点（如 https://s3.us-east-1.amazonaws.com）
+  final String endpoint;
+  /// WebDAV 用户名 / S3 Access Key ID
+  final String username;
+  /// WebDAV 密码 / S3 Secret Access Key
+  final String password;
+  /// S3 存储桶（WebDAV 不使用）
+  final String bucket;
+  /// S3 区域（WebDAV 不使用）
+  final String region;
+  /// 远端目录 / 键前缀：多台设备填同一个即互相同步
+  final String remoteRoot;
+
+  const SyncSettings({
+    required this.backend,
+    required this.endpoint,
+    required this.username,
+    required this.password,
+    required this.bucket,
+    required this.region,
+    required this.remoteRoot,
+  });
+
+  static Future<SyncSettings> default_() =>
+      RustLib.instance.api.crateApiDataModelsSyncSettingsDefault();
+
+  @override
+  int get hashCode =>
+      backend.hashCode ^
+      endpoint.hashCode ^
+      username.hashCode ^
+      password.hashCode ^
+      bucket.hashCode ^
+      region.hashCode ^
+      remoteRoot.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SyncSettings &&
+          runtimeType == other.runtimeType &&
+          backend == other.backend &&
+          endpoint == other.endpoint &&
+          username == other.username &&
+          password == other.password &&
+          bucket == other.bucket &&
+          region == other.region &&
+          remoteRoot == other.remoteRoot;
+}
+
+/// 思考内容（thinking_content）保留策略，由维护任务执行
+@freezed
+sealed class ThinkingRetention with _$ThinkingRetention {
+  const ThinkingRetention._();
+
+  /// 永久保留
+  const factory ThinkingRetention.keepAll() = ThinkingRetention_KeepAll;
+  /// 只保留最近 N 轮的思考内容
+  const factory ThinkingRetention.keepLastTurns(int field0) =
+      ThinkingRetention_KeepLastTurns;
+  /// 所在轮次被记忆摘要覆盖后丢弃
+  const factory ThinkingRetention.discardAfterSummary() =
+      ThinkingRetention_DiscardAfterSummary;
+
+  static Future<ThinkingRetention> default_() =>
+      RustLib.instance.api.crateApiDataModelsThinkingRetentionDefault();
+}
+
+/// 故事时间线上的一个条目（「前情提要」滑杆的一格）
+class TimelineEntry {
+  /// 摘要 ID 或事实 ID
+  final String id;
+  final TimelineEntryKind kind;
+  final String text;
+  final int turnStart;
+  final int turnEnd;
+  /// 起始轮次对应的时间（毫秒）；无法对应到消息时取条目自身的创建时间
+  final PlatformInt64 timestamp;
+  final PlatformInt64 endTimestamp;
+
+  const TimelineEntry({
+    required this.id,
+    required this.kind,
+    required this.text,
+    required this.turnStart,
+    required this.turnEnd,
+    required this.timestamp,
+    required this.endTimestamp,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      kind.hashCode ^
+      text.hashCode ^
+      turnStart.hashCode ^
+      turnEnd.hashCode ^
+      timestamp.hashCode ^
+      endTimestamp.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TimelineEntry &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          kind == other.kind &&
+          text == other.text &&
+          turnStart == other.turnStart &&
+          turnEnd == other.turnEnd &&
+          timestamp == other.timestamp &&
+          endTimestamp == other.endTimestamp;
+}
+
+/// 故事时间线条目类型
+enum TimelineEntryKind {
+  /// 一段记忆摘要覆盖的剧情
+  chapter,
+  /// 知识库中的关键事件
+  event,
+}
+
+/// 一轮回复的性能指标
+class TurnMetrics {
+  final String conversationId;
+  final PlatformInt64 startedAt;
+  final PlatformInt64 totalMs;
+  /// 首字延迟；本轮没有推送任何 delta 时为 None
+  final PlatformInt64? firstTokenMs;
+  /// 各阶段累计耗时（未执行的阶段不出现）
+  final List<PhaseDuration> phases;
+  final int retries;
+
+  const TurnMetrics({
+    required this.conversationId,
+    required this.startedAt,
+    required this.totalMs,
+    this.firstTokenMs,
+    required this.phases,
+    required this.retries,
+  });
+
+  @override
+  int get hashCode =>
+      conversationId.hashCode ^
+      startedAt.hashCode ^
+      totalMs.hashCode ^
+      firstTokenMs.hashCode ^
+      phases.hashCode ^
+      retries.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TurnMetrics &&
+          runtimeType == other.runtimeType &&
+          conversationId == other.conversationId &&
+          startedAt == other.startedAt &&
+          totalMs == other.totalMs &&
+          firstTokenMs == other.firstTokenMs &&
+          phases == other.phases &&
+          retries == other.retries;
+}
+
+/// 群聊的发言调度方式
+enum TurnPolicy {
+  /// 按角色顺序轮流发言
+  roundRobin,
+  /// 由快速模型根据上下文挑选下一位发言者（失败时退回轮流）
+  modelChosen;
+
+  static Future<TurnPolicy> default_() =>
+      RustLib.instance.api.crateApiDataModelsTurnPolicyDefault();
+}
+
+/// 用户在对话中扮演的身份（personas.json），每个对话可选用一个
+class UserPersona {
+  final String id;
+  final String name;
+  /// 外貌、性格、背景等自由描述
+  final String description;
+  /// 人称代词（如「她」「他」「ta」），为空时按名字称呼
+  final String pronouns;
+  final PlatformInt64 createdAt;
+
+  const UserPersona({
+    required this.id,
+    required this.name,
+    required this.description,
+    required this.pronouns,
+    required this.createdAt,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      name.hashCode ^
+      description.hashCode ^
+      pronouns.hashCode ^
+      createdAt.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is UserPersona &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          name == other.name &&
+          description == other.description &&
+          pronouns == other.pronouns &&
+          createdAt == other.createdAt;
 }
@@ -55,14 +55,26 @@ extension ChatStreamEventPatterns on ChatStreamEvent {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( ChatStreamEvent_ContentDelta value)?  contentDelta,TResult Function( ChatStreamEvent_ThinkingDelta value)?  thinkingDelta,TResult Function( ChatStreamEvent_Done value)?  done,TResult Function( ChatStreamEvent_Error value)?  error,required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( ChatStreamEvent_ContentDelta value)?  contentDelta,TResult Function( ChatStreamEvent_ThinkingDelta value)?  thinkingDelta,TResult Function( ChatStreamEvent_Done value)?  done,TResult Function( ChatStreamEvent_Error value)?  error,TResult Function( ChatStreamEvent_ConfigChanged value)?  configChanged,TResult Function( ChatStreamEvent_Reaction value)?  reaction,TResult Function( ChatStreamEvent_SystemNotice value)?  systemNotice,TResult Function( ChatStreamEvent_Illustration value)?  illustration,TResult Function( ChatStreamEvent_AudioReady value)?  audioReady,TResult Function( ChatStreamEvent_Completed value)?  completed,TResult Function( ChatStreamEvent_Outbox value)?  outbox,TResult Function( ChatStreamEvent_Safety value)?  safety,TResult Function( ChatStreamEvent_CheckIn value)?  checkIn,TResult Function( ChatStreamEvent_SummaryPreview value)?  summaryPreview,TResult Function( ChatStreamEvent_DraftDelta value)?  draftDelta,TResult Function( ChatStreamEvent_Refined value)?  refined,required TResult orElse(),}){
 final _that = this;
 switch (_that) {
 case ChatStreamEvent_ContentDelta() when contentDelta != null:
 return contentDelta(_that);case ChatStreamEvent_ThinkingDelta() when thinkingDelta != null:
 return thinkingDelta(_that);case ChatStreamEvent_Done() when done != null:
 return done(_that);case ChatStreamEvent_Error() when error != null:
-return error(_that);case _:
+return error(_that);case ChatStreamEvent_ConfigChanged() when configChanged != null:
+return configChanged(_that);case ChatStreamEvent_Reaction() when reaction != null:
+return reaction(_that);case ChatStreamEvent_SystemNotice() when systemNotice != null:
+return systemNotice(_that);case ChatStreamEvent_Illustration() when illustration != null:
+return illustration(_that);case ChatStreamEvent_AudioReady() when audioReady != null:
+return audioReady(_that);case ChatStreamEvent_Completed() when completed != null:
+return completed(_that);case ChatStreamEvent_Outbox() when outbox != null:
+return outbox(_that);case ChatStreamEvent_Safety() when safety != null:
+return safety(_that);case ChatStreamEvent_CheckIn() when checkIn != null:
+return checkIn(_that);case ChatStreamEvent_SummaryPreview() when summaryPreview != null:
+return summaryPreview(_that);case ChatStreamEvent_DraftDelta() when draftDelta != null:
+return draftDelta(_that);case ChatStreamEvent_Refined() when refined != null:
+return refined(_that);case _:
   return orElse();
 
 }
@@ -80,14 +92,26 @@ return error(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( ChatStreamEvent_ContentDelta value)  contentDelta,required TResult Function( ChatStreamEvent_ThinkingDelta value)  thinkingDelta,required TResult Function( ChatStreamEvent_Done value)  done,required TResult Function( ChatStreamEvent_Error value)  error,}){
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( ChatStreamEvent_ContentDelta value)  contentDelta,required TResult Function( ChatStreamEvent_ThinkingDelta value)  thinkingDelta,required TResult Function( ChatStreamEvent_Done value)  done,required TResult Function( ChatStreamEvent_Error value)  error,required TResult Function( ChatStreamEvent_ConfigChanged value)  configChanged,required TResult Function( ChatStreamEvent_Reaction value)  reaction,required TResult Function( ChatStreamEvent_SystemNotice value)  systemNotice,required TResult Function( ChatStreamEvent_Illustration value)  illustration,required TResult Function( ChatStreamEvent_AudioReady value)  audioReady,required TResult Function( ChatStreamEvent_Completed value)  completed,required TResult Function( ChatStreamEvent_Outbox value)  outbox,required TResult Function( ChatStreamEvent_Safety value)  safety,required TResult Function( ChatStreamEvent_CheckIn value)  checkIn,required TResult Function( ChatStreamEvent_SummaryPreview value)  summaryPreview,required TResult Function( ChatStreamEvent_DraftDelta value)  draftDelta,required TResult Function( ChatStreamEvent_Refined value)  refined,}){
 final _that = this;
 switch (_that) {
 case ChatStreamEvent_ContentDelta():
 return contentDelta(_that);case ChatStreamEvent_ThinkingDelta():
 return thinkingDelta(_that);case ChatStreamEvent_Done():
 return done(_that);case ChatStreamEvent_Error():
-return error(_that);}
+return error(_that);case ChatStreamEvent_ConfigChanged():
+return configChanged(_that);case ChatStreamEvent_Reaction():
+return reaction(_that);case ChatStreamEvent_SystemNotice():
+return systemNotice(_that);case ChatStreamEvent_Illustration():
+return illustration(_that);case ChatStreamEvent_AudioReady():
+return audioReady(_that);case ChatStreamEvent_Completed():
+return completed(_that);case ChatStreamEvent_Outbox():
+return outbox(_that);case ChatStreamEvent_Safety():
+return safety(_that);case ChatStreamEvent_CheckIn():
+return checkIn(_that);case ChatStreamEvent_SummaryPreview():
+return summaryPreview(_that);case ChatStreamEvent_DraftDelta():
+return draftDelta(_that);case ChatStreamEvent_Refined():
+return refined(_that);}
 }
 /// A variant of `map` that fallback to returning `null`.
 ///
@@ -101,14 +125,26 @@ return error(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( ChatStreamEvent_ContentDelta value)?  contentDelta,TResult? Function( ChatStreamEvent_ThinkingDelta value)?  thinkingDelta,TResult? Function( ChatStreamEvent_Done value)?  done,TResult? Function( ChatStreamEvent_Error value)?  error,}){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( ChatStreamEvent_ContentDelta value)?  contentDelta,TResult? Function( ChatStreamEvent_ThinkingDelta value)?  thinkingDelta,TResult? Function( ChatStreamEvent_Done value)?  done,TResult? Function( ChatStreamEvent_Error value)?  error,TResult? Function( ChatStreamEvent_ConfigChanged value)?  configChanged,TResult? Function( ChatStreamEvent_Reaction value)?  reaction,TResult? Function( ChatStreamEvent_SystemNotice value)?  systemNotice,TResult? Function( ChatStreamEvent_Illustration value)?  illustration,TResult? Function( ChatStreamEvent_AudioReady value)?  audioReady,TResult? Function( ChatStreamEvent_Completed value)?  completed,TResult? Function( ChatStreamEvent_Outbox value)?  outbox,TResult? Function( ChatStreamEvent_Safety value)?  safety,TResult? Function( ChatStreamEvent_CheckIn value)?  checkIn,TResult? Function( ChatStreamEvent_SummaryPreview value)?  summaryPreview,TResult? Function( ChatStreamEvent_DraftDelta value)?  draftDelta,TResult? Function( ChatStreamEvent_Refined value)?  refined,}){
 final _that = this;
 switch (_that) {
 case ChatStreamEvent_ContentDelta() when contentDelta != null:
 return contentDelta(_that);case ChatStreamEvent_ThinkingDelta() when thinkingDelta != null:
 return thinkingDelta(_that);case ChatStreamEvent_Done() when done != null:
 return done(_that);case ChatStreamEvent_Error() when error != null:
-return error(_that);case _:
+return error(_that);case ChatStreamEvent_ConfigChanged() when configChanged != null:
+return configChanged(_that);case ChatStreamEvent_Reaction() when reaction != null:
+return reaction(_that);case ChatStreamEvent_SystemNotice() when systemNotice != null:
+return systemNotice(_that);case ChatStreamEvent_Illustration() when illustration != null:
+return illustration(_that);case ChatStreamEvent_AudioReady() when audioReady != null:
+return audioReady(_that);case ChatStreamEvent_Completed() when completed != null:
+return completed(_that);case ChatStreamEvent_Outbox() when outbox != null:
+return outbox(_that);case ChatStreamEvent_Safety() when safety != null:
+return safety(_that);case ChatStreamEvent_CheckIn() when checkIn != null:
+return checkIn(_that);case ChatStreamEvent_SummaryPreview() when summaryPreview != null:
+return summaryPreview(_that);case ChatStreamEvent_DraftDelta() when draftDelta != null:
+return draftDelta(_that);case ChatStreamEvent_Refined() when refined != null:
+return refined(_that);case _:
   return null;
 
 }
@@ -125,13 +161,25 @@ return error(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( String field0)?  contentDelta,TResult Function( String field0)?  thinkingDelta,TResult Function()?  done,TResult Function( String field0)?  error,required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( String field0)?  contentDelta,TResult Function( String field0)?  thinkingDelta,TResult Function()?  done,TResult Function( String field0)?  error,TResult Function()?  configChanged,TResult Function( ReactionEvent field0)?  reaction,TResult Function( String field0)?  systemNotice,TResult Function( Message field0)?  illustration,TResult Function( AudioReadyEvent field0)?  audioReady,TResult Function( GenerationCompletedEvent field0)?  completed,TResult Function( OutboxEntry field0)?  outbox,TResult Function( SafetyEvent field0)?  safety,TResult Function( String field0)?  checkIn,TResult Function( MemorySummary field0)?  summaryPreview,TResult Function( String field0)?  draftDelta,TResult Function( String field0)?  refined,required TResult orElse(),}) {final _that = this;
 switch (_that) {
 case ChatStreamEvent_ContentDelta() when contentDelta != null:
 return contentDelta(_that.field0);case ChatStreamEvent_ThinkingDelta() when thinkingDelta != null:
 return thinkingDelta(_that.field0);case ChatStreamEvent_Done() when done != null:
 return done();case ChatStreamEvent_Error() when error != null:
-return error(_that.field0);case _:
+return error(_that.field0);case ChatStreamEvent_ConfigChanged() when configChanged != null:
+return configChanged();case ChatStreamEvent_Reaction() when reaction != null:
+return reaction(_that.field0);case ChatStreamEvent_SystemNotice() when systemNotice != null:
+return systemNotice(_that.field0);case ChatStreamEvent_Illustration() when illustration != null:
+return illustration(_that.field0);case ChatStreamEvent_AudioReady() when audioReady != null:
+return audioReady(_that.field0);case ChatStreamEvent_Completed() when completed != null:
+return completed(_that.field0);case ChatStreamEvent_Outbox() when outbox != null:
+return outbox(_that.field0);case ChatStreamEvent_Safety() when safety != null:
+return safety(_that.field0);case ChatStreamEvent_CheckIn() when checkIn != null:
+return checkIn(_that.field0);case ChatStreamEvent_SummaryPreview() when summaryPreview != null:
+return summaryPreview(_that.field0);case ChatStreamEvent_DraftDelta() when draftDelta != null:
+return draftDelta(_that.field0);case ChatStreamEvent_Refined() when refined != null:
+return refined(_that.field0);case _:
   return orElse();
 
 }
@@ -149,13 +197,25 @@ return error(_that.field0);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( String field0)  contentDelta,required TResult Function( String field0)  thinkingDelta,required TResult Function()  done,required TResult Function( String field0)  error,}) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( String field0)  contentDelta,required TResult Function( String field0)  thinkingDelta,required TResult Function()  done,required TResult Function( String field0)  error,required TResult Function()  configChanged,required TResult Function( ReactionEvent field0)  reaction,required TResult Function( String field0)  systemNotice,required TResult Function( Message field0)  illustration,required TResult Function( AudioReadyEvent field0)  audioReady,required TResult Function( GenerationCompletedEvent field0)  completed,required TResult Function( OutboxEntry field0)  outbox,required TResult Function( SafetyEvent field0)  safety,required TResult Function( String field0)  checkIn,required TResult Function( MemorySummary field0)  summaryPreview,required TResult Function( String field0)  draftDelta,required TResult Function( String field0)  refined,}) {final _that = this;
 switch (_that) {
 case ChatStreamEvent_ContentDelta():
 return contentDelta(_that.field0);case ChatStreamEvent_ThinkingDelta():
 return thinkingDelta(_that.field0);case ChatStreamEvent_Done():
 return done();case ChatStreamEvent_Error():
-return error(_that.field0);}
+return error(_that.field0);case ChatStreamEvent_ConfigChanged():
+return configChanged();case ChatStreamEvent_Reaction():
+return reaction(_that.field0);case ChatStreamEvent_SystemNotice():
+return systemNotice(_that.field0);case ChatStreamEvent_Illustration():
+return illustration(_that.field0);case ChatStreamEvent_AudioReady():
+return audioReady(_that.field0);case ChatStreamEvent_Completed():
+return completed(_that.field0);case ChatStreamEvent_Outbox():
+return outbox(_that.field0);case ChatStreamEvent_Safety():
+return safety(_that.field0);case ChatStreamEvent_CheckIn():
+return checkIn(_that.field0);case ChatStreamEvent_SummaryPreview():
+return summaryPreview(_that.field0);case ChatStreamEvent_DraftDelta():
+return draftDelta(_that.field0);case ChatStreamEvent_Refined():
+return refined(_that.field0);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -169,13 +229,25 @@ return error(_that.field0);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( String field0)?  contentDelta,TResult? Function( String field0)?  thinkingDelta,TResult? Function()?  done,TResult? Function( String field0)?  error,}) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( String field0)?  contentDelta,TResult? Function( String field0)?  thinkingDelta,TResult? Function()?  done,TResult? Function( String field0)?  error,TResult? Function()?  configChanged,TResult? Function( ReactionEvent field0)?  reaction,TResult? Function( String field0)?  systemNotice,TResult? Function( Message field0)?  illustration,TResult? Function( AudioReadyEvent field0)?  audioReady,TResult? Function( GenerationCompletedEvent field0)?  completed,TResult? Function( OutboxEntry field0)?  outbox,TResult? Function( SafetyEvent field0)?  safety,TResult? Function( String field0)?  checkIn,TResult? Function( MemorySummary field0)?  summaryPreview,TResult? Function( String field0)?  draftDelta,TResult? Function( String field0)?  refined,}) {final _that = this;
 switch (_that) {
 case ChatStreamEvent_ContentDelta() when contentDelta != null:
 return contentDelta(_that.field0);case ChatStreamEvent_ThinkingDelta() when thinkingDelta != null:
 return thinkingDelta(_that.field0);case ChatStreamEvent_Done() when done != null:
 return done();case ChatStreamEvent_Error() when error != null:
-return error(_that.field0);case _:
+return error(_that.field0);case ChatStreamEvent_ConfigChanged() when configChanged != null:
+return configChanged();case ChatStreamEvent_Reaction() when reaction != null:
+return reaction(_that.field0);case ChatStreamEvent_SystemNotice() when systemNotice != null:
+return systemNotice(_that.field0);case ChatStreamEvent_Illustration() when illustration != null:
+return illustration(_that.field0);case ChatStreamEvent_AudioReady() when audioReady != null:
+return audioReady(_that.field0);case ChatStreamEvent_Completed() when completed != null:
+return completed(_that.field0);case ChatStreamEvent_Outbox() when outbox != null:
+return outbox(_that.field0);case ChatStreamEvent_Safety() when safety != null:
+return safety(_that.field0);case ChatStreamEvent_CheckIn() when checkIn != null:
+return checkIn(_that.field0);case ChatStreamEvent_SummaryPreview() when summaryPreview != null:
+return summaryPreview(_that.field0);case ChatStreamEvent_DraftDelta() when draftDelta != null:
+return draftDelta(_that.field0);case ChatStreamEvent_Refined() when refined != null:
+return refined(_that.field0);case _:
   return null;
 
 }
@@ -413,4 +485,1350 @@ as String,
 
 }
 
+/// @nodoc
+
+
+class ChatStreamEvent_ConfigChanged extends ChatStreamEvent {
+  const ChatStreamEvent_ConfigChanged(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ChatStreamEvent_ConfigChanged);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ChatStreamEvent.configChanged()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+
+
+class ChatStreamEvent_Reaction extends ChatStreamEvent {
+  const ChatStreamEvent_Reaction(this.field0): super._();
+  
+
+ final  ReactionEvent field0;
+
+/// Create a copy of ChatStreamEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ChatStreamEvent_ReactionCopyWith<ChatStreamEvent_Reaction> get copyWith => _$ChatStreamEvent_ReactionCopyWithImpl<ChatStreamEvent_Reaction>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ChatStreamEvent_Reaction&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'ChatStreamEvent.reaction(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ChatStreamEvent_ReactionCopyWith<$Res> implements $ChatStreamEventCopyWith<$Res> {
+  factory $ChatStreamEvent_ReactionCopyWith(ChatStreamEvent_Reaction value, $Res Function(ChatStreamEvent_Reaction) _then) = _$ChatStreamEvent_ReactionCopyWithImpl;
+@useResult
+$Res call({
+ ReactionEvent field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$ChatStreamEvent_ReactionCopyWithImpl<$Res>
+    implements $ChatStreamEvent_ReactionCopyWith<$Res> {
+  _$ChatStreamEvent_ReactionCopyWithImpl(this._self, this._then);
+
+  final ChatStreamEvent_Reaction _self;
+  final $Res Function(ChatStreamEvent_Reaction) _then;
+
+/// Create a copy of ChatStreamEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(ChatStreamEvent_Reaction(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as ReactionEvent,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ChatStreamEvent_SystemNotice extends ChatStreamEvent {
+  const ChatStreamEvent_SystemNotice(this.field0): super._();
+  
+
+ final  String field0;
+
+/// Create a copy of ChatStreamEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ChatStreamEvent_SystemNoticeCopyWith<ChatStreamEvent_SystemNotice> get copyWith => _$ChatStreamEvent_SystemNoticeCopyWithImpl<ChatStreamEvent_SystemNotice>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ChatStreamEvent_SystemNotice&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'ChatStreamEvent.systemNotice(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ChatStreamEvent_SystemNoticeCopyWith<$Res> implements $ChatStreamEventCopyWith<$Res> {
+  factory $ChatStreamEvent_SystemNoticeCopyWith(ChatStreamEvent_SystemNotice value, $Res Function(ChatStreamEvent_SystemNotice) _then) = _$ChatStreamEvent_SystemNoticeCopyWithImpl;
+@useResult
+$Res call({
+ String field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$ChatStreamEvent_SystemNoticeCopyWithImpl<$Res>
+    implements $ChatStreamEvent_SystemNoticeCopyWith<$Res> {
+  _$ChatStreamEvent_SystemNoticeCopyWithImpl(this._self, this._then);
+
+  final ChatStreamEvent_SystemNotice _self;
+  final $Res Function(ChatStreamEvent_SystemNotice) _then;
+
+/// Create a copy of ChatStreamEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(ChatStreamEvent_SystemNotice(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ChatStreamEvent_Illustration extends ChatStreamEvent {
+  const ChatStreamEvent_Illustration(this.field0): super._();
+  
+
+ final  Message field0;
+
+/// Create a copy of ChatStreamEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ChatStreamEvent_IllustrationCopyWith<ChatStreamEvent_Illustration> get copyWith => _$ChatStreamEvent_IllustrationCopyWithImpl<ChatStreamEvent_Illustration>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ChatStreamEvent_Illustration&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'ChatStreamEvent.illustration(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ChatStreamEvent_IllustrationCopyWith<$Res> implements $ChatStreamEventCopyWith<$Res> {
+  factory $ChatStreamEvent_IllustrationCopyWith(ChatStreamEvent_Illustration value, $Res Function(ChatStreamEvent_Illustration) _then) = _$ChatStreamEvent_IllustrationCopyWithImpl;
+@useResult
+$Res call({
+ Message field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$ChatStreamEvent_IllustrationCopyWithImpl<$Res>
+    implements $ChatStreamEvent_IllustrationCopyWith<$Res> {
+  _$ChatStreamEvent_IllustrationCopyWithImpl(this._self, this._then);
+
+  final ChatStreamEvent_Illustration _self;
+  final $Res Function(ChatStreamEvent_Illustration) _then;
+
+/// Create a copy of ChatStreamEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(ChatStreamEvent_Illustration(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as Message,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ChatStreamEvent_AudioReady extends ChatStreamEvent {
+  const ChatStreamEvent_AudioReady(this.field0): super._();
+  
+
+ final  AudioReadyEvent field0;
+
+/// Create a copy of ChatStreamEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ChatStreamEvent_AudioReadyCopyWith<ChatStreamEvent_AudioReady> get copyWith => _$ChatStreamEvent_AudioReadyCopyWithImpl<ChatStreamEvent_AudioReady>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ChatStreamEvent_AudioReady&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'ChatStreamEvent.audioReady(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ChatStreamEvent_AudioReadyCopyWith<$Res> implements $ChatStreamEventCopyWith<$Res> {
+  factory $ChatStreamEvent_AudioReadyCopyWith(ChatStreamEvent_AudioReady value, $Res Function(ChatStreamEvent_AudioReady) _then) = _$ChatStreamEvent_AudioReadyCopyWithImpl;
+@useResult
+$Res call({
+ AudioReadyEvent field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$ChatStreamEvent_AudioReadyCopyWithImpl<$Res>
+    implements $ChatStreamEvent_AudioReadyCopyWith<$Res> {
+  _$ChatStreamEvent_AudioReadyCopyWithImpl(this._self, this._then);
+
+  final ChatStreamEvent_AudioReady _self;
+  final $Res Function(ChatStreamEvent_AudioReady) _then;
+
+/// Create a copy of ChatStreamEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(ChatStreamEvent_AudioReady(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as AudioReadyEvent,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ChatStreamEvent_Completed extends ChatStreamEvent {
+  const ChatStreamEvent_Completed(this.field0): super._();
+  
+
+ final  GenerationCompletedEvent field0;
+
+/// Create a copy of ChatStreamEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ChatStreamEvent_CompletedCopyWith<ChatStreamEvent_Completed> get copyWith => _$ChatStreamEvent_CompletedCopyWithImpl<ChatStreamEvent_Completed>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ChatStreamEvent_Completed&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'ChatStreamEvent.completed(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ChatStreamEvent_CompletedCopyWith<$Res> implements $ChatStreamEventCopyWith<$Res> {
+  factory $ChatStreamEvent_CompletedCopyWith(ChatStreamEvent_Completed value, $Res Function(ChatStreamEvent_Completed) _then) = _$ChatStreamEvent_CompletedCopyWithImpl;
+@useResult
+$Res call({
+ GenerationCompletedEvent field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$ChatStreamEvent_CompletedCopyWithImpl<$Res>
+    implements $ChatStreamEvent_CompletedCopyWith<$Res> {
+  _$ChatStreamEvent_CompletedCopyWithImpl(this._self, this._then);
+
+  final ChatStreamEvent_Completed _self;
+  final $Res Function(ChatStreamEvent_Completed) _then;
+
+/// Create a copy of ChatStreamEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(ChatStreamEvent_Completed(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as GenerationCompletedEvent,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ChatStreamEvent_Outbox extends ChatStreamEvent {
+  const ChatStreamEvent_Outbox(this.field0): super._();
+  
+
+ final  OutboxEntry field0;
+
+/// Create a copy of ChatStreamEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ChatStreamEvent_OutboxCopyWith<ChatStreamEvent_Outbox> get copyWith => _$ChatStreamEvent_OutboxCopyWithImpl<ChatStreamEvent_Outbox>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ChatStreamEvent_Outbox&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'ChatStreamEvent.outbox(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ChatStreamEvent_OutboxCopyWith<$Res> implements $ChatStreamEventCopyWith<$Res> {
+  factory $ChatStreamEvent_OutboxCopyWith(ChatStreamEvent_Outbox value, $Res Function(ChatStreamEvent_Outbox) _then) = _$ChatStreamEvent_OutboxCopyWithImpl;
+@useResult
+$Res call({
+ OutboxEntry field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$ChatStreamEvent_OutboxCopyWithImpl<$Res>
+    implements $ChatStreamEvent_OutboxCopyWith<$Res> {
+  _$ChatStreamEvent_OutboxCopyWithImpl(this._self, this._then);
+
+  final ChatStreamEvent_Outbox _self;
+  final $Res Function(ChatStreamEvent_Outbox) _then;
+
+/// Create a copy of ChatStreamEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(ChatStreamEvent_Outbox(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as OutboxEntry,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ChatStreamEvent_Safety extends ChatStreamEvent {
+  const ChatStreamEvent_Safety(this.field0): super._();
+  
+
+ final  SafetyEvent field0;
+
+/// Create a copy of ChatStreamEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ChatStreamEvent_SafetyCopyWith<ChatStreamEvent_Safety> get copyWith => _$ChatStreamEvent_SafetyCopyWithImpl<ChatStreamEvent_Safety>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ChatStreamEvent_Safety&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'ChatStreamEvent.safety(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ChatStreamEvent_SafetyCopyWith<$Res> implements $ChatStreamEventCopyWith<$Res> {
+  factory $ChatStreamEvent_SafetyCopyWith(ChatStreamEvent_Safety value, $Res Function(ChatStreamEvent_Safety) _then) = _$ChatStreamEvent_SafetyCopyWithImpl;
+@useResult
+$Res call({
+ SafetyEvent field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$ChatStreamEvent_SafetyCopyWithImpl<$Res>
+    implements $ChatStreamEvent_SafetyCopyWith<$Res> {
+  _$ChatStreamEvent_SafetyCopyWithImpl(this._self, this._then);
+
+  final ChatStreamEvent_Safety _self;
+  final $Res Function(ChatStreamEvent_Safety) _then;
+
+/// Create a copy of ChatStreamEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(ChatStreamEvent_Safety(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as SafetyEvent,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ChatStreamEvent_CheckIn extends ChatStreamEvent {
+  const ChatStreamEvent_CheckIn(this.field0): super._();
+  
+
+ final  String field0;
+
+/// Create a copy of ChatStreamEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ChatStreamEvent_CheckInCopyWith<ChatStreamEvent_CheckIn> get copyWith => _$ChatStreamEvent_CheckInCopyWithImpl<ChatStreamEvent_CheckIn>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ChatStreamEvent_CheckIn&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'ChatStreamEvent.checkIn(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ChatStreamEvent_CheckInCopyWith<$Res> implements $ChatStreamEventCopyWith<$Res> {
+  factory $ChatStreamEvent_CheckInCopyWith(ChatStreamEvent_CheckIn value, $Res Function(ChatStreamEvent_CheckIn) _then) = _$ChatStreamEvent_CheckInCopyWithImpl;
+@useResult
+$Res call({
+ String field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$ChatStreamEvent_CheckInCopyWithImpl<$Res>
+    implements $ChatStreamEvent_CheckInCopyWith<$Res> {
+  _$ChatStreamEvent_CheckInCopyWithImpl(this._self, this._then);
+
+  final ChatStreamEvent_CheckIn _self;
+  final $Res Function(ChatStreamEvent_CheckIn) _then;
+
+/// Create a copy of ChatStreamEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(ChatStreamEvent_CheckIn(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ChatStreamEvent_SummaryPreview extends ChatStreamEvent {
+  const ChatStreamEvent_SummaryPreview(this.field0): super._();
+  
+
+ final  MemorySummary field0;
+
+/// Create a copy of ChatStreamEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ChatStreamEvent_SummaryPreviewCopyWith<ChatStreamEvent_SummaryPreview> get copyWith => _$ChatStreamEvent_SummaryPreviewCopyWithImpl<ChatStreamEvent_SummaryPreview>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ChatStreamEvent_SummaryPreview&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'ChatStreamEvent.summaryPreview(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ChatStreamEvent_SummaryPreviewCopyWith<$Res> implements $ChatStreamEventCopyWith<$Res> {
+  factory $ChatStreamEvent_SummaryPreviewCopyWith(ChatStreamEvent_SummaryPreview value, $Res Function(ChatStreamEvent_SummaryPreview) _then) = _$ChatStreamEvent_SummaryPreviewCopyWithImpl;
+@useResult
+$Res call({
+ MemorySummary field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$ChatStreamEvent_SummaryPreviewCopyWithImpl<$Res>
+    implements $ChatStreamEvent_SummaryPreviewCopyWith<$Res> {
+  _$ChatStreamEvent_SummaryPreviewCopyWithImpl(this._self, this._then);
+
+  final ChatStreamEvent_SummaryPreview _self;
+  final $Res Function(ChatStreamEvent_SummaryPreview) _then;
+
+/// Create a copy of ChatStreamEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(ChatStreamEvent_SummaryPreview(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as MemorySummary,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ChatStreamEvent_DraftDelta extends ChatStreamEvent {
+  const ChatStreamEvent_DraftDelta(this.field0): super._();
+  
+
+ final  String field0;
+
+/// Create a copy of ChatStreamEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ChatStreamEvent_DraftDeltaCopyWith<ChatStreamEvent_DraftDelta> get copyWith => _$ChatStreamEvent_DraftDeltaCopyWithImpl<ChatStreamEvent_DraftDelta>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ChatStreamEvent_DraftDelta&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'ChatStreamEvent.draftDelta(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ChatStreamEvent_DraftDeltaCopyWith<$Res> implements $ChatStreamEventCopyWith<$Res> {
+  factory $ChatStreamEvent_DraftDeltaCopyWith(ChatStreamEvent_DraftDelta value, $Res Function(ChatStreamEvent_DraftDelta) _then) = _$ChatStreamEvent_DraftDeltaCopyWithImpl;
+@useResult
+$Res call({
+ String field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$ChatStreamEvent_DraftDeltaCopyWithImpl<$Res>
+    implements $ChatStreamEvent_DraftDeltaCopyWith<$Res> {
+  _$ChatStreamEvent_DraftDeltaCopyWithImpl(this._self, this._then);
+
+  final ChatStreamEvent_DraftDelta _self;
+  final $Res Function(ChatStreamEvent_DraftDelta) _then;
+
+/// Create a copy of ChatStreamEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(ChatStreamEvent_DraftDelta(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ChatStreamEvent_Refined extends ChatStreamEvent {
+  const ChatStreamEvent_Refined(this.field0): super._();
+  
+
+ final  String field0;
+
+/// Create a copy of ChatStreamEvent
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ChatStreamEvent_RefinedCopyWith<ChatStreamEvent_Refined> get copyWith => _$ChatStreamEvent_RefinedCopyWithImpl<ChatStreamEvent_Refined>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ChatStreamEvent_Refined&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'ChatStreamEvent.refined(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ChatStreamEvent_RefinedCopyWith<$Res> implements $ChatStreamEventCopyWith<$Res> {
+  factory $ChatStreamEvent_RefinedCopyWith(ChatStreamEvent_Refined value, $Res Function(ChatStreamEvent_Refined) _then) = _$ChatStreamEvent_RefinedCopyWithImpl;
+@useResult
+$Res call({
+ String field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$ChatStreamEvent_RefinedCopyWithImpl<$Res>
+    implements $ChatStreamEvent_RefinedCopyWith<$Res> {
+  _$ChatStreamEvent_RefinedCopyWithImpl(this._self, this._then);
+
+  final ChatStreamEvent_Refined _self;
+  final $Res Function(ChatStreamEvent_Refined) _then;
+
+/// Create a copy of ChatStreamEvent
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(ChatStreamEvent_Refined(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$EncryptionSecret {
+
+ Object get field0;
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is EncryptionSecret&&const DeepCollectionEquality().equals(other.field0, field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,const DeepCollectionEquality().hash(field0));
+
+@override
+String toString() {
+  return 'EncryptionSecret(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+class $EncryptionSecretCopyWith<$Res>  {
+$EncryptionSecretCopyWith(EncryptionSecret _, $Res Function(EncryptionSecret) __);
+}
+
+
+/// Adds pattern-matching-related methods to [EncryptionSecret].
+extension EncryptionSecretPatterns on EncryptionSecret {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( EncryptionSecret_Passphrase value)?  passphrase,TResult Function( EncryptionSecret_Keystore value)?  keystore,required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case EncryptionSecret_Passphrase() when passphrase != null:
+return passphrase(_that);case EncryptionSecret_Keystore() when keystore != null:
+return keystore(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( EncryptionSecret_Passphrase value)  passphrase,required TResult Function( EncryptionSecret_Keystore value)  keystore,}){
+final _that = this;
+switch (_that) {
+case EncryptionSecret_Passphrase():
+return passphrase(_that);case EncryptionSecret_Keystore():
+return keystore(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( EncryptionSecret_Passphrase value)?  passphrase,TResult? Function( EncryptionSecret_Keystore value)?  keystore,}){
+final _that = this;
+switch (_that) {
+case EncryptionSecret_Passphrase() when passphrase != null:
+return passphrase(_that);case EncryptionSecret_Keystore() when keystore != null:
+return keystore(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( String field0)?  passphrase,TResult Function( Uint8List field0)?  keystore,required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case EncryptionSecret_Passphrase() when passphrase != null:
+return passphrase(_that.field0);case EncryptionSecret_Keystore() when keystore != null:
+return keystore(_that.field0);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( String field0)  passphrase,required TResult Function( Uint8List field0)  keystore,}) {final _that = this;
+switch (_that) {
+case EncryptionSecret_Passphrase():
+return passphrase(_that.field0);case EncryptionSecret_Keystore():
+return keystore(_that.field0);}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( String field0)?  passphrase,TResult? Function( Uint8List field0)?  keystore,}) {final _that = this;
+switch (_that) {
+case EncryptionSecret_Passphrase() when passphrase != null:
+return passphrase(_that.field0);case EncryptionSecret_Keystore() when keystore != null:
+return keystore(_that.field0);case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class EncryptionSecret_Passphrase extends EncryptionSecret {
+  const EncryptionSecret_Passphrase(this.field0): super._();
+  
+
+ final  String field0;
+
+/// Create a copy of EncryptionSecret
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$EncryptionSecret_PassphraseCopyWith<EncryptionSecret_Passphrase> get copyWith => _$EncryptionSecret_PassphraseCopyWithImpl<EncryptionSecret_Passphrase>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is EncryptionSecret_Passphrase&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'EncryptionSecret.passphrase(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $EncryptionSecret_PassphraseCopyWith<$Res> implements $EncryptionSecretCopyWith<$Res> {
+  factory $EncryptionSecret_PassphraseCopyWith(EncryptionSecret_Passphrase value, $Res Function(EncryptionSecret_Passphrase) _then) = _$EncryptionSecret_PassphraseCopyWithImpl;
+@useResult
+$Res call({
+ String field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$EncryptionSecret_PassphraseCopyWithImpl<$Res>
+    implements $EncryptionSecret_PassphraseCopyWith<$Res> {
+  _$EncryptionSecret_PassphraseCopyWithImpl(this._self, this._then);
+
+  final EncryptionSecret_Passphrase _self;
+  final $Res Function(EncryptionSecret_Passphrase) _then;
+
+/// Create a copy of EncryptionSecret
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(EncryptionSecret_Passphrase(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as String,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class EncryptionSecret_Keystore extends EncryptionSecret {
+  const EncryptionSecret_Keystore(this.field0): super._();
+  
+
+ final  Uint8List field0;
+
+/// Create a copy of EncryptionSecret
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$EncryptionSecret_KeystoreCopyWith<EncryptionSecret_Keystore> get copyWith => _$EncryptionSecret_KeystoreCopyWithImpl<EncryptionSecret_Keystore>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is EncryptionSecret_Keystore&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'EncryptionSecret.keystore(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $EncryptionSecret_KeystoreCopyWith<$Res> implements $EncryptionSecretCopyWith<$Res> {
+  factory $EncryptionSecret_KeystoreCopyWith(EncryptionSecret_Keystore value, $Res Function(EncryptionSecret_Keystore) _then) = _$EncryptionSecret_KeystoreCopyWithImpl;
+@useResult
+$Res call({
+ Uint8List field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$EncryptionSecret_KeystoreCopyWithImpl<$Res>
+    implements $EncryptionSecret_KeystoreCopyWith<$Res> {
+  _$EncryptionSecret_KeystoreCopyWithImpl(this._self, this._then);
+
+  final EncryptionSecret_Keystore _self;
+  final $Res Function(EncryptionSecret_Keystore) _then;
+
+/// Create a copy of EncryptionSecret
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(EncryptionSecret_Keystore(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as Uint8List,
+  ));
+}
+
+
+}
+
+/// @nodoc
+mixin _$ThinkingRetention {
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ThinkingRetention);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ThinkingRetention()';
+}
+
+
+}
+
+/// @nodoc
+class $ThinkingRetentionCopyWith<$Res>  {
+$ThinkingRetentionCopyWith(ThinkingRetention _, $Res Function(ThinkingRetention) __);
+}
+
+
+/// Adds pattern-matching-related methods to [ThinkingRetention].
+extension ThinkingRetentionPatterns on ThinkingRetention {
+/// A variant of `map` that fallback to returning `orElse`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( ThinkingRetention_KeepAll value)?  keepAll,TResult Function( ThinkingRetention_KeepLastTurns value)?  keepLastTurns,TResult Function( ThinkingRetention_DiscardAfterSummary value)?  discardAfterSummary,required TResult orElse(),}){
+final _that = this;
+switch (_that) {
+case ThinkingRetention_KeepAll() when keepAll != null:
+return keepAll(_that);case ThinkingRetention_KeepLastTurns() when keepLastTurns != null:
+return keepLastTurns(_that);case ThinkingRetention_DiscardAfterSummary() when discardAfterSummary != null:
+return discardAfterSummary(_that);case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// Callbacks receives the raw object, upcasted.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case final Subclass2 value:
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( ThinkingRetention_KeepAll value)  keepAll,required TResult Function( ThinkingRetention_KeepLastTurns value)  keepLastTurns,required TResult Function( ThinkingRetention_DiscardAfterSummary value)  discardAfterSummary,}){
+final _that = this;
+switch (_that) {
+case ThinkingRetention_KeepAll():
+return keepAll(_that);case ThinkingRetention_KeepLastTurns():
+return keepLastTurns(_that);case ThinkingRetention_DiscardAfterSummary():
+return discardAfterSummary(_that);}
+}
+/// A variant of `map` that fallback to returning `null`.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case final Subclass value:
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( ThinkingRetention_KeepAll value)?  keepAll,TResult? Function( ThinkingRetention_KeepLastTurns value)?  keepLastTurns,TResult? Function( ThinkingRetention_DiscardAfterSummary value)?  discardAfterSummary,}){
+final _that = this;
+switch (_that) {
+case ThinkingRetention_KeepAll() when keepAll != null:
+return keepAll(_that);case ThinkingRetention_KeepLastTurns() when keepLastTurns != null:
+return keepLastTurns(_that);case ThinkingRetention_DiscardAfterSummary() when discardAfterSummary != null:
+return discardAfterSummary(_that);case _:
+  return null;
+
+}
+}
+/// A variant of `when` that fallback to an `orElse` callback.
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return orElse();
+/// }
+/// ```
+
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function()?  keepAll,TResult Function( int field0)?  keepLastTurns,TResult Function()?  discardAfterSummary,required TResult orElse(),}) {final _that = this;
+switch (_that) {
+case ThinkingRetention_KeepAll() when keepAll != null:
+return keepAll();case ThinkingRetention_KeepLastTurns() when keepLastTurns != null:
+return keepLastTurns(_that.field0);case ThinkingRetention_DiscardAfterSummary() when discardAfterSummary != null:
+return discardAfterSummary();case _:
+  return orElse();
+
+}
+}
+/// A `switch`-like method, using callbacks.
+///
+/// As opposed to `map`, this offers destructuring.
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case Subclass2(:final field2):
+///     return ...;
+/// }
+/// ```
+
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function()  keepAll,required TResult Function( int field0)  keepLastTurns,required TResult Function()  discardAfterSummary,}) {final _that = this;
+switch (_that) {
+case ThinkingRetention_KeepAll():
+return keepAll();case ThinkingRetention_KeepLastTurns():
+return keepLastTurns(_that.field0);case ThinkingRetention_DiscardAfterSummary():
+return discardAfterSummary();}
+}
+/// A variant of `when` that fallback to returning `null`
+///
+/// It is equivalent to doing:
+/// ```dart
+/// switch (sealedClass) {
+///   case Subclass(:final field):
+///     return ...;
+///   case _:
+///     return null;
+/// }
+/// ```
+
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function()?  keepAll,TResult? Function( int field0)?  keepLastTurns,TResult? Function()?  discardAfterSummary,}) {final _that = this;
+switch (_that) {
+case ThinkingRetention_KeepAll() when keepAll != null:
+return keepAll();case ThinkingRetention_KeepLastTurns() when keepLastTurns != null:
+return keepLastTurns(_that.field0);case ThinkingRetention_DiscardAfterSummary() when discardAfterSummary != null:
+return discardAfterSummary();case _:
+  return null;
+
+}
+}
+
+}
+
+/// @nodoc
+
+
+class ThinkingRetention_KeepAll extends ThinkingRetention {
+  const ThinkingRetention_KeepAll(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ThinkingRetention_KeepAll);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ThinkingRetention.keepAll()';
+}
+
+
+}
+
+
+
+
+/// @nodoc
+
+
+class ThinkingRetention_KeepLastTurns extends ThinkingRetention {
+  const ThinkingRetention_KeepLastTurns(this.field0): super._();
+  
+
+ final  int field0;
+
+/// Create a copy of ThinkingRetention
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$ThinkingRetention_KeepLastTurnsCopyWith<ThinkingRetention_KeepLastTurns> get copyWith => _$ThinkingRetention_KeepLastTurnsCopyWithImpl<ThinkingRetention_KeepLastTurns>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ThinkingRetention_KeepLastTurns&&(identical(other.field0, field0) || other.field0 == field0));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,field0);
+
+@override
+String toString() {
+  return 'ThinkingRetention.keepLastTurns(field0: $field0)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $ThinkingRetention_KeepLastTurnsCopyWith<$Res> implements $ThinkingRetentionCopyWith<$Res> {
+  factory $ThinkingRetention_KeepLastTurnsCopyWith(ThinkingRetention_KeepLastTurns value, $Res Function(ThinkingRetention_KeepLastTurns) _then) = _$ThinkingRetention_KeepLastTurnsCopyWithImpl;
+@useResult
+$Res call({
+ int field0
+});
+
+
+
+
+}
+/// @nodoc
+class _$ThinkingRetention_KeepLastTurnsCopyWithImpl<$Res>
+    implements $ThinkingRetention_KeepLastTurnsCopyWith<$Res> {
+  _$ThinkingRetention_KeepLastTurnsCopyWithImpl(this._self, this._then);
+
+  final ThinkingRetention_KeepLastTurns _self;
+  final $Res Function(ThinkingRetention_KeepLastTurns) _then;
+
+/// Create a copy of ThinkingRetention
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+  return _then(ThinkingRetention_KeepLastTurns(
+null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
+as int,
+  ));
+}
+
+
+}
+
+/// @nodoc
+
+
+class ThinkingRetention_DiscardAfterSummary extends ThinkingRetention {
+  const ThinkingRetention_DiscardAfterSummary(): super._();
+  
+
+
+
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is ThinkingRetention_DiscardAfterSummary);
+}
+
+
+@override
+int get hashCode => runtimeType.hashCode;
+
+@override
+String toString() {
+  return 'ThinkingRetention.discardAfterSummary()';
+}
+
+
+}
+
+
+
+
 // dart format on
@@ -0,0 +1,119 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// 单条事实
+class Fact {
+  final String id;
+  final String content;
+  final FactCategory category;
+  /// 事实来源：从哪轮对话中提取
+  final int sourceTurn;
+  /// 创建时间
+  final PlatformInt64 createdAt;
+  /// 最后确认/更新时间
+  final PlatformInt64 lastConfirmedAt;
+  /// 关键词索引（用于BM25检索）
+  final List<String> keywords;
+  /// 关联实体（用于关系图检索）
+  final List<String> entities;
+  /// 置信度 0.0-1.0（多次确认的事实置信度更高）
+  final double confidence;
+  /// 被引用次数（检索命中次数，用于热度排序）
+  final int hitCount;
+  /// 上下文卡片：结构化元信息（参考智谱增强型上下文）
+  final String contextSnippet;
+  /// 描述同一内容的记忆摘要 ID（与 MemorySummary::linked_fact_ids 互为反向链接）
+  final List<String> summaryIds;
+  /// 被哪条新事实取代（仅归档中的事实有值，见 find_contradiction）
+  final String? supersededBy;
+  /// 被取代的时间
+  final PlatformInt64? supersededAt;
+  /// 用户手动添加或修改过：后续自动提取不再改写其内容、分类与置信度，
+  /// 与之矛盾的新提取事实直接丢弃
+  final bool manualOverride;
+  /// 记录时对方使用的人设（见 PersonaStore）；用户档案按人设分区
+  final String? personaId;
+
+  const Fact({
+    required this.id,
+    required this.content,
+    required this.category,
+    required this.sourceTurn,
+    required this.createdAt,
+    required this.lastConfirmedAt,
+    required this.keywords,
+    required this.entities,
+    required this.confidence,
+    required this.hitCount,
+    required this.contextSnippet,
+    required this.summaryIds,
+    this.supersededBy,
+    this.supersededAt,
+    required this.manualOverride,
+    this.personaId,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      content.hashCode ^
+      category.hashCode ^
+      sourceTurn.hashCode ^
+      createdAt.hashCode ^
+      lastConfirmedAt.hashCode ^
+      keywords.hashCode ^
+      entities.hashCode ^
+      confidence.hashCode ^
+      hitCount.hashCode ^
+      contextSnippet.hashCode ^
+      summaryIds.hashCode ^
+      supersededBy.hashCode ^
+      supersededAt.hashCode ^
+      manualOverride.hashCode ^
+      personaId.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Fact &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          content == other.content &&
+          category == other.category &&
+          sourceTurn == other.sourceTurn &&
+          createdAt == other.createdAt &&
+          lastConfirmedAt == other.lastConfirmedAt &&
+          keywords == other.keywords &&
+          entities == other.entities &&
+          confidence == other.confidence &&
+          hitCount == other.hitCount &&
+          contextSnippet == other.contextSnippet &&
+          summaryIds == other.summaryIds &&
+          supersededBy == other.supersededBy &&
+          supersededAt == other.supersededAt &&
+          manualOverride == other.manualOverride &&
+          personaId == other.personaId;
+}
+
+/// 事实分类 — 决定事实的存储优先级和检索权重
+enum FactCategory {
+  /// 身份信息：姓名、年龄、职业、性格设定（永不过期）
+  identity,
+  /// 人物关系：A和B的关系（高优先级，可更新）
+  relationship,
+  /// 偏好习惯：喜好、习惯、口癖（中优先级）
+  preference,
+  /// 关键事件：已发生的重要事件（永不过期）
+  event,
+  /// 当前状态：情绪、位置、正在做的事（可被新状态覆盖）
+  currentState,
+  /// 承诺约定：双方的承诺和约定（高优先级）
+  promise,
+  /// 共识观点：双方达成的共识（中优先级）
+  consensus,
+}
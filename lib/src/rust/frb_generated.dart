@@ -5,6 +5,7 @@
 
 import 'api/chat_api.dart';
 import 'api/data_models.dart';
+import 'api/knowledge_store.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
@@ -65,7 +66,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => -21643838;
+  int get rustContentHash => -1263351065;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -76,474 +77,759 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
+  Future<ActionFrequency> crateApiDataModelsActionFrequencyDefault();
+
   Future<bool> crateApiChatApiAddAssistantMessage({
     required String conversationId,
     required String content,
   });
 
+  Future<Fact?> crateApiChatApiAddManualFact({
+    String? conversationId,
+    required String content,
+    required FactCategory category,
+    double? confidence,
+  });
+
   Future<bool> crateApiChatApiAddSystemMessage({
     required String conversationId,
     required String content,
   });
 
+  Future<String?> crateApiChatApiAddUserAuthoredReply({
+    required String conversationId,
+    required String content,
+  });
+
+  Future<AmbientContext> crateApiDataModelsAmbientContextDefault();
+
+  Future<ConversationInsights?> crateApiChatApiAnalyzeConversation({
+    required String conversationId,
+  });
+
   Future<AppSettings> crateApiDataModelsAppSettingsDefault();
 
+  Future<MemorySummary> crateApiChatApiApproveMemorySummary({
+    required String conversationId,
+    required String summaryId,
+  });
+
+  Future<List<MemoryFidelityReport>> crateApiChatApiAuditAllMemoryFidelity();
+
+  Future<MemoryFidelityReport?> crateApiChatApiAuditMemoryFidelity({
+    required String conversationId,
+  });
+
+  Future<BackoffStrategy> crateApiDataModelsBackoffStrategyDefault();
+
+  Future<BackupRetention> crateApiDataModelsBackupRetentionDefault();
+
+  Future<BackupScope> crateApiDataModelsBackupScopeDefault();
+
+  Future<BlockingStats> crateApiDataModelsBlockingStatsDefault();
+
+  Future<void> crateApiChatApiClearLogs();
+
+  Future<void> crateApiChatApiClearMetrics();
+
+  Future<ConversationClosure> crateApiChatApiCloseConversation({
+    required String conversationId,
+    required String model,
+  });
+
+  Future<bool> crateApiChatApiCloseSandboxConversation({required String id});
+
+  Future<ContentIntensity> crateApiDataModelsContentIntensityDefault();
+
+  Future<ContextLayers> crateApiDataModelsContextLayersDefault();
+
+  Future<ConversationFilter> crateApiDataModelsConversationFilterDefault();
+
+  Future<ConversationMode> crateApiDataModelsConversationModeDefault();
+
+  Future<ConversationOrganization> crateApiDataModelsConversationOrganizationDefault();
+
+  Future<BackupSnapshotInfo?> crateApiChatApiCreateBackup({
+    String? archivePath,
+  });
+
+  Future<String?> crateApiChatApiCreateBranch({
+    required String conversationId,
+    required String fromMessageId,
+    String? name,
+  });
+
   Future<Conversation> crateApiChatApiCreateConversation();
 
+  Future<Conversation?> crateApiChatApiCreateSandboxConversation({
+    required CharacterCard card,
+  });
+
   Future<bool> crateApiChatApiDeleteConversation({required String id});
 
+  Future<bool> crateApiChatApiDeleteFact({
+    String? conversationId,
+    required String factId,
+  });
+
+  Future<bool> crateApiChatApiDeleteMemory({
+    required String conversationId,
+    required String summaryId,
+  });
+
   Future<bool> crateApiChatApiDeleteMessage({
     required String conversationId,
     required String messageId,
   });
 
+  Future<bool> crateApiChatApiDeletePersona({required String personaId});
+
   Future<MessageType> crateApiChatApiDetectMessageType({
     required String content,
   });
 
+  Future<MessageType> crateApiChatApiDetectMessageTypeIn({
+    required String conversationId,
+    required String content,
+  });
+
+  Future<DeviceConditions> crateApiDataModelsDeviceConditionsDefault();
+
   Future<DialogueStyle> crateApiDataModelsDialogueStyleDefault();
 
+  Future<int> crateApiChatApiDisableEncryption({
+    required EncryptionSecret secret,
+  });
+
+  Future<bool> crateApiChatApiDiscardMemorySummary({
+    required String conversationId,
+  });
+
+  Future<bool> crateApiChatApiDiscardOutboxEntry({required String entryId});
+
+  Future<bool> crateApiChatApiDissolveGroupChat({
+    required String conversationId,
+  });
+
+  Future<bool> crateApiChatApiEditMemory({
+    required String conversationId,
+    required String summaryId,
+    required String summary,
+    required List<String> coreFacts,
+  });
+
   Future<bool> crateApiChatApiEditMessage({
     required String conversationId,
     required String messageId,
     required String newContent,
   });
 
+  Future<EmojiUsage> crateApiDataModelsEmojiUsageDefault();
+
+  Future<int> crateApiChatApiEnableEncryption({
+    required EncryptionSecret secret,
+  });
+
+  Future<MemoryExplanation?> crateApiChatApiExplainMemory({
+    required String conversationId,
+    required String summaryId,
+  });
+
+  Future<RelevanceInsight?> crateApiChatApiExplainRelevance({
+    required String conversationId,
+    required String fact,
+    required String userContent,
+  });
+
+  Future<DataExportInfo> crateApiChatApiExportAllData({
+    required String targetPath,
+  });
+
+  Future<String> crateApiChatApiExportKnowledgeGraph({
+    String? conversationId,
+    required GraphExportFormat format,
+  });
+
+  Future<String> crateApiChatApiExportLogs({required int limit});
+
+  Future<List<GraphEdge>> crateApiChatApiFindGraphPath({
+    String? conversationId,
+    required String from,
+    required String to,
+  });
+
+  Future<Formality> crateApiDataModelsFormalityDefault();
+
+  Future<DailyDigest?> crateApiChatApiGenerateDailyDigest();
+
+  Future<GenerationMetadata> crateApiDataModelsGenerationMetadataDefault();
+
   Future<List<ModelInfo>> crateApiChatApiGetAvailableModels();
 
+  Future<List<BlockedTopic>> crateApiChatApiGetBlockedTopics({
+    required String conversationId,
+  });
+
+  Future<BlockingStats> crateApiChatApiGetBlockingStats();
+
+  Future<MoodState?> crateApiChatApiGetCharacterMood({
+    required String conversationId,
+  });
+
+  Future<List<CharacterRelation>> crateApiChatApiGetCharacterRelations({
+    required String conversationId,
+  });
+
+  Future<Map<String, String>> crateApiChatApiGetCharacterVoices();
+
+  Future<CheckInSchedule?> crateApiChatApiGetCheckInSchedule({
+    required String conversationId,
+  });
+
   Future<Conversation?> crateApiChatApiGetConversation({required String id});
 
   Future<List<ConversationSummary>> crateApiChatApiGetConversationList();
 
-  Future<AppSettings> crateApiChatApiGetSettings();
-
-  Future<int> crateApiChatApiGetTurnCount({required String conversationId});
+  Future<List<DegradationRecord>> crateApiChatApiGetDegradationHistory({
+    required String conversationId,
+  });
 
-  Future<void> crateApiChatApiInitApp({required String dataPath});
+  Future<List<LexiconEntry>> crateApiChatApiGetEmotionLexicon();
 
-  Future<MessageType> crateApiDataModelsMessageTypeDefault();
+  Future<EncryptionStatus> crateApiChatApiGetEncryptionStatus();
 
-  Stream<ChatStreamEvent> crateApiChatApiRegenerateResponse({
+  Future<List<FactConflict>> crateApiChatApiGetFactConflicts({
     required String conversationId,
-    required String model,
-    required bool enableThinking,
   });
 
-  Future<bool> crateApiChatApiRestartStory({required String conversationId});
+  Future<List<ConversationSummary>> crateApiChatApiGetFilteredConversationList({
+    required ConversationFilter filter,
+  });
 
-  Future<List<String>> crateApiChatApiRollbackToMessage({
+  Future<GenerationMetadata?> crateApiChatApiGetGenerationMetadata({
     required String conversationId,
     required String messageId,
   });
 
-  Future<bool> crateApiChatApiSaveSettings({required AppSettings settings});
+  Future<GroupChat?> crateApiChatApiGetGroupChat({
+    required String conversationId,
+  });
 
-  Future<List<MemorySearchResult>> crateApiChatApiSearchMemories({
+  Future<JobSchedulerStats> crateApiChatApiGetJobSchedulerStats();
+
+  Future<KnowledgeBinding> crateApiChatApiGetKnowledgeBinding({
     required String conversationId,
-    required String query,
-    required BigInt topK,
   });
 
-  Stream<ChatStreamEvent> crateApiChatApiSendMessage({
+  Future<KnowledgeGraphView> crateApiChatApiGetKnowledgeGraph({
+    String? conversationId,
+  });
+
+  Future<List<LoreEntry>> crateApiChatApiGetLoreEntries({
+    String? conversationId,
+  });
+
+  Future<List<MemoryFidelityReport>> crateApiChatApiGetMemoryFidelityHistory({
     required String conversationId,
-    required String content,
-    required String model,
-    required bool enableThinking,
   });
 
-  Future<void> crateApiChatApiSetApiKey({required String apiKey});
+  Future<MetricsSnapshot> crateApiChatApiGetMetrics();
 
-  Future<bool> crateApiChatApiSetDialogueStyle({
+  Future<List<OutboxEntry>> crateApiChatApiGetOutbox();
+
+  Future<MemorySummary?> crateApiChatApiGetPendingMemorySummary({
     required String conversationId,
-    required DialogueStyle style,
   });
 
-  Future<bool> crateApiChatApiShouldSummarizeMemory({
+  Future<String?> crateApiChatApiGetPromptTemplate({required String name});
+
+  Future<RetentionPolicy> crateApiChatApiGetRetentionPolicy();
+
+  Future<SafetyPolicy> crateApiChatApiGetSafetyPolicy();
+
+  Future<SayDoRuleSet> crateApiChatApiGetSaydoRules({
     required String conversationId,
   });
 
-  Stream<ChatStreamEvent> crateApiChatApiTriggerMemorySummarize({
+  Future<AppSettings> crateApiChatApiGetSettings();
+
+  Future<List<ShadowEvalRecord>> crateApiChatApiGetShadowEvalRecords({
     required String conversationId,
   });
 
-  Future<bool> crateApiChatApiValidateApiKey({required String apiKey});
-}
+  Future<ShadowEvalSummary> crateApiChatApiGetShadowEvalSummary({
+    String? conversationId,
+  });
 
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
+  Future<List<TimelineEntry>> crateApiChatApiGetStoryTimeline({
+    required String conversationId,
   });
 
-  @override
-  Future<bool> crateApiChatApiAddAssistantMessage({
+  Future<SummarizationConfig> crateApiChatApiGetSummarizationConfig({
     required String conversationId,
-    required String content,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(conversationId, serializer);
-          sse_encode_String(content, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 1,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiChatApiAddAssistantMessageConstMeta,
-        argValues: [conversationId, content],
-        apiImpl: this,
-      ),
-    );
-  }
+  });
 
-  TaskConstMeta get kCrateApiChatApiAddAssistantMessageConstMeta =>
-      const TaskConstMeta(
-        debugName: "add_assistant_message",
-        argNames: ["conversationId", "content"],
-      );
+  Future<SyncSettings> crateApiChatApiGetSyncSettings();
 
-  @override
-  Future<bool> crateApiChatApiAddSystemMessage({
+  Future<int> crateApiChatApiGetTurnCount({required String conversationId});
+
+  Future<HousekeepingReport> crateApiDataModelsHousekeepingReportDefault();
+
+  Stream<ChatStreamEvent> crateApiChatApiIllustrateScene({
     required String conversationId,
-    required String content,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(conversationId, serializer);
-          sse_encode_String(content, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 2,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiChatApiAddSystemMessageConstMeta,
-        argValues: [conversationId, content],
-        apiImpl: this,
-      ),
-    );
-  }
+    String? hint,
+    required String model,
+  });
 
-  TaskConstMeta get kCrateApiChatApiAddSystemMessageConstMeta =>
-      const TaskConstMeta(
-        debugName: "add_system_message",
-        argNames: ["conversationId", "content"],
-      );
+  Future<DataExportInfo> crateApiChatApiImportAllData({
+    required String archivePath,
+  });
 
-  @override
-  Future<AppSettings> crateApiDataModelsAppSettingsDefault() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 3,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_app_settings,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiDataModelsAppSettingsDefaultConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
+  Future<IndexScope> crateApiDataModelsIndexScopeDefault();
 
-  TaskConstMeta get kCrateApiDataModelsAppSettingsDefaultConstMeta =>
-      const TaskConstMeta(debugName: "app_settings_default", argNames: []);
+  Future<void> crateApiChatApiInitApp({required String dataPath});
 
-  @override
-  Future<Conversation> crateApiChatApiCreateConversation() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 4,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_conversation,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiChatApiCreateConversationConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
+  Future<bool> crateApiChatApiInvalidateDistilledState({
+    required String conversationId,
+  });
 
-  TaskConstMeta get kCrateApiChatApiCreateConversationConstMeta =>
-      const TaskConstMeta(debugName: "create_conversation", argNames: []);
+  Future<bool> crateApiChatApiIsStreamingDegraded();
 
-  @override
-  Future<bool> crateApiChatApiDeleteConversation({required String id}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(id, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 5,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiChatApiDeleteConversationConstMeta,
-        argValues: [id],
-        apiImpl: this,
-      ),
-    );
-  }
+  Future<JobSchedulerStats> crateApiDataModelsJobSchedulerStatsDefault();
 
-  TaskConstMeta get kCrateApiChatApiDeleteConversationConstMeta =>
-      const TaskConstMeta(debugName: "delete_conversation", argNames: ["id"]);
+  Future<KnowledgeBinding> crateApiDataModelsKnowledgeBindingDefault();
 
-  @override
-  Future<bool> crateApiChatApiDeleteMessage({
+  Future<KnowledgeGraphView> crateApiDataModelsKnowledgeGraphViewDefault();
+
+  Future<List<BackgroundTaskEvent>> crateApiChatApiListBackgroundTasks();
+
+  Future<List<BackupSnapshotInfo>> crateApiChatApiListBackups({
+    String? archivePath,
+  });
+
+  Future<List<BranchInfo>> crateApiChatApiListBranches({
     required String conversationId,
-    required String messageId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(conversationId, serializer);
-          sse_encode_String(messageId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 6,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiChatApiDeleteMessageConstMeta,
-        argValues: [conversationId, messageId],
-        apiImpl: this,
-      ),
-    );
-  }
+  });
 
-  TaskConstMeta get kCrateApiChatApiDeleteMessageConstMeta =>
-      const TaskConstMeta(
-        debugName: "delete_message",
-        argNames: ["conversationId", "messageId"],
-      );
+  Future<List<String>> crateApiChatApiListConversationFolders();
 
-  @override
-  Future<MessageType> crateApiChatApiDetectMessageType({
-    required String content,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(content, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 7,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_message_type,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiChatApiDetectMessageTypeConstMeta,
-        argValues: [content],
-        apiImpl: this,
-      ),
-    );
-  }
+  Future<List<String>> crateApiChatApiListConversationTags();
 
-  TaskConstMeta get kCrateApiChatApiDetectMessageTypeConstMeta =>
-      const TaskConstMeta(
-        debugName: "detect_message_type",
-        argNames: ["content"],
-      );
+  Future<List<Fact>> crateApiChatApiListFacts({String? conversationId});
 
-  @override
-  Future<DialogueStyle> crateApiDataModelsDialogueStyleDefault() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 8,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_dialogue_style,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiDataModelsDialogueStyleDefaultConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
+  Future<List<Fact>> crateApiChatApiListLayerFacts({
+    required String conversationId,
+    required KnowledgeLayer layer,
+  });
 
-  TaskConstMeta get kCrateApiDataModelsDialogueStyleDefaultConstMeta =>
-      const TaskConstMeta(debugName: "dialogue_style_default", argNames: []);
+  Future<List<MemorySummary>> crateApiChatApiListMemories({
+    required String conversationId,
+  });
 
-  @override
-  Future<bool> crateApiChatApiEditMessage({
+  Future<List<UserPersona>> crateApiChatApiListPersonas();
+
+  Future<List<PromptTemplateInfo>> crateApiChatApiListPromptTemplates();
+
+  Future<List<Message>> crateApiChatApiListStarredMessages({
     required String conversationId,
-    required String messageId,
-    required String newContent,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(conversationId, serializer);
-          sse_encode_String(messageId, serializer);
-          sse_encode_String(newContent, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 9,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiChatApiEditMessageConstMeta,
-        argValues: [conversationId, messageId, newContent],
-        apiImpl: this,
-      ),
-    );
-  }
+  });
 
-  TaskConstMeta get kCrateApiChatApiEditMessageConstMeta => const TaskConstMeta(
-    debugName: "edit_message",
-    argNames: ["conversationId", "messageId", "newContent"],
-  );
+  Future<List<Message>> crateApiChatApiLoadColdStorageMessages({
+    required String conversationId,
+  });
 
-  @override
-  Future<List<ModelInfo>> crateApiChatApiGetAvailableModels() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 10,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_model_info,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiChatApiGetAvailableModelsConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
+  Future<MessageType> crateApiDataModelsMessageTypeDefault();
 
-  TaskConstMeta get kCrateApiChatApiGetAvailableModelsConstMeta =>
-      const TaskConstMeta(debugName: "get_available_models", argNames: []);
+  Future<MetricsSnapshot> crateApiDataModelsMetricsSnapshotDefault();
 
-  @override
-  Future<Conversation?> crateApiChatApiGetConversation({required String id}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(id, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 11,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_box_autoadd_conversation,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiChatApiGetConversationConstMeta,
-        argValues: [id],
-        apiImpl: this,
-      ),
-    );
-  }
+  Future<LayoutVerification?> crateApiChatApiMigrateDataLayout({
+    required String targetPath,
+  });
 
-  TaskConstMeta get kCrateApiChatApiGetConversationConstMeta =>
-      const TaskConstMeta(debugName: "get_conversation", argNames: ["id"]);
+  Future<NarrationPerspective> crateApiDataModelsNarrationPerspectiveDefault();
 
-  @override
-  Future<List<ConversationSummary>> crateApiChatApiGetConversationList() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 12,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_conversation_summary,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiChatApiGetConversationListConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
+  Future<OutboxStatus> crateApiDataModelsOutboxStatusDefault();
 
-  TaskConstMeta get kCrateApiChatApiGetConversationListConstMeta =>
-      const TaskConstMeta(debugName: "get_conversation_list", argNames: []);
+  Future<bool> crateApiChatApiPinMemory({
+    required String conversationId,
+    required String summaryId,
+    String? fact,
+    required bool pinned,
+  });
+
+  Future<PreflightAdvisory?> crateApiChatApiPreflightMessage({
+    required String conversationId,
+    required String draft,
+  });
+
+  Future<ReengagementBrief?> crateApiChatApiPrepareReengagement({
+    required String conversationId,
+  });
+
+  Stream<ChatStreamEvent> crateApiChatApiPreviewMemorySummary({
+    required String conversationId,
+  });
+
+  Future<bool> crateApiChatApiPromoteFact({
+    required String conversationId,
+    required String factId,
+    required KnowledgeLayer layer,
+  });
+
+  Future<ProviderKind> crateApiDataModelsProviderKindDefault();
+
+  Future<int> crateApiChatApiPruneBackups({
+    String? archivePath,
+    required BackupRetention retention,
+  });
+
+  Future<List<CharacterRelation>> crateApiChatApiQueryCharacterRelation({
+    required String conversationId,
+    required String a,
+    required String b,
+  });
+
+  Future<KnowledgeGraphView> crateApiChatApiQueryGraphNeighbors({
+    String? conversationId,
+    required String entity,
+    required int depth,
+  });
+
+  Future<DistilledSystemState?> crateApiChatApiRefreshDistilledState({
+    required String conversationId,
+  });
+
+  Stream<ChatStreamEvent> crateApiChatApiRegenerateFrom({
+    required String conversationId,
+    required String messageId,
+    required bool asBranch,
+    required String model,
+    required bool enableThinking,
+  });
+
+  Stream<ChatStreamEvent> crateApiChatApiRegenerateResponse({
+    required String conversationId,
+    required String model,
+    required bool enableThinking,
+  });
+
+  Stream<ReindexProgress> crateApiChatApiReindexAll({
+    required IndexScope scope,
+  });
+
+  Future<int> crateApiChatApiReloadPromptTemplates();
+
+  Future<bool> crateApiChatApiRemoveBlockedTopic({
+    required String conversationId,
+    required String topicId,
+  });
+
+  Future<bool> crateApiChatApiRemoveLoreEntry({
+    String? conversationId,
+    required String entryId,
+  });
+
+  Future<IntegrityReport?> crateApiChatApiRepairIntegrity({
+    required String conversationId,
+  });
+
+  Future<ReplyLength> crateApiDataModelsReplyLengthDefault();
+
+  Future<ReplySignals> crateApiDataModelsReplySignalsDefault();
+
+  Future<void> crateApiChatApiResetPromptTemplate({required String name});
+
+  Future<ResponseStyle> crateApiDataModelsResponseStyleDefault();
+
+  Future<bool> crateApiChatApiRestartStory({required String conversationId});
+
+  Future<int> crateApiChatApiRestoreBackup({
+    String? archivePath,
+    String? snapshotId,
+    required BackupScope scope,
+  });
+
+  Future<RetentionPolicy> crateApiDataModelsRetentionPolicyDefault();
+
+  Future<RetryPolicy> crateApiDataModelsRetryPolicyDefault();
+
+  Future<bool> crateApiChatApiRewriteReply({
+    required String conversationId,
+    required String messageId,
+    required String newContent,
+  });
+
+  Future<List<String>> crateApiChatApiRollbackToMessage({
+    required String conversationId,
+    required String messageId,
+  });
+
+  Future<int> crateApiChatApiRunDeferredJobs();
+
+  Future<HousekeepingReport> crateApiChatApiRunHousekeeping();
+
+  Future<int> crateApiChatApiRunThinkingRetention();
+
+  Future<SafetyPolicy> crateApiDataModelsSafetyPolicyDefault();
+
+  Future<void> crateApiChatApiSaveEmotionLexicon({
+    required List<LexiconEntry> entries,
+  });
+
+  Future<LoreEntry?> crateApiChatApiSaveLoreEntry({
+    String? conversationId,
+    required LoreEntry entry,
+  });
+
+  Future<UserPersona> crateApiChatApiSavePersona({
+    required UserPersona persona,
+  });
+
+  Future<void> crateApiChatApiSavePromptTemplate({
+    required String name,
+    required String content,
+  });
+
+  Future<bool> crateApiChatApiSaveSettings({required AppSettings settings});
+
+  Future<SayDoRuleSet> crateApiDataModelsSayDoRuleSetDefault();
+
+  Future<List<SearchHit>> crateApiChatApiSearchConversations({
+    required String query,
+  });
+
+  Future<List<MemorySearchResult>> crateApiChatApiSearchMemories({
+    required String conversationId,
+    required String query,
+    required BigInt topK,
+  });
+
+  Future<Message?> crateApiChatApiSelectAlternative({
+    required String conversationId,
+    required String messageId,
+    required int index,
+  });
+
+  Stream<ChatStreamEvent> crateApiChatApiSendMessage({
+    required String conversationId,
+    required String content,
+    required String model,
+    required bool enableThinking,
+    AmbientContext? ambient,
+  });
+
+  Stream<ChatStreamEvent> crateApiChatApiSendMessageWithImages({
+    required String conversationId,
+    required String content,
+    required List<MessageAttachment> images,
+    required String model,
+    required bool enableThinking,
+    AmbientContext? ambient,
+  });
+
+  Future<void> crateApiChatApiSetApiKey({required String apiKey});
+
+  Future<bool> crateApiChatApiSetCharacterVoice({
+    required String characterKey,
+    String? voice,
+  });
+
+  Future<void> crateApiChatApiSetChatProvider({
+    required ProviderKind provider,
+    String? baseUrl,
+    String? model,
+    String? apiKey,
+  });
+
+  Future<bool> crateApiChatApiSetCheckInSchedule({
+    required CheckInSchedule schedule,
+  });
+
+  Future<bool> crateApiChatApiSetContextLayers({
+    required String conversationId,
+    required ContextLayers layers,
+  });
+
+  Future<bool> crateApiChatApiSetConversationArchived({
+    required String conversationId,
+    required bool archived,
+  });
+
+  Future<bool> crateApiChatApiSetConversationMode({
+    required String conversationId,
+    required ConversationMode mode,
+  });
+
+  Future<ConversationOrganization?> crateApiChatApiSetConversationOrganization({
+    required String conversationId,
+    required ConversationOrganization organization,
+  });
+
+  Future<bool> crateApiChatApiSetConversationPersona({
+    required String conversationId,
+    String? personaId,
+  });
+
+  Future<bool> crateApiChatApiSetConversationPinned({
+    required String conversationId,
+    required bool pinned,
+  });
+
+  Future<void> crateApiChatApiSetDeviceConditions({
+    required DeviceConditions conditions,
+  });
+
+  Future<bool> crateApiChatApiSetDialogueStyle({
+    required String conversationId,
+    required DialogueStyle style,
+  });
+
+  Future<void> crateApiChatApiSetFastDraft({required bool enabled});
+
+  Future<GroupChat?> crateApiChatApiSetGroupChat({
+    required String conversationId,
+    required List<GroupCharacter> characters,
+    required TurnPolicy turnPolicy,
+  });
+
+  Future<void> crateApiChatApiSetKnowledgeBinding({
+    required String conversationId,
+    required KnowledgeBinding binding,
+  });
+
+  Future<bool> crateApiChatApiSetMemoryFactTier({
+    required String conversationId,
+    required String summaryId,
+    required String fact,
+    required MemoryTier tier,
+  });
+
+  Future<Message> crateApiChatApiSetMessageReactions({
+    required String conversationId,
+    required String messageId,
+    required List<String> reactions,
+  });
+
+  Future<Message?> crateApiChatApiSetMessageStarred({
+    required String conversationId,
+    required String messageId,
+    required bool starred,
+  });
+
+  Future<bool> crateApiChatApiSetNarrationPerspective({
+    required String conversationId,
+    required NarrationPerspective perspective,
+  });
+
+  Future<void> crateApiChatApiSetNetworkAvailable({required bool available});
+
+  Future<void> crateApiChatApiSetProxy({ProxySettings? proxy});
+
+  Future<bool> crateApiChatApiSetResponseStyle({
+    required String conversationId,
+    required ResponseStyle style,
+  });
+
+  Future<void> crateApiChatApiSetRetentionPolicy({
+    required RetentionPolicy policy,
+  });
+
+  Future<void> crateApiChatApiSetRetryPolicy({required RetryPolicy policy});
+
+  Future<void> crateApiChatApiSetSafetyPolicy({required SafetyPolicy policy});
+
+  Future<void> crateApiChatApiSetSaydoRules({
+    required String conversationId,
+    required SayDoRuleSet rules,
+  });
+
+  Future<void> crateApiChatApiSetSummarizationConfig({
+    required String conversationId,
+    SummarizationConfig? config,
+  });
+
+  Future<void> crateApiChatApiSetSyncSettings({required SyncSettings settings});
+
+  Future<bool> crateApiChatApiSetThinkingRetention({
+    required String conversationId,
+    required ThinkingRetention policy,
+  });
+
+  Future<void> crateApiChatApiSetTimeAwareness({required bool enabled});
+
+  Future<ShadowEvalSummary> crateApiDataModelsShadowEvalSummaryDefault();
+
+  Future<bool> crateApiChatApiShouldSummarizeMemory({
+    required String conversationId,
+  });
+
+  Future<SummarizationConfig> crateApiDataModelsSummarizationConfigDefault();
+
+  Future<bool> crateApiChatApiSwitchBranch({
+    required String conversationId,
+    required String branchId,
+  });
+
+  Future<SyncBackendKind> crateApiDataModelsSyncBackendKindDefault();
+
+  Future<SyncReport> crateApiChatApiSyncNow();
+
+  Future<SyncReport> crateApiDataModelsSyncReportDefault();
+
+  Future<SyncSettings> crateApiDataModelsSyncSettingsDefault();
+
+  Future<ThinkingRetention> crateApiDataModelsThinkingRetentionDefault();
+
+  Stream<ChatStreamEvent> crateApiChatApiTriggerMemorySummarize({
+    required String conversationId,
+  });
+
+  Future<TurnPolicy> crateApiDataModelsTurnPolicyDefault();
+
+  Future<Message?> crateApiChatApiUndoMessageEdit({
+    required String conversationId,
+    required String messageId,
+  });
+
+  Future<void> crateApiChatApiUnlockEncryption({
+    required EncryptionSecret secret,
+  });
+
+  Future<bool> crateApiChatApiUpdateFact({
+    String? conversationId,
+    required String factId,
+    required String content,
+    required FactCategory category,
+    required double confidence,
+  });
+
+  Future<LayoutVerification?> crateApiChatApiUpgradeDataLayout();
+
+  Future<bool> crateApiChatApiValidateApiKey({required String apiKey});
+
+  Future<LayoutVerification?> crateApiChatApiVerifyDataLayout();
+
+  Future<IntegrityReport?> crateApiChatApiVerifyIntegrity({
+    required String conversationId,
+  });
+
+  Stream<BackgroundTaskEvent> crateApiChatApiWatchBackgroundTasks();
+
+  Stream<ChatStreamEvent> crateApiChatApiWatchCheckIns();
+
+  Stream<ChatStreamEvent> crateApiChatApiWatchOutbox();
+}
+
+class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+  RustLibApiImpl({
+    required super.handler,
+    required super.wire,
+    required super.generalizedFrbRustBinding,
+    required super.portManager,
+  });
 
   @override
-  Future<AppSettings> crateApiChatApiGetSettings() {
+  Future<ActionFrequency> crateApiDataModelsActionFrequencyDefault() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -551,154 +837,199 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 13,
+            funcId: 1,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_app_settings,
+          decodeSuccessData: sse_decode_action_frequency,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiChatApiGetSettingsConstMeta,
+        constMeta: kCrateApiDataModelsActionFrequencyDefaultConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiChatApiGetSettingsConstMeta =>
-      const TaskConstMeta(debugName: "get_settings", argNames: []);
+  TaskConstMeta get kCrateApiDataModelsActionFrequencyDefaultConstMeta =>
+      const TaskConstMeta(debugName: "action_frequency_default", argNames: []);
 
   @override
-  Future<int> crateApiChatApiGetTurnCount({required String conversationId}) {
+  Future<bool> crateApiChatApiAddAssistantMessage({
+    required String conversationId,
+    required String content,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(conversationId, serializer);
+          sse_encode_String(content, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 14,
+            funcId: 2,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_u_32,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiChatApiGetTurnCountConstMeta,
-        argValues: [conversationId],
+        constMeta: kCrateApiChatApiAddAssistantMessageConstMeta,
+        argValues: [conversationId, content],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiChatApiGetTurnCountConstMeta =>
+  TaskConstMeta get kCrateApiChatApiAddAssistantMessageConstMeta =>
       const TaskConstMeta(
-        debugName: "get_turn_count",
-        argNames: ["conversationId"],
+        debugName: "add_assistant_message",
+        argNames: ["conversationId", "content"],
       );
 
   @override
-  Future<void> crateApiChatApiInitApp({required String dataPath}) {
+  Future<Fact?> crateApiChatApiAddManualFact({
+    String? conversationId,
+    required String content,
+    required FactCategory category,
+    double? confidence,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(dataPath, serializer);
+          sse_encode_opt_String(conversationId, serializer);
+          sse_encode_String(content, serializer);
+          sse_encode_fact_category(category, serializer);
+          sse_encode_opt_box_autoadd_f_64(confidence, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 15,
+            funcId: 3,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_opt_box_autoadd_fact,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiChatApiInitAppConstMeta,
-        argValues: [dataPath],
+        constMeta: kCrateApiChatApiAddManualFactConstMeta,
+        argValues: [conversationId, content, category, confidence],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiChatApiInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: ["dataPath"]);
+  TaskConstMeta get kCrateApiChatApiAddManualFactConstMeta =>
+      const TaskConstMeta(
+        debugName: "add_manual_fact",
+        argNames: ["conversationId", "content", "category", "confidence"],
+      );
 
   @override
-  Future<MessageType> crateApiDataModelsMessageTypeDefault() {
+  Future<bool> crateApiChatApiAddSystemMessage({
+    required String conversationId,
+    required String content,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          sse_encode_String(content, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 16,
+            funcId: 4,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_message_type,
+          decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiDataModelsMessageTypeDefaultConstMeta,
-        argValues: [],
+        constMeta: kCrateApiChatApiAddSystemMessageConstMeta,
+        argValues: [conversationId, content],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiDataModelsMessageTypeDefaultConstMeta =>
-      const TaskConstMeta(debugName: "message_type_default", argNames: []);
+  TaskConstMeta get kCrateApiChatApiAddSystemMessageConstMeta =>
+      const TaskConstMeta(
+        debugName: "add_system_message",
+        argNames: ["conversationId", "content"],
+      );
 
   @override
-  Stream<ChatStreamEvent> crateApiChatApiRegenerateResponse({
+  Future<String?> crateApiChatApiAddUserAuthoredReply({
     required String conversationId,
-    required String model,
-    required bool enableThinking,
+    required String content,
   }) {
-    final sink = RustStreamSink<ChatStreamEvent>();
-    unawaited(
-      handler.executeNormal(
-        NormalTask(
-          callFfi: (port_) {
-            final serializer = SseSerializer(generalizedFrbRustBinding);
-            sse_encode_String(conversationId, serializer);
-            sse_encode_String(model, serializer);
-            sse_encode_bool(enableThinking, serializer);
-            sse_encode_StreamSink_chat_stream_event_Sse(sink, serializer);
-            pdeCallFfi(
-              generalizedFrbRustBinding,
-              serializer,
-              funcId: 17,
-              port: port_,
-            );
-          },
-          codec: SseCodec(
-            decodeSuccessData: sse_decode_unit,
-            decodeErrorData: null,
-          ),
-          constMeta: kCrateApiChatApiRegenerateResponseConstMeta,
-          argValues: [conversationId, model, enableThinking, sink],
-          apiImpl: this,
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          sse_encode_String(content, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 5,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: null,
         ),
+        constMeta: kCrateApiChatApiAddUserAuthoredReplyConstMeta,
+        argValues: [conversationId, content],
+        apiImpl: this,
       ),
     );
-    return sink.stream;
   }
 
-  TaskConstMeta get kCrateApiChatApiRegenerateResponseConstMeta =>
+  TaskConstMeta get kCrateApiChatApiAddUserAuthoredReplyConstMeta =>
       const TaskConstMeta(
-        debugName: "regenerate_response",
-        argNames: ["conversationId", "model", "enableThinking", "sink"],
+        debugName: "add_user_authored_reply",
+        argNames: ["conversationId", "content"],
       );
 
   @override
-  Future<bool> crateApiChatApiRestartStory({required String conversationId}) {
+  Future<AmbientContext> crateApiDataModelsAmbientContextDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 6,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_ambient_context,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDataModelsAmbientContextDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDataModelsAmbientContextDefaultConstMeta =>
+      const TaskConstMeta(debugName: "ambient_context_default", argNames: []);
+
+  @override
+  Future<ConversationInsights?> crateApiChatApiAnalyzeConversation({
+    required String conversationId,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -707,1446 +1038,15618 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 18,
+            funcId: 7,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_opt_box_autoadd_conversation_insights,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiChatApiRestartStoryConstMeta,
+        constMeta: kCrateApiChatApiAnalyzeConversationConstMeta,
         argValues: [conversationId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiChatApiRestartStoryConstMeta =>
+  TaskConstMeta get kCrateApiChatApiAnalyzeConversationConstMeta =>
       const TaskConstMeta(
-        debugName: "restart_story",
+        debugName: "analyze_conversation",
         argNames: ["conversationId"],
       );
 
   @override
-  Future<List<String>> crateApiChatApiRollbackToMessage({
+  Future<AppSettings> crateApiDataModelsAppSettingsDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 8,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_app_settings,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDataModelsAppSettingsDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDataModelsAppSettingsDefaultConstMeta =>
+      const TaskConstMeta(debugName: "app_settings_default", argNames: []);
+
+  @override
+  Future<MemorySummary> crateApiChatApiApproveMemorySummary({
     required String conversationId,
-    required String messageId,
+    required String summaryId,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(conversationId, serializer);
-          sse_encode_String(messageId, serializer);
+          sse_encode_String(summaryId, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 19,
+            funcId: 9,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_String,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_memory_summary,
+          decodeErrorData: sse_decode_String,
         ),
-        constMeta: kCrateApiChatApiRollbackToMessageConstMeta,
-        argValues: [conversationId, messageId],
+        constMeta: kCrateApiChatApiApproveMemorySummaryConstMeta,
+        argValues: [conversationId, summaryId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiChatApiRollbackToMessageConstMeta =>
+  TaskConstMeta get kCrateApiChatApiApproveMemorySummaryConstMeta =>
       const TaskConstMeta(
-        debugName: "rollback_to_message",
-        argNames: ["conversationId", "messageId"],
+        debugName: "approve_memory_summary",
+        argNames: ["conversationId", "summaryId"],
       );
 
   @override
-  Future<bool> crateApiChatApiSaveSettings({required AppSettings settings}) {
+  Future<List<MemoryFidelityReport>> crateApiChatApiAuditAllMemoryFidelity() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_app_settings(settings, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 20,
+            funcId: 10,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_list_memory_fidelity_report,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiChatApiSaveSettingsConstMeta,
-        argValues: [settings],
+        constMeta: kCrateApiChatApiAuditAllMemoryFidelityConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiChatApiSaveSettingsConstMeta =>
-      const TaskConstMeta(debugName: "save_settings", argNames: ["settings"]);
+  TaskConstMeta get kCrateApiChatApiAuditAllMemoryFidelityConstMeta =>
+      const TaskConstMeta(debugName: "audit_all_memory_fidelity", argNames: []);
 
   @override
-  Future<List<MemorySearchResult>> crateApiChatApiSearchMemories({
+  Future<MemoryFidelityReport?> crateApiChatApiAuditMemoryFidelity({
     required String conversationId,
-    required String query,
-    required BigInt topK,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_String(conversationId, serializer);
-          sse_encode_String(query, serializer);
-          sse_encode_usize(topK, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 21,
+            funcId: 11,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_list_memory_search_result,
+          decodeSuccessData: sse_decode_opt_box_autoadd_memory_fidelity_report,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiChatApiSearchMemoriesConstMeta,
-        argValues: [conversationId, query, topK],
+        constMeta: kCrateApiChatApiAuditMemoryFidelityConstMeta,
+        argValues: [conversationId],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiChatApiSearchMemoriesConstMeta =>
+  TaskConstMeta get kCrateApiChatApiAuditMemoryFidelityConstMeta =>
       const TaskConstMeta(
-        debugName: "search_memories",
-        argNames: ["conversationId", "query", "topK"],
+        debugName: "audit_memory_fidelity",
+        argNames: ["conversationId"],
       );
 
   @override
-  Stream<ChatStreamEvent> crateApiChatApiSendMessage({
-    required String conversationId,
-    required String content,
-    required String model,
-    required bool enableThinking,
-  }) {
-    final sink = RustStreamSink<ChatStreamEvent>();
-    unawaited(
-      handler.executeNormal(
-        NormalTask(
-          callFfi: (port_) {
-            final serializer = SseSerializer(generalizedFrbRustBinding);
-            sse_encode_String(conversationId, serializer);
-            sse_encode_String(content, serializer);
-            sse_encode_String(model, serializer);
-            sse_encode_bool(enableThinking, serializer);
-            sse_encode_StreamSink_chat_stream_event_Sse(sink, serializer);
-            pdeCallFfi(
-              generalizedFrbRustBinding,
-              serializer,
-              funcId: 22,
-              port: port_,
-            );
-          },
-          codec: SseCodec(
-            decodeSuccessData: sse_decode_unit,
-            decodeErrorData: null,
-          ),
-          constMeta: kCrateApiChatApiSendMessageConstMeta,
-          argValues: [conversationId, content, model, enableThinking, sink],
-          apiImpl: this,
+  Future<BackoffStrategy> crateApiDataModelsBackoffStrategyDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 12,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_backoff_strategy,
+          decodeErrorData: null,
         ),
+        constMeta: kCrateApiDataModelsBackoffStrategyDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
       ),
     );
-    return sink.stream;
   }
 
-  TaskConstMeta get kCrateApiChatApiSendMessageConstMeta => const TaskConstMeta(
-    debugName: "send_message",
-    argNames: ["conversationId", "content", "model", "enableThinking", "sink"],
-  );
+  TaskConstMeta get kCrateApiDataModelsBackoffStrategyDefaultConstMeta =>
+      const TaskConstMeta(debugName: "backoff_strategy_default", argNames: []);
 
   @override
-  Future<void> crateApiChatApiSetApiKey({required String apiKey}) {
+  Future<BackupRetention> crateApiDataModelsBackupRetentionDefault() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(apiKey, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 23,
+            funcId: 13,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_backup_retention,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiChatApiSetApiKeyConstMeta,
-        argValues: [apiKey],
+        constMeta: kCrateApiDataModelsBackupRetentionDefaultConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiChatApiSetApiKeyConstMeta =>
-      const TaskConstMeta(debugName: "set_api_key", argNames: ["apiKey"]);
+  TaskConstMeta get kCrateApiDataModelsBackupRetentionDefaultConstMeta =>
+      const TaskConstMeta(debugName: "backup_retention_default", argNames: []);
 
   @override
-  Future<bool> crateApiChatApiSetDialogueStyle({
-    required String conversationId,
-    required DialogueStyle style,
-  }) {
+  Future<BackupScope> crateApiDataModelsBackupScopeDefault() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(conversationId, serializer);
-          sse_encode_dialogue_style(style, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
-            funcId: 24,
+            funcId: 14,
             port: port_,
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+          decodeSuccessData: sse_decode_backup_scope,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiChatApiSetDialogueStyleConstMeta,
-        argValues: [conversationId, style],
+        constMeta: kCrateApiDataModelsBackupScopeDefaultConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiChatApiSetDialogueStyleConstMeta =>
-      const TaskConstMeta(
-        debugName: "set_dialogue_style",
-        argNames: ["conversationId", "style"],
-      );
+  TaskConstMeta get kCrateApiDataModelsBackupScopeDefaultConstMeta =>
+      const TaskConstMeta(debugName: "backup_scope_default", argNames: []);
+
+  @override
+  Future<BlockingStats> crateApiDataModelsBlockingStatsDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 15,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_blocking_stats,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDataModelsBlockingStatsDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDataModelsBlockingStatsDefaultConstMeta =>
+      const TaskConstMeta(debugName: "blocking_stats_default", argNames: []);
+
+  @override
+  Future<void> crateApiChatApiClearLogs() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 16,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiClearLogsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiClearLogsConstMeta =>
+      const TaskConstMeta(debugName: "clear_logs", argNames: []);
+
+  @override
+  Future<void> crateApiChatApiClearMetrics() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 17,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiClearMetricsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiClearMetricsConstMeta =>
+      const TaskConstMeta(debugName: "clear_metrics", argNames: []);
+
+  @override
+  Future<ConversationClosure> crateApiChatApiCloseConversation({
+    required String conversationId,
+    required String model,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          sse_encode_String(model, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 18,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_conversation_closure,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiChatApiCloseConversationConstMeta,
+        argValues: [conversationId, model],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiCloseConversationConstMeta =>
+      const TaskConstMeta(
+        debugName: "close_conversation",
+        argNames: ["conversationId", "model"],
+      );
+
+  @override
+  Future<bool> crateApiChatApiCloseSandboxConversation({required String id}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(id, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 19,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiCloseSandboxConversationConstMeta,
+        argValues: [id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiCloseSandboxConversationConstMeta =>
+      const TaskConstMeta(
+        debugName: "close_sandbox_conversation",
+        argNames: ["id"],
+      );
+
+  @override
+  Future<ContentIntensity> crateApiDataModelsContentIntensityDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 20,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_content_intensity,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDataModelsContentIntensityDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDataModelsContentIntensityDefaultConstMeta =>
+      const TaskConstMeta(debugName: "content_intensity_default", argNames: []);
+
+  @override
+  Future<ContextLayers> crateApiDataModelsContextLayersDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 21,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_context_layers,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDataModelsContextLayersDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDataModelsContextLayersDefaultConstMeta =>
+      const TaskConstMeta(debugName: "context_layers_default", argNames: []);
+
+  @override
+  Future<ConversationFilter> crateApiDataModelsConversationFilterDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 22,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_conversation_filter,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDataModelsConversationFilterDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDataModelsConversationFilterDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "conversation_filter_default",
+        argNames: [],
+      );
+
+  @override
+  Future<ConversationMode> crateApiDataModelsConversationModeDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 23,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_conversation_mode,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDataModelsConversationModeDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDataModelsConversationModeDefaultConstMeta =>
+      const TaskConstMeta(debugName: "conversation_mode_default", argNames: []);
+
+  @override
+  Future<ConversationOrganization> crateApiDataModelsConversationOrganizationDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 24,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_conversation_organization,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDataModelsConversationOrganizationDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDataModelsConversationOrganizationDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "conversation_organization_default",
+        argNames: [],
+      );
+
+  @override
+  Future<BackupSnapshotInfo?> crateApiChatApiCreateBackup({
+    String? archivePath,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(archivePath, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 25,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_backup_snapshot_info,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiCreateBackupConstMeta,
+        argValues: [archivePath],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiCreateBackupConstMeta =>
+      const TaskConstMeta(
+        debugName: "create_backup",
+        argNames: ["archivePath"],
+      );
+
+  @override
+  Future<String?> crateApiChatApiCreateBranch({
+    required String conversationId,
+    required String fromMessageId,
+    String? name,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          sse_encode_String(fromMessageId, serializer);
+          sse_encode_opt_String(name, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 26,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiCreateBranchConstMeta,
+        argValues: [conversationId, fromMessageId, name],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiCreateBranchConstMeta =>
+      const TaskConstMeta(
+        debugName: "create_branch",
+        argNames: ["conversationId", "fromMessageId", "name"],
+      );
+
+  @override
+  Future<Conversation> crateApiChatApiCreateConversation() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 27,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_conversation,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiCreateConversationConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiCreateConversationConstMeta =>
+      const TaskConstMeta(debugName: "create_conversation", argNames: []);
+
+  @override
+  Future<Conversation?> crateApiChatApiCreateSandboxConversation({
+    required CharacterCard card,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_character_card(card, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 28,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_conversation,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiCreateSandboxConversationConstMeta,
+        argValues: [card],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiCreateSandboxConversationConstMeta =>
+      const TaskConstMeta(
+        debugName: "create_sandbox_conversation",
+        argNames: ["card"],
+      );
+
+  @override
+  Future<bool> crateApiChatApiDeleteConversation({required String id}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(id, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 29,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiDeleteConversationConstMeta,
+        argValues: [id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiDeleteConversationConstMeta =>
+      const TaskConstMeta(debugName: "delete_conversation", argNames: ["id"]);
+
+  @override
+  Future<bool> crateApiChatApiDeleteFact({
+    String? conversationId,
+    required String factId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(conversationId, serializer);
+          sse_encode_String(factId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 30,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiDeleteFactConstMeta,
+        argValues: [conversationId, factId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiDeleteFactConstMeta => const TaskConstMeta(
+    debugName: "delete_fact",
+    argNames: ["conversationId", "factId"],
+  );
+
+  @override
+  Future<bool> crateApiChatApiDeleteMemory({
+    required String conversationId,
+    required String summaryId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          sse_encode_String(summaryId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 31,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiDeleteMemoryConstMeta,
+        argValues: [conversationId, summaryId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiDeleteMemoryConstMeta =>
+      const TaskConstMeta(
+        debugName: "delete_memory",
+        argNames: ["conversationId", "summaryId"],
+      );
+
+  @override
+  Future<bool> crateApiChatApiDeleteMessage({
+    required String conversationId,
+    required String messageId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          sse_encode_String(messageId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 32,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiDeleteMessageConstMeta,
+        argValues: [conversationId, messageId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiDeleteMessageConstMeta =>
+      const TaskConstMeta(
+        debugName: "delete_message",
+        argNames: ["conversationId", "messageId"],
+      );
+
+  @override
+  Future<bool> crateApiChatApiDeletePersona({required String personaId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(personaId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 33,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiChatApiDeletePersonaConstMeta,
+        argValues: [personaId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiDeletePersonaConstMeta =>
+      const TaskConstMeta(debugName: "delete_persona", argNames: ["personaId"]);
+
+  @override
+  Future<MessageType> crateApiChatApiDetectMessageType({
+    required String content,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(content, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 34,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_message_type,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiDetectMessageTypeConstMeta,
+        argValues: [content],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiDetectMessageTypeConstMeta =>
+      const TaskConstMeta(
+        debugName: "detect_message_type",
+        argNames: ["content"],
+      );
+
+  @override
+  Future<MessageType> crateApiChatApiDetectMessageTypeIn({
+    required String conversationId,
+    required String content,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          sse_encode_String(content, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 35,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_message_type,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiDetectMessageTypeInConstMeta,
+        argValues: [conversationId, content],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiDetectMessageTypeInConstMeta =>
+      const TaskConstMeta(
+        debugName: "detect_message_type_in",
+        argNames: ["conversationId", "content"],
+      );
+
+  @override
+  Future<DeviceConditions> crateApiDataModelsDeviceConditionsDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 36,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_device_conditions,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDataModelsDeviceConditionsDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDataModelsDeviceConditionsDefaultConstMeta =>
+      const TaskConstMeta(debugName: "device_conditions_default", argNames: []);
+
+  @override
+  Future<DialogueStyle> crateApiDataModelsDialogueStyleDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 37,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_dialogue_style,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDataModelsDialogueStyleDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDataModelsDialogueStyleDefaultConstMeta =>
+      const TaskConstMeta(debugName: "dialogue_style_default", argNames: []);
+
+  @override
+  Future<int> crateApiChatApiDisableEncryption({
+    required EncryptionSecret secret,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_encryption_secret(secret, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 38,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiChatApiDisableEncryptionConstMeta,
+        argValues: [secret],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiDisableEncryptionConstMeta =>
+      const TaskConstMeta(
+        debugName: "disable_encryption",
+        argNames: ["secret"],
+      );
+
+  @override
+  Future<bool> crateApiChatApiDiscardMemorySummary({
+    required String conversationId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 39,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiDiscardMemorySummaryConstMeta,
+        argValues: [conversationId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiDiscardMemorySummaryConstMeta =>
+      const TaskConstMeta(
+        debugName: "discard_memory_summary",
+        argNames: ["conversationId"],
+      );
+
+  @override
+  Future<bool> crateApiChatApiDiscardOutboxEntry({required String entryId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(entryId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 40,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiDiscardOutboxEntryConstMeta,
+        argValues: [entryId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiDiscardOutboxEntryConstMeta =>
+      const TaskConstMeta(
+        debugName: "discard_outbox_entry",
+        argNames: ["entryId"],
+      );
+
+  @override
+  Future<bool> crateApiChatApiDissolveGroupChat({
+    required String conversationId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 41,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiDissolveGroupChatConstMeta,
+        argValues: [conversationId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiDissolveGroupChatConstMeta =>
+      const TaskConstMeta(
+        debugName: "dissolve_group_chat",
+        argNames: ["conversationId"],
+      );
+
+  @override
+  Future<bool> crateApiChatApiEditMemory({
+    required String conversationId,
+    required String summaryId,
+    required String summary,
+    required List<String> coreFacts,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          sse_encode_String(summaryId, serializer);
+          sse_encode_String(summary, serializer);
+          sse_encode_list_String(coreFacts, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 42,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiEditMemoryConstMeta,
+        argValues: [conversationId, summaryId, summary, coreFacts],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiEditMemoryConstMeta => const TaskConstMeta(
+    debugName: "edit_memory",
+    argNames: ["conversationId", "summaryId", "summary", "coreFacts"],
+  );
+
+  @override
+  Future<bool> crateApiChatApiEditMessage({
+    required String conversationId,
+    required String messageId,
+    required String newContent,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          sse_encode_String(messageId, serializer);
+          sse_encode_String(newContent, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 43,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiEditMessageConstMeta,
+        argValues: [conversationId, messageId, newContent],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiEditMessageConstMeta => const TaskConstMeta(
+    debugName: "edit_message",
+    argNames: ["conversationId", "messageId", "newContent"],
+  );
+
+  @override
+  Future<EmojiUsage> crateApiDataModelsEmojiUsageDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 44,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_emoji_usage,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDataModelsEmojiUsageDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDataModelsEmojiUsageDefaultConstMeta =>
+      const TaskConstMeta(debugName: "emoji_usage_default", argNames: []);
+
+  @override
+  Future<int> crateApiChatApiEnableEncryption({
+    required EncryptionSecret secret,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_encryption_secret(secret, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 45,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiChatApiEnableEncryptionConstMeta,
+        argValues: [secret],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiEnableEncryptionConstMeta =>
+      const TaskConstMeta(debugName: "enable_encryption", argNames: ["secret"]);
+
+  @override
+  Future<MemoryExplanation?> crateApiChatApiExplainMemory({
+    required String conversationId,
+    required String summaryId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          sse_encode_String(summaryId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 46,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_memory_explanation,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiExplainMemoryConstMeta,
+        argValues: [conversationId, summaryId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiExplainMemoryConstMeta =>
+      const TaskConstMeta(
+        debugName: "explain_memory",
+        argNames: ["conversationId", "summaryId"],
+      );
+
+  @override
+  Future<RelevanceInsight?> crateApiChatApiExplainRelevance({
+    required String conversationId,
+    required String fact,
+    required String userContent,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          sse_encode_String(fact, serializer);
+          sse_encode_String(userContent, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 47,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_relevance_insight,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiExplainRelevanceConstMeta,
+        argValues: [conversationId, fact, userContent],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiExplainRelevanceConstMeta =>
+      const TaskConstMeta(
+        debugName: "explain_relevance",
+        argNames: ["conversationId", "fact", "userContent"],
+      );
+
+  @override
+  Future<DataExportInfo> crateApiChatApiExportAllData({
+    required String targetPath,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(targetPath, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 48,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_data_export_info,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiChatApiExportAllDataConstMeta,
+        argValues: [targetPath],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiExportAllDataConstMeta =>
+      const TaskConstMeta(
+        debugName: "export_all_data",
+        argNames: ["targetPath"],
+      );
+
+  @override
+  Future<String> crateApiChatApiExportKnowledgeGraph({
+    String? conversationId,
+    required GraphExportFormat format,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(conversationId, serializer);
+          sse_encode_graph_export_format(format, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 49,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiExportKnowledgeGraphConstMeta,
+        argValues: [conversationId, format],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiExportKnowledgeGraphConstMeta =>
+      const TaskConstMeta(
+        debugName: "export_knowledge_graph",
+        argNames: ["conversationId", "format"],
+      );
+
+  @override
+  Future<String> crateApiChatApiExportLogs({required int limit}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_32(limit, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 50,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiExportLogsConstMeta,
+        argValues: [limit],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiExportLogsConstMeta =>
+      const TaskConstMeta(debugName: "export_logs", argNames: ["limit"]);
+
+  @override
+  Future<List<GraphEdge>> crateApiChatApiFindGraphPath({
+    String? conversationId,
+    required String from,
+    required String to,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(conversationId, serializer);
+          sse_encode_String(from, serializer);
+          sse_encode_String(to, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 51,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_graph_edge,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiFindGraphPathConstMeta,
+        argValues: [conversationId, from, to],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiFindGraphPathConstMeta =>
+      const TaskConstMeta(
+        debugName: "find_graph_path",
+        argNames: ["conversationId", "from", "to"],
+      );
+
+  @override
+  Future<Formality> crateApiDataModelsFormalityDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 52,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_formality,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDataModelsFormalityDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDataModelsFormalityDefaultConstMeta =>
+      const TaskConstMeta(debugName: "formality_default", argNames: []);
+
+  @override
+  Future<DailyDigest?> crateApiChatApiGenerateDailyDigest() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 53,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_daily_digest,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiGenerateDailyDigestConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiGenerateDailyDigestConstMeta =>
+      const TaskConstMeta(debugName: "generate_daily_digest", argNames: []);
+
+  @override
+  Future<GenerationMetadata> crateApiDataModelsGenerationMetadataDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 54,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_generation_metadata,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDataModelsGenerationMetadataDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDataModelsGenerationMetadataDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "generation_metadata_default",
+        argNames: [],
+      );
+
+  @override
+  Future<List<ModelInfo>> crateApiChatApiGetAvailableModels() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 55,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_model_info,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiGetAvailableModelsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiGetAvailableModelsConstMeta =>
+      const TaskConstMeta(debugName: "get_available_models", argNames: []);
+
+  @override
+  Future<List<BlockedTopic>> crateApiChatApiGetBlockedTopics({
+    required String conversationId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 56,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_blocked_topic,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiGetBlockedTopicsConstMeta,
+        argValues: [conversationId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiGetBlockedTopicsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_blocked_topics",
+        argNames: ["conversationId"],
+      );
+
+  @override
+  Future<BlockingStats> crateApiChatApiGetBlockingStats() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 57,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_blocking_stats,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiGetBlockingStatsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiGetBlockingStatsConstMeta =>
+      const TaskConstMeta(debugName: "get_blocking_stats", argNames: []);
+
+  @override
+  Future<MoodState?> crateApiChatApiGetCharacterMood({
+    required String conversationId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 58,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_mood_state,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiGetCharacterMoodConstMeta,
+        argValues: [conversationId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiGetCharacterMoodConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_character_mood",
+        argNames: ["conversationId"],
+      );
+
+  @override
+  Future<List<CharacterRelation>> crateApiChatApiGetCharacterRelations({
+    required String conversationId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 59,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_character_relation,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiGetCharacterRelationsConstMeta,
+        argValues: [conversationId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiGetCharacterRelationsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_character_relations",
+        argNames: ["conversationId"],
+      );
+
+  @override
+  Future<Map<String, String>> crateApiChatApiGetCharacterVoices() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 60,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_Map_String_String_None,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiGetCharacterVoicesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiGetCharacterVoicesConstMeta =>
+      const TaskConstMeta(debugName: "get_character_voices", argNames: []);
+
+  @override
+  Future<CheckInSchedule?> crateApiChatApiGetCheckInSchedule({
+    required String conversationId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 61,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_check_in_schedule,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiGetCheckInScheduleConstMeta,
+        argValues: [conversationId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiGetCheckInScheduleConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_check_in_schedule",
+        argNames: ["conversationId"],
+      );
+
+  @override
+  Future<Conversation?> crateApiChatApiGetConversation({required String id}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(id, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 62,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_conversation,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiGetConversationConstMeta,
+        argValues: [id],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiGetConversationConstMeta =>
+      const TaskConstMeta(debugName: "get_conversation", argNames: ["id"]);
+
+  @override
+  Future<List<ConversationSummary>> crateApiChatApiGetConversationList() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 63,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_conversation_summary,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiGetConversationListConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiGetConversationListConstMeta =>
+      const TaskConstMeta(debugName: "get_conversation_list", argNames: []);
+
+  @override
+  Future<List<DegradationRecord>> crateApiChatApiGetDegradationHistory({
+    required String conversationId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 64,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_degradation_record,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiGetDegradationHistoryConstMeta,
+        argValues: [conversationId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiGetDegradationHistoryConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_degradation_history",
+        argNames: ["conversationId"],
+      );
+
+  @override
+  Future<List<LexiconEntry>> crateApiChatApiGetEmotionLexicon() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 65,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_lexicon_entry,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiGetEmotionLexiconConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiGetEmotionLexiconConstMeta =>
+      const TaskConstMeta(debugName: "get_emotion_lexicon", argNames: []);
+
+  @override
+  Future<EncryptionStatus> crateApiChatApiGetEncryptionStatus() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 66,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_encryption_status,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiGetEncryptionStatusConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiGetEncryptionStatusConstMeta =>
+      const TaskConstMeta(debugName: "get_encryption_status", argNames: []);
+
+  @override
+  Future<List<FactConflict>> crateApiChatApiGetFactConflicts({
+    required String conversationId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 67,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_fact_conflict,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiGetFactConflictsConstMeta,
+        argValues: [conversationId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiGetFactConflictsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_fact_conflicts",
+        argNames: ["conversationId"],
+      );
+
+  @override
+  Future<List<ConversationSummary>> crateApiChatApiGetFilteredConversationList({
+    required ConversationFilter filter,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_conversation_filter(filter, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 68,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_conversation_summary,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiGetFilteredConversationListConstMeta,
+        argValues: [filter],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiGetFilteredConversationListConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_filtered_conversation_list",
+        argNames: ["filter"],
+      );
+
+  @override
+  Future<GenerationMetadata?> crateApiChatApiGetGenerationMetadata({
+    required String conversationId,
+    required String messageId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          sse_encode_String(messageId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 69,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_generation_metadata,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiGetGenerationMetadataConstMeta,
+        argValues: [conversationId, messageId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiGetGenerationMetadataConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_generation_metadata",
+        argNames: ["conversationId", "messageId"],
+      );
+
+  @override
+  Future<GroupChat?> crateApiChatApiGetGroupChat({
+    required String conversationId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 70,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_group_chat,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiGetGroupChatConstMeta,
+        argValues: [conversationId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiGetGroupChatConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_group_chat",
+        argNames: ["conversationId"],
+      );
+
+  @override
+  Future<JobSchedulerStats> crateApiChatApiGetJobSchedulerStats() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 71,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_job_scheduler_stats,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiGetJobSchedulerStatsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiGetJobSchedulerStatsConstMeta =>
+      const TaskConstMeta(debugName: "get_job_scheduler_stats", argNames: []);
+
+  @override
+  Future<KnowledgeBinding> crateApiChatApiGetKnowledgeBinding({
+    required String conversationId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 72,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_knowledge_binding,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiGetKnowledgeBindingConstMeta,
+        argValues: [conversationId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiGetKnowledgeBindingConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_knowledge_binding",
+        argNames: ["conversationId"],
+      );
+
+  @override
+  Future<KnowledgeGraphView> crateApiChatApiGetKnowledgeGraph({
+    String? conversationId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(conversationId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 73,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_knowledge_graph_view,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiGetKnowledgeGraphConstMeta,
+        argValues: [conversationId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiGetKnowledgeGraphConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_knowledge_graph",
+        argNames: ["conversationId"],
+      );
+
+  @override
+  Future<List<LoreEntry>> crateApiChatApiGetLoreEntries({
+    String? conversationId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(conversationId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 74,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_lore_entry,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiGetLoreEntriesConstMeta,
+        argValues: [conversationId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiGetLoreEntriesConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_lore_entries",
+        argNames: ["conversationId"],
+      );
+
+  @override
+  Future<List<MemoryFidelityReport>> crateApiChatApiGetMemoryFidelityHistory({
+    required String conversationId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 75,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_memory_fidelity_report,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiGetMemoryFidelityHistoryConstMeta,
+        argValues: [conversationId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiGetMemoryFidelityHistoryConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_memory_fidelity_history",
+        argNames: ["conversationId"],
+      );
+
+  @override
+  Future<MetricsSnapshot> crateApiChatApiGetMetrics() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 76,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_metrics_snapshot,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiGetMetricsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiGetMetricsConstMeta =>
+      const TaskConstMeta(debugName: "get_metrics", argNames: []);
+
+  @override
+  Future<List<OutboxEntry>> crateApiChatApiGetOutbox() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 77,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_outbox_entry,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiGetOutboxConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiGetOutboxConstMeta =>
+      const TaskConstMeta(debugName: "get_outbox", argNames: []);
+
+  @override
+  Future<MemorySummary?> crateApiChatApiGetPendingMemorySummary({
+    required String conversationId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 78,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_memory_summary,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiGetPendingMemorySummaryConstMeta,
+        argValues: [conversationId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiGetPendingMemorySummaryConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_pending_memory_summary",
+        argNames: ["conversationId"],
+      );
+
+  @override
+  Future<String?> crateApiChatApiGetPromptTemplate({required String name}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 79,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiChatApiGetPromptTemplateConstMeta,
+        argValues: [name],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiGetPromptTemplateConstMeta =>
+      const TaskConstMeta(debugName: "get_prompt_template", argNames: ["name"]);
+
+  @override
+  Future<RetentionPolicy> crateApiChatApiGetRetentionPolicy() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 80,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_retention_policy,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiGetRetentionPolicyConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiGetRetentionPolicyConstMeta =>
+      const TaskConstMeta(debugName: "get_retention_policy", argNames: []);
+
+  @override
+  Future<SafetyPolicy> crateApiChatApiGetSafetyPolicy() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 81,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_safety_policy,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiGetSafetyPolicyConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiGetSafetyPolicyConstMeta =>
+      const TaskConstMeta(debugName: "get_safety_policy", argNames: []);
+
+  @override
+  Future<SayDoRuleSet> crateApiChatApiGetSaydoRules({
+    required String conversationId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 82,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_say_do_rule_set,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiGetSaydoRulesConstMeta,
+        argValues: [conversationId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiGetSaydoRulesConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_saydo_rules",
+        argNames: ["conversationId"],
+      );
+
+  @override
+  Future<AppSettings> crateApiChatApiGetSettings() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 83,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_app_settings,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiGetSettingsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiGetSettingsConstMeta =>
+      const TaskConstMeta(debugName: "get_settings", argNames: []);
+
+  @override
+  Future<List<ShadowEvalRecord>> crateApiChatApiGetShadowEvalRecords({
+    required String conversationId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 84,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_shadow_eval_record,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiGetShadowEvalRecordsConstMeta,
+        argValues: [conversationId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiGetShadowEvalRecordsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_shadow_eval_records",
+        argNames: ["conversationId"],
+      );
+
+  @override
+  Future<ShadowEvalSummary> crateApiChatApiGetShadowEvalSummary({
+    String? conversationId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(conversationId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 85,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_shadow_eval_summary,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiGetShadowEvalSummaryConstMeta,
+        argValues: [conversationId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiGetShadowEvalSummaryConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_shadow_eval_summary",
+        argNames: ["conversationId"],
+      );
+
+  @override
+  Future<List<TimelineEntry>> crateApiChatApiGetStoryTimeline({
+    required String conversationId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 86,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_timeline_entry,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiGetStoryTimelineConstMeta,
+        argValues: [conversationId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiGetStoryTimelineConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_story_timeline",
+        argNames: ["conversationId"],
+      );
+
+  @override
+  Future<SummarizationConfig> crateApiChatApiGetSummarizationConfig({
+    required String conversationId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 87,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_summarization_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiGetSummarizationConfigConstMeta,
+        argValues: [conversationId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiGetSummarizationConfigConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_summarization_config",
+        argNames: ["conversationId"],
+      );
+
+  @override
+  Future<SyncSettings> crateApiChatApiGetSyncSettings() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 88,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_sync_settings,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiGetSyncSettingsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiGetSyncSettingsConstMeta =>
+      const TaskConstMeta(debugName: "get_sync_settings", argNames: []);
+
+  @override
+  Future<int> crateApiChatApiGetTurnCount({required String conversationId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 89,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiGetTurnCountConstMeta,
+        argValues: [conversationId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiGetTurnCountConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_turn_count",
+        argNames: ["conversationId"],
+      );
+
+  @override
+  Future<HousekeepingReport> crateApiDataModelsHousekeepingReportDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 90,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_housekeeping_report,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDataModelsHousekeepingReportDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDataModelsHousekeepingReportDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "housekeeping_report_default",
+        argNames: [],
+      );
+
+  @override
+  Stream<ChatStreamEvent> crateApiChatApiIllustrateScene({
+    required String conversationId,
+    String? hint,
+    required String model,
+  }) {
+    final sink = RustStreamSink<ChatStreamEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(conversationId, serializer);
+            sse_encode_opt_String(hint, serializer);
+            sse_encode_String(model, serializer);
+            sse_encode_StreamSink_chat_stream_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 91,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiChatApiIllustrateSceneConstMeta,
+          argValues: [conversationId, hint, model, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiChatApiIllustrateSceneConstMeta =>
+      const TaskConstMeta(
+        debugName: "illustrate_scene",
+        argNames: ["conversationId", "hint", "model", "sink"],
+      );
+
+  @override
+  Future<DataExportInfo> crateApiChatApiImportAllData({
+    required String archivePath,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(archivePath, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 92,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_data_export_info,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiChatApiImportAllDataConstMeta,
+        argValues: [archivePath],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiImportAllDataConstMeta =>
+      const TaskConstMeta(
+        debugName: "import_all_data",
+        argNames: ["archivePath"],
+      );
+
+  @override
+  Future<IndexScope> crateApiDataModelsIndexScopeDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 93,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_index_scope,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDataModelsIndexScopeDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDataModelsIndexScopeDefaultConstMeta =>
+      const TaskConstMeta(debugName: "index_scope_default", argNames: []);
+
+  @override
+  Future<void> crateApiChatApiInitApp({required String dataPath}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(dataPath, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 94,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiInitAppConstMeta,
+        argValues: [dataPath],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiInitAppConstMeta =>
+      const TaskConstMeta(debugName: "init_app", argNames: ["dataPath"]);
+
+  @override
+  Future<bool> crateApiChatApiInvalidateDistilledState({
+    required String conversationId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 95,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiInvalidateDistilledStateConstMeta,
+        argValues: [conversationId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiInvalidateDistilledStateConstMeta =>
+      const TaskConstMeta(
+        debugName: "invalidate_distilled_state",
+        argNames: ["conversationId"],
+      );
+
+  @override
+  Future<bool> crateApiChatApiIsStreamingDegraded() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 96,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiIsStreamingDegradedConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiIsStreamingDegradedConstMeta =>
+      const TaskConstMeta(debugName: "is_streaming_degraded", argNames: []);
+
+  @override
+  Future<JobSchedulerStats> crateApiDataModelsJobSchedulerStatsDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 97,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_job_scheduler_stats,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDataModelsJobSchedulerStatsDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDataModelsJobSchedulerStatsDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "job_scheduler_stats_default",
+        argNames: [],
+      );
+
+  @override
+  Future<KnowledgeBinding> crateApiDataModelsKnowledgeBindingDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 98,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_knowledge_binding,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDataModelsKnowledgeBindingDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDataModelsKnowledgeBindingDefaultConstMeta =>
+      const TaskConstMeta(debugName: "knowledge_binding_default", argNames: []);
+
+  @override
+  Future<KnowledgeGraphView> crateApiDataModelsKnowledgeGraphViewDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 99,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_knowledge_graph_view,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDataModelsKnowledgeGraphViewDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDataModelsKnowledgeGraphViewDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "knowledge_graph_view_default",
+        argNames: [],
+      );
+
+  @override
+  Future<List<BackgroundTaskEvent>> crateApiChatApiListBackgroundTasks() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 100,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_background_task_event,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiListBackgroundTasksConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiListBackgroundTasksConstMeta =>
+      const TaskConstMeta(debugName: "list_background_tasks", argNames: []);
+
+  @override
+  Future<List<BackupSnapshotInfo>> crateApiChatApiListBackups({
+    String? archivePath,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(archivePath, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 101,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_backup_snapshot_info,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiListBackupsConstMeta,
+        argValues: [archivePath],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiListBackupsConstMeta =>
+      const TaskConstMeta(debugName: "list_backups", argNames: ["archivePath"]);
+
+  @override
+  Future<List<BranchInfo>> crateApiChatApiListBranches({
+    required String conversationId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 102,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_branch_info,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiListBranchesConstMeta,
+        argValues: [conversationId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiListBranchesConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_branches",
+        argNames: ["conversationId"],
+      );
+
+  @override
+  Future<List<String>> crateApiChatApiListConversationFolders() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 103,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiListConversationFoldersConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiListConversationFoldersConstMeta =>
+      const TaskConstMeta(debugName: "list_conversation_folders", argNames: []);
+
+  @override
+  Future<List<String>> crateApiChatApiListConversationTags() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 104,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiListConversationTagsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiListConversationTagsConstMeta =>
+      const TaskConstMeta(debugName: "list_conversation_tags", argNames: []);
+
+  @override
+  Future<List<Fact>> crateApiChatApiListFacts({String? conversationId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(conversationId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 105,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_fact,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiListFactsConstMeta,
+        argValues: [conversationId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiListFactsConstMeta => const TaskConstMeta(
+    debugName: "list_facts",
+    argNames: ["conversationId"],
+  );
+
+  @override
+  Future<List<Fact>> crateApiChatApiListLayerFacts({
+    required String conversationId,
+    required KnowledgeLayer layer,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          sse_encode_knowledge_layer(layer, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 106,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_fact,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiListLayerFactsConstMeta,
+        argValues: [conversationId, layer],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiListLayerFactsConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_layer_facts",
+        argNames: ["conversationId", "layer"],
+      );
+
+  @override
+  Future<List<MemorySummary>> crateApiChatApiListMemories({
+    required String conversationId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 107,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_memory_summary,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiListMemoriesConstMeta,
+        argValues: [conversationId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiListMemoriesConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_memories",
+        argNames: ["conversationId"],
+      );
+
+  @override
+  Future<List<UserPersona>> crateApiChatApiListPersonas() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 108,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_user_persona,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiListPersonasConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiListPersonasConstMeta =>
+      const TaskConstMeta(debugName: "list_personas", argNames: []);
+
+  @override
+  Future<List<PromptTemplateInfo>> crateApiChatApiListPromptTemplates() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 109,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prompt_template_info,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiListPromptTemplatesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiListPromptTemplatesConstMeta =>
+      const TaskConstMeta(debugName: "list_prompt_templates", argNames: []);
+
+  @override
+  Future<List<Message>> crateApiChatApiListStarredMessages({
+    required String conversationId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 110,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_message,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiListStarredMessagesConstMeta,
+        argValues: [conversationId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiListStarredMessagesConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_starred_messages",
+        argNames: ["conversationId"],
+      );
+
+  @override
+  Future<List<Message>> crateApiChatApiLoadColdStorageMessages({
+    required String conversationId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 111,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_message,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiChatApiLoadColdStorageMessagesConstMeta,
+        argValues: [conversationId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiLoadColdStorageMessagesConstMeta =>
+      const TaskConstMeta(
+        debugName: "load_cold_storage_messages",
+        argNames: ["conversationId"],
+      );
+
+  @override
+  Future<MessageType> crateApiDataModelsMessageTypeDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 112,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_message_type,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDataModelsMessageTypeDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDataModelsMessageTypeDefaultConstMeta =>
+      const TaskConstMeta(debugName: "message_type_default", argNames: []);
+
+  @override
+  Future<MetricsSnapshot> crateApiDataModelsMetricsSnapshotDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 113,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_metrics_snapshot,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDataModelsMetricsSnapshotDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDataModelsMetricsSnapshotDefaultConstMeta =>
+      const TaskConstMeta(debugName: "metrics_snapshot_default", argNames: []);
+
+  @override
+  Future<LayoutVerification?> crateApiChatApiMigrateDataLayout({
+    required String targetPath,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(targetPath, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 114,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_layout_verification,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiMigrateDataLayoutConstMeta,
+        argValues: [targetPath],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiMigrateDataLayoutConstMeta =>
+      const TaskConstMeta(
+        debugName: "migrate_data_layout",
+        argNames: ["targetPath"],
+      );
+
+  @override
+  Future<NarrationPerspective> crateApiDataModelsNarrationPerspectiveDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 115,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_narration_perspective,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDataModelsNarrationPerspectiveDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDataModelsNarrationPerspectiveDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "narration_perspective_default",
+        argNames: [],
+      );
+
+  @override
+  Future<OutboxStatus> crateApiDataModelsOutboxStatusDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 116,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_outbox_status,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDataModelsOutboxStatusDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDataModelsOutboxStatusDefaultConstMeta =>
+      const TaskConstMeta(debugName: "outbox_status_default", argNames: []);
+
+  @override
+  Future<bool> crateApiChatApiPinMemory({
+    required String conversationId,
+    required String summaryId,
+    String? fact,
+    required bool pinned,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          sse_encode_String(summaryId, serializer);
+          sse_encode_opt_String(fact, serializer);
+          sse_encode_bool(pinned, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 117,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiPinMemoryConstMeta,
+        argValues: [conversationId, summaryId, fact, pinned],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiPinMemoryConstMeta => const TaskConstMeta(
+    debugName: "pin_memory",
+    argNames: ["conversationId", "summaryId", "fact", "pinned"],
+  );
+
+  @override
+  Future<PreflightAdvisory?> crateApiChatApiPreflightMessage({
+    required String conversationId,
+    required String draft,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          sse_encode_String(draft, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 118,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_preflight_advisory,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiPreflightMessageConstMeta,
+        argValues: [conversationId, draft],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiPreflightMessageConstMeta =>
+      const TaskConstMeta(
+        debugName: "preflight_message",
+        argNames: ["conversationId", "draft"],
+      );
+
+  @override
+  Future<ReengagementBrief?> crateApiChatApiPrepareReengagement({
+    required String conversationId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 119,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_reengagement_brief,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiPrepareReengagementConstMeta,
+        argValues: [conversationId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiPrepareReengagementConstMeta =>
+      const TaskConstMeta(
+        debugName: "prepare_reengagement",
+        argNames: ["conversationId"],
+      );
+
+  @override
+  Stream<ChatStreamEvent> crateApiChatApiPreviewMemorySummary({
+    required String conversationId,
+  }) {
+    final sink = RustStreamSink<ChatStreamEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(conversationId, serializer);
+            sse_encode_StreamSink_chat_stream_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 120,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiChatApiPreviewMemorySummaryConstMeta,
+          argValues: [conversationId, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiChatApiPreviewMemorySummaryConstMeta =>
+      const TaskConstMeta(
+        debugName: "preview_memory_summary",
+        argNames: ["conversationId", "sink"],
+      );
+
+  @override
+  Future<bool> crateApiChatApiPromoteFact({
+    required String conversationId,
+    required String factId,
+    required KnowledgeLayer layer,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          sse_encode_String(factId, serializer);
+          sse_encode_knowledge_layer(layer, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 121,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiChatApiPromoteFactConstMeta,
+        argValues: [conversationId, factId, layer],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiPromoteFactConstMeta => const TaskConstMeta(
+    debugName: "promote_fact",
+    argNames: ["conversationId", "factId", "layer"],
+  );
+
+  @override
+  Future<ProviderKind> crateApiDataModelsProviderKindDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 122,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_provider_kind,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDataModelsProviderKindDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDataModelsProviderKindDefaultConstMeta =>
+      const TaskConstMeta(debugName: "provider_kind_default", argNames: []);
+
+  @override
+  Future<int> crateApiChatApiPruneBackups({
+    String? archivePath,
+    required BackupRetention retention,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(archivePath, serializer);
+          sse_encode_box_autoadd_backup_retention(retention, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 123,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiPruneBackupsConstMeta,
+        argValues: [archivePath, retention],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiPruneBackupsConstMeta =>
+      const TaskConstMeta(
+        debugName: "prune_backups",
+        argNames: ["archivePath", "retention"],
+      );
+
+  @override
+  Future<List<CharacterRelation>> crateApiChatApiQueryCharacterRelation({
+    required String conversationId,
+    required String a,
+    required String b,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          sse_encode_String(a, serializer);
+          sse_encode_String(b, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 124,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_character_relation,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiQueryCharacterRelationConstMeta,
+        argValues: [conversationId, a, b],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiQueryCharacterRelationConstMeta =>
+      const TaskConstMeta(
+        debugName: "query_character_relation",
+        argNames: ["conversationId", "a", "b"],
+      );
+
+  @override
+  Future<KnowledgeGraphView> crateApiChatApiQueryGraphNeighbors({
+    String? conversationId,
+    required String entity,
+    required int depth,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(conversationId, serializer);
+          sse_encode_String(entity, serializer);
+          sse_encode_u_32(depth, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 125,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_knowledge_graph_view,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiQueryGraphNeighborsConstMeta,
+        argValues: [conversationId, entity, depth],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiQueryGraphNeighborsConstMeta =>
+      const TaskConstMeta(
+        debugName: "query_graph_neighbors",
+        argNames: ["conversationId", "entity", "depth"],
+      );
+
+  @override
+  Future<DistilledSystemState?> crateApiChatApiRefreshDistilledState({
+    required String conversationId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 126,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_distilled_system_state,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiRefreshDistilledStateConstMeta,
+        argValues: [conversationId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiRefreshDistilledStateConstMeta =>
+      const TaskConstMeta(
+        debugName: "refresh_distilled_state",
+        argNames: ["conversationId"],
+      );
+
+  @override
+  Stream<ChatStreamEvent> crateApiChatApiRegenerateFrom({
+    required String conversationId,
+    required String messageId,
+    required bool asBranch,
+    required String model,
+    required bool enableThinking,
+  }) {
+    final sink = RustStreamSink<ChatStreamEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(conversationId, serializer);
+            sse_encode_String(messageId, serializer);
+            sse_encode_bool(asBranch, serializer);
+            sse_encode_String(model, serializer);
+            sse_encode_bool(enableThinking, serializer);
+            sse_encode_StreamSink_chat_stream_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 127,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiChatApiRegenerateFromConstMeta,
+          argValues: [
+            conversationId,
+            messageId,
+            asBranch,
+            model,
+            enableThinking,
+            sink,
+          ],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiChatApiRegenerateFromConstMeta =>
+      const TaskConstMeta(
+        debugName: "regenerate_from",
+        argNames: [
+          "conversationId",
+          "messageId",
+          "asBranch",
+          "model",
+          "enableThinking",
+          "sink",
+        ],
+      );
+
+  @override
+  Stream<ChatStreamEvent> crateApiChatApiRegenerateResponse({
+    required String conversationId,
+    required String model,
+    required bool enableThinking,
+  }) {
+    final sink = RustStreamSink<ChatStreamEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(conversationId, serializer);
+            sse_encode_String(model, serializer);
+            sse_encode_bool(enableThinking, serializer);
+            sse_encode_StreamSink_chat_stream_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 128,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiChatApiRegenerateResponseConstMeta,
+          argValues: [conversationId, model, enableThinking, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiChatApiRegenerateResponseConstMeta =>
+      const TaskConstMeta(
+        debugName: "regenerate_response",
+        argNames: ["conversationId", "model", "enableThinking", "sink"],
+      );
+
+  @override
+  Stream<ReindexProgress> crateApiChatApiReindexAll({
+    required IndexScope scope,
+  }) {
+    final sink = RustStreamSink<ReindexProgress>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_index_scope(scope, serializer);
+            sse_encode_StreamSink_reindex_progress_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 129,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiChatApiReindexAllConstMeta,
+          argValues: [scope, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiChatApiReindexAllConstMeta => const TaskConstMeta(
+    debugName: "reindex_all",
+    argNames: ["scope", "sink"],
+  );
+
+  @override
+  Future<int> crateApiChatApiReloadPromptTemplates() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 130,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiReloadPromptTemplatesConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiReloadPromptTemplatesConstMeta =>
+      const TaskConstMeta(debugName: "reload_prompt_templates", argNames: []);
+
+  @override
+  Future<bool> crateApiChatApiRemoveBlockedTopic({
+    required String conversationId,
+    required String topicId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          sse_encode_String(topicId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 131,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiRemoveBlockedTopicConstMeta,
+        argValues: [conversationId, topicId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiRemoveBlockedTopicConstMeta =>
+      const TaskConstMeta(
+        debugName: "remove_blocked_topic",
+        argNames: ["conversationId", "topicId"],
+      );
+
+  @override
+  Future<bool> crateApiChatApiRemoveLoreEntry({
+    String? conversationId,
+    required String entryId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(conversationId, serializer);
+          sse_encode_String(entryId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 132,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiRemoveLoreEntryConstMeta,
+        argValues: [conversationId, entryId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiRemoveLoreEntryConstMeta =>
+      const TaskConstMeta(
+        debugName: "remove_lore_entry",
+        argNames: ["conversationId", "entryId"],
+      );
+
+  @override
+  Future<IntegrityReport?> crateApiChatApiRepairIntegrity({
+    required String conversationId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 133,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_integrity_report,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiRepairIntegrityConstMeta,
+        argValues: [conversationId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiRepairIntegrityConstMeta =>
+      const TaskConstMeta(
+        debugName: "repair_integrity",
+        argNames: ["conversationId"],
+      );
+
+  @override
+  Future<ReplyLength> crateApiDataModelsReplyLengthDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 134,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_reply_length,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDataModelsReplyLengthDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDataModelsReplyLengthDefaultConstMeta =>
+      const TaskConstMeta(debugName: "reply_length_default", argNames: []);
+
+  @override
+  Future<ReplySignals> crateApiDataModelsReplySignalsDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 135,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_reply_signals,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDataModelsReplySignalsDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDataModelsReplySignalsDefaultConstMeta =>
+      const TaskConstMeta(debugName: "reply_signals_default", argNames: []);
+
+  @override
+  Future<void> crateApiChatApiResetPromptTemplate({required String name}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 136,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiChatApiResetPromptTemplateConstMeta,
+        argValues: [name],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiResetPromptTemplateConstMeta =>
+      const TaskConstMeta(
+        debugName: "reset_prompt_template",
+        argNames: ["name"],
+      );
+
+  @override
+  Future<ResponseStyle> crateApiDataModelsResponseStyleDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 137,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_response_style,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDataModelsResponseStyleDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDataModelsResponseStyleDefaultConstMeta =>
+      const TaskConstMeta(debugName: "response_style_default", argNames: []);
+
+  @override
+  Future<bool> crateApiChatApiRestartStory({required String conversationId}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 138,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiRestartStoryConstMeta,
+        argValues: [conversationId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiRestartStoryConstMeta =>
+      const TaskConstMeta(
+        debugName: "restart_story",
+        argNames: ["conversationId"],
+      );
+
+  @override
+  Future<int> crateApiChatApiRestoreBackup({
+    String? archivePath,
+    String? snapshotId,
+    required BackupScope scope,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(archivePath, serializer);
+          sse_encode_opt_String(snapshotId, serializer);
+          sse_encode_backup_scope(scope, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 139,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiChatApiRestoreBackupConstMeta,
+        argValues: [archivePath, snapshotId, scope],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiRestoreBackupConstMeta =>
+      const TaskConstMeta(
+        debugName: "restore_backup",
+        argNames: ["archivePath", "snapshotId", "scope"],
+      );
+
+  @override
+  Future<RetentionPolicy> crateApiDataModelsRetentionPolicyDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 140,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_retention_policy,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDataModelsRetentionPolicyDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDataModelsRetentionPolicyDefaultConstMeta =>
+      const TaskConstMeta(debugName: "retention_policy_default", argNames: []);
+
+  @override
+  Future<RetryPolicy> crateApiDataModelsRetryPolicyDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 141,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_retry_policy,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDataModelsRetryPolicyDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDataModelsRetryPolicyDefaultConstMeta =>
+      const TaskConstMeta(debugName: "retry_policy_default", argNames: []);
+
+  @override
+  Future<bool> crateApiChatApiRewriteReply({
+    required String conversationId,
+    required String messageId,
+    required String newContent,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          sse_encode_String(messageId, serializer);
+          sse_encode_String(newContent, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 142,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiRewriteReplyConstMeta,
+        argValues: [conversationId, messageId, newContent],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiRewriteReplyConstMeta =>
+      const TaskConstMeta(
+        debugName: "rewrite_reply",
+        argNames: ["conversationId", "messageId", "newContent"],
+      );
+
+  @override
+  Future<List<String>> crateApiChatApiRollbackToMessage({
+    required String conversationId,
+    required String messageId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          sse_encode_String(messageId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 143,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiRollbackToMessageConstMeta,
+        argValues: [conversationId, messageId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiRollbackToMessageConstMeta =>
+      const TaskConstMeta(
+        debugName: "rollback_to_message",
+        argNames: ["conversationId", "messageId"],
+      );
+
+  @override
+  Future<int> crateApiChatApiRunDeferredJobs() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 144,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiRunDeferredJobsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiRunDeferredJobsConstMeta =>
+      const TaskConstMeta(debugName: "run_deferred_jobs", argNames: []);
+
+  @override
+  Future<HousekeepingReport> crateApiChatApiRunHousekeeping() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 145,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_housekeeping_report,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiChatApiRunHousekeepingConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiRunHousekeepingConstMeta =>
+      const TaskConstMeta(debugName: "run_housekeeping", argNames: []);
+
+  @override
+  Future<int> crateApiChatApiRunThinkingRetention() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 146,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiRunThinkingRetentionConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiRunThinkingRetentionConstMeta =>
+      const TaskConstMeta(debugName: "run_thinking_retention", argNames: []);
+
+  @override
+  Future<SafetyPolicy> crateApiDataModelsSafetyPolicyDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 147,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_safety_policy,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDataModelsSafetyPolicyDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDataModelsSafetyPolicyDefaultConstMeta =>
+      const TaskConstMeta(debugName: "safety_policy_default", argNames: []);
+
+  @override
+  Future<void> crateApiChatApiSaveEmotionLexicon({
+    required List<LexiconEntry> entries,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_lexicon_entry(entries, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 148,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiChatApiSaveEmotionLexiconConstMeta,
+        argValues: [entries],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiSaveEmotionLexiconConstMeta =>
+      const TaskConstMeta(
+        debugName: "save_emotion_lexicon",
+        argNames: ["entries"],
+      );
+
+  @override
+  Future<LoreEntry?> crateApiChatApiSaveLoreEntry({
+    String? conversationId,
+    required LoreEntry entry,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(conversationId, serializer);
+          sse_encode_box_autoadd_lore_entry(entry, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 149,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_lore_entry,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiSaveLoreEntryConstMeta,
+        argValues: [conversationId, entry],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiSaveLoreEntryConstMeta =>
+      const TaskConstMeta(
+        debugName: "save_lore_entry",
+        argNames: ["conversationId", "entry"],
+      );
+
+  @override
+  Future<UserPersona> crateApiChatApiSavePersona({
+    required UserPersona persona,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_user_persona(persona, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 150,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_user_persona,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiChatApiSavePersonaConstMeta,
+        argValues: [persona],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiSavePersonaConstMeta =>
+      const TaskConstMeta(debugName: "save_persona", argNames: ["persona"]);
+
+  @override
+  Future<void> crateApiChatApiSavePromptTemplate({
+    required String name,
+    required String content,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          sse_encode_String(content, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 151,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiChatApiSavePromptTemplateConstMeta,
+        argValues: [name, content],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiSavePromptTemplateConstMeta =>
+      const TaskConstMeta(
+        debugName: "save_prompt_template",
+        argNames: ["name", "content"],
+      );
+
+  @override
+  Future<bool> crateApiChatApiSaveSettings({required AppSettings settings}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_app_settings(settings, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 152,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiSaveSettingsConstMeta,
+        argValues: [settings],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiSaveSettingsConstMeta =>
+      const TaskConstMeta(debugName: "save_settings", argNames: ["settings"]);
+
+  @override
+  Future<SayDoRuleSet> crateApiDataModelsSayDoRuleSetDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 153,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_say_do_rule_set,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDataModelsSayDoRuleSetDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDataModelsSayDoRuleSetDefaultConstMeta =>
+      const TaskConstMeta(debugName: "say_do_rule_set_default", argNames: []);
+
+  @override
+  Future<List<SearchHit>> crateApiChatApiSearchConversations({
+    required String query,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(query, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 154,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_search_hit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiSearchConversationsConstMeta,
+        argValues: [query],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiSearchConversationsConstMeta =>
+      const TaskConstMeta(
+        debugName: "search_conversations",
+        argNames: ["query"],
+      );
+
+  @override
+  Future<List<MemorySearchResult>> crateApiChatApiSearchMemories({
+    required String conversationId,
+    required String query,
+    required BigInt topK,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          sse_encode_String(query, serializer);
+          sse_encode_usize(topK, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 155,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_memory_search_result,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiSearchMemoriesConstMeta,
+        argValues: [conversationId, query, topK],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiSearchMemoriesConstMeta =>
+      const TaskConstMeta(
+        debugName: "search_memories",
+        argNames: ["conversationId", "query", "topK"],
+      );
+
+  @override
+  Future<Message?> crateApiChatApiSelectAlternative({
+    required String conversationId,
+    required String messageId,
+    required int index,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          sse_encode_String(messageId, serializer);
+          sse_encode_u_32(index, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 156,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_message,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiSelectAlternativeConstMeta,
+        argValues: [conversationId, messageId, index],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiSelectAlternativeConstMeta =>
+      const TaskConstMeta(
+        debugName: "select_alternative",
+        argNames: ["conversationId", "messageId", "index"],
+      );
+
+  @override
+  Stream<ChatStreamEvent> crateApiChatApiSendMessage({
+    required String conversationId,
+    required String content,
+    required String model,
+    required bool enableThinking,
+    AmbientContext? ambient,
+  }) {
+    final sink = RustStreamSink<ChatStreamEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(conversationId, serializer);
+            sse_encode_String(content, serializer);
+            sse_encode_String(model, serializer);
+            sse_encode_bool(enableThinking, serializer);
+            sse_encode_opt_box_autoadd_ambient_context(ambient, serializer);
+            sse_encode_StreamSink_chat_stream_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 157,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiChatApiSendMessageConstMeta,
+          argValues: [
+            conversationId,
+            content,
+            model,
+            enableThinking,
+            ambient,
+            sink,
+          ],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiChatApiSendMessageConstMeta => const TaskConstMeta(
+    debugName: "send_message",
+    argNames: [
+      "conversationId",
+      "content",
+      "model",
+      "enableThinking",
+      "ambient",
+      "sink",
+    ],
+  );
+
+  @override
+  Stream<ChatStreamEvent> crateApiChatApiSendMessageWithImages({
+    required String conversationId,
+    required String content,
+    required List<MessageAttachment> images,
+    required String model,
+    required bool enableThinking,
+    AmbientContext? ambient,
+  }) {
+    final sink = RustStreamSink<ChatStreamEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(conversationId, serializer);
+            sse_encode_String(content, serializer);
+            sse_encode_list_message_attachment(images, serializer);
+            sse_encode_String(model, serializer);
+            sse_encode_bool(enableThinking, serializer);
+            sse_encode_opt_box_autoadd_ambient_context(ambient, serializer);
+            sse_encode_StreamSink_chat_stream_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 158,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiChatApiSendMessageWithImagesConstMeta,
+          argValues: [
+            conversationId,
+            content,
+            images,
+            model,
+            enableThinking,
+            ambient,
+            sink,
+          ],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiChatApiSendMessageWithImagesConstMeta =>
+      const TaskConstMeta(
+        debugName: "send_message_with_images",
+        argNames: [
+          "conversationId",
+          "content",
+          "images",
+          "model",
+          "enableThinking",
+          "ambient",
+          "sink",
+        ],
+      );
+
+  @override
+  Future<void> crateApiChatApiSetApiKey({required String apiKey}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(apiKey, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 159,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiChatApiSetApiKeyConstMeta,
+        argValues: [apiKey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiSetApiKeyConstMeta =>
+      const TaskConstMeta(debugName: "set_api_key", argNames: ["apiKey"]);
+
+  @override
+  Future<bool> crateApiChatApiSetCharacterVoice({
+    required String characterKey,
+    String? voice,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(characterKey, serializer);
+          sse_encode_opt_String(voice, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 160,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiSetCharacterVoiceConstMeta,
+        argValues: [characterKey, voice],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiSetCharacterVoiceConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_character_voice",
+        argNames: ["characterKey", "voice"],
+      );
+
+  @override
+  Future<void> crateApiChatApiSetChatProvider({
+    required ProviderKind provider,
+    String? baseUrl,
+    String? model,
+    String? apiKey,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_provider_kind(provider, serializer);
+          sse_encode_opt_String(baseUrl, serializer);
+          sse_encode_opt_String(model, serializer);
+          sse_encode_opt_String(apiKey, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 161,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiChatApiSetChatProviderConstMeta,
+        argValues: [provider, baseUrl, model, apiKey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiSetChatProviderConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_chat_provider",
+        argNames: ["provider", "baseUrl", "model", "apiKey"],
+      );
+
+  @override
+  Future<bool> crateApiChatApiSetCheckInSchedule({
+    required CheckInSchedule schedule,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_check_in_schedule(schedule, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 162,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiSetCheckInScheduleConstMeta,
+        argValues: [schedule],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiSetCheckInScheduleConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_check_in_schedule",
+        argNames: ["schedule"],
+      );
+
+  @override
+  Future<bool> crateApiChatApiSetContextLayers({
+    required String conversationId,
+    required ContextLayers layers,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          sse_encode_box_autoadd_context_layers(layers, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 163,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiSetContextLayersConstMeta,
+        argValues: [conversationId, layers],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiSetContextLayersConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_context_layers",
+        argNames: ["conversationId", "layers"],
+      );
+
+  @override
+  Future<bool> crateApiChatApiSetConversationArchived({
+    required String conversationId,
+    required bool archived,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          sse_encode_bool(archived, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 164,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiSetConversationArchivedConstMeta,
+        argValues: [conversationId, archived],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiSetConversationArchivedConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_conversation_archived",
+        argNames: ["conversationId", "archived"],
+      );
+
+  @override
+  Future<bool> crateApiChatApiSetConversationMode({
+    required String conversationId,
+    required ConversationMode mode,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          sse_encode_conversation_mode(mode, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 165,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiSetConversationModeConstMeta,
+        argValues: [conversationId, mode],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiSetConversationModeConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_conversation_mode",
+        argNames: ["conversationId", "mode"],
+      );
+
+  @override
+  Future<ConversationOrganization?> crateApiChatApiSetConversationOrganization({
+    required String conversationId,
+    required ConversationOrganization organization,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          sse_encode_box_autoadd_conversation_organization(
+            organization,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 166,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_conversation_organization,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiSetConversationOrganizationConstMeta,
+        argValues: [conversationId, organization],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiSetConversationOrganizationConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_conversation_organization",
+        argNames: ["conversationId", "organization"],
+      );
+
+  @override
+  Future<bool> crateApiChatApiSetConversationPersona({
+    required String conversationId,
+    String? personaId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          sse_encode_opt_String(personaId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 167,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiSetConversationPersonaConstMeta,
+        argValues: [conversationId, personaId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiSetConversationPersonaConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_conversation_persona",
+        argNames: ["conversationId", "personaId"],
+      );
+
+  @override
+  Future<bool> crateApiChatApiSetConversationPinned({
+    required String conversationId,
+    required bool pinned,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          sse_encode_bool(pinned, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 168,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiSetConversationPinnedConstMeta,
+        argValues: [conversationId, pinned],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiSetConversationPinnedConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_conversation_pinned",
+        argNames: ["conversationId", "pinned"],
+      );
+
+  @override
+  Future<void> crateApiChatApiSetDeviceConditions({
+    required DeviceConditions conditions,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_device_conditions(conditions, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 169,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiSetDeviceConditionsConstMeta,
+        argValues: [conditions],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiSetDeviceConditionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_device_conditions",
+        argNames: ["conditions"],
+      );
+
+  @override
+  Future<bool> crateApiChatApiSetDialogueStyle({
+    required String conversationId,
+    required DialogueStyle style,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          sse_encode_dialogue_style(style, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 170,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiSetDialogueStyleConstMeta,
+        argValues: [conversationId, style],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiSetDialogueStyleConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_dialogue_style",
+        argNames: ["conversationId", "style"],
+      );
+
+  @override
+  Future<void> crateApiChatApiSetFastDraft({required bool enabled}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(enabled, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 171,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiChatApiSetFastDraftConstMeta,
+        argValues: [enabled],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiSetFastDraftConstMeta =>
+      const TaskConstMeta(debugName: "set_fast_draft", argNames: ["enabled"]);
+
+  @override
+  Future<GroupChat?> crateApiChatApiSetGroupChat({
+    required String conversationId,
+    required List<GroupCharacter> characters,
+    required TurnPolicy turnPolicy,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          sse_encode_list_group_character(characters, serializer);
+          sse_encode_turn_policy(turnPolicy, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 172,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_group_chat,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiSetGroupChatConstMeta,
+        argValues: [conversationId, characters, turnPolicy],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiSetGroupChatConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_group_chat",
+        argNames: ["conversationId", "characters", "turnPolicy"],
+      );
+
+  @override
+  Future<void> crateApiChatApiSetKnowledgeBinding({
+    required String conversationId,
+    required KnowledgeBinding binding,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          sse_encode_box_autoadd_knowledge_binding(binding, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 173,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiChatApiSetKnowledgeBindingConstMeta,
+        argValues: [conversationId, binding],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiSetKnowledgeBindingConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_knowledge_binding",
+        argNames: ["conversationId", "binding"],
+      );
+
+  @override
+  Future<bool> crateApiChatApiSetMemoryFactTier({
+    required String conversationId,
+    required String summaryId,
+    required String fact,
+    required MemoryTier tier,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          sse_encode_String(summaryId, serializer);
+          sse_encode_String(fact, serializer);
+          sse_encode_memory_tier(tier, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 174,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiSetMemoryFactTierConstMeta,
+        argValues: [conversationId, summaryId, fact, tier],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiSetMemoryFactTierConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_memory_fact_tier",
+        argNames: ["conversationId", "summaryId", "fact", "tier"],
+      );
+
+  @override
+  Future<Message> crateApiChatApiSetMessageReactions({
+    required String conversationId,
+    required String messageId,
+    required List<String> reactions,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          sse_encode_String(messageId, serializer);
+          sse_encode_list_String(reactions, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 175,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_message,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiChatApiSetMessageReactionsConstMeta,
+        argValues: [conversationId, messageId, reactions],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiSetMessageReactionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_message_reactions",
+        argNames: ["conversationId", "messageId", "reactions"],
+      );
+
+  @override
+  Future<Message?> crateApiChatApiSetMessageStarred({
+    required String conversationId,
+    required String messageId,
+    required bool starred,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          sse_encode_String(messageId, serializer);
+          sse_encode_bool(starred, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 176,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_message,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiSetMessageStarredConstMeta,
+        argValues: [conversationId, messageId, starred],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiSetMessageStarredConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_message_starred",
+        argNames: ["conversationId", "messageId", "starred"],
+      );
+
+  @override
+  Future<bool> crateApiChatApiSetNarrationPerspective({
+    required String conversationId,
+    required NarrationPerspective perspective,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          sse_encode_narration_perspective(perspective, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 177,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiSetNarrationPerspectiveConstMeta,
+        argValues: [conversationId, perspective],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiSetNarrationPerspectiveConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_narration_perspective",
+        argNames: ["conversationId", "perspective"],
+      );
+
+  @override
+  Future<void> crateApiChatApiSetNetworkAvailable({required bool available}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(available, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 178,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiSetNetworkAvailableConstMeta,
+        argValues: [available],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiSetNetworkAvailableConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_network_available",
+        argNames: ["available"],
+      );
+
+  @override
+  Future<void> crateApiChatApiSetProxy({ProxySettings? proxy}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_box_autoadd_proxy_settings(proxy, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 179,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiChatApiSetProxyConstMeta,
+        argValues: [proxy],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiSetProxyConstMeta =>
+      const TaskConstMeta(debugName: "set_proxy", argNames: ["proxy"]);
+
+  @override
+  Future<bool> crateApiChatApiSetResponseStyle({
+    required String conversationId,
+    required ResponseStyle style,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          sse_encode_box_autoadd_response_style(style, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 180,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiSetResponseStyleConstMeta,
+        argValues: [conversationId, style],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiSetResponseStyleConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_response_style",
+        argNames: ["conversationId", "style"],
+      );
+
+  @override
+  Future<void> crateApiChatApiSetRetentionPolicy({
+    required RetentionPolicy policy,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_retention_policy(policy, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 181,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiChatApiSetRetentionPolicyConstMeta,
+        argValues: [policy],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiSetRetentionPolicyConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_retention_policy",
+        argNames: ["policy"],
+      );
+
+  @override
+  Future<void> crateApiChatApiSetRetryPolicy({required RetryPolicy policy}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_retry_policy(policy, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 182,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiChatApiSetRetryPolicyConstMeta,
+        argValues: [policy],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiSetRetryPolicyConstMeta =>
+      const TaskConstMeta(debugName: "set_retry_policy", argNames: ["policy"]);
+
+  @override
+  Future<void> crateApiChatApiSetSafetyPolicy({required SafetyPolicy policy}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_safety_policy(policy, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 183,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiChatApiSetSafetyPolicyConstMeta,
+        argValues: [policy],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiSetSafetyPolicyConstMeta =>
+      const TaskConstMeta(debugName: "set_safety_policy", argNames: ["policy"]);
+
+  @override
+  Future<void> crateApiChatApiSetSaydoRules({
+    required String conversationId,
+    required SayDoRuleSet rules,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          sse_encode_box_autoadd_say_do_rule_set(rules, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 184,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiChatApiSetSaydoRulesConstMeta,
+        argValues: [conversationId, rules],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiSetSaydoRulesConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_saydo_rules",
+        argNames: ["conversationId", "rules"],
+      );
+
+  @override
+  Future<void> crateApiChatApiSetSummarizationConfig({
+    required String conversationId,
+    SummarizationConfig? config,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          sse_encode_opt_box_autoadd_summarization_config(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 185,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiChatApiSetSummarizationConfigConstMeta,
+        argValues: [conversationId, config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiSetSummarizationConfigConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_summarization_config",
+        argNames: ["conversationId", "config"],
+      );
+
+  @override
+  Future<void> crateApiChatApiSetSyncSettings({
+    required SyncSettings settings,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_sync_settings(settings, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 186,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiChatApiSetSyncSettingsConstMeta,
+        argValues: [settings],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiSetSyncSettingsConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_sync_settings",
+        argNames: ["settings"],
+      );
+
+  @override
+  Future<bool> crateApiChatApiSetThinkingRetention({
+    required String conversationId,
+    required ThinkingRetention policy,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          sse_encode_box_autoadd_thinking_retention(policy, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 187,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiSetThinkingRetentionConstMeta,
+        argValues: [conversationId, policy],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiSetThinkingRetentionConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_thinking_retention",
+        argNames: ["conversationId", "policy"],
+      );
+
+  @override
+  Future<void> crateApiChatApiSetTimeAwareness({required bool enabled}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(enabled, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 188,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiChatApiSetTimeAwarenessConstMeta,
+        argValues: [enabled],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiSetTimeAwarenessConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_time_awareness",
+        argNames: ["enabled"],
+      );
+
+  @override
+  Future<ShadowEvalSummary> crateApiDataModelsShadowEvalSummaryDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 189,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_shadow_eval_summary,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDataModelsShadowEvalSummaryDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDataModelsShadowEvalSummaryDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "shadow_eval_summary_default",
+        argNames: [],
+      );
+
+  @override
+  Future<bool> crateApiChatApiShouldSummarizeMemory({
+    required String conversationId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 190,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiShouldSummarizeMemoryConstMeta,
+        argValues: [conversationId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiShouldSummarizeMemoryConstMeta =>
+      const TaskConstMeta(
+        debugName: "should_summarize_memory",
+        argNames: ["conversationId"],
+      );
+
+  @override
+  Future<SummarizationConfig> crateApiDataModelsSummarizationConfigDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 191,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_summarization_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDataModelsSummarizationConfigDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDataModelsSummarizationConfigDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "summarization_config_default",
+        argNames: [],
+      );
+
+  @override
+  Future<bool> crateApiChatApiSwitchBranch({
+    required String conversationId,
+    required String branchId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          sse_encode_String(branchId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 192,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiSwitchBranchConstMeta,
+        argValues: [conversationId, branchId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiSwitchBranchConstMeta =>
+      const TaskConstMeta(
+        debugName: "switch_branch",
+        argNames: ["conversationId", "branchId"],
+      );
+
+  @override
+  Future<SyncBackendKind> crateApiDataModelsSyncBackendKindDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 193,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_sync_backend_kind,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDataModelsSyncBackendKindDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDataModelsSyncBackendKindDefaultConstMeta =>
+      const TaskConstMeta(debugName: "sync_backend_kind_default", argNames: []);
+
+  @override
+  Future<SyncReport> crateApiChatApiSyncNow() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 194,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_sync_report,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiChatApiSyncNowConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiSyncNowConstMeta =>
+      const TaskConstMeta(debugName: "sync_now", argNames: []);
+
+  @override
+  Future<SyncReport> crateApiDataModelsSyncReportDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 195,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_sync_report,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDataModelsSyncReportDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDataModelsSyncReportDefaultConstMeta =>
+      const TaskConstMeta(debugName: "sync_report_default", argNames: []);
+
+  @override
+  Future<SyncSettings> crateApiDataModelsSyncSettingsDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 196,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_sync_settings,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDataModelsSyncSettingsDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDataModelsSyncSettingsDefaultConstMeta =>
+      const TaskConstMeta(debugName: "sync_settings_default", argNames: []);
+
+  @override
+  Future<ThinkingRetention> crateApiDataModelsThinkingRetentionDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 197,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_thinking_retention,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDataModelsThinkingRetentionDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDataModelsThinkingRetentionDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "thinking_retention_default",
+        argNames: [],
+      );
+
+  @override
+  Stream<ChatStreamEvent> crateApiChatApiTriggerMemorySummarize({
+    required String conversationId,
+  }) {
+    final sink = RustStreamSink<ChatStreamEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(conversationId, serializer);
+            sse_encode_StreamSink_chat_stream_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 198,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiChatApiTriggerMemorySummarizeConstMeta,
+          argValues: [conversationId, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiChatApiTriggerMemorySummarizeConstMeta =>
+      const TaskConstMeta(
+        debugName: "trigger_memory_summarize",
+        argNames: ["conversationId", "sink"],
+      );
+
+  @override
+  Future<TurnPolicy> crateApiDataModelsTurnPolicyDefault() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 199,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_turn_policy,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiDataModelsTurnPolicyDefaultConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiDataModelsTurnPolicyDefaultConstMeta =>
+      const TaskConstMeta(debugName: "turn_policy_default", argNames: []);
+
+  @override
+  Future<Message?> crateApiChatApiUndoMessageEdit({
+    required String conversationId,
+    required String messageId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          sse_encode_String(messageId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 200,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_message,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiUndoMessageEditConstMeta,
+        argValues: [conversationId, messageId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiUndoMessageEditConstMeta =>
+      const TaskConstMeta(
+        debugName: "undo_message_edit",
+        argNames: ["conversationId", "messageId"],
+      );
+
+  @override
+  Future<void> crateApiChatApiUnlockEncryption({
+    required EncryptionSecret secret,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_encryption_secret(secret, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 201,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiChatApiUnlockEncryptionConstMeta,
+        argValues: [secret],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiUnlockEncryptionConstMeta =>
+      const TaskConstMeta(debugName: "unlock_encryption", argNames: ["secret"]);
+
+  @override
+  Future<bool> crateApiChatApiUpdateFact({
+    String? conversationId,
+    required String factId,
+    required String content,
+    required FactCategory category,
+    required double confidence,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(conversationId, serializer);
+          sse_encode_String(factId, serializer);
+          sse_encode_String(content, serializer);
+          sse_encode_fact_category(category, serializer);
+          sse_encode_f_64(confidence, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 202,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiUpdateFactConstMeta,
+        argValues: [conversationId, factId, content, category, confidence],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiUpdateFactConstMeta => const TaskConstMeta(
+    debugName: "update_fact",
+    argNames: ["conversationId", "factId", "content", "category", "confidence"],
+  );
+
+  @override
+  Future<LayoutVerification?> crateApiChatApiUpgradeDataLayout() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 203,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_layout_verification,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiUpgradeDataLayoutConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiUpgradeDataLayoutConstMeta =>
+      const TaskConstMeta(debugName: "upgrade_data_layout", argNames: []);
+
+  @override
+  Future<bool> crateApiChatApiValidateApiKey({required String apiKey}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(apiKey, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 204,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiValidateApiKeyConstMeta,
+        argValues: [apiKey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiValidateApiKeyConstMeta =>
+      const TaskConstMeta(debugName: "validate_api_key", argNames: ["apiKey"]);
+
+  @override
+  Future<LayoutVerification?> crateApiChatApiVerifyDataLayout() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 205,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_layout_verification,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiVerifyDataLayoutConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiVerifyDataLayoutConstMeta =>
+      const TaskConstMeta(debugName: "verify_data_layout", argNames: []);
+
+  @override
+  Future<IntegrityReport?> crateApiChatApiVerifyIntegrity({
+    required String conversationId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(conversationId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 206,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_integrity_report,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiChatApiVerifyIntegrityConstMeta,
+        argValues: [conversationId],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiChatApiVerifyIntegrityConstMeta =>
+      const TaskConstMeta(
+        debugName: "verify_integrity",
+        argNames: ["conversationId"],
+      );
+
+  @override
+  Stream<BackgroundTaskEvent> crateApiChatApiWatchBackgroundTasks() {
+    final sink = RustStreamSink<BackgroundTaskEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_background_task_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 207,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiChatApiWatchBackgroundTasksConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiChatApiWatchBackgroundTasksConstMeta =>
+      const TaskConstMeta(
+        debugName: "watch_background_tasks",
+        argNames: ["sink"],
+      );
+
+  @override
+  Stream<ChatStreamEvent> crateApiChatApiWatchCheckIns() {
+    final sink = RustStreamSink<ChatStreamEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_chat_stream_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 208,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiChatApiWatchCheckInsConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiChatApiWatchCheckInsConstMeta =>
+      const TaskConstMeta(debugName: "watch_check_ins", argNames: ["sink"]);
+
+  @override
+  Stream<ChatStreamEvent> crateApiChatApiWatchOutbox() {
+    final sink = RustStreamSink<ChatStreamEvent>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_StreamSink_chat_stream_event_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 209,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: null,
+          ),
+          constMeta: kCrateApiChatApiWatchOutboxConstMeta,
+          argValues: [sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiChatApiWatchOutboxConstMeta =>
+      const TaskConstMeta(debugName: "watch_outbox", argNames: ["sink"]);
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  Map<String, String> dco_decode_Map_String_String_None(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Map.fromEntries(
+      dco_decode_list_record_string_string(
+        raw,
+      ).map((e) => MapEntry(e.$1, e.$2)),
+    );
+  }
+
+  @protected
+  RustStreamSink<BackgroundTaskEvent> dco_decode_StreamSink_background_task_event_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<ChatStreamEvent> dco_decode_StreamSink_chat_stream_event_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<ReindexProgress> dco_decode_StreamSink_reindex_progress_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  ActionFrequency dco_decode_action_frequency(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ActionFrequency.values[raw as int];
+  }
+
+  @protected
+  AmbientContext dco_decode_ambient_context(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return AmbientContext(
+      weather: dco_decode_opt_String(arr[0]),
+      stepCount: dco_decode_opt_box_autoadd_u_32(arr[1]),
+      nowPlaying: dco_decode_opt_String(arr[2]),
+      calendarBusy: dco_decode_opt_box_autoadd_bool(arr[3]),
+    );
+  }
+
+  @protected
+  AppSettings dco_decode_app_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 17)
+      throw Exception('unexpected arr length: expect 17 but see ${arr.length}');
+    return AppSettings(
+      apiKey: dco_decode_opt_String(arr[0]),
+      defaultModel: dco_decode_String(arr[1]),
+      enableThinkingByDefault: dco_decode_bool(arr[2]),
+      chatModel: dco_decode_String(arr[3]),
+      thinkingModel: dco_decode_String(arr[4]),
+      enableWebSearch: dco_decode_bool(arr[5]),
+      contentIntensity: dco_decode_content_intensity(arr[6]),
+      provider: dco_decode_provider_kind(arr[7]),
+      providerBaseUrl: dco_decode_opt_String(arr[8]),
+      providerModel: dco_decode_opt_String(arr[9]),
+      providerApiKey: dco_decode_opt_String(arr[10]),
+      shadowEvalRate: dco_decode_f_64(arr[11]),
+      enableTts: dco_decode_bool(arr[12]),
+      proxy: dco_decode_opt_box_autoadd_proxy_settings(arr[13]),
+      retry: dco_decode_retry_policy(arr[14]),
+      timeAwareness: dco_decode_bool(arr[15]),
+      fastDraft: dco_decode_bool(arr[16]),
+    );
+  }
+
+  @protected
+  AudioReadyEvent dco_decode_audio_ready_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return AudioReadyEvent(
+      messageId: dco_decode_String(arr[0]),
+      audioPath: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  BackgroundJobKind dco_decode_background_job_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return BackgroundJobKind.values[raw as int];
+  }
+
+  @protected
+  BackgroundTaskEvent dco_decode_background_task_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return BackgroundTaskEvent(
+      taskId: dco_decode_String(arr[0]),
+      kind: dco_decode_background_job_kind(arr[1]),
+      conversationId: dco_decode_String(arr[2]),
+      status: dco_decode_background_task_status(arr[3]),
+      updatedAt: dco_decode_i_64(arr[4]),
+    );
+  }
+
+  @protected
+  BackgroundTaskStatus dco_decode_background_task_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return BackgroundTaskStatus.values[raw as int];
+  }
+
+  @protected
+  BackoffStrategy dco_decode_backoff_strategy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return BackoffStrategy.values[raw as int];
+  }
+
+  @protected
+  BackupRetention dco_decode_backup_retention(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return BackupRetention(
+      keepLast: dco_decode_u_32(arr[0]),
+      keepDaily: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  BackupScope dco_decode_backup_scope(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return BackupScope.values[raw as int];
+  }
+
+  @protected
+  BackupSnapshotInfo dco_decode_backup_snapshot_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return BackupSnapshotInfo(
+      id: dco_decode_String(arr[0]),
+      createdAt: dco_decode_i_64(arr[1]),
+      fileCount: dco_decode_u_32(arr[2]),
+      totalBytes: dco_decode_u_64(arr[3]),
+      addedBytes: dco_decode_u_64(arr[4]),
+    );
+  }
+
+  @protected
+  BlockedTopic dco_decode_blocked_topic(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return BlockedTopic(
+      id: dco_decode_String(arr[0]),
+      topic: dco_decode_String(arr[1]),
+      keywords: dco_decode_list_String(arr[2]),
+      evidence: dco_decode_list_String(arr[3]),
+      occurrences: dco_decode_u_32(arr[4]),
+      explicit: dco_decode_bool(arr[5]),
+      active: dco_decode_bool(arr[6]),
+      firstSeen: dco_decode_i_64(arr[7]),
+      lastSeen: dco_decode_i_64(arr[8]),
+    );
+  }
+
+  @protected
+  BlockingStats dco_decode_blocking_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return BlockingStats(
+      tasks: dco_decode_u_64(arr[0]),
+      totalRunMs: dco_decode_u_64(arr[1]),
+      maxRunMs: dco_decode_u_64(arr[2]),
+      maxQueueMs: dco_decode_u_64(arr[3]),
+      slowestLabel: dco_decode_String(arr[4]),
+    );
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  AmbientContext dco_decode_box_autoadd_ambient_context(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_ambient_context(raw);
+  }
+
+  @protected
+  AppSettings dco_decode_box_autoadd_app_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_app_settings(raw);
+  }
+
+  @protected
+  BackupRetention dco_decode_box_autoadd_backup_retention(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_backup_retention(raw);
+  }
+
+  @protected
+  BackupSnapshotInfo dco_decode_box_autoadd_backup_snapshot_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_backup_snapshot_info(raw);
+  }
+
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  CharacterCard dco_decode_box_autoadd_character_card(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_character_card(raw);
+  }
+
+  @protected
+  CheckInSchedule dco_decode_box_autoadd_check_in_schedule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_check_in_schedule(raw);
+  }
+
+  @protected
+  CognitiveInsight dco_decode_box_autoadd_cognitive_insight(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_cognitive_insight(raw);
+  }
+
+  @protected
+  ContextLayers dco_decode_box_autoadd_context_layers(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_context_layers(raw);
+  }
+
+  @protected
+  Conversation dco_decode_box_autoadd_conversation(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_conversation(raw);
+  }
+
+  @protected
+  ConversationFilter dco_decode_box_autoadd_conversation_filter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_conversation_filter(raw);
+  }
+
+  @protected
+  ConversationInsights dco_decode_box_autoadd_conversation_insights(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_conversation_insights(raw);
+  }
+
+  @protected
+  ConversationOrganization dco_decode_box_autoadd_conversation_organization(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_conversation_organization(raw);
+  }
+
+  @protected
+  DailyDigest dco_decode_box_autoadd_daily_digest(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_daily_digest(raw);
+  }
+
+  @protected
+  DeviceConditions dco_decode_box_autoadd_device_conditions(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_device_conditions(raw);
+  }
+
+  @protected
+  DistilledSystemState dco_decode_box_autoadd_distilled_system_state(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_distilled_system_state(raw);
+  }
+
+  @protected
+  EncryptionKeySource dco_decode_box_autoadd_encryption_key_source(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_encryption_key_source(raw);
+  }
+
+  @protected
+  EncryptionSecret dco_decode_box_autoadd_encryption_secret(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_encryption_secret(raw);
+  }
+
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  Fact dco_decode_box_autoadd_fact(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_fact(raw);
+  }
+
+  @protected
+  GenerationMetadata dco_decode_box_autoadd_generation_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_generation_metadata(raw);
+  }
+
+  @protected
+  GroupChat dco_decode_box_autoadd_group_chat(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_group_chat(raw);
+  }
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_i_64(raw);
+  }
+
+  @protected
+  IntegrityReport dco_decode_box_autoadd_integrity_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_integrity_report(raw);
+  }
+
+  @protected
+  KnowledgeBinding dco_decode_box_autoadd_knowledge_binding(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_knowledge_binding(raw);
+  }
+
+  @protected
+  LayoutVerification dco_decode_box_autoadd_layout_verification(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_layout_verification(raw);
+  }
+
+  @protected
+  LoreEntry dco_decode_box_autoadd_lore_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_lore_entry(raw);
+  }
+
+  @protected
+  MemoryContextCard dco_decode_box_autoadd_memory_context_card(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_memory_context_card(raw);
+  }
+
+  @protected
+  MemoryExplanation dco_decode_box_autoadd_memory_explanation(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_memory_explanation(raw);
+  }
+
+  @protected
+  MemoryFidelityReport dco_decode_box_autoadd_memory_fidelity_report(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_memory_fidelity_report(raw);
+  }
+
+  @protected
+  MemorySummary dco_decode_box_autoadd_memory_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_memory_summary(raw);
+  }
+
+  @protected
+  Message dco_decode_box_autoadd_message(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_message(raw);
+  }
+
+  @protected
+  MoodState dco_decode_box_autoadd_mood_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_mood_state(raw);
+  }
+
+  @protected
+  PreflightAdvisory dco_decode_box_autoadd_preflight_advisory(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_preflight_advisory(raw);
+  }
+
+  @protected
+  ProxySettings dco_decode_box_autoadd_proxy_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_proxy_settings(raw);
+  }
+
+  @protected
+  ReengagementBrief dco_decode_box_autoadd_reengagement_brief(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_reengagement_brief(raw);
+  }
+
+  @protected
+  RelevanceInsight dco_decode_box_autoadd_relevance_insight(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_relevance_insight(raw);
+  }
+
+  @protected
+  ResponseStyle dco_decode_box_autoadd_response_style(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_response_style(raw);
+  }
+
+  @protected
+  RetentionPolicy dco_decode_box_autoadd_retention_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_retention_policy(raw);
+  }
+
+  @protected
+  RetryPolicy dco_decode_box_autoadd_retry_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_retry_policy(raw);
+  }
+
+  @protected
+  SafetyPolicy dco_decode_box_autoadd_safety_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_safety_policy(raw);
+  }
+
+  @protected
+  SayDoMark dco_decode_box_autoadd_say_do_mark(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_say_do_mark(raw);
+  }
+
+  @protected
+  SayDoRuleSet dco_decode_box_autoadd_say_do_rule_set(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_say_do_rule_set(raw);
+  }
+
+  @protected
+  SummarizationConfig dco_decode_box_autoadd_summarization_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_summarization_config(raw);
+  }
+
+  @protected
+  SyncSettings dco_decode_box_autoadd_sync_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_sync_settings(raw);
+  }
+
+  @protected
+  ThinkingRetention dco_decode_box_autoadd_thinking_retention(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_thinking_retention(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  UserPersona dco_decode_box_autoadd_user_persona(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_user_persona(raw);
+  }
+
+  @protected
+  BranchInfo dco_decode_branch_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return BranchInfo(
+      id: dco_decode_String(arr[0]),
+      name: dco_decode_String(arr[1]),
+      parentMessageId: dco_decode_opt_String(arr[2]),
+      messageCount: dco_decode_u_32(arr[3]),
+      lastMessagePreview: dco_decode_String(arr[4]),
+      createdAt: dco_decode_i_64(arr[5]),
+      isActive: dco_decode_bool(arr[6]),
+    );
+  }
+
+  @protected
+  CharacterCard dco_decode_character_card(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return CharacterCard(
+      name: dco_decode_String(arr[0]),
+      systemPrompt: dco_decode_String(arr[1]),
+      greeting: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  CharacterRelation dco_decode_character_relation(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return CharacterRelation(
+      from: dco_decode_String(arr[0]),
+      to: dco_decode_String(arr[1]),
+      relation: dco_decode_String(arr[2]),
+      sentiment: dco_decode_relation_sentiment(arr[3]),
+      factId: dco_decode_String(arr[4]),
+      sourceTurn: dco_decode_u_32(arr[5]),
+      history: dco_decode_list_relation_change(arr[6]),
+    );
+  }
+
+  @protected
+  ChatStreamEvent dco_decode_chat_stream_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return ChatStreamEvent_ContentDelta(dco_decode_String(raw[1]));
+      case 1:
+        return ChatStreamEvent_ThinkingDelta(dco_decode_String(raw[1]));
+      case 2:
+        return ChatStreamEvent_Done();
+      case 3:
+        return ChatStreamEvent_Error(dco_decode_String(raw[1]));
+      case 4:
+        return ChatStreamEvent_ConfigChanged();
+      case 5:
+        return ChatStreamEvent_Reaction(dco_decode_reaction_event(raw[1]));
+      case 6:
+        return ChatStreamEvent_SystemNotice(dco_decode_String(raw[1]));
+      case 7:
+        return ChatStreamEvent_Illustration(dco_decode_message(raw[1]));
+      case 8:
+        return ChatStreamEvent_AudioReady(dco_decode_audio_ready_event(raw[1]));
+      case 9:
+        return ChatStreamEvent_Completed(
+          dco_decode_generation_completed_event(raw[1]),
+        );
+      case 10:
+        return ChatStreamEvent_Outbox(dco_decode_outbox_entry(raw[1]));
+      case 11:
+        return ChatStreamEvent_Safety(dco_decode_safety_event(raw[1]));
+      case 12:
+        return ChatStreamEvent_CheckIn(dco_decode_String(raw[1]));
+      case 13:
+        return ChatStreamEvent_SummaryPreview(
+          dco_decode_memory_summary(raw[1]),
+        );
+      case 14:
+        return ChatStreamEvent_DraftDelta(dco_decode_String(raw[1]));
+      case 15:
+        return ChatStreamEvent_Refined(dco_decode_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  CheckInSchedule dco_decode_check_in_schedule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return CheckInSchedule(
+      conversationId: dco_decode_String(arr[0]),
+      enabled: dco_decode_bool(arr[1]),
+      silenceHours: dco_decode_u_32(arr[2]),
+      lastSentAt: dco_decode_opt_box_autoadd_i_64(arr[3]),
+    );
+  }
+
+  @protected
+  CognitiveInsight dco_decode_cognitive_insight(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return CognitiveInsight(
+      emotion: dco_decode_emotion_insight(arr[0]),
+      intent: dco_decode_intent_kind(arr[1]),
+      relationship: dco_decode_relationship_insight(arr[2]),
+      empathyStrategy: dco_decode_empathy_kind(arr[3]),
+      detectedPatterns: dco_decode_list_language_pattern_kind(arr[4]),
+      cognitivePrompt: dco_decode_String(arr[5]),
+    );
+  }
+
+  @protected
+  ContentIntensity dco_decode_content_intensity(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ContentIntensity.values[raw as int];
+  }
+
+  @protected
+  ContextLayers dco_decode_context_layers(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ContextLayers(
+      shortTermMemory: dco_decode_bool(arr[0]),
+      cognitiveSnapshot: dco_decode_bool(arr[1]),
+      diversityHint: dco_decode_bool(arr[2]),
+      humanizationHint: dco_decode_bool(arr[3]),
+      knowledgeInjection: dco_decode_bool(arr[4]),
+    );
+  }
+
+  @protected
+  Conversation dco_decode_conversation(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 21)
+      throw Exception('unexpected arr length: expect 21 but see ${arr.length}');
+    return Conversation(
+      id: dco_decode_String(arr[0]),
+      title: dco_decode_String(arr[1]),
+      messages: dco_decode_list_message(arr[2]),
+      model: dco_decode_String(arr[3]),
+      createdAt: dco_decode_i_64(arr[4]),
+      updatedAt: dco_decode_i_64(arr[5]),
+      dialogueStyle: dco_decode_dialogue_style(arr[6]),
+      turnCount: dco_decode_u_32(arr[7]),
+      memorySummaries: dco_decode_list_memory_summary(arr[8]),
+      mode: dco_decode_conversation_mode(arr[9]),
+      thinkingRetention: dco_decode_thinking_retention(arr[10]),
+      branchId: dco_decode_String(arr[11]),
+      parentMessageId: dco_decode_opt_String(arr[12]),
+      branches: dco_decode_list_conversation_branch(arr[13]),
+      narration: dco_decode_narration_perspective(arr[14]),
+      contextLayers: dco_decode_context_layers(arr[15]),
+      closedAt: dco_decode_opt_box_autoadd_i_64(arr[16]),
+      responseStyle: dco_decode_response_style(arr[17]),
+      personaId: dco_decode_opt_String(arr[18]),
+      organization: dco_decode_conversation_organization(arr[19]),
+      coldTurns: dco_decode_u_32(arr[20]),
+    );
+  }
+
+  @protected
+  ConversationBranch dco_decode_conversation_branch(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return ConversationBranch(
+      id: dco_decode_String(arr[0]),
+      name: dco_decode_String(arr[1]),
+      parentMessageId: dco_decode_opt_String(arr[2]),
+      messages: dco_decode_list_message(arr[3]),
+      turnCount: dco_decode_u_32(arr[4]),
+      memorySummaries: dco_decode_list_memory_summary(arr[5]),
+      createdAt: dco_decode_i_64(arr[6]),
+      coldTurns: dco_decode_u_32(arr[7]),
+    );
+  }
+
+  @protected
+  ConversationClosure dco_decode_conversation_closure(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ConversationClosure(
+      epilogue: dco_decode_String(arr[0]),
+      recap: dco_decode_String(arr[1]),
+      archivePath: dco_decode_String(arr[2]),
+      closedAt: dco_decode_i_64(arr[3]),
+    );
+  }
+
+  @protected
+  ConversationFilter dco_decode_conversation_filter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ConversationFilter(
+      folder: dco_decode_opt_String(arr[0]),
+      tags: dco_decode_list_String(arr[1]),
+      archived: dco_decode_bool(arr[2]),
+      query: dco_decode_String(arr[3]),
+    );
+  }
+
+  @protected
+  ConversationInsights dco_decode_conversation_insights(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ConversationInsights(
+      cognitive: dco_decode_opt_box_autoadd_cognitive_insight(arr[0]),
+      shortTerm: dco_decode_short_term_insight(arr[1]),
+    );
+  }
+
+  @protected
+  ConversationMode dco_decode_conversation_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ConversationMode.values[raw as int];
+  }
+
+  @protected
+  ConversationOrganization dco_decode_conversation_organization(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ConversationOrganization(
+      tags: dco_decode_list_String(arr[0]),
+      folder: dco_decode_opt_String(arr[1]),
+      pinned: dco_decode_bool(arr[2]),
+      archived: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  ConversationSummary dco_decode_conversation_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return ConversationSummary(
+      id: dco_decode_String(arr[0]),
+      title: dco_decode_String(arr[1]),
+      lastMessagePreview: dco_decode_String(arr[2]),
+      model: dco_decode_String(arr[3]),
+      updatedAt: dco_decode_i_64(arr[4]),
+      organization: dco_decode_conversation_organization(arr[5]),
+    );
+  }
+
+  @protected
+  DailyDigest dco_decode_daily_digest(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return DailyDigest(
+      generatedAt: dco_decode_i_64(arr[0]),
+      entries: dco_decode_list_digest_entry(arr[1]),
+      skipped: dco_decode_u_32(arr[2]),
+      estimatedTokens: dco_decode_u_32(arr[3]),
+    );
+  }
+
+  @protected
+  DataExportInfo dco_decode_data_export_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return DataExportInfo(
+      formatVersion: dco_decode_u_32(arr[0]),
+      layoutVersion: dco_decode_u_32(arr[1]),
+      createdAt: dco_decode_i_64(arr[2]),
+      fileCount: dco_decode_u_32(arr[3]),
+      totalBytes: dco_decode_u_64(arr[4]),
+    );
+  }
+
+  @protected
+  DeferredJob dco_decode_deferred_job(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return DeferredJob(
+      kind: dco_decode_background_job_kind(arr[0]),
+      conversationId: dco_decode_String(arr[1]),
+      deferredAt: dco_decode_i_64(arr[2]),
+      reason: dco_decode_String(arr[3]),
+    );
+  }
+
+  @protected
+  DegradationKind dco_decode_degradation_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DegradationKind.values[raw as int];
+  }
+
+  @protected
+  DegradationRecord dco_decode_degradation_record(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return DegradationRecord(
+      turn: dco_decode_u_32(arr[0]),
+      kind: dco_decode_degradation_kind(arr[1]),
+      model: dco_decode_String(arr[2]),
+      detail: dco_decode_String(arr[3]),
+      timestamp: dco_decode_i_64(arr[4]),
+    );
+  }
+
+  @protected
+  DeviceConditions dco_decode_device_conditions(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return DeviceConditions(
+      batteryLevel: dco_decode_opt_box_autoadd_f_64(arr[0]),
+      isCharging: dco_decode_bool(arr[1]),
+      lowPowerMode: dco_decode_bool(arr[2]),
+      meteredNetwork: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  DialogueStyle dco_decode_dialogue_style(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DialogueStyle.values[raw as int];
+  }
+
+  @protected
+  DigestEntry dco_decode_digest_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return DigestEntry(
+      conversationId: dco_decode_String(arr[0]),
+      title: dco_decode_String(arr[1]),
+      note: dco_decode_String(arr[2]),
+      lastActiveAt: dco_decode_i_64(arr[3]),
+    );
+  }
+
+  @protected
+  DistilledSystemState dco_decode_distilled_system_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return DistilledSystemState(
+      corePrompt: dco_decode_String(arr[0]),
+      lastMemoryCount: dco_decode_usize(arr[1]),
+      lastMaxCompressionGen: dco_decode_u_32(arr[2]),
+      characterPromptHash: dco_decode_u_64(arr[3]),
+      lastTurnCount: dco_decode_u_32(arr[4]),
+      distilledAt: dco_decode_i_64(arr[5]),
+      coreFactsSnapshot: dco_decode_list_String(arr[6]),
+    );
+  }
+
+  @protected
+  DraftTone dco_decode_draft_tone(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return DraftTone.values[raw as int];
+  }
+
+  @protected
+  EmojiUsage dco_decode_emoji_usage(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return EmojiUsage.values[raw as int];
+  }
+
+  @protected
+  EmotionDimension dco_decode_emotion_dimension(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return EmotionDimension.values[raw as int];
+  }
+
+  @protected
+  EmotionInsight dco_decode_emotion_insight(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return EmotionInsight(
+      joy: dco_decode_f_64(arr[0]),
+      sadness: dco_decode_f_64(arr[1]),
+      anger: dco_decode_f_64(arr[2]),
+      fear: dco_decode_f_64(arr[3]),
+      surprise: dco_decode_f_64(arr[4]),
+      intimacy: dco_decode_f_64(arr[5]),
+      trust: dco_decode_f_64(arr[6]),
+      anticipation: dco_decode_f_64(arr[7]),
+      valence: dco_decode_f_64(arr[8]),
+      arousal: dco_decode_f_64(arr[9]),
+    );
+  }
+
+  @protected
+  EmotionalTurnInsight dco_decode_emotional_turn_insight(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return EmotionalTurnInsight(
+      turn: dco_decode_u_32(arr[0]),
+      valence: dco_decode_f_64(arr[1]),
+      arousal: dco_decode_f_64(arr[2]),
+      dominantEmotion: dco_decode_String(arr[3]),
+    );
+  }
+
+  @protected
+  EmpathyKind dco_decode_empathy_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return EmpathyKind.values[raw as int];
+  }
+
+  @protected
+  EncryptionKeySource dco_decode_encryption_key_source(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return EncryptionKeySource.values[raw as int];
+  }
+
+  @protected
+  EncryptionSecret dco_decode_encryption_secret(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return EncryptionSecret_Passphrase(dco_decode_String(raw[1]));
+      case 1:
+        return EncryptionSecret_Keystore(
+          dco_decode_list_prim_u_8_strict(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  EncryptionStatus dco_decode_encryption_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return EncryptionStatus(
+      enabled: dco_decode_bool(arr[0]),
+      unlocked: dco_decode_bool(arr[1]),
+      source: dco_decode_opt_box_autoadd_encryption_key_source(arr[2]),
+    );
+  }
+
+  @protected
+  double dco_decode_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  Fact dco_decode_fact(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 16)
+      throw Exception('unexpected arr length: expect 16 but see ${arr.length}');
+    return Fact(
+      id: dco_decode_String(arr[0]),
+      content: dco_decode_String(arr[1]),
+      category: dco_decode_fact_category(arr[2]),
+      sourceTurn: dco_decode_u_32(arr[3]),
+      createdAt: dco_decode_i_64(arr[4]),
+      lastConfirmedAt: dco_decode_i_64(arr[5]),
+      keywords: dco_decode_list_String(arr[6]),
+      entities: dco_decode_list_String(arr[7]),
+      confidence: dco_decode_f_64(arr[8]),
+      hitCount: dco_decode_u_32(arr[9]),
+      contextSnippet: dco_decode_String(arr[10]),
+      summaryIds: dco_decode_list_String(arr[11]),
+      supersededBy: dco_decode_opt_String(arr[12]),
+      supersededAt: dco_decode_opt_box_autoadd_i_64(arr[13]),
+      manualOverride: dco_decode_bool(arr[14]),
+      personaId: dco_decode_opt_String(arr[15]),
+    );
+  }
+
+  @protected
+  FactCategory dco_decode_fact_category(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return FactCategory.values[raw as int];
+  }
+
+  @protected
+  FactConflict dco_decode_fact_conflict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return FactConflict(
+      supersededFactId: dco_decode_String(arr[0]),
+      supersededContent: dco_decode_String(arr[1]),
+      activeFactId: dco_decode_String(arr[2]),
+      activeContent: dco_decode_String(arr[3]),
+      detectedAt: dco_decode_i_64(arr[4]),
+    );
+  }
+
+  @protected
+  FingerprintInsight dco_decode_fingerprint_insight(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return FingerprintInsight(
+      openingChars: dco_decode_String(arr[0]),
+      paragraphCount: dco_decode_u_32(arr[1]),
+      avgSentenceLen: dco_decode_f_64(arr[2]),
+      endingChars: dco_decode_String(arr[3]),
+      endsWithQuestion: dco_decode_bool(arr[4]),
+      totalLength: dco_decode_u_32(arr[5]),
+      hasActionMarker: dco_decode_bool(arr[6]),
+      hasListFormat: dco_decode_bool(arr[7]),
+      emotionalTone: dco_decode_String(arr[8]),
+    );
+  }
+
+  @protected
+  Formality dco_decode_formality(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Formality.values[raw as int];
+  }
+
+  @protected
+  GenerationCompletedEvent dco_decode_generation_completed_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return GenerationCompletedEvent(
+      messageId: dco_decode_String(arr[0]),
+      metadata: dco_decode_generation_metadata(arr[1]),
+    );
+  }
+
+  @protected
+  GenerationMetadata dco_decode_generation_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 10)
+      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
+    return GenerationMetadata(
+      model: dco_decode_String(arr[0]),
+      finishReason: dco_decode_opt_String(arr[1]),
+      promptTokens: dco_decode_u_32(arr[2]),
+      completionTokens: dco_decode_u_32(arr[3]),
+      totalTokens: dco_decode_u_32(arr[4]),
+      cachedTokens: dco_decode_u_32(arr[5]),
+      streamed: dco_decode_bool(arr[6]),
+      interrupted: dco_decode_bool(arr[7]),
+      fallbacks: dco_decode_list_String(arr[8]),
+      latencyMs: dco_decode_i_64(arr[9]),
+    );
+  }
+
+  @protected
+  GraphEdge dco_decode_graph_edge(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return GraphEdge(
+      factId: dco_decode_String(arr[0]),
+      source: dco_decode_String(arr[1]),
+      target: dco_decode_String(arr[2]),
+      relation: dco_decode_String(arr[3]),
+      category: dco_decode_String(arr[4]),
+    );
+  }
+
+  @protected
+  GraphExportFormat dco_decode_graph_export_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return GraphExportFormat.values[raw as int];
+  }
+
+  @protected
+  GraphNode dco_decode_graph_node(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return GraphNode(
+      id: dco_decode_String(arr[0]),
+      label: dco_decode_String(arr[1]),
+      degree: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  GroupCharacter dco_decode_group_character(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return GroupCharacter(
+      id: dco_decode_String(arr[0]),
+      name: dco_decode_String(arr[1]),
+      systemPrompt: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  GroupChat dco_decode_group_chat(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return GroupChat(
+      conversationId: dco_decode_String(arr[0]),
+      characters: dco_decode_list_group_character(arr[1]),
+      turnPolicy: dco_decode_turn_policy(arr[2]),
+    );
+  }
+
+  @protected
+  HousekeepingReport dco_decode_housekeeping_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return HousekeepingReport(
+      archived: dco_decode_list_String(arr[0]),
+      rolledOverMessages: dco_decode_u_32(arr[1]),
+      purgedFiles: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64(raw);
+  }
+
+  @protected
+  IndexScope dco_decode_index_scope(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return IndexScope.values[raw as int];
+  }
+
+  @protected
+  IntegrityIssue dco_decode_integrity_issue(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return IntegrityIssue(
+      kind: dco_decode_integrity_issue_kind(arr[0]),
+      detail: dco_decode_String(arr[1]),
+      repaired: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  IntegrityIssueKind dco_decode_integrity_issue_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return IntegrityIssueKind.values[raw as int];
+  }
+
+  @protected
+  IntegrityReport dco_decode_integrity_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return IntegrityReport(
+      conversationId: dco_decode_String(arr[0]),
+      actualTurns: dco_decode_u_32(arr[1]),
+      recordedTurnCount: dco_decode_u_32(arr[2]),
+      issues: dco_decode_list_integrity_issue(arr[3]),
+      checkedAt: dco_decode_i_64(arr[4]),
+    );
+  }
+
+  @protected
+  IntentKind dco_decode_intent_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return IntentKind.values[raw as int];
+  }
+
+  @protected
+  JobSchedulerStats dco_decode_job_scheduler_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return JobSchedulerStats(
+      running: dco_decode_u_32(arr[0]),
+      completed: dco_decode_u_64(arr[1]),
+      timedOut: dco_decode_u_64(arr[2]),
+      deferredTotal: dco_decode_u_64(arr[3]),
+      pending: dco_decode_list_deferred_job(arr[4]),
+    );
+  }
+
+  @protected
+  KnowledgeBinding dco_decode_knowledge_binding(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return KnowledgeBinding(
+      characterId: dco_decode_opt_String(arr[0]),
+      characterName: dco_decode_opt_String(arr[1]),
+      worldId: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  KnowledgeGraphView dco_decode_knowledge_graph_view(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return KnowledgeGraphView(
+      nodes: dco_decode_list_graph_node(arr[0]),
+      edges: dco_decode_list_graph_edge(arr[1]),
+    );
+  }
+
+  @protected
+  KnowledgeLayer dco_decode_knowledge_layer(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return KnowledgeLayer.values[raw as int];
+  }
+
+  @protected
+  LanguagePatternKind dco_decode_language_pattern_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return LanguagePatternKind.values[raw as int];
+  }
+
+  @protected
+  LayoutVerification dco_decode_layout_verification(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return LayoutVerification(
+      version: dco_decode_u_32(arr[0]),
+      checkedFiles: dco_decode_u_32(arr[1]),
+      missing: dco_decode_list_String(arr[2]),
+      corrupted: dco_decode_list_String(arr[3]),
+      unexpected: dco_decode_list_String(arr[4]),
+    );
+  }
+
+  @protected
+  LexiconEntry dco_decode_lexicon_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return LexiconEntry(
+      word: dco_decode_String(arr[0]),
+      dimension: dco_decode_emotion_dimension(arr[1]),
+      weight: dco_decode_f_64(arr[2]),
+    );
+  }
+
+  @protected
+  LinkedFact dco_decode_linked_fact(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return LinkedFact(
+      factId: dco_decode_String(arr[0]),
+      content: dco_decode_String(arr[1]),
+      sourceTurn: dco_decode_u_32(arr[2]),
+      summaryIds: dco_decode_list_String(arr[3]),
+    );
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<BackgroundTaskEvent> dco_decode_list_background_task_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_background_task_event)
+        .toList();
+  }
+
+  @protected
+  List<BackupSnapshotInfo> dco_decode_list_backup_snapshot_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_backup_snapshot_info).toList();
+  }
+
+  @protected
+  List<BlockedTopic> dco_decode_list_blocked_topic(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_blocked_topic).toList();
+  }
+
+  @protected
+  List<BranchInfo> dco_decode_list_branch_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_branch_info).toList();
+  }
+
+  @protected
+  List<CharacterRelation> dco_decode_list_character_relation(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_character_relation).toList();
+  }
+
+  @protected
+  List<ConversationBranch> dco_decode_list_conversation_branch(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_conversation_branch).toList();
+  }
+
+  @protected
+  List<ConversationSummary> dco_decode_list_conversation_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_conversation_summary).toList();
+  }
+
+  @protected
+  List<DeferredJob> dco_decode_list_deferred_job(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_deferred_job).toList();
+  }
+
+  @protected
+  List<DegradationRecord> dco_decode_list_degradation_record(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_degradation_record).toList();
+  }
+
+  @protected
+  List<DigestEntry> dco_decode_list_digest_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_digest_entry).toList();
+  }
+
+  @protected
+  List<EmotionalTurnInsight> dco_decode_list_emotional_turn_insight(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_emotional_turn_insight)
+        .toList();
+  }
+
+  @protected
+  List<Fact> dco_decode_list_fact(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_fact).toList();
+  }
+
+  @protected
+  List<FactConflict> dco_decode_list_fact_conflict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_fact_conflict).toList();
+  }
+
+  @protected
+  List<FingerprintInsight> dco_decode_list_fingerprint_insight(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_fingerprint_insight).toList();
+  }
+
+  @protected
+  List<GraphEdge> dco_decode_list_graph_edge(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_graph_edge).toList();
+  }
+
+  @protected
+  List<GraphNode> dco_decode_list_graph_node(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_graph_node).toList();
+  }
+
+  @protected
+  List<GroupCharacter> dco_decode_list_group_character(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_group_character).toList();
+  }
+
+  @protected
+  List<IntegrityIssue> dco_decode_list_integrity_issue(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_integrity_issue).toList();
+  }
+
+  @protected
+  List<LanguagePatternKind> dco_decode_list_language_pattern_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_language_pattern_kind)
+        .toList();
+  }
+
+  @protected
+  List<LexiconEntry> dco_decode_list_lexicon_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_lexicon_entry).toList();
+  }
+
+  @protected
+  List<LinkedFact> dco_decode_list_linked_fact(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_linked_fact).toList();
+  }
+
+  @protected
+  List<LoreEntry> dco_decode_list_lore_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_lore_entry).toList();
+  }
+
+  @protected
+  List<MemoryFidelityReport> dco_decode_list_memory_fidelity_report(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_memory_fidelity_report)
+        .toList();
+  }
+
+  @protected
+  List<MemorySearchResult> dco_decode_list_memory_search_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_memory_search_result).toList();
+  }
+
+  @protected
+  List<MemorySummary> dco_decode_list_memory_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_memory_summary).toList();
+  }
+
+  @protected
+  List<MemoryTier> dco_decode_list_memory_tier(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_memory_tier).toList();
+  }
+
+  @protected
+  List<Message> dco_decode_list_message(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_message).toList();
+  }
+
+  @protected
+  List<MessageAlternative> dco_decode_list_message_alternative(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_message_alternative).toList();
+  }
+
+  @protected
+  List<MessageAttachment> dco_decode_list_message_attachment(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_message_attachment).toList();
+  }
+
+  @protected
+  List<MessageEdit> dco_decode_list_message_edit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_message_edit).toList();
+  }
+
+  @protected
+  List<ModelInfo> dco_decode_list_model_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_model_info).toList();
+  }
+
+  @protected
+  List<OutboxEntry> dco_decode_list_outbox_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_outbox_entry).toList();
+  }
+
+  @protected
+  List<PhaseDuration> dco_decode_list_phase_duration(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_phase_duration).toList();
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<PromptTemplateInfo> dco_decode_list_prompt_template_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_prompt_template_info).toList();
+  }
+
+  @protected
+  List<(String, String)> dco_decode_list_record_string_string(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_record_string_string).toList();
+  }
+
+  @protected
+  List<RelationChange> dco_decode_list_relation_change(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_relation_change).toList();
+  }
+
+  @protected
+  List<SafetyRule> dco_decode_list_safety_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_safety_rule).toList();
+  }
+
+  @protected
+  List<SayDoRule> dco_decode_list_say_do_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_say_do_rule).toList();
+  }
+
+  @protected
+  List<SearchHit> dco_decode_list_search_hit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_search_hit).toList();
+  }
+
+  @protected
+  List<ShadowEvalRecord> dco_decode_list_shadow_eval_record(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_shadow_eval_record).toList();
+  }
+
+  @protected
+  List<TimelineEntry> dco_decode_list_timeline_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_timeline_entry).toList();
+  }
+
+  @protected
+  List<TurnMetrics> dco_decode_list_turn_metrics(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_turn_metrics).toList();
+  }
+
+  @protected
+  List<UserPersona> dco_decode_list_user_persona(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_user_persona).toList();
+  }
+
+  @protected
+  LoreEntry dco_decode_lore_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return LoreEntry(
+      id: dco_decode_String(arr[0]),
+      title: dco_decode_String(arr[1]),
+      content: dco_decode_String(arr[2]),
+      keywords: dco_decode_list_String(arr[3]),
+      insertionDepth: dco_decode_u_32(arr[4]),
+      priority: dco_decode_i_32(arr[5]),
+      enabled: dco_decode_bool(arr[6]),
+    );
+  }
+
+  @protected
+  MemoryContextCard dco_decode_memory_context_card(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return MemoryContextCard(
+      sourceRange: dco_decode_String(arr[0]),
+      topicTags: dco_decode_list_String(arr[1]),
+      keyEntities: dco_decode_list_String(arr[2]),
+      emotionalTone: dco_decode_String(arr[3]),
+      causalLinks: dco_decode_list_String(arr[4]),
+    );
+  }
+
+  @protected
+  MemoryExplanation dco_decode_memory_explanation(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return MemoryExplanation(
+      summaryId: dco_decode_String(arr[0]),
+      summary: dco_decode_String(arr[1]),
+      turnRangeStart: dco_decode_u_32(arr[2]),
+      turnRangeEnd: dco_decode_u_32(arr[3]),
+      compressionGeneration: dco_decode_u_32(arr[4]),
+      linkedFacts: dco_decode_list_linked_fact(arr[5]),
+    );
+  }
+
+  @protected
+  MemoryFidelityReport dco_decode_memory_fidelity_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return MemoryFidelityReport(
+      conversationId: dco_decode_String(arr[0]),
+      score: dco_decode_f_64(arr[1]),
+      sampledFacts: dco_decode_u_32(arr[2]),
+      lostFacts: dco_decode_list_String(arr[3]),
+      previousScore: dco_decode_opt_box_autoadd_f_64(arr[4]),
+      alert: dco_decode_bool(arr[5]),
+      auditedAt: dco_decode_i_64(arr[6]),
+    );
+  }
+
+  @protected
+  MemorySearchResult dco_decode_memory_search_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return MemorySearchResult(
+      summary: dco_decode_String(arr[0]),
+      coreFacts: dco_decode_list_String(arr[1]),
+      relevanceScore: dco_decode_f_64(arr[2]),
+    );
+  }
+
+  @protected
+  MemorySummary dco_decode_memory_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 14)
+      throw Exception('unexpected arr length: expect 14 but see ${arr.length}');
+    return MemorySummary(
+      id: dco_decode_String(arr[0]),
+      summary: dco_decode_String(arr[1]),
+      coreFacts: dco_decode_list_String(arr[2]),
+      turnRangeStart: dco_decode_u_32(arr[3]),
+      turnRangeEnd: dco_decode_u_32(arr[4]),
+      createdAt: dco_decode_i_64(arr[5]),
+      keywords: dco_decode_list_String(arr[6]),
+      compressionGeneration: dco_decode_u_32(arr[7]),
+      contextCard: dco_decode_opt_box_autoadd_memory_context_card(arr[8]),
+      factTiers: dco_decode_list_memory_tier(arr[9]),
+      linkedFactIds: dco_decode_list_String(arr[10]),
+      pinned: dco_decode_bool(arr[11]),
+      pinnedFacts: dco_decode_list_String(arr[12]),
+      stale: dco_decode_bool(arr[13]),
+    );
+  }
+
+  @protected
+  MemoryTier dco_decode_memory_tier(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MemoryTier.values[raw as int];
+  }
+
+  @protected
+  Message dco_decode_message(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 16)
+      throw Exception('unexpected arr length: expect 16 but see ${arr.length}');
+    return Message(
+      id: dco_decode_String(arr[0]),
+      role: dco_decode_message_role(arr[1]),
+      content: dco_decode_String(arr[2]),
+      thinkingContent: dco_decode_opt_String(arr[3]),
+      model: dco_decode_String(arr[4]),
+      timestamp: dco_decode_i_64(arr[5]),
+      messageType: dco_decode_message_type(arr[6]),
+      generationMetadata: dco_decode_opt_box_autoadd_generation_metadata(
+        arr[7],
+      ),
+      characterId: dco_decode_opt_String(arr[8]),
+      attachments: dco_decode_list_message_attachment(arr[9]),
+      audioPath: dco_decode_opt_String(arr[10]),
+      alternatives: dco_decode_list_message_alternative(arr[11]),
+      selectedAlternative: dco_decode_u_32(arr[12]),
+      starred: dco_decode_bool(arr[13]),
+      reactions: dco_decode_list_String(arr[14]),
+      editHistory: dco_decode_list_message_edit(arr[15]),
+    );
+  }
+
+  @protected
+  MessageAlternative dco_decode_message_alternative(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return MessageAlternative(
+      content: dco_decode_String(arr[0]),
+      thinkingContent: dco_decode_opt_String(arr[1]),
+      model: dco_decode_String(arr[2]),
+      timestamp: dco_decode_i_64(arr[3]),
+      generationMetadata: dco_decode_opt_box_autoadd_generation_metadata(
+        arr[4],
+      ),
+      audioPath: dco_decode_opt_String(arr[5]),
+    );
+  }
+
+  @protected
+  MessageAttachment dco_decode_message_attachment(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return MessageAttachment(
+      id: dco_decode_String(arr[0]),
+      mimeType: dco_decode_String(arr[1]),
+      url: dco_decode_opt_String(arr[2]),
+      caption: dco_decode_opt_String(arr[3]),
+    );
+  }
+
+  @protected
+  MessageEdit dco_decode_message_edit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return MessageEdit(
+      content: dco_decode_String(arr[0]),
+      timestamp: dco_decode_i_64(arr[1]),
+      editedAt: dco_decode_i_64(arr[2]),
+    );
+  }
+
+  @protected
+  MessageRole dco_decode_message_role(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MessageRole.values[raw as int];
+  }
+
+  @protected
+  MessageType dco_decode_message_type(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MessageType.values[raw as int];
+  }
+
+  @protected
+  MetricPhase dco_decode_metric_phase(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return MetricPhase.values[raw as int];
+  }
+
+  @protected
+  MetricsSnapshot dco_decode_metrics_snapshot(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return MetricsSnapshot(
+      turns: dco_decode_list_turn_metrics(arr[0]),
+      avgFirstTokenMs: dco_decode_opt_box_autoadd_i_64(arr[1]),
+      avgPhaseMs: dco_decode_list_phase_duration(arr[2]),
+      totalRetries: dco_decode_u_32(arr[3]),
+    );
+  }
+
+  @protected
+  ModelInfo dco_decode_model_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ModelInfo(
+      id: dco_decode_String(arr[0]),
+      name: dco_decode_String(arr[1]),
+      contextTokens: dco_decode_usize(arr[2]),
+      maxOutputTokens: dco_decode_usize(arr[3]),
+      supportsThinking: dco_decode_bool(arr[4]),
+    );
+  }
+
+  @protected
+  MoodState dco_decode_mood_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return MoodState(
+      valence: dco_decode_f_64(arr[0]),
+      arousal: dco_decode_f_64(arr[1]),
+      mood: dco_decode_String(arr[2]),
+      updatedAt: dco_decode_i_64(arr[3]),
+    );
+  }
+
+  @protected
+  NarrationPerspective dco_decode_narration_perspective(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return NarrationPerspective.values[raw as int];
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  AmbientContext? dco_decode_opt_box_autoadd_ambient_context(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_ambient_context(raw);
+  }
+
+  @protected
+  BackupSnapshotInfo? dco_decode_opt_box_autoadd_backup_snapshot_info(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_backup_snapshot_info(raw);
+  }
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_bool(raw);
+  }
+
+  @protected
+  CheckInSchedule? dco_decode_opt_box_autoadd_check_in_schedule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_check_in_schedule(raw);
+  }
+
+  @protected
+  CognitiveInsight? dco_decode_opt_box_autoadd_cognitive_insight(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_cognitive_insight(raw);
+  }
+
+  @protected
+  Conversation? dco_decode_opt_box_autoadd_conversation(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_conversation(raw);
+  }
+
+  @protected
+  ConversationInsights? dco_decode_opt_box_autoadd_conversation_insights(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_conversation_insights(raw);
+  }
+
+  @protected
+  ConversationOrganization? dco_decode_opt_box_autoadd_conversation_organization(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_conversation_organization(raw);
+  }
+
+  @protected
+  DailyDigest? dco_decode_opt_box_autoadd_daily_digest(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_daily_digest(raw);
+  }
+
+  @protected
+  DistilledSystemState? dco_decode_opt_box_autoadd_distilled_system_state(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_distilled_system_state(raw);
+  }
+
+  @protected
+  EncryptionKeySource? dco_decode_opt_box_autoadd_encryption_key_source(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_encryption_key_source(raw);
+  }
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_f_64(raw);
+  }
+
+  @protected
+  Fact? dco_decode_opt_box_autoadd_fact(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_fact(raw);
+  }
+
+  @protected
+  GenerationMetadata? dco_decode_opt_box_autoadd_generation_metadata(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_generation_metadata(raw);
+  }
+
+  @protected
+  GroupChat? dco_decode_opt_box_autoadd_group_chat(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_group_chat(raw);
+  }
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_i_64(raw);
+  }
+
+  @protected
+  IntegrityReport? dco_decode_opt_box_autoadd_integrity_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_integrity_report(raw);
+  }
+
+  @protected
+  LayoutVerification? dco_decode_opt_box_autoadd_layout_verification(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_layout_verification(raw);
+  }
+
+  @protected
+  LoreEntry? dco_decode_opt_box_autoadd_lore_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_lore_entry(raw);
+  }
+
+  @protected
+  MemoryContextCard? dco_decode_opt_box_autoadd_memory_context_card(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_memory_context_card(raw);
+  }
+
+  @protected
+  MemoryExplanation? dco_decode_opt_box_autoadd_memory_explanation(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_memory_explanation(raw);
+  }
+
+  @protected
+  MemoryFidelityReport? dco_decode_opt_box_autoadd_memory_fidelity_report(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_memory_fidelity_report(raw);
+  }
+
+  @protected
+  MemorySummary? dco_decode_opt_box_autoadd_memory_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_memory_summary(raw);
+  }
+
+  @protected
+  Message? dco_decode_opt_box_autoadd_message(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_message(raw);
+  }
+
+  @protected
+  MoodState? dco_decode_opt_box_autoadd_mood_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_mood_state(raw);
+  }
+
+  @protected
+  PreflightAdvisory? dco_decode_opt_box_autoadd_preflight_advisory(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_preflight_advisory(raw);
+  }
+
+  @protected
+  ProxySettings? dco_decode_opt_box_autoadd_proxy_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_proxy_settings(raw);
+  }
+
+  @protected
+  ReengagementBrief? dco_decode_opt_box_autoadd_reengagement_brief(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_reengagement_brief(raw);
+  }
+
+  @protected
+  RelevanceInsight? dco_decode_opt_box_autoadd_relevance_insight(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_relevance_insight(raw);
+  }
+
+  @protected
+  SayDoMark? dco_decode_opt_box_autoadd_say_do_mark(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_say_do_mark(raw);
+  }
+
+  @protected
+  SummarizationConfig? dco_decode_opt_box_autoadd_summarization_config(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_summarization_config(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  OutboxEntry dco_decode_outbox_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return OutboxEntry(
+      id: dco_decode_String(arr[0]),
+      conversationId: dco_decode_String(arr[1]),
+      content: dco_decode_String(arr[2]),
+      attachments: dco_decode_list_message_attachment(arr[3]),
+      model: dco_decode_String(arr[4]),
+      enableThinking: dco_decode_bool(arr[5]),
+      status: dco_decode_outbox_status(arr[6]),
+      queuedAt: dco_decode_i_64(arr[7]),
+      lastError: dco_decode_opt_String(arr[8]),
+    );
+  }
+
+  @protected
+  OutboxStatus dco_decode_outbox_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return OutboxStatus.values[raw as int];
+  }
+
+  @protected
+  PhaseDuration dco_decode_phase_duration(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return PhaseDuration(
+      phase: dco_decode_metric_phase(arr[0]),
+      durationMs: dco_decode_i_64(arr[1]),
+    );
+  }
+
+  @protected
+  PreflightAdvisory dco_decode_preflight_advisory(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return PreflightAdvisory(
+      tone: dco_decode_draft_tone(arr[0]),
+      regretRisk: dco_decode_f_64(arr[1]),
+      tensionDelta: dco_decode_f_64(arr[2]),
+      closenessDelta: dco_decode_f_64(arr[3]),
+      lateNight: dco_decode_bool(arr[4]),
+      suggestPause: dco_decode_bool(arr[5]),
+      notes: dco_decode_list_String(arr[6]),
+    );
+  }
+
+  @protected
+  PromptTemplateInfo dco_decode_prompt_template_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return PromptTemplateInfo(
+      name: dco_decode_String(arr[0]),
+      description: dco_decode_String(arr[1]),
+      variables: dco_decode_list_String(arr[2]),
+      customized: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  ProviderKind dco_decode_provider_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ProviderKind.values[raw as int];
+  }
+
+  @protected
+  ProxySettings dco_decode_proxy_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ProxySettings(
+      url: dco_decode_String(arr[0]),
+      username: dco_decode_opt_String(arr[1]),
+      password: dco_decode_opt_String(arr[2]),
+      bypass: dco_decode_list_String(arr[3]),
+    );
+  }
+
+  @protected
+  ReactionEvent dco_decode_reaction_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ReactionEvent(
+      emoji: dco_decode_String(arr[0]),
+      label: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  (String, String) dco_decode_record_string_string(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (dco_decode_String(arr[0]), dco_decode_String(arr[1]));
+  }
+
+  @protected
+  ReengagementBrief dco_decode_reengagement_brief(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return ReengagementBrief(
+      conversationId: dco_decode_String(arr[0]),
+      elapsedDays: dco_decode_u_32(arr[1]),
+      recap: dco_decode_String(arr[2]),
+      opener: dco_decode_String(arr[3]),
+      pendingThreads: dco_decode_list_String(arr[4]),
+      generatedAt: dco_decode_i_64(arr[5]),
+      lastMessageId: dco_decode_String(arr[6]),
+    );
+  }
+
+  @protected
+  ReindexProgress dco_decode_reindex_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ReindexProgress(
+      conversationId: dco_decode_String(arr[0]),
+      completed: dco_decode_u_32(arr[1]),
+      total: dco_decode_u_32(arr[2]),
+      rebuiltEntries: dco_decode_u_32(arr[3]),
+      error: dco_decode_opt_String(arr[4]),
+    );
+  }
+
+  @protected
+  RelationChange dco_decode_relation_change(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return RelationChange(
+      relation: dco_decode_String(arr[0]),
+      factId: dco_decode_String(arr[1]),
+      sourceTurn: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  RelationSentiment dco_decode_relation_sentiment(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return RelationSentiment.values[raw as int];
+  }
+
+  @protected
+  RelationshipInsight dco_decode_relationship_insight(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return RelationshipInsight(
+      closeness: dco_decode_f_64(arr[0]),
+      trustLevel: dco_decode_f_64(arr[1]),
+      tension: dco_decode_f_64(arr[2]),
+      powerBalance: dco_decode_f_64(arr[3]),
+      trend: dco_decode_f_64(arr[4]),
+    );
+  }
+
+  @protected
+  RelevanceInsight dco_decode_relevance_insight(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return RelevanceInsight(
+      tfidfScore: dco_decode_f_64(arr[0]),
+      keywordOverlap: dco_decode_f_64(arr[1]),
+      topicMatch: dco_decode_f_64(arr[2]),
+      finalScore: dco_decode_f_64(arr[3]),
+    );
+  }
+
+  @protected
+  ReplyLength dco_decode_reply_length(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ReplyLength.values[raw as int];
+  }
+
+  @protected
+  ReplySignals dco_decode_reply_signals(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ReplySignals(
+      lengthChars: dco_decode_u_32(arr[0]),
+      groundedFacts: dco_decode_u_32(arr[1]),
+      userOverlap: dco_decode_f_64(arr[2]),
+      repetition: dco_decode_f_64(arr[3]),
+    );
+  }
+
+  @protected
+  ResponseStyle dco_decode_response_style(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ResponseStyle(
+      replyLength: dco_decode_reply_length(arr[0]),
+      formality: dco_decode_formality(arr[1]),
+      emojiUsage: dco_decode_emoji_usage(arr[2]),
+      actionFrequency: dco_decode_action_frequency(arr[3]),
+    );
+  }
+
+  @protected
+  RetentionPolicy dco_decode_retention_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return RetentionPolicy(
+      archiveAfterIdleDays: dco_decode_u_32(arr[0]),
+      maxMessagesPerConversation: dco_decode_u_32(arr[1]),
+      purgeOrphans: dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  RetryPolicy dco_decode_retry_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return RetryPolicy(
+      maxRetries: dco_decode_u_32(arr[0]),
+      initialDelayMs: dco_decode_u_64(arr[1]),
+      maxDelayMs: dco_decode_u_64(arr[2]),
+      backoff: dco_decode_backoff_strategy(arr[3]),
+      retryNetworkErrors: dco_decode_bool(arr[4]),
+      retryStreamErrors: dco_decode_bool(arr[5]),
+      retryServerErrors: dco_decode_bool(arr[6]),
+      retryRateLimited: dco_decode_bool(arr[7]),
+      maxRetryAfterSecs: dco_decode_u_64(arr[8]),
+    );
+  }
+
+  @protected
+  SafetyAction dco_decode_safety_action(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SafetyAction.values[raw as int];
+  }
+
+  @protected
+  SafetyCategory dco_decode_safety_category(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SafetyCategory.values[raw as int];
+  }
+
+  @protected
+  SafetyEvent dco_decode_safety_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return SafetyEvent(
+      stage: dco_decode_safety_stage(arr[0]),
+      category: dco_decode_safety_category(arr[1]),
+      action: dco_decode_safety_action(arr[2]),
+      matched: dco_decode_list_String(arr[3]),
+    );
+  }
+
+  @protected
+  SafetyPolicy dco_decode_safety_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return SafetyPolicy(
+      enabled: dco_decode_bool(arr[0]),
+      checkInput: dco_decode_bool(arr[1]),
+      checkOutput: dco_decode_bool(arr[2]),
+      rules: dco_decode_list_safety_rule(arr[3]),
+    );
+  }
+
+  @protected
+  SafetyRule dco_decode_safety_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return SafetyRule(
+      category: dco_decode_safety_category(arr[0]),
+      action: dco_decode_safety_action(arr[1]),
+      extraTerms: dco_decode_list_String(arr[2]),
+    );
+  }
+
+  @protected
+  SafetyStage dco_decode_safety_stage(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SafetyStage.values[raw as int];
+  }
+
+  @protected
+  SayDoMark dco_decode_say_do_mark(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SayDoMark.values[raw as int];
+  }
+
+  @protected
+  SayDoRule dco_decode_say_do_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return SayDoRule(
+      pattern: dco_decode_String(arr[0]),
+      mark: dco_decode_say_do_mark(arr[1]),
+      priority: dco_decode_i_32(arr[2]),
+    );
+  }
+
+  @protected
+  SayDoRuleSet dco_decode_say_do_rule_set(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return SayDoRuleSet(
+      rules: dco_decode_list_say_do_rule(arr[0]),
+      unmatched: dco_decode_opt_box_autoadd_say_do_mark(arr[1]),
+    );
+  }
+
+  @protected
+  SearchHit dco_decode_search_hit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return SearchHit(
+      conversationId: dco_decode_String(arr[0]),
+      conversationTitle: dco_decode_String(arr[1]),
+      messageId: dco_decode_String(arr[2]),
+      role: dco_decode_message_role(arr[3]),
+      snippet: dco_decode_String(arr[4]),
+      highlightStart: dco_decode_u_32(arr[5]),
+      highlightEnd: dco_decode_u_32(arr[6]),
+      timestamp: dco_decode_i_64(arr[7]),
+    );
+  }
+
+  @protected
+  ShadowEvalRecord dco_decode_shadow_eval_record(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return ShadowEvalRecord(
+      turn: dco_decode_u_32(arr[0]),
+      injectedFacts: dco_decode_u_32(arr[1]),
+      mainModel: dco_decode_String(arr[2]),
+      shadowModel: dco_decode_String(arr[3]),
+      main: dco_decode_reply_signals(arr[4]),
+      shadow: dco_decode_reply_signals(arr[5]),
+      createdAt: dco_decode_i_64(arr[6]),
+    );
+  }
+
+  @protected
+  ShadowEvalSummary dco_decode_shadow_eval_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ShadowEvalSummary(
+      samples: dco_decode_u_32(arr[0]),
+      avgGroundedGain: dco_decode_f_64(arr[1]),
+      avgOverlapGain: dco_decode_f_64(arr[2]),
+      avgRepetitionDelta: dco_decode_f_64(arr[3]),
+      groundedWinRate: dco_decode_f_64(arr[4]),
+    );
+  }
+
+  @protected
+  ShortTermInsight dco_decode_short_term_insight(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return ShortTermInsight(
+      activeTopics: dco_decode_list_String(arr[0]),
+      emotionalArc: dco_decode_list_emotional_turn_insight(arr[1]),
+      pendingThreads: dco_decode_list_String(arr[2]),
+      responseFingerprints: dco_decode_list_fingerprint_insight(arr[3]),
+    );
+  }
+
+  @protected
+  SummarizationConfig dco_decode_summarization_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return SummarizationConfig(
+      intervalTurns: dco_decode_u_32(arr[0]),
+      windowMessages: dco_decode_u_32(arr[1]),
+    );
+  }
+
+  @protected
+  SyncBackendKind dco_decode_sync_backend_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SyncBackendKind.values[raw as int];
+  }
+
+  @protected
+  SyncReport dco_decode_sync_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return SyncReport(
+      pushed: dco_decode_u_32(arr[0]),
+      pulled: dco_decode_u_32(arr[1]),
+      deleted: dco_decode_u_32(arr[2]),
+      conflicts: dco_decode_list_String(arr[3]),
+    );
+  }
+
+  @protected
+  SyncSettings dco_decode_sync_settings(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return SyncSettings(
+      backend: dco_decode_sync_backend_kind(arr[0]),
+      endpoint: dco_decode_String(arr[1]),
+      username: dco_decode_String(arr[2]),
+      password: dco_decode_String(arr[3]),
+      bucket: dco_decode_String(arr[4]),
+      region: dco_decode_String(arr[5]),
+      remoteRoot: dco_decode_String(arr[6]),
+    );
+  }
+
+  @protected
+  ThinkingRetention dco_decode_thinking_retention(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return ThinkingRetention_KeepAll();
+      case 1:
+        return ThinkingRetention_KeepLastTurns(dco_decode_u_32(raw[1]));
+      case 2:
+        return ThinkingRetention_DiscardAfterSummary();
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  TimelineEntry dco_decode_timeline_entry(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return TimelineEntry(
+      id: dco_decode_String(arr[0]),
+      kind: dco_decode_timeline_entry_kind(arr[1]),
+      text: dco_decode_String(arr[2]),
+      turnStart: dco_decode_u_32(arr[3]),
+      turnEnd: dco_decode_u_32(arr[4]),
+      timestamp: dco_decode_i_64(arr[5]),
+      endTimestamp: dco_decode_i_64(arr[6]),
+    );
+  }
+
+  @protected
+  TimelineEntryKind dco_decode_timeline_entry_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TimelineEntryKind.values[raw as int];
+  }
+
+  @protected
+  TurnMetrics dco_decode_turn_metrics(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return TurnMetrics(
+      conversationId: dco_decode_String(arr[0]),
+      startedAt: dco_decode_i_64(arr[1]),
+      totalMs: dco_decode_i_64(arr[2]),
+      firstTokenMs: dco_decode_opt_box_autoadd_i_64(arr[3]),
+      phases: dco_decode_list_phase_duration(arr[4]),
+      retries: dco_decode_u_32(arr[5]),
+    );
+  }
+
+  @protected
+  TurnPolicy dco_decode_turn_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TurnPolicy.values[raw as int];
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  UserPersona dco_decode_user_persona(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return UserPersona(
+      id: dco_decode_String(arr[0]),
+      name: dco_decode_String(arr[1]),
+      description: dco_decode_String(arr[2]),
+      pronouns: dco_decode_String(arr[3]),
+      createdAt: dco_decode_i_64(arr[4]),
+    );
+  }
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  Map<String, String> sse_decode_Map_String_String_None(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_record_string_string(deserializer);
+    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+  }
+
+  @protected
+  RustStreamSink<BackgroundTaskEvent> sse_decode_StreamSink_background_task_event_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<ChatStreamEvent> sse_decode_StreamSink_chat_stream_event_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<ReindexProgress> sse_decode_StreamSink_reindex_progress_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  ActionFrequency sse_decode_action_frequency(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ActionFrequency.values[inner];
+  }
+
+  @protected
+  AmbientContext sse_decode_ambient_context(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_weather = sse_decode_opt_String(deserializer);
+    var var_stepCount = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_nowPlaying = sse_decode_opt_String(deserializer);
+    var var_calendarBusy = sse_decode_opt_box_autoadd_bool(deserializer);
+    return AmbientContext(
+      weather: var_weather,
+      stepCount: var_stepCount,
+      nowPlaying: var_nowPlaying,
+      calendarBusy: var_calendarBusy,
+    );
+  }
+
+  @protected
+  AppSettings sse_decode_app_settings(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_apiKey = sse_decode_opt_String(deserializer);
+    var var_defaultModel = sse_decode_String(deserializer);
+    var var_enableThinkingByDefault = sse_decode_bool(deserializer);
+    var var_chatModel = sse_decode_String(deserializer);
+    var var_thinkingModel = sse_decode_String(deserializer);
+    var var_enableWebSearch = sse_decode_bool(deserializer);
+    var var_contentIntensity = sse_decode_content_intensity(deserializer);
+    var var_provider = sse_decode_provider_kind(deserializer);
+    var var_providerBaseUrl = sse_decode_opt_String(deserializer);
+    var var_providerModel = sse_decode_opt_String(deserializer);
+    var var_providerApiKey = sse_decode_opt_String(deserializer);
+    var var_shadowEvalRate = sse_decode_f_64(deserializer);
+    var var_enableTts = sse_decode_bool(deserializer);
+    var var_proxy = sse_decode_opt_box_autoadd_proxy_settings(deserializer);
+    var var_retry = sse_decode_retry_policy(deserializer);
+    var var_timeAwareness = sse_decode_bool(deserializer);
+    var var_fastDraft = sse_decode_bool(deserializer);
+    return AppSettings(
+      apiKey: var_apiKey,
+      defaultModel: var_defaultModel,
+      enableThinkingByDefault: var_enableThinkingByDefault,
+      chatModel: var_chatModel,
+      thinkingModel: var_thinkingModel,
+      enableWebSearch: var_enableWebSearch,
+      contentIntensity: var_contentIntensity,
+      provider: var_provider,
+      providerBaseUrl: var_providerBaseUrl,
+      providerModel: var_providerModel,
+      providerApiKey: var_providerApiKey,
+      shadowEvalRate: var_shadowEvalRate,
+      enableTts: var_enableTts,
+      proxy: var_proxy,
+      retry: var_retry,
+      timeAwareness: var_timeAwareness,
+      fastDraft: var_fastDraft,
+    );
+  }
+
+  @protected
+  AudioReadyEvent sse_decode_audio_ready_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_messageId = sse_decode_String(deserializer);
+    var var_audioPath = sse_decode_String(deserializer);
+    return AudioReadyEvent(messageId: var_messageId, audioPath: var_audioPath);
+  }
+
+  @protected
+  BackgroundJobKind sse_decode_background_job_kind(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return BackgroundJobKind.values[inner];
+  }
+
+  @protected
+  BackgroundTaskEvent sse_decode_background_task_event(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_taskId = sse_decode_String(deserializer);
+    var var_kind = sse_decode_background_job_kind(deserializer);
+    var var_conversationId = sse_decode_String(deserializer);
+    var var_status = sse_decode_background_task_status(deserializer);
+    var var_updatedAt = sse_decode_i_64(deserializer);
+    return BackgroundTaskEvent(
+      taskId: var_taskId,
+      kind: var_kind,
+      conversationId: var_conversationId,
+      status: var_status,
+      updatedAt: var_updatedAt,
+    );
+  }
+
+  @protected
+  BackgroundTaskStatus sse_decode_background_task_status(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return BackgroundTaskStatus.values[inner];
+  }
+
+  @protected
+  BackoffStrategy sse_decode_backoff_strategy(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return BackoffStrategy.values[inner];
+  }
+
+  @protected
+  BackupRetention sse_decode_backup_retention(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_keepLast = sse_decode_u_32(deserializer);
+    var var_keepDaily = sse_decode_u_32(deserializer);
+    return BackupRetention(keepLast: var_keepLast, keepDaily: var_keepDaily);
+  }
+
+  @protected
+  BackupScope sse_decode_backup_scope(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return BackupScope.values[inner];
+  }
+
+  @protected
+  BackupSnapshotInfo sse_decode_backup_snapshot_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_createdAt = sse_decode_i_64(deserializer);
+    var var_fileCount = sse_decode_u_32(deserializer);
+    var var_totalBytes = sse_decode_u_64(deserializer);
+    var var_addedBytes = sse_decode_u_64(deserializer);
+    return BackupSnapshotInfo(
+      id: var_id,
+      createdAt: var_createdAt,
+      fileCount: var_fileCount,
+      totalBytes: var_totalBytes,
+      addedBytes: var_addedBytes,
+    );
+  }
+
+  @protected
+  BlockedTopic sse_decode_blocked_topic(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_topic = sse_decode_String(deserializer);
+    var var_keywords = sse_decode_list_String(deserializer);
+    var var_evidence = sse_decode_list_String(deserializer);
+    var var_occurrences = sse_decode_u_32(deserializer);
+    var var_explicit = sse_decode_bool(deserializer);
+    var var_active = sse_decode_bool(deserializer);
+    var var_firstSeen = sse_decode_i_64(deserializer);
+    var var_lastSeen = sse_decode_i_64(deserializer);
+    return BlockedTopic(
+      id: var_id,
+      topic: var_topic,
+      keywords: var_keywords,
+      evidence: var_evidence,
+      occurrences: var_occurrences,
+      explicit: var_explicit,
+      active: var_active,
+      firstSeen: var_firstSeen,
+      lastSeen: var_lastSeen,
+    );
+  }
+
+  @protected
+  BlockingStats sse_decode_blocking_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_tasks = sse_decode_u_64(deserializer);
+    var var_totalRunMs = sse_decode_u_64(deserializer);
+    var var_maxRunMs = sse_decode_u_64(deserializer);
+    var var_maxQueueMs = sse_decode_u_64(deserializer);
+    var var_slowestLabel = sse_decode_String(deserializer);
+    return BlockingStats(
+      tasks: var_tasks,
+      totalRunMs: var_totalRunMs,
+      maxRunMs: var_maxRunMs,
+      maxQueueMs: var_maxQueueMs,
+      slowestLabel: var_slowestLabel,
+    );
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  AmbientContext sse_decode_box_autoadd_ambient_context(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_ambient_context(deserializer));
+  }
+
+  @protected
+  AppSettings sse_decode_box_autoadd_app_settings(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_app_settings(deserializer));
+  }
+
+  @protected
+  BackupRetention sse_decode_box_autoadd_backup_retention(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_backup_retention(deserializer));
+  }
+
+  @protected
+  BackupSnapshotInfo sse_decode_box_autoadd_backup_snapshot_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_backup_snapshot_info(deserializer));
+  }
+
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_bool(deserializer));
+  }
+
+  @protected
+  CharacterCard sse_decode_box_autoadd_character_card(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_character_card(deserializer));
+  }
+
+  @protected
+  CheckInSchedule sse_decode_box_autoadd_check_in_schedule(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_check_in_schedule(deserializer));
+  }
+
+  @protected
+  CognitiveInsight sse_decode_box_autoadd_cognitive_insight(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_cognitive_insight(deserializer));
+  }
+
+  @protected
+  ContextLayers sse_decode_box_autoadd_context_layers(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_context_layers(deserializer));
+  }
+
+  @protected
+  Conversation sse_decode_box_autoadd_conversation(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_conversation(deserializer));
+  }
+
+  @protected
+  ConversationFilter sse_decode_box_autoadd_conversation_filter(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_conversation_filter(deserializer));
+  }
+
+  @protected
+  ConversationInsights sse_decode_box_autoadd_conversation_insights(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_conversation_insights(deserializer));
+  }
+
+  @protected
+  ConversationOrganization sse_decode_box_autoadd_conversation_organization(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_conversation_organization(deserializer));
+  }
+
+  @protected
+  DailyDigest sse_decode_box_autoadd_daily_digest(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_daily_digest(deserializer));
+  }
+
+  @protected
+  DeviceConditions sse_decode_box_autoadd_device_conditions(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_device_conditions(deserializer));
+  }
+
+  @protected
+  DistilledSystemState sse_decode_box_autoadd_distilled_system_state(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_distilled_system_state(deserializer));
+  }
+
+  @protected
+  EncryptionKeySource sse_decode_box_autoadd_encryption_key_source(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_encryption_key_source(deserializer));
+  }
+
+  @protected
+  EncryptionSecret sse_decode_box_autoadd_encryption_secret(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_encryption_secret(deserializer));
+  }
+
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_f_64(deserializer));
+  }
+
+  @protected
+  Fact sse_decode_box_autoadd_fact(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_fact(deserializer));
+  }
+
+  @protected
+  GenerationMetadata sse_decode_box_autoadd_generation_metadata(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_generation_metadata(deserializer));
+  }
+
+  @protected
+  GroupChat sse_decode_box_autoadd_group_chat(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_group_chat(deserializer));
+  }
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_i_64(deserializer));
+  }
+
+  @protected
+  IntegrityReport sse_decode_box_autoadd_integrity_report(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_integrity_report(deserializer));
+  }
+
+  @protected
+  KnowledgeBinding sse_decode_box_autoadd_knowledge_binding(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_knowledge_binding(deserializer));
+  }
+
+  @protected
+  LayoutVerification sse_decode_box_autoadd_layout_verification(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_layout_verification(deserializer));
+  }
+
+  @protected
+  LoreEntry sse_decode_box_autoadd_lore_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_lore_entry(deserializer));
+  }
+
+  @protected
+  MemoryContextCard sse_decode_box_autoadd_memory_context_card(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_memory_context_card(deserializer));
+  }
+
+  @protected
+  MemoryExplanation sse_decode_box_autoadd_memory_explanation(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_memory_explanation(deserializer));
+  }
+
+  @protected
+  MemoryFidelityReport sse_decode_box_autoadd_memory_fidelity_report(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_memory_fidelity_report(deserializer));
+  }
+
+  @protected
+  MemorySummary sse_decode_box_autoadd_memory_summary(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_memory_summary(deserializer));
+  }
+
+  @protected
+  Message sse_decode_box_autoadd_message(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_message(deserializer));
+  }
+
+  @protected
+  MoodState sse_decode_box_autoadd_mood_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_mood_state(deserializer));
+  }
+
+  @protected
+  PreflightAdvisory sse_decode_box_autoadd_preflight_advisory(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_preflight_advisory(deserializer));
+  }
+
+  @protected
+  ProxySettings sse_decode_box_autoadd_proxy_settings(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_proxy_settings(deserializer));
+  }
+
+  @protected
+  ReengagementBrief sse_decode_box_autoadd_reengagement_brief(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_reengagement_brief(deserializer));
+  }
+
+  @protected
+  RelevanceInsight sse_decode_box_autoadd_relevance_insight(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_relevance_insight(deserializer));
+  }
+
+  @protected
+  ResponseStyle sse_decode_box_autoadd_response_style(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_response_style(deserializer));
+  }
+
+  @protected
+  RetentionPolicy sse_decode_box_autoadd_retention_policy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_retention_policy(deserializer));
+  }
+
+  @protected
+  RetryPolicy sse_decode_box_autoadd_retry_policy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_retry_policy(deserializer));
+  }
+
+  @protected
+  SafetyPolicy sse_decode_box_autoadd_safety_policy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_safety_policy(deserializer));
+  }
+
+  @protected
+  SayDoMark sse_decode_box_autoadd_say_do_mark(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_say_do_mark(deserializer));
+  }
+
+  @protected
+  SayDoRuleSet sse_decode_box_autoadd_say_do_rule_set(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_say_do_rule_set(deserializer));
+  }
+
+  @protected
+  SummarizationConfig sse_decode_box_autoadd_summarization_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_summarization_config(deserializer));
+  }
+
+  @protected
+  SyncSettings sse_decode_box_autoadd_sync_settings(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_sync_settings(deserializer));
+  }
+
+  @protected
+  ThinkingRetention sse_decode_box_autoadd_thinking_retention(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_thinking_retention(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  UserPersona sse_decode_box_autoadd_user_persona(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_user_persona(deserializer));
+  }
+
+  @protected
+  BranchInfo sse_decode_branch_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_parentMessageId = sse_decode_opt_String(deserializer);
+    var var_messageCount = sse_decode_u_32(deserializer);
+    var var_lastMessagePreview = sse_decode_String(deserializer);
+    var var_createdAt = sse_decode_i_64(deserializer);
+    var var_isActive = sse_decode_bool(deserializer);
+    return BranchInfo(
+      id: var_id,
+      name: var_name,
+      parentMessageId: var_parentMessageId,
+      messageCount: var_messageCount,
+      lastMessagePreview: var_lastMessagePreview,
+      createdAt: var_createdAt,
+      isActive: var_isActive,
+    );
+  }
+
+  @protected
+  CharacterCard sse_decode_character_card(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_systemPrompt = sse_decode_String(deserializer);
+    var var_greeting = sse_decode_String(deserializer);
+    return CharacterCard(
+      name: var_name,
+      systemPrompt: var_systemPrompt,
+      greeting: var_greeting,
+    );
+  }
+
+  @protected
+  CharacterRelation sse_decode_character_relation(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_from = sse_decode_String(deserializer);
+    var var_to = sse_decode_String(deserializer);
+    var var_relation = sse_decode_String(deserializer);
+    var var_sentiment = sse_decode_relation_sentiment(deserializer);
+    var var_factId = sse_decode_String(deserializer);
+    var var_sourceTurn = sse_decode_u_32(deserializer);
+    var var_history = sse_decode_list_relation_change(deserializer);
+    return CharacterRelation(
+      from: var_from,
+      to: var_to,
+      relation: var_relation,
+      sentiment: var_sentiment,
+      factId: var_factId,
+      sourceTurn: var_sourceTurn,
+      history: var_history,
+    );
+  }
+
+  @protected
+  ChatStreamEvent sse_decode_chat_stream_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_String(deserializer);
+        return ChatStreamEvent_ContentDelta(var_field0);
+      case 1:
+        var var_field0 = sse_decode_String(deserializer);
+        return ChatStreamEvent_ThinkingDelta(var_field0);
+      case 2:
+        return ChatStreamEvent_Done();
+      case 3:
+        var var_field0 = sse_decode_String(deserializer);
+        return ChatStreamEvent_Error(var_field0);
+      case 4:
+        return ChatStreamEvent_ConfigChanged();
+      case 5:
+        var var_field0 = sse_decode_reaction_event(deserializer);
+        return ChatStreamEvent_Reaction(var_field0);
+      case 6:
+        var var_field0 = sse_decode_String(deserializer);
+        return ChatStreamEvent_SystemNotice(var_field0);
+      case 7:
+        var var_field0 = sse_decode_message(deserializer);
+        return ChatStreamEvent_Illustration(var_field0);
+      case 8:
+        var var_field0 = sse_decode_audio_ready_event(deserializer);
+        return ChatStreamEvent_AudioReady(var_field0);
+      case 9:
+        var var_field0 = sse_decode_generation_completed_event(deserializer);
+        return ChatStreamEvent_Completed(var_field0);
+      case 10:
+        var var_field0 = sse_decode_outbox_entry(deserializer);
+        return ChatStreamEvent_Outbox(var_field0);
+      case 11:
+        var var_field0 = sse_decode_safety_event(deserializer);
+        return ChatStreamEvent_Safety(var_field0);
+      case 12:
+        var var_field0 = sse_decode_String(deserializer);
+        return ChatStreamEvent_CheckIn(var_field0);
+      case 13:
+        var var_field0 = sse_decode_memory_summary(deserializer);
+        return ChatStreamEvent_SummaryPreview(var_field0);
+      case 14:
+        var var_field0 = sse_decode_String(deserializer);
+        return ChatStreamEvent_DraftDelta(var_field0);
+      case 15:
+        var var_field0 = sse_decode_String(deserializer);
+        return ChatStreamEvent_Refined(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  CheckInSchedule sse_decode_check_in_schedule(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_conversationId = sse_decode_String(deserializer);
+    var var_enabled = sse_decode_bool(deserializer);
+    var var_silenceHours = sse_decode_u_32(deserializer);
+    var var_lastSentAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    return CheckInSchedule(
+      conversationId: var_conversationId,
+      enabled: var_enabled,
+      silenceHours: var_silenceHours,
+      lastSentAt: var_lastSentAt,
+    );
+  }
+
+  @protected
+  CognitiveInsight sse_decode_cognitive_insight(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_emotion = sse_decode_emotion_insight(deserializer);
+    var var_intent = sse_decode_intent_kind(deserializer);
+    var var_relationship = sse_decode_relationship_insight(deserializer);
+    var var_empathyStrategy = sse_decode_empathy_kind(deserializer);
+    var var_detectedPatterns = sse_decode_list_language_pattern_kind(
+      deserializer,
+    );
+    var var_cognitivePrompt = sse_decode_String(deserializer);
+    return CognitiveInsight(
+      emotion: var_emotion,
+      intent: var_intent,
+      relationship: var_relationship,
+      empathyStrategy: var_empathyStrategy,
+      detectedPatterns: var_detectedPatterns,
+      cognitivePrompt: var_cognitivePrompt,
+    );
+  }
+
+  @protected
+  ContentIntensity sse_decode_content_intensity(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ContentIntensity.values[inner];
+  }
+
+  @protected
+  ContextLayers sse_decode_context_layers(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_shortTermMemory = sse_decode_bool(deserializer);
+    var var_cognitiveSnapshot = sse_decode_bool(deserializer);
+    var var_diversityHint = sse_decode_bool(deserializer);
+    var var_humanizationHint = sse_decode_bool(deserializer);
+    var var_knowledgeInjection = sse_decode_bool(deserializer);
+    return ContextLayers(
+      shortTermMemory: var_shortTermMemory,
+      cognitiveSnapshot: var_cognitiveSnapshot,
+      diversityHint: var_diversityHint,
+      humanizationHint: var_humanizationHint,
+      knowledgeInjection: var_knowledgeInjection,
+    );
+  }
+
+  @protected
+  Conversation sse_decode_conversation(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_messages = sse_decode_list_message(deserializer);
+    var var_model = sse_decode_String(deserializer);
+    var var_createdAt = sse_decode_i_64(deserializer);
+    var var_updatedAt = sse_decode_i_64(deserializer);
+    var var_dialogueStyle = sse_decode_dialogue_style(deserializer);
+    var var_turnCount = sse_decode_u_32(deserializer);
+    var var_memorySummaries = sse_decode_list_memory_summary(deserializer);
+    var var_mode = sse_decode_conversation_mode(deserializer);
+    var var_thinkingRetention = sse_decode_thinking_retention(deserializer);
+    var var_branchId = sse_decode_String(deserializer);
+    var var_parentMessageId = sse_decode_opt_String(deserializer);
+    var var_branches = sse_decode_list_conversation_branch(deserializer);
+    var var_narration = sse_decode_narration_perspective(deserializer);
+    var var_contextLayers = sse_decode_context_layers(deserializer);
+    var var_closedAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_responseStyle = sse_decode_response_style(deserializer);
+    var var_personaId = sse_decode_opt_String(deserializer);
+    var var_organization = sse_decode_conversation_organization(deserializer);
+    var var_coldTurns = sse_decode_u_32(deserializer);
+    return Conversation(
+      id: var_id,
+      title: var_title,
+      messages: var_messages,
+      model: var_model,
+      createdAt: var_createdAt,
+      updatedAt: var_updatedAt,
+      dialogueStyle: var_dialogueStyle,
+      turnCount: var_turnCount,
+      memorySummaries: var_memorySummaries,
+      mode: var_mode,
+      thinkingRetention: var_thinkingRetention,
+      branchId: var_branchId,
+      parentMessageId: var_parentMessageId,
+      branches: var_branches,
+      narration: var_narration,
+      contextLayers: var_contextLayers,
+      closedAt: var_closedAt,
+      responseStyle: var_responseStyle,
+      personaId: var_personaId,
+      organization: var_organization,
+      coldTurns: var_coldTurns,
+    );
+  }
+
+  @protected
+  ConversationBranch sse_decode_conversation_branch(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_parentMessageId = sse_decode_opt_String(deserializer);
+    var var_messages = sse_decode_list_message(deserializer);
+    var var_turnCount = sse_decode_u_32(deserializer);
+    var var_memorySummaries = sse_decode_list_memory_summary(deserializer);
+    var var_createdAt = sse_decode_i_64(deserializer);
+    var var_coldTurns = sse_decode_u_32(deserializer);
+    return ConversationBranch(
+      id: var_id,
+      name: var_name,
+      parentMessageId: var_parentMessageId,
+      messages: var_messages,
+      turnCount: var_turnCount,
+      memorySummaries: var_memorySummaries,
+      createdAt: var_createdAt,
+      coldTurns: var_coldTurns,
+    );
+  }
+
+  @protected
+  ConversationClosure sse_decode_conversation_closure(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_epilogue = sse_decode_String(deserializer);
+    var var_recap = sse_decode_String(deserializer);
+    var var_archivePath = sse_decode_String(deserializer);
+    var var_closedAt = sse_decode_i_64(deserializer);
+    return ConversationClosure(
+      epilogue: var_epilogue,
+      recap: var_recap,
+      archivePath: var_archivePath,
+      closedAt: var_closedAt,
+    );
+  }
+
+  @protected
+  ConversationFilter sse_decode_conversation_filter(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_folder = sse_decode_opt_String(deserializer);
+    var var_tags = sse_decode_list_String(deserializer);
+    var var_archived = sse_decode_bool(deserializer);
+    var var_query = sse_decode_String(deserializer);
+    return ConversationFilter(
+      folder: var_folder,
+      tags: var_tags,
+      archived: var_archived,
+      query: var_query,
+    );
+  }
+
+  @protected
+  ConversationInsights sse_decode_conversation_insights(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_cognitive = sse_decode_opt_box_autoadd_cognitive_insight(
+      deserializer,
+    );
+    var var_shortTerm = sse_decode_short_term_insight(deserializer);
+    return ConversationInsights(
+      cognitive: var_cognitive,
+      shortTerm: var_shortTerm,
+    );
+  }
+
+  @protected
+  ConversationMode sse_decode_conversation_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ConversationMode.values[inner];
+  }
+
+  @protected
+  ConversationOrganization sse_decode_conversation_organization(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_tags = sse_decode_list_String(deserializer);
+    var var_folder = sse_decode_opt_String(deserializer);
+    var var_pinned = sse_decode_bool(deserializer);
+    var var_archived = sse_decode_bool(deserializer);
+    return ConversationOrganization(
+      tags: var_tags,
+      folder: var_folder,
+      pinned: var_pinned,
+      archived: var_archived,
+    );
+  }
+
+  @protected
+  ConversationSummary sse_decode_conversation_summary(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_lastMessagePreview = sse_decode_String(deserializer);
+    var var_model = sse_decode_String(deserializer);
+    var var_updatedAt = sse_decode_i_64(deserializer);
+    var var_organization = sse_decode_conversation_organization(deserializer);
+    return ConversationSummary(
+      id: var_id,
+      title: var_title,
+      lastMessagePreview: var_lastMessagePreview,
+      model: var_model,
+      updatedAt: var_updatedAt,
+      organization: var_organization,
+    );
+  }
+
+  @protected
+  DailyDigest sse_decode_daily_digest(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_generatedAt = sse_decode_i_64(deserializer);
+    var var_entries = sse_decode_list_digest_entry(deserializer);
+    var var_skipped = sse_decode_u_32(deserializer);
+    var var_estimatedTokens = sse_decode_u_32(deserializer);
+    return DailyDigest(
+      generatedAt: var_generatedAt,
+      entries: var_entries,
+      skipped: var_skipped,
+      estimatedTokens: var_estimatedTokens,
+    );
+  }
+
+  @protected
+  DataExportInfo sse_decode_data_export_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_formatVersion = sse_decode_u_32(deserializer);
+    var var_layoutVersion = sse_decode_u_32(deserializer);
+    var var_createdAt = sse_decode_i_64(deserializer);
+    var var_fileCount = sse_decode_u_32(deserializer);
+    var var_totalBytes = sse_decode_u_64(deserializer);
+    return DataExportInfo(
+      formatVersion: var_formatVersion,
+      layoutVersion: var_layoutVersion,
+      createdAt: var_createdAt,
+      fileCount: var_fileCount,
+      totalBytes: var_totalBytes,
+    );
+  }
+
+  @protected
+  DeferredJob sse_decode_deferred_job(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_background_job_kind(deserializer);
+    var var_conversationId = sse_decode_String(deserializer);
+    var var_deferredAt = sse_decode_i_64(deserializer);
+    var var_reason = sse_decode_String(deserializer);
+    return DeferredJob(
+      kind: var_kind,
+      conversationId: var_conversationId,
+      deferredAt: var_deferredAt,
+      reason: var_reason,
+    );
+  }
+
+  @protected
+  DegradationKind sse_decode_degradation_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return DegradationKind.values[inner];
+  }
+
+  @protected
+  DegradationRecord sse_decode_degradation_record(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_turn = sse_decode_u_32(deserializer);
+    var var_kind = sse_decode_degradation_kind(deserializer);
+    var var_model = sse_decode_String(deserializer);
+    var var_detail = sse_decode_String(deserializer);
+    var var_timestamp = sse_decode_i_64(deserializer);
+    return DegradationRecord(
+      turn: var_turn,
+      kind: var_kind,
+      model: var_model,
+      detail: var_detail,
+      timestamp: var_timestamp,
+    );
+  }
+
+  @protected
+  DeviceConditions sse_decode_device_conditions(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_batteryLevel = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_isCharging = sse_decode_bool(deserializer);
+    var var_lowPowerMode = sse_decode_bool(deserializer);
+    var var_meteredNetwork = sse_decode_bool(deserializer);
+    return DeviceConditions(
+      batteryLevel: var_batteryLevel,
+      isCharging: var_isCharging,
+      lowPowerMode: var_lowPowerMode,
+      meteredNetwork: var_meteredNetwork,
+    );
+  }
+
+  @protected
+  DialogueStyle sse_decode_dialogue_style(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return DialogueStyle.values[inner];
+  }
+
+  @protected
+  DigestEntry sse_decode_digest_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_conversationId = sse_decode_String(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_note = sse_decode_String(deserializer);
+    var var_lastActiveAt = sse_decode_i_64(deserializer);
+    return DigestEntry(
+      conversationId: var_conversationId,
+      title: var_title,
+      note: var_note,
+      lastActiveAt: var_lastActiveAt,
+    );
+  }
+
+  @protected
+  DistilledSystemState sse_decode_distilled_system_state(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_corePrompt = sse_decode_String(deserializer);
+    var var_lastMemoryCount = sse_decode_usize(deserializer);
+    var var_lastMaxCompressionGen = sse_decode_u_32(deserializer);
+    var var_characterPromptHash = sse_decode_u_64(deserializer);
+    var var_lastTurnCount = sse_decode_u_32(deserializer);
+    var var_distilledAt = sse_decode_i_64(deserializer);
+    var var_coreFactsSnapshot = sse_decode_list_String(deserializer);
+    return DistilledSystemState(
+      corePrompt: var_corePrompt,
+      lastMemoryCount: var_lastMemoryCount,
+      lastMaxCompressionGen: var_lastMaxCompressionGen,
+      characterPromptHash: var_characterPromptHash,
+      lastTurnCount: var_lastTurnCount,
+      distilledAt: var_distilledAt,
+      coreFactsSnapshot: var_coreFactsSnapshot,
+    );
+  }
+
+  @protected
+  DraftTone sse_decode_draft_tone(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return DraftTone.values[inner];
+  }
+
+  @protected
+  EmojiUsage sse_decode_emoji_usage(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return EmojiUsage.values[inner];
+  }
+
+  @protected
+  EmotionDimension sse_decode_emotion_dimension(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return EmotionDimension.values[inner];
+  }
+
+  @protected
+  EmotionInsight sse_decode_emotion_insight(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_joy = sse_decode_f_64(deserializer);
+    var var_sadness = sse_decode_f_64(deserializer);
+    var var_anger = sse_decode_f_64(deserializer);
+    var var_fear = sse_decode_f_64(deserializer);
+    var var_surprise = sse_decode_f_64(deserializer);
+    var var_intimacy = sse_decode_f_64(deserializer);
+    var var_trust = sse_decode_f_64(deserializer);
+    var var_anticipation = sse_decode_f_64(deserializer);
+    var var_valence = sse_decode_f_64(deserializer);
+    var var_arousal = sse_decode_f_64(deserializer);
+    return EmotionInsight(
+      joy: var_joy,
+      sadness: var_sadness,
+      anger: var_anger,
+      fear: var_fear,
+      surprise: var_surprise,
+      intimacy: var_intimacy,
+      trust: var_trust,
+      anticipation: var_anticipation,
+      valence: var_valence,
+      arousal: var_arousal,
+    );
+  }
+
+  @protected
+  EmotionalTurnInsight sse_decode_emotional_turn_insight(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_turn = sse_decode_u_32(deserializer);
+    var var_valence = sse_decode_f_64(deserializer);
+    var var_arousal = sse_decode_f_64(deserializer);
+    var var_dominantEmotion = sse_decode_String(deserializer);
+    return EmotionalTurnInsight(
+      turn: var_turn,
+      valence: var_valence,
+      arousal: var_arousal,
+      dominantEmotion: var_dominantEmotion,
+    );
+  }
+
+  @protected
+  EmpathyKind sse_decode_empathy_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return EmpathyKind.values[inner];
+  }
+
+  @protected
+  EncryptionKeySource sse_decode_encryption_key_source(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return EncryptionKeySource.values[inner];
+  }
+
+  @protected
+  EncryptionSecret sse_decode_encryption_secret(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_String(deserializer);
+        return EncryptionSecret_Passphrase(var_field0);
+      case 1:
+        var var_field0 = sse_decode_list_prim_u_8_strict(deserializer);
+        return EncryptionSecret_Keystore(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  EncryptionStatus sse_decode_encryption_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_enabled = sse_decode_bool(deserializer);
+    var var_unlocked = sse_decode_bool(deserializer);
+    var var_source = sse_decode_opt_box_autoadd_encryption_key_source(
+      deserializer,
+    );
+    return EncryptionStatus(
+      enabled: var_enabled,
+      unlocked: var_unlocked,
+      source: var_source,
+    );
+  }
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat64();
+  }
+
+  @protected
+  Fact sse_decode_fact(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_content = sse_decode_String(deserializer);
+    var var_category = sse_decode_fact_category(deserializer);
+    var var_sourceTurn = sse_decode_u_32(deserializer);
+    var var_createdAt = sse_decode_i_64(deserializer);
+    var var_lastConfirmedAt = sse_decode_i_64(deserializer);
+    var var_keywords = sse_decode_list_String(deserializer);
+    var var_entities = sse_decode_list_String(deserializer);
+    var var_confidence = sse_decode_f_64(deserializer);
+    var var_hitCount = sse_decode_u_32(deserializer);
+    var var_contextSnippet = sse_decode_String(deserializer);
+    var var_summaryIds = sse_decode_list_String(deserializer);
+    var var_supersededBy = sse_decode_opt_String(deserializer);
+    var var_supersededAt = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_manualOverride = sse_decode_bool(deserializer);
+    var var_personaId = sse_decode_opt_String(deserializer);
+    return Fact(
+      id: var_id,
+      content: var_content,
+      category: var_category,
+      sourceTurn: var_sourceTurn,
+      createdAt: var_createdAt,
+      lastConfirmedAt: var_lastConfirmedAt,
+      keywords: var_keywords,
+      entities: var_entities,
+      confidence: var_confidence,
+      hitCount: var_hitCount,
+      contextSnippet: var_contextSnippet,
+      summaryIds: var_summaryIds,
+      supersededBy: var_supersededBy,
+      supersededAt: var_supersededAt,
+      manualOverride: var_manualOverride,
+      personaId: var_personaId,
+    );
+  }
+
+  @protected
+  FactCategory sse_decode_fact_category(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return FactCategory.values[inner];
+  }
+
+  @protected
+  FactConflict sse_decode_fact_conflict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_supersededFactId = sse_decode_String(deserializer);
+    var var_supersededContent = sse_decode_String(deserializer);
+    var var_activeFactId = sse_decode_String(deserializer);
+    var var_activeContent = sse_decode_String(deserializer);
+    var var_detectedAt = sse_decode_i_64(deserializer);
+    return FactConflict(
+      supersededFactId: var_supersededFactId,
+      supersededContent: var_supersededContent,
+      activeFactId: var_activeFactId,
+      activeContent: var_activeContent,
+      detectedAt: var_detectedAt,
+    );
+  }
+
+  @protected
+  FingerprintInsight sse_decode_fingerprint_insight(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_openingChars = sse_decode_String(deserializer);
+    var var_paragraphCount = sse_decode_u_32(deserializer);
+    var var_avgSentenceLen = sse_decode_f_64(deserializer);
+    var var_endingChars = sse_decode_String(deserializer);
+    var var_endsWithQuestion = sse_decode_bool(deserializer);
+    var var_totalLength = sse_decode_u_32(deserializer);
+    var var_hasActionMarker = sse_decode_bool(deserializer);
+    var var_hasListFormat = sse_decode_bool(deserializer);
+    var var_emotionalTone = sse_decode_String(deserializer);
+    return FingerprintInsight(
+      openingChars: var_openingChars,
+      paragraphCount: var_paragraphCount,
+      avgSentenceLen: var_avgSentenceLen,
+      endingChars: var_endingChars,
+      endsWithQuestion: var_endsWithQuestion,
+      totalLength: var_totalLength,
+      hasActionMarker: var_hasActionMarker,
+      hasListFormat: var_hasListFormat,
+      emotionalTone: var_emotionalTone,
+    );
+  }
+
+  @protected
+  Formality sse_decode_formality(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return Formality.values[inner];
+  }
+
+  @protected
+  GenerationCompletedEvent sse_decode_generation_completed_event(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_messageId = sse_decode_String(deserializer);
+    var var_metadata = sse_decode_generation_metadata(deserializer);
+    return GenerationCompletedEvent(
+      messageId: var_messageId,
+      metadata: var_metadata,
+    );
+  }
+
+  @protected
+  GenerationMetadata sse_decode_generation_metadata(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_model = sse_decode_String(deserializer);
+    var var_finishReason = sse_decode_opt_String(deserializer);
+    var var_promptTokens = sse_decode_u_32(deserializer);
+    var var_completionTokens = sse_decode_u_32(deserializer);
+    var var_totalTokens = sse_decode_u_32(deserializer);
+    var var_cachedTokens = sse_decode_u_32(deserializer);
+    var var_streamed = sse_decode_bool(deserializer);
+    var var_interrupted = sse_decode_bool(deserializer);
+    var var_fallbacks = sse_decode_list_String(deserializer);
+    var var_latencyMs = sse_decode_i_64(deserializer);
+    return GenerationMetadata(
+      model: var_model,
+      finishReason: var_finishReason,
+      promptTokens: var_promptTokens,
+      completionTokens: var_completionTokens,
+      totalTokens: var_totalTokens,
+      cachedTokens: var_cachedTokens,
+      streamed: var_streamed,
+      interrupted: var_interrupted,
+      fallbacks: var_fallbacks,
+      latencyMs: var_latencyMs,
+    );
+  }
+
+  @protected
+  GraphEdge sse_decode_graph_edge(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_factId = sse_decode_String(deserializer);
+    var var_source = sse_decode_String(deserializer);
+    var var_target = sse_decode_String(deserializer);
+    var var_relation = sse_decode_String(deserializer);
+    var var_category = sse_decode_String(deserializer);
+    return GraphEdge(
+      factId: var_factId,
+      source: var_source,
+      target: var_target,
+      relation: var_relation,
+      category: var_category,
+    );
+  }
+
+  @protected
+  GraphExportFormat sse_decode_graph_export_format(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return GraphExportFormat.values[inner];
+  }
+
+  @protected
+  GraphNode sse_decode_graph_node(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_label = sse_decode_String(deserializer);
+    var var_degree = sse_decode_u_32(deserializer);
+    return GraphNode(id: var_id, label: var_label, degree: var_degree);
+  }
+
+  @protected
+  GroupCharacter sse_decode_group_character(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_systemPrompt = sse_decode_String(deserializer);
+    return GroupCharacter(
+      id: var_id,
+      name: var_name,
+      systemPrompt: var_systemPrompt,
+    );
+  }
+
+  @protected
+  GroupChat sse_decode_group_chat(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_conversationId = sse_decode_String(deserializer);
+    var var_characters = sse_decode_list_group_character(deserializer);
+    var var_turnPolicy = sse_decode_turn_policy(deserializer);
+    return GroupChat(
+      conversationId: var_conversationId,
+      characters: var_characters,
+      turnPolicy: var_turnPolicy,
+    );
+  }
+
+  @protected
+  HousekeepingReport sse_decode_housekeeping_report(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_archived = sse_decode_list_String(deserializer);
+    var var_rolledOverMessages = sse_decode_u_32(deserializer);
+    var var_purgedFiles = sse_decode_u_32(deserializer);
+    return HousekeepingReport(
+      archived: var_archived,
+      rolledOverMessages: var_rolledOverMessages,
+      purgedFiles: var_purgedFiles,
+    );
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getPlatformInt64();
+  }
+
+  @protected
+  IndexScope sse_decode_index_scope(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return IndexScope.values[inner];
+  }
+
+  @protected
+  IntegrityIssue sse_decode_integrity_issue(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_integrity_issue_kind(deserializer);
+    var var_detail = sse_decode_String(deserializer);
+    var var_repaired = sse_decode_bool(deserializer);
+    return IntegrityIssue(
+      kind: var_kind,
+      detail: var_detail,
+      repaired: var_repaired,
+    );
+  }
+
+  @protected
+  IntegrityIssueKind sse_decode_integrity_issue_kind(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return IntegrityIssueKind.values[inner];
+  }
+
+  @protected
+  IntegrityReport sse_decode_integrity_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_conversationId = sse_decode_String(deserializer);
+    var var_actualTurns = sse_decode_u_32(deserializer);
+    var var_recordedTurnCount = sse_decode_u_32(deserializer);
+    var var_issues = sse_decode_list_integrity_issue(deserializer);
+    var var_checkedAt = sse_decode_i_64(deserializer);
+    return IntegrityReport(
+      conversationId: var_conversationId,
+      actualTurns: var_actualTurns,
+      recordedTurnCount: var_recordedTurnCount,
+      issues: var_issues,
+      checkedAt: var_checkedAt,
+    );
+  }
+
+  @protected
+  IntentKind sse_decode_intent_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return IntentKind.values[inner];
+  }
+
+  @protected
+  JobSchedulerStats sse_decode_job_scheduler_stats(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_running = sse_decode_u_32(deserializer);
+    var var_completed = sse_decode_u_64(deserializer);
+    var var_timedOut = sse_decode_u_64(deserializer);
+    var var_deferredTotal = sse_decode_u_64(deserializer);
+    var var_pending = sse_decode_list_deferred_job(deserializer);
+    return JobSchedulerStats(
+      running: var_running,
+      completed: var_completed,
+      timedOut: var_timedOut,
+      deferredTotal: var_deferredTotal,
+      pending: var_pending,
+    );
+  }
+
+  @protected
+  KnowledgeBinding sse_decode_knowledge_binding(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_characterId = sse_decode_opt_String(deserializer);
+    var var_characterName = sse_decode_opt_String(deserializer);
+    var var_worldId = sse_decode_opt_String(deserializer);
+    return KnowledgeBinding(
+      characterId: var_characterId,
+      characterName: var_characterName,
+      worldId: var_worldId,
+    );
+  }
+
+  @protected
+  KnowledgeGraphView sse_decode_knowledge_graph_view(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_nodes = sse_decode_list_graph_node(deserializer);
+    var var_edges = sse_decode_list_graph_edge(deserializer);
+    return KnowledgeGraphView(nodes: var_nodes, edges: var_edges);
+  }
+
+  @protected
+  KnowledgeLayer sse_decode_knowledge_layer(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return KnowledgeLayer.values[inner];
+  }
+
+  @protected
+  LanguagePatternKind sse_decode_language_pattern_kind(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return LanguagePatternKind.values[inner];
+  }
+
+  @protected
+  LayoutVerification sse_decode_layout_verification(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_version = sse_decode_u_32(deserializer);
+    var var_checkedFiles = sse_decode_u_32(deserializer);
+    var var_missing = sse_decode_list_String(deserializer);
+    var var_corrupted = sse_decode_list_String(deserializer);
+    var var_unexpected = sse_decode_list_String(deserializer);
+    return LayoutVerification(
+      version: var_version,
+      checkedFiles: var_checkedFiles,
+      missing: var_missing,
+      corrupted: var_corrupted,
+      unexpected: var_unexpected,
+    );
+  }
+
+  @protected
+  LexiconEntry sse_decode_lexicon_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_word = sse_decode_String(deserializer);
+    var var_dimension = sse_decode_emotion_dimension(deserializer);
+    var var_weight = sse_decode_f_64(deserializer);
+    return LexiconEntry(
+      word: var_word,
+      dimension: var_dimension,
+      weight: var_weight,
+    );
+  }
+
+  @protected
+  LinkedFact sse_decode_linked_fact(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_factId = sse_decode_String(deserializer);
+    var var_content = sse_decode_String(deserializer);
+    var var_sourceTurn = sse_decode_u_32(deserializer);
+    var var_summaryIds = sse_decode_list_String(deserializer);
+    return LinkedFact(
+      factId: var_factId,
+      content: var_content,
+      sourceTurn: var_sourceTurn,
+      summaryIds: var_summaryIds,
+    );
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<BackgroundTaskEvent> sse_decode_list_background_task_event(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <BackgroundTaskEvent>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_background_task_event(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<BackupSnapshotInfo> sse_decode_list_backup_snapshot_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <BackupSnapshotInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_backup_snapshot_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<BlockedTopic> sse_decode_list_blocked_topic(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <BlockedTopic>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_blocked_topic(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<BranchInfo> sse_decode_list_branch_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <BranchInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_branch_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CharacterRelation> sse_decode_list_character_relation(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CharacterRelation>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_character_relation(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ConversationBranch> sse_decode_list_conversation_branch(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ConversationBranch>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_conversation_branch(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ConversationSummary> sse_decode_list_conversation_summary(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ConversationSummary>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_conversation_summary(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DeferredJob> sse_decode_list_deferred_job(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DeferredJob>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_deferred_job(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DegradationRecord> sse_decode_list_degradation_record(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DegradationRecord>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_degradation_record(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DigestEntry> sse_decode_list_digest_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DigestEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_digest_entry(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<EmotionalTurnInsight> sse_decode_list_emotional_turn_insight(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <EmotionalTurnInsight>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_emotional_turn_insight(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Fact> sse_decode_list_fact(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Fact>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_fact(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FactConflict> sse_decode_list_fact_conflict(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FactConflict>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_fact_conflict(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FingerprintInsight> sse_decode_list_fingerprint_insight(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FingerprintInsight>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_fingerprint_insight(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<GraphEdge> sse_decode_list_graph_edge(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <GraphEdge>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_graph_edge(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<GraphNode> sse_decode_list_graph_node(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <GraphNode>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_graph_node(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<GroupCharacter> sse_decode_list_group_character(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <GroupCharacter>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_group_character(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<IntegrityIssue> sse_decode_list_integrity_issue(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <IntegrityIssue>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_integrity_issue(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<LanguagePatternKind> sse_decode_list_language_pattern_kind(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <LanguagePatternKind>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_language_pattern_kind(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<LexiconEntry> sse_decode_list_lexicon_entry(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <LexiconEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_lexicon_entry(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<LinkedFact> sse_decode_list_linked_fact(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <LinkedFact>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_linked_fact(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<LoreEntry> sse_decode_list_lore_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <LoreEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_lore_entry(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MemoryFidelityReport> sse_decode_list_memory_fidelity_report(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MemoryFidelityReport>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_memory_fidelity_report(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MemorySearchResult> sse_decode_list_memory_search_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MemorySearchResult>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_memory_search_result(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MemorySummary> sse_decode_list_memory_summary(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MemorySummary>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_memory_summary(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MemoryTier> sse_decode_list_memory_tier(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MemoryTier>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_memory_tier(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Message> sse_decode_list_message(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Message>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_message(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MessageAlternative> sse_decode_list_message_alternative(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MessageAlternative>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_message_alternative(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MessageAttachment> sse_decode_list_message_attachment(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MessageAttachment>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_message_attachment(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MessageEdit> sse_decode_list_message_edit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MessageEdit>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_message_edit(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ModelInfo> sse_decode_list_model_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ModelInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_model_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<OutboxEntry> sse_decode_list_outbox_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <OutboxEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_outbox_entry(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<PhaseDuration> sse_decode_list_phase_duration(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PhaseDuration>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_phase_duration(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<PromptTemplateInfo> sse_decode_list_prompt_template_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <PromptTemplateInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_prompt_template_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<(String, String)> sse_decode_list_record_string_string(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <(String, String)>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_record_string_string(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<RelationChange> sse_decode_list_relation_change(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RelationChange>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_relation_change(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SafetyRule> sse_decode_list_safety_rule(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SafetyRule>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_safety_rule(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SayDoRule> sse_decode_list_say_do_rule(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SayDoRule>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_say_do_rule(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<SearchHit> sse_decode_list_search_hit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <SearchHit>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_search_hit(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ShadowEvalRecord> sse_decode_list_shadow_eval_record(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ShadowEvalRecord>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_shadow_eval_record(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TimelineEntry> sse_decode_list_timeline_entry(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TimelineEntry>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_timeline_entry(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TurnMetrics> sse_decode_list_turn_metrics(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TurnMetrics>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_turn_metrics(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<UserPersona> sse_decode_list_user_persona(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <UserPersona>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_user_persona(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  LoreEntry sse_decode_lore_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_content = sse_decode_String(deserializer);
+    var var_keywords = sse_decode_list_String(deserializer);
+    var var_insertionDepth = sse_decode_u_32(deserializer);
+    var var_priority = sse_decode_i_32(deserializer);
+    var var_enabled = sse_decode_bool(deserializer);
+    return LoreEntry(
+      id: var_id,
+      title: var_title,
+      content: var_content,
+      keywords: var_keywords,
+      insertionDepth: var_insertionDepth,
+      priority: var_priority,
+      enabled: var_enabled,
+    );
+  }
+
+  @protected
+  MemoryContextCard sse_decode_memory_context_card(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_sourceRange = sse_decode_String(deserializer);
+    var var_topicTags = sse_decode_list_String(deserializer);
+    var var_keyEntities = sse_decode_list_String(deserializer);
+    var var_emotionalTone = sse_decode_String(deserializer);
+    var var_causalLinks = sse_decode_list_String(deserializer);
+    return MemoryContextCard(
+      sourceRange: var_sourceRange,
+      topicTags: var_topicTags,
+      keyEntities: var_keyEntities,
+      emotionalTone: var_emotionalTone,
+      causalLinks: var_causalLinks,
+    );
+  }
+
+  @protected
+  MemoryExplanation sse_decode_memory_explanation(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_summaryId = sse_decode_String(deserializer);
+    var var_summary = sse_decode_String(deserializer);
+    var var_turnRangeStart = sse_decode_u_32(deserializer);
+    var var_turnRangeEnd = sse_decode_u_32(deserializer);
+    var var_compressionGeneration = sse_decode_u_32(deserializer);
+    var var_linkedFacts = sse_decode_list_linked_fact(deserializer);
+    return MemoryExplanation(
+      summaryId: var_summaryId,
+      summary: var_summary,
+      turnRangeStart: var_turnRangeStart,
+      turnRangeEnd: var_turnRangeEnd,
+      compressionGeneration: var_compressionGeneration,
+      linkedFacts: var_linkedFacts,
+    );
+  }
+
+  @protected
+  MemoryFidelityReport sse_decode_memory_fidelity_report(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_conversationId = sse_decode_String(deserializer);
+    var var_score = sse_decode_f_64(deserializer);
+    var var_sampledFacts = sse_decode_u_32(deserializer);
+    var var_lostFacts = sse_decode_list_String(deserializer);
+    var var_previousScore = sse_decode_opt_box_autoadd_f_64(deserializer);
+    var var_alert = sse_decode_bool(deserializer);
+    var var_auditedAt = sse_decode_i_64(deserializer);
+    return MemoryFidelityReport(
+      conversationId: var_conversationId,
+      score: var_score,
+      sampledFacts: var_sampledFacts,
+      lostFacts: var_lostFacts,
+      previousScore: var_previousScore,
+      alert: var_alert,
+      auditedAt: var_auditedAt,
+    );
+  }
+
+  @protected
+  MemorySearchResult sse_decode_memory_search_result(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_summary = sse_decode_String(deserializer);
+    var var_coreFacts = sse_decode_list_String(deserializer);
+    var var_relevanceScore = sse_decode_f_64(deserializer);
+    return MemorySearchResult(
+      summary: var_summary,
+      coreFacts: var_coreFacts,
+      relevanceScore: var_relevanceScore,
+    );
+  }
+
+  @protected
+  MemorySummary sse_decode_memory_summary(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_summary = sse_decode_String(deserializer);
+    var var_coreFacts = sse_decode_list_String(deserializer);
+    var var_turnRangeStart = sse_decode_u_32(deserializer);
+    var var_turnRangeEnd = sse_decode_u_32(deserializer);
+    var var_createdAt = sse_decode_i_64(deserializer);
+    var var_keywords = sse_decode_list_String(deserializer);
+    var var_compressionGeneration = sse_decode_u_32(deserializer);
+    var var_contextCard = sse_decode_opt_box_autoadd_memory_context_card(
+      deserializer,
+    );
+    var var_factTiers = sse_decode_list_memory_tier(deserializer);
+    var var_linkedFactIds = sse_decode_list_String(deserializer);
+    var var_pinned = sse_decode_bool(deserializer);
+    var var_pinnedFacts = sse_decode_list_String(deserializer);
+    var var_stale = sse_decode_bool(deserializer);
+    return MemorySummary(
+      id: var_id,
+      summary: var_summary,
+      coreFacts: var_coreFacts,
+      turnRangeStart: var_turnRangeStart,
+      turnRangeEnd: var_turnRangeEnd,
+      createdAt: var_createdAt,
+      keywords: var_keywords,
+      compressionGeneration: var_compressionGeneration,
+      contextCard: var_contextCard,
+      factTiers: var_factTiers,
+      linkedFactIds: var_linkedFactIds,
+      pinned: var_pinned,
+      pinnedFacts: var_pinnedFacts,
+      stale: var_stale,
+    );
+  }
+
+  @protected
+  MemoryTier sse_decode_memory_tier(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return MemoryTier.values[inner];
+  }
+
+  @protected
+  Message sse_decode_message(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_role = sse_decode_message_role(deserializer);
+    var var_content = sse_decode_String(deserializer);
+    var var_thinkingContent = sse_decode_opt_String(deserializer);
+    var var_model = sse_decode_String(deserializer);
+    var var_timestamp = sse_decode_i_64(deserializer);
+    var var_messageType = sse_decode_message_type(deserializer);
+    var var_generationMetadata = sse_decode_opt_box_autoadd_generation_metadata(
+      deserializer,
+    );
+    var var_characterId = sse_decode_opt_String(deserializer);
+    var var_attachments = sse_decode_list_message_attachment(deserializer);
+    var var_audioPath = sse_decode_opt_String(deserializer);
+    var var_alternatives = sse_decode_list_message_alternative(deserializer);
+    var var_selectedAlternative = sse_decode_u_32(deserializer);
+    var var_starred = sse_decode_bool(deserializer);
+    var var_reactions = sse_decode_list_String(deserializer);
+    var var_editHistory = sse_decode_list_message_edit(deserializer);
+    return Message(
+      id: var_id,
+      role: var_role,
+      content: var_content,
+      thinkingContent: var_thinkingContent,
+      model: var_model,
+      timestamp: var_timestamp,
+      messageType: var_messageType,
+      generationMetadata: var_generationMetadata,
+      characterId: var_characterId,
+      attachments: var_attachments,
+      audioPath: var_audioPath,
+      alternatives: var_alternatives,
+      selectedAlternative: var_selectedAlternative,
+      starred: var_starred,
+      reactions: var_reactions,
+      editHistory: var_editHistory,
+    );
+  }
+
+  @protected
+  MessageAlternative sse_decode_message_alternative(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_content = sse_decode_String(deserializer);
+    var var_thinkingContent = sse_decode_opt_String(deserializer);
+    var var_model = sse_decode_String(deserializer);
+    var var_timestamp = sse_decode_i_64(deserializer);
+    var var_generationMetadata = sse_decode_opt_box_autoadd_generation_metadata(
+      deserializer,
+    );
+    var var_audioPath = sse_decode_opt_String(deserializer);
+    return MessageAlternative(
+      content: var_content,
+      thinkingContent: var_thinkingContent,
+      model: var_model,
+      timestamp: var_timestamp,
+      generationMetadata: var_generationMetadata,
+      audioPath: var_audioPath,
+    );
+  }
+
+  @protected
+  MessageAttachment sse_decode_message_attachment(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_mimeType = sse_decode_String(deserializer);
+    var var_url = sse_decode_opt_String(deserializer);
+    var var_caption = sse_decode_opt_String(deserializer);
+    return MessageAttachment(
+      id: var_id,
+      mimeType: var_mimeType,
+      url: var_url,
+      caption: var_caption,
+    );
+  }
+
+  @protected
+  MessageEdit sse_decode_message_edit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_content = sse_decode_String(deserializer);
+    var var_timestamp = sse_decode_i_64(deserializer);
+    var var_editedAt = sse_decode_i_64(deserializer);
+    return MessageEdit(
+      content: var_content,
+      timestamp: var_timestamp,
+      editedAt: var_editedAt,
+    );
+  }
+
+  @protected
+  MessageRole sse_decode_message_role(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return MessageRole.values[inner];
+  }
+
+  @protected
+  MessageType sse_decode_message_type(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return MessageType.values[inner];
+  }
+
+  @protected
+  MetricPhase sse_decode_metric_phase(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return MetricPhase.values[inner];
+  }
+
+  @protected
+  MetricsSnapshot sse_decode_metrics_snapshot(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_turns = sse_decode_list_turn_metrics(deserializer);
+    var var_avgFirstTokenMs = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_avgPhaseMs = sse_decode_list_phase_duration(deserializer);
+    var var_totalRetries = sse_decode_u_32(deserializer);
+    return MetricsSnapshot(
+      turns: var_turns,
+      avgFirstTokenMs: var_avgFirstTokenMs,
+      avgPhaseMs: var_avgPhaseMs,
+      totalRetries: var_totalRetries,
+    );
+  }
+
+  @protected
+  ModelInfo sse_decode_model_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_contextTokens = sse_decode_usize(deserializer);
+    var var_maxOutputTokens = sse_decode_usize(deserializer);
+    var var_supportsThinking = sse_decode_bool(deserializer);
+    return ModelInfo(
+      id: var_id,
+      name: var_name,
+      contextTokens: var_contextTokens,
+      maxOutputTokens: var_maxOutputTokens,
+      supportsThinking: var_supportsThinking,
+    );
+  }
+
+  @protected
+  MoodState sse_decode_mood_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_valence = sse_decode_f_64(deserializer);
+    var var_arousal = sse_decode_f_64(deserializer);
+    var var_mood = sse_decode_String(deserializer);
+    var var_updatedAt = sse_decode_i_64(deserializer);
+    return MoodState(
+      valence: var_valence,
+      arousal: var_arousal,
+      mood: var_mood,
+      updatedAt: var_updatedAt,
+    );
+  }
+
+  @protected
+  NarrationPerspective sse_decode_narration_perspective(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return NarrationPerspective.values[inner];
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  AmbientContext? sse_decode_opt_box_autoadd_ambient_context(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_ambient_context(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BackupSnapshotInfo? sse_decode_opt_box_autoadd_backup_snapshot_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_backup_snapshot_info(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_bool(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  CheckInSchedule? sse_decode_opt_box_autoadd_check_in_schedule(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_check_in_schedule(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  CognitiveInsight? sse_decode_opt_box_autoadd_cognitive_insight(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_cognitive_insight(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Conversation? sse_decode_opt_box_autoadd_conversation(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_conversation(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ConversationInsights? sse_decode_opt_box_autoadd_conversation_insights(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_conversation_insights(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ConversationOrganization? sse_decode_opt_box_autoadd_conversation_organization(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_conversation_organization(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  DailyDigest? sse_decode_opt_box_autoadd_daily_digest(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_daily_digest(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  DistilledSystemState? sse_decode_opt_box_autoadd_distilled_system_state(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_distilled_system_state(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  EncryptionKeySource? sse_decode_opt_box_autoadd_encryption_key_source(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_encryption_key_source(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_f_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Fact? sse_decode_opt_box_autoadd_fact(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_fact(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  GenerationMetadata? sse_decode_opt_box_autoadd_generation_metadata(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_generation_metadata(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  GroupChat? sse_decode_opt_box_autoadd_group_chat(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_group_chat(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  IntegrityReport? sse_decode_opt_box_autoadd_integrity_report(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_integrity_report(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  LayoutVerification? sse_decode_opt_box_autoadd_layout_verification(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_layout_verification(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  LoreEntry? sse_decode_opt_box_autoadd_lore_entry(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_lore_entry(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  MemoryContextCard? sse_decode_opt_box_autoadd_memory_context_card(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_memory_context_card(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  MemoryExplanation? sse_decode_opt_box_autoadd_memory_explanation(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_memory_explanation(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  MemoryFidelityReport? sse_decode_opt_box_autoadd_memory_fidelity_report(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_memory_fidelity_report(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  MemorySummary? sse_decode_opt_box_autoadd_memory_summary(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_memory_summary(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Message? sse_decode_opt_box_autoadd_message(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_message(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  MoodState? sse_decode_opt_box_autoadd_mood_state(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_mood_state(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PreflightAdvisory? sse_decode_opt_box_autoadd_preflight_advisory(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_preflight_advisory(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ProxySettings? sse_decode_opt_box_autoadd_proxy_settings(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_proxy_settings(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ReengagementBrief? sse_decode_opt_box_autoadd_reengagement_brief(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_reengagement_brief(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  RelevanceInsight? sse_decode_opt_box_autoadd_relevance_insight(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_relevance_insight(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  SayDoMark? sse_decode_opt_box_autoadd_say_do_mark(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_say_do_mark(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  SummarizationConfig? sse_decode_opt_box_autoadd_summarization_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_summarization_config(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  OutboxEntry sse_decode_outbox_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_conversationId = sse_decode_String(deserializer);
+    var var_content = sse_decode_String(deserializer);
+    var var_attachments = sse_decode_list_message_attachment(deserializer);
+    var var_model = sse_decode_String(deserializer);
+    var var_enableThinking = sse_decode_bool(deserializer);
+    var var_status = sse_decode_outbox_status(deserializer);
+    var var_queuedAt = sse_decode_i_64(deserializer);
+    var var_lastError = sse_decode_opt_String(deserializer);
+    return OutboxEntry(
+      id: var_id,
+      conversationId: var_conversationId,
+      content: var_content,
+      attachments: var_attachments,
+      model: var_model,
+      enableThinking: var_enableThinking,
+      status: var_status,
+      queuedAt: var_queuedAt,
+      lastError: var_lastError,
+    );
+  }
+
+  @protected
+  OutboxStatus sse_decode_outbox_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return OutboxStatus.values[inner];
+  }
+
+  @protected
+  PhaseDuration sse_decode_phase_duration(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_phase = sse_decode_metric_phase(deserializer);
+    var var_durationMs = sse_decode_i_64(deserializer);
+    return PhaseDuration(phase: var_phase, durationMs: var_durationMs);
+  }
+
+  @protected
+  PreflightAdvisory sse_decode_preflight_advisory(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_tone = sse_decode_draft_tone(deserializer);
+    var var_regretRisk = sse_decode_f_64(deserializer);
+    var var_tensionDelta = sse_decode_f_64(deserializer);
+    var var_closenessDelta = sse_decode_f_64(deserializer);
+    var var_lateNight = sse_decode_bool(deserializer);
+    var var_suggestPause = sse_decode_bool(deserializer);
+    var var_notes = sse_decode_list_String(deserializer);
+    return PreflightAdvisory(
+      tone: var_tone,
+      regretRisk: var_regretRisk,
+      tensionDelta: var_tensionDelta,
+      closenessDelta: var_closenessDelta,
+      lateNight: var_lateNight,
+      suggestPause: var_suggestPause,
+      notes: var_notes,
+    );
+  }
+
+  @protected
+  PromptTemplateInfo sse_decode_prompt_template_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_description = sse_decode_String(deserializer);
+    var var_variables = sse_decode_list_String(deserializer);
+    var var_customized = sse_decode_bool(deserializer);
+    return PromptTemplateInfo(
+      name: var_name,
+      description: var_description,
+      variables: var_variables,
+      customized: var_customized,
+    );
+  }
+
+  @protected
+  ProviderKind sse_decode_provider_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ProviderKind.values[inner];
+  }
+
+  @protected
+  ProxySettings sse_decode_proxy_settings(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_url = sse_decode_String(deserializer);
+    var var_username = sse_decode_opt_String(deserializer);
+    var var_password = sse_decode_opt_String(deserializer);
+    var var_bypass = sse_decode_list_String(deserializer);
+    return ProxySettings(
+      url: var_url,
+      username: var_username,
+      password: var_password,
+      bypass: var_bypass,
+    );
+  }
+
+  @protected
+  ReactionEvent sse_decode_reaction_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_emoji = sse_decode_String(deserializer);
+    var var_label = sse_decode_String(deserializer);
+    return ReactionEvent(emoji: var_emoji, label: var_label);
+  }
+
+  @protected
+  (String, String) sse_decode_record_string_string(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_String(deserializer);
+    var var_field1 = sse_decode_String(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  ReengagementBrief sse_decode_reengagement_brief(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_conversationId = sse_decode_String(deserializer);
+    var var_elapsedDays = sse_decode_u_32(deserializer);
+    var var_recap = sse_decode_String(deserializer);
+    var var_opener = sse_decode_String(deserializer);
+    var var_pendingThreads = sse_decode_list_String(deserializer);
+    var var_generatedAt = sse_decode_i_64(deserializer);
+    var var_lastMessageId = sse_decode_String(deserializer);
+    return ReengagementBrief(
+      conversationId: var_conversationId,
+      elapsedDays: var_elapsedDays,
+      recap: var_recap,
+      opener: var_opener,
+      pendingThreads: var_pendingThreads,
+      generatedAt: var_generatedAt,
+      lastMessageId: var_lastMessageId,
+    );
+  }
+
+  @protected
+  ReindexProgress sse_decode_reindex_progress(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_conversationId = sse_decode_String(deserializer);
+    var var_completed = sse_decode_u_32(deserializer);
+    var var_total = sse_decode_u_32(deserializer);
+    var var_rebuiltEntries = sse_decode_u_32(deserializer);
+    var var_error = sse_decode_opt_String(deserializer);
+    return ReindexProgress(
+      conversationId: var_conversationId,
+      completed: var_completed,
+      total: var_total,
+      rebuiltEntries: var_rebuiltEntries,
+      error: var_error,
+    );
+  }
+
+  @protected
+  RelationChange sse_decode_relation_change(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_relation = sse_decode_String(deserializer);
+    var var_factId = sse_decode_String(deserializer);
+    var var_sourceTurn = sse_decode_u_32(deserializer);
+    return RelationChange(
+      relation: var_relation,
+      factId: var_factId,
+      sourceTurn: var_sourceTurn,
+    );
+  }
+
+  @protected
+  RelationSentiment sse_decode_relation_sentiment(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return RelationSentiment.values[inner];
+  }
+
+  @protected
+  RelationshipInsight sse_decode_relationship_insight(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_closeness = sse_decode_f_64(deserializer);
+    var var_trustLevel = sse_decode_f_64(deserializer);
+    var var_tension = sse_decode_f_64(deserializer);
+    var var_powerBalance = sse_decode_f_64(deserializer);
+    var var_trend = sse_decode_f_64(deserializer);
+    return RelationshipInsight(
+      closeness: var_closeness,
+      trustLevel: var_trustLevel,
+      tension: var_tension,
+      powerBalance: var_powerBalance,
+      trend: var_trend,
+    );
+  }
+
+  @protected
+  RelevanceInsight sse_decode_relevance_insight(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_tfidfScore = sse_decode_f_64(deserializer);
+    var var_keywordOverlap = sse_decode_f_64(deserializer);
+    var var_topicMatch = sse_decode_f_64(deserializer);
+    var var_finalScore = sse_decode_f_64(deserializer);
+    return RelevanceInsight(
+      tfidfScore: var_tfidfScore,
+      keywordOverlap: var_keywordOverlap,
+      topicMatch: var_topicMatch,
+      finalScore: var_finalScore,
+    );
+  }
+
+  @protected
+  ReplyLength sse_decode_reply_length(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ReplyLength.values[inner];
+  }
+
+  @protected
+  ReplySignals sse_decode_reply_signals(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_lengthChars = sse_decode_u_32(deserializer);
+    var var_groundedFacts = sse_decode_u_32(deserializer);
+    var var_userOverlap = sse_decode_f_64(deserializer);
+    var var_repetition = sse_decode_f_64(deserializer);
+    return ReplySignals(
+      lengthChars: var_lengthChars,
+      groundedFacts: var_groundedFacts,
+      userOverlap: var_userOverlap,
+      repetition: var_repetition,
+    );
+  }
+
+  @protected
+  ResponseStyle sse_decode_response_style(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_replyLength = sse_decode_reply_length(deserializer);
+    var var_formality = sse_decode_formality(deserializer);
+    var var_emojiUsage = sse_decode_emoji_usage(deserializer);
+    var var_actionFrequency = sse_decode_action_frequency(deserializer);
+    return ResponseStyle(
+      replyLength: var_replyLength,
+      formality: var_formality,
+      emojiUsage: var_emojiUsage,
+      actionFrequency: var_actionFrequency,
+    );
+  }
+
+  @protected
+  RetentionPolicy sse_decode_retention_policy(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_archiveAfterIdleDays = sse_decode_u_32(deserializer);
+    var var_maxMessagesPerConversation = sse_decode_u_32(deserializer);
+    var var_purgeOrphans = sse_decode_bool(deserializer);
+    return RetentionPolicy(
+      archiveAfterIdleDays: var_archiveAfterIdleDays,
+      maxMessagesPerConversation: var_maxMessagesPerConversation,
+      purgeOrphans: var_purgeOrphans,
+    );
+  }
+
+  @protected
+  RetryPolicy sse_decode_retry_policy(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_maxRetries = sse_decode_u_32(deserializer);
+    var var_initialDelayMs = sse_decode_u_64(deserializer);
+    var var_maxDelayMs = sse_decode_u_64(deserializer);
+    var var_backoff = sse_decode_backoff_strategy(deserializer);
+    var var_retryNetworkErrors = sse_decode_bool(deserializer);
+    var var_retryStreamErrors = sse_decode_bool(deserializer);
+    var var_retryServerErrors = sse_decode_bool(deserializer);
+    var var_retryRateLimited = sse_decode_bool(deserializer);
+    var var_maxRetryAfterSecs = sse_decode_u_64(deserializer);
+    return RetryPolicy(
+      maxRetries: var_maxRetries,
+      initialDelayMs: var_initialDelayMs,
+      maxDelayMs: var_maxDelayMs,
+      backoff: var_backoff,
+      retryNetworkErrors: var_retryNetworkErrors,
+      retryStreamErrors: var_retryStreamErrors,
+      retryServerErrors: var_retryServerErrors,
+      retryRateLimited: var_retryRateLimited,
+      maxRetryAfterSecs: var_maxRetryAfterSecs,
+    );
+  }
+
+  @protected
+  SafetyAction sse_decode_safety_action(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SafetyAction.values[inner];
+  }
+
+  @protected
+  SafetyCategory sse_decode_safety_category(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SafetyCategory.values[inner];
+  }
+
+  @protected
+  SafetyEvent sse_decode_safety_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_stage = sse_decode_safety_stage(deserializer);
+    var var_category = sse_decode_safety_category(deserializer);
+    var var_action = sse_decode_safety_action(deserializer);
+    var var_matched = sse_decode_list_String(deserializer);
+    return SafetyEvent(
+      stage: var_stage,
+      category: var_category,
+      action: var_action,
+      matched: var_matched,
+    );
+  }
+
+  @protected
+  SafetyPolicy sse_decode_safety_policy(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_enabled = sse_decode_bool(deserializer);
+    var var_checkInput = sse_decode_bool(deserializer);
+    var var_checkOutput = sse_decode_bool(deserializer);
+    var var_rules = sse_decode_list_safety_rule(deserializer);
+    return SafetyPolicy(
+      enabled: var_enabled,
+      checkInput: var_checkInput,
+      checkOutput: var_checkOutput,
+      rules: var_rules,
+    );
+  }
+
+  @protected
+  SafetyRule sse_decode_safety_rule(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_category = sse_decode_safety_category(deserializer);
+    var var_action = sse_decode_safety_action(deserializer);
+    var var_extraTerms = sse_decode_list_String(deserializer);
+    return SafetyRule(
+      category: var_category,
+      action: var_action,
+      extraTerms: var_extraTerms,
+    );
+  }
+
+  @protected
+  SafetyStage sse_decode_safety_stage(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SafetyStage.values[inner];
+  }
+
+  @protected
+  SayDoMark sse_decode_say_do_mark(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SayDoMark.values[inner];
+  }
+
+  @protected
+  SayDoRule sse_decode_say_do_rule(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_pattern = sse_decode_String(deserializer);
+    var var_mark = sse_decode_say_do_mark(deserializer);
+    var var_priority = sse_decode_i_32(deserializer);
+    return SayDoRule(
+      pattern: var_pattern,
+      mark: var_mark,
+      priority: var_priority,
+    );
+  }
+
+  @protected
+  SayDoRuleSet sse_decode_say_do_rule_set(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_rules = sse_decode_list_say_do_rule(deserializer);
+    var var_unmatched = sse_decode_opt_box_autoadd_say_do_mark(deserializer);
+    return SayDoRuleSet(rules: var_rules, unmatched: var_unmatched);
+  }
+
+  @protected
+  SearchHit sse_decode_search_hit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_conversationId = sse_decode_String(deserializer);
+    var var_conversationTitle = sse_decode_String(deserializer);
+    var var_messageId = sse_decode_String(deserializer);
+    var var_role = sse_decode_message_role(deserializer);
+    var var_snippet = sse_decode_String(deserializer);
+    var var_highlightStart = sse_decode_u_32(deserializer);
+    var var_highlightEnd = sse_decode_u_32(deserializer);
+    var var_timestamp = sse_decode_i_64(deserializer);
+    return SearchHit(
+      conversationId: var_conversationId,
+      conversationTitle: var_conversationTitle,
+      messageId: var_messageId,
+      role: var_role,
+      snippet: var_snippet,
+      highlightStart: var_highlightStart,
+      highlightEnd: var_highlightEnd,
+      timestamp: var_timestamp,
+    );
+  }
+
+  @protected
+  ShadowEvalRecord sse_decode_shadow_eval_record(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_turn = sse_decode_u_32(deserializer);
+    var var_injectedFacts = sse_decode_u_32(deserializer);
+    var var_mainModel = sse_decode_String(deserializer);
+    var var_shadowModel = sse_decode_String(deserializer);
+    var var_main = sse_decode_reply_signals(deserializer);
+    var var_shadow = sse_decode_reply_signals(deserializer);
+    var var_createdAt = sse_decode_i_64(deserializer);
+    return ShadowEvalRecord(
+      turn: var_turn,
+      injectedFacts: var_injectedFacts,
+      mainModel: var_mainModel,
+      shadowModel: var_shadowModel,
+      main: var_main,
+      shadow: var_shadow,
+      createdAt: var_createdAt,
+    );
+  }
+
+  @protected
+  ShadowEvalSummary sse_decode_shadow_eval_summary(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_samples = sse_decode_u_32(deserializer);
+    var var_avgGroundedGain = sse_decode_f_64(deserializer);
+    var var_avgOverlapGain = sse_decode_f_64(deserializer);
+    var var_avgRepetitionDelta = sse_decode_f_64(deserializer);
+    var var_groundedWinRate = sse_decode_f_64(deserializer);
+    return ShadowEvalSummary(
+      samples: var_samples,
+      avgGroundedGain: var_avgGroundedGain,
+      avgOverlapGain: var_avgOverlapGain,
+      avgRepetitionDelta: var_avgRepetitionDelta,
+      groundedWinRate: var_groundedWinRate,
+    );
+  }
+
+  @protected
+  ShortTermInsight sse_decode_short_term_insight(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_activeTopics = sse_decode_list_String(deserializer);
+    var var_emotionalArc = sse_decode_list_emotional_turn_insight(deserializer);
+    var var_pendingThreads = sse_decode_list_String(deserializer);
+    var var_responseFingerprints = sse_decode_list_fingerprint_insight(
+      deserializer,
+    );
+    return ShortTermInsight(
+      activeTopics: var_activeTopics,
+      emotionalArc: var_emotionalArc,
+      pendingThreads: var_pendingThreads,
+      responseFingerprints: var_responseFingerprints,
+    );
+  }
+
+  @protected
+  SummarizationConfig sse_decode_summarization_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_intervalTurns = sse_decode_u_32(deserializer);
+    var var_windowMessages = sse_decode_u_32(deserializer);
+    return SummarizationConfig(
+      intervalTurns: var_intervalTurns,
+      windowMessages: var_windowMessages,
+    );
+  }
+
+  @protected
+  SyncBackendKind sse_decode_sync_backend_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return SyncBackendKind.values[inner];
+  }
+
+  @protected
+  SyncReport sse_decode_sync_report(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_pushed = sse_decode_u_32(deserializer);
+    var var_pulled = sse_decode_u_32(deserializer);
+    var var_deleted = sse_decode_u_32(deserializer);
+    var var_conflicts = sse_decode_list_String(deserializer);
+    return SyncReport(
+      pushed: var_pushed,
+      pulled: var_pulled,
+      deleted: var_deleted,
+      conflicts: var_conflicts,
+    );
+  }
+
+  @protected
+  SyncSettings sse_decode_sync_settings(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_backend = sse_decode_sync_backend_kind(deserializer);
+    var var_endpoint = sse_decode_String(deserializer);
+    var var_username = sse_decode_String(deserializer);
+    var var_password = sse_decode_String(deserializer);
+    var var_bucket = sse_decode_String(deserializer);
+    var var_region = sse_decode_String(deserializer);
+    var var_remoteRoot = sse_decode_String(deserializer);
+    return SyncSettings(
+      backend: var_backend,
+      endpoint: var_endpoint,
+      username: var_username,
+      password: var_password,
+      bucket: var_bucket,
+      region: var_region,
+      remoteRoot: var_remoteRoot,
+    );
+  }
+
+  @protected
+  ThinkingRetention sse_decode_thinking_retention(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return ThinkingRetention_KeepAll();
+      case 1:
+        var var_field0 = sse_decode_u_32(deserializer);
+        return ThinkingRetention_KeepLastTurns(var_field0);
+      case 2:
+        return ThinkingRetention_DiscardAfterSummary();
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  TimelineEntry sse_decode_timeline_entry(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_kind = sse_decode_timeline_entry_kind(deserializer);
+    var var_text = sse_decode_String(deserializer);
+    var var_turnStart = sse_decode_u_32(deserializer);
+    var var_turnEnd = sse_decode_u_32(deserializer);
+    var var_timestamp = sse_decode_i_64(deserializer);
+    var var_endTimestamp = sse_decode_i_64(deserializer);
+    return TimelineEntry(
+      id: var_id,
+      kind: var_kind,
+      text: var_text,
+      turnStart: var_turnStart,
+      turnEnd: var_turnEnd,
+      timestamp: var_timestamp,
+      endTimestamp: var_endTimestamp,
+    );
+  }
+
+  @protected
+  TimelineEntryKind sse_decode_timeline_entry_kind(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return TimelineEntryKind.values[inner];
+  }
+
+  @protected
+  TurnMetrics sse_decode_turn_metrics(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_conversationId = sse_decode_String(deserializer);
+    var var_startedAt = sse_decode_i_64(deserializer);
+    var var_totalMs = sse_decode_i_64(deserializer);
+    var var_firstTokenMs = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_phases = sse_decode_list_phase_duration(deserializer);
+    var var_retries = sse_decode_u_32(deserializer);
+    return TurnMetrics(
+      conversationId: var_conversationId,
+      startedAt: var_startedAt,
+      totalMs: var_totalMs,
+      firstTokenMs: var_firstTokenMs,
+      phases: var_phases,
+      retries: var_retries,
+    );
+  }
+
+  @protected
+  TurnPolicy sse_decode_turn_policy(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return TurnPolicy.values[inner];
+  }
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
+  }
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+  }
+
+  @protected
+  UserPersona sse_decode_user_persona(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_description = sse_decode_String(deserializer);
+    var var_pronouns = sse_decode_String(deserializer);
+    var var_createdAt = sse_decode_i_64(deserializer);
+    return UserPersona(
+      id: var_id,
+      name: var_name,
+      description: var_description,
+      pronouns: var_pronouns,
+      createdAt: var_createdAt,
+    );
+  }
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
+  }
+
+  @protected
+  void sse_encode_Map_String_String_None(
+    Map<String, String> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_record_string_string(
+      self.entries.map((e) => (e.key, e.value)).toList(),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_background_task_event_Sse(
+    RustStreamSink<BackgroundTaskEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_background_task_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_chat_stream_event_Sse(
+    RustStreamSink<ChatStreamEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_chat_stream_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_reindex_progress_Sse(
+    RustStreamSink<ReindexProgress> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_reindex_progress,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+  }
+
+  @protected
+  void sse_encode_action_frequency(
+    ActionFrequency self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_ambient_context(
+    AmbientContext self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.weather, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.stepCount, serializer);
+    sse_encode_opt_String(self.nowPlaying, serializer);
+    sse_encode_opt_box_autoadd_bool(self.calendarBusy, serializer);
+  }
+
+  @protected
+  void sse_encode_app_settings(AppSettings self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.apiKey, serializer);
+    sse_encode_String(self.defaultModel, serializer);
+    sse_encode_bool(self.enableThinkingByDefault, serializer);
+    sse_encode_String(self.chatModel, serializer);
+    sse_encode_String(self.thinkingModel, serializer);
+    sse_encode_bool(self.enableWebSearch, serializer);
+    sse_encode_content_intensity(self.contentIntensity, serializer);
+    sse_encode_provider_kind(self.provider, serializer);
+    sse_encode_opt_String(self.providerBaseUrl, serializer);
+    sse_encode_opt_String(self.providerModel, serializer);
+    sse_encode_opt_String(self.providerApiKey, serializer);
+    sse_encode_f_64(self.shadowEvalRate, serializer);
+    sse_encode_bool(self.enableTts, serializer);
+    sse_encode_opt_box_autoadd_proxy_settings(self.proxy, serializer);
+    sse_encode_retry_policy(self.retry, serializer);
+    sse_encode_bool(self.timeAwareness, serializer);
+    sse_encode_bool(self.fastDraft, serializer);
+  }
+
+  @protected
+  void sse_encode_audio_ready_event(
+    AudioReadyEvent self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.messageId, serializer);
+    sse_encode_String(self.audioPath, serializer);
+  }
+
+  @protected
+  void sse_encode_background_job_kind(
+    BackgroundJobKind self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_background_task_event(
+    BackgroundTaskEvent self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.taskId, serializer);
+    sse_encode_background_job_kind(self.kind, serializer);
+    sse_encode_String(self.conversationId, serializer);
+    sse_encode_background_task_status(self.status, serializer);
+    sse_encode_i_64(self.updatedAt, serializer);
+  }
+
+  @protected
+  void sse_encode_background_task_status(
+    BackgroundTaskStatus self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_backoff_strategy(
+    BackoffStrategy self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_backup_retention(
+    BackupRetention self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.keepLast, serializer);
+    sse_encode_u_32(self.keepDaily, serializer);
+  }
+
+  @protected
+  void sse_encode_backup_scope(BackupScope self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_backup_snapshot_info(
+    BackupSnapshotInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_i_64(self.createdAt, serializer);
+    sse_encode_u_32(self.fileCount, serializer);
+    sse_encode_u_64(self.totalBytes, serializer);
+    sse_encode_u_64(self.addedBytes, serializer);
+  }
+
+  @protected
+  void sse_encode_blocked_topic(BlockedTopic self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.topic, serializer);
+    sse_encode_list_String(self.keywords, serializer);
+    sse_encode_list_String(self.evidence, serializer);
+    sse_encode_u_32(self.occurrences, serializer);
+    sse_encode_bool(self.explicit, serializer);
+    sse_encode_bool(self.active, serializer);
+    sse_encode_i_64(self.firstSeen, serializer);
+    sse_encode_i_64(self.lastSeen, serializer);
+  }
+
+  @protected
+  void sse_encode_blocking_stats(BlockingStats self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.tasks, serializer);
+    sse_encode_u_64(self.totalRunMs, serializer);
+    sse_encode_u_64(self.maxRunMs, serializer);
+    sse_encode_u_64(self.maxQueueMs, serializer);
+    sse_encode_String(self.slowestLabel, serializer);
+  }
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_ambient_context(
+    AmbientContext self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_ambient_context(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_app_settings(
+    AppSettings self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_app_settings(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_backup_retention(
+    BackupRetention self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_backup_retention(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_backup_snapshot_info(
+    BackupSnapshotInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_backup_snapshot_info(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_character_card(
+    CharacterCard self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_character_card(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_check_in_schedule(
+    CheckInSchedule self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_check_in_schedule(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_cognitive_insight(
+    CognitiveInsight self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_cognitive_insight(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_context_layers(
+    ContextLayers self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_context_layers(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_conversation(
+    Conversation self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_conversation(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_conversation_filter(
+    ConversationFilter self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_conversation_filter(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_conversation_insights(
+    ConversationInsights self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_conversation_insights(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_conversation_organization(
+    ConversationOrganization self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_conversation_organization(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_daily_digest(
+    DailyDigest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_daily_digest(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_device_conditions(
+    DeviceConditions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_device_conditions(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_distilled_system_state(
+    DistilledSystemState self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_distilled_system_state(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_encryption_key_source(
+    EncryptionKeySource self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_encryption_key_source(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_encryption_secret(
+    EncryptionSecret self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_encryption_secret(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_fact(Fact self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_fact(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_generation_metadata(
+    GenerationMetadata self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_generation_metadata(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_group_chat(
+    GroupChat self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_group_chat(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_integrity_report(
+    IntegrityReport self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_integrity_report(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_knowledge_binding(
+    KnowledgeBinding self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_knowledge_binding(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_layout_verification(
+    LayoutVerification self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_layout_verification(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_lore_entry(
+    LoreEntry self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_lore_entry(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_memory_context_card(
+    MemoryContextCard self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_memory_context_card(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_memory_explanation(
+    MemoryExplanation self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_memory_explanation(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_memory_fidelity_report(
+    MemoryFidelityReport self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_memory_fidelity_report(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_memory_summary(
+    MemorySummary self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_memory_summary(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_message(Message self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_message(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_mood_state(
+    MoodState self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_mood_state(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_preflight_advisory(
+    PreflightAdvisory self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_preflight_advisory(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_proxy_settings(
+    ProxySettings self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_proxy_settings(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_reengagement_brief(
+    ReengagementBrief self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_reengagement_brief(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_relevance_insight(
+    RelevanceInsight self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_relevance_insight(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_response_style(
+    ResponseStyle self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_response_style(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_retention_policy(
+    RetentionPolicy self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_retention_policy(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_retry_policy(
+    RetryPolicy self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_retry_policy(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_safety_policy(
+    SafetyPolicy self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_safety_policy(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_say_do_mark(
+    SayDoMark self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_say_do_mark(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_say_do_rule_set(
+    SayDoRuleSet self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_say_do_rule_set(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_summarization_config(
+    SummarizationConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_summarization_config(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_sync_settings(
+    SyncSettings self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_sync_settings(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_thinking_retention(
+    ThinkingRetention self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_thinking_retention(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_user_persona(
+    UserPersona self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_user_persona(self, serializer);
+  }
+
+  @protected
+  void sse_encode_branch_info(BranchInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_opt_String(self.parentMessageId, serializer);
+    sse_encode_u_32(self.messageCount, serializer);
+    sse_encode_String(self.lastMessagePreview, serializer);
+    sse_encode_i_64(self.createdAt, serializer);
+    sse_encode_bool(self.isActive, serializer);
+  }
+
+  @protected
+  void sse_encode_character_card(CharacterCard self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.systemPrompt, serializer);
+    sse_encode_String(self.greeting, serializer);
+  }
+
+  @protected
+  void sse_encode_character_relation(
+    CharacterRelation self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.from, serializer);
+    sse_encode_String(self.to, serializer);
+    sse_encode_String(self.relation, serializer);
+    sse_encode_relation_sentiment(self.sentiment, serializer);
+    sse_encode_String(self.factId, serializer);
+    sse_encode_u_32(self.sourceTurn, serializer);
+    sse_encode_list_relation_change(self.history, serializer);
+  }
+
+  @protected
+  void sse_encode_chat_stream_event(
+    ChatStreamEvent self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case ChatStreamEvent_ContentDelta(field0: final field0):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(field0, serializer);
+      case ChatStreamEvent_ThinkingDelta(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(field0, serializer);
+      case ChatStreamEvent_Done():
+        sse_encode_i_32(2, serializer);
+      case ChatStreamEvent_Error(field0: final field0):
+        sse_encode_i_32(3, serializer);
+        sse_encode_String(field0, serializer);
+      case ChatStreamEvent_ConfigChanged():
+        sse_encode_i_32(4, serializer);
+      case ChatStreamEvent_Reaction(field0: final field0):
+        sse_encode_i_32(5, serializer);
+        sse_encode_reaction_event(field0, serializer);
+      case ChatStreamEvent_SystemNotice(field0: final field0):
+        sse_encode_i_32(6, serializer);
+        sse_encode_String(field0, serializer);
+      case ChatStreamEvent_Illustration(field0: final field0):
+        sse_encode_i_32(7, serializer);
+        sse_encode_message(field0, serializer);
+      case ChatStreamEvent_AudioReady(field0: final field0):
+        sse_encode_i_32(8, serializer);
+        sse_encode_audio_ready_event(field0, serializer);
+      case ChatStreamEvent_Completed(field0: final field0):
+        sse_encode_i_32(9, serializer);
+        sse_encode_generation_completed_event(field0, serializer);
+      case ChatStreamEvent_Outbox(field0: final field0):
+        sse_encode_i_32(10, serializer);
+        sse_encode_outbox_entry(field0, serializer);
+      case ChatStreamEvent_Safety(field0: final field0):
+        sse_encode_i_32(11, serializer);
+        sse_encode_safety_event(field0, serializer);
+      case ChatStreamEvent_CheckIn(field0: final field0):
+        sse_encode_i_32(12, serializer);
+        sse_encode_String(field0, serializer);
+      case ChatStreamEvent_SummaryPreview(field0: final field0):
+        sse_encode_i_32(13, serializer);
+        sse_encode_memory_summary(field0, serializer);
+      case ChatStreamEvent_DraftDelta(field0: final field0):
+        sse_encode_i_32(14, serializer);
+        sse_encode_String(field0, serializer);
+      case ChatStreamEvent_Refined(field0: final field0):
+        sse_encode_i_32(15, serializer);
+        sse_encode_String(field0, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_check_in_schedule(
+    CheckInSchedule self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.conversationId, serializer);
+    sse_encode_bool(self.enabled, serializer);
+    sse_encode_u_32(self.silenceHours, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.lastSentAt, serializer);
+  }
+
+  @protected
+  void sse_encode_cognitive_insight(
+    CognitiveInsight self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_emotion_insight(self.emotion, serializer);
+    sse_encode_intent_kind(self.intent, serializer);
+    sse_encode_relationship_insight(self.relationship, serializer);
+    sse_encode_empathy_kind(self.empathyStrategy, serializer);
+    sse_encode_list_language_pattern_kind(self.detectedPatterns, serializer);
+    sse_encode_String(self.cognitivePrompt, serializer);
+  }
+
+  @protected
+  void sse_encode_content_intensity(
+    ContentIntensity self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_context_layers(ContextLayers self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.shortTermMemory, serializer);
+    sse_encode_bool(self.cognitiveSnapshot, serializer);
+    sse_encode_bool(self.diversityHint, serializer);
+    sse_encode_bool(self.humanizationHint, serializer);
+    sse_encode_bool(self.knowledgeInjection, serializer);
+  }
+
+  @protected
+  void sse_encode_conversation(Conversation self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_list_message(self.messages, serializer);
+    sse_encode_String(self.model, serializer);
+    sse_encode_i_64(self.createdAt, serializer);
+    sse_encode_i_64(self.updatedAt, serializer);
+    sse_encode_dialogue_style(self.dialogueStyle, serializer);
+    sse_encode_u_32(self.turnCount, serializer);
+    sse_encode_list_memory_summary(self.memorySummaries, serializer);
+    sse_encode_conversation_mode(self.mode, serializer);
+    sse_encode_thinking_retention(self.thinkingRetention, serializer);
+    sse_encode_String(self.branchId, serializer);
+    sse_encode_opt_String(self.parentMessageId, serializer);
+    sse_encode_list_conversation_branch(self.branches, serializer);
+    sse_encode_narration_perspective(self.narration, serializer);
+    sse_encode_context_layers(self.contextLayers, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.closedAt, serializer);
+    sse_encode_response_style(self.responseStyle, serializer);
+    sse_encode_opt_String(self.personaId, serializer);
+    sse_encode_conversation_organization(self.organization, serializer);
+    sse_encode_u_32(self.coldTurns, serializer);
+  }
+
+  @protected
+  void sse_encode_conversation_branch(
+    ConversationBranch self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_opt_String(self.parentMessageId, serializer);
+    sse_encode_list_message(self.messages, serializer);
+    sse_encode_u_32(self.turnCount, serializer);
+    sse_encode_list_memory_summary(self.memorySummaries, serializer);
+    sse_encode_i_64(self.createdAt, serializer);
+    sse_encode_u_32(self.coldTurns, serializer);
+  }
+
+  @protected
+  void sse_encode_conversation_closure(
+    ConversationClosure self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.epilogue, serializer);
+    sse_encode_String(self.recap, serializer);
+    sse_encode_String(self.archivePath, serializer);
+    sse_encode_i_64(self.closedAt, serializer);
+  }
+
+  @protected
+  void sse_encode_conversation_filter(
+    ConversationFilter self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.folder, serializer);
+    sse_encode_list_String(self.tags, serializer);
+    sse_encode_bool(self.archived, serializer);
+    sse_encode_String(self.query, serializer);
+  }
+
+  @protected
+  void sse_encode_conversation_insights(
+    ConversationInsights self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_cognitive_insight(self.cognitive, serializer);
+    sse_encode_short_term_insight(self.shortTerm, serializer);
+  }
+
+  @protected
+  void sse_encode_conversation_mode(
+    ConversationMode self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_conversation_organization(
+    ConversationOrganization self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_String(self.tags, serializer);
+    sse_encode_opt_String(self.folder, serializer);
+    sse_encode_bool(self.pinned, serializer);
+    sse_encode_bool(self.archived, serializer);
+  }
+
+  @protected
+  void sse_encode_conversation_summary(
+    ConversationSummary self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_String(self.lastMessagePreview, serializer);
+    sse_encode_String(self.model, serializer);
+    sse_encode_i_64(self.updatedAt, serializer);
+    sse_encode_conversation_organization(self.organization, serializer);
+  }
+
+  @protected
+  void sse_encode_daily_digest(DailyDigest self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self.generatedAt, serializer);
+    sse_encode_list_digest_entry(self.entries, serializer);
+    sse_encode_u_32(self.skipped, serializer);
+    sse_encode_u_32(self.estimatedTokens, serializer);
+  }
+
+  @protected
+  void sse_encode_data_export_info(
+    DataExportInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.formatVersion, serializer);
+    sse_encode_u_32(self.layoutVersion, serializer);
+    sse_encode_i_64(self.createdAt, serializer);
+    sse_encode_u_32(self.fileCount, serializer);
+    sse_encode_u_64(self.totalBytes, serializer);
+  }
+
+  @protected
+  void sse_encode_deferred_job(DeferredJob self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_background_job_kind(self.kind, serializer);
+    sse_encode_String(self.conversationId, serializer);
+    sse_encode_i_64(self.deferredAt, serializer);
+    sse_encode_String(self.reason, serializer);
+  }
+
+  @protected
+  void sse_encode_degradation_kind(
+    DegradationKind self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_degradation_record(
+    DegradationRecord self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.turn, serializer);
+    sse_encode_degradation_kind(self.kind, serializer);
+    sse_encode_String(self.model, serializer);
+    sse_encode_String(self.detail, serializer);
+    sse_encode_i_64(self.timestamp, serializer);
+  }
+
+  @protected
+  void sse_encode_device_conditions(
+    DeviceConditions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_f_64(self.batteryLevel, serializer);
+    sse_encode_bool(self.isCharging, serializer);
+    sse_encode_bool(self.lowPowerMode, serializer);
+    sse_encode_bool(self.meteredNetwork, serializer);
+  }
+
+  @protected
+  void sse_encode_dialogue_style(DialogueStyle self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_digest_entry(DigestEntry self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.conversationId, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_String(self.note, serializer);
+    sse_encode_i_64(self.lastActiveAt, serializer);
+  }
+
+  @protected
+  void sse_encode_distilled_system_state(
+    DistilledSystemState self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.corePrompt, serializer);
+    sse_encode_usize(self.lastMemoryCount, serializer);
+    sse_encode_u_32(self.lastMaxCompressionGen, serializer);
+    sse_encode_u_64(self.characterPromptHash, serializer);
+    sse_encode_u_32(self.lastTurnCount, serializer);
+    sse_encode_i_64(self.distilledAt, serializer);
+    sse_encode_list_String(self.coreFactsSnapshot, serializer);
+  }
+
+  @protected
+  void sse_encode_draft_tone(DraftTone self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_emoji_usage(EmojiUsage self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_emotion_dimension(
+    EmotionDimension self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_emotion_insight(
+    EmotionInsight self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self.joy, serializer);
+    sse_encode_f_64(self.sadness, serializer);
+    sse_encode_f_64(self.anger, serializer);
+    sse_encode_f_64(self.fear, serializer);
+    sse_encode_f_64(self.surprise, serializer);
+    sse_encode_f_64(self.intimacy, serializer);
+    sse_encode_f_64(self.trust, serializer);
+    sse_encode_f_64(self.anticipation, serializer);
+    sse_encode_f_64(self.valence, serializer);
+    sse_encode_f_64(self.arousal, serializer);
+  }
+
+  @protected
+  void sse_encode_emotional_turn_insight(
+    EmotionalTurnInsight self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.turn, serializer);
+    sse_encode_f_64(self.valence, serializer);
+    sse_encode_f_64(self.arousal, serializer);
+    sse_encode_String(self.dominantEmotion, serializer);
+  }
+
+  @protected
+  void sse_encode_empathy_kind(EmpathyKind self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_encryption_key_source(
+    EncryptionKeySource self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_encryption_secret(
+    EncryptionSecret self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case EncryptionSecret_Passphrase(field0: final field0):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(field0, serializer);
+      case EncryptionSecret_Keystore(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_list_prim_u_8_strict(field0, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_encryption_status(
+    EncryptionStatus self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.enabled, serializer);
+    sse_encode_bool(self.unlocked, serializer);
+    sse_encode_opt_box_autoadd_encryption_key_source(self.source, serializer);
+  }
+
+  @protected
+  void sse_encode_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putFloat64(self);
+  }
+
+  @protected
+  void sse_encode_fact(Fact self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.content, serializer);
+    sse_encode_fact_category(self.category, serializer);
+    sse_encode_u_32(self.sourceTurn, serializer);
+    sse_encode_i_64(self.createdAt, serializer);
+    sse_encode_i_64(self.lastConfirmedAt, serializer);
+    sse_encode_list_String(self.keywords, serializer);
+    sse_encode_list_String(self.entities, serializer);
+    sse_encode_f_64(self.confidence, serializer);
+    sse_encode_u_32(self.hitCount, serializer);
+    sse_encode_String(self.contextSnippet, serializer);
+    sse_encode_list_String(self.summaryIds, serializer);
+    sse_encode_opt_String(self.supersededBy, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.supersededAt, serializer);
+    sse_encode_bool(self.manualOverride, serializer);
+    sse_encode_opt_String(self.personaId, serializer);
+  }
+
+  @protected
+  void sse_encode_fact_category(FactCategory self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_fact_conflict(FactConflict self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.supersededFactId, serializer);
+    sse_encode_String(self.supersededContent, serializer);
+    sse_encode_String(self.activeFactId, serializer);
+    sse_encode_String(self.activeContent, serializer);
+    sse_encode_i_64(self.detectedAt, serializer);
+  }
+
+  @protected
+  void sse_encode_fingerprint_insight(
+    FingerprintInsight self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.openingChars, serializer);
+    sse_encode_u_32(self.paragraphCount, serializer);
+    sse_encode_f_64(self.avgSentenceLen, serializer);
+    sse_encode_String(self.endingChars, serializer);
+    sse_encode_bool(self.endsWithQuestion, serializer);
+    sse_encode_u_32(self.totalLength, serializer);
+    sse_encode_bool(self.hasActionMarker, serializer);
+    sse_encode_bool(self.hasListFormat, serializer);
+    sse_encode_String(self.emotionalTone, serializer);
+  }
+
+  @protected
+  void sse_encode_formality(Formality self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_generation_completed_event(
+    GenerationCompletedEvent self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.messageId, serializer);
+    sse_encode_generation_metadata(self.metadata, serializer);
+  }
+
+  @protected
+  void sse_encode_generation_metadata(
+    GenerationMetadata self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.model, serializer);
+    sse_encode_opt_String(self.finishReason, serializer);
+    sse_encode_u_32(self.promptTokens, serializer);
+    sse_encode_u_32(self.completionTokens, serializer);
+    sse_encode_u_32(self.totalTokens, serializer);
+    sse_encode_u_32(self.cachedTokens, serializer);
+    sse_encode_bool(self.streamed, serializer);
+    sse_encode_bool(self.interrupted, serializer);
+    sse_encode_list_String(self.fallbacks, serializer);
+    sse_encode_i_64(self.latencyMs, serializer);
+  }
+
+  @protected
+  void sse_encode_graph_edge(GraphEdge self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.factId, serializer);
+    sse_encode_String(self.source, serializer);
+    sse_encode_String(self.target, serializer);
+    sse_encode_String(self.relation, serializer);
+    sse_encode_String(self.category, serializer);
+  }
+
+  @protected
+  void sse_encode_graph_export_format(
+    GraphExportFormat self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_graph_node(GraphNode self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.label, serializer);
+    sse_encode_u_32(self.degree, serializer);
+  }
+
+  @protected
+  void sse_encode_group_character(
+    GroupCharacter self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.systemPrompt, serializer);
+  }
+
+  @protected
+  void sse_encode_group_chat(GroupChat self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.conversationId, serializer);
+    sse_encode_list_group_character(self.characters, serializer);
+    sse_encode_turn_policy(self.turnPolicy, serializer);
+  }
+
+  @protected
+  void sse_encode_housekeeping_report(
+    HousekeepingReport self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_String(self.archived, serializer);
+    sse_encode_u_32(self.rolledOverMessages, serializer);
+    sse_encode_u_32(self.purgedFiles, serializer);
+  }
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putInt32(self);
+  }
+
+  @protected
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putPlatformInt64(self);
+  }
+
+  @protected
+  void sse_encode_index_scope(IndexScope self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_integrity_issue(
+    IntegrityIssue self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_integrity_issue_kind(self.kind, serializer);
+    sse_encode_String(self.detail, serializer);
+    sse_encode_bool(self.repaired, serializer);
+  }
+
+  @protected
+  void sse_encode_integrity_issue_kind(
+    IntegrityIssueKind self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_integrity_report(
+    IntegrityReport self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.conversationId, serializer);
+    sse_encode_u_32(self.actualTurns, serializer);
+    sse_encode_u_32(self.recordedTurnCount, serializer);
+    sse_encode_list_integrity_issue(self.issues, serializer);
+    sse_encode_i_64(self.checkedAt, serializer);
+  }
+
+  @protected
+  void sse_encode_intent_kind(IntentKind self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_job_scheduler_stats(
+    JobSchedulerStats self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.running, serializer);
+    sse_encode_u_64(self.completed, serializer);
+    sse_encode_u_64(self.timedOut, serializer);
+    sse_encode_u_64(self.deferredTotal, serializer);
+    sse_encode_list_deferred_job(self.pending, serializer);
+  }
+
+  @protected
+  void sse_encode_knowledge_binding(
+    KnowledgeBinding self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.characterId, serializer);
+    sse_encode_opt_String(self.characterName, serializer);
+    sse_encode_opt_String(self.worldId, serializer);
+  }
+
+  @protected
+  void sse_encode_knowledge_graph_view(
+    KnowledgeGraphView self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_graph_node(self.nodes, serializer);
+    sse_encode_list_graph_edge(self.edges, serializer);
+  }
+
+  @protected
+  void sse_encode_knowledge_layer(
+    KnowledgeLayer self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_language_pattern_kind(
+    LanguagePatternKind self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_layout_verification(
+    LayoutVerification self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.version, serializer);
+    sse_encode_u_32(self.checkedFiles, serializer);
+    sse_encode_list_String(self.missing, serializer);
+    sse_encode_list_String(self.corrupted, serializer);
+    sse_encode_list_String(self.unexpected, serializer);
+  }
+
+  @protected
+  void sse_encode_lexicon_entry(LexiconEntry self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.word, serializer);
+    sse_encode_emotion_dimension(self.dimension, serializer);
+    sse_encode_f_64(self.weight, serializer);
+  }
+
+  @protected
+  void sse_encode_linked_fact(LinkedFact self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.factId, serializer);
+    sse_encode_String(self.content, serializer);
+    sse_encode_u_32(self.sourceTurn, serializer);
+    sse_encode_list_String(self.summaryIds, serializer);
+  }
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_background_task_event(
+    List<BackgroundTaskEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_background_task_event(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_backup_snapshot_info(
+    List<BackupSnapshotInfo> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_backup_snapshot_info(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_blocked_topic(
+    List<BlockedTopic> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_blocked_topic(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_branch_info(
+    List<BranchInfo> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_branch_info(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_character_relation(
+    List<CharacterRelation> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_character_relation(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_conversation_branch(
+    List<ConversationBranch> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_conversation_branch(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_conversation_summary(
+    List<ConversationSummary> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_conversation_summary(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_deferred_job(
+    List<DeferredJob> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_deferred_job(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_degradation_record(
+    List<DegradationRecord> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_degradation_record(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_digest_entry(
+    List<DigestEntry> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_digest_entry(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_emotional_turn_insight(
+    List<EmotionalTurnInsight> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_emotional_turn_insight(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_fact(List<Fact> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_fact(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_fact_conflict(
+    List<FactConflict> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_fact_conflict(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_fingerprint_insight(
+    List<FingerprintInsight> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_fingerprint_insight(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_graph_edge(
+    List<GraphEdge> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_graph_edge(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_graph_node(
+    List<GraphNode> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_graph_node(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_group_character(
+    List<GroupCharacter> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_group_character(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_integrity_issue(
+    List<IntegrityIssue> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_integrity_issue(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_language_pattern_kind(
+    List<LanguagePatternKind> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_language_pattern_kind(item, serializer);
+    }
+  }
 
-  @override
-  Future<bool> crateApiChatApiShouldSummarizeMemory({
-    required String conversationId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(conversationId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 25,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiChatApiShouldSummarizeMemoryConstMeta,
-        argValues: [conversationId],
-        apiImpl: this,
-      ),
-    );
+  @protected
+  void sse_encode_list_lexicon_entry(
+    List<LexiconEntry> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_lexicon_entry(item, serializer);
+    }
   }
 
-  TaskConstMeta get kCrateApiChatApiShouldSummarizeMemoryConstMeta =>
-      const TaskConstMeta(
-        debugName: "should_summarize_memory",
-        argNames: ["conversationId"],
-      );
+  @protected
+  void sse_encode_list_linked_fact(
+    List<LinkedFact> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_linked_fact(item, serializer);
+    }
+  }
 
-  @override
-  Stream<ChatStreamEvent> crateApiChatApiTriggerMemorySummarize({
-    required String conversationId,
-  }) {
-    final sink = RustStreamSink<ChatStreamEvent>();
-    unawaited(
-      handler.executeNormal(
-        NormalTask(
-          callFfi: (port_) {
-            final serializer = SseSerializer(generalizedFrbRustBinding);
-            sse_encode_String(conversationId, serializer);
-            sse_encode_StreamSink_chat_stream_event_Sse(sink, serializer);
-            pdeCallFfi(
-              generalizedFrbRustBinding,
-              serializer,
-              funcId: 26,
-              port: port_,
-            );
-          },
-          codec: SseCodec(
-            decodeSuccessData: sse_decode_unit,
-            decodeErrorData: null,
-          ),
-          constMeta: kCrateApiChatApiTriggerMemorySummarizeConstMeta,
-          argValues: [conversationId, sink],
-          apiImpl: this,
-        ),
-      ),
-    );
-    return sink.stream;
+  @protected
+  void sse_encode_list_lore_entry(
+    List<LoreEntry> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_lore_entry(item, serializer);
+    }
   }
 
-  TaskConstMeta get kCrateApiChatApiTriggerMemorySummarizeConstMeta =>
-      const TaskConstMeta(
-        debugName: "trigger_memory_summarize",
-        argNames: ["conversationId", "sink"],
-      );
+  @protected
+  void sse_encode_list_memory_fidelity_report(
+    List<MemoryFidelityReport> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_memory_fidelity_report(item, serializer);
+    }
+  }
 
-  @override
-  Future<bool> crateApiChatApiValidateApiKey({required String apiKey}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(apiKey, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 27,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiChatApiValidateApiKeyConstMeta,
-        argValues: [apiKey],
-        apiImpl: this,
-      ),
-    );
+  @protected
+  void sse_encode_list_memory_search_result(
+    List<MemorySearchResult> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_memory_search_result(item, serializer);
+    }
   }
 
-  TaskConstMeta get kCrateApiChatApiValidateApiKeyConstMeta =>
-      const TaskConstMeta(debugName: "validate_api_key", argNames: ["apiKey"]);
+  @protected
+  void sse_encode_list_memory_summary(
+    List<MemorySummary> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_memory_summary(item, serializer);
+    }
+  }
 
   @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
+  void sse_encode_list_memory_tier(
+    List<MemoryTier> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_memory_tier(item, serializer);
+    }
   }
 
   @protected
-  RustStreamSink<ChatStreamEvent> dco_decode_StreamSink_chat_stream_event_Sse(
-    dynamic raw,
+  void sse_encode_list_message(List<Message> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_message(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_message_alternative(
+    List<MessageAlternative> self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_message_alternative(item, serializer);
+    }
   }
 
   @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
+  void sse_encode_list_message_attachment(
+    List<MessageAttachment> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_message_attachment(item, serializer);
+    }
   }
 
   @protected
-  AppSettings dco_decode_app_settings(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return AppSettings(
-      apiKey: dco_decode_opt_String(arr[0]),
-      defaultModel: dco_decode_String(arr[1]),
-      enableThinkingByDefault: dco_decode_bool(arr[2]),
-      chatModel: dco_decode_String(arr[3]),
-      thinkingModel: dco_decode_String(arr[4]),
-    );
+  void sse_encode_list_message_edit(
+    List<MessageEdit> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_message_edit(item, serializer);
+    }
   }
 
   @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
+  void sse_encode_list_model_info(
+    List<ModelInfo> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_model_info(item, serializer);
+    }
   }
 
   @protected
-  AppSettings dco_decode_box_autoadd_app_settings(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_app_settings(raw);
+  void sse_encode_list_outbox_entry(
+    List<OutboxEntry> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_outbox_entry(item, serializer);
+    }
   }
 
   @protected
-  Conversation dco_decode_box_autoadd_conversation(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_conversation(raw);
+  void sse_encode_list_phase_duration(
+    List<PhaseDuration> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_phase_duration(item, serializer);
+    }
   }
 
   @protected
-  MemoryContextCard dco_decode_box_autoadd_memory_context_card(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_memory_context_card(raw);
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
   }
 
   @protected
-  ChatStreamEvent dco_decode_chat_stream_event(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
-      case 0:
-        return ChatStreamEvent_ContentDelta(dco_decode_String(raw[1]));
-      case 1:
-        return ChatStreamEvent_ThinkingDelta(dco_decode_String(raw[1]));
-      case 2:
-        return ChatStreamEvent_Done();
-      case 3:
-        return ChatStreamEvent_Error(dco_decode_String(raw[1]));
-      default:
-        throw Exception("unreachable");
+  void sse_encode_list_prompt_template_info(
+    List<PromptTemplateInfo> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_prompt_template_info(item, serializer);
     }
   }
 
   @protected
-  Conversation dco_decode_conversation(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 9)
-      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
-    return Conversation(
-      id: dco_decode_String(arr[0]),
-      title: dco_decode_String(arr[1]),
-      messages: dco_decode_list_message(arr[2]),
-      model: dco_decode_String(arr[3]),
-      createdAt: dco_decode_i_64(arr[4]),
-      updatedAt: dco_decode_i_64(arr[5]),
-      dialogueStyle: dco_decode_dialogue_style(arr[6]),
-      turnCount: dco_decode_u_32(arr[7]),
-      memorySummaries: dco_decode_list_memory_summary(arr[8]),
-    );
+  void sse_encode_list_record_string_string(
+    List<(String, String)> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_record_string_string(item, serializer);
+    }
   }
 
   @protected
-  ConversationSummary dco_decode_conversation_summary(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return ConversationSummary(
-      id: dco_decode_String(arr[0]),
-      title: dco_decode_String(arr[1]),
-      lastMessagePreview: dco_decode_String(arr[2]),
-      model: dco_decode_String(arr[3]),
-      updatedAt: dco_decode_i_64(arr[4]),
-    );
+  void sse_encode_list_relation_change(
+    List<RelationChange> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_relation_change(item, serializer);
+    }
   }
 
   @protected
-  DialogueStyle dco_decode_dialogue_style(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return DialogueStyle.values[raw as int];
+  void sse_encode_list_safety_rule(
+    List<SafetyRule> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_safety_rule(item, serializer);
+    }
   }
 
   @protected
-  double dco_decode_f_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
+  void sse_encode_list_say_do_rule(
+    List<SayDoRule> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_say_do_rule(item, serializer);
+    }
   }
 
   @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_list_search_hit(
+    List<SearchHit> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_search_hit(item, serializer);
+    }
   }
 
   @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeI64(raw);
+  void sse_encode_list_shadow_eval_record(
+    List<ShadowEvalRecord> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_shadow_eval_record(item, serializer);
+    }
   }
 
   @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  void sse_encode_list_timeline_entry(
+    List<TimelineEntry> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_timeline_entry(item, serializer);
+    }
   }
 
   @protected
-  List<ConversationSummary> dco_decode_list_conversation_summary(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_conversation_summary).toList();
+  void sse_encode_list_turn_metrics(
+    List<TurnMetrics> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_turn_metrics(item, serializer);
+    }
   }
 
   @protected
-  List<MemorySearchResult> dco_decode_list_memory_search_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_memory_search_result).toList();
+  void sse_encode_list_user_persona(
+    List<UserPersona> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_user_persona(item, serializer);
+    }
   }
 
   @protected
-  List<MemorySummary> dco_decode_list_memory_summary(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_memory_summary).toList();
+  void sse_encode_lore_entry(LoreEntry self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_String(self.content, serializer);
+    sse_encode_list_String(self.keywords, serializer);
+    sse_encode_u_32(self.insertionDepth, serializer);
+    sse_encode_i_32(self.priority, serializer);
+    sse_encode_bool(self.enabled, serializer);
   }
 
   @protected
-  List<MemoryTier> dco_decode_list_memory_tier(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_memory_tier).toList();
+  void sse_encode_memory_context_card(
+    MemoryContextCard self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.sourceRange, serializer);
+    sse_encode_list_String(self.topicTags, serializer);
+    sse_encode_list_String(self.keyEntities, serializer);
+    sse_encode_String(self.emotionalTone, serializer);
+    sse_encode_list_String(self.causalLinks, serializer);
   }
 
   @protected
-  List<Message> dco_decode_list_message(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_message).toList();
+  void sse_encode_memory_explanation(
+    MemoryExplanation self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.summaryId, serializer);
+    sse_encode_String(self.summary, serializer);
+    sse_encode_u_32(self.turnRangeStart, serializer);
+    sse_encode_u_32(self.turnRangeEnd, serializer);
+    sse_encode_u_32(self.compressionGeneration, serializer);
+    sse_encode_list_linked_fact(self.linkedFacts, serializer);
   }
 
   @protected
-  List<ModelInfo> dco_decode_list_model_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_model_info).toList();
+  void sse_encode_memory_fidelity_report(
+    MemoryFidelityReport self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.conversationId, serializer);
+    sse_encode_f_64(self.score, serializer);
+    sse_encode_u_32(self.sampledFacts, serializer);
+    sse_encode_list_String(self.lostFacts, serializer);
+    sse_encode_opt_box_autoadd_f_64(self.previousScore, serializer);
+    sse_encode_bool(self.alert, serializer);
+    sse_encode_i_64(self.auditedAt, serializer);
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+  void sse_encode_memory_search_result(
+    MemorySearchResult self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.summary, serializer);
+    sse_encode_list_String(self.coreFacts, serializer);
+    sse_encode_f_64(self.relevanceScore, serializer);
   }
 
   @protected
-  MemoryContextCard dco_decode_memory_context_card(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return MemoryContextCard(
-      sourceRange: dco_decode_String(arr[0]),
-      topicTags: dco_decode_list_String(arr[1]),
-      keyEntities: dco_decode_list_String(arr[2]),
-      emotionalTone: dco_decode_String(arr[3]),
-      causalLinks: dco_decode_list_String(arr[4]),
+  void sse_encode_memory_summary(MemorySummary self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.summary, serializer);
+    sse_encode_list_String(self.coreFacts, serializer);
+    sse_encode_u_32(self.turnRangeStart, serializer);
+    sse_encode_u_32(self.turnRangeEnd, serializer);
+    sse_encode_i_64(self.createdAt, serializer);
+    sse_encode_list_String(self.keywords, serializer);
+    sse_encode_u_32(self.compressionGeneration, serializer);
+    sse_encode_opt_box_autoadd_memory_context_card(
+      self.contextCard,
+      serializer,
     );
+    sse_encode_list_memory_tier(self.factTiers, serializer);
+    sse_encode_list_String(self.linkedFactIds, serializer);
+    sse_encode_bool(self.pinned, serializer);
+    sse_encode_list_String(self.pinnedFacts, serializer);
+    sse_encode_bool(self.stale, serializer);
   }
 
   @protected
-  MemorySearchResult dco_decode_memory_search_result(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return MemorySearchResult(
-      summary: dco_decode_String(arr[0]),
-      coreFacts: dco_decode_list_String(arr[1]),
-      relevanceScore: dco_decode_f_64(arr[2]),
-    );
+  void sse_encode_memory_tier(MemoryTier self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  MemorySummary dco_decode_memory_summary(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 10)
-      throw Exception('unexpected arr length: expect 10 but see ${arr.length}');
-    return MemorySummary(
-      id: dco_decode_String(arr[0]),
-      summary: dco_decode_String(arr[1]),
-      coreFacts: dco_decode_list_String(arr[2]),
-      turnRangeStart: dco_decode_u_32(arr[3]),
-      turnRangeEnd: dco_decode_u_32(arr[4]),
-      createdAt: dco_decode_i_64(arr[5]),
-      keywords: dco_decode_list_String(arr[6]),
-      compressionGeneration: dco_decode_u_32(arr[7]),
-      contextCard: dco_decode_opt_box_autoadd_memory_context_card(arr[8]),
-      factTiers: dco_decode_list_memory_tier(arr[9]),
+  void sse_encode_message(Message self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_message_role(self.role, serializer);
+    sse_encode_String(self.content, serializer);
+    sse_encode_opt_String(self.thinkingContent, serializer);
+    sse_encode_String(self.model, serializer);
+    sse_encode_i_64(self.timestamp, serializer);
+    sse_encode_message_type(self.messageType, serializer);
+    sse_encode_opt_box_autoadd_generation_metadata(
+      self.generationMetadata,
+      serializer,
     );
+    sse_encode_opt_String(self.characterId, serializer);
+    sse_encode_list_message_attachment(self.attachments, serializer);
+    sse_encode_opt_String(self.audioPath, serializer);
+    sse_encode_list_message_alternative(self.alternatives, serializer);
+    sse_encode_u_32(self.selectedAlternative, serializer);
+    sse_encode_bool(self.starred, serializer);
+    sse_encode_list_String(self.reactions, serializer);
+    sse_encode_list_message_edit(self.editHistory, serializer);
   }
 
   @protected
-  MemoryTier dco_decode_memory_tier(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return MemoryTier.values[raw as int];
-  }
-
-  @protected
-  Message dco_decode_message(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 7)
-      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
-    return Message(
-      id: dco_decode_String(arr[0]),
-      role: dco_decode_message_role(arr[1]),
-      content: dco_decode_String(arr[2]),
-      thinkingContent: dco_decode_opt_String(arr[3]),
-      model: dco_decode_String(arr[4]),
-      timestamp: dco_decode_i_64(arr[5]),
-      messageType: dco_decode_message_type(arr[6]),
+  void sse_encode_message_alternative(
+    MessageAlternative self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.content, serializer);
+    sse_encode_opt_String(self.thinkingContent, serializer);
+    sse_encode_String(self.model, serializer);
+    sse_encode_i_64(self.timestamp, serializer);
+    sse_encode_opt_box_autoadd_generation_metadata(
+      self.generationMetadata,
+      serializer,
     );
+    sse_encode_opt_String(self.audioPath, serializer);
   }
 
   @protected
-  MessageRole dco_decode_message_role(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return MessageRole.values[raw as int];
+  void sse_encode_message_attachment(
+    MessageAttachment self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.mimeType, serializer);
+    sse_encode_opt_String(self.url, serializer);
+    sse_encode_opt_String(self.caption, serializer);
   }
 
   @protected
-  MessageType dco_decode_message_type(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return MessageType.values[raw as int];
+  void sse_encode_message_edit(MessageEdit self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.content, serializer);
+    sse_encode_i_64(self.timestamp, serializer);
+    sse_encode_i_64(self.editedAt, serializer);
   }
 
   @protected
-  ModelInfo dco_decode_model_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return ModelInfo(
-      id: dco_decode_String(arr[0]),
-      name: dco_decode_String(arr[1]),
-      contextTokens: dco_decode_usize(arr[2]),
-      maxOutputTokens: dco_decode_usize(arr[3]),
-      supportsThinking: dco_decode_bool(arr[4]),
-    );
+  void sse_encode_message_role(MessageRole self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
+  void sse_encode_message_type(MessageType self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  Conversation? dco_decode_opt_box_autoadd_conversation(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_conversation(raw);
+  void sse_encode_metric_phase(MetricPhase self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  MemoryContextCard? dco_decode_opt_box_autoadd_memory_context_card(
-    dynamic raw,
+  void sse_encode_metrics_snapshot(
+    MetricsSnapshot self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_memory_context_card(raw);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_turn_metrics(self.turns, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.avgFirstTokenMs, serializer);
+    sse_encode_list_phase_duration(self.avgPhaseMs, serializer);
+    sse_encode_u_32(self.totalRetries, serializer);
   }
 
   @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_model_info(ModelInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_usize(self.contextTokens, serializer);
+    sse_encode_usize(self.maxOutputTokens, serializer);
+    sse_encode_bool(self.supportsThinking, serializer);
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_mood_state(MoodState self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self.valence, serializer);
+    sse_encode_f_64(self.arousal, serializer);
+    sse_encode_String(self.mood, serializer);
+    sse_encode_i_64(self.updatedAt, serializer);
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  void sse_encode_narration_perspective(
+    NarrationPerspective self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
+    }
   }
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_ambient_context(
+    AmbientContext? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_ambient_context(self, serializer);
+    }
   }
 
   @protected
-  RustStreamSink<ChatStreamEvent> sse_decode_StreamSink_chat_stream_event_Sse(
-    SseDeserializer deserializer,
+  void sse_encode_opt_box_autoadd_backup_snapshot_info(
+    BackupSnapshotInfo? self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    throw UnimplementedError('Unreachable ()');
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_backup_snapshot_info(self, serializer);
+    }
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_bool(self, serializer);
+    }
   }
 
   @protected
-  AppSettings sse_decode_app_settings(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_check_in_schedule(
+    CheckInSchedule? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_apiKey = sse_decode_opt_String(deserializer);
-    var var_defaultModel = sse_decode_String(deserializer);
-    var var_enableThinkingByDefault = sse_decode_bool(deserializer);
-    var var_chatModel = sse_decode_String(deserializer);
-    var var_thinkingModel = sse_decode_String(deserializer);
-    return AppSettings(
-      apiKey: var_apiKey,
-      defaultModel: var_defaultModel,
-      enableThinkingByDefault: var_enableThinkingByDefault,
-      chatModel: var_chatModel,
-      thinkingModel: var_thinkingModel,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_check_in_schedule(self, serializer);
+    }
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_cognitive_insight(
+    CognitiveInsight? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_cognitive_insight(self, serializer);
+    }
   }
 
   @protected
-  AppSettings sse_decode_box_autoadd_app_settings(
-    SseDeserializer deserializer,
+  void sse_encode_opt_box_autoadd_conversation(
+    Conversation? self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_app_settings(deserializer));
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_conversation(self, serializer);
+    }
   }
 
   @protected
-  Conversation sse_decode_box_autoadd_conversation(
-    SseDeserializer deserializer,
+  void sse_encode_opt_box_autoadd_conversation_insights(
+    ConversationInsights? self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_conversation(deserializer));
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_conversation_insights(self, serializer);
+    }
   }
 
   @protected
-  MemoryContextCard sse_decode_box_autoadd_memory_context_card(
-    SseDeserializer deserializer,
+  void sse_encode_opt_box_autoadd_conversation_organization(
+    ConversationOrganization? self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_memory_context_card(deserializer));
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_conversation_organization(self, serializer);
+    }
   }
 
   @protected
-  ChatStreamEvent sse_decode_chat_stream_event(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_daily_digest(
+    DailyDigest? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        var var_field0 = sse_decode_String(deserializer);
-        return ChatStreamEvent_ContentDelta(var_field0);
-      case 1:
-        var var_field0 = sse_decode_String(deserializer);
-        return ChatStreamEvent_ThinkingDelta(var_field0);
-      case 2:
-        return ChatStreamEvent_Done();
-      case 3:
-        var var_field0 = sse_decode_String(deserializer);
-        return ChatStreamEvent_Error(var_field0);
-      default:
-        throw UnimplementedError('');
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_daily_digest(self, serializer);
     }
   }
 
   @protected
-  Conversation sse_decode_conversation(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_distilled_system_state(
+    DistilledSystemState? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_String(deserializer);
-    var var_title = sse_decode_String(deserializer);
-    var var_messages = sse_decode_list_message(deserializer);
-    var var_model = sse_decode_String(deserializer);
-    var var_createdAt = sse_decode_i_64(deserializer);
-    var var_updatedAt = sse_decode_i_64(deserializer);
-    var var_dialogueStyle = sse_decode_dialogue_style(deserializer);
-    var var_turnCount = sse_decode_u_32(deserializer);
-    var var_memorySummaries = sse_decode_list_memory_summary(deserializer);
-    return Conversation(
-      id: var_id,
-      title: var_title,
-      messages: var_messages,
-      model: var_model,
-      createdAt: var_createdAt,
-      updatedAt: var_updatedAt,
-      dialogueStyle: var_dialogueStyle,
-      turnCount: var_turnCount,
-      memorySummaries: var_memorySummaries,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_distilled_system_state(self, serializer);
+    }
   }
 
   @protected
-  ConversationSummary sse_decode_conversation_summary(
-    SseDeserializer deserializer,
+  void sse_encode_opt_box_autoadd_encryption_key_source(
+    EncryptionKeySource? self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_String(deserializer);
-    var var_title = sse_decode_String(deserializer);
-    var var_lastMessagePreview = sse_decode_String(deserializer);
-    var var_model = sse_decode_String(deserializer);
-    var var_updatedAt = sse_decode_i_64(deserializer);
-    return ConversationSummary(
-      id: var_id,
-      title: var_title,
-      lastMessagePreview: var_lastMessagePreview,
-      model: var_model,
-      updatedAt: var_updatedAt,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_encryption_key_source(self, serializer);
+    }
   }
 
   @protected
-  DialogueStyle sse_decode_dialogue_style(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return DialogueStyle.values[inner];
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_f_64(self, serializer);
+    }
   }
 
   @protected
-  double sse_decode_f_64(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_fact(Fact? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat64();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_fact(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_generation_metadata(
+    GenerationMetadata? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_generation_metadata(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_group_chat(
+    GroupChat? self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_group_chat(self, serializer);
+    }
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_i_64(self, serializer);
+    }
   }
 
   @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_integrity_report(
+    IntegrityReport? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getPlatformInt64();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_integrity_report(self, serializer);
+    }
   }
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_layout_verification(
+    LayoutVerification? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_layout_verification(self, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<ConversationSummary> sse_decode_list_conversation_summary(
-    SseDeserializer deserializer,
+  void sse_encode_opt_box_autoadd_lore_entry(
+    LoreEntry? self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ConversationSummary>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_conversation_summary(deserializer));
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_lore_entry(self, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<MemorySearchResult> sse_decode_list_memory_search_result(
-    SseDeserializer deserializer,
+  void sse_encode_opt_box_autoadd_memory_context_card(
+    MemoryContextCard? self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <MemorySearchResult>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_memory_search_result(deserializer));
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_memory_context_card(self, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<MemorySummary> sse_decode_list_memory_summary(
-    SseDeserializer deserializer,
+  void sse_encode_opt_box_autoadd_memory_explanation(
+    MemoryExplanation? self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <MemorySummary>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_memory_summary(deserializer));
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_memory_explanation(self, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<MemoryTier> sse_decode_list_memory_tier(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_memory_fidelity_report(
+    MemoryFidelityReport? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <MemoryTier>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_memory_tier(deserializer));
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_memory_fidelity_report(self, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<Message> sse_decode_list_message(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_memory_summary(
+    MemorySummary? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Message>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_message(deserializer));
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_memory_summary(self, serializer);
     }
-    return ans_;
   }
 
   @protected
-  List<ModelInfo> sse_decode_list_model_info(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_message(
+    Message? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <ModelInfo>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_model_info(deserializer));
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_message(self, serializer);
     }
-    return ans_;
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_mood_state(
+    MoodState? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_mood_state(self, serializer);
+    }
   }
 
   @protected
-  MemoryContextCard sse_decode_memory_context_card(
-    SseDeserializer deserializer,
+  void sse_encode_opt_box_autoadd_preflight_advisory(
+    PreflightAdvisory? self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_sourceRange = sse_decode_String(deserializer);
-    var var_topicTags = sse_decode_list_String(deserializer);
-    var var_keyEntities = sse_decode_list_String(deserializer);
-    var var_emotionalTone = sse_decode_String(deserializer);
-    var var_causalLinks = sse_decode_list_String(deserializer);
-    return MemoryContextCard(
-      sourceRange: var_sourceRange,
-      topicTags: var_topicTags,
-      keyEntities: var_keyEntities,
-      emotionalTone: var_emotionalTone,
-      causalLinks: var_causalLinks,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_preflight_advisory(self, serializer);
+    }
   }
 
   @protected
-  MemorySearchResult sse_decode_memory_search_result(
-    SseDeserializer deserializer,
+  void sse_encode_opt_box_autoadd_proxy_settings(
+    ProxySettings? self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_summary = sse_decode_String(deserializer);
-    var var_coreFacts = sse_decode_list_String(deserializer);
-    var var_relevanceScore = sse_decode_f_64(deserializer);
-    return MemorySearchResult(
-      summary: var_summary,
-      coreFacts: var_coreFacts,
-      relevanceScore: var_relevanceScore,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_proxy_settings(self, serializer);
+    }
   }
 
   @protected
-  MemorySummary sse_decode_memory_summary(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_reengagement_brief(
+    ReengagementBrief? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_String(deserializer);
-    var var_summary = sse_decode_String(deserializer);
-    var var_coreFacts = sse_decode_list_String(deserializer);
-    var var_turnRangeStart = sse_decode_u_32(deserializer);
-    var var_turnRangeEnd = sse_decode_u_32(deserializer);
-    var var_createdAt = sse_decode_i_64(deserializer);
-    var var_keywords = sse_decode_list_String(deserializer);
-    var var_compressionGeneration = sse_decode_u_32(deserializer);
-    var var_contextCard = sse_decode_opt_box_autoadd_memory_context_card(
-      deserializer,
-    );
-    var var_factTiers = sse_decode_list_memory_tier(deserializer);
-    return MemorySummary(
-      id: var_id,
-      summary: var_summary,
-      coreFacts: var_coreFacts,
-      turnRangeStart: var_turnRangeStart,
-      turnRangeEnd: var_turnRangeEnd,
-      createdAt: var_createdAt,
-      keywords: var_keywords,
-      compressionGeneration: var_compressionGeneration,
-      contextCard: var_contextCard,
-      factTiers: var_factTiers,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_reengagement_brief(self, serializer);
+    }
   }
 
   @protected
-  MemoryTier sse_decode_memory_tier(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_relevance_insight(
+    RelevanceInsight? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return MemoryTier.values[inner];
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_relevance_insight(self, serializer);
+    }
   }
 
   @protected
-  Message sse_decode_message(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_say_do_mark(
+    SayDoMark? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_String(deserializer);
-    var var_role = sse_decode_message_role(deserializer);
-    var var_content = sse_decode_String(deserializer);
-    var var_thinkingContent = sse_decode_opt_String(deserializer);
-    var var_model = sse_decode_String(deserializer);
-    var var_timestamp = sse_decode_i_64(deserializer);
-    var var_messageType = sse_decode_message_type(deserializer);
-    return Message(
-      id: var_id,
-      role: var_role,
-      content: var_content,
-      thinkingContent: var_thinkingContent,
-      model: var_model,
-      timestamp: var_timestamp,
-      messageType: var_messageType,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_say_do_mark(self, serializer);
+    }
   }
 
   @protected
-  MessageRole sse_decode_message_role(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_summarization_config(
+    SummarizationConfig? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return MessageRole.values[inner];
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_summarization_config(self, serializer);
+    }
   }
 
   @protected
-  MessageType sse_decode_message_type(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return MessageType.values[inner];
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
+    }
   }
 
   @protected
-  ModelInfo sse_decode_model_info(SseDeserializer deserializer) {
+  void sse_encode_outbox_entry(OutboxEntry self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_String(deserializer);
-    var var_name = sse_decode_String(deserializer);
-    var var_contextTokens = sse_decode_usize(deserializer);
-    var var_maxOutputTokens = sse_decode_usize(deserializer);
-    var var_supportsThinking = sse_decode_bool(deserializer);
-    return ModelInfo(
-      id: var_id,
-      name: var_name,
-      contextTokens: var_contextTokens,
-      maxOutputTokens: var_maxOutputTokens,
-      supportsThinking: var_supportsThinking,
-    );
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.conversationId, serializer);
+    sse_encode_String(self.content, serializer);
+    sse_encode_list_message_attachment(self.attachments, serializer);
+    sse_encode_String(self.model, serializer);
+    sse_encode_bool(self.enableThinking, serializer);
+    sse_encode_outbox_status(self.status, serializer);
+    sse_encode_i_64(self.queuedAt, serializer);
+    sse_encode_opt_String(self.lastError, serializer);
   }
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
+  void sse_encode_outbox_status(OutboxStatus self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
-    }
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  Conversation? sse_decode_opt_box_autoadd_conversation(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_phase_duration(PhaseDuration self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_conversation(deserializer));
-    } else {
-      return null;
-    }
+    sse_encode_metric_phase(self.phase, serializer);
+    sse_encode_i_64(self.durationMs, serializer);
   }
 
   @protected
-  MemoryContextCard? sse_decode_opt_box_autoadd_memory_context_card(
-    SseDeserializer deserializer,
+  void sse_encode_preflight_advisory(
+    PreflightAdvisory self,
+    SseSerializer serializer,
   ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_memory_context_card(deserializer));
-    } else {
-      return null;
-    }
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_draft_tone(self.tone, serializer);
+    sse_encode_f_64(self.regretRisk, serializer);
+    sse_encode_f_64(self.tensionDelta, serializer);
+    sse_encode_f_64(self.closenessDelta, serializer);
+    sse_encode_bool(self.lateNight, serializer);
+    sse_encode_bool(self.suggestPause, serializer);
+    sse_encode_list_String(self.notes, serializer);
   }
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
+  void sse_encode_prompt_template_info(
+    PromptTemplateInfo self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.description, serializer);
+    sse_encode_list_String(self.variables, serializer);
+    sse_encode_bool(self.customized, serializer);
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_provider_kind(ProviderKind self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_proxy_settings(ProxySettings self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.url, serializer);
+    sse_encode_opt_String(self.username, serializer);
+    sse_encode_opt_String(self.password, serializer);
+    sse_encode_list_String(self.bypass, serializer);
   }
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
+  void sse_encode_reaction_event(ReactionEvent self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+    sse_encode_String(self.emoji, serializer);
+    sse_encode_String(self.label, serializer);
   }
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
+  void sse_encode_record_string_string(
+    (String, String) self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
+    sse_encode_String(self.$1, serializer);
+    sse_encode_String(self.$2, serializer);
   }
 
   @protected
-  void sse_encode_StreamSink_chat_stream_event_Sse(
-    RustStreamSink<ChatStreamEvent> self,
+  void sse_encode_reengagement_brief(
+    ReengagementBrief self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(
-      self.setupAndSerialize(
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_chat_stream_event,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-      ),
-      serializer,
-    );
+    sse_encode_String(self.conversationId, serializer);
+    sse_encode_u_32(self.elapsedDays, serializer);
+    sse_encode_String(self.recap, serializer);
+    sse_encode_String(self.opener, serializer);
+    sse_encode_list_String(self.pendingThreads, serializer);
+    sse_encode_i_64(self.generatedAt, serializer);
+    sse_encode_String(self.lastMessageId, serializer);
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_reindex_progress(
+    ReindexProgress self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+    sse_encode_String(self.conversationId, serializer);
+    sse_encode_u_32(self.completed, serializer);
+    sse_encode_u_32(self.total, serializer);
+    sse_encode_u_32(self.rebuiltEntries, serializer);
+    sse_encode_opt_String(self.error, serializer);
   }
 
   @protected
-  void sse_encode_app_settings(AppSettings self, SseSerializer serializer) {
+  void sse_encode_relation_change(
+    RelationChange self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_opt_String(self.apiKey, serializer);
-    sse_encode_String(self.defaultModel, serializer);
-    sse_encode_bool(self.enableThinkingByDefault, serializer);
-    sse_encode_String(self.chatModel, serializer);
-    sse_encode_String(self.thinkingModel, serializer);
+    sse_encode_String(self.relation, serializer);
+    sse_encode_String(self.factId, serializer);
+    sse_encode_u_32(self.sourceTurn, serializer);
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_relation_sentiment(
+    RelationSentiment self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_app_settings(
-    AppSettings self,
+  void sse_encode_relationship_insight(
+    RelationshipInsight self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_app_settings(self, serializer);
+    sse_encode_f_64(self.closeness, serializer);
+    sse_encode_f_64(self.trustLevel, serializer);
+    sse_encode_f_64(self.tension, serializer);
+    sse_encode_f_64(self.powerBalance, serializer);
+    sse_encode_f_64(self.trend, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_conversation(
-    Conversation self,
+  void sse_encode_relevance_insight(
+    RelevanceInsight self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_conversation(self, serializer);
+    sse_encode_f_64(self.tfidfScore, serializer);
+    sse_encode_f_64(self.keywordOverlap, serializer);
+    sse_encode_f_64(self.topicMatch, serializer);
+    sse_encode_f_64(self.finalScore, serializer);
   }
 
   @protected
-  void sse_encode_box_autoadd_memory_context_card(
-    MemoryContextCard self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_reply_length(ReplyLength self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_memory_context_card(self, serializer);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_chat_stream_event(
-    ChatStreamEvent self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_reply_signals(ReplySignals self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    switch (self) {
-      case ChatStreamEvent_ContentDelta(field0: final field0):
-        sse_encode_i_32(0, serializer);
-        sse_encode_String(field0, serializer);
-      case ChatStreamEvent_ThinkingDelta(field0: final field0):
-        sse_encode_i_32(1, serializer);
-        sse_encode_String(field0, serializer);
-      case ChatStreamEvent_Done():
-        sse_encode_i_32(2, serializer);
-      case ChatStreamEvent_Error(field0: final field0):
-        sse_encode_i_32(3, serializer);
-        sse_encode_String(field0, serializer);
-    }
+    sse_encode_u_32(self.lengthChars, serializer);
+    sse_encode_u_32(self.groundedFacts, serializer);
+    sse_encode_f_64(self.userOverlap, serializer);
+    sse_encode_f_64(self.repetition, serializer);
   }
 
   @protected
-  void sse_encode_conversation(Conversation self, SseSerializer serializer) {
+  void sse_encode_response_style(ResponseStyle self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.id, serializer);
-    sse_encode_String(self.title, serializer);
-    sse_encode_list_message(self.messages, serializer);
-    sse_encode_String(self.model, serializer);
-    sse_encode_i_64(self.createdAt, serializer);
-    sse_encode_i_64(self.updatedAt, serializer);
-    sse_encode_dialogue_style(self.dialogueStyle, serializer);
-    sse_encode_u_32(self.turnCount, serializer);
-    sse_encode_list_memory_summary(self.memorySummaries, serializer);
+    sse_encode_reply_length(self.replyLength, serializer);
+    sse_encode_formality(self.formality, serializer);
+    sse_encode_emoji_usage(self.emojiUsage, serializer);
+    sse_encode_action_frequency(self.actionFrequency, serializer);
   }
 
   @protected
-  void sse_encode_conversation_summary(
-    ConversationSummary self,
+  void sse_encode_retention_policy(
+    RetentionPolicy self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.id, serializer);
-    sse_encode_String(self.title, serializer);
-    sse_encode_String(self.lastMessagePreview, serializer);
-    sse_encode_String(self.model, serializer);
-    sse_encode_i_64(self.updatedAt, serializer);
+    sse_encode_u_32(self.archiveAfterIdleDays, serializer);
+    sse_encode_u_32(self.maxMessagesPerConversation, serializer);
+    sse_encode_bool(self.purgeOrphans, serializer);
   }
 
   @protected
-  void sse_encode_dialogue_style(DialogueStyle self, SseSerializer serializer) {
+  void sse_encode_retry_policy(RetryPolicy self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
+    sse_encode_u_32(self.maxRetries, serializer);
+    sse_encode_u_64(self.initialDelayMs, serializer);
+    sse_encode_u_64(self.maxDelayMs, serializer);
+    sse_encode_backoff_strategy(self.backoff, serializer);
+    sse_encode_bool(self.retryNetworkErrors, serializer);
+    sse_encode_bool(self.retryStreamErrors, serializer);
+    sse_encode_bool(self.retryServerErrors, serializer);
+    sse_encode_bool(self.retryRateLimited, serializer);
+    sse_encode_u_64(self.maxRetryAfterSecs, serializer);
   }
 
   @protected
-  void sse_encode_f_64(double self, SseSerializer serializer) {
+  void sse_encode_safety_action(SafetyAction self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat64(self);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
+  void sse_encode_safety_category(
+    SafetyCategory self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
+  void sse_encode_safety_event(SafetyEvent self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putPlatformInt64(self);
+    sse_encode_safety_stage(self.stage, serializer);
+    sse_encode_safety_category(self.category, serializer);
+    sse_encode_safety_action(self.action, serializer);
+    sse_encode_list_String(self.matched, serializer);
   }
 
   @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+  void sse_encode_safety_policy(SafetyPolicy self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_String(item, serializer);
-    }
+    sse_encode_bool(self.enabled, serializer);
+    sse_encode_bool(self.checkInput, serializer);
+    sse_encode_bool(self.checkOutput, serializer);
+    sse_encode_list_safety_rule(self.rules, serializer);
   }
 
   @protected
-  void sse_encode_list_conversation_summary(
-    List<ConversationSummary> self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_safety_rule(SafetyRule self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_conversation_summary(item, serializer);
-    }
+    sse_encode_safety_category(self.category, serializer);
+    sse_encode_safety_action(self.action, serializer);
+    sse_encode_list_String(self.extraTerms, serializer);
   }
 
   @protected
-  void sse_encode_list_memory_search_result(
-    List<MemorySearchResult> self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_safety_stage(SafetyStage self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_memory_search_result(item, serializer);
-    }
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_list_memory_summary(
-    List<MemorySummary> self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_say_do_mark(SayDoMark self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_memory_summary(item, serializer);
-    }
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_list_memory_tier(
-    List<MemoryTier> self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_say_do_rule(SayDoRule self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_memory_tier(item, serializer);
-    }
+    sse_encode_String(self.pattern, serializer);
+    sse_encode_say_do_mark(self.mark, serializer);
+    sse_encode_i_32(self.priority, serializer);
   }
 
   @protected
-  void sse_encode_list_message(List<Message> self, SseSerializer serializer) {
+  void sse_encode_say_do_rule_set(SayDoRuleSet self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_message(item, serializer);
-    }
+    sse_encode_list_say_do_rule(self.rules, serializer);
+    sse_encode_opt_box_autoadd_say_do_mark(self.unmatched, serializer);
   }
 
   @protected
-  void sse_encode_list_model_info(
-    List<ModelInfo> self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_search_hit(SearchHit self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_model_info(item, serializer);
-    }
+    sse_encode_String(self.conversationId, serializer);
+    sse_encode_String(self.conversationTitle, serializer);
+    sse_encode_String(self.messageId, serializer);
+    sse_encode_message_role(self.role, serializer);
+    sse_encode_String(self.snippet, serializer);
+    sse_encode_u_32(self.highlightStart, serializer);
+    sse_encode_u_32(self.highlightEnd, serializer);
+    sse_encode_i_64(self.timestamp, serializer);
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
+  void sse_encode_shadow_eval_record(
+    ShadowEvalRecord self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+    sse_encode_u_32(self.turn, serializer);
+    sse_encode_u_32(self.injectedFacts, serializer);
+    sse_encode_String(self.mainModel, serializer);
+    sse_encode_String(self.shadowModel, serializer);
+    sse_encode_reply_signals(self.main, serializer);
+    sse_encode_reply_signals(self.shadow, serializer);
+    sse_encode_i_64(self.createdAt, serializer);
   }
 
   @protected
-  void sse_encode_memory_context_card(
-    MemoryContextCard self,
+  void sse_encode_shadow_eval_summary(
+    ShadowEvalSummary self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.sourceRange, serializer);
-    sse_encode_list_String(self.topicTags, serializer);
-    sse_encode_list_String(self.keyEntities, serializer);
-    sse_encode_String(self.emotionalTone, serializer);
-    sse_encode_list_String(self.causalLinks, serializer);
+    sse_encode_u_32(self.samples, serializer);
+    sse_encode_f_64(self.avgGroundedGain, serializer);
+    sse_encode_f_64(self.avgOverlapGain, serializer);
+    sse_encode_f_64(self.avgRepetitionDelta, serializer);
+    sse_encode_f_64(self.groundedWinRate, serializer);
   }
 
   @protected
-  void sse_encode_memory_search_result(
-    MemorySearchResult self,
+  void sse_encode_short_term_insight(
+    ShortTermInsight self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.summary, serializer);
-    sse_encode_list_String(self.coreFacts, serializer);
-    sse_encode_f_64(self.relevanceScore, serializer);
+    sse_encode_list_String(self.activeTopics, serializer);
+    sse_encode_list_emotional_turn_insight(self.emotionalArc, serializer);
+    sse_encode_list_String(self.pendingThreads, serializer);
+    sse_encode_list_fingerprint_insight(self.responseFingerprints, serializer);
   }
 
   @protected
-  void sse_encode_memory_summary(MemorySummary self, SseSerializer serializer) {
+  void sse_encode_summarization_config(
+    SummarizationConfig self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.id, serializer);
-    sse_encode_String(self.summary, serializer);
-    sse_encode_list_String(self.coreFacts, serializer);
-    sse_encode_u_32(self.turnRangeStart, serializer);
-    sse_encode_u_32(self.turnRangeEnd, serializer);
-    sse_encode_i_64(self.createdAt, serializer);
-    sse_encode_list_String(self.keywords, serializer);
-    sse_encode_u_32(self.compressionGeneration, serializer);
-    sse_encode_opt_box_autoadd_memory_context_card(
-      self.contextCard,
-      serializer,
-    );
-    sse_encode_list_memory_tier(self.factTiers, serializer);
+    sse_encode_u_32(self.intervalTurns, serializer);
+    sse_encode_u_32(self.windowMessages, serializer);
   }
 
   @protected
-  void sse_encode_memory_tier(MemoryTier self, SseSerializer serializer) {
+  void sse_encode_sync_backend_kind(
+    SyncBackendKind self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_message(Message self, SseSerializer serializer) {
+  void sse_encode_sync_report(SyncReport self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.id, serializer);
-    sse_encode_message_role(self.role, serializer);
-    sse_encode_String(self.content, serializer);
-    sse_encode_opt_String(self.thinkingContent, serializer);
-    sse_encode_String(self.model, serializer);
-    sse_encode_i_64(self.timestamp, serializer);
-    sse_encode_message_type(self.messageType, serializer);
+    sse_encode_u_32(self.pushed, serializer);
+    sse_encode_u_32(self.pulled, serializer);
+    sse_encode_u_32(self.deleted, serializer);
+    sse_encode_list_String(self.conflicts, serializer);
   }
 
   @protected
-  void sse_encode_message_role(MessageRole self, SseSerializer serializer) {
+  void sse_encode_sync_settings(SyncSettings self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
+    sse_encode_sync_backend_kind(self.backend, serializer);
+    sse_encode_String(self.endpoint, serializer);
+    sse_encode_String(self.username, serializer);
+    sse_encode_String(self.password, serializer);
+    sse_encode_String(self.bucket, serializer);
+    sse_encode_String(self.region, serializer);
+    sse_encode_String(self.remoteRoot, serializer);
   }
 
   @protected
-  void sse_encode_message_type(MessageType self, SseSerializer serializer) {
+  void sse_encode_thinking_retention(
+    ThinkingRetention self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
+    switch (self) {
+      case ThinkingRetention_KeepAll():
+        sse_encode_i_32(0, serializer);
+      case ThinkingRetention_KeepLastTurns(field0: final field0):
+        sse_encode_i_32(1, serializer);
+        sse_encode_u_32(field0, serializer);
+      case ThinkingRetention_DiscardAfterSummary():
+        sse_encode_i_32(2, serializer);
+    }
   }
 
   @protected
-  void sse_encode_model_info(ModelInfo self, SseSerializer serializer) {
+  void sse_encode_timeline_entry(TimelineEntry self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_String(self.id, serializer);
-    sse_encode_String(self.name, serializer);
-    sse_encode_usize(self.contextTokens, serializer);
-    sse_encode_usize(self.maxOutputTokens, serializer);
-    sse_encode_bool(self.supportsThinking, serializer);
+    sse_encode_timeline_entry_kind(self.kind, serializer);
+    sse_encode_String(self.text, serializer);
+    sse_encode_u_32(self.turnStart, serializer);
+    sse_encode_u_32(self.turnEnd, serializer);
+    sse_encode_i_64(self.timestamp, serializer);
+    sse_encode_i_64(self.endTimestamp, serializer);
   }
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+  void sse_encode_timeline_entry_kind(
+    TimelineEntryKind self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_conversation(
-    Conversation? self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_turn_metrics(TurnMetrics self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_conversation(self, serializer);
-    }
+    sse_encode_String(self.conversationId, serializer);
+    sse_encode_i_64(self.startedAt, serializer);
+    sse_encode_i_64(self.totalMs, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.firstTokenMs, serializer);
+    sse_encode_list_phase_duration(self.phases, serializer);
+    sse_encode_u_32(self.retries, serializer);
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_memory_context_card(
-    MemoryContextCard? self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_turn_policy(TurnPolicy self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_memory_context_card(self, serializer);
-    }
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
@@ -2155,6 +16658,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putUint32(self);
   }
 
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putBigUint64(self);
+  }
+
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -2166,6 +16675,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
   }
 
+  @protected
+  void sse_encode_user_persona(UserPersona self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.description, serializer);
+    sse_encode_String(self.pronouns, serializer);
+    sse_encode_i_64(self.createdAt, serializer);
+  }
+
   @protected
   void sse_encode_usize(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
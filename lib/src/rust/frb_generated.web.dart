@@ -8,6 +8,7 @@
 
 import 'api/chat_api.dart';
 import 'api/data_models.dart';
+import 'api/knowledge_store.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
@@ -24,412 +25,3322 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   AnyhowException dco_decode_AnyhowException(dynamic raw);
 
+  @protected
+  Map<String, String> dco_decode_Map_String_String_None(dynamic raw);
+
+  @protected
+  RustStreamSink<BackgroundTaskEvent> dco_decode_StreamSink_background_task_event_Sse(
+    dynamic raw,
+  );
+
   @protected
   RustStreamSink<ChatStreamEvent> dco_decode_StreamSink_chat_stream_event_Sse(
     dynamic raw,
   );
 
+  @protected
+  RustStreamSink<ReindexProgress> dco_decode_StreamSink_reindex_progress_Sse(
+    dynamic raw,
+  );
+
   @protected
   String dco_decode_String(dynamic raw);
 
+  @protected
+  ActionFrequency dco_decode_action_frequency(dynamic raw);
+
+  @protected
+  AmbientContext dco_decode_ambient_context(dynamic raw);
+
   @protected
   AppSettings dco_decode_app_settings(dynamic raw);
 
+  @protected
+  AudioReadyEvent dco_decode_audio_ready_event(dynamic raw);
+
+  @protected
+  BackgroundJobKind dco_decode_background_job_kind(dynamic raw);
+
+  @protected
+  BackgroundTaskEvent dco_decode_background_task_event(dynamic raw);
+
+  @protected
+  BackgroundTaskStatus dco_decode_background_task_status(dynamic raw);
+
+  @protected
+  BackoffStrategy dco_decode_backoff_strategy(dynamic raw);
+
+  @protected
+  BackupRetention dco_decode_backup_retention(dynamic raw);
+
+  @protected
+  BackupScope dco_decode_backup_scope(dynamic raw);
+
+  @protected
+  BackupSnapshotInfo dco_decode_backup_snapshot_info(dynamic raw);
+
+  @protected
+  BlockedTopic dco_decode_blocked_topic(dynamic raw);
+
+  @protected
+  BlockingStats dco_decode_blocking_stats(dynamic raw);
+
   @protected
   bool dco_decode_bool(dynamic raw);
 
+  @protected
+  AmbientContext dco_decode_box_autoadd_ambient_context(dynamic raw);
+
   @protected
   AppSettings dco_decode_box_autoadd_app_settings(dynamic raw);
 
+  @protected
+  BackupRetention dco_decode_box_autoadd_backup_retention(dynamic raw);
+
+  @protected
+  BackupSnapshotInfo dco_decode_box_autoadd_backup_snapshot_info(dynamic raw);
+
+  @protected
+  bool dco_decode_box_autoadd_bool(dynamic raw);
+
+  @protected
+  CharacterCard dco_decode_box_autoadd_character_card(dynamic raw);
+
+  @protected
+  CheckInSchedule dco_decode_box_autoadd_check_in_schedule(dynamic raw);
+
+  @protected
+  CognitiveInsight dco_decode_box_autoadd_cognitive_insight(dynamic raw);
+
+  @protected
+  ContextLayers dco_decode_box_autoadd_context_layers(dynamic raw);
+
   @protected
   Conversation dco_decode_box_autoadd_conversation(dynamic raw);
 
+  @protected
+  ConversationFilter dco_decode_box_autoadd_conversation_filter(dynamic raw);
+
+  @protected
+  ConversationInsights dco_decode_box_autoadd_conversation_insights(
+    dynamic raw,
+  );
+
+  @protected
+  ConversationOrganization dco_decode_box_autoadd_conversation_organization(
+    dynamic raw,
+  );
+
+  @protected
+  DailyDigest dco_decode_box_autoadd_daily_digest(dynamic raw);
+
+  @protected
+  DeviceConditions dco_decode_box_autoadd_device_conditions(dynamic raw);
+
+  @protected
+  DistilledSystemState dco_decode_box_autoadd_distilled_system_state(
+    dynamic raw,
+  );
+
+  @protected
+  EncryptionKeySource dco_decode_box_autoadd_encryption_key_source(dynamic raw);
+
+  @protected
+  EncryptionSecret dco_decode_box_autoadd_encryption_secret(dynamic raw);
+
+  @protected
+  double dco_decode_box_autoadd_f_64(dynamic raw);
+
+  @protected
+  Fact dco_decode_box_autoadd_fact(dynamic raw);
+
+  @protected
+  GenerationMetadata dco_decode_box_autoadd_generation_metadata(dynamic raw);
+
+  @protected
+  GroupChat dco_decode_box_autoadd_group_chat(dynamic raw);
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
+
+  @protected
+  IntegrityReport dco_decode_box_autoadd_integrity_report(dynamic raw);
+
+  @protected
+  KnowledgeBinding dco_decode_box_autoadd_knowledge_binding(dynamic raw);
+
+  @protected
+  LayoutVerification dco_decode_box_autoadd_layout_verification(dynamic raw);
+
+  @protected
+  LoreEntry dco_decode_box_autoadd_lore_entry(dynamic raw);
+
   @protected
   MemoryContextCard dco_decode_box_autoadd_memory_context_card(dynamic raw);
 
+  @protected
+  MemoryExplanation dco_decode_box_autoadd_memory_explanation(dynamic raw);
+
+  @protected
+  MemoryFidelityReport dco_decode_box_autoadd_memory_fidelity_report(
+    dynamic raw,
+  );
+
+  @protected
+  MemorySummary dco_decode_box_autoadd_memory_summary(dynamic raw);
+
+  @protected
+  Message dco_decode_box_autoadd_message(dynamic raw);
+
+  @protected
+  MoodState dco_decode_box_autoadd_mood_state(dynamic raw);
+
+  @protected
+  PreflightAdvisory dco_decode_box_autoadd_preflight_advisory(dynamic raw);
+
+  @protected
+  ProxySettings dco_decode_box_autoadd_proxy_settings(dynamic raw);
+
+  @protected
+  ReengagementBrief dco_decode_box_autoadd_reengagement_brief(dynamic raw);
+
+  @protected
+  RelevanceInsight dco_decode_box_autoadd_relevance_insight(dynamic raw);
+
+  @protected
+  ResponseStyle dco_decode_box_autoadd_response_style(dynamic raw);
+
+  @protected
+  RetentionPolicy dco_decode_box_autoadd_retention_policy(dynamic raw);
+
+  @protected
+  RetryPolicy dco_decode_box_autoadd_retry_policy(dynamic raw);
+
+  @protected
+  SafetyPolicy dco_decode_box_autoadd_safety_policy(dynamic raw);
+
+  @protected
+  SayDoMark dco_decode_box_autoadd_say_do_mark(dynamic raw);
+
+  @protected
+  SayDoRuleSet dco_decode_box_autoadd_say_do_rule_set(dynamic raw);
+
+  @protected
+  SummarizationConfig dco_decode_box_autoadd_summarization_config(dynamic raw);
+
+  @protected
+  SyncSettings dco_decode_box_autoadd_sync_settings(dynamic raw);
+
+  @protected
+  ThinkingRetention dco_decode_box_autoadd_thinking_retention(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  UserPersona dco_decode_box_autoadd_user_persona(dynamic raw);
+
+  @protected
+  BranchInfo dco_decode_branch_info(dynamic raw);
+
+  @protected
+  CharacterCard dco_decode_character_card(dynamic raw);
+
+  @protected
+  CharacterRelation dco_decode_character_relation(dynamic raw);
+
   @protected
   ChatStreamEvent dco_decode_chat_stream_event(dynamic raw);
 
+  @protected
+  CheckInSchedule dco_decode_check_in_schedule(dynamic raw);
+
+  @protected
+  CognitiveInsight dco_decode_cognitive_insight(dynamic raw);
+
+  @protected
+  ContentIntensity dco_decode_content_intensity(dynamic raw);
+
+  @protected
+  ContextLayers dco_decode_context_layers(dynamic raw);
+
   @protected
   Conversation dco_decode_conversation(dynamic raw);
 
+  @protected
+  ConversationBranch dco_decode_conversation_branch(dynamic raw);
+
+  @protected
+  ConversationClosure dco_decode_conversation_closure(dynamic raw);
+
+  @protected
+  ConversationFilter dco_decode_conversation_filter(dynamic raw);
+
+  @protected
+  ConversationInsights dco_decode_conversation_insights(dynamic raw);
+
+  @protected
+  ConversationMode dco_decode_conversation_mode(dynamic raw);
+
+  @protected
+  ConversationOrganization dco_decode_conversation_organization(dynamic raw);
+
   @protected
   ConversationSummary dco_decode_conversation_summary(dynamic raw);
 
+  @protected
+  DailyDigest dco_decode_daily_digest(dynamic raw);
+
+  @protected
+  DataExportInfo dco_decode_data_export_info(dynamic raw);
+
+  @protected
+  DeferredJob dco_decode_deferred_job(dynamic raw);
+
+  @protected
+  DegradationKind dco_decode_degradation_kind(dynamic raw);
+
+  @protected
+  DegradationRecord dco_decode_degradation_record(dynamic raw);
+
+  @protected
+  DeviceConditions dco_decode_device_conditions(dynamic raw);
+
   @protected
   DialogueStyle dco_decode_dialogue_style(dynamic raw);
 
+  @protected
+  DigestEntry dco_decode_digest_entry(dynamic raw);
+
+  @protected
+  DistilledSystemState dco_decode_distilled_system_state(dynamic raw);
+
+  @protected
+  DraftTone dco_decode_draft_tone(dynamic raw);
+
+  @protected
+  EmojiUsage dco_decode_emoji_usage(dynamic raw);
+
+  @protected
+  EmotionDimension dco_decode_emotion_dimension(dynamic raw);
+
+  @protected
+  EmotionInsight dco_decode_emotion_insight(dynamic raw);
+
+  @protected
+  EmotionalTurnInsight dco_decode_emotional_turn_insight(dynamic raw);
+
+  @protected
+  EmpathyKind dco_decode_empathy_kind(dynamic raw);
+
+  @protected
+  EncryptionKeySource dco_decode_encryption_key_source(dynamic raw);
+
+  @protected
+  EncryptionSecret dco_decode_encryption_secret(dynamic raw);
+
+  @protected
+  EncryptionStatus dco_decode_encryption_status(dynamic raw);
+
   @protected
   double dco_decode_f_64(dynamic raw);
 
+  @protected
+  Fact dco_decode_fact(dynamic raw);
+
+  @protected
+  FactCategory dco_decode_fact_category(dynamic raw);
+
+  @protected
+  FactConflict dco_decode_fact_conflict(dynamic raw);
+
+  @protected
+  FingerprintInsight dco_decode_fingerprint_insight(dynamic raw);
+
+  @protected
+  Formality dco_decode_formality(dynamic raw);
+
+  @protected
+  GenerationCompletedEvent dco_decode_generation_completed_event(dynamic raw);
+
+  @protected
+  GenerationMetadata dco_decode_generation_metadata(dynamic raw);
+
+  @protected
+  GraphEdge dco_decode_graph_edge(dynamic raw);
+
+  @protected
+  GraphExportFormat dco_decode_graph_export_format(dynamic raw);
+
+  @protected
+  GraphNode dco_decode_graph_node(dynamic raw);
+
+  @protected
+  GroupCharacter dco_decode_group_character(dynamic raw);
+
+  @protected
+  GroupChat dco_decode_group_chat(dynamic raw);
+
+  @protected
+  HousekeepingReport dco_decode_housekeeping_report(dynamic raw);
+
   @protected
   int dco_decode_i_32(dynamic raw);
 
   @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw);
+  PlatformInt64 dco_decode_i_64(dynamic raw);
+
+  @protected
+  IndexScope dco_decode_index_scope(dynamic raw);
+
+  @protected
+  IntegrityIssue dco_decode_integrity_issue(dynamic raw);
+
+  @protected
+  IntegrityIssueKind dco_decode_integrity_issue_kind(dynamic raw);
+
+  @protected
+  IntegrityReport dco_decode_integrity_report(dynamic raw);
+
+  @protected
+  IntentKind dco_decode_intent_kind(dynamic raw);
+
+  @protected
+  JobSchedulerStats dco_decode_job_scheduler_stats(dynamic raw);
+
+  @protected
+  KnowledgeBinding dco_decode_knowledge_binding(dynamic raw);
+
+  @protected
+  KnowledgeGraphView dco_decode_knowledge_graph_view(dynamic raw);
+
+  @protected
+  KnowledgeLayer dco_decode_knowledge_layer(dynamic raw);
+
+  @protected
+  LanguagePatternKind dco_decode_language_pattern_kind(dynamic raw);
+
+  @protected
+  LayoutVerification dco_decode_layout_verification(dynamic raw);
+
+  @protected
+  LexiconEntry dco_decode_lexicon_entry(dynamic raw);
+
+  @protected
+  LinkedFact dco_decode_linked_fact(dynamic raw);
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw);
+
+  @protected
+  List<BackgroundTaskEvent> dco_decode_list_background_task_event(dynamic raw);
+
+  @protected
+  List<BackupSnapshotInfo> dco_decode_list_backup_snapshot_info(dynamic raw);
+
+  @protected
+  List<BlockedTopic> dco_decode_list_blocked_topic(dynamic raw);
+
+  @protected
+  List<BranchInfo> dco_decode_list_branch_info(dynamic raw);
+
+  @protected
+  List<CharacterRelation> dco_decode_list_character_relation(dynamic raw);
+
+  @protected
+  List<ConversationBranch> dco_decode_list_conversation_branch(dynamic raw);
+
+  @protected
+  List<ConversationSummary> dco_decode_list_conversation_summary(dynamic raw);
+
+  @protected
+  List<DeferredJob> dco_decode_list_deferred_job(dynamic raw);
+
+  @protected
+  List<DegradationRecord> dco_decode_list_degradation_record(dynamic raw);
+
+  @protected
+  List<DigestEntry> dco_decode_list_digest_entry(dynamic raw);
+
+  @protected
+  List<EmotionalTurnInsight> dco_decode_list_emotional_turn_insight(
+    dynamic raw,
+  );
+
+  @protected
+  List<Fact> dco_decode_list_fact(dynamic raw);
+
+  @protected
+  List<FactConflict> dco_decode_list_fact_conflict(dynamic raw);
+
+  @protected
+  List<FingerprintInsight> dco_decode_list_fingerprint_insight(dynamic raw);
+
+  @protected
+  List<GraphEdge> dco_decode_list_graph_edge(dynamic raw);
+
+  @protected
+  List<GraphNode> dco_decode_list_graph_node(dynamic raw);
+
+  @protected
+  List<GroupCharacter> dco_decode_list_group_character(dynamic raw);
+
+  @protected
+  List<IntegrityIssue> dco_decode_list_integrity_issue(dynamic raw);
+
+  @protected
+  List<LanguagePatternKind> dco_decode_list_language_pattern_kind(dynamic raw);
+
+  @protected
+  List<LexiconEntry> dco_decode_list_lexicon_entry(dynamic raw);
+
+  @protected
+  List<LinkedFact> dco_decode_list_linked_fact(dynamic raw);
+
+  @protected
+  List<LoreEntry> dco_decode_list_lore_entry(dynamic raw);
+
+  @protected
+  List<MemoryFidelityReport> dco_decode_list_memory_fidelity_report(
+    dynamic raw,
+  );
+
+  @protected
+  List<MemorySearchResult> dco_decode_list_memory_search_result(dynamic raw);
+
+  @protected
+  List<MemorySummary> dco_decode_list_memory_summary(dynamic raw);
+
+  @protected
+  List<MemoryTier> dco_decode_list_memory_tier(dynamic raw);
+
+  @protected
+  List<Message> dco_decode_list_message(dynamic raw);
+
+  @protected
+  List<MessageAlternative> dco_decode_list_message_alternative(dynamic raw);
+
+  @protected
+  List<MessageAttachment> dco_decode_list_message_attachment(dynamic raw);
+
+  @protected
+  List<MessageEdit> dco_decode_list_message_edit(dynamic raw);
+
+  @protected
+  List<ModelInfo> dco_decode_list_model_info(dynamic raw);
+
+  @protected
+  List<OutboxEntry> dco_decode_list_outbox_entry(dynamic raw);
+
+  @protected
+  List<PhaseDuration> dco_decode_list_phase_duration(dynamic raw);
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  List<PromptTemplateInfo> dco_decode_list_prompt_template_info(dynamic raw);
+
+  @protected
+  List<(String, String)> dco_decode_list_record_string_string(dynamic raw);
+
+  @protected
+  List<RelationChange> dco_decode_list_relation_change(dynamic raw);
+
+  @protected
+  List<SafetyRule> dco_decode_list_safety_rule(dynamic raw);
+
+  @protected
+  List<SayDoRule> dco_decode_list_say_do_rule(dynamic raw);
+
+  @protected
+  List<SearchHit> dco_decode_list_search_hit(dynamic raw);
+
+  @protected
+  List<ShadowEvalRecord> dco_decode_list_shadow_eval_record(dynamic raw);
+
+  @protected
+  List<TimelineEntry> dco_decode_list_timeline_entry(dynamic raw);
+
+  @protected
+  List<TurnMetrics> dco_decode_list_turn_metrics(dynamic raw);
+
+  @protected
+  List<UserPersona> dco_decode_list_user_persona(dynamic raw);
+
+  @protected
+  LoreEntry dco_decode_lore_entry(dynamic raw);
+
+  @protected
+  MemoryContextCard dco_decode_memory_context_card(dynamic raw);
+
+  @protected
+  MemoryExplanation dco_decode_memory_explanation(dynamic raw);
+
+  @protected
+  MemoryFidelityReport dco_decode_memory_fidelity_report(dynamic raw);
+
+  @protected
+  MemorySearchResult dco_decode_memory_search_result(dynamic raw);
+
+  @protected
+  MemorySummary dco_decode_memory_summary(dynamic raw);
+
+  @protected
+  MemoryTier dco_decode_memory_tier(dynamic raw);
+
+  @protected
+  Message dco_decode_message(dynamic raw);
+
+  @protected
+  MessageAlternative dco_decode_message_alternative(dynamic raw);
+
+  @protected
+  MessageAttachment dco_decode_message_attachment(dynamic raw);
+
+  @protected
+  MessageEdit dco_decode_message_edit(dynamic raw);
+
+  @protected
+  MessageRole dco_decode_message_role(dynamic raw);
+
+  @protected
+  MessageType dco_decode_message_type(dynamic raw);
+
+  @protected
+  MetricPhase dco_decode_metric_phase(dynamic raw);
+
+  @protected
+  MetricsSnapshot dco_decode_metrics_snapshot(dynamic raw);
+
+  @protected
+  ModelInfo dco_decode_model_info(dynamic raw);
+
+  @protected
+  MoodState dco_decode_mood_state(dynamic raw);
+
+  @protected
+  NarrationPerspective dco_decode_narration_perspective(dynamic raw);
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw);
+
+  @protected
+  AmbientContext? dco_decode_opt_box_autoadd_ambient_context(dynamic raw);
+
+  @protected
+  BackupSnapshotInfo? dco_decode_opt_box_autoadd_backup_snapshot_info(
+    dynamic raw,
+  );
+
+  @protected
+  bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
+
+  @protected
+  CheckInSchedule? dco_decode_opt_box_autoadd_check_in_schedule(dynamic raw);
+
+  @protected
+  CognitiveInsight? dco_decode_opt_box_autoadd_cognitive_insight(dynamic raw);
+
+  @protected
+  Conversation? dco_decode_opt_box_autoadd_conversation(dynamic raw);
+
+  @protected
+  ConversationInsights? dco_decode_opt_box_autoadd_conversation_insights(
+    dynamic raw,
+  );
+
+  @protected
+  ConversationOrganization? dco_decode_opt_box_autoadd_conversation_organization(
+    dynamic raw,
+  );
+
+  @protected
+  DailyDigest? dco_decode_opt_box_autoadd_daily_digest(dynamic raw);
+
+  @protected
+  DistilledSystemState? dco_decode_opt_box_autoadd_distilled_system_state(
+    dynamic raw,
+  );
+
+  @protected
+  EncryptionKeySource? dco_decode_opt_box_autoadd_encryption_key_source(
+    dynamic raw,
+  );
+
+  @protected
+  double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
+
+  @protected
+  Fact? dco_decode_opt_box_autoadd_fact(dynamic raw);
+
+  @protected
+  GenerationMetadata? dco_decode_opt_box_autoadd_generation_metadata(
+    dynamic raw,
+  );
+
+  @protected
+  GroupChat? dco_decode_opt_box_autoadd_group_chat(dynamic raw);
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+
+  @protected
+  IntegrityReport? dco_decode_opt_box_autoadd_integrity_report(dynamic raw);
+
+  @protected
+  LayoutVerification? dco_decode_opt_box_autoadd_layout_verification(
+    dynamic raw,
+  );
+
+  @protected
+  LoreEntry? dco_decode_opt_box_autoadd_lore_entry(dynamic raw);
+
+  @protected
+  MemoryContextCard? dco_decode_opt_box_autoadd_memory_context_card(
+    dynamic raw,
+  );
+
+  @protected
+  MemoryExplanation? dco_decode_opt_box_autoadd_memory_explanation(dynamic raw);
+
+  @protected
+  MemoryFidelityReport? dco_decode_opt_box_autoadd_memory_fidelity_report(
+    dynamic raw,
+  );
+
+  @protected
+  MemorySummary? dco_decode_opt_box_autoadd_memory_summary(dynamic raw);
+
+  @protected
+  Message? dco_decode_opt_box_autoadd_message(dynamic raw);
+
+  @protected
+  MoodState? dco_decode_opt_box_autoadd_mood_state(dynamic raw);
+
+  @protected
+  PreflightAdvisory? dco_decode_opt_box_autoadd_preflight_advisory(dynamic raw);
+
+  @protected
+  ProxySettings? dco_decode_opt_box_autoadd_proxy_settings(dynamic raw);
+
+  @protected
+  ReengagementBrief? dco_decode_opt_box_autoadd_reengagement_brief(dynamic raw);
+
+  @protected
+  RelevanceInsight? dco_decode_opt_box_autoadd_relevance_insight(dynamic raw);
+
+  @protected
+  SayDoMark? dco_decode_opt_box_autoadd_say_do_mark(dynamic raw);
+
+  @protected
+  SummarizationConfig? dco_decode_opt_box_autoadd_summarization_config(
+    dynamic raw,
+  );
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  OutboxEntry dco_decode_outbox_entry(dynamic raw);
+
+  @protected
+  OutboxStatus dco_decode_outbox_status(dynamic raw);
+
+  @protected
+  PhaseDuration dco_decode_phase_duration(dynamic raw);
+
+  @protected
+  PreflightAdvisory dco_decode_preflight_advisory(dynamic raw);
+
+  @protected
+  PromptTemplateInfo dco_decode_prompt_template_info(dynamic raw);
+
+  @protected
+  ProviderKind dco_decode_provider_kind(dynamic raw);
+
+  @protected
+  ProxySettings dco_decode_proxy_settings(dynamic raw);
+
+  @protected
+  ReactionEvent dco_decode_reaction_event(dynamic raw);
+
+  @protected
+  (String, String) dco_decode_record_string_string(dynamic raw);
+
+  @protected
+  ReengagementBrief dco_decode_reengagement_brief(dynamic raw);
+
+  @protected
+  ReindexProgress dco_decode_reindex_progress(dynamic raw);
+
+  @protected
+  RelationChange dco_decode_relation_change(dynamic raw);
+
+  @protected
+  RelationSentiment dco_decode_relation_sentiment(dynamic raw);
+
+  @protected
+  RelationshipInsight dco_decode_relationship_insight(dynamic raw);
+
+  @protected
+  RelevanceInsight dco_decode_relevance_insight(dynamic raw);
+
+  @protected
+  ReplyLength dco_decode_reply_length(dynamic raw);
+
+  @protected
+  ReplySignals dco_decode_reply_signals(dynamic raw);
+
+  @protected
+  ResponseStyle dco_decode_response_style(dynamic raw);
+
+  @protected
+  RetentionPolicy dco_decode_retention_policy(dynamic raw);
+
+  @protected
+  RetryPolicy dco_decode_retry_policy(dynamic raw);
+
+  @protected
+  SafetyAction dco_decode_safety_action(dynamic raw);
+
+  @protected
+  SafetyCategory dco_decode_safety_category(dynamic raw);
+
+  @protected
+  SafetyEvent dco_decode_safety_event(dynamic raw);
+
+  @protected
+  SafetyPolicy dco_decode_safety_policy(dynamic raw);
+
+  @protected
+  SafetyRule dco_decode_safety_rule(dynamic raw);
+
+  @protected
+  SafetyStage dco_decode_safety_stage(dynamic raw);
+
+  @protected
+  SayDoMark dco_decode_say_do_mark(dynamic raw);
+
+  @protected
+  SayDoRule dco_decode_say_do_rule(dynamic raw);
+
+  @protected
+  SayDoRuleSet dco_decode_say_do_rule_set(dynamic raw);
+
+  @protected
+  SearchHit dco_decode_search_hit(dynamic raw);
+
+  @protected
+  ShadowEvalRecord dco_decode_shadow_eval_record(dynamic raw);
+
+  @protected
+  ShadowEvalSummary dco_decode_shadow_eval_summary(dynamic raw);
+
+  @protected
+  ShortTermInsight dco_decode_short_term_insight(dynamic raw);
+
+  @protected
+  SummarizationConfig dco_decode_summarization_config(dynamic raw);
+
+  @protected
+  SyncBackendKind dco_decode_sync_backend_kind(dynamic raw);
+
+  @protected
+  SyncReport dco_decode_sync_report(dynamic raw);
+
+  @protected
+  SyncSettings dco_decode_sync_settings(dynamic raw);
+
+  @protected
+  ThinkingRetention dco_decode_thinking_retention(dynamic raw);
+
+  @protected
+  TimelineEntry dco_decode_timeline_entry(dynamic raw);
+
+  @protected
+  TimelineEntryKind dco_decode_timeline_entry_kind(dynamic raw);
+
+  @protected
+  TurnMetrics dco_decode_turn_metrics(dynamic raw);
+
+  @protected
+  TurnPolicy dco_decode_turn_policy(dynamic raw);
+
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw);
+
+  @protected
+  int dco_decode_u_8(dynamic raw);
+
+  @protected
+  void dco_decode_unit(dynamic raw);
+
+  @protected
+  UserPersona dco_decode_user_persona(dynamic raw);
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw);
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+  @protected
+  Map<String, String> sse_decode_Map_String_String_None(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<BackgroundTaskEvent> sse_decode_StreamSink_background_task_event_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<ChatStreamEvent> sse_decode_StreamSink_chat_stream_event_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<ReindexProgress> sse_decode_StreamSink_reindex_progress_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer);
+
+  @protected
+  ActionFrequency sse_decode_action_frequency(SseDeserializer deserializer);
+
+  @protected
+  AmbientContext sse_decode_ambient_context(SseDeserializer deserializer);
+
+  @protected
+  AppSettings sse_decode_app_settings(SseDeserializer deserializer);
+
+  @protected
+  AudioReadyEvent sse_decode_audio_ready_event(SseDeserializer deserializer);
+
+  @protected
+  BackgroundJobKind sse_decode_background_job_kind(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  BackgroundTaskEvent sse_decode_background_task_event(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  BackgroundTaskStatus sse_decode_background_task_status(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  BackoffStrategy sse_decode_backoff_strategy(SseDeserializer deserializer);
+
+  @protected
+  BackupRetention sse_decode_backup_retention(SseDeserializer deserializer);
+
+  @protected
+  BackupScope sse_decode_backup_scope(SseDeserializer deserializer);
+
+  @protected
+  BackupSnapshotInfo sse_decode_backup_snapshot_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  BlockedTopic sse_decode_blocked_topic(SseDeserializer deserializer);
+
+  @protected
+  BlockingStats sse_decode_blocking_stats(SseDeserializer deserializer);
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer);
+
+  @protected
+  AmbientContext sse_decode_box_autoadd_ambient_context(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AppSettings sse_decode_box_autoadd_app_settings(SseDeserializer deserializer);
+
+  @protected
+  BackupRetention sse_decode_box_autoadd_backup_retention(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  BackupSnapshotInfo sse_decode_box_autoadd_backup_snapshot_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  CharacterCard sse_decode_box_autoadd_character_card(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CheckInSchedule sse_decode_box_autoadd_check_in_schedule(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CognitiveInsight sse_decode_box_autoadd_cognitive_insight(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ContextLayers sse_decode_box_autoadd_context_layers(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Conversation sse_decode_box_autoadd_conversation(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ConversationFilter sse_decode_box_autoadd_conversation_filter(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ConversationInsights sse_decode_box_autoadd_conversation_insights(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ConversationOrganization sse_decode_box_autoadd_conversation_organization(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DailyDigest sse_decode_box_autoadd_daily_digest(SseDeserializer deserializer);
+
+  @protected
+  DeviceConditions sse_decode_box_autoadd_device_conditions(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DistilledSystemState sse_decode_box_autoadd_distilled_system_state(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  EncryptionKeySource sse_decode_box_autoadd_encryption_key_source(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  EncryptionSecret sse_decode_box_autoadd_encryption_secret(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
+
+  @protected
+  Fact sse_decode_box_autoadd_fact(SseDeserializer deserializer);
+
+  @protected
+  GenerationMetadata sse_decode_box_autoadd_generation_metadata(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  GroupChat sse_decode_box_autoadd_group_chat(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  IntegrityReport sse_decode_box_autoadd_integrity_report(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  KnowledgeBinding sse_decode_box_autoadd_knowledge_binding(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LayoutVerification sse_decode_box_autoadd_layout_verification(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LoreEntry sse_decode_box_autoadd_lore_entry(SseDeserializer deserializer);
+
+  @protected
+  MemoryContextCard sse_decode_box_autoadd_memory_context_card(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MemoryExplanation sse_decode_box_autoadd_memory_explanation(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MemoryFidelityReport sse_decode_box_autoadd_memory_fidelity_report(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MemorySummary sse_decode_box_autoadd_memory_summary(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Message sse_decode_box_autoadd_message(SseDeserializer deserializer);
+
+  @protected
+  MoodState sse_decode_box_autoadd_mood_state(SseDeserializer deserializer);
+
+  @protected
+  PreflightAdvisory sse_decode_box_autoadd_preflight_advisory(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ProxySettings sse_decode_box_autoadd_proxy_settings(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ReengagementBrief sse_decode_box_autoadd_reengagement_brief(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RelevanceInsight sse_decode_box_autoadd_relevance_insight(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ResponseStyle sse_decode_box_autoadd_response_style(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RetentionPolicy sse_decode_box_autoadd_retention_policy(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RetryPolicy sse_decode_box_autoadd_retry_policy(SseDeserializer deserializer);
+
+  @protected
+  SafetyPolicy sse_decode_box_autoadd_safety_policy(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SayDoMark sse_decode_box_autoadd_say_do_mark(SseDeserializer deserializer);
+
+  @protected
+  SayDoRuleSet sse_decode_box_autoadd_say_do_rule_set(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SummarizationConfig sse_decode_box_autoadd_summarization_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SyncSettings sse_decode_box_autoadd_sync_settings(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ThinkingRetention sse_decode_box_autoadd_thinking_retention(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  UserPersona sse_decode_box_autoadd_user_persona(SseDeserializer deserializer);
+
+  @protected
+  BranchInfo sse_decode_branch_info(SseDeserializer deserializer);
+
+  @protected
+  CharacterCard sse_decode_character_card(SseDeserializer deserializer);
+
+  @protected
+  CharacterRelation sse_decode_character_relation(SseDeserializer deserializer);
+
+  @protected
+  ChatStreamEvent sse_decode_chat_stream_event(SseDeserializer deserializer);
+
+  @protected
+  CheckInSchedule sse_decode_check_in_schedule(SseDeserializer deserializer);
+
+  @protected
+  CognitiveInsight sse_decode_cognitive_insight(SseDeserializer deserializer);
+
+  @protected
+  ContentIntensity sse_decode_content_intensity(SseDeserializer deserializer);
+
+  @protected
+  ContextLayers sse_decode_context_layers(SseDeserializer deserializer);
+
+  @protected
+  Conversation sse_decode_conversation(SseDeserializer deserializer);
+
+  @protected
+  ConversationBranch sse_decode_conversation_branch(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ConversationClosure sse_decode_conversation_closure(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ConversationFilter sse_decode_conversation_filter(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ConversationInsights sse_decode_conversation_insights(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ConversationMode sse_decode_conversation_mode(SseDeserializer deserializer);
+
+  @protected
+  ConversationOrganization sse_decode_conversation_organization(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ConversationSummary sse_decode_conversation_summary(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DailyDigest sse_decode_daily_digest(SseDeserializer deserializer);
+
+  @protected
+  DataExportInfo sse_decode_data_export_info(SseDeserializer deserializer);
+
+  @protected
+  DeferredJob sse_decode_deferred_job(SseDeserializer deserializer);
+
+  @protected
+  DegradationKind sse_decode_degradation_kind(SseDeserializer deserializer);
+
+  @protected
+  DegradationRecord sse_decode_degradation_record(SseDeserializer deserializer);
+
+  @protected
+  DeviceConditions sse_decode_device_conditions(SseDeserializer deserializer);
+
+  @protected
+  DialogueStyle sse_decode_dialogue_style(SseDeserializer deserializer);
+
+  @protected
+  DigestEntry sse_decode_digest_entry(SseDeserializer deserializer);
+
+  @protected
+  DistilledSystemState sse_decode_distilled_system_state(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DraftTone sse_decode_draft_tone(SseDeserializer deserializer);
+
+  @protected
+  EmojiUsage sse_decode_emoji_usage(SseDeserializer deserializer);
+
+  @protected
+  EmotionDimension sse_decode_emotion_dimension(SseDeserializer deserializer);
+
+  @protected
+  EmotionInsight sse_decode_emotion_insight(SseDeserializer deserializer);
+
+  @protected
+  EmotionalTurnInsight sse_decode_emotional_turn_insight(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  EmpathyKind sse_decode_empathy_kind(SseDeserializer deserializer);
+
+  @protected
+  EncryptionKeySource sse_decode_encryption_key_source(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  EncryptionSecret sse_decode_encryption_secret(SseDeserializer deserializer);
+
+  @protected
+  EncryptionStatus sse_decode_encryption_status(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer);
+
+  @protected
+  Fact sse_decode_fact(SseDeserializer deserializer);
+
+  @protected
+  FactCategory sse_decode_fact_category(SseDeserializer deserializer);
+
+  @protected
+  FactConflict sse_decode_fact_conflict(SseDeserializer deserializer);
+
+  @protected
+  FingerprintInsight sse_decode_fingerprint_insight(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Formality sse_decode_formality(SseDeserializer deserializer);
+
+  @protected
+  GenerationCompletedEvent sse_decode_generation_completed_event(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  GenerationMetadata sse_decode_generation_metadata(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  GraphEdge sse_decode_graph_edge(SseDeserializer deserializer);
+
+  @protected
+  GraphExportFormat sse_decode_graph_export_format(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  GraphNode sse_decode_graph_node(SseDeserializer deserializer);
+
+  @protected
+  GroupCharacter sse_decode_group_character(SseDeserializer deserializer);
+
+  @protected
+  GroupChat sse_decode_group_chat(SseDeserializer deserializer);
+
+  @protected
+  HousekeepingReport sse_decode_housekeeping_report(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+  @protected
+  IndexScope sse_decode_index_scope(SseDeserializer deserializer);
+
+  @protected
+  IntegrityIssue sse_decode_integrity_issue(SseDeserializer deserializer);
+
+  @protected
+  IntegrityIssueKind sse_decode_integrity_issue_kind(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  IntegrityReport sse_decode_integrity_report(SseDeserializer deserializer);
+
+  @protected
+  IntentKind sse_decode_intent_kind(SseDeserializer deserializer);
+
+  @protected
+  JobSchedulerStats sse_decode_job_scheduler_stats(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  KnowledgeBinding sse_decode_knowledge_binding(SseDeserializer deserializer);
+
+  @protected
+  KnowledgeGraphView sse_decode_knowledge_graph_view(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  KnowledgeLayer sse_decode_knowledge_layer(SseDeserializer deserializer);
+
+  @protected
+  LanguagePatternKind sse_decode_language_pattern_kind(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LayoutVerification sse_decode_layout_verification(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LexiconEntry sse_decode_lexicon_entry(SseDeserializer deserializer);
+
+  @protected
+  LinkedFact sse_decode_linked_fact(SseDeserializer deserializer);
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<BackgroundTaskEvent> sse_decode_list_background_task_event(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<BackupSnapshotInfo> sse_decode_list_backup_snapshot_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<BlockedTopic> sse_decode_list_blocked_topic(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<BranchInfo> sse_decode_list_branch_info(SseDeserializer deserializer);
+
+  @protected
+  List<CharacterRelation> sse_decode_list_character_relation(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ConversationBranch> sse_decode_list_conversation_branch(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<ConversationSummary> sse_decode_list_conversation_summary(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<DeferredJob> sse_decode_list_deferred_job(SseDeserializer deserializer);
+
+  @protected
+  List<DegradationRecord> sse_decode_list_degradation_record(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<DigestEntry> sse_decode_list_digest_entry(SseDeserializer deserializer);
+
+  @protected
+  List<EmotionalTurnInsight> sse_decode_list_emotional_turn_insight(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<Fact> sse_decode_list_fact(SseDeserializer deserializer);
+
+  @protected
+  List<FactConflict> sse_decode_list_fact_conflict(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<FingerprintInsight> sse_decode_list_fingerprint_insight(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<GraphEdge> sse_decode_list_graph_edge(SseDeserializer deserializer);
+
+  @protected
+  List<GraphNode> sse_decode_list_graph_node(SseDeserializer deserializer);
+
+  @protected
+  List<GroupCharacter> sse_decode_list_group_character(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<IntegrityIssue> sse_decode_list_integrity_issue(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<LanguagePatternKind> sse_decode_list_language_pattern_kind(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<LexiconEntry> sse_decode_list_lexicon_entry(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<LinkedFact> sse_decode_list_linked_fact(SseDeserializer deserializer);
+
+  @protected
+  List<LoreEntry> sse_decode_list_lore_entry(SseDeserializer deserializer);
+
+  @protected
+  List<MemoryFidelityReport> sse_decode_list_memory_fidelity_report(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<MemorySearchResult> sse_decode_list_memory_search_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<MemorySummary> sse_decode_list_memory_summary(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<MemoryTier> sse_decode_list_memory_tier(SseDeserializer deserializer);
+
+  @protected
+  List<Message> sse_decode_list_message(SseDeserializer deserializer);
+
+  @protected
+  List<MessageAlternative> sse_decode_list_message_alternative(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<MessageAttachment> sse_decode_list_message_attachment(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<MessageEdit> sse_decode_list_message_edit(SseDeserializer deserializer);
+
+  @protected
+  List<ModelInfo> sse_decode_list_model_info(SseDeserializer deserializer);
+
+  @protected
+  List<OutboxEntry> sse_decode_list_outbox_entry(SseDeserializer deserializer);
+
+  @protected
+  List<PhaseDuration> sse_decode_list_phase_duration(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  List<PromptTemplateInfo> sse_decode_list_prompt_template_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<(String, String)> sse_decode_list_record_string_string(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<RelationChange> sse_decode_list_relation_change(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<SafetyRule> sse_decode_list_safety_rule(SseDeserializer deserializer);
+
+  @protected
+  List<SayDoRule> sse_decode_list_say_do_rule(SseDeserializer deserializer);
+
+  @protected
+  List<SearchHit> sse_decode_list_search_hit(SseDeserializer deserializer);
+
+  @protected
+  List<ShadowEvalRecord> sse_decode_list_shadow_eval_record(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<TimelineEntry> sse_decode_list_timeline_entry(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<TurnMetrics> sse_decode_list_turn_metrics(SseDeserializer deserializer);
+
+  @protected
+  List<UserPersona> sse_decode_list_user_persona(SseDeserializer deserializer);
+
+  @protected
+  LoreEntry sse_decode_lore_entry(SseDeserializer deserializer);
+
+  @protected
+  MemoryContextCard sse_decode_memory_context_card(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MemoryExplanation sse_decode_memory_explanation(SseDeserializer deserializer);
+
+  @protected
+  MemoryFidelityReport sse_decode_memory_fidelity_report(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MemorySearchResult sse_decode_memory_search_result(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MemorySummary sse_decode_memory_summary(SseDeserializer deserializer);
+
+  @protected
+  MemoryTier sse_decode_memory_tier(SseDeserializer deserializer);
+
+  @protected
+  Message sse_decode_message(SseDeserializer deserializer);
+
+  @protected
+  MessageAlternative sse_decode_message_alternative(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MessageAttachment sse_decode_message_attachment(SseDeserializer deserializer);
+
+  @protected
+  MessageEdit sse_decode_message_edit(SseDeserializer deserializer);
+
+  @protected
+  MessageRole sse_decode_message_role(SseDeserializer deserializer);
+
+  @protected
+  MessageType sse_decode_message_type(SseDeserializer deserializer);
+
+  @protected
+  MetricPhase sse_decode_metric_phase(SseDeserializer deserializer);
+
+  @protected
+  MetricsSnapshot sse_decode_metrics_snapshot(SseDeserializer deserializer);
+
+  @protected
+  ModelInfo sse_decode_model_info(SseDeserializer deserializer);
+
+  @protected
+  MoodState sse_decode_mood_state(SseDeserializer deserializer);
+
+  @protected
+  NarrationPerspective sse_decode_narration_perspective(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  AmbientContext? sse_decode_opt_box_autoadd_ambient_context(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  BackupSnapshotInfo? sse_decode_opt_box_autoadd_backup_snapshot_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
+
+  @protected
+  CheckInSchedule? sse_decode_opt_box_autoadd_check_in_schedule(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CognitiveInsight? sse_decode_opt_box_autoadd_cognitive_insight(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Conversation? sse_decode_opt_box_autoadd_conversation(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ConversationInsights? sse_decode_opt_box_autoadd_conversation_insights(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ConversationOrganization? sse_decode_opt_box_autoadd_conversation_organization(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DailyDigest? sse_decode_opt_box_autoadd_daily_digest(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  DistilledSystemState? sse_decode_opt_box_autoadd_distilled_system_state(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  EncryptionKeySource? sse_decode_opt_box_autoadd_encryption_key_source(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+
+  @protected
+  Fact? sse_decode_opt_box_autoadd_fact(SseDeserializer deserializer);
+
+  @protected
+  GenerationMetadata? sse_decode_opt_box_autoadd_generation_metadata(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  GroupChat? sse_decode_opt_box_autoadd_group_chat(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  IntegrityReport? sse_decode_opt_box_autoadd_integrity_report(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LayoutVerification? sse_decode_opt_box_autoadd_layout_verification(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  LoreEntry? sse_decode_opt_box_autoadd_lore_entry(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MemoryContextCard? sse_decode_opt_box_autoadd_memory_context_card(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MemoryExplanation? sse_decode_opt_box_autoadd_memory_explanation(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MemoryFidelityReport? sse_decode_opt_box_autoadd_memory_fidelity_report(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MemorySummary? sse_decode_opt_box_autoadd_memory_summary(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Message? sse_decode_opt_box_autoadd_message(SseDeserializer deserializer);
+
+  @protected
+  MoodState? sse_decode_opt_box_autoadd_mood_state(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PreflightAdvisory? sse_decode_opt_box_autoadd_preflight_advisory(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ProxySettings? sse_decode_opt_box_autoadd_proxy_settings(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ReengagementBrief? sse_decode_opt_box_autoadd_reengagement_brief(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RelevanceInsight? sse_decode_opt_box_autoadd_relevance_insight(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SayDoMark? sse_decode_opt_box_autoadd_say_do_mark(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SummarizationConfig? sse_decode_opt_box_autoadd_summarization_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  OutboxEntry sse_decode_outbox_entry(SseDeserializer deserializer);
+
+  @protected
+  OutboxStatus sse_decode_outbox_status(SseDeserializer deserializer);
+
+  @protected
+  PhaseDuration sse_decode_phase_duration(SseDeserializer deserializer);
+
+  @protected
+  PreflightAdvisory sse_decode_preflight_advisory(SseDeserializer deserializer);
+
+  @protected
+  PromptTemplateInfo sse_decode_prompt_template_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ProviderKind sse_decode_provider_kind(SseDeserializer deserializer);
+
+  @protected
+  ProxySettings sse_decode_proxy_settings(SseDeserializer deserializer);
+
+  @protected
+  ReactionEvent sse_decode_reaction_event(SseDeserializer deserializer);
+
+  @protected
+  (String, String) sse_decode_record_string_string(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ReengagementBrief sse_decode_reengagement_brief(SseDeserializer deserializer);
+
+  @protected
+  ReindexProgress sse_decode_reindex_progress(SseDeserializer deserializer);
+
+  @protected
+  RelationChange sse_decode_relation_change(SseDeserializer deserializer);
+
+  @protected
+  RelationSentiment sse_decode_relation_sentiment(SseDeserializer deserializer);
+
+  @protected
+  RelationshipInsight sse_decode_relationship_insight(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RelevanceInsight sse_decode_relevance_insight(SseDeserializer deserializer);
+
+  @protected
+  ReplyLength sse_decode_reply_length(SseDeserializer deserializer);
+
+  @protected
+  ReplySignals sse_decode_reply_signals(SseDeserializer deserializer);
+
+  @protected
+  ResponseStyle sse_decode_response_style(SseDeserializer deserializer);
+
+  @protected
+  RetentionPolicy sse_decode_retention_policy(SseDeserializer deserializer);
+
+  @protected
+  RetryPolicy sse_decode_retry_policy(SseDeserializer deserializer);
+
+  @protected
+  SafetyAction sse_decode_safety_action(SseDeserializer deserializer);
+
+  @protected
+  SafetyCategory sse_decode_safety_category(SseDeserializer deserializer);
+
+  @protected
+  SafetyEvent sse_decode_safety_event(SseDeserializer deserializer);
+
+  @protected
+  SafetyPolicy sse_decode_safety_policy(SseDeserializer deserializer);
+
+  @protected
+  SafetyRule sse_decode_safety_rule(SseDeserializer deserializer);
+
+  @protected
+  SafetyStage sse_decode_safety_stage(SseDeserializer deserializer);
+
+  @protected
+  SayDoMark sse_decode_say_do_mark(SseDeserializer deserializer);
+
+  @protected
+  SayDoRule sse_decode_say_do_rule(SseDeserializer deserializer);
+
+  @protected
+  SayDoRuleSet sse_decode_say_do_rule_set(SseDeserializer deserializer);
+
+  @protected
+  SearchHit sse_decode_search_hit(SseDeserializer deserializer);
+
+  @protected
+  ShadowEvalRecord sse_decode_shadow_eval_record(SseDeserializer deserializer);
+
+  @protected
+  ShadowEvalSummary sse_decode_shadow_eval_summary(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  ShortTermInsight sse_decode_short_term_insight(SseDeserializer deserializer);
+
+  @protected
+  SummarizationConfig sse_decode_summarization_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SyncBackendKind sse_decode_sync_backend_kind(SseDeserializer deserializer);
+
+  @protected
+  SyncReport sse_decode_sync_report(SseDeserializer deserializer);
+
+  @protected
+  SyncSettings sse_decode_sync_settings(SseDeserializer deserializer);
+
+  @protected
+  ThinkingRetention sse_decode_thinking_retention(SseDeserializer deserializer);
+
+  @protected
+  TimelineEntry sse_decode_timeline_entry(SseDeserializer deserializer);
+
+  @protected
+  TimelineEntryKind sse_decode_timeline_entry_kind(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  TurnMetrics sse_decode_turn_metrics(SseDeserializer deserializer);
+
+  @protected
+  TurnPolicy sse_decode_turn_policy(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer);
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer);
+
+  @protected
+  UserPersona sse_decode_user_persona(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer);
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_Map_String_String_None(
+    Map<String, String> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_background_task_event_Sse(
+    RustStreamSink<BackgroundTaskEvent> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_chat_stream_event_Sse(
+    RustStreamSink<ChatStreamEvent> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_reindex_progress_Sse(
+    RustStreamSink<ReindexProgress> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_action_frequency(
+    ActionFrequency self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_ambient_context(
+    AmbientContext self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_app_settings(AppSettings self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_audio_ready_event(
+    AudioReadyEvent self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_background_job_kind(
+    BackgroundJobKind self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_background_task_event(
+    BackgroundTaskEvent self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_background_task_status(
+    BackgroundTaskStatus self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_backoff_strategy(
+    BackoffStrategy self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_backup_retention(
+    BackupRetention self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_backup_scope(BackupScope self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_backup_snapshot_info(
+    BackupSnapshotInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_blocked_topic(BlockedTopic self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_blocking_stats(BlockingStats self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_ambient_context(
+    AmbientContext self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_app_settings(
+    AppSettings self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_backup_retention(
+    BackupRetention self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_backup_snapshot_info(
+    BackupSnapshotInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_character_card(
+    CharacterCard self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_check_in_schedule(
+    CheckInSchedule self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_cognitive_insight(
+    CognitiveInsight self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_context_layers(
+    ContextLayers self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_conversation(
+    Conversation self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_conversation_filter(
+    ConversationFilter self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_conversation_insights(
+    ConversationInsights self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_conversation_organization(
+    ConversationOrganization self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_daily_digest(
+    DailyDigest self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_device_conditions(
+    DeviceConditions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_distilled_system_state(
+    DistilledSystemState self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_encryption_key_source(
+    EncryptionKeySource self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_encryption_secret(
+    EncryptionSecret self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_fact(Fact self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_generation_metadata(
+    GenerationMetadata self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_group_chat(
+    GroupChat self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_integrity_report(
+    IntegrityReport self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_knowledge_binding(
+    KnowledgeBinding self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_layout_verification(
+    LayoutVerification self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_lore_entry(
+    LoreEntry self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_memory_context_card(
+    MemoryContextCard self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_memory_explanation(
+    MemoryExplanation self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_memory_fidelity_report(
+    MemoryFidelityReport self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_memory_summary(
+    MemorySummary self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_message(Message self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_mood_state(
+    MoodState self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_preflight_advisory(
+    PreflightAdvisory self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_proxy_settings(
+    ProxySettings self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_reengagement_brief(
+    ReengagementBrief self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_relevance_insight(
+    RelevanceInsight self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_response_style(
+    ResponseStyle self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_retention_policy(
+    RetentionPolicy self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_retry_policy(
+    RetryPolicy self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_safety_policy(
+    SafetyPolicy self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_say_do_mark(
+    SayDoMark self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_say_do_rule_set(
+    SayDoRuleSet self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_summarization_config(
+    SummarizationConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_sync_settings(
+    SyncSettings self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_thinking_retention(
+    ThinkingRetention self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_user_persona(
+    UserPersona self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_branch_info(BranchInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_character_card(CharacterCard self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_character_relation(
+    CharacterRelation self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_chat_stream_event(
+    ChatStreamEvent self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_check_in_schedule(
+    CheckInSchedule self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_cognitive_insight(
+    CognitiveInsight self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_content_intensity(
+    ContentIntensity self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_context_layers(ContextLayers self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_conversation(Conversation self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_conversation_branch(
+    ConversationBranch self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_conversation_closure(
+    ConversationClosure self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_conversation_filter(
+    ConversationFilter self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_conversation_insights(
+    ConversationInsights self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_conversation_mode(
+    ConversationMode self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_conversation_organization(
+    ConversationOrganization self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_conversation_summary(
+    ConversationSummary self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_daily_digest(DailyDigest self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_data_export_info(
+    DataExportInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_deferred_job(DeferredJob self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_degradation_kind(
+    DegradationKind self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_degradation_record(
+    DegradationRecord self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_device_conditions(
+    DeviceConditions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_dialogue_style(DialogueStyle self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_digest_entry(DigestEntry self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_distilled_system_state(
+    DistilledSystemState self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_draft_tone(DraftTone self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_emoji_usage(EmojiUsage self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_emotion_dimension(
+    EmotionDimension self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_emotion_insight(
+    EmotionInsight self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_emotional_turn_insight(
+    EmotionalTurnInsight self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_empathy_kind(EmpathyKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_encryption_key_source(
+    EncryptionKeySource self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_encryption_secret(
+    EncryptionSecret self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_encryption_status(
+    EncryptionStatus self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_f_64(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_fact(Fact self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_fact_category(FactCategory self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_fact_conflict(FactConflict self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_fingerprint_insight(
+    FingerprintInsight self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_formality(Formality self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_generation_completed_event(
+    GenerationCompletedEvent self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_generation_metadata(
+    GenerationMetadata self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_graph_edge(GraphEdge self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_graph_export_format(
+    GraphExportFormat self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_graph_node(GraphNode self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_group_character(
+    GroupCharacter self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_group_chat(GroupChat self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_housekeeping_report(
+    HousekeepingReport self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_index_scope(IndexScope self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_integrity_issue(
+    IntegrityIssue self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_integrity_issue_kind(
+    IntegrityIssueKind self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_integrity_report(
+    IntegrityReport self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_intent_kind(IntentKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_job_scheduler_stats(
+    JobSchedulerStats self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_knowledge_binding(
+    KnowledgeBinding self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_knowledge_graph_view(
+    KnowledgeGraphView self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_knowledge_layer(
+    KnowledgeLayer self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_language_pattern_kind(
+    LanguagePatternKind self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_layout_verification(
+    LayoutVerification self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_lexicon_entry(LexiconEntry self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_linked_fact(LinkedFact self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_background_task_event(
+    List<BackgroundTaskEvent> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_backup_snapshot_info(
+    List<BackupSnapshotInfo> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_blocked_topic(
+    List<BlockedTopic> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_branch_info(
+    List<BranchInfo> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_character_relation(
+    List<CharacterRelation> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_conversation_branch(
+    List<ConversationBranch> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_conversation_summary(
+    List<ConversationSummary> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_deferred_job(
+    List<DeferredJob> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_degradation_record(
+    List<DegradationRecord> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_digest_entry(
+    List<DigestEntry> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_emotional_turn_insight(
+    List<EmotionalTurnInsight> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_fact(List<Fact> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_fact_conflict(
+    List<FactConflict> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_fingerprint_insight(
+    List<FingerprintInsight> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_graph_edge(
+    List<GraphEdge> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_graph_node(
+    List<GraphNode> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_group_character(
+    List<GroupCharacter> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_integrity_issue(
+    List<IntegrityIssue> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_language_pattern_kind(
+    List<LanguagePatternKind> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_lexicon_entry(
+    List<LexiconEntry> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_linked_fact(
+    List<LinkedFact> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_lore_entry(
+    List<LoreEntry> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_memory_fidelity_report(
+    List<MemoryFidelityReport> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_memory_search_result(
+    List<MemorySearchResult> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_memory_summary(
+    List<MemorySummary> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_memory_tier(
+    List<MemoryTier> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_message(List<Message> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_message_alternative(
+    List<MessageAlternative> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_message_attachment(
+    List<MessageAttachment> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_message_edit(
+    List<MessageEdit> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_model_info(
+    List<ModelInfo> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_outbox_entry(
+    List<OutboxEntry> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_phase_duration(
+    List<PhaseDuration> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prompt_template_info(
+    List<PromptTemplateInfo> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_record_string_string(
+    List<(String, String)> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_relation_change(
+    List<RelationChange> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<String> dco_decode_list_String(dynamic raw);
+  void sse_encode_list_safety_rule(
+    List<SafetyRule> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<ConversationSummary> dco_decode_list_conversation_summary(dynamic raw);
+  void sse_encode_list_say_do_rule(
+    List<SayDoRule> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<MemorySearchResult> dco_decode_list_memory_search_result(dynamic raw);
+  void sse_encode_list_search_hit(
+    List<SearchHit> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<MemorySummary> dco_decode_list_memory_summary(dynamic raw);
+  void sse_encode_list_shadow_eval_record(
+    List<ShadowEvalRecord> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<MemoryTier> dco_decode_list_memory_tier(dynamic raw);
+  void sse_encode_list_timeline_entry(
+    List<TimelineEntry> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<Message> dco_decode_list_message(dynamic raw);
+  void sse_encode_list_turn_metrics(
+    List<TurnMetrics> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<ModelInfo> dco_decode_list_model_info(dynamic raw);
+  void sse_encode_list_user_persona(
+    List<UserPersona> self,
+    SseSerializer serializer,
+  );
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+  void sse_encode_lore_entry(LoreEntry self, SseSerializer serializer);
 
   @protected
-  MemoryContextCard dco_decode_memory_context_card(dynamic raw);
+  void sse_encode_memory_context_card(
+    MemoryContextCard self,
+    SseSerializer serializer,
+  );
 
   @protected
-  MemorySearchResult dco_decode_memory_search_result(dynamic raw);
+  void sse_encode_memory_explanation(
+    MemoryExplanation self,
+    SseSerializer serializer,
+  );
 
   @protected
-  MemorySummary dco_decode_memory_summary(dynamic raw);
+  void sse_encode_memory_fidelity_report(
+    MemoryFidelityReport self,
+    SseSerializer serializer,
+  );
 
   @protected
-  MemoryTier dco_decode_memory_tier(dynamic raw);
+  void sse_encode_memory_search_result(
+    MemorySearchResult self,
+    SseSerializer serializer,
+  );
 
   @protected
-  Message dco_decode_message(dynamic raw);
+  void sse_encode_memory_summary(MemorySummary self, SseSerializer serializer);
 
   @protected
-  MessageRole dco_decode_message_role(dynamic raw);
+  void sse_encode_memory_tier(MemoryTier self, SseSerializer serializer);
 
   @protected
-  MessageType dco_decode_message_type(dynamic raw);
+  void sse_encode_message(Message self, SseSerializer serializer);
 
   @protected
-  ModelInfo dco_decode_model_info(dynamic raw);
+  void sse_encode_message_alternative(
+    MessageAlternative self,
+    SseSerializer serializer,
+  );
 
   @protected
-  String? dco_decode_opt_String(dynamic raw);
+  void sse_encode_message_attachment(
+    MessageAttachment self,
+    SseSerializer serializer,
+  );
 
   @protected
-  Conversation? dco_decode_opt_box_autoadd_conversation(dynamic raw);
+  void sse_encode_message_edit(MessageEdit self, SseSerializer serializer);
 
   @protected
-  MemoryContextCard? dco_decode_opt_box_autoadd_memory_context_card(
-    dynamic raw,
-  );
+  void sse_encode_message_role(MessageRole self, SseSerializer serializer);
 
   @protected
-  int dco_decode_u_32(dynamic raw);
+  void sse_encode_message_type(MessageType self, SseSerializer serializer);
 
   @protected
-  int dco_decode_u_8(dynamic raw);
+  void sse_encode_metric_phase(MetricPhase self, SseSerializer serializer);
 
   @protected
-  void dco_decode_unit(dynamic raw);
+  void sse_encode_metrics_snapshot(
+    MetricsSnapshot self,
+    SseSerializer serializer,
+  );
 
   @protected
-  BigInt dco_decode_usize(dynamic raw);
+  void sse_encode_model_info(ModelInfo self, SseSerializer serializer);
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+  void sse_encode_mood_state(MoodState self, SseSerializer serializer);
 
   @protected
-  RustStreamSink<ChatStreamEvent> sse_decode_StreamSink_chat_stream_event_Sse(
-    SseDeserializer deserializer,
+  void sse_encode_narration_perspective(
+    NarrationPerspective self,
+    SseSerializer serializer,
   );
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer);
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
 
   @protected
-  AppSettings sse_decode_app_settings(SseDeserializer deserializer);
+  void sse_encode_opt_box_autoadd_ambient_context(
+    AmbientContext? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+  void sse_encode_opt_box_autoadd_backup_snapshot_info(
+    BackupSnapshotInfo? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  AppSettings sse_decode_box_autoadd_app_settings(SseDeserializer deserializer);
+  void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
 
   @protected
-  Conversation sse_decode_box_autoadd_conversation(
-    SseDeserializer deserializer,
+  void sse_encode_opt_box_autoadd_check_in_schedule(
+    CheckInSchedule? self,
+    SseSerializer serializer,
   );
 
   @protected
-  MemoryContextCard sse_decode_box_autoadd_memory_context_card(
-    SseDeserializer deserializer,
+  void sse_encode_opt_box_autoadd_cognitive_insight(
+    CognitiveInsight? self,
+    SseSerializer serializer,
   );
 
   @protected
-  ChatStreamEvent sse_decode_chat_stream_event(SseDeserializer deserializer);
+  void sse_encode_opt_box_autoadd_conversation(
+    Conversation? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  Conversation sse_decode_conversation(SseDeserializer deserializer);
+  void sse_encode_opt_box_autoadd_conversation_insights(
+    ConversationInsights? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  ConversationSummary sse_decode_conversation_summary(
-    SseDeserializer deserializer,
+  void sse_encode_opt_box_autoadd_conversation_organization(
+    ConversationOrganization? self,
+    SseSerializer serializer,
   );
 
   @protected
-  DialogueStyle sse_decode_dialogue_style(SseDeserializer deserializer);
+  void sse_encode_opt_box_autoadd_daily_digest(
+    DailyDigest? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  double sse_decode_f_64(SseDeserializer deserializer);
+  void sse_encode_opt_box_autoadd_distilled_system_state(
+    DistilledSystemState? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  void sse_encode_opt_box_autoadd_encryption_key_source(
+    EncryptionKeySource? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer);
+  void sse_encode_opt_box_autoadd_fact(Fact? self, SseSerializer serializer);
 
   @protected
-  List<ConversationSummary> sse_decode_list_conversation_summary(
-    SseDeserializer deserializer,
+  void sse_encode_opt_box_autoadd_generation_metadata(
+    GenerationMetadata? self,
+    SseSerializer serializer,
   );
 
   @protected
-  List<MemorySearchResult> sse_decode_list_memory_search_result(
-    SseDeserializer deserializer,
+  void sse_encode_opt_box_autoadd_group_chat(
+    GroupChat? self,
+    SseSerializer serializer,
   );
 
   @protected
-  List<MemorySummary> sse_decode_list_memory_summary(
-    SseDeserializer deserializer,
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
+    SseSerializer serializer,
   );
 
   @protected
-  List<MemoryTier> sse_decode_list_memory_tier(SseDeserializer deserializer);
+  void sse_encode_opt_box_autoadd_integrity_report(
+    IntegrityReport? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<Message> sse_decode_list_message(SseDeserializer deserializer);
+  void sse_encode_opt_box_autoadd_layout_verification(
+    LayoutVerification? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  List<ModelInfo> sse_decode_list_model_info(SseDeserializer deserializer);
+  void sse_encode_opt_box_autoadd_lore_entry(
+    LoreEntry? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+  void sse_encode_opt_box_autoadd_memory_context_card(
+    MemoryContextCard? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  MemoryContextCard sse_decode_memory_context_card(
-    SseDeserializer deserializer,
+  void sse_encode_opt_box_autoadd_memory_explanation(
+    MemoryExplanation? self,
+    SseSerializer serializer,
   );
 
   @protected
-  MemorySearchResult sse_decode_memory_search_result(
-    SseDeserializer deserializer,
+  void sse_encode_opt_box_autoadd_memory_fidelity_report(
+    MemoryFidelityReport? self,
+    SseSerializer serializer,
   );
 
   @protected
-  MemorySummary sse_decode_memory_summary(SseDeserializer deserializer);
+  void sse_encode_opt_box_autoadd_memory_summary(
+    MemorySummary? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  MemoryTier sse_decode_memory_tier(SseDeserializer deserializer);
+  void sse_encode_opt_box_autoadd_message(
+    Message? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  Message sse_decode_message(SseDeserializer deserializer);
+  void sse_encode_opt_box_autoadd_mood_state(
+    MoodState? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  MessageRole sse_decode_message_role(SseDeserializer deserializer);
+  void sse_encode_opt_box_autoadd_preflight_advisory(
+    PreflightAdvisory? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  MessageType sse_decode_message_type(SseDeserializer deserializer);
+  void sse_encode_opt_box_autoadd_proxy_settings(
+    ProxySettings? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  ModelInfo sse_decode_model_info(SseDeserializer deserializer);
+  void sse_encode_opt_box_autoadd_reengagement_brief(
+    ReengagementBrief? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+  void sse_encode_opt_box_autoadd_relevance_insight(
+    RelevanceInsight? self,
+    SseSerializer serializer,
+  );
 
   @protected
-  Conversation? sse_decode_opt_box_autoadd_conversation(
-    SseDeserializer deserializer,
+  void sse_encode_opt_box_autoadd_say_do_mark(
+    SayDoMark? self,
+    SseSerializer serializer,
   );
 
   @protected
-  MemoryContextCard? sse_decode_opt_box_autoadd_memory_context_card(
-    SseDeserializer deserializer,
+  void sse_encode_opt_box_autoadd_summarization_config(
+    SummarizationConfig? self,
+    SseSerializer serializer,
   );
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer);
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+  void sse_encode_outbox_entry(OutboxEntry self, SseSerializer serializer);
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+  void sse_encode_outbox_status(OutboxStatus self, SseSerializer serializer);
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer);
+  void sse_encode_phase_duration(PhaseDuration self, SseSerializer serializer);
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
+  void sse_encode_preflight_advisory(
+    PreflightAdvisory self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_StreamSink_chat_stream_event_Sse(
-    RustStreamSink<ChatStreamEvent> self,
+  void sse_encode_prompt_template_info(
+    PromptTemplateInfo self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+  void sse_encode_provider_kind(ProviderKind self, SseSerializer serializer);
 
   @protected
-  void sse_encode_app_settings(AppSettings self, SseSerializer serializer);
+  void sse_encode_proxy_settings(ProxySettings self, SseSerializer serializer);
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+  void sse_encode_reaction_event(ReactionEvent self, SseSerializer serializer);
 
   @protected
-  void sse_encode_box_autoadd_app_settings(
-    AppSettings self,
+  void sse_encode_record_string_string(
+    (String, String) self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_box_autoadd_conversation(
-    Conversation self,
+  void sse_encode_reengagement_brief(
+    ReengagementBrief self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_box_autoadd_memory_context_card(
-    MemoryContextCard self,
+  void sse_encode_reindex_progress(
+    ReindexProgress self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_chat_stream_event(
-    ChatStreamEvent self,
+  void sse_encode_relation_change(
+    RelationChange self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_conversation(Conversation self, SseSerializer serializer);
-
-  @protected
-  void sse_encode_conversation_summary(
-    ConversationSummary self,
+  void sse_encode_relation_sentiment(
+    RelationSentiment self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_dialogue_style(DialogueStyle self, SseSerializer serializer);
+  void sse_encode_relationship_insight(
+    RelationshipInsight self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_f_64(double self, SseSerializer serializer);
+  void sse_encode_relevance_insight(
+    RelevanceInsight self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+  void sse_encode_reply_length(ReplyLength self, SseSerializer serializer);
 
   @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+  void sse_encode_reply_signals(ReplySignals self, SseSerializer serializer);
 
   @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+  void sse_encode_response_style(ResponseStyle self, SseSerializer serializer);
 
   @protected
-  void sse_encode_list_conversation_summary(
-    List<ConversationSummary> self,
+  void sse_encode_retention_policy(
+    RetentionPolicy self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_list_memory_search_result(
-    List<MemorySearchResult> self,
-    SseSerializer serializer,
-  );
+  void sse_encode_retry_policy(RetryPolicy self, SseSerializer serializer);
 
   @protected
-  void sse_encode_list_memory_summary(
-    List<MemorySummary> self,
-    SseSerializer serializer,
-  );
+  void sse_encode_safety_action(SafetyAction self, SseSerializer serializer);
 
   @protected
-  void sse_encode_list_memory_tier(
-    List<MemoryTier> self,
+  void sse_encode_safety_category(
+    SafetyCategory self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_list_message(List<Message> self, SseSerializer serializer);
+  void sse_encode_safety_event(SafetyEvent self, SseSerializer serializer);
 
   @protected
-  void sse_encode_list_model_info(
-    List<ModelInfo> self,
+  void sse_encode_safety_policy(SafetyPolicy self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_safety_rule(SafetyRule self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_safety_stage(SafetyStage self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_say_do_mark(SayDoMark self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_say_do_rule(SayDoRule self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_say_do_rule_set(SayDoRuleSet self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_search_hit(SearchHit self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_shadow_eval_record(
+    ShadowEvalRecord self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
+  void sse_encode_shadow_eval_summary(
+    ShadowEvalSummary self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_memory_context_card(
-    MemoryContextCard self,
+  void sse_encode_short_term_insight(
+    ShortTermInsight self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_memory_search_result(
-    MemorySearchResult self,
+  void sse_encode_summarization_config(
+    SummarizationConfig self,
     SseSerializer serializer,
   );
 
   @protected
-  void sse_encode_memory_summary(MemorySummary self, SseSerializer serializer);
+  void sse_encode_sync_backend_kind(
+    SyncBackendKind self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_memory_tier(MemoryTier self, SseSerializer serializer);
+  void sse_encode_sync_report(SyncReport self, SseSerializer serializer);
 
   @protected
-  void sse_encode_message(Message self, SseSerializer serializer);
+  void sse_encode_sync_settings(SyncSettings self, SseSerializer serializer);
 
   @protected
-  void sse_encode_message_role(MessageRole self, SseSerializer serializer);
+  void sse_encode_thinking_retention(
+    ThinkingRetention self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_message_type(MessageType self, SseSerializer serializer);
+  void sse_encode_timeline_entry(TimelineEntry self, SseSerializer serializer);
 
   @protected
-  void sse_encode_model_info(ModelInfo self, SseSerializer serializer);
+  void sse_encode_timeline_entry_kind(
+    TimelineEntryKind self,
+    SseSerializer serializer,
+  );
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
+  void sse_encode_turn_metrics(TurnMetrics self, SseSerializer serializer);
 
   @protected
-  void sse_encode_opt_box_autoadd_conversation(
-    Conversation? self,
-    SseSerializer serializer,
-  );
+  void sse_encode_turn_policy(TurnPolicy self, SseSerializer serializer);
 
   @protected
-  void sse_encode_opt_box_autoadd_memory_context_card(
-    MemoryContextCard? self,
-    SseSerializer serializer,
-  );
+  void sse_encode_u_32(int self, SseSerializer serializer);
 
   @protected
-  void sse_encode_u_32(int self, SseSerializer serializer);
+  void sse_encode_u_64(BigInt self, SseSerializer serializer);
 
   @protected
   void sse_encode_u_8(int self, SseSerializer serializer);
@@ -437,6 +3348,9 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_unit(void self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_user_persona(UserPersona self, SseSerializer serializer);
+
   @protected
   void sse_encode_usize(BigInt self, SseSerializer serializer);
 }
@@ -478,7 +478,7 @@ class ChatState extends ChangeNotifier {
         if (_currentConversationId != conversationId) return;
 
         try {
-          event.when(
+          event.maybeWhen(
             contentDelta: (delta) => appendStreamingContent(delta),
             thinkingDelta: (delta) => appendThinkingContent(delta),
             done: () {
@@ -509,6 +509,8 @@ class ChatState extends ChangeNotifier {
               debugPrint('[ChatState] Stream error event: $msg');
               notifyListeners();
             },
+            // 其余事件（反应、插画、发件箱等）本页暂不展示
+            orElse: () {},
           );
         } catch (e) {
           debugPrint('[ChatState] Error processing stream event: $e');
@@ -622,6 +624,12 @@ class ChatState extends ChangeNotifier {
           model: _selectedModel,
           timestamp: DateTime.now().millisecondsSinceEpoch,
           messageType: MessageType.say,
+          attachments: const [],
+          alternatives: const [],
+          selectedAlternative: 0,
+          starred: false,
+          reactions: const [],
+          editHistory: const [],
         ),
       );
     notifyListeners();
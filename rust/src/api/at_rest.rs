@@ -0,0 +1,474 @@
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{OnceLock, RwLock};
+
+use aes_gcm::aead::rand_core::RngCore;
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+use serde::{Deserialize, Serialize};
+
+use super::data_models::{EncryptionKeySource, EncryptionSecret, EncryptionStatus};
+use super::error_handler::ChatError;
+
+// ═══════════════════════════════════════════════════════════════════
+//  静态加密 (At-rest Encryption)
+//  ─────────────────────────────────────────────────────────────────
+//  对话、冷存储消息、记忆索引（含蒸馏状态）、事实库与全文搜索索引可选 AES-256-GCM 加密：
+//    - 密钥来源：用户口令（PBKDF2-HMAC-SHA256 + 随机盐派生），或宿主 App
+//      从平台密钥库（Keychain / Keystore）取出的 32 字节密钥
+//    - 文件格式：MAGIC + nonce(12) + 密文。不带 MAGIC 的文件按明文读取，
+//      所以启用前写下的文件照常可读、下次保存时自动加密；启用 / 停用时
+//      还会把 ENCRYPTED_DIRS 下的文件整体迁移一遍
+//    - encryption.json 只记录密钥来源、盐与校验块（用于验证凭据），不含密钥
+//  密钥只保存在内存中：App 每次启动后需先 unlock 才能读取加密文件，
+//  未解锁时读取加密文件返回 InvalidData 错误。
+//
+//  原子写入：所有经由本模块的写入先写 {file}.tmp 并落盘，再改名替换原文件，
+//  写到一半崩溃只会留下残缺的临时文件。替换前把旧版本保留为 {file}.bak
+//  （硬链接，不复制数据）。load 读取或解析主文件失败时退回备份，
+//  并用备份修复主文件，而不是让整个对话永远报 StorageError。
+//
+//  存储结构：
+//    encryption.json
+//    {file}.bak      — 各受管文件的上一版
+// ═══════════════════════════════════════════════════════════════════
+
+/// 加密文件头
+const MAGIC: &[u8; 8] = b"T2UENC1\0";
+const NONCE_LEN: usize = 12;
+const SALT_LEN: usize = 16;
+const KEY_LEN: usize = 32;
+/// 口令派生的迭代次数
+const PBKDF2_ROUNDS: u32 = 200_000;
+/// 校验块明文：解密成功即说明凭据正确
+const CHECK_PLAINTEXT: &[u8] = b"talk2u-at-rest-check";
+const CONFIG_FILE: &str = "encryption.json";
+
+/// 受加密保护的布局目录
+pub const ENCRYPTED_DIRS: [&str; 5] = [
+    "conversations",
+    "memory_index",
+    "knowledge_base",
+    "search_index",
+    "cold_storage",
+];
+
+type KeyBytes = [u8; KEY_LEN];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct EncryptionConfig {
+    source: EncryptionKeySource,
+    /// base64url
+    salt: String,
+    /// base64url，用派生密钥加密的 CHECK_PLAINTEXT
+    check: String,
+}
+
+static ACTIVE_KEY: OnceLock<RwLock<Option<KeyBytes>>> = OnceLock::new();
+
+fn key_slot() -> &'static RwLock<Option<KeyBytes>> {
+    ACTIVE_KEY.get_or_init(|| RwLock::new(None))
+}
+
+fn active_key() -> Option<KeyBytes> {
+    key_slot().read().ok().and_then(|k| *k)
+}
+
+fn install_key(key: Option<KeyBytes>) {
+    if let Ok(mut slot) = key_slot().write() {
+        *slot = key;
+    }
+}
+
+// ── 文件读写（供 ConversationStore / MemoryEngine / KnowledgeStore 使用） ──
+
+/// 读取文件并按需解密
+pub fn read(path: impl AsRef<Path>) -> io::Result<Vec<u8>> {
+    open(active_key().as_ref(), &fs::read(path)?)
+}
+
+/// 写入文件：已解锁加密时加密后写入，否则写明文。先写临时文件再改名替换，
+/// 被替换的旧版本保留为备份
+pub fn write(path: impl AsRef<Path>, data: impl AsRef<[u8]>) -> io::Result<()> {
+    let path = path.as_ref();
+    let tmp = sidecar(path, TEMP_SUFFIX);
+    {
+        let mut file = fs::File::create(&tmp)?;
+        file.write_all(&seal(active_key().as_ref(), data.as_ref())?)?;
+        file.sync_all()?;
+    }
+    if path.exists() {
+        let backup = backup_path(path);
+        let _ = fs::remove_file(&backup);
+        // 不支持硬链接的文件系统退回复制
+        fs::hard_link(path, &backup).or_else(|_| fs::copy(path, &backup).map(|_| ()))?;
+    }
+    fs::rename(&tmp, path)
+}
+
+/// 读取并解析文件；主文件读不出或解析失败时退回上一版备份，并用备份修复主文件。
+/// 备份同样失败时返回主文件的错误
+pub fn load<T>(
+    path: impl AsRef<Path>,
+    what: &str,
+    parse: impl Fn(&[u8]) -> Result<T, ChatError>,
+) -> Result<T, ChatError> {
+    let path = path.as_ref();
+    let attempt = |p: &Path| {
+        read(p)
+            .map_err(|e| ChatError::StorageError {
+                message: format!("Failed to read {}: {}", what, e),
+            })
+            .and_then(|data| parse(&data))
+    };
+    let error = match attempt(path) {
+        Ok(value) => return Ok(value),
+        Err(e) => e,
+    };
+    let backup = backup_path(path);
+    if !backup.exists() {
+        return Err(error);
+    }
+    let value = match attempt(&backup) {
+        Ok(value) => value,
+        Err(_) => return Err(error),
+    };
+    tracing::warn!(path = %path.display(), error = %error, "文件损坏，已从备份恢复");
+    // 先修复主文件：否则下次写入会把损坏的主文件轮换成备份
+    let tmp = sidecar(path, TEMP_SUFFIX);
+    if let Err(e) = fs::copy(&backup, &tmp).and_then(|_| fs::rename(&tmp, path)) {
+        tracing::warn!(path = %path.display(), error = %e, "备份恢复写回失败");
+    }
+    Ok(value)
+}
+
+/// 原样写入已是存储格式的内容（备份恢复、同步拉取），同样先写临时文件再改名。
+/// 旧备份随之删除：它不是新内容的上一版，留着会让 load 在出错时退回过期数据
+pub fn install(path: impl AsRef<Path>, data: impl AsRef<[u8]>) -> io::Result<()> {
+    let path = path.as_ref();
+    let tmp = sidecar(path, TEMP_SUFFIX);
+    {
+        let mut file = fs::File::create(&tmp)?;
+        file.write_all(data.as_ref())?;
+        file.sync_all()?;
+    }
+    let backup = backup_path(path);
+    if backup.exists() {
+        fs::remove_file(&backup)?;
+    }
+    fs::rename(&tmp, path)
+}
+
+/// 删除文件及其备份
+pub fn remove(path: impl AsRef<Path>) -> io::Result<()> {
+    let path = path.as_ref();
+    let backup = backup_path(path);
+    if backup.exists() {
+        fs::remove_file(&backup)?;
+    }
+    if path.exists() {
+        fs::remove_file(path)?;
+    }
+    Ok(())
+}
+
+const TEMP_SUFFIX: &str = ".tmp";
+pub const BACKUP_SUFFIX: &str = ".bak";
+
+fn sidecar(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+pub fn backup_path(path: &Path) -> PathBuf {
+    sidecar(path, BACKUP_SUFFIX)
+}
+
+/// 写入过程中的临时文件或备份（不属于数据本身，同步等场景应跳过）
+pub fn is_sidecar(file_name: &str) -> bool {
+    file_name.ends_with(TEMP_SUFFIX) || file_name.ends_with(BACKUP_SUFFIX)
+}
+
+fn is_sealed(data: &[u8]) -> bool {
+    data.len() >= MAGIC.len() + NONCE_LEN && data.starts_with(MAGIC)
+}
+
+fn seal(key: Option<&KeyBytes>, plain: &[u8]) -> io::Result<Vec<u8>> {
+    let Some(key) = key else {
+        return Ok(plain.to_vec());
+    };
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key));
+    let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+    let ciphertext = cipher
+        .encrypt(&nonce, plain)
+        .map_err(|_| io::Error::other("encryption failed"))?;
+    let mut out = Vec::with_capacity(MAGIC.len() + NONCE_LEN + ciphertext.len());
+    out.extend_from_slice(MAGIC);
+    out.extend_from_slice(&nonce);
+    out.extend_from_slice(&ciphertext);
+    Ok(out)
+}
+
+fn open(key: Option<&KeyBytes>, data: &[u8]) -> io::Result<Vec<u8>> {
+    if !is_sealed(data) {
+        return Ok(data.to_vec());
+    }
+    let key = key.ok_or_else(|| {
+        io::Error::new(io::ErrorKind::InvalidData, "file is encrypted and storage is locked")
+    })?;
+    let cipher = Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key));
+    let (nonce, ciphertext) = data[MAGIC.len()..].split_at(NONCE_LEN);
+    cipher
+        .decrypt(Nonce::from_slice(nonce), ciphertext)
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "decryption failed"))
+}
+
+// ── 启用 / 解锁 / 停用 ──
+
+fn config_path(base_path: &str) -> PathBuf {
+    PathBuf::from(base_path).join(CONFIG_FILE)
+}
+
+fn load_config(base_path: &str) -> Option<EncryptionConfig> {
+    let json = fs::read_to_string(config_path(base_path)).ok()?;
+    serde_json::from_str(&json).ok()
+}
+
+fn derive_key(secret: &EncryptionSecret, salt: &[u8]) -> Result<KeyBytes, ChatError> {
+    match secret {
+        EncryptionSecret::Passphrase(passphrase) => {
+            if passphrase.is_empty() {
+                return Err(ChatError::ValidationError {
+                    message: "Passphrase must not be empty".to_string(),
+                });
+            }
+            let mut key = [0u8; KEY_LEN];
+            pbkdf2::pbkdf2_hmac::<sha2::Sha256>(
+                passphrase.as_bytes(),
+                salt,
+                PBKDF2_ROUNDS,
+                &mut key,
+            );
+            Ok(key)
+        }
+        EncryptionSecret::Keystore(bytes) => {
+            bytes
+                .as_slice()
+                .try_into()
+                .map_err(|_| ChatError::ValidationError {
+                    message: format!("Keystore key must be {} bytes", KEY_LEN),
+                })
+        }
+    }
+}
+
+fn source_of(secret: &EncryptionSecret) -> EncryptionKeySource {
+    match secret {
+        EncryptionSecret::Passphrase(_) => EncryptionKeySource::Passphrase,
+        EncryptionSecret::Keystore(_) => EncryptionKeySource::Keystore,
+    }
+}
+
+/// 按已保存的配置验证凭据，返回密钥
+fn verify(config: &EncryptionConfig, secret: &EncryptionSecret) -> Result<KeyBytes, ChatError> {
+    let invalid = || ChatError::ValidationError {
+        message: "Incorrect passphrase or key".to_string(),
+    };
+    if source_of(secret) != config.source {
+        return Err(invalid());
+    }
+    let salt = base64url::decode(&config.salt).map_err(|_| invalid())?;
+    let check = base64url::decode(&config.check).map_err(|_| invalid())?;
+    let key = derive_key(secret, &salt)?;
+    match open(Some(&key), &check) {
+        Ok(plain) if plain == CHECK_PLAINTEXT => Ok(key),
+        _ => Err(invalid()),
+    }
+}
+
+/// 当前加密状态
+pub fn status(base_path: &str) -> EncryptionStatus {
+    match load_config(base_path) {
+        Some(config) => EncryptionStatus {
+            enabled: true,
+            unlocked: active_key().is_some(),
+            source: Some(config.source),
+        },
+        None => EncryptionStatus {
+            enabled: false,
+            unlocked: true,
+            source: None,
+        },
+    }
+}
+
+/// 启用加密：写入配置、装载密钥，再把现有明文文件迁移为密文，返回迁移的文件数
+pub fn enable(base_path: &str, secret: &EncryptionSecret) -> Result<u32, ChatError> {
+    if load_config(base_path).is_some() {
+        return Err(ChatError::ValidationError {
+            message: "At-rest encryption is already enabled".to_string(),
+        });
+    }
+    let mut salt = [0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    let key = derive_key(secret, &salt)?;
+    let check = seal(Some(&key), CHECK_PLAINTEXT).map_err(|e| ChatError::StorageError {
+        message: format!("Failed to create encryption check: {}", e),
+    })?;
+    let config = EncryptionConfig {
+        source: source_of(secret),
+        salt: base64url::encode(salt),
+        check: base64url::encode(check),
+    };
+    let json = serde_json::to_string_pretty(&config).map_err(|e| ChatError::StorageError {
+        message: format!("Failed to serialize encryption config: {}", e),
+    })?;
+    // 先落配置再迁移：中途中断时已加密的文件仍可凭同一凭据解锁
+    fs::write(config_path(base_path), json).map_err(|e| ChatError::StorageError {
+        message: format!("Failed to write encryption config: {}", e),
+    })?;
+    install_key(Some(key));
+    migrate(base_path, None, Some(&key))
+}
+
+/// 启动后解锁：验证凭据并装载密钥
+pub fn unlock(base_path: &str, secret: &EncryptionSecret) -> Result<(), ChatError> {
+    let config = load_config(base_path).ok_or_else(|| ChatError::ValidationError {
+        message: "At-rest encryption is not enabled".to_string(),
+    })?;
+    install_key(Some(verify(&config, secret)?));
+    Ok(())
+}
+
+/// 停用加密：验证凭据后把密文迁移回明文并删除配置，返回迁移的文件数
+pub fn disable(base_path: &str, secret: &EncryptionSecret) -> Result<u32, ChatError> {
+    let config = load_config(base_path).ok_or_else(|| ChatError::ValidationError {
+        message: "At-rest encryption is not enabled".to_string(),
+    })?;
+    let key = verify(&config, secret)?;
+    let migrated = migrate(base_path, Some(&key), None)?;
+    fs::remove_file(config_path(base_path)).map_err(|e| ChatError::StorageError {
+        message: format!("Failed to remove encryption config: {}", e),
+    })?;
+    install_key(None);
+    Ok(migrated)
+}
+
+/// 用 from 解密、to 重新加密 ENCRYPTED_DIRS 下的全部文件（先写临时文件再替换），
+/// 内容已是目标形态的文件跳过；返回改写的文件数
+fn migrate(base_path: &str, from: Option<&KeyBytes>, to: Option<&KeyBytes>) -> Result<u32, ChatError> {
+    let storage_err = |context: &str, e: io::Error| ChatError::StorageError {
+        message: format!("{}: {}", context, e),
+    };
+    let mut migrated = 0;
+    for dir in ENCRYPTED_DIRS {
+        let dir = PathBuf::from(base_path).join(dir);
+        let Ok(entries) = fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if !path.is_file() || path.extension().is_some_and(|e| e == "tmp") {
+                continue;
+            }
+            let data = fs::read(&path).map_err(|e| storage_err("Failed to read file", e))?;
+            if is_sealed(&data) == to.is_some() {
+                continue;
+            }
+            let plain = open(from, &data).map_err(|e| storage_err("Failed to decrypt file", e))?;
+            let out = seal(to, &plain).map_err(|e| storage_err("Failed to encrypt file", e))?;
+            let tmp = path.with_extension("tmp");
+            fs::write(&tmp, out).map_err(|e| storage_err("Failed to write file", e))?;
+            fs::rename(&tmp, &path).map_err(|e| storage_err("Failed to replace file", e))?;
+            migrated += 1;
+        }
+    }
+    Ok(migrated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seal_open_and_migration_roundtrip() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let base = tmp.path().to_str().unwrap();
+        let key: KeyBytes = [7u8; KEY_LEN];
+
+        let sealed = seal(Some(&key), b"{\"facts\":[]}").unwrap();
+        assert!(is_sealed(&sealed));
+        assert_eq!(open(Some(&key), &sealed).unwrap(), b"{\"facts\":[]}");
+        assert!(open(Some(&[8u8; KEY_LEN]), &sealed).is_err());
+        assert!(open(None, &sealed).is_err(), "未解锁时不能读取密文");
+        assert_eq!(open(None, b"plain").unwrap(), b"plain", "明文原样读取");
+
+        let dir = tmp.path().join("knowledge_base");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("c1_facts.json"), "[1]").unwrap();
+        fs::write(tmp.path().join("settings.json"), "{}").unwrap();
+
+        assert_eq!(migrate(base, None, Some(&key)).unwrap(), 1);
+        assert_eq!(migrate(base, None, Some(&key)).unwrap(), 0, "已加密的文件跳过");
+        let raw = fs::read(dir.join("c1_facts.json")).unwrap();
+        assert!(is_sealed(&raw));
+        assert_eq!(fs::read_to_string(tmp.path().join("settings.json")).unwrap(), "{}");
+
+        assert_eq!(migrate(base, Some(&key), None).unwrap(), 1);
+        assert_eq!(fs::read_to_string(dir.join("c1_facts.json")).unwrap(), "[1]");
+    }
+
+    #[test]
+    fn test_atomic_write_keeps_backup_and_recovers() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let path = tmp.path().join("c1.json");
+        let parse = |data: &[u8]| {
+            serde_json::from_slice::<Vec<u32>>(data).map_err(|e| ChatError::StorageError {
+                message: e.to_string(),
+            })
+        };
+
+        write(&path, "[1]").unwrap();
+        write(&path, "[1,2]").unwrap();
+        assert!(!sidecar(&path, TEMP_SUFFIX).exists());
+        assert_eq!(fs::read_to_string(backup_path(&path)).unwrap(), "[1]");
+
+        // 写到一半崩溃留下的残缺文件：退回备份并修复主文件
+        fs::write(&path, "[1,").unwrap();
+        assert_eq!(load(&path, "test", parse).unwrap(), vec![1]);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "[1]");
+
+        // 备份同样损坏时如实报错
+        fs::write(&path, "[1,").unwrap();
+        fs::write(backup_path(&path), "oops").unwrap();
+        assert!(load(&path, "test", parse).is_err());
+
+        // 原样写入外来内容时不保留过期备份
+        write(&path, "[1,2,3]").unwrap();
+        install(&path, "[4]").unwrap();
+        assert!(!backup_path(&path).exists());
+        assert_eq!(load(&path, "test", parse).unwrap(), vec![4]);
+
+        remove(&path).unwrap();
+        assert!(!path.exists() && !backup_path(&path).exists());
+        assert!(is_sidecar("c1.json.bak") && !is_sidecar("c1.json"));
+    }
+
+    #[test]
+    fn test_credentials_are_verified_against_check_block() {
+        let salt = [1u8; SALT_LEN];
+        let secret = EncryptionSecret::Passphrase("海边的约定".to_string());
+        let key = derive_key(&secret, &salt).unwrap();
+        let config = EncryptionConfig {
+            source: EncryptionKeySource::Passphrase,
+            salt: base64url::encode(salt),
+            check: base64url::encode(seal(Some(&key), CHECK_PLAINTEXT).unwrap()),
+        };
+        assert_eq!(verify(&config, &secret).unwrap(), key);
+        assert!(verify(&config, &EncryptionSecret::Passphrase("错的".to_string())).is_err());
+        assert!(verify(&config, &EncryptionSecret::Keystore(key.to_vec())).is_err());
+        assert!(derive_key(&EncryptionSecret::Keystore(vec![0; 16]), &salt).is_err());
+    }
+}
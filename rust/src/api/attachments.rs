@@ -0,0 +1,235 @@
+use std::fs;
+use std::path::PathBuf;
+
+use flutter_rust_bridge::frb;
+
+use super::data_models::{Message, MessageAttachment, MessageRole};
+use super::error_handler::ChatError;
+
+// ═══════════════════════════════════════════════════════════════════
+//  图片附件 (Attachments)
+//  ─────────────────────────────────────────────────────────────────
+//  用户可以随消息发送图片，由视觉模型 VISION_MODEL 识图：
+//    1. 落盘：data URL 形式的本地图片单独存成文件，消息里只留元信息，
+//       避免对话文件随图片膨胀；远程图片只记地址
+//    2. 识图：本轮先让视觉模型描述图片，描述写回附件的 caption
+//    3. 回复：本轮最后一条用户消息以多模态内容（文本 + image_url）发送，
+//       历史轮次的图片只以 caption 文字出现，不重复上传
+//    4. 记忆：事实提取时 caption 随消息一起提供，图中所见也能入库
+//
+//  存储结构：
+//    attachments/{conversation_id}__{attachment_id}.txt   图片 data URL
+// ═══════════════════════════════════════════════════════════════════
+
+/// 识图与多模态回复使用的视觉模型
+pub const VISION_MODEL: &str = "glm-4v";
+/// 识图描述的输出上限
+pub const CAPTION_MAX_TOKENS: u32 = 300;
+/// 单张图片识图的超时
+pub const CAPTION_TIMEOUT_SECS: u64 = 30;
+/// 只发图片、没有文字时的占位正文
+pub const IMAGE_ONLY_CONTENT: &str = "（发来了图片）";
+/// 单条消息最多附带的图片数
+pub const MAX_ATTACHMENTS_PER_MESSAGE: usize = 4;
+/// 单张图片 data URL 的最大长度（约 6MB 原图）
+const MAX_DATA_URL_BYTES: usize = 8 * 1024 * 1024;
+/// 文件名中对话ID与附件ID的分隔符
+const NAME_SEPARATOR: &str = "__";
+
+#[frb(opaque)]
+#[derive(Clone)]
+pub struct AttachmentStore {
+    base_path: String,
+}
+
+impl AttachmentStore {
+    pub fn new(base_path: &str) -> Self {
+        Self {
+            base_path: base_path.to_string(),
+        }
+    }
+
+    fn attachments_dir(&self) -> Result<PathBuf, ChatError> {
+        let dir = PathBuf::from(&self.base_path).join("attachments");
+        if !dir.exists() {
+            fs::create_dir_all(&dir).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to create attachments directory: {}", e),
+            })?;
+        }
+        Ok(dir)
+    }
+
+    fn data_path(&self, conversation_id: &str, attachment_id: &str) -> Result<PathBuf, ChatError> {
+        Ok(self.attachments_dir()?.join(format!(
+            "{}{}{}.txt",
+            conversation_id, NAME_SEPARATOR, attachment_id
+        )))
+    }
+
+    /// 校验并落盘一组附件：data URL 存为文件（url 置空），远程地址原样保留
+    pub fn persist(
+        &self,
+        conversation_id: &str,
+        attachments: Vec<MessageAttachment>,
+    ) -> Result<Vec<MessageAttachment>, ChatError> {
+        if attachments.len() > MAX_ATTACHMENTS_PER_MESSAGE {
+            return Err(ChatError::ValidationError {
+                message: format!(
+                    "At most {} images can be attached to one message",
+                    MAX_ATTACHMENTS_PER_MESSAGE
+                ),
+            });
+        }
+        let mut saved = Vec::with_capacity(attachments.len());
+        for mut attachment in attachments {
+            if !attachment.mime_type.starts_with("image/") {
+                return Err(ChatError::ValidationError {
+                    message: format!("Unsupported attachment type: {}", attachment.mime_type),
+                });
+            }
+            let url = attachment.url.take().unwrap_or_default();
+            if attachment.id.is_empty() {
+                attachment.id = uuid::Uuid::new_v4().to_string();
+            }
+            if url.starts_with("data:image/") {
+                if url.len() > MAX_DATA_URL_BYTES {
+                    return Err(ChatError::ValidationError {
+                        message: "Image is too large".to_string(),
+                    });
+                }
+                fs::write(self.data_path(conversation_id, &attachment.id)?, url).map_err(|e| {
+                    ChatError::StorageError {
+                        message: format!("Failed to write attachment: {}", e),
+                    }
+                })?;
+            } else if url.starts_with("https://") || url.starts_with("http://") {
+                attachment.url = Some(url);
+            } else {
+                return Err(ChatError::ValidationError {
+                    message: "Attachment needs a data URL or an http(s) URL".to_string(),
+                });
+            }
+            saved.push(attachment);
+        }
+        Ok(saved)
+    }
+
+    /// 发送给模型的图片地址：远程地址或本地存的 data URL
+    pub fn image_url(&self, conversation_id: &str, attachment: &MessageAttachment) -> Option<String> {
+        if let Some(url) = &attachment.url {
+            return Some(url.clone());
+        }
+        fs::read_to_string(self.data_path(conversation_id, &attachment.id).ok()?).ok()
+    }
+
+    /// 给本轮最后一条用户消息的附件填上可发送的图片地址（仅用于构建请求，不落盘）
+    pub fn resolve_latest(&self, conversation_id: &str, messages: &mut [Message]) {
+        let Some(latest) = messages.iter_mut().rev().find(|m| m.role == MessageRole::User) else {
+            return;
+        };
+        for attachment in latest.attachments.iter_mut() {
+            attachment.url = self.image_url(conversation_id, attachment);
+        }
+        latest.attachments.retain(|a| a.url.is_some());
+    }
+
+    /// 删除对话的全部本地图片
+    pub fn delete(&self, conversation_id: &str) -> Result<(), ChatError> {
+        let prefix = format!("{}{}", conversation_id, NAME_SEPARATOR);
+        let entries = match fs::read_dir(self.attachments_dir()?) {
+            Ok(entries) => entries,
+            Err(_) => return Ok(()),
+        };
+        for entry in entries.filter_map(|e| e.ok()) {
+            let is_ours = entry
+                .file_name()
+                .to_str()
+                .is_some_and(|name| name.starts_with(&prefix));
+            if is_ours {
+                fs::remove_file(entry.path()).map_err(|e| ChatError::StorageError {
+                    message: format!("Failed to delete attachment: {}", e),
+                })?;
+            }
+        }
+        Ok(())
+    }
+
+    /// 识图 prompt：只描述看到的内容，供后续对话与事实提取使用
+    pub fn build_caption_prompt(user_content: &str) -> String {
+        format!(
+            "用户在聊天中发来了图片，并说：「{}」\n\
+             请客观描述图片内容：主体、场景、文字、能看出的地点/时间/人物关系等细节。\n\
+             不要猜测图片以外的信息，不要评价，100字以内，只输出描述。",
+            user_content.trim()
+        )
+    }
+
+    /// 消息在纯文本场景中的表示：正文 + 图片描述
+    pub fn text_with_captions(message: &Message) -> String {
+        if message.attachments.is_empty() {
+            return message.content.clone();
+        }
+        let mut text = message.content.clone();
+        for attachment in &message.attachments {
+            match attachment.caption.as_deref().map(str::trim) {
+                Some(caption) if !caption.is_empty() => {
+                    text.push_str(&format!("\n[图片：{}]", caption))
+                }
+                _ => text.push_str("\n[图片]"),
+            }
+        }
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn image(url: &str) -> MessageAttachment {
+        MessageAttachment {
+            id: String::new(),
+            mime_type: "image/png".to_string(),
+            url: Some(url.to_string()),
+            caption: None,
+        }
+    }
+
+    #[test]
+    fn test_persist_stores_data_urls_out_of_the_message() {
+        let tmp = TempDir::new().unwrap();
+        let store = AttachmentStore::new(tmp.path().to_str().unwrap());
+        let saved = store
+            .persist("c1", vec![image("data:image/png;base64,iVBORw0"), image("https://x.cn/a.png")])
+            .unwrap();
+        assert!(saved[0].url.is_none() && !saved[0].id.is_empty());
+        assert_eq!(store.image_url("c1", &saved[0]).unwrap(), "data:image/png;base64,iVBORw0");
+        assert_eq!(saved[1].url.as_deref(), Some("https://x.cn/a.png"));
+
+        assert!(store.persist("c1", vec![image("file:///etc/passwd")]).is_err());
+        let mut pdf = image("https://x.cn/a.pdf");
+        pdf.mime_type = "application/pdf".to_string();
+        assert!(store.persist("c1", vec![pdf]).is_err());
+
+        store.persist("c10", vec![image("data:image/png;base64,AAAA")]).unwrap();
+        store.delete("c1").unwrap();
+        assert!(store.image_url("c1", &saved[0]).is_none());
+        assert_eq!(std::fs::read_dir(tmp.path().join("attachments")).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn test_text_with_captions() {
+        let mut msg = Message {
+            id: String::new(),
+            timestamp: 0,
+            attachments: vec![image("https://x.cn/a.png"), image("https://x.cn/b.png")],
+            ..Message::new(MessageRole::User, "看我家猫", "")
+        };
+        msg.attachments[0].caption = Some("一只橘猫趴在窗台上".to_string());
+        assert_eq!(
+            AttachmentStore::text_with_captions(&msg),
+            "看我家猫\n[图片：一只橘猫趴在窗台上]\n[图片]"
+        );
+    }
+}
@@ -0,0 +1,183 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
+
+use tokio::sync::broadcast;
+
+use super::data_models::{
+    BackgroundJobKind, BackgroundTaskEvent, BackgroundTaskStatus, DeferredJob,
+};
+
+// ═══════════════════════════════════════════════════════════════════
+//  后台任务队列 (Background Tasks)
+//  ─────────────────────────────────────────────────────────────────
+//  回复落盘后的事实提取与记忆摘要不再在 send_message 里等待：
+//    1. 入队：ChatEngine 把任务交给这里后立即返回，Flutter 等待的发送调用随之结束；
+//       同一对话的同类任务还在排队时不重复入队
+//    2. 执行：在 tokio 任务中调用 init_app 安装的执行器（新建引擎跑
+//       ChatEngine::run_deferred_job），仍经 JobScheduler 限流与延后
+//    3. 状态：每次状态变化广播一条 BackgroundTaskEvent（watch_background_tasks）
+//  没有安装执行器或不在 tokio 运行时中时（如单元测试）入队失败，调用方就地执行。
+// ═══════════════════════════════════════════════════════════════════
+
+/// 状态事件通道容量（订阅者跟不上时丢弃最旧的事件）
+const EVENT_CHANNEL_CAPACITY: usize = 64;
+
+pub type TaskFuture = Pin<Box<dyn Future<Output = bool> + Send>>;
+/// 执行一个任务，返回是否完成
+pub type TaskRunner = Arc<dyn Fn(DeferredJob) -> TaskFuture + Send + Sync>;
+
+pub struct BackgroundTasks {
+    runner: RwLock<Option<TaskRunner>>,
+    /// 排队或执行中的任务，按任务 ID
+    active: Mutex<HashMap<String, BackgroundTaskEvent>>,
+    events: broadcast::Sender<BackgroundTaskEvent>,
+}
+
+static GLOBAL: OnceLock<BackgroundTasks> = OnceLock::new();
+
+impl Default for BackgroundTasks {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BackgroundTasks {
+    pub fn new() -> Self {
+        let (events, _) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+        Self {
+            runner: RwLock::new(None),
+            active: Mutex::new(HashMap::new()),
+            events,
+        }
+    }
+
+    pub fn global() -> &'static BackgroundTasks {
+        GLOBAL.get_or_init(BackgroundTasks::new)
+    }
+
+    /// 安装任务执行器（init_app 调用；重复调用替换之前的）
+    pub fn install_runner(&self, runner: TaskRunner) {
+        *self.runner.write().unwrap() = Some(runner);
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<BackgroundTaskEvent> {
+        self.events.subscribe()
+    }
+
+    /// 排队或执行中的任务（按入队时间排序）
+    pub fn active(&self) -> Vec<BackgroundTaskEvent> {
+        let mut tasks: Vec<BackgroundTaskEvent> = self
+            .active
+            .lock()
+            .map(|active| active.values().cloned().collect())
+            .unwrap_or_default();
+        tasks.sort_by_key(|t| t.updated_at);
+        tasks
+    }
+
+    /// 把任务放进队列后台执行；返回 false 表示没有入队，调用方应就地执行
+    pub fn enqueue(&'static self, kind: BackgroundJobKind, conversation_id: &str) -> bool {
+        let Some(runner) = self.runner.read().unwrap().clone() else {
+            return false;
+        };
+        let Ok(handle) = tokio::runtime::Handle::try_current() else {
+            return false;
+        };
+        let task = {
+            let mut active = self.active.lock().unwrap();
+            let queued = active.values().any(|t| {
+                t.kind == kind
+                    && t.conversation_id == conversation_id
+                    && t.status == BackgroundTaskStatus::Queued
+            });
+            if queued {
+                return true;
+            }
+            let task = BackgroundTaskEvent {
+                task_id: uuid::Uuid::new_v4().to_string(),
+                kind,
+                conversation_id: conversation_id.to_string(),
+                status: BackgroundTaskStatus::Queued,
+                updated_at: chrono::Utc::now().timestamp_millis(),
+            };
+            active.insert(task.task_id.clone(), task.clone());
+            task
+        };
+        let _ = self.events.send(task.clone());
+
+        let job = DeferredJob {
+            kind,
+            conversation_id: conversation_id.to_string(),
+            deferred_at: task.updated_at,
+            reason: String::new(),
+        };
+        handle.spawn(async move {
+            self.update(&task.task_id, BackgroundTaskStatus::Running);
+            let done = runner(job).await;
+            let status = if done {
+                BackgroundTaskStatus::Completed
+            } else {
+                BackgroundTaskStatus::Failed
+            };
+            self.update(&task.task_id, status);
+        });
+        true
+    }
+
+    /// 更新任务状态并广播；结束的任务移出队列
+    fn update(&self, task_id: &str, status: BackgroundTaskStatus) {
+        let event = {
+            let mut active = self.active.lock().unwrap();
+            let Some(task) = active.get_mut(task_id) else {
+                return;
+            };
+            task.status = status;
+            task.updated_at = chrono::Utc::now().timestamp_millis();
+            let event = task.clone();
+            if matches!(
+                status,
+                BackgroundTaskStatus::Completed | BackgroundTaskStatus::Failed
+            ) {
+                active.remove(task_id);
+            }
+            event
+        };
+        let _ = self.events.send(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_enqueued_task_reports_status_changes() {
+        let tasks: &'static BackgroundTasks = Box::leak(Box::new(BackgroundTasks::new()));
+        // 没有执行器：不入队，由调用方就地执行
+        assert!(!tasks.enqueue(BackgroundJobKind::FactExtraction, "c"));
+
+        let release = Arc::new(tokio::sync::Notify::new());
+        let gate = release.clone();
+        tasks.install_runner(Arc::new(move |job: DeferredJob| {
+            let gate = gate.clone();
+            Box::pin(async move {
+                gate.notified().await;
+                job.conversation_id == "c"
+            })
+        }));
+        let mut events = tasks.subscribe();
+        assert!(tasks.enqueue(BackgroundJobKind::FactExtraction, "c"));
+        let queued = events.recv().await.unwrap();
+        assert_eq!(queued.status, BackgroundTaskStatus::Queued);
+        assert_eq!(events.recv().await.unwrap().status, BackgroundTaskStatus::Running);
+        assert_eq!(tasks.active().len(), 1);
+
+        release.notify_one();
+        let finished = events.recv().await.unwrap();
+        assert_eq!(finished.task_id, queued.task_id);
+        assert_eq!(finished.status, BackgroundTaskStatus::Completed);
+        assert!(tasks.active().is_empty());
+    }
+}
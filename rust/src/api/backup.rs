@@ -0,0 +1,485 @@
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufReader, BufWriter, ErrorKind, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+use flutter_rust_bridge::frb;
+use serde::{Deserialize, Serialize};
+
+use super::at_rest;
+use super::data_layout::{DataLayoutMigrator, ManifestEntry};
+use super::data_models::{BackupRetention, BackupScope, BackupSnapshotInfo};
+use super::error_handler::ChatError;
+
+// ═══════════════════════════════════════════════════════════════════
+//  增量备份 (Incremental Backup)
+//  ─────────────────────────────────────────────────────────────────
+//  把整个数据目录（对话、记忆、知识库、设置，范围同 DataLayoutMigrator）
+//  备份进单个归档文件，批量操作出错后可回滚：
+//    · 内容寻址：文件按 SHA-256 存一次，后续快照只追加新内容
+//    · 快照：记录每个文件的相对路径、大小与哈希（即布局清单）
+//    · 保留策略：最近 N 份 + 每天最新一份，清理时重写归档去掉无用内容
+//    · 恢复：按范围（全部/对话/记忆/知识/设置）回滚到某份快照，
+//      范围内快照之后新增的文件会被删除
+//
+//  归档格式（只追加，结尾的半截记录在下次写入时截掉）：
+//    "T2BK\x01" 文件头
+//    记录 = 类型(1B) + 长度(u32 LE) + 内容
+//      1 内容块：64 字节十六进制哈希 + 原始字节
+//      2 快照：BackupSnapshot 的 JSON
+// ═══════════════════════════════════════════════════════════════════
+
+/// 默认归档位置（相对数据目录；不在布局目录内，不会被备份自身收录）
+pub const DEFAULT_ARCHIVE: &str = "backups/archive.t2b";
+
+const MAGIC: &[u8; 5] = b"T2BK\x01";
+const RECORD_BLOB: u8 = 1;
+const RECORD_SNAPSHOT: u8 = 2;
+const RECORD_HEADER_LEN: u64 = 5;
+const HASH_LEN: usize = 64;
+const DAY_MS: i64 = 24 * 60 * 60 * 1000;
+
+/// 对话相关的布局目录
+const CONVERSATION_DIRS: [&str; 10] = [
+    "conversations",
+    "group_chats",
+    "attachments",
+    "audio",
+    "reengagement",
+    "shadow_eval",
+    "archives",
+    "outbox",
+    "saydo_rules",
+    "check_ins",
+];
+/// 记忆相关的布局目录
+const MEMORY_DIRS: [&str; 5] = [
+    "memory_index",
+    "memory_vectors",
+    "memory_audit",
+    "decision_log",
+    "mood",
+];
+/// 与根目录设置文件一起归入设置范围的布局目录
+const CONFIG_DIRS: [&str; 1] = ["prompts"];
+/// 知识相关的布局目录
+const KNOWLEDGE_DIRS: [&str; 3] = ["knowledge_base", "lorebook", "blocked_topics"];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct BackupSnapshot {
+    id: String,
+    created_at: i64,
+    added_bytes: u64,
+    files: Vec<ManifestEntry>,
+}
+
+impl BackupSnapshot {
+    fn info(&self) -> BackupSnapshotInfo {
+        BackupSnapshotInfo {
+            id: self.id.clone(),
+            created_at: self.created_at,
+            file_count: self.files.len() as u32,
+            total_bytes: self.files.iter().map(|f| f.size).sum(),
+            added_bytes: self.added_bytes,
+        }
+    }
+}
+
+/// 扫描归档得到的索引
+#[derive(Default)]
+struct ArchiveIndex {
+    /// 哈希 → (内容偏移, 长度)
+    blobs: HashMap<String, (u64, u64)>,
+    /// 按创建顺序
+    snapshots: Vec<BackupSnapshot>,
+    /// 最后一条完整记录的结尾
+    valid_len: u64,
+}
+
+fn storage_err(context: &str, e: impl std::fmt::Display) -> ChatError {
+    ChatError::StorageError {
+        message: format!("{}: {}", context, e),
+    }
+}
+
+/// 相对路径是否属于恢复范围
+fn scope_covers(scope: BackupScope, path: &str) -> bool {
+    let dir = path.split_once('/').map(|(dir, _)| dir);
+    match scope {
+        BackupScope::All => true,
+        BackupScope::Conversations => dir.is_some_and(|d| CONVERSATION_DIRS.contains(&d)),
+        BackupScope::Memory => dir.is_some_and(|d| MEMORY_DIRS.contains(&d)),
+        BackupScope::Knowledge => dir.is_some_and(|d| KNOWLEDGE_DIRS.contains(&d)),
+        BackupScope::Config => dir.is_none_or(|d| CONFIG_DIRS.contains(&d)),
+    }
+}
+
+fn write_record(w: &mut impl Write, kind: u8, parts: &[&[u8]]) -> Result<(), ChatError> {
+    let len: usize = parts.iter().map(|p| p.len()).sum();
+    let len = u32::try_from(len).map_err(|_| ChatError::StorageError {
+        message: "Backup record too large".to_string(),
+    })?;
+    w.write_all(&[kind])
+        .and_then(|_| w.write_all(&len.to_le_bytes()))
+        .map_err(|e| storage_err("Failed to write backup", e))?;
+    for part in parts {
+        w.write_all(part)
+            .map_err(|e| storage_err("Failed to write backup", e))?;
+    }
+    Ok(())
+}
+
+fn read_at(file: &mut File, offset: u64, len: u64) -> Result<Vec<u8>, ChatError> {
+    let mut data = vec![0u8; len as usize];
+    file.seek(SeekFrom::Start(offset))
+        .and_then(|_| file.read_exact(&mut data))
+        .map_err(|e| storage_err("Failed to read backup", e))?;
+    Ok(data)
+}
+
+#[frb(opaque)]
+pub struct BackupManager {
+    base_path: String,
+    archive_path: PathBuf,
+}
+
+impl BackupManager {
+    /// archive_path 为 None 时使用数据目录下的 DEFAULT_ARCHIVE
+    pub fn new(base_path: &str, archive_path: Option<&str>) -> Self {
+        let archive_path = archive_path
+            .map(PathBuf::from)
+            .unwrap_or_else(|| Path::new(base_path).join(DEFAULT_ARCHIVE));
+        Self {
+            base_path: base_path.to_string(),
+            archive_path,
+        }
+    }
+
+    fn scan(&self) -> Result<ArchiveIndex, ChatError> {
+        let file = match File::open(&self.archive_path) {
+            Ok(f) => f,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(ArchiveIndex::default()),
+            Err(e) => return Err(storage_err("Failed to open backup", e)),
+        };
+        let total = file
+            .metadata()
+            .map_err(|e| storage_err("Failed to open backup", e))?
+            .len();
+        if total == 0 {
+            return Ok(ArchiveIndex::default());
+        }
+        let mut reader = BufReader::new(file);
+        let mut magic = [0u8; 5];
+        if reader.read_exact(&mut magic).is_err() || &magic != MAGIC {
+            return Err(ChatError::ValidationError {
+                message: format!("'{}' is not a backup archive", self.archive_path.display()),
+            });
+        }
+
+        let mut index = ArchiveIndex::default();
+        let mut pos = MAGIC.len() as u64;
+        while pos + RECORD_HEADER_LEN <= total {
+            let mut header = [0u8; 5];
+            if reader.read_exact(&mut header).is_err() {
+                break;
+            }
+            let len = u32::from_le_bytes([header[1], header[2], header[3], header[4]]) as u64;
+            if pos + RECORD_HEADER_LEN + len > total {
+                break;
+            }
+            match header[0] {
+                RECORD_BLOB if len >= HASH_LEN as u64 => {
+                    let mut hash = [0u8; HASH_LEN];
+                    if reader.read_exact(&mut hash).is_err() {
+                        break;
+                    }
+                    let data_len = len - HASH_LEN as u64;
+                    if reader.seek_relative(data_len as i64).is_err() {
+                        break;
+                    }
+                    let data_offset = pos + RECORD_HEADER_LEN + HASH_LEN as u64;
+                    index
+                        .blobs
+                        .insert(String::from_utf8_lossy(&hash).to_string(), (data_offset, data_len));
+                }
+                RECORD_SNAPSHOT => {
+                    let mut json = vec![0u8; len as usize];
+                    if reader.read_exact(&mut json).is_err() {
+                        break;
+                    }
+                    match serde_json::from_slice::<BackupSnapshot>(&json) {
+                        Ok(snapshot) => index.snapshots.push(snapshot),
+                        Err(_) => break,
+                    }
+                }
+                _ => break,
+            }
+            pos += RECORD_HEADER_LEN + len;
+        }
+        index.valid_len = pos;
+        Ok(index)
+    }
+
+    /// 全部快照，按时间从旧到新
+    pub fn list(&self) -> Result<Vec<BackupSnapshotInfo>, ChatError> {
+        Ok(self.scan()?.snapshots.iter().map(BackupSnapshot::info).collect())
+    }
+
+    /// 创建一份增量快照（只写入归档中还没有的内容），随后按保留策略清理
+    pub fn create(&self, retention: &BackupRetention) -> Result<BackupSnapshotInfo, ChatError> {
+        let files = DataLayoutMigrator::new(&self.base_path).collect_files()?;
+        let index = self.scan()?;
+
+        if let Some(parent) = self.archive_path.parent() {
+            fs::create_dir_all(parent).map_err(|e| storage_err("Failed to create backup directory", e))?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .read(true)
+            .write(true)
+            .open(&self.archive_path)
+            .map_err(|e| storage_err("Failed to open backup", e))?;
+        // 截掉上次中断留下的半截记录
+        file.set_len(index.valid_len)
+            .and_then(|_| file.seek(SeekFrom::End(0)))
+            .map_err(|e| storage_err("Failed to prepare backup", e))?;
+        let mut writer = BufWriter::new(file);
+        if index.valid_len == 0 {
+            writer
+                .write_all(MAGIC)
+                .map_err(|e| storage_err("Failed to write backup", e))?;
+        }
+
+        let mut written: HashSet<String> = HashSet::new();
+        let mut entries = Vec::with_capacity(files.len());
+        let mut added_bytes = 0u64;
+        for (rel, abs) in files {
+            let data = fs::read(&abs).map_err(|e| storage_err(&format!("Failed to read '{}'", rel), e))?;
+            let hash = DataLayoutMigrator::sha256_hex(&data);
+            if !index.blobs.contains_key(&hash) && written.insert(hash.clone()) {
+                write_record(&mut writer, RECORD_BLOB, &[hash.as_bytes(), &data])?;
+                added_bytes += data.len() as u64;
+            }
+            entries.push(ManifestEntry {
+                path: rel,
+                size: data.len() as u64,
+                sha256: hash,
+            });
+        }
+
+        let snapshot = BackupSnapshot {
+            id: uuid::Uuid::new_v4().to_string(),
+            created_at: chrono::Utc::now().timestamp_millis(),
+            added_bytes,
+            files: entries,
+        };
+        let json = serde_json::to_vec(&snapshot).map_err(|e| storage_err("Failed to serialize backup", e))?;
+        write_record(&mut writer, RECORD_SNAPSHOT, &[&json])?;
+        let file = writer
+            .into_inner()
+            .map_err(|e| storage_err("Failed to write backup", e))?;
+        file.sync_all()
+            .map_err(|e| storage_err("Failed to write backup", e))?;
+        drop(file);
+
+        self.prune(retention)?;
+        Ok(snapshot.info())
+    }
+
+    /// 保留的快照ID：最近 keep_last 份 + 最近 keep_daily 天中每天最新的一份
+    fn retained_ids(snapshots: &[BackupSnapshot], retention: &BackupRetention) -> HashSet<String> {
+        // 归档只追加，记录顺序即创建顺序
+        let newest_first: Vec<&BackupSnapshot> = snapshots.iter().rev().collect();
+
+        let mut keep: HashSet<String> = newest_first
+            .iter()
+            .take(retention.keep_last as usize)
+            .map(|s| s.id.clone())
+            .collect();
+        let mut days: Vec<i64> = Vec::new();
+        for s in &newest_first {
+            let day = s.created_at.div_euclid(DAY_MS);
+            if days.contains(&day) {
+                continue;
+            }
+            if days.len() >= retention.keep_daily as usize {
+                break;
+            }
+            days.push(day);
+            keep.insert(s.id.clone());
+        }
+        keep
+    }
+
+    /// 按保留策略删除旧快照并重写归档（去掉不再被引用的内容），返回删除的快照数
+    pub fn prune(&self, retention: &BackupRetention) -> Result<u32, ChatError> {
+        let index = self.scan()?;
+        let keep = Self::retained_ids(&index.snapshots, retention);
+        let removed = index.snapshots.len() - keep.len();
+        if removed == 0 {
+            return Ok(0);
+        }
+
+        let tmp_path = self.archive_path.with_extension("t2b.tmp");
+        let mut source = File::open(&self.archive_path).map_err(|e| storage_err("Failed to open backup", e))?;
+        let tmp = File::create(&tmp_path).map_err(|e| storage_err("Failed to create backup", e))?;
+        let mut writer = BufWriter::new(tmp);
+        writer
+            .write_all(MAGIC)
+            .map_err(|e| storage_err("Failed to write backup", e))?;
+
+        let kept: Vec<&BackupSnapshot> = index.snapshots.iter().filter(|s| keep.contains(&s.id)).collect();
+        let mut copied: HashSet<&str> = HashSet::new();
+        for entry in kept.iter().flat_map(|s| s.files.iter()) {
+            if !copied.insert(entry.sha256.as_str()) {
+                continue;
+            }
+            if let Some(&(offset, len)) = index.blobs.get(&entry.sha256) {
+                let data = read_at(&mut source, offset, len)?;
+                write_record(&mut writer, RECORD_BLOB, &[entry.sha256.as_bytes(), &data])?;
+            }
+        }
+        for snapshot in kept {
+            let json = serde_json::to_vec(snapshot).map_err(|e| storage_err("Failed to serialize backup", e))?;
+            write_record(&mut writer, RECORD_SNAPSHOT, &[&json])?;
+        }
+        let tmp = writer
+            .into_inner()
+            .map_err(|e| storage_err("Failed to write backup", e))?;
+        tmp.sync_all()
+            .map_err(|e| storage_err("Failed to write backup", e))?;
+        drop(tmp);
+        fs::rename(&tmp_path, &self.archive_path).map_err(|e| storage_err("Failed to replace backup", e))?;
+        Ok(removed as u32)
+    }
+
+    /// 把范围内的数据回滚到指定快照（None 为最新一份），返回写回的文件数
+    pub fn restore(&self, snapshot_id: Option<&str>, scope: BackupScope) -> Result<u32, ChatError> {
+        let index = self.scan()?;
+        let snapshot = match snapshot_id {
+            Some(id) => index.snapshots.iter().find(|s| s.id == id),
+            None => index.snapshots.last(),
+        }
+        .ok_or_else(|| ChatError::ValidationError {
+            message: "Backup snapshot not found".to_string(),
+        })?;
+
+        let entries: Vec<&ManifestEntry> = snapshot.files.iter().filter(|e| scope_covers(scope, &e.path)).collect();
+        // 先确认内容齐全，避免恢复到一半才发现归档损坏
+        if let Some(missing) = entries.iter().find(|e| !index.blobs.contains_key(&e.sha256)) {
+            return Err(ChatError::StorageError {
+                message: format!("Backup is missing content for '{}'", missing.path),
+            });
+        }
+
+        let mut source = File::open(&self.archive_path).map_err(|e| storage_err("Failed to open backup", e))?;
+        let mut restored = 0u32;
+        for entry in &entries {
+            let (offset, len) = index.blobs[&entry.sha256];
+            let data = read_at(&mut source, offset, len)?;
+            if DataLayoutMigrator::sha256_hex(&data) != entry.sha256 {
+                return Err(ChatError::StorageError {
+                    message: format!("Backup content corrupted for '{}'", entry.path),
+                });
+            }
+            let dst = entry
+                .path
+                .split('/')
+                .fold(PathBuf::from(&self.base_path), |acc, part| acc.join(part));
+            if let Some(parent) = dst.parent() {
+                fs::create_dir_all(parent).map_err(|e| storage_err("Failed to create directory", e))?;
+            }
+            // 快照里是存储格式的原始内容，原样写回并丢弃恢复前的备份
+            at_rest::install(&dst, &data)
+                .map_err(|e| storage_err(&format!("Failed to restore '{}'", entry.path), e))?;
+            restored += 1;
+        }
+
+        // 范围内、快照之后才出现的文件一并删除
+        let in_snapshot: HashSet<&str> = entries.iter().map(|e| e.path.as_str()).collect();
+        for (rel, abs) in DataLayoutMigrator::new(&self.base_path).collect_files()? {
+            if scope_covers(scope, &rel) && !in_snapshot.contains(rel.as_str()) {
+                at_rest::remove(&abs).map_err(|e| storage_err(&format!("Failed to remove '{}'", rel), e))?;
+            }
+        }
+        Ok(restored)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write(base: &Path, rel: &str, content: &str) {
+        let path = base.join(rel);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn test_incremental_backup_and_scoped_restore() {
+        let tmp = TempDir::new().unwrap();
+        let base = tmp.path();
+        write(base, "settings.json", "{}");
+        write(base, "conversations/c1.msgpack", "first");
+        write(base, "knowledge_base/c1.json", "facts");
+        let manager = BackupManager::new(base.to_str().unwrap(), None);
+        let retention = BackupRetention::default();
+
+        let first = manager.create(&retention).unwrap();
+        assert_eq!(first.file_count, 3);
+        assert_eq!(first.added_bytes, first.total_bytes);
+
+        // 只有改动的文件写入新内容
+        write(base, "conversations/c1.msgpack", "second");
+        write(base, "conversations/c2.msgpack", "new");
+        write(base, "knowledge_base/c1.json", "changed facts");
+        let second = manager.create(&retention).unwrap();
+        assert_eq!(second.added_bytes, ("second".len() + "new".len() + "changed facts".len()) as u64);
+        assert_eq!(manager.list().unwrap().len(), 2);
+
+        let restored = manager.restore(Some(&first.id), BackupScope::Conversations).unwrap();
+        assert_eq!(restored, 1);
+        assert_eq!(fs::read_to_string(base.join("conversations/c1.msgpack")).unwrap(), "first");
+        assert!(!base.join("conversations/c2.msgpack").exists());
+        // 范围外的文件不受影响
+        assert_eq!(fs::read_to_string(base.join("knowledge_base/c1.json")).unwrap(), "changed facts");
+    }
+
+    #[test]
+    fn test_prune_keeps_recent_snapshots_and_their_content() {
+        let tmp = TempDir::new().unwrap();
+        let base = tmp.path();
+        let manager = BackupManager::new(base.to_str().unwrap(), None);
+        let keep_all = BackupRetention {
+            keep_last: 10,
+            keep_daily: 0,
+        };
+        for i in 0..3 {
+            write(base, "conversations/c1.msgpack", &format!("v{}", i));
+            manager.create(&keep_all).unwrap();
+        }
+        let size_before = fs::metadata(base.join(DEFAULT_ARCHIVE)).unwrap().len();
+
+        let removed = manager
+            .prune(&BackupRetention {
+                keep_last: 1,
+                keep_daily: 0,
+            })
+            .unwrap();
+        assert_eq!(removed, 2);
+        assert!(fs::metadata(base.join(DEFAULT_ARCHIVE)).unwrap().len() < size_before);
+
+        write(base, "conversations/c1.msgpack", "broken");
+        manager.restore(None, BackupScope::All).unwrap();
+        assert_eq!(fs::read_to_string(base.join("conversations/c1.msgpack")).unwrap(), "v2");
+
+        // 中断写入留下的半截记录不影响读取
+        let mut file = OpenOptions::new().append(true).open(base.join(DEFAULT_ARCHIVE)).unwrap();
+        file.write_all(&[RECORD_BLOB, 0xff, 0xff]).unwrap();
+        assert_eq!(manager.list().unwrap().len(), 1);
+        assert_eq!(manager.create(&keep_all).unwrap().added_bytes, 0);
+    }
+}
@@ -0,0 +1,117 @@
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use super::data_models::BlockingStats;
+
+// ═══════════════════════════════════════════════════════════════════
+//  CPU 密集任务隔离 (Blocking Pool)
+//  ─────────────────────────────────────────────────────────────────
+//  BM25 / TF-IDF 检索、回复指纹分析等纯 CPU 计算如果直接跑在异步运行时
+//  线程上，会卡住同一线程上的 SSE 流读取，表现为打字机输出一顿一顿。
+//  存储层的同步文件读写同理：慢速闪存上一次 fsync 就能卡住运行时，
+//  ConversationStore / MemoryEngine / KnowledgeStore 的 *_async 接口也经由这里。
+//  这里统一通过 tokio 的 blocking 线程池执行，并记录：
+//    - 排队延迟：提交到真正开始执行的等待时间（线程池是否饱和）
+//    - 执行耗时：任务本身的 CPU 时间（超过阈值打印告警）
+// ═══════════════════════════════════════════════════════════════════
+
+/// 单个任务执行超过该耗时即打印告警
+const SLOW_TASK_WARN_MS: u64 = 200;
+
+static STATS: OnceLock<Mutex<BlockingStats>> = OnceLock::new();
+
+fn stats() -> &'static Mutex<BlockingStats> {
+    STATS.get_or_init(|| Mutex::new(BlockingStats::default()))
+}
+
+fn record(label: &str, queued: Duration, run: Duration) {
+    let run_ms = run.as_millis() as u64;
+    let queue_ms = queued.as_millis() as u64;
+    if let Ok(mut s) = stats().lock() {
+        s.tasks += 1;
+        s.total_run_ms += run_ms;
+        s.max_queue_ms = s.max_queue_ms.max(queue_ms);
+        if run_ms >= s.max_run_ms {
+            s.max_run_ms = run_ms;
+            s.slowest_label = label.to_string();
+        }
+    }
+    if run_ms > SLOW_TASK_WARN_MS {
+        tracing::warn!(label, run_ms, queue_ms, "blocking pool slow task");
+    }
+}
+
+/// 当前统计快照
+pub fn snapshot() -> BlockingStats {
+    stats().lock().map(|s| s.clone()).unwrap_or_default()
+}
+
+/// 在 blocking 线程池上执行 CPU 密集任务，异步等待结果
+/// 任务内 panic 会在调用方重新抛出，行为与直接调用一致
+pub async fn offload<T, F>(label: &'static str, f: F) -> T
+where
+    F: FnOnce() -> T + Send + 'static,
+    T: Send + 'static,
+{
+    let submitted = Instant::now();
+    let handle = tokio::task::spawn_blocking(move || {
+        let started = Instant::now();
+        let result = f();
+        record(label, started - submitted, started.elapsed());
+        result
+    });
+    match handle.await {
+        Ok(result) => result,
+        Err(e) if e.is_panic() => std::panic::resume_unwind(e.into_panic()),
+        Err(e) => panic!("blocking task '{}' was cancelled: {}", label, e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn busy_wait(ms: u64) -> u64 {
+        let start = Instant::now();
+        let mut n = 0u64;
+        while start.elapsed() < Duration::from_millis(ms) {
+            n = n.wrapping_add(1);
+        }
+        n
+    }
+
+    /// 单线程运行时上模拟 SSE 读取：CPU 任务卸载后，定时器任务的最大间隔应保持很小
+    #[tokio::test]
+    async fn test_offload_keeps_reactor_responsive() {
+        let ticker = tokio::spawn(async {
+            let mut max_gap = Duration::ZERO;
+            let mut last = Instant::now();
+            for _ in 0..30 {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                max_gap = max_gap.max(last.elapsed());
+                last = Instant::now();
+            }
+            max_gap
+        });
+
+        let n = offload("test_busy", || busy_wait(300)).await;
+        assert!(n > 0);
+
+        let max_gap = ticker.await.unwrap();
+        assert!(
+            max_gap < Duration::from_millis(150),
+            "reactor stalled for {:?}",
+            max_gap
+        );
+
+        let s = snapshot();
+        assert!(s.tasks >= 1);
+        assert!(s.max_run_ms >= 300);
+    }
+
+    #[tokio::test]
+    async fn test_offload_returns_value() {
+        let v = offload("test_sum", || (1..=100).sum::<u32>()).await;
+        assert_eq!(v, 5050);
+    }
+}
@@ -0,0 +1,236 @@
+use std::collections::HashMap;
+
+use super::data_models::{CharacterRelation, RelationChange, RelationSentiment};
+use super::knowledge_store::{Fact, KnowledgeStore};
+
+// ═══════════════════════════════════════════════════════════════════
+//  角色关系网 (Character Relations)
+//  ─────────────────────────────────────────────────────────────────
+//  RelationshipDynamics 只刻画用户与 AI 之间的关系。多角色故事里，
+//  NPC 彼此之间的关系（艾琳→暗恋→凯、凯→提防→维克多）散落在事实库中，
+//  「暗恋」与后来的「讨厌」分类不同、关系词不同，不构成冲突而同时有效，
+//  模型看到的是自相矛盾的人际网。这里把这些事实整理成有向关系表：
+//    - 一对角色（有方向）只保留最新的一种关系，较早的关系进入 history
+//    - 双方都是事实登记的实体、且都不是用户本人的三元组才算角色关系
+//    - 关系词按情感倾向粗分为好感 / 敌意 / 中性
+//  检索时回答「A 和 B 对彼此怎么想」（两个方向各一条），生成时把
+//  当前消息提到的角色的关系注入知识上下文。
+//
+//  存储结构：无。按需从事实库与归档事实构建，只存在于内存中
+// ═══════════════════════════════════════════════════════════════════
+
+/// 注入上下文的关系条数上限
+const MAX_RELATIONS_IN_CONTEXT: usize = 8;
+
+/// 指代用户本人的主体 / 客体：用户与 AI 的关系由 RelationshipDynamics 负责
+const USER_ALIASES: [&str; 5] = ["用户", "我", "你", "user", "ai角色"];
+
+const POSITIVE_RELATIONS: [&str; 16] = [
+    "爱", "喜欢", "暗恋", "信任", "崇拜", "仰慕", "感激", "关心", "保护", "思念", "依赖", "欣赏",
+    "朋友", "恋人", "love", "trust",
+];
+
+const NEGATIVE_RELATIONS: [&str; 16] = [
+    "恨", "讨厌", "厌恶", "嫉妒", "提防", "怀疑", "敌", "背叛", "害怕", "怨", "鄙视", "仇", "威胁",
+    "欺骗", "hate", "distrust",
+];
+
+/// 关系词的情感倾向（负面词优先：「不再信任」含「信任」但是敌意）
+pub fn sentiment_of(relation: &str) -> RelationSentiment {
+    let relation = relation.to_lowercase();
+    if relation.starts_with('不') || NEGATIVE_RELATIONS.iter().any(|w| relation.contains(w)) {
+        RelationSentiment::Negative
+    } else if POSITIVE_RELATIONS.iter().any(|w| relation.contains(w)) {
+        RelationSentiment::Positive
+    } else {
+        RelationSentiment::Neutral
+    }
+}
+
+fn sentiment_label(sentiment: RelationSentiment) -> &'static str {
+    match sentiment {
+        RelationSentiment::Positive => "好感",
+        RelationSentiment::Negative => "敌意",
+        RelationSentiment::Neutral => "中性",
+    }
+}
+
+pub struct CharacterWeb {
+    /// 按关系最近变化的先后排列（新的在后）
+    relations: Vec<CharacterRelation>,
+}
+
+impl CharacterWeb {
+    /// active 为当前事实，archived 为被取代的归档事实（也参与关系历史）
+    pub fn from_facts(active: &[Fact], archived: &[Fact]) -> Self {
+        let mut candidates: Vec<(&Fact, [String; 3])> = active
+            .iter()
+            .chain(archived)
+            .filter_map(|fact| Self::character_triple(fact).map(|triple| (fact, triple)))
+            .collect();
+        candidates.sort_by_key(|(fact, _)| (fact.source_turn, fact.created_at));
+
+        let mut relations: Vec<CharacterRelation> = Vec::new();
+        let mut pair_index: HashMap<(String, String), usize> = HashMap::new();
+        for (fact, [from, relation, to]) in candidates {
+            let key = (
+                KnowledgeStore::normalize_term(&from),
+                KnowledgeStore::normalize_term(&to),
+            );
+            let Some(&idx) = pair_index.get(&key) else {
+                pair_index.insert(key, relations.len());
+                relations.push(CharacterRelation {
+                    from,
+                    to,
+                    sentiment: sentiment_of(&relation),
+                    relation,
+                    fact_id: fact.id.clone(),
+                    source_turn: fact.source_turn,
+                    history: Vec::new(),
+                });
+                continue;
+            };
+            let current = &mut relations[idx];
+            if KnowledgeStore::normalize_term(&current.relation)
+                != KnowledgeStore::normalize_term(&relation)
+            {
+                current.history.push(RelationChange {
+                    relation: std::mem::take(&mut current.relation),
+                    fact_id: current.fact_id.clone(),
+                    source_turn: current.source_turn,
+                });
+                current.sentiment = sentiment_of(&relation);
+                current.relation = relation;
+            }
+            // 同一关系被再次确认时只更新出处
+            current.fact_id = fact.id.clone();
+            current.source_turn = fact.source_turn;
+            current.from = from;
+            current.to = to;
+        }
+        relations.sort_by_key(|r| r.source_turn);
+        Self { relations }
+    }
+
+    /// 两端都是角色的三元组（主体、关系、客体，保留原文）
+    fn character_triple(fact: &Fact) -> Option<[String; 3]> {
+        let [subject, relation, object] = KnowledgeStore::triple_parts(&fact.content)?;
+        let subject_key = KnowledgeStore::normalize_term(&subject);
+        let object_key = KnowledgeStore::normalize_term(&object);
+        if subject_key == object_key
+            || USER_ALIASES.contains(&subject_key.as_str())
+            || USER_ALIASES.contains(&object_key.as_str())
+        {
+            return None;
+        }
+        let entities: Vec<String> = fact
+            .entities
+            .iter()
+            .map(|e| KnowledgeStore::normalize_term(e))
+            .collect();
+        (entities.contains(&subject_key) && entities.contains(&object_key))
+            .then_some([subject, relation, object])
+    }
+
+    pub fn relations(&self) -> &[CharacterRelation] {
+        &self.relations
+    }
+
+    /// 两个角色对彼此的关系（a→b 在前，b→a 在后；没有记录的方向缺省）
+    pub fn between(&self, a: &str, b: &str) -> Vec<CharacterRelation> {
+        let a = KnowledgeStore::normalize_term(a);
+        let b = KnowledgeStore::normalize_term(b);
+        let find = |from: &str, to: &str| {
+            self.relations.iter().find(|r| {
+                KnowledgeStore::normalize_term(&r.from) == from
+                    && KnowledgeStore::normalize_term(&r.to) == to
+            })
+        };
+        find(&a, &b)
+            .into_iter()
+            .chain(find(&b, &a))
+            .cloned()
+            .collect()
+    }
+
+    /// 文本提到的角色所涉及的关系（最近变化的优先），格式化为上下文注入段落；
+    /// 没有相关关系时为空串
+    pub fn build_context(&self, text: &str) -> String {
+        let text = KnowledgeStore::normalize_term(text);
+        let mentioned = |name: &str| text.contains(&KnowledgeStore::normalize_term(name));
+        let selected: Vec<&CharacterRelation> = self
+            .relations
+            .iter()
+            .rev()
+            .filter(|r| mentioned(&r.from) || mentioned(&r.to))
+            .take(MAX_RELATIONS_IN_CONTEXT)
+            .collect();
+        if selected.is_empty() {
+            return String::new();
+        }
+
+        let mut context = String::from("【角色关系 — 角色之间的当前关系，言行须与之一致】\n");
+        for relation in selected {
+            context.push_str(&format!(
+                "  · {} → {} → {}（{}",
+                relation.from,
+                relation.relation,
+                relation.to,
+                sentiment_label(relation.sentiment)
+            ));
+            if let Some(previous) = relation.history.last() {
+                context.push_str(&format!("；此前：{}", previous.relation));
+            }
+            context.push_str("）\n");
+        }
+        context
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::knowledge_store::FactCategory;
+
+    fn fact(id: &str, content: &str, entities: &[&str], turn: u32) -> Fact {
+        let mut fact = KnowledgeStore::user_fact(content, turn);
+        fact.id = id.to_string();
+        fact.category = FactCategory::Relationship;
+        fact.entities = entities.iter().map(|e| e.to_string()).collect();
+        fact
+    }
+
+    #[test]
+    fn test_latest_relation_per_direction_with_history() {
+        let active = vec![
+            fact("f1", "艾琳→暗恋→凯", &["艾琳", "凯"], 3),
+            fact("f2", "凯→信任→艾琳", &["凯", "艾琳"], 4),
+            fact("f3", "艾琳→讨厌→凯", &["艾琳", "凯"], 9),
+            // 用户与角色之间、客体不是实体的三元组不进入关系网
+            fact("f4", "用户→喜欢→艾琳", &["用户", "艾琳"], 5),
+            fact("f5", "凯→住在→王都", &["凯"], 6),
+        ];
+        let archived = vec![fact("f0", "艾琳 → 朋友 → 凯", &["艾琳", "凯"], 1)];
+        let web = CharacterWeb::from_facts(&active, &archived);
+        assert_eq!(web.relations().len(), 2);
+
+        let pair = web.between("凯", "艾琳");
+        assert_eq!(pair.len(), 2);
+        assert_eq!(pair[0].relation, "信任");
+        assert_eq!(pair[0].sentiment, RelationSentiment::Positive);
+        assert_eq!(pair[1].relation, "讨厌");
+        assert_eq!(pair[1].sentiment, RelationSentiment::Negative);
+        assert_eq!(pair[1].fact_id, "f3");
+        let history: Vec<&str> = pair[1]
+            .history
+            .iter()
+            .map(|h| h.relation.as_str())
+            .collect();
+        assert_eq!(history, vec!["朋友", "暗恋"]);
+
+        let context = web.build_context("凯今天怎么没来？");
+        assert!(context.contains("艾琳 → 讨厌 → 凯（敌意；此前：暗恋）"));
+        assert!(context.contains("凯 → 信任 → 艾琳（好感）"));
+        assert!(web.build_context("今天天气不错").is_empty());
+    }
+}
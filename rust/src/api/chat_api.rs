@@ -1,21 +1,97 @@
-use std::sync::OnceLock;
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock};
 
+use super::background_tasks::{self, BackgroundTasks};
+use super::backup::BackupManager;
+use super::blocking_pool;
 use super::chat_engine::ChatEngine;
-use super::config_manager::ConfigManager;
-use super::conversation_store::ConversationStore;
+use super::chat_provider;
+use super::check_ins::CheckInPlanner;
+use super::cognitive_engine::{self, CognitiveEngine};
+use super::config_manager::{self, ConfigManager, ModelRegistry};
+use super::context_cache::ContextCache;
+use super::conversation_store::{ConversationStore, TurnGuard};
+use super::daily_digest::DailyDigestGenerator;
+use super::data_export::DataExporter;
+use super::data_layout::DataLayoutMigrator;
 use super::data_models::*;
+use super::decision_log::DecisionLog;
+use super::embedding::{EmbeddingBackend, EmbeddingStore};
+use super::fidelity_audit::FidelityAuditor;
+use super::integrity_checker::IntegrityChecker;
+use super::job_scheduler::JobScheduler;
 use super::jwt_auth::JwtAuth;
-use super::knowledge_store::KnowledgeStore;
-use super::memory_engine::MemoryEngine;
+use super::knowledge_graph;
+use super::knowledge_store::{Fact, FactCategory, KnowledgeStore};
+use super::memory_engine::{self, MemoryEngine};
+use super::log_store;
+use super::metrics;
+use super::mood::MoodStore;
+use super::network_adaptation;
+use super::personas::PersonaStore;
+use super::prompt_templates::{self, PromptTemplateStore};
+use super::reengagement::ReengagementGenerator;
+use super::reindexer::Reindexer;
+use super::safety_filter;
+use super::saydo_detector::{SayDoDetector, SayDoRuleStore};
+use super::search_index::SearchIndex;
+use super::topic_blocks::BlockedTopicStore;
+use super::tokenizer;
+use super::timeline::StoryTimeline;
+use super::tts::{AudioStore, TtsClient};
+use super::closure::ArchiveStore;
+use super::cloud_sync::{self, SyncEngine};
+use super::lorebook::{LorebookStore, GLOBAL_LOREBOOK_ID};
+use super::shadow_eval::ShadowEvalStore;
+use super::at_rest;
+use super::attachments::AttachmentStore;
+use super::group_chat::GroupChatStore;
+use super::housekeeping::Housekeeper;
+use super::illustration::CogViewClient;
+use super::store_cache;
 
 static CONFIG_MANAGER: OnceLock<ConfigManager> = OnceLock::new();
 static CONVERSATION_STORE: OnceLock<ConversationStore> = OnceLock::new();
 static DATA_PATH: OnceLock<String> = OnceLock::new();
 
 pub fn init_app(data_path: String) {
+    log_store::init();
     DATA_PATH.get_or_init(|| data_path.clone());
     CONFIG_MANAGER.get_or_init(|| ConfigManager::new(&data_path));
     CONVERSATION_STORE.get_or_init(|| ConversationStore::new(&data_path));
+    install_persisted_config(&data_path);
+    BackgroundTasks::global().install_runner(Arc::new(run_background_task));
+}
+
+/// 载入数据目录中的设置类文件（启动时，以及整库恢复之后）
+fn install_persisted_config(data_path: &str) {
+    tokenizer::load_from_dir(data_path);
+    ModelRegistry::install(get_config_manager().load_model_registry());
+    cognitive_engine::install_custom_lexicon(get_config_manager().load_emotion_lexicon());
+    memory_engine::install_stop_words(get_config_manager().load_stop_words());
+    prompt_templates::install(PromptTemplateStore::new(data_path).load_all());
+    safety_filter::install_policy(get_config_manager().load_safety_policy());
+    let settings = get_config_manager().load_settings();
+    config_manager::install_proxy(settings.proxy);
+    config_manager::install_retry_policy(settings.retry);
+}
+
+/// 后台任务队列的执行器：每个任务按当时的设置新建引擎
+fn run_background_task(job: DeferredJob) -> background_tasks::TaskFuture {
+    Box::pin(async move {
+        let settings = get_config_manager().load_settings();
+        let engine = match build_online_engine(&settings) {
+            Ok(e) => e.with_settings(settings),
+            Err(_) => return false,
+        };
+        // 回复落盘后才入队：等发送释放生成锁，也不与用户的编辑交错
+        let _turn = ConversationStore::lock_turn(&job.conversation_id).await;
+        let done = engine.run_deferred_job(&job).await;
+        if done && job.kind == BackgroundJobKind::Summarization {
+            after_memory_summarized(&job.conversation_id);
+        }
+        done
+    })
 }
 
 fn get_data_path() -> &'static str {
@@ -57,103 +133,698 @@ pub fn create_conversation() -> Conversation {
     conv
 }
 
+/// 创建沙盒试聊对话：仅存在于内存，不提取事实、不生成摘要
+pub fn create_sandbox_conversation(card: CharacterCard) -> Option<Conversation> {
+    get_conversation_store()
+        .create_sandbox_conversation(&card)
+        .ok()
+}
+
+/// 关闭沙盒对话并丢弃其全部内容
+pub fn close_sandbox_conversation(id: String) -> bool {
+    if !ConversationStore::is_sandbox(&id) {
+        return false;
+    }
+    get_conversation_store().delete_conversation(&id).is_ok()
+}
+
 pub fn get_conversation_list() -> Vec<ConversationSummary> {
     get_conversation_store().list_conversations()
 }
 
+/// 按文件夹 / 标签 / 归档状态 / 标题筛选对话，置顶的排在最前
+pub fn get_filtered_conversation_list(filter: ConversationFilter) -> Vec<ConversationSummary> {
+    get_conversation_store().list_conversations_filtered(&filter)
+}
+
+/// 全部对话用到的文件夹名
+pub fn list_conversation_folders() -> Vec<String> {
+    get_conversation_store().list_folders()
+}
+
+/// 全部对话用到的标签
+pub fn list_conversation_tags() -> Vec<String> {
+    get_conversation_store().list_tags()
+}
+
+/// 设置对话的标签、文件夹、置顶与归档，返回整理后的结果（去掉空白与重复标签）
+pub fn set_conversation_organization(
+    conversation_id: String,
+    organization: ConversationOrganization,
+) -> Option<ConversationOrganization> {
+    let Ok(_turn) = ConversationStore::try_lock_turn(&conversation_id) else {
+        return None;
+    };
+    get_conversation_store()
+        .set_organization(&conversation_id, organization)
+        .ok()
+}
+
+/// 置顶 / 取消置顶对话
+pub fn set_conversation_pinned(conversation_id: String, pinned: bool) -> bool {
+    update_organization(&conversation_id, |org| org.pinned = pinned)
+}
+
+/// 归档 / 取消归档对话
+pub fn set_conversation_archived(conversation_id: String, archived: bool) -> bool {
+    update_organization(&conversation_id, |org| org.archived = archived)
+}
+
+fn update_organization(
+    conversation_id: &str,
+    update: impl FnOnce(&mut ConversationOrganization),
+) -> bool {
+    let Ok(_turn) = ConversationStore::try_lock_turn(conversation_id) else {
+        return false;
+    };
+    let store = get_conversation_store();
+    let mut organization = match store.load_conversation(conversation_id) {
+        Ok(conv) => conv.organization,
+        Err(_) => return false,
+    };
+    update(&mut organization);
+    store.set_organization(conversation_id, organization).is_ok()
+}
+
 pub fn get_conversation(id: String) -> Option<Conversation> {
     get_conversation_store().load_conversation(&id).ok()
 }
 
 pub fn delete_conversation(id: String) -> bool {
+    let Ok(_turn) = ConversationStore::try_lock_turn(&id) else {
+        return false;
+    };
     let memory = MemoryEngine::new(get_data_path());
-    let _ = memory.delete_memory_index(&id);
     let knowledge = KnowledgeStore::new(get_data_path());
-    let _ = knowledge.delete_knowledge(&id);
+    let groups = GroupChatStore::new(get_data_path());
+    // 群聊角色各自的记忆/知识命名空间随对话一起删除
+    let mut scopes = vec![id.clone()];
+    if let Some(group) = groups.load(&id) {
+        scopes.extend(GroupChatStore::scopes(&group));
+    }
+    for scope in &scopes {
+        let _ = memory.delete_memory_index(scope);
+        let _ = knowledge.delete_knowledge(scope);
+        let _ = FidelityAuditor::new(get_data_path()).delete(scope);
+        let _ = EmbeddingStore::new(get_data_path()).delete(scope);
+        ContextCache::global().invalidate(scope);
+    }
+    let _ = groups.delete(&id);
+    let _ = knowledge.layers().remove_binding(&id);
+    MemoryEngine::discard_summary_draft(&id);
+    let _ = get_config_manager().set_summarization_config(&id, None);
+    let _ = DecisionLog::new(get_data_path()).delete(&id);
+    let _ = BlockedTopicStore::new(get_data_path()).delete(&id);
+    let _ = MoodStore::new(get_data_path()).delete(&id);
+    let _ = SayDoRuleStore::new(get_data_path()).delete(&id);
+    let _ = LorebookStore::new(get_data_path()).delete(&id);
+    let _ = ShadowEvalStore::new(get_data_path()).delete(&id);
+    let _ = AttachmentStore::new(get_data_path()).delete(&id);
+    let _ = Housekeeper::new(get_data_path()).delete(&id);
+    let _ = ReengagementGenerator::new(None, get_data_path()).delete(&id);
+    let _ = AudioStore::new(get_data_path()).delete(&id);
+    let _ = ArchiveStore::new(get_data_path()).delete(&id);
     get_conversation_store().delete_conversation(&id).is_ok()
 }
 
+/// 单条回复的生成信息（消息详情页）：结束原因、用量与降级；无记录时返回 None
+pub fn get_generation_metadata(
+    conversation_id: String,
+    message_id: String,
+) -> Option<GenerationMetadata> {
+    get_conversation_store()
+        .load_conversation(&conversation_id)
+        .ok()?
+        .messages
+        .into_iter()
+        .find(|m| m.id == message_id)?
+        .generation_metadata
+}
+
 pub fn delete_message(conversation_id: String, message_id: String) -> bool {
+    let Ok(_turn) = ConversationStore::try_lock_turn(&conversation_id) else {
+        return false;
+    };
     get_conversation_store()
         .delete_message(&conversation_id, &message_id)
         .is_ok()
 }
 
+/// 编辑消息；旧版本保留在 edit_history 中，覆盖该轮的记忆摘要随之作废
 pub fn edit_message(conversation_id: String, message_id: String, new_content: String) -> bool {
-    get_conversation_store()
-        .edit_message(&conversation_id, &message_id, &new_content)
-        .is_ok()
+    let Ok(_turn) = ConversationStore::try_lock_turn(&conversation_id) else {
+        return false;
+    };
+    let store = get_conversation_store();
+    let before = store.load_conversation(&conversation_id);
+    match store.edit_message(&conversation_id, &message_id, &new_content) {
+        Ok(conv) => {
+            sync_edited_memory(before.ok().as_ref(), &conv);
+            true
+        }
+        Err(_) => false,
+    }
+}
+
+/// 撤销消息最近一次编辑，返回恢复后的消息；没有可撤销的编辑时为 None
+pub fn undo_message_edit(conversation_id: String, message_id: String) -> Option<Message> {
+    let Ok(_turn) = ConversationStore::try_lock_turn(&conversation_id) else {
+        return None;
+    };
+    let store = get_conversation_store();
+    let before = store.load_conversation(&conversation_id);
+    let conv = store.undo_edit(&conversation_id, &message_id).ok()?;
+    sync_edited_memory(before.ok().as_ref(), &conv);
+    conv.messages.into_iter().find(|m| m.id == message_id)
+}
+
+/// 编辑让部分记忆摘要过时时，让记忆索引跟上，并排队按编辑后的原文重新总结
+/// （后台任务队列不可用时记入待补跑列表，由 run_deferred_jobs 补跑）
+fn sync_edited_memory(before: Option<&Conversation>, conv: &Conversation) {
+    let Some(before) = before else {
+        return;
+    };
+    let newly_stale = conv.memory_summaries.iter().any(|s| {
+        s.stale
+            && before
+                .memory_summaries
+                .iter()
+                .any(|old| old.id == s.id && !old.stale)
+    });
+    if !newly_stale {
+        return;
+    }
+    sync_timeline_memory(conv);
+    if !ConversationStore::is_sandbox(&conv.id)
+        && !BackgroundTasks::global().enqueue(BackgroundJobKind::Summarization, &conv.id)
+    {
+        JobScheduler::global().queue(
+            BackgroundJobKind::Summarization,
+            &conv.id,
+            "消息编辑后重新总结",
+        );
+    }
 }
 
 pub fn rollback_to_message(conversation_id: String, message_id: String) -> Vec<String> {
+    let Ok(_turn) = ConversationStore::try_lock_turn(&conversation_id) else {
+        return Vec::new();
+    };
     get_conversation_store()
         .rollback_to_message(&conversation_id, &message_id)
         .unwrap_or_default()
 }
 
+// ── Branches ──
+
+/// 换分支或截断后让记忆索引跟随当前时间线（蒸馏缓存基于旧历史，一并作废）
+fn sync_timeline_memory(conv: &Conversation) {
+    if ConversationStore::is_sandbox(&conv.id) {
+        return;
+    }
+    let memory = MemoryEngine::new(get_data_path());
+    let _ = memory.save_memory_index(&conv.id, &conv.memory_summaries);
+    let _ = memory.delete_distilled_state(&conv.id);
+}
+
+/// 从指定消息分叉出新时间线并切换过去，返回新分支ID
+pub fn create_branch(
+    conversation_id: String,
+    from_message_id: String,
+    name: Option<String>,
+) -> Option<String> {
+    let Ok(_turn) = ConversationStore::try_lock_turn(&conversation_id) else {
+        return None;
+    };
+    let conv = get_conversation_store()
+        .create_branch(&conversation_id, &from_message_id, name.as_deref())
+        .ok()?;
+    sync_timeline_memory(&conv);
+    Some(conv.branch_id)
+}
+
+pub fn list_branches(conversation_id: String) -> Vec<BranchInfo> {
+    get_conversation_store()
+        .list_branches(&conversation_id)
+        .unwrap_or_default()
+}
+
+/// 切换到指定分支；之后的对话只基于该分支的历史与记忆
+pub fn switch_branch(conversation_id: String, branch_id: String) -> bool {
+    let Ok(_turn) = ConversationStore::try_lock_turn(&conversation_id) else {
+        return false;
+    };
+    match get_conversation_store().switch_branch(&conversation_id, &branch_id) {
+        Ok(conv) => {
+            sync_timeline_memory(&conv);
+            true
+        }
+        Err(_) => false,
+    }
+}
+
 pub fn add_system_message(conversation_id: String, content: String) -> bool {
-    let msg = Message {
-        id: uuid::Uuid::new_v4().to_string(),
-        role: MessageRole::System,
-        content,
-        thinking_content: None,
-        model: "system".to_string(),
-        timestamp: chrono::Utc::now().timestamp_millis(),
-        message_type: MessageType::Say,
+    let Ok(_turn) = ConversationStore::try_lock_turn(&conversation_id) else {
+        return false;
     };
+    let msg = Message::new(MessageRole::System, content, "system");
     get_conversation_store()
         .add_message(&conversation_id, msg)
         .is_ok()
 }
 
 pub fn add_assistant_message(conversation_id: String, content: String) -> bool {
-    let msg = Message {
-        id: uuid::Uuid::new_v4().to_string(),
-        role: MessageRole::Assistant,
-        content,
-        thinking_content: None,
-        model: "glm-4.7".to_string(),
-        timestamp: chrono::Utc::now().timestamp_millis(),
-        message_type: MessageType::Say,
+    let Ok(_turn) = ConversationStore::try_lock_turn(&conversation_id) else {
+        return false;
     };
+    let msg = Message::new(MessageRole::Assistant, content, "glm-4.7");
     get_conversation_store()
         .add_message(&conversation_id, msg)
         .is_ok()
 }
 
-pub fn restart_story(conversation_id: String) -> bool {
+// ── Puppeteering ──
+
+/// 操偶模式：用户亲自替角色写一条回复，返回新消息ID。
+/// 这条回复会进入后续上下文，但不计入回复风格统计（反套路检测只看模型自己的回复）
+pub fn add_user_authored_reply(conversation_id: String, content: String) -> Option<String> {
+    let Ok(_turn) = ConversationStore::try_lock_turn(&conversation_id) else {
+        return None;
+    };
+    let message_type = ChatEngine::detect_message_type(&content);
+    get_conversation_store()
+        .add_user_authored_reply(&conversation_id, &content, message_type)
+        .ok()
+        .map(|m| m.id)
+}
+
+/// 操偶模式：用户改写一条角色回复，改写后同样视为用户撰写
+pub fn rewrite_reply(conversation_id: String, message_id: String, new_content: String) -> bool {
+    let Ok(_turn) = ConversationStore::try_lock_turn(&conversation_id) else {
+        return false;
+    };
+    let message_type = ChatEngine::detect_message_type(&new_content);
+    get_conversation_store()
+        .rewrite_reply(&conversation_id, &message_id, &new_content, message_type)
+        .is_ok()
+}
+
+/// 收束故事：角色写下尾声，补做摘要与事实提取，生成最终回顾与 Markdown 归档，
+/// 之后对话只读（仍可浏览）。model 为空时使用设置中的对话模型
+pub async fn close_conversation(
+    conversation_id: String,
+    model: String,
+) -> Result<ConversationClosure, String> {
+    // 尾声与补做的摘要都会写回对话，生成中的回复结束前不能收束
+    let _turn = ConversationStore::try_lock_turn(&conversation_id).map_err(|e| e.to_string())?;
     let settings = get_config_manager().load_settings();
-    let api_key = match settings.api_key {
-        Some(key) => key,
-        None => return false,
+    let engine = build_online_engine(&settings)?;
+    let model = if model.is_empty() { settings.chat_model.clone() } else { model };
+    engine
+        .close_conversation(&conversation_id, &model)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+pub fn restart_story(conversation_id: String) -> bool {
+    let Ok(_turn) = ConversationStore::try_lock_turn(&conversation_id) else {
+        return false;
     };
-    match ChatEngine::new(&api_key, get_data_path()) {
+    let settings = get_config_manager().load_settings();
+    match build_online_engine(&settings) {
         Ok(engine) => engine.restart_story(&conversation_id).is_ok(),
         Err(_) => false,
     }
 }
 
+/// 作废对话的蒸馏缓存（下一次上下文超长时重新蒸馏）
+pub fn invalidate_distilled_state(conversation_id: String) -> bool {
+    MemoryEngine::new(get_data_path())
+        .delete_distilled_state(&conversation_id)
+        .is_ok()
+}
+
+/// 立即重新蒸馏对话的核心状态并返回；蒸馏失败或没有结果时返回 None（旧缓存已作废）
+pub async fn refresh_distilled_state(conversation_id: String) -> Option<DistilledSystemState> {
+    let settings = get_config_manager().load_settings();
+    let engine = build_online_engine(&settings).ok()?;
+    match engine.refresh_distilled_state(&conversation_id).await {
+        Ok(state) => state,
+        Err(e) => {
+            tracing::warn!(conversation_id, error = %e, "蒸馏状态刷新失败");
+            None
+        }
+    }
+}
+
+/// 角色当前的心情（已按离开的时间回落）；还没有互动过时返回 None
+pub fn get_character_mood(conversation_id: String) -> Option<MoodState> {
+    MoodStore::new(get_data_path())
+        .current(&conversation_id, chrono::Utc::now().timestamp_millis())
+}
+
+/// 发送前预检：分析草稿语气与对关系的预期影响，只给建议不拦截。
+/// 对话不存在时返回 None
+pub fn preflight_message(conversation_id: String, draft: String) -> Option<PreflightAdvisory> {
+    use chrono::Timelike;
+    let conv = get_conversation_store().load_conversation(&conversation_id).ok()?;
+    let start = conv.messages.len().saturating_sub(20);
+    let history: Vec<&Message> = conv.messages[start..].iter().collect();
+    let local_hour = chrono::Local::now().hour();
+    Some(CognitiveEngine::preflight(&history, &draft, local_hour))
+}
+
+/// 对话当前的认知分析与短期记忆（与下一轮上下文增强看到的一致）
+pub fn analyze_conversation(conversation_id: String) -> Option<ConversationInsights> {
+    let conv = get_conversation_store().load_conversation(&conversation_id).ok()?;
+    let non_system: Vec<&Message> = conv
+        .messages
+        .iter()
+        .filter(|m| m.role != MessageRole::System)
+        .collect();
+    let cognitive = (non_system.len() >= 2)
+        .then(|| CognitiveInsight::from(&CognitiveEngine::analyze(&non_system)));
+    let short_term = MemoryEngine::build_short_term_context(&conv.messages);
+    Some(ConversationInsights {
+        cognitive,
+        short_term: (&short_term).into(),
+    })
+}
+
+/// 一条事实与当前对话的相关性明细（用于解释某条记忆为何被/未被注入）
+pub fn explain_relevance(
+    conversation_id: String,
+    fact: String,
+    user_content: String,
+) -> Option<RelevanceInsight> {
+    let conv = get_conversation_store().load_conversation(&conversation_id).ok()?;
+    let short_term = MemoryEngine::build_short_term_context(&conv.messages);
+    let score = MemoryEngine::compute_relevance(&fact, &short_term.active_topics, &user_content);
+    Some((&score).into())
+}
+
+/// 角色从用户纠正中学到的回避话题（含未生效的候选，按 active 区分）
+pub fn get_blocked_topics(conversation_id: String) -> Vec<BlockedTopic> {
+    BlockedTopicStore::new(get_data_path())
+        .load(&conversation_id)
+        .unwrap_or_default()
+}
+
+/// 移除一个学到的回避话题
+pub fn remove_blocked_topic(conversation_id: String, topic_id: String) -> bool {
+    BlockedTopicStore::new(get_data_path())
+        .remove(&conversation_id, &topic_id)
+        .unwrap_or(false)
+}
+
+/// 世界设定条目；conversation_id 为 None 时取所有对话共享的全局设定
+pub fn get_lore_entries(conversation_id: Option<String>) -> Vec<LoreEntry> {
+    let book_id = conversation_id.unwrap_or_else(|| GLOBAL_LOREBOOK_ID.to_string());
+    LorebookStore::new(get_data_path())
+        .load(&book_id)
+        .unwrap_or_default()
+}
+
+/// 新增或更新世界设定条目（id 为空时新建）；缺少触发词或内容时返回 None
+pub fn save_lore_entry(conversation_id: Option<String>, entry: LoreEntry) -> Option<LoreEntry> {
+    let book_id = conversation_id.unwrap_or_else(|| GLOBAL_LOREBOOK_ID.to_string());
+    LorebookStore::new(get_data_path()).upsert(&book_id, entry).ok()
+}
+
+pub fn remove_lore_entry(conversation_id: Option<String>, entry_id: String) -> bool {
+    let book_id = conversation_id.unwrap_or_else(|| GLOBAL_LOREBOOK_ID.to_string());
+    LorebookStore::new(get_data_path())
+        .remove(&book_id, &entry_id)
+        .unwrap_or(false)
+}
+
+/// 设为多角色群聊或更新角色名单（至少两位，名字不能重复）；失败时返回 None。
+/// 群聊对话照常用 send_message / regenerate_response，引擎会自动挑选发言角色
+pub fn set_group_chat(
+    conversation_id: String,
+    characters: Vec<GroupCharacter>,
+    turn_policy: TurnPolicy,
+) -> Option<GroupChat> {
+    get_conversation_store().load_conversation(&conversation_id).ok()?;
+    GroupChatStore::new(get_data_path())
+        .save(&conversation_id, characters, turn_policy)
+        .ok()
+}
+
+/// 对话的群聊配置；不是群聊时返回 None
+pub fn get_group_chat(conversation_id: String) -> Option<GroupChat> {
+    GroupChatStore::new(get_data_path()).load(&conversation_id)
+}
+
+/// 解散群聊，恢复为单角色对话（各角色的记忆与知识保留，重新组群时沿用）
+pub fn dissolve_group_chat(conversation_id: String) -> bool {
+    GroupChatStore::new(get_data_path())
+        .delete(&conversation_id)
+        .is_ok()
+}
+
 pub fn set_dialogue_style(conversation_id: String, style: DialogueStyle) -> bool {
+    let Ok(_turn) = ConversationStore::try_lock_turn(&conversation_id) else {
+        return false;
+    };
     get_conversation_store()
         .set_dialogue_style(&conversation_id, style)
         .is_ok()
 }
 
+/// 切换对话模式（聊天 / 长文共写）
+pub fn set_conversation_mode(conversation_id: String, mode: ConversationMode) -> bool {
+    let Ok(_turn) = ConversationStore::try_lock_turn(&conversation_id) else {
+        return false;
+    };
+    get_conversation_store()
+        .set_conversation_mode(&conversation_id, mode)
+        .is_ok()
+}
+
+/// 设置对话的叙述视角（第一人称 / 第三人称 / 不限）
+pub fn set_narration_perspective(
+    conversation_id: String,
+    perspective: NarrationPerspective,
+) -> bool {
+    let Ok(_turn) = ConversationStore::try_lock_turn(&conversation_id) else {
+        return false;
+    };
+    get_conversation_store()
+        .set_narration_perspective(&conversation_id, perspective)
+        .is_ok()
+}
+
+/// 逐层开关对话的上下文增强（短期记忆 / 认知快照 / 多样性提示 / 拟人化提示 / 知识注入）
+pub fn set_context_layers(conversation_id: String, layers: ContextLayers) -> bool {
+    let Ok(_turn) = ConversationStore::try_lock_turn(&conversation_id) else {
+        return false;
+    };
+    get_conversation_store()
+        .set_context_layers(&conversation_id, layers)
+        .is_ok()
+}
+
+/// 设置对话的回复风格偏好（回复长度 / 正式程度 / 表情 / 动作描写频率，Auto 为自动判断）
+pub fn set_response_style(conversation_id: String, style: ResponseStyle) -> bool {
+    let Ok(_turn) = ConversationStore::try_lock_turn(&conversation_id) else {
+        return false;
+    };
+    get_conversation_store()
+        .set_response_style(&conversation_id, style)
+        .is_ok()
+}
+
+/// 用户人设列表（数据目录下 personas.json，按创建时间排序）
+pub fn list_personas() -> Vec<UserPersona> {
+    PersonaStore::new(get_data_path()).list()
+}
+
+/// 新建（id 留空）或更新人设，返回保存后的人设；名称为空或过长时报错
+pub fn save_persona(persona: UserPersona) -> Result<UserPersona, String> {
+    PersonaStore::new(get_data_path())
+        .save(persona)
+        .map_err(|e| e.to_string())
+}
+
+/// 删除人设及其分区下的用户档案事实；选用它的对话此后按未选人设处理
+pub fn delete_persona(persona_id: String) -> Result<bool, String> {
+    let data_path = get_data_path();
+    let deleted = PersonaStore::new(data_path)
+        .delete(&persona_id)
+        .map_err(|e| e.to_string())?;
+    if deleted {
+        KnowledgeStore::new(data_path)
+            .remove_persona_profile(&persona_id)
+            .map_err(|e| e.to_string())?;
+    }
+    Ok(deleted)
+}
+
+/// 为对话选用人设（None 为不使用人设）；人设不存在时返回 false
+pub fn set_conversation_persona(conversation_id: String, persona_id: Option<String>) -> bool {
+    let Ok(_turn) = ConversationStore::try_lock_turn(&conversation_id) else {
+        return false;
+    };
+    if let Some(id) = &persona_id {
+        if PersonaStore::new(get_data_path()).get(id).is_none() {
+            return false;
+        }
+    }
+    get_conversation_store()
+        .set_persona(&conversation_id, persona_id)
+        .is_ok()
+}
+
+/// 设置对话的思考内容保留策略
+pub fn set_thinking_retention(conversation_id: String, policy: ThinkingRetention) -> bool {
+    let Ok(_turn) = ConversationStore::try_lock_turn(&conversation_id) else {
+        return false;
+    };
+    get_conversation_store()
+        .set_thinking_retention(&conversation_id, policy)
+        .is_ok()
+}
+
+/// 维护任务：对所有对话执行思考内容保留策略，返回清除的思考内容条数
+pub fn run_thinking_retention() -> u32 {
+    let store = get_conversation_store();
+    store
+        .list_conversations()
+        .iter()
+        .filter_map(|summary| {
+            // 正在生成的对话跳过，下次维护再处理
+            let _turn = ConversationStore::try_lock_turn(&summary.id).ok()?;
+            store.apply_thinking_retention(&summary.id).ok()
+        })
+        .sum()
+}
+
 pub fn detect_message_type(content: String) -> MessageType {
     ChatEngine::detect_message_type(&content)
 }
 
+/// 按对话的自定义 Say/Do 规则检测消息类型（未配置规则时同 detect_message_type）
+pub fn detect_message_type_in(conversation_id: String, content: String) -> MessageType {
+    let rules = SayDoRuleStore::new(get_data_path()).load_compiled(&conversation_id);
+    SayDoDetector::detect_with_rules(&content, &rules)
+}
+
+/// 对话的自定义 Say/Do 识别规则（未配置时为空，即内置识别）
+pub fn get_saydo_rules(conversation_id: String) -> SayDoRuleSet {
+    SayDoRuleStore::new(get_data_path()).load(&conversation_id)
+}
+
+/// 设置对话的 Say/Do 识别规则；正则无效时不保存，传空规则恢复内置识别
+pub fn set_saydo_rules(conversation_id: String, rules: SayDoRuleSet) -> Result<(), String> {
+    SayDoRuleStore::new(get_data_path())
+        .save(&conversation_id, &rules)
+        .map_err(|e| e.to_string())
+}
+
 pub fn get_turn_count(conversation_id: String) -> u32 {
     get_conversation_store()
         .get_turn_count(&conversation_id)
         .unwrap_or(0)
 }
 
+/// 交叉校验对话、记忆索引与知识库的一致性（只读）
+pub fn verify_integrity(conversation_id: String) -> Option<IntegrityReport> {
+    IntegrityChecker::new(get_data_path())
+        .verify(&conversation_id, false)
+        .ok()
+}
+
+/// 校验并自动修复不一致项，返回修复前检测到的问题
+pub fn repair_integrity(conversation_id: String) -> Option<IntegrityReport> {
+    IntegrityChecker::new(get_data_path())
+        .verify(&conversation_id, true)
+        .ok()
+}
+
+/// 最近的降级/回退决策记录（按时间先后），用于解释回复质量波动
+pub fn get_degradation_history(conversation_id: String) -> Vec<DegradationRecord> {
+    DecisionLog::new(get_data_path())
+        .load(&conversation_id)
+        .unwrap_or_default()
+}
+
+/// 记忆保真度审计：抽样历史检查点中的事实，核对当前摘要是否仍能推出
+pub fn audit_memory_fidelity(conversation_id: String) -> Option<MemoryFidelityReport> {
+    let summaries = MemoryEngine::new(get_data_path())
+        .load_memory_index(&conversation_id)
+        .ok()?;
+    FidelityAuditor::new(get_data_path())
+        .audit(&conversation_id, &summaries)
+        .ok()
+}
+
+/// 对所有对话执行保真度审计，只返回需要告警的结果
+pub fn audit_all_memory_fidelity() -> Vec<MemoryFidelityReport> {
+    get_conversation_store()
+        .list_conversations()
+        .into_iter()
+        .filter_map(|c| audit_memory_fidelity(c.id))
+        .filter(|r| r.alert)
+        .collect()
+}
+
+/// 历次保真度审计结果（按时间先后），用于观察压缩代数增长时的记忆流失
+pub fn get_memory_fidelity_history(conversation_id: String) -> Vec<MemoryFidelityReport> {
+    FidelityAuditor::new(get_data_path())
+        .history(&conversation_id)
+        .unwrap_or_default()
+}
+
+/// 知识注入影子评估的原始记录（按时间先后）
+pub fn get_shadow_eval_records(conversation_id: String) -> Vec<ShadowEvalRecord> {
+    ShadowEvalStore::new(get_data_path())
+        .load(&conversation_id)
+        .unwrap_or_default()
+}
+
+/// 影子评估汇总；conversation_id 为 None 时汇总所有对话
+pub fn get_shadow_eval_summary(conversation_id: Option<String>) -> ShadowEvalSummary {
+    let store = ShadowEvalStore::new(get_data_path());
+    let ids = match conversation_id {
+        Some(id) => vec![id],
+        None => get_conversation_store()
+            .list_conversations()
+            .into_iter()
+            .map(|c| c.id)
+            .collect(),
+    };
+    let records: Vec<ShadowEvalRecord> = ids
+        .iter()
+        .flat_map(|id| store.load(id).unwrap_or_default())
+        .collect();
+    ShadowEvalStore::summarize(&records)
+}
+
 pub fn should_summarize_memory(conversation_id: String) -> bool {
     let turn_count = get_conversation_store()
         .get_turn_count(&conversation_id)
         .unwrap_or(0);
-    MemoryEngine::should_summarize(turn_count)
+    let interval = get_config_manager()
+        .load_summarization_config(&conversation_id)
+        .interval_turns;
+    MemoryEngine::should_summarize(turn_count, interval)
+}
+
+/// 对话的记忆摘要节奏（间隔轮数、读取的消息条数）
+pub fn get_summarization_config(conversation_id: String) -> SummarizationConfig {
+    get_config_manager().load_summarization_config(&conversation_id)
+}
+
+/// 设置对话的记忆摘要节奏：重度角色扮演可以每 30 轮摘要一次，轻量闲聊每 5 轮；
+/// config 为 None 时恢复默认（每 10 轮、最近 20 条消息）
+pub fn set_summarization_config(
+    conversation_id: String,
+    config: Option<SummarizationConfig>,
+) -> Result<(), String> {
+    get_config_manager()
+        .set_summarization_config(&conversation_id, config)
+        .map_err(|e| e.to_string())
 }
 
 pub fn search_memories(
@@ -168,54 +839,693 @@ pub fn search_memories(
     MemoryEngine::search_memories(&query, &summaries, top_k)
 }
 
-pub fn get_settings() -> AppSettings {
-    get_config_manager().load_settings()
+/// 记忆溯源：某条摘要由知识库中哪些事实支撑
+pub fn explain_memory(conversation_id: String, summary_id: String) -> Option<MemoryExplanation> {
+    let summary = MemoryEngine::new(get_data_path())
+        .load_memory_index(&conversation_id)
+        .ok()?
+        .into_iter()
+        .find(|s| s.id == summary_id)?;
+    let linked_facts = KnowledgeStore::new(get_data_path())
+        .linked_facts(&conversation_id, &summary)
+        .into_iter()
+        .map(|f| LinkedFact {
+            fact_id: f.id,
+            content: f.content,
+            source_turn: f.source_turn,
+            summary_ids: f.summary_ids,
+        })
+        .collect();
+    Some(MemoryExplanation {
+        summary_id: summary.id,
+        summary: summary.summary,
+        turn_range_start: summary.turn_range_start,
+        turn_range_end: summary.turn_range_end,
+        compression_generation: summary.compression_generation,
+        linked_facts,
+    })
+}
+
+/// 知识库中的事实；conversation_id 为 None 时为跨对话共享的用户档案
+pub fn list_facts(conversation_id: Option<String>) -> Vec<Fact> {
+    let store = KnowledgeStore::new(get_data_path());
+    match conversation_id {
+        Some(id) => store.get_all_facts(&id),
+        None => store.get_global_facts(),
+    }
+}
+
+/// 手动添加事实（confidence 缺省为 1.0）；内容为空或置信度越界时返回 None
+pub fn add_manual_fact(
+    conversation_id: Option<String>,
+    content: String,
+    category: FactCategory,
+    confidence: Option<f64>,
+) -> Option<Fact> {
+    KnowledgeStore::new(get_data_path())
+        .add_manual_fact(
+            conversation_id.as_deref(),
+            &content,
+            category,
+            confidence.unwrap_or(1.0),
+        )
+        .ok()
+}
+
+/// 修改事实；修改后的事实不再被自动提取覆盖
+pub fn update_fact(
+    conversation_id: Option<String>,
+    fact_id: String,
+    content: String,
+    category: FactCategory,
+    confidence: f64,
+) -> bool {
+    KnowledgeStore::new(get_data_path())
+        .update_fact(conversation_id.as_deref(), &fact_id, &content, category, confidence)
+        .unwrap_or(false)
+}
+
+pub fn delete_fact(conversation_id: Option<String>, fact_id: String) -> bool {
+    KnowledgeStore::new(get_data_path())
+        .delete_fact(conversation_id.as_deref(), &fact_id)
+        .unwrap_or(false)
+}
+
+/// 对话与角色层 / 世界层的绑定
+pub fn get_knowledge_binding(conversation_id: String) -> KnowledgeBinding {
+    KnowledgeStore::new(get_data_path())
+        .layers()
+        .binding(&conversation_id)
+}
+
+/// 绑定角色层 / 世界层（各项均为空时解除绑定）；之后提取的事实按规则晋升，
+/// 检索时一并合并。ID 只能由字母、数字、- 和 _ 组成
+pub fn set_knowledge_binding(
+    conversation_id: String,
+    binding: KnowledgeBinding,
+) -> Result<(), String> {
+    KnowledgeStore::new(get_data_path())
+        .layers()
+        .set_binding(&conversation_id, binding)
+        .map_err(|e| e.to_string())
+}
+
+/// 对话（或群聊角色视角 `{对话ID}@{角色ID}`）某一层的事实；未绑定该层时为空
+pub fn list_layer_facts(conversation_id: String, layer: KnowledgeLayer) -> Vec<Fact> {
+    let store = KnowledgeStore::new(get_data_path());
+    match store.layers().layer_scope(&conversation_id, layer) {
+        Some(scope) => store.get_all_facts(&scope),
+        None => Vec::new(),
+    }
+}
+
+/// 手动把对话中的一条事实晋升到角色层 / 世界层（群聊角色视角也可晋升到对话共享层）；
+/// 事实不存在时返回 false
+pub fn promote_fact(
+    conversation_id: String,
+    fact_id: String,
+    layer: KnowledgeLayer,
+) -> Result<bool, String> {
+    KnowledgeStore::new(get_data_path())
+        .layers()
+        .promote_fact(&conversation_id, &fact_id, layer)
+        .map_err(|e| e.to_string())
+}
+
+/// 知识图谱：实体为节点、事实为边；conversation_id 为 None 时为用户档案
+pub fn get_knowledge_graph(conversation_id: Option<String>) -> KnowledgeGraphView {
+    KnowledgeStore::new(get_data_path())
+        .graph(conversation_id.as_deref())
+        .view()
+}
+
+/// 与某个实体相距 depth 跳以内的子图（「和妹妹有关的都有什么」）
+pub fn query_graph_neighbors(
+    conversation_id: Option<String>,
+    entity: String,
+    depth: u32,
+) -> KnowledgeGraphView {
+    KnowledgeStore::new(get_data_path())
+        .graph(conversation_id.as_deref())
+        .neighbors(&entity, depth)
+}
+
+/// 两个实体之间最短的关系链；不连通时为空
+pub fn find_graph_path(conversation_id: Option<String>, from: String, to: String) -> Vec<GraphEdge> {
+    KnowledgeStore::new(get_data_path())
+        .graph(conversation_id.as_deref())
+        .find_path(&from, &to)
+}
+
+/// 导出知识图谱（JSON 或 GraphML 文本）
+pub fn export_knowledge_graph(conversation_id: Option<String>, format: GraphExportFormat) -> String {
+    let view = KnowledgeStore::new(get_data_path())
+        .graph(conversation_id.as_deref())
+        .view();
+    knowledge_graph::export(&view, format)
+}
+
+/// 对话中角色之间的关系（有向，每对角色每个方向一条当前关系及其历史）
+pub fn get_character_relations(conversation_id: String) -> Vec<CharacterRelation> {
+    KnowledgeStore::new(get_data_path())
+        .character_web(&conversation_id)
+        .relations()
+        .to_vec()
+}
+
+/// 两个角色对彼此的看法：a→b 与 b→a 两个方向（没有记录的方向缺省）
+pub fn query_character_relation(
+    conversation_id: String,
+    a: String,
+    b: String,
+) -> Vec<CharacterRelation> {
+    KnowledgeStore::new(get_data_path())
+        .character_web(&conversation_id)
+        .between(&a, &b)
+}
+
+/// 故事时间线：记忆摘要（章节）与关键事件按时间排列；对话不存在时为空
+pub fn get_story_timeline(conversation_id: String) -> Vec<TimelineEntry> {
+    let conv = match get_conversation_store().load_conversation(&conversation_id) {
+        Ok(c) => c,
+        Err(_) => return Vec::new(),
+    };
+    // 记忆索引是检索的实际来源，缺失时退回对话内的副本
+    let summaries = MemoryEngine::new(get_data_path())
+        .load_memory_index(&conversation_id)
+        .ok()
+        .filter(|s| !s.is_empty())
+        .unwrap_or(conv.memory_summaries);
+    let facts = KnowledgeStore::new(get_data_path()).get_all_facts(&conversation_id);
+    StoryTimeline::build(&conv.messages, conv.cold_turns, &summaries, &facts)
+}
+
+/// 事实冲突记录：哪些旧事实被新事实取代（新的在前）
+pub fn get_fact_conflicts(conversation_id: String) -> Vec<FactConflict> {
+    KnowledgeStore::new(get_data_path()).list_conflicts(&conversation_id)
+}
+
+/// 置顶 / 取消置顶记忆摘要（fact 为 None）或其中一条核心事实；
+/// 置顶内容在分级合并中不会被丢弃、合并或改写
+pub fn pin_memory(
+    conversation_id: String,
+    summary_id: String,
+    fact: Option<String>,
+    pinned: bool,
+) -> bool {
+    let Ok(_turn) = ConversationStore::try_lock_turn(&conversation_id) else {
+        return false;
+    };
+    match MemoryEngine::new(get_data_path()).set_pinned(
+        &conversation_id,
+        &summary_id,
+        fact.as_deref(),
+        pinned,
+    ) {
+        Ok(Some(summaries)) => get_conversation_store()
+            .update_memory_summaries(&conversation_id, &summaries)
+            .is_ok(),
+        _ => false,
+    }
+}
+
+/// 记忆浏览：对话的全部记忆摘要（按轮次排列，含核心事实与排级）
+pub fn list_memories(conversation_id: String) -> Vec<MemorySummary> {
+    let mut summaries = MemoryEngine::new(get_data_path())
+        .load_memory_index(&conversation_id)
+        .unwrap_or_default();
+    summaries.sort_by_key(|s| (s.turn_range_start, s.created_at));
+    summaries
+}
+
+/// 修改记忆摘要的正文与核心事实（传入完整的事实列表）；摘要为空或不存在时返回 false
+pub fn edit_memory(
+    conversation_id: String,
+    summary_id: String,
+    summary: String,
+    core_facts: Vec<String>,
+) -> bool {
+    let Ok(_turn) = ConversationStore::try_lock_turn(&conversation_id) else {
+        return false;
+    };
+    let edited = MemoryEngine::new(get_data_path()).edit_summary(
+        &conversation_id,
+        &summary_id,
+        &summary,
+        &core_facts,
+    );
+    match edited {
+        Ok(Some(summaries)) => {
+            // 内容变了，旧向量作废，下次同步时重算
+            let _ = EmbeddingStore::new(get_data_path()).invalidate(&conversation_id, &summary_id);
+            persist_edited_memories(&conversation_id, summaries)
+        }
+        _ => false,
+    }
+}
+
+/// 删除一条记忆摘要
+pub fn delete_memory(conversation_id: String, summary_id: String) -> bool {
+    let Ok(_turn) = ConversationStore::try_lock_turn(&conversation_id) else {
+        return false;
+    };
+    match MemoryEngine::new(get_data_path()).delete_summary(&conversation_id, &summary_id) {
+        Ok(Some(summaries)) => {
+            let _ = EmbeddingStore::new(get_data_path()).invalidate(&conversation_id, &summary_id);
+            persist_edited_memories(&conversation_id, summaries)
+        }
+        _ => false,
+    }
+}
+
+/// 调整记忆摘要中一条核心事实的排级（Identity / CriticalEvent 在分级合并中永不压缩）
+pub fn set_memory_fact_tier(
+    conversation_id: String,
+    summary_id: String,
+    fact: String,
+    tier: MemoryTier,
+) -> bool {
+    let Ok(_turn) = ConversationStore::try_lock_turn(&conversation_id) else {
+        return false;
+    };
+    match MemoryEngine::new(get_data_path()).set_fact_tier(
+        &conversation_id,
+        &summary_id,
+        &fact,
+        tier,
+    ) {
+        Ok(Some(summaries)) => {
+            let _ = MemoryEngine::new(get_data_path()).delete_distilled_state(&conversation_id);
+            get_conversation_store()
+                .update_memory_summaries(&conversation_id, &summaries)
+                .is_ok()
+        }
+        _ => false,
+    }
+}
+
+/// 用户改动记忆后：重建事实 ↔ 摘要链接，写回记忆索引与对话内的副本；
+/// 蒸馏缓存基于旧记忆，一并作废
+fn persist_edited_memories(conversation_id: &str, mut summaries: Vec<MemorySummary>) -> bool {
+    let memory = MemoryEngine::new(get_data_path());
+    let _ = memory.delete_distilled_state(conversation_id);
+    if KnowledgeStore::new(get_data_path())
+        .cross_link(conversation_id, &mut summaries)
+        .is_ok()
+        && memory.save_memory_index(conversation_id, &summaries).is_err()
+    {
+        return false;
+    }
+    get_conversation_store()
+        .update_memory_summaries(conversation_id, &summaries)
+        .is_ok()
+}
+
+// ── Index maintenance ──
+
+/// 检索算法升级后，后台重建所有对话中过期的索引，每完成一个对话推送一次进度
+pub async fn reindex_all(
+    scope: IndexScope,
+    sink: crate::frb_generated::StreamSink<ReindexProgress>,
+) {
+    Reindexer::new(get_data_path())
+        .reindex_all(scope, |progress| {
+            let _ = sink.add(progress);
+        })
+        .await;
+}
+
+// ── Diagnostics ──
+
+/// CPU 密集任务的线程池运行统计（用于确认检索计算没有拖慢流式输出）
+pub fn get_blocking_stats() -> BlockingStats {
+    blocking_pool::snapshot()
+}
+
+/// 是否处于弱网非流式模式（回复整段一次性返回，界面可提示「网络较差」）
+pub fn is_streaming_degraded() -> bool {
+    network_adaptation::is_degraded()
+}
+
+/// 跨全部对话按内容搜索消息，返回带片段的命中（最多 search_index::MAX_SEARCH_HITS 条）
+pub fn search_conversations(query: String) -> Vec<SearchHit> {
+    SearchIndex::new(get_data_path()).search(get_conversation_store(), &query)
+}
+
+// ── Data layout ──
+
+/// 就地升级数据目录到当前布局版本，并刷新布局清单
+pub fn upgrade_data_layout() -> Option<LayoutVerification> {
+    DataLayoutMigrator::new(get_data_path()).upgrade().ok()
+}
+
+/// 将数据目录与布局清单快照比对
+pub fn verify_data_layout() -> Option<LayoutVerification> {
+    DataLayoutMigrator::new(get_data_path()).verify().ok()
+}
+
+/// 将整个数据目录迁移到新路径（换设备/换存储位置），完成后在目标端校验
+pub fn migrate_data_layout(target_path: String) -> Option<LayoutVerification> {
+    DataLayoutMigrator::new(get_data_path())
+        .migrate_to(&target_path)
+        .ok()
+}
+
+// ── Backup ──
+
+/// 创建一份增量备份（archive_path 为 None 时写入数据目录下的默认归档），按默认保留策略清理旧快照
+pub fn create_backup(archive_path: Option<String>) -> Option<BackupSnapshotInfo> {
+    BackupManager::new(get_data_path(), archive_path.as_deref())
+        .create(&BackupRetention::default())
+        .ok()
+}
+
+/// 归档中的全部快照（从旧到新）
+pub fn list_backups(archive_path: Option<String>) -> Vec<BackupSnapshotInfo> {
+    BackupManager::new(get_data_path(), archive_path.as_deref())
+        .list()
+        .unwrap_or_default()
+}
+
+/// 按范围回滚到指定快照（snapshot_id 为 None 时用最新一份），返回写回的文件数
+pub fn restore_backup(
+    archive_path: Option<String>,
+    snapshot_id: Option<String>,
+    scope: BackupScope,
+) -> Result<u32, String> {
+    // 生成中的回复会把旧数据写回回滚后的文件：有对话在生成时拒绝回滚
+    let _turns = get_conversation_store()
+        .try_lock_all_turns()
+        .map_err(|e| e.to_string())?;
+    let restored = BackupManager::new(get_data_path(), archive_path.as_deref())
+        .restore(snapshot_id.as_deref(), scope)
+        .map_err(|e| e.to_string())?;
+    ContextCache::global().clear();
+    store_cache::clear_all();
+    Ok(restored)
+}
+
+/// 按保留策略清理旧快照，返回删除的快照数
+pub fn prune_backups(archive_path: Option<String>, retention: BackupRetention) -> u32 {
+    BackupManager::new(get_data_path(), archive_path.as_deref())
+        .prune(&retention)
+        .unwrap_or(0)
+}
+
+// ── Full data export ──
+
+/// 把整个数据目录导出为单个 tar 归档（带清单与校验和），用于换设备
+pub async fn export_all_data(target_path: String) -> Result<DataExportInfo, String> {
+    blocking_pool::offload("data_export", move || {
+        DataExporter::new(get_data_path()).export(&target_path)
+    })
+    .await
+    .map_err(|e| e.to_string())
+}
+
+/// 用导出归档整体替换当前数据（全部校验通过才生效），完成后重新载入设置；
+/// 数据启用了静态加密时，之后需用原凭据解锁
+pub async fn import_all_data(archive_path: String) -> Result<DataExportInfo, String> {
+    // 生成中的回复会把旧数据写回导入后的目录：有对话在生成时拒绝导入
+    let _turns = get_conversation_store()
+        .try_lock_all_turns()
+        .map_err(|e| e.to_string())?;
+    let info = blocking_pool::offload("data_import", move || {
+        DataExporter::new(get_data_path()).restore(&archive_path)
+    })
+    .await
+    .map_err(|e| e.to_string())?;
+    install_persisted_config(get_data_path());
+    ContextCache::global().clear();
+    store_cache::clear_all();
+    Ok(info)
+}
+
+// ── Cloud sync ──
+
+/// 云同步设置（数据目录下 sync.json；未填写地址时不同步）
+pub fn get_sync_settings() -> SyncSettings {
+    get_config_manager().load_sync_settings()
+}
+
+pub fn set_sync_settings(settings: SyncSettings) -> Result<(), String> {
+    get_config_manager()
+        .save_sync_settings(&settings)
+        .map_err(|e| e.to_string())
+}
+
+/// 与远端同步一次对话、记忆索引与知识库；宿主在启动、回到前台或定时调用
+pub async fn sync_now() -> Result<SyncReport, String> {
+    let settings = get_config_manager().load_sync_settings();
+    let backend = cloud_sync::backend_from_settings(&settings).map_err(|e| e.to_string())?;
+    // 拉取会改写对话文件：有对话在生成时本次不同步，下次再来
+    let _turns = get_conversation_store()
+        .try_lock_all_turns()
+        .map_err(|e| e.to_string())?;
+    let report = SyncEngine::new(get_data_path(), backend)
+        .sync(chrono::Utc::now().timestamp_millis())
+        .await
+        .map_err(|e| e.to_string())?;
+    if report.pulled > 0 || report.deleted > 0 {
+        ContextCache::global().clear();
+        store_cache::clear_all();
+    }
+    Ok(report)
+}
+
+// ── At-rest encryption ──
+
+/// 对话 / 记忆 / 事实文件的加密状态（启用后每次启动需先解锁）
+pub fn get_encryption_status() -> EncryptionStatus {
+    at_rest::status(get_data_path())
+}
+
+/// 启用静态加密并把现有文件迁移为密文，返回迁移的文件数
+pub fn enable_encryption(secret: EncryptionSecret) -> Result<u32, String> {
+    at_rest::enable(get_data_path(), &secret).map_err(|e| e.to_string())
+}
+
+/// 启动后解锁加密存储（口令或平台密钥库中的密钥）
+pub fn unlock_encryption(secret: EncryptionSecret) -> Result<(), String> {
+    at_rest::unlock(get_data_path(), &secret).map_err(|e| e.to_string())
+}
+
+/// 停用静态加密并把文件迁移回明文，返回迁移的文件数
+pub fn disable_encryption(secret: EncryptionSecret) -> Result<u32, String> {
+    at_rest::disable(get_data_path(), &secret).map_err(|e| e.to_string())
+}
+
+pub fn get_settings() -> AppSettings {
+    get_config_manager().load_settings()
+}
+
+pub fn save_settings(settings: AppSettings) -> bool {
+    get_config_manager().save_settings(&settings).is_ok()
+}
+
+/// 设置角色音色（群聊填角色ID，单聊填对话ID；None 恢复默认音色）
+pub fn set_character_voice(character_key: String, voice: Option<String>) -> bool {
+    get_config_manager()
+        .set_character_voice(&character_key, voice)
+        .is_ok()
+}
+
+/// 已配置的角色音色
+pub fn get_character_voices() -> HashMap<String, String> {
+    get_config_manager().load_voices()
+}
+
+pub fn set_api_key(api_key: String) -> Result<(), String> {
+    if !JwtAuth::validate_api_key_format(&api_key) {
+        return Err("Invalid API key format. Expected: user_id.user_secret".to_string());
+    }
+    let mut settings = get_config_manager().load_settings();
+    settings.api_key = Some(api_key);
+    get_config_manager()
+        .save_settings(&settings)
+        .map_err(|e| e.to_string())
+}
+
+pub fn validate_api_key(api_key: String) -> bool {
+    JwtAuth::validate_api_key_format(&api_key)
+}
+
+/// 切换对话提供方。OpenAI 兼容端点 / Anthropic 需要接口地址、模型名与 Key，
+/// 本地模型只需模型名（地址默认 Ollama），智谱沿用 set_api_key 保存的 Key
+pub fn set_chat_provider(
+    provider: ProviderKind,
+    base_url: Option<String>,
+    model: Option<String>,
+    api_key: Option<String>,
+) -> Result<(), String> {
+    get_config_manager()
+        .set_provider(provider, base_url, model, api_key)
+        .map_err(|e| e.to_string())
+}
+
+/// 设置网络代理（HTTP / HTTPS / SOCKS5，可带认证与直连列表）；None 取消代理配置
+pub fn set_proxy(proxy: Option<ProxySettings>) -> Result<(), String> {
+    get_config_manager()
+        .set_proxy(proxy)
+        .map_err(|e| e.to_string())
+}
+
+/// 设置请求重试策略（次数、退避方式、可重试的错误类别、Retry-After 上限）
+pub fn set_retry_policy(policy: RetryPolicy) -> Result<(), String> {
+    get_config_manager()
+        .set_retry_policy(policy)
+        .map_err(|e| e.to_string())
+}
+
+/// 开关现实时间感知：每轮告诉角色现在几点、星期几、距上一条消息多久
+pub fn set_time_awareness(enabled: bool) -> Result<(), String> {
+    get_config_manager()
+        .set_time_awareness(enabled)
+        .map_err(|e| e.to_string())
+}
+
+/// 开关快速草稿：深度推理期间先流式给出 glm-4.7-flash 的简短回复（DraftDelta），
+/// 完整回复到达后发送 Refined 替换草稿
+pub fn set_fast_draft(enabled: bool) -> Result<(), String> {
+    get_config_manager()
+        .set_fast_draft(enabled)
+        .map_err(|e| e.to_string())
+}
+
+/// 自定义情感词条（数据目录下 emotion_lexicon.json）
+pub fn get_emotion_lexicon() -> Vec<LexiconEntry> {
+    cognitive_engine::custom_lexicon().as_ref().clone()
+}
+
+/// 整体替换自定义情感词条，保存后立即参与情感分析；有空词或强度越界时不生效
+pub fn save_emotion_lexicon(entries: Vec<LexiconEntry>) -> Result<(), String> {
+    let manager = get_config_manager();
+    manager
+        .save_emotion_lexicon(&entries)
+        .map_err(|e| e.to_string())?;
+    cognitive_engine::install_custom_lexicon(manager.load_emotion_lexicon());
+    Ok(())
+}
+
+/// 内容安全过滤设置（数据目录下 safety.json；默认关闭）
+pub fn get_safety_policy() -> SafetyPolicy {
+    safety_filter::policy().as_ref().clone()
+}
+
+/// 保存内容安全过滤设置，下一轮对话起生效
+pub fn set_safety_policy(policy: SafetyPolicy) -> Result<(), String> {
+    get_config_manager()
+        .save_safety_policy(&policy)
+        .map_err(|e| e.to_string())?;
+    safety_filter::install_policy(policy);
+    Ok(())
+}
+
+/// 数据保留策略（数据目录下 retention.json；默认全部关闭）
+pub fn get_retention_policy() -> RetentionPolicy {
+    get_config_manager().load_retention_policy()
+}
+
+pub fn set_retention_policy(policy: RetentionPolicy) -> Result<(), String> {
+    get_config_manager()
+        .save_retention_policy(&policy)
+        .map_err(|e| e.to_string())
+}
+
+/// 按保留策略执行一次数据维护（自动归档、冷存储、孤立文件清理）；
+/// 宿主在启动或空闲时调用
+pub async fn run_housekeeping() -> Result<HousekeepingReport, String> {
+    let policy = get_config_manager().load_retention_policy();
+    let now = chrono::Utc::now().timestamp_millis();
+    blocking_pool::offload("housekeeping", move || {
+        Housekeeper::new(get_data_path()).run(&policy, now)
+    })
+    .await
+    .map_err(|e| e.to_string())
+}
+
+/// 对话已转入冷存储的早期消息（旧 → 新）
+pub fn load_cold_storage_messages(conversation_id: String) -> Result<Vec<Message>, String> {
+    Housekeeper::new(get_data_path())
+        .cold_messages(&conversation_id)
+        .map_err(|e| e.to_string())
+}
+
+/// 可覆盖的提示词模板（数据目录下 prompts/{name}.txt）及其变量
+pub fn list_prompt_templates() -> Vec<PromptTemplateInfo> {
+    PromptTemplateStore::new(get_data_path()).list()
+}
+
+/// 用户覆盖的模板原文；未覆盖时为 None（使用内置提示）
+pub fn get_prompt_template(name: String) -> Result<Option<String>, String> {
+    PromptTemplateStore::new(get_data_path())
+        .load(&name)
+        .map_err(|e| e.to_string())
 }
 
-pub fn save_settings(settings: AppSettings) -> bool {
-    get_config_manager().save_settings(&settings).is_ok()
+/// 保存覆盖模板，下一轮对话起生效；模板语法错误时不保存，内容为空等同于恢复内置
+pub fn save_prompt_template(name: String, content: String) -> Result<(), String> {
+    let store = PromptTemplateStore::new(get_data_path());
+    store.save(&name, &content).map_err(|e| e.to_string())?;
+    prompt_templates::install(store.load_all());
+    Ok(())
 }
 
-pub fn set_api_key(api_key: String) -> Result<(), String> {
-    if !JwtAuth::validate_api_key_format(&api_key) {
-        return Err("Invalid API key format. Expected: user_id.user_secret".to_string());
-    }
-    let mut settings = get_config_manager().load_settings();
-    settings.api_key = Some(api_key);
-    get_config_manager()
-        .save_settings(&settings)
-        .map_err(|e| e.to_string())
+/// 删除覆盖模板，恢复内置提示
+pub fn reset_prompt_template(name: String) -> Result<(), String> {
+    let store = PromptTemplateStore::new(get_data_path());
+    store.reset(&name).map_err(|e| e.to_string())?;
+    prompt_templates::install(store.load_all());
+    Ok(())
 }
 
-pub fn validate_api_key(api_key: String) -> bool {
-    JwtAuth::validate_api_key_format(&api_key)
+/// 重新读取 prompts 目录（在应用外手动编辑模板文件后调用），返回生效的覆盖数
+pub fn reload_prompt_templates() -> u32 {
+    let templates = PromptTemplateStore::new(get_data_path()).load_all();
+    let count = templates.len() as u32;
+    prompt_templates::install(templates);
+    count
 }
 
+/// 模型选择列表（内置声明 + 数据目录下 models.json 中的声明）
 pub fn get_available_models() -> Vec<ModelInfo> {
-    // 参考: https://docs.bigmodel.cn/cn/guide/start/concept-param
-    vec![
-        ModelInfo {
-            id: "glm-4.7".to_string(),
-            name: "GLM-4.7（对话+思考）".to_string(),
-            context_tokens: 128000,
-            max_output_tokens: 131072,
-            supports_thinking: true,
-        },
-        ModelInfo {
-            id: "glm-4-air".to_string(),
-            name: "GLM-4-Air（深度推理）".to_string(),
-            context_tokens: 128000,
-            max_output_tokens: 4095,
-            supports_thinking: true,
-        },
-        ModelInfo {
-            id: "glm-4.7-flash".to_string(),
-            name: "GLM-4.7-Flash（快速）".to_string(),
-            context_tokens: 128000,
-            max_output_tokens: 131072,
-            supports_thinking: false,
-        },
-    ]
+    ModelRegistry::global()
+        .listed()
+        .map(|m| ModelInfo {
+            id: m.id.clone(),
+            name: if m.name.is_empty() { m.id.clone() } else { m.name.clone() },
+            context_tokens: m.context_tokens,
+            max_output_tokens: m.max_output_tokens as usize,
+            supports_thinking: m.supports_thinking(),
+        })
+        .collect()
+}
+
+/// 按设置中的提供方构建在线引擎
+fn build_online_engine(settings: &AppSettings) -> Result<ChatEngine, String> {
+    let provider = chat_provider::from_settings(settings)?;
+    Ok(ChatEngine::with_provider(provider, get_data_path())
+        .with_embedding(EmbeddingBackend::from_settings(settings))
+        .with_illustrator(CogViewClient::from_settings(settings))
+        .with_tts(TtsClient::from_settings(settings, get_config_manager().load_voices())))
+}
+
+/// 离线回声模式：不需要 API Key，回复由本地确定性生成
+fn run_offline(
+    conversation_id: &str,
+    content: Option<&str>,
+    settings: &AppSettings,
+    sink: &crate::frb_generated::StreamSink<ChatStreamEvent>,
+) {
+    let engine = ChatEngine::new_offline(get_data_path())
+        .with_settings(settings.clone())
+        .watch_settings(get_config_manager().subscribe());
+    if let Err(e) = engine.respond_offline(conversation_id, content, |event| {
+        let _ = sink.add(event);
+    }) {
+        let _ = sink.add(ChatStreamEvent::Error(e.to_string()));
+        let _ = sink.add(ChatStreamEvent::Done);
+    }
 }
 
 pub async fn send_message(
@@ -223,29 +1533,116 @@ pub async fn send_message(
     content: String,
     model: String,
     enable_thinking: bool,
+    ambient: Option<AmbientContext>,
+    sink: crate::frb_generated::StreamSink<ChatStreamEvent>,
+) {
+    run_send(&conversation_id, &content, Vec::new(), &model, enable_thinking, ambient, &sink).await;
+}
+
+/// 发送带图片的消息：images 的 url 为 data URL（本地图片）或 http(s) 地址。
+/// 本轮由视觉模型识图并回复；离线模式下忽略图片
+pub async fn send_message_with_images(
+    conversation_id: String,
+    content: String,
+    images: Vec<MessageAttachment>,
+    model: String,
+    enable_thinking: bool,
+    ambient: Option<AmbientContext>,
     sink: crate::frb_generated::StreamSink<ChatStreamEvent>,
 ) {
+    run_send(&conversation_id, &content, images, &model, enable_thinking, ambient, &sink).await;
+}
+
+async fn run_send(
+    conversation_id: &str,
+    content: &str,
+    images: Vec<MessageAttachment>,
+    model: &str,
+    enable_thinking: bool,
+    ambient: Option<AmbientContext>,
+    sink: &crate::frb_generated::StreamSink<ChatStreamEvent>,
+) {
+    let Some(_turn) = claim_turn(conversation_id, sink) else {
+        return;
+    };
     let settings = get_config_manager().load_settings();
-    let api_key = match settings.api_key.clone() {
-        Some(key) => key,
-        None => {
-            let _ = sink.add(ChatStreamEvent::Error(
-                "未配置 API Key，请在设置中填写您的智谱 API Key".to_string(),
-            ));
+    if settings.provider == ProviderKind::LocalEcho {
+        run_offline(conversation_id, Some(content), &settings, sink);
+        return;
+    }
+    if still_offline(&settings).await {
+        // 离线：消息进入发件箱，网络恢复后由 watch_outbox 重放
+        match get_conversation_store().enqueue_outbox(
+            conversation_id,
+            content,
+            images,
+            model,
+            enable_thinking,
+        ) {
+            Ok(entry) => {
+                let _ = sink.add(ChatStreamEvent::Outbox(entry));
+            }
+            Err(e) => {
+                let _ = sink.add(ChatStreamEvent::Error(e.to_string()));
+            }
+        }
+        let _ = sink.add(ChatStreamEvent::Done);
+        return;
+    }
+    let _ = send_now(conversation_id, content, images, model, enable_thinking, ambient, &settings, sink)
+        .await;
+}
+
+/// 取得对话的生成锁；同一对话已有回复在生成（连点发送、生成中重新生成）时
+/// 推送 Error + Done 拒绝本次请求
+fn claim_turn(
+    conversation_id: &str,
+    sink: &crate::frb_generated::StreamSink<ChatStreamEvent>,
+) -> Option<TurnGuard> {
+    match ConversationStore::try_lock_turn(conversation_id) {
+        Ok(guard) => Some(guard),
+        Err(e) => {
+            let _ = sink.add(ChatStreamEvent::Error(e.to_string()));
             let _ = sink.add(ChatStreamEvent::Done);
-            return;
+            None
         }
-    };
+    }
+}
 
-    let chat_model = resolve_chat_model(&model, &settings);
-    let thinking_model = resolve_thinking_model(&settings);
+/// 已知离线时先探测一次：网络其实已恢复就直接发送
+async fn still_offline(settings: &AppSettings) -> bool {
+    if !network_adaptation::is_offline() {
+        return false;
+    }
+    match chat_provider::from_settings(settings) {
+        Ok(provider) => !network_adaptation::probe(&provider.endpoint()).await,
+        Err(_) => false,
+    }
+}
 
-    let engine = match ChatEngine::new(&api_key, get_data_path()) {
-        Ok(e) => e,
+/// 在线发送并把事件推给 sink；返回未能生成回复时上报给用户的错误
+#[allow(clippy::too_many_arguments)]
+async fn send_now(
+    conversation_id: &str,
+    content: &str,
+    images: Vec<MessageAttachment>,
+    model: &str,
+    enable_thinking: bool,
+    ambient: Option<AmbientContext>,
+    settings: &AppSettings,
+    sink: &crate::frb_generated::StreamSink<ChatStreamEvent>,
+) -> Result<(), String> {
+    let chat_model = resolve_chat_model(model, settings);
+    let thinking_model = resolve_thinking_model(settings);
+
+    let engine = match build_online_engine(settings) {
+        Ok(e) => e
+            .with_settings(settings.clone())
+            .watch_settings(get_config_manager().subscribe()),
         Err(err) => {
-            let _ = sink.add(ChatStreamEvent::Error(err));
+            let _ = sink.add(ChatStreamEvent::Error(err.clone()));
             let _ = sink.add(ChatStreamEvent::Done);
-            return;
+            return Err(err);
         }
     };
 
@@ -256,11 +1653,13 @@ pub async fn send_message(
     let pipeline_result = tokio::time::timeout(
         std::time::Duration::from_secs(300),
         engine.send_message(
-            &conversation_id,
-            &content,
+            conversation_id,
+            content,
+            images,
             &chat_model,
             &thinking_model,
             enable_thinking,
+            ambient.as_ref(),
             |event| {
                 if let ChatStreamEvent::Done = &event {
                     done_sent.store(true, std::sync::atomic::Ordering::Release);
@@ -273,53 +1672,399 @@ pub async fn send_message(
 
     // 仅在 Done 未发送时报错：Done 已发送说明回复已成功生成并保存，
     // 后续步骤（如事实提取）超时不应覆盖成功状态
-    match pipeline_result {
-        Ok(Ok(())) => {}
-        Ok(Err(e)) => {
-            if !done_sent.load(std::sync::atomic::Ordering::Acquire) {
-                let _ = sink.add(ChatStreamEvent::Error(e.to_string()));
+    let error = match pipeline_result {
+        Ok(Ok(())) => None,
+        Ok(Err(e)) => Some(e.to_string()),
+        Err(_timeout) => Some("处理超时（5分钟），请缩短对话或重试".to_string()),
+    };
+    let outcome = if done_sent.load(std::sync::atomic::Ordering::Acquire) {
+        Ok(())
+    } else {
+        if let Some(error) = &error {
+            let _ = sink.add(ChatStreamEvent::Error(error.clone()));
+        }
+        let _ = sink.add(ChatStreamEvent::Done);
+        error.map_or(Ok(()), Err)
+    };
+
+    // 给 FRB 事件队列留出刷新时间，确保 Done 事件在流关闭前送达 Dart
+    tokio::time::sleep(std::time::Duration::from_millis(300)).await;
+    outcome
+}
+
+/// 离线发件箱的探测间隔：离线且有待发消息时按此间隔探测网络是否恢复
+const OUTBOX_PROBE_INTERVAL_SECS: u64 = 15;
+
+/// 当前有效的发件箱监听（新的监听取代旧的，避免重复重放）
+static OUTBOX_WATCHER: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+
+/// 监听离线发件箱：网络恢复（请求成功、探测成功或 set_network_available(true)）后
+/// 按入队顺序重放待发消息。每条重放先推送 Outbox(状态 Sending)，随后是与
+/// send_message 相同的流事件直到 Done；失败时推送 Outbox(状态 Failed)。
+/// App 启动后调用一次，流保持打开；再次调用会取代之前的监听
+pub async fn watch_outbox(sink: crate::frb_generated::StreamSink<ChatStreamEvent>) {
+    let generation = OUTBOX_WATCHER.fetch_add(1, std::sync::atomic::Ordering::AcqRel) + 1;
+    let is_current =
+        || OUTBOX_WATCHER.load(std::sync::atomic::Ordering::Acquire) == generation;
+    while is_current() {
+        if !network_adaptation::is_offline() && replay_outbox(&sink).await.is_err() {
+            // Dart 侧已关闭流
+            return;
+        }
+        let has_queued = get_conversation_store()
+            .list_outbox()
+            .iter()
+            .any(|e| e.status == OutboxStatus::Queued);
+        tokio::select! {
+            _ = network_adaptation::wait_online() => {}
+            _ = tokio::time::sleep(std::time::Duration::from_secs(OUTBOX_PROBE_INTERVAL_SECS)) => {
+                if has_queued && network_adaptation::is_offline() {
+                    let settings = get_config_manager().load_settings();
+                    if let Ok(provider) = chat_provider::from_settings(&settings) {
+                        network_adaptation::probe(&provider.endpoint()).await;
+                    }
+                }
             }
         }
-        Err(_timeout) => {
-            if !done_sent.load(std::sync::atomic::Ordering::Acquire) {
-                let _ = sink.add(ChatStreamEvent::Error(
-                    "处理超时（5分钟），请缩短对话或重试".to_string(),
-                ));
+    }
+}
+
+/// 按入队顺序重放 Queued 条目；再次离线时停下，剩余条目留待下次恢复。
+/// 返回 Err 表示 sink 已关闭
+async fn replay_outbox(
+    sink: &crate::frb_generated::StreamSink<ChatStreamEvent>,
+) -> Result<(), ()> {
+    let store = get_conversation_store();
+    for mut entry in store.list_outbox() {
+        if entry.status != OutboxStatus::Queued {
+            continue;
+        }
+        if network_adaptation::is_offline() {
+            break;
+        }
+        entry.status = OutboxStatus::Sending;
+        let _ = store.save_outbox_entry(&entry);
+        if sink.add(ChatStreamEvent::Outbox(entry.clone())).is_err() {
+            entry.status = OutboxStatus::Queued;
+            let _ = store.save_outbox_entry(&entry);
+            return Err(());
+        }
+        // 用户正在该对话里发送时排队等候，不与之交错
+        let _turn = ConversationStore::lock_turn(&entry.conversation_id).await;
+        let settings = get_config_manager().load_settings();
+        let result = send_now(
+            &entry.conversation_id,
+            &entry.content,
+            entry.attachments.clone(),
+            &entry.model,
+            entry.enable_thinking,
+            None,
+            &settings,
+            sink,
+        )
+        .await;
+        match result {
+            Ok(()) => {
+                if let Err(e) = store.remove_outbox_entry(&entry.id) {
+                    tracing::warn!(entry_id = %entry.id, error = %e, "发件箱条目移除失败");
+                }
+            }
+            Err(error) => {
+                // 用户消息可能已写入对话，不再自动重试，交给用户处理
+                tracing::warn!(conversation_id = %entry.conversation_id, error = %error, "发件箱重放失败");
+                entry.status = OutboxStatus::Failed;
+                entry.last_error = Some(error);
+                let _ = store.save_outbox_entry(&entry);
+                let _ = sink.add(ChatStreamEvent::Outbox(entry));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// 发件箱中的全部条目（按入队先后）
+pub fn get_outbox() -> Vec<OutboxEntry> {
+    get_conversation_store().list_outbox()
+}
+
+/// 放弃一条待发 / 失败的离线消息
+pub fn discard_outbox_entry(entry_id: String) -> bool {
+    get_conversation_store().remove_outbox_entry(&entry_id).is_ok()
+}
+
+/// 宿主 App 上报系统网络状态：可用时立即触发发件箱重放，不可用时新消息直接入队
+pub fn set_network_available(available: bool) {
+    if available {
+        network_adaptation::mark_online();
+    } else {
+        network_adaptation::mark_offline();
+    }
+}
+
+/// 对话的主动联系设置；未设置时为 None
+pub fn get_check_in_schedule(conversation_id: String) -> Option<CheckInSchedule> {
+    get_conversation_store().load_check_in_schedule(&conversation_id)
+}
+
+/// 开启 / 修改对话的主动联系：对方沉默 silence_hours 小时后角色主动发一条消息。
+/// 群聊、沙盒对话与 0 小时无效，返回 false
+pub fn set_check_in_schedule(schedule: CheckInSchedule) -> bool {
+    if GroupChatStore::new(get_data_path())
+        .load(&schedule.conversation_id)
+        .is_some()
+    {
+        return false;
+    }
+    get_conversation_store()
+        .set_check_in_schedule(&schedule)
+        .is_ok()
+}
+
+/// 当前有效的主动联系监听（新的监听取代旧的，避免重复发送）
+static CHECK_IN_WATCHER: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+
+/// 监听角色主动联系：开启了主动联系的对话到期时（见 check_ins），角色不等用户
+/// 消息直接发一条。每条先推送 CheckIn(对话 ID)，随后是该条回复的正常流事件直到 Done。
+/// 离线或离线回声模式下不生成，等恢复后再发。App 启动后调用一次，流保持打开；
+/// 再次调用会取代之前的监听
+pub async fn watch_check_ins(sink: crate::frb_generated::StreamSink<ChatStreamEvent>) {
+    let generation = CHECK_IN_WATCHER.fetch_add(1, std::sync::atomic::Ordering::AcqRel) + 1;
+    let is_current =
+        || CHECK_IN_WATCHER.load(std::sync::atomic::Ordering::Acquire) == generation;
+    while is_current() {
+        let now = chrono::Utc::now().timestamp_millis();
+        let store = get_conversation_store();
+        let settings = get_config_manager().load_settings();
+        let can_send =
+            settings.provider != ProviderKind::LocalEcho && !network_adaptation::is_offline();
+        let mut next_due: Option<i64> = None;
+        for schedule in store.list_check_in_schedules() {
+            let Ok(conv) = store.load_active_branch(&schedule.conversation_id) else {
+                continue;
+            };
+            let Some(due) = CheckInPlanner::due_at(&schedule, &conv) else {
+                continue;
+            };
+            if due <= now && can_send {
+                // 失败的对话不计入下次醒来时间，最迟 MAX_SLEEP_SECS 后重试
+                if run_check_in(&schedule.conversation_id, &settings, &sink).await.is_err() {
+                    // Dart 侧已关闭流
+                    return;
+                }
+            } else {
+                next_due = Some(next_due.map_or(due, |n| n.min(due)));
             }
         }
+        let secs = CheckInPlanner::sleep_secs(next_due, now);
+        tokio::time::sleep(std::time::Duration::from_secs(secs)).await;
     }
+}
 
+/// 生成一条主动联系并推给 sink；返回 Err 表示 sink 已关闭
+async fn run_check_in(
+    conversation_id: &str,
+    settings: &AppSettings,
+    sink: &crate::frb_generated::StreamSink<ChatStreamEvent>,
+) -> Result<(), ()> {
+    // 用户正在该对话里聊天：本次不主动联系，下一轮检查时按新的沉默时长重新计算
+    let Ok(_turn) = ConversationStore::try_lock_turn(conversation_id) else {
+        return Ok(());
+    };
+    sink.add(ChatStreamEvent::CheckIn(conversation_id.to_string()))
+        .map_err(|_| ())?;
+    let engine = match build_online_engine(settings) {
+        Ok(e) => e
+            .with_settings(settings.clone())
+            .watch_settings(get_config_manager().subscribe()),
+        Err(err) => {
+            let _ = sink.add(ChatStreamEvent::Error(err));
+            let _ = sink.add(ChatStreamEvent::Done);
+            return Ok(());
+        }
+    };
+    let done_sent = std::sync::atomic::AtomicBool::new(false);
+    let result = tokio::time::timeout(
+        std::time::Duration::from_secs(300),
+        engine.send_check_in(conversation_id, &settings.chat_model, |event| {
+            if let ChatStreamEvent::Done = &event {
+                done_sent.store(true, std::sync::atomic::Ordering::Release);
+            }
+            let _ = sink.add(event);
+        }),
+    )
+    .await;
     if !done_sent.load(std::sync::atomic::Ordering::Acquire) {
+        let error = match result {
+            Ok(Err(e)) => e.to_string(),
+            _ => "主动联系生成超时".to_string(),
+        };
+        tracing::warn!(conversation_id, error = %error, "主动联系生成失败");
+        let _ = sink.add(ChatStreamEvent::Error(error));
         let _ = sink.add(ChatStreamEvent::Done);
     }
+    Ok(())
+}
 
-    // 给 FRB 事件队列留出刷新时间，确保 Done 事件在流关闭前送达 Dart
-    tokio::time::sleep(std::time::Duration::from_millis(300)).await;
+/// 切换回复显示的版本（重新生成的每一版都会保留在 Message::alternatives 中）；
+/// 返回切换后的消息，消息不存在或序号越界时返回 None
+pub fn select_alternative(
+    conversation_id: String,
+    message_id: String,
+    index: u32,
+) -> Option<Message> {
+    let Ok(_turn) = ConversationStore::try_lock_turn(&conversation_id) else {
+        return None;
+    };
+    get_conversation_store()
+        .select_alternative(&conversation_id, &message_id, index)
+        .ok()
+}
+
+/// 收藏 / 取消收藏一条消息（收藏的时刻在长期记忆检索中加权）；消息不存在时返回 None
+pub fn set_message_starred(
+    conversation_id: String,
+    message_id: String,
+    starred: bool,
+) -> Option<Message> {
+    let Ok(_turn) = ConversationStore::try_lock_turn(&conversation_id) else {
+        return None;
+    };
+    get_conversation_store()
+        .set_message_starred(&conversation_id, &message_id, starred)
+        .ok()
+}
+
+/// 替换一条消息的表情回应（传空列表清除）
+pub fn set_message_reactions(
+    conversation_id: String,
+    message_id: String,
+    reactions: Vec<String>,
+) -> Result<Message, String> {
+    let _turn = ConversationStore::try_lock_turn(&conversation_id).map_err(|e| e.to_string())?;
+    get_conversation_store()
+        .set_message_reactions(&conversation_id, &message_id, &reactions)
+        .map_err(|e| e.to_string())
+}
+
+/// 对话中收藏的消息（按对话顺序）
+pub fn list_starred_messages(conversation_id: String) -> Vec<Message> {
+    get_conversation_store()
+        .load_conversation(&conversation_id)
+        .map(|conv| conv.messages.into_iter().filter(|m| m.starred).collect())
+        .unwrap_or_default()
 }
 
+/// 重新生成最后一轮回复：末尾已有回复时新回复作为它的另一个版本追加
+/// （旧版本可用 select_alternative 切回），否则作为新回复追加
 pub async fn regenerate_response(
     conversation_id: String,
     model: String,
     enable_thinking: bool,
     sink: crate::frb_generated::StreamSink<ChatStreamEvent>,
 ) {
+    let Some(_turn) = claim_turn(&conversation_id, &sink) else {
+        return;
+    };
+    run_regeneration(&conversation_id, &model, enable_thinking, &sink).await;
+}
+
+/// 从任意消息重新生成：截断（as_branch=false）或分叉（as_branch=true）到
+/// 该消息所属的用户输入，然后基于截断后的历史与记忆重跑完整管线
+pub async fn regenerate_from(
+    conversation_id: String,
+    message_id: String,
+    as_branch: bool,
+    model: String,
+    enable_thinking: bool,
+    sink: crate::frb_generated::StreamSink<ChatStreamEvent>,
+) {
+    let Some(_turn) = claim_turn(&conversation_id, &sink) else {
+        return;
+    };
+    let conv = match get_conversation_store().prepare_regeneration(
+        &conversation_id,
+        &message_id,
+        as_branch,
+    ) {
+        Ok(conv) => conv,
+        Err(e) => {
+            let _ = sink.add(ChatStreamEvent::Error(e.to_string()));
+            let _ = sink.add(ChatStreamEvent::Done);
+            return;
+        }
+    };
+    sync_timeline_memory(&conv);
+    if !as_branch && !ConversationStore::is_sandbox(&conversation_id) {
+        // 就地截断后，被丢弃轮次里提取的事实不再成立（分支共享知识库，不清理）
+        if let Err(e) = KnowledgeStore::new(get_data_path())
+            .prune_after_turn(&conversation_id, conv.turn_count)
+        {
+            let _ = sink.add(ChatStreamEvent::Error(e.to_string()));
+            let _ = sink.add(ChatStreamEvent::Done);
+            return;
+        }
+    }
+    run_regeneration(&conversation_id, &model, enable_thinking, &sink).await;
+}
+
+/// 为当前场景生成插画（同 /draw）：hint 为可选的画面补充描述。
+/// 成功时推送 Illustration 事件（插画消息已写入对话），失败时以 SystemNotice 提示
+pub async fn illustrate_scene(
+    conversation_id: String,
+    hint: Option<String>,
+    model: String,
+    sink: crate::frb_generated::StreamSink<ChatStreamEvent>,
+) {
+    let Some(_turn) = claim_turn(&conversation_id, &sink) else {
+        return;
+    };
     let settings = get_config_manager().load_settings();
-    let api_key = match settings.api_key.clone() {
-        Some(key) => key,
-        None => {
-            let _ = sink.add(ChatStreamEvent::Error(
-                "未配置 API Key，请在设置中填写您的智谱 API Key".to_string(),
+    let engine = match build_online_engine(&settings) {
+        Ok(e) if settings.provider != ProviderKind::LocalEcho => e.with_settings(settings.clone()),
+        _ => {
+            let _ = sink.add(ChatStreamEvent::SystemNotice(
+                "当前服务商不支持插画生成，需要使用智谱 API Key".to_string(),
             ));
             let _ = sink.add(ChatStreamEvent::Done);
             return;
         }
     };
-
     let chat_model = resolve_chat_model(&model, &settings);
+    // 引擎在所有正常路径上自行发送 Done；只有存储错误提前返回时需要补发
+    let result = engine
+        .illustrate_scene(
+            &conversation_id,
+            hint.as_deref().unwrap_or(""),
+            &chat_model,
+            |event| {
+                let _ = sink.add(event);
+            },
+        )
+        .await;
+    if let Err(e) = result {
+        let _ = sink.add(ChatStreamEvent::Error(e.to_string()));
+        let _ = sink.add(ChatStreamEvent::Done);
+    }
+}
+
+async fn run_regeneration(
+    conversation_id: &str,
+    model: &str,
+    enable_thinking: bool,
+    sink: &crate::frb_generated::StreamSink<ChatStreamEvent>,
+) {
+    let settings = get_config_manager().load_settings();
+    if settings.provider == ProviderKind::LocalEcho {
+        run_offline(conversation_id, None, &settings, sink);
+        return;
+    }
+    let chat_model = resolve_chat_model(model, &settings);
     let thinking_model = resolve_thinking_model(&settings);
 
-    let engine = match ChatEngine::new(&api_key, get_data_path()) {
-        Ok(e) => e,
+    let engine = match build_online_engine(&settings) {
+        Ok(e) => e
+            .with_settings(settings.clone())
+            .watch_settings(get_config_manager().subscribe()),
         Err(err) => {
             let _ = sink.add(ChatStreamEvent::Error(err));
             let _ = sink.add(ChatStreamEvent::Done);
@@ -332,7 +2077,7 @@ pub async fn regenerate_response(
     let pipeline_result = tokio::time::timeout(
         std::time::Duration::from_secs(300),
         engine.regenerate_response(
-            &conversation_id,
+            conversation_id,
             &chat_model,
             &thinking_model,
             enable_thinking,
@@ -369,24 +2114,191 @@ pub async fn regenerate_response(
     tokio::time::sleep(std::time::Duration::from_millis(300)).await;
 }
 
+/// 每日心声：过去 24 小时内活跃的每个角色以自己的口吻写一句话，汇总返回。
+/// 并发与花费均有上限；未配置在线提供方时返回 None
+pub async fn generate_daily_digest() -> Option<DailyDigest> {
+    let settings = get_config_manager().load_settings();
+    let provider = chat_provider::from_settings(&settings).ok()?;
+    let generator = DailyDigestGenerator::new(provider.as_ref(), get_data_path());
+    Some(generator.generate(chrono::Utc::now().timestamp_millis()).await)
+}
+
+/// 久别重逢：对话沉寂超过一周时，返回前情提要与建议的角色开场白。
+/// 每个对话只生成一次，直到有新消息才重新生成；最近还在聊时返回 None
+pub async fn prepare_reengagement(conversation_id: String) -> Option<ReengagementBrief> {
+    let settings = get_config_manager().load_settings();
+    let provider = chat_provider::from_settings(&settings).ok();
+    let generator = ReengagementGenerator::new(provider.as_deref(), get_data_path());
+    generator
+        .prepare(&conversation_id, chrono::Utc::now().timestamp_millis())
+        .await
+        .ok()
+        .flatten()
+}
+
+/// 宿主 App 上报设备状态（电量、充电、省电模式、计费网络），
+/// 后台任务据此决定是否延后；状态变化时调用即可
+pub fn set_device_conditions(conditions: DeviceConditions) {
+    JobScheduler::global().set_conditions(conditions);
+}
+
+/// 调试面板：最近各轮回复的首字延迟、分阶段耗时与重试次数
+pub fn get_metrics() -> MetricsSnapshot {
+    metrics::snapshot()
+}
+
+/// 清空性能指标记录
+pub fn clear_metrics() {
+    metrics::clear();
+}
+
+/// 导出最近的日志（纯文本，每行一条），供用户反馈问题时附上；limit 为 0 时导出全部
+pub fn export_logs(limit: u32) -> String {
+    log_store::export(limit as usize)
+}
+
+/// 清空日志缓冲区
+pub fn clear_logs() {
+    log_store::clear();
+}
+
+/// 后台任务调度统计（执行中、超时、延后与待补跑的任务）
+pub fn get_job_scheduler_stats() -> JobSchedulerStats {
+    JobScheduler::global().stats()
+}
+
+/// 订阅后台任务（事实提取、记忆摘要）的状态变化；send_message 在回复落盘后即返回，
+/// 这些任务随后在后台执行
+pub async fn watch_background_tasks(sink: crate::frb_generated::StreamSink<BackgroundTaskEvent>) {
+    let mut events = BackgroundTasks::global().subscribe();
+    loop {
+        match events.recv().await {
+            Ok(event) => {
+                if sink.add(event).is_err() {
+                    return;
+                }
+            }
+            Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => continue,
+            Err(tokio::sync::broadcast::error::RecvError::Closed) => return,
+        }
+    }
+}
+
+/// 排队或执行中的后台任务
+pub fn list_background_tasks() -> Vec<BackgroundTaskEvent> {
+    BackgroundTasks::global().active()
+}
+
+/// 补跑因设备压力或配额被延后的后台任务（建议在开始充电或回到前台时调用）；
+/// 当前条件下仍不能执行的继续等待。返回补跑成功的任务数
+pub async fn run_deferred_jobs() -> u32 {
+    let settings = get_config_manager().load_settings();
+    let engine = match build_online_engine(&settings) {
+        Ok(e) => e.with_settings(settings),
+        Err(_) => return 0,
+    };
+    let jobs = JobScheduler::global().take_runnable(chrono::Utc::now().timestamp_millis());
+    let mut done = 0;
+    for job in &jobs {
+        let _turn = ConversationStore::lock_turn(&job.conversation_id).await;
+        if engine.run_deferred_job(job).await {
+            done += 1;
+        }
+    }
+    done
+}
+
 pub async fn trigger_memory_summarize(
     conversation_id: String,
     sink: crate::frb_generated::StreamSink<ChatStreamEvent>,
 ) {
     let settings = get_config_manager().load_settings();
-    let api_key = match settings.api_key {
-        Some(key) => key,
-        None => return,
-    };
-
-    let engine = match ChatEngine::new(&api_key, get_data_path()) {
+    let engine = match build_online_engine(&settings) {
         Ok(e) => e,
         Err(_) => return,
     };
+    // 摘要读取消息区间后再写回，排在进行中的回复之后
+    let _turn = ConversationStore::lock_turn(&conversation_id).await;
 
-    let _ = engine
-        .summarize_memory(&conversation_id, |event| {
-            let _ = sink.add(event);
-        })
+    // 设备压力或配额不足时延后，条件恢复后由 run_deferred_jobs 补跑
+    let summarized = JobScheduler::global()
+        .run(
+            BackgroundJobKind::Summarization,
+            &conversation_id,
+            engine.summarize_memory(&conversation_id, |event| {
+                let _ = sink.add(event);
+            }),
+        )
         .await;
+    if summarized.is_none() {
+        return;
+    }
+    after_memory_summarized(&conversation_id);
+}
+
+/// 摘要写入后的收尾：思考内容保留策略与保真度审计
+fn after_memory_summarized(conversation_id: &str) {
+    // 新摘要可能让「摘要后丢弃」策略下的思考内容到期
+    let _ = get_conversation_store().apply_thinking_retention(conversation_id);
+
+    // 摘要可能触发了分级合并，顺带审计一次保真度
+    if let Some(report) = audit_memory_fidelity(conversation_id.to_string()) {
+        if report.alert {
+            tracing::warn!(
+                conversation_id,
+                score = report.score,
+                lost_facts = report.lost_facts.len(),
+                "记忆保真度审计告警"
+            );
+        }
+    }
+}
+
+/// 手动摘要：立即总结最近的对话，以 SummaryPreview 事件返回草稿（其后为 Done）；
+/// 草稿不会写入记忆，需调用 approve_memory_summary 确认，或 discard_memory_summary 丢弃
+pub async fn preview_memory_summary(
+    conversation_id: String,
+    sink: crate::frb_generated::StreamSink<ChatStreamEvent>,
+) {
+    let settings = get_config_manager().load_settings();
+    let result = match build_online_engine(&settings) {
+        Ok(engine) => engine
+            .preview_summary(&conversation_id)
+            .await
+            .map_err(|e| e.to_string()),
+        Err(err) => Err(err),
+    };
+    let event = match result {
+        Ok(Some(draft)) => ChatStreamEvent::SummaryPreview(draft),
+        Ok(None) => ChatStreamEvent::Error("没有可以总结的对话内容".to_string()),
+        Err(err) => ChatStreamEvent::Error(err),
+    };
+    let _ = sink.add(event);
+    let _ = sink.add(ChatStreamEvent::Done);
+}
+
+/// 对话当前待确认的手动摘要草稿
+pub fn get_pending_memory_summary(conversation_id: String) -> Option<MemorySummary> {
+    MemoryEngine::summary_draft(&conversation_id)
+}
+
+/// 确认手动摘要的草稿并写入长期记忆，返回写入后的摘要
+pub async fn approve_memory_summary(
+    conversation_id: String,
+    summary_id: String,
+) -> Result<MemorySummary, String> {
+    let _turn = ConversationStore::try_lock_turn(&conversation_id).map_err(|e| e.to_string())?;
+    let settings = get_config_manager().load_settings();
+    let engine = build_online_engine(&settings)?;
+    let memory = engine
+        .approve_summary_draft(&conversation_id, &summary_id)
+        .await
+        .map_err(|e| e.to_string())?;
+    after_memory_summarized(&conversation_id);
+    Ok(memory)
+}
+
+/// 丢弃手动摘要的草稿；没有草稿时返回 false
+pub fn discard_memory_summary(conversation_id: String) -> bool {
+    MemoryEngine::discard_summary_draft(&conversation_id)
 }
@@ -1,29 +1,246 @@
-﻿use super::cognitive_engine::CognitiveEngine;
+﻿use super::ambient_context::AmbientContextFilter;
+use super::attachments::{self, AttachmentStore};
+use super::background_tasks::BackgroundTasks;
+use super::blocking_pool;
+use super::check_ins::CheckInPlanner;
+use super::closure::{self, ArchiveStore};
+use super::chat_provider::{ChatProvider, ZhipuProvider};
+use super::coauthor_engine::CoAuthorEngine;
+use super::cognitive_engine::CognitiveEngine;
+use super::context_cache::{ContextCache, PrefixLayers};
 use super::conversation_store::ConversationStore;
+use super::config_manager::{ConfigManager, ModelRegistry, ThinkingField};
 use super::data_models::*;
+use super::decision_log::DecisionLog;
+use super::embedding::{EmbeddingBackend, EmbeddingStore, SemanticQuery};
 use super::error_handler::ChatError;
-use super::jwt_auth::JwtAuth;
+use super::fidelity_audit::FidelityAuditor;
+use super::intensity_dial::IntensityDial;
+use super::job_scheduler::JobScheduler;
 use super::knowledge_store::{FactCategory, KnowledgeStore};
+use super::local_responder::{LocalResponder, LOCAL_MODEL_ID};
 use super::memory_engine::MemoryEngine;
-use super::saydo_detector::SayDoDetector;
+use super::metrics::{self, PhaseTimer};
+use super::mood::MoodStore;
+use super::narration::NarrationGuard;
+use super::personas::PersonaStore;
+use super::prompt_templates::{self, TemplateVars};
+use super::quick_commands::QuickCommand;
+use super::safety_filter::{self, SafetyFilter};
+use super::saydo_detector::{SayDoDetector, SayDoRuleStore};
+use super::shadow_eval::{self, ShadowEvalStore};
 use super::streaming_handler::StreamingHandler;
-use std::collections::hash_map::DefaultHasher;
-use std::hash::{Hash, Hasher};
-
-const BIGMODEL_API_URL: &str = "https://open.bigmodel.cn/api/paas/v4/chat/completions";
+use super::topic_blocks::BlockedTopicStore;
+use super::tokenizer;
+use super::tts::{AudioStore, TtsClient};
+use super::lorebook::LorebookStore;
+use super::group_chat::GroupChatStore;
+use super::housekeeping::Housekeeper;
+use super::illustration::{self, CogViewClient};
+use super::web_search::WebSearchGate;
+use super::text_utils;
+use super::time_awareness::TimeAwareness;
+use tokio::sync::broadcast;
 
 const REASONING_TIMEOUT_SECS: u64 = 90;
 const DISTILLATION_TIMEOUT_SECS: u64 = 120;
 const FACT_EXTRACTION_TIMEOUT_SECS: u64 = 60;
+/// /continue 在聊天模式下写入的用户消息（以动作示意对方接着说）
+const CONTINUE_NUDGE: &str = "（示意你接着说下去）";
+/// 回复骨架规划的时限：超时直接跳过，不拖慢首字
+const SKELETON_TIMEOUT_MS: u64 = 1000;
+/// 回复骨架规划的输出上限
+const SKELETON_MAX_TOKENS: u32 = 100;
+/// 群聊由模型挑选发言者的时限：超时按轮流顺序发言
+const SPEAKER_CHOICE_TIMEOUT_MS: u64 = 1500;
+/// 挑选发言者的输出上限（只需要一个名字）
+const SPEAKER_CHOICE_MAX_TOKENS: u32 = 16;
+
+/// 长文共写模式的输出 token 下限（受模型最大输出约束）
+const LONG_FORM_MIN_OUTPUT_TOKENS: u32 = 8192;
+
+/// 每检测到一种回复模式固化，frequency_penalty 增加的幅度
+const PENALTY_STEP_PER_PATTERN: f64 = 0.3;
+/// frequency_penalty 上限（OpenAI 兼容参数范围 -2.0 ~ 2.0，取保守值）
+const MAX_FREQUENCY_PENALTY: f64 = 1.2;
+
+/// 单次请求的生成参数微调（随对话模式/状态变化）
+#[derive(Debug, Clone, Default)]
+pub struct RequestTuning {
+    /// 长文共写：抬高 max_tokens 下限，避免章节被截断
+    pub long_form: bool,
+    /// 反重复采样惩罚：0.0 表示不发送该字段
+    pub frequency_penalty: f64,
+    pub presence_penalty: f64,
+    /// 本轮挂载联网搜索工具
+    pub web_search: bool,
+}
+
+/// 快速草稿使用的模型与附加指令
+const DRAFT_MODEL: &str = "glm-4.7-flash";
+const DRAFT_PROMPT: &str = "【快速回应】先用一两句话简短、自然地回应对方，保持角色口吻与语气；\
+                            不展开情节，不下定论，稍后会有更完整的回复。";
+
+/// 离线引擎的占位密钥（格式合法，但不会用于任何请求）
+const OFFLINE_PLACEHOLDER_KEY: &str = "offline.local";
+
+/// 本轮注入的指令放在最后一条用户消息之前，紧挨着要回应的那句话；没有用户消息时追加到末尾
+fn insert_before_last_user(messages: &mut Vec<Message>, msg: Message) {
+    match messages.iter().rposition(|m| m.role == MessageRole::User) {
+        Some(idx) => messages.insert(idx, msg),
+        None => messages.push(msg),
+    }
+}
 
 pub struct ChatEngine {
-    jwt_auth: std::sync::Mutex<JwtAuth>,
+    /// 在线对话后端（请求改写、鉴权、流解析），见 chat_provider
+    provider: Box<dyn ChatProvider>,
     conversation_store: ConversationStore,
     memory_engine: MemoryEngine,
     knowledge_store: KnowledgeStore,
+    decision_log: DecisionLog,
+    /// 摘要写入前记录事实检查点，供保真度审计抽样
+    fidelity_auditor: FidelityAuditor,
+    /// 向量后端（None 时记忆/事实检索只走词法排序）
+    embedding: Option<EmbeddingBackend>,
+    /// 摘要与事实的向量，按ID存放
+    embedding_store: EmbeddingStore,
+    /// 从用户纠正中学到的回避话题
+    blocked_topics: BlockedTopicStore,
+    /// 角色跨会话延续的心情
+    mood: MoodStore,
+    /// 用户在对话中扮演的人设
+    personas: PersonaStore,
+    /// 对话自定义的 Say/Do 识别规则
+    saydo_rules: SayDoRuleStore,
+    /// 用户编写的世界设定（触发词命中时注入上下文）
+    lorebook: LorebookStore,
+    /// 多角色群聊配置（有配置的对话走群聊管线）
+    group_chats: GroupChatStore,
+    /// 知识注入影子评估记录
+    shadow_eval: ShadowEvalStore,
+    /// 用户发送的图片
+    attachments: AttachmentStore,
+    /// 场景插画后端（None 时 /draw 只返回提示）
+    illustrator: Option<CogViewClient>,
+    /// 语音合成后端（None 时不合成语音）
+    tts: Option<TtsClient>,
+    /// 回复语音文件
+    audio: AudioStore,
+    /// 故事收束的 Markdown 归档
+    archives: ArchiveStore,
+    /// 冷存储（重新总结早期轮次时读取已移出的消息）
+    housekeeper: Housekeeper,
+    /// 对话级配置（记忆摘要节奏等）
+    config: ConfigManager,
+    /// 本轮管线中产生的降级决策，管线结束后按轮次落盘
+    pending_decisions: std::sync::Mutex<Vec<DegradationRecord>>,
+    /// 本轮对话模型最终成功那次请求的生成信息，回复落盘时附到消息上
+    last_generation: std::sync::Mutex<Option<GenerationMetadata>>,
+    /// 当前生效的设置快照（联网搜索开关、内容强度、默认模型等）
+    settings: std::sync::RwLock<AppSettings>,
+    /// 设置变更订阅：每轮开始前取最新一份，无需重建引擎
+    settings_rx: std::sync::Mutex<Option<broadcast::Receiver<AppSettings>>>,
 }
 
 impl ChatEngine {
+    /// 记录一条降级决策（轮次在落盘时补齐）
+    fn record_decision(&self, kind: DegradationKind, model: &str, detail: String) {
+        if let Ok(mut pending) = self.pending_decisions.lock() {
+            pending.push(DegradationRecord {
+                turn: 0,
+                kind,
+                model: model.to_string(),
+                detail,
+                timestamp: chrono::Utc::now().timestamp_millis(),
+            });
+        }
+    }
+
+    /// 记下对话模型成功请求的生成信息
+    fn store_generation(&self, meta: GenerationMetadata) {
+        if let Ok(mut last) = self.last_generation.lock() {
+            *last = Some(meta);
+        }
+    }
+
+    /// 包装事件回调：第一个思考 / 正文 delta 推送时记下首字延迟（见 metrics）
+    fn marking_first_token(on_event: impl Fn(ChatStreamEvent)) -> impl Fn(ChatStreamEvent) {
+        move |event| {
+            if matches!(
+                event,
+                ChatStreamEvent::ContentDelta(_) | ChatStreamEvent::ThinkingDelta(_)
+            ) {
+                metrics::mark_first_token();
+            }
+            on_event(event)
+        }
+    }
+
+    /// 回复落盘后推送生成统计（没有生成信息的回复不推送）
+    fn emit_completed(message: &Message, on_event: &impl Fn(ChatStreamEvent)) {
+        if let Some(metadata) = &message.generation_metadata {
+            on_event(ChatStreamEvent::Completed(GenerationCompletedEvent {
+                message_id: message.id.clone(),
+                metadata: metadata.clone(),
+            }));
+        }
+    }
+
+    /// 取出本轮回复的生成信息，补上本轮的降级说明与耗时（决策在落盘前仍在 pending 中）
+    fn take_generation_metadata(&self, started_at: i64) -> Option<GenerationMetadata> {
+        let mut meta = self.last_generation.lock().ok()?.take()?;
+        if let Ok(pending) = self.pending_decisions.lock() {
+            meta.fallbacks = pending.iter().map(|d| d.detail.clone()).collect();
+        }
+        meta.latency_ms = chrono::Utc::now().timestamp_millis() - started_at;
+        Some(meta)
+    }
+
+    /// 将本轮的降级决策写入决策日志
+    fn flush_decisions(&self, conversation_id: &str) {
+        let mut records: Vec<DegradationRecord> = match self.pending_decisions.lock() {
+            Ok(mut pending) => pending.drain(..).collect(),
+            Err(_) => return,
+        };
+        if records.is_empty() || ConversationStore::is_sandbox(conversation_id) {
+            return;
+        }
+        let turn = self
+            .conversation_store
+            .get_turn_count(conversation_id)
+            .unwrap_or(0);
+        for record in &mut records {
+            record.turn = turn;
+        }
+        if let Err(e) = self.decision_log.append(conversation_id, &records) {
+            tracing::warn!(conversation_id, error = %e, "降级决策日志写入失败");
+        }
+    }
+
+    /// 历史窗口是否因 token 预算被截断：返回 (保留条数, 应保留条数)
+    /// 20 条上限本身是设计行为，不计为截断
+    fn history_truncation(
+        conv: &Conversation,
+        enhanced_messages: &[Message],
+    ) -> Option<(usize, usize)> {
+        let expected = conv
+            .messages
+            .iter()
+            .filter(|m| m.role != MessageRole::System)
+            .count()
+            .min(20);
+        let kept = enhanced_messages
+            .iter()
+            .filter(|m| m.role != MessageRole::System)
+            .count();
+        if kept < expected {
+            Some((kept, expected))
+        } else {
+            None
+        }
+    }
+
     fn build_compact_retry_messages(messages: &[Message], max_non_system: usize) -> Vec<Message> {
         let mut compact: Vec<Message> = Vec::new();
 
@@ -44,18 +261,154 @@ impl ChatEngine {
         compact
     }
 
+    /// 按 token 预算从最早的非 system 消息开始丢弃，至少保留最后一条
+    fn trim_to_token_budget(messages: &[Message], budget: usize) -> Vec<Message> {
+        let mut trimmed = messages.to_vec();
+        while Self::estimate_token_count(&trimmed) > budget {
+            let non_system = trimmed.iter().filter(|m| m.role != MessageRole::System).count();
+            if non_system <= 1 {
+                break;
+            }
+            match trimmed.iter().position(|m| m.role != MessageRole::System) {
+                Some(idx) => {
+                    trimmed.remove(idx);
+                }
+                None => break,
+            }
+        }
+        trimmed
+    }
+
+    /// 服务端报告上下文超长时，按其上限计算保留的历史与输出上限
+    ///
+    /// 本地 token 估算与服务端计数有偏差：有实际请求量时按比例校准，
+    /// 再留 5% 余量；输出上限最多占上下文的四分之一。
+    fn shrink_for_context_limit(
+        messages: &[Message],
+        limit: u32,
+        requested: Option<u32>,
+        max_tokens: u32,
+    ) -> (Vec<Message>, u32) {
+        let output_tokens = max_tokens.min(limit / 4).max(1);
+        let margin = limit / 20;
+        let provider_budget = limit.saturating_sub(output_tokens + margin) as f64;
+
+        let estimate = Self::estimate_token_count(messages).max(1) as f64;
+        let ratio = requested
+            .map(|r| (r as f64 / estimate).clamp(0.5, 4.0))
+            .unwrap_or(1.0);
+        let budget = (provider_budget / ratio) as usize;
+        (Self::trim_to_token_budget(messages, budget), output_tokens)
+    }
+
+    /// 上下文超长时的上限：优先用服务端报告的值，其次模型注册表声明的窗口，
+    /// 再次提供方声明的窗口；都没有时为 None
+    fn context_limit_for(&self, model: &str, reported: Option<u32>) -> Option<u32> {
+        reported.or_else(|| {
+            ModelRegistry::global()
+                .context_tokens(model)
+                .or(self.provider.capabilities().context_window)
+                .map(|window| window.min(u32::MAX as usize) as u32)
+        })
+    }
+
+    /// 提供方声明了上下文窗口时，为输出预留空间后截断历史
+    fn fit_context_window(&self, model: &str, messages: &[Message]) -> Vec<Message> {
+        let caps = self.provider.capabilities();
+        let window = match caps.context_window {
+            Some(window) => window,
+            None => return messages.to_vec(),
+        };
+        let reserved = caps.max_output_tokens.unwrap_or(1024) as usize;
+        let budget = window.saturating_sub(reserved);
+        let fitted = Self::trim_to_token_budget(messages, budget);
+        if fitted.len() < messages.len() {
+            self.record_decision(
+                DegradationKind::ContextTruncated,
+                model,
+                format!(
+                    "上下文窗口 {} token，丢弃最早的 {} 条历史消息",
+                    window,
+                    messages.len() - fitted.len()
+                ),
+            );
+        }
+        fitted
+    }
+
+    /// 提供方不支持思考模式时关闭思考管线，退回单模型对话
+    fn effective_thinking(&self, model: &str, requested: bool) -> bool {
+        if requested && !self.provider.capabilities().supports_thinking {
+            self.record_decision(
+                DegradationKind::ThinkingDropped,
+                model,
+                "当前提供方不支持思考模式，改用单模型对话".to_string(),
+            );
+            return false;
+        }
+        requested
+    }
+
+    /// 经内容安全过滤的对话请求：输入命中 Soften 时在本轮上下文插入谨慎处理提示，
+    /// 回复生成后分类，Block 时清掉已推送的内容并报错，Soften 时遮蔽命中词语
+    async fn request_screened(
+        &self,
+        model: &str,
+        enhanced_messages: &[Message],
+        tuning: &RequestTuning,
+        on_event: &impl Fn(ChatStreamEvent),
+    ) -> Result<(String, String), ChatError> {
+        let policy = safety_filter::policy();
+        if !policy.enabled {
+            return self
+                .request_with_fallback(model, false, enhanced_messages, tuning, on_event)
+                .await;
+        }
+
+        let last_user_idx = enhanced_messages
+            .iter()
+            .rposition(|m| m.role == MessageRole::User);
+        let soften_prompt = last_user_idx.and_then(|idx| {
+            SafetyFilter::build_soften_prompt(&SafetyFilter::screen_input(
+                &policy,
+                &enhanced_messages[idx].content,
+            ))
+        });
+        let mut messages = enhanced_messages.to_vec();
+        if let Some(prompt) = soften_prompt {
+            insert_before_last_user(&mut messages, Message::system_prompt(prompt));
+        }
+
+        let (content, thinking) = self
+            .request_with_fallback(model, false, &messages, tuning, on_event)
+            .await?;
+        let outcome = SafetyFilter::screen_output(&policy, &content);
+        for event in &outcome.events {
+            on_event(ChatStreamEvent::Safety(event.clone()));
+        }
+        if outcome.blocked {
+            on_event(ChatStreamEvent::Error("__RETRY_RESET__".to_string()));
+            return Err(ChatError::ValidationError {
+                message: SafetyFilter::blocked_notice(&outcome.events),
+            });
+        }
+        Ok((outcome.content, thinking))
+    }
+
     async fn request_with_fallback(
         &self,
         model: &str,
         actual_thinking: bool,
         enhanced_messages: &[Message],
+        tuning: &RequestTuning,
         on_event: &impl Fn(ChatStreamEvent),
     ) -> Result<(String, String), ChatError> {
-        let token = {
-            let mut auth = self.jwt_auth.lock().unwrap();
-            auth.get_token()
-        };
-
+        let _timer = PhaseTimer::start(MetricPhase::Chat);
+        if let Ok(mut last) = self.last_generation.lock() {
+            *last = None;
+        }
+        let fitted = self.fit_context_window(model, enhanced_messages);
+        let enhanced_messages = fitted.as_slice();
         let attempt_count = std::sync::atomic::AtomicU32::new(0);
         let need_content_reset = std::sync::atomic::AtomicBool::new(false);
         let intermediate_errors = std::sync::Mutex::new(Vec::<String>::new());
@@ -74,43 +427,113 @@ impl ChatEngine {
             other => on_event(other),
         };
 
-        let request_body = Self::build_request_body(enhanced_messages, model, actual_thinking);
-        match StreamingHandler::stream_chat(BIGMODEL_API_URL, &token, request_body, &filtered_event)
+        let request_body =
+            Self::build_request_body_with(enhanced_messages, model, actual_thinking, tuning);
+        let first_max_tokens = request_body["max_tokens"].as_u64().unwrap_or(0) as u32;
+        match StreamingHandler::stream_chat_with_metadata(self.provider.as_ref(), request_body, &filtered_event)
             .await
         {
-            Ok((content, thinking)) if !content.trim().is_empty() => {
+            Ok((content, thinking, meta)) if !content.trim().is_empty() => {
+                self.store_generation(meta);
                 return Ok((content, thinking));
             }
-            Ok((_, ref thinking)) if actual_thinking && !thinking.trim().is_empty() => {
+            Ok((_, ref thinking, _)) if actual_thinking && !thinking.trim().is_empty() => {
+                self.record_decision(
+                    DegradationKind::ThinkingDropped,
+                    model,
+                    "仅返回思考内容，关闭思考重试".to_string(),
+                );
                 attempt_count.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
                 need_content_reset.store(true, std::sync::atomic::Ordering::Relaxed);
-                let retry_body = Self::build_request_body(enhanced_messages, model, false);
-                match StreamingHandler::stream_chat(
-                    BIGMODEL_API_URL,
-                    &token,
+                let retry_body =
+                    Self::build_request_body_with(enhanced_messages, model, false, tuning);
+                match StreamingHandler::stream_chat_with_metadata(
+                    self.provider.as_ref(),
                     retry_body,
                     &filtered_event,
                 )
                 .await
                 {
-                    Ok((content, thinking)) if !content.trim().is_empty() => {
+                    Ok((content, thinking, meta)) if !content.trim().is_empty() => {
+                        self.store_generation(meta);
                         return Ok((content, thinking));
                     }
                     _ => {}
                 }
             }
             Ok(_) => {}
-            Err(_) => {}
+            // 按上下文上限精确裁剪一次，而不是盲目压缩到最近几条；服务端没给出上限时
+            // （如 GLM 1261「Prompt 超长」）按模型注册表 / 提供方声明的上下文窗口裁剪
+            Err(ChatError::ContextLengthExceeded {
+                limit_tokens,
+                requested_tokens,
+                ..
+            }) => match self.context_limit_for(model, limit_tokens) {
+                Some(limit) => {
+                    // 没有实际请求量时，被拒本身说明请求量至少达到了上限
+                    let requested_tokens = requested_tokens.or_else(|| {
+                        limit_tokens.is_none().then(|| {
+                            limit.max(Self::estimate_token_count(enhanced_messages) as u32)
+                        })
+                    });
+                    let (shrunk, output_tokens) = Self::shrink_for_context_limit(
+                        enhanced_messages,
+                        limit,
+                        requested_tokens,
+                        first_max_tokens,
+                    );
+                    self.record_decision(
+                        DegradationKind::ContextTruncated,
+                        model,
+                        format!(
+                            "{} {} token（本次请求 {}），裁剪 {} 条历史、输出上限 {} 后重试",
+                            if limit_tokens.is_some() { "服务端上下文上限" } else { "模型上下文窗口" },
+                            limit,
+                            requested_tokens.map(|r| r.to_string()).unwrap_or_else(|| "未知".to_string()),
+                            enhanced_messages.len() - shrunk.len(),
+                            output_tokens
+                        ),
+                    );
+                    attempt_count.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                    need_content_reset.store(true, std::sync::atomic::Ordering::Relaxed);
+                    let mut shrunk_body = Self::build_request_body_with(&shrunk, model, false, tuning);
+                    shrunk_body["max_tokens"] = serde_json::json!(output_tokens);
+                    if let Ok((content, thinking, meta)) = StreamingHandler::stream_chat_with_metadata(
+                        self.provider.as_ref(),
+                        shrunk_body,
+                        &filtered_event,
+                    )
+                    .await
+                    {
+                        if !content.trim().is_empty() {
+                            self.store_generation(meta);
+                            return Ok((content, thinking));
+                        }
+                    }
+                }
+                None => {
+                    tracing::warn!(model, "上下文超长且上限未知，改用精简上下文重试");
+                }
+            },
+            Err(e) => {
+                tracing::warn!(model, error = %e, "对话请求失败，改用精简上下文重试");
+            }
         }
 
+        self.record_decision(
+            DegradationKind::CompactRetry,
+            model,
+            "完整上下文未生成内容，压缩至最近 6 条消息重试".to_string(),
+        );
         attempt_count.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
         need_content_reset.store(true, std::sync::atomic::Ordering::Relaxed);
         let compact = Self::build_compact_retry_messages(enhanced_messages, 6);
-        let compact_body = Self::build_request_body(&compact, model, false);
-        match StreamingHandler::stream_chat(BIGMODEL_API_URL, &token, compact_body, &filtered_event)
+        let compact_body = Self::build_request_body_with(&compact, model, false, tuning);
+        match StreamingHandler::stream_chat_with_metadata(self.provider.as_ref(), compact_body, &filtered_event)
             .await
         {
-            Ok((content, thinking)) if !content.trim().is_empty() => {
+            Ok((content, thinking, meta)) if !content.trim().is_empty() => {
+                self.store_generation(meta);
                 return Ok((content, thinking));
             }
             _ => {}
@@ -123,10 +546,19 @@ impl ChatEngine {
         } else {
             model
         };
-        let fallback_body = Self::build_request_body(&ultra_compact, fallback_model, false);
-        match StreamingHandler::stream_chat(BIGMODEL_API_URL, &token, fallback_body, on_event).await
+        self.record_decision(
+            DegradationKind::ModelFallback,
+            fallback_model,
+            format!("{} 压缩重试仍失败，以最近 4 条消息回退到 {}", model, fallback_model),
+        );
+        let fallback_body =
+            Self::build_request_body_with(&ultra_compact, fallback_model, false, tuning);
+        match StreamingHandler::stream_chat_with_metadata(self.provider.as_ref(), fallback_body, on_event).await
         {
-            Ok((content, thinking)) if !content.trim().is_empty() => Ok((content, thinking)),
+            Ok((content, thinking, meta)) if !content.trim().is_empty() => {
+                self.store_generation(meta);
+                Ok((content, thinking))
+            }
             Ok(_) => {
                 let diag = if let Ok(errs) = intermediate_errors.lock() {
                     if errs.is_empty() {
@@ -160,15 +592,24 @@ impl ChatEngine {
         &self,
         thinking_model: &str,
         enhanced_messages: &[Message],
+        prompt_vars: &TemplateVars,
         on_event: &impl Fn(ChatStreamEvent),
     ) -> (String, String) {
+        let _timer = PhaseTimer::start(MetricPhase::Reasoning);
         // 使用 tokio::time::timeout 保护推理调用，防止无限等待
         let result = tokio::time::timeout(
             std::time::Duration::from_secs(REASONING_TIMEOUT_SECS),
-            self.request_reasoning_inner(thinking_model, enhanced_messages, on_event),
+            self.request_reasoning_inner(thinking_model, enhanced_messages, prompt_vars, on_event),
         )
         .await;
 
+        if result.is_err() {
+            self.record_decision(
+                DegradationKind::ReasoningTimeout,
+                thinking_model,
+                format!("基础推理超过 {}s，已跳过", REASONING_TIMEOUT_SECS),
+            );
+        }
         result.unwrap_or_default()
     }
 
@@ -177,18 +618,15 @@ impl ChatEngine {
         &self,
         thinking_model: &str,
         enhanced_messages: &[Message],
+        prompt_vars: &TemplateVars,
         on_event: &impl Fn(ChatStreamEvent),
     ) -> (String, String) {
-        let token = {
-            let mut auth = self.jwt_auth.lock().unwrap();
-            auth.get_token()
-        };
-
         let mut reasoning_messages = enhanced_messages.to_vec();
-        let analysis_instruction = Message {
-            id: String::new(),
-            role: MessageRole::System,
-            content: "【内心推演 — 以角色的视角理解这句话】\n\
+        let analysis_instruction = Message::system_prompt(prompt_templates::render_or(
+            "reasoning",
+            prompt_vars,
+            || {
+                "【内心推演 — 以角色的视角理解这句话】\n\
                       \n\
                       闭上眼，你就是这个角色。对方刚说完这句话。\n\
                       在开口之前，你心里闪过了什么？\n\
@@ -219,22 +657,11 @@ impl ChatEngine {
                       - 500-800 字，思考密度优先\n\
                       - 不要写回复内容，只输出你的思考过程\n\
                       - 记忆/上下文中的事实必须原样复述，绝不允许遗漏或篡改"
-                .to_string(),
-            thinking_content: None,
-            model: "system".to_string(),
-            timestamp: 0,
-            message_type: MessageType::Say,
-        };
+                    .to_string()
+            },
+        ));
 
-        // 将分析指令插入到最后一条用户消息之前
-        let last_user_idx = reasoning_messages
-            .iter()
-            .rposition(|m| m.role == MessageRole::User);
-        if let Some(idx) = last_user_idx {
-            reasoning_messages.insert(idx, analysis_instruction);
-        } else {
-            reasoning_messages.push(analysis_instruction);
-        }
+        insert_before_last_user(&mut reasoning_messages, analysis_instruction);
 
         let request_body = Self::build_request_body(&reasoning_messages, thinking_model, true);
         let reasoning_event = |event: ChatStreamEvent| {
@@ -244,8 +671,7 @@ impl ChatEngine {
         };
 
         match StreamingHandler::stream_chat(
-            BIGMODEL_API_URL,
-            &token,
+            self.provider.as_ref(),
             request_body,
             &reasoning_event,
         )
@@ -261,231 +687,548 @@ impl ChatEngine {
                 };
                 (conclusion, thinking)
             }
-            Err(_) => (String::new(), String::new()),
+            Err(e) => {
+                tracing::warn!(error = %e, "推理请求失败，跳过本轮推理");
+                (String::new(), String::new())
+            }
         }
     }
 
     fn extract_reasoning_brief(thinking: &str) -> String {
-        let chars: Vec<char> = thinking.chars().collect();
-        if chars.len() <= 500 {
+        let tail = text_utils::tail_chars(thinking, 500);
+        if tail.len() == thinking.len() {
             thinking.to_string()
         } else {
-            let start = chars.len() - 500;
-            format!("...{}", chars[start..].iter().collect::<String>())
+            format!("...{}", tail)
         }
     }
 
     pub fn new(api_key: &str, data_path: &str) -> Result<Self, String> {
-        let jwt_auth = JwtAuth::new(api_key)?;
+        let provider = ZhipuProvider::new(api_key)?;
+        Ok(Self::with_provider(Box::new(provider), data_path))
+    }
+
+    /// 以任意在线提供方构建引擎（OpenAI 兼容、Anthropic 等，见 chat_provider::from_settings）
+    pub fn with_provider(provider: Box<dyn ChatProvider>, data_path: &str) -> Self {
         let conversation_store = ConversationStore::new(data_path);
         let memory_engine = MemoryEngine::new(data_path);
         let knowledge_store = KnowledgeStore::new(data_path);
-        Ok(Self {
-            jwt_auth: std::sync::Mutex::new(jwt_auth),
+        Self {
+            provider,
             conversation_store,
             memory_engine,
             knowledge_store,
-        })
+            decision_log: DecisionLog::new(data_path),
+            fidelity_auditor: FidelityAuditor::new(data_path),
+            embedding: None,
+            embedding_store: EmbeddingStore::new(data_path),
+            blocked_topics: BlockedTopicStore::new(data_path),
+            mood: MoodStore::new(data_path),
+            personas: PersonaStore::new(data_path),
+            saydo_rules: SayDoRuleStore::new(data_path),
+            lorebook: LorebookStore::new(data_path),
+            group_chats: GroupChatStore::new(data_path),
+            shadow_eval: ShadowEvalStore::new(data_path),
+            attachments: AttachmentStore::new(data_path),
+            illustrator: None,
+            tts: None,
+            audio: AudioStore::new(data_path),
+            archives: ArchiveStore::new(data_path),
+            housekeeper: Housekeeper::new(data_path),
+            config: ConfigManager::new(data_path),
+            pending_decisions: std::sync::Mutex::new(Vec::new()),
+            last_generation: std::sync::Mutex::new(None),
+            settings: std::sync::RwLock::new(AppSettings::default()),
+            settings_rx: std::sync::Mutex::new(None),
+        }
     }
 
-    /// Validate message content — reject blank messages (whitespace-only).
-    pub fn validate_message(content: &str) -> Result<(), ChatError> {
-        if content.trim().is_empty() {
-            return Err(ChatError::ValidationError {
-                message: "Message cannot be blank".to_string(),
-            });
-        }
-        Ok(())
+    /// 离线引擎：只走本地存储与记忆检索，不发起任何网络请求
+    pub fn new_offline(data_path: &str) -> Self {
+        // 占位密钥仅用于满足构造，离线管线不会签发 token
+        Self::new(OFFLINE_PLACEHOLDER_KEY, data_path)
+            .expect("offline placeholder key must be well-formed")
     }
 
-    /// 自动检测消息的 say/do 类型
-    pub fn detect_message_type(content: &str) -> MessageType {
-        SayDoDetector::detect(content)
+    /// 启用向量检索（见 EmbeddingBackend::from_settings）
+    pub fn with_embedding(mut self, backend: Option<EmbeddingBackend>) -> Self {
+        self.embedding = backend;
+        self
     }
 
-    /// 根据模型判断是否允许启用思考（用于 build_request_body 的安全守卫）
-    ///
-    /// 参考 GLM 思考模式文档: https://docs.bigmodel.cn/cn/guide/capabilities/thinking-mode
-    /// - GLM-4.7: 默认开启 Thinking，支持轮级思考、交错式思考、保留式思考
-    /// - GLM-4-AIR: 推理专用模型，支持思考
-    /// - GLM-4.7-FLASH: 快速模型，不支持思考
-    pub fn should_enable_thinking(model: &str, user_preference: bool) -> bool {
-        match model {
-            // GLM-4.7: 文档明确支持思考模式（默认开启）
-            "glm-4.7" => user_preference,
-            // GLM-4-AIR: 推理模型，支持思考
-            "glm-4-air" => user_preference,
-            // GLM-4.7-FLASH: 快速对话模型，不支持思考
-            "glm-4.7-flash" => false,
-            _ => false,
-        }
+    /// 启用场景插画（见 CogViewClient::from_settings）
+    pub fn with_illustrator(mut self, illustrator: Option<CogViewClient>) -> Self {
+        self.illustrator = illustrator;
+        self
     }
 
-    /// 估算消息列表的 token 数
-    /// 改进版：基于字符数而非 UTF-8 字节数，对中文更准确
-    /// 中文 1 字 ≈ 1.5 token，英文 1 词 ≈ 1 token
-    pub fn estimate_token_count(messages: &[Message]) -> usize {
-        let mut total_tokens: usize = 0;
-        for msg in messages {
-            let char_count = msg.content.chars().count();
-            // 统计中文字符占比，动态调整 token 估算系数
-            let cjk_chars = msg
-                .content
-                .chars()
-                .filter(|c| *c > '\u{4e00}' && *c < '\u{9fff}')
-                .count();
-            let ascii_words = msg
-                .content
-                .split_whitespace()
-                .filter(|w| w.is_ascii())
-                .count();
-            // 中文按 1.5 token/字，英文按 1 token/词，其他按 1
-            total_tokens += (cjk_chars as f64 * 1.5) as usize
-                + ascii_words
-                + (char_count - cjk_chars - ascii_words);
+    /// 启用语音回复（见 TtsClient::from_settings）
+    pub fn with_tts(mut self, tts: Option<TtsClient>) -> Self {
+        self.tts = tts;
+        self
+    }
+
+    /// 以给定设置作为初始快照
+    pub fn with_settings(self, settings: AppSettings) -> Self {
+        if let Ok(mut current) = self.settings.write() {
+            *current = settings;
         }
-        // 加上消息格式开销（每条消息约 4 token 的格式开销）
-        total_tokens + messages.len() * 4
+        self
     }
 
-    /// 根据上下文长度选择总结模型
-    /// 超过 128K token 使用 glm-4-long，否则使用 glm-4.7-flash
-    pub fn choose_summary_model(messages: &[Message]) -> &'static str {
-        let estimated_tokens = Self::estimate_token_count(messages);
-        if estimated_tokens > 128_000 {
-            "glm-4-long"
-        } else {
-            "glm-4.7-flash"
+    /// 订阅设置变更（见 ConfigManager::subscribe），后续轮次自动采用新设置
+    pub fn watch_settings(self, rx: broadcast::Receiver<AppSettings>) -> Self {
+        if let Ok(mut slot) = self.settings_rx.lock() {
+            *slot = Some(rx);
         }
+        self
     }
 
-    /// 评估上下文复杂度，决定是否需要 GLM-4-LONG 辅助处理
-    /// 返回: (是否需要长上下文蒸馏, 估算总 token 数)
-    fn assess_context_needs(
-        messages: &[Message],
-        memory_summaries: &[MemorySummary],
-    ) -> (bool, usize) {
-        let msg_tokens = Self::estimate_token_count(messages);
-        let memory_tokens: usize = memory_summaries
-            .iter()
-            .map(|s| s.summary.len() / 2 + s.core_facts.iter().map(|f| f.len() / 2).sum::<usize>())
-            .sum();
-        let total_tokens = msg_tokens + memory_tokens;
-        // 当总 token 超过 48K 或记忆条目超过 15 条时，使用 GLM-4-LONG
-        let needs_long = total_tokens > 48_000 || memory_summaries.len() > 15;
-        (needs_long, total_tokens)
+    fn current_settings(&self) -> AppSettings {
+        self.settings
+            .read()
+            .map(|s| s.clone())
+            .unwrap_or_default()
     }
 
-    /// ══ 长上下文蒸馏（GLM-4-LONG）══
-    /// 当对话历史+记忆超过 GLM-4-AIR 的有效处理范围时，
-    /// 先用 GLM-4-LONG 进行无损信息蒸馏，提取核心脉络，
-    /// 再将蒸馏结果注入后续管线。
-    ///
-    /// 增加超时保护：最多等待 DISTILLATION_TIMEOUT_SECS 秒。
-    async fn request_long_context_distillation(
+    /// 取出订阅中积压的设置变更，只保留最新一份；有变更时发出 ConfigChanged
+    /// 返回 (变更前, 变更后) 快照
+    fn apply_settings_updates(
         &self,
-        enhanced_messages: &[Message],
-        memory_summaries: &[MemorySummary],
-        user_content: &str,
         on_event: &impl Fn(ChatStreamEvent),
-    ) -> String {
-        let result = tokio::time::timeout(
-            std::time::Duration::from_secs(DISTILLATION_TIMEOUT_SECS),
-            self.request_long_context_distillation_inner(
-                enhanced_messages,
-                memory_summaries,
-                user_content,
-                on_event,
-            ),
-        )
-        .await;
+    ) -> Option<(AppSettings, AppSettings)> {
+        let latest = {
+            let mut slot = self.settings_rx.lock().ok()?;
+            let rx = slot.as_mut()?;
+            let mut latest = None;
+            loop {
+                match rx.try_recv() {
+                    Ok(settings) => latest = Some(settings),
+                    Err(broadcast::error::TryRecvError::Lagged(_)) => continue,
+                    Err(_) => break,
+                }
+            }
+            latest?
+        };
+        let previous = {
+            let mut current = self.settings.write().ok()?;
+            std::mem::replace(&mut *current, latest.clone())
+        };
+        if previous == latest {
+            return None;
+        }
+        on_event(ChatStreamEvent::ConfigChanged);
+        Some((previous, latest))
+    }
 
-        result.unwrap_or_default()
+    /// 设置变更后重新确定本轮模型：调用方沿用的是旧默认模型时换成新默认模型，
+    /// 用户显式指定的其他模型保持不变
+    fn rebase_models(
+        chat_model: &str,
+        thinking_model: &str,
+        previous: &AppSettings,
+        current: &AppSettings,
+    ) -> (String, String) {
+        let chat = if chat_model == previous.chat_model && !current.chat_model.trim().is_empty() {
+            current.chat_model.clone()
+        } else {
+            chat_model.to_string()
+        };
+        let thinking = if thinking_model == previous.thinking_model
+            && !current.thinking_model.trim().is_empty()
+        {
+            current.thinking_model.clone()
+        } else {
+            thinking_model.to_string()
+        };
+        (chat, thinking)
     }
 
-    /// request_long_context_distillation 的内部实现
-    async fn request_long_context_distillation_inner(
+    /// 每轮开始前采用最新设置，返回本轮实际使用的 (对话模型, 推理模型)
+    fn refresh_turn_models(
         &self,
-        enhanced_messages: &[Message],
-        memory_summaries: &[MemorySummary],
-        user_content: &str,
+        chat_model: &str,
+        thinking_model: &str,
         on_event: &impl Fn(ChatStreamEvent),
-    ) -> String {
-        let token = {
-            let mut auth = self.jwt_auth.lock().unwrap();
-            auth.get_token()
-        };
-
-        // 构建蒸馏请求上下文
-        let mut distill_messages = enhanced_messages.to_vec();
-
-        // 构建完整记忆摘要（不依赖搜索，全量注入）
-        let mut full_memory = String::new();
-        if !memory_summaries.is_empty() {
-            full_memory.push_str("【全量记忆存档】\n");
-            for (i, summary) in memory_summaries.iter().enumerate() {
-                full_memory.push_str(&format!(
-                    "记忆段 {} (轮次 {}-{}):\n  概要: {}\n",
-                    i + 1,
-                    summary.turn_range_start,
-                    summary.turn_range_end,
-                    summary.summary
-                ));
-                for fact in &summary.core_facts {
-                    full_memory.push_str(&format!("  事实: {}\n", fact));
-                }
+    ) -> (String, String) {
+        match self.apply_settings_updates(on_event) {
+            Some((previous, current)) => {
+                Self::rebase_models(chat_model, thinking_model, &previous, &current)
             }
+            None => (chat_model.to_string(), thinking_model.to_string()),
         }
+    }
 
-        let distill_instruction = Message {
-            id: String::new(),
-            role: MessageRole::System,
-            content: format!(
-                "【长上下文无损蒸馏任务】\n\
-                 你正在处理一段超长对话。请将以上所有信息蒸馏为高密度摘要。\n\
-                 \n\
-                 {}\n\
-                 \n\
-                 当前用户最新消息: 「{}」\n\
-                 \n\
-                 ■ 蒸馏要求（严格执行）：\n\
-                 \n\
-                 1. 【不可变事实清单】（逐条列出，一条都不能少）\n\
-                    - 所有角色身份、关系、设定\n\
-                    - 所有已发生的关键事件（按时间线）\n\
-                    - 所有承诺、约定、共识\n\
-                    - 当前生效的状态（位置、心情、正在做的事）\n\
-                 \n\
-                 2. 【情感脉络时间线】\n\
-                    - 关系从开始到现在的温度变化轨迹\n\
-                    - 最近 5 轮的情绪走向\n\
-                    - 当前情感基调和未解决的情感议题\n\
-                 \n\
-                 3. 【当前对话焦点】\n\
-                    - 用户最新消息的完整语义解读\n\
-                    - 与历史上下文的所有关联点\n\
-                    - 需要在回复中呼应的历史细节\n\
-                 \n\
-                 ■ 输出格式：纯文本，按上述三个板块组织\n\
-                 ■ 信息零丢失原则：宁可多写，不可遗漏任何核心事实\n\
-                 ■ 总字数控制在 1500 字以内",
-                full_memory, user_content
-            ),
-            thinking_content: None,
-            model: "system".to_string(),
-            timestamp: 0,
-            message_type: MessageType::Say,
-        };
+    /// 注入内容强度提示块（插入到最后一条用户消息之前）
+    fn inject_intensity_prompt(&self, enhanced_messages: &mut Vec<Message>) {
+        let intensity_msg = Message::system_prompt(
+            IntensityDial::build_prompt(self.current_settings().content_intensity),
+        );
+        insert_before_last_user(enhanced_messages, intensity_msg);
+    }
 
-        distill_messages.push(distill_instruction);
+    /// 注入对话设置的叙述视角提示（不限视角时不注入）
+    fn inject_narration_prompt(conv: &Conversation, enhanced_messages: &mut Vec<Message>) {
+        let Some(prompt) = NarrationGuard::build_prompt(&conv.narration) else {
+            return;
+        };
+        let narration_msg = Message::system_prompt(prompt);
+        insert_before_last_user(enhanced_messages, narration_msg);
+    }
 
-        let request_body = Self::build_request_body(&distill_messages, "glm-4-long", false);
+    /// 对话选用的人设（已被删除时为 None）
+    fn active_persona(&self, conv: &Conversation) -> Option<UserPersona> {
+        conv.persona_id
+            .as_deref()
+            .and_then(|id| self.personas.get(id))
+    }
 
-        // GLM-4-LONG 蒸馏是静默执行的，不向前端推送事件
+    /// 把稳定前缀（角色设定 → 用户人设 → 身份锚点）放在上下文最前面。
+    /// 这几层多数轮次不变，由 ContextCache 按 cache_key 复用；
+    /// 逐字节不变的前缀也让提供方的上下文缓存得以命中
+    fn apply_stable_prefix(
+        &self,
+        cache_key: &str,
+        conv: &Conversation,
+        identity_facts: &[String],
+        enhanced_messages: &mut Vec<Message>,
+    ) {
+        let character = conv
+            .messages
+            .iter()
+            .find(|m| m.role == MessageRole::System && m.message_type != MessageType::OutOfCharacter);
+        // 上下文构建时角色设定已放在开头，换成完整的前缀
+        if let (Some(first), Some(character)) = (enhanced_messages.first(), character) {
+            if first.role == MessageRole::System && first.content == character.content {
+                enhanced_messages.remove(0);
+            }
+        }
+        let persona = self.active_persona(conv);
+        let prefix = ContextCache::global().prefix(
+            cache_key,
+            &PrefixLayers {
+                character,
+                persona: persona.as_ref(),
+                identity_facts,
+            },
+        );
+        enhanced_messages.splice(0..0, prefix);
+    }
+
+    /// 注入现实时间提示（插入到最后一条用户消息之前；设置关闭时不注入）
+    fn inject_time_awareness(&self, conv: &Conversation, enhanced_messages: &mut Vec<Message>) {
+        if !self.current_settings().time_awareness {
+            return;
+        }
+        let now = chrono::Local::now();
+        let elapsed = TimeAwareness::previous_message_at(&conv.messages)
+            .map(|at| now.timestamp_millis() - at);
+        let time_msg = Message::system_prompt(
+            TimeAwareness::build_prompt(now.naive_local(), elapsed),
+        );
+        insert_before_last_user(enhanced_messages, time_msg);
+    }
+
+    /// 生成后视角检查：可无歧义修正的直接改写人称，否则保留原文并提示
+    fn enforce_narration(
+        conv: &Conversation,
+        content: &str,
+        on_event: &impl Fn(ChatStreamEvent),
+    ) -> String {
+        let check = NarrationGuard::enforce(&conv.narration, content);
+        if check.drifted && check.corrected == 0 {
+            on_event(ChatStreamEvent::SystemNotice(
+                "这条回复的叙述视角与设置不一致，可以用 /regen 重新生成".to_string(),
+            ));
+        }
+        check.content
+    }
+
+    /// 从最新用户消息学习回避话题（沙盒对话不学习）
+    fn learn_blocked_topic(&self, conv: &Conversation) {
+        if ConversationStore::is_sandbox(&conv.id) {
+            return;
+        }
+        let start = conv.messages.len().saturating_sub(6);
+        let recent: Vec<&Message> = conv.messages[start..].iter().collect();
+        if let Some(signal) = CognitiveEngine::detect_topic_block(&recent) {
+            if let Err(e) = self.blocked_topics.record(&conv.id, &signal) {
+                tracing::warn!(conversation_id = %conv.id, error = %e, "话题回避记录写入失败");
+            }
+        }
+    }
+
+    /// 一轮互动结束后更新角色心情（沙盒与共写模式没有需要延续的角色情绪）
+    fn record_mood(&self, conv: &Conversation) {
+        if ConversationStore::is_sandbox(&conv.id) || conv.mode == ConversationMode::CoAuthor {
+            return;
+        }
+        let non_system: Vec<&Message> = conv
+            .messages
+            .iter()
+            .filter(|m| m.role != MessageRole::System)
+            .collect();
+        if non_system.is_empty() {
+            return;
+        }
+        let analysis = CognitiveEngine::analyze(&non_system);
+        let now = chrono::Utc::now().timestamp_millis();
+        if let Err(e) = self.mood.record_exchange(&conv.id, &analysis, now) {
+            tracing::warn!(conversation_id = %conv.id, error = %e, "角色心情写入失败");
+        }
+    }
+
+    /// 注入回避话题提示块（插入到最后一条用户消息之前）
+    fn inject_blocked_topics_prompt(&self, conversation_id: &str, enhanced_messages: &mut Vec<Message>) {
+        let prompt = match BlockedTopicStore::build_prompt(&self.blocked_topics.active(conversation_id)) {
+            Some(p) => p,
+            None => return,
+        };
+        let topics_msg = Message::system_prompt(prompt);
+        insert_before_last_user(enhanced_messages, topics_msg);
+    }
+
+    /// 长动作场景的微规划：用快速模型先出「反应 → 动作 → 钩子」三拍骨架，
+    /// 作为隐藏引导注入，抑制长段铺陈。超时或解析失败时静默跳过。
+    async fn inject_reply_skeleton(
+        &self,
+        conv: &Conversation,
+        content: &str,
+        message_type: &MessageType,
+        enhanced_messages: &mut Vec<Message>,
+    ) {
+        if !SayDoDetector::needs_skeleton(message_type, content) {
+            return;
+        }
+        let last_reply = conv
+            .messages
+            .iter()
+            .rev()
+            .find(|m| m.role == MessageRole::Assistant)
+            .map(|m| m.content.as_str());
+        let plan_messages = vec![Message {
+            id: String::new(),
+            timestamp: 0,
+            ..Message::new(
+                MessageRole::User,
+                SayDoDetector::build_skeleton_prompt(content, last_reply),
+                "glm-4.7-flash",
+            )
+        }];
+        let mut request_body = Self::build_request_body(&plan_messages, "glm-4.7-flash", false);
+        request_body["max_tokens"] = serde_json::json!(SKELETON_MAX_TOKENS);
+
+        let silent_event = |_event: ChatStreamEvent| {};
+        let planned = tokio::time::timeout(
+            std::time::Duration::from_millis(SKELETON_TIMEOUT_MS),
+            StreamingHandler::stream_chat(self.provider.as_ref(), request_body, &silent_event),
+        )
+        .await;
+        let beats = match planned {
+            Ok(Ok((text, _))) => match SayDoDetector::parse_skeleton(&text) {
+                Some(beats) => beats,
+                None => return,
+            },
+            _ => return,
+        };
+
+        let skeleton_msg = Message::system_prompt(SayDoDetector::build_skeleton_guidance(&beats));
+        insert_before_last_user(enhanced_messages, skeleton_msg);
+    }
+
+    /// 本轮是否挂载联网搜索：需用户开启、非共写模式、且输入为现实信息提问
+    fn should_web_search(&self, conv: &Conversation, content: &str) -> bool {
+        self.current_settings().enable_web_search
+            && conv.mode != ConversationMode::CoAuthor
+            && WebSearchGate::is_informational(content)
+    }
+
+    /// Validate message content — reject blank messages (whitespace-only).
+    pub fn validate_message(content: &str) -> Result<(), ChatError> {
+        if content.trim().is_empty() {
+            return Err(ChatError::ValidationError {
+                message: "Message cannot be blank".to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// 自动检测消息的 say/do 类型
+    pub fn detect_message_type(content: &str) -> MessageType {
+        SayDoDetector::detect(content)
+    }
+
+    /// 按对话的自定义规则检测 say/do 类型（未配置时同 detect_message_type）
+    fn detect_type_for(&self, conversation_id: &str, content: &str) -> MessageType {
+        SayDoDetector::detect_with_rules(content, &self.saydo_rules.load_compiled(conversation_id))
+    }
+
+    /// 根据模型判断是否允许启用思考（用于 build_request_body 的安全守卫）
+    ///
+    /// 参考 GLM 思考模式文档: https://docs.bigmodel.cn/cn/guide/capabilities/thinking-mode
+    /// 各模型是否支持思考见 ModelRegistry 的声明，未声明的模型不开启
+    pub fn should_enable_thinking(model: &str, user_preference: bool) -> bool {
+        user_preference && ModelRegistry::global().supports_thinking(model)
+    }
+
+    /// 消息列表的 token 数（见 tokenizer::global：有词表时按 BPE 计数，否则估算）
+    pub fn estimate_token_count(messages: &[Message]) -> usize {
+        tokenizer::global().count_messages(messages)
+    }
+
+    /// 根据上下文长度选择总结模型
+    /// 超过 128K token 使用 glm-4-long，否则使用 glm-4.7-flash
+    pub fn choose_summary_model(messages: &[Message]) -> &'static str {
+        let estimated_tokens = Self::estimate_token_count(messages);
+        if estimated_tokens > 128_000 {
+            "glm-4-long"
+        } else {
+            "glm-4.7-flash"
+        }
+    }
+
+    /// 评估上下文复杂度，决定是否需要 GLM-4-LONG 辅助处理
+    /// 返回: (是否需要长上下文蒸馏, 估算总 token 数)
+    fn assess_context_needs(
+        messages: &[Message],
+        memory_summaries: &[MemorySummary],
+    ) -> (bool, usize) {
+        let counter = tokenizer::global();
+        let msg_tokens = counter.count_messages(messages);
+        let memory_tokens: usize = memory_summaries
+            .iter()
+            .map(|s| {
+                counter.count_text(&s.summary)
+                    + s.core_facts.iter().map(|f| counter.count_text(f)).sum::<usize>()
+            })
+            .sum();
+        let total_tokens = msg_tokens + memory_tokens;
+        // 当总 token 超过 48K 或记忆条目超过 15 条时，使用 GLM-4-LONG
+        let needs_long = total_tokens > 48_000 || memory_summaries.len() > 15;
+        (needs_long, total_tokens)
+    }
+
+    /// ══ 长上下文蒸馏（GLM-4-LONG）══
+    /// 当对话历史+记忆超过 GLM-4-AIR 的有效处理范围时，
+    /// 先用 GLM-4-LONG 进行无损信息蒸馏，提取核心脉络，
+    /// 再将蒸馏结果注入后续管线。
+    ///
+    /// 增加超时保护：最多等待 DISTILLATION_TIMEOUT_SECS 秒。
+    async fn request_long_context_distillation(
+        &self,
+        conversation_id: &str,
+        enhanced_messages: &[Message],
+        memory_summaries: &[MemorySummary],
+        user_content: &str,
+        prompt_vars: &TemplateVars,
+    ) -> String {
+        let _timer = PhaseTimer::start(MetricPhase::Distillation);
+        let result = JobScheduler::global()
+            .run(
+                BackgroundJobKind::Distillation,
+                conversation_id,
+                tokio::time::timeout(
+                    std::time::Duration::from_secs(DISTILLATION_TIMEOUT_SECS),
+                    self.request_long_context_distillation_inner(
+                        enhanced_messages,
+                        memory_summaries,
+                        user_content,
+                        prompt_vars,
+                    ),
+                ),
+            )
+            .await
+            .unwrap_or(Ok(String::new()));
+
+        if result.is_err() {
+            JobScheduler::global().record_timeout();
+            self.record_decision(
+                DegradationKind::DistillationTimeout,
+                "glm-4-long",
+                format!("长上下文蒸馏超过 {}s，已跳过", DISTILLATION_TIMEOUT_SECS),
+            );
+        }
+        result.unwrap_or_default()
+    }
+
+    /// request_long_context_distillation 的内部实现
+    async fn request_long_context_distillation_inner(
+        &self,
+        enhanced_messages: &[Message],
+        memory_summaries: &[MemorySummary],
+        user_content: &str,
+        prompt_vars: &TemplateVars,
+    ) -> String {
+        // 构建蒸馏请求上下文
+        let mut distill_messages = enhanced_messages.to_vec();
+
+        // 构建完整记忆摘要（不依赖搜索，全量注入）
+        let mut full_memory = String::new();
+        if !memory_summaries.is_empty() {
+            full_memory.push_str("【全量记忆存档】\n");
+            for (i, summary) in memory_summaries.iter().enumerate() {
+                full_memory.push_str(&format!(
+                    "记忆段 {} (轮次 {}-{}):\n  概要: {}\n",
+                    i + 1,
+                    summary.turn_range_start,
+                    summary.turn_range_end,
+                    summary.summary
+                ));
+                for fact in &summary.core_facts {
+                    full_memory.push_str(&format!("  事实: {}\n", fact));
+                }
+            }
+        }
+
+        let distill_instruction = Message::system_prompt(prompt_templates::render_or(
+            "distillation",
+            &prompt_vars
+                .clone()
+                .with("user_message", user_content)
+                .with("full_memory", full_memory.as_str()),
+            || {
+                format!(
+                    "【长上下文无损蒸馏任务】\n\
+                     你正在处理一段超长对话。请将以上所有信息蒸馏为高密度摘要。\n\
+                     \n\
+                     {}\n\
+                     \n\
+                     当前用户最新消息: 「{}」\n\
+                     \n\
+                     ■ 蒸馏要求（严格执行）：\n\
+                     \n\
+                     1. 【不可变事实清单】（逐条列出，一条都不能少）\n\
+                        - 所有角色身份、关系、设定\n\
+                        - 所有已发生的关键事件（按时间线）\n\
+                        - 所有承诺、约定、共识\n\
+                        - 当前生效的状态（位置、心情、正在做的事）\n\
+                     \n\
+                     2. 【情感脉络时间线】\n\
+                        - 关系从开始到现在的温度变化轨迹\n\
+                        - 最近 5 轮的情绪走向\n\
+                        - 当前情感基调和未解决的情感议题\n\
+                     \n\
+                     3. 【当前对话焦点】\n\
+                        - 用户最新消息的完整语义解读\n\
+                        - 与历史上下文的所有关联点\n\
+                        - 需要在回复中呼应的历史细节\n\
+                     \n\
+                     ■ 输出格式：纯文本，按上述三个板块组织\n\
+                     ■ 信息零丢失原则：宁可多写，不可遗漏任何核心事实\n\
+                     ■ 总字数控制在 1500 字以内",
+                    full_memory, user_content
+                )
+            },
+        ));
+
+        distill_messages.push(distill_instruction);
+
+        let request_body = Self::build_request_body(&distill_messages, "glm-4-long", false);
+
+        // GLM-4-LONG 蒸馏是静默执行的，不向前端推送事件
         let silent_event = |_event: ChatStreamEvent| {};
-        let _ = on_event; // 保留参数以维持接口一致性
 
-        match StreamingHandler::stream_chat(BIGMODEL_API_URL, &token, request_body, &silent_event)
+        match StreamingHandler::stream_chat(self.provider.as_ref(), request_body, &silent_event)
             .await
         {
             Ok((content, _)) => {
@@ -495,8 +1238,9 @@ impl ChatEngine {
                     String::new()
                 }
             }
-            Err(_) => {
+            Err(e) => {
                 // GLM-4-LONG 蒸馏失败是非致命的，继续用原始上下文
+                tracing::warn!(error = %e, "长上下文蒸馏失败，使用原始上下文");
                 String::new()
             }
         }
@@ -513,24 +1257,121 @@ impl ChatEngine {
     ///   1. BM25+语义检索相关事实（已有的 top 10）
     ///   2. 身份事实仅在与当前话题有一定关联时作为背景注入
     ///   3. 完全无关的事实不注入，避免 AI 在不相关的回复中提及
-    fn retrieve_knowledge_context(
+    async fn retrieve_knowledge_context(
         &self,
+        conv: &Conversation,
         conversation_id: &str,
         user_content: &str,
+        semantic: Option<&SemanticQuery>,
         enhanced_messages: &mut Vec<Message>,
     ) {
-        // 检索相关事实（top 10，已通过 BM25 + 语义排序）
-        let search_results = self
-            .knowledge_store
-            .search_facts(conversation_id, user_content, 10);
+        if !conv.context_layers.knowledge_injection {
+            return;
+        }
+        let _timer = PhaseTimer::start(MetricPhase::Retrieval);
+        // BM25 + TF-IDF 检索是纯 CPU 计算，放到 blocking 线程池，避免卡住流式读取
+        let store = self.knowledge_store.clone();
+        let conv_id = conversation_id.to_string();
+        let query = user_content.to_string();
+        let semantic = semantic.cloned();
+        let persona_id = conv.persona_id.clone();
+        let computed = blocking_pool::offload("knowledge_retrieval", move || {
+            Self::compute_knowledge_context(
+                &store,
+                &conv_id,
+                &query,
+                semantic.as_ref(),
+                persona_id.as_deref(),
+            )
+        })
+        .await;
+        let (knowledge_context, hit_ids) = match computed {
+            Some(result) => result,
+            None => return,
+        };
+
+        // 记录命中的事实ID（用于更新热度）
+        if let Err(e) = self.knowledge_store.layers().record_hits(conversation_id, &hit_ids) {
+            tracing::debug!(conversation_id, error = %e, "事实命中记录写入失败");
+        }
+
+        let knowledge_msg = Message::system_prompt(knowledge_context);
+        insert_before_last_user(enhanced_messages, knowledge_msg);
+    }
+
+    /// 取本轮查询向量；对话尚无文档向量时不发请求
+    async fn semantic_query(&self, conversation_id: &str, text: &str) -> Option<SemanticQuery> {
+        let backend = self.embedding.as_ref()?;
+        let vectors = self.embedding_store.load(conversation_id).ok()?;
+        if vectors.is_empty() || text.trim().is_empty() {
+            return None;
+        }
+        let _timer = PhaseTimer::start(MetricPhase::Retrieval);
+        let query = backend
+            .embed(&[text.to_string()])
+            .await
+            .ok()?
+            .into_iter()
+            .next()?;
+        Some(SemanticQuery { query, vectors })
+    }
+
+    /// 为新写入的摘要/事实补算向量，并清理已合并或删除的条目
+    async fn refresh_embeddings(&self, conversation_id: &str) {
+        let backend = match self.embedding.as_ref() {
+            Some(b) => b,
+            None => return,
+        };
+        let mut docs: Vec<(String, String)> = self
+            .memory_engine
+            .load_memory_index_async(conversation_id)
+            .await
+            .unwrap_or_default()
+            .iter()
+            .map(|s| {
+                let text = format!(
+                    "{}\n{}",
+                    MemoryEngine::build_enhanced_search_text(s),
+                    s.core_facts.join("；")
+                );
+                (s.id.clone(), text)
+            })
+            .collect();
+        docs.extend(
+            self.knowledge_store
+                .get_all_facts_async(conversation_id)
+                .await
+                .into_iter()
+                .map(|f| (f.id, f.content)),
+        );
+        if let Err(e) = self
+            .embedding_store
+            .sync(conversation_id, &docs, backend)
+            .await
+        {
+            tracing::warn!(conversation_id, error = %e, "向量索引同步失败");
+        }
+    }
+
+    /// 计算知识上下文与命中的事实ID；无可注入内容时返回 None
+    fn compute_knowledge_context(
+        store: &KnowledgeStore,
+        conversation_id: &str,
+        user_content: &str,
+        semantic: Option<&SemanticQuery>,
+        persona_id: Option<&str>,
+    ) -> Option<(String, Vec<String>)> {
+        // 检索相关事实（top 10，已通过 BM25 + 语义排序；对话层、角色层、世界层合并）
+        let layers = store.layers();
+        let search_results = layers.search(conversation_id, user_content, 10, semantic);
 
         // 获取身份/承诺类永久事实
-        let all_facts = self.knowledge_store.get_all_facts(conversation_id);
+        let all_facts = layers.all_facts(conversation_id);
         let active_topics = MemoryEngine::extract_active_topics_from_text(user_content);
 
         // 对身份事实进行相关性门控
         // 核心身份（名字等）始终注入，其他身份事实需要有一定相关性
-        let identity_facts: Vec<_> = all_facts
+        let mut identity_facts: Vec<_> = all_facts
             .iter()
             .filter(|f| matches!(f.category, FactCategory::Identity | FactCategory::Promise))
             .filter(|f| {
@@ -558,65 +1399,261 @@ impl ChatEngine {
             .cloned()
             .collect();
 
+        // 用户档案（跨对话共享）始终注入
+        identity_facts.extend(store.profile_facts_for(&all_facts, persona_id));
+
         // 构建知识上下文
-        let knowledge_context =
+        let mut knowledge_context =
             KnowledgeStore::build_knowledge_context(&search_results, &identity_facts);
 
-        if !knowledge_context.is_empty() {
-            // 记录命中的事实ID（用于更新热度）
-            let hit_ids: Vec<String> = search_results.iter().map(|r| r.fact.id.clone()).collect();
-            let _ = self.knowledge_store.record_hits(conversation_id, &hit_ids);
-
-            let knowledge_msg = Message {
-                id: String::new(),
-                role: MessageRole::System,
-                content: knowledge_context,
-                thinking_content: None,
-                model: "system".to_string(),
-                timestamp: 0,
-                message_type: MessageType::Say,
-            };
-            // 插入到最后一条用户消息之前
-            let last_user_idx = enhanced_messages
-                .iter()
-                .rposition(|m| m.role == MessageRole::User);
-            if let Some(idx) = last_user_idx {
-                enhanced_messages.insert(idx, knowledge_msg);
-            } else {
-                enhanced_messages.push(knowledge_msg);
+        // 消息提到的角色之间的关系（多角色故事的人际网）
+        let relations_context = store.character_web(conversation_id).build_context(user_content);
+        if !relations_context.is_empty() {
+            if !knowledge_context.is_empty() {
+                knowledge_context.push('\n');
             }
+            knowledge_context.push_str(&relations_context);
+        }
+
+        if knowledge_context.is_empty() {
+            return None;
         }
+        let hit_ids: Vec<String> = search_results.iter().map(|r| r.fact.id.clone()).collect();
+        Some((knowledge_context, hit_ids))
     }
 
-    /// ══ GLM-4-AIR 深度检索分析（Phase 1 增强）══
-    /// 在原有推理分析的基础上，增加对本地知识库的深度检索指令
-    /// GLM-4-AIR 负责：
-    ///   1. 分析用户意图，判断需要哪些知识
-    ///   2. 基于注入的知识库事实进行深度推理
-    ///   3. 输出结构化分析结论，供 GLM-4.7 参考
-    ///
-    /// 增加超时保护：最多等待 REASONING_TIMEOUT_SECS 秒。
-    async fn request_enhanced_reasoning(
+    /// 推理管线的上下文准备（Phase 0.3–0.7）：知识检索、已蒸馏核心状态，
+    /// 上下文超长时再做长上下文蒸馏；返回注入后的上下文
+    async fn prepare_pipeline_context(
         &self,
-        thinking_model: &str,
+        conv: &Conversation,
         conversation_id: &str,
-        enhanced_messages: &[Message],
-        _user_content: &str,
-        on_event: &impl Fn(ChatStreamEvent),
-    ) -> (String, String) {
-        // 使用 tokio::time::timeout 保护增强推理调用
-        let result = tokio::time::timeout(
-            std::time::Duration::from_secs(REASONING_TIMEOUT_SECS),
-            self.request_enhanced_reasoning_inner(
-                thinking_model,
-                conversation_id,
-                enhanced_messages,
-                _user_content,
-                on_event,
-            ),
+        user_content: &str,
+        semantic: Option<&SemanticQuery>,
+        prompt_vars: &TemplateVars,
+        mut enhanced_messages: Vec<Message>,
+    ) -> Vec<Message> {
+        // ── Phase 0.3: 本地知识库检索（纯本地，零延迟）──
+        self.retrieve_knowledge_context(
+            conv,
+            conversation_id,
+            user_content,
+            semantic,
+            &mut enhanced_messages,
         )
         .await;
 
+        // ── Phase 0.4: 读取已蒸馏的核心状态（若存在且未过期）──
+        let memory_summaries_for_assess = self
+            .memory_engine
+            .load_memory_index_async(conversation_id)
+            .await
+            .unwrap_or_default();
+        let character_prompt_hash = MemoryEngine::character_prompt_hash(&conv.messages);
+        if let Some(distilled_state) = self
+            .memory_engine
+            .load_fresh_distilled_state_async(
+                conversation_id,
+                character_prompt_hash,
+                &memory_summaries_for_assess,
+            )
+            .await
+        {
+            if !distilled_state.core_prompt.trim().is_empty() {
+                let distilled_msg = Message::system_prompt(format!(
+                    "【历史蒸馏核心状态（持久化）】\n{}\n",
+                    distilled_state.core_prompt
+                ));
+                insert_before_last_user(&mut enhanced_messages, distilled_msg);
+            }
+        }
+
+        // ── Phase 0.5: 评估上下文复杂度，决定是否需要 GLM-4-LONG ──
+        let (needs_long_context, _total_tokens) =
+            Self::assess_context_needs(&enhanced_messages, &memory_summaries_for_assess);
+
+        // ── Phase 0.7: 长上下文蒸馏（GLM-4-LONG，仅在上下文超长时触发）──
+        if needs_long_context {
+            let distilled = self
+                .request_long_context_distillation(
+                    conversation_id,
+                    &enhanced_messages,
+                    &memory_summaries_for_assess,
+                    user_content,
+                    prompt_vars,
+                )
+                .await;
+            if !distilled.trim().is_empty() {
+                let distilled_state = MemoryEngine::distilled_state(
+                    &distilled,
+                    &memory_summaries_for_assess,
+                    character_prompt_hash,
+                    conv.turn_count,
+                );
+                if !ConversationStore::is_sandbox(conversation_id) {
+                    if let Err(e) = self
+                        .memory_engine
+                        .save_distilled_state_async(conversation_id, &distilled_state)
+                        .await
+                    {
+                        tracing::warn!(conversation_id, error = %e, "蒸馏状态写入失败");
+                    }
+                }
+
+                let distill_msg = Message::system_prompt(format!(
+                    "【长上下文蒸馏摘要 — 以下为 GLM-4-LONG 整理的关键信息，必须严格遵守】\n{}\n",
+                    distilled
+                ));
+                insert_before_last_user(&mut enhanced_messages, distill_msg);
+            }
+        }
+
+        enhanced_messages
+    }
+
+    /// 准备上下文后再推理：返回（注入后的上下文，（推理结论，思考链））
+    #[allow(clippy::too_many_arguments)]
+    async fn prepare_and_reason(
+        &self,
+        conv: &Conversation,
+        conversation_id: &str,
+        user_content: &str,
+        semantic: Option<&SemanticQuery>,
+        thinking_model: &str,
+        prompt_vars: &TemplateVars,
+        on_event: &impl Fn(ChatStreamEvent),
+        enhanced_messages: Vec<Message>,
+    ) -> (Vec<Message>, (String, String)) {
+        let context = self
+            .prepare_pipeline_context(
+                conv,
+                conversation_id,
+                user_content,
+                semantic,
+                prompt_vars,
+                enhanced_messages,
+            )
+            .await;
+        let reasoning = self
+            .run_reasoning_phase(
+                thinking_model,
+                conversation_id,
+                &context,
+                user_content,
+                prompt_vars,
+                on_event,
+            )
+            .await;
+        (context, reasoning)
+    }
+
+    /// 快速草稿的请求上下文：推理前的上下文 + 简短回应指令（放在最后一条用户消息之前）
+    fn build_draft_messages(messages: &[Message]) -> Vec<Message> {
+        let mut draft_messages = messages.to_vec();
+        let instruction = Message::system_prompt(DRAFT_PROMPT);
+        insert_before_last_user(&mut draft_messages, instruction);
+        draft_messages
+    }
+
+    /// 快速草稿：glm-4.7-flash 的简短回复以 DraftDelta 推流；
+    /// 草稿只用于展示，不落盘，失败时静默（完整回复照常到达）
+    async fn stream_fast_draft(&self, messages: &[Message], on_event: &impl Fn(ChatStreamEvent)) {
+        let draft_messages = Self::build_draft_messages(messages);
+        let result = self
+            .request_with_fallback(
+                DRAFT_MODEL,
+                false,
+                &draft_messages,
+                &RequestTuning::default(),
+                &|event| {
+                    if let ChatStreamEvent::ContentDelta(delta) = event {
+                        on_event(ChatStreamEvent::DraftDelta(delta));
+                    }
+                },
+            )
+            .await;
+        if let Err(e) = result {
+            tracing::debug!(error = %e, "快速草稿生成失败");
+        }
+    }
+
+    /// 推理阶段（Phase 1）：增强推理，失败时回退到基础推理；返回（结论，思考链）
+    async fn run_reasoning_phase(
+        &self,
+        thinking_model: &str,
+        conversation_id: &str,
+        enhanced_messages: &[Message],
+        user_content: &str,
+        prompt_vars: &TemplateVars,
+        on_event: &impl Fn(ChatStreamEvent),
+    ) -> (String, String) {
+        // ── Phase 1: 推理模型（GLM-4-AIR）知识增强深度分析 ──
+        let (mut reasoning_conclusion, mut thinking_text) = self
+            .request_enhanced_reasoning(
+                thinking_model,
+                conversation_id,
+                enhanced_messages,
+                user_content,
+                prompt_vars,
+                on_event,
+            )
+            .await;
+
+        // 增强推理失败时回退到基础推理链路，确保该能力在生产链路中可用
+        if reasoning_conclusion.trim().is_empty() {
+            let (fallback_conclusion, fallback_thinking) = self
+                .request_reasoning(thinking_model, enhanced_messages, prompt_vars, on_event)
+                .await;
+            if !fallback_conclusion.trim().is_empty() {
+                reasoning_conclusion = fallback_conclusion;
+            }
+            if !fallback_thinking.trim().is_empty() {
+                thinking_text = fallback_thinking;
+            }
+        }
+
+        (reasoning_conclusion, thinking_text)
+    }
+
+    /// ══ GLM-4-AIR 深度检索分析（Phase 1 增强）══
+    /// 在原有推理分析的基础上，增加对本地知识库的深度检索指令
+    /// GLM-4-AIR 负责：
+    ///   1. 分析用户意图，判断需要哪些知识
+    ///   2. 基于注入的知识库事实进行深度推理
+    ///   3. 输出结构化分析结论，供 GLM-4.7 参考
+    ///
+    /// 增加超时保护：最多等待 REASONING_TIMEOUT_SECS 秒。
+    async fn request_enhanced_reasoning(
+        &self,
+        thinking_model: &str,
+        conversation_id: &str,
+        enhanced_messages: &[Message],
+        _user_content: &str,
+        prompt_vars: &TemplateVars,
+        on_event: &impl Fn(ChatStreamEvent),
+    ) -> (String, String) {
+        let _timer = PhaseTimer::start(MetricPhase::Reasoning);
+        // 使用 tokio::time::timeout 保护增强推理调用
+        let result = tokio::time::timeout(
+            std::time::Duration::from_secs(REASONING_TIMEOUT_SECS),
+            self.request_enhanced_reasoning_inner(
+                thinking_model,
+                conversation_id,
+                enhanced_messages,
+                _user_content,
+                prompt_vars,
+                on_event,
+            ),
+        )
+        .await;
+
+        if result.is_err() {
+            self.record_decision(
+                DegradationKind::ReasoningTimeout,
+                thinking_model,
+                format!("增强推理超过 {}s，已跳过", REASONING_TIMEOUT_SECS),
+            );
+        }
         result.unwrap_or_default()
     }
 
@@ -627,18 +1664,14 @@ impl ChatEngine {
         conversation_id: &str,
         enhanced_messages: &[Message],
         _user_content: &str,
+        prompt_vars: &TemplateVars,
         on_event: &impl Fn(ChatStreamEvent),
     ) -> (String, String) {
-        let token = {
-            let mut auth = self.jwt_auth.lock().unwrap();
-            auth.get_token()
-        };
-
         // 在原始上下文基础上追加增强推理指令
         let mut reasoning_messages = enhanced_messages.to_vec();
 
         // 获取知识库概况（辅助推理）
-        let all_facts = self.knowledge_store.get_all_facts(conversation_id);
+        let all_facts = self.knowledge_store.get_all_facts_async(conversation_id).await;
         let fact_summary = if !all_facts.is_empty() {
             let mut summary = String::from("【本地知识库概况】\n");
             let categories: Vec<(&str, usize)> = vec![
@@ -708,64 +1741,54 @@ impl ChatEngine {
             String::new()
         };
 
-        let analysis_instruction = Message {
-            id: String::new(),
-            role: MessageRole::System,
-            content: format!(
-                "【内心推演 — 知识增强模式】\n\
-                 \n\
-                 闭上眼，你就是这个角色。对方刚说完这句话。\n\
-                 \n\
-                 {}\n\
-                 \n\
-                 请从以下角度进行内心推演（用自然思维流，不要列编号清单）：\n\
-                 \n\
-                 ▸ 第一反应：听到这话，你心里的感受是什么？\n\
-                   不需要分析，先感受——是暖了一下？还是心里一紧？还是觉得好笑？\n\
-                 \n\
-                 ▸ 知识检索：你脑子里有没有和这件事相关的记忆/事实？\n\
-                   对照知识库，哪些事实与当前话题直接相关？（必须逐条引用原文）\n\
-                   对方说的和你记忆中的是否有矛盾？\n\
-                   有没有新的信息值得记住？\n\
-                 \n\
-                 ▸ 弦外之音：表面意思之下是否有别的含义？\n\
-                   引用原话关键词来说明你的判断\n\
-                 \n\
-                 ▸ 上下文线索：最近几轮对话的走向是什么？\n\
-                   和这句话有什么连续性？是在同一个话题里，还是转了？\n\
-                 \n\
-                 ▸ 关系直觉：你们此刻的距离感和温度怎么样？\n\
-                   对方在靠近？试探？撒娇？还是有些疲惫？\n\
-                 \n\
-                 ▸ 回应策略：你想怎么回？\n\
-                   切入方式——动作/接话/反问/沉默后开口？\n\
-                   核心要回应的点是什么？（引用用户原话 + 知识库事实）\n\
-                   收束方式——提问/温柔确认/动作/自然停下？\n\
-                   什么方式是绝对不能用的？\n\
-                 \n\
-                 ■ 输出要求：\n\
-                 - 用自然的思维流表达，像是回话前脑海中闪过的念头\n\
-                 - 引用对话原文和知识库事实作为依据\n\
-                 - 500-800 字，思考密度优先\n\
-                 - 不要写回复内容，只输出思考过程\n\
-                 - 知识库中的事实必须原样复述，绝不允许遗漏或篡改",
-                fact_summary
-            ),
-            thinking_content: None,
-            model: "system".to_string(),
-            timestamp: 0,
-            message_type: MessageType::Say,
-        };
+        let analysis_instruction = Message::system_prompt(prompt_templates::render_or(
+            "enhanced_reasoning",
+            &prompt_vars.clone().with("fact_summary", fact_summary.as_str()),
+            || {
+                format!(
+                    "【内心推演 — 知识增强模式】\n\
+                     \n\
+                     闭上眼，你就是这个角色。对方刚说完这句话。\n\
+                     \n\
+                     {}\n\
+                     \n\
+                     请从以下角度进行内心推演（用自然思维流，不要列编号清单）：\n\
+                     \n\
+                     ▸ 第一反应：听到这话，你心里的感受是什么？\n\
+                       不需要分析，先感受——是暖了一下？还是心里一紧？还是觉得好笑？\n\
+                     \n\
+                     ▸ 知识检索：你脑子里有没有和这件事相关的记忆/事实？\n\
+                       对照知识库，哪些事实与当前话题直接相关？（必须逐条引用原文）\n\
+                       对方说的和你记忆中的是否有矛盾？\n\
+                       有没有新的信息值得记住？\n\
+                     \n\
+                     ▸ 弦外之音：表面意思之下是否有别的含义？\n\
+                       引用原话关键词来说明你的判断\n\
+                     \n\
+                     ▸ 上下文线索：最近几轮对话的走向是什么？\n\
+                       和这句话有什么连续性？是在同一个话题里，还是转了？\n\
+                     \n\
+                     ▸ 关系直觉：你们此刻的距离感和温度怎么样？\n\
+                       对方在靠近？试探？撒娇？还是有些疲惫？\n\
+                     \n\
+                     ▸ 回应策略：你想怎么回？\n\
+                       切入方式——动作/接话/反问/沉默后开口？\n\
+                       核心要回应的点是什么？（引用用户原话 + 知识库事实）\n\
+                       收束方式——提问/温柔确认/动作/自然停下？\n\
+                       什么方式是绝对不能用的？\n\
+                     \n\
+                     ■ 输出要求：\n\
+                     - 用自然的思维流表达，像是回话前脑海中闪过的念头\n\
+                     - 引用对话原文和知识库事实作为依据\n\
+                     - 500-800 字，思考密度优先\n\
+                     - 不要写回复内容，只输出思考过程\n\
+                     - 知识库中的事实必须原样复述，绝不允许遗漏或篡改",
+                    fact_summary
+                )
+            },
+        ));
 
-        // 将分析指令插入到最后一条用户消息之前
-        let last_user_idx = reasoning_messages
-            .iter()
-            .rposition(|m| m.role == MessageRole::User);
-        if let Some(idx) = last_user_idx {
-            reasoning_messages.insert(idx, analysis_instruction);
-        } else {
-            reasoning_messages.push(analysis_instruction);
-        }
+        insert_before_last_user(&mut reasoning_messages, analysis_instruction);
 
         let request_body = Self::build_request_body(&reasoning_messages, thinking_model, true);
 
@@ -777,8 +1800,7 @@ impl ChatEngine {
         };
 
         match StreamingHandler::stream_chat(
-            BIGMODEL_API_URL,
-            &token,
+            self.provider.as_ref(),
             request_body,
             &reasoning_event,
         )
@@ -794,8 +1816,9 @@ impl ChatEngine {
                 };
                 (conclusion, thinking)
             }
-            Err(_) => {
+            Err(e) => {
                 // 推理失败是非致命的
+                tracing::warn!(error = %e, "增强推理请求失败，跳过本轮推理");
                 (String::new(), String::new())
             }
         }
@@ -806,33 +1829,67 @@ impl ChatEngine {
     /// 存入本地知识库，供后续对话检索
     ///
     /// 增加超时保护：最多等待 FACT_EXTRACTION_TIMEOUT_SECS 秒。
-    async fn extract_and_store_facts(
-        &self,
-        conversation_id: &str,
-        on_event: &impl Fn(ChatStreamEvent),
-    ) {
-        let result = tokio::time::timeout(
-            std::time::Duration::from_secs(FACT_EXTRACTION_TIMEOUT_SECS),
-            self.extract_and_store_facts_inner(conversation_id, on_event),
-        )
-        .await;
+    async fn extract_and_store_facts(&self, conversation_id: &str) {
+        let conv = match self.conversation_store.load_active_branch_async(conversation_id).await {
+            Ok(c) => c,
+            Err(e) => {
+                tracing::warn!(conversation_id, error = %e, "事实提取：加载对话失败");
+                return;
+            }
+        };
+        self.extract_and_store_facts_for(conversation_id, &conv).await;
+    }
 
-        if result.is_err() {
-            // 超时不影响主流程
+    /// 从 conv 的最近对话中提取事实，存入 knowledge_id 对应的知识库
+    /// （群聊时为发言角色的命名空间，conv 为该角色视角的对话）
+    #[tracing::instrument(skip_all, fields(knowledge_id))]
+    async fn extract_and_store_facts_for(&self, knowledge_id: &str, conv: &Conversation) {
+        // 设备压力或配额不足时由调度器延后（见 job_scheduler），条件恢复后按对话补跑
+        let result = JobScheduler::global()
+            .run(
+                BackgroundJobKind::FactExtraction,
+                &conv.id,
+                tokio::time::timeout(
+                    std::time::Duration::from_secs(FACT_EXTRACTION_TIMEOUT_SECS),
+                    self.extract_and_store_facts_inner(knowledge_id, conv),
+                ),
+            )
+            .await;
+
+        if let Some(Err(_)) = result {
+            // 超时不影响主流程，仅记录
+            JobScheduler::global().record_timeout();
+            self.record_decision(
+                DegradationKind::FactExtractionTimeout,
+                "glm-4.7-flash",
+                format!("事实提取超过 {}s，本轮未入库", FACT_EXTRACTION_TIMEOUT_SECS),
+            );
         }
     }
 
-    /// extract_and_store_facts 的内部实现
-    async fn extract_and_store_facts_inner(
-        &self,
-        conversation_id: &str,
-        on_event: &impl Fn(ChatStreamEvent),
-    ) {
-        let conv = match self.conversation_store.load_conversation(conversation_id) {
-            Ok(c) => c,
-            Err(_) => return,
-        };
+    /// 回复落盘后把事实提取与到期的记忆摘要交给后台任务队列，发送调用不再等待。
+    /// 返回 false 表示队列不可用（未 init_app 或不在运行时中），调用方就地提取事实
+    fn enqueue_after_reply(&self, conversation_id: &str) -> bool {
+        let tasks = BackgroundTasks::global();
+        if !tasks.enqueue(BackgroundJobKind::FactExtraction, conversation_id) {
+            return false;
+        }
+        let turn_count = self
+            .conversation_store
+            .get_turn_count(conversation_id)
+            .unwrap_or(0);
+        let interval = self
+            .config
+            .load_summarization_config(conversation_id)
+            .interval_turns;
+        if MemoryEngine::should_summarize(turn_count, interval) {
+            tasks.enqueue(BackgroundJobKind::Summarization, conversation_id);
+        }
+        true
+    }
 
+    /// extract_and_store_facts 的内部实现
+    async fn extract_and_store_facts_inner(&self, knowledge_id: &str, conv: &Conversation) {
         // 获取最近 10 条非 system 消息
         let recent_messages: Vec<Message> = conv
             .messages
@@ -850,55 +1907,54 @@ impl ChatEngine {
             return;
         }
 
-        let existing_facts = self.knowledge_store.get_all_facts(conversation_id);
+        // 用户档案也列为已有事实，避免每个对话重新提取一遍
+        let mut existing_facts = self.knowledge_store.get_all_facts_async(knowledge_id).await;
+        existing_facts.extend(
+            self.knowledge_store
+                .profile_facts_for(&existing_facts, conv.persona_id.as_deref()),
+        );
 
         // 构建事实提取 prompt
         let prompt =
             KnowledgeStore::build_fact_extraction_prompt(&recent_messages, &existing_facts);
 
         let extract_messages = vec![
+            Message::system_prompt(
+                "你是一个精确的事实提取系统。从对话中提取可持久化存储的事实，严格输出JSON格式。",
+            ),
             Message {
                 id: String::new(),
-                role: MessageRole::System,
-                content:
-                    "你是一个精确的事实提取系统。从对话中提取可持久化存储的事实，严格输出JSON格式。"
-                        .to_string(),
-                thinking_content: None,
-                model: "system".to_string(),
-                timestamp: 0,
-                message_type: MessageType::Say,
-            },
-            Message {
-                id: String::new(),
-                role: MessageRole::User,
-                content: prompt,
-                thinking_content: None,
-                model: "glm-4.7-flash".to_string(),
                 timestamp: 0,
-                message_type: MessageType::Say,
+                ..Message::new(MessageRole::User, prompt, "glm-4.7-flash")
             },
         ];
 
         let request_body = Self::build_request_body(&extract_messages, "glm-4.7-flash", false);
 
-        let token = {
-            let mut auth = self.jwt_auth.lock().unwrap();
-            auth.get_token()
-        };
-
         // 静默执行，不向前端发送事件
         let silent_event = |_event: ChatStreamEvent| {};
-        let _ = on_event;
 
-        if let Ok((text, _)) =
-            StreamingHandler::stream_chat(BIGMODEL_API_URL, &token, request_body, &silent_event)
-                .await
+        match StreamingHandler::stream_chat(self.provider.as_ref(), request_body, &silent_event)
+            .await
         {
-            let turn = conv.turn_count;
-            let new_facts = KnowledgeStore::parse_extracted_facts(&text, turn);
-            if !new_facts.is_empty() {
-                let _ = self.knowledge_store.add_facts(conversation_id, new_facts);
+            Ok((text, _)) => {
+                let turn = conv.turn_count;
+                let mut new_facts = KnowledgeStore::parse_extracted_facts(&text, turn);
+                for fact in &mut new_facts {
+                    fact.persona_id = conv.persona_id.clone();
+                }
+                if !new_facts.is_empty() {
+                    if let Err(e) = self
+                        .knowledge_store
+                        .add_facts_async(knowledge_id, new_facts)
+                        .await
+                    {
+                        tracing::warn!(knowledge_id, error = %e, "提取的事实写入失败");
+                    }
+                    self.refresh_embeddings(knowledge_id).await;
+                }
             }
+            Err(e) => tracing::warn!(knowledge_id, error = %e, "事实提取请求失败"),
         }
     }
 
@@ -912,6 +1968,16 @@ impl ChatEngine {
         messages: &[Message],
         model: &str,
         enable_thinking: bool,
+    ) -> serde_json::Value {
+        Self::build_request_body_with(messages, model, enable_thinking, &RequestTuning::default())
+    }
+
+    /// 同 build_request_body，额外按 RequestTuning 调整生成参数
+    pub fn build_request_body_with(
+        messages: &[Message],
+        model: &str,
+        enable_thinking: bool,
+        tuning: &RequestTuning,
     ) -> serde_json::Value {
         // ── 合并所有 system 消息为单条 ──
         let system_content: String = messages
@@ -940,7 +2006,7 @@ impl ChatEngine {
             };
             api_messages.push(serde_json::json!({
                 "role": role,
-                "content": m.content,
+                "content": AttachmentStore::text_with_captions(m),
             }));
         }
 
@@ -960,35 +2026,53 @@ impl ChatEngine {
             }
             merged_api_messages.push(msg);
         }
-        let api_messages = merged_api_messages;
+        let mut api_messages = merged_api_messages;
+
+        // ═══ 多模态：视觉模型请求中，本轮用户消息附带图片 ═══
+        // 只有最后一条用户消息上传图片，历史图片已以描述文字出现在正文中
+        if model == attachments::VISION_MODEL {
+            let images: Vec<&str> = messages
+                .iter()
+                .rev()
+                .find(|m| m.role == MessageRole::User)
+                .map(|m| m.attachments.iter().filter_map(|a| a.url.as_deref()).collect())
+                .unwrap_or_default();
+            let last_user = api_messages.iter_mut().rev().find(|m| m["role"] == "user");
+            if let (false, Some(last_user)) = (images.is_empty(), last_user) {
+                let text = last_user["content"].as_str().unwrap_or("").to_string();
+                let mut parts = vec![serde_json::json!({"type": "text", "text": text})];
+                parts.extend(images.iter().map(|url| {
+                    serde_json::json!({"type": "image_url", "image_url": {"url": url}})
+                }));
+                last_user["content"] = serde_json::json!(parts);
+            }
+        }
         // ═══ 动态 max_tokens 计算 ═══
         // 参考: https://docs.bigmodel.cn/cn/guide/start/concept-param
         // 原则: input + output ≤ 100K（用户要求每次调用最多 100K token）
-        //
-        // 各模型最大 output token（官方文档）：
-        //   glm-4.7:       默认 65536, 最大 131072
-        //   glm-4.7-flash: 默认 65536, 最大 131072（同系列）
-        //   glm-4-air:     动态计算,  最大 4095
-        //   glm-4-long:    旧模型,    最大 4095
+        // 各模型最大 output token 见 ModelRegistry 的声明
         const TOTAL_TOKEN_BUDGET: usize = 100_000;
 
         let input_estimate = Self::estimate_token_count(messages);
 
-        let model_max_output: u32 = match model {
-            "glm-4.7" => 131072,
-            "glm-4.7-flash" => 131072,
-            "glm-4-air" => 4095,
-            "glm-4-long" => 4095,
-            _ => 16384,
-        };
+        let registry = ModelRegistry::global();
+        let model_max_output = registry.max_output_tokens(model);
 
         // 可用输出 = 总预算 − 输入估算，下限 1024，上限为模型最大输出
-        let available_output = if TOTAL_TOKEN_BUDGET > input_estimate + 1024 {
+        // 长文共写模式下限抬高到 LONG_FORM_MIN_OUTPUT_TOKENS
+        let (min_output, over_budget_output) = if tuning.long_form {
+            (LONG_FORM_MIN_OUTPUT_TOKENS, LONG_FORM_MIN_OUTPUT_TOKENS)
+        } else {
+            (1024u32, 2048u32)
+        };
+        let available_output = if TOTAL_TOKEN_BUDGET > input_estimate + min_output as usize {
             (TOTAL_TOKEN_BUDGET - input_estimate) as u32
         } else {
-            2048u32 // 最低保障：即使上下文超预算，也保留 2K 输出空间
+            over_budget_output // 最低保障：即使上下文超预算，也保留输出空间
         };
-        let max_tokens: u32 = available_output.min(model_max_output).max(1024);
+        let max_tokens: u32 = available_output
+            .min(model_max_output)
+            .max(min_output.min(model_max_output));
 
         let mut body = serde_json::json!({
             "model": model,
@@ -997,31 +2081,45 @@ impl ChatEngine {
             "max_tokens": max_tokens,
         });
 
+        // ═══ 反重复采样惩罚 ═══
+        // 由多样性检测驱动：检测到模式固化时在采样层面压制重复，而不只是追加提示词
+        if tuning.frequency_penalty > 0.0 {
+            body["frequency_penalty"] = serde_json::json!(tuning.frequency_penalty);
+        }
+        if tuning.presence_penalty > 0.0 {
+            body["presence_penalty"] = serde_json::json!(tuning.presence_penalty);
+        }
+
+        // ═══ 联网搜索 ═══
+        // 服务端检索，结果以参考资料形式交给模型，由 search_prompt 约束用法
+        if tuning.web_search {
+            body["tools"] = serde_json::json!([WebSearchGate::build_tool()]);
+        }
+
         // ═══ Thinking 模式控制 ═══
         // 参考: https://docs.bigmodel.cn/cn/guide/capabilities/thinking-mode
         //
-        // GLM-4.7: 默认开启 Thinking，必须显式 disabled 才能关闭
-        // GLM-4-AIR: 推理模型，按用户偏好开关
-        // GLM-4.7-FLASH: 快速模型，显式 disabled
-        // 其他模型: 不发送 thinking 字段（旧模型不支持）
+        // 写法见 ModelRegistry 的声明：
+        //   Toggle:   按用户偏好开关（GLM-4.7 默认开启，必须显式 disabled 才能关闭）
+        //   Disabled: 始终显式 disabled
+        //   Omit:     不发送 thinking 字段（旧模型不支持）
         //
         // budget_tokens: 思考预算（官方文档推荐），防止思考无限消耗 token
-        match model {
-            "glm-4.7" | "glm-4-air" => {
+        match registry.thinking_field(model) {
+            ThinkingField::Toggle { budget_tokens } => {
                 if Self::should_enable_thinking(model, enable_thinking) {
-                    let budget = if model == "glm-4-air" { 10240 } else { 16384 };
                     body["thinking"] = serde_json::json!({
                         "type": "enabled",
-                        "budget_tokens": budget
+                        "budget_tokens": budget_tokens
                     });
                 } else {
                     body["thinking"] = serde_json::json!({"type": "disabled"});
                 }
             }
-            "glm-4.7-flash" => {
+            ThinkingField::Disabled => {
                 body["thinking"] = serde_json::json!({"type": "disabled"});
             }
-            _ => {}
+            ThinkingField::Omit => {}
         }
 
         body
@@ -1034,17 +2132,24 @@ impl ChatEngine {
     ///   层3: 情感状态追踪（基于最近对话推断当前情绪基线）
     ///   层4: 对话历史窗口（最近 20 条消息）
     ///   层5: 风格约束（say/do 模式提示）
+    ///
+    /// semantic 为本轮查询向量，有值时记忆检索额外融合向量相似度；
+    /// lore_entries 为对话可用的世界设定，命中触发词的按各自深度插入历史窗口；
+    /// mood 为角色延续下来的心情（已衰减到当前时间），随第 3 层注入
     pub fn build_context_enhanced_messages(
         conv: &Conversation,
         user_content: &str,
         memory_summaries: &[MemorySummary],
+        semantic: Option<&SemanticQuery>,
+        lore_entries: &[LoreEntry],
+        mood: Option<&MoodState>,
     ) -> Vec<Message> {
         let mut enhanced_messages: Vec<Message> = Vec::new();
 
-        // 层1: 保留角色 system 消息（身份锚定）
+        // 层1: 保留角色 system 消息（身份锚定；导演指令另行注入）
         let mut system_token_budget: usize = 0;
         for msg in &conv.messages {
-            if msg.role == MessageRole::System {
+            if msg.role == MessageRole::System && msg.message_type != MessageType::OutOfCharacter {
                 enhanced_messages.push(msg.clone());
                 system_token_budget += msg.content.len() / 2;
                 break;
@@ -1065,7 +2170,7 @@ impl ChatEngine {
         let short_term = MemoryEngine::build_short_term_context(&conv.messages);
 
         // 步骤 2.2：注入短期记忆（情感弧线 + 未展开线索）
-        {
+        if conv.context_layers.short_term_memory {
             let mut short_term_prompt = String::new();
 
             // 情感弧线描述
@@ -1090,15 +2195,7 @@ impl ChatEngine {
 
             if !short_term_prompt.is_empty() {
                 system_token_budget += short_term_prompt.len() / 2;
-                enhanced_messages.push(Message {
-                    id: String::new(),
-                    role: MessageRole::System,
-                    content: short_term_prompt,
-                    thinking_content: None,
-                    model: "system".to_string(),
-                    timestamp: 0,
-                    message_type: MessageType::Say,
-                });
+                enhanced_messages.push(Message::system_prompt(short_term_prompt));
             }
         }
 
@@ -1108,11 +2205,17 @@ impl ChatEngine {
             let active_topics = MemoryEngine::extract_active_topics_from_text(user_content);
 
             // 检索与当前话题最相关的记忆摘要（BM25 + 语义融合）
-            let search_results = MemoryEngine::search_memories(user_content, memory_summaries, 5);
+            let starred_turns = MemoryEngine::starred_turns(&conv.messages, conv.turn_count);
+            let search_results = MemoryEngine::search_memories_semantic(
+                user_content,
+                memory_summaries,
+                5,
+                semantic,
+                &starred_turns,
+            );
 
-            // 收集所有核心事实并按层级+相关性分类
-            let mut identity_facts: Vec<String> = Vec::new(); // 身份事实（始终注入）
-            let mut relevant_facts: Vec<(String, f64)> = Vec::new(); // 其他事实（相关性门控）
+            // 身份事实在稳定前缀中注入（见 ContextCache），这里只收集其他事实（相关性门控）
+            let mut relevant_facts: Vec<(String, f64)> = Vec::new();
 
             for summary in memory_summaries.iter() {
                 for (i, fact) in summary.core_facts.iter().enumerate() {
@@ -1123,12 +2226,7 @@ impl ChatEngine {
                     };
 
                     match tier {
-                        MemoryTier::Identity => {
-                            // 身份事实始终保留（核心锚点）
-                            if !identity_facts.contains(fact) {
-                                identity_facts.push(fact.clone());
-                            }
-                        }
+                        MemoryTier::Identity => {}
                         _ => {
                             // 其他事实通过相关性评分门控
                             let relevance = MemoryEngine::compute_relevance_score(
@@ -1174,14 +2272,6 @@ impl ChatEngine {
                 }
             }
 
-            // 注入身份锚点（始终存在，但以背景方式提供）
-            if !identity_facts.is_empty() {
-                context.push_str("▸ 基础设定（背景知识）：\n");
-                for fact in &identity_facts {
-                    context.push_str(&format!("  ● {}\n", fact));
-                }
-            }
-
             // 注入相关性达标的其他事实
             if !relevant_facts.is_empty() {
                 context
@@ -1201,15 +2291,7 @@ impl ChatEngine {
             );
 
             system_token_budget += context.len() / 2;
-            enhanced_messages.push(Message {
-                id: String::new(),
-                role: MessageRole::System,
-                content: context,
-                thinking_content: None,
-                model: "system".to_string(),
-                timestamp: 0,
-                message_type: MessageType::Say,
-            });
+            enhanced_messages.push(Message::system_prompt(context));
         }
 
         // 层3: 认知思维引擎（替代简单的情感关键词匹配和连贯性检测）
@@ -1220,7 +2302,7 @@ impl ChatEngine {
             .filter(|m| m.role != MessageRole::System)
             .collect();
 
-        if non_system.len() >= 2 {
+        if non_system.len() >= 2 && conv.context_layers.cognitive_snapshot {
             let cognitive_analysis = CognitiveEngine::analyze(&non_system);
             let pattern_labels = if cognitive_analysis.detected_patterns.is_empty() {
                 "无".to_string()
@@ -1250,18 +2332,19 @@ impl ChatEngine {
             );
             if !cognitive_prompt.is_empty() {
                 system_token_budget += cognitive_prompt.len() / 2;
-                enhanced_messages.push(Message {
-                    id: String::new(),
-                    role: MessageRole::System,
-                    content: cognitive_prompt,
-                    thinking_content: None,
-                    model: "system".to_string(),
-                    timestamp: 0,
-                    message_type: MessageType::Say,
-                });
+                enhanced_messages.push(Message::system_prompt(cognitive_prompt));
             }
         }
 
+        // 层3（续）: 角色延续下来的心情 — 不依赖本轮消息数，重新打开对话时同样生效
+        let mood_prompt = mood
+            .filter(|_| conv.context_layers.cognitive_snapshot)
+            .and_then(|m| MoodStore::build_prompt(m, chrono::Utc::now().timestamp_millis()));
+        if let Some(mood_prompt) = mood_prompt {
+            system_token_budget += mood_prompt.len() / 2;
+            enhanced_messages.push(Message::system_prompt(mood_prompt));
+        }
+
         // 层4: 添加最近的对话消息，动态调整数量以适应上下文窗口
         // 用户要求每次调用最多 100K token（input + output），
         // 这里预留 ~20K 给 output（max_tokens），input 上限 80K
@@ -1292,21 +2375,41 @@ impl ChatEngine {
         }
 
         selected_messages.reverse();
+
+        // 层4.5: 世界设定 — 独立预算，不占用上面的历史窗口
+        let lore_scan = LorebookStore::scan_text(&conv.messages, user_content);
+        let triggered_lore = LorebookStore::select(lore_entries, &lore_scan);
+        if !triggered_lore.is_empty() {
+            let mut depths: Vec<u32> = triggered_lore.iter().map(|e| e.insertion_depth).collect();
+            depths.sort_unstable_by(|a, b| b.cmp(a));
+            depths.dedup();
+            // 由深到浅插入：已插入的设定都在更靠前的位置，不影响后续按末尾计数
+            for depth in depths {
+                let group: Vec<&LoreEntry> = triggered_lore
+                    .iter()
+                    .copied()
+                    .filter(|e| e.insertion_depth == depth)
+                    .collect();
+                let at = selected_messages
+                    .len()
+                    .saturating_sub(depth as usize);
+                selected_messages.insert(
+                    at,
+                    Message::system_prompt(LorebookStore::build_prompt(&group)),
+                );
+            }
+        }
         enhanced_messages.extend(selected_messages);
 
         // 层5: 风格约束（say/do 模式提示）— 由调用方在外部注入
         // 层5.5: 回复多样性约束（防止 AI 回复模式固化）
-        let diversity_hint = Self::build_diversity_hint(&non_system);
+        let diversity_hint = if conv.context_layers.diversity_hint {
+            Self::build_diversity_hint(&non_system)
+        } else {
+            String::new()
+        };
         if !diversity_hint.is_empty() {
-            enhanced_messages.push(Message {
-                id: String::new(),
-                role: MessageRole::System,
-                content: diversity_hint,
-                thinking_content: None,
-                model: "system".to_string(),
-                timestamp: 0,
-                message_type: MessageType::Say,
-            });
+            enhanced_messages.push(Message::system_prompt(diversity_hint));
         }
 
         enhanced_messages
@@ -1316,27 +2419,7 @@ impl ChatEngine {
     /// 使用回复指纹系统检测模式固化，生成具体的反公式化建议
     /// 检测维度：开头模式、结尾模式、长度、段落结构、情感基调、动作描写、列表格式
     fn build_diversity_hint(recent_messages: &[&Message]) -> String {
-        let ai_messages: Vec<&&Message> = recent_messages
-            .iter()
-            .filter(|m| m.role == MessageRole::Assistant)
-            .collect();
-
-        if ai_messages.len() < 3 {
-            return String::new();
-        }
-
-        // 使用回复指纹系统进行结构化分析
-        let fingerprints: Vec<super::memory_engine::ResponseFingerprint> = ai_messages
-            .iter()
-            .rev()
-            .take(5)
-            .map(|m| MemoryEngine::fingerprint_response(&m.content))
-            .collect::<Vec<_>>()
-            .into_iter()
-            .rev()
-            .collect();
-
-        let pattern_suggestions = MemoryEngine::analyze_response_patterns(&fingerprints);
+        let pattern_suggestions = Self::detect_pattern_fixation(recent_messages);
 
         if pattern_suggestions.is_empty() {
             return String::new();
@@ -1361,15 +2444,113 @@ impl ChatEngine {
         hint
     }
 
-    /// 构建“真人感 + 内容密度 + 强上下文联系”的系统提示
-    /// 目标：
-    /// 1) 避免模板化、客服化回复
-    /// 2) 根据用户输入复杂度动态控制回复长度
+    /// 基于最近 5 条 AI 回复的指纹检测模式固化，返回打破建议（不足 3 条时为空）
+    /// 用户亲自撰写的回复（操偶模式）不计入，避免把用户的写法误判为模型套路
+    fn detect_pattern_fixation(recent_messages: &[&Message]) -> Vec<String> {
+        let ai_messages: Vec<&&Message> = recent_messages
+            .iter()
+            .filter(|m| m.role == MessageRole::Assistant && !ConversationStore::is_user_authored(m))
+            .collect();
+
+        if ai_messages.len() < 3 {
+            return Vec::new();
+        }
+
+        // 使用回复指纹系统进行结构化分析
+        let fingerprints: Vec<super::memory_engine::ResponseFingerprint> = ai_messages
+            .iter()
+            .rev()
+            .take(5)
+            .map(|m| MemoryEngine::fingerprint_response(&m.content))
+            .collect::<Vec<_>>()
+            .into_iter()
+            .rev()
+            .collect();
+
+        MemoryEngine::analyze_response_patterns(&fingerprints)
+    }
+
+    /// 在 blocking 线程池上构建上下文（记忆检索 + 回复指纹分析均为 CPU 密集）
+    async fn build_context_enhanced_messages_offloaded(
+        conv: &Conversation,
+        user_content: &str,
+        memory_summaries: Vec<MemorySummary>,
+        semantic: Option<SemanticQuery>,
+        lore_entries: Vec<LoreEntry>,
+        mood: Option<MoodState>,
+    ) -> Vec<Message> {
+        let conv = conv.clone();
+        let user_content = user_content.to_string();
+        blocking_pool::offload("context_build", move || {
+            Self::build_context_enhanced_messages(
+                &conv,
+                &user_content,
+                &memory_summaries,
+                semantic.as_ref(),
+                &lore_entries,
+                mood.as_ref(),
+            )
+        })
+        .await
+    }
+
+    /// 在 blocking 线程池上做认知分析并挑选即时反应
+    async fn choose_reaction_offloaded(
+        conv: &Conversation,
+        user_content: &str,
+    ) -> Option<ReactionEvent> {
+        let recent: Vec<Message> = conv
+            .messages
+            .iter()
+            .filter(|m| m.role != MessageRole::System)
+            .cloned()
+            .collect();
+        let user_content = user_content.to_string();
+        blocking_pool::offload("reaction", move || {
+            let refs: Vec<&Message> = recent.iter().collect();
+            let analysis = CognitiveEngine::analyze(&refs);
+            CognitiveEngine::choose_reaction(&analysis, &user_content)
+        })
+        .await
+    }
+
+    /// 在 blocking 线程池上计算采样惩罚（回复指纹分析）
+    async fn compute_repetition_penalties_offloaded(conv: &Conversation) -> (f64, f64) {
+        let recent: Vec<Message> = conv
+            .messages
+            .iter()
+            .filter(|m| m.role != MessageRole::System)
+            .cloned()
+            .collect();
+        blocking_pool::offload("repetition_penalties", move || {
+            let refs: Vec<&Message> = recent.iter().collect();
+            Self::compute_repetition_penalties(&refs)
+        })
+        .await
+    }
+
+    /// 根据模式固化程度计算下一轮的采样惩罚 (frequency_penalty, presence_penalty)
+    /// 固化项越多惩罚越高；无固化时为 (0, 0)，请求体不携带惩罚字段
+    pub fn compute_repetition_penalties(recent_messages: &[&Message]) -> (f64, f64) {
+        let fixations = Self::detect_pattern_fixation(recent_messages).len();
+        if fixations == 0 {
+            return (0.0, 0.0);
+        }
+        let frequency = (fixations as f64 * PENALTY_STEP_PER_PATTERN).min(MAX_FREQUENCY_PENALTY);
+        (frequency, frequency / 2.0)
+    }
+
+    /// 构建“真人感 + 内容密度 + 强上下文联系”的系统提示
+    /// 目标：
+    /// 1) 避免模板化、客服化回复
+    /// 2) 根据用户输入复杂度动态控制回复长度
     /// 3) 保证至少锚定一个当前消息细节 + 一个历史上下文线索
     fn build_humanization_hint(
         user_content: &str,
         recent_messages: &[&Message],
         message_type: &MessageType,
+        style: &ResponseStyle,
+        prompt_vars: &TemplateVars,
     ) -> String {
         let user_len = user_content.chars().count();
         let lower = user_content.to_lowercase();
@@ -1425,7 +2606,7 @@ impl ChatEngine {
         // 分析最近AI回复的结构模式，生成针对性的变化指导
         let ai_recent: Vec<&&Message> = recent_messages
             .iter()
-            .filter(|m| m.role == MessageRole::Assistant)
+            .filter(|m| m.role == MessageRole::Assistant && !ConversationStore::is_user_authored(m))
             .rev()
             .take(3)
             .collect();
@@ -1512,49 +2693,426 @@ impl ChatEngine {
                 "混合模式下动作和对话互相印证。总长度灵活，短则 30 字，长则 300+ 字",
                 "动作和台词要互相呼应：比如「说着话，手不自觉地攥紧了杯子」——动作泄露真实情绪",
             ),
+            MessageType::Document => (
+                "正文长度服从情节需要，一个完整场景通常 800 字以上",
+                "场景推进为主，对白与叙述交织，段落之间自然过渡",
+            ),
+            MessageType::Narration => (
+                "承接旁白的反应，30-200 字，随场景变化的大小伸缩",
+                "先让角色感知到场景的变化，再用动作和对白回应",
+            ),
+            MessageType::OutOfCharacter => (
+                "场外回答简短直接，通常 20-120 字",
+                "只回答场外问题，不夹带角色扮演内容",
+            ),
+        };
+
+        // 对话设置了风格偏好时，以偏好为准（Auto 项沿用上面的判断）
+        let length_rule = match style.reply_length {
+            ReplyLength::Auto => length_rule,
+            ReplyLength::Short => "回复简短：通常 10-50 字，一两句说完，不展开",
+            ReplyLength::Medium => "回复适中：通常 50-150 字，说清楚但不铺陈",
+            ReplyLength::Long => "回复充实：通常 150-400 字，细节与情绪都展开写",
+        };
+        let preferences: Vec<&str> = [
+            match style.formality {
+                Formality::Auto => None,
+                Formality::Casual => Some("- 用语口语随意，网络用语、语气词都可以用"),
+                Formality::Neutral => Some("- 用语自然得体，少用网络用语"),
+                Formality::Formal => Some("- 用语正式礼貌，不用网络用语，语气词尽量少"),
+            },
+            match style.emoji_usage {
+                EmojiUsage::Auto => None,
+                EmojiUsage::Never => Some("- 不使用任何表情符号或颜文字"),
+                EmojiUsage::Occasional => Some("- 偶尔用一个表情符号点缀，不要每句都用"),
+                EmojiUsage::Frequent => Some("- 多用表情符号和颜文字表达情绪"),
+            },
+            match style.action_frequency {
+                ActionFrequency::Auto => None,
+                ActionFrequency::Never => Some("- 不写动作或神态描写，只说话"),
+                ActionFrequency::Occasional => Some("- 偶尔穿插一句简短的动作描写"),
+                ActionFrequency::Frequent => Some("- 每次回复都带上动作或神态描写"),
+            },
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        let style_rule = preferences.join("\n");
+        let style_section = if style_rule.is_empty() {
+            String::new()
+        } else {
+            format!("\n═══ 对方设定的回复风格（优先于以上建议） ═══\n{}", style_rule)
+        };
+
+        let vars = prompt_vars
+            .clone()
+            .with("rhythm_guide", rhythm_guide)
+            .with("structure_guide", structure_guide.as_str())
+            .with("length_rule", length_rule)
+            .with("structure_rule", structure_rule)
+            .with("style_rule", style_rule.as_str());
+        prompt_templates::render_or("humanization", &vars, || {
+            format!(
+                "【人格内核 — 你不是在「扮演」，你「就是」这个人】\n\
+                 \n\
+                 ═══ 此刻的状态 ═══\n\
+                 {}\n\
+                 {}\n\
+                 {}\n\
+                 {}{}\n\
+                 \n\
+                 ═══ 你是一个有血有肉的人 ═══\n\
+                 你的情绪不是贴标签，而是自然流露在语气、用词、节奏中：\n\
+                 - 开心时语速快、用词跳跃、可能突然蹦出不相干的话\n\
+                 - 难过时话变少、句子变短、可能省略号变多\n\
+                 - 生气时可能说反话、语气变冲、或者刻意冷淡\n\
+                 - 你可以有矛盾的情绪：嘴上说「讨厌」但行为很在乎\n\
+                 \n\
+                 ═══ 说话方式 ═══\n\
+                 - 像微信聊天，不是写作文。口语、省略句、语气词都正常\n\
+                 - 真人会跑题、会打断自己、会说到一半改口\n\
+                 - 真人有联想——对方说了 A，你想到 B，自然聊到 B\n\
+                 - 不需要每句话都完整。「嗯」「啊这」「我靠」都是有效回复\n\
+                 - 不是每次都需要推进话题或提问。把话说完也行。自然停下也行\n\
+                 \n\
+                 ═══ 绝对禁止（每违反一条就暴露AI身份） ═══\n\
+                 - 「作为AI」「我理解你的感受」「加油哦」「没关系的」← 客服话术\n\
+                 - 1. 2. 3. 编号回答 ← 机器人行为\n\
+                 - 每次都先肯定再回应再提问 ← 三段式模板\n\
+                 - 重复对方的话再评价（「你说的'XX'让我...」）← 咨询模板\n\
+                 - 在情感场景给建议（对方说难过你说「试试做XX」）← 说教\n\
+                 - 没做错事就道歉 ← 讨好型AI\n\
+                 - 每条消息都表现极大热情和关心 ← 你有自己的情绪波动\n\
+                 - 没被问到的事主动说出来 ← 记忆是脑子里的，不是台词本\n\
+                 \n\
+                 ═══ 记忆使用原则 ═══\n\
+                 你脑子里有一些关于对方的信息，但真人不会时刻把这些挂在嘴边：\n\
+                 - 只有对话自然涉及时才提起\n\
+                 - 不要像数据库一样输出已知信息\n\
+                 - 可以在意想不到的时机提起某件小事——这才像真人\n\
+                 - 有些事你知道但选择性遗忘也完全正常\n",
+                rhythm_guide, structure_guide, length_rule, structure_rule, style_section
+            )
+        })
+    }
+
+    /// 提示词模板的公共变量（角色名、对方称呼、角色当前心情）与本轮用户消息
+    fn prompt_vars(&self, conv: &Conversation, user_content: &str) -> TemplateVars {
+        let mood = self
+            .mood
+            .current(&conv.id, chrono::Utc::now().timestamp_millis())
+            .map(|state| state.mood)
+            .unwrap_or_else(|| "平静".to_string());
+        let vars = TemplateVars::base(&conv.messages, &mood).with("user_message", user_content);
+        match self.active_persona(conv) {
+            Some(persona) => vars.with("user", persona.name),
+            None => vars,
+        }
+    }
+
+    /// 共写模式：以共写提示替代 say/do 风格提示与人格提示，插入到最后一条用户消息之前
+    fn inject_coauthor_prompt(
+        conv: &Conversation,
+        user_content: &str,
+        enhanced_messages: &mut Vec<Message>,
+    ) {
+        let coauthor_msg = Message::system_prompt(
+            CoAuthorEngine::build_coauthor_prompt(&conv.messages, user_content),
+        );
+        insert_before_last_user(enhanced_messages, coauthor_msg);
+    }
+
+    /// 尚未生效的导演指令：最后一条 AI 回复之后记入的 /ooc 指令
+    fn pending_directives(conv: &Conversation) -> Vec<&str> {
+        let start = conv
+            .messages
+            .iter()
+            .rposition(|m| m.role == MessageRole::Assistant)
+            .map_or(0, |idx| idx + 1);
+        conv.messages[start..]
+            .iter()
+            .filter(|m| m.role == MessageRole::System && m.message_type == MessageType::OutOfCharacter)
+            .map(|m| m.content.as_str())
+            .collect()
+    }
+
+    /// 把尚未生效的导演指令插到最后一条用户消息之前
+    fn inject_directives(conv: &Conversation, enhanced_messages: &mut Vec<Message>) {
+        let directives = Self::pending_directives(conv);
+        if directives.is_empty() {
+            return;
+        }
+        let directive_msg = Message {
+            message_type: MessageType::OutOfCharacter,
+            ..Message::system_prompt(QuickCommand::build_directive_prompt(&directives))
+        };
+        insert_before_last_user(enhanced_messages, directive_msg);
+    }
+
+    /// 发送消息（管线见 send_message_inner），结束后无论成功与否都落盘本轮降级决策。
+    /// attachments 为随消息发送的图片（见 attachments）；带图片的消息不解析快捷命令
+    #[allow(clippy::too_many_arguments)]
+    #[tracing::instrument(skip_all, fields(conversation_id, model = chat_model))]
+    pub async fn send_message(
+        &self,
+        conversation_id: &str,
+        content: &str,
+        attachments: Vec<MessageAttachment>,
+        chat_model: &str,
+        thinking_model: &str,
+        enable_thinking: bool,
+        ambient: Option<&AmbientContext>,
+        on_event: impl Fn(ChatStreamEvent),
+    ) -> Result<(), ChatError> {
+        let (chat_model, thinking_model) =
+            self.refresh_turn_models(chat_model, thinking_model, &on_event);
+        let enable_thinking = self.effective_thinking(&chat_model, enable_thinking);
+        let on_event = Self::marking_first_token(on_event);
+        let command = if attachments.is_empty() {
+            QuickCommand::parse(content)
+        } else {
+            None
+        };
+        let result = match command {
+            Some(command) => {
+                self.run_quick_command(
+                    conversation_id,
+                    command,
+                    &chat_model,
+                    &thinking_model,
+                    enable_thinking,
+                    ambient,
+                    on_event,
+                )
+                .await
+            }
+            None => {
+                metrics::track_turn(
+                    conversation_id,
+                    self.send_message_inner(
+                        conversation_id,
+                        content,
+                        attachments,
+                        &chat_model,
+                        &thinking_model,
+                        enable_thinking,
+                        ambient,
+                        on_event,
+                    ),
+                )
+                .await
+            }
+        };
+        self.flush_decisions(conversation_id);
+        result
+    }
+
+    /// 执行快捷命令：/regen、/continue 走完整管线，其余在本地完成，
+    /// 结果以 SystemNotice 事件返回（不写入对话）
+    #[allow(clippy::too_many_arguments)]
+    async fn run_quick_command(
+        &self,
+        conversation_id: &str,
+        command: QuickCommand,
+        chat_model: &str,
+        thinking_model: &str,
+        enable_thinking: bool,
+        ambient: Option<&AmbientContext>,
+        on_event: impl Fn(ChatStreamEvent),
+    ) -> Result<(), ChatError> {
+        let conv = self.conversation_store.load_active_branch_async(conversation_id).await?;
+        let notice = match command {
+            QuickCommand::Regen => {
+                // 丢掉上一条回复（若最后一条已是用户消息则原样保留）再重新生成
+                if let Some(last) = conv.messages.last() {
+                    self.conversation_store
+                        .prepare_regeneration(conversation_id, &last.id, false)?;
+                }
+                return self
+                    .regenerate_response_inner(
+                        conversation_id,
+                        chat_model,
+                        thinking_model,
+                        enable_thinking,
+                        on_event,
+                    )
+                    .await;
+            }
+            QuickCommand::Continue => {
+                // 共写模式下空输入即续写指令
+                let nudge = if conv.mode == ConversationMode::CoAuthor {
+                    ""
+                } else {
+                    CONTINUE_NUDGE
+                };
+                return self
+                    .send_message_inner(
+                        conversation_id,
+                        nudge,
+                        Vec::new(),
+                        chat_model,
+                        thinking_model,
+                        enable_thinking,
+                        ambient,
+                        on_event,
+                    )
+                    .await;
+            }
+            QuickCommand::Recap => {
+                let summaries = self
+                    .memory_engine
+                    .load_memory_index_async(conversation_id)
+                    .await
+                    .unwrap_or_default();
+                QuickCommand::build_recap(&summaries, conv.turn_count)
+            }
+            QuickCommand::Mood => {
+                let history: Vec<&Message> = conv
+                    .messages
+                    .iter()
+                    .filter(|m| m.role != MessageRole::System)
+                    .collect();
+                if history.is_empty() {
+                    "还没有开始聊天，暂时看不出情绪。".to_string()
+                } else {
+                    QuickCommand::describe_mood(&CognitiveEngine::analyze(&history))
+                }
+            }
+            QuickCommand::Remember(text) if text.is_empty() => {
+                "用法：/remember 要记住的内容".to_string()
+            }
+            QuickCommand::Remember(text) => {
+                let mut fact = KnowledgeStore::user_fact(&text, conv.turn_count);
+                fact.persona_id = conv.persona_id.clone();
+                self.knowledge_store.add_facts_async(conversation_id, vec![fact]).await?;
+                self.refresh_embeddings(conversation_id).await;
+                format!("已记住：{}", text)
+            }
+            QuickCommand::Draw(hint) => {
+                return self
+                    .illustrate_scene(conversation_id, &hint, chat_model, on_event)
+                    .await;
+            }
+            QuickCommand::Ooc(text) if text.is_empty() => "用法：/ooc 给导演的指令".to_string(),
+            QuickCommand::Ooc(text) => {
+                let directive = Message {
+                    message_type: MessageType::OutOfCharacter,
+                    ..Message::new(MessageRole::System, text, "system")
+                };
+                self.conversation_store.add_message_async(conversation_id, directive).await?;
+                "导演指令已记下，角色下一次回复时生效".to_string()
+            }
+            QuickCommand::Help => QuickCommand::help_text().to_string(),
+            QuickCommand::Unknown(name) => {
+                format!("未知命令 /{}\n{}", name, QuickCommand::help_text())
+            }
+        };
+        on_event(ChatStreamEvent::SystemNotice(notice));
+        on_event(ChatStreamEvent::Done);
+        Ok(())
+    }
+
+    /// 场景插画：对话模型写画面描述 → CogView 出图 → 作为 AI 消息落盘，
+    /// 通过 Illustration 事件推送；失败只以 SystemNotice 提示，不写入对话
+    pub async fn illustrate_scene(
+        &self,
+        conversation_id: &str,
+        hint: &str,
+        chat_model: &str,
+        on_event: impl Fn(ChatStreamEvent),
+    ) -> Result<(), ChatError> {
+        let Some(illustrator) = self.illustrator.as_ref() else {
+            on_event(ChatStreamEvent::SystemNotice(
+                "当前服务商不支持插画生成，需要使用智谱 API Key".to_string(),
+            ));
+            on_event(ChatStreamEvent::Done);
+            return Ok(());
+        };
+        let conv = self.conversation_store.load_active_branch_async(conversation_id).await?;
+        if conv.messages.iter().all(|m| m.role == MessageRole::System) && hint.trim().is_empty() {
+            on_event(ChatStreamEvent::SystemNotice(
+                "还没有可以画的场景，先聊几句或写上想画的内容：/draw 描述".to_string(),
+            ));
+            on_event(ChatStreamEvent::Done);
+            return Ok(());
+        }
+
+        let describe = Message::new(
+            MessageRole::User,
+            CogViewClient::build_describe_prompt(&conv.messages, hint),
+            chat_model,
+        );
+        let mut request_body = Self::build_request_body(&[describe], chat_model, false);
+        request_body["max_tokens"] = serde_json::json!(illustration::DESCRIBE_MAX_TOKENS);
+        let described = tokio::time::timeout(
+            std::time::Duration::from_secs(illustration::DESCRIBE_TIMEOUT_SECS),
+            StreamingHandler::complete_silently(self.provider.as_ref(), request_body),
+        )
+        .await;
+        let prompt = match described {
+            Ok(Ok(text)) if !text.trim().is_empty() => text.trim().to_string(),
+            _ => CogViewClient::fallback_prompt(&conv.messages, hint),
         };
 
-        format!(
-            "【人格内核 — 你不是在「扮演」，你「就是」这个人】\n\
-             \n\
-             ═══ 此刻的状态 ═══\n\
-             {}\n\
-             {}\n\
-             {}\n\
-             {}\n\
-             \n\
-             ═══ 你是一个有血有肉的人 ═══\n\
-             你的情绪不是贴标签，而是自然流露在语气、用词、节奏中：\n\
-             - 开心时语速快、用词跳跃、可能突然蹦出不相干的话\n\
-             - 难过时话变少、句子变短、可能省略号变多\n\
-             - 生气时可能说反话、语气变冲、或者刻意冷淡\n\
-             - 你可以有矛盾的情绪：嘴上说「讨厌」但行为很在乎\n\
-             \n\
-             ═══ 说话方式 ═══\n\
-             - 像微信聊天，不是写作文。口语、省略句、语气词都正常\n\
-             - 真人会跑题、会打断自己、会说到一半改口\n\
-             - 真人有联想——对方说了 A，你想到 B，自然聊到 B\n\
-             - 不需要每句话都完整。「嗯」「啊这」「我靠」都是有效回复\n\
-             - 不是每次都需要推进话题或提问。把话说完也行。自然停下也行\n\
-             \n\
-             ═══ 绝对禁止（每违反一条就暴露AI身份） ═══\n\
-             - 「作为AI」「我理解你的感受」「加油哦」「没关系的」← 客服话术\n\
-             - 1. 2. 3. 编号回答 ← 机器人行为\n\
-             - 每次都先肯定再回应再提问 ← 三段式模板\n\
-             - 重复对方的话再评价（「你说的'XX'让我...」）← 咨询模板\n\
-             - 在情感场景给建议（对方说难过你说「试试做XX」）← 说教\n\
-             - 没做错事就道歉 ← 讨好型AI\n\
-             - 每条消息都表现极大热情和关心 ← 你有自己的情绪波动\n\
-             - 没被问到的事主动说出来 ← 记忆是脑子里的，不是台词本\n\
-             \n\
-             ═══ 记忆使用原则 ═══\n\
-             你脑子里有一些关于对方的信息，但真人不会时刻把这些挂在嘴边：\n\
-             - 只有对话自然涉及时才提起\n\
-             - 不要像数据库一样输出已知信息\n\
-             - 可以在意想不到的时机提起某件小事——这才像真人\n\
-             - 有些事你知道但选择性遗忘也完全正常\n",
-            rhythm_guide, structure_guide, length_rule, structure_rule
+        let url = match illustrator.generate(&prompt).await {
+            Ok(url) => url,
+            Err(e) => {
+                on_event(ChatStreamEvent::SystemNotice(format!("插画生成失败：{}", e)));
+                on_event(ChatStreamEvent::Done);
+                return Ok(());
+            }
+        };
+        let attachments = self.attachments.persist(
+            conversation_id,
+            vec![MessageAttachment {
+                id: String::new(),
+                mime_type: "image/png".to_string(),
+                url: Some(url),
+                caption: Some(prompt),
+            }],
+        )?;
+        let message = Message {
+            attachments,
+            ..Message::new(
+                MessageRole::Assistant,
+                illustration::ILLUSTRATION_CONTENT.to_string(),
+                chat_model,
+            )
+        };
+        self.conversation_store
+            .add_message_async(conversation_id, message.clone())
+            .await?;
+        on_event(ChatStreamEvent::Illustration(message));
+        on_event(ChatStreamEvent::Done);
+        Ok(())
+    }
+
+    /// 重新生成AI回复（管线见 regenerate_response_inner），结束后落盘本轮降级决策
+    #[tracing::instrument(skip_all, fields(conversation_id, model = chat_model))]
+    pub async fn regenerate_response(
+        &self,
+        conversation_id: &str,
+        chat_model: &str,
+        thinking_model: &str,
+        enable_thinking: bool,
+        on_event: impl Fn(ChatStreamEvent),
+    ) -> Result<(), ChatError> {
+        let (chat_model, thinking_model) =
+            self.refresh_turn_models(chat_model, thinking_model, &on_event);
+        let enable_thinking = self.effective_thinking(&chat_model, enable_thinking);
+        let result = metrics::track_turn(
+            conversation_id,
+            self.regenerate_response_inner(
+                conversation_id,
+                &chat_model,
+                &thinking_model,
+                enable_thinking,
+                Self::marking_first_token(on_event),
+            ),
         )
+        .await;
+        self.flush_decisions(conversation_id);
+        result
     }
 
     /// Send a message: validate → detect type → persist user msg → build context →
@@ -1567,309 +3125,707 @@ impl ChatEngine {
     ///
     /// 单模型模式（enable_thinking=false 时）：
     ///   直接使用 chat_model 生成对话回复
-    pub async fn send_message(
+    #[allow(clippy::too_many_arguments)]
+    async fn send_message_inner(
         &self,
         conversation_id: &str,
         content: &str,
+        attachments: Vec<MessageAttachment>,
         chat_model: &str,
         thinking_model: &str,
         enable_thinking: bool,
+        ambient: Option<&AmbientContext>,
         on_event: impl Fn(ChatStreamEvent),
     ) -> Result<(), ChatError> {
+        let started_at = chrono::Utc::now().timestamp_millis();
+        // 发送前的内容安全检查：被拦截的消息不落盘
+        let input_flags = SafetyFilter::screen_input(&safety_filter::policy(), content);
+        for event in &input_flags {
+            on_event(ChatStreamEvent::Safety(event.clone()));
+        }
+        if SafetyFilter::is_blocked(&input_flags) {
+            return Err(ChatError::ValidationError {
+                message: SafetyFilter::blocked_notice(&input_flags),
+            });
+        }
+        if let Some(group) = self.group_chats.load(conversation_id) {
+            if !attachments.is_empty() {
+                return Err(ChatError::ValidationError {
+                    message: "Images are not supported in group chats".to_string(),
+                });
+            }
+            return self
+                .send_group_message(&group, content, chat_model, started_at, on_event)
+                .await;
+        }
+        // 共写模式：空输入视为「继续」；只发图片时补一句占位正文
+        let mode = self.conversation_store.load_conversation_async(conversation_id).await?.mode;
+        let content = if mode == ConversationMode::CoAuthor {
+            CoAuthorEngine::resolve_input(content)
+        } else if content.trim().is_empty() && !attachments.is_empty() {
+            attachments::IMAGE_ONLY_CONTENT.to_string()
+        } else {
+            content.to_string()
+        };
+        let content = content.as_str();
+
         Self::validate_message(content)?;
+        let attachments = self.attachments.persist(conversation_id, attachments)?;
+        let has_images = !attachments.is_empty();
 
         // 自动检测 say/do 类型
-        let message_type = Self::detect_message_type(content);
+        let message_type = self.detect_type_for(conversation_id, content);
 
         let user_msg = Message {
-            id: uuid::Uuid::new_v4().to_string(),
-            role: MessageRole::User,
-            content: content.to_string(),
-            thinking_content: None,
-            model: chat_model.to_string(),
-            timestamp: chrono::Utc::now().timestamp_millis(),
             message_type: message_type.clone(),
+            attachments,
+            ..Message::new(MessageRole::User, content, chat_model)
         };
         self.conversation_store
-            .add_message(conversation_id, user_msg)?;
+            .add_message_async(conversation_id, user_msg.clone())
+            .await?;
+
+        // 带图片的一轮：先识图写回描述，回复改由视觉模型以多模态内容生成
+        if has_images {
+            self.caption_attachments(conversation_id, &user_msg).await;
+        }
+        let chat_model = if has_images {
+            attachments::VISION_MODEL
+        } else {
+            chat_model
+        };
 
         // 增加轮次计数
         self.conversation_store
-            .increment_turn_count(conversation_id)?;
+            .increment_turn_count_async(conversation_id)
+            .await?;
 
-        let conv = self.conversation_store.load_conversation(conversation_id)?;
+        let conv = self.conversation_store.load_active_branch_async(conversation_id).await?;
+
+        // 即时反应：推理/检索耗时较长，先让角色对这条消息「有反应」
+        if conv.mode != ConversationMode::CoAuthor {
+            self.learn_blocked_topic(&conv);
+            if let Some(reaction) = Self::choose_reaction_offloaded(&conv, content).await {
+                on_event(ChatStreamEvent::Reaction(reaction));
+            }
+        }
 
         // 加载记忆索引
         let memory_summaries = self
             .memory_engine
-            .load_memory_index(conversation_id)
+            .load_memory_index_async(conversation_id)
+            .await
             .unwrap_or_default();
 
+        // 本轮检索用的查询向量（无向量后端或取向量失败时为 None，退回纯词法检索）
+        let semantic = self.semantic_query(conversation_id, content).await;
+
         // 构建上下文增强的消息列表
-        let mut enhanced_messages =
-            Self::build_context_enhanced_messages(&conv, content, &memory_summaries);
+        let identity_facts = ContextCache::identity_facts(&memory_summaries);
+        let mut enhanced_messages = Self::build_context_enhanced_messages_offloaded(
+            &conv,
+            content,
+            memory_summaries,
+            semantic.clone(),
+            self.lorebook.entries_for(conversation_id),
+            self.mood
+                .current(conversation_id, chrono::Utc::now().timestamp_millis()),
+        )
+        .await;
+        if has_images {
+            self.attachments.resolve_latest(conversation_id, &mut enhanced_messages);
+        }
+        if let Some((kept, expected)) = Self::history_truncation(&conv, &enhanced_messages) {
+            self.record_decision(
+                DegradationKind::ContextTruncated,
+                chat_model,
+                format!("历史窗口超出 token 预算，仅保留 {}/{} 条消息", kept, expected),
+            );
+        }
 
-        // 注入 say/do 模式提示（插入到最后一条用户消息之前，确保用户消息是最后一条）
-        let style_hint = SayDoDetector::build_style_prompt(&message_type);
-        let style_msg = Message {
-            id: String::new(),
-            role: MessageRole::System,
-            content: style_hint.to_string(),
-            thinking_content: None,
-            model: "system".to_string(),
-            timestamp: 0,
-            message_type: MessageType::Say,
-        };
-        // 找到最后一条用户消息的位置，将 style hint 插入到它之前
-        let last_user_idx = enhanced_messages
-            .iter()
-            .rposition(|m| m.role == MessageRole::User);
-        if let Some(idx) = last_user_idx {
-            enhanced_messages.insert(idx, style_msg);
+        let prompt_vars = self.prompt_vars(&conv, content);
+        Self::inject_directives(&conv, &mut enhanced_messages);
+        if conv.mode == ConversationMode::CoAuthor {
+            Self::inject_coauthor_prompt(&conv, content, &mut enhanced_messages);
         } else {
-            enhanced_messages.push(style_msg);
+            // 注入 say/do 模式提示（插入到最后一条用户消息之前，确保用户消息是最后一条）
+            let style_hint = prompt_templates::render_or(
+                prompt_templates::style_template_name(&message_type),
+                &prompt_vars,
+                || SayDoDetector::build_style_prompt(&message_type).to_string(),
+            );
+            let style_msg = Message::system_prompt(style_hint);
+            insert_before_last_user(&mut enhanced_messages, style_msg);
+
+            let non_system_for_hint: Vec<&Message> = conv
+                .messages
+                .iter()
+                .filter(|m| m.role != MessageRole::System)
+                .collect();
+            let quality_hint =
+                Self::build_humanization_hint(
+                    content,
+                    &non_system_for_hint,
+                    &message_type,
+                    &conv.response_style,
+                    &prompt_vars,
+                );
+            let quality_msg = Message::system_prompt(quality_hint);
+            if conv.context_layers.humanization_hint {
+                insert_before_last_user(&mut enhanced_messages, quality_msg);
+            }
         }
 
-        let non_system_for_hint: Vec<&Message> = conv
-            .messages
-            .iter()
-            .filter(|m| m.role != MessageRole::System)
-            .collect();
-        let quality_hint =
-            Self::build_humanization_hint(content, &non_system_for_hint, &message_type);
-        let quality_msg = Message {
-            id: String::new(),
-            role: MessageRole::System,
-            content: quality_hint,
-            thinking_content: None,
-            model: "system".to_string(),
-            timestamp: 0,
-            message_type: MessageType::Say,
-        };
-        let last_user_idx = enhanced_messages
-            .iter()
-            .rposition(|m| m.role == MessageRole::User);
-        if let Some(idx) = last_user_idx {
-            enhanced_messages.insert(idx, quality_msg);
-        } else {
-            enhanced_messages.push(quality_msg);
+        self.inject_intensity_prompt(&mut enhanced_messages);
+        Self::inject_narration_prompt(&conv, &mut enhanced_messages);
+        self.apply_stable_prefix(conversation_id, &conv, &identity_facts, &mut enhanced_messages);
+        self.inject_time_awareness(&conv, &mut enhanced_messages);
+        if conv.mode != ConversationMode::CoAuthor {
+            self.inject_blocked_topics_prompt(conversation_id, &mut enhanced_messages);
+            self.inject_reply_skeleton(&conv, content, &message_type, &mut enhanced_messages)
+                .await;
         }
 
+        // 注入宿主 App 提供的环境上下文（已做隐私过滤）
+        if let Some(ambient) = ambient {
+            let ambient_prompt = AmbientContextFilter::build_prompt(ambient);
+            if !ambient_prompt.is_empty() {
+                let ambient_msg = Message::system_prompt(ambient_prompt);
+                insert_before_last_user(&mut enhanced_messages, ambient_msg);
+            }
+        }
+
+        let (frequency_penalty, presence_penalty) =
+            Self::compute_repetition_penalties_offloaded(&conv).await;
+        let tuning = RequestTuning {
+            long_form: conv.mode == ConversationMode::CoAuthor,
+            frequency_penalty,
+            presence_penalty,
+            web_search: self.should_web_search(&conv, content),
+        };
+
         // ══ 四级模型管线：知识检索 → 长上下文蒸馏 → 深度推理 → 自然对话 ══
+        let fast_draft = enable_thinking && self.current_settings().fast_draft;
         let (full_content, full_thinking) = if enable_thinking {
-            // ── Phase 0.3: 本地知识库检索（纯本地，零延迟）──
-            self.retrieve_knowledge_context(conversation_id, content, &mut enhanced_messages);
-
-            // ── Phase 0.4: 读取已蒸馏的核心状态（若存在）──
-            if let Ok(Some(distilled_state)) =
-                self.memory_engine.load_distilled_state(conversation_id)
-            {
-                if !distilled_state.core_prompt.trim().is_empty() {
-                    let distilled_msg = Message {
-                        id: String::new(),
-                        role: MessageRole::System,
-                        content: format!(
-                            "【历史蒸馏核心状态（持久化）】\n{}\n",
-                            distilled_state.core_prompt
-                        ),
-                        thinking_content: None,
-                        model: "system".to_string(),
-                        timestamp: 0,
-                        message_type: MessageType::Say,
-                    };
-                    let last_user_idx = enhanced_messages
-                        .iter()
-                        .rposition(|m| m.role == MessageRole::User);
-                    if let Some(idx) = last_user_idx {
-                        enhanced_messages.insert(idx, distilled_msg);
-                    } else {
-                        enhanced_messages.push(distilled_msg);
+            // ── Phase 0.3–0.7 → Phase 1：GLM-4-AIR 推理必须看到检索到的知识、
+            // 已蒸馏的核心状态与长上下文蒸馏，所以排在上下文准备之后。
+            // 快速草稿只作展示，基于准备前的上下文与这条链并发流式输出 ──
+            let draft_base = enhanced_messages.clone();
+            let pipeline_base = std::mem::take(&mut enhanced_messages);
+            let ((context, (reasoning_conclusion, thinking_text)), _) = tokio::join!(
+                self.prepare_and_reason(
+                    &conv,
+                    conversation_id,
+                    content,
+                    semantic.as_ref(),
+                    thinking_model,
+                    &prompt_vars,
+                    &on_event,
+                    pipeline_base,
+                ),
+                async {
+                    if fast_draft {
+                        self.stream_fast_draft(&draft_base, &on_event).await;
                     }
-                }
+                },
+            );
+            enhanced_messages = context;
+
+            // ── Phase 2: 将推理结论注入上下文，供对话模型参考 ──
+            if !reasoning_conclusion.trim().is_empty() {
+                let reasoning_msg = Message::system_prompt(format!(
+                    "【深度推理分析结果（GLM-4-AIR + 本地知识库）】\n{}\n\n\
+                     ■ 执行指令：\n\
+                     基于以上分析和知识库事实，以角色身份自然地回复用户。\n\
+                     - 分析中提到的关键事实必须准确体现在回复中\n\
+                     - 知识库中的事实不可矛盾或篡改\n\
+                     - 分析建议的情感策略必须执行\n\
+                     - 不要在回复中提及分析过程本身\n\
+                     - 回复必须完整，不要截断或省略\n\
+                     - 像真人一样自然地表达，有情绪、有温度、有个性",
+                    reasoning_conclusion
+                ));
+                insert_before_last_user(&mut enhanced_messages, reasoning_msg);
             }
 
-            // ── Phase 0.5: 评估上下文复杂度，决定是否需要 GLM-4-LONG ──
-            let memory_summaries_for_assess = self
-                .memory_engine
-                .load_memory_index(conversation_id)
-                .unwrap_or_default();
-            let (needs_long_context, _total_tokens) =
-                Self::assess_context_needs(&enhanced_messages, &memory_summaries_for_assess);
-
-            // ── Phase 0.7: 长上下文蒸馏（GLM-4-LONG，仅在上下文超长时触发）──
-            if needs_long_context {
-                let distilled = self
-                    .request_long_context_distillation(
-                        &enhanced_messages,
-                        &memory_summaries_for_assess,
-                        content,
-                        &on_event,
-                    )
-                    .await;
-                if !distilled.trim().is_empty() {
-                    let core_facts_snapshot: Vec<String> = memory_summaries_for_assess
-                        .iter()
-                        .flat_map(|s| s.core_facts.clone())
-                        .collect();
-                    let mut hasher = DefaultHasher::new();
-                    let character_prompt = enhanced_messages
-                        .iter()
-                        .find(|m| m.role == MessageRole::System)
-                        .map(|m| m.content.as_str())
-                        .unwrap_or_default();
-                    character_prompt.hash(&mut hasher);
-                    let distilled_state = DistilledSystemState {
-                        core_prompt: distilled.clone(),
-                        last_memory_count: memory_summaries_for_assess.len(),
-                        last_max_compression_gen: memory_summaries_for_assess
-                            .iter()
-                            .map(|s| s.compression_generation)
-                            .max()
-                            .unwrap_or(0),
-                        character_prompt_hash: hasher.finish(),
-                        last_turn_count: conv.turn_count,
-                        distilled_at: chrono::Utc::now().timestamp_millis(),
-                        core_facts_snapshot,
-                    };
-                    let _ = self
-                        .memory_engine
-                        .save_distilled_state(conversation_id, &distilled_state);
-
-                    let distill_msg = Message {
-                        id: String::new(),
-                        role: MessageRole::System,
-                        content: format!(
-                            "【长上下文蒸馏摘要 — 以下为 GLM-4-LONG 整理的关键信息，必须严格遵守】\n{}\n",
-                            distilled
-                        ),
-                        thinking_content: None,
-                        model: "system".to_string(),
-                        timestamp: 0,
-                        message_type: MessageType::Say,
-                    };
-                    let last_user_idx = enhanced_messages
-                        .iter()
-                        .rposition(|m| m.role == MessageRole::User);
-                    if let Some(idx) = last_user_idx {
-                        enhanced_messages.insert(idx, distill_msg);
-                    } else {
-                        enhanced_messages.push(distill_msg);
-                    }
-                }
+            // ── Phase 3: 对话模型（GLM-4.7）生成自然回复 ──
+            // 对话模型始终关闭思考，由推理模型专责思考；
+            // 快速草稿已在展示时不逐字推流，完成后整段以 Refined 替换
+            let (content, _) = self
+                .request_screened(chat_model, &enhanced_messages, &tuning, &|event| match event {
+                    ChatStreamEvent::ContentDelta(_) if fast_draft => {}
+                    other => on_event(other),
+                })
+                .await?;
+            if fast_draft {
+                on_event(ChatStreamEvent::Refined(content.clone()));
             }
 
-            // ── Phase 1: 推理模型（GLM-4-AIR）知识增强深度分析 ──
-            let (mut reasoning_conclusion, mut thinking_text) = self
-                .request_enhanced_reasoning(
-                    thinking_model,
+            (content, thinking_text)
+        } else {
+            // ── 单模型模式也注入知识库 ──
+            self.retrieve_knowledge_context(
+                &conv,
+                conversation_id,
+                content,
+                semantic.as_ref(),
+                &mut enhanced_messages,
+            )
+            .await;
+            self.request_screened(chat_model, &enhanced_messages, &tuning, &on_event)
+                .await?
+        };
+
+        // 如果 AI 返回了空内容（已经过多级降级重试），报告最终错误
+        if full_content.trim().is_empty() {
+            on_event(ChatStreamEvent::Error(
+                "AI 暂时无法生成回复，已自动尝试多种方式均未成功。请重试或缩短之前的对话。"
+                    .to_string(),
+            ));
+            on_event(ChatStreamEvent::Done);
+            return Ok(());
+        }
+
+        let thinking = if full_thinking.is_empty() {
+            None
+        } else {
+            Some(full_thinking)
+        };
+
+        // 生成后强度检查：超出档位尺度的词语遮蔽后再落盘
+        let intensity = self.current_settings().content_intensity;
+        let full_content = IntensityDial::enforce(intensity, &full_content).content;
+        let full_content = Self::enforce_narration(&conv, &full_content, &on_event);
+        let shadow_sampled = conv.mode != ConversationMode::CoAuthor
+            && !ConversationStore::is_sandbox(conversation_id)
+            && ShadowEvalStore::is_sampled(
+                conversation_id,
+                conv.turn_count,
+                self.current_settings().shadow_eval_rate,
+            );
+        let main_reply = if shadow_sampled {
+            Some(full_content.clone())
+        } else {
+            None
+        };
+
+        let assistant_msg = Message {
+            thinking_content: thinking,
+            message_type: if conv.mode == ConversationMode::CoAuthor {
+                MessageType::Document
+            } else {
+                MessageType::Say
+            },
+            generation_metadata: self.take_generation_metadata(started_at),
+            ..Message::new(MessageRole::Assistant, full_content, chat_model)
+        };
+        self.conversation_store
+            .add_message_async(conversation_id, assistant_msg.clone())
+            .await?;
+
+        // Send Done after message is persisted so Flutter reloads the saved data
+        Self::emit_completed(&assistant_msg, &on_event);
+        on_event(ChatStreamEvent::Done);
+        self.speak_reply(conversation_id, &assistant_msg, &on_event)
+            .await;
+        self.record_mood(&conv);
+
+        // ── 后台任务：提取事实、到期时生成记忆摘要（沙盒对话不入库）──
+        if !ConversationStore::is_sandbox(conversation_id)
+            && !self.enqueue_after_reply(conversation_id)
+        {
+            self.extract_and_store_facts(conversation_id).await;
+        }
+
+        // ── 抽样轮次：无注入的影子回复对比（不展示、不落盘到对话）──
+        if let Some(main_reply) = main_reply {
+            JobScheduler::global()
+                .run(
+                    BackgroundJobKind::ShadowEval,
                     conversation_id,
-                    &enhanced_messages,
-                    content,
-                    &on_event,
+                    self.run_shadow_eval(&conv, content, &main_reply, chat_model, semantic.as_ref()),
                 )
                 .await;
+        }
 
-            // 增强推理失败时回退到基础推理链路，确保该能力在生产链路中可用
-            if reasoning_conclusion.trim().is_empty() {
-                let (fallback_conclusion, fallback_thinking) = self
-                    .request_reasoning(thinking_model, &enhanced_messages, &on_event)
-                    .await;
-                if !fallback_conclusion.trim().is_empty() {
-                    reasoning_conclusion = fallback_conclusion;
-                }
-                if !fallback_thinking.trim().is_empty() {
-                    thinking_text = fallback_thinking;
+        Ok(())
+    }
+
+    /// 语音回复：为已落盘的回复合成语音，路径写回消息后推送 AudioReady；
+    /// 合成失败时这条回复只是没有语音（共写正文不朗读）
+    async fn speak_reply(
+        &self,
+        conversation_id: &str,
+        message: &Message,
+        on_event: &impl Fn(ChatStreamEvent),
+    ) {
+        let Some(tts) = self.tts.as_ref() else {
+            return;
+        };
+        if message.message_type == MessageType::Document {
+            return;
+        }
+        let Some(text) = TtsClient::speakable_text(&message.content) else {
+            return;
+        };
+        let voice = tts.voice_for(conversation_id, message.character_id.as_deref());
+        let Ok(audio) = tts.synthesize(&text, voice).await else {
+            return;
+        };
+        let Ok(audio_path) = self.audio.save(conversation_id, &message.id, &audio) else {
+            return;
+        };
+        if self
+            .conversation_store
+            .set_audio_path(conversation_id, &message.id, &audio_path)
+            .is_ok()
+        {
+            on_event(ChatStreamEvent::AudioReady(AudioReadyEvent {
+                message_id: message.id.clone(),
+                audio_path,
+            }));
+        }
+    }
+
+    /// 识图：视觉模型逐张描述本轮图片，描述写回消息附件；超时或失败的图片保持无描述
+    async fn caption_attachments(&self, conversation_id: &str, message: &Message) {
+        let mut captions = Vec::new();
+        for attachment in &message.attachments {
+            let Some(url) = self.attachments.image_url(conversation_id, attachment) else {
+                continue;
+            };
+            let probe = Message {
+                content: AttachmentStore::build_caption_prompt(&message.content),
+                attachments: vec![MessageAttachment {
+                    url: Some(url),
+                    caption: None,
+                    ..attachment.clone()
+                }],
+                ..message.clone()
+            };
+            let mut request_body =
+                Self::build_request_body(&[probe], attachments::VISION_MODEL, false);
+            request_body["max_tokens"] = serde_json::json!(attachments::CAPTION_MAX_TOKENS);
+            let described = tokio::time::timeout(
+                std::time::Duration::from_secs(attachments::CAPTION_TIMEOUT_SECS),
+                StreamingHandler::complete_silently(self.provider.as_ref(), request_body),
+            )
+            .await;
+            if let Ok(Ok(text)) = described {
+                if !text.trim().is_empty() {
+                    captions.push((attachment.id.clone(), text.trim().to_string()));
                 }
             }
+        }
+        if !captions.is_empty() {
+            if let Err(e) = self
+                .conversation_store
+                .set_attachment_captions(conversation_id, &message.id, &captions)
+            {
+                tracing::warn!(conversation_id, error = %e, "附件描述写入失败");
+            }
+        }
+    }
 
-            // ── Phase 2: 将推理结论注入上下文，供对话模型参考 ──
-            if !reasoning_conclusion.trim().is_empty() {
-                let reasoning_msg = Message {
-                    id: String::new(),
-                    role: MessageRole::System,
-                    content: format!(
-                        "【深度推理分析结果（GLM-4-AIR + 本地知识库）】\n{}\n\n\
-                         ■ 执行指令：\n\
-                         基于以上分析和知识库事实，以角色身份自然地回复用户。\n\
-                         - 分析中提到的关键事实必须准确体现在回复中\n\
-                         - 知识库中的事实不可矛盾或篡改\n\
-                         - 分析建议的情感策略必须执行\n\
-                         - 不要在回复中提及分析过程本身\n\
-                         - 回复必须完整，不要截断或省略\n\
-                         - 像真人一样自然地表达，有情绪、有温度、有个性",
-                        reasoning_conclusion
-                    ),
-                    thinking_content: None,
-                    model: "system".to_string(),
-                    timestamp: 0,
-                    message_type: MessageType::Say,
-                };
-                // 插入到最后一条用户消息之前
-                let last_user_idx = enhanced_messages
-                    .iter()
-                    .rposition(|m| m.role == MessageRole::User);
-                if let Some(idx) = last_user_idx {
-                    enhanced_messages.insert(idx, reasoning_msg);
-                } else {
-                    enhanced_messages.push(reasoning_msg);
-                }
+    /// 知识注入影子评估：去掉记忆/知识/世界设定注入，用廉价模型非流式重答一次，
+    /// 与正式回复比较质量信号后记录（见 shadow_eval）
+    async fn run_shadow_eval(
+        &self,
+        conv: &Conversation,
+        content: &str,
+        main_reply: &str,
+        chat_model: &str,
+        semantic: Option<&SemanticQuery>,
+    ) {
+        let mut shadow_messages =
+            Self::build_context_enhanced_messages(conv, content, &[], None, &[], None);
+        self.inject_intensity_prompt(&mut shadow_messages);
+        let mut request_body =
+            Self::build_request_body(&shadow_messages, shadow_eval::SHADOW_MODEL, false);
+        request_body["max_tokens"] = serde_json::json!(shadow_eval::SHADOW_MAX_TOKENS);
+
+        let shadow_reply = match tokio::time::timeout(
+            std::time::Duration::from_secs(shadow_eval::SHADOW_TIMEOUT_SECS),
+            StreamingHandler::complete_silently(self.provider.as_ref(), request_body),
+        )
+        .await
+        {
+            Ok(Ok(reply)) if !reply.trim().is_empty() => reply,
+            _ => return,
+        };
+
+        // 本轮注入的事实：检索到的知识 + 记忆核心事实
+        let mut injected_facts: Vec<String> = self
+            .knowledge_store
+            .search_facts(&conv.id, content, 10, semantic)
+            .into_iter()
+            .map(|r| r.fact.content)
+            .collect();
+        injected_facts.extend(
+            self.memory_engine
+                .load_memory_index_async(&conv.id)
+                .await
+                .unwrap_or_default()
+                .into_iter()
+                .flat_map(|s| s.core_facts),
+        );
+        let previous_reply = conv
+            .messages
+            .iter()
+            .rev()
+            .find(|m| m.role == MessageRole::Assistant)
+            .map(|m| m.content.as_str());
+
+        let intensity = self.current_settings().content_intensity;
+        let shadow_reply = IntensityDial::enforce(intensity, &shadow_reply).content;
+        let record = ShadowEvalRecord {
+            turn: conv.turn_count,
+            injected_facts: injected_facts.len() as u32,
+            main_model: chat_model.to_string(),
+            shadow_model: shadow_eval::SHADOW_MODEL.to_string(),
+            main: ShadowEvalStore::measure(main_reply, content, previous_reply, &injected_facts),
+            shadow: ShadowEvalStore::measure(&shadow_reply, content, previous_reply, &injected_facts),
+            created_at: chrono::Utc::now().timestamp_millis(),
+        };
+        if let Err(e) = self.shadow_eval.append(&conv.id, record) {
+            tracing::debug!(conversation_id = %conv.id, error = %e, "影子评估记录写入失败");
+        }
+    }
+
+    /// 群聊发送：写入用户消息后进入群聊的一轮
+    async fn send_group_message(
+        &self,
+        group: &GroupChat,
+        content: &str,
+        chat_model: &str,
+        started_at: i64,
+        on_event: impl Fn(ChatStreamEvent),
+    ) -> Result<(), ChatError> {
+        Self::validate_message(content)?;
+        let conversation_id = group.conversation_id.as_str();
+        let user_msg = Message {
+            message_type: self.detect_type_for(conversation_id, content),
+            ..Message::new(MessageRole::User, content, chat_model)
+        };
+        self.conversation_store
+            .add_message_async(conversation_id, user_msg)
+            .await?;
+        self.conversation_store
+            .increment_turn_count_async(conversation_id)
+            .await?;
+        self.run_group_turn(group, chat_model, started_at, on_event)
+            .await
+    }
+
+    /// 群聊的一轮：挑选发言者，以该角色的视角、人设、知识与记忆生成回复。
+    /// 群聊不走思考管线，对话模型直接生成；回复以 character_id 标注发言角色
+    async fn run_group_turn(
+        &self,
+        group: &GroupChat,
+        chat_model: &str,
+        started_at: i64,
+        on_event: impl Fn(ChatStreamEvent),
+    ) -> Result<(), ChatError> {
+        let conversation_id = group.conversation_id.as_str();
+        let conv = self.conversation_store.load_active_branch_async(conversation_id).await?;
+        let query = conv
+            .messages
+            .iter()
+            .rev()
+            .find(|m| m.role != MessageRole::System)
+            .map(|m| m.content.clone())
+            .unwrap_or_default();
+
+        let speaker = self.choose_speaker(group, &conv).await;
+        let scope = MemoryEngine::character_scope(conversation_id, &speaker.id);
+        let view = GroupChatStore::character_view(&conv, group, &speaker);
+
+        let memory_summaries = self
+            .memory_engine
+            .load_memory_index_async(&scope)
+            .await
+            .unwrap_or_default();
+        let identity_facts = ContextCache::identity_facts(&memory_summaries);
+        let mut enhanced_messages = Self::build_context_enhanced_messages_offloaded(
+            &view,
+            &query,
+            memory_summaries,
+            None,
+            self.lorebook.entries_for(conversation_id),
+            None,
+        )
+        .await;
+        self.retrieve_knowledge_context(&view, &scope, &query, None, &mut enhanced_messages)
+            .await;
+        self.inject_intensity_prompt(&mut enhanced_messages);
+        Self::inject_narration_prompt(&view, &mut enhanced_messages);
+        self.apply_stable_prefix(&scope, &view, &identity_facts, &mut enhanced_messages);
+        self.inject_time_awareness(&view, &mut enhanced_messages);
+        self.inject_blocked_topics_prompt(conversation_id, &mut enhanced_messages);
+
+        let (frequency_penalty, presence_penalty) =
+            Self::compute_repetition_penalties_offloaded(&view).await;
+        let tuning = RequestTuning {
+            long_form: false,
+            frequency_penalty,
+            presence_penalty,
+            web_search: false,
+        };
+        let (full_content, _) = self
+            .request_screened(chat_model, &enhanced_messages, &tuning, &on_event)
+            .await?;
+
+        if full_content.trim().is_empty() {
+            on_event(ChatStreamEvent::Error(
+                "AI 暂时无法生成回复，已自动尝试多种方式均未成功。请重试或缩短之前的对话。"
+                    .to_string(),
+            ));
+            on_event(ChatStreamEvent::Done);
+            return Ok(());
+        }
+
+        let intensity = self.current_settings().content_intensity;
+        let full_content = IntensityDial::enforce(intensity, &full_content).content;
+        let full_content = Self::enforce_narration(&view, &full_content, &on_event);
+        let assistant_msg = Message {
+            generation_metadata: self.take_generation_metadata(started_at),
+            character_id: Some(speaker.id.clone()),
+            ..Message::new(MessageRole::Assistant, full_content, chat_model)
+        };
+        self.conversation_store
+            .add_message_async(conversation_id, assistant_msg.clone())
+            .await?;
+
+        Self::emit_completed(&assistant_msg, &on_event);
+        on_event(ChatStreamEvent::Done);
+        self.speak_reply(conversation_id, &assistant_msg, &on_event)
+            .await;
+
+        // 事实只记入各角色自己的知识库；队列不可用时只提取发言角色的
+        if !ConversationStore::is_sandbox(conversation_id)
+            && !self.enqueue_after_reply(conversation_id)
+        {
+            if let Ok(conv) = self
+                .conversation_store
+                .load_active_branch_async(conversation_id)
+                .await
+            {
+                let view = GroupChatStore::character_view(&conv, group, &speaker);
+                self.extract_and_store_facts_for(&scope, &view).await;
             }
+        }
+
+        Ok(())
+    }
 
-            // ── Phase 3: 对话模型（GLM-4.7）生成自然回复 ──
-            // 对话模型始终关闭思考，由推理模型专责思考
-            let (content, _) = self
-                .request_with_fallback(chat_model, false, &enhanced_messages, &on_event)
-                .await?;
+    /// 挑选下一位发言者：用户点名优先；其次按调度方式轮流或由快速模型挑选
+    async fn choose_speaker(&self, group: &GroupChat, conv: &Conversation) -> GroupCharacter {
+        let last = conv
+            .messages
+            .iter()
+            .rev()
+            .find(|m| m.role != MessageRole::System);
+        if let Some(addressed) = last
+            .filter(|m| m.role == MessageRole::User)
+            .and_then(|m| GroupChatStore::addressed(group, &m.content))
+        {
+            return addressed.clone();
+        }
 
-            (content, thinking_text)
-        } else {
-            // ── 单模型模式也注入知识库 ──
-            self.retrieve_knowledge_context(conversation_id, content, &mut enhanced_messages);
-            self.request_with_fallback(chat_model, false, &enhanced_messages, &on_event)
-                .await?
-        };
+        let round_robin = GroupChatStore::next_round_robin(group, &conv.messages).clone();
+        if group.turn_policy == TurnPolicy::RoundRobin {
+            return round_robin;
+        }
 
-        // 如果 AI 返回了空内容（已经过多级降级重试），报告最终错误
-        if full_content.trim().is_empty() {
-            on_event(ChatStreamEvent::Error(
-                "AI 暂时无法生成回复，已自动尝试多种方式均未成功。请重试或缩短之前的对话。"
-                    .to_string(),
-            ));
-            on_event(ChatStreamEvent::Done);
-            return Ok(());
+        let choice_messages = vec![Message {
+            id: String::new(),
+            timestamp: 0,
+            ..Message::new(
+                MessageRole::User,
+                GroupChatStore::build_speaker_choice_prompt(group, &conv.messages),
+                "glm-4.7-flash",
+            )
+        }];
+        let mut request_body = Self::build_request_body(&choice_messages, "glm-4.7-flash", false);
+        request_body["max_tokens"] = serde_json::json!(SPEAKER_CHOICE_MAX_TOKENS);
+
+        let silent_event = |_event: ChatStreamEvent| {};
+        let chosen = tokio::time::timeout(
+            std::time::Duration::from_millis(SPEAKER_CHOICE_TIMEOUT_MS),
+            StreamingHandler::stream_chat(self.provider.as_ref(), request_body, &silent_event),
+        )
+        .await;
+        match chosen {
+            Ok(Ok((text, _))) => GroupChatStore::parse_speaker_choice(group, &text)
+                .cloned()
+                .unwrap_or(round_robin),
+            _ => round_robin,
         }
+    }
 
-        let thinking = if full_thinking.is_empty() {
-            None
-        } else {
-            Some(full_thinking)
+    /// 离线回声管线：content 为 Some 时作为新消息发送，为 None 时基于最后一条用户消息重新生成
+    /// 存储、轮次计数、记忆检索与在线管线一致，回复由 LocalResponder 确定性生成
+    pub fn respond_offline(
+        &self,
+        conversation_id: &str,
+        content: Option<&str>,
+        on_event: impl Fn(ChatStreamEvent),
+    ) -> Result<(), ChatError> {
+        // 离线模式不涉及模型选择，只需采用最新的强度等设置
+        let _ = self.apply_settings_updates(&on_event);
+
+        let user_content = match content {
+            Some(content) => {
+                Self::validate_message(content)?;
+                let user_msg = Message {
+                    message_type: self.detect_type_for(conversation_id, content),
+                    ..Message::new(MessageRole::User, content, LOCAL_MODEL_ID)
+                };
+                self.conversation_store
+                    .add_message(conversation_id, user_msg)?;
+                self.conversation_store
+                    .increment_turn_count(conversation_id)?;
+                content.to_string()
+            }
+            None => self
+                .conversation_store
+                .load_conversation(conversation_id)?
+                .messages
+                .iter()
+                .rev()
+                .find(|m| m.role == MessageRole::User)
+                .map(|m| m.content.clone())
+                .ok_or_else(|| ChatError::ValidationError {
+                    message: "No user message found to regenerate from".to_string(),
+                })?,
         };
 
-        let assistant_msg = Message {
-            id: uuid::Uuid::new_v4().to_string(),
-            role: MessageRole::Assistant,
-            content: full_content,
-            thinking_content: thinking,
-            model: chat_model.to_string(),
-            timestamp: chrono::Utc::now().timestamp_millis(),
-            message_type: MessageType::Say,
-        };
+        let conv = self.conversation_store.load_active_branch(conversation_id)?;
+        let memory_summaries = self
+            .memory_engine
+            .load_memory_index(conversation_id)
+            .unwrap_or_default();
+        let memories = MemoryEngine::search_memories(&user_content, &memory_summaries, 1);
+        let message_type = self.detect_type_for(conversation_id, &user_content);
+
+        let reply = LocalResponder::respond(&conv.messages, &user_content, &message_type, &memories);
+        let intensity = self.current_settings().content_intensity;
+        let reply = IntensityDial::enforce(intensity, &reply).content;
+        for chunk in LocalResponder::chunk_reply(&reply) {
+            on_event(ChatStreamEvent::ContentDelta(chunk));
+        }
+
+        let assistant_msg = Message::new(MessageRole::Assistant, reply, LOCAL_MODEL_ID);
         self.conversation_store
             .add_message(conversation_id, assistant_msg)?;
 
-        // Send Done after message is persisted so Flutter reloads the saved data
         on_event(ChatStreamEvent::Done);
-
-        // ── 后台任务：异步提取事实存入知识库 ──
-        self.extract_and_store_facts(conversation_id, &on_event)
-            .await;
-
         Ok(())
     }
 
     /// 重新生成AI回复：不添加用户消息，直接基于现有对话上下文重新请求AI
     /// 同样遵循三级模型管线：GLM-4-LONG蒸馏→GLM-4-AIR推理→GLM-4.7对话
-    pub async fn regenerate_response(
+    async fn regenerate_response_inner(
         &self,
         conversation_id: &str,
         chat_model: &str,
@@ -1877,7 +3833,14 @@ impl ChatEngine {
         enable_thinking: bool,
         on_event: impl Fn(ChatStreamEvent),
     ) -> Result<(), ChatError> {
-        let conv = self.conversation_store.load_conversation(conversation_id)?;
+        let started_at = chrono::Utc::now().timestamp_millis();
+        // 群聊重新生成：重新挑选发言者（也可用来让下一位角色接话）
+        if let Some(group) = self.group_chats.load(conversation_id) {
+            return self
+                .run_group_turn(&group, chat_model, started_at, on_event)
+                .await;
+        }
+        let conv = self.conversation_store.load_active_branch_async(conversation_id).await?;
 
         // 找到最后一条用户消息的内容（用于构建上下文）
         let last_user_content = conv
@@ -1894,240 +3857,144 @@ impl ChatEngine {
             });
         }
 
-        let message_type = Self::detect_message_type(&last_user_content);
+        let message_type = self.detect_type_for(conversation_id, &last_user_content);
 
         // 加载记忆索引
         let memory_summaries = self
             .memory_engine
-            .load_memory_index(conversation_id)
+            .load_memory_index_async(conversation_id)
+            .await
             .unwrap_or_default();
 
-        // 构建上下文增强的消息列表
-        let mut enhanced_messages =
-            Self::build_context_enhanced_messages(&conv, &last_user_content, &memory_summaries);
+        let semantic = self.semantic_query(conversation_id, &last_user_content).await;
 
-        // 注入 say/do 模式提示
-        let style_hint = SayDoDetector::build_style_prompt(&message_type);
-        let style_msg = Message {
-            id: String::new(),
-            role: MessageRole::System,
-            content: style_hint.to_string(),
-            thinking_content: None,
-            model: "system".to_string(),
-            timestamp: 0,
-            message_type: MessageType::Say,
-        };
-        let last_user_idx = enhanced_messages
-            .iter()
-            .rposition(|m| m.role == MessageRole::User);
-        if let Some(idx) = last_user_idx {
-            enhanced_messages.insert(idx, style_msg);
-        } else {
-            enhanced_messages.push(style_msg);
+        // 构建上下文增强的消息列表
+        let identity_facts = ContextCache::identity_facts(&memory_summaries);
+        let mut enhanced_messages = Self::build_context_enhanced_messages_offloaded(
+            &conv,
+            &last_user_content,
+            memory_summaries,
+            semantic.clone(),
+            self.lorebook.entries_for(conversation_id),
+            self.mood
+                .current(conversation_id, chrono::Utc::now().timestamp_millis()),
+        )
+        .await;
+        if let Some((kept, expected)) = Self::history_truncation(&conv, &enhanced_messages) {
+            self.record_decision(
+                DegradationKind::ContextTruncated,
+                chat_model,
+                format!("历史窗口超出 token 预算，仅保留 {}/{} 条消息", kept, expected),
+            );
         }
 
-        let non_system_for_hint: Vec<&Message> = conv
-            .messages
-            .iter()
-            .filter(|m| m.role != MessageRole::System)
-            .collect();
-        let quality_hint =
-            Self::build_humanization_hint(&last_user_content, &non_system_for_hint, &message_type);
-        let quality_msg = Message {
-            id: String::new(),
-            role: MessageRole::System,
-            content: quality_hint,
-            thinking_content: None,
-            model: "system".to_string(),
-            timestamp: 0,
-            message_type: MessageType::Say,
-        };
-        let last_user_idx = enhanced_messages
-            .iter()
-            .rposition(|m| m.role == MessageRole::User);
-        if let Some(idx) = last_user_idx {
-            enhanced_messages.insert(idx, quality_msg);
+        let prompt_vars = self.prompt_vars(&conv, &last_user_content);
+        Self::inject_directives(&conv, &mut enhanced_messages);
+        if conv.mode == ConversationMode::CoAuthor {
+            Self::inject_coauthor_prompt(&conv, &last_user_content, &mut enhanced_messages);
         } else {
-            enhanced_messages.push(quality_msg);
+            // 注入 say/do 模式提示
+            let style_hint = prompt_templates::render_or(
+                prompt_templates::style_template_name(&message_type),
+                &prompt_vars,
+                || SayDoDetector::build_style_prompt(&message_type).to_string(),
+            );
+            let style_msg = Message::system_prompt(style_hint);
+            insert_before_last_user(&mut enhanced_messages, style_msg);
+
+            let non_system_for_hint: Vec<&Message> = conv
+                .messages
+                .iter()
+                .filter(|m| m.role != MessageRole::System)
+                .collect();
+            let quality_hint =
+                Self::build_humanization_hint(
+                    &last_user_content,
+                    &non_system_for_hint,
+                    &message_type,
+                    &conv.response_style,
+                    &prompt_vars,
+                );
+            let quality_msg = Message::system_prompt(quality_hint);
+            if conv.context_layers.humanization_hint {
+                insert_before_last_user(&mut enhanced_messages, quality_msg);
+            }
         }
 
-        // ══ 四级模型管线（与 send_message 相同逻辑）══
-        let (full_content, full_thinking) = if enable_thinking {
-            // ── Phase 0.3: 本地知识库检索 ──
-            self.retrieve_knowledge_context(
-                conversation_id,
+        self.inject_intensity_prompt(&mut enhanced_messages);
+        Self::inject_narration_prompt(&conv, &mut enhanced_messages);
+        self.apply_stable_prefix(conversation_id, &conv, &identity_facts, &mut enhanced_messages);
+        self.inject_time_awareness(&conv, &mut enhanced_messages);
+        if conv.mode != ConversationMode::CoAuthor {
+            self.inject_blocked_topics_prompt(conversation_id, &mut enhanced_messages);
+            self.inject_reply_skeleton(
+                &conv,
                 &last_user_content,
+                &message_type,
                 &mut enhanced_messages,
-            );
-
-            // ── Phase 0.4: 读取已蒸馏的核心状态（若存在）──
-            if let Ok(Some(distilled_state)) =
-                self.memory_engine.load_distilled_state(conversation_id)
-            {
-                if !distilled_state.core_prompt.trim().is_empty() {
-                    let distilled_msg = Message {
-                        id: String::new(),
-                        role: MessageRole::System,
-                        content: format!(
-                            "【历史蒸馏核心状态（持久化）】\n{}\n",
-                            distilled_state.core_prompt
-                        ),
-                        thinking_content: None,
-                        model: "system".to_string(),
-                        timestamp: 0,
-                        message_type: MessageType::Say,
-                    };
-                    let last_user_idx = enhanced_messages
-                        .iter()
-                        .rposition(|m| m.role == MessageRole::User);
-                    if let Some(idx) = last_user_idx {
-                        enhanced_messages.insert(idx, distilled_msg);
-                    } else {
-                        enhanced_messages.push(distilled_msg);
-                    }
-                }
-            }
+            )
+            .await;
+        }
 
-            // ── Phase 0.5: 评估上下文复杂度 ──
-            let memory_summaries_for_assess = self
-                .memory_engine
-                .load_memory_index(conversation_id)
-                .unwrap_or_default();
-            let (needs_long_context, _total_tokens) =
-                Self::assess_context_needs(&enhanced_messages, &memory_summaries_for_assess);
-
-            // ── Phase 0.7: 长上下文蒸馏（GLM-4-LONG，仅在需要时触发）──
-            if needs_long_context {
-                let distilled = self
-                    .request_long_context_distillation(
-                        &enhanced_messages,
-                        &memory_summaries_for_assess,
-                        &last_user_content,
-                        &on_event,
-                    )
-                    .await;
-                if !distilled.trim().is_empty() {
-                    let core_facts_snapshot: Vec<String> = memory_summaries_for_assess
-                        .iter()
-                        .flat_map(|s| s.core_facts.clone())
-                        .collect();
-                    let mut hasher = DefaultHasher::new();
-                    let character_prompt = enhanced_messages
-                        .iter()
-                        .find(|m| m.role == MessageRole::System)
-                        .map(|m| m.content.as_str())
-                        .unwrap_or_default();
-                    character_prompt.hash(&mut hasher);
-                    let distilled_state = DistilledSystemState {
-                        core_prompt: distilled.clone(),
-                        last_memory_count: memory_summaries_for_assess.len(),
-                        last_max_compression_gen: memory_summaries_for_assess
-                            .iter()
-                            .map(|s| s.compression_generation)
-                            .max()
-                            .unwrap_or(0),
-                        character_prompt_hash: hasher.finish(),
-                        last_turn_count: conv.turn_count,
-                        distilled_at: chrono::Utc::now().timestamp_millis(),
-                        core_facts_snapshot,
-                    };
-                    let _ = self
-                        .memory_engine
-                        .save_distilled_state(conversation_id, &distilled_state);
-
-                    let distill_msg = Message {
-                        id: String::new(),
-                        role: MessageRole::System,
-                        content: format!(
-                            "【长上下文蒸馏摘要 — 以下为 GLM-4-LONG 整理的关键信息，必须严格遵守】\n{}\n",
-                            distilled
-                        ),
-                        thinking_content: None,
-                        model: "system".to_string(),
-                        timestamp: 0,
-                        message_type: MessageType::Say,
-                    };
-                    let last_user_idx = enhanced_messages
-                        .iter()
-                        .rposition(|m| m.role == MessageRole::User);
-                    if let Some(idx) = last_user_idx {
-                        enhanced_messages.insert(idx, distill_msg);
-                    } else {
-                        enhanced_messages.push(distill_msg);
-                    }
-                }
-            }
+        let (frequency_penalty, presence_penalty) =
+            Self::compute_repetition_penalties_offloaded(&conv).await;
+        let tuning = RequestTuning {
+            long_form: conv.mode == ConversationMode::CoAuthor,
+            frequency_penalty,
+            presence_penalty,
+            web_search: self.should_web_search(&conv, &last_user_content),
+        };
 
-            // ── Phase 1: 推理模型（GLM-4-AIR）知识增强深度分析 ──
-            let (mut reasoning_conclusion, mut thinking_text) = self
-                .request_enhanced_reasoning(
-                    thinking_model,
+        // ══ 四级模型管线（与 send_message 相同逻辑）══
+        let (full_content, full_thinking) = if enable_thinking {
+            // ── Phase 0.3–0.7 → Phase 1：推理基于检索与蒸馏注入后的上下文 ──
+            let (context, (reasoning_conclusion, thinking_text)) = self
+                .prepare_and_reason(
+                    &conv,
                     conversation_id,
-                    &enhanced_messages,
                     &last_user_content,
+                    semantic.as_ref(),
+                    thinking_model,
+                    &prompt_vars,
                     &on_event,
+                    std::mem::take(&mut enhanced_messages),
                 )
                 .await;
-
-            // 增强推理失败时回退到基础推理链路，确保该能力在生产链路中可用
-            if reasoning_conclusion.trim().is_empty() {
-                let (fallback_conclusion, fallback_thinking) = self
-                    .request_reasoning(thinking_model, &enhanced_messages, &on_event)
-                    .await;
-                if !fallback_conclusion.trim().is_empty() {
-                    reasoning_conclusion = fallback_conclusion;
-                }
-                if !fallback_thinking.trim().is_empty() {
-                    thinking_text = fallback_thinking;
-                }
-            }
+            enhanced_messages = context;
 
             // ── Phase 2: 将推理结论注入上下文 ──
             if !reasoning_conclusion.trim().is_empty() {
-                let reasoning_msg = Message {
-                    id: String::new(),
-                    role: MessageRole::System,
-                    content: format!(
-                        "【深度推理分析结果（GLM-4-AIR + 本地知识库）】\n{}\n\n\
-                         ■ 执行指令：\n\
-                         基于以上分析和知识库事实，以角色身份自然地回复用户。\n\
-                         - 分析中提到的关键事实必须准确体现在回复中\n\
-                         - 知识库中的事实不可矛盾或篡改\n\
-                         - 分析建议的情感策略必须执行\n\
-                         - 不要在回复中提及分析过程本身\n\
-                         - 回复必须完整，不要截断或省略\n\
-                         - 像真人一样自然地表达，有情绪、有温度、有个性",
-                        reasoning_conclusion
-                    ),
-                    thinking_content: None,
-                    model: "system".to_string(),
-                    timestamp: 0,
-                    message_type: MessageType::Say,
-                };
-                let last_user_idx = enhanced_messages
-                    .iter()
-                    .rposition(|m| m.role == MessageRole::User);
-                if let Some(idx) = last_user_idx {
-                    enhanced_messages.insert(idx, reasoning_msg);
-                } else {
-                    enhanced_messages.push(reasoning_msg);
-                }
+                let reasoning_msg = Message::system_prompt(format!(
+                    "【深度推理分析结果（GLM-4-AIR + 本地知识库）】\n{}\n\n\
+                     ■ 执行指令：\n\
+                     基于以上分析和知识库事实，以角色身份自然地回复用户。\n\
+                     - 分析中提到的关键事实必须准确体现在回复中\n\
+                     - 知识库中的事实不可矛盾或篡改\n\
+                     - 分析建议的情感策略必须执行\n\
+                     - 不要在回复中提及分析过程本身\n\
+                     - 回复必须完整，不要截断或省略\n\
+                     - 像真人一样自然地表达，有情绪、有温度、有个性",
+                    reasoning_conclusion
+                ));
+                insert_before_last_user(&mut enhanced_messages, reasoning_msg);
             }
 
             // ── Phase 3: 对话模型（GLM-4.7）生成自然回复 ──
             let (content, _) = self
-                .request_with_fallback(chat_model, false, &enhanced_messages, &on_event)
+                .request_screened(chat_model, &enhanced_messages, &tuning, &on_event)
                 .await?;
 
             (content, thinking_text)
         } else {
             // ── 单模型模式也注入知识库 ──
             self.retrieve_knowledge_context(
+                &conv,
                 conversation_id,
                 &last_user_content,
+                semantic.as_ref(),
                 &mut enhanced_messages,
-            );
-            self.request_with_fallback(chat_model, false, &enhanced_messages, &on_event)
+            ).await;
+            self.request_screened(chat_model, &enhanced_messages, &tuning, &on_event)
                 .await?
         };
 
@@ -2147,24 +4014,201 @@ impl ChatEngine {
             Some(full_thinking)
         };
 
+        // 生成后强度检查：超出档位尺度的词语遮蔽后再落盘
+        let intensity = self.current_settings().content_intensity;
+        let full_content = IntensityDial::enforce(intensity, &full_content).content;
+        let full_content = Self::enforce_narration(&conv, &full_content, &on_event);
+
         let assistant_msg = Message {
-            id: uuid::Uuid::new_v4().to_string(),
-            role: MessageRole::Assistant,
-            content: full_content,
             thinking_content: thinking,
-            model: chat_model.to_string(),
-            timestamp: chrono::Utc::now().timestamp_millis(),
-            message_type: MessageType::Say,
+            message_type: if conv.mode == ConversationMode::CoAuthor {
+                MessageType::Document
+            } else {
+                MessageType::Say
+            },
+            generation_metadata: self.take_generation_metadata(started_at),
+            ..Message::new(MessageRole::Assistant, full_content, chat_model)
         };
-        self.conversation_store
-            .add_message(conversation_id, assistant_msg)?;
+        let assistant_msg = self
+            .conversation_store
+            .add_reply_alternative(conversation_id, assistant_msg)?;
 
         // Send Done after message is persisted so Flutter reloads the saved data
+        Self::emit_completed(&assistant_msg, &on_event);
         on_event(ChatStreamEvent::Done);
+        self.speak_reply(conversation_id, &assistant_msg, &on_event)
+            .await;
+
+        Ok(())
+    }
+
+    /// 角色主动联系（见 check_ins）：对方沉默一段时间后，不等用户消息直接生成一条回复。
+    /// 上下文构建与请求与单模型回复相同，主动联系提示放在上下文末尾；
+    /// 成功落盘后记录联系时间，对方回复之前不会再次触发。群聊不支持
+    #[tracing::instrument(skip_all, fields(conversation_id, model = chat_model))]
+    pub async fn send_check_in(
+        &self,
+        conversation_id: &str,
+        chat_model: &str,
+        on_event: impl Fn(ChatStreamEvent),
+    ) -> Result<(), ChatError> {
+        let (chat_model, _) = self.refresh_turn_models(chat_model, chat_model, &on_event);
+        let result = metrics::track_turn(
+            conversation_id,
+            self.send_check_in_inner(conversation_id, &chat_model, Self::marking_first_token(on_event)),
+        )
+        .await;
+        self.flush_decisions(conversation_id);
+        result
+    }
+
+    async fn send_check_in_inner(
+        &self,
+        conversation_id: &str,
+        chat_model: &str,
+        on_event: impl Fn(ChatStreamEvent),
+    ) -> Result<(), ChatError> {
+        let started_at = chrono::Utc::now().timestamp_millis();
+        if self.group_chats.load(conversation_id).is_some() {
+            return Err(ChatError::ValidationError {
+                message: "Group chats do not support check-ins".to_string(),
+            });
+        }
+        self.conversation_store.load_open_async(conversation_id).await?;
+        let conv = self.conversation_store.load_active_branch_async(conversation_id).await?;
+        let last_activity = conv
+            .messages
+            .iter()
+            .rev()
+            .find(|m| m.role != MessageRole::System)
+            .map(|m| m.timestamp)
+            .unwrap_or(started_at);
+        // 检索与提示以最后一句对话为线索
+        let query = conv
+            .messages
+            .iter()
+            .rev()
+            .find(|m| m.role != MessageRole::System)
+            .map(|m| m.content.clone())
+            .unwrap_or_default();
+
+        let memory_summaries = self
+            .memory_engine
+            .load_memory_index_async(conversation_id)
+            .await
+            .unwrap_or_default();
+        let semantic = self.semantic_query(conversation_id, &query).await;
+        let identity_facts = ContextCache::identity_facts(&memory_summaries);
+        let mut enhanced_messages = Self::build_context_enhanced_messages_offloaded(
+            &conv,
+            &query,
+            memory_summaries,
+            semantic.clone(),
+            self.lorebook.entries_for(conversation_id),
+            self.mood.current(conversation_id, started_at),
+        )
+        .await;
+        self.inject_intensity_prompt(&mut enhanced_messages);
+        Self::inject_narration_prompt(&conv, &mut enhanced_messages);
+        self.apply_stable_prefix(conversation_id, &conv, &identity_facts, &mut enhanced_messages);
+        self.inject_time_awareness(&conv, &mut enhanced_messages);
+        self.inject_blocked_topics_prompt(conversation_id, &mut enhanced_messages);
+        self.retrieve_knowledge_context(
+            &conv,
+            conversation_id,
+            &query,
+            semantic.as_ref(),
+            &mut enhanced_messages,
+        )
+        .await;
+
+        let idle = CheckInPlanner::describe_idle(started_at - last_activity);
+        let prompt_vars = self.prompt_vars(&conv, "").with("idle", idle.clone());
+        enhanced_messages.push(Message::system_prompt(prompt_templates::render_or(
+            "check_in",
+            &prompt_vars,
+            || CheckInPlanner::build_prompt(&idle),
+        )));
+
+        let (frequency_penalty, presence_penalty) =
+            Self::compute_repetition_penalties_offloaded(&conv).await;
+        let tuning = RequestTuning {
+            long_form: false,
+            frequency_penalty,
+            presence_penalty,
+            web_search: false,
+        };
+        let (full_content, _) = self
+            .request_screened(chat_model, &enhanced_messages, &tuning, &on_event)
+            .await?;
+        if full_content.trim().is_empty() {
+            return Err(ChatError::StreamError {
+                message: "Check-in generation returned empty content".to_string(),
+            });
+        }
+
+        let intensity = self.current_settings().content_intensity;
+        let full_content = IntensityDial::enforce(intensity, &full_content).content;
+        let full_content = Self::enforce_narration(&conv, &full_content, &on_event);
+        let assistant_msg = Message {
+            generation_metadata: self.take_generation_metadata(started_at),
+            ..Message::new(MessageRole::Assistant, full_content, chat_model)
+        };
+        self.conversation_store
+            .add_message_async(conversation_id, assistant_msg.clone())
+            .await?;
+        self.conversation_store
+            .mark_check_in_sent(conversation_id, assistant_msg.timestamp)?;
 
+        Self::emit_completed(&assistant_msg, &on_event);
+        on_event(ChatStreamEvent::Done);
+        self.speak_reply(conversation_id, &assistant_msg, &on_event)
+            .await;
         Ok(())
     }
 
+    /// 补跑一个被调度器延后的后台任务；影子评估依赖当轮上下文，不补跑
+    #[tracing::instrument(skip_all, fields(conversation_id = %job.conversation_id, kind = ?job.kind))]
+    pub async fn run_deferred_job(&self, job: &DeferredJob) -> bool {
+        match job.kind {
+            BackgroundJobKind::FactExtraction => {
+                let Ok(conv) = self
+                    .conversation_store
+                    .load_active_branch_async(&job.conversation_id)
+                    .await
+                else {
+                    return false;
+                };
+                match self.group_chats.load(&job.conversation_id) {
+                    Some(group) => {
+                        for character in &group.characters {
+                            let view = GroupChatStore::character_view(&conv, &group, character);
+                            let scope = MemoryEngine::character_scope(&conv.id, &character.id);
+                            self.extract_and_store_facts_for(&scope, &view).await;
+                        }
+                    }
+                    None => self.extract_and_store_facts_for(&conv.id, &conv).await,
+                }
+                true
+            }
+            BackgroundJobKind::Summarization => JobScheduler::global()
+                .run(BackgroundJobKind::Summarization, &job.conversation_id, async {
+                    // 编辑作废的摘要先按新原文重写，再补到期的摘要
+                    if let Err(e) = self.resummarize_stale(&job.conversation_id).await {
+                        tracing::warn!(
+                            conversation_id = %job.conversation_id,
+                            error = %e,
+                            "过时记忆摘要重新总结失败"
+                        );
+                    }
+                    self.summarize_memory(&job.conversation_id, |_| {}).await
+                })
+                .await
+                .is_some_and(|r| r.is_ok()),
+            BackgroundJobKind::Distillation | BackgroundJobKind::ShadowEval => false,
+        }
+    }
+
     /// 执行记忆总结（由外部调用，在 send_message 完成后异步触发）
     /// 采用双阶段验证：
     ///   阶段1: 使用总结模型生成摘要
@@ -2174,27 +4218,129 @@ impl ChatEngine {
         conversation_id: &str,
         on_event: impl Fn(ChatStreamEvent),
     ) -> Result<Option<MemorySummary>, ChatError> {
-        let conv = self.conversation_store.load_conversation(conversation_id)?;
-
-        if !MemoryEngine::should_summarize(conv.turn_count) {
-            return Ok(None);
-        }
+        // 沙盒对话不生成记忆摘要
+        if ConversationStore::is_sandbox(conversation_id) {
+            return Ok(None);
+        }
+
+        let conv = self.conversation_store.load_active_branch_async(conversation_id).await?;
+
+        let interval = self
+            .config
+            .load_summarization_config(conversation_id)
+            .interval_turns;
+        if !MemoryEngine::should_summarize(conv.turn_count, interval) {
+            return Ok(None);
+        }
+
+        // 群聊：每个角色按自己的视角各自形成记忆，存入各自的命名空间
+        if let Some(group) = self.group_chats.load(conversation_id) {
+            let mut latest = None;
+            for character in &group.characters {
+                let view = GroupChatStore::character_view(&conv, &group, character);
+                let scope = MemoryEngine::character_scope(conversation_id, &character.id);
+                if let Some(memory) = self.summarize_into(&scope, &view, &on_event).await? {
+                    latest = Some(memory);
+                }
+            }
+            return Ok(latest);
+        }
+
+        self.summarize_into(conversation_id, &conv, &on_event).await
+    }
+
+    /// 手动触发摘要：生成草稿但不写入记忆，暂存等待确认（approve_summary_draft）。
+    /// 不受自动摘要间隔限制；群聊按角色各自形成记忆，不支持手动摘要
+    pub async fn preview_summary(
+        &self,
+        conversation_id: &str,
+    ) -> Result<Option<MemorySummary>, ChatError> {
+        if ConversationStore::is_sandbox(conversation_id) {
+            return Ok(None);
+        }
+        if self.group_chats.load(conversation_id).is_some() {
+            return Err(ChatError::ValidationError {
+                message: "Group chats are summarized per character automatically".to_string(),
+            });
+        }
+        let conv = self.conversation_store.load_active_branch_async(conversation_id).await?;
+        if conv.turn_count == 0 {
+            return Ok(None);
+        }
+        // 草稿生成过程不向前端推流，只在完成后发送 SummaryPreview
+        let draft = self.draft_summary(conversation_id, &conv, &|_| {}).await?;
+        if let Some(draft) = &draft {
+            MemoryEngine::stage_summary_draft(conversation_id, draft.clone());
+        }
+        Ok(draft)
+    }
+
+    /// 确认手动摘要的草稿并写入记忆；草稿不存在或已被新草稿替换时报错
+    pub async fn approve_summary_draft(
+        &self,
+        conversation_id: &str,
+        summary_id: &str,
+    ) -> Result<MemorySummary, ChatError> {
+        let draft = MemoryEngine::take_summary_draft(conversation_id, summary_id).ok_or_else(|| {
+            ChatError::ValidationError {
+                message: format!("No pending summary '{}'", summary_id),
+            }
+        })?;
+        self.commit_summary(conversation_id, conversation_id, draft)
+            .await
+    }
+
+    /// 总结 conv 的最近对话并写入 memory_id 的记忆索引
+    /// （普通对话即对话ID；群聊为角色命名空间，此时不回写对话文件）
+    #[tracing::instrument(skip_all, fields(memory_id))]
+    async fn summarize_into(
+        &self,
+        memory_id: &str,
+        conv: &Conversation,
+        on_event: &impl Fn(ChatStreamEvent),
+    ) -> Result<Option<MemorySummary>, ChatError> {
+        // 后台队列与前端 trigger_memory_summarize 可能先后触发同一轮的摘要
+        let covered = self
+            .memory_engine
+            .load_memory_index_async(memory_id)
+            .await
+            .unwrap_or_default()
+            .iter()
+            .any(|m| m.turn_range_end >= conv.turn_count);
+        if covered {
+            return Ok(None);
+        }
+        match self.draft_summary(memory_id, conv, on_event).await? {
+            Some(memory) => self.commit_summary(memory_id, &conv.id, memory).await.map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// 生成 conv 最近对话的摘要（含核心事实验证），不写入；结果无法解析时为 None
+    async fn draft_summary(
+        &self,
+        memory_id: &str,
+        conv: &Conversation,
+        on_event: &impl Fn(ChatStreamEvent),
+    ) -> Result<Option<MemorySummary>, ChatError> {
+        // 摘要节奏按对话配置（群聊各角色共用对话的配置）
+        let config = self.config.load_summarization_config(&conv.id);
 
-        // 获取需要总结的消息范围
-        let turn_start = if conv.turn_count > 10 {
-            conv.turn_count - 10 + 1
+        // 获取需要总结的消息范围（上次摘要之后的一个间隔）
+        let turn_start = if conv.turn_count > config.interval_turns {
+            conv.turn_count - config.interval_turns + 1
         } else {
             1
         };
         let turn_end = conv.turn_count;
 
-        // 获取最近 20 条消息用于总结
+        // 获取最近 window_messages 条消息用于总结
         let recent_messages: Vec<Message> = conv
             .messages
             .iter()
             .filter(|m| m.role != MessageRole::System)
             .rev()
-            .take(20)
+            .take(config.window_messages as usize)
             .cloned()
             .collect::<Vec<_>>()
             .into_iter()
@@ -2203,7 +4349,8 @@ impl ChatEngine {
 
         let existing_summaries = self
             .memory_engine
-            .load_memory_index(conversation_id)
+            .load_memory_index_async(memory_id)
+            .await
             .unwrap_or_default();
 
         // 动态选择总结模型
@@ -2223,43 +4370,29 @@ impl ChatEngine {
         };
 
         let summary_messages = vec![
+            Message::system_prompt(
+                "你是一个精确的记忆管理系统，负责总结对话内容。请严格按照要求的JSON格式输出。",
+            ),
             Message {
                 id: String::new(),
-                role: MessageRole::System,
-                content:
-                    "你是一个精确的记忆管理系统，负责总结对话内容。请严格按照要求的JSON格式输出。"
-                        .to_string(),
-                thinking_content: None,
-                model: "system".to_string(),
-                timestamp: 0,
-                message_type: MessageType::Say,
-            },
-            Message {
-                id: String::new(),
-                role: MessageRole::User,
-                content: prompt,
-                thinking_content: None,
-                model: summary_model.to_string(),
                 timestamp: 0,
-                message_type: MessageType::Say,
+                ..Message::new(MessageRole::User, prompt, summary_model)
             },
         ];
 
         let request_body = Self::build_request_body(&summary_messages, summary_model, false);
 
-        let token = {
-            let mut auth = self.jwt_auth.lock().unwrap();
-            auth.get_token()
-        };
-
         let (summary_text, _) =
-            StreamingHandler::stream_chat(BIGMODEL_API_URL, &token, request_body, &on_event)
+            StreamingHandler::stream_chat(self.provider.as_ref(), request_body, on_event)
                 .await?;
 
         // 解析总结结果
         let parsed = match Self::parse_summary_json(&summary_text) {
             Ok(p) => p,
-            Err(_) => return Ok(None),
+            Err(e) => {
+                tracing::warn!(memory_id, error = %e, "记忆摘要结果解析失败，本轮不写入");
+                return Ok(None);
+            }
         };
 
         let (final_summary, mut final_core_facts) = parsed;
@@ -2278,37 +4411,21 @@ impl ChatEngine {
             );
 
             let verify_messages = vec![
+                Message::system_prompt(
+                    "你是一个严谨的事实验证系统。请检查新总结是否完整保留了所有原始核心事实。只输出JSON。",
+                ),
                 Message {
                     id: String::new(),
-                    role: MessageRole::System,
-                    content: "你是一个严谨的事实验证系统。请检查新总结是否完整保留了所有原始核心事实。只输出JSON。".to_string(),
-                    thinking_content: None,
-                    model: "system".to_string(),
-                    timestamp: 0,
-                    message_type: MessageType::Say,
-                },
-                Message {
-                    id: String::new(),
-                    role: MessageRole::User,
-                    content: verify_prompt,
-                    thinking_content: None,
-                    model: "glm-4.7-flash".to_string(),
                     timestamp: 0,
-                    message_type: MessageType::Say,
+                    ..Message::new(MessageRole::User, verify_prompt, "glm-4.7-flash")
                 },
             ];
 
             let verify_body = Self::build_request_body(&verify_messages, "glm-4.7-flash", false);
 
-            let verify_token = {
-                let mut auth = self.jwt_auth.lock().unwrap();
-                auth.get_token()
-            };
-
             // 验证阶段的事件不传递给前端（静默执行）
             if let Ok((verify_text, _)) = StreamingHandler::stream_chat(
-                BIGMODEL_API_URL,
-                &verify_token,
+                self.provider.as_ref(),
                 verify_body,
                 |_| {}, // 静默，不向前端发送验证阶段的流事件
             )
@@ -2347,13 +4464,7 @@ impl ChatEngine {
         }
 
         // 构建最终记忆摘要
-        let keywords = MemoryEngine::extract_keywords(&final_summary);
-        let mut all_keywords = keywords;
-        for fact in &final_core_facts {
-            all_keywords.extend(MemoryEngine::extract_keywords(fact));
-        }
-        all_keywords.sort();
-        all_keywords.dedup();
+        let all_keywords = MemoryEngine::summary_keywords(&final_summary, &final_core_facts);
 
         let fact_tiers = MemoryEngine::classify_all_facts(&final_core_facts);
         let max_generation = existing_summaries
@@ -2373,25 +4484,192 @@ impl ChatEngine {
             compression_generation: max_generation,
             context_card: None,
             fact_tiers,
+            linked_fact_ids: Vec::new(),
+            pinned: false,
+            pinned_facts: Vec::new(),
+            stale: false,
         };
         let context_card = MemoryEngine::build_context_card(&memory);
         memory.context_card = Some(context_card);
+        Ok(Some(memory))
+    }
 
-        let mut summaries = existing_summaries;
+    /// 把摘要写入 memory_id 的记忆索引：保真度检查点、分级合并、事实链接、向量刷新
+    async fn commit_summary(
+        &self,
+        memory_id: &str,
+        conversation_id: &str,
+        memory: MemorySummary,
+    ) -> Result<MemorySummary, ChatError> {
+        let mut summaries = self
+            .memory_engine
+            .load_memory_index_async(memory_id)
+            .await
+            .unwrap_or_default();
         summaries.push(memory.clone());
 
+        // 合并会压缩事实，先留检查点供保真度审计对照
+        if !ConversationStore::is_sandbox(memory_id) {
+            if let Err(e) = self
+                .fidelity_auditor
+                .record_checkpoint(memory_id, &summaries)
+            {
+                tracing::debug!(memory_id, error = %e, "保真度检查点写入失败");
+            }
+        }
+
         if MemoryEngine::should_tiered_merge(&summaries) {
             let (merged, _) = MemoryEngine::tiered_merge(&summaries);
             summaries = merged;
         }
 
+        // 写入前建立事实 ↔ 摘要链接（合并后重新判定，被压缩掉的事实随之断链）
+        if let Err(e) = self
+            .knowledge_store
+            .cross_link(memory_id, &mut summaries)
+        {
+            tracing::debug!(memory_id, error = %e, "事实与摘要链接失败");
+        }
+
         self.memory_engine
-            .save_memory_index(conversation_id, &summaries)?;
+            .save_memory_index(memory_id, &summaries)?;
+
+        if memory_id == conversation_id {
+            self.conversation_store
+                .update_memory_summaries(memory_id, &summaries)?;
+        }
+
+        if !ConversationStore::is_sandbox(memory_id) {
+            self.refresh_embeddings(memory_id).await;
+        }
+
+        // 返回带链接的版本（最新摘要不参与合并，id 不变）
+        let memory = summaries
+            .iter()
+            .find(|s| s.id == memory.id)
+            .cloned()
+            .unwrap_or(memory);
+        Ok(memory)
+    }
+
+    /// 重新总结被编辑标记为过时的记忆摘要：按摘要的轮次范围取编辑后的原文
+    /// （含已移入冷存储的早期消息），原地替换摘要与核心事实，ID、置顶与压缩代数不变，
+    /// 置顶的核心事实原样带入。返回重写的条数；结果无法解析或原文已不在的摘要保持过时
+    pub async fn resummarize_stale(&self, conversation_id: &str) -> Result<u32, ChatError> {
+        if ConversationStore::is_sandbox(conversation_id) {
+            return Ok(0);
+        }
+        let mut summaries = self
+            .memory_engine
+            .load_memory_index_async(conversation_id)
+            .await
+            .unwrap_or_default();
+        if !summaries.iter().any(|s| s.stale) {
+            return Ok(0);
+        }
+
+        let conv = self.conversation_store.load_active_branch_async(conversation_id).await?;
+        let housekeeper = self.housekeeper.clone();
+        let id = conversation_id.to_string();
+        let mut history =
+            blocking_pool::offload("cold_storage_read", move || housekeeper.cold_messages(&id))
+                .await?;
+        history.retain(|m| !conv.messages.iter().any(|live| live.id == m.id));
+        history.extend(conv.messages.iter().cloned());
+        // 轮次从 turn_count 倒推，与 ConversationStore::turn_at 一致
+        let live_turns = history.iter().filter(|m| m.role == MessageRole::User).count() as u32;
+        let mut turn = conv.turn_count.saturating_sub(live_turns);
+        let numbered: Vec<(u32, &Message)> = history
+            .iter()
+            .map(|m| {
+                if m.role == MessageRole::User {
+                    turn += 1;
+                }
+                (turn.max(1), m)
+            })
+            .filter(|(_, m)| m.role != MessageRole::System)
+            .collect();
+
+        let mut rewritten = 0;
+        for i in 0..summaries.len() {
+            if !summaries[i].stale {
+                continue;
+            }
+            let range = summaries[i].turn_range_start..=summaries[i].turn_range_end;
+            let messages: Vec<Message> = numbered
+                .iter()
+                .filter(|(t, _)| range.contains(t))
+                .map(|(_, m)| (*m).clone())
+                .collect();
+            if messages.is_empty() {
+                continue;
+            }
+            let confirmed: Vec<MemorySummary> =
+                summaries.iter().filter(|s| !s.stale).cloned().collect();
+            let prompt = MemoryEngine::build_summarize_prompt(
+                &messages,
+                &confirmed,
+                *range.start(),
+                *range.end(),
+            );
+            let summary_model = Self::choose_summary_model(&messages);
+            let request = vec![
+                Message::system_prompt(
+                    "你是一个精确的记忆管理系统，负责总结对话内容。请严格按照要求的JSON格式输出。",
+                ),
+                Message {
+                    id: String::new(),
+                    timestamp: 0,
+                    ..Message::new(MessageRole::User, prompt, summary_model)
+                },
+            ];
+            let body = Self::build_request_body(&request, summary_model, false);
+            let (text, _) =
+                StreamingHandler::stream_chat(self.provider.as_ref(), body, |_| {}).await?;
+            let (summary_text, mut core_facts) = match Self::parse_summary_json(&text) {
+                Ok(parsed) => parsed,
+                Err(e) => {
+                    tracing::warn!(
+                        conversation_id,
+                        error = %e,
+                        "重新总结结果解析失败，摘要保持过时"
+                    );
+                    continue;
+                }
+            };
+
+            let summary = &mut summaries[i];
+            for fact in &summary.pinned_facts {
+                if !core_facts.contains(fact) {
+                    core_facts.push(fact.clone());
+                }
+            }
+            summary.keywords = MemoryEngine::summary_keywords(&summary_text, &core_facts);
+            summary.fact_tiers = MemoryEngine::classify_all_facts(&core_facts);
+            summary.summary = summary_text;
+            summary.core_facts = core_facts;
+            summary.stale = false;
+            summary.context_card = Some(MemoryEngine::build_context_card(summary));
+            // 同一 ID 的内容变了：丢掉旧向量，下面的刷新按新内容重算
+            let _ = self.embedding_store.invalidate(conversation_id, &summary.id);
+            rewritten += 1;
+        }
+        if rewritten == 0 {
+            return Ok(0);
+        }
 
+        if let Err(e) = self
+            .knowledge_store
+            .cross_link(conversation_id, &mut summaries)
+        {
+            tracing::debug!(conversation_id, error = %e, "事实与摘要链接失败");
+        }
+        self.memory_engine
+            .save_memory_index(conversation_id, &summaries)?;
         self.conversation_store
             .update_memory_summaries(conversation_id, &summaries)?;
-
-        Ok(Some(memory))
+        self.refresh_embeddings(conversation_id).await;
+        Ok(rewritten)
     }
 
     fn parse_summary_json(text: &str) -> Result<(String, Vec<String>), String> {
@@ -2427,8 +4705,106 @@ impl ChatEngine {
         Ok((summary, core_facts))
     }
 
+    /// 收束一段故事（流程见 closure）：尾声 → 强制收尾摘要与事实提取 →
+    /// 最终回顾 → Markdown 归档 → 标记只读。已收束的对话返回 ValidationError
+    #[tracing::instrument(skip_all, fields(conversation_id, model = chat_model))]
+    pub async fn close_conversation(
+        &self,
+        conversation_id: &str,
+        chat_model: &str,
+    ) -> Result<ConversationClosure, ChatError> {
+        let conv = self.conversation_store.load_open_async(conversation_id).await?;
+
+        // ── 尾声：角色口吻的告别，作为最后一条回复落盘 ──
+        let mut request_messages = conv.messages.clone();
+        request_messages.push(Message::new(
+            MessageRole::User,
+            closure::EPILOGUE_PROMPT.to_string(),
+            chat_model,
+        ));
+        let mut request_body = Self::build_request_body(&request_messages, chat_model, false);
+        request_body["max_tokens"] = serde_json::json!(closure::EPILOGUE_MAX_TOKENS);
+        let epilogue = match tokio::time::timeout(
+            std::time::Duration::from_secs(closure::CLOSURE_TIMEOUT_SECS),
+            StreamingHandler::complete_silently(self.provider.as_ref(), request_body),
+        )
+        .await
+        {
+            Ok(Ok(text)) if !text.trim().is_empty() => text.trim().to_string(),
+            _ => closure::FALLBACK_EPILOGUE.to_string(),
+        };
+        self.conversation_store.add_message_async(
+            conversation_id,
+            Message::new(MessageRole::Assistant, epilogue.clone(), chat_model),
+        ).await?;
+
+        // ── 收尾：不等轮次到点，补做摘要与事实提取（沙盒对话不留记忆） ──
+        let conv = self.conversation_store.load_active_branch_async(conversation_id).await?;
+        if !ConversationStore::is_sandbox(conversation_id) && conv.turn_count > 0 {
+            match self.group_chats.load(conversation_id) {
+                Some(group) => {
+                    for character in &group.characters {
+                        let view = GroupChatStore::character_view(&conv, &group, character);
+                        let scope = MemoryEngine::character_scope(conversation_id, &character.id);
+                        if let Err(e) = self.summarize_into(&scope, &view, &|_| {}).await {
+                            tracing::warn!(memory_id = %scope, error = %e, "收束时补做摘要失败");
+                        }
+                        self.extract_and_store_facts_for(&scope, &view).await;
+                    }
+                }
+                None => {
+                    if let Err(e) = self.summarize_into(conversation_id, &conv, &|_| {}).await {
+                        tracing::warn!(conversation_id, error = %e, "收束时补做摘要失败");
+                    }
+                    self.extract_and_store_facts_for(conversation_id, &conv).await;
+                }
+            }
+        }
+
+        // ── 最终回顾 ──
+        let conv = self.conversation_store.load_active_branch_async(conversation_id).await?;
+        let recap_request = Message::new(
+            MessageRole::User,
+            closure::build_recap_prompt(&conv),
+            chat_model,
+        );
+        let mut request_body = Self::build_request_body(&[recap_request], chat_model, false);
+        request_body["max_tokens"] = serde_json::json!(closure::RECAP_MAX_TOKENS);
+        let recap = match tokio::time::timeout(
+            std::time::Duration::from_secs(closure::CLOSURE_TIMEOUT_SECS),
+            StreamingHandler::complete_silently(self.provider.as_ref(), request_body),
+        )
+        .await
+        {
+            Ok(Ok(text)) if !text.trim().is_empty() => text.trim().to_string(),
+            _ => {
+                let summaries = self
+                    .memory_engine
+                    .load_memory_index_async(conversation_id)
+                    .await
+                    .unwrap_or_default();
+                closure::fallback_recap(&conv, &summaries)
+            }
+        };
+
+        // ── 归档并标记只读 ──
+        let closed_at = chrono::Utc::now().timestamp_millis();
+        let archive_path = self.archives.save(
+            conversation_id,
+            &closure::render_archive(&conv, &recap, closed_at),
+        )?;
+        self.conversation_store.mark_closed_async(conversation_id, closed_at).await?;
+
+        Ok(ConversationClosure {
+            epilogue,
+            recap,
+            archive_path,
+            closed_at,
+        })
+    }
+
     pub fn restart_story(&self, conversation_id: &str) -> Result<(), ChatError> {
-        let mut conv = self.conversation_store.load_conversation(conversation_id)?;
+        let mut conv = self.conversation_store.load_open(conversation_id)?;
         let mut kept_messages: Vec<Message> = Vec::new();
         let mut found_greeting = false;
 
@@ -2449,9 +4825,69 @@ impl ChatEngine {
         self.conversation_store.save_conversation(&conv)?;
         self.memory_engine.delete_memory_index(conversation_id)?;
         self.knowledge_store.delete_knowledge(conversation_id)?;
+        self.fidelity_auditor.delete(conversation_id)?;
+        self.embedding_store.delete(conversation_id)?;
+        self.mood.delete(conversation_id)?;
+        self.memory_engine.delete_distilled_state(conversation_id)?;
 
         Ok(())
     }
+
+    /// 立即重新蒸馏对话的核心状态（不等到上下文超长的下一轮），返回新状态；
+    /// 蒸馏没有结果时旧缓存作废并返回 None
+    pub async fn refresh_distilled_state(
+        &self,
+        conversation_id: &str,
+    ) -> Result<Option<DistilledSystemState>, ChatError> {
+        if ConversationStore::is_sandbox(conversation_id) {
+            return Ok(None);
+        }
+        let conv = self.conversation_store.load_active_branch_async(conversation_id).await?;
+        self.memory_engine.delete_distilled_state(conversation_id)?;
+        let summaries = self
+            .memory_engine
+            .load_memory_index_async(conversation_id)
+            .await
+            .unwrap_or_default();
+        let last_user_content = conv
+            .messages
+            .iter()
+            .rev()
+            .find(|m| m.role == MessageRole::User)
+            .map(|m| m.content.clone())
+            .unwrap_or_default();
+        let lore = self.lorebook.entries_for(conversation_id);
+        let enhanced_messages = Self::build_context_enhanced_messages(
+            &conv,
+            &last_user_content,
+            &summaries,
+            None,
+            &lore,
+            None,
+        );
+        let prompt_vars = self.prompt_vars(&conv, &last_user_content);
+        let distilled = self
+            .request_long_context_distillation(
+                conversation_id,
+                &enhanced_messages,
+                &summaries,
+                &last_user_content,
+                &prompt_vars,
+            )
+            .await;
+        if distilled.trim().is_empty() {
+            return Ok(None);
+        }
+        let state = MemoryEngine::distilled_state(
+            &distilled,
+            &summaries,
+            MemoryEngine::character_prompt_hash(&conv.messages),
+            conv.turn_count,
+        );
+        self.memory_engine
+            .save_distilled_state(conversation_id, &state)?;
+        Ok(Some(state))
+    }
 }
 
 #[cfg(test)]
@@ -2459,15 +4895,7 @@ mod tests {
     use super::*;
 
     fn make_message(role: MessageRole, content: &str) -> Message {
-        Message {
-            id: uuid::Uuid::new_v4().to_string(),
-            role,
-            content: content.to_string(),
-            thinking_content: None,
-            model: "glm-4-flash".to_string(),
-            timestamp: chrono::Utc::now().timestamp_millis(),
-            message_type: MessageType::Say,
-        }
+        Message::new(role, content, "glm-4-flash")
     }
 
     #[test]
@@ -2535,6 +4963,31 @@ mod tests {
         assert_eq!(api_msgs[2]["content"], "How are you?");
     }
 
+    #[test]
+    fn test_build_request_body_sends_images_only_for_vision_model() {
+        let image = |url: Option<&str>, caption: Option<&str>| MessageAttachment {
+            id: "a1".to_string(),
+            mime_type: "image/png".to_string(),
+            url: url.map(str::to_string),
+            caption: caption.map(str::to_string),
+        };
+        let mut earlier = make_message(MessageRole::User, "昨天的照片");
+        earlier.attachments = vec![image(None, Some("海边日落"))];
+        let mut latest = make_message(MessageRole::User, "这是哪里？");
+        latest.attachments = vec![image(Some("https://x.cn/a.png"), Some("一座红色的桥"))];
+        let messages = vec![earlier, make_message(MessageRole::Assistant, "好美"), latest];
+
+        let body = ChatEngine::build_request_body(&messages, attachments::VISION_MODEL, false);
+        let api_msgs = body["messages"].as_array().unwrap();
+        assert_eq!(api_msgs[0]["content"], "昨天的照片\n[图片：海边日落]");
+        let parts = api_msgs[2]["content"].as_array().unwrap();
+        assert_eq!(parts[0]["text"], "这是哪里？\n[图片：一座红色的桥]");
+        assert_eq!(parts[1]["image_url"]["url"], "https://x.cn/a.png");
+
+        let text_only = ChatEngine::build_request_body(&messages, "glm-4.7", false);
+        assert!(text_only["messages"][2]["content"].is_string());
+    }
+
     #[test]
     fn test_build_request_body_system_role() {
         let messages = vec![make_message(MessageRole::System, "You are helpful")];
@@ -2632,6 +5085,460 @@ mod tests {
         assert_eq!(body["messages"][0]["content"], content);
     }
 
+    #[test]
+    fn test_build_request_body_long_form_raises_output_floor() {
+        let messages = vec![make_message(MessageRole::User, &"字".repeat(200_000))];
+        let tuning = RequestTuning {
+            long_form: true,
+            ..Default::default()
+        };
+
+        let body = ChatEngine::build_request_body(&messages, "glm-4.7", false);
+        assert_eq!(body["max_tokens"], 2048);
+        let body = ChatEngine::build_request_body_with(&messages, "glm-4.7", false, &tuning);
+        assert_eq!(body["max_tokens"], LONG_FORM_MIN_OUTPUT_TOKENS);
+        // 仍受模型最大输出约束
+        let body = ChatEngine::build_request_body_with(&messages, "glm-4-air", false, &tuning);
+        assert_eq!(body["max_tokens"], 4095);
+    }
+
+    #[test]
+    fn test_build_request_body_penalties_only_when_set() {
+        let messages = vec![make_message(MessageRole::User, "你好")];
+        let body = ChatEngine::build_request_body(&messages, "glm-4.7", false);
+        assert!(body.get("frequency_penalty").is_none());
+        assert!(body.get("presence_penalty").is_none());
+
+        let tuning = RequestTuning {
+            frequency_penalty: 0.6,
+            presence_penalty: 0.3,
+            ..Default::default()
+        };
+        let body = ChatEngine::build_request_body_with(&messages, "glm-4.7", false, &tuning);
+        assert_eq!(body["frequency_penalty"], 0.6);
+        assert_eq!(body["presence_penalty"], 0.3);
+    }
+
+    #[test]
+    fn test_settings_hot_reload_applies_latest_and_notifies() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let base = tmp.path().to_str().unwrap();
+        let manager = crate::api::config_manager::ConfigManager::new(base);
+        let engine = ChatEngine::new("id.secret", base)
+            .unwrap()
+            .with_settings(AppSettings::default())
+            .watch_settings(manager.subscribe());
+
+        let events = std::cell::RefCell::new(Vec::new());
+        let on_event = |e: ChatStreamEvent| events.borrow_mut().push(e);
+        assert!(engine.apply_settings_updates(&on_event).is_none());
+
+        let first = AppSettings {
+            enable_web_search: true,
+            ..AppSettings::default()
+        };
+        manager.save_settings(&first).unwrap();
+        let mut second = first.clone();
+        second.content_intensity = ContentIntensity::Mild;
+        second.chat_model = "glm-4.7-flash".to_string();
+        manager.save_settings(&second).unwrap();
+
+        let (chat, thinking) = engine.refresh_turn_models("glm-4.7", "glm-4-air", &on_event);
+        assert_eq!(chat, "glm-4.7-flash");
+        assert_eq!(thinking, "glm-4-air");
+        assert_eq!(engine.current_settings(), second);
+        assert_eq!(events.borrow().len(), 1);
+        assert!(matches!(events.borrow()[0], ChatStreamEvent::ConfigChanged));
+    }
+
+    #[tokio::test]
+    async fn test_quick_commands_stay_out_of_history() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let engine = ChatEngine::new_offline(tmp.path().to_str().unwrap());
+        let store = ConversationStore::new(tmp.path().to_str().unwrap());
+        let conv = store.create_conversation();
+        store.save_conversation(&conv).unwrap();
+
+        let events = std::cell::RefCell::new(Vec::new());
+        let on_event = |e: ChatStreamEvent| events.borrow_mut().push(e);
+        for command in ["/remember 她不吃香菜", "/recap", "/dance"] {
+            engine
+                .send_message(&conv.id, command, Vec::new(), "glm-4.7", "glm-4-air", false, None, on_event)
+                .await
+                .unwrap();
+        }
+
+        let notices: Vec<String> = events
+            .borrow()
+            .iter()
+            .filter_map(|e| match e {
+                ChatStreamEvent::SystemNotice(text) => Some(text.clone()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(notices.len(), 3);
+        assert!(notices[0].contains("她不吃香菜"));
+        assert!(notices[2].contains("/regen"));
+        assert!(store.load_conversation(&conv.id).unwrap().messages.is_empty());
+        let facts = KnowledgeStore::new(tmp.path().to_str().unwrap()).get_all_facts(&conv.id);
+        assert_eq!(facts[0].content, "她不吃香菜");
+    }
+
+    #[tokio::test]
+    async fn test_ooc_directive_applies_to_next_reply_only() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let engine = ChatEngine::new_offline(tmp.path().to_str().unwrap());
+        let store = ConversationStore::new(tmp.path().to_str().unwrap());
+        let conv = store.create_conversation();
+        store.save_conversation(&conv).unwrap();
+
+        engine
+            .send_message(&conv.id, "/ooc 让场景转到海边", Vec::new(), "glm-4.7", "glm-4-air", false, None, |_| {})
+            .await
+            .unwrap();
+        let mut conv = store.load_conversation(&conv.id).unwrap();
+        assert_eq!(conv.turn_count, 0);
+        assert_eq!(conv.messages.len(), 1);
+        assert_eq!(conv.messages[0].role, MessageRole::System);
+        assert_eq!(conv.messages[0].message_type, MessageType::OutOfCharacter);
+
+        conv.messages.push(make_message(MessageRole::User, "我们走走吧"));
+        let mut enhanced = vec![make_message(MessageRole::User, "我们走走吧")];
+        ChatEngine::inject_directives(&conv, &mut enhanced);
+        assert_eq!(enhanced.len(), 2);
+        assert!(enhanced[0].content.contains("- 让场景转到海边"));
+        assert_eq!(enhanced[1].role, MessageRole::User);
+
+        conv.messages.push(make_message(MessageRole::Assistant, "（牵起你的手走向海边）"));
+        assert!(ChatEngine::pending_directives(&conv).is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_group_speaker_prefers_addressed_then_rotates() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let path = tmp.path().to_str().unwrap();
+        let engine = ChatEngine::new_offline(path);
+        let character = |name: &str| GroupCharacter {
+            id: String::new(),
+            name: name.to_string(),
+            system_prompt: format!("你是{}", name),
+        };
+        let group = GroupChatStore::new(path)
+            .save("g1", vec![character("小雨"), character("阿晴")], TurnPolicy::RoundRobin)
+            .unwrap();
+
+        let mut conv = ConversationStore::new(path).create_conversation();
+        conv.messages = vec![make_message(MessageRole::User, "大家晚上好")];
+        assert_eq!(engine.choose_speaker(&group, &conv).await.name, "小雨");
+
+        let mut reply = make_message(MessageRole::Assistant, "晚上好呀");
+        reply.character_id = Some(group.characters[0].id.clone());
+        conv.messages.push(reply);
+        conv.messages.push(make_message(MessageRole::User, "你们明天去哪"));
+        assert_eq!(engine.choose_speaker(&group, &conv).await.name, "阿晴");
+        conv.messages.push(make_message(MessageRole::User, "小雨，你呢"));
+        assert_eq!(engine.choose_speaker(&group, &conv).await.name, "小雨");
+    }
+
+    #[test]
+    fn test_respond_offline_runs_store_pipeline() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let engine = ChatEngine::new_offline(tmp.path().to_str().unwrap());
+        let store = ConversationStore::new(tmp.path().to_str().unwrap());
+        let conv = store.create_conversation();
+        store.save_conversation(&conv).unwrap();
+
+        let events = std::cell::RefCell::new(Vec::new());
+        let on_event = |e: ChatStreamEvent| events.borrow_mut().push(e);
+        engine.respond_offline(&conv.id, Some("你好呀"), on_event).unwrap();
+        engine.respond_offline(&conv.id, None, on_event).unwrap();
+
+        let saved = store.load_conversation(&conv.id).unwrap();
+        assert_eq!(saved.turn_count, 1);
+        assert_eq!(saved.messages.len(), 3);
+        assert_eq!(saved.messages[1].content, saved.messages[2].content);
+        assert_eq!(saved.messages[1].model, LOCAL_MODEL_ID);
+        assert!(matches!(events.borrow().last(), Some(ChatStreamEvent::Done)));
+        assert!(engine.respond_offline(&conv.id, Some("  "), on_event).is_err());
+    }
+
+    #[test]
+    fn test_local_provider_degrades_thinking_and_context() {
+        use crate::api::streaming_handler::LocalLlmProvider;
+        let tmp = tempfile::TempDir::new().unwrap();
+        let provider = LocalLlmProvider::new(None, "qwen2.5:7b");
+        let engine = ChatEngine::with_provider(Box::new(provider), tmp.path().to_str().unwrap());
+        assert!(!engine.effective_thinking("qwen2.5:7b", true));
+
+        let mut messages = vec![make_message(MessageRole::System, "你是小雨")];
+        for i in 0..40 {
+            messages.push(make_message(MessageRole::User, &"很长的一段旧对话".repeat(50 + i)));
+        }
+        messages.push(make_message(MessageRole::User, "最后一句"));
+        let fitted = engine.fit_context_window("qwen2.5:7b", &messages);
+        assert!(fitted.len() < messages.len());
+        assert_eq!(fitted[0].role, MessageRole::System);
+        assert_eq!(fitted.last().unwrap().content, "最后一句");
+        assert_eq!(engine.pending_decisions.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_generation_metadata_collects_turn_fallbacks() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let engine = ChatEngine::new("offline.local", tmp.path().to_str().unwrap()).unwrap();
+        assert!(engine.take_generation_metadata(0).is_none());
+
+        engine.record_decision(
+            DegradationKind::ModelFallback,
+            "glm-4.7-flash",
+            "回退到 glm-4.7-flash".to_string(),
+        );
+        engine.store_generation(GenerationMetadata {
+            model: "glm-4.7-flash".to_string(),
+            finish_reason: Some("stop".to_string()),
+            streamed: true,
+            ..GenerationMetadata::default()
+        });
+        let started_at = chrono::Utc::now().timestamp_millis() - 50;
+        let meta = engine.take_generation_metadata(started_at).unwrap();
+        assert_eq!(meta.fallbacks, vec!["回退到 glm-4.7-flash".to_string()]);
+        assert!(meta.latency_ms >= 50);
+        assert!(engine.take_generation_metadata(started_at).is_none(), "取出后清空");
+
+        let events = std::cell::RefCell::new(Vec::new());
+        let on_event = |e: ChatStreamEvent| events.borrow_mut().push(e);
+        let mut reply = make_message(MessageRole::Assistant, "好呀");
+        ChatEngine::emit_completed(&reply, &on_event);
+        assert!(events.borrow().is_empty(), "没有生成信息时不推送");
+        reply.generation_metadata = Some(meta.clone());
+        ChatEngine::emit_completed(&reply, &on_event);
+        match events.borrow().as_slice() {
+            [ChatStreamEvent::Completed(done)] => {
+                assert_eq!(done.message_id, reply.id);
+                assert_eq!(done.metadata, meta);
+            }
+            other => panic!("unexpected {:?}", other),
+        };
+    }
+
+    #[test]
+    fn test_shrink_for_context_limit_fits_reported_limit() {
+        let mut messages = vec![make_message(MessageRole::System, "你是小雨")];
+        for _ in 0..30 {
+            messages.push(make_message(MessageRole::User, &"旧对话内容".repeat(100)));
+        }
+        messages.push(make_message(MessageRole::User, "最后一句"));
+        let estimate = ChatEngine::estimate_token_count(&messages) as u32;
+        let limit = estimate / 2;
+
+        let (shrunk, output) =
+            ChatEngine::shrink_for_context_limit(&messages, limit, Some(estimate), 65536);
+        assert_eq!(output, limit / 4);
+        assert!(ChatEngine::estimate_token_count(&shrunk) as u32 + output <= limit);
+        assert_eq!(shrunk[0].role, MessageRole::System);
+        assert_eq!(shrunk.last().unwrap().content, "最后一句");
+        // 只裁剪到刚好放得下，而不是压缩到固定条数
+        assert!(shrunk.len() > 6);
+    }
+
+    #[test]
+    fn test_context_limit_falls_back_to_declared_window() {
+        use crate::api::streaming_handler::LocalLlmProvider;
+        let tmp = tempfile::TempDir::new().unwrap();
+        let engine = ChatEngine::new("offline.local", tmp.path().to_str().unwrap()).unwrap();
+        // GLM 1261 不带数字：用注册表声明的窗口
+        assert_eq!(engine.context_limit_for("glm-4.7", None), Some(128000));
+        assert_eq!(engine.context_limit_for("glm-4.7", Some(9000)), Some(9000));
+
+        let provider = LocalLlmProvider::new(None, "qwen2.5:7b");
+        let local = ChatEngine::with_provider(Box::new(provider), tmp.path().to_str().unwrap());
+        let window = local.provider.capabilities().context_window.unwrap();
+        assert_eq!(local.context_limit_for("qwen2.5:7b", None), Some(window as u32));
+    }
+
+    #[tokio::test]
+    async fn test_reasoning_context_includes_distilled_state() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let engine = ChatEngine::new_offline(tmp.path().to_str().unwrap());
+        let store = ConversationStore::new(tmp.path().to_str().unwrap());
+        let mut conv = store.create_conversation();
+        conv.messages = vec![
+            make_message(MessageRole::System, "你是小雨"),
+            make_message(MessageRole::User, "还记得上次吗"),
+        ];
+        store.save_conversation(&conv).unwrap();
+        let hash = MemoryEngine::character_prompt_hash(&conv.messages);
+        let state = MemoryEngine::distilled_state("小雨怕打雷", &[], hash, 1);
+        engine
+            .memory_engine
+            .save_distilled_state_async(&conv.id, &state)
+            .await
+            .unwrap();
+
+        // 推理拿到的是上下文准备之后的结果，持久化的蒸馏状态已在最后一条用户消息之前
+        let context = engine
+            .prepare_pipeline_context(
+                &conv,
+                &conv.id,
+                "还记得上次吗",
+                None,
+                &TemplateVars::default(),
+                conv.messages.clone(),
+            )
+            .await;
+        assert_eq!(context.len(), 3);
+        assert!(context[1].content.contains("小雨怕打雷"));
+        assert_eq!(context[2].role, MessageRole::User);
+    }
+
+    #[test]
+    fn test_rebase_models_keeps_explicit_choice() {
+        let previous = AppSettings::default();
+        let current = AppSettings {
+            chat_model: "glm-4.7-flash".to_string(),
+            thinking_model: "glm-4-plus".to_string(),
+            ..AppSettings::default()
+        };
+        let (chat, thinking) =
+            ChatEngine::rebase_models("glm-4-long", "glm-4-air", &previous, &current);
+        assert_eq!(chat, "glm-4-long");
+        assert_eq!(thinking, "glm-4-plus");
+    }
+
+    #[test]
+    fn test_build_request_body_attaches_web_search_tool() {
+        let messages = vec![make_message(MessageRole::User, "今天有什么新闻？")];
+        let body = ChatEngine::build_request_body(&messages, "glm-4.7", false);
+        assert!(body.get("tools").is_none());
+
+        let tuning = RequestTuning {
+            web_search: true,
+            ..Default::default()
+        };
+        let body = ChatEngine::build_request_body_with(&messages, "glm-4.7", false, &tuning);
+        assert_eq!(body["tools"][0]["type"], "web_search");
+    }
+
+    #[test]
+    fn test_compute_repetition_penalties_on_fixation() {
+        let fixed: Vec<Message> = (0..4)
+            .flat_map(|_| {
+                vec![
+                    make_message(MessageRole::User, "在干嘛"),
+                    make_message(MessageRole::Assistant, "嗯嗯，我在想你呢，你呢？"),
+                ]
+            })
+            .collect();
+        let refs: Vec<&Message> = fixed.iter().collect();
+        let (frequency, presence) = ChatEngine::compute_repetition_penalties(&refs);
+        assert!(frequency > 0.0 && frequency <= MAX_FREQUENCY_PENALTY);
+        assert!((presence - frequency / 2.0).abs() < 1e-9);
+
+        let short: Vec<&Message> = fixed.iter().take(2).collect();
+        assert_eq!(ChatEngine::compute_repetition_penalties(&short), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_history_truncation_ignores_window_cap() {
+        let store = ConversationStore::new("unused");
+        let mut conv = store.create_conversation();
+        conv.messages = (0..30)
+            .map(|i| {
+                let role = if i % 2 == 0 { MessageRole::User } else { MessageRole::Assistant };
+                make_message(role, "嗯")
+            })
+            .collect();
+        let enhanced = ChatEngine::build_context_enhanced_messages(&conv, "嗯", &[], None, &[], None);
+        assert_eq!(ChatEngine::history_truncation(&conv, &enhanced), None);
+
+        let trimmed: Vec<Message> = enhanced
+            .iter()
+            .filter(|m| m.role != MessageRole::System)
+            .skip(5)
+            .cloned()
+            .collect();
+        assert_eq!(ChatEngine::history_truncation(&conv, &trimmed), Some((15, 20)));
+    }
+
+    #[test]
+    fn test_context_layers_can_be_disabled() {
+        let store = ConversationStore::new("unused");
+        let mut conv = store.create_conversation();
+        conv.messages = vec![
+            make_message(MessageRole::User, "今天好难过，工作上又出错了"),
+            make_message(MessageRole::Assistant, "怎么啦？慢慢说"),
+            make_message(MessageRole::User, "被领导当众批评了"),
+        ];
+        let has_snapshot = |messages: &[Message]| messages.iter().any(|m| m.content.contains("【认知快照】"));
+        let enhanced = ChatEngine::build_context_enhanced_messages(&conv, "被领导当众批评了", &[], None, &[], None);
+        assert!(has_snapshot(&enhanced));
+
+        conv.context_layers.cognitive_snapshot = false;
+        conv.context_layers.short_term_memory = false;
+        let enhanced = ChatEngine::build_context_enhanced_messages(&conv, "被领导当众批评了", &[], None, &[], None);
+        assert!(!has_snapshot(&enhanced));
+        assert!(!enhanced.iter().any(|m| m.content.contains("【短期记忆")));
+    }
+
+    #[test]
+    fn test_lore_entries_inserted_at_their_depth() {
+        let store = ConversationStore::new("unused");
+        let mut conv = store.create_conversation();
+        conv.messages = vec![
+            make_message(MessageRole::User, "我们到雾港了"),
+            make_message(MessageRole::Assistant, "海风好大"),
+            make_message(MessageRole::User, "去码头看看"),
+        ];
+        let lore = |title: &str, keyword: &str, depth: u32| LoreEntry {
+            id: String::new(),
+            title: title.to_string(),
+            content: format!("{}的设定", title),
+            keywords: vec![keyword.to_string()],
+            insertion_depth: depth,
+            priority: 0,
+            enabled: true,
+        };
+        let entries = vec![lore("雾港", "雾港", 2), lore("码头", "码头", 0), lore("王城", "王城", 1)];
+        let enhanced =
+            ChatEngine::build_context_enhanced_messages(&conv, "去码头看看", &[], None, &entries, None);
+        let tail: Vec<&str> = enhanced[enhanced.len() - 5..]
+            .iter()
+            .map(|m| m.content.as_str())
+            .collect();
+        assert_eq!(tail[0], "我们到雾港了");
+        assert!(tail[1].contains("雾港的设定"));
+        assert_eq!(&tail[2..4], &["海风好大", "去码头看看"]);
+        assert!(tail[4].contains("码头的设定"));
+        assert!(!enhanced.iter().any(|m| m.content.contains("王城")));
+    }
+
+    #[test]
+    fn test_response_style_overrides_humanization_heuristics() {
+        let vars = TemplateVars::base(&[], "平静");
+        let auto = ChatEngine::build_humanization_hint(
+            "你好呀",
+            &[],
+            &MessageType::Say,
+            &ResponseStyle::default(),
+            &vars,
+        );
+        assert!(!auto.contains("对方设定的回复风格"));
+
+        let style = ResponseStyle {
+            reply_length: ReplyLength::Short,
+            emoji_usage: EmojiUsage::Never,
+            action_frequency: ActionFrequency::Frequent,
+            ..ResponseStyle::default()
+        };
+        let hint =
+            ChatEngine::build_humanization_hint("你好呀", &[], &MessageType::Say, &style, &vars);
+        assert!(hint.contains("回复简短"));
+        assert!(!hint.contains("真人聊天有长有短"));
+        assert!(hint.contains("不使用任何表情符号"));
+        assert!(hint.contains("每次回复都带上动作或神态描写"));
+        assert!(!hint.contains("用语正式礼貌"));
+    }
+
     #[test]
     fn test_detect_message_type() {
         assert_eq!(ChatEngine::detect_message_type("你好"), MessageType::Say);
@@ -2673,4 +5580,18 @@ mod tests {
         let result = ChatEngine::parse_summary_json(text).unwrap();
         assert_eq!(result.0, "概括内容");
     }
+
+    #[test]
+    fn test_draft_messages_place_instruction_before_last_user_message() {
+        let messages = vec![
+            make_message(MessageRole::System, "角色设定"),
+            make_message(MessageRole::User, "早"),
+            make_message(MessageRole::Assistant, "早呀"),
+            make_message(MessageRole::User, "今天去哪"),
+        ];
+        let draft = ChatEngine::build_draft_messages(&messages);
+        assert_eq!(draft.len(), 5);
+        assert_eq!(draft[3].content, DRAFT_PROMPT);
+        assert_eq!(draft[4].content, "今天去哪");
+    }
 }
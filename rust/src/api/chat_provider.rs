@@ -0,0 +1,542 @@
+// ═══════════════════════════════════════════════════════════════════
+//  对话提供方 (Chat Provider)
+//  ─────────────────────────────────────────────────────────────────
+//  ChatEngine 的管线始终以智谱 GLM（OpenAI 兼容）格式构造请求体，
+//  各家后端的差异全部收敛在提供方内部：
+//    1. 请求：把 GLM 请求体改写为目标协议（模型名映射、字段裁剪）
+//    2. 鉴权：生成请求头（智谱 JWT / Bearer Key / x-api-key）
+//    3. 解析：把目标协议的 SSE 行还原为 ChatStreamEvent，
+//       并读出结束原因与 token 用量（GenerationMetadata）
+//  新增后端只需实现 ChatProvider 并在 from_settings 中登记，
+//  chat_engine.rs 无需改动；能力不足的后端通过 ModelCapabilities
+//  声明，由管线自行降级（关闭思考、收紧上下文）。
+// ═══════════════════════════════════════════════════════════════════
+
+use std::sync::Mutex;
+
+use serde_json::{json, Value};
+
+use super::data_models::{AppSettings, ChatStreamEvent, GenerationMetadata, ProviderKind};
+use super::jwt_auth::JwtAuth;
+use super::streaming_handler::{LocalLlmProvider, StreamingHandler};
+
+const BIGMODEL_API_URL: &str = "https://open.bigmodel.cn/api/paas/v4/chat/completions";
+const ANTHROPIC_API_URL: &str = "https://api.anthropic.com/v1/messages";
+const ANTHROPIC_VERSION: &str = "2023-06-01";
+
+/// Anthropic 要求必须显式给出 max_tokens
+const ANTHROPIC_DEFAULT_MAX_TOKENS: u64 = 4096;
+/// Anthropic 思考预算下限（低于此值服务端拒绝）
+const ANTHROPIC_MIN_THINKING_BUDGET: u64 = 1024;
+
+/// 模型能力标记：管线据此决定是否走思考管线、是否压缩上下文
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ModelCapabilities {
+    /// 支持思考模式（不支持时跳过推理/蒸馏阶段，退回单模型对话）
+    pub supports_thinking: bool,
+    /// 上下文窗口（token，输入 + 输出）；None 表示沿用管线自身的 token 预算
+    pub context_window: Option<usize>,
+    /// 单次输出上限（token）；None 表示沿用按模型计算的 max_tokens
+    pub max_output_tokens: Option<u32>,
+}
+
+impl Default for ModelCapabilities {
+    fn default() -> Self {
+        Self {
+            supports_thinking: true,
+            context_window: None,
+            max_output_tokens: None,
+        }
+    }
+}
+
+/// 在线对话后端：请求构造、鉴权与流解析
+pub trait ChatProvider: Send + Sync {
+    /// 流式对话接口地址
+    fn endpoint(&self) -> String;
+
+    /// 本次请求的鉴权头（可能触发 token 续签）
+    fn auth_headers(&self) -> Vec<(String, String)>;
+
+    /// 把管线构造的 GLM 格式请求体改写为本提供方的请求体
+    fn build_request(&self, body: Value) -> Value;
+
+    /// 解析一行 SSE 数据；默认按 OpenAI 兼容格式
+    fn parse_stream_line(&self, line: &str) -> Option<ChatStreamEvent> {
+        StreamingHandler::parse_sse_line(line)
+    }
+
+    /// 解析非流式响应（弱网模式下使用），返回 (正文, 思考)；默认按 OpenAI 兼容格式
+    fn parse_completion(&self, json: &Value) -> Option<(String, String)> {
+        StreamingHandler::parse_completion(json)
+    }
+
+    /// 从响应 JSON（SSE 数据块或非流式整体）读取模型、结束原因与用量；默认按 OpenAI 兼容格式
+    fn read_generation_metadata(&self, json: &Value, meta: &mut GenerationMetadata) {
+        StreamingHandler::read_generation_metadata(json, meta)
+    }
+
+    /// 模型能力；默认视为完整能力
+    fn capabilities(&self) -> ModelCapabilities {
+        ModelCapabilities::default()
+    }
+}
+
+/// 按设置构建在线提供方（LocalEcho 不经过网络，此处按智谱处理以兼容记忆总结等后台任务）
+pub fn from_settings(settings: &AppSettings) -> Result<Box<dyn ChatProvider>, String> {
+    match settings.provider {
+        ProviderKind::Zhipu | ProviderKind::LocalEcho => {
+            let api_key = settings
+                .api_key
+                .as_deref()
+                .ok_or_else(|| "未配置 API Key，请在设置中填写您的智谱 API Key".to_string())?;
+            Ok(Box::new(ZhipuProvider::new(api_key)?))
+        }
+        ProviderKind::OpenAiCompatible => {
+            let base_url = non_empty(&settings.provider_base_url)
+                .ok_or_else(|| "OpenAI 兼容提供方需要填写接口地址".to_string())?;
+            let model = non_empty(&settings.provider_model)
+                .ok_or_else(|| "OpenAI 兼容提供方需要填写模型名".to_string())?;
+            Ok(Box::new(OpenAiCompatibleProvider::new(
+                base_url,
+                non_empty(&settings.provider_api_key),
+                model,
+            )))
+        }
+        ProviderKind::Anthropic => {
+            let api_key = non_empty(&settings.provider_api_key)
+                .ok_or_else(|| "Anthropic 提供方需要填写 API Key".to_string())?;
+            let model = non_empty(&settings.provider_model)
+                .ok_or_else(|| "Anthropic 提供方需要填写模型名".to_string())?;
+            Ok(Box::new(AnthropicProvider::new(
+                non_empty(&settings.provider_base_url),
+                api_key,
+                model,
+            )))
+        }
+        ProviderKind::LocalLlm => {
+            let model = non_empty(&settings.provider_model)
+                .ok_or_else(|| "本地模型需要填写模型名（如 ollama list 中的名称）".to_string())?;
+            Ok(Box::new(LocalLlmProvider::new(
+                non_empty(&settings.provider_base_url),
+                model,
+            )))
+        }
+    }
+}
+
+fn non_empty(value: &Option<String>) -> Option<&str> {
+    value.as_deref().map(str::trim).filter(|v| !v.is_empty())
+}
+
+/// OpenAI 风格的 base_url 可能给到 /v1 或完整路径，统一补全到 chat/completions
+pub fn chat_completions_url(base_url: &str) -> String {
+    let trimmed = base_url.trim_end_matches('/');
+    if trimmed.ends_with("/chat/completions") {
+        trimmed.to_string()
+    } else {
+        format!("{}/chat/completions", trimmed)
+    }
+}
+
+// ── 智谱 BigModel ──
+
+/// 智谱 GLM：请求体原样发送，JWT 鉴权
+pub struct ZhipuProvider {
+    jwt_auth: Mutex<JwtAuth>,
+}
+
+impl ZhipuProvider {
+    pub fn new(api_key: &str) -> Result<Self, String> {
+        Ok(Self {
+            jwt_auth: Mutex::new(JwtAuth::new(api_key)?),
+        })
+    }
+}
+
+impl ChatProvider for ZhipuProvider {
+    fn endpoint(&self) -> String {
+        BIGMODEL_API_URL.to_string()
+    }
+
+    fn auth_headers(&self) -> Vec<(String, String)> {
+        let token = {
+            let mut auth = self.jwt_auth.lock().unwrap();
+            auth.get_token()
+        };
+        vec![("Authorization".to_string(), format!("Bearer {}", token))]
+    }
+
+    fn build_request(&self, body: Value) -> Value {
+        body
+    }
+}
+
+// ── OpenAI 兼容端点 ──
+
+/// OpenAI 兼容端点（OpenAI、DeepSeek、vLLM、LM Studio 等）
+///
+/// GLM 专有字段（thinking、web_search 工具）不被识别，发送前剔除；
+/// 管线按角色选用的 GLM 模型统一映射到配置的模型。
+pub struct OpenAiCompatibleProvider {
+    url: String,
+    api_key: Option<String>,
+    model: String,
+}
+
+impl OpenAiCompatibleProvider {
+    pub fn new(base_url: &str, api_key: Option<&str>, model: &str) -> Self {
+        Self {
+            url: chat_completions_url(base_url),
+            api_key: api_key.map(str::to_string),
+            model: model.to_string(),
+        }
+    }
+}
+
+impl ChatProvider for OpenAiCompatibleProvider {
+    fn endpoint(&self) -> String {
+        self.url.clone()
+    }
+
+    fn auth_headers(&self) -> Vec<(String, String)> {
+        match &self.api_key {
+            Some(key) => vec![("Authorization".to_string(), format!("Bearer {}", key))],
+            None => Vec::new(),
+        }
+    }
+
+    fn build_request(&self, mut body: Value) -> Value {
+        if let Some(obj) = body.as_object_mut() {
+            obj.insert("model".to_string(), json!(self.model));
+            obj.remove("thinking");
+            obj.remove("tools");
+        }
+        body
+    }
+}
+
+// ── Anthropic Messages API ──
+
+/// Anthropic：system 提升为顶层字段，思考预算换算为 extended thinking
+pub struct AnthropicProvider {
+    url: String,
+    api_key: String,
+    model: String,
+}
+
+impl AnthropicProvider {
+    pub fn new(base_url: Option<&str>, api_key: &str, model: &str) -> Self {
+        let url = match base_url {
+            Some(base) => {
+                let trimmed = base.trim_end_matches('/');
+                if trimmed.ends_with("/messages") {
+                    trimmed.to_string()
+                } else {
+                    format!("{}/messages", trimmed)
+                }
+            }
+            None => ANTHROPIC_API_URL.to_string(),
+        };
+        Self {
+            url,
+            api_key: api_key.to_string(),
+            model: model.to_string(),
+        }
+    }
+}
+
+impl ChatProvider for AnthropicProvider {
+    fn endpoint(&self) -> String {
+        self.url.clone()
+    }
+
+    fn auth_headers(&self) -> Vec<(String, String)> {
+        vec![
+            ("x-api-key".to_string(), self.api_key.clone()),
+            ("anthropic-version".to_string(), ANTHROPIC_VERSION.to_string()),
+        ]
+    }
+
+    fn build_request(&self, body: Value) -> Value {
+        // 开头连续的 system 消息是稳定前缀（角色设定、人设、身份锚点，见 context_cache），
+        // 单独成块并打上缓存断点；其余 system 消息每轮变化，放在断点之后
+        let mut prefix_parts: Vec<String> = Vec::new();
+        let mut system_parts: Vec<String> = Vec::new();
+        let mut messages: Vec<Value> = Vec::new();
+        for msg in body["messages"].as_array().cloned().unwrap_or_default() {
+            let content = msg["content"].as_str().unwrap_or("").to_string();
+            if msg["role"] == "system" {
+                if messages.is_empty() && system_parts.is_empty() {
+                    prefix_parts.push(content);
+                } else {
+                    system_parts.push(content);
+                }
+            } else {
+                messages.push(json!({ "role": msg["role"], "content": content }));
+            }
+        }
+
+        let max_tokens = body["max_tokens"]
+            .as_u64()
+            .unwrap_or(ANTHROPIC_DEFAULT_MAX_TOKENS);
+        let mut request = json!({
+            "model": self.model,
+            "messages": messages,
+            "max_tokens": max_tokens,
+            "stream": true,
+        });
+        let mut system_blocks: Vec<Value> = Vec::new();
+        if !prefix_parts.is_empty() {
+            system_blocks.push(json!({
+                "type": "text",
+                "text": prefix_parts.join("\n\n"),
+                "cache_control": { "type": "ephemeral" },
+            }));
+        }
+        if !system_parts.is_empty() {
+            system_blocks.push(json!({ "type": "text", "text": system_parts.join("\n\n") }));
+        }
+        if !system_blocks.is_empty() {
+            request["system"] = Value::Array(system_blocks);
+        }
+        for key in ["temperature", "top_p"] {
+            if let Some(v) = body.get(key) {
+                request[key] = v.clone();
+            }
+        }
+
+        // 思考预算必须落在 [1024, max_tokens) 区间内，否则不开启
+        if body["thinking"]["type"] == "enabled" {
+            let budget = body["thinking"]["budget_tokens"]
+                .as_u64()
+                .unwrap_or(ANTHROPIC_MIN_THINKING_BUDGET)
+                .max(ANTHROPIC_MIN_THINKING_BUDGET);
+            if budget < max_tokens {
+                request["thinking"] = json!({ "type": "enabled", "budget_tokens": budget });
+                // 开启思考时服务端只接受默认温度
+                if let Some(obj) = request.as_object_mut() {
+                    obj.remove("temperature");
+                    obj.remove("top_p");
+                }
+            }
+        }
+        request
+    }
+
+    fn parse_stream_line(&self, line: &str) -> Option<ChatStreamEvent> {
+        let data = line.trim().strip_prefix("data:")?.trim();
+        let json: Value = serde_json::from_str(data).ok()?;
+        match json["type"].as_str()? {
+            "content_block_delta" => {
+                let delta = &json["delta"];
+                match delta["type"].as_str()? {
+                    "text_delta" => delta["text"]
+                        .as_str()
+                        .filter(|t| !t.is_empty())
+                        .map(|t| ChatStreamEvent::ContentDelta(t.to_string())),
+                    "thinking_delta" => delta["thinking"]
+                        .as_str()
+                        .filter(|t| !t.is_empty())
+                        .map(|t| ChatStreamEvent::ThinkingDelta(t.to_string())),
+                    _ => None,
+                }
+            }
+            "message_stop" => Some(ChatStreamEvent::Done),
+            "error" => Some(ChatStreamEvent::Error(
+                json["error"]["message"]
+                    .as_str()
+                    .unwrap_or("Unknown API error")
+                    .to_string(),
+            )),
+            _ => None,
+        }
+    }
+
+    fn parse_completion(&self, json: &Value) -> Option<(String, String)> {
+        let blocks = json["content"].as_array()?;
+        let mut content = String::new();
+        let mut thinking = String::new();
+        for block in blocks {
+            match block["type"].as_str() {
+                Some("text") => content.push_str(block["text"].as_str().unwrap_or("")),
+                Some("thinking") => thinking.push_str(block["thinking"].as_str().unwrap_or("")),
+                _ => {}
+            }
+        }
+        Some((content, thinking))
+    }
+
+    fn read_generation_metadata(&self, json: &Value, meta: &mut GenerationMetadata) {
+        // 流式：message_start 带模型与输入用量，message_delta 带结束原因与累计输出用量；
+        // 非流式：整体响应的 type 为 message
+        let message = match json["type"].as_str() {
+            Some("message_start") => &json["message"],
+            Some("message_delta") => {
+                if let Some(reason) = json["delta"]["stop_reason"].as_str() {
+                    meta.finish_reason = Some(reason.to_string());
+                }
+                StreamingHandler::apply_usage(meta, None, json["usage"]["output_tokens"].as_u64(), None);
+                return;
+            }
+            Some("message") => json,
+            _ => return,
+        };
+        if let Some(model) = message["model"].as_str().filter(|m| !m.is_empty()) {
+            meta.model = model.to_string();
+        }
+        if let Some(reason) = message["stop_reason"].as_str() {
+            meta.finish_reason = Some(reason.to_string());
+        }
+        StreamingHandler::apply_usage(
+            meta,
+            message["usage"]["input_tokens"].as_u64(),
+            message["usage"]["output_tokens"].as_u64(),
+            None,
+        );
+        if let Some(cached) = message["usage"]["cache_read_input_tokens"].as_u64() {
+            meta.cached_tokens = cached as u32;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn glm_body() -> Value {
+        json!({
+            "model": "glm-4-air",
+            "messages": [
+                {"role": "system", "content": "你是小雨"},
+                {"role": "user", "content": "你好"},
+            ],
+            "stream": true,
+            "max_tokens": 4095,
+            "temperature": 0.8,
+            "thinking": {"type": "enabled", "budget_tokens": 2048},
+            "tools": [{"type": "web_search"}],
+        })
+    }
+
+    #[test]
+    fn test_openai_compatible_maps_model_and_strips_glm_fields() {
+        let provider =
+            OpenAiCompatibleProvider::new("http://localhost:8000/v1/", Some("sk-test"), "qwen2.5");
+        assert_eq!(provider.endpoint(), "http://localhost:8000/v1/chat/completions");
+        let body = provider.build_request(glm_body());
+        assert_eq!(body["model"], "qwen2.5");
+        assert!(body.get("thinking").is_none());
+        assert!(body.get("tools").is_none());
+        assert_eq!(body["messages"].as_array().unwrap().len(), 2);
+        assert_eq!(provider.auth_headers()[0].1, "Bearer sk-test");
+    }
+
+    #[test]
+    fn test_anthropic_lifts_system_and_maps_thinking() {
+        let provider = AnthropicProvider::new(None, "ak-test", "claude-test");
+        let body = provider.build_request(glm_body());
+        assert_eq!(
+            body["system"],
+            json!([{"type": "text", "text": "你是小雨", "cache_control": {"type": "ephemeral"}}])
+        );
+        assert_eq!(body["messages"].as_array().unwrap().len(), 1);
+        assert_eq!(body["model"], "claude-test");
+        assert_eq!(body["thinking"]["budget_tokens"], 2048);
+        assert!(body.get("temperature").is_none());
+        assert!(provider
+            .auth_headers()
+            .iter()
+            .any(|(k, v)| k == "x-api-key" && v == "ak-test"));
+    }
+
+    #[test]
+    fn test_anthropic_parses_stream_events() {
+        let provider = AnthropicProvider::new(None, "ak-test", "claude-test");
+        let text = r#"data: {"type":"content_block_delta","index":0,"delta":{"type":"text_delta","text":"嗯"}}"#;
+        assert!(matches!(
+            provider.parse_stream_line(text),
+            Some(ChatStreamEvent::ContentDelta(ref t)) if t == "嗯"
+        ));
+        let thinking = r#"data: {"type":"content_block_delta","index":0,"delta":{"type":"thinking_delta","thinking":"想想"}}"#;
+        assert!(matches!(
+            provider.parse_stream_line(thinking),
+            Some(ChatStreamEvent::ThinkingDelta(ref t)) if t == "想想"
+        ));
+        assert!(matches!(
+            provider.parse_stream_line(r#"data: {"type":"message_stop"}"#),
+            Some(ChatStreamEvent::Done)
+        ));
+        assert!(provider.parse_stream_line("event: message_stop").is_none());
+    }
+
+    #[test]
+    fn test_parse_completion_for_non_streaming_mode() {
+        let openai = json!({"choices": [{"message": {"content": "你好呀", "reasoning_content": "先打招呼"}}]});
+        let zhipu = ZhipuProvider::new("offline.local").unwrap();
+        assert_eq!(
+            zhipu.parse_completion(&openai),
+            Some(("你好呀".to_string(), "先打招呼".to_string()))
+        );
+
+        let anthropic = AnthropicProvider::new(None, "ak-test", "claude-test");
+        let body = json!({"content": [
+            {"type": "thinking", "thinking": "想想"},
+            {"type": "text", "text": "嗯"},
+        ]});
+        assert_eq!(
+            anthropic.parse_completion(&body),
+            Some(("嗯".to_string(), "想想".to_string()))
+        );
+        assert!(anthropic.parse_completion(&openai).is_none());
+    }
+
+    #[test]
+    fn test_read_generation_metadata_per_protocol() {
+        let zhipu = ZhipuProvider::new("offline.local").unwrap();
+        let mut meta = GenerationMetadata::default();
+        let last_chunk = json!({
+            "model": "glm-4.7",
+            "choices": [{"index": 0, "delta": {}, "finish_reason": "length"}],
+            "usage": {"prompt_tokens": 120, "completion_tokens": 30, "total_tokens": 150,
+                      "prompt_tokens_details": {"cached_tokens": 96}},
+        });
+        zhipu.read_generation_metadata(&last_chunk, &mut meta);
+        assert_eq!(meta.model, "glm-4.7");
+        assert_eq!(meta.cached_tokens, 96);
+        assert_eq!(meta.finish_reason.as_deref(), Some("length"));
+        assert_eq!((meta.prompt_tokens, meta.completion_tokens, meta.total_tokens), (120, 30, 150));
+
+        let anthropic = AnthropicProvider::new(None, "ak-test", "claude-test");
+        let mut meta = GenerationMetadata::default();
+        let start = json!({"type": "message_start", "message": {
+            "model": "claude-test",
+            "usage": {"input_tokens": 80, "output_tokens": 1, "cache_read_input_tokens": 64}}});
+        let delta = json!({"type": "message_delta",
+            "delta": {"stop_reason": "end_turn"}, "usage": {"output_tokens": 42}});
+        anthropic.read_generation_metadata(&start, &mut meta);
+        anthropic.read_generation_metadata(&delta, &mut meta);
+        assert_eq!(meta.model, "claude-test");
+        assert_eq!(meta.finish_reason.as_deref(), Some("end_turn"));
+        assert_eq!((meta.prompt_tokens, meta.completion_tokens, meta.total_tokens), (80, 42, 122));
+        assert_eq!(meta.cached_tokens, 64);
+    }
+
+    #[test]
+    fn test_from_settings_requires_provider_fields() {
+        let settings = AppSettings {
+            provider: ProviderKind::OpenAiCompatible,
+            provider_model: Some("llama3".to_string()),
+            ..AppSettings::default()
+        };
+        assert!(from_settings(&settings).is_err());
+
+        let settings = AppSettings {
+            provider_base_url: Some("http://localhost:11434/v1".to_string()),
+            ..settings
+        };
+        let provider = from_settings(&settings).unwrap();
+        assert_eq!(provider.endpoint(), "http://localhost:11434/v1/chat/completions");
+        assert!(provider.auth_headers().is_empty());
+    }
+}
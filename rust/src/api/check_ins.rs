@@ -0,0 +1,156 @@
+use super::data_models::{CheckInSchedule, Conversation, MessageRole};
+
+// ═══════════════════════════════════════════════════════════════════
+//  主动联系 (Proactive Check-ins)
+//  ─────────────────────────────────────────────────────────────────
+//  真人朋友隔了半天没收到消息，会自己发一句「在忙吗」。开启主动联系的对话：
+//    1. 调度：chat_api::watch_check_ins 用 tokio 定时器睡到最早的到期时间
+//       （最长 MAX_SLEEP_SECS，期间修改的设置下次醒来生效）
+//    2. 到期：对方沉默超过 silence_hours，且上次主动联系之后对方说过话
+//       ——对方不回复就不会接连发第二条
+//    3. 生成：走正常的上下文构建与对话模型请求，把 check_in 提示放在
+//       上下文末尾，让角色以自己的口吻开启话题（ChatEngine::send_check_in）
+//  已收束的对话、还没开始聊的对话不会主动联系。
+//
+//  存储结构：
+//    check_ins/{conversation_id}.json（见 ConversationStore）
+// ═══════════════════════════════════════════════════════════════════
+
+const HOUR_MS: i64 = 60 * 60 * 1000;
+const DAY_MS: i64 = 24 * HOUR_MS;
+/// 调度循环单次最长睡眠：设置变化（新开启、改时长）最迟这么久后生效
+pub const MAX_SLEEP_SECS: u64 = 5 * 60;
+/// 没有到期的对话时也至少睡这么久，避免到期但生成失败的对话被反复重试
+pub const MIN_SLEEP_SECS: u64 = 30;
+
+pub struct CheckInPlanner;
+
+impl CheckInPlanner {
+    /// 对话下一次主动联系的时间；不需要主动联系时为 None
+    pub fn due_at(schedule: &CheckInSchedule, conv: &Conversation) -> Option<i64> {
+        if !schedule.enabled || schedule.silence_hours == 0 || conv.closed_at.is_some() {
+            return None;
+        }
+        let last_user_at = conv
+            .messages
+            .iter()
+            .rev()
+            .find(|m| m.role == MessageRole::User)
+            .map(|m| m.timestamp)?;
+        if schedule.last_sent_at.is_some_and(|sent| sent >= last_user_at) {
+            return None;
+        }
+        let last_activity = conv
+            .messages
+            .iter()
+            .rev()
+            .find(|m| m.role != MessageRole::System)
+            .map(|m| m.timestamp)
+            .unwrap_or(last_user_at);
+        Some(last_activity + schedule.silence_hours as i64 * HOUR_MS)
+    }
+
+    /// 调度循环下一次醒来前应睡的秒数
+    pub fn sleep_secs(next_due: Option<i64>, now: i64) -> u64 {
+        let until = next_due.map_or(MAX_SLEEP_SECS, |due| {
+            ((due - now).max(0) as u64).div_ceil(1000)
+        });
+        until.clamp(MIN_SLEEP_SECS, MAX_SLEEP_SECS)
+    }
+
+    /// 「约 5 小时」「约 2 天」
+    pub fn describe_idle(idle_ms: i64) -> String {
+        if idle_ms >= DAY_MS {
+            format!("约 {} 天", idle_ms / DAY_MS)
+        } else {
+            format!("约 {} 小时", (idle_ms / HOUR_MS).max(1))
+        }
+    }
+
+    /// 内置的主动联系提示（可被 check_in 模板覆盖）
+    pub fn build_prompt(idle: &str) -> String {
+        format!(
+            "【主动联系】对方已经{}没有说话了，现在由你主动发一条消息：\n\
+             - 像真人隔了一阵子想起对方那样自然开口：问候近况、分享身边的小事，\
+             或接着上次没聊完的话题\n\
+             - 只发一条简短的消息，符合你此刻的心情与你们的关系\n\
+             - 不要抱怨对方没回复，也不要提到这是被安排的",
+            idle
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::conversation_store::ConversationStore;
+    use crate::api::data_models::Message;
+
+    fn message(role: MessageRole, timestamp: i64) -> Message {
+        Message {
+            id: String::new(),
+            timestamp,
+            ..Message::new(role, "嗯", "")
+        }
+    }
+
+    #[test]
+    fn test_check_in_due_once_per_silence() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let store = ConversationStore::new(tmp.path().to_str().unwrap());
+        let mut conv = store.create_conversation();
+        let mut schedule = CheckInSchedule {
+            conversation_id: conv.id.clone(),
+            enabled: true,
+            silence_hours: 6,
+            last_sent_at: None,
+        };
+        // 还没开始聊
+        assert_eq!(CheckInPlanner::due_at(&schedule, &conv), None);
+
+        conv.messages.push(message(MessageRole::User, 1_000));
+        conv.messages.push(message(MessageRole::Assistant, 2_000));
+        assert_eq!(CheckInPlanner::due_at(&schedule, &conv), Some(2_000 + 6 * HOUR_MS));
+
+        // 已主动联系过、对方还没回复
+        schedule.last_sent_at = Some(3_000);
+        assert_eq!(CheckInPlanner::due_at(&schedule, &conv), None);
+        conv.messages.push(message(MessageRole::User, 4_000));
+        assert_eq!(CheckInPlanner::due_at(&schedule, &conv), Some(4_000 + 6 * HOUR_MS));
+
+        schedule.enabled = false;
+        assert_eq!(CheckInPlanner::due_at(&schedule, &conv), None);
+
+        assert_eq!(CheckInPlanner::sleep_secs(None, 0), MAX_SLEEP_SECS);
+        assert_eq!(CheckInPlanner::sleep_secs(Some(-5), 0), MIN_SLEEP_SECS);
+        assert_eq!(CheckInPlanner::sleep_secs(Some(90_500), 0), 91);
+    }
+
+    #[test]
+    fn test_schedule_persists_and_follows_conversation() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let store = ConversationStore::new(tmp.path().to_str().unwrap());
+        let conv = store.create_conversation();
+        store.save_conversation(&conv).unwrap();
+        let mut schedule = CheckInSchedule {
+            conversation_id: conv.id.clone(),
+            enabled: true,
+            silence_hours: 0,
+            last_sent_at: None,
+        };
+        assert!(store.set_check_in_schedule(&schedule).is_err());
+        schedule.silence_hours = 12;
+        store.set_check_in_schedule(&schedule).unwrap();
+        store.mark_check_in_sent(&conv.id, 42).unwrap();
+
+        // 修改设置保留上次主动联系的时间
+        schedule.silence_hours = 24;
+        store.set_check_in_schedule(&schedule).unwrap();
+        let loaded = store.load_check_in_schedule(&conv.id).unwrap();
+        assert_eq!((loaded.silence_hours, loaded.last_sent_at), (24, Some(42)));
+        assert_eq!(store.list_check_in_schedules().len(), 1);
+
+        store.delete_conversation(&conv.id).unwrap();
+        assert!(store.list_check_in_schedules().is_empty());
+    }
+}
@@ -0,0 +1,231 @@
+use std::fs;
+use std::path::PathBuf;
+
+use super::data_models::{Conversation, MemorySummary, Message, MessageRole};
+use super::error_handler::ChatError;
+use super::text_utils;
+
+// ═══════════════════════════════════════════════════════════════════
+//  故事收束 (Conversation Closure)
+//  ─────────────────────────────────────────────────────────────────
+//  用户决定结束一段故事时（ChatEngine::close_conversation）：
+//    1. 尾声：角色以自己的口吻写一段告别 / 尾声，作为最后一条回复落盘
+//    2. 收尾：不论轮次是否到点，强制补做一次记忆摘要与事实提取
+//    3. 回顾：生成整段故事的最终回顾（失败时由已有摘要拼接）
+//    4. 归档：回顾 + 完整对话导出为 Markdown 文件
+//    5. 只读：写入 Conversation.closed_at，此后消息相关的修改全部拒绝，
+//       对话本身、分支列表与归档仍可浏览
+//
+//  存储结构：
+//    archives/{conversation_id}.md
+// ═══════════════════════════════════════════════════════════════════
+
+/// 尾声 / 回顾请求的超时
+pub const CLOSURE_TIMEOUT_SECS: u64 = 60;
+/// 尾声的最大输出
+pub const EPILOGUE_MAX_TOKENS: u32 = 800;
+/// 回顾的最大输出
+pub const RECAP_MAX_TOKENS: u32 = 1200;
+/// 回顾 prompt 中每条消息保留的字符数
+const RECAP_MESSAGE_CHARS: usize = 200;
+/// 回顾 prompt 最多带上的消息数（取最近的）
+const RECAP_CONTEXT_MESSAGES: usize = 60;
+/// 兜底回顾最多引用的原文条数
+const FALLBACK_RECAP_MESSAGES: usize = 6;
+
+/// 追加在对话末尾、请角色写尾声的指令
+pub const EPILOGUE_PROMPT: &str = "【故事即将结束】用户决定在这里为这段故事画上句号。\
+请你保持角色身份与一贯的语气，写下最后一段告别 / 尾声：\n\
+- 回应你们之间最重要的经历与未了的心事，给出一个有余韵的收尾\n\
+- 不要跳出角色，不要提及「模型」「对话」「结束按钮」之类的字眼\n\
+- 300字以内，只输出尾声本身";
+
+/// 尾声生成失败时的兜底
+pub const FALLBACK_EPILOGUE: &str = "（故事在这里轻轻落下帷幕。谢谢你陪我走完这一程，愿我们在别的故事里再相遇。）";
+
+/// 让模型写最终回顾的 prompt
+pub fn build_recap_prompt(conv: &Conversation) -> String {
+    let transcript: Vec<String> = story_messages(conv)
+        .rev()
+        .take(RECAP_CONTEXT_MESSAGES)
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+        .map(|m| {
+            format!(
+                "{}：{}",
+                speaker(m),
+                text_utils::ellipsize(&m.content, RECAP_MESSAGE_CHARS, "…")
+            )
+        })
+        .collect();
+    let earlier: Vec<&str> = conv
+        .memory_summaries
+        .iter()
+        .map(|s| s.summary.as_str())
+        .collect();
+    let earlier = if earlier.is_empty() {
+        String::new()
+    } else {
+        format!("更早的剧情摘要：\n{}\n\n", earlier.join("\n"))
+    };
+    format!(
+        "{}最近的对话：\n{}\n\n\
+         这段故事已经结束。请以旁观者的视角写一份最终回顾：\n\
+         - 按时间顺序梳理主要情节与转折\n\
+         - 写出两人关系的变化与最终落点\n\
+         - 列出值得纪念的瞬间\n\
+         - 500字以内，只输出回顾本身",
+        earlier,
+        transcript.join("\n")
+    )
+}
+
+/// 回顾生成失败时的兜底：已有摘要按时间拼接，没有摘要时引用最后几条原文
+pub fn fallback_recap(conv: &Conversation, summaries: &[MemorySummary]) -> String {
+    if !summaries.is_empty() {
+        let mut ordered: Vec<&MemorySummary> = summaries.iter().collect();
+        ordered.sort_by_key(|s| s.turn_range_start);
+        return ordered
+            .iter()
+            .map(|s| s.summary.trim())
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n");
+    }
+    let recent: Vec<String> = story_messages(conv)
+        .rev()
+        .take(FALLBACK_RECAP_MESSAGES)
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+        .map(|m| format!("{}：{}", speaker(m), text_utils::ellipsize(&m.content, 80, "…")))
+        .collect();
+    recent.join("\n")
+}
+
+/// 导出为 Markdown：标题、起止时间、最终回顾，然后是完整对话（不含 system）
+pub fn render_archive(conv: &Conversation, recap: &str, closed_at: i64) -> String {
+    let title = if conv.title.trim().is_empty() {
+        "未命名的故事"
+    } else {
+        conv.title.trim()
+    };
+    let mut out = format!(
+        "# {}\n\n- 开始：{}\n- 结束：{}\n- 轮次：{}\n\n## 最终回顾\n\n{}\n\n## 完整对话\n\n",
+        title,
+        format_time(conv.created_at),
+        format_time(closed_at),
+        conv.turn_count,
+        recap.trim()
+    );
+    for m in story_messages(conv) {
+        out.push_str(&format!("**{}**：{}\n\n", speaker(m), m.content.trim()));
+    }
+    out
+}
+
+fn story_messages(conv: &Conversation) -> impl DoubleEndedIterator<Item = &Message> {
+    conv.messages.iter().filter(|m| m.role != MessageRole::System)
+}
+
+fn speaker(message: &Message) -> &'static str {
+    if message.role == MessageRole::User {
+        "用户"
+    } else {
+        "角色"
+    }
+}
+
+fn format_time(millis: i64) -> String {
+    chrono::DateTime::from_timestamp_millis(millis)
+        .map(|t| {
+            t.with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M")
+                .to_string()
+        })
+        .unwrap_or_default()
+}
+
+/// 收束归档的存储
+pub struct ArchiveStore {
+    base_path: String,
+}
+
+impl ArchiveStore {
+    pub fn new(base_path: &str) -> Self {
+        Self {
+            base_path: base_path.to_string(),
+        }
+    }
+
+    fn archives_dir(&self) -> Result<PathBuf, ChatError> {
+        let dir = PathBuf::from(&self.base_path).join("archives");
+        if !dir.exists() {
+            fs::create_dir_all(&dir).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to create archives directory: {}", e),
+            })?;
+        }
+        Ok(dir)
+    }
+
+    /// 写入对话的归档，返回文件路径
+    pub fn save(&self, conversation_id: &str, content: &str) -> Result<String, ChatError> {
+        let path = self.archives_dir()?.join(format!("{}.md", conversation_id));
+        fs::write(&path, content).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to write archive: {}", e),
+        })?;
+        Ok(path.to_string_lossy().to_string())
+    }
+
+    /// 删除对话的归档
+    pub fn delete(&self, conversation_id: &str) -> Result<(), ChatError> {
+        let path = self.archives_dir()?.join(format!("{}.md", conversation_id));
+        if path.exists() {
+            fs::remove_file(&path).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to delete archive: {}", e),
+            })?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn msg(role: MessageRole, content: &str) -> Message {
+        Message {
+            timestamp: 0,
+            ..Message::new(role, content, "glm-4.7")
+        }
+    }
+
+    #[test]
+    fn test_archive_and_fallback_recap() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let store = super::super::conversation_store::ConversationStore::new(
+            tmp.path().to_str().unwrap(),
+        );
+        let mut conv = store.create_conversation();
+        conv.title = "雨夜".to_string();
+        conv.messages = vec![
+            msg(MessageRole::System, "你是小雨"),
+            msg(MessageRole::User, "我要走了"),
+            msg(MessageRole::Assistant, "路上小心"),
+        ];
+
+        let recap = fallback_recap(&conv, &[]);
+        assert_eq!(recap, "用户：我要走了\n角色：路上小心");
+        let archive = render_archive(&conv, &recap, conv.created_at);
+        assert!(archive.starts_with("# 雨夜"));
+        assert!(archive.contains("**角色**：路上小心"));
+        assert!(!archive.contains("你是小雨"));
+
+        let archives = ArchiveStore::new(tmp.path().to_str().unwrap());
+        let path = archives.save(&conv.id, &archive).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), archive);
+        archives.delete(&conv.id).unwrap();
+        assert!(!std::path::Path::new(&path).exists());
+    }
+}
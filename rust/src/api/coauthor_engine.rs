@@ -0,0 +1,208 @@
+use super::data_models::{Message, MessageRole, MessageType};
+use super::text_utils;
+
+// ═══════════════════════════════════════════════════════════════════
+//  长文共写引擎 (Co-Author Engine)
+//  ─────────────────────────────────────────────────────────────────
+//  对话处于 CoAuthor 模式时，AI 不再是聊天对象，而是共同作者：
+//    1. 散文化提示：叙事视角、节奏、段落，而非聊天口吻
+//    2. 章节感知：从已写正文中提取章节标题，形成目录注入上下文
+//    3. 默认续写：空输入或「继续」类指令 → 紧接上文往下写
+//
+//  产出的正文以 MessageType::Document 存储，与聊天消息并存。
+// ═══════════════════════════════════════════════════════════════════
+
+/// 空输入时替换成的续写指令
+pub const CONTINUE_DIRECTIVE: &str = "继续";
+
+/// 注入上下文的上文结尾长度（字符）
+const TAIL_EXCERPT_CHARS: usize = 600;
+
+/// 目录最多列出的章节数（超出时保留最近的章节）
+const MAX_OUTLINE_CHAPTERS: usize = 30;
+
+/// 正文中识别到的章节标记
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChapterMarker {
+    pub title: String,
+    /// 所在消息在对话中的下标
+    pub message_index: usize,
+}
+
+pub struct CoAuthorEngine;
+
+impl CoAuthorEngine {
+    /// 共写模式下的输入归一化：空输入视为续写
+    pub fn resolve_input(content: &str) -> String {
+        if content.trim().is_empty() {
+            CONTINUE_DIRECTIVE.to_string()
+        } else {
+            content.to_string()
+        }
+    }
+
+    /// 判断是否为续写指令（不带新的写作要求）
+    pub fn is_continuation(content: &str) -> bool {
+        let trimmed = content
+            .trim()
+            .trim_end_matches(['。', '.', '！', '!', '~', '～']);
+        if trimmed.is_empty() {
+            return true;
+        }
+        let lower = trimmed.to_lowercase();
+        [
+            "继续", "接着写", "往下写", "继续写", "接着", "然后呢", "下一章", "continue", "go on",
+        ]
+        .iter()
+        .any(|k| lower == *k)
+    }
+
+    /// 判断一行文本是否为章节标题
+    fn parse_chapter_heading(line: &str) -> Option<String> {
+        let trimmed = line.trim().trim_start_matches('#').trim();
+        if trimmed.is_empty() || trimmed.chars().count() > 40 {
+            return None;
+        }
+
+        // 中文：第X章 / 第X回 / 第X节
+        if let Some(rest) = trimmed.strip_prefix('第') {
+            let head: String = rest.chars().take(8).collect();
+            if head.contains('章') || head.contains('回') || head.contains('节') {
+                return Some(trimmed.to_string());
+            }
+        }
+
+        // 英文：Chapter N
+        if trimmed.to_lowercase().starts_with("chapter ") {
+            return Some(trimmed.to_string());
+        }
+
+        // Markdown 标题（原行以 # 开头）
+        if line.trim_start().starts_with('#') {
+            return Some(trimmed.to_string());
+        }
+
+        None
+    }
+
+    /// 从正文消息中提取章节目录
+    pub fn extract_chapter_outline(messages: &[Message]) -> Vec<ChapterMarker> {
+        let mut outline = Vec::new();
+        for (idx, msg) in messages.iter().enumerate() {
+            if msg.message_type != MessageType::Document {
+                continue;
+            }
+            for line in msg.content.lines() {
+                if let Some(title) = Self::parse_chapter_heading(line) {
+                    outline.push(ChapterMarker {
+                        title,
+                        message_index: idx,
+                    });
+                }
+            }
+        }
+        outline
+    }
+
+    /// 最近一段正文的结尾，用于让续写无缝衔接
+    fn tail_excerpt(messages: &[Message]) -> Option<String> {
+        let last_doc = messages
+            .iter()
+            .rev()
+            .find(|m| m.role == MessageRole::Assistant && m.message_type == MessageType::Document)?;
+        Some(text_utils::tail_chars(&last_doc.content, TAIL_EXCERPT_CHARS).to_string())
+    }
+
+    /// 构建共写模式的系统提示（替代聊天模式的 say/do 风格提示与人格提示）
+    pub fn build_coauthor_prompt(messages: &[Message], user_content: &str) -> String {
+        let mut prompt = String::from(
+            "【共写模式 — 你是这部作品的共同作者，不是聊天对象】\n\
+             \n\
+             ═══ 写作准则 ═══\n\
+             - 输出成段的叙事正文，不要用聊天口吻，不要和读者对话\n\
+             - 保持已确立的叙事视角、时态、人物性格和文风\n\
+             - 场景、动作、对白、心理交织推进，详略服从节奏\n\
+             - 对白用引号，段落之间自然过渡，不写提纲或分点\n\
+             - 不要在正文前后加解释、总结或「以下是续写」之类的说明\n\
+             - 新开章节时单独一行写章节标题（如「第三章 雨夜」）\n",
+        );
+
+        let outline = Self::extract_chapter_outline(messages);
+        if !outline.is_empty() {
+            prompt.push_str("\n═══ 已完成章节 ═══\n");
+            let skip = outline.len().saturating_sub(MAX_OUTLINE_CHAPTERS);
+            for marker in outline.iter().skip(skip) {
+                prompt.push_str(&format!("  · {}\n", marker.title));
+            }
+            if let Some(current) = outline.last() {
+                prompt.push_str(&format!("当前所在章节：{}\n", current.title));
+            }
+        }
+
+        if Self::is_continuation(user_content) {
+            prompt.push_str(
+                "\n═══ 本轮任务：续写 ═══\n\
+                 紧接上文最后一句往下写，不重复、不回顾、不总结已写内容。\n",
+            );
+            if let Some(tail) = Self::tail_excerpt(messages) {
+                prompt.push_str(&format!("上文结尾：\n……{}\n", tail));
+            }
+        } else {
+            prompt.push_str(
+                "\n═══ 本轮任务 ═══\n\
+                 按作者（用户）本轮的要求写作；若要求是修改或补充，直接给出改写后的正文。\n",
+            );
+        }
+
+        prompt
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_doc(content: &str) -> Message {
+        Message {
+            timestamp: 0,
+            message_type: MessageType::Document,
+            ..Message::new(MessageRole::Assistant, content, "glm-4.7")
+        }
+    }
+
+    #[test]
+    fn test_resolve_input_empty_becomes_continue() {
+        assert_eq!(CoAuthorEngine::resolve_input("  "), CONTINUE_DIRECTIVE);
+        assert_eq!(CoAuthorEngine::resolve_input("写第二章"), "写第二章");
+    }
+
+    #[test]
+    fn test_is_continuation() {
+        assert!(CoAuthorEngine::is_continuation("继续"));
+        assert!(CoAuthorEngine::is_continuation("接着写。"));
+        assert!(CoAuthorEngine::is_continuation("Continue"));
+        assert!(!CoAuthorEngine::is_continuation("继续，但让主角受伤"));
+    }
+
+    #[test]
+    fn test_extract_chapter_outline() {
+        let messages = vec![
+            make_doc("第一章 初遇\n她推开门。"),
+            make_doc("雨还在下。"),
+            make_doc("## 第二章 雨夜\n路灯亮了。\nChapter 3: Dawn"),
+        ];
+        let outline = CoAuthorEngine::extract_chapter_outline(&messages);
+        let titles: Vec<&str> = outline.iter().map(|c| c.title.as_str()).collect();
+        assert_eq!(titles, vec!["第一章 初遇", "第二章 雨夜", "Chapter 3: Dawn"]);
+        assert_eq!(outline[1].message_index, 2);
+    }
+
+    #[test]
+    fn test_build_coauthor_prompt_continuation_includes_tail() {
+        let messages = vec![make_doc("第一章 初遇\n她推开门，屋里一片漆黑。")];
+        let prompt = CoAuthorEngine::build_coauthor_prompt(&messages, "继续");
+        assert!(prompt.contains("当前所在章节：第一章 初遇"));
+        assert!(prompt.contains("续写"));
+        assert!(prompt.contains("屋里一片漆黑"));
+    }
+}
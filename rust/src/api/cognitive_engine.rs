@@ -1,6 +1,17 @@
-use super::data_models::{Message, MessageRole};
+use std::sync::{Arc, OnceLock, RwLock};
 
-type EmotionLexiconEntry = (&'static str, usize, &'static [(&'static str, f64)]);
+use super::data_models::{
+    CognitiveInsight, DraftTone, EmotionDimension, EmotionInsight, EmpathyKind, IntentKind,
+    LanguagePatternKind, LexiconEntry, Message, MessageRole, PreflightAdvisory,
+    ReactionEvent, RelationshipInsight,
+};
+use super::language_packs::{self, LanguagePack, LANGUAGES};
+
+/// 合并自定义词条后某一语言的情感词典：(维度索引, [(关键词, 强度)])
+type MergedLexicon = Vec<(usize, Vec<(String, f64)>)>;
+
+/// 用户自定义情感词条（emotion_lexicon.json），init_app 时安装
+static CUSTOM_LEXICON: OnceLock<RwLock<Arc<Vec<LexiconEntry>>>> = OnceLock::new();
 
 // ═══════════════════════════════════════════════════════════════════
 //  认知思维引擎 (Cognitive Engine)
@@ -144,8 +155,69 @@ pub enum LanguagePattern {
     TopicAvoidance,
 }
 
+/// 话题回避信号：用户明确叫停或对某个问题连续敷衍
+#[derive(Debug, Clone, PartialEq)]
+pub struct TopicBlockSignal {
+    /// 话题描述（用户点名的话题，或被回避的那个问题）
+    pub topic: String,
+    /// 话题关键词（用于把多次回避归并到同一话题）
+    pub keywords: Vec<String>,
+    /// 用户原话
+    pub evidence: String,
+    /// 明确说了「别聊这个」之类的话（一次即生效；敷衍需多次）
+    pub explicit: bool,
+}
+
+/// 明确叫停话题的说法
+const TOPIC_BLOCK_MARKERS: &[&str] = &[
+    "别聊这个", "别聊了", "不想聊", "不想谈", "不想说", "不说这个", "别提", "不要提", "别再提",
+    "别问了", "别再问", "不要再问", "换个话题", "说点别的",
+];
+
+/// 敷衍式回应（只在上一条是提问时算回避）
+const DEFLECTION_REPLIES: &[&str] = &[
+    "嗯", "哦", "随便", "没什么", "不知道", "算了", "还好", "就那样", "...", "…", "……",
+];
+
+/// 话题尾部的语气词
+const TOPIC_TRAILING: &[&str] = &["好不好", "好吗", "行吗", "可以吗", "了吧", "了", "啦", "吧", "哈"];
+
+/// 指代词：「别提这个」没有点名具体话题
+const TOPIC_PLACEHOLDERS: &[&str] = &["这个", "那个", "这些", "那些", "这事", "那事", "它"];
+
+/// 代词/虚词：含这些字的二元组不作为话题关键词
+const TOPIC_NOISE_CHARS: &str = "你我他她它的了吗呢吧啊呀是在有就都也还很么这那个";
+
 pub struct CognitiveEngine;
 
+fn custom_lexicon_slot() -> &'static RwLock<Arc<Vec<LexiconEntry>>> {
+    CUSTOM_LEXICON.get_or_init(|| RwLock::new(Arc::new(Vec::new())))
+}
+
+/// 替换进程内的自定义情感词条（下一次分析生效）
+pub fn install_custom_lexicon(entries: Vec<LexiconEntry>) {
+    *custom_lexicon_slot().write().unwrap() = Arc::new(entries);
+}
+
+/// 当前生效的自定义情感词条
+pub fn custom_lexicon() -> Arc<Vec<LexiconEntry>> {
+    custom_lexicon_slot().read().unwrap().clone()
+}
+
+/// 情感维度在感知层得分数组中的下标
+fn dimension_index(dimension: EmotionDimension) -> usize {
+    match dimension {
+        EmotionDimension::Joy => 0,
+        EmotionDimension::Sadness => 1,
+        EmotionDimension::Anger => 2,
+        EmotionDimension::Fear => 3,
+        EmotionDimension::Surprise => 4,
+        EmotionDimension::Intimacy => 5,
+        EmotionDimension::Trust => 6,
+        EmotionDimension::Anticipation => 7,
+    }
+}
+
 impl CognitiveEngine {
     /// 主入口：对整段对话进行认知分析，生成完整的认知上下文
     pub fn analyze(messages: &[&Message]) -> CognitiveAnalysis {
@@ -168,6 +240,213 @@ impl CognitiveEngine {
         }
     }
 
+    /// 根据认知分析挑选一个即时反应（在完整回复流出之前发送）
+    /// 平淡闲聊或对方想要独处时不反应，避免每条消息都贴表情
+    pub fn choose_reaction(analysis: &CognitiveAnalysis, user_content: &str) -> Option<ReactionEvent> {
+        let reaction = |emoji: &str, label: &str| {
+            Some(ReactionEvent {
+                emoji: emoji.to_string(),
+                label: label.to_string(),
+            })
+        };
+        let emotion = &analysis.emotion;
+
+        match analysis.intent {
+            DialogueIntent::Withdrawn => None,
+            DialogueIntent::SeekingComfort | DialogueIntent::EmotionalVenting => {
+                reaction("🫂", "抱抱")
+            }
+            DialogueIntent::ExpressingAffection => reaction("❤️", "心动"),
+            DialogueIntent::ExpressingDispleasure => reaction("🥺", "委屈"),
+            DialogueIntent::TestingBoundary => reaction("🤔", "琢磨"),
+            DialogueIntent::Playful => reaction("😏", "坏笑"),
+            DialogueIntent::Reconciling => reaction("🤝", "和好"),
+            DialogueIntent::DeepSharing => reaction("💭", "认真听"),
+            DialogueIntent::SeekingResponse => reaction("👀", "在听"),
+            DialogueIntent::Farewell => {
+                if user_content.contains("晚安") {
+                    reaction("🌙", "晚安")
+                } else {
+                    reaction("👋", "再见")
+                }
+            }
+            DialogueIntent::SharingDaily => {
+                if emotion.surprise > 0.5 && emotion.arousal > 0.5 {
+                    reaction("😮", "惊讶")
+                } else if emotion.joy > 0.3 {
+                    reaction("😆", "开心")
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    /// 发送前预检：把草稿当作下一条用户消息分析，对比发送前后的关系状态
+    ///
+    /// 只给建议不拦截；local_hour 为用户本地小时（0-23），深夜发送会放大风险
+    pub fn preflight(history: &[&Message], draft: &str, local_hour: u32) -> PreflightAdvisory {
+        let draft_msg = Message {
+            id: String::new(),
+            timestamp: 0,
+            ..Message::new(MessageRole::User, draft, "")
+        };
+        let before = Self::analyze(history);
+        let mut with_draft: Vec<&Message> = history.to_vec();
+        with_draft.push(&draft_msg);
+        let after = Self::analyze(&with_draft);
+
+        let emotion = &after.emotion;
+        let patterns = &after.detected_patterns;
+        let tone = if emotion.anger >= 0.4 {
+            DraftTone::Hostile
+        } else if patterns.contains(&LanguagePattern::Sarcasm) {
+            DraftTone::Sarcastic
+        } else if emotion.fear >= 0.4 {
+            DraftTone::Anxious
+        } else if emotion.sadness >= 0.4 {
+            DraftTone::Sad
+        } else if after.intent == DialogueIntent::Playful {
+            DraftTone::Playful
+        } else if emotion.intimacy >= 0.3 || emotion.joy >= 0.4 {
+            DraftTone::Warm
+        } else {
+            DraftTone::Calm
+        };
+
+        let tension_delta = after.relationship.tension - before.relationship.tension;
+        let closeness_delta = after.relationship.closeness - before.relationship.closeness;
+        let late_night = local_hour < 6;
+
+        let mut risk: f64 = match tone {
+            DraftTone::Hostile => 0.5,
+            DraftTone::Sarcastic => 0.35,
+            DraftTone::Anxious | DraftTone::Sad => 0.15,
+            _ => 0.0,
+        };
+        risk += tension_delta.max(0.0) * 0.8 + (-closeness_delta).max(0.0) * 0.5;
+        if emotion.arousal > 0.6 {
+            risk += 0.15;
+        }
+        if patterns.contains(&LanguagePattern::Urgent) {
+            risk += 0.1;
+        }
+        if late_night && risk > 0.0 {
+            risk += 0.15;
+        }
+        let regret_risk = risk.clamp(0.0, 1.0);
+
+        let mut notes = Vec::new();
+        match tone {
+            DraftTone::Hostile => notes.push("这条消息火气不小，发出去可能伤到对方".to_string()),
+            DraftTone::Sarcastic => notes.push("语气有点阴阳怪气，对方可能会往坏处想".to_string()),
+            DraftTone::Anxious | DraftTone::Sad => {
+                notes.push("你现在情绪有点低，也许先说说自己的感受会更好".to_string())
+            }
+            _ => {}
+        }
+        if tension_delta > 0.1 {
+            notes.push("发送后关系可能变得更紧张".to_string());
+        }
+        if late_night && regret_risk > 0.0 {
+            notes.push("现在是深夜，情绪容易被放大，要不睡醒再看看？".to_string());
+        }
+
+        PreflightAdvisory {
+            tone,
+            regret_risk,
+            tension_delta,
+            closeness_delta,
+            late_night,
+            suggest_pause: regret_risk >= 0.6,
+            notes,
+        }
+    }
+
+    /// 从最新一条用户消息识别话题回避：
+    ///   - 明确叫停（「别提我前任了」「别聊这个」）：点名了话题就用点名的，
+    ///     否则取上一条 AI 消息里的问题作为话题
+    ///   - 敷衍（上一条 AI 在提问，用户只回「嗯」「随便」）
+    pub fn detect_topic_block(messages: &[&Message]) -> Option<TopicBlockSignal> {
+        let latest_idx = messages.iter().rposition(|m| m.role == MessageRole::User)?;
+        let latest = messages[latest_idx].content.trim();
+        let prev_ai = messages[..latest_idx]
+            .iter()
+            .rev()
+            .find(|m| m.role == MessageRole::Assistant)
+            .map(|m| m.content.as_str());
+        let asked = prev_ai.and_then(Self::last_question);
+
+        if let Some(marker) = TOPIC_BLOCK_MARKERS.iter().find(|m| latest.contains(**m)) {
+            let named = Self::named_topic(latest, marker);
+            let topic = named.or(asked)?;
+            return Some(TopicBlockSignal {
+                keywords: Self::topic_keywords(&topic),
+                topic,
+                evidence: latest.to_string(),
+                explicit: true,
+            });
+        }
+
+        let question = asked?;
+        if !DEFLECTION_REPLIES.contains(&latest) {
+            return None;
+        }
+        let keywords = Self::topic_keywords(&question);
+        if keywords.is_empty() {
+            return None;
+        }
+        Some(TopicBlockSignal {
+            topic: question,
+            keywords,
+            evidence: latest.to_string(),
+            explicit: false,
+        })
+    }
+
+    /// 叫停语后面点名的话题（「别提我前任了」→「我前任」）
+    fn named_topic(text: &str, marker: &str) -> Option<String> {
+        let start = text.find(marker)? + marker.len();
+        let mut topic: &str = text[start..]
+            .split(|c: char| c.is_ascii_punctuation() || "，。！？、；…~～ ".contains(c))
+            .next()
+            .unwrap_or("")
+            .trim();
+        for suffix in TOPIC_TRAILING {
+            topic = topic.strip_suffix(suffix).unwrap_or(topic);
+        }
+        let topic = topic.trim_start_matches('了').trim();
+        if topic.chars().count() < 2 || TOPIC_PLACEHOLDERS.contains(&topic) {
+            return None;
+        }
+        Some(topic.to_string())
+    }
+
+    /// AI 消息中的最后一个问句（回避的通常是这个问题）
+    fn last_question(text: &str) -> Option<String> {
+        let end = text.rfind(['？', '?'])?;
+        let head = &text[..end];
+        let start = head
+            .rfind(|c: char| "。，！？,!?\n*（）()".contains(c))
+            .map(|i| i + head[i..].chars().next().map_or(1, |c| c.len_utf8()))
+            .unwrap_or(0);
+        let question: String = head[start..].trim().chars().take(30).collect();
+        if question.chars().count() < 2 {
+            return None;
+        }
+        Some(question)
+    }
+
+    /// 话题关键词：英文词与不含代词/虚词的中文二元组
+    pub fn topic_keywords(topic: &str) -> Vec<String> {
+        super::memory_engine::MemoryEngine::extract_keywords(topic)
+            .into_iter()
+            .filter(|k| {
+                k.is_ascii()
+                    || (k.chars().count() == 2 && !k.chars().any(|c| TOPIC_NOISE_CHARS.contains(c)))
+            })
+            .collect()
+    }
 
     // ═══════════════════════════════════════════════════════════════
     //  第一层：感知层 — 多维度情感感知
@@ -183,75 +462,32 @@ impl CognitiveEngine {
             };
         }
 
-        // 扩展情感词典：每个词带有强度权重
-        let emotion_lexicon: &[EmotionLexiconEntry] = &[
-            // (情感名, 维度索引, [(关键词, 强度)])
-            ("joy", 0, &[
-                ("开心", 0.8), ("高兴", 0.8), ("快乐", 0.9), ("笑", 0.5), ("哈哈", 0.7),
-                ("嘻嘻", 0.6), ("太好了", 0.8), ("喜欢", 0.7), ("爱", 0.9), ("幸福", 0.95),
-                ("温暖", 0.6), ("感谢", 0.5), ("谢谢", 0.4), ("棒", 0.6), ("赞", 0.5),
-                ("耶", 0.7), ("嘿嘿", 0.6), ("甜", 0.7), ("哈哈哈", 0.8), ("噗", 0.5),
-                ("好耶", 0.8), ("绝了", 0.7), ("爽", 0.7), ("舒服", 0.6), ("满足", 0.7),
-                ("开心死了", 1.0), ("乐", 0.6), ("美", 0.5), ("妙", 0.5), ("嘿嘿嘿", 0.7),
-                ("好开心", 0.9), ("超开心", 1.0), ("太棒了", 0.9), ("好喜欢", 0.9),
-                ("心花怒放", 1.0), ("飘了", 0.7), ("上头", 0.6),
-            ]),
-            ("sadness", 1, &[
-                ("难过", 0.8), ("伤心", 0.9), ("痛苦", 1.0), ("哭", 0.8), ("呜呜", 0.7),
-                ("失望", 0.7), ("沮丧", 0.8), ("孤独", 0.8), ("寂寞", 0.7), ("心疼", 0.7),
-                ("遗憾", 0.6), ("可惜", 0.5), ("唉", 0.5), ("叹", 0.4), ("泪", 0.7),
-                ("委屈", 0.8), ("心酸", 0.8), ("难受", 0.8), ("不开心", 0.7), ("丧", 0.6),
-                ("emo", 0.7), ("崩溃", 1.0), ("受不了", 0.9), ("好累", 0.6), ("算了", 0.5),
-                ("无所谓了", 0.6), ("没意思", 0.5), ("心碎", 1.0), ("扎心", 0.8),
-                ("好难过", 0.9), ("想哭", 0.8), ("眼泪", 0.7), ("哭了", 0.9),
-                ("不想说话", 0.7), ("好烦", 0.6), ("活着好累", 1.0),
-            ]),
-            ("anger", 2, &[
-                ("生气", 0.8), ("愤怒", 1.0), ("气死", 0.9), ("混蛋", 0.9), ("可恶", 0.8),
-                ("滚", 1.0), ("烦死", 0.8), ("受够", 0.9), ("讨厌", 0.7), ("烦", 0.6),
-                ("恼", 0.6), ("怒", 0.8), ("闭嘴", 0.9), ("够了", 0.8), ("你行", 0.5),
-                ("随便你", 0.6), ("爱咋咋", 0.7), ("切", 0.4), ("啧", 0.4),
-                ("有病", 0.8), ("神经病", 0.9), ("你够了", 0.8), ("别烦我", 0.8),
-                ("我不想理你", 0.7), ("走开", 0.8), ("少来", 0.6), ("你烦不烦", 0.8),
-            ]),
-            ("fear", 3, &[
-                ("害怕", 0.8), ("恐惧", 1.0), ("担心", 0.6), ("紧张", 0.6), ("不安", 0.7),
-                ("慌", 0.7), ("怕", 0.6), ("焦虑", 0.8), ("忐忑", 0.7), ("心虚", 0.6),
-                ("发抖", 0.8), ("不敢", 0.6), ("完了", 0.7), ("怎么办", 0.6), ("糟了", 0.7),
-                ("慌了", 0.7), ("好怕", 0.8), ("吓死了", 0.8), ("瑟瑟发抖", 0.7),
-                ("心慌", 0.7), ("不会吧", 0.4), ("万一", 0.5),
-            ]),
-            ("surprise", 4, &[
-                ("惊讶", 0.7), ("天哪", 0.8), ("不会吧", 0.6), ("真的吗", 0.5),
-                ("居然", 0.6), ("竟然", 0.6), ("没想到", 0.6), ("啊", 0.3), ("哇", 0.5),
-                ("诶", 0.3), ("卧槽", 0.8), ("我靠", 0.7), ("天呐", 0.8), ("不是吧", 0.6),
-                ("啊？", 0.5), ("嗯？", 0.3), ("等等", 0.4), ("什么鬼", 0.6),
-                ("离谱", 0.6), ("绝了", 0.5), ("震惊", 0.8), ("我的天", 0.8),
-            ]),
-            ("intimacy", 5, &[
-                ("抱", 0.7), ("靠", 0.5), ("牵手", 0.8), ("依偎", 0.9), ("亲", 0.8),
-                ("蹭", 0.7), ("贴", 0.6), ("挽", 0.7), ("搂", 0.8), ("窝", 0.6),
-                ("枕", 0.7), ("偎", 0.8), ("想你", 0.9), ("在吗", 0.4), ("陪我", 0.7),
-                ("别走", 0.8), ("过来", 0.5), ("靠近", 0.6), ("抱抱", 0.8), ("摸摸头", 0.7),
-                ("宝", 0.6), ("亲爱的", 0.8), ("乖", 0.5), ("想见你", 0.9),
-                ("好想你", 1.0), ("不要走", 0.9), ("留下来", 0.8), ("牵", 0.6),
-                ("拉着", 0.5), ("挨着", 0.6), ("暖暖的", 0.6), ("心跳", 0.7),
-            ]),
-            ("trust", 6, &[
-                ("相信", 0.8), ("信任", 0.9), ("放心", 0.7), ("安心", 0.7), ("依赖", 0.7),
-                ("靠谱", 0.6), ("踏实", 0.6), ("陪", 0.5), ("懂", 0.5), ("理解", 0.6),
-                ("知道", 0.3), ("明白", 0.4), ("你说的对", 0.6), ("听你的", 0.7),
-                ("交给你", 0.7), ("有你在", 0.8), ("你在就好", 0.9), ("安全感", 0.9),
-                ("放心吧", 0.6), ("我信你", 0.9),
-            ]),
-            ("anticipation", 7, &[
-                ("期待", 0.8), ("盼", 0.7), ("等", 0.4), ("希望", 0.6), ("要是", 0.5),
-                ("如果能", 0.6), ("好想", 0.7), ("什么时候", 0.5), ("快点", 0.6),
-                ("等不及", 0.8), ("明天", 0.3), ("下次", 0.4), ("以后", 0.3), ("一起", 0.5),
-                ("想要", 0.6), ("能不能", 0.5), ("可以吗", 0.4), ("会不会", 0.4),
-                ("好期待", 0.9), ("迫不及待", 0.9),
-            ]),
-        ];
+        // 各语言的情感词典（见 language_packs），合并自定义词条：
+        // 同一维度的同名词以自定义强度为准（方言、圈内用语等）
+        let custom = custom_lexicon();
+        let lexicons: Vec<MergedLexicon> = LANGUAGES
+            .iter()
+            .map(|&language| {
+                let pack = language_packs::pack(language);
+                pack.emotion_lexicon
+                    .iter()
+                    .map(|&(_name, dim_idx, keywords)| {
+                        let custom_words: Vec<(String, f64)> = custom
+                            .iter()
+                            .filter(|e| dimension_index(e.dimension) == dim_idx)
+                            .map(|e| (pack.normalize(&e.word).into_owned(), e.weight))
+                            .collect();
+                        let mut merged: Vec<(String, f64)> = keywords
+                            .iter()
+                            .filter(|&&(kw, _)| !custom_words.iter().any(|(word, _)| word == kw))
+                            .map(|&(kw, intensity)| (kw.to_string(), intensity))
+                            .collect();
+                        merged.extend(custom_words);
+                        (dim_idx, merged)
+                    })
+                    .collect()
+            })
+            .collect();
 
         let decay_half_life: f64 = 3.0;
         let mut scores = [0.0f64; 8];
@@ -264,21 +500,16 @@ impl CognitiveEngine {
             let weight = (0.5_f64).powf(distance / decay_half_life);
             let role_factor = if msg.role == MessageRole::User { 1.3 } else { 0.7 };
 
-            let text = &msg.content;
+            let pack = language_packs::pack_for(&msg.content);
+            let lexicon = &lexicons[LANGUAGES.iter().position(|&l| l == pack.language).unwrap_or(0)];
+            let text = pack.normalize(&msg.content);
 
             // 否定检测：如果关键词前面有否定词，翻转情感极性
-            let negation_prefixes = ["不", "没", "别", "非", "未", "无", "莫", "勿", "才没", "又不", "并不", "才不"];
-
-            for (_name, dim_idx, keywords) in emotion_lexicon.iter() {
+            for (dim_idx, keywords) in lexicon.iter() {
                 let mut dim_score = 0.0f64;
-                for &(kw, intensity) in *keywords {
-                    if let Some(pos) = text.find(kw) {
-                        // 检查前面是否有否定词
-                        let prefix_start = pos.saturating_sub(6);
-                        let prefix = &text[prefix_start..pos];
-                        let is_negated = negation_prefixes.iter().any(|neg| prefix.ends_with(neg));
-
-                        if is_negated {
+                for (kw, intensity) in keywords {
+                    if let Some(pos) = pack.find(&text, kw) {
+                        if pack.is_negated(&text, pos) {
                             // 否定翻转：正面情感变负面，负面情感变正面
                             // "不开心" → sadness+, joy-
                             // "不难过" → joy+, sadness-
@@ -295,7 +526,7 @@ impl CognitiveEngine {
             }
 
             // 标点符号情感信号
-            let punct_signals = Self::analyze_punctuation(text);
+            let punct_signals = Self::analyze_punctuation(&text);
             scores[0] += punct_signals.joy_signal * weight * role_factor;
             scores[1] += punct_signals.sadness_signal * weight * role_factor;
             scores[2] += punct_signals.anger_signal * weight * role_factor;
@@ -388,50 +619,35 @@ impl CognitiveEngine {
         }
 
         let latest = &recent_user[0].content;
+        let pack = language_packs::pack_for(latest);
+        let scale = pack.length_scale;
+        let has = |marker: &str| pack.contains(latest, marker);
 
         // ── 否定式表达检测 ──
         // "没事" "不是" "才没有" "没什么" "不要紧" — 可能是口是心非
-        let negation_phrases = [
-            "没事", "不是", "才没有", "没什么", "不要紧", "没关系", "无所谓",
-            "不在乎", "才不是", "才不会", "我没有", "不用了", "不需要",
-            "没有啊", "不是啦", "才没", "我才不", "不用管我",
-        ];
-        let negation_count = negation_phrases.iter().filter(|p| latest.contains(*p)).count();
+        let negation_count = pack.negation_phrases.iter().filter(|p| has(p)).count();
         if negation_count >= 1 {
             patterns.push(LanguagePattern::Negation);
             // 如果否定词多且消息短，很可能是口是心非
-            if negation_count >= 2 || (latest.chars().count() <= 10 && negation_count >= 1) {
+            if negation_count >= 2 || (latest.chars().count() <= 10 * scale && negation_count >= 1) {
                 patterns.push(LanguagePattern::Contradictory);
             }
         }
 
         // ── 反讽/阴阳怪气检测 ──
-        let sarcasm_markers = [
-            ("行啊", 0.7), ("厉害了", 0.8), ("随便", 0.5), ("哦", 0.3),
-            ("呵呵", 0.9), ("好的呢", 0.7), ("是是是", 0.8), ("对对对", 0.7),
-            ("你说的都对", 0.9), ("行吧行吧", 0.7), ("嗯嗯嗯", 0.4),
-            ("好好好", 0.3), ("你开心就好", 0.8), ("随你", 0.6),
-            ("爱咋咋地", 0.8), ("你厉害", 0.7), ("了不起", 0.6),
-            ("真棒啊", 0.5), // 需要结合语境判断
-        ];
-        let sarcasm_score: f64 = sarcasm_markers.iter()
-            .filter(|(marker, _)| latest.contains(marker))
+        let sarcasm_score: f64 = pack.sarcasm_markers.iter()
+            .filter(|(marker, _)| has(marker))
             .map(|(_, weight)| weight)
             .sum();
 
         // 短消息 + 反讽标记 = 高概率反讽
-        let is_short = latest.chars().count() <= 15;
+        let is_short = latest.chars().count() <= 15 * scale;
         if sarcasm_score >= 0.6 || (sarcasm_score >= 0.3 && is_short) {
             patterns.push(LanguagePattern::Sarcasm);
         }
 
         // ── 欲言又止检测 ──
-        let hesitation_markers = [
-            "我...", "算了", "没什么", "不说了", "还是算了", "其实...",
-            "我想说...", "就是...", "那个...", "嗯...", "唉算了",
-            "不说了不说了", "没事没事", "当我没说",
-        ];
-        if hesitation_markers.iter().any(|m| latest.contains(m)) {
+        if pack.hesitation_markers.iter().any(|m| has(m)) {
             patterns.push(LanguagePattern::Hesitation);
         }
         // 消息以省略号结尾也是欲言又止
@@ -472,57 +688,41 @@ impl CognitiveEngine {
         let punct_count = latest.chars().filter(|c| {
             matches!(c, '！' | '!' | '？' | '?' | '。' | '，' | ',' | '.')
         }).count();
-        if char_count > 0 && char_count <= 20 && punct_count as f64 / char_count as f64 > 0.2 {
+        if char_count > 0 && char_count <= 20 * scale && punct_count as f64 / char_count as f64 > 0.2 {
             patterns.push(LanguagePattern::Urgent);
         }
 
         // ── 语气拖沓检测 ──
         let ellipsis_count = latest.matches("...").count() + latest.matches("…").count();
         let tilde_count = latest.chars().filter(|&c| c == '～' || c == '~').count();
-        if ellipsis_count >= 2 || (tilde_count >= 2 && char_count > 15) {
+        if ellipsis_count >= 2 || (tilde_count >= 2 && char_count > 15 * scale) {
             patterns.push(LanguagePattern::Dragging);
         }
 
         // ── 试探性语言检测 ──
-        let probing_markers = [
-            "你觉得呢", "如果", "假如", "要是", "会不会", "你说",
-            "你想不想", "你愿意吗", "可以吗", "好不好", "行不行",
-            "你介意吗", "你在意吗", "你会怎么", "你喜欢吗",
-        ];
-        if probing_markers.iter().any(|m| latest.contains(m)) {
+        if pack.probing_markers.iter().any(|m| has(m)) {
             patterns.push(LanguagePattern::Probing);
         }
 
         // ── 撒娇语气检测 ──
-        let coquettish_markers = [
-            "嘛", "啦", "呀", "哼", "人家", "讨厌", "不嘛", "好不好嘛",
-            "你都不", "都不理我", "哼哼", "呜", "嘤嘤", "QAQ",
-        ];
-        let coquettish_count = coquettish_markers.iter().filter(|m| latest.contains(*m)).count();
+        let coquettish_count = pack.coquettish_markers.iter().filter(|m| has(m)).count();
         if coquettish_count >= 2 || (tilde_count >= 1 && coquettish_count >= 1) {
             patterns.push(LanguagePattern::Coquettish);
         }
 
         // ── 防御姿态检测 ──
-        let defensive_markers = [
-            "关你什么事", "我自己可以", "不用你管", "你管得着吗",
-            "跟你没关系", "别管我", "我的事", "你别管",
-            "不需要你", "少管闲事", "我又没", "我哪有",
-        ];
-        if defensive_markers.iter().any(|m| latest.contains(m)) {
+        if pack.defensive_markers.iter().any(|m| has(m)) {
             patterns.push(LanguagePattern::Defensive);
         }
 
         // ── 情绪压抑检测 ──
         // 表面平静但有微妙的负面信号
-        let suppression_signals = [
-            "嗯", "哦", "好", "知道了", "行", "好吧", "嗯嗯",
-        ];
-        let is_flat_response = suppression_signals.iter().any(|s| latest.trim() == *s);
+        let flat = pack.normalize(latest.trim().trim_end_matches(['.', '。']));
+        let is_flat_response = pack.flat_replies.iter().any(|s| flat == *s);
         if is_flat_response && recent_user.len() >= 2 {
             // 之前的消息更长/更有情绪，现在突然变短 → 可能在压抑
             let prev_len = recent_user[1].content.chars().count();
-            if prev_len > 10 {
+            if prev_len > 10 * scale {
                 patterns.push(LanguagePattern::Suppressed);
             }
         }
@@ -534,8 +734,7 @@ impl CognitiveEngine {
             let similarity = Self::text_similarity(latest, prev);
             if similarity < 0.05 && latest.chars().count() > 5 && prev.chars().count() > 5 {
                 // 检查是否有回避信号词
-                let avoidance_words = ["不说这个了", "换个话题", "别提了", "不想聊", "说点别的"];
-                if avoidance_words.iter().any(|w| latest.contains(w)) || similarity < 0.02 {
+                if pack.avoidance_words.iter().any(|w| has(w)) || similarity < 0.02 {
                     patterns.push(LanguagePattern::TopicAvoidance);
                 }
             }
@@ -587,6 +786,8 @@ impl CognitiveEngine {
         }
 
         let latest = &recent_user[0].content;
+        let pack = language_packs::pack_for(latest);
+        let has = |word: &str| pack.contains(latest, word);
 
         // ── 基于语言模式的意图推断 ──
 
@@ -623,20 +824,17 @@ impl CognitiveEngine {
         // ── 基于关键词的意图推断 ──
 
         // 告别信号
-        let farewell_words = ["晚安", "拜拜", "再见", "走了", "睡了", "下次见", "明天见", "88", "886"];
-        if farewell_words.iter().any(|w| latest.contains(w)) {
+        if pack.farewell_words.iter().any(|w| has(w)) {
             return DialogueIntent::Farewell;
         }
 
         // 道歉/和解信号
-        let reconcile_words = ["对不起", "抱歉", "我错了", "是我不好", "原谅我", "别生气了", "我不该"];
-        if reconcile_words.iter().any(|w| latest.contains(w)) {
+        if pack.reconcile_words.iter().any(|w| has(w)) {
             return DialogueIntent::Reconciling;
         }
 
         // 玩闹信号
-        let playful_words = ["哈哈哈", "笑死", "逗你的", "开玩笑", "骗你的", "嘿嘿", "坏蛋", "讨厌啦"];
-        if playful_words.iter().any(|w| latest.contains(w)) && emotion.anger < 0.3 {
+        if pack.playful_words.iter().any(|w| has(w)) && emotion.anger < 0.3 {
             return DialogueIntent::Playful;
         }
 
@@ -663,13 +861,13 @@ impl CognitiveEngine {
         }
 
         // 冷淡信号（消息很短 + 低唤醒 + 低效价）
-        let is_very_short = latest.chars().count() <= 5;
+        let is_very_short = latest.chars().count() <= 5 * pack.length_scale;
         if is_very_short && emotion.arousal < 0.2 && emotion.valence < 0.1 {
             return DialogueIntent::Withdrawn;
         }
 
         // 消息较长 + 情感丰富 → 深度交流
-        if latest.chars().count() > 50 && emotion.arousal > 0.3 {
+        if latest.chars().count() > 50 * pack.length_scale && emotion.arousal > 0.3 {
             return DialogueIntent::DeepSharing;
         }
 
@@ -701,48 +899,23 @@ impl CognitiveEngine {
 
         // ── 亲密度计算 ──
         // 基于：亲密词汇频率 + 消息长度互动 + 情感正面度
-        let intimacy_words = [
-            "宝", "亲爱的", "乖", "想你", "抱", "亲", "蹭", "喜欢你",
-            "爱你", "心跳", "脸红", "害羞", "暖", "甜",
-        ];
-        let mut intimacy_hits = 0u32;
-        for msg in non_system.iter().rev().take(10) {
-            for word in &intimacy_words {
-                if msg.content.contains(word) {
-                    intimacy_hits += 1;
-                }
-            }
-        }
+        let hits = |msg: &Message, words: fn(&LanguagePack) -> &'static [&'static str]| {
+            let pack = language_packs::pack_for(&msg.content);
+            words(pack).iter().filter(|w| pack.contains(&msg.content, w)).count() as u32
+        };
+        let intimacy_words = |pack: &LanguagePack| pack.intimacy_words;
+        let intimacy_hits: u32 = non_system.iter().rev().take(10).map(|m| hits(m, intimacy_words)).sum();
         let closeness = (0.3 + intimacy_hits as f64 * 0.07 + emotion.intimacy * 0.3).min(1.0);
 
         // ── 信任度计算 ──
         // 基于：对话轮次 + 信任词汇 + 自我暴露程度
-        let trust_words = ["相信", "信任", "放心", "懂", "理解", "安心", "交给你", "听你的"];
-        let mut trust_hits = 0u32;
-        for msg in non_system.iter().rev().take(10) {
-            for word in &trust_words {
-                if msg.content.contains(word) {
-                    trust_hits += 1;
-                }
-            }
-        }
+        let trust_hits: u32 = non_system.iter().rev().take(10).map(|m| hits(m, |p| p.trust_words)).sum();
         // 对话越长，基础信任越高
         let conversation_length_factor = (non_system.len() as f64 / 20.0).min(0.3);
         let trust_level = (0.2 + trust_hits as f64 * 0.08 + conversation_length_factor + emotion.trust * 0.2).min(1.0);
 
         // ── 冲突张力计算 ──
-        let conflict_words = [
-            "生气", "烦", "讨厌", "滚", "够了", "别说了", "不想理你",
-            "随便", "呵呵", "哦", "行吧",
-        ];
-        let mut conflict_hits = 0u32;
-        for msg in non_system.iter().rev().take(6) {
-            for word in &conflict_words {
-                if msg.content.contains(word) {
-                    conflict_hits += 1;
-                }
-            }
-        }
+        let conflict_hits: u32 = non_system.iter().rev().take(6).map(|m| hits(m, |p| p.conflict_words)).sum();
         let tension = (conflict_hits as f64 * 0.12 + emotion.anger * 0.3).min(1.0);
 
         // ── 主导权分析 ──
@@ -776,10 +949,10 @@ impl CognitiveEngine {
         let mid = non_system.len() / 2;
         if mid > 0 {
             let early_positive: f64 = non_system[..mid].iter()
-                .map(|m| intimacy_words.iter().filter(|w| m.content.contains(*w)).count() as f64)
+                .map(|m| hits(m, intimacy_words) as f64)
                 .sum();
             let late_positive: f64 = non_system[mid..].iter()
-                .map(|m| intimacy_words.iter().filter(|w| m.content.contains(*w)).count() as f64)
+                .map(|m| hits(m, intimacy_words) as f64)
                 .sum();
             let early_avg = early_positive / mid as f64;
             let late_avg = late_positive / (non_system.len() - mid) as f64;
@@ -1145,19 +1318,13 @@ impl CognitiveEngine {
     }
 
     fn has_negative_signal(text: &str) -> bool {
-        let negative_words = [
-            "难过", "伤心", "生气", "烦", "累", "算了", "唉", "哭",
-            "不开心", "讨厌", "滚", "够了", "无聊", "没意思", "emo",
-        ];
-        negative_words.iter().any(|w| text.contains(w))
+        let pack = language_packs::pack_for(text);
+        pack.negative_signals.iter().any(|w| pack.contains(text, w))
     }
 
     fn has_positive_signal(text: &str) -> bool {
-        let positive_words = [
-            "开心", "高兴", "哈哈", "喜欢", "爱", "棒", "好", "嘿嘿",
-            "耶", "甜", "暖", "幸福", "谢谢",
-        ];
-        positive_words.iter().any(|w| text.contains(w))
+        let pack = language_packs::pack_for(text);
+        pack.positive_signals.iter().any(|w| pack.contains(text, w))
     }
 }
 
@@ -1171,23 +1338,201 @@ struct PunctuationSignals {
 //  单元测试
 // ═══════════════════════════════════════════════════════════════
 
+// ── 对外镜像（data_models 中的稳定版本） ──
+
+impl From<&DialogueIntent> for IntentKind {
+    fn from(value: &DialogueIntent) -> Self {
+        match value {
+            DialogueIntent::SeekingComfort => IntentKind::SeekingComfort,
+            DialogueIntent::ExpressingAffection => IntentKind::ExpressingAffection,
+            DialogueIntent::ExpressingDispleasure => IntentKind::ExpressingDispleasure,
+            DialogueIntent::TestingBoundary => IntentKind::TestingBoundary,
+            DialogueIntent::SharingDaily => IntentKind::SharingDaily,
+            DialogueIntent::SeekingResponse => IntentKind::SeekingResponse,
+            DialogueIntent::EmotionalVenting => IntentKind::EmotionalVenting,
+            DialogueIntent::Playful => IntentKind::Playful,
+            DialogueIntent::Reconciling => IntentKind::Reconciling,
+            DialogueIntent::Farewell => IntentKind::Farewell,
+            DialogueIntent::Withdrawn => IntentKind::Withdrawn,
+            DialogueIntent::DeepSharing => IntentKind::DeepSharing,
+        }
+    }
+}
+
+impl From<&EmpathyStrategy> for EmpathyKind {
+    fn from(value: &EmpathyStrategy) -> Self {
+        match value {
+            EmpathyStrategy::Mirror => EmpathyKind::Mirror,
+            EmpathyStrategy::Accompany => EmpathyKind::Accompany,
+            EmpathyStrategy::Distract => EmpathyKind::Distract,
+            EmpathyStrategy::Responsive => EmpathyKind::Responsive,
+            EmpathyStrategy::PlayfulCounter => EmpathyKind::PlayfulCounter,
+            EmpathyStrategy::GentleFirm => EmpathyKind::GentleFirm,
+            EmpathyStrategy::ProactiveCare => EmpathyKind::ProactiveCare,
+            EmpathyStrategy::NaturalFlow => EmpathyKind::NaturalFlow,
+            EmpathyStrategy::GiveSpace => EmpathyKind::GiveSpace,
+            EmpathyStrategy::Escalate => EmpathyKind::Escalate,
+        }
+    }
+}
+
+impl From<&LanguagePattern> for LanguagePatternKind {
+    fn from(value: &LanguagePattern) -> Self {
+        match value {
+            LanguagePattern::Negation => LanguagePatternKind::Negation,
+            LanguagePattern::Sarcasm => LanguagePatternKind::Sarcasm,
+            LanguagePattern::Hesitation => LanguagePatternKind::Hesitation,
+            LanguagePattern::Repetition => LanguagePatternKind::Repetition,
+            LanguagePattern::Urgent => LanguagePatternKind::Urgent,
+            LanguagePattern::Dragging => LanguagePatternKind::Dragging,
+            LanguagePattern::Contradictory => LanguagePatternKind::Contradictory,
+            LanguagePattern::Probing => LanguagePatternKind::Probing,
+            LanguagePattern::Coquettish => LanguagePatternKind::Coquettish,
+            LanguagePattern::Defensive => LanguagePatternKind::Defensive,
+            LanguagePattern::Suppressed => LanguagePatternKind::Suppressed,
+            LanguagePattern::TopicAvoidance => LanguagePatternKind::TopicAvoidance,
+        }
+    }
+}
+
+impl From<&CognitiveAnalysis> for CognitiveInsight {
+    fn from(analysis: &CognitiveAnalysis) -> Self {
+        let e = &analysis.emotion;
+        let r = &analysis.relationship;
+        Self {
+            emotion: EmotionInsight {
+                joy: e.joy,
+                sadness: e.sadness,
+                anger: e.anger,
+                fear: e.fear,
+                surprise: e.surprise,
+                intimacy: e.intimacy,
+                trust: e.trust,
+                anticipation: e.anticipation,
+                valence: e.valence,
+                arousal: e.arousal,
+            },
+            intent: (&analysis.intent).into(),
+            relationship: RelationshipInsight {
+                closeness: r.closeness,
+                trust_level: r.trust_level,
+                tension: r.tension,
+                power_balance: r.power_balance,
+                trend: r.trend,
+            },
+            empathy_strategy: (&analysis.empathy_strategy).into(),
+            detected_patterns: analysis.detected_patterns.iter().map(Into::into).collect(),
+            cognitive_prompt: analysis.cognitive_prompt.clone(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use super::super::data_models::MessageType;
 
     fn make_msg(role: MessageRole, content: &str) -> Message {
         Message {
             id: String::new(),
-            role,
-            content: content.to_string(),
-            thinking_content: None,
-            model: "test".to_string(),
             timestamp: 0,
-            message_type: MessageType::Say,
+            ..Message::new(role, content, "test")
         }
     }
 
+    #[test]
+    fn test_custom_lexicon_entries_are_scored() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let manager = crate::api::config_manager::ConfigManager::new(tmp.path().to_str().unwrap());
+        std::fs::write(
+            tmp.path().join("emotion_lexicon.json"),
+            r#"[{"word": "巴适", "dimension": "joy", "weight": 0.9},
+                {"word": "", "dimension": "anger", "weight": 0.5},
+                {"word": "恼火", "dimension": "anger", "weight": 3.0}]"#,
+        )
+        .unwrap();
+        let entries = manager.load_emotion_lexicon();
+        assert_eq!(entries.len(), 1);
+
+        let msgs = [make_msg(MessageRole::User, "今天这顿火锅巴适得板")];
+        let refs: Vec<&Message> = msgs.iter().collect();
+        assert_eq!(CognitiveEngine::perceive_emotion(&refs).joy, 0.0);
+
+        install_custom_lexicon(entries);
+        let emotion = CognitiveEngine::perceive_emotion(&refs);
+        install_custom_lexicon(Vec::new());
+        assert!(emotion.joy > 0.3, "joy = {}", emotion.joy);
+        assert!(emotion.valence > 0.0);
+    }
+
+    #[test]
+    fn test_english_and_japanese_analysis() {
+        let msgs = [make_msg(MessageRole::User, "I'm so sad and lonely tonight... I can't stop crying")];
+        let refs: Vec<&Message> = msgs.iter().collect();
+        let analysis = CognitiveEngine::analyze(&refs);
+        assert!(analysis.emotion.sadness > 0.5, "sadness = {}", analysis.emotion.sadness);
+        assert!(analysis.emotion.valence < 0.0);
+        assert!(matches!(
+            analysis.intent,
+            DialogueIntent::SeekingComfort | DialogueIntent::EmotionalVenting
+        ));
+
+        let msgs = [make_msg(MessageRole::User, "I'm not happy with you")];
+        let refs: Vec<&Message> = msgs.iter().collect();
+        assert!(CognitiveEngine::perceive_emotion(&refs).joy < 0.01);
+
+        let msgs = [make_msg(MessageRole::User, "Sorry, I was wrong. Please don't be mad")];
+        let refs: Vec<&Message> = msgs.iter().collect();
+        assert_eq!(CognitiveEngine::analyze(&refs).intent, DialogueIntent::Reconciling);
+
+        let msgs = [make_msg(MessageRole::User, "今日はありがとう、おやすみ")];
+        let refs: Vec<&Message> = msgs.iter().collect();
+        let analysis = CognitiveEngine::analyze(&refs);
+        assert_eq!(analysis.intent, DialogueIntent::Farewell);
+        assert!(analysis.emotion.joy > 0.0);
+    }
+
+    #[test]
+    fn test_choose_reaction_follows_intent() {
+        let msgs = [make_msg(MessageRole::User, "好难过...想哭")];
+        let refs: Vec<&Message> = msgs.iter().collect();
+        let mut analysis = CognitiveEngine::analyze(&refs);
+        analysis.intent = DialogueIntent::SeekingComfort;
+        let reaction = CognitiveEngine::choose_reaction(&analysis, "好难过...想哭").unwrap();
+        assert_eq!(reaction.emoji, "🫂");
+
+        let mut withdrawn = analysis.clone();
+        withdrawn.intent = DialogueIntent::Withdrawn;
+        assert!(CognitiveEngine::choose_reaction(&withdrawn, "嗯").is_none());
+
+        let mut farewell = analysis;
+        farewell.intent = DialogueIntent::Farewell;
+        let reaction = CognitiveEngine::choose_reaction(&farewell, "困了，晚安").unwrap();
+        assert_eq!(reaction.label, "晚安");
+    }
+
+    #[test]
+    fn test_cognitive_insight_mirrors_analysis() {
+        let msgs = [
+            make_msg(MessageRole::User, "没事，随便你"),
+            make_msg(MessageRole::Assistant, "你是不是生气了？"),
+            make_msg(MessageRole::User, "没事，真的没事"),
+        ];
+        let refs: Vec<&Message> = msgs.iter().collect();
+        let analysis = CognitiveEngine::analyze(&refs);
+        let insight = CognitiveInsight::from(&analysis);
+        assert_eq!(insight.intent, IntentKind::from(&analysis.intent));
+        assert_eq!(insight.empathy_strategy, EmpathyKind::from(&analysis.empathy_strategy));
+        assert_eq!(insight.detected_patterns.len(), analysis.detected_patterns.len());
+        assert_eq!(insight.emotion.valence, analysis.emotion.valence);
+        assert_eq!(insight.relationship.tension, analysis.relationship.tension);
+
+        // 镜像可序列化，供 Flutter / 外部消费
+        let json = serde_json::to_string(&insight).unwrap();
+        let decoded: CognitiveInsight = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded.detected_patterns, insight.detected_patterns);
+        assert_eq!(decoded.cognitive_prompt, insight.cognitive_prompt);
+    }
+
     #[test]
     fn test_emotion_perception_joy() {
         let msgs = [make_msg(MessageRole::User, "哈哈哈太开心了！")];
@@ -1326,4 +1671,46 @@ mod tests {
         let sim2 = CognitiveEngine::text_similarity("你好世界", "再见朋友");
         assert!(sim2 < 0.3, "different texts should have low similarity");
     }
+
+    #[test]
+    fn test_preflight_flags_late_night_rage_text() {
+        let msgs = [make_msg(MessageRole::User, "今天加班好累"),
+            make_msg(MessageRole::Assistant, "辛苦啦，早点休息")];
+        let refs: Vec<&Message> = msgs.iter().collect();
+
+        let rage = CognitiveEngine::preflight(&refs, "你烦死了！！滚！别再来找我！！", 3);
+        assert_eq!(rage.tone, DraftTone::Hostile);
+        assert!(rage.late_night);
+        assert!(rage.suggest_pause, "risk was {}", rage.regret_risk);
+        assert!(!rage.notes.is_empty());
+
+        let warm = CognitiveEngine::preflight(&refs, "谢谢你呀，晚安～", 22);
+        assert!(!warm.suggest_pause);
+        assert!(warm.regret_risk < rage.regret_risk);
+    }
+
+    #[test]
+    fn test_detect_topic_block() {
+        let ask = make_msg(MessageRole::Assistant, "*歪头* 说起来，你和前任后来还有联系吗？");
+
+        let named = [ask.clone(), make_msg(MessageRole::User, "别提我前任了好吗")];
+        let refs: Vec<&Message> = named.iter().collect();
+        let signal = CognitiveEngine::detect_topic_block(&refs).unwrap();
+        assert!(signal.explicit);
+        assert_eq!(signal.topic, "我前任");
+
+        let unnamed = [ask.clone(), make_msg(MessageRole::User, "别聊这个")];
+        let refs: Vec<&Message> = unnamed.iter().collect();
+        let signal = CognitiveEngine::detect_topic_block(&refs).unwrap();
+        assert_eq!(signal.topic, "你和前任后来还有联系吗");
+        assert!(signal.keywords.contains(&"前任".to_string()));
+
+        let deflect = [ask.clone(), make_msg(MessageRole::User, "随便")];
+        let refs: Vec<&Message> = deflect.iter().collect();
+        assert!(!CognitiveEngine::detect_topic_block(&refs).unwrap().explicit);
+
+        let answered = [ask, make_msg(MessageRole::User, "偶尔会聊两句")];
+        let refs: Vec<&Message> = answered.iter().collect();
+        assert!(CognitiveEngine::detect_topic_block(&refs).is_none());
+    }
 }
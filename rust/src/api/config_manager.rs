@@ -1,23 +1,84 @@
+use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
+use std::sync::{Arc, OnceLock, RwLock};
 
 use flutter_rust_bridge::frb;
+use serde::{Deserialize, Serialize};
+use tokio::sync::broadcast;
 
-use super::data_models::AppSettings;
+use super::chat_provider;
+use super::data_models::{
+    AppSettings, LexiconEntry, ProviderKind, ProxySettings, RetentionPolicy, RetryPolicy,
+    SafetyPolicy, SummarizationConfig, SyncBackendKind, SyncSettings,
+};
 use super::error_handler::ChatError;
 
+/// 设置变更广播容量：订阅方每轮只取最新一份，积压过多直接丢弃旧的
+const CHANGE_CHANNEL_CAPACITY: usize = 8;
+/// 模型声明文件（与内置声明合并，同 ID 覆盖）
+const MODELS_FILE: &str = "models.json";
+/// 自定义情感词条文件（与内置情感词典合并）
+const EMOTION_LEXICON_FILE: &str = "emotion_lexicon.json";
+/// 自定义停用词目录：每种语言一个 {语言代码}.txt（与内置停用词合并）
+const STOP_WORDS_DIR: &str = "stop_words";
+/// 内容安全过滤设置文件
+const SAFETY_POLICY_FILE: &str = "safety.json";
+/// 数据保留策略文件
+const RETENTION_FILE: &str = "retention.json";
+/// 云同步设置文件
+const SYNC_FILE: &str = "sync.json";
+/// 各对话的记忆摘要节奏（对话ID → 设置；未设置的对话用默认值）
+const SUMMARIZATION_FILE: &str = "summarization.json";
+/// 摘要间隔上限（轮）
+const MAX_SUMMARIZE_INTERVAL: u32 = 100;
+/// 摘要窗口范围（条）
+const MIN_SUMMARY_WINDOW: u32 = 2;
+const MAX_SUMMARY_WINDOW: u32 = 200;
+/// 未声明模型的默认输出上限
+const DEFAULT_MAX_OUTPUT_TOKENS: u32 = 16384;
+/// 默认首个数据块等待时间
+const DEFAULT_FIRST_CHUNK_TIMEOUT_SECS: u64 = 180;
+/// 默认数据块间隔
+const DEFAULT_SUBSEQUENT_CHUNK_TIMEOUT_SECS: u64 = 90;
+/// 未声明模型的同时请求上限
+const DEFAULT_MAX_CONCURRENT_REQUESTS: u32 = 3;
+/// 未声明模型的每秒请求上限
+const DEFAULT_REQUESTS_PER_SECOND: f64 = 2.0;
+/// 重试次数上限（避免配置错误时无休止重试）
+const MAX_RETRIES_LIMIT: u32 = 10;
+
+static MODEL_REGISTRY: OnceLock<RwLock<Arc<ModelRegistry>>> = OnceLock::new();
+/// 当前生效的代理（所有 HTTP 客户端构建时读取）
+static ACTIVE_PROXY: OnceLock<RwLock<Option<ProxySettings>>> = OnceLock::new();
+/// 当前生效的重试策略（RetryHandler::with_policy 读取）
+static ACTIVE_RETRY_POLICY: OnceLock<RwLock<RetryPolicy>> = OnceLock::new();
+/// 始终直连的本机地址（本地推理服务不应绕道代理）
+const LOOPBACK_HOSTS: [&str; 3] = ["localhost", "127.0.0.1", "::1"];
+/// 支持的代理协议
+const PROXY_SCHEMES: [&str; 4] = ["http://", "https://", "socks5://", "socks5h://"];
+
 #[frb(opaque)]
 pub struct ConfigManager {
     config_path: String,
+    /// 设置变更广播：save_settings 成功后推送新设置
+    changes: broadcast::Sender<AppSettings>,
 }
 
 impl ConfigManager {
     pub fn new(config_path: &str) -> Self {
+        let (changes, _) = broadcast::channel(CHANGE_CHANNEL_CAPACITY);
         Self {
             config_path: config_path.to_string(),
+            changes,
         }
     }
 
+    /// 订阅设置变更（热更新：已创建的 ChatEngine 在下一轮采用新设置）
+    pub fn subscribe(&self) -> broadcast::Receiver<AppSettings> {
+        self.changes.subscribe()
+    }
+
     /// 加载设置。如果文件不存在或无法解析，返回默认设置。
     pub fn load_settings(&self) -> AppSettings {
         let file_path = Path::new(&self.config_path).join("settings.json");
@@ -30,6 +91,7 @@ impl ConfigManager {
     }
 
     /// 保存设置到 JSON 文件。如果目录不存在则自动创建。
+    /// 写入成功后向订阅者广播新设置。
     pub fn save_settings(&self, settings: &AppSettings) -> Result<(), ChatError> {
         let dir = Path::new(&self.config_path);
         if !dir.exists() {
@@ -47,15 +109,644 @@ impl ConfigManager {
             message: format!("Failed to write settings file: {}", e),
         })?;
 
+        install_proxy(settings.proxy.clone());
+        install_retry_policy(settings.retry.clone());
+        // 没有订阅者时发送失败，属正常情况
+        let _ = self.changes.send(settings.clone());
+
         Ok(())
     }
+
+    /// 角色音色表（角色 → 音色）：键为群聊角色ID或单聊的对话ID。
+    /// 文件不存在或无法解析时为空（全部使用默认音色）
+    pub fn load_voices(&self) -> HashMap<String, String> {
+        let file_path = Path::new(&self.config_path).join("voices.json");
+        fs::read_to_string(file_path)
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    /// 设置角色音色；voice 为 None 或空时恢复默认音色
+    pub fn set_character_voice(
+        &self,
+        character_key: &str,
+        voice: Option<String>,
+    ) -> Result<(), ChatError> {
+        let mut voices = self.load_voices();
+        match voice.map(|v| v.trim().to_string()).filter(|v| !v.is_empty()) {
+            Some(voice) => voices.insert(character_key.to_string(), voice),
+            None => voices.remove(character_key),
+        };
+
+        let dir = Path::new(&self.config_path);
+        if !dir.exists() {
+            fs::create_dir_all(dir).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to create config directory: {}", e),
+            })?;
+        }
+        let json = serde_json::to_string_pretty(&voices).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to serialize voices: {}", e),
+        })?;
+        fs::write(dir.join("voices.json"), json).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to write voices file: {}", e),
+        })
+    }
+
+    fn load_summarization_configs(&self) -> HashMap<String, SummarizationConfig> {
+        let file_path = Path::new(&self.config_path).join(SUMMARIZATION_FILE);
+        fs::read_to_string(file_path)
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    /// 对话的记忆摘要节奏；未单独设置时为默认值（每 10 轮、最近 20 条消息）
+    pub fn load_summarization_config(&self, conversation_id: &str) -> SummarizationConfig {
+        self.load_summarization_configs()
+            .remove(conversation_id)
+            .unwrap_or_default()
+    }
+
+    /// 设置对话的记忆摘要节奏；config 为 None 时恢复默认。超出范围时不落盘
+    pub fn set_summarization_config(
+        &self,
+        conversation_id: &str,
+        config: Option<SummarizationConfig>,
+    ) -> Result<(), ChatError> {
+        let mut configs = self.load_summarization_configs();
+        match config {
+            Some(config) => {
+                if config.interval_turns == 0 || config.interval_turns > MAX_SUMMARIZE_INTERVAL {
+                    return Err(ChatError::ValidationError {
+                        message: format!(
+                            "Summarization interval must be 1-{} turns",
+                            MAX_SUMMARIZE_INTERVAL
+                        ),
+                    });
+                }
+                if !(MIN_SUMMARY_WINDOW..=MAX_SUMMARY_WINDOW).contains(&config.window_messages) {
+                    return Err(ChatError::ValidationError {
+                        message: format!(
+                            "Summarization window must be {}-{} messages",
+                            MIN_SUMMARY_WINDOW, MAX_SUMMARY_WINDOW
+                        ),
+                    });
+                }
+                configs.insert(conversation_id.to_string(), config);
+            }
+            None => {
+                if configs.remove(conversation_id).is_none() {
+                    return Ok(());
+                }
+            }
+        }
+
+        let dir = Path::new(&self.config_path);
+        if !dir.exists() {
+            fs::create_dir_all(dir).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to create config directory: {}", e),
+            })?;
+        }
+        let json = serde_json::to_string_pretty(&configs).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to serialize summarization configs: {}", e),
+        })?;
+        fs::write(dir.join(SUMMARIZATION_FILE), json).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to write summarization file: {}", e),
+        })
+    }
+
+    /// 切换对话提供方并保存。非智谱提供方会先校验配置是否足以构建请求，
+    /// 校验失败时不落盘。
+    pub fn set_provider(
+        &self,
+        provider: ProviderKind,
+        base_url: Option<String>,
+        model: Option<String>,
+        api_key: Option<String>,
+    ) -> Result<(), ChatError> {
+        let settings = AppSettings {
+            provider,
+            provider_base_url: base_url,
+            provider_model: model,
+            provider_api_key: api_key,
+            ..self.load_settings()
+        };
+
+        if !matches!(provider, ProviderKind::Zhipu | ProviderKind::LocalEcho) {
+            chat_provider::from_settings(&settings)
+                .map_err(|message| ChatError::ValidationError { message })?;
+        }
+
+        self.save_settings(&settings)
+    }
+
+    /// 设置网络代理并保存；地址或认证无效时不落盘。None 表示不使用代理配置
+    pub fn set_proxy(&self, proxy: Option<ProxySettings>) -> Result<(), ChatError> {
+        if let Some(proxy) = &proxy {
+            build_proxy(proxy)?;
+        }
+        let settings = AppSettings {
+            proxy,
+            ..self.load_settings()
+        };
+        self.save_settings(&settings)
+    }
+
+    /// 设置请求重试策略并保存；参数不合理时不落盘
+    pub fn set_retry_policy(&self, policy: RetryPolicy) -> Result<(), ChatError> {
+        if policy.max_retries > MAX_RETRIES_LIMIT {
+            return Err(ChatError::ValidationError {
+                message: format!("max_retries must be at most {}", MAX_RETRIES_LIMIT),
+            });
+        }
+        if policy.initial_delay_ms == 0 || policy.max_delay_ms < policy.initial_delay_ms {
+            return Err(ChatError::ValidationError {
+                message: "Retry delays must satisfy 0 < initial_delay_ms <= max_delay_ms".to_string(),
+            });
+        }
+        let settings = AppSettings {
+            retry: policy,
+            ..self.load_settings()
+        };
+        self.save_settings(&settings)
+    }
+
+    /// 开关现实时间感知并保存（下一轮对话生效）
+    pub fn set_time_awareness(&self, enabled: bool) -> Result<(), ChatError> {
+        let settings = AppSettings {
+            time_awareness: enabled,
+            ..self.load_settings()
+        };
+        self.save_settings(&settings)
+    }
+
+    /// 开关快速草稿并保存（下一轮对话生效）
+    pub fn set_fast_draft(&self, enabled: bool) -> Result<(), ChatError> {
+        let settings = AppSettings {
+            fast_draft: enabled,
+            ..self.load_settings()
+        };
+        self.save_settings(&settings)
+    }
+
+    /// 模型注册表：内置声明 + models.json（新模型发布时只需改文件）。
+    /// 文件不存在或无法解析时只有内置声明
+    pub fn load_model_registry(&self) -> ModelRegistry {
+        let mut registry = ModelRegistry::builtin();
+        let file_path = Path::new(&self.config_path).join(MODELS_FILE);
+        if let Some(specs) = fs::read_to_string(file_path)
+            .ok()
+            .and_then(|contents| serde_json::from_str::<Vec<ModelSpec>>(&contents).ok())
+        {
+            registry.merge(specs);
+        }
+        registry
+    }
+
+    /// 自定义情感词条（emotion_lexicon.json）。文件不存在或无法解析时为空，
+    /// 单条不合法（空词、强度越界）的词条被跳过
+    pub fn load_emotion_lexicon(&self) -> Vec<LexiconEntry> {
+        let file_path = Path::new(&self.config_path).join(EMOTION_LEXICON_FILE);
+        fs::read_to_string(file_path)
+            .ok()
+            .and_then(|contents| serde_json::from_str::<Vec<LexiconEntry>>(&contents).ok())
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|entry| validate_lexicon_entry(entry).is_ok())
+            .collect()
+    }
+
+    /// 整体替换自定义情感词条并保存；有不合法词条时不落盘
+    pub fn save_emotion_lexicon(&self, entries: &[LexiconEntry]) -> Result<(), ChatError> {
+        for entry in entries {
+            validate_lexicon_entry(entry)?;
+        }
+        let entries: Vec<LexiconEntry> = entries
+            .iter()
+            .map(|entry| LexiconEntry {
+                word: entry.word.trim().to_string(),
+                ..entry.clone()
+            })
+            .collect();
+
+        let dir = Path::new(&self.config_path);
+        if !dir.exists() {
+            fs::create_dir_all(dir).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to create config directory: {}", e),
+            })?;
+        }
+        let json = serde_json::to_string_pretty(&entries).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to serialize emotion lexicon: {}", e),
+        })?;
+        fs::write(dir.join(EMOTION_LEXICON_FILE), json).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to write emotion lexicon file: {}", e),
+        })
+    }
+
+    /// 自定义停用词（stop_words/{语言代码}.txt，每行一个词，# 开头为注释）。
+    /// 返回 语言代码 → 词表；目录不存在时为空，读不了的文件被跳过
+    pub fn load_stop_words(&self) -> HashMap<String, Vec<String>> {
+        let dir = Path::new(&self.config_path).join(STOP_WORDS_DIR);
+        let Ok(entries) = fs::read_dir(dir) else {
+            return HashMap::new();
+        };
+        let mut lists = HashMap::new();
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("txt") {
+                continue;
+            }
+            let Some(language) = path.file_stem().and_then(|s| s.to_str()) else {
+                continue;
+            };
+            let Ok(contents) = fs::read_to_string(&path) else {
+                continue;
+            };
+            let words: Vec<String> = contents
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                .map(str::to_lowercase)
+                .collect();
+            lists.insert(language.to_lowercase(), words);
+        }
+        lists
+    }
+
+    /// 内容安全过滤设置（safety.json）。文件不存在或无法解析时为默认设置（关闭）
+    pub fn load_safety_policy(&self) -> SafetyPolicy {
+        let file_path = Path::new(&self.config_path).join(SAFETY_POLICY_FILE);
+        fs::read_to_string(file_path)
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    /// 保存内容安全过滤设置；同一分类有多条规则时不落盘
+    pub fn save_safety_policy(&self, policy: &SafetyPolicy) -> Result<(), ChatError> {
+        for (i, rule) in policy.rules.iter().enumerate() {
+            if policy.rules[..i].iter().any(|r| r.category == rule.category) {
+                return Err(ChatError::ValidationError {
+                    message: format!("Duplicate safety rule for {:?}", rule.category),
+                });
+            }
+        }
+        let dir = Path::new(&self.config_path);
+        if !dir.exists() {
+            fs::create_dir_all(dir).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to create config directory: {}", e),
+            })?;
+        }
+        let json = serde_json::to_string_pretty(policy).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to serialize safety policy: {}", e),
+        })?;
+        fs::write(dir.join(SAFETY_POLICY_FILE), json).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to write safety policy file: {}", e),
+        })
+    }
+
+    /// 数据保留策略（retention.json）。文件不存在或无法解析时全部关闭
+    pub fn load_retention_policy(&self) -> RetentionPolicy {
+        let file_path = Path::new(&self.config_path).join(RETENTION_FILE);
+        fs::read_to_string(file_path)
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save_retention_policy(&self, policy: &RetentionPolicy) -> Result<(), ChatError> {
+        let dir = Path::new(&self.config_path);
+        if !dir.exists() {
+            fs::create_dir_all(dir).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to create config directory: {}", e),
+            })?;
+        }
+        let json = serde_json::to_string_pretty(policy).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to serialize retention policy: {}", e),
+        })?;
+        fs::write(dir.join(RETENTION_FILE), json).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to write retention policy file: {}", e),
+        })
+    }
+
+    /// 云同步设置（sync.json）。文件不存在或无法解析时为默认设置（不同步）
+    pub fn load_sync_settings(&self) -> SyncSettings {
+        let file_path = Path::new(&self.config_path).join(SYNC_FILE);
+        fs::read_to_string(file_path)
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    /// 保存云同步设置；地址不是 http(s)、S3 缺少存储桶或区域时不落盘
+    pub fn save_sync_settings(&self, settings: &SyncSettings) -> Result<(), ChatError> {
+        let endpoint = settings.endpoint.trim();
+        if !endpoint.is_empty()
+            && !endpoint.starts_with("http://")
+            && !endpoint.starts_with("https://")
+        {
+            return Err(ChatError::ValidationError {
+                message: "Sync endpoint must be an http(s) URL".to_string(),
+            });
+        }
+        if settings.backend == SyncBackendKind::S3
+            && !endpoint.is_empty()
+            && (settings.bucket.trim().is_empty() || settings.region.trim().is_empty())
+        {
+            return Err(ChatError::ValidationError {
+                message: "S3 sync requires a bucket and region".to_string(),
+            });
+        }
+        let dir = Path::new(&self.config_path);
+        if !dir.exists() {
+            fs::create_dir_all(dir).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to create config directory: {}", e),
+            })?;
+        }
+        let json = serde_json::to_string_pretty(settings).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to serialize sync settings: {}", e),
+        })?;
+        fs::write(dir.join(SYNC_FILE), json).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to write sync settings file: {}", e),
+        })
+    }
+}
+
+fn validate_lexicon_entry(entry: &LexiconEntry) -> Result<(), ChatError> {
+    if entry.word.trim().is_empty() {
+        return Err(ChatError::ValidationError {
+            message: "Lexicon word must not be empty".to_string(),
+        });
+    }
+    if !(entry.weight > 0.0 && entry.weight <= 1.0) {
+        return Err(ChatError::ValidationError {
+            message: format!("Lexicon weight for '{}' must be in (0, 1]", entry.word),
+        });
+    }
+    Ok(())
+}
+
+fn retry_slot() -> &'static RwLock<RetryPolicy> {
+    ACTIVE_RETRY_POLICY.get_or_init(|| RwLock::new(RetryPolicy::default()))
+}
+
+/// 替换当前生效的重试策略（下一个请求生效）
+pub fn install_retry_policy(policy: RetryPolicy) {
+    *retry_slot().write().unwrap() = policy;
+}
+
+/// 当前生效的重试策略（init_app 之前为默认策略）
+pub fn retry_policy() -> RetryPolicy {
+    retry_slot().read().unwrap().clone()
+}
+
+fn proxy_slot() -> &'static RwLock<Option<ProxySettings>> {
+    ACTIVE_PROXY.get_or_init(|| RwLock::new(None))
+}
+
+/// 替换当前生效的代理（已创建的客户端不受影响，下一个请求生效）
+pub fn install_proxy(proxy: Option<ProxySettings>) {
+    *proxy_slot().write().unwrap() = proxy;
+}
+
+/// 由代理配置构建 reqwest 代理：带认证时附加 Basic / SOCKS5 用户名密码，
+/// bypass 中的主机与本机地址直连
+pub fn build_proxy(settings: &ProxySettings) -> Result<reqwest::Proxy, ChatError> {
+    let url = settings.url.trim();
+    if !PROXY_SCHEMES.iter().any(|scheme| url.to_lowercase().starts_with(scheme)) {
+        return Err(ChatError::ValidationError {
+            message: format!("Unsupported proxy URL '{}': expected http, https or socks5", url),
+        });
+    }
+    let mut proxy = reqwest::Proxy::all(url).map_err(|e| ChatError::ValidationError {
+        message: format!("Invalid proxy URL '{}': {}", url, e),
+    })?;
+    if let Some(username) = settings.username.as_deref().filter(|u| !u.is_empty()) {
+        proxy = proxy.basic_auth(username, settings.password.as_deref().unwrap_or(""));
+    }
+    let bypass: Vec<&str> = LOOPBACK_HOSTS
+        .iter()
+        .copied()
+        .chain(settings.bypass.iter().map(|h| h.trim()).filter(|h| !h.is_empty()))
+        .collect();
+    Ok(proxy.no_proxy(reqwest::NoProxy::from_string(&bypass.join(","))))
+}
+
+/// 为客户端应用当前代理；未配置时保持 reqwest 默认行为（读取系统代理环境变量）
+pub fn apply_proxy(builder: reqwest::ClientBuilder) -> Result<reqwest::ClientBuilder, ChatError> {
+    match proxy_slot().read().unwrap().as_ref() {
+        Some(settings) => Ok(builder.proxy(build_proxy(settings)?)),
+        None => Ok(builder),
+    }
+}
+
+/// 请求体中 thinking 字段的写法
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ThinkingField {
+    /// 不发送（旧模型不认识该字段）
+    Omit,
+    /// 始终显式 disabled（不支持思考，但默认会开启）
+    Disabled,
+    /// 按用户偏好开关，开启时带思考预算
+    Toggle { budget_tokens: u32 },
+}
+
+/// 一个模型的声明
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModelSpec {
+    pub id: String,
+    /// 显示名；为空时显示 ID
+    #[serde(default)]
+    pub name: String,
+    pub context_tokens: usize,
+    pub max_output_tokens: u32,
+    pub thinking: ThinkingField,
+    /// 首个数据块的最长等待（推理 / 长上下文模型预热更久）
+    #[serde(default = "default_first_chunk_timeout")]
+    pub first_chunk_timeout_secs: u64,
+    /// 后续数据块之间的最长间隔
+    #[serde(default = "default_subsequent_chunk_timeout")]
+    pub subsequent_chunk_timeout_secs: u64,
+    /// 是否出现在模型选择列表（内部专用模型为 false）
+    #[serde(default = "default_listed")]
+    pub listed: bool,
+    /// 同一模型同时进行的请求上限（见 streaming_handler 的请求队列）；0 表示不限
+    #[serde(default = "default_max_concurrent_requests")]
+    pub max_concurrent_requests: u32,
+    /// 同一模型每秒发起的请求上限；0 表示不限
+    #[serde(default = "default_requests_per_second")]
+    pub requests_per_second: f64,
+}
+
+fn default_first_chunk_timeout() -> u64 {
+    DEFAULT_FIRST_CHUNK_TIMEOUT_SECS
+}
+
+fn default_subsequent_chunk_timeout() -> u64 {
+    DEFAULT_SUBSEQUENT_CHUNK_TIMEOUT_SECS
+}
+
+fn default_listed() -> bool {
+    true
+}
+
+fn default_max_concurrent_requests() -> u32 {
+    DEFAULT_MAX_CONCURRENT_REQUESTS
+}
+
+fn default_requests_per_second() -> f64 {
+    DEFAULT_REQUESTS_PER_SECOND
+}
+
+impl ModelSpec {
+    pub fn supports_thinking(&self) -> bool {
+        matches!(self.thinking, ThinkingField::Toggle { .. })
+    }
+}
+
+/// 模型注册表：模型的输出上限、思考支持、上下文窗口与超时
+///
+/// 参考: https://docs.bigmodel.cn/cn/guide/start/concept-param
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModelRegistry {
+    models: Vec<ModelSpec>,
+}
+
+impl ModelRegistry {
+    /// 内置声明
+    pub fn builtin() -> Self {
+        let spec = |id: &str, name: &str, context_tokens, max_output_tokens, thinking, listed| ModelSpec {
+            id: id.to_string(),
+            name: name.to_string(),
+            context_tokens,
+            max_output_tokens,
+            thinking,
+            first_chunk_timeout_secs: DEFAULT_FIRST_CHUNK_TIMEOUT_SECS,
+            subsequent_chunk_timeout_secs: DEFAULT_SUBSEQUENT_CHUNK_TIMEOUT_SECS,
+            listed,
+            max_concurrent_requests: DEFAULT_MAX_CONCURRENT_REQUESTS,
+            requests_per_second: DEFAULT_REQUESTS_PER_SECOND,
+        };
+        // 推理模型 / 长上下文模型首 token 最长等 5 分钟，推理链中间段可能有长停顿
+        let slow = |spec: ModelSpec| ModelSpec {
+            first_chunk_timeout_secs: 300,
+            subsequent_chunk_timeout_secs: 120,
+            ..spec
+        };
+        Self {
+            models: vec![
+                // GLM-4.7: 默认开启 Thinking，必须显式 disabled 才能关闭
+                spec("glm-4.7", "GLM-4.7（对话+思考）", 128000, 131072, ThinkingField::Toggle { budget_tokens: 16384 }, true),
+                // GLM-4-AIR: 推理模型，按用户偏好开关
+                slow(spec("glm-4-air", "GLM-4-Air（深度推理）", 128000, 4095, ThinkingField::Toggle { budget_tokens: 10240 }, true)),
+                // GLM-4.7-FLASH: 快速模型，显式 disabled
+                spec("glm-4.7-flash", "GLM-4.7-Flash（快速）", 128000, 131072, ThinkingField::Disabled, true),
+                // GLM-4-LONG: 旧模型，不发送 thinking 字段
+                slow(spec("glm-4-long", "GLM-4-Long（长上下文）", 1_000_000, 4095, ThinkingField::Omit, false)),
+                // GLM-4V: 识图
+                spec("glm-4v", "GLM-4V（识图）", 8192, 1024, ThinkingField::Omit, false),
+            ],
+        }
+    }
+
+    /// 合并声明：同 ID 覆盖，新 ID 追加
+    pub fn merge(&mut self, specs: Vec<ModelSpec>) {
+        for spec in specs {
+            match self.models.iter_mut().find(|m| m.id == spec.id) {
+                Some(existing) => *existing = spec,
+                None => self.models.push(spec),
+            }
+        }
+    }
+
+    pub fn get(&self, id: &str) -> Option<&ModelSpec> {
+        self.models.iter().find(|m| m.id == id)
+    }
+
+    /// 模型选择列表中的模型
+    pub fn listed(&self) -> impl Iterator<Item = &ModelSpec> {
+        self.models.iter().filter(|m| m.listed)
+    }
+
+    /// 是否允许启用思考：未声明的模型不发送思考
+    pub fn supports_thinking(&self, id: &str) -> bool {
+        self.get(id).is_some_and(ModelSpec::supports_thinking)
+    }
+
+    /// 单次输出上限：未声明的模型用 DEFAULT_MAX_OUTPUT_TOKENS
+    pub fn max_output_tokens(&self, id: &str) -> u32 {
+        self.get(id)
+            .map(|m| m.max_output_tokens)
+            .unwrap_or(DEFAULT_MAX_OUTPUT_TOKENS)
+    }
+
+    /// 上下文窗口：未声明的模型为 None
+    pub fn context_tokens(&self, id: &str) -> Option<usize> {
+        self.get(id).map(|m| m.context_tokens)
+    }
+
+    /// thinking 字段写法：未声明的模型不发送
+    pub fn thinking_field(&self, id: &str) -> ThinkingField {
+        self.get(id)
+            .map(|m| m.thinking.clone())
+            .unwrap_or(ThinkingField::Omit)
+    }
+
+    /// (首个数据块等待, 数据块间隔)，单位秒
+    pub fn chunk_timeouts(&self, id: &str) -> (u64, u64) {
+        self.get(id)
+            .map(|m| (m.first_chunk_timeout_secs, m.subsequent_chunk_timeout_secs))
+            .unwrap_or((DEFAULT_FIRST_CHUNK_TIMEOUT_SECS, DEFAULT_SUBSEQUENT_CHUNK_TIMEOUT_SECS))
+    }
+
+    /// (同时请求上限, 每秒请求上限)；未声明的模型取默认值
+    pub fn request_limits(&self, id: &str) -> (u32, f64) {
+        self.get(id)
+            .map(|m| (m.max_concurrent_requests, m.requests_per_second))
+            .unwrap_or((DEFAULT_MAX_CONCURRENT_REQUESTS, DEFAULT_REQUESTS_PER_SECOND))
+    }
+
+    fn slot() -> &'static RwLock<Arc<ModelRegistry>> {
+        MODEL_REGISTRY.get_or_init(|| RwLock::new(Arc::new(ModelRegistry::builtin())))
+    }
+
+    /// 进程内共享的注册表（init_app 之前为内置声明）
+    pub fn global() -> Arc<ModelRegistry> {
+        Self::slot().read().unwrap().clone()
+    }
+
+    /// 替换进程内共享的注册表
+    pub fn install(registry: ModelRegistry) {
+        *Self::slot().write().unwrap() = Arc::new(registry);
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::api::data_models::ContentIntensity;
     use tempfile::TempDir;
 
+    #[test]
+    fn test_load_stop_words_per_language() {
+        let tmp = TempDir::new().unwrap();
+        let manager = ConfigManager::new(tmp.path().to_str().unwrap());
+        assert!(manager.load_stop_words().is_empty());
+
+        let dir = tmp.path().join("stop_words");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("zh.txt"), "# 口头禅\n然后\n\n  就是说  \n").unwrap();
+        fs::write(dir.join("EN.txt"), "Basically\nliterally\n").unwrap();
+        fs::write(dir.join("notes.md"), "ignored\n").unwrap();
+
+        let lists = manager.load_stop_words();
+        assert_eq!(lists.len(), 2);
+        assert_eq!(lists["zh"], vec!["然后".to_string(), "就是说".to_string()]);
+        assert_eq!(lists["en"], vec!["basically".to_string(), "literally".to_string()]);
+    }
+
     #[test]
     fn test_load_defaults_when_no_file() {
         let tmp = TempDir::new().unwrap();
@@ -80,6 +771,18 @@ mod tests {
             enable_thinking_by_default: true,
             chat_model: "glm-4.7".to_string(),
             thinking_model: "glm-4-air".to_string(),
+            enable_web_search: false,
+            content_intensity: ContentIntensity::Normal,
+            provider: ProviderKind::Zhipu,
+            provider_base_url: None,
+            provider_model: None,
+            provider_api_key: None,
+            shadow_eval_rate: 0.0,
+            enable_tts: false,
+            proxy: None,
+            retry: RetryPolicy::default(),
+            time_awareness: true,
+            fast_draft: false,
         };
 
         manager.save_settings(&settings).unwrap();
@@ -99,6 +802,18 @@ mod tests {
             enable_thinking_by_default: false,
             chat_model: "glm-4.7".to_string(),
             thinking_model: "glm-4-air".to_string(),
+            enable_web_search: false,
+            content_intensity: ContentIntensity::Normal,
+            provider: ProviderKind::Zhipu,
+            provider_base_url: None,
+            provider_model: None,
+            provider_api_key: None,
+            shadow_eval_rate: 0.0,
+            enable_tts: false,
+            proxy: None,
+            retry: RetryPolicy::default(),
+            time_awareness: true,
+            fast_draft: false,
         };
         manager.save_settings(&first).unwrap();
 
@@ -108,6 +823,18 @@ mod tests {
             enable_thinking_by_default: true,
             chat_model: "glm-4.7".to_string(),
             thinking_model: "glm-4-air".to_string(),
+            enable_web_search: false,
+            content_intensity: ContentIntensity::Normal,
+            provider: ProviderKind::Zhipu,
+            provider_base_url: None,
+            provider_model: None,
+            provider_api_key: None,
+            shadow_eval_rate: 0.0,
+            enable_tts: false,
+            proxy: None,
+            retry: RetryPolicy::default(),
+            time_awareness: true,
+            fast_draft: false,
         };
         manager.save_settings(&second).unwrap();
 
@@ -115,6 +842,22 @@ mod tests {
         assert_eq!(loaded, second);
     }
 
+    #[test]
+    fn test_save_notifies_subscribers() {
+        let tmp = TempDir::new().unwrap();
+        let manager = ConfigManager::new(tmp.path().to_str().unwrap());
+        let mut rx = manager.subscribe();
+
+        let settings = AppSettings {
+            enable_web_search: true,
+            ..AppSettings::default()
+        };
+        manager.save_settings(&settings).unwrap();
+
+        assert_eq!(rx.try_recv().unwrap(), settings);
+        assert!(rx.try_recv().is_err());
+    }
+
     #[test]
     fn test_load_returns_default_for_invalid_json() {
         let tmp = TempDir::new().unwrap();
@@ -127,6 +870,99 @@ mod tests {
         assert_eq!(settings, AppSettings::default());
     }
 
+    #[test]
+    fn test_character_voices_round_trip() {
+        let tmp = TempDir::new().unwrap();
+        let manager = ConfigManager::new(tmp.path().join("cfg").to_str().unwrap());
+        assert!(manager.load_voices().is_empty());
+
+        manager.set_character_voice("conv-1", Some("xiaochen".to_string())).unwrap();
+        manager.set_character_voice("char-a", Some(" jam ".to_string())).unwrap();
+        let voices = manager.load_voices();
+        assert_eq!(voices.get("conv-1").map(String::as_str), Some("xiaochen"));
+        assert_eq!(voices.get("char-a").map(String::as_str), Some("jam"));
+
+        manager.set_character_voice("conv-1", None).unwrap();
+        assert!(!manager.load_voices().contains_key("conv-1"));
+        // 音色与设置分开存放，不影响设置
+        assert_eq!(manager.load_settings(), AppSettings::default());
+    }
+
+    #[test]
+    fn test_summarization_config_per_conversation() {
+        let tmp = TempDir::new().unwrap();
+        let manager = ConfigManager::new(tmp.path().to_str().unwrap());
+        assert_eq!(manager.load_summarization_config("c1"), SummarizationConfig::default());
+
+        let heavy = SummarizationConfig {
+            interval_turns: 30,
+            window_messages: 60,
+        };
+        manager.set_summarization_config("c1", Some(heavy)).unwrap();
+        let invalid = SummarizationConfig {
+            interval_turns: 0,
+            window_messages: 20,
+        };
+        assert!(manager.set_summarization_config("c2", Some(invalid)).is_err());
+        assert_eq!(manager.load_summarization_config("c1"), heavy);
+        assert_eq!(manager.load_summarization_config("c2"), SummarizationConfig::default());
+
+        manager.set_summarization_config("c1", None).unwrap();
+        assert_eq!(manager.load_summarization_config("c1"), SummarizationConfig::default());
+    }
+
+    #[test]
+    fn test_model_registry_merges_declarations_file() {
+        let tmp = TempDir::new().unwrap();
+        let manager = ConfigManager::new(tmp.path().to_str().unwrap());
+        let builtin = manager.load_model_registry();
+        assert_eq!(builtin, ModelRegistry::builtin());
+        assert_eq!(builtin.max_output_tokens("glm-4-air"), 4095);
+        assert_eq!(builtin.max_output_tokens("unknown"), DEFAULT_MAX_OUTPUT_TOKENS);
+        assert_eq!(builtin.chunk_timeouts("glm-4-long"), (300, 120));
+
+        fs::write(
+            tmp.path().join(MODELS_FILE),
+            r#"[
+                {"id": "glm-5", "name": "GLM-5", "context_tokens": 200000, "max_output_tokens": 131072,
+                 "thinking": {"toggle": {"budget_tokens": 32768}}},
+                {"id": "glm-4.7-flash", "context_tokens": 128000, "max_output_tokens": 8192, "thinking": "omit"}
+            ]"#,
+        )
+        .unwrap();
+        let registry = manager.load_model_registry();
+        assert!(registry.supports_thinking("glm-5"));
+        assert_eq!(registry.thinking_field("glm-5"), ThinkingField::Toggle { budget_tokens: 32768 });
+        assert_eq!(registry.chunk_timeouts("glm-5"), (180, 90));
+        assert_eq!(registry.max_output_tokens("glm-4.7-flash"), 8192);
+        assert_eq!(registry.thinking_field("glm-4.7-flash"), ThinkingField::Omit);
+        assert_eq!(registry.listed().count(), 4);
+    }
+
+    #[test]
+    fn test_set_proxy_validates_before_saving() {
+        let tmp = TempDir::new().unwrap();
+        let manager = ConfigManager::new(tmp.path().to_str().unwrap());
+        let proxy = |url: &str| ProxySettings {
+            url: url.to_string(),
+            username: Some("user".to_string()),
+            password: Some("pass".to_string()),
+            bypass: vec!["192.168.0.0/16".to_string()],
+        };
+
+        assert!(manager.set_proxy(Some(proxy("ftp://proxy.local:21"))).is_err());
+        assert!(manager.set_proxy(Some(proxy("not a url"))).is_err());
+        assert_eq!(manager.load_settings().proxy, None);
+
+        manager.set_proxy(Some(proxy("socks5h://127.0.0.1:1080"))).unwrap();
+        assert_eq!(manager.load_settings().proxy, Some(proxy("socks5h://127.0.0.1:1080")));
+        manager.set_proxy(Some(proxy("http://proxy.local:7890"))).unwrap();
+        assert!(apply_proxy(reqwest::Client::builder()).unwrap().build().is_ok());
+
+        manager.set_proxy(None).unwrap();
+        assert_eq!(manager.load_settings().proxy, None);
+    }
+
     #[test]
     fn test_save_creates_directory_if_missing() {
         let tmp = TempDir::new().unwrap();
@@ -139,10 +975,44 @@ mod tests {
             enable_thinking_by_default: false,
             chat_model: "glm-4.7".to_string(),
             thinking_model: "glm-4-air".to_string(),
+            enable_web_search: false,
+            content_intensity: ContentIntensity::Normal,
+            provider: ProviderKind::Zhipu,
+            provider_base_url: None,
+            provider_model: None,
+            provider_api_key: None,
+            shadow_eval_rate: 0.0,
+            enable_tts: false,
+            proxy: None,
+            retry: RetryPolicy::default(),
+            time_awareness: true,
+            fast_draft: false,
         };
 
         manager.save_settings(&settings).unwrap();
         let loaded = manager.load_settings();
         assert_eq!(loaded, settings);
     }
+
+    #[test]
+    fn test_set_provider_validates_before_saving() {
+        let tmp = TempDir::new().unwrap();
+        let manager = ConfigManager::new(tmp.path().to_str().unwrap());
+
+        let err = manager.set_provider(ProviderKind::Anthropic, None, None, None);
+        assert!(matches!(err, Err(ChatError::ValidationError { .. })));
+        assert_eq!(manager.load_settings().provider, ProviderKind::Zhipu);
+
+        manager
+            .set_provider(
+                ProviderKind::OpenAiCompatible,
+                Some("http://localhost:8080/v1".to_string()),
+                Some("qwen2.5-7b".to_string()),
+                None,
+            )
+            .unwrap();
+        let loaded = manager.load_settings();
+        assert_eq!(loaded.provider, ProviderKind::OpenAiCompatible);
+        assert_eq!(loaded.provider_model.as_deref(), Some("qwen2.5-7b"));
+    }
 }
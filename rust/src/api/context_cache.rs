@@ -0,0 +1,168 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::{Mutex, OnceLock};
+
+use super::data_models::{MemorySummary, MemoryTier, Message, UserPersona};
+use super::personas::PersonaStore;
+
+// ═══════════════════════════════════════════════════════════════════
+//  上下文前缀缓存 (Context Prefix Cache)
+//  ─────────────────────────────────────────────────────────────────
+//  系统层上下文里有几层多数轮次不变：角色设定、用户人设、身份锚点
+//  （记忆中 Identity 层级的事实）。ChatEngine 把它们作为稳定前缀放在上下文最前面：
+//    1. 复用：按对话缓存组装好的前缀及其指纹（各层内容的哈希），
+//       指纹不变时直接复用，只重建每轮变化的层（记忆检索、认知快照、历史窗口……）
+//    2. 提供方缓存：前缀逐字节不变，智谱 / OpenAI 兼容端点的隐式上下文缓存可以命中；
+//       Anthropic 请求在前缀末尾打缓存断点（见 chat_provider）。
+//       命中的 token 数记入 GenerationMetadata::cached_tokens
+//  仅在内存中缓存；重启后首轮重新组装。
+// ═══════════════════════════════════════════════════════════════════
+
+/// 最多缓存的对话数：超出时整体清空（重新组装代价很低）
+const MAX_ENTRIES: usize = 64;
+
+struct CachedPrefix {
+    fingerprint: u64,
+    messages: Vec<Message>,
+}
+
+/// 稳定前缀的各层输入
+pub struct PrefixLayers<'a> {
+    pub character: Option<&'a Message>,
+    pub persona: Option<&'a UserPersona>,
+    pub identity_facts: &'a [String],
+}
+
+#[derive(Default)]
+pub struct ContextCache {
+    entries: Mutex<HashMap<String, CachedPrefix>>,
+}
+
+static GLOBAL: OnceLock<ContextCache> = OnceLock::new();
+
+impl ContextCache {
+    pub fn global() -> &'static ContextCache {
+        GLOBAL.get_or_init(ContextCache::default)
+    }
+
+    /// 身份锚点：所有记忆摘要中 Identity 层级的事实（去重，按出现顺序）
+    pub fn identity_facts(summaries: &[MemorySummary]) -> Vec<String> {
+        let mut facts: Vec<String> = Vec::new();
+        for summary in summaries {
+            for (i, fact) in summary.core_facts.iter().enumerate() {
+                if summary.fact_tiers.get(i) == Some(&MemoryTier::Identity) && !facts.contains(fact)
+                {
+                    facts.push(fact.clone());
+                }
+            }
+        }
+        facts
+    }
+
+    /// key 对应对话的稳定前缀（角色设定 → 用户人设 → 身份锚点）；
+    /// 各层与上次相同时复用上次组装的消息
+    pub fn prefix(&self, key: &str, layers: &PrefixLayers) -> Vec<Message> {
+        let fingerprint = Self::fingerprint(layers);
+        let mut entries = match self.entries.lock() {
+            Ok(entries) => entries,
+            Err(_) => return Self::build(layers),
+        };
+        if let Some(cached) = entries.get(key).filter(|c| c.fingerprint == fingerprint) {
+            return cached.messages.clone();
+        }
+        let messages = Self::build(layers);
+        if entries.len() >= MAX_ENTRIES && !entries.contains_key(key) {
+            entries.clear();
+        }
+        entries.insert(
+            key.to_string(),
+            CachedPrefix {
+                fingerprint,
+                messages: messages.clone(),
+            },
+        );
+        messages
+    }
+
+    /// 丢弃对话的缓存（删除对话时调用）
+    pub fn invalidate(&self, key: &str) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.remove(key);
+        }
+    }
+
+    /// 丢弃全部缓存（整库恢复后调用）
+    pub fn clear(&self) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.clear();
+        }
+    }
+
+    fn fingerprint(layers: &PrefixLayers) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        layers.character.map(|m| m.content.as_str()).hash(&mut hasher);
+        layers
+            .persona
+            .map(|p| (&p.name, &p.description, &p.pronouns))
+            .hash(&mut hasher);
+        layers.identity_facts.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn build(layers: &PrefixLayers) -> Vec<Message> {
+        let mut messages: Vec<Message> = layers.character.cloned().into_iter().collect();
+        if let Some(persona) = layers.persona {
+            messages.push(Message::system_prompt(PersonaStore::build_prompt(persona)));
+        }
+        if !layers.identity_facts.is_empty() {
+            let mut anchor = String::from("【身份锚点·基础设定（背景知识）】\n");
+            for fact in layers.identity_facts {
+                anchor.push_str(&format!("  ● {}\n", fact));
+            }
+            anchor.push_str("这些是长期不变的设定，回复不得与之矛盾；对话没有涉及时不要主动罗列。");
+            messages.push(Message::system_prompt(anchor));
+        }
+        messages
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prefix_reused_until_a_layer_changes() {
+        let cache = ContextCache::default();
+        let character = Message::system_prompt("你是小雨");
+        let mut persona = UserPersona {
+            id: "p".to_string(),
+            name: "林晚".to_string(),
+            description: String::new(),
+            pronouns: String::new(),
+            created_at: 0,
+        };
+        let facts = vec!["小雨是画家".to_string()];
+        let layers = PrefixLayers {
+            character: Some(&character),
+            persona: Some(&persona),
+            identity_facts: &facts,
+        };
+        let first = cache.prefix("c", &layers);
+        assert_eq!(first.len(), 3);
+        assert_eq!(first[0].content, "你是小雨");
+        assert!(first[1].content.contains("林晚"));
+        assert!(first[2].content.contains("小雨是画家"));
+        assert_eq!(cache.prefix("c", &layers), first);
+
+        persona.name = "阿晚".to_string();
+        let layers = PrefixLayers {
+            character: Some(&character),
+            persona: Some(&persona),
+            identity_facts: &[],
+        };
+        let rebuilt = cache.prefix("c", &layers);
+        assert_eq!(rebuilt.len(), 2);
+        assert!(rebuilt[1].content.contains("阿晚"));
+    }
+}
@@ -1,11 +1,55 @@
+use std::collections::{BTreeSet, HashMap};
 use std::fs;
 use std::path::PathBuf;
+use std::sync::{Arc, Mutex, OnceLock};
 
 use flutter_rust_bridge::frb;
 
+use super::at_rest;
+use super::blocking_pool;
 use super::data_models::*;
 use super::error_handler::ChatError;
+use super::text_utils;
+
+/// 沙盒对话 ID 前缀：此类对话只存在于内存，不落盘
+pub const SANDBOX_ID_PREFIX: &str = "sandbox-";
+
+/// 用户亲自撰写/改写的角色回复（操偶模式）在 model 字段上的标记
+pub const USER_AUTHORED_MODEL_ID: &str = "user-authored";
+
+/// 一条消息最多的表情回应数
+const MAX_REACTIONS: usize = 8;
+
+/// 一条消息保留的编辑历史版本数
+const MAX_EDIT_HISTORY: usize = 20;
+
+/// 沙盒对话的内存存储（进程内共享，关闭或退出即丢弃）
+static SANDBOX_CONVERSATIONS: OnceLock<Mutex<HashMap<String, Conversation>>> = OnceLock::new();
+
+fn sandbox_conversations() -> &'static Mutex<HashMap<String, Conversation>> {
+    SANDBOX_CONVERSATIONS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// 每个对话一把异步锁：同一对话的生成管线（写入用户消息、生成回复、轮次计数、
+/// 事实提取）串行执行，连点发送不会交错写入
+static TURN_LOCKS: OnceLock<Mutex<HashMap<String, Arc<tokio::sync::Mutex<()>>>>> =
+    OnceLock::new();
+
+/// 持有期间独占对话的生成管线，drop 即释放
+pub type TurnGuard = tokio::sync::OwnedMutexGuard<()>;
+
+fn turn_lock(conversation_id: &str) -> Arc<tokio::sync::Mutex<()>> {
+    let mut locks = TURN_LOCKS
+        .get_or_init(|| Mutex::new(HashMap::new()))
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    // 顺手清掉无人持有也无人等待的锁，表不会随对话数无限增长
+    locks.retain(|_, lock| Arc::strong_count(lock) > 1);
+    locks.entry(conversation_id.to_string()).or_default().clone()
+}
+
 #[frb(opaque)]
+#[derive(Clone)]
 pub struct ConversationStore {
     pub base_path: String,
 }
@@ -17,6 +61,41 @@ impl ConversationStore {
         }
     }
 
+    /// 立即取得对话的生成锁；已有生成在进行时返回 ValidationError（用户触发的发送）
+    pub fn try_lock_turn(conversation_id: &str) -> Result<TurnGuard, ChatError> {
+        turn_lock(conversation_id)
+            .try_lock_owned()
+            .map_err(|_| ChatError::ValidationError {
+                message: format!(
+                    "Conversation '{}' is already generating a reply; wait for it to finish",
+                    conversation_id
+                ),
+            })
+    }
+
+    /// 立即取得所有已存对话的生成锁（导入、恢复备份、同步拉取等整体改写数据的操作）；
+    /// 任一对话正在生成时返回 ValidationError
+    pub fn try_lock_all_turns(&self) -> Result<Vec<TurnGuard>, ChatError> {
+        let entries = fs::read_dir(self.conversations_dir()?).map_err(|e| {
+            ChatError::StorageError {
+                message: format!("Failed to read conversations directory: {}", e),
+            }
+        })?;
+        // .json 迁移前后两份文件可能并存：按 ID 去重，同一把锁只取一次
+        let ids: BTreeSet<String> = entries
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| matches!(p.extension().and_then(|e| e.to_str()), Some("msgpack" | "json")))
+            .filter_map(|p| p.file_stem().and_then(|s| s.to_str()).map(str::to_string))
+            .collect();
+        ids.iter().map(|id| Self::try_lock_turn(id)).collect()
+    }
+
+    /// 排队等待对话的生成锁（发件箱重放等可以等待的后台任务）
+    pub async fn lock_turn(conversation_id: &str) -> TurnGuard {
+        turn_lock(conversation_id).lock_owned().await
+    }
+
     fn conversations_dir(&self) -> Result<PathBuf, ChatError> {
         let dir = PathBuf::from(&self.base_path).join("conversations");
         if !dir.exists() {
@@ -62,32 +141,101 @@ impl ConversationStore {
             dialogue_style: DialogueStyle::default(),
             turn_count: 0,
             memory_summaries: Vec::new(),
+            mode: ConversationMode::default(),
+            thinking_retention: ThinkingRetention::default(),
+            branch_id: MAIN_BRANCH_ID.to_string(),
+            parent_message_id: None,
+            branches: Vec::new(),
+            narration: NarrationPerspective::default(),
+            context_layers: ContextLayers::default(),
+            closed_at: None,
+            response_style: ResponseStyle::default(),
+            persona_id: None,
+            organization: ConversationOrganization::default(),
+            cold_turns: 0,
+        }
+    }
+
+    /// Whether the conversation is an ephemeral sandbox (in-memory only).
+    pub fn is_sandbox(id: &str) -> bool {
+        id.starts_with(SANDBOX_ID_PREFIX)
+    }
+
+    /// Create an in-memory sandbox conversation seeded from a character card.
+    /// Nothing is written to disk; discard it with `delete_conversation`.
+    pub fn create_sandbox_conversation(
+        &self,
+        card: &CharacterCard,
+    ) -> Result<Conversation, ChatError> {
+        let mut conv = self.create_conversation();
+        conv.id = format!("{}{}", SANDBOX_ID_PREFIX, uuid::Uuid::new_v4());
+        conv.title = card.name.clone();
+        let now = conv.created_at;
+        if !card.system_prompt.trim().is_empty() {
+            conv.messages.push(Message {
+                timestamp: now,
+                ..Message::new(MessageRole::System, card.system_prompt.clone(), "system")
+            });
+        }
+        if !card.greeting.trim().is_empty() {
+            conv.messages.push(Message {
+                timestamp: now,
+                ..Message::new(MessageRole::Assistant, card.greeting.clone(), conv.model.clone())
+            });
         }
+        self.save_conversation(&conv)?;
+        Ok(conv)
     }
 
     pub fn save_conversation(&self, conversation: &Conversation) -> Result<(), ChatError> {
+        if Self::is_sandbox(&conversation.id) {
+            let mut sandboxes = sandbox_conversations().lock().map_err(|_| ChatError::StorageError {
+                message: "Sandbox store poisoned".to_string(),
+            })?;
+            sandboxes.insert(conversation.id.clone(), conversation.clone());
+            return Ok(());
+        }
         let path = self.conversation_path(&conversation.id)?;
         let data = rmp_serde::to_vec(conversation).map_err(|e| ChatError::StorageError {
             message: format!("Failed to serialize conversation: {}", e),
         })?;
-        fs::write(&path, data).map_err(|e| ChatError::StorageError {
+        at_rest::write(&path, data).map_err(|e| ChatError::StorageError {
             message: format!("Failed to write conversation file: {}", e),
         })
     }
 
     pub fn load_conversation(&self, id: &str) -> Result<Conversation, ChatError> {
+        if Self::is_sandbox(id) {
+            let sandboxes = sandbox_conversations().lock().map_err(|_| ChatError::StorageError {
+                message: "Sandbox store poisoned".to_string(),
+            })?;
+            return sandboxes.get(id).cloned().ok_or_else(|| ChatError::StorageError {
+                message: format!("Sandbox conversation '{}' not found", id),
+            });
+        }
+
         // Try migration first
         let _ = self.migrate_json_if_needed(id);
 
         let path = self.conversation_path(id)?;
-        let data = fs::read(&path).map_err(|e| ChatError::StorageError {
-            message: format!("Failed to read conversation file '{}': {}", id, e),
-        })?;
-        rmp_serde::from_slice(&data).map_err(|e| ChatError::StorageError {
-            message: format!("Failed to deserialize conversation '{}': {}", id, e),
+        at_rest::load(&path, &format!("conversation file '{}'", id), |data| {
+            rmp_serde::from_slice(data).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to deserialize conversation '{}': {}", id, e),
+            })
         })
     }
 
+    /// 加载对话用于修改：已收束（只读）的对话拒绝修改
+    pub fn load_open(&self, id: &str) -> Result<Conversation, ChatError> {
+        let conv = self.load_conversation(id)?;
+        if conv.closed_at.is_some() {
+            return Err(ChatError::ValidationError {
+                message: format!("Conversation '{}' is closed and read-only", id),
+            });
+        }
+        Ok(conv)
+    }
+
     pub fn list_conversations(&self) -> Vec<ConversationSummary> {
         let dir = match self.conversations_dir() {
             Ok(d) => d,
@@ -107,7 +255,7 @@ impl ConversationStore {
 
                 let conv: Conversation = match ext {
                     "msgpack" => {
-                        let data = fs::read(&path).ok()?;
+                        let data = at_rest::read(&path).ok()?;
                         rmp_serde::from_slice(&data).ok()?
                     }
                     "json" => {
@@ -121,7 +269,7 @@ impl ConversationStore {
                 let last_message_preview = conv
                     .messages
                     .last()
-                    .map(|m| m.content.chars().take(50).collect::<String>())
+                    .map(|m| text_utils::truncate_chars(&m.content, 50).to_string())
                     .unwrap_or_default();
 
                 Some(ConversationSummary {
@@ -130,28 +278,86 @@ impl ConversationStore {
                     last_message_preview,
                     model: conv.model,
                     updated_at: conv.updated_at,
+                    organization: conv.organization,
                 })
             })
             .collect();
 
-        summaries.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
+        summaries.sort_by_key(|s| std::cmp::Reverse(s.updated_at));
+        summaries
+    }
+
+    /// 按筛选条件列出对话：置顶的在前，其余按更新时间倒序
+    pub fn list_conversations_filtered(
+        &self,
+        filter: &ConversationFilter,
+    ) -> Vec<ConversationSummary> {
+        let query = filter.query.trim().to_lowercase();
+        let mut summaries: Vec<ConversationSummary> = self
+            .list_conversations()
+            .into_iter()
+            .filter(|s| {
+                let org = &s.organization;
+                org.archived == filter.archived
+                    && filter.folder.as_ref().is_none_or(|f| org.folder.as_ref() == Some(f))
+                    && filter.tags.iter().all(|t| org.tags.contains(t))
+                    && (query.is_empty() || s.title.to_lowercase().contains(&query))
+            })
+            .collect();
+        // 稳定排序：同为置顶 / 非置顶时保持更新时间倒序
+        summaries.sort_by_key(|s| !s.organization.pinned);
         summaries
     }
 
+    /// 全部对话用到的文件夹（去重，按名称排序）
+    pub fn list_folders(&self) -> Vec<String> {
+        let mut folders: Vec<String> = self
+            .list_conversations()
+            .into_iter()
+            .filter_map(|s| s.organization.folder)
+            .collect();
+        folders.sort();
+        folders.dedup();
+        folders
+    }
+
+    /// 全部对话用到的标签（去重，按名称排序）
+    pub fn list_tags(&self) -> Vec<String> {
+        let mut tags: Vec<String> = self
+            .list_conversations()
+            .into_iter()
+            .flat_map(|s| s.organization.tags)
+            .collect();
+        tags.sort();
+        tags.dedup();
+        tags
+    }
+
     pub fn delete_conversation(&self, id: &str) -> Result<(), ChatError> {
+        if Self::is_sandbox(id) {
+            if let Ok(mut sandboxes) = sandbox_conversations().lock() {
+                sandboxes.remove(id);
+            }
+            return Ok(());
+        }
+
+        // 该对话尚未发出的离线消息一并丢弃
+        for entry in self.list_outbox() {
+            if entry.conversation_id == id {
+                self.remove_outbox_entry(&entry.id)?;
+            }
+        }
+        self.remove_check_in_schedule(id)?;
+
         let path = self.conversation_path(id)?;
         // Also try to delete legacy json
         let dir = self.conversations_dir()?;
         let json_path = dir.join(format!("{}.json", id));
         let _ = fs::remove_file(&json_path);
 
-        if path.exists() {
-            fs::remove_file(&path).map_err(|e| ChatError::StorageError {
-                message: format!("Failed to delete conversation '{}': {}", id, e),
-            })
-        } else {
-            Ok(())
-        }
+        at_rest::remove(&path).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to delete conversation '{}': {}", id, e),
+        })
     }
 
     pub fn add_message(
@@ -159,10 +365,10 @@ impl ConversationStore {
         conversation_id: &str,
         message: Message,
     ) -> Result<(), ChatError> {
-        let mut conv = self.load_conversation(conversation_id)?;
+        let mut conv = self.load_open(conversation_id)?;
 
         if conv.title.is_empty() && message.role == MessageRole::User {
-            let title: String = message.content.chars().take(20).collect();
+            let title = text_utils::truncate_chars(&message.content, 20).to_string();
             conv.title = title;
         }
 
@@ -177,7 +383,7 @@ impl ConversationStore {
         conversation_id: &str,
         message_id: &str,
     ) -> Result<(), ChatError> {
-        let mut conv = self.load_conversation(conversation_id)?;
+        let mut conv = self.load_open(conversation_id)?;
         let original_len = conv.messages.len();
         conv.messages.retain(|m| m.id != message_id);
         if conv.messages.len() == original_len {
@@ -189,6 +395,105 @@ impl ConversationStore {
         self.save_conversation(&conv)
     }
 
+    // ── 同一轮回复的多个版本（swipe）──
+
+    fn alternative_of(message: &Message) -> MessageAlternative {
+        MessageAlternative {
+            content: message.content.clone(),
+            thinking_content: message.thinking_content.clone(),
+            model: message.model.clone(),
+            timestamp: message.timestamp,
+            generation_metadata: message.generation_metadata.clone(),
+            audio_path: message.audio_path.clone(),
+        }
+    }
+
+    fn show_alternative(message: &mut Message, alternative: &MessageAlternative) {
+        message.content = alternative.content.clone();
+        message.thinking_content = alternative.thinking_content.clone();
+        message.model = alternative.model.clone();
+        message.timestamp = alternative.timestamp;
+        message.generation_metadata = alternative.generation_metadata.clone();
+        message.audio_path = alternative.audio_path.clone();
+    }
+
+    /// 把当前显示的版本（可能被编辑过或刚合成了语音）写回 alternatives
+    fn sync_selected_alternative(message: &mut Message) {
+        let current = Self::alternative_of(message);
+        match message
+            .alternatives
+            .get_mut(message.selected_alternative as usize)
+        {
+            Some(slot) => *slot = current,
+            None => {
+                message.alternatives.push(current);
+                message.selected_alternative = message.alternatives.len() as u32 - 1;
+            }
+        }
+    }
+
+    /// 写入重新生成的回复：时间线末尾已是本轮回复时，作为它的新版本追加并切换过去
+    /// （旧版本保留，可用 select_alternative 切回）；否则作为新消息追加。返回写入后的消息
+    pub fn add_reply_alternative(
+        &self,
+        conversation_id: &str,
+        reply: Message,
+    ) -> Result<Message, ChatError> {
+        let mut conv = self.load_open(conversation_id)?;
+        let saved = match conv
+            .messages
+            .last_mut()
+            .filter(|m| m.role == MessageRole::Assistant)
+        {
+            Some(last) => {
+                let alternative = Self::alternative_of(&reply);
+                Self::sync_selected_alternative(last);
+                Self::show_alternative(last, &alternative);
+                last.alternatives.push(alternative);
+                last.selected_alternative = last.alternatives.len() as u32 - 1;
+                last.message_type = reply.message_type;
+                last.clone()
+            }
+            None => {
+                conv.messages.push(reply.clone());
+                reply
+            }
+        };
+        conv.updated_at = chrono::Utc::now().timestamp_millis();
+        self.save_conversation(&conv)?;
+        Ok(saved)
+    }
+
+    /// 切换回复显示的版本；返回切换后的消息
+    pub fn select_alternative(
+        &self,
+        conversation_id: &str,
+        message_id: &str,
+        index: u32,
+    ) -> Result<Message, ChatError> {
+        let mut conv = self.load_open(conversation_id)?;
+        let message = conv
+            .messages
+            .iter_mut()
+            .find(|m| m.id == message_id)
+            .ok_or_else(|| ChatError::StorageError {
+                message: format!("Message '{}' not found", message_id),
+            })?;
+        if index as usize >= message.alternatives.len() {
+            return Err(ChatError::ValidationError {
+                message: format!("Message '{}' has no alternative {}", message_id, index),
+            });
+        }
+        Self::sync_selected_alternative(message);
+        message.selected_alternative = index;
+        let alternative = message.alternatives[index as usize].clone();
+        Self::show_alternative(message, &alternative);
+        let selected = message.clone();
+        conv.updated_at = chrono::Utc::now().timestamp_millis();
+        self.save_conversation(&conv)?;
+        Ok(selected)
+    }
+
     /// Increment the turn count for a conversation.
     pub fn increment_turn_count(&self, conversation_id: &str) -> Result<(), ChatError> {
         let mut conv = self.load_conversation(conversation_id)?;
@@ -208,26 +513,241 @@ impl ConversationStore {
         self.save_conversation(&conv)
     }
 
-    /// Edit a message's content in a conversation.
+    /// 编辑消息内容：旧版本进入 edit_history（最多保留 MAX_EDIT_HISTORY 版），
+    /// 覆盖该轮的记忆摘要标记为过时、等待重新总结。返回编辑后的对话
     pub fn edit_message(
         &self,
         conversation_id: &str,
         message_id: &str,
         new_content: &str,
+    ) -> Result<Conversation, ChatError> {
+        let mut conv = self.load_open(conversation_id)?;
+        let pos = Self::message_position(&conv, message_id)?;
+        let now = chrono::Utc::now().timestamp_millis();
+        let msg = &mut conv.messages[pos];
+        if msg.content == new_content {
+            return Ok(conv);
+        }
+        msg.edit_history.push(MessageEdit {
+            content: std::mem::replace(&mut msg.content, new_content.to_string()),
+            timestamp: msg.timestamp,
+            edited_at: now,
+        });
+        if msg.edit_history.len() > MAX_EDIT_HISTORY {
+            let excess = msg.edit_history.len() - MAX_EDIT_HISTORY;
+            msg.edit_history.drain(..excess);
+        }
+        msg.timestamp = now;
+        Self::invalidate_summaries_at(&mut conv, pos);
+        conv.updated_at = now;
+        self.save_conversation(&conv)?;
+        Ok(conv)
+    }
+
+    /// 撤销最近一次编辑：恢复 edit_history 末尾的版本，覆盖该轮的记忆摘要同样标记为过时。
+    /// 返回撤销后的对话；消息没有编辑记录时报错
+    pub fn undo_edit(
+        &self,
+        conversation_id: &str,
+        message_id: &str,
+    ) -> Result<Conversation, ChatError> {
+        let mut conv = self.load_open(conversation_id)?;
+        let pos = Self::message_position(&conv, message_id)?;
+        let msg = &mut conv.messages[pos];
+        let previous = msg
+            .edit_history
+            .pop()
+            .ok_or_else(|| ChatError::ValidationError {
+                message: format!("Message '{}' has no edits to undo", message_id),
+            })?;
+        msg.content = previous.content;
+        msg.timestamp = previous.timestamp;
+        Self::invalidate_summaries_at(&mut conv, pos);
+        conv.updated_at = chrono::Utc::now().timestamp_millis();
+        self.save_conversation(&conv)?;
+        Ok(conv)
+    }
+
+    fn message_position(conv: &Conversation, message_id: &str) -> Result<usize, ChatError> {
+        conv.messages
+            .iter()
+            .position(|m| m.id == message_id)
+            .ok_or_else(|| ChatError::StorageError {
+                message: format!("Message '{}' not found", message_id),
+            })
+    }
+
+    /// 第 pos 条消息之后的用户消息数（即之后还有几轮）
+    fn user_turns_after(conv: &Conversation, pos: usize) -> u32 {
+        conv.messages[pos + 1..]
+            .iter()
+            .filter(|m| m.role == MessageRole::User)
+            .count() as u32
+    }
+
+    /// 第 pos 条消息所属的轮次：从 turn_count 倒推，早期消息移入冷存储后依然准确
+    /// （与 MemoryEngine::starred_turns 的计数一致）
+    pub fn turn_at(conv: &Conversation, pos: usize) -> u32 {
+        conv.turn_count
+            .saturating_sub(Self::user_turns_after(conv, pos))
+            .max(1)
+    }
+
+    /// 把轮次范围覆盖第 pos 条消息的记忆摘要标记为过时：它们概括的是改写前的内容。
+    /// 摘要（包括合并摘要与置顶摘要）保留到后台重新总结为止
+    fn invalidate_summaries_at(conv: &mut Conversation, pos: usize) {
+        let turn = Self::turn_at(conv, pos);
+        for summary in conv.memory_summaries.iter_mut() {
+            if (summary.turn_range_start..=summary.turn_range_end).contains(&turn) {
+                summary.stale = true;
+            }
+        }
+    }
+
+    // ── Puppeteering (user-authored replies) ──
+
+    /// 角色回复是否由用户亲自撰写；这类回复照常进入上下文，但不计入回复风格统计
+    pub fn is_user_authored(message: &Message) -> bool {
+        message.role == MessageRole::Assistant && message.model == USER_AUTHORED_MODEL_ID
+    }
+
+    /// 以角色身份追加一条由用户撰写的回复
+    pub fn add_user_authored_reply(
+        &self,
+        conversation_id: &str,
+        content: &str,
+        message_type: MessageType,
+    ) -> Result<Message, ChatError> {
+        if content.trim().is_empty() {
+            return Err(ChatError::ValidationError {
+                message: "Reply content cannot be empty".to_string(),
+            });
+        }
+        let msg = Message {
+            message_type,
+            ..Message::new(MessageRole::Assistant, content, USER_AUTHORED_MODEL_ID)
+        };
+        self.add_message(conversation_id, msg.clone())?;
+        Ok(msg)
+    }
+
+    /// 用户改写一条角色回复：内容替换，并标记为用户撰写（原思考内容不再对应，一并清除）
+    pub fn rewrite_reply(
+        &self,
+        conversation_id: &str,
+        message_id: &str,
+        new_content: &str,
+        message_type: MessageType,
+    ) -> Result<(), ChatError> {
+        let mut conv = self.load_open(conversation_id)?;
+        let msg = conv
+            .messages
+            .iter_mut()
+            .find(|m| m.id == message_id && m.role == MessageRole::Assistant)
+            .ok_or_else(|| ChatError::StorageError {
+                message: format!("Reply '{}' not found", message_id),
+            })?;
+        msg.content = new_content.to_string();
+        msg.thinking_content = None;
+        msg.generation_metadata = None;
+        msg.model = USER_AUTHORED_MODEL_ID.to_string();
+        msg.message_type = message_type;
+        msg.timestamp = chrono::Utc::now().timestamp_millis();
+        conv.updated_at = msg.timestamp;
+        self.save_conversation(&conv)
+    }
+
+    /// 写回图片附件的识图描述（按附件ID匹配，其余字段不变）
+    pub fn set_attachment_captions(
+        &self,
+        conversation_id: &str,
+        message_id: &str,
+        captions: &[(String, String)],
     ) -> Result<(), ChatError> {
         let mut conv = self.load_conversation(conversation_id)?;
-        let found = conv.messages.iter_mut().find(|m| m.id == message_id);
-        match found {
-            Some(msg) => {
-                msg.content = new_content.to_string();
-                msg.timestamp = chrono::Utc::now().timestamp_millis();
-                conv.updated_at = chrono::Utc::now().timestamp_millis();
-                self.save_conversation(&conv)
+        let msg = conv
+            .messages
+            .iter_mut()
+            .find(|m| m.id == message_id)
+            .ok_or_else(|| ChatError::StorageError {
+                message: format!("Message '{}' not found", message_id),
+            })?;
+        for (attachment_id, caption) in captions {
+            if let Some(attachment) = msg.attachments.iter_mut().find(|a| &a.id == attachment_id) {
+                attachment.caption = Some(caption.clone());
             }
-            None => Err(ChatError::StorageError {
+        }
+        self.save_conversation(&conv)
+    }
+
+    /// 记录一条消息的语音文件路径（见 tts）
+    pub fn set_audio_path(
+        &self,
+        conversation_id: &str,
+        message_id: &str,
+        audio_path: &str,
+    ) -> Result<(), ChatError> {
+        let mut conv = self.load_conversation(conversation_id)?;
+        let msg = conv
+            .messages
+            .iter_mut()
+            .find(|m| m.id == message_id)
+            .ok_or_else(|| ChatError::StorageError {
                 message: format!("Message '{}' not found", message_id),
-            }),
+            })?;
+        msg.audio_path = Some(audio_path.to_string());
+        self.save_conversation(&conv)
+    }
+
+    /// 收藏 / 取消收藏一条消息；返回更新后的消息（已收束的对话也可以收藏）
+    pub fn set_message_starred(
+        &self,
+        conversation_id: &str,
+        message_id: &str,
+        starred: bool,
+    ) -> Result<Message, ChatError> {
+        self.update_message(conversation_id, message_id, |msg| msg.starred = starred)
+    }
+
+    /// 替换一条消息的表情回应（去掉空白与重复，最多 MAX_REACTIONS 个）；返回更新后的消息
+    pub fn set_message_reactions(
+        &self,
+        conversation_id: &str,
+        message_id: &str,
+        reactions: &[String],
+    ) -> Result<Message, ChatError> {
+        let mut cleaned: Vec<String> = Vec::new();
+        for reaction in reactions.iter().map(|r| r.trim()) {
+            if !reaction.is_empty() && !cleaned.iter().any(|r| r == reaction) {
+                cleaned.push(reaction.to_string());
+            }
         }
+        if cleaned.len() > MAX_REACTIONS {
+            return Err(ChatError::ValidationError {
+                message: format!("At most {} reactions per message", MAX_REACTIONS),
+            });
+        }
+        self.update_message(conversation_id, message_id, |msg| msg.reactions = cleaned)
+    }
+
+    fn update_message(
+        &self,
+        conversation_id: &str,
+        message_id: &str,
+        update: impl FnOnce(&mut Message),
+    ) -> Result<Message, ChatError> {
+        let mut conv = self.load_conversation(conversation_id)?;
+        let msg = conv
+            .messages
+            .iter_mut()
+            .find(|m| m.id == message_id)
+            .ok_or_else(|| ChatError::StorageError {
+                message: format!("Message '{}' not found", message_id),
+            })?;
+        update(msg);
+        let updated = msg.clone();
+        self.save_conversation(&conv)?;
+        Ok(updated)
     }
 
     /// Rollback: delete the target message and all messages after it.
@@ -237,7 +757,7 @@ impl ConversationStore {
         conversation_id: &str,
         message_id: &str,
     ) -> Result<Vec<String>, ChatError> {
-        let mut conv = self.load_conversation(conversation_id)?;
+        let mut conv = self.load_open(conversation_id)?;
         let pos = conv
             .messages
             .iter()
@@ -255,21 +775,1148 @@ impl ConversationStore {
         Ok(deleted_ids)
     }
 
-    /// Update dialogue style for a conversation.
-    pub fn set_dialogue_style(
+    // ── Branches (alternate timelines) ──
+
+    /// 从指定消息分叉出新分支并切换过去：该消息及之前的历史保留，
+    /// 之后的消息随原分支存档。覆盖分叉点之后轮次的记忆摘要不带入新分支。
+    /// 返回切换后的对话（调用方据此同步记忆索引）。
+    pub fn create_branch(
         &self,
         conversation_id: &str,
-        style: DialogueStyle,
-    ) -> Result<(), ChatError> {
-        let mut conv = self.load_conversation(conversation_id)?;
-        conv.dialogue_style = style;
+        from_message_id: &str,
+        name: Option<&str>,
+    ) -> Result<Conversation, ChatError> {
+        let mut conv = self.load_open(conversation_id)?;
+        let pos = conv
+            .messages
+            .iter()
+            .position(|m| m.id == from_message_id)
+            .ok_or_else(|| ChatError::StorageError {
+                message: format!("Message '{}' not found", from_message_id),
+            })?;
+        let now = chrono::Utc::now().timestamp_millis();
+
+        Self::stash_active_branch(&mut conv);
+        let branch_id = uuid::Uuid::new_v4().to_string();
+        let name = name
+            .map(str::trim)
+            .filter(|n| !n.is_empty())
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("分支 {}", conv.branches.len()));
+        conv.branches.push(ConversationBranch {
+            id: branch_id.clone(),
+            name,
+            parent_message_id: Some(from_message_id.to_string()),
+            messages: Vec::new(),
+            turn_count: 0,
+            memory_summaries: Vec::new(),
+            created_at: now,
+            cold_turns: 0,
+        });
+
+        Self::truncate_after(&mut conv, pos);
+        conv.branch_id = branch_id;
+        conv.parent_message_id = Some(from_message_id.to_string());
+        conv.updated_at = now;
+        self.save_conversation(&conv)?;
+        Ok(conv)
+    }
+
+    /// 只保留 pos 及之前的消息，并让轮数与记忆摘要跟随截断后的历史
+    /// （早期消息可能已移入冷存储，轮数按截掉的用户消息扣减而不是重新数）
+    fn truncate_after(conv: &mut Conversation, pos: usize) {
+        let removed = Self::user_turns_after(conv, pos);
+        conv.messages.truncate(pos + 1);
+        conv.turn_count = conv.turn_count.saturating_sub(removed);
+        let turn_count = conv.turn_count;
+        conv.memory_summaries
+            .retain(|s| s.turn_range_end <= turn_count);
+    }
+
+    /// 为从任意消息重新生成做准备：截断（或分叉）到该消息所属的用户输入为止。
+    /// 指定用户消息时保留它本身；指定回复时退到它之前最近的用户消息。
+    /// as_branch 为 true 时原时间线存档为分支，否则就地截断。
+    pub fn prepare_regeneration(
+        &self,
+        conversation_id: &str,
+        message_id: &str,
+        as_branch: bool,
+    ) -> Result<Conversation, ChatError> {
+        let mut conv = self.load_open(conversation_id)?;
+        let pos = conv
+            .messages
+            .iter()
+            .position(|m| m.id == message_id)
+            .ok_or_else(|| ChatError::StorageError {
+                message: format!("Message '{}' not found", message_id),
+            })?;
+        let keep = conv.messages[..=pos]
+            .iter()
+            .rposition(|m| m.role == MessageRole::User)
+            .ok_or_else(|| ChatError::ValidationError {
+                message: "No user message found to regenerate from".to_string(),
+            })?;
+
+        if as_branch {
+            let fork_at = conv.messages[keep].id.clone();
+            return self.create_branch(conversation_id, &fork_at, None);
+        }
+        Self::truncate_after(&mut conv, keep);
         conv.updated_at = chrono::Utc::now().timestamp_millis();
-        self.save_conversation(&conv)
+        self.save_conversation(&conv)?;
+        Ok(conv)
     }
 
-    /// Get the turn count for a conversation.
-    pub fn get_turn_count(&self, conversation_id: &str) -> Result<u32, ChatError> {
+    /// 只含当前分支的对话视图：其他时间线的存档被剥离，供上下文构建等只读场景使用。
+    /// 不要把返回值写回（会丢失其他分支）。
+    pub fn load_active_branch(&self, conversation_id: &str) -> Result<Conversation, ChatError> {
+        let mut conv = self.load_conversation(conversation_id)?;
+        conv.branches.clear();
+        Ok(conv)
+    }
+
+    /// 列出全部分支；首次分叉前只有主线
+    pub fn list_branches(&self, conversation_id: &str) -> Result<Vec<BranchInfo>, ChatError> {
         let conv = self.load_conversation(conversation_id)?;
-        Ok(conv.turn_count)
+        let preview = |messages: &[Message]| {
+            messages
+                .last()
+                .map(|m| text_utils::truncate_chars(&m.content, 50).to_string())
+                .unwrap_or_default()
+        };
+        if conv.branches.is_empty() {
+            return Ok(vec![BranchInfo {
+                id: conv.branch_id.clone(),
+                name: "主线".to_string(),
+                parent_message_id: None,
+                message_count: conv.messages.len() as u32,
+                last_message_preview: preview(&conv.messages),
+                created_at: conv.created_at,
+                is_active: true,
+            }]);
+        }
+        Ok(conv
+            .branches
+            .iter()
+            .map(|b| {
+                let is_active = b.id == conv.branch_id;
+                let messages = if is_active { &conv.messages } else { &b.messages };
+                BranchInfo {
+                    id: b.id.clone(),
+                    name: b.name.clone(),
+                    parent_message_id: b.parent_message_id.clone(),
+                    message_count: messages.len() as u32,
+                    last_message_preview: preview(messages),
+                    created_at: b.created_at,
+                    is_active,
+                }
+            })
+            .collect())
+    }
+
+    /// 切换到指定分支：当前分支存档，目标分支的历史与记忆整体换入。
+    /// 返回切换后的对话（调用方据此同步记忆索引）。
+    pub fn switch_branch(
+        &self,
+        conversation_id: &str,
+        branch_id: &str,
+    ) -> Result<Conversation, ChatError> {
+        let mut conv = self.load_open(conversation_id)?;
+        if conv.branch_id == branch_id {
+            return Ok(conv);
+        }
+        let target = conv
+            .branches
+            .iter()
+            .position(|b| b.id == branch_id)
+            .ok_or_else(|| ChatError::StorageError {
+                message: format!("Branch '{}' not found", branch_id),
+            })?;
+
+        Self::stash_active_branch(&mut conv);
+        let branch = &mut conv.branches[target];
+        let messages = std::mem::take(&mut branch.messages);
+        let memory_summaries = std::mem::take(&mut branch.memory_summaries);
+        let turn_count = branch.turn_count;
+        let cold_turns = branch.cold_turns;
+        let parent_message_id = branch.parent_message_id.clone();
+
+        conv.messages = messages;
+        conv.memory_summaries = memory_summaries;
+        conv.turn_count = turn_count;
+        conv.cold_turns = cold_turns;
+        conv.branch_id = branch_id.to_string();
+        conv.parent_message_id = parent_message_id;
+        conv.updated_at = chrono::Utc::now().timestamp_millis();
+        self.save_conversation(&conv)?;
+        Ok(conv)
+    }
+
+    /// 把当前分支的历史与记忆存入其分支记录（首次分叉时补建主线记录）
+    fn stash_active_branch(conv: &mut Conversation) {
+        if !conv.branches.iter().any(|b| b.id == conv.branch_id) {
+            conv.branches.push(ConversationBranch {
+                id: conv.branch_id.clone(),
+                name: "主线".to_string(),
+                parent_message_id: conv.parent_message_id.clone(),
+                messages: Vec::new(),
+                turn_count: 0,
+                memory_summaries: Vec::new(),
+                created_at: conv.created_at,
+                cold_turns: 0,
+            });
+        }
+        let active_id = conv.branch_id.clone();
+        if let Some(active) = conv.branches.iter_mut().find(|b| b.id == active_id) {
+            active.messages = conv.messages.clone();
+            active.turn_count = conv.turn_count;
+            active.memory_summaries = conv.memory_summaries.clone();
+            active.cold_turns = conv.cold_turns;
+        }
+    }
+
+    /// Update dialogue style for a conversation.
+    pub fn set_dialogue_style(
+        &self,
+        conversation_id: &str,
+        style: DialogueStyle,
+    ) -> Result<(), ChatError> {
+        let mut conv = self.load_conversation(conversation_id)?;
+        conv.dialogue_style = style;
+        conv.updated_at = chrono::Utc::now().timestamp_millis();
+        self.save_conversation(&conv)
+    }
+
+    /// Switch a conversation between chat and co-authoring mode.
+    pub fn set_conversation_mode(
+        &self,
+        conversation_id: &str,
+        mode: ConversationMode,
+    ) -> Result<(), ChatError> {
+        let mut conv = self.load_conversation(conversation_id)?;
+        conv.mode = mode;
+        conv.updated_at = chrono::Utc::now().timestamp_millis();
+        self.save_conversation(&conv)
+    }
+
+    /// 设置对话的叙述视角（下一条回复生效）
+    pub fn set_narration_perspective(
+        &self,
+        conversation_id: &str,
+        perspective: NarrationPerspective,
+    ) -> Result<(), ChatError> {
+        let mut conv = self.load_conversation(conversation_id)?;
+        conv.narration = perspective;
+        conv.updated_at = chrono::Utc::now().timestamp_millis();
+        self.save_conversation(&conv)
+    }
+
+    /// 设置对话的上下文增强层开关（下一轮回复生效）
+    pub fn set_context_layers(
+        &self,
+        conversation_id: &str,
+        layers: ContextLayers,
+    ) -> Result<(), ChatError> {
+        let mut conv = self.load_conversation(conversation_id)?;
+        conv.context_layers = layers;
+        conv.updated_at = chrono::Utc::now().timestamp_millis();
+        self.save_conversation(&conv)
+    }
+
+    /// 设置对话的回复风格偏好（下一轮回复生效）
+    pub fn set_response_style(
+        &self,
+        conversation_id: &str,
+        style: ResponseStyle,
+    ) -> Result<(), ChatError> {
+        let mut conv = self.load_conversation(conversation_id)?;
+        conv.response_style = style;
+        conv.updated_at = chrono::Utc::now().timestamp_millis();
+        self.save_conversation(&conv)
+    }
+
+    /// 设置对话使用的用户人设（None 为不使用人设）
+    pub fn set_persona(
+        &self,
+        conversation_id: &str,
+        persona_id: Option<String>,
+    ) -> Result<(), ChatError> {
+        let mut conv = self.load_conversation(conversation_id)?;
+        conv.persona_id = persona_id;
+        conv.updated_at = chrono::Utc::now().timestamp_millis();
+        self.save_conversation(&conv)
+    }
+
+    /// 设置对话的标签、文件夹、置顶与归档（已收束的对话也可以整理）；
+    /// 标签与文件夹名去掉首尾空白，空标签与重复标签丢弃，空文件夹名视为未归类。
+    /// 不更新 updated_at：整理对话不应改变它在列表中的先后
+    pub fn set_organization(
+        &self,
+        conversation_id: &str,
+        organization: ConversationOrganization,
+    ) -> Result<ConversationOrganization, ChatError> {
+        let mut tags: Vec<String> = Vec::new();
+        for tag in organization.tags.iter().map(|t| t.trim()) {
+            if !tag.is_empty() && !tags.iter().any(|t| t == tag) {
+                tags.push(tag.to_string());
+            }
+        }
+        let normalized = ConversationOrganization {
+            tags,
+            folder: organization
+                .folder
+                .map(|f| f.trim().to_string())
+                .filter(|f| !f.is_empty()),
+            pinned: organization.pinned,
+            archived: organization.archived,
+        };
+        let mut conv = self.load_conversation(conversation_id)?;
+        conv.organization = normalized.clone();
+        self.save_conversation(&conv)?;
+        Ok(normalized)
+    }
+
+    /// 标记对话已收束：此后只读
+    pub fn mark_closed(&self, conversation_id: &str, closed_at: i64) -> Result<(), ChatError> {
+        let mut conv = self.load_open(conversation_id)?;
+        conv.closed_at = Some(closed_at);
+        conv.updated_at = closed_at;
+        self.save_conversation(&conv)
+    }
+
+    /// 设置对话的思考内容保留策略（下一次维护时生效）
+    pub fn set_thinking_retention(
+        &self,
+        conversation_id: &str,
+        policy: ThinkingRetention,
+    ) -> Result<(), ChatError> {
+        let mut conv = self.load_conversation(conversation_id)?;
+        conv.thinking_retention = policy;
+        conv.updated_at = chrono::Utc::now().timestamp_millis();
+        self.save_conversation(&conv)
+    }
+
+    /// 按保留策略清除过期的 thinking_content，返回清除的消息数（不修改 updated_at）
+    /// 第 N 条用户消息及其后的回复属于第 N 轮；轮次与 turn_at 一样从 turn_count 倒推，
+    /// 早期消息移入冷存储后仍与记忆摘要的轮次范围对得上
+    pub fn strip_expired_thinking(conv: &mut Conversation) -> u32 {
+        // 该轮次及之前的思考内容需要清除
+        let expire_through = match conv.thinking_retention {
+            ThinkingRetention::KeepAll => return 0,
+            ThinkingRetention::KeepLastTurns(n) => conv.turn_count.saturating_sub(n),
+            ThinkingRetention::DiscardAfterSummary => conv
+                .memory_summaries
+                .iter()
+                .map(|s| s.turn_range_end)
+                .max()
+                .unwrap_or(0),
+        };
+
+        let mut turn = conv.turn_count;
+        let mut stripped = 0u32;
+        for msg in conv.messages.iter_mut().rev() {
+            if turn.max(1) <= expire_through && msg.thinking_content.take().is_some() {
+                stripped += 1;
+            }
+            if msg.role == MessageRole::User {
+                turn = turn.saturating_sub(1);
+            }
+        }
+        stripped
+    }
+
+    /// 维护任务：对单个对话执行思考内容保留策略
+    pub fn apply_thinking_retention(&self, conversation_id: &str) -> Result<u32, ChatError> {
+        let mut conv = self.load_conversation(conversation_id)?;
+        let stripped = Self::strip_expired_thinking(&mut conv);
+        if stripped > 0 {
+            self.save_conversation(&conv)?;
+        }
+        Ok(stripped)
+    }
+
+    /// Get the turn count for a conversation.
+    pub fn get_turn_count(&self, conversation_id: &str) -> Result<u32, ChatError> {
+        let conv = self.load_conversation(conversation_id)?;
+        Ok(conv.turn_count)
+    }
+
+    // ── 离线发件箱：outbox/{entry_id}.json ──
+
+    fn outbox_dir(&self) -> Result<PathBuf, ChatError> {
+        let dir = PathBuf::from(&self.base_path).join("outbox");
+        if !dir.exists() {
+            fs::create_dir_all(&dir).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to create outbox directory: {}", e),
+            })?;
+        }
+        Ok(dir)
+    }
+
+    /// 离线时把一次发送放入发件箱（状态 Queued）。对话须存在且未收束，沙盒对话不入队
+    pub fn enqueue_outbox(
+        &self,
+        conversation_id: &str,
+        content: &str,
+        attachments: Vec<MessageAttachment>,
+        model: &str,
+        enable_thinking: bool,
+    ) -> Result<OutboxEntry, ChatError> {
+        if Self::is_sandbox(conversation_id) {
+            return Err(ChatError::ValidationError {
+                message: "Sandbox conversations cannot queue offline messages".to_string(),
+            });
+        }
+        self.load_open(conversation_id)?;
+        let entry = OutboxEntry {
+            id: uuid::Uuid::new_v4().to_string(),
+            conversation_id: conversation_id.to_string(),
+            content: content.to_string(),
+            attachments,
+            model: model.to_string(),
+            enable_thinking,
+            status: OutboxStatus::Queued,
+            queued_at: chrono::Utc::now().timestamp_millis(),
+            last_error: None,
+        };
+        self.save_outbox_entry(&entry)?;
+        Ok(entry)
+    }
+
+    /// 写入（新建或更新）发件箱条目
+    pub fn save_outbox_entry(&self, entry: &OutboxEntry) -> Result<(), ChatError> {
+        let path = self.outbox_dir()?.join(format!("{}.json", entry.id));
+        let json = serde_json::to_string(entry).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to serialize outbox entry: {}", e),
+        })?;
+        fs::write(&path, json).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to write outbox entry: {}", e),
+        })
+    }
+
+    /// 发件箱中的全部条目（按入队先后）
+    pub fn list_outbox(&self) -> Vec<OutboxEntry> {
+        let entries = match self.outbox_dir().and_then(|dir| {
+            fs::read_dir(&dir).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to read outbox directory: {}", e),
+            })
+        }) {
+            Ok(entries) => entries,
+            Err(_) => return Vec::new(),
+        };
+        let mut outbox: Vec<OutboxEntry> = entries
+            .filter_map(|entry| {
+                let json = fs::read_to_string(entry.ok()?.path()).ok()?;
+                serde_json::from_str(&json).ok()
+            })
+            .collect();
+        outbox.sort_by_key(|e| e.queued_at);
+        outbox
+    }
+
+    /// 移出发件箱（重放成功或用户放弃）
+    pub fn remove_outbox_entry(&self, entry_id: &str) -> Result<(), ChatError> {
+        let path = self.outbox_dir()?.join(format!("{}.json", entry_id));
+        if path.exists() {
+            fs::remove_file(&path).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to delete outbox entry: {}", e),
+            })?;
+        }
+        Ok(())
+    }
+
+    // ── 主动联系设置：check_ins/{conversation_id}.json ──
+
+    fn check_ins_dir(&self) -> Result<PathBuf, ChatError> {
+        let dir = PathBuf::from(&self.base_path).join("check_ins");
+        if !dir.exists() {
+            fs::create_dir_all(&dir).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to create check-in directory: {}", e),
+            })?;
+        }
+        Ok(dir)
+    }
+
+    /// 保存对话的主动联系设置。对话须存在，沙盒对话不支持，沉默时长至少 1 小时；
+    /// 已有设置时保留上次主动联系的时间
+    pub fn set_check_in_schedule(&self, schedule: &CheckInSchedule) -> Result<(), ChatError> {
+        if Self::is_sandbox(&schedule.conversation_id) {
+            return Err(ChatError::ValidationError {
+                message: "Sandbox conversations cannot schedule check-ins".to_string(),
+            });
+        }
+        if schedule.silence_hours == 0 {
+            return Err(ChatError::ValidationError {
+                message: "Check-in silence must be at least 1 hour".to_string(),
+            });
+        }
+        self.load_conversation(&schedule.conversation_id)?;
+        let mut schedule = schedule.clone();
+        if let Some(existing) = self.load_check_in_schedule(&schedule.conversation_id) {
+            schedule.last_sent_at = schedule.last_sent_at.or(existing.last_sent_at);
+        }
+        self.write_check_in_schedule(&schedule)
+    }
+
+    fn write_check_in_schedule(&self, schedule: &CheckInSchedule) -> Result<(), ChatError> {
+        let path = self
+            .check_ins_dir()?
+            .join(format!("{}.json", schedule.conversation_id));
+        let json = serde_json::to_string(schedule).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to serialize check-in schedule: {}", e),
+        })?;
+        fs::write(&path, json).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to write check-in schedule: {}", e),
+        })
+    }
+
+    /// 对话的主动联系设置；未设置时为 None
+    pub fn load_check_in_schedule(&self, conversation_id: &str) -> Option<CheckInSchedule> {
+        let path = self
+            .check_ins_dir()
+            .ok()?
+            .join(format!("{}.json", conversation_id));
+        let json = fs::read_to_string(path).ok()?;
+        serde_json::from_str(&json).ok()
+    }
+
+    /// 全部对话的主动联系设置
+    pub fn list_check_in_schedules(&self) -> Vec<CheckInSchedule> {
+        let entries = match self.check_ins_dir().and_then(|dir| {
+            fs::read_dir(&dir).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to read check-in directory: {}", e),
+            })
+        }) {
+            Ok(entries) => entries,
+            Err(_) => return Vec::new(),
+        };
+        entries
+            .filter_map(|entry| {
+                let json = fs::read_to_string(entry.ok()?.path()).ok()?;
+                serde_json::from_str(&json).ok()
+            })
+            .collect()
+    }
+
+    /// 记录一次主动联系（对方回复之前不再主动联系）
+    pub fn mark_check_in_sent(&self, conversation_id: &str, sent_at: i64) -> Result<(), ChatError> {
+        let Some(mut schedule) = self.load_check_in_schedule(conversation_id) else {
+            return Ok(());
+        };
+        schedule.last_sent_at = Some(sent_at);
+        self.write_check_in_schedule(&schedule)
+    }
+
+    pub fn remove_check_in_schedule(&self, conversation_id: &str) -> Result<(), ChatError> {
+        let path = self
+            .check_ins_dir()?
+            .join(format!("{}.json", conversation_id));
+        if path.exists() {
+            fs::remove_file(&path).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to delete check-in schedule: {}", e),
+            })?;
+        }
+        Ok(())
+    }
+}
+
+// ── 异步接口：生成管线里的读写放到 blocking 线程池，慢速闪存不卡住运行时 ──
+
+impl ConversationStore {
+    async fn offload<T, F>(&self, label: &'static str, f: F) -> T
+    where
+        F: FnOnce(&ConversationStore) -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let store = self.clone();
+        blocking_pool::offload(label, move || f(&store)).await
+    }
+
+    pub async fn load_conversation_async(&self, id: &str) -> Result<Conversation, ChatError> {
+        let id = id.to_string();
+        self.offload("conversation_load", move |s| s.load_conversation(&id))
+            .await
+    }
+
+    pub async fn load_open_async(&self, id: &str) -> Result<Conversation, ChatError> {
+        let id = id.to_string();
+        self.offload("conversation_load", move |s| s.load_open(&id)).await
+    }
+
+    pub async fn load_active_branch_async(
+        &self,
+        conversation_id: &str,
+    ) -> Result<Conversation, ChatError> {
+        let id = conversation_id.to_string();
+        self.offload("conversation_load", move |s| s.load_active_branch(&id))
+            .await
+    }
+
+    pub async fn add_message_async(
+        &self,
+        conversation_id: &str,
+        message: Message,
+    ) -> Result<(), ChatError> {
+        let id = conversation_id.to_string();
+        self.offload("conversation_write", move |s| s.add_message(&id, message))
+            .await
+    }
+
+    pub async fn increment_turn_count_async(&self, conversation_id: &str) -> Result<(), ChatError> {
+        let id = conversation_id.to_string();
+        self.offload("conversation_write", move |s| s.increment_turn_count(&id))
+            .await
+    }
+
+    pub async fn mark_closed_async(
+        &self,
+        conversation_id: &str,
+        closed_at: i64,
+    ) -> Result<(), ChatError> {
+        let id = conversation_id.to_string();
+        self.offload("conversation_write", move |s| s.mark_closed(&id, closed_at))
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn make_card() -> CharacterCard {
+        CharacterCard {
+            name: "小雨".to_string(),
+            system_prompt: "你是小雨".to_string(),
+            greeting: "你来啦".to_string(),
+        }
+    }
+
+    fn make_turn(content: &str, thinking: Option<&str>) -> Vec<Message> {
+        let base = Message {
+            timestamp: 0,
+            ..Message::new(MessageRole::User, content, "glm-4.7")
+        };
+        let reply = Message {
+            id: uuid::Uuid::new_v4().to_string(),
+            role: MessageRole::Assistant,
+            thinking_content: thinking.map(|t| t.to_string()),
+            ..base.clone()
+        };
+        vec![base, reply]
+    }
+
+    #[test]
+    fn test_thinking_retention_policies() {
+        let tmp = TempDir::new().unwrap();
+        let store = ConversationStore::new(tmp.path().to_str().unwrap());
+        let mut conv = store.create_conversation();
+        for i in 0..4 {
+            conv.messages.extend(make_turn(&format!("第{}轮", i), Some("思考")));
+        }
+        conv.turn_count = 4;
+        store.save_conversation(&conv).unwrap();
+
+        // 默认全部保留
+        assert_eq!(store.apply_thinking_retention(&conv.id).unwrap(), 0);
+
+        store
+            .set_thinking_retention(&conv.id, ThinkingRetention::KeepLastTurns(1))
+            .unwrap();
+        assert_eq!(store.apply_thinking_retention(&conv.id).unwrap(), 3);
+        let loaded = store.load_conversation(&conv.id).unwrap();
+        assert!(loaded.messages[7].thinking_content.is_some());
+        assert!(loaded.messages[5].thinking_content.is_none());
+
+        let mut summarized = conv.clone();
+        summarized.thinking_retention = ThinkingRetention::DiscardAfterSummary;
+        summarized.memory_summaries = vec![MemorySummary {
+            id: "s1".to_string(),
+            summary: String::new(),
+            core_facts: vec![],
+            turn_range_start: 1,
+            turn_range_end: 2,
+            created_at: 0,
+            keywords: vec![],
+            compression_generation: 0,
+            context_card: None,
+            fact_tiers: vec![],
+            linked_fact_ids: vec![],
+            pinned: false,
+            pinned_facts: Vec::new(),
+            stale: false,
+        }];
+        assert_eq!(ConversationStore::strip_expired_thinking(&mut summarized), 2);
+        assert!(summarized.messages[5].thinking_content.is_some());
+
+        // 前 10 轮已移入冷存储：剩下两轮是第 11、12 轮，摘要覆盖到第 11 轮
+        let mut rolled = store.create_conversation();
+        rolled.messages.extend(make_turn("第11轮", Some("思考")));
+        rolled.messages.extend(make_turn("第12轮", Some("思考")));
+        rolled.turn_count = 12;
+        rolled.cold_turns = 10;
+        rolled.thinking_retention = ThinkingRetention::DiscardAfterSummary;
+        rolled.memory_summaries = vec![MemorySummary {
+            turn_range_end: 11,
+            ..summarized.memory_summaries[0].clone()
+        }];
+        assert_eq!(ConversationStore::strip_expired_thinking(&mut rolled), 1);
+        assert!(rolled.messages[1].thinking_content.is_none());
+        assert!(rolled.messages[3].thinking_content.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_turn_lock_rejects_overlap_and_serializes_waiters() {
+        let id = "turn-lock-test";
+        let first = ConversationStore::try_lock_turn(id).unwrap();
+        assert!(ConversationStore::try_lock_turn(id).is_err(), "连点发送被拒绝");
+        assert!(ConversationStore::try_lock_turn("turn-lock-other").is_ok(), "其他对话不受影响");
+
+        let waiter = tokio::spawn(async move {
+            let _turn = ConversationStore::lock_turn(id).await;
+        });
+        tokio::task::yield_now().await;
+        assert!(!waiter.is_finished(), "排队者等待当前生成结束");
+        drop(first);
+        waiter.await.unwrap();
+        assert!(ConversationStore::try_lock_turn(id).is_ok());
+    }
+
+    #[test]
+    fn test_lock_all_turns_refuses_while_one_is_generating() {
+        let tmp = TempDir::new().unwrap();
+        let store = ConversationStore::new(tmp.path().to_str().unwrap());
+        let (a, b) = (store.create_conversation(), store.create_conversation());
+        store.save_conversation(&a).unwrap();
+        store.save_conversation(&b).unwrap();
+
+        let turn = ConversationStore::try_lock_turn(&b.id).unwrap();
+        assert!(store.try_lock_all_turns().is_err());
+        drop(turn);
+        let all = store.try_lock_all_turns().unwrap();
+        assert_eq!(all.len(), 2);
+        assert!(ConversationStore::try_lock_turn(&a.id).is_err(), "整体操作期间不能开始生成");
+    }
+
+    #[tokio::test]
+    async fn test_async_interface_matches_blocking_store() {
+        let tmp = TempDir::new().unwrap();
+        let store = ConversationStore::new(tmp.path().to_str().unwrap());
+        let conv = store.create_conversation();
+        store.save_conversation(&conv).unwrap();
+        for message in make_turn("你好", None) {
+            store.add_message_async(&conv.id, message).await.unwrap();
+        }
+        store.increment_turn_count_async(&conv.id).await.unwrap();
+
+        let loaded = store.load_active_branch_async(&conv.id).await.unwrap();
+        assert_eq!(loaded.messages.len(), conv.messages.len() + 2);
+        assert_eq!(loaded.turn_count, conv.turn_count + 1);
+        store.mark_closed_async(&conv.id, 1).await.unwrap();
+        assert!(store.load_open_async(&conv.id).await.is_err());
+    }
+
+    #[test]
+    fn test_truncated_conversation_falls_back_to_backup() {
+        let tmp = TempDir::new().unwrap();
+        let store = ConversationStore::new(tmp.path().to_str().unwrap());
+        let mut conv = store.create_conversation();
+        conv.messages.extend(make_turn("第一轮", None));
+        store.save_conversation(&conv).unwrap();
+        conv.messages.extend(make_turn("第二轮", None));
+        store.save_conversation(&conv).unwrap();
+
+        let path = store.conversation_path(&conv.id).unwrap();
+        let data = fs::read(&path).unwrap();
+        fs::write(&path, &data[..data.len() / 2]).unwrap();
+
+        let loaded = store.load_conversation(&conv.id).unwrap();
+        assert_eq!(loaded.messages.len(), conv.messages.len() - 2);
+
+        store.delete_conversation(&conv.id).unwrap();
+        let conv_dir = tmp.path().join("conversations");
+        assert_eq!(fs::read_dir(conv_dir).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_sandbox_conversation_stays_in_memory() {
+        let tmp = TempDir::new().unwrap();
+        let store = ConversationStore::new(tmp.path().to_str().unwrap());
+
+        let conv = store.create_sandbox_conversation(&make_card()).unwrap();
+        assert!(ConversationStore::is_sandbox(&conv.id));
+        assert_eq!(conv.messages.len(), 2);
+        assert_eq!(conv.messages[0].role, MessageRole::System);
+
+        store
+            .add_message(
+                &conv.id,
+                Message {
+                    id: "u1".to_string(),
+                    timestamp: 0,
+                    ..Message::new(MessageRole::User, "嗨", "glm-4.7")
+                },
+            )
+            .unwrap();
+        assert_eq!(store.load_conversation(&conv.id).unwrap().messages.len(), 3);
+        assert!(store.list_conversations().is_empty());
+        let conv_dir = tmp.path().join("conversations");
+        assert_eq!(fs::read_dir(conv_dir).unwrap().count(), 0);
+
+        store.delete_conversation(&conv.id).unwrap();
+        assert!(store.load_conversation(&conv.id).is_err());
+    }
+
+    #[test]
+    fn test_branch_fork_and_switch() {
+        let tmp = TempDir::new().unwrap();
+        let store = ConversationStore::new(tmp.path().to_str().unwrap());
+        let mut conv = store.create_conversation();
+        for i in 0..3 {
+            conv.messages.extend(make_turn(&format!("第{}轮", i), None));
+        }
+        conv.turn_count = 3;
+        conv.memory_summaries = vec![MemorySummary {
+            id: "late".to_string(),
+            summary: "第三轮的事".to_string(),
+            core_facts: vec![],
+            turn_range_start: 3,
+            turn_range_end: 3,
+            created_at: 0,
+            keywords: vec![],
+            compression_generation: 0,
+            context_card: None,
+            fact_tiers: vec![],
+            linked_fact_ids: vec![],
+            pinned: false,
+            pinned_facts: Vec::new(),
+            stale: false,
+        }];
+        store.save_conversation(&conv).unwrap();
+        assert_eq!(store.list_branches(&conv.id).unwrap().len(), 1);
+
+        // 从第一轮的回复处分叉
+        let fork_at = conv.messages[1].id.clone();
+        let forked = store.create_branch(&conv.id, &fork_at, Some("另一种结局")).unwrap();
+        assert_eq!(forked.messages.len(), 2);
+        assert_eq!(forked.turn_count, 1);
+        assert!(forked.memory_summaries.is_empty());
+        assert_eq!(forked.parent_message_id.as_deref(), Some(fork_at.as_str()));
+        store
+            .add_message(&conv.id, make_turn("新的走向", None).remove(0))
+            .unwrap();
+
+        let branches = store.list_branches(&conv.id).unwrap();
+        assert_eq!(branches.len(), 2);
+        let active = branches.iter().find(|b| b.is_active).unwrap();
+        assert_eq!(active.name, "另一种结局");
+        assert_eq!(active.message_count, 3);
+        assert!(store.load_active_branch(&conv.id).unwrap().branches.is_empty());
+
+        // 切回主线：历史与记忆原样恢复，分支上的新消息不混入
+        let main = store.switch_branch(&conv.id, MAIN_BRANCH_ID).unwrap();
+        assert_eq!(main.messages.len(), 6);
+        assert_eq!(main.turn_count, 3);
+        assert_eq!(main.memory_summaries.len(), 1);
+        assert!(main.messages.iter().all(|m| m.content != "新的走向"));
+
+        let back = store.switch_branch(&conv.id, &active.id).unwrap();
+        assert_eq!(back.messages.last().unwrap().content, "新的走向");
+        assert!(store.switch_branch(&conv.id, "missing").is_err());
+    }
+
+    #[test]
+    fn test_prepare_regeneration_from_earlier_reply() {
+        let tmp = TempDir::new().unwrap();
+        let store = ConversationStore::new(tmp.path().to_str().unwrap());
+        let mut conv = store.create_conversation();
+        for i in 0..3 {
+            conv.messages.extend(make_turn(&format!("第{}轮", i), None));
+        }
+        conv.turn_count = 3;
+        store.save_conversation(&conv).unwrap();
+
+        // 指定第二轮的回复：退到第二轮的用户消息
+        let truncated = store
+            .prepare_regeneration(&conv.id, &conv.messages[3].id, false)
+            .unwrap();
+        assert_eq!(truncated.messages.len(), 3);
+        assert_eq!(truncated.messages.last().unwrap().content, "第1轮");
+        assert_eq!(truncated.turn_count, 2);
+        assert!(truncated.branches.is_empty());
+
+        // 以分支方式：原时间线整体保留
+        let forked = store
+            .prepare_regeneration(&conv.id, &conv.messages[0].id, true)
+            .unwrap();
+        assert_eq!(forked.messages.len(), 1);
+        assert_eq!(forked.turn_count, 1);
+        assert_eq!(forked.branches.len(), 2);
+        assert!(store.prepare_regeneration(&conv.id, "missing", false).is_err());
+    }
+
+    #[test]
+    fn test_regenerated_replies_become_alternatives() {
+        let tmp = TempDir::new().unwrap();
+        let store = ConversationStore::new(tmp.path().to_str().unwrap());
+        let mut conv = store.create_conversation();
+        conv.messages.extend(make_turn("你好", None));
+        conv.messages[1].content = "第一版".to_string();
+        store.save_conversation(&conv).unwrap();
+        let reply_id = conv.messages[1].id.clone();
+
+        let mut second = make_turn("", None).remove(1);
+        second.content = "第二版".to_string();
+        let saved = store.add_reply_alternative(&conv.id, second).unwrap();
+        assert_eq!(saved.id, reply_id);
+        assert_eq!((saved.content.as_str(), saved.selected_alternative), ("第二版", 1));
+        assert_eq!(saved.alternatives.len(), 2);
+
+        // 切回第一版；当前版本的编辑先写回
+        store.edit_message(&conv.id, &reply_id, "第二版（改）").unwrap();
+        let first = store.select_alternative(&conv.id, &reply_id, 0).unwrap();
+        assert_eq!(first.content, "第一版");
+        let loaded = store.load_conversation(&conv.id).unwrap();
+        assert_eq!(loaded.messages.len(), 2);
+        assert_eq!(loaded.messages[1].alternatives[1].content, "第二版（改）");
+        assert!(store.select_alternative(&conv.id, &reply_id, 2).is_err());
+
+        // 末尾是用户消息时作为新回复追加
+        store.add_message(&conv.id, make_turn("再见", None).remove(0)).unwrap();
+        store
+            .add_reply_alternative(&conv.id, make_turn("", None).remove(1))
+            .unwrap();
+        assert_eq!(store.load_conversation(&conv.id).unwrap().messages.len(), 4);
+    }
+
+    #[test]
+    fn test_star_and_react_to_message() {
+        let tmp = TempDir::new().unwrap();
+        let store = ConversationStore::new(tmp.path().to_str().unwrap());
+        let mut conv = store.create_conversation();
+        conv.messages.extend(make_turn("你好", None));
+        store.save_conversation(&conv).unwrap();
+        let reply_id = conv.messages[1].id.clone();
+
+        assert!(store.set_message_starred(&conv.id, &reply_id, true).unwrap().starred);
+        let reactions: Vec<String> = ["❤️", " 😂 ", "❤️", ""].iter().map(|r| r.to_string()).collect();
+        let reacted = store.set_message_reactions(&conv.id, &reply_id, &reactions).unwrap();
+        assert_eq!(reacted.reactions, vec!["❤️".to_string(), "😂".to_string()]);
+        let too_many: Vec<String> = (0..=MAX_REACTIONS).map(|i| i.to_string()).collect();
+        assert!(store.set_message_reactions(&conv.id, &reply_id, &too_many).is_err());
+        assert!(store.set_message_starred(&conv.id, "missing", true).is_err());
+
+        let loaded = store.load_conversation(&conv.id).unwrap();
+        assert!(loaded.messages[1].starred);
+        assert_eq!(loaded.messages[1].reactions.len(), 2);
+    }
+
+    #[test]
+    fn test_edit_history_undo_and_summary_invalidation() {
+        let tmp = TempDir::new().unwrap();
+        let store = ConversationStore::new(tmp.path().to_str().unwrap());
+        let mut conv = store.create_conversation();
+        for i in 0..3 {
+            conv.messages.extend(make_turn(&format!("第{}轮", i), None));
+        }
+        conv.turn_count = 3;
+        let summary = |id: &str, start: u32, end: u32| MemorySummary {
+            id: id.to_string(),
+            summary: String::new(),
+            core_facts: vec![],
+            turn_range_start: start,
+            turn_range_end: end,
+            created_at: 0,
+            keywords: vec![],
+            compression_generation: 0,
+            context_card: None,
+            fact_tiers: vec![],
+            linked_fact_ids: vec![],
+            pinned: false,
+            pinned_facts: Vec::new(),
+            stale: false,
+        };
+        conv.memory_summaries = vec![summary("s1", 1, 1), summary("s2", 2, 3)];
+        store.save_conversation(&conv).unwrap();
+        let original = conv.messages[3].content.clone();
+        let reply_id = conv.messages[3].id.clone();
+
+        // 第 2 轮的回复被改写两次：覆盖第 2 轮的摘要标记过时（不删除），之前的不受影响
+        store.edit_message(&conv.id, &reply_id, "改一").unwrap();
+        let edited = store.edit_message(&conv.id, &reply_id, "改二").unwrap();
+        assert_eq!(edited.messages[3].content, "改二");
+        let history: Vec<&str> = edited.messages[3]
+            .edit_history
+            .iter()
+            .map(|e| e.content.as_str())
+            .collect();
+        assert_eq!(history, vec![original.as_str(), "改一"]);
+        let stale: Vec<(&str, bool)> = edited
+            .memory_summaries
+            .iter()
+            .map(|s| (s.id.as_str(), s.stale))
+            .collect();
+        assert_eq!(stale, vec![("s1", false), ("s2", true)]);
+
+        let undone = store.undo_edit(&conv.id, &reply_id).unwrap();
+        assert_eq!(undone.messages[3].content, "改一");
+        store.undo_edit(&conv.id, &reply_id).unwrap();
+        let loaded = store.load_conversation(&conv.id).unwrap();
+        assert_eq!(loaded.messages[3].content, original);
+        assert_eq!(loaded.messages[3].timestamp, conv.messages[3].timestamp);
+        assert!(loaded.messages[3].edit_history.is_empty());
+        assert!(store.undo_edit(&conv.id, &reply_id).is_err());
+
+        // 第 1 轮移入冷存储后，轮次仍从 turn_count 倒推：改第 2 轮不会误伤第 1 轮的摘要
+        let mut rolled = loaded;
+        rolled.messages.drain(..2);
+        for s in rolled.memory_summaries.iter_mut() {
+            s.stale = false;
+        }
+        store.save_conversation(&rolled).unwrap();
+        assert_eq!(ConversationStore::turn_at(&rolled, 1), 2);
+        let edited = store.edit_message(&conv.id, &reply_id, "改三").unwrap();
+        assert!(!edited.memory_summaries[0].stale);
+        assert!(edited.memory_summaries[1].stale);
+    }
+
+    #[test]
+    fn test_filtered_list_by_organization() {
+        let tmp = TempDir::new().unwrap();
+        let store = ConversationStore::new(tmp.path().to_str().unwrap());
+        let mut ids = Vec::new();
+        for (i, title) in ["雨夜侦探", "星港日常", "旧城往事"].iter().enumerate() {
+            let mut conv = store.create_conversation();
+            conv.title = title.to_string();
+            conv.updated_at = i as i64;
+            store.save_conversation(&conv).unwrap();
+            ids.push(conv.id);
+        }
+        let org = |tags: &[&str], folder: Option<&str>, pinned: bool, archived: bool| {
+            ConversationOrganization {
+                tags: tags.iter().map(|t| t.to_string()).collect(),
+                folder: folder.map(|f| f.to_string()),
+                pinned,
+                archived,
+            }
+        };
+        let saved = store
+            .set_organization(&ids[0], org(&["悬疑", " 悬疑 ", ""], Some(" 长篇 "), true, false))
+            .unwrap();
+        assert_eq!(saved, org(&["悬疑"], Some("长篇"), true, false));
+        store.set_organization(&ids[1], org(&["日常"], Some("长篇"), false, false)).unwrap();
+        store.set_organization(&ids[2], org(&["悬疑"], None, false, true)).unwrap();
+
+        // 置顶在前，其余按更新时间倒序；归档的不出现
+        let active = store.list_conversations_filtered(&ConversationFilter::default());
+        let titles: Vec<&str> = active.iter().map(|s| s.title.as_str()).collect();
+        assert_eq!(titles, vec!["雨夜侦探", "星港日常"]);
+
+        let archived = store.list_conversations_filtered(&ConversationFilter {
+            archived: true,
+            tags: vec!["悬疑".to_string()],
+            ..ConversationFilter::default()
+        });
+        assert_eq!(archived.len(), 1);
+        assert_eq!(archived[0].id, ids[2]);
+
+        let by_query = store.list_conversations_filtered(&ConversationFilter {
+            folder: Some("长篇".to_string()),
+            query: "星港".to_string(),
+            ..ConversationFilter::default()
+        });
+        assert_eq!(by_query.len(), 1);
+        assert_eq!(store.list_folders(), vec!["长篇".to_string()]);
+        assert_eq!(store.list_tags(), vec!["悬疑".to_string(), "日常".to_string()]);
+    }
+
+    #[test]
+    fn test_user_authored_replies_are_marked() {
+        let tmp = TempDir::new().unwrap();
+        let store = ConversationStore::new(tmp.path().to_str().unwrap());
+        let mut conv = store.create_conversation();
+        conv.messages.extend(make_turn("你好", Some("想想")));
+        store.save_conversation(&conv).unwrap();
+
+        let reply_id = conv.messages[1].id.clone();
+        store
+            .rewrite_reply(&conv.id, &reply_id, "（歪头）嗯？", MessageType::Mixed)
+            .unwrap();
+        let added = store
+            .add_user_authored_reply(&conv.id, "我在听", MessageType::Say)
+            .unwrap();
+        assert!(store.add_user_authored_reply(&conv.id, "  ", MessageType::Say).is_err());
+        assert!(store
+            .rewrite_reply(&conv.id, &conv.messages[0].id, "改用户消息", MessageType::Say)
+            .is_err());
+
+        let loaded = store.load_conversation(&conv.id).unwrap();
+        assert!(!ConversationStore::is_user_authored(&loaded.messages[0]));
+        assert!(ConversationStore::is_user_authored(&loaded.messages[1]));
+        assert!(loaded.messages[1].thinking_content.is_none());
+        assert_eq!(loaded.messages[2].id, added.id);
+        assert!(ConversationStore::is_user_authored(&loaded.messages[2]));
+    }
+
+    #[test]
+    fn test_closed_conversation_is_read_only() {
+        let tmp = TempDir::new().unwrap();
+        let store = ConversationStore::new(tmp.path().to_str().unwrap());
+        let mut conv = store.create_conversation();
+        conv.messages.extend(make_turn("再见", None));
+        store.save_conversation(&conv).unwrap();
+
+        store.mark_closed(&conv.id, 1_700_000_000_000).unwrap();
+        let reply_id = conv.messages[1].id.clone();
+        assert!(matches!(
+            store.add_user_authored_reply(&conv.id, "还想说", MessageType::Say),
+            Err(ChatError::ValidationError { .. })
+        ));
+        assert!(store.edit_message(&conv.id, &reply_id, "改").is_err());
+        assert!(store.rollback_to_message(&conv.id, &reply_id).is_err());
+        assert!(store.mark_closed(&conv.id, 1_700_000_000_001).is_err());
+
+        let loaded = store.load_conversation(&conv.id).unwrap();
+        assert_eq!(loaded.closed_at, Some(1_700_000_000_000));
+        assert_eq!(loaded.messages.len(), 2);
+    }
+
+    #[test]
+    fn test_outbox_queues_in_order_and_follows_conversation() {
+        let tmp = TempDir::new().unwrap();
+        let store = ConversationStore::new(tmp.path().to_str().unwrap());
+        let conv = store.create_conversation();
+        store.save_conversation(&conv).unwrap();
+        let other = store.create_conversation();
+        store.save_conversation(&other).unwrap();
+
+        let first = store
+            .enqueue_outbox(&conv.id, "在吗", Vec::new(), "glm-4.7", false)
+            .unwrap();
+        let mut second = store
+            .enqueue_outbox(&other.id, "早安", Vec::new(), "glm-4.7", true)
+            .unwrap();
+        second.queued_at = first.queued_at + 1;
+        second.status = OutboxStatus::Failed;
+        store.save_outbox_entry(&second).unwrap();
+        assert!(store
+            .enqueue_outbox("missing", "你好", Vec::new(), "glm-4.7", false)
+            .is_err());
+
+        let outbox = store.list_outbox();
+        assert_eq!(outbox.len(), 2);
+        assert_eq!(outbox[0], first);
+        assert_eq!(outbox[0].status, OutboxStatus::Queued);
+        assert_eq!(outbox[1].status, OutboxStatus::Failed);
+
+        store.delete_conversation(&conv.id).unwrap();
+        assert_eq!(store.list_outbox(), vec![second.clone()]);
+        store.remove_outbox_entry(&second.id).unwrap();
+        assert!(store.list_outbox().is_empty());
+
+        store.mark_closed(&other.id, 1_700_000_000_000).unwrap();
+        assert!(store
+            .enqueue_outbox(&other.id, "还在吗", Vec::new(), "glm-4.7", false)
+            .is_err());
     }
 }
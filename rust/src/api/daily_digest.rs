@@ -0,0 +1,275 @@
+use futures::StreamExt;
+
+use super::chat_engine::ChatEngine;
+use super::chat_provider::ChatProvider;
+use super::conversation_store::ConversationStore;
+use super::data_models::*;
+use super::streaming_handler::StreamingHandler;
+use super::topic_blocks::BlockedTopicStore;
+use super::text_utils;
+
+// ═══════════════════════════════════════════════════════════════════
+//  每日心声 (Daily Digest)
+//  ─────────────────────────────────────────────────────────────────
+//  「今天你的伙伴们在想什么」：为过去 24 小时内活跃的每个对话，
+//  让角色以自己的口吻写一句话，汇总成一份日报。
+//    1. 选取：最近活跃的聊天对话（跳过沙盒与共写），按活跃时间倒序
+//    2. 限流：最多 MAX_CONCURRENT_NOTES 个请求同时进行
+//    3. 控费：对话数与预估 token 总量都有上限，超出的对话直接跳过
+//    4. 容错：单个对话生成失败只计入 skipped，不影响其他对话
+// ═══════════════════════════════════════════════════════════════════
+
+/// 活跃窗口：24 小时
+const ACTIVE_WINDOW_MS: i64 = 24 * 60 * 60 * 1000;
+/// 最多为多少个对话生成心声
+const MAX_DIGEST_CONVERSATIONS: usize = 12;
+/// 同时进行的请求数
+const MAX_CONCURRENT_NOTES: usize = 3;
+/// 单次生成预估 token 总量上限
+const DIGEST_TOKEN_BUDGET: u32 = 30_000;
+/// 每个对话带入的最近消息条数
+const CONTEXT_MESSAGES: usize = 10;
+/// 带入消息的单条截断长度（字符）
+const CONTEXT_MESSAGE_CHARS: usize = 200;
+/// 单条心声的输出上限
+const NOTE_MAX_TOKENS: u32 = 80;
+/// 心声最长字符数（模型超长时截断）
+const NOTE_MAX_CHARS: usize = 60;
+/// 用便宜的快速模型
+const DIGEST_MODEL: &str = "glm-4.7-flash";
+
+pub struct DailyDigestGenerator<'a> {
+    provider: &'a dyn ChatProvider,
+    conversation_store: ConversationStore,
+    blocked_topics: BlockedTopicStore,
+}
+
+impl<'a> DailyDigestGenerator<'a> {
+    pub fn new(provider: &'a dyn ChatProvider, base_path: &str) -> Self {
+        Self {
+            provider,
+            conversation_store: ConversationStore::new(base_path),
+            blocked_topics: BlockedTopicStore::new(base_path),
+        }
+    }
+
+    /// 生成每日心声（now 为毫秒时间戳）
+    pub async fn generate(&self, now: i64) -> DailyDigest {
+        let mut active: Vec<Conversation> = self
+            .conversation_store
+            .list_conversations()
+            .into_iter()
+            .filter(|s| now - s.updated_at <= ACTIVE_WINDOW_MS)
+            .filter(|s| !ConversationStore::is_sandbox(&s.id))
+            .filter_map(|s| self.conversation_store.load_conversation(&s.id).ok())
+            .filter(|c| c.mode == ConversationMode::Chat)
+            .filter(|c| c.messages.iter().any(|m| m.role != MessageRole::System))
+            .collect();
+        active.sort_by_key(|c| std::cmp::Reverse(c.updated_at));
+
+        let mut skipped = active.len().saturating_sub(MAX_DIGEST_CONVERSATIONS) as u32;
+        active.truncate(MAX_DIGEST_CONVERSATIONS);
+
+        // 先按预算挑出要生成的对话，保证总花费有上限
+        let mut estimated_tokens = 0u32;
+        let mut jobs: Vec<(Conversation, Vec<Message>)> = Vec::new();
+        for conv in active {
+            let messages = self.build_note_messages(&conv, now);
+            let cost = Self::estimate_tokens(&messages);
+            if estimated_tokens + cost > DIGEST_TOKEN_BUDGET {
+                skipped += 1;
+                continue;
+            }
+            estimated_tokens += cost;
+            jobs.push((conv, messages));
+        }
+
+        let results: Vec<Option<DigestEntry>> = futures::stream::iter(jobs)
+            .map(|(conv, messages)| async move {
+                let note = self.request_note(&messages).await?;
+                Some(DigestEntry {
+                    conversation_id: conv.id,
+                    title: conv.title,
+                    note,
+                    last_active_at: conv.updated_at,
+                })
+            })
+            .buffer_unordered(MAX_CONCURRENT_NOTES)
+            .collect()
+            .await;
+
+        let mut entries: Vec<DigestEntry> = Vec::new();
+        for result in results {
+            match result {
+                Some(entry) => entries.push(entry),
+                None => skipped += 1,
+            }
+        }
+        entries.sort_by_key(|e| std::cmp::Reverse(e.last_active_at));
+
+        DailyDigest {
+            generated_at: now,
+            entries,
+            skipped,
+            estimated_tokens,
+        }
+    }
+
+    /// 角色设定 + 回避话题 + 最近对话 + 生成指令
+    fn build_note_messages(&self, conv: &Conversation, now: i64) -> Vec<Message> {
+        let make = |role: MessageRole, content: String| Message {
+            id: String::new(),
+            timestamp: 0,
+            ..Message::new(role, content, DIGEST_MODEL)
+        };
+
+        let mut messages = Vec::new();
+        let mut system = conv
+            .messages
+            .iter()
+            .find(|m| m.role == MessageRole::System)
+            .map(|m| m.content.clone())
+            .unwrap_or_default();
+        if let Some(avoid) = BlockedTopicStore::build_prompt(&self.blocked_topics.active(&conv.id)) {
+            system.push_str("\n\n");
+            system.push_str(&avoid);
+        }
+        if !system.trim().is_empty() {
+            messages.push(Message::system_prompt(system));
+        }
+
+        let recent: Vec<&Message> = conv
+            .messages
+            .iter()
+            .filter(|m| m.role != MessageRole::System)
+            .collect();
+        let start = recent.len().saturating_sub(CONTEXT_MESSAGES);
+        for msg in &recent[start..] {
+            let content = text_utils::truncate_chars(&msg.content, CONTEXT_MESSAGE_CHARS).to_string();
+            messages.push(make(msg.role.clone(), content));
+        }
+
+        let date = chrono::DateTime::from_timestamp_millis(now)
+            .map(|t| t.with_timezone(&chrono::Local).format("%m月%d日").to_string())
+            .unwrap_or_default();
+        messages.push(make(
+            MessageRole::User,
+            format!(
+                "（旁白）今天是{}。请保持你的角色身份和说话方式，用一句话写下你今天心里在想的、\
+                 和对方有关的事（不超过 40 字）。只输出这句话本身，不要引号、不要动作描写。",
+                date
+            ),
+        ));
+        messages
+    }
+
+    fn estimate_tokens(messages: &[Message]) -> u32 {
+        let chars: usize = messages.iter().map(|m| m.content.chars().count()).sum();
+        (chars as u32 * 2).div_ceil(3) + NOTE_MAX_TOKENS
+    }
+
+    async fn request_note(&self, messages: &[Message]) -> Option<String> {
+        let mut body = ChatEngine::build_request_body(messages, DIGEST_MODEL, false);
+        body["max_tokens"] = serde_json::json!(NOTE_MAX_TOKENS);
+        let silent = |_event: ChatStreamEvent| {};
+        let (text, _) = StreamingHandler::stream_chat(self.provider, body, silent)
+            .await
+            .ok()?;
+        Self::clean_note(&text)
+    }
+
+    /// 只保留第一行，去掉引号与多余空白，超长截断
+    pub fn clean_note(text: &str) -> Option<String> {
+        let line = text.lines().map(str::trim).find(|l| !l.is_empty())?;
+        let line = line.trim_matches(|c: char| "\"'“”「」『』".contains(c)).trim();
+        if line.is_empty() {
+            return None;
+        }
+        let chars: Vec<char> = line.chars().collect();
+        if chars.len() <= NOTE_MAX_CHARS {
+            Some(line.to_string())
+        } else {
+            Some(format!("{}…", chars[..NOTE_MAX_CHARS].iter().collect::<String>()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::chat_provider::ZhipuProvider;
+    use crate::api::cognitive_engine::TopicBlockSignal;
+    use tempfile::TempDir;
+
+    fn msg(role: MessageRole, content: &str) -> Message {
+        Message {
+            id: String::new(),
+            timestamp: 0,
+            ..Message::new(role, content, "test")
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stale_conversations_are_not_requested() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().to_str().unwrap();
+        let store = ConversationStore::new(path);
+        let mut conv = store.create_conversation();
+        conv.messages.push(msg(MessageRole::User, "早"));
+        conv.updated_at -= 2 * ACTIVE_WINDOW_MS;
+        store.save_conversation(&conv).unwrap();
+
+        let provider = ZhipuProvider::new("offline.local").unwrap();
+        let digest = DailyDigestGenerator::new(&provider, path)
+            .generate(chrono::Utc::now().timestamp_millis())
+            .await;
+        assert!(digest.entries.is_empty());
+        assert_eq!(digest.skipped, 0);
+        assert_eq!(digest.estimated_tokens, 0);
+    }
+
+    #[test]
+    fn test_note_prompt_carries_persona_and_blocked_topics() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().to_str().unwrap();
+        let store = ConversationStore::new(path);
+        let mut conv = store.create_conversation();
+        conv.messages = vec![
+            msg(MessageRole::System, "你是小雨，说话温柔"),
+            msg(MessageRole::User, "今天好累"),
+            msg(MessageRole::Assistant, "抱抱你"),
+        ];
+        BlockedTopicStore::new(path)
+            .record(
+                &conv.id,
+                &TopicBlockSignal {
+                    topic: "我的工作".to_string(),
+                    keywords: vec!["工作".to_string()],
+                    evidence: "别提我的工作".to_string(),
+                    explicit: true,
+                },
+            )
+            .unwrap();
+
+        let provider = ZhipuProvider::new("offline.local").unwrap();
+        let generator = DailyDigestGenerator::new(&provider, path);
+        let messages = generator.build_note_messages(&conv, 0);
+        assert_eq!(messages.len(), 4);
+        assert!(messages[0].content.contains("小雨"));
+        assert!(messages[0].content.contains("我的工作"));
+        assert_eq!(messages[3].role, MessageRole::User);
+        assert!(DailyDigestGenerator::estimate_tokens(&messages) > NOTE_MAX_TOKENS);
+    }
+
+    #[test]
+    fn test_clean_note_keeps_first_line() {
+        assert_eq!(
+            DailyDigestGenerator::clean_note("\n「今天也想听你讲讲公司的事」\n（笑）"),
+            Some("今天也想听你讲讲公司的事".to_string())
+        );
+        assert!(DailyDigestGenerator::clean_note("  \n \"\" ").is_none());
+        let long = "好".repeat(100);
+        let cleaned = DailyDigestGenerator::clean_note(&long).unwrap();
+        assert_eq!(cleaned.chars().count(), NOTE_MAX_CHARS + 1);
+    }
+}
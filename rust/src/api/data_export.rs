@@ -0,0 +1,583 @@
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter, ErrorKind, Read, Write};
+use std::path::{Path, PathBuf};
+
+use flutter_rust_bridge::frb;
+use serde::{Deserialize, Serialize};
+
+use super::data_layout::{
+    DataLayoutMigrator, ManifestEntry, CURRENT_LAYOUT_VERSION, LAYOUT_DIRS, LAYOUT_ROOT_FILES,
+    MANIFEST_FILE,
+};
+use super::data_models::DataExportInfo;
+use super::error_handler::ChatError;
+
+// ═══════════════════════════════════════════════════════════════════
+//  整库导出与恢复 (Data Export)
+//  ─────────────────────────────────────────────────────────────────
+//  把整个数据目录（范围同 DataLayoutMigrator：对话、记忆索引及蒸馏状态、
+//  知识库、设置……）打包成单个 tar 归档，换设备时在新设备上整体恢复。
+//  与增量备份（backup）不同，导出文件自包含、可用任意 tar 工具查看。
+//    · 归档第一项是 export_manifest.json：格式版本、布局版本、
+//      每个文件的相对路径、大小与 SHA-256；其余各项在 data/ 下
+//    · 恢复分两步：先解包到暂存目录并逐个校验，全部通过后
+//      把现有布局整体挪开、换入暂存内容；中途失败则换回原样
+//    · 静态加密的文件按原样导出（encryption.json 一并带走），
+//      恢复后用原凭据解锁即可
+//
+//  存储结构（恢复过程中的临时目录，完成后删除）：
+//    .restore_staging/    解包中的新数据
+//    .restore_previous/   被换下的旧数据
+// ═══════════════════════════════════════════════════════════════════
+
+/// 导出归档的格式版本
+pub const EXPORT_FORMAT_VERSION: u32 = 1;
+
+const EXPORT_MANIFEST: &str = "export_manifest.json";
+const DATA_PREFIX: &str = "data/";
+const STAGING_DIR: &str = ".restore_staging";
+const PREVIOUS_DIR: &str = ".restore_previous";
+const BLOCK: usize = 512;
+/// 归档清单的大小上限
+const MAX_MANIFEST_BYTES: u64 = 16 * 1024 * 1024;
+/// 单个文件的大小上限（即使清单声明得更大也拒绝）
+const MAX_ENTRY_BYTES: u64 = 1024 * 1024 * 1024;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct ExportManifest {
+    format_version: u32,
+    layout_version: u32,
+    created_at: i64,
+    files: Vec<ManifestEntry>,
+}
+
+impl ExportManifest {
+    fn info(&self) -> DataExportInfo {
+        DataExportInfo {
+            format_version: self.format_version,
+            layout_version: self.layout_version,
+            created_at: self.created_at,
+            file_count: self.files.len() as u32,
+            total_bytes: self.files.iter().map(|f| f.size).sum(),
+        }
+    }
+}
+
+fn storage_err(context: &str, e: impl std::fmt::Display) -> ChatError {
+    ChatError::StorageError {
+        message: format!("{}: {}", context, e),
+    }
+}
+
+/// 相对路径必须落在布局内（规范目录下的文件或根目录设置文件），拒绝 `..` 等越界路径
+fn is_layout_path(rel: &str) -> bool {
+    match rel.split_once('/') {
+        None => LAYOUT_ROOT_FILES.contains(&rel),
+        Some((dir, name)) => {
+            LAYOUT_DIRS.contains(&dir)
+                && !name.is_empty()
+                && !name.contains('/')
+                && !name.contains('\\')
+                && name != "."
+                && name != ".."
+        }
+    }
+}
+
+fn rel_to_path(base: &Path, rel: &str) -> PathBuf {
+    rel.split('/')
+        .fold(base.to_path_buf(), |acc, part| acc.join(part))
+}
+
+// ── tar（POSIX ustar）读写 ──
+
+fn write_octal(field: &mut [u8], value: u64) {
+    let digits = format!("{:0width$o}", value, width = field.len() - 1);
+    field[..digits.len()].copy_from_slice(digits.as_bytes());
+    field[digits.len()] = 0;
+}
+
+fn write_tar_entry(
+    w: &mut impl Write,
+    path: &str,
+    data: &[u8],
+    mtime: i64,
+) -> Result<(), ChatError> {
+    // 超过 100 字节的路径按 ustar 规则拆成 prefix + name
+    let (prefix, name) = if path.len() <= 100 {
+        ("", path)
+    } else {
+        path.rsplit_once('/').unwrap_or(("", path))
+    };
+    if name.len() > 100 || prefix.len() > 155 {
+        return Err(ChatError::StorageError {
+            message: format!("Path too long for export: {}", path),
+        });
+    }
+    let mut header = [0u8; BLOCK];
+    header[..name.len()].copy_from_slice(name.as_bytes());
+    write_octal(&mut header[100..108], 0o644);
+    write_octal(&mut header[108..116], 0);
+    write_octal(&mut header[116..124], 0);
+    write_octal(&mut header[124..136], data.len() as u64);
+    write_octal(&mut header[136..148], mtime.max(0) as u64);
+    header[156] = b'0';
+    header[257..263].copy_from_slice(b"ustar\0");
+    header[263..265].copy_from_slice(b"00");
+    header[345..345 + prefix.len()].copy_from_slice(prefix.as_bytes());
+    header[148..156].fill(b' ');
+    let checksum: u64 = header.iter().map(|b| *b as u64).sum();
+    write_octal(&mut header[148..155], checksum);
+    header[155] = b' ';
+
+    let padding = (BLOCK - data.len() % BLOCK) % BLOCK;
+    w.write_all(&header)
+        .and_then(|_| w.write_all(data))
+        .and_then(|_| w.write_all(&vec![0u8; padding]))
+        .map_err(|e| storage_err("Failed to write export", e))
+}
+
+fn parse_octal(field: &[u8]) -> Option<u64> {
+    let text: String = field
+        .iter()
+        .take_while(|b| **b != 0)
+        .map(|b| *b as char)
+        .collect();
+    let text = text.trim();
+    if text.is_empty() {
+        return Some(0);
+    }
+    u64::from_str_radix(text, 8).ok()
+}
+
+/// 读下一项（路径, 内容）；到达归档结尾时为 None。
+/// limit 按路径给出这一项允许的最大字节数（None 表示跳过该项、读下一项）：
+/// 头部声明的大小先与它及 MAX_ENTRY_BYTES 比对，再分配内存，
+/// 损坏或恶意的归档不会让导入去申请头部随意声明的大小
+fn read_tar_entry(
+    r: &mut impl Read,
+    limit: &impl Fn(&str) -> Result<Option<u64>, ChatError>,
+) -> Result<Option<(String, Vec<u8>)>, ChatError> {
+    let corrupt = || ChatError::StorageError {
+        message: "Export archive is corrupted".to_string(),
+    };
+    loop {
+        let mut header = [0u8; BLOCK];
+        match r.read_exact(&mut header) {
+            Ok(()) => {}
+            Err(e) if e.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+            Err(e) => return Err(storage_err("Failed to read export", e)),
+        }
+        if header.iter().all(|b| *b == 0) {
+            return Ok(None);
+        }
+        let stored = parse_octal(&header[148..156]).ok_or_else(corrupt)?;
+        let mut check = header;
+        check[148..156].fill(b' ');
+        if check.iter().map(|b| *b as u64).sum::<u64>() != stored {
+            return Err(corrupt());
+        }
+
+        let field = |range: std::ops::Range<usize>| {
+            String::from_utf8_lossy(&header[range])
+                .trim_end_matches('\0')
+                .to_string()
+        };
+        let name = field(0..100);
+        let prefix = field(345..500);
+        let path = if prefix.is_empty() {
+            name
+        } else {
+            format!("{}/{}", prefix, name)
+        };
+        let size = parse_octal(&header[124..136]).ok_or_else(corrupt)?;
+        let padded = size.div_ceil(BLOCK as u64) * BLOCK as u64;
+        // 只收普通文件，其余类型（目录、链接）与调用方不要的项直接跳过
+        let regular = header[156] == b'0' || header[156] == 0;
+        let allowed = if regular { limit(&path)? } else { None };
+        let Some(allowed) = allowed else {
+            let skipped = std::io::copy(&mut r.by_ref().take(padded), &mut std::io::sink())
+                .map_err(|_| corrupt())?;
+            if skipped != padded {
+                return Err(corrupt());
+            }
+            continue;
+        };
+        if size > allowed.min(MAX_ENTRY_BYTES) {
+            return Err(ChatError::StorageError {
+                message: format!(
+                    "Export entry '{}' declares {} bytes, more than expected",
+                    path, size
+                ),
+            });
+        }
+        let size = size as usize;
+        let mut data = vec![0u8; size];
+        r.read_exact(&mut data).map_err(|_| corrupt())?;
+        let padding = (BLOCK - size % BLOCK) % BLOCK;
+        r.read_exact(&mut [0u8; BLOCK][..padding])
+            .map_err(|_| corrupt())?;
+        return Ok(Some((path, data)));
+    }
+}
+
+#[frb(opaque)]
+pub struct DataExporter {
+    base_path: String,
+}
+
+impl DataExporter {
+    pub fn new(base_path: &str) -> Self {
+        Self {
+            base_path: base_path.to_string(),
+        }
+    }
+
+    /// 把数据目录导出为 target_path 处的 tar 归档（先写临时文件，完成后改名）
+    pub fn export(&self, target_path: &str) -> Result<DataExportInfo, ChatError> {
+        let migrator = DataLayoutMigrator::new(&self.base_path);
+        migrator.upgrade()?;
+        let layout = migrator.build_manifest()?;
+        let files = migrator.collect_files()?;
+        let manifest = ExportManifest {
+            format_version: EXPORT_FORMAT_VERSION,
+            layout_version: layout.version,
+            created_at: chrono::Utc::now().timestamp_millis(),
+            files: layout.files,
+        };
+        let mtime = manifest.created_at / 1000;
+
+        let target = PathBuf::from(target_path);
+        if let Some(parent) = target.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)
+                .map_err(|e| storage_err("Failed to create export directory", e))?;
+        }
+        let partial = target.with_extension("partial");
+        let result = (|| {
+            let mut w = BufWriter::new(
+                File::create(&partial).map_err(|e| storage_err("Failed to create export", e))?,
+            );
+            let json = serde_json::to_vec_pretty(&manifest)
+                .map_err(|e| storage_err("Failed to serialize export manifest", e))?;
+            write_tar_entry(&mut w, EXPORT_MANIFEST, &json, mtime)?;
+            for entry in &manifest.files {
+                let abs = files
+                    .get(&entry.path)
+                    .ok_or_else(|| ChatError::StorageError {
+                        message: format!("Source file vanished: {}", entry.path),
+                    })?;
+                let data = fs::read(abs)
+                    .map_err(|e| storage_err(&format!("Failed to read '{}'", entry.path), e))?;
+                // 清单与内容必须对应同一时刻；导出期间被改写的文件直接报错
+                if DataLayoutMigrator::sha256_hex(&data) != entry.sha256 {
+                    return Err(ChatError::StorageError {
+                        message: format!("'{}' changed during export", entry.path),
+                    });
+                }
+                write_tar_entry(
+                    &mut w,
+                    &format!("{}{}", DATA_PREFIX, entry.path),
+                    &data,
+                    mtime,
+                )?;
+            }
+            // 归档结尾：两个全零块
+            w.write_all(&[0u8; BLOCK * 2])
+                .and_then(|_| w.flush())
+                .map_err(|e| storage_err("Failed to write export", e))
+        })();
+        if let Err(e) = result {
+            let _ = fs::remove_file(&partial);
+            return Err(e);
+        }
+        fs::rename(&partial, &target).map_err(|e| storage_err("Failed to finalize export", e))?;
+        Ok(manifest.info())
+    }
+
+    /// 用导出归档整体替换数据目录：全部校验通过才换入，任何一步失败都保持原数据不变
+    pub fn restore(&self, archive_path: &str) -> Result<DataExportInfo, ChatError> {
+        let base = PathBuf::from(&self.base_path);
+        let staging = base.join(STAGING_DIR);
+        let _ = fs::remove_dir_all(&staging);
+        fs::create_dir_all(&staging)
+            .map_err(|e| storage_err("Failed to create staging directory", e))?;
+
+        let manifest = match self.unpack(archive_path, &staging) {
+            Ok(manifest) => manifest,
+            Err(e) => {
+                let _ = fs::remove_dir_all(&staging);
+                return Err(e);
+            }
+        };
+        if let Err(e) = self.swap_in(&staging) {
+            let _ = fs::remove_dir_all(&staging);
+            return Err(e);
+        }
+        let _ = fs::remove_dir_all(&staging);
+        // 换入的是旧版本布局时就地升级，并重建布局清单
+        DataLayoutMigrator::new(&self.base_path).upgrade()?;
+        Ok(manifest.info())
+    }
+
+    /// 解包到暂存目录并逐个校验，返回归档清单
+    fn unpack(&self, archive_path: &str, staging: &Path) -> Result<ExportManifest, ChatError> {
+        let file = File::open(archive_path).map_err(|e| storage_err("Failed to open export", e))?;
+        let mut r = BufReader::new(file);
+
+        let manifest_limit = |_: &str| Ok(Some(MAX_MANIFEST_BYTES));
+        let manifest: ExportManifest = match read_tar_entry(&mut r, &manifest_limit)? {
+            Some((path, data)) if path == EXPORT_MANIFEST => serde_json::from_slice(&data)
+                .map_err(|e| storage_err("Failed to parse export manifest", e))?,
+            _ => {
+                return Err(ChatError::ValidationError {
+                    message: "Not a Talk2u data export".to_string(),
+                })
+            }
+        };
+        if manifest.format_version > EXPORT_FORMAT_VERSION
+            || manifest.layout_version > CURRENT_LAYOUT_VERSION
+        {
+            return Err(ChatError::ValidationError {
+                message: format!(
+                    "Export format {} / layout {} is newer than this app supports",
+                    manifest.format_version, manifest.layout_version
+                ),
+            });
+        }
+        if let Some(bad) = manifest.files.iter().find(|f| !is_layout_path(&f.path)) {
+            return Err(ChatError::ValidationError {
+                message: format!("Export contains an invalid path: {}", bad.path),
+            });
+        }
+
+        // 清单之外的 data/ 项直接拒绝；其余项按清单记录的大小读取
+        let entry_of = |rel: &str| {
+            manifest
+                .files
+                .iter()
+                .find(|f| f.path == rel)
+                .ok_or_else(|| ChatError::ValidationError {
+                    message: format!("Export contains a file not in its manifest: {}", rel),
+                })
+        };
+        let data_limit = |path: &str| match path.strip_prefix(DATA_PREFIX) {
+            Some(rel) => entry_of(rel).map(|entry| Some(entry.size)),
+            None => Ok(None),
+        };
+        let mut restored = 0usize;
+        while let Some((path, data)) = read_tar_entry(&mut r, &data_limit)? {
+            let Some(rel) = path.strip_prefix(DATA_PREFIX) else {
+                continue;
+            };
+            let entry = entry_of(rel)?;
+            if data.len() as u64 != entry.size
+                || DataLayoutMigrator::sha256_hex(&data) != entry.sha256
+            {
+                return Err(ChatError::StorageError {
+                    message: format!("Export content corrupted for '{}'", rel),
+                });
+            }
+            let dst = rel_to_path(staging, rel);
+            if let Some(parent) = dst.parent() {
+                fs::create_dir_all(parent)
+                    .map_err(|e| storage_err("Failed to create directory", e))?;
+            }
+            fs::write(&dst, &data)
+                .map_err(|e| storage_err(&format!("Failed to stage '{}'", rel), e))?;
+            restored += 1;
+        }
+        if restored != manifest.files.len() {
+            return Err(ChatError::StorageError {
+                message: format!(
+                    "Export is incomplete: {} of {} files",
+                    restored,
+                    manifest.files.len()
+                ),
+            });
+        }
+        Ok(manifest)
+    }
+
+    /// 现有布局挪进 PREVIOUS_DIR，暂存内容换入；失败时按相反顺序换回
+    fn swap_in(&self, staging: &Path) -> Result<(), ChatError> {
+        let base = PathBuf::from(&self.base_path);
+        let previous = base.join(PREVIOUS_DIR);
+        let _ = fs::remove_dir_all(&previous);
+        fs::create_dir_all(&previous)
+            .map_err(|e| storage_err("Failed to create restore directory", e))?;
+
+        let mut current: Vec<String> = fs::read_dir(&base)
+            .map_err(|e| storage_err("Failed to read data directory", e))?
+            .filter_map(|e| e.ok())
+            .filter_map(|e| e.file_name().to_str().map(|n| n.to_string()))
+            .filter(|name| {
+                let lower = name.to_lowercase();
+                LAYOUT_DIRS.contains(&lower.as_str())
+                    || LAYOUT_ROOT_FILES.contains(&name.as_str())
+                    || name == MANIFEST_FILE
+            })
+            .collect();
+        current.sort();
+        let incoming: Vec<String> = fs::read_dir(staging)
+            .map_err(|e| storage_err("Failed to read staging directory", e))?
+            .filter_map(|e| e.ok())
+            .filter_map(|e| e.file_name().to_str().map(|n| n.to_string()))
+            .collect();
+
+        let mut moved_out: Vec<&str> = Vec::new();
+        let mut moved_in: Vec<&str> = Vec::new();
+        let result = (|| {
+            for name in &current {
+                fs::rename(base.join(name), previous.join(name))
+                    .map_err(|e| storage_err(&format!("Failed to move '{}' aside", name), e))?;
+                moved_out.push(name);
+            }
+            for name in &incoming {
+                fs::rename(staging.join(name), base.join(name))
+                    .map_err(|e| storage_err(&format!("Failed to restore '{}'", name), e))?;
+                moved_in.push(name);
+            }
+            Ok(())
+        })();
+
+        if result.is_err() {
+            for name in moved_in.iter().rev() {
+                let _ = fs::rename(base.join(name), staging.join(name));
+            }
+            for name in moved_out.iter().rev() {
+                let _ = fs::rename(previous.join(name), base.join(name));
+            }
+        }
+        let _ = fs::remove_dir_all(&previous);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write(base: &Path, rel: &str, content: &str) {
+        let path = rel_to_path(base, rel);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn test_export_and_restore_replaces_layout() {
+        let src = TempDir::new().unwrap();
+        let dst = TempDir::new().unwrap();
+        let long_scope = format!("{}@{}", "c".repeat(36), "a".repeat(36));
+        write(src.path(), "conversations/c1.msgpack", "conv");
+        write(
+            src.path(),
+            &format!("memory_index/{}_distilled.json", long_scope),
+            "{}",
+        );
+        write(src.path(), "knowledge_base/c1_facts.json", "[]");
+        write(src.path(), "settings.json", "{}");
+        write(dst.path(), "conversations/old.msgpack", "old");
+        write(dst.path(), "backups/archive.t2b", "keep");
+
+        let archive = src.path().join("exports").join("all.tar");
+        let exported = DataExporter::new(src.path().to_str().unwrap())
+            .export(archive.to_str().unwrap())
+            .unwrap();
+        assert_eq!(exported.file_count, 4);
+        assert_eq!(exported.format_version, EXPORT_FORMAT_VERSION);
+
+        let restored = DataExporter::new(dst.path().to_str().unwrap())
+            .restore(archive.to_str().unwrap())
+            .unwrap();
+        assert_eq!(restored, exported);
+        assert_eq!(
+            fs::read_to_string(dst.path().join("conversations/c1.msgpack")).unwrap(),
+            "conv"
+        );
+        assert!(dst
+            .path()
+            .join(format!("memory_index/{}_distilled.json", long_scope))
+            .exists());
+        assert!(!dst.path().join("conversations/old.msgpack").exists());
+        // 布局外的文件不受影响，临时目录已清理
+        assert!(dst.path().join("backups/archive.t2b").exists());
+        assert!(!dst.path().join(STAGING_DIR).exists());
+        assert!(!dst.path().join(PREVIOUS_DIR).exists());
+        let verification = DataLayoutMigrator::new(dst.path().to_str().unwrap())
+            .verify()
+            .unwrap();
+        assert_eq!(verification.checked_files, 4);
+    }
+
+    #[test]
+    fn test_corrupted_export_leaves_data_untouched() {
+        let src = TempDir::new().unwrap();
+        let dst = TempDir::new().unwrap();
+        write(src.path(), "conversations/c1.msgpack", "conversation-body");
+        write(dst.path(), "conversations/old.msgpack", "old");
+        let archive = src.path().join("all.tar");
+        DataExporter::new(src.path().to_str().unwrap())
+            .export(archive.to_str().unwrap())
+            .unwrap();
+
+        let mut bytes = fs::read(&archive).unwrap();
+        let pos = bytes
+            .windows(b"conversation-body".len())
+            .position(|w| w == b"conversation-body")
+            .unwrap();
+        bytes[pos] = b'X';
+        fs::write(&archive, bytes).unwrap();
+
+        let result =
+            DataExporter::new(dst.path().to_str().unwrap()).restore(archive.to_str().unwrap());
+        assert!(result.is_err());
+        assert_eq!(
+            fs::read_to_string(dst.path().join("conversations/old.msgpack")).unwrap(),
+            "old"
+        );
+        assert!(!dst.path().join(STAGING_DIR).exists());
+    }
+
+    #[test]
+    fn test_oversized_entry_is_rejected_before_reading() {
+        let dst = TempDir::new().unwrap();
+        let manifest = ExportManifest {
+            format_version: EXPORT_FORMAT_VERSION,
+            layout_version: CURRENT_LAYOUT_VERSION,
+            created_at: 0,
+            files: vec![ManifestEntry {
+                path: "conversations/c1.msgpack".to_string(),
+                size: 4,
+                sha256: DataLayoutMigrator::sha256_hex(b"conv"),
+            }],
+        };
+        let mut bytes = Vec::new();
+        write_tar_entry(
+            &mut bytes,
+            EXPORT_MANIFEST,
+            &serde_json::to_vec(&manifest).unwrap(),
+            0,
+        )
+        .unwrap();
+        let header_at = bytes.len();
+        write_tar_entry(&mut bytes, "data/conversations/c1.msgpack", b"conv", 0).unwrap();
+        // 头部声明约 8 GB：按声明分配会直接耗尽内存
+        let header = &mut bytes[header_at..header_at + BLOCK];
+        write_octal(&mut header[124..136], 0o77_777_777_777);
+        header[148..156].fill(b' ');
+        let checksum: u64 = header.iter().map(|b| *b as u64).sum();
+        write_octal(&mut header[148..155], checksum);
+        header[155] = b' ';
+        let archive = dst.path().join("forged.tar");
+        fs::write(&archive, bytes).unwrap();
+
+        let result =
+            DataExporter::new(dst.path().to_str().unwrap()).restore(archive.to_str().unwrap());
+        assert!(matches!(
+            result,
+            Err(ChatError::StorageError { message }) if message.contains("more than expected")
+        ));
+        assert!(!dst.path().join(STAGING_DIR).exists());
+    }
+}
@@ -0,0 +1,444 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use flutter_rust_bridge::frb;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::conversation_store::ConversationStore;
+use super::data_models::LayoutVerification;
+use super::error_handler::ChatError;
+
+// ═══════════════════════════════════════════════════════════════════
+//  数据目录布局与迁移 (Data Layout)
+//  ─────────────────────────────────────────────────────────────────
+//  data_path 下的目录结构此前是隐式约定，这里显式版本化：
+//    v1：无清单的隐式布局，可能残留 conversations/*.json、目录名大小写不一
+//    v2：conversations/*.msgpack + 小写规范目录名 + layout_manifest.json
+//
+//  清单记录每个文件的相对路径（统一 `/` 分隔）、大小与 SHA-256，
+//  按路径排序，保证同一份数据生成的清单逐字节一致。
+//  迁移到新路径/新设备时：先就地升级 → 按清单复制 → 在目标端重算校验。
+//  大小写仅不同的路径在大小写不敏感的文件系统上会互相覆盖，迁移前直接拒绝。
+// ═══════════════════════════════════════════════════════════════════
+
+pub const CURRENT_LAYOUT_VERSION: u32 = 2;
+
+pub(crate) const MANIFEST_FILE: &str = "layout_manifest.json";
+
+/// 布局内的规范目录名（均为小写）
+pub(crate) const LAYOUT_DIRS: [&str; 21] = [
+    "conversations",
+    "memory_index",
+    "knowledge_base",
+    "decision_log",
+    "memory_audit",
+    "memory_vectors",
+    "blocked_topics",
+    "lorebook",
+    "group_chats",
+    "shadow_eval",
+    "attachments",
+    "reengagement",
+    "audio",
+    "archives",
+    "outbox",
+    "search_index",
+    "mood",
+    "saydo_rules",
+    "prompts",
+    "check_ins",
+    "cold_storage",
+];
+
+/// 布局内的根目录文件
+pub(crate) const LAYOUT_ROOT_FILES: [&str; 12] = [
+    "settings.json",
+    "index_versions.json",
+    "voices.json",
+    "tokenizer.json",
+    "models.json",
+    "encryption.json",
+    "emotion_lexicon.json",
+    "safety.json",
+    "personas.json",
+    "summarization.json",
+    "retention.json",
+    "sync.json",
+];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ManifestEntry {
+    pub path: String,
+    pub size: u64,
+    pub sha256: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LayoutManifest {
+    pub version: u32,
+    pub files: Vec<ManifestEntry>,
+}
+
+#[frb(opaque)]
+pub struct DataLayoutMigrator {
+    base_path: String,
+}
+
+impl DataLayoutMigrator {
+    pub fn new(base_path: &str) -> Self {
+        Self {
+            base_path: base_path.to_string(),
+        }
+    }
+
+    fn manifest_path(&self) -> PathBuf {
+        PathBuf::from(&self.base_path).join(MANIFEST_FILE)
+    }
+
+    pub fn load_manifest(&self) -> Result<Option<LayoutManifest>, ChatError> {
+        let path = self.manifest_path();
+        if !path.exists() {
+            return Ok(None);
+        }
+        let json = fs::read_to_string(&path).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to read layout manifest: {}", e),
+        })?;
+        serde_json::from_str(&json)
+            .map(Some)
+            .map_err(|e| ChatError::StorageError {
+                message: format!("Failed to parse layout manifest: {}", e),
+            })
+    }
+
+    fn save_manifest(&self, manifest: &LayoutManifest) -> Result<(), ChatError> {
+        let json = serde_json::to_string_pretty(manifest).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to serialize layout manifest: {}", e),
+        })?;
+        fs::write(self.manifest_path(), json).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to write layout manifest: {}", e),
+        })
+    }
+
+    /// 当前布局版本：有清单以清单为准，否则视为 v1 隐式布局
+    pub fn detect_version(&self) -> Result<u32, ChatError> {
+        Ok(self.load_manifest()?.map(|m| m.version).unwrap_or(1))
+    }
+
+    pub(crate) fn sha256_hex(data: &[u8]) -> String {
+        Sha256::digest(data)
+            .iter()
+            .map(|b| format!("{:02x}", b))
+            .collect()
+    }
+
+    /// 按规范相对路径收集布局内的文件（键为小写目录名 + 原文件名，按路径排序）
+    /// 大小写仅不同的路径会在大小写不敏感的文件系统上冲突，直接报错
+    pub(crate) fn collect_files(&self) -> Result<BTreeMap<String, PathBuf>, ChatError> {
+        let base = PathBuf::from(&self.base_path);
+        let mut files: BTreeMap<String, PathBuf> = BTreeMap::new();
+        let mut folded: BTreeMap<String, String> = BTreeMap::new();
+
+        let mut insert = |rel: String, abs: PathBuf| -> Result<(), ChatError> {
+            let key = rel.to_lowercase();
+            if let Some(existing) = folded.get(&key) {
+                return Err(ChatError::StorageError {
+                    message: format!(
+                        "Case-insensitive path collision: '{}' vs '{}'",
+                        existing, rel
+                    ),
+                });
+            }
+            folded.insert(key, rel.clone());
+            files.insert(rel, abs);
+            Ok(())
+        };
+
+        let entries = match fs::read_dir(&base) {
+            Ok(e) => e,
+            Err(_) => return Ok(files),
+        };
+        let mut entries: Vec<PathBuf> = entries.filter_map(|e| e.ok().map(|e| e.path())).collect();
+        entries.sort();
+
+        for path in entries {
+            let name = match path.file_name().and_then(|n| n.to_str()) {
+                Some(n) => n.to_string(),
+                None => continue,
+            };
+            if path.is_file() {
+                if LAYOUT_ROOT_FILES.contains(&name.as_str()) {
+                    insert(name, path)?;
+                }
+                continue;
+            }
+            let lower = name.to_lowercase();
+            let canonical = match LAYOUT_DIRS.iter().find(|d| **d == lower) {
+                Some(d) => *d,
+                None => continue,
+            };
+            let mut children: Vec<PathBuf> = fs::read_dir(&path)
+                .map_err(|e| ChatError::StorageError {
+                    message: format!("Failed to read '{}': {}", name, e),
+                })?
+                .filter_map(|e| e.ok().map(|e| e.path()))
+                .filter(|p| p.is_file())
+                .collect();
+            children.sort();
+            for child in children {
+                if let Some(file_name) = child.file_name().and_then(|n| n.to_str()) {
+                    insert(format!("{}/{}", canonical, file_name), child.clone())?;
+                }
+            }
+        }
+        Ok(files)
+    }
+
+    /// 根据磁盘现状生成清单（确定性：路径排序 + 内容哈希）
+    pub fn build_manifest(&self) -> Result<LayoutManifest, ChatError> {
+        let mut entries = Vec::new();
+        for (rel, abs) in self.collect_files()? {
+            let data = fs::read(&abs).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to read '{}': {}", rel, e),
+            })?;
+            entries.push(ManifestEntry {
+                path: rel,
+                size: data.len() as u64,
+                sha256: Self::sha256_hex(&data),
+            });
+        }
+        Ok(LayoutManifest {
+            version: CURRENT_LAYOUT_VERSION,
+            files: entries,
+        })
+    }
+
+    /// 将目录名规范为小写（两步重命名，兼容大小写不敏感的文件系统）
+    fn normalize_dir_case(&self) -> Result<(), ChatError> {
+        let base = PathBuf::from(&self.base_path);
+        let entries = match fs::read_dir(&base) {
+            Ok(e) => e,
+            Err(_) => return Ok(()),
+        };
+        for entry in entries.filter_map(|e| e.ok()) {
+            let path = entry.path();
+            let name = match path.file_name().and_then(|n| n.to_str()) {
+                Some(n) => n.to_string(),
+                None => continue,
+            };
+            let lower = name.to_lowercase();
+            if !path.is_dir() || name == lower || !LAYOUT_DIRS.contains(&lower.as_str()) {
+                continue;
+            }
+            let staging = base.join(format!("{}.migrating", lower));
+            let rename_err = |e: std::io::Error| ChatError::StorageError {
+                message: format!("Failed to normalize '{}': {}", name, e),
+            };
+            fs::rename(&path, &staging).map_err(rename_err)?;
+            fs::rename(&staging, base.join(&lower)).map_err(rename_err)?;
+        }
+        Ok(())
+    }
+
+    /// 就地升级到当前布局版本，并刷新清单
+    pub fn upgrade(&self) -> Result<LayoutVerification, ChatError> {
+        // 先做冲突检查，避免规范化目录名时覆盖数据
+        self.collect_files()?;
+
+        if self.detect_version()? < 2 {
+            self.normalize_dir_case()?;
+            // 将遗留的 conversations/*.json 转为 msgpack
+            let store = ConversationStore::new(&self.base_path);
+            let legacy_ids: Vec<String> = self
+                .collect_files()?
+                .keys()
+                .filter_map(|rel| rel.strip_prefix("conversations/"))
+                .filter_map(|name| name.strip_suffix(".json"))
+                .map(|id| id.to_string())
+                .collect();
+            for id in legacy_ids {
+                store.load_conversation(&id)?;
+            }
+        }
+
+        let manifest = self.build_manifest()?;
+        self.save_manifest(&manifest)?;
+        self.verify()
+    }
+
+    /// 将磁盘现状与清单快照比对
+    pub fn verify(&self) -> Result<LayoutVerification, ChatError> {
+        let manifest = self.load_manifest()?.ok_or_else(|| ChatError::StorageError {
+            message: "Layout manifest not found".to_string(),
+        })?;
+        let current = self.build_manifest()?;
+        let current_map: BTreeMap<&str, &ManifestEntry> =
+            current.files.iter().map(|e| (e.path.as_str(), e)).collect();
+        let expected_map: BTreeMap<&str, &ManifestEntry> =
+            manifest.files.iter().map(|e| (e.path.as_str(), e)).collect();
+
+        let mut missing = Vec::new();
+        let mut corrupted = Vec::new();
+        for (path, expected) in &expected_map {
+            match current_map.get(path) {
+                None => missing.push(path.to_string()),
+                Some(actual) if actual.sha256 != expected.sha256 || actual.size != expected.size => {
+                    corrupted.push(path.to_string())
+                }
+                Some(_) => {}
+            }
+        }
+        let unexpected = current_map
+            .keys()
+            .filter(|p| !expected_map.contains_key(*p))
+            .map(|p| p.to_string())
+            .collect();
+
+        Ok(LayoutVerification {
+            version: manifest.version,
+            checked_files: expected_map.len() as u32,
+            missing,
+            corrupted,
+            unexpected,
+        })
+    }
+
+    /// 迁移整个数据目录到新路径（跨设备/跨文件系统）
+    /// 目标目录必须尚无清单；复制完成后在目标端重新计算校验
+    pub fn migrate_to(&self, target_path: &str) -> Result<LayoutVerification, ChatError> {
+        let target = DataLayoutMigrator::new(target_path);
+        if target.load_manifest()?.is_some() {
+            return Err(ChatError::ValidationError {
+                message: format!("Target '{}' already contains a data layout", target_path),
+            });
+        }
+
+        self.upgrade()?;
+        let manifest = self.build_manifest()?;
+        let source_files = self.collect_files()?;
+
+        for entry in &manifest.files {
+            let src = source_files.get(&entry.path).ok_or_else(|| ChatError::StorageError {
+                message: format!("Source file vanished: {}", entry.path),
+            })?;
+            let dst = entry
+                .path
+                .split('/')
+                .fold(PathBuf::from(target_path), |acc, part| acc.join(part));
+            if let Some(parent) = dst.parent() {
+                fs::create_dir_all(parent).map_err(|e| ChatError::StorageError {
+                    message: format!("Failed to create '{}': {}", parent.display(), e),
+                })?;
+            }
+            fs::copy(src, &dst).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to copy '{}': {}", entry.path, e),
+            })?;
+        }
+
+        if !Path::new(target_path).exists() {
+            fs::create_dir_all(target_path).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to create target directory: {}", e),
+            })?;
+        }
+        target.save_manifest(&manifest)?;
+        target.verify()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn is_clean(v: &LayoutVerification) -> bool {
+        v.missing.is_empty() && v.corrupted.is_empty() && v.unexpected.is_empty()
+    }
+
+    #[test]
+    fn test_upgrade_legacy_layout() {
+        let tmp = TempDir::new().unwrap();
+        let base = tmp.path().to_str().unwrap();
+        let store = ConversationStore::new(base);
+        let conv = store.create_conversation();
+        fs::create_dir_all(tmp.path().join("Conversations")).unwrap();
+        fs::write(
+            tmp.path().join("Conversations").join(format!("{}.json", conv.id)),
+            serde_json::to_string(&conv).unwrap(),
+        )
+        .unwrap();
+        fs::write(tmp.path().join("settings.json"), "{}").unwrap();
+        fs::write(tmp.path().join("unrelated.txt"), "x").unwrap();
+
+        let migrator = DataLayoutMigrator::new(base);
+        assert_eq!(migrator.detect_version().unwrap(), 1);
+
+        let report = migrator.upgrade().unwrap();
+        assert!(is_clean(&report));
+        assert_eq!(migrator.detect_version().unwrap(), CURRENT_LAYOUT_VERSION);
+
+        let manifest = migrator.load_manifest().unwrap().unwrap();
+        let paths: Vec<&str> = manifest.files.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec![format!("conversations/{}.msgpack", conv.id).as_str(), "settings.json"]
+        );
+        assert_eq!(store.load_conversation(&conv.id).unwrap(), conv);
+    }
+
+    #[test]
+    fn test_manifest_is_deterministic() {
+        let tmp = TempDir::new().unwrap();
+        let base = tmp.path().to_str().unwrap();
+        fs::create_dir_all(tmp.path().join("memory_index")).unwrap();
+        fs::write(tmp.path().join("memory_index").join("b.json"), "[]").unwrap();
+        fs::write(tmp.path().join("memory_index").join("a.json"), "[]").unwrap();
+
+        let migrator = DataLayoutMigrator::new(base);
+        let first = serde_json::to_string(&migrator.build_manifest().unwrap()).unwrap();
+        let second = serde_json::to_string(&migrator.build_manifest().unwrap()).unwrap();
+        assert_eq!(first, second);
+        assert!(first.find("a.json").unwrap() < first.find("b.json").unwrap());
+    }
+
+    #[test]
+    fn test_verify_detects_changes() {
+        let tmp = TempDir::new().unwrap();
+        let base = tmp.path().to_str().unwrap();
+        fs::create_dir_all(tmp.path().join("knowledge_base")).unwrap();
+        fs::write(tmp.path().join("knowledge_base").join("c_facts.json"), "[]").unwrap();
+        fs::write(tmp.path().join("settings.json"), "{}").unwrap();
+
+        let migrator = DataLayoutMigrator::new(base);
+        migrator.upgrade().unwrap();
+        fs::write(tmp.path().join("knowledge_base").join("c_facts.json"), "[1]").unwrap();
+        fs::remove_file(tmp.path().join("settings.json")).unwrap();
+        fs::write(tmp.path().join("knowledge_base").join("d_facts.json"), "[]").unwrap();
+
+        let report = migrator.verify().unwrap();
+        assert_eq!(report.corrupted, vec!["knowledge_base/c_facts.json"]);
+        assert_eq!(report.missing, vec!["settings.json"]);
+        assert_eq!(report.unexpected, vec!["knowledge_base/d_facts.json"]);
+    }
+
+    #[test]
+    fn test_migrate_to_new_path_verifies() {
+        let src = TempDir::new().unwrap();
+        let dst = TempDir::new().unwrap();
+        let store = ConversationStore::new(src.path().to_str().unwrap());
+        let conv = store.create_conversation();
+        store.save_conversation(&conv).unwrap();
+
+        let target = dst.path().join("moved");
+        let migrator = DataLayoutMigrator::new(src.path().to_str().unwrap());
+        let report = migrator.migrate_to(target.to_str().unwrap()).unwrap();
+        assert!(is_clean(&report));
+        assert_eq!(report.checked_files, 1);
+
+        let moved = ConversationStore::new(target.to_str().unwrap());
+        assert_eq!(moved.load_conversation(&conv.id).unwrap(), conv);
+
+        // 目标已有布局时拒绝覆盖
+        assert!(migrator.migrate_to(target.to_str().unwrap()).is_err());
+    }
+}
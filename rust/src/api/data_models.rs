@@ -8,6 +8,204 @@ pub enum ChatStreamEvent {
     ThinkingDelta(String),
     Done,
     Error(String),
+    /// 本轮开始前采用了新的设置（设置热更新生效）
+    ConfigChanged,
+    /// 用户消息落盘后、正式回复之前的即时反应
+    Reaction(ReactionEvent),
+    /// 快捷命令的执行结果（系统提示样式展示，不写入对话）
+    SystemNotice(String),
+    /// 场景插画已生成：已写入对话的插画消息（图片在 attachments 中）
+    Illustration(Message),
+    /// 回复的语音已合成（回复落盘之后）
+    AudioReady(AudioReadyEvent),
+    /// 回复已落盘，附带生成统计（紧接在 Done 之前；回复未能生成时不发送）
+    Completed(GenerationCompletedEvent),
+    /// 离线发件箱状态变化：离线时消息入队（Queued），网络恢复后开始重放（Sending，
+    /// 其后是该条发送的正常流事件直到 Done），重放失败（Failed）
+    Outbox(OutboxEntry),
+    /// 内容安全过滤命中（拦截时其后紧跟 Error）
+    Safety(SafetyEvent),
+    /// 角色主动联系（值为对话 ID）：其后是该对话这条消息的正常流事件直到 Done
+    CheckIn(String),
+    /// 手动摘要的草稿（尚未写入记忆，确认后才保存，见 approve_memory_summary）
+    SummaryPreview(MemorySummary),
+    /// 快速草稿的增量（AppSettings::fast_draft 开启时，完整管线的回复到达前先行展示）
+    DraftDelta(String),
+    /// 完整管线的回复（整段替换快速草稿；其后是 Completed、Done）
+    Refined(String),
+}
+
+/// 角色主动联系的设置（每个对话一份，见 watch_check_ins）
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CheckInSchedule {
+    pub conversation_id: String,
+    pub enabled: bool,
+    /// 对方沉默多少小时后由角色主动发一条消息
+    pub silence_hours: u32,
+    /// 上次主动联系的时间；对方回复之前不会再次主动联系
+    #[serde(default)]
+    pub last_sent_at: Option<i64>,
+}
+
+/// 发件箱条目的状态
+#[frb]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum OutboxStatus {
+    /// 等待网络恢复
+    #[default]
+    Queued,
+    /// 正在重放
+    Sending,
+    /// 重放失败（用户消息可能已写入对话，不再自动重试）
+    Failed,
+}
+
+/// 离线时暂存的一次发送：网络恢复后按入队顺序重放
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OutboxEntry {
+    pub id: String,
+    pub conversation_id: String,
+    pub content: String,
+    #[serde(default)]
+    pub attachments: Vec<MessageAttachment>,
+    pub model: String,
+    pub enable_thinking: bool,
+    #[serde(default)]
+    pub status: OutboxStatus,
+    pub queued_at: i64,
+    /// 最近一次重放失败的原因
+    #[serde(default)]
+    pub last_error: Option<String>,
+}
+
+/// 一条回复的生成统计：结束原因、用量、回退后实际使用的模型与耗时
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GenerationCompletedEvent {
+    pub message_id: String,
+    pub metadata: GenerationMetadata,
+}
+
+/// 一条回复的语音合成完成
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AudioReadyEvent {
+    pub message_id: String,
+    /// 本地音频文件路径
+    pub audio_path: String,
+}
+
+/// 角色对用户消息的即时反应（表情 + 简短标签）
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReactionEvent {
+    pub emoji: String,
+    pub label: String,
+}
+
+/// 草稿语气（发送前预检）
+#[frb]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum DraftTone {
+    Calm,
+    Warm,
+    Playful,
+    Sad,
+    Anxious,
+    Sarcastic,
+    Hostile,
+}
+
+/// 发送前预检建议：只提示，不拦截发送
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PreflightAdvisory {
+    pub tone: DraftTone,
+    /// 后悔风险 0.0-1.0
+    pub regret_risk: f64,
+    /// 发送后关系张力的预测变化（正 = 更紧张）
+    pub tension_delta: f64,
+    /// 发送后亲密度的预测变化（负 = 更疏远）
+    pub closeness_delta: f64,
+    /// 深夜（0-5 点）发送
+    pub late_night: bool,
+    /// 风险超过阈值，建议缓一缓再发
+    pub suggest_pause: bool,
+    /// 面向用户的提示语
+    pub notes: Vec<String>,
+}
+
+/// 从用户纠正中学到的回避话题（按对话，即按角色）
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BlockedTopic {
+    pub id: String,
+    /// 话题描述（用户点名的话题，或被回避的问题）
+    pub topic: String,
+    /// 话题关键词（多次回避按关键词归并）
+    pub keywords: Vec<String>,
+    /// 用户原话（最近几次）
+    pub evidence: Vec<String>,
+    /// 回避次数
+    pub occurrences: u32,
+    /// 用户明确说过「别聊这个」之类的话
+    pub explicit: bool,
+    /// 已生效：明确叫停一次，或敷衍累计多次
+    pub active: bool,
+    pub first_seen: i64,
+    pub last_seen: i64,
+}
+
+/// 用户编写的世界设定条目（Lorebook）：最近对话命中触发词时注入上下文
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LoreEntry {
+    /// 为空时由存储层生成
+    pub id: String,
+    pub title: String,
+    pub content: String,
+    /// 触发词（不区分大小写，命中任意一个即触发）
+    pub keywords: Vec<String>,
+    /// 插入深度：条目之后保留的历史消息条数（0 = 放在全部历史之后）
+    pub insertion_depth: u32,
+    /// 优先级：预算不足时高优先级先入选
+    pub priority: i32,
+    pub enabled: bool,
+}
+
+/// 群聊中的一个角色：独立的人设与知识/记忆命名空间
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GroupCharacter {
+    /// 为空时由存储层生成
+    pub id: String,
+    pub name: String,
+    /// 该角色的人设（system prompt）
+    pub system_prompt: String,
+}
+
+/// 群聊的发言调度方式
+#[derive(Default)]
+#[frb]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum TurnPolicy {
+    /// 按角色顺序轮流发言
+    #[default]
+    RoundRobin,
+    /// 由快速模型根据上下文挑选下一位发言者（失败时退回轮流）
+    ModelChosen,
+}
+
+/// 多角色群聊配置（按对话存放）
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GroupChat {
+    pub conversation_id: String,
+    pub characters: Vec<GroupCharacter>,
+    #[serde(default)]
+    pub turn_policy: TurnPolicy,
 }
 
 #[derive(Default)]
@@ -18,6 +216,12 @@ pub enum MessageType {
     Say,
     Do,
     Mixed,
+    /// 共写模式产出的正文片段（与聊天消息并存于同一对话）
+    Document,
+    /// 第三人称的旁白 / 场景描写（如「【旁白】夜幕降临……」）
+    Narration,
+    /// 跳出角色的场外交流（如「(OOC: 这段剧情是不是太快了)」）
+    OutOfCharacter,
 }
 
 
@@ -32,6 +236,132 @@ pub struct Message {
     pub timestamp: i64,
     #[serde(default)]
     pub message_type: MessageType,
+    /// 回复的生成信息（结束原因、用量、降级）；用户消息与旧数据为 None
+    #[serde(default)]
+    pub generation_metadata: Option<GenerationMetadata>,
+    /// 群聊中发言角色的ID（GroupCharacter::id）；单角色对话与用户消息为 None
+    #[serde(default)]
+    pub character_id: Option<String>,
+    /// 用户附带的图片；图片数据单独存放，这里只保留元信息
+    #[serde(default)]
+    pub attachments: Vec<MessageAttachment>,
+    /// 回复语音的本地文件路径（见 tts）；未合成时为 None
+    #[serde(default)]
+    pub audio_path: Option<String>,
+    /// 同一轮生成过的全部版本（含当前显示的这一版）；从未重新生成过时为空
+    #[serde(default)]
+    pub alternatives: Vec<MessageAlternative>,
+    /// 当前显示的是 alternatives 中的第几版
+    #[serde(default)]
+    pub selected_alternative: u32,
+    /// 用户收藏：收藏过的时刻在长期记忆检索中加权（见 MemoryEngine::starred_turns）
+    #[serde(default)]
+    pub starred: bool,
+    /// 用户对这条消息的表情回应（去重，按添加顺序）
+    #[serde(default)]
+    pub reactions: Vec<String>,
+    /// 被编辑替换掉的旧版本（旧的在前）；撤销编辑时从末尾恢复
+    #[serde(default)]
+    pub edit_history: Vec<MessageEdit>,
+}
+
+impl Message {
+    /// 新消息：生成 ID、记录当前时间，其余字段取空值。
+    /// 需要设置其它字段时用结构体更新语法：`Message { character_id, ..Message::new(..) }`
+    #[frb(ignore)]
+    pub fn new(role: MessageRole, content: impl Into<String>, model: impl Into<String>) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            role,
+            content: content.into(),
+            thinking_content: None,
+            model: model.into(),
+            timestamp: chrono::Utc::now().timestamp_millis(),
+            message_type: MessageType::Say,
+            generation_metadata: None,
+            character_id: None,
+            attachments: Vec::new(),
+            audio_path: None,
+            alternatives: Vec::new(),
+            selected_alternative: 0,
+            starred: false,
+            reactions: Vec::new(),
+            edit_history: Vec::new(),
+        }
+    }
+
+    /// 只随本次请求发给模型的系统指令：不写入对话，所以没有 ID，时间戳为 0。
+    /// 要写入对话的系统消息仍用 `Message::new`
+    #[frb(ignore)]
+    pub fn system_prompt(content: impl Into<String>) -> Self {
+        Self {
+            id: String::new(),
+            timestamp: 0,
+            ..Self::new(MessageRole::System, content, "system")
+        }
+    }
+}
+
+/// 同一轮回复的一个版本（重新生成时追加，可左右切换）
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MessageAlternative {
+    pub content: String,
+    pub thinking_content: Option<String>,
+    pub model: String,
+    pub timestamp: i64,
+    #[serde(default)]
+    pub generation_metadata: Option<GenerationMetadata>,
+    #[serde(default)]
+    pub audio_path: Option<String>,
+}
+
+/// 消息被编辑前的一个版本
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MessageEdit {
+    pub content: String,
+    /// 这一版原本的时间戳
+    pub timestamp: i64,
+    /// 被替换的时刻
+    pub edited_at: i64,
+}
+
+/// 消息附件（图片）
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MessageAttachment {
+    pub id: String,
+    pub mime_type: String,
+    /// 远程图片地址；发送时也可传 data URL，落盘后本地图片为 None
+    pub url: Option<String>,
+    /// 视觉模型看到的内容描述（识图后写回，供后续轮次与知识库使用）
+    pub caption: Option<String>,
+}
+
+/// 单条回复的生成信息，供消息详情页展示
+#[frb]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GenerationMetadata {
+    /// 实际生成回复的模型（优先取服务端返回的名称）
+    pub model: String,
+    /// 结束原因（stop / length / sensitive / end_turn …），服务端未给出时为 None
+    pub finish_reason: Option<String>,
+    /// token 用量；服务端未返回用量时均为 0
+    pub prompt_tokens: u32,
+    pub completion_tokens: u32,
+    pub total_tokens: u32,
+    /// 输入中命中提供方上下文缓存的 token 数（服务端未返回时为 0）
+    #[serde(default)]
+    pub cached_tokens: u32,
+    /// false 表示弱网模式下以非流式请求完成
+    pub streamed: bool,
+    /// 流在中途断开，回复只保留了已收到的部分
+    pub interrupted: bool,
+    /// 本轮生成中触发的降级与回退（重试、压缩、换模型等的说明）
+    pub fallbacks: Vec<String>,
+    /// 从开始生成到回复落盘的耗时（毫秒）
+    pub latency_ms: i64,
 }
 
 #[frb]
@@ -70,6 +400,231 @@ pub struct Conversation {
     pub turn_count: u32,
     #[serde(default)]
     pub memory_summaries: Vec<MemorySummary>,
+    #[serde(default)]
+    pub mode: ConversationMode,
+    #[serde(default)]
+    pub thinking_retention: ThinkingRetention,
+    /// 当前所在分支（时间线）
+    #[serde(default = "default_branch_id")]
+    pub branch_id: String,
+    /// 当前分支的分叉点消息ID；主线为 None
+    #[serde(default)]
+    pub parent_message_id: Option<String>,
+    /// 全部分支的记录；首次分叉前为空（只有隐含的主线）。
+    /// 当前分支的消息就是 messages，其记录中的 messages/memory_summaries 留空
+    #[serde(default)]
+    pub branches: Vec<ConversationBranch>,
+    /// 叙述视角（第一人称 / 第三人称约束）
+    #[serde(default)]
+    pub narration: NarrationPerspective,
+    /// 上下文增强各层的开关
+    #[serde(default)]
+    pub context_layers: ContextLayers,
+    /// 故事收束的时间；非空时对话只读（仍可浏览）
+    #[serde(default)]
+    pub closed_at: Option<i64>,
+    /// 回复风格偏好（长度、正式程度、表情、动作描写）
+    #[serde(default)]
+    pub response_style: ResponseStyle,
+    /// 本对话中用户使用的人设 ID（见 PersonaStore）；None 时不注入人设
+    #[serde(default)]
+    pub persona_id: Option<String>,
+    /// 标签、文件夹、置顶与归档（只影响对话列表的整理）
+    #[serde(default)]
+    pub organization: ConversationOrganization,
+    /// 已移入冷存储的用户轮数：实际轮数 = cold_turns + messages 中的用户消息数
+    #[serde(default)]
+    pub cold_turns: u32,
+}
+
+/// 故事收束的结果
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConversationClosure {
+    /// 角色的尾声（已作为最后一条回复写入对话）
+    pub epilogue: String,
+    /// 整段故事的最终回顾
+    pub recap: String,
+    /// 导出的 Markdown 归档路径
+    pub archive_path: String,
+    pub closed_at: i64,
+}
+
+/// 主线分支ID
+pub const MAIN_BRANCH_ID: &str = "main";
+
+fn default_branch_id() -> String {
+    MAIN_BRANCH_ID.to_string()
+}
+
+/// 对话分支：从某条消息分叉出的另一条时间线
+/// 未激活时保存该分支的完整历史与记忆，切换时整体换入
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConversationBranch {
+    pub id: String,
+    pub name: String,
+    /// 分叉点消息ID；主线为 None
+    pub parent_message_id: Option<String>,
+    pub messages: Vec<Message>,
+    pub turn_count: u32,
+    pub memory_summaries: Vec<MemorySummary>,
+    pub created_at: i64,
+    /// 该分支已移入冷存储的用户轮数（见 Conversation::cold_turns）
+    #[serde(default)]
+    pub cold_turns: u32,
+}
+
+/// 分支列表项（不含消息正文）
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BranchInfo {
+    pub id: String,
+    pub name: String,
+    pub parent_message_id: Option<String>,
+    pub message_count: u32,
+    pub last_message_preview: String,
+    pub created_at: i64,
+    pub is_active: bool,
+}
+
+/// 思考内容（thinking_content）保留策略，由维护任务执行
+#[derive(Default)]
+#[frb]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum ThinkingRetention {
+    /// 永久保留
+    #[default]
+    KeepAll,
+    /// 只保留最近 N 轮的思考内容
+    KeepLastTurns(u32),
+    /// 所在轮次被记忆摘要覆盖后丢弃
+    DiscardAfterSummary,
+}
+
+/// 对话模式：聊天伙伴 / 长文共写
+#[derive(Default)]
+#[frb]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum ConversationMode {
+    #[default]
+    Chat,
+    CoAuthor,
+}
+
+/// 叙述视角：不限 / 第一人称角色口吻 / 第三人称小说旁白
+#[derive(Default)]
+#[frb]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub enum NarrationPerspective {
+    #[default]
+    Free,
+    FirstPerson,
+    ThirdPerson,
+}
+
+/// 上下文增强层开关：某些角色被个别提示层带偏时，可逐层关闭（默认全部开启）
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ContextLayers {
+    /// 短期记忆（情绪轨迹、未展开线索）
+    pub short_term_memory: bool,
+    /// 认知快照（意图、共情策略、情绪与关系数值）
+    pub cognitive_snapshot: bool,
+    /// 回复多样性提示
+    pub diversity_hint: bool,
+    /// 拟人化提示
+    pub humanization_hint: bool,
+    /// 知识库事实注入
+    pub knowledge_injection: bool,
+}
+
+impl Default for ContextLayers {
+    fn default() -> Self {
+        Self {
+            short_term_memory: true,
+            cognitive_snapshot: true,
+            diversity_hint: true,
+            humanization_hint: true,
+            knowledge_injection: true,
+        }
+    }
+}
+
+/// 回复长度偏好
+#[derive(Default)]
+#[frb]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ReplyLength {
+    /// 按对方消息与场景自动判断
+    #[default]
+    Auto,
+    Short,
+    Medium,
+    Long,
+}
+
+/// 用语正式程度偏好
+#[derive(Default)]
+#[frb]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Formality {
+    #[default]
+    Auto,
+    Casual,
+    Neutral,
+    Formal,
+}
+
+/// 表情 / 颜文字使用偏好
+#[derive(Default)]
+#[frb]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EmojiUsage {
+    #[default]
+    Auto,
+    Never,
+    Occasional,
+    Frequent,
+}
+
+/// 动作描写频率偏好
+#[derive(Default)]
+#[frb]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ActionFrequency {
+    #[default]
+    Auto,
+    Never,
+    Occasional,
+    Frequent,
+}
+
+/// 对话的回复风格偏好：Auto 项沿用拟人化提示的启发式判断
+#[frb]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ResponseStyle {
+    pub reply_length: ReplyLength,
+    pub formality: Formality,
+    pub emoji_usage: EmojiUsage,
+    pub action_frequency: ActionFrequency,
+}
+
+/// 用户在对话中扮演的身份（personas.json），每个对话可选用一个
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserPersona {
+    pub id: String,
+    pub name: String,
+    /// 外貌、性格、背景等自由描述
+    #[serde(default)]
+    pub description: String,
+    /// 人称代词（如「她」「他」「ta」），为空时按名字称呼
+    #[serde(default)]
+    pub pronouns: String,
+    pub created_at: i64,
 }
 
 #[frb]
@@ -88,6 +643,18 @@ pub struct MemorySummary {
     pub context_card: Option<MemoryContextCard>,
     #[serde(default)]
     pub fact_tiers: Vec<MemoryTier>,
+    /// 知识库中描述同一内容的事实 ID（与 Fact::summary_ids 互为反向链接）
+    #[serde(default)]
+    pub linked_fact_ids: Vec<String>,
+    /// 用户置顶：整条摘要不参与分级合并，原样保留
+    #[serde(default)]
+    pub pinned: bool,
+    /// 用户置顶的核心事实（core_facts 中的原文）：合并时一字不改地带入合并结果
+    #[serde(default)]
+    pub pinned_facts: Vec<String>,
+    /// 覆盖轮次内的消息被编辑过：内容已过时，等待后台按编辑后的原文重新总结
+    #[serde(default)]
+    pub stale: bool,
 }
 
 /// 压缩影响等级 — 随压缩代数递增，逐步影响不同维度
@@ -115,62 +682,381 @@ pub struct ConversationSummary {
     pub last_message_preview: String,
     pub model: String,
     pub updated_at: i64,
+    #[serde(default)]
+    pub organization: ConversationOrganization,
 }
 
+/// 对话的整理信息：标签、文件夹、置顶、归档
 #[frb]
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct AppSettings {
-    pub api_key: Option<String>,
-    pub default_model: String,
-    pub enable_thinking_by_default: bool,
-    #[serde(default = "default_chat_model")]
-    pub chat_model: String,
-    #[serde(default = "default_thinking_model")]
-    pub thinking_model: String,
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConversationOrganization {
+    /// 标签（去重，按添加顺序）
+    pub tags: Vec<String>,
+    /// 所在文件夹；None 为未归类
+    pub folder: Option<String>,
+    /// 置顶：筛选列表中排在最前
+    pub pinned: bool,
+    /// 归档：不出现在未归档的筛选列表中
+    pub archived: bool,
 }
 
-fn default_chat_model() -> String {
-    "glm-4.7".to_string()
+/// 对话列表的筛选条件（各项同时满足）
+#[frb]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConversationFilter {
+    /// 只列出该文件夹中的对话；None 为不限
+    pub folder: Option<String>,
+    /// 必须同时带有的标签
+    pub tags: Vec<String>,
+    /// true 只列出已归档的对话，false 只列出未归档的
+    pub archived: bool,
+    /// 标题中包含的文字（不区分大小写）；空为不限
+    pub query: String,
 }
 
-fn default_thinking_model() -> String {
-    "glm-4-air".to_string()
+/// 内容安全分类
+#[frb]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SafetyCategory {
+    /// 自伤、自杀
+    SelfHarm,
+    /// 暴力伤害的具体方法
+    Violence,
+    /// 露骨色情
+    Sexual,
+    /// 仇恨、歧视
+    Hate,
+    /// 违法犯罪的具体方法（毒品、武器、诈骗等）
+    Illegal,
 }
 
-impl Default for AppSettings {
-    fn default() -> Self {
-        Self {
-            api_key: None,
-            default_model: "glm-4.7".to_string(),
-            enable_thinking_by_default: true,
-            chat_model: "glm-4.7".to_string(),
-            thinking_model: "glm-4-air".to_string(),
-        }
-    }
+/// 命中某一分类后的处理方式
+#[frb]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SafetyAction {
+    /// 拦截：输入不发送、回复不落盘
+    Block,
+    /// 缓和：输入时提示模型谨慎处理，回复中的命中词语遮蔽后落盘
+    Soften,
+    /// 仅通知：发送 Safety 事件，内容不变
+    Warn,
 }
 
+/// 检查发生的阶段
 #[frb]
-#[derive(Debug, Clone)]
-pub struct ModelInfo {
-    pub id: String,
-    pub name: String,
-    pub context_tokens: usize,
-    pub max_output_tokens: usize,
-    pub supports_thinking: bool,
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SafetyStage {
+    /// 用户消息发送前
+    Input,
+    /// 回复生成后
+    Output,
 }
 
+/// 一个分类的处理规则
 #[frb]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct MemoryContextCard {
-    pub source_range: String,
-    pub topic_tags: Vec<String>,
-    pub key_entities: Vec<String>,
-    pub emotional_tone: String,
-    pub causal_links: Vec<String>,
+pub struct SafetyRule {
+    pub category: SafetyCategory,
+    pub action: SafetyAction,
+    /// 在内置词表之外追加的词语（英文不区分大小写）
+    #[serde(default)]
+    pub extra_terms: Vec<String>,
 }
 
+/// 对话的记忆摘要节奏（summarization.json，见 ConfigManager::load_summarization_config）
 #[frb]
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SummarizationConfig {
+    /// 每多少轮生成一次摘要
+    pub interval_turns: u32,
+    /// 每次摘要读取的最近消息条数
+    pub window_messages: u32,
+}
+
+impl Default for SummarizationConfig {
+    fn default() -> Self {
+        Self {
+            interval_turns: 10,
+            window_messages: 20,
+        }
+    }
+}
+
+/// 数据保留策略（retention.json）；各项为 0 / false 时不启用
+#[frb]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RetentionPolicy {
+    /// 闲置超过多少天的对话自动归档
+    pub archive_after_idle_days: u32,
+    /// 每个对话主线保留的消息上限；更早的消息转入冷存储
+    pub max_messages_per_conversation: u32,
+    /// 清理对话已不存在的记忆 / 知识文件
+    pub purge_orphans: bool,
+}
+
+/// 一次数据维护的结果
+#[frb]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct HousekeepingReport {
+    /// 本次自动归档的对话ID
+    pub archived: Vec<String>,
+    /// 转入冷存储的消息条数
+    pub rolled_over_messages: u32,
+    /// 删除的孤立文件数
+    pub purged_files: u32,
+}
+
+/// 内容安全过滤设置（safety.json）；没有规则的分类不检查
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SafetyPolicy {
+    pub enabled: bool,
+    /// 检查用户消息
+    pub check_input: bool,
+    /// 检查生成的回复
+    pub check_output: bool,
+    pub rules: Vec<SafetyRule>,
+}
+
+impl Default for SafetyPolicy {
+    fn default() -> Self {
+        let rule = |category, action| SafetyRule {
+            category,
+            action,
+            extra_terms: Vec::new(),
+        };
+        Self {
+            enabled: false,
+            check_input: true,
+            check_output: true,
+            rules: vec![
+                rule(SafetyCategory::SelfHarm, SafetyAction::Soften),
+                rule(SafetyCategory::Violence, SafetyAction::Warn),
+                rule(SafetyCategory::Sexual, SafetyAction::Soften),
+                rule(SafetyCategory::Hate, SafetyAction::Soften),
+                rule(SafetyCategory::Illegal, SafetyAction::Block),
+            ],
+        }
+    }
+}
+
+/// 安全过滤的一次命中
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SafetyEvent {
+    pub stage: SafetyStage,
+    pub category: SafetyCategory,
+    pub action: SafetyAction,
+    /// 命中的词语（按出现顺序，去重）
+    pub matched: Vec<String>,
+}
+
+/// 生成内容强度：情绪升级、冲突与粗口的尺度
+#[derive(Default)]
+#[frb]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum ContentIntensity {
+    /// 温和：不升级冲突，不说粗口
+    Mild,
+    #[default]
+    Normal,
+    /// 戏剧化：允许激烈冲突与符合人设的粗口
+    Dramatic,
+}
+
+/// 回复提供方：智谱在线模型 / 本地离线回声（演示、测试、无 API Key 时使用）
+/// / OpenAI 兼容端点 / Anthropic / 本地大模型（Ollama、llama.cpp，协议适配见 chat_provider）
+#[derive(Default)]
+#[frb]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum ProviderKind {
+    #[default]
+    Zhipu,
+    LocalEcho,
+    OpenAiCompatible,
+    Anthropic,
+    LocalLlm,
+}
+
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AppSettings {
+    pub api_key: Option<String>,
+    pub default_model: String,
+    pub enable_thinking_by_default: bool,
+    #[serde(default = "default_chat_model")]
+    pub chat_model: String,
+    #[serde(default = "default_thinking_model")]
+    pub thinking_model: String,
+    /// 允许对现实信息类提问联网搜索
+    #[serde(default)]
+    pub enable_web_search: bool,
+    #[serde(default)]
+    pub content_intensity: ContentIntensity,
+    #[serde(default)]
+    pub provider: ProviderKind,
+    /// 非智谱提供方的接口地址（OpenAI 兼容端点必填，Anthropic / 本地模型留空则用默认地址）
+    #[serde(default)]
+    pub provider_base_url: Option<String>,
+    /// 非智谱提供方的模型名（GLM 模型名无法沿用，所有角色统一映射到此模型）
+    #[serde(default)]
+    pub provider_model: Option<String>,
+    /// 非智谱提供方的 API Key（与智谱 id.secret 格式不同，单独保存）
+    #[serde(default)]
+    pub provider_api_key: Option<String>,
+    /// 知识注入影子评估的抽样比例（0.0 关闭，1.0 每轮都评估）
+    #[serde(default)]
+    pub shadow_eval_rate: f64,
+    /// 回复落盘后合成语音（音色按角色配置，见 ConfigManager::load_voices）
+    #[serde(default)]
+    pub enable_tts: bool,
+    /// 网络代理（None 时沿用系统代理环境变量）
+    #[serde(default)]
+    pub proxy: Option<ProxySettings>,
+    /// 请求失败的重试策略
+    #[serde(default)]
+    pub retry: RetryPolicy,
+    /// 每轮注入现实时间（本地时间、星期、距上一条消息多久），见 time_awareness
+    #[serde(default = "default_time_awareness")]
+    pub time_awareness: bool,
+    /// 快速草稿：推理管线运行期间先由 glm-4.7-flash 流式给出简短回复（DraftDelta），
+    /// 完整回复到达后以 Refined 替换（仅思考模式下生效）
+    #[serde(default)]
+    pub fast_draft: bool,
+}
+
+/// 网络代理：http:// / https:// / socks5:// / socks5h:// 地址
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProxySettings {
+    pub url: String,
+    #[serde(default)]
+    pub username: Option<String>,
+    #[serde(default)]
+    pub password: Option<String>,
+    /// 不走代理的主机（域名、IP 或 CIDR；本机地址始终直连）
+    #[serde(default)]
+    pub bypass: Vec<String>,
+}
+
+/// 重试间隔的增长方式
+#[derive(Default)]
+#[frb]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum BackoffStrategy {
+    /// 每次等待 initial_delay_ms
+    Fixed,
+    /// 每次翻倍
+    Exponential,
+    /// 翻倍后在 [一半, 全部] 之间随机取值，避免多个请求同时重试
+    #[default]
+    ExponentialJitter,
+}
+
+/// 请求失败的重试策略（见 error_handler::RetryHandler）
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RetryPolicy {
+    /// 首次失败后最多再试几次
+    pub max_retries: u32,
+    pub initial_delay_ms: u64,
+    /// 单次退避等待的上限
+    pub max_delay_ms: u64,
+    pub backoff: BackoffStrategy,
+    /// 网络错误（连接失败、超时）
+    pub retry_network_errors: bool,
+    /// 流中断 / 响应格式错误
+    pub retry_stream_errors: bool,
+    /// 服务端 5xx
+    pub retry_server_errors: bool,
+    /// 429 / 并发与频率限制
+    pub retry_rate_limited: bool,
+    /// 服务端要求等待（Retry-After）超过该秒数时不再重试
+    pub max_retry_after_secs: u64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_delay_ms: 1000,
+            max_delay_ms: 30_000,
+            backoff: BackoffStrategy::ExponentialJitter,
+            retry_network_errors: true,
+            retry_stream_errors: true,
+            retry_server_errors: true,
+            retry_rate_limited: true,
+            max_retry_after_secs: 60,
+        }
+    }
+}
+
+fn default_time_awareness() -> bool {
+    true
+}
+
+fn default_chat_model() -> String {
+    "glm-4.7".to_string()
+}
+
+fn default_thinking_model() -> String {
+    "glm-4-air".to_string()
+}
+
+impl Default for AppSettings {
+    fn default() -> Self {
+        Self {
+            api_key: None,
+            default_model: "glm-4.7".to_string(),
+            enable_thinking_by_default: true,
+            chat_model: "glm-4.7".to_string(),
+            thinking_model: "glm-4-air".to_string(),
+            enable_web_search: false,
+            content_intensity: ContentIntensity::Normal,
+            provider: ProviderKind::Zhipu,
+            provider_base_url: None,
+            provider_model: None,
+            provider_api_key: None,
+            shadow_eval_rate: 0.0,
+            enable_tts: false,
+            proxy: None,
+            retry: RetryPolicy::default(),
+            time_awareness: true,
+            fast_draft: false,
+        }
+    }
+}
+
+#[frb]
+#[derive(Debug, Clone)]
+pub struct ModelInfo {
+    pub id: String,
+    pub name: String,
+    pub context_tokens: usize,
+    pub max_output_tokens: usize,
+    pub supports_thinking: bool,
+}
+
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MemoryContextCard {
+    pub source_range: String,
+    pub topic_tags: Vec<String>,
+    pub key_entities: Vec<String>,
+    pub emotional_tone: String,
+    pub causal_links: Vec<String>,
+}
+
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum MemoryTier {
     Identity,
     CriticalEvent,
@@ -187,6 +1073,31 @@ pub struct MemorySearchResult {
     pub relevance_score: f64,
 }
 
+/// 摘要链接到的一条知识库事实
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LinkedFact {
+    pub fact_id: String,
+    pub content: String,
+    pub source_turn: u32,
+    /// 同样链接到该事实的摘要 ID（含当前摘要）
+    pub summary_ids: Vec<String>,
+}
+
+/// 记忆溯源：一条摘要及其链接的事实
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MemoryExplanation {
+    pub summary_id: String,
+    pub summary: String,
+    pub turn_range_start: u32,
+    pub turn_range_end: u32,
+    pub compression_generation: u32,
+    pub linked_facts: Vec<LinkedFact>,
+}
+
+/// 长上下文蒸馏的持久化缓存（见 MemoryEngine::load_fresh_distilled_state）
+#[frb]
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DistilledSystemState {
     pub core_prompt: String,
@@ -197,3 +1108,899 @@ pub struct DistilledSystemState {
     pub distilled_at: i64,
     pub core_facts_snapshot: Vec<String>,
 }
+
+/// 完整性问题类型
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum IntegrityIssueKind {
+    /// turn_count 与实际用户消息轮数不一致
+    TurnCountMismatch,
+    /// 记忆摘要的轮次范围越界或倒置
+    SummaryRangeInvalid,
+    /// 记忆索引文件与对话内嵌摘要不同步
+    SummaryIndexDesync,
+    /// 事实的来源轮次不存在
+    OrphanFact,
+    /// 倒排索引指向不存在的事实，或事实未被索引
+    DanglingIndexEntry,
+}
+
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IntegrityIssue {
+    pub kind: IntegrityIssueKind,
+    pub detail: String,
+    pub repaired: bool,
+}
+
+/// 对话完整性检查报告
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IntegrityReport {
+    pub conversation_id: String,
+    /// 按用户消息统计的实际轮数
+    pub actual_turns: u32,
+    /// 对话记录中的 turn_count
+    pub recorded_turn_count: u32,
+    pub issues: Vec<IntegrityIssue>,
+    pub checked_at: i64,
+}
+
+/// 宿主 App 提供的环境上下文（全部可选，缺省即不注入）
+#[frb]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AmbientContext {
+    /// 天气描述，如「小雨 12°C」
+    pub weather: Option<String>,
+    /// 今日步数
+    pub step_count: Option<u32>,
+    /// 正在播放的歌曲
+    pub now_playing: Option<String>,
+    /// 日程忙闲：true=忙碌中，false=空闲
+    pub calendar_busy: Option<bool>,
+}
+
+/// 降级/回退决策类型
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum DegradationKind {
+    /// 思考模式只返回了思考内容，关闭思考重试
+    ThinkingDropped,
+    /// 压缩上下文后重试
+    CompactRetry,
+    /// 回退到快速模型
+    ModelFallback,
+    /// 推理阶段超时被跳过
+    ReasoningTimeout,
+    /// 长上下文蒸馏超时被跳过
+    DistillationTimeout,
+    /// 历史消息因 token 预算被截断
+    ContextTruncated,
+    /// 事实提取超时
+    FactExtractionTimeout,
+}
+
+/// 单条降级决策记录（按轮次持久化）
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DegradationRecord {
+    pub turn: u32,
+    pub kind: DegradationKind,
+    pub model: String,
+    pub detail: String,
+    pub timestamp: i64,
+}
+
+/// 记忆保真度审计结果：历史检查点中的事实有多少仍能从当前摘要推出
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MemoryFidelityReport {
+    pub conversation_id: String,
+    /// 保真度 0.0-1.0（抽样事实中仍可推出的比例）
+    pub score: f64,
+    pub sampled_facts: u32,
+    /// 抽样中已无法从当前摘要推出的事实
+    pub lost_facts: Vec<String>,
+    /// 上一次审计的保真度
+    pub previous_score: Option<f64>,
+    /// 保真度过低或较上次明显下降
+    pub alert: bool,
+    pub audited_at: i64,
+}
+
+/// 一条回复的质量信号（影子评估用）
+#[frb]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReplySignals {
+    pub length_chars: u32,
+    /// 回复中能推出的本轮注入事实数
+    pub grounded_facts: u32,
+    /// 与用户本轮输入的关键词重合度 0.0-1.0
+    pub user_overlap: f64,
+    /// 与上一条 AI 回复的相似度 0.0-1.0（越高越像在复读）
+    pub repetition: f64,
+}
+
+/// 一次影子评估：正式回复（有记忆/知识注入）与无注入的影子回复对比
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShadowEvalRecord {
+    pub turn: u32,
+    /// 本轮注入的事实数（检索到的知识 + 记忆核心事实）
+    pub injected_facts: u32,
+    pub main_model: String,
+    pub shadow_model: String,
+    pub main: ReplySignals,
+    pub shadow: ReplySignals,
+    pub created_at: i64,
+}
+
+/// 影子评估的汇总：注入带来的平均差值（正式 − 影子）
+#[frb]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ShadowEvalSummary {
+    pub samples: u32,
+    pub avg_grounded_gain: f64,
+    pub avg_overlap_gain: f64,
+    /// 负值表示注入后更少复读
+    pub avg_repetition_delta: f64,
+    /// 正式回复引用的注入事实多于影子回复的样本比例
+    pub grounded_win_rate: f64,
+}
+
+/// 每日心声中的一条：某个角色今天想对用户说的一句话
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DigestEntry {
+    pub conversation_id: String,
+    pub title: String,
+    /// 角色口吻的一句话
+    pub note: String,
+    /// 对话最后活跃时间
+    pub last_active_at: i64,
+}
+
+/// 每日心声：过去 24 小时内活跃的角色各自的一句话
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DailyDigest {
+    pub generated_at: i64,
+    /// 按对话活跃时间倒序
+    pub entries: Vec<DigestEntry>,
+    /// 因数量/成本上限或生成失败而跳过的对话数
+    pub skipped: u32,
+    /// 本次生成预估消耗的 token 数
+    pub estimated_tokens: u32,
+}
+
+/// 久别重逢：重新打开沉寂已久的对话时的前情提要与开场建议
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReengagementBrief {
+    pub conversation_id: String,
+    /// 距上一条消息过去的天数
+    pub elapsed_days: u32,
+    /// 「前情提要」：长期记忆与最后几句对话的浓缩
+    pub recap: String,
+    /// 建议的角色开场白（提及分别的时间与没聊完的话题）
+    pub opener: String,
+    /// 上次没聊完的线索
+    pub pending_threads: Vec<String>,
+    pub generated_at: i64,
+    /// 生成时对话的最后一条消息ID；对话有新消息后缓存失效
+    pub last_message_id: String,
+}
+
+/// 角色卡（用于沙盒试聊）
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CharacterCard {
+    pub name: String,
+    /// 角色系统提示词
+    pub system_prompt: String,
+    /// 开场白（可为空）
+    pub greeting: String,
+}
+
+/// 数据目录布局校验结果（与清单快照比对）
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LayoutVerification {
+    pub version: u32,
+    pub checked_files: u32,
+    /// 清单中有、磁盘上缺失的文件
+    pub missing: Vec<String>,
+    /// 内容校验和不一致的文件
+    pub corrupted: Vec<String>,
+    /// 磁盘上有、清单中没有的文件
+    pub unexpected: Vec<String>,
+}
+
+/// CPU 密集任务（检索/指纹分析）在 blocking 线程池上的运行统计
+#[frb]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BlockingStats {
+    pub tasks: u64,
+    pub total_run_ms: u64,
+    pub max_run_ms: u64,
+    /// 提交到开始执行的最长等待（线程池饱和程度）
+    pub max_queue_ms: u64,
+    /// 最慢一次任务的标签
+    pub slowest_label: String,
+}
+
+/// 宿主 App 上报的设备状态，用于后台任务调度（见 job_scheduler）
+#[frb]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DeviceConditions {
+    /// 电量 0.0-1.0；None 表示未知（桌面端等）
+    pub battery_level: Option<f64>,
+    pub is_charging: bool,
+    /// 系统省电模式
+    pub low_power_mode: bool,
+    /// 按流量计费的网络（蜂窝数据等）
+    pub metered_network: bool,
+}
+
+/// 后台任务类型
+#[frb]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BackgroundJobKind {
+    /// 回复后的事实提取
+    FactExtraction,
+    /// 记忆摘要
+    Summarization,
+    /// 长上下文蒸馏（回复管线内，不可延后）
+    Distillation,
+    /// 知识注入影子评估
+    ShadowEval,
+}
+
+/// 因设备压力或配额被延后的后台任务
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeferredJob {
+    pub kind: BackgroundJobKind,
+    pub conversation_id: String,
+    pub deferred_at: i64,
+    /// 延后原因
+    pub reason: String,
+}
+
+/// 后台任务队列中任务的状态
+#[frb]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BackgroundTaskStatus {
+    Queued,
+    Running,
+    Completed,
+    /// 执行失败，或被调度器延后（稍后由 run_deferred_jobs 补跑）
+    Failed,
+}
+
+/// 后台任务的状态变化（见 watch_background_tasks）
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackgroundTaskEvent {
+    pub task_id: String,
+    pub kind: BackgroundJobKind,
+    pub conversation_id: String,
+    pub status: BackgroundTaskStatus,
+    pub updated_at: i64,
+}
+
+/// 后台任务调度统计
+#[frb]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct JobSchedulerStats {
+    /// 正在执行的后台任务数
+    pub running: u32,
+    pub completed: u64,
+    /// 超出单次预算（超时）被中止的任务数
+    pub timed_out: u64,
+    /// 累计被延后的次数
+    pub deferred_total: u64,
+    /// 等待补跑的任务
+    pub pending: Vec<DeferredJob>,
+}
+
+/// 一轮回复中计时的阶段
+#[frb]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MetricPhase {
+    /// 知识库 / 向量检索
+    Retrieval,
+    /// 长上下文蒸馏
+    Distillation,
+    /// 推理模型分析
+    Reasoning,
+    /// 对话模型生成（含回退重试）
+    Chat,
+}
+
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PhaseDuration {
+    pub phase: MetricPhase,
+    pub duration_ms: i64,
+}
+
+/// 一轮回复的性能指标
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TurnMetrics {
+    pub conversation_id: String,
+    pub started_at: i64,
+    pub total_ms: i64,
+    /// 首字延迟；本轮没有推送任何 delta 时为 None
+    pub first_token_ms: Option<i64>,
+    /// 各阶段累计耗时（未执行的阶段不出现）
+    pub phases: Vec<PhaseDuration>,
+    pub retries: u32,
+}
+
+/// 调试面板的性能指标：最近轮次（新的在前）与平均值
+#[frb]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MetricsSnapshot {
+    pub turns: Vec<TurnMetrics>,
+    pub avg_first_token_ms: Option<i64>,
+    /// 各阶段在执行过的轮次中的平均耗时
+    pub avg_phase_ms: Vec<PhaseDuration>,
+    pub total_retries: u32,
+}
+
+/// 重建索引的范围
+#[derive(Default)]
+#[frb]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum IndexScope {
+    #[default]
+    All,
+    /// 知识库：事实关键词 + 倒排索引
+    Knowledge,
+    /// 记忆摘要关键词
+    Memory,
+}
+
+/// 批量重建索引的进度
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReindexProgress {
+    pub conversation_id: String,
+    pub completed: u32,
+    pub total: u32,
+    /// 本对话重建的条目数（事实 + 摘要）；已是最新版本时为 0
+    pub rebuilt_entries: u32,
+    pub error: Option<String>,
+}
+
+/// 备份恢复的范围
+#[derive(Default)]
+#[frb]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum BackupScope {
+    #[default]
+    All,
+    /// 对话、群聊及其附件、语音
+    Conversations,
+    /// 记忆摘要、向量、审计与决策日志
+    Memory,
+    /// 知识库、世界设定、屏蔽话题
+    Knowledge,
+    /// 根目录下的设置文件与自定义提示词模板
+    Config,
+}
+
+/// 备份保留策略：保留最近 keep_last 份，另外每天保留最新一份（最近 keep_daily 天）
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackupRetention {
+    pub keep_last: u32,
+    pub keep_daily: u32,
+}
+
+impl Default for BackupRetention {
+    fn default() -> Self {
+        Self {
+            keep_last: 5,
+            keep_daily: 7,
+        }
+    }
+}
+
+/// 一份备份快照的概要
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackupSnapshotInfo {
+    pub id: String,
+    pub created_at: i64,
+    pub file_count: u32,
+    /// 快照内文件的总大小
+    pub total_bytes: u64,
+    /// 创建时新写入归档的字节数（其余内容与已有快照去重）
+    pub added_bytes: u64,
+}
+
+/// 整库导出归档的概要（导出 / 导入后返回）
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DataExportInfo {
+    /// 归档格式版本
+    pub format_version: u32,
+    /// 归档内数据的布局版本
+    pub layout_version: u32,
+    pub created_at: i64,
+    pub file_count: u32,
+    pub total_bytes: u64,
+}
+
+/// 云同步的远端类型
+#[frb]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SyncBackendKind {
+    #[default]
+    WebDav,
+    /// S3 及兼容的对象存储（MinIO、R2……），路径风格访问
+    S3,
+}
+
+/// 云同步设置（sync.json）；endpoint 为空时不同步
+#[frb]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SyncSettings {
+    pub backend: SyncBackendKind,
+    /// WebDAV 根地址，或 S3 端点（如 https://s3.us-east-1.amazonaws.com）
+    pub endpoint: String,
+    /// WebDAV 用户名 / S3 Access Key ID
+    pub username: String,
+    /// WebDAV 密码 / S3 Secret Access Key
+    pub password: String,
+    /// S3 存储桶（WebDAV 不使用）
+    pub bucket: String,
+    /// S3 区域（WebDAV 不使用）
+    pub region: String,
+    /// 远端目录 / 键前缀：多台设备填同一个即互相同步
+    pub remote_root: String,
+}
+
+impl Default for SyncSettings {
+    fn default() -> Self {
+        Self {
+            backend: SyncBackendKind::WebDav,
+            endpoint: String::new(),
+            username: String::new(),
+            password: String::new(),
+            bucket: String::new(),
+            region: "us-east-1".to_string(),
+            remote_root: "talk2u".to_string(),
+        }
+    }
+}
+
+/// 一次云同步的结果
+#[frb]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SyncReport {
+    /// 上传的文件数
+    pub pushed: u32,
+    /// 下载的文件数
+    pub pulled: u32,
+    /// 按另一端的删除而删掉的文件数（本地与远端合计）
+    pub deleted: u32,
+    /// 两端都改过的文件（相对路径）：保留本地版本，远端版本另存为冲突副本
+    pub conflicts: Vec<String>,
+}
+
+// ── 分析结果的对外镜像（认知引擎 / 短期记忆的内部结构可能随实现调整，这里是稳定版本） ──
+
+/// 对话意图（镜像 cognitive_engine::DialogueIntent）
+#[frb]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum IntentKind {
+    SeekingComfort,
+    ExpressingAffection,
+    ExpressingDispleasure,
+    TestingBoundary,
+    SharingDaily,
+    SeekingResponse,
+    EmotionalVenting,
+    Playful,
+    Reconciling,
+    Farewell,
+    Withdrawn,
+    DeepSharing,
+}
+
+/// 共情策略（镜像 cognitive_engine::EmpathyStrategy）
+#[frb]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum EmpathyKind {
+    Mirror,
+    Accompany,
+    Distract,
+    Responsive,
+    PlayfulCounter,
+    GentleFirm,
+    ProactiveCare,
+    NaturalFlow,
+    GiveSpace,
+    Escalate,
+}
+
+/// 语言模式（镜像 cognitive_engine::LanguagePattern）
+#[frb]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum LanguagePatternKind {
+    Negation,
+    Sarcasm,
+    Hesitation,
+    Repetition,
+    Urgent,
+    Dragging,
+    Contradictory,
+    Probing,
+    Coquettish,
+    Defensive,
+    Suppressed,
+    TopicAvoidance,
+}
+
+/// 情感维度得分（镜像 cognitive_engine::EmotionVector）
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmotionInsight {
+    pub joy: f64,
+    pub sadness: f64,
+    pub anger: f64,
+    pub fear: f64,
+    pub surprise: f64,
+    pub intimacy: f64,
+    pub trust: f64,
+    pub anticipation: f64,
+    /// 综合效价：正=积极，负=消极
+    pub valence: f64,
+    /// 唤醒度：0=平静，1=激动
+    pub arousal: f64,
+}
+
+/// 关系动态（镜像 cognitive_engine::RelationshipDynamics）
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RelationshipInsight {
+    pub closeness: f64,
+    pub trust_level: f64,
+    pub tension: f64,
+    /// -1.0（对方主导）到 1.0（AI 主导）
+    pub power_balance: f64,
+    /// 正=升温，负=降温
+    pub trend: f64,
+}
+
+/// 认知分析结果（镜像 cognitive_engine::CognitiveAnalysis）
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CognitiveInsight {
+    pub emotion: EmotionInsight,
+    pub intent: IntentKind,
+    pub relationship: RelationshipInsight,
+    pub empathy_strategy: EmpathyKind,
+    pub detected_patterns: Vec<LanguagePatternKind>,
+    /// 注入给模型的认知提示
+    pub cognitive_prompt: String,
+}
+
+/// 某一轮的情绪（镜像 memory_engine::EmotionalSnapshot）
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmotionalTurnInsight {
+    pub turn: u32,
+    pub valence: f64,
+    pub arousal: f64,
+    pub dominant_emotion: String,
+}
+
+/// 回复结构指纹（镜像 memory_engine::ResponseFingerprint）
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FingerprintInsight {
+    pub opening_chars: String,
+    pub paragraph_count: u32,
+    pub avg_sentence_len: f64,
+    pub ending_chars: String,
+    pub ends_with_question: bool,
+    pub total_length: u32,
+    pub has_action_marker: bool,
+    pub has_list_format: bool,
+    /// warm / neutral / cold / playful / concerned
+    pub emotional_tone: String,
+}
+
+/// 短期记忆（镜像 memory_engine::ShortTermContext）
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShortTermInsight {
+    pub active_topics: Vec<String>,
+    pub emotional_arc: Vec<EmotionalTurnInsight>,
+    pub pending_threads: Vec<String>,
+    pub response_fingerprints: Vec<FingerprintInsight>,
+}
+
+/// 相关性评分明细（镜像 memory_engine::RelevanceScore）
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RelevanceInsight {
+    pub tfidf_score: f64,
+    pub keyword_overlap: f64,
+    /// 事实关键词直接出现在用户消息中
+    pub topic_match: f64,
+    pub final_score: f64,
+}
+
+/// 一段对话的管线分析快照
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConversationInsights {
+    /// 非 system 消息少于两条时为 None（与上下文增强的门槛一致）
+    pub cognitive: Option<CognitiveInsight>,
+    pub short_term: ShortTermInsight,
+}
+
+/// 静态加密的密钥来源
+#[frb]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EncryptionKeySource {
+    /// 由用户口令派生
+    Passphrase,
+    /// 平台密钥库（Keychain / Keystore）中保存的 32 字节密钥
+    Keystore,
+}
+
+/// 启用 / 解锁 / 停用静态加密时提供的凭据
+#[frb]
+#[derive(Clone)]
+pub enum EncryptionSecret {
+    Passphrase(String),
+    Keystore(Vec<u8>),
+}
+
+/// 静态加密状态
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EncryptionStatus {
+    pub enabled: bool,
+    /// 本次启动后是否已解锁（未启用时恒为 true）
+    pub unlocked: bool,
+    pub source: Option<EncryptionKeySource>,
+}
+
+/// 全文搜索的一条命中
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SearchHit {
+    pub conversation_id: String,
+    pub conversation_title: String,
+    pub message_id: String,
+    pub role: MessageRole,
+    /// 命中处前后的片段（截断处以 … 表示，换行替换为空格）
+    pub snippet: String,
+    /// 第一个命中词在 snippet 中的字符区间 [start, end)，供高亮
+    pub highlight_start: u32,
+    pub highlight_end: u32,
+    pub timestamp: i64,
+}
+
+/// 事实冲突：同一主体、同一关系出现了不同客体，新事实生效、旧事实归档
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FactConflict {
+    pub superseded_fact_id: String,
+    pub superseded_content: String,
+    pub active_fact_id: String,
+    /// 取代它的事实内容（该事实之后被删除时为空）
+    pub active_content: String,
+    pub detected_at: i64,
+}
+
+/// 知识层：检索时从具体到宽泛依次合并，内容冲突时具体的一层为准
+#[frb]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum KnowledgeLayer {
+    /// 单个对话（群聊中还包括某个角色在该对话里的视角）
+    Conversation,
+    /// 某个角色：同一角色的群聊、番外对话共享
+    Character,
+    /// 世界观：绑定同一世界的所有对话共享
+    World,
+}
+
+/// 对话与角色层 / 世界层的绑定（未绑定的层不参与检索与晋升）
+#[frb]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct KnowledgeBinding {
+    /// 对话中 AI 扮演的角色；群聊角色直接使用 GroupCharacter::id
+    pub character_id: Option<String>,
+    /// 角色名：主体为该名字的事实才晋升到角色层（群聊取 GroupCharacter::name）
+    pub character_name: Option<String>,
+    pub world_id: Option<String>,
+}
+
+/// 知识图谱节点：事实三元组中的主体 / 客体
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GraphNode {
+    /// 归一化后的实体名（去空白、小写），用作节点 ID
+    pub id: String,
+    /// 首次出现时的原文
+    pub label: String,
+    /// 相连的边数
+    pub degree: u32,
+}
+
+/// 知识图谱的边：一条事实，由主体指向客体
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GraphEdge {
+    pub fact_id: String,
+    pub source: String,
+    pub target: String,
+    pub relation: String,
+    /// 事实分类标签（身份 / 关系 / 偏好……）
+    pub category: String,
+}
+
+/// 知识图谱（或其子图）
+#[frb]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct KnowledgeGraphView {
+    pub nodes: Vec<GraphNode>,
+    pub edges: Vec<GraphEdge>,
+}
+
+/// 知识图谱导出格式
+#[frb]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GraphExportFormat {
+    Json,
+    GraphMl,
+}
+
+/// 角色之间关系的情感倾向（按关系词粗分）
+#[frb]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RelationSentiment {
+    /// 喜欢、信任、暗恋……
+    Positive,
+    /// 讨厌、嫉妒、敌视……
+    Negative,
+    /// 同事、兄妹等不带感情色彩的关系
+    Neutral,
+}
+
+/// 同一对角色之间较早的关系（已被新关系取代）
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RelationChange {
+    pub relation: String,
+    pub fact_id: String,
+    pub source_turn: u32,
+}
+
+/// 一个角色对另一个角色的当前关系（有向：from 如何看待 to）
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CharacterRelation {
+    pub from: String,
+    pub to: String,
+    pub relation: String,
+    pub sentiment: RelationSentiment,
+    /// 记录当前关系的事实
+    pub fact_id: String,
+    pub source_turn: u32,
+    /// 较早的关系，按时间先后排列
+    pub history: Vec<RelationChange>,
+}
+
+/// 故事时间线条目类型
+#[frb]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TimelineEntryKind {
+    /// 一段记忆摘要覆盖的剧情
+    Chapter,
+    /// 知识库中的关键事件
+    Event,
+}
+
+/// 故事时间线上的一个条目（「前情提要」滑杆的一格）
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimelineEntry {
+    /// 摘要 ID 或事实 ID
+    pub id: String,
+    pub kind: TimelineEntryKind,
+    pub text: String,
+    pub turn_start: u32,
+    pub turn_end: u32,
+    /// 起始轮次对应的时间（毫秒）；无法对应到消息时取条目自身的创建时间
+    pub timestamp: i64,
+    pub end_timestamp: i64,
+}
+
+/// 角色跨会话延续的心情（见 mood 模块）
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MoodState {
+    /// 效价：-1 消极 ~ 1 积极
+    pub valence: f64,
+    /// 唤醒度：0 平静 ~ 1 激动
+    pub arousal: f64,
+    /// 心情名称（兴奋 / 愉快 / 平静 / 恼火……）
+    pub mood: String,
+    /// 最后一次互动的时间（毫秒）
+    pub updated_at: i64,
+}
+
+/// 情感维度（对应 CognitiveEngine 感知层的八个维度）
+#[frb]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EmotionDimension {
+    Joy,
+    Sadness,
+    Anger,
+    Fear,
+    Surprise,
+    Intimacy,
+    Trust,
+    Anticipation,
+}
+
+/// 用户自定义的情感词条（emotion_lexicon.json），与内置词典合并后参与情感感知
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LexiconEntry {
+    pub word: String,
+    pub dimension: EmotionDimension,
+    /// 强度 0 ~ 1，与内置词条同一量纲
+    pub weight: f64,
+}
+
+/// 可覆盖的提示词模板（prompts/{name}.txt）
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PromptTemplateInfo {
+    pub name: String,
+    pub description: String,
+    /// 模板中可用的变量名（写作 {{name}}）
+    pub variables: Vec<String>,
+    /// 是否已被用户模板覆盖
+    pub customized: bool,
+}
+
+/// 自定义识别规则标记的内容类型
+#[frb]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SayDoMark {
+    Say,
+    Do,
+}
+
+/// 自定义 Say/Do 识别规则：正则命中的片段按 mark 计为对白或动作
+#[frb]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SayDoRule {
+    pub pattern: String,
+    pub mark: SayDoMark,
+    /// 优先级高的规则先认领文本，已被认领的片段不再参与后续规则
+    pub priority: i32,
+}
+
+/// 对话的 Say/Do 识别规则
+#[frb]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SayDoRuleSet {
+    pub rules: Vec<SayDoRule>,
+    /// 没有被任何规则命中的文字算什么；None 时交给内置识别（括号 / 星号为动作，其余为对白）
+    pub unmatched: Option<SayDoMark>,
+}
@@ -0,0 +1,135 @@
+use std::fs;
+use std::path::PathBuf;
+
+use flutter_rust_bridge::frb;
+
+use super::data_models::DegradationRecord;
+use super::error_handler::ChatError;
+
+// ═══════════════════════════════════════════════════════════════════
+//  降级决策日志 (Decision Log)
+//  ─────────────────────────────────────────────────────────────────
+//  每次回退到快速模型、推理超时被跳过、上下文被截断时记录一条，
+//  按轮次持久化，便于用户了解某次回复变差的原因、便于排查质量问题。
+//
+//  存储结构：
+//    decision_log/{conversation_id}.json
+// ═══════════════════════════════════════════════════════════════════
+
+/// 每个对话最多保留的记录数（超出时丢弃最旧的）
+const MAX_RECORDS_PER_CONVERSATION: usize = 200;
+
+#[frb(opaque)]
+pub struct DecisionLog {
+    base_path: String,
+}
+
+impl DecisionLog {
+    pub fn new(base_path: &str) -> Self {
+        Self {
+            base_path: base_path.to_string(),
+        }
+    }
+
+    fn log_dir(&self) -> Result<PathBuf, ChatError> {
+        let dir = PathBuf::from(&self.base_path).join("decision_log");
+        if !dir.exists() {
+            fs::create_dir_all(&dir).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to create decision log directory: {}", e),
+            })?;
+        }
+        Ok(dir)
+    }
+
+    fn log_path(&self, conversation_id: &str) -> Result<PathBuf, ChatError> {
+        Ok(self.log_dir()?.join(format!("{}.json", conversation_id)))
+    }
+
+    pub fn load(&self, conversation_id: &str) -> Result<Vec<DegradationRecord>, ChatError> {
+        let path = self.log_path(conversation_id)?;
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+        let json = fs::read_to_string(&path).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to read decision log: {}", e),
+        })?;
+        serde_json::from_str(&json).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to parse decision log: {}", e),
+        })
+    }
+
+    /// 追加记录，超出上限时丢弃最旧的
+    pub fn append(
+        &self,
+        conversation_id: &str,
+        records: &[DegradationRecord],
+    ) -> Result<(), ChatError> {
+        if records.is_empty() {
+            return Ok(());
+        }
+        let mut all = self.load(conversation_id).unwrap_or_default();
+        all.extend_from_slice(records);
+        if all.len() > MAX_RECORDS_PER_CONVERSATION {
+            let overflow = all.len() - MAX_RECORDS_PER_CONVERSATION;
+            all.drain(..overflow);
+        }
+        let path = self.log_path(conversation_id)?;
+        let json = serde_json::to_string_pretty(&all).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to serialize decision log: {}", e),
+        })?;
+        fs::write(&path, json).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to write decision log: {}", e),
+        })
+    }
+
+    pub fn delete(&self, conversation_id: &str) -> Result<(), ChatError> {
+        let path = self.log_path(conversation_id)?;
+        if path.exists() {
+            fs::remove_file(&path).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to delete decision log: {}", e),
+            })?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::data_models::DegradationKind;
+    use tempfile::TempDir;
+
+    fn make_record(turn: u32) -> DegradationRecord {
+        DegradationRecord {
+            turn,
+            kind: DegradationKind::ModelFallback,
+            model: "glm-4.7-flash".to_string(),
+            detail: "test".to_string(),
+            timestamp: 0,
+        }
+    }
+
+    #[test]
+    fn test_load_empty_when_missing() {
+        let tmp = TempDir::new().unwrap();
+        let log = DecisionLog::new(tmp.path().to_str().unwrap());
+        assert!(log.load("none").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_append_keeps_latest_records() {
+        let tmp = TempDir::new().unwrap();
+        let log = DecisionLog::new(tmp.path().to_str().unwrap());
+        let records: Vec<DegradationRecord> =
+            (0..(MAX_RECORDS_PER_CONVERSATION as u32 + 5)).map(make_record).collect();
+        log.append("c1", &records[..10]).unwrap();
+        log.append("c1", &records[10..]).unwrap();
+
+        let loaded = log.load("c1").unwrap();
+        assert_eq!(loaded.len(), MAX_RECORDS_PER_CONVERSATION);
+        assert_eq!(loaded[0].turn, 5);
+
+        log.delete("c1").unwrap();
+        assert!(log.load("c1").unwrap().is_empty());
+    }
+}
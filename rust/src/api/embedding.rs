@@ -0,0 +1,408 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use flutter_rust_bridge::frb;
+use serde_json::{json, Value};
+
+use super::config_manager;
+use super::data_models::{AppSettings, ProviderKind};
+use super::error_handler::ChatError;
+use super::jwt_auth::JwtAuth;
+
+// ═══════════════════════════════════════════════════════════════════
+//  向量检索 (Embedding Retrieval)
+//  ─────────────────────────────────────────────────────────────────
+//  BM25 与字符 n-gram 只认字面，「她怕猫」检索不到「对猫毛过敏」。
+//  这里为摘要与事实补一路向量召回：
+//    1. 后端：智谱 embedding-3，或本地 OpenAI 兼容的 /embeddings（如 Ollama）
+//    2. 存储：向量与摘要/事实分开存放，按摘要ID/事实ID索引，
+//       不随 MemorySummary 传给前端
+//    3. 融合：查询向量与文档向量的余弦相似度作为第三路排名，
+//       与 BM25、关键词余弦一起做加权 RRF
+//  没有后端或向量缺失时，检索自动退回纯词法排序。
+//
+//  存储结构：
+//    memory_vectors/{conversation_id}.json   （ID → 向量）
+// ═══════════════════════════════════════════════════════════════════
+
+const BIGMODEL_EMBEDDING_URL: &str = "https://open.bigmodel.cn/api/paas/v4/embeddings";
+const BIGMODEL_EMBEDDING_MODEL: &str = "embedding-3";
+/// embedding-3 支持 256/512/1024/2048 维；512 维足以区分对话级语义，存储也更小
+const BIGMODEL_EMBEDDING_DIMENSIONS: u32 = 512;
+const LOCAL_EMBEDDING_DEFAULT_BASE_URL: &str = "http://localhost:11434/v1";
+const LOCAL_EMBEDDING_DEFAULT_MODEL: &str = "nomic-embed-text";
+/// 单次请求的最大文本条数（embedding-3 上限为 64）
+const EMBEDDING_BATCH_SIZE: usize = 64;
+/// 向量请求超时：检索在回复前同步等待，宁可放弃向量也不拖慢回复
+const EMBEDDING_TIMEOUT_SECS: u64 = 8;
+
+/// 向量后端
+pub enum EmbeddingBackend {
+    /// 智谱 embedding-3（JWT 鉴权）
+    BigModel { jwt_auth: Mutex<JwtAuth> },
+    /// 本地 OpenAI 兼容 /embeddings 端点
+    Local { url: String, model: String },
+}
+
+impl EmbeddingBackend {
+    pub fn bigmodel(api_key: &str) -> Result<Self, String> {
+        Ok(Self::BigModel {
+            jwt_auth: Mutex::new(JwtAuth::new(api_key)?),
+        })
+    }
+
+    pub fn local(base_url: Option<&str>, model: Option<&str>) -> Self {
+        let base = base_url
+            .unwrap_or(LOCAL_EMBEDDING_DEFAULT_BASE_URL)
+            .trim_end_matches('/');
+        let url = if base.ends_with("/embeddings") {
+            base.to_string()
+        } else {
+            format!("{}/embeddings", base)
+        };
+        Self::Local {
+            url,
+            model: model.unwrap_or(LOCAL_EMBEDDING_DEFAULT_MODEL).to_string(),
+        }
+    }
+
+    /// 按设置选择后端：智谱用 embedding-3，本地模型用本地端点；其他提供方不做向量检索
+    pub fn from_settings(settings: &AppSettings) -> Option<Self> {
+        let non_empty = |v: &Option<String>| {
+            v.as_deref().map(str::trim).filter(|s| !s.is_empty()).map(str::to_string)
+        };
+        match settings.provider {
+            ProviderKind::Zhipu => Self::bigmodel(&non_empty(&settings.api_key)?).ok(),
+            ProviderKind::LocalLlm => Some(Self::local(
+                non_empty(&settings.provider_base_url).as_deref(),
+                None,
+            )),
+            _ => None,
+        }
+    }
+
+    fn endpoint(&self) -> &str {
+        match self {
+            Self::BigModel { .. } => BIGMODEL_EMBEDDING_URL,
+            Self::Local { url, .. } => url,
+        }
+    }
+
+    fn auth_headers(&self) -> Vec<(String, String)> {
+        match self {
+            Self::BigModel { jwt_auth } => {
+                let token = {
+                    let mut auth = jwt_auth.lock().unwrap();
+                    auth.get_token()
+                };
+                vec![("Authorization".to_string(), format!("Bearer {}", token))]
+            }
+            Self::Local { .. } => Vec::new(),
+        }
+    }
+
+    pub fn build_request(&self, texts: &[String]) -> Value {
+        match self {
+            Self::BigModel { .. } => json!({
+                "model": BIGMODEL_EMBEDDING_MODEL,
+                "input": texts,
+                "dimensions": BIGMODEL_EMBEDDING_DIMENSIONS,
+            }),
+            Self::Local { model, .. } => json!({
+                "model": model,
+                "input": texts,
+            }),
+        }
+    }
+
+    /// 解析 OpenAI 风格响应：data[].embedding，按 index 还原输入顺序
+    pub fn parse_response(body: &Value, expected: usize) -> Result<Vec<Vec<f32>>, ChatError> {
+        let data = body
+            .get("data")
+            .and_then(|d| d.as_array())
+            .ok_or_else(|| ChatError::StreamError {
+                message: "Embedding response missing data".to_string(),
+            })?;
+        let mut vectors: Vec<Option<Vec<f32>>> = vec![None; expected];
+        for (pos, item) in data.iter().enumerate() {
+            let index = item
+                .get("index")
+                .and_then(|i| i.as_u64())
+                .map(|i| i as usize)
+                .unwrap_or(pos);
+            let vector: Vec<f32> = item
+                .get("embedding")
+                .and_then(|e| e.as_array())
+                .map(|arr| arr.iter().filter_map(|v| v.as_f64()).map(|v| v as f32).collect())
+                .unwrap_or_default();
+            if index < expected && !vector.is_empty() {
+                vectors[index] = Some(vector);
+            }
+        }
+        vectors
+            .into_iter()
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| ChatError::StreamError {
+                message: format!("Embedding response incomplete: expected {} vectors", expected),
+            })
+    }
+
+    /// 批量取向量（超过单批上限时分批请求）
+    pub async fn embed(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, ChatError> {
+        if texts.is_empty() {
+            return Ok(Vec::new());
+        }
+        let client = config_manager::apply_proxy(
+            reqwest::Client::builder().timeout(std::time::Duration::from_secs(EMBEDDING_TIMEOUT_SECS)),
+        )?
+        .build()
+        .map_err(|e| ChatError::NetworkError {
+            message: e.to_string(),
+        })?;
+
+        let mut vectors = Vec::with_capacity(texts.len());
+        for batch in texts.chunks(EMBEDDING_BATCH_SIZE) {
+            let mut req = client.post(self.endpoint());
+            for (name, value) in self.auth_headers() {
+                req = req.header(name.as_str(), value.as_str());
+            }
+            let resp = req
+                .json(&self.build_request(batch))
+                .send()
+                .await
+                .map_err(|e| ChatError::NetworkError {
+                    message: format!("向量请求失败: {}", e),
+                })?;
+            let status = resp.status();
+            if !status.is_success() {
+                return Err(ChatError::ApiError {
+                    status: status.as_u16(),
+                    message: resp.text().await.unwrap_or_default(),
+                });
+            }
+            let body: Value = resp.json().await.map_err(|e| ChatError::StreamError {
+                message: format!("Failed to parse embedding response: {}", e),
+            })?;
+            vectors.extend(Self::parse_response(&body, batch.len())?);
+        }
+        Ok(vectors)
+    }
+}
+
+/// 余弦相似度；维度不一致（换过后端）或零向量时为 0
+pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f64 {
+    if a.len() != b.len() || a.is_empty() {
+        return 0.0;
+    }
+    let mut dot = 0.0f64;
+    let mut norm_a = 0.0f64;
+    let mut norm_b = 0.0f64;
+    for (x, y) in a.iter().zip(b.iter()) {
+        dot += *x as f64 * *y as f64;
+        norm_a += *x as f64 * *x as f64;
+        norm_b += *y as f64 * *y as f64;
+    }
+    if norm_a == 0.0 || norm_b == 0.0 {
+        return 0.0;
+    }
+    dot / (norm_a.sqrt() * norm_b.sqrt())
+}
+
+/// 一次检索的向量上下文：查询向量 + 该对话全部文档向量（按摘要ID/事实ID）
+#[derive(Debug, Clone, Default)]
+pub struct SemanticQuery {
+    pub query: Vec<f32>,
+    pub vectors: HashMap<String, Vec<f32>>,
+}
+
+impl SemanticQuery {
+    /// 按文档顺序计算余弦排名（降序）；没有任何文档向量时返回 None
+    pub fn rank<'a>(&self, ids: impl Iterator<Item = &'a str>) -> Option<Vec<(usize, f64)>> {
+        let mut found = false;
+        let mut ranks: Vec<(usize, f64)> = ids
+            .enumerate()
+            .map(|(i, id)| match self.vectors.get(id) {
+                Some(v) => {
+                    found = true;
+                    (i, cosine_similarity(&self.query, v).max(0.0))
+                }
+                None => (i, 0.0),
+            })
+            .collect();
+        if !found {
+            return None;
+        }
+        ranks.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        Some(ranks)
+    }
+}
+
+/// 向量存储
+#[frb(opaque)]
+#[derive(Clone)]
+pub struct EmbeddingStore {
+    base_path: String,
+}
+
+impl EmbeddingStore {
+    pub fn new(base_path: &str) -> Self {
+        Self {
+            base_path: base_path.to_string(),
+        }
+    }
+
+    fn vectors_dir(&self) -> Result<PathBuf, ChatError> {
+        let dir = PathBuf::from(&self.base_path).join("memory_vectors");
+        if !dir.exists() {
+            fs::create_dir_all(&dir).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to create memory vectors directory: {}", e),
+            })?;
+        }
+        Ok(dir)
+    }
+
+    fn vectors_path(&self, conversation_id: &str) -> Result<PathBuf, ChatError> {
+        Ok(self.vectors_dir()?.join(format!("{}.json", conversation_id)))
+    }
+
+    pub fn load(&self, conversation_id: &str) -> Result<HashMap<String, Vec<f32>>, ChatError> {
+        let path = self.vectors_path(conversation_id)?;
+        if !path.exists() {
+            return Ok(HashMap::new());
+        }
+        let json = fs::read_to_string(&path).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to read memory vectors: {}", e),
+        })?;
+        serde_json::from_str(&json).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to parse memory vectors: {}", e),
+        })
+    }
+
+    pub fn save(
+        &self,
+        conversation_id: &str,
+        vectors: &HashMap<String, Vec<f32>>,
+    ) -> Result<(), ChatError> {
+        let path = self.vectors_path(conversation_id)?;
+        let json = serde_json::to_string(vectors).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to serialize memory vectors: {}", e),
+        })?;
+        fs::write(&path, json).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to write memory vectors: {}", e),
+        })
+    }
+
+    pub fn delete(&self, conversation_id: &str) -> Result<(), ChatError> {
+        let path = self.vectors_path(conversation_id)?;
+        if path.exists() {
+            fs::remove_file(&path).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to delete memory vectors: {}", e),
+            })?;
+        }
+        Ok(())
+    }
+
+    /// 丢弃一篇文档的向量（文档内容被修改后调用，下次同步时重算）
+    pub fn invalidate(&self, conversation_id: &str, doc_id: &str) -> Result<(), ChatError> {
+        let mut vectors = self.load(conversation_id)?;
+        if vectors.remove(doc_id).is_some() {
+            self.save(conversation_id, &vectors)?;
+        }
+        Ok(())
+    }
+
+    /// 增量同步：为缺少向量的文档补算，删除已不存在的文档的向量
+    /// docs 为 (ID, 检索文本)；后端失败时保留已有向量并返回错误
+    pub async fn sync(
+        &self,
+        conversation_id: &str,
+        docs: &[(String, String)],
+        backend: &EmbeddingBackend,
+    ) -> Result<(), ChatError> {
+        let mut vectors = self.load(conversation_id).unwrap_or_default();
+        let before = vectors.len();
+        vectors.retain(|id, _| docs.iter().any(|(doc_id, _)| doc_id == id));
+        let pruned = vectors.len() != before;
+
+        let missing: Vec<&(String, String)> = docs
+            .iter()
+            .filter(|(id, text)| !vectors.contains_key(id) && !text.trim().is_empty())
+            .collect();
+        if !missing.is_empty() {
+            let texts: Vec<String> = missing.iter().map(|(_, text)| text.clone()).collect();
+            let embedded = backend.embed(&texts).await;
+            match embedded {
+                Ok(embedded) => {
+                    for ((id, _), vector) in missing.into_iter().zip(embedded) {
+                        vectors.insert(id.clone(), vector);
+                    }
+                }
+                Err(e) => {
+                    if pruned {
+                        self.save(conversation_id, &vectors)?;
+                    }
+                    return Err(e);
+                }
+            }
+        } else if !pruned {
+            return Ok(());
+        }
+        self.save(conversation_id, &vectors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_cosine_similarity_and_rank() {
+        assert!((cosine_similarity(&[1.0, 0.0], &[1.0, 0.0]) - 1.0).abs() < 1e-9);
+        assert_eq!(cosine_similarity(&[1.0, 0.0], &[0.0, 1.0]), 0.0);
+        assert_eq!(cosine_similarity(&[1.0], &[1.0, 0.0]), 0.0);
+
+        let mut vectors = HashMap::new();
+        vectors.insert("a".to_string(), vec![0.0, 1.0]);
+        vectors.insert("b".to_string(), vec![1.0, 0.1]);
+        let query = SemanticQuery {
+            query: vec![1.0, 0.0],
+            vectors,
+        };
+        let ranks = query.rank(["a", "b", "c"].into_iter()).unwrap();
+        assert_eq!(ranks[0].0, 1);
+        assert!(query.rank(["x"].into_iter()).is_none());
+    }
+
+    #[test]
+    fn test_parse_response_restores_input_order() {
+        let body = json!({
+            "data": [
+                {"index": 1, "embedding": [0.0, 1.0]},
+                {"index": 0, "embedding": [1.0, 0.0]},
+            ]
+        });
+        let vectors = EmbeddingBackend::parse_response(&body, 2).unwrap();
+        assert_eq!(vectors[0], vec![1.0, 0.0]);
+        assert_eq!(vectors[1], vec![0.0, 1.0]);
+        assert!(EmbeddingBackend::parse_response(&body, 3).is_err());
+
+        let request = EmbeddingBackend::local(None, None).build_request(&["你好".to_string()]);
+        assert_eq!(request["model"], LOCAL_EMBEDDING_DEFAULT_MODEL);
+    }
+
+    #[test]
+    fn test_store_roundtrip_and_delete() {
+        let tmp = TempDir::new().unwrap();
+        let store = EmbeddingStore::new(tmp.path().to_str().unwrap());
+        assert!(store.load("c1").unwrap().is_empty());
+        let mut vectors = HashMap::new();
+        vectors.insert("s1".to_string(), vec![0.5, 0.5]);
+        store.save("c1", &vectors).unwrap();
+        assert_eq!(store.load("c1").unwrap(), vectors);
+        store.delete("c1").unwrap();
+        assert!(store.load("c1").unwrap().is_empty());
+    }
+}
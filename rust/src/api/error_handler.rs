@@ -1,10 +1,14 @@
 use flutter_rust_bridge::frb;
 use std::fmt;
+
+use super::data_models::{BackoffStrategy, RetryPolicy};
+use super::metrics;
 use std::future::Future;
 use tokio::time::sleep;
 use std::time::Duration;
 
 #[frb(opaque)]
+#[allow(clippy::enum_variant_names)]
 #[derive(Debug, Clone)]
 pub enum ChatError {
     ApiError { status: u16, message: String },
@@ -16,6 +20,12 @@ pub enum ChatError {
     StreamError { message: String },
     /// GLM 业务错误（携带业务错误码，便于精确分类）
     GlmBusinessError { code: String, message: String },
+    /// 请求超出模型上下文长度（服务端给出上限/实际值时一并带回，便于精确裁剪）
+    ContextLengthExceeded {
+        limit_tokens: Option<u32>,
+        requested_tokens: Option<u32>,
+        message: String,
+    },
 }
 
 impl fmt::Display for ChatError {
@@ -45,6 +55,9 @@ impl fmt::Display for ChatError {
             ChatError::GlmBusinessError { code, message } => {
                 write!(f, "GLM error (code {}): {}", code, message)
             }
+            ChatError::ContextLengthExceeded { message, .. } => {
+                write!(f, "Context length exceeded: {}", message)
+            }
         }
     }
 }
@@ -74,6 +87,29 @@ impl ChatError {
         }
     }
 
+    /// 用响应头 Retry-After 覆盖限流错误的等待时间（其余错误原样返回）。
+    /// 支持秒数与 HTTP 日期两种写法，无法解析时保留业务码给出的默认值
+    pub fn with_retry_after(self, header: Option<&str>) -> Self {
+        match self {
+            ChatError::RateLimitError { retry_after_secs } => ChatError::RateLimitError {
+                retry_after_secs: header
+                    .and_then(|h| Self::parse_retry_after(h, chrono::Utc::now().timestamp()))
+                    .unwrap_or(retry_after_secs),
+            },
+            other => other,
+        }
+    }
+
+    /// 解析 Retry-After：「120」或「Wed, 21 Oct 2015 07:28:00 GMT」（now 为秒级时间戳）
+    pub fn parse_retry_after(value: &str, now: i64) -> Option<u64> {
+        let value = value.trim();
+        if let Ok(secs) = value.parse::<u64>() {
+            return Some(secs);
+        }
+        let at = chrono::DateTime::parse_from_rfc2822(value).ok()?.timestamp();
+        Some(at.saturating_sub(now).max(0) as u64)
+    }
+
     /// 根据 GLM API 响应体解析错误
     /// 响应格式: {"error": {"code": "1002", "message": "..."}}
     ///
@@ -96,6 +132,15 @@ impl ChatError {
                 .unwrap_or("未知错误")
                 .to_string();
 
+            if Self::is_context_length_error(&code, &message) {
+                let (limit_tokens, requested_tokens) = Self::parse_context_limits(&message);
+                return ChatError::ContextLengthExceeded {
+                    limit_tokens,
+                    requested_tokens,
+                    message,
+                };
+            }
+
             Self::classify_glm_error(status_code, &code, &message)
         } else {
             ChatError::ApiError {
@@ -105,6 +150,54 @@ impl ChatError {
         }
     }
 
+    /// 上下文超长：GLM 业务码 1261，OpenAI 兼容端点的 context_length_exceeded，
+    /// 以及 Anthropic / vLLM / Ollama 等只在 message 中说明的情况
+    fn is_context_length_error(code: &str, message: &str) -> bool {
+        if matches!(code, "1261" | "context_length_exceeded") {
+            return true;
+        }
+        let lower = message.to_lowercase();
+        // 只认明确指向上下文 / 提示词的说法：单独的「超长」也会出现在标题、参数等无关报错里
+        [
+            "maximum context length",
+            "context length",
+            "prompt is too long",
+            "prompt 超长",
+            "上下文超长",
+            "上下文长度超过",
+            "超过最大长度",
+        ]
+        .iter()
+        .any(|marker| lower.contains(marker))
+    }
+
+    /// 从错误信息中提取（上下文上限, 实际请求量），提取不到的一项为 None
+    ///
+    /// 覆盖的格式：
+    /// - OpenAI / vLLM: "maximum context length is 8192 tokens. However, your messages
+    ///   resulted in 9000 tokens" / "... you requested 9000 tokens"
+    /// - Anthropic: "prompt is too long: 210000 tokens > 200000 maximum"
+    fn parse_context_limits(message: &str) -> (Option<u32>, Option<u32>) {
+        let lower = message.to_lowercase();
+        let number_after = |marker: &str| -> Option<u32> {
+            let start = lower.find(marker)? + marker.len();
+            let digits: String = lower[start..]
+                .chars()
+                .skip_while(|c| !c.is_ascii_digit())
+                .take_while(|c| c.is_ascii_digit())
+                .collect();
+            digits.parse().ok()
+        };
+
+        let limit = ["context length is", "maximum context length of", "最大长度", "> "]
+            .iter()
+            .find_map(|m| number_after(m));
+        let requested = ["resulted in", "you requested", "too long:", "requested"]
+            .iter()
+            .find_map(|m| number_after(m));
+        (limit, requested)
+    }
+
     /// 根据 GLM 业务错误码分类为具体 ChatError 变体
     ///
     /// 错误码映射（参考 https://docs.bigmodel.cn/cn/api/api-code）：
@@ -225,15 +318,61 @@ impl ChatError {
 
 #[frb(opaque)]
 pub struct RetryHandler {
-    max_retries: u32,
-    initial_delay_ms: u64,
+    policy: RetryPolicy,
 }
 
 impl RetryHandler {
+    /// 固定次数、逐次翻倍的重试（不限单次等待，全部可重试错误都重试）；
+    /// 线上请求一律走 with_policy
+    #[cfg(test)]
     pub fn new(max_retries: u32, initial_delay_ms: u64) -> Self {
-        Self {
+        Self::with_policy(RetryPolicy {
             max_retries,
             initial_delay_ms,
+            max_delay_ms: u64::MAX,
+            backoff: BackoffStrategy::Exponential,
+            max_retry_after_secs: u64::MAX,
+            ..RetryPolicy::default()
+        })
+    }
+
+    /// 按设置中的重试策略（见 config_manager::retry_policy）
+    pub fn with_policy(policy: RetryPolicy) -> Self {
+        Self { policy }
+    }
+
+    /// 错误可重试（见 ChatError::is_retryable）且该类错误在策略中开启了重试
+    pub fn should_retry(&self, err: &ChatError) -> bool {
+        if !err.is_retryable() {
+            return false;
+        }
+        match err {
+            ChatError::NetworkError { .. } => self.policy.retry_network_errors,
+            ChatError::StreamError { .. } => self.policy.retry_stream_errors,
+            ChatError::RateLimitError { .. } => self.policy.retry_rate_limited,
+            ChatError::GlmBusinessError { code, .. } if code != "500" => {
+                self.policy.retry_rate_limited
+            }
+            _ => self.policy.retry_server_errors,
+        }
+    }
+
+    /// 第 attempt 次重试（从 0 开始）前的退避等待
+    pub fn backoff_delay_ms(&self, attempt: u32) -> u64 {
+        let base = self.policy.initial_delay_ms;
+        let grown = match self.policy.backoff {
+            BackoffStrategy::Fixed => base,
+            BackoffStrategy::Exponential | BackoffStrategy::ExponentialJitter => {
+                base.saturating_mul(1u64.checked_shl(attempt).unwrap_or(u64::MAX))
+            }
+        };
+        let capped = grown.min(self.policy.max_delay_ms);
+        match self.policy.backoff {
+            BackoffStrategy::ExponentialJitter if capped > 1 => {
+                let half = capped / 2;
+                half + (uuid::Uuid::new_v4().as_u128() % (capped - half + 1) as u128) as u64
+            }
+            _ => capped,
         }
     }
 
@@ -243,27 +382,29 @@ impl RetryHandler {
         Fut: Future<Output = Result<T, ChatError>>,
     {
         let mut last_error: Option<ChatError> = None;
-        let mut delay_ms = self.initial_delay_ms;
 
-        for attempt in 0..=self.max_retries {
+        for attempt in 0..=self.policy.max_retries {
             match operation().await {
                 Ok(value) => return Ok(value),
                 Err(err) => {
-                    if !err.is_retryable() {
+                    if !self.should_retry(&err) {
                         return Err(err);
                     }
 
                     last_error = Some(err.clone());
-                    if attempt < self.max_retries {
+                    if attempt < self.policy.max_retries {
+                        // 限流时按服务端要求的时间等待；要求等太久则放弃，交给上层提示
                         let wait_ms = if let ChatError::RateLimitError { retry_after_secs } = &err
                         {
-                            retry_after_secs * 1000
+                            if *retry_after_secs > self.policy.max_retry_after_secs {
+                                return Err(err);
+                            }
+                            retry_after_secs.saturating_mul(1000)
                         } else {
-                            let current = delay_ms;
-                            delay_ms *= 2;
-                            current
+                            self.backoff_delay_ms(attempt)
                         };
 
+                        metrics::record_retry();
                         sleep(Duration::from_millis(wait_ms)).await;
                     }
                 }
@@ -297,6 +438,47 @@ mod tests {
         assert_eq!(err.to_string(), "Validation error: empty message");
     }
 
+    #[test]
+    fn test_context_length_errors_parse_limits() {
+        let openai = r#"{"error":{"code":"context_length_exceeded","message":"This model's maximum context length is 8192 tokens. However, your messages resulted in 9000 tokens."}}"#;
+        match ChatError::from_glm_response(400, openai) {
+            ChatError::ContextLengthExceeded { limit_tokens, requested_tokens, .. } => {
+                assert_eq!(limit_tokens, Some(8192));
+                assert_eq!(requested_tokens, Some(9000));
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+
+        let anthropic = r#"{"type":"error","error":{"type":"invalid_request_error","message":"prompt is too long: 210000 tokens > 200000 maximum"}}"#;
+        match ChatError::from_glm_response(400, anthropic) {
+            ChatError::ContextLengthExceeded { limit_tokens, requested_tokens, .. } => {
+                assert_eq!(limit_tokens, Some(200000));
+                assert_eq!(requested_tokens, Some(210000));
+            }
+            other => panic!("unexpected {:?}", other),
+        }
+
+        let glm = r#"{"error":{"code":"1261","message":"Prompt 超长"}}"#;
+        let err = ChatError::from_glm_response(400, glm);
+        assert!(matches!(
+            err,
+            ChatError::ContextLengthExceeded { limit_tokens: None, requested_tokens: None, .. }
+        ));
+        assert!(!err.is_retryable());
+
+        // 与上下文无关的「超长」不算上下文超长
+        let unrelated = r#"{"error":{"code":"1214","message":"user_id 参数超长"}}"#;
+        assert!(!matches!(
+            ChatError::from_glm_response(400, unrelated),
+            ChatError::ContextLengthExceeded { .. }
+        ));
+        let anonymous = r#"{"error":{"message":"Prompt 超长"}}"#;
+        assert!(matches!(
+            ChatError::from_glm_response(400, anonymous),
+            ChatError::ContextLengthExceeded { .. }
+        ));
+    }
+
     #[test]
     fn test_chat_error_is_retryable() {
         assert!(ChatError::NetworkError { message: "timeout".into() }.is_retryable());
@@ -317,6 +499,56 @@ mod tests {
         assert!(!ChatError::GlmBusinessError { code: "1113".into(), message: "余额".into() }.is_retryable());
     }
 
+    #[test]
+    fn test_retry_policy_classes_backoff_and_retry_after() {
+        let handler = RetryHandler::with_policy(RetryPolicy {
+            initial_delay_ms: 1000,
+            max_delay_ms: 5000,
+            retry_stream_errors: false,
+            ..RetryPolicy::default()
+        });
+        assert!(handler.should_retry(&ChatError::NetworkError { message: "x".into() }));
+        assert!(!handler.should_retry(&ChatError::StreamError { message: "x".into() }));
+        assert!(!handler.should_retry(&ChatError::AuthError { message: "x".into() }));
+        for attempt in 0..6 {
+            let delay = handler.backoff_delay_ms(attempt);
+            let full = (1000u64 << attempt).min(5000);
+            assert!(delay >= full / 2 && delay <= full, "attempt {} → {}", attempt, delay);
+        }
+        let fixed = RetryHandler::with_policy(RetryPolicy {
+            backoff: BackoffStrategy::Fixed,
+            ..RetryPolicy::default()
+        });
+        assert_eq!(fixed.backoff_delay_ms(4), 1000);
+
+        let now = chrono::DateTime::parse_from_rfc2822("Wed, 21 Oct 2015 07:28:00 GMT")
+            .unwrap()
+            .timestamp();
+        assert_eq!(ChatError::parse_retry_after(" 7 ", now), Some(7));
+        assert_eq!(ChatError::parse_retry_after("Wed, 21 Oct 2015 07:28:30 GMT", now), Some(30));
+        assert_eq!(ChatError::parse_retry_after("soon", now), None);
+        let err = ChatError::from_glm_response(429, "{}").with_retry_after(Some("12"));
+        assert!(matches!(err, ChatError::RateLimitError { retry_after_secs: 12 }));
+    }
+
+    #[tokio::test]
+    async fn test_retry_gives_up_when_retry_after_too_long() {
+        let handler = RetryHandler::with_policy(RetryPolicy {
+            max_retry_after_secs: 5,
+            ..RetryPolicy::default()
+        });
+        let call_count = Arc::new(AtomicU32::new(0));
+        let cc = call_count.clone();
+        let result = handler
+            .execute_with_retry(move || {
+                cc.fetch_add(1, Ordering::SeqCst);
+                async { Err::<i32, ChatError>(ChatError::RateLimitError { retry_after_secs: 30 }) }
+            })
+            .await;
+        assert!(matches!(result, Err(ChatError::RateLimitError { retry_after_secs: 30 })));
+        assert_eq!(call_count.load(Ordering::SeqCst), 1);
+    }
+
     #[tokio::test]
     async fn test_retry_immediate_success() {
         let handler = RetryHandler::new(3, 100);
@@ -0,0 +1,321 @@
+use std::fs;
+use std::path::PathBuf;
+
+use flutter_rust_bridge::frb;
+use serde::{Deserialize, Serialize};
+
+use super::data_models::{MemoryFidelityReport, MemorySummary};
+use super::error_handler::ChatError;
+use super::memory_engine::MemoryEngine;
+
+// ═══════════════════════════════════════════════════════════════════
+//  记忆保真度审计 (Memory Fidelity Audit)
+//  ─────────────────────────────────────────────────────────────────
+//  压缩影响等级只是按代数「估计」损失，这里实际测量：
+//    1. 检查点：每次写入摘要前，把当时全部核心事实存一份
+//    2. 抽样：从历史检查点中均匀抽取事实
+//    3. 核对：事实原文出现在当前摘要中，或其实体/关键词大部分仍能找到，
+//       即视为「仍可推出」
+//    4. 评分：可推出比例即保真度；低于阈值或较上次明显下降时告警
+//
+//  存储结构：
+//    memory_audit/{conversation_id}.json
+// ═══════════════════════════════════════════════════════════════════
+
+/// 每个对话最多保留的检查点数（超出时丢弃最旧的）
+const MAX_CHECKPOINTS: usize = 20;
+/// 每个对话最多保留的审计历史条数
+const MAX_HISTORY: usize = 50;
+/// 单次审计抽样的事实数
+const SAMPLE_SIZE: usize = 30;
+/// 关键词命中比例达到该值即视为可推出
+const ENTITY_MATCH_THRESHOLD: f64 = 0.6;
+/// 虚词/量词：含这些字的二元组不算实体（避免「了一」「的橘」之类的噪声）
+const FUNCTION_CHARS: &str = "的了一只个在是和与也都就还很叫着把被对吗呢啊吧呀";
+/// 保真度低于该值告警
+const FIDELITY_ALERT_THRESHOLD: f64 = 0.7;
+/// 较上次审计下降超过该值告警
+const FIDELITY_DROP_ALERT: f64 = 0.15;
+
+/// 某次写入摘要前的核心事实快照
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MemoryCheckpoint {
+    pub generation: u32,
+    pub turn_range_end: u32,
+    pub created_at: i64,
+    pub facts: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct AuditLog {
+    #[serde(default)]
+    checkpoints: Vec<MemoryCheckpoint>,
+    #[serde(default)]
+    history: Vec<MemoryFidelityReport>,
+}
+
+#[frb(opaque)]
+pub struct FidelityAuditor {
+    base_path: String,
+}
+
+impl FidelityAuditor {
+    pub fn new(base_path: &str) -> Self {
+        Self {
+            base_path: base_path.to_string(),
+        }
+    }
+
+    fn audit_dir(&self) -> Result<PathBuf, ChatError> {
+        let dir = PathBuf::from(&self.base_path).join("memory_audit");
+        if !dir.exists() {
+            fs::create_dir_all(&dir).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to create memory audit directory: {}", e),
+            })?;
+        }
+        Ok(dir)
+    }
+
+    fn log_path(&self, conversation_id: &str) -> Result<PathBuf, ChatError> {
+        Ok(self.audit_dir()?.join(format!("{}.json", conversation_id)))
+    }
+
+    fn load_log(&self, conversation_id: &str) -> Result<AuditLog, ChatError> {
+        let path = self.log_path(conversation_id)?;
+        if !path.exists() {
+            return Ok(AuditLog::default());
+        }
+        let json = fs::read_to_string(&path).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to read memory audit log: {}", e),
+        })?;
+        serde_json::from_str(&json).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to parse memory audit log: {}", e),
+        })
+    }
+
+    fn save_log(&self, conversation_id: &str, log: &AuditLog) -> Result<(), ChatError> {
+        let path = self.log_path(conversation_id)?;
+        let json = serde_json::to_string_pretty(log).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to serialize memory audit log: {}", e),
+        })?;
+        fs::write(&path, json).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to write memory audit log: {}", e),
+        })
+    }
+
+    /// 记录检查点（在合并/压缩之前调用）；事实与上一个检查点相同时跳过
+    pub fn record_checkpoint(
+        &self,
+        conversation_id: &str,
+        summaries: &[MemorySummary],
+    ) -> Result<(), ChatError> {
+        let mut facts: Vec<String> = Vec::new();
+        for fact in summaries.iter().flat_map(|s| s.core_facts.iter()) {
+            if !facts.contains(fact) {
+                facts.push(fact.clone());
+            }
+        }
+        if facts.is_empty() {
+            return Ok(());
+        }
+
+        let mut log = self.load_log(conversation_id).unwrap_or_default();
+        if log.checkpoints.last().map(|c| &c.facts) == Some(&facts) {
+            return Ok(());
+        }
+        log.checkpoints.push(MemoryCheckpoint {
+            generation: summaries
+                .iter()
+                .map(|s| s.compression_generation)
+                .max()
+                .unwrap_or(0),
+            turn_range_end: summaries.iter().map(|s| s.turn_range_end).max().unwrap_or(0),
+            created_at: chrono::Utc::now().timestamp_millis(),
+            facts,
+        });
+        if log.checkpoints.len() > MAX_CHECKPOINTS {
+            let overflow = log.checkpoints.len() - MAX_CHECKPOINTS;
+            log.checkpoints.drain(..overflow);
+        }
+        self.save_log(conversation_id, &log)
+    }
+
+    /// 审计：从检查点抽样事实，核对当前摘要是否仍能推出，并记入历史
+    pub fn audit(
+        &self,
+        conversation_id: &str,
+        current: &[MemorySummary],
+    ) -> Result<MemoryFidelityReport, ChatError> {
+        let mut log = self.load_log(conversation_id)?;
+
+        let mut pool: Vec<&String> = Vec::new();
+        for fact in log.checkpoints.iter().flat_map(|c| c.facts.iter()) {
+            if !pool.contains(&fact) {
+                pool.push(fact);
+            }
+        }
+        let sample = Self::sample_evenly(&pool, SAMPLE_SIZE);
+
+        let corpus: String = current
+            .iter()
+            .flat_map(|s| std::iter::once(&s.summary).chain(s.core_facts.iter()))
+            .map(|s| s.as_str())
+            .collect::<Vec<&str>>()
+            .join("\n");
+        let lost_facts: Vec<String> = sample
+            .iter()
+            .filter(|fact| !Self::is_derivable(fact, &corpus))
+            .map(|fact| fact.to_string())
+            .collect();
+
+        let score = if sample.is_empty() {
+            1.0
+        } else {
+            1.0 - lost_facts.len() as f64 / sample.len() as f64
+        };
+        let previous_score = log.history.last().map(|r| r.score);
+        let dropped = previous_score.is_some_and(|prev| prev - score > FIDELITY_DROP_ALERT);
+        let report = MemoryFidelityReport {
+            conversation_id: conversation_id.to_string(),
+            score,
+            sampled_facts: sample.len() as u32,
+            lost_facts,
+            previous_score,
+            alert: !sample.is_empty() && (score < FIDELITY_ALERT_THRESHOLD || dropped),
+            audited_at: chrono::Utc::now().timestamp_millis(),
+        };
+
+        log.history.push(report.clone());
+        if log.history.len() > MAX_HISTORY {
+            let overflow = log.history.len() - MAX_HISTORY;
+            log.history.drain(..overflow);
+        }
+        self.save_log(conversation_id, &log)?;
+        Ok(report)
+    }
+
+    /// 历次审计结果（按时间先后）
+    pub fn history(&self, conversation_id: &str) -> Result<Vec<MemoryFidelityReport>, ChatError> {
+        Ok(self.load_log(conversation_id)?.history)
+    }
+
+    pub fn delete(&self, conversation_id: &str) -> Result<(), ChatError> {
+        let path = self.log_path(conversation_id)?;
+        if path.exists() {
+            fs::remove_file(&path).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to delete memory audit log: {}", e),
+            })?;
+        }
+        Ok(())
+    }
+
+    /// 等间隔抽样，保证新旧检查点都有代表（结果确定，便于前后两次审计对比）
+    fn sample_evenly<'a>(pool: &[&'a String], size: usize) -> Vec<&'a String> {
+        if pool.len() <= size {
+            return pool.to_vec();
+        }
+        (0..size).map(|i| pool[i * pool.len() / size]).collect()
+    }
+
+    /// 事实原文出现在语料中，或其实体/关键词足够多地出现在语料中
+    pub fn is_derivable(fact: &str, corpus: &str) -> bool {
+        let normalize = |s: &str| -> String {
+            s.chars()
+                .filter(|c| c.is_alphanumeric())
+                .flat_map(|c| c.to_lowercase())
+                .collect()
+        };
+        let fact_norm = normalize(fact);
+        if fact_norm.is_empty() {
+            return true;
+        }
+        let corpus_norm = normalize(corpus);
+        if corpus_norm.contains(&fact_norm) {
+            return true;
+        }
+
+        // 整句（中文无空格）不作为实体，只保留实词二元组与英文/数字词
+        let entities: Vec<String> = MemoryEngine::extract_keywords(fact)
+            .into_iter()
+            .filter(|k| {
+                k.is_ascii()
+                    || (k.chars().count() == 2 && !k.chars().any(|c| FUNCTION_CHARS.contains(c)))
+            })
+            .collect();
+        if entities.is_empty() {
+            return false;
+        }
+        let hits = entities.iter().filter(|e| corpus_norm.contains(e.as_str())).count();
+        hits as f64 / entities.len() as f64 >= ENTITY_MATCH_THRESHOLD
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn make_summary(summary: &str, facts: &[&str], generation: u32) -> MemorySummary {
+        MemorySummary {
+            id: uuid::Uuid::new_v4().to_string(),
+            summary: summary.to_string(),
+            core_facts: facts.iter().map(|f| f.to_string()).collect(),
+            turn_range_start: 1,
+            turn_range_end: 10,
+            created_at: 0,
+            keywords: vec![],
+            compression_generation: generation,
+            context_card: None,
+            fact_tiers: vec![],
+            linked_fact_ids: vec![],
+            pinned: false,
+            pinned_facts: Vec::new(),
+            stale: false,
+        }
+    }
+
+    #[test]
+    fn test_is_derivable_matches_text_and_entities() {
+        let corpus = "小雨养了一只叫团子的橘猫。她在杭州读研究生。";
+        assert!(FidelityAuditor::is_derivable("小雨养了一只叫团子的橘猫", corpus));
+        assert!(FidelityAuditor::is_derivable("小雨在杭州读研", corpus));
+        assert!(!FidelityAuditor::is_derivable("小雨对花生过敏", corpus));
+    }
+
+    #[test]
+    fn test_audit_scores_loss_and_alerts_on_drop() {
+        let tmp = TempDir::new().unwrap();
+        let auditor = FidelityAuditor::new(tmp.path().to_str().unwrap());
+        let full = vec![make_summary(
+            "初识",
+            &["小雨养了一只叫团子的橘猫", "小雨对花生过敏", "小雨在杭州读研"],
+            0,
+        )];
+        auditor.record_checkpoint("c1", &full).unwrap();
+        // 相同事实不重复记录
+        auditor.record_checkpoint("c1", &full).unwrap();
+
+        let first = auditor.audit("c1", &full).unwrap();
+        assert_eq!(first.sampled_facts, 3);
+        assert!((first.score - 1.0).abs() < 1e-9);
+        assert!(!first.alert);
+
+        let compressed = vec![make_summary("小雨在杭州读研，养了橘猫团子", &[], 3)];
+        let second = auditor.audit("c1", &compressed).unwrap();
+        assert_eq!(second.lost_facts, vec!["小雨对花生过敏".to_string()]);
+        assert_eq!(second.previous_score, Some(1.0));
+        assert!(second.alert);
+        assert_eq!(auditor.history("c1").unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_audit_without_checkpoints_is_clean() {
+        let tmp = TempDir::new().unwrap();
+        let auditor = FidelityAuditor::new(tmp.path().to_str().unwrap());
+        let report = auditor.audit("none", &[]).unwrap();
+        assert_eq!(report.sampled_facts, 0);
+        assert!(!report.alert);
+        auditor.delete("none").unwrap();
+        assert!(auditor.history("none").unwrap().is_empty());
+    }
+}
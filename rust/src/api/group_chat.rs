@@ -0,0 +1,334 @@
+use std::fs;
+use std::path::PathBuf;
+
+use flutter_rust_bridge::frb;
+
+use super::data_models::*;
+use super::error_handler::ChatError;
+use super::memory_engine::MemoryEngine;
+use super::text_utils;
+
+// ═══════════════════════════════════════════════════════════════════
+//  多角色群聊 (Group Chat)
+//  ─────────────────────────────────────────────────────────────────
+//  一个对话里可以有多位 AI 角色，各自有人设、知识库与记忆：
+//    1. 配置：group_chats/{conversation_id}.json 记录角色名单与调度方式；
+//       有配置的对话即为群聊，发送/重新生成自动走群聊管线
+//    2. 调度：用户点名的角色优先；否则按 TurnPolicy 轮流，
+//       或由快速模型挑选下一位（超时/失败时退回轮流）
+//    3. 视角：为发言角色构建专属视图——人设换成该角色的 system prompt，
+//       其他角色的发言改为带「【名字】」前缀的 user 消息，
+//       避免模型把别人的台词当成自己说过的话
+//    4. 隔离：每个角色的知识与记忆存放在 MemoryEngine::character_scope
+//       命名空间下，互不串味
+//  回复消息通过 Message::character_id 标注发言角色。
+// ═══════════════════════════════════════════════════════════════════
+
+/// 群聊最少角色数
+const MIN_CHARACTERS: usize = 2;
+/// 挑选发言者时带入的最近消息条数
+const SPEAKER_CONTEXT_MESSAGES: usize = 8;
+/// 挑选发言者时单条消息的截断长度（字符）
+const SPEAKER_CONTEXT_CHARS: usize = 120;
+
+#[frb(opaque)]
+#[derive(Clone)]
+pub struct GroupChatStore {
+    base_path: String,
+}
+
+impl GroupChatStore {
+    pub fn new(base_path: &str) -> Self {
+        Self {
+            base_path: base_path.to_string(),
+        }
+    }
+
+    fn groups_dir(&self) -> Result<PathBuf, ChatError> {
+        let dir = PathBuf::from(&self.base_path).join("group_chats");
+        if !dir.exists() {
+            fs::create_dir_all(&dir).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to create group chat directory: {}", e),
+            })?;
+        }
+        Ok(dir)
+    }
+
+    fn group_path(&self, conversation_id: &str) -> Result<PathBuf, ChatError> {
+        Ok(self.groups_dir()?.join(format!("{}.json", conversation_id)))
+    }
+
+    /// 对话的群聊配置；不是群聊时返回 None
+    pub fn load(&self, conversation_id: &str) -> Option<GroupChat> {
+        let path = self.group_path(conversation_id).ok()?;
+        let json = fs::read_to_string(&path).ok()?;
+        serde_json::from_str(&json).ok()
+    }
+
+    /// 设为群聊（或更新角色名单）；角色 id 为空时生成，名字不能为空或重复
+    pub fn save(
+        &self,
+        conversation_id: &str,
+        characters: Vec<GroupCharacter>,
+        turn_policy: TurnPolicy,
+    ) -> Result<GroupChat, ChatError> {
+        if characters.len() < MIN_CHARACTERS {
+            return Err(ChatError::ValidationError {
+                message: format!("A group chat needs at least {} characters", MIN_CHARACTERS),
+            });
+        }
+        let mut normalized: Vec<GroupCharacter> = Vec::new();
+        for mut character in characters {
+            character.name = character.name.trim().to_string();
+            if character.name.is_empty() {
+                return Err(ChatError::ValidationError {
+                    message: "Group character name cannot be empty".to_string(),
+                });
+            }
+            if normalized.iter().any(|c| c.name == character.name) {
+                return Err(ChatError::ValidationError {
+                    message: format!("Duplicate group character name '{}'", character.name),
+                });
+            }
+            if character.id.is_empty() {
+                character.id = uuid::Uuid::new_v4().to_string();
+            }
+            normalized.push(character);
+        }
+
+        let group = GroupChat {
+            conversation_id: conversation_id.to_string(),
+            characters: normalized,
+            turn_policy,
+        };
+        let json = serde_json::to_string_pretty(&group).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to serialize group chat: {}", e),
+        })?;
+        fs::write(self.group_path(conversation_id)?, json).map_err(|e| {
+            ChatError::StorageError {
+                message: format!("Failed to write group chat: {}", e),
+            }
+        })?;
+        Ok(group)
+    }
+
+    pub fn delete(&self, conversation_id: &str) -> Result<(), ChatError> {
+        let path = self.group_path(conversation_id)?;
+        if path.exists() {
+            fs::remove_file(&path).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to delete group chat: {}", e),
+            })?;
+        }
+        Ok(())
+    }
+
+    /// 各角色的知识/记忆命名空间
+    pub fn scopes(group: &GroupChat) -> Vec<String> {
+        group
+            .characters
+            .iter()
+            .map(|c| MemoryEngine::character_scope(&group.conversation_id, &c.id))
+            .collect()
+    }
+
+    /// 用户在消息里点名的角色（名字出现得最早的一位）
+    pub fn addressed<'a>(group: &'a GroupChat, content: &str) -> Option<&'a GroupCharacter> {
+        group
+            .characters
+            .iter()
+            .filter_map(|c| content.find(c.name.as_str()).map(|pos| (pos, c)))
+            .min_by_key(|(pos, _)| *pos)
+            .map(|(_, c)| c)
+    }
+
+    /// 轮流发言：上一位发言角色的下一位；还没有人发言时从第一位开始
+    pub fn next_round_robin<'a>(group: &'a GroupChat, messages: &[Message]) -> &'a GroupCharacter {
+        let last_idx = messages
+            .iter()
+            .rev()
+            .filter_map(|m| m.character_id.as_deref())
+            .find_map(|id| group.characters.iter().position(|c| c.id == id));
+        match last_idx {
+            Some(idx) => &group.characters[(idx + 1) % group.characters.len()],
+            None => &group.characters[0],
+        }
+    }
+
+    fn speaker_label(group: &GroupChat, message: &Message) -> String {
+        match message.role {
+            MessageRole::User => "用户".to_string(),
+            _ => message
+                .character_id
+                .as_deref()
+                .and_then(|id| group.characters.iter().find(|c| c.id == id))
+                .map(|c| c.name.clone())
+                .unwrap_or_else(|| "其他角色".to_string()),
+        }
+    }
+
+    /// 让快速模型挑选下一位发言者的提示
+    pub fn build_speaker_choice_prompt(group: &GroupChat, messages: &[Message]) -> String {
+        let names: Vec<&str> = group.characters.iter().map(|c| c.name.as_str()).collect();
+        let recent: Vec<&Message> = messages
+            .iter()
+            .filter(|m| m.role != MessageRole::System)
+            .collect();
+        let start = recent.len().saturating_sub(SPEAKER_CONTEXT_MESSAGES);
+        let transcript: Vec<String> = recent[start..]
+            .iter()
+            .map(|m| {
+                let text = text_utils::truncate_chars(&m.content, SPEAKER_CONTEXT_CHARS);
+                format!("【{}】{}", Self::speaker_label(group, m), text)
+            })
+            .collect();
+        format!(
+            "下面是一段多人群聊的最近记录，在场角色：{}。\n\
+             判断接下来最自然该由哪位角色发言，只输出角色名字本身，不要解释。\n\n{}",
+            names.join("、"),
+            transcript.join("\n")
+        )
+    }
+
+    /// 从模型输出中认出角色名（出现得最早的一位）
+    pub fn parse_speaker_choice<'a>(group: &'a GroupChat, text: &str) -> Option<&'a GroupCharacter> {
+        Self::addressed(group, text.trim())
+    }
+
+    /// 发言角色视角下的对话：换上该角色的人设，其他角色的发言改为带名字前缀的 user 消息
+    pub fn character_view(
+        conv: &Conversation,
+        group: &GroupChat,
+        speaker: &GroupCharacter,
+    ) -> Conversation {
+        let others: Vec<&str> = group
+            .characters
+            .iter()
+            .filter(|c| c.id != speaker.id)
+            .map(|c| c.name.as_str())
+            .collect();
+        let mut system = speaker.system_prompt.trim().to_string();
+        if let Some(scene) = conv
+            .messages
+            .iter()
+            .find(|m| m.role == MessageRole::System)
+            .filter(|m| !m.content.trim().is_empty())
+        {
+            system.push_str("\n\n【场景设定】\n");
+            system.push_str(scene.content.trim());
+        }
+        system.push_str(&format!(
+            "\n\n【群聊】你是「{}」。这是一场多人对话，在场的还有：{}。\n\
+             - 其他角色的发言会以「【名字】」开头出现在对话里\n\
+             - 你只以「{}」的身份说话，不要替其他角色发言，也不要在回复开头写自己的名字\n",
+            speaker.name,
+            others.join("、"),
+            speaker.name
+        ));
+
+        let mut messages = vec![Message {
+            character_id: Some(speaker.id.clone()),
+            ..Message::system_prompt(system)
+        }];
+        for msg in conv.messages.iter().filter(|m| m.role != MessageRole::System) {
+            if msg.role == MessageRole::Assistant
+                && msg.character_id.as_deref() != Some(speaker.id.as_str())
+            {
+                let mut relabeled = msg.clone();
+                relabeled.role = MessageRole::User;
+                relabeled.content = format!("【{}】{}", Self::speaker_label(group, msg), msg.content);
+                relabeled.thinking_content = None;
+                messages.push(relabeled);
+            } else {
+                messages.push(msg.clone());
+            }
+        }
+
+        let mut view = conv.clone();
+        view.messages = messages;
+        view.memory_summaries = Vec::new();
+        view
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn character(name: &str) -> GroupCharacter {
+        GroupCharacter {
+            id: String::new(),
+            name: name.to_string(),
+            system_prompt: format!("你是{}", name),
+        }
+    }
+
+    fn msg(role: MessageRole, content: &str, character_id: Option<&str>) -> Message {
+        Message {
+            id: String::new(),
+            timestamp: 0,
+            character_id: character_id.map(|s| s.to_string()),
+            ..Message::new(role, content, "test")
+        }
+    }
+
+    #[test]
+    fn test_save_validates_roster_and_schedules_round_robin() {
+        let tmp = TempDir::new().unwrap();
+        let store = GroupChatStore::new(tmp.path().to_str().unwrap());
+        assert!(store.load("c1").is_none());
+        assert!(store.save("c1", vec![character("小雨")], TurnPolicy::RoundRobin).is_err());
+        assert!(store
+            .save("c1", vec![character("小雨"), character(" 小雨 ")], TurnPolicy::RoundRobin)
+            .is_err());
+
+        let group = store
+            .save("c1", vec![character("小雨"), character("阿晴"), character("老陈")], TurnPolicy::RoundRobin)
+            .unwrap();
+        assert_eq!(store.load("c1").unwrap(), group);
+        let ids: Vec<&str> = group.characters.iter().map(|c| c.id.as_str()).collect();
+
+        assert_eq!(GroupChatStore::next_round_robin(&group, &[]).name, "小雨");
+        let history = vec![
+            msg(MessageRole::User, "大家好", None),
+            msg(MessageRole::Assistant, "嗨", Some(ids[2])),
+        ];
+        assert_eq!(GroupChatStore::next_round_robin(&group, &history).name, "小雨");
+        assert_eq!(
+            GroupChatStore::addressed(&group, "阿晴你觉得呢？小雨先别说").unwrap().name,
+            "阿晴"
+        );
+        assert_eq!(
+            GroupChatStore::parse_speaker_choice(&group, " 老陈\n").unwrap().name,
+            "老陈"
+        );
+        assert!(GroupChatStore::parse_speaker_choice(&group, "路人").is_none());
+    }
+
+    #[test]
+    fn test_character_view_relabels_other_speakers() {
+        let tmp = TempDir::new().unwrap();
+        let store = GroupChatStore::new(tmp.path().to_str().unwrap());
+        let group = store
+            .save("c1", vec![character("小雨"), character("阿晴")], TurnPolicy::ModelChosen)
+            .unwrap();
+        let (rain, sunny) = (&group.characters[0], &group.characters[1]);
+
+        let conv = Conversation {
+            messages: vec![
+                msg(MessageRole::System, "咖啡馆里", None),
+                msg(MessageRole::User, "今天喝什么", None),
+                msg(MessageRole::Assistant, "拿铁吧", Some(&rain.id)),
+                msg(MessageRole::Assistant, "我要美式", Some(&sunny.id)),
+            ],
+            ..crate::api::conversation_store::ConversationStore::new("unused").create_conversation()
+        };
+        let view = GroupChatStore::character_view(&conv, &group, rain);
+        assert!(view.messages[0].content.starts_with("你是小雨"));
+        assert!(view.messages[0].content.contains("咖啡馆里"));
+        assert!(view.messages[0].content.contains("阿晴"));
+        assert_eq!(view.messages[2].role, MessageRole::Assistant);
+        assert_eq!(view.messages[3].role, MessageRole::User);
+        assert_eq!(view.messages[3].content, "【阿晴】我要美式");
+    }
+}
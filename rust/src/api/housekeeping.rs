@@ -0,0 +1,397 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::PathBuf;
+
+use flutter_rust_bridge::frb;
+
+use super::at_rest;
+use super::conversation_store::ConversationStore;
+use super::data_models::{HousekeepingReport, Message, MessageRole, RetentionPolicy};
+use super::error_handler::ChatError;
+use super::knowledge_layers;
+
+// ═══════════════════════════════════════════════════════════════════
+//  数据保留与自动清理 (Housekeeping)
+//  ─────────────────────────────────────────────────────────────────
+//  长期使用后数据目录只增不减。按保留策略（retention.json）执行一次维护：
+//    1. 自动归档：闲置超过 N 天的对话标记为已归档（置顶的除外）
+//    2. 冷存储：主线消息超过上限时，最早的非 system 消息移入冷存储，
+//       对话文件只保留最近的部分；分支的分叉点及其之后的消息不动
+//    3. 孤立文件：对话已不存在的记忆索引 / 知识库 / 向量 / 审计文件直接删除
+//  冷存储经 at_rest 整体读出、追加、原子写回（随静态加密一起加密，保留 .bak）。
+//  先写冷存储再回写对话：中途失败时消息仍留在对话里，下次移出时按消息ID去重，
+//  不会丢消息也不会重复。正在生成回复的对话本次跳过（持有生成锁才动对话文件）。
+//
+//  存储结构：
+//    cold_storage/{conversation_id}.jsonl   （每行一条 Message，旧 → 新）
+// ═══════════════════════════════════════════════════════════════════
+
+const DAY_MS: i64 = 24 * 60 * 60 * 1000;
+
+/// 以对话（或群聊角色 `{对话ID}@{角色ID}`）为命名空间的目录
+const SCOPED_DIRS: [&str; 4] = [
+    "memory_index",
+    "knowledge_base",
+    "memory_vectors",
+    "memory_audit",
+];
+/// 同一命名空间的附属文件后缀（长的在前）
+const SCOPE_SUFFIXES: [&str; 4] = ["_archived_facts", "_distilled", "_facts", "_index"];
+/// 不属于任何对话的共享文件
+const SHARED_FILES: [&str; 1] = ["global_facts.json"];
+
+#[frb(opaque)]
+#[derive(Clone)]
+pub struct Housekeeper {
+    base_path: String,
+    conversation_store: ConversationStore,
+}
+
+impl Housekeeper {
+    pub fn new(base_path: &str) -> Self {
+        Self {
+            base_path: base_path.to_string(),
+            conversation_store: ConversationStore::new(base_path),
+        }
+    }
+
+    /// 按策略执行一次维护；now 为毫秒时间戳
+    pub fn run(&self, policy: &RetentionPolicy, now: i64) -> Result<HousekeepingReport, ChatError> {
+        let mut report = HousekeepingReport::default();
+        for summary in self.conversation_store.list_conversations() {
+            // 与生成管线共用对话的生成锁：正在生成的对话下次再维护
+            let Ok(_turn) = ConversationStore::try_lock_turn(&summary.id) else {
+                continue;
+            };
+            let idle_days = (now - summary.updated_at) / DAY_MS;
+            if policy.archive_after_idle_days > 0
+                && idle_days >= policy.archive_after_idle_days as i64
+                && !summary.organization.archived
+                && !summary.organization.pinned
+            {
+                let mut organization = summary.organization.clone();
+                organization.archived = true;
+                self.conversation_store
+                    .set_organization(&summary.id, organization)?;
+                report.archived.push(summary.id.clone());
+            }
+            if policy.max_messages_per_conversation > 0 {
+                report.rolled_over_messages +=
+                    self.roll_over(&summary.id, policy.max_messages_per_conversation as usize)?;
+            }
+        }
+        if policy.purge_orphans {
+            report.purged_files = self.purge_orphans()?;
+        }
+        Ok(report)
+    }
+
+    /// 对话已转入冷存储的消息（旧 → 新）
+    pub fn cold_messages(&self, conversation_id: &str) -> Result<Vec<Message>, ChatError> {
+        let path = self.cold_path(conversation_id)?;
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+        at_rest::load(&path, "cold storage", |data| {
+            String::from_utf8_lossy(data)
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .map(|line| {
+                    serde_json::from_str(line).map_err(|e| ChatError::StorageError {
+                        message: format!("Failed to parse cold storage: {}", e),
+                    })
+                })
+                .collect()
+        })
+    }
+
+    pub fn delete(&self, conversation_id: &str) -> Result<(), ChatError> {
+        let path = self.cold_path(conversation_id)?;
+        at_rest::remove(&path).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to delete cold storage: {}", e),
+        })
+    }
+
+    /// 把超出上限的最早消息移入冷存储，返回移走的条数
+    fn roll_over(&self, conversation_id: &str, max_messages: usize) -> Result<u32, ChatError> {
+        let mut conv = self.conversation_store.load_conversation(conversation_id)?;
+        let kept = conv
+            .messages
+            .iter()
+            .filter(|m| m.role != MessageRole::System)
+            .count();
+        if kept <= max_messages {
+            return Ok(0);
+        }
+        let fork_points: HashSet<&str> = conv
+            .branches
+            .iter()
+            .filter_map(|b| b.parent_message_id.as_deref())
+            .collect();
+        let mut excess = kept - max_messages;
+        let mut cold: Vec<usize> = Vec::new();
+        for (i, message) in conv.messages.iter().enumerate() {
+            if excess == 0 || fork_points.contains(message.id.as_str()) {
+                break;
+            }
+            if message.role != MessageRole::System {
+                cold.push(i);
+                excess -= 1;
+            }
+        }
+        if cold.is_empty() {
+            return Ok(0);
+        }
+
+        // 上次移出后没来得及回写对话时，这些消息已在冷存储里：按ID去重
+        let mut stored = self.cold_messages(conversation_id)?;
+        let stored_ids: HashSet<String> = stored.iter().map(|m| m.id.clone()).collect();
+        stored.extend(
+            cold.iter()
+                .map(|&i| &conv.messages[i])
+                .filter(|m| !stored_ids.contains(&m.id))
+                .cloned(),
+        );
+        let mut lines = String::new();
+        for message in &stored {
+            let json = serde_json::to_string(message).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to serialize cold message: {}", e),
+            })?;
+            lines.push_str(&json);
+            lines.push('\n');
+        }
+        at_rest::write(self.cold_path(conversation_id)?, lines).map_err(|e| {
+            ChatError::StorageError {
+                message: format!("Failed to write cold storage: {}", e),
+            }
+        })?;
+
+        let moved = cold.len() as u32;
+        // 移走的轮数记在对话上，完整性校验按 cold_turns + 剩余用户消息数核对轮数
+        conv.cold_turns += cold
+            .iter()
+            .filter(|&&i| conv.messages[i].role == MessageRole::User)
+            .count() as u32;
+        let cold: HashSet<usize> = cold.into_iter().collect();
+        let mut index = 0;
+        conv.messages.retain(|_| {
+            let keep = !cold.contains(&index);
+            index += 1;
+            keep
+        });
+        self.conversation_store.save_conversation(&conv)?;
+        Ok(moved)
+    }
+
+    /// 删除所属对话已不存在的记忆 / 知识文件，返回删除的文件数
+    fn purge_orphans(&self) -> Result<u32, ChatError> {
+        let conversations_dir = PathBuf::from(&self.base_path).join("conversations");
+        // 读不到对话目录时无法判断归属，什么都不删
+        let live: HashSet<String> = match fs::read_dir(&conversations_dir) {
+            Ok(entries) => entries
+                .filter_map(|e| e.ok())
+                .filter_map(|e| {
+                    let path = e.path();
+                    path.file_stem()
+                        .and_then(|s| s.to_str())
+                        .map(|s| s.to_string())
+                })
+                .collect(),
+            Err(_) => return Ok(0),
+        };
+
+        let mut purged = 0;
+        for dir_name in SCOPED_DIRS {
+            let dir = PathBuf::from(&self.base_path).join(dir_name);
+            let entries = match fs::read_dir(&dir) {
+                Ok(entries) => entries,
+                Err(_) => continue,
+            };
+            for entry in entries.filter_map(|e| e.ok()) {
+                let path = entry.path();
+                if !path.is_file() {
+                    continue;
+                }
+                let file_name = entry.file_name().to_string_lossy().to_string();
+                if SHARED_FILES.contains(&file_name.as_str()) {
+                    continue;
+                }
+                let owner = match Self::owner_of(&file_name) {
+                    Some(owner) => owner,
+                    None => continue,
+                };
+                if live.contains(owner)
+                    || ConversationStore::is_sandbox(owner)
+                    || knowledge_layers::is_shared_scope(owner)
+                {
+                    continue;
+                }
+                fs::remove_file(&path).map_err(|e| ChatError::StorageError {
+                    message: format!("Failed to delete orphaned file '{}': {}", file_name, e),
+                })?;
+                purged += 1;
+            }
+        }
+        Ok(purged)
+    }
+
+    /// 文件所属的对话ID：去掉备份后缀、扩展名、附属后缀与群聊角色部分
+    fn owner_of(file_name: &str) -> Option<&str> {
+        let file_name = file_name
+            .strip_suffix(at_rest::BACKUP_SUFFIX)
+            .unwrap_or(file_name);
+        let stem = file_name.strip_suffix(".json")?;
+        let scope = SCOPE_SUFFIXES
+            .iter()
+            .find_map(|suffix| stem.strip_suffix(suffix))
+            .unwrap_or(stem);
+        let owner = scope.split('@').next().unwrap_or(scope);
+        (!owner.is_empty()).then_some(owner)
+    }
+
+    fn cold_path(&self, conversation_id: &str) -> Result<PathBuf, ChatError> {
+        let dir = PathBuf::from(&self.base_path).join("cold_storage");
+        if !dir.exists() {
+            fs::create_dir_all(&dir).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to create cold storage directory: {}", e),
+            })?;
+        }
+        Ok(dir.join(format!("{}.jsonl", conversation_id)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn message(id: &str, role: MessageRole) -> Message {
+        Message {
+            id: id.to_string(),
+            timestamp: 0,
+            ..Message::new(role, id, "test")
+        }
+    }
+
+    #[test]
+    fn test_archive_idle_and_roll_over_to_cold_storage() {
+        let tmp = TempDir::new().unwrap();
+        let base = tmp.path().to_str().unwrap();
+        let store = ConversationStore::new(base);
+        let mut conv = store.create_conversation();
+        conv.messages.push(message("sys", MessageRole::System));
+        for i in 0..6 {
+            let role = if i % 2 == 0 {
+                MessageRole::User
+            } else {
+                MessageRole::Assistant
+            };
+            conv.messages.push(message(&format!("m{}", i), role));
+        }
+        conv.updated_at = 0;
+        store.save_conversation(&conv).unwrap();
+
+        let housekeeper = Housekeeper::new(base);
+        let policy = RetentionPolicy {
+            archive_after_idle_days: 30,
+            max_messages_per_conversation: 4,
+            purge_orphans: false,
+        };
+        let report = housekeeper.run(&policy, 31 * DAY_MS).unwrap();
+        assert_eq!(report.archived, vec![conv.id.clone()]);
+        assert_eq!(report.rolled_over_messages, 2);
+
+        let saved = store.load_conversation(&conv.id).unwrap();
+        assert!(saved.organization.archived);
+        let ids: Vec<&str> = saved.messages.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(ids, vec!["sys", "m2", "m3", "m4", "m5"]);
+        let cold = housekeeper.cold_messages(&conv.id).unwrap();
+        assert_eq!(
+            cold.iter().map(|m| m.id.as_str()).collect::<Vec<_>>(),
+            vec!["m0", "m1"]
+        );
+
+        // 已归档、已在上限内：再次执行无变化
+        let again = housekeeper.run(&policy, 31 * DAY_MS).unwrap();
+        assert_eq!(again, HousekeepingReport::default());
+    }
+
+    #[test]
+    fn test_roll_over_skips_busy_conversations_and_never_duplicates() {
+        let tmp = TempDir::new().unwrap();
+        let base = tmp.path().to_str().unwrap();
+        let store = ConversationStore::new(base);
+        let mut conv = store.create_conversation();
+        for i in 0..4 {
+            conv.messages.push(message(&format!("m{}", i), MessageRole::User));
+        }
+        store.save_conversation(&conv).unwrap();
+        let housekeeper = Housekeeper::new(base);
+        let policy = RetentionPolicy {
+            archive_after_idle_days: 0,
+            max_messages_per_conversation: 2,
+            purge_orphans: false,
+        };
+
+        // 生成中的对话不动
+        let turn = ConversationStore::try_lock_turn(&conv.id).unwrap();
+        assert_eq!(housekeeper.run(&policy, 0).unwrap().rolled_over_messages, 0);
+        drop(turn);
+
+        // 模拟上次写完冷存储、回写对话前崩溃：m0 已在冷存储里
+        let cold_path = housekeeper.cold_path(&conv.id).unwrap();
+        let line = serde_json::to_string(&conv.messages[0]).unwrap();
+        at_rest::write(&cold_path, format!("{}\n", line)).unwrap();
+        assert_eq!(housekeeper.run(&policy, 0).unwrap().rolled_over_messages, 2);
+        let cold: Vec<String> = housekeeper
+            .cold_messages(&conv.id)
+            .unwrap()
+            .into_iter()
+            .map(|m| m.id)
+            .collect();
+        assert_eq!(cold, vec!["m0", "m1"]);
+        assert_eq!(store.load_conversation(&conv.id).unwrap().messages.len(), 2);
+    }
+
+    #[test]
+    fn test_purge_orphans_keeps_live_and_shared_files() {
+        let tmp = TempDir::new().unwrap();
+        let base = tmp.path().to_str().unwrap();
+        let store = ConversationStore::new(base);
+        let conv = store.create_conversation();
+        store.save_conversation(&conv).unwrap();
+
+        let knowledge = tmp.path().join("knowledge_base");
+        let memory = tmp.path().join("memory_index");
+        fs::create_dir_all(&knowledge).unwrap();
+        fs::create_dir_all(&memory).unwrap();
+        for name in [
+            format!("{}_facts.json", conv.id),
+            "global_facts.json".to_string(),
+            "_character_erin_facts.json".to_string(),
+            "gone_facts.json".to_string(),
+            "gone_archived_facts.json".to_string(),
+        ] {
+            fs::write(knowledge.join(name), "[]").unwrap();
+        }
+        for name in [
+            format!("{}@alice.json", conv.id),
+            "gone@alice.json".to_string(),
+            "gone_distilled.json".to_string(),
+        ] {
+            fs::write(memory.join(name), "[]").unwrap();
+        }
+
+        let policy = RetentionPolicy {
+            purge_orphans: true,
+            ..Default::default()
+        };
+        let report = Housekeeper::new(base).run(&policy, 0).unwrap();
+        assert_eq!(report.purged_files, 4);
+        assert!(knowledge.join(format!("{}_facts.json", conv.id)).exists());
+        assert!(knowledge.join("global_facts.json").exists());
+        assert!(knowledge.join("_character_erin_facts.json").exists());
+        assert!(memory.join(format!("{}@alice.json", conv.id)).exists());
+        assert!(!memory.join("gone@alice.json").exists());
+    }
+}
@@ -0,0 +1,210 @@
+use std::sync::Mutex;
+
+use serde_json::{json, Value};
+
+use super::config_manager;
+use super::data_models::{AppSettings, Message, MessageRole, ProviderKind};
+use super::error_handler::ChatError;
+use super::jwt_auth::JwtAuth;
+use super::text_utils;
+
+// ═══════════════════════════════════════════════════════════════════
+//  场景插画 (Scene Illustration)
+//  ─────────────────────────────────────────────────────────────────
+//  用户点「画一张」或发送 /draw，为当前场景生成一张插画：
+//    1. 画面描述：对话模型把最近几轮浓缩成一段画面描述（人物、动作、
+//       场景、光线），失败时退回最近几轮原文拼接
+//    2. 生成：智谱 CogView（images/generations，非流式）
+//    3. 落盘：插画作为一条 AI 消息写入对话，图片以附件形式保存，
+//       画面描述记为 caption，后续对话与记忆只看到描述文字
+//    4. 推送：通过 ChatStreamEvent::Illustration 把这条消息交给前端
+//  CogView 返回的是临时地址，前端应在收到后自行缓存图片。
+//  只有智谱提供方可用；其他提供方返回提示。
+// ═══════════════════════════════════════════════════════════════════
+
+const COGVIEW_URL: &str = "https://open.bigmodel.cn/api/paas/v4/images/generations";
+const COGVIEW_MODEL: &str = "cogview-3-flash";
+const COGVIEW_SIZE: &str = "1024x1024";
+/// 生成请求超时（出图通常需要十几秒）
+const COGVIEW_TIMEOUT_SECS: u64 = 60;
+/// 画面描述最长字符数（CogView prompt 上限以内）
+const MAX_PROMPT_CHARS: usize = 500;
+/// 参与画面描述的最近消息数
+const SCENE_CONTEXT_MESSAGES: usize = 6;
+
+/// 画面描述的输出上限
+pub const DESCRIBE_MAX_TOKENS: u32 = 300;
+/// 画面描述请求的超时
+pub const DESCRIBE_TIMEOUT_SECS: u64 = 20;
+/// 插画消息的正文
+pub const ILLUSTRATION_CONTENT: &str = "（画了一张插画）";
+
+/// 智谱 CogView 文生图
+pub struct CogViewClient {
+    jwt_auth: Mutex<JwtAuth>,
+}
+
+impl CogViewClient {
+    pub fn new(api_key: &str) -> Result<Self, String> {
+        Ok(Self {
+            jwt_auth: Mutex::new(JwtAuth::new(api_key)?),
+        })
+    }
+
+    /// 按设置创建：只有智谱提供方且配置了 API Key 时可用
+    pub fn from_settings(settings: &AppSettings) -> Option<Self> {
+        if settings.provider != ProviderKind::Zhipu {
+            return None;
+        }
+        let key = settings.api_key.as_deref().map(str::trim).filter(|k| !k.is_empty())?;
+        Self::new(key).ok()
+    }
+
+    pub fn build_request(prompt: &str) -> Value {
+        json!({
+            "model": COGVIEW_MODEL,
+            "prompt": text_utils::truncate_chars(prompt, MAX_PROMPT_CHARS),
+            "size": COGVIEW_SIZE,
+        })
+    }
+
+    /// 解析响应：data[0].url
+    pub fn parse_response(body: &Value) -> Result<String, ChatError> {
+        body.get("data")
+            .and_then(|d| d.as_array())
+            .and_then(|d| d.first())
+            .and_then(|item| item.get("url"))
+            .and_then(|u| u.as_str())
+            .filter(|u| !u.is_empty())
+            .map(str::to_string)
+            .ok_or_else(|| ChatError::StreamError {
+                message: "Image generation response missing url".to_string(),
+            })
+    }
+
+    /// 生成一张图片，返回图片地址
+    pub async fn generate(&self, prompt: &str) -> Result<String, ChatError> {
+        let client = config_manager::apply_proxy(
+            reqwest::Client::builder().timeout(std::time::Duration::from_secs(COGVIEW_TIMEOUT_SECS)),
+        )?
+        .build()
+        .map_err(|e| ChatError::NetworkError {
+            message: e.to_string(),
+        })?;
+        let token = {
+            let mut auth = self.jwt_auth.lock().unwrap();
+            auth.get_token()
+        };
+        let resp = client
+            .post(COGVIEW_URL)
+            .header("Authorization", format!("Bearer {}", token))
+            .json(&Self::build_request(prompt))
+            .send()
+            .await
+            .map_err(|e| ChatError::NetworkError {
+                message: format!("插画请求失败: {}", e),
+            })?;
+        let status = resp.status();
+        if !status.is_success() {
+            return Err(ChatError::ApiError {
+                status: status.as_u16(),
+                message: resp.text().await.unwrap_or_default(),
+            });
+        }
+        let body: Value = resp.json().await.map_err(|e| ChatError::StreamError {
+            message: format!("Failed to parse image generation response: {}", e),
+        })?;
+        Self::parse_response(&body)
+    }
+
+    /// 最近几轮对话（不含 system），按时间顺序
+    fn recent_scene(messages: &[Message]) -> Vec<&Message> {
+        let mut recent: Vec<&Message> = messages
+            .iter()
+            .rev()
+            .filter(|m| m.role != MessageRole::System && m.content != ILLUSTRATION_CONTENT)
+            .take(SCENE_CONTEXT_MESSAGES)
+            .collect();
+        recent.reverse();
+        recent
+    }
+
+    /// 让对话模型写画面描述的 prompt
+    pub fn build_describe_prompt(messages: &[Message], hint: &str) -> String {
+        let mut scene = String::new();
+        for m in Self::recent_scene(messages) {
+            let speaker = if m.role == MessageRole::User { "用户" } else { "角色" };
+            scene.push_str(&format!("{}：{}\n", speaker, text_utils::ellipsize(&m.content, 200, "…")));
+        }
+        let hint = if hint.trim().is_empty() {
+            String::new()
+        } else {
+            format!("用户希望画面包含：{}\n", hint.trim())
+        };
+        format!(
+            "以下是一段对话的最近几轮：\n{}{}\
+             请为「当前这一刻」的场景写一段插画的画面描述，用于文生图：\n\
+             - 写清人物外貌与动作、所处场景、光线与氛围、画风（默认日系插画）\n\
+             - 只写画面里看得到的东西，不写对白、不写心理活动\n\
+             - 150字以内，只输出描述本身",
+            scene, hint
+        )
+    }
+
+    /// 画面描述失败时的兜底：最近几轮原文拼接
+    pub fn fallback_prompt(messages: &[Message], hint: &str) -> String {
+        let mut prompt = String::from("日系插画风格，");
+        if !hint.trim().is_empty() {
+            prompt.push_str(hint.trim());
+            prompt.push('，');
+        }
+        let scene: Vec<String> = Self::recent_scene(messages)
+            .iter()
+            .map(|m| m.content.trim().to_string())
+            .collect();
+        prompt.push_str(&scene.join(" "));
+        text_utils::truncate_chars(&prompt, MAX_PROMPT_CHARS).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn msg(role: MessageRole, content: &str) -> Message {
+        Message {
+            id: String::new(),
+            timestamp: 0,
+            ..Message::new(role, content, "")
+        }
+    }
+
+    #[test]
+    fn test_parse_response_reads_first_url() {
+        let body = json!({"created": 1, "data": [{"url": "https://x.cn/1.png"}]});
+        assert_eq!(CogViewClient::parse_response(&body).unwrap(), "https://x.cn/1.png");
+        assert!(CogViewClient::parse_response(&json!({"data": []})).is_err());
+
+        let long = "雨".repeat(MAX_PROMPT_CHARS + 50);
+        let request = CogViewClient::build_request(&long);
+        assert_eq!(request["model"], COGVIEW_MODEL);
+        assert_eq!(request["prompt"].as_str().unwrap().chars().count(), MAX_PROMPT_CHARS);
+    }
+
+    #[test]
+    fn test_scene_prompts_skip_system_and_earlier_illustrations() {
+        let messages = vec![
+            msg(MessageRole::System, "你是小雨"),
+            msg(MessageRole::User, "*撑着伞走到你身边*"),
+            msg(MessageRole::Assistant, ILLUSTRATION_CONTENT),
+            msg(MessageRole::Assistant, "*抬头看了看天* 雨好大呀"),
+        ];
+        let describe = CogViewClient::build_describe_prompt(&messages, "黄昏");
+        assert!(describe.contains("用户：*撑着伞走到你身边*"));
+        assert!(describe.contains("用户希望画面包含：黄昏"));
+        assert!(!describe.contains("你是小雨"));
+
+        let fallback = CogViewClient::fallback_prompt(&messages, "");
+        assert_eq!(fallback, "日系插画风格，*撑着伞走到你身边* *抬头看了看天* 雨好大呀");
+    }
+}
@@ -0,0 +1,420 @@
+use std::collections::HashSet;
+
+use flutter_rust_bridge::frb;
+
+use super::conversation_store::ConversationStore;
+use super::data_models::*;
+use super::error_handler::ChatError;
+use super::knowledge_store::KnowledgeStore;
+use super::memory_engine::MemoryEngine;
+
+// ═══════════════════════════════════════════════════════════════════
+//  对话完整性检查 (Integrity Checker)
+//  ─────────────────────────────────────────────────────────────────
+//  对话、记忆索引、知识库分别落盘，回滚/删除消息/异常中断后
+//  三者之间可能出现不一致。本模块交叉校验：
+//    1. turn_count 与实际用户消息轮数
+//    2. 记忆摘要的轮次范围与实际轮数
+//    3. 记忆索引文件与对话内嵌摘要是否同步
+//    4. 事实的 source_turn 是否指向存在的轮次
+//    5. 倒排索引是否存在悬空条目
+//
+//  auto_repair = true 时就地修复并回写；否则只读，不修改任何文件。
+// ═══════════════════════════════════════════════════════════════════
+
+#[frb(opaque)]
+pub struct IntegrityChecker {
+    conversation_store: ConversationStore,
+    memory_engine: MemoryEngine,
+    knowledge_store: KnowledgeStore,
+}
+
+impl IntegrityChecker {
+    pub fn new(base_path: &str) -> Self {
+        Self {
+            conversation_store: ConversationStore::new(base_path),
+            memory_engine: MemoryEngine::new(base_path),
+            knowledge_store: KnowledgeStore::new(base_path),
+        }
+    }
+
+    pub fn verify(
+        &self,
+        conversation_id: &str,
+        auto_repair: bool,
+    ) -> Result<IntegrityReport, ChatError> {
+        let mut conv = self.conversation_store.load_conversation(conversation_id)?;
+        let mut issues: Vec<IntegrityIssue> = Vec::new();
+        let mut conv_dirty = false;
+
+        // 早期消息可能已移入冷存储，那部分轮数记在 cold_turns 上
+        let actual_turns = conv.cold_turns
+            + conv
+                .messages
+                .iter()
+                .filter(|m| m.role == MessageRole::User)
+                .count() as u32;
+        let recorded_turn_count = conv.turn_count;
+
+        // ── 1. 轮数校验 ──
+        if conv.turn_count != actual_turns {
+            issues.push(IntegrityIssue {
+                kind: IntegrityIssueKind::TurnCountMismatch,
+                detail: format!(
+                    "turn_count={} but conversation has {} user turns",
+                    conv.turn_count, actual_turns
+                ),
+                repaired: auto_repair,
+            });
+            conv.turn_count = actual_turns;
+            conv_dirty = true;
+        }
+
+        // ── 2. 记忆摘要校验 ──
+        let index_summaries = self.memory_engine.load_memory_index(conversation_id)?;
+        let index_ids: Vec<&str> = index_summaries.iter().map(|s| s.id.as_str()).collect();
+        let conv_ids: Vec<&str> = conv.memory_summaries.iter().map(|s| s.id.as_str()).collect();
+        let desynced = index_ids != conv_ids;
+        if desynced {
+            issues.push(IntegrityIssue {
+                kind: IntegrityIssueKind::SummaryIndexDesync,
+                detail: format!(
+                    "memory index has {} summaries, conversation has {}",
+                    index_ids.len(),
+                    conv_ids.len()
+                ),
+                repaired: auto_repair,
+            });
+        }
+
+        // 记忆索引是检索的实际来源，优先以它为准
+        let mut summaries = if index_summaries.is_empty() {
+            conv.memory_summaries.clone()
+        } else {
+            index_summaries
+        };
+        let mut summaries_dirty = desynced;
+
+        summaries.retain(|s| {
+            let invalid = s.turn_range_start > s.turn_range_end || s.turn_range_start > actual_turns;
+            if invalid {
+                issues.push(IntegrityIssue {
+                    kind: IntegrityIssueKind::SummaryRangeInvalid,
+                    detail: format!(
+                        "summary {} covers turns {}-{} outside 1-{}, dropped",
+                        s.id, s.turn_range_start, s.turn_range_end, actual_turns
+                    ),
+                    repaired: auto_repair,
+                });
+                summaries_dirty = true;
+            }
+            !invalid
+        });
+        for summary in summaries.iter_mut() {
+            if summary.turn_range_end > actual_turns {
+                issues.push(IntegrityIssue {
+                    kind: IntegrityIssueKind::SummaryRangeInvalid,
+                    detail: format!(
+                        "summary {} ends at turn {} beyond {}, clamped",
+                        summary.id, summary.turn_range_end, actual_turns
+                    ),
+                    repaired: auto_repair,
+                });
+                summary.turn_range_end = actual_turns;
+                summaries_dirty = true;
+            }
+        }
+
+        // ── 3. 倒排索引校验（基于清理前的事实集合，孤儿事实不重复计为悬空）──
+        let mut facts = self.knowledge_store.load_facts(conversation_id)?;
+        let fact_ids: HashSet<String> = facts.iter().map(|f| f.id.clone()).collect();
+        let mut index_dirty = false;
+        match self.knowledge_store.load_index(conversation_id)? {
+            Some(index) => {
+                let mut indexed: HashSet<&str> = HashSet::new();
+                let mut dangling: HashSet<&str> = HashSet::new();
+                for ids in index
+                    .keyword_index
+                    .values()
+                    .chain(index.entity_index.values())
+                    .chain(index.category_index.values())
+                {
+                    for id in ids {
+                        if fact_ids.contains(id) {
+                            indexed.insert(id.as_str());
+                        } else {
+                            dangling.insert(id.as_str());
+                        }
+                    }
+                }
+                if !dangling.is_empty() {
+                    issues.push(IntegrityIssue {
+                        kind: IntegrityIssueKind::DanglingIndexEntry,
+                        detail: format!("index references {} missing facts", dangling.len()),
+                        repaired: auto_repair,
+                    });
+                    index_dirty = true;
+                }
+                let unindexed = fact_ids.iter().filter(|id| !indexed.contains(id.as_str())).count();
+                if unindexed > 0 {
+                    issues.push(IntegrityIssue {
+                        kind: IntegrityIssueKind::DanglingIndexEntry,
+                        detail: format!("{} facts missing from index", unindexed),
+                        repaired: auto_repair,
+                    });
+                    index_dirty = true;
+                }
+            }
+            None if !facts.is_empty() => {
+                issues.push(IntegrityIssue {
+                    kind: IntegrityIssueKind::DanglingIndexEntry,
+                    detail: "index file missing".to_string(),
+                    repaired: auto_repair,
+                });
+                index_dirty = true;
+            }
+            None => {}
+        }
+
+        // ── 4. 事实来源校验 ──
+        let mut facts_dirty = false;
+        facts.retain(|f| {
+            let orphan = f.source_turn > actual_turns;
+            if orphan {
+                issues.push(IntegrityIssue {
+                    kind: IntegrityIssueKind::OrphanFact,
+                    detail: format!(
+                        "fact {} references turn {} beyond {}, removed",
+                        f.id, f.source_turn, actual_turns
+                    ),
+                    repaired: auto_repair,
+                });
+                facts_dirty = true;
+            }
+            !orphan
+        });
+
+        if auto_repair {
+            if summaries_dirty {
+                self.memory_engine
+                    .save_memory_index(conversation_id, &summaries)?;
+                conv.memory_summaries = summaries;
+                conv_dirty = true;
+            }
+            if conv_dirty {
+                self.conversation_store.save_conversation(&conv)?;
+            }
+            if facts_dirty {
+                self.knowledge_store.save_facts(conversation_id, &facts)?;
+            }
+            if index_dirty || facts_dirty {
+                self.knowledge_store.rebuild_index(conversation_id, &facts)?;
+            }
+        }
+
+        Ok(IntegrityReport {
+            conversation_id: conversation_id.to_string(),
+            actual_turns,
+            recorded_turn_count,
+            issues,
+            checked_at: chrono::Utc::now().timestamp_millis(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::housekeeping::Housekeeper;
+    use crate::api::knowledge_store::{Fact, FactCategory};
+    use tempfile::TempDir;
+
+    fn make_message(role: MessageRole, content: &str) -> Message {
+        Message {
+            timestamp: 0,
+            ..Message::new(role, content, "glm-4.7")
+        }
+    }
+
+    fn make_summary(id: &str, start: u32, end: u32) -> MemorySummary {
+        MemorySummary {
+            id: id.to_string(),
+            summary: "摘要".to_string(),
+            core_facts: vec![],
+            turn_range_start: start,
+            turn_range_end: end,
+            created_at: 0,
+            keywords: vec![],
+            compression_generation: 0,
+            context_card: None,
+            fact_tiers: vec![],
+            linked_fact_ids: vec![],
+            pinned: false,
+            pinned_facts: Vec::new(),
+            stale: false,
+        }
+    }
+
+    fn make_fact(id: &str, source_turn: u32) -> Fact {
+        Fact {
+            id: id.to_string(),
+            content: format!("用户→提到→{}", id),
+            category: FactCategory::Event,
+            source_turn,
+            created_at: 0,
+            last_confirmed_at: 0,
+            keywords: vec![id.to_string()],
+            entities: vec!["用户".to_string()],
+            confidence: 0.8,
+            hit_count: 0,
+            context_snippet: String::new(),
+            summary_ids: Vec::new(),
+            superseded_by: None,
+            superseded_at: None,
+            manual_override: false,
+            persona_id: None,
+        }
+    }
+
+    /// 两轮对话，但 turn_count、摘要、事实都停留在回滚前的第 5 轮
+    fn setup_broken(base: &str) -> String {
+        let store = ConversationStore::new(base);
+        let mut conv = store.create_conversation();
+        conv.messages = vec![
+            make_message(MessageRole::User, "你好"),
+            make_message(MessageRole::Assistant, "嗨"),
+            make_message(MessageRole::User, "今天去哪"),
+            make_message(MessageRole::Assistant, "去海边"),
+        ];
+        conv.turn_count = 5;
+        conv.memory_summaries = vec![make_summary("s1", 1, 5)];
+        store.save_conversation(&conv).unwrap();
+
+        let memory = MemoryEngine::new(base);
+        memory
+            .save_memory_index(&conv.id, &[make_summary("s1", 1, 5), make_summary("s2", 4, 5)])
+            .unwrap();
+
+        let knowledge = KnowledgeStore::new(base);
+        let facts = vec![make_fact("f1", 1), make_fact("f2", 4)];
+        knowledge.rebuild_index(&conv.id, &facts).unwrap();
+        knowledge.save_facts(&conv.id, &facts[..1]).unwrap();
+        conv.id
+    }
+
+    #[test]
+    fn test_verify_clean_conversation() {
+        let tmp = TempDir::new().unwrap();
+        let base = tmp.path().to_str().unwrap();
+        let store = ConversationStore::new(base);
+        let mut conv = store.create_conversation();
+        conv.messages = vec![make_message(MessageRole::User, "你好")];
+        conv.turn_count = 1;
+        store.save_conversation(&conv).unwrap();
+
+        let report = IntegrityChecker::new(base).verify(&conv.id, false).unwrap();
+        assert_eq!(report.actual_turns, 1);
+        assert!(report.issues.is_empty());
+    }
+
+    #[test]
+    fn test_verify_reports_without_modifying() {
+        let tmp = TempDir::new().unwrap();
+        let base = tmp.path().to_str().unwrap();
+        let id = setup_broken(base);
+
+        let report = IntegrityChecker::new(base).verify(&id, false).unwrap();
+        let kinds: Vec<&IntegrityIssueKind> = report.issues.iter().map(|i| &i.kind).collect();
+        assert!(kinds.contains(&&IntegrityIssueKind::TurnCountMismatch));
+        assert!(kinds.contains(&&IntegrityIssueKind::SummaryIndexDesync));
+        assert!(kinds.contains(&&IntegrityIssueKind::SummaryRangeInvalid));
+        assert!(kinds.contains(&&IntegrityIssueKind::DanglingIndexEntry));
+        assert!(report.issues.iter().all(|i| !i.repaired));
+
+        let conv = ConversationStore::new(base).load_conversation(&id).unwrap();
+        assert_eq!(conv.turn_count, 5);
+    }
+
+    #[test]
+    fn test_auto_repair_converges() {
+        let tmp = TempDir::new().unwrap();
+        let base = tmp.path().to_str().unwrap();
+        let id = setup_broken(base);
+        let checker = IntegrityChecker::new(base);
+
+        let report = checker.verify(&id, true).unwrap();
+        assert!(!report.issues.is_empty());
+        assert!(report.issues.iter().all(|i| i.repaired));
+
+        let conv = ConversationStore::new(base).load_conversation(&id).unwrap();
+        assert_eq!(conv.turn_count, 2);
+        assert_eq!(conv.memory_summaries.len(), 1);
+        assert_eq!(conv.memory_summaries[0].turn_range_end, 2);
+
+        let second = checker.verify(&id, false).unwrap();
+        assert!(second.issues.is_empty(), "{:?}", second.issues);
+    }
+
+    #[test]
+    fn test_orphan_fact_removed() {
+        let tmp = TempDir::new().unwrap();
+        let base = tmp.path().to_str().unwrap();
+        let store = ConversationStore::new(base);
+        let mut conv = store.create_conversation();
+        conv.messages = vec![make_message(MessageRole::User, "你好")];
+        conv.turn_count = 1;
+        store.save_conversation(&conv).unwrap();
+        let knowledge = KnowledgeStore::new(base);
+        let facts = vec![make_fact("f1", 1), make_fact("f9", 9)];
+        knowledge.save_facts(&conv.id, &facts).unwrap();
+        knowledge.rebuild_index(&conv.id, &facts).unwrap();
+
+        let report = IntegrityChecker::new(base).verify(&conv.id, true).unwrap();
+        assert_eq!(report.issues.len(), 1);
+        assert_eq!(report.issues[0].kind, IntegrityIssueKind::OrphanFact);
+        let facts = knowledge.load_facts(&conv.id).unwrap();
+        assert_eq!(facts.len(), 1);
+        assert_eq!(facts[0].id, "f1");
+    }
+
+    #[test]
+    fn test_rolled_over_turns_are_not_dropped() {
+        let tmp = TempDir::new().unwrap();
+        let base = tmp.path().to_str().unwrap();
+        let store = ConversationStore::new(base);
+        let mut conv = store.create_conversation();
+        for (user, reply) in [("你好", "嗨"), ("今天去哪", "去海边"), ("带什么", "带伞")] {
+            conv.messages.push(make_message(MessageRole::User, user));
+            conv.messages.push(make_message(MessageRole::Assistant, reply));
+        }
+        conv.turn_count = 3;
+        conv.memory_summaries = vec![make_summary("s1", 1, 3)];
+        store.save_conversation(&conv).unwrap();
+        MemoryEngine::new(base)
+            .save_memory_index(&conv.id, &conv.memory_summaries)
+            .unwrap();
+        let knowledge = KnowledgeStore::new(base);
+        let facts = vec![make_fact("f1", 1), make_fact("f3", 3)];
+        knowledge.save_facts(&conv.id, &facts).unwrap();
+        knowledge.rebuild_index(&conv.id, &facts).unwrap();
+
+        // 前两轮移入冷存储，只剩最后一轮的两条消息
+        let policy = RetentionPolicy {
+            archive_after_idle_days: 0,
+            max_messages_per_conversation: 2,
+            purge_orphans: false,
+        };
+        let report = Housekeeper::new(base).run(&policy, 0).unwrap();
+        assert_eq!(report.rolled_over_messages, 4);
+        assert_eq!(store.load_conversation(&conv.id).unwrap().cold_turns, 2);
+
+        let report = IntegrityChecker::new(base).verify(&conv.id, true).unwrap();
+        assert_eq!(report.actual_turns, 3);
+        assert!(report.issues.is_empty(), "{:?}", report.issues);
+        let saved = store.load_conversation(&conv.id).unwrap();
+        assert_eq!(saved.turn_count, 3);
+        assert_eq!(saved.memory_summaries.len(), 1);
+        assert_eq!(saved.memory_summaries[0].turn_range_end, 3);
+        assert_eq!(knowledge.load_facts(&conv.id).unwrap().len(), 2);
+    }
+}
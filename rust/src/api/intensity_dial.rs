@@ -0,0 +1,140 @@
+use super::data_models::ContentIntensity;
+
+// ═══════════════════════════════════════════════════════════════════
+//  内容强度调节 (Intensity Dial)
+//  ─────────────────────────────────────────────────────────────────
+//  同一个角色卡在不同场合使用（通勤路上 / 深夜独处 / 和朋友一起看），
+//  能接受的情绪烈度和用语尺度不同。三档强度：
+//    Mild     — 冲突点到为止，情绪克制，不说粗口
+//    Normal   — 情绪真实但不失控，仅允许轻度口头语
+//    Dramatic — 允许激烈冲突、情绪爆发和符合人设的粗口
+//
+//  双重约束：
+//    1. 生成前：按档位注入提示块，约束情绪升级、冲突与用语
+//    2. 生成后：按档位检查成稿，超出尺度的词语以 ＊ 遮蔽后再落盘
+// ═══════════════════════════════════════════════════════════════════
+
+/// 轻度口头语：Mild 档遮蔽
+const MILD_TERMS: &[&str] = &[
+    "该死", "混蛋", "滚开", "滚蛋", "闭嘴", "见鬼", "damn",
+];
+
+/// 重度粗口：Mild / Normal 档遮蔽
+const HARSH_TERMS: &[&str] = &[
+    "他妈的", "他妈", "妈的", "卧槽", "我操", "操你", "傻逼", "王八蛋", "狗日的", "去死",
+    "fuck", "shit", "bitch",
+];
+
+/// 遮蔽字符
+const MASK_CHAR: char = '＊';
+
+/// 生成后检查结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct IntensityCheck {
+    /// 遮蔽后的内容
+    pub content: String,
+    /// 被遮蔽的词语数
+    pub masked: usize,
+}
+
+pub struct IntensityDial;
+
+impl IntensityDial {
+    /// 生成前的强度提示块
+    pub fn build_prompt(level: ContentIntensity) -> String {
+        match level {
+            ContentIntensity::Mild => "【内容强度：温和】\n\
+                 - 情绪表达克制，不激化矛盾；出现分歧时倾向理解和缓和\n\
+                 - 冲突点到为止，不写争吵升级、威胁或伤害\n\
+                 - 不使用任何粗口、脏话或侮辱性词语，生气也用文明的方式表达"
+                .to_string(),
+            ContentIntensity::Normal => "【内容强度：常规】\n\
+                 - 情绪真实自然，可以生气、委屈、吃醋，但不失控\n\
+                 - 冲突可以发生，但要有分寸，不进行人身攻击\n\
+                 - 不使用重度粗口；符合人设时可偶尔用轻度口头语"
+                .to_string(),
+            ContentIntensity::Dramatic => "【内容强度：戏剧化】\n\
+                 - 允许强烈的情绪起伏和激烈冲突，情绪爆发要有铺垫和因果\n\
+                 - 符合人设时可以使用粗口，但不要为了刺激而堆砌\n\
+                 - 冲突服务于剧情和人物关系，不针对用户本人进行侮辱"
+                .to_string(),
+        }
+    }
+
+    /// 当前档位需要遮蔽的词语
+    fn blocked_terms(level: ContentIntensity) -> Vec<&'static str> {
+        match level {
+            ContentIntensity::Mild => HARSH_TERMS.iter().chain(MILD_TERMS).copied().collect(),
+            ContentIntensity::Normal => HARSH_TERMS.to_vec(),
+            ContentIntensity::Dramatic => Vec::new(),
+        }
+    }
+
+    /// 生成后检查：遮蔽超出当前档位尺度的词语（长词优先，英文不区分大小写）
+    pub fn enforce(level: ContentIntensity, content: &str) -> IntensityCheck {
+        let mut terms = Self::blocked_terms(level);
+        terms.sort_by_key(|t| std::cmp::Reverse(t.chars().count()));
+
+        let mut result = content.to_string();
+        let mut masked = 0;
+        for term in terms {
+            let lower = result.to_lowercase();
+            // to_lowercase 对本表中的字符不改变字节长度，下标可直接复用
+            if lower.len() != result.len() {
+                continue;
+            }
+            let positions: Vec<usize> = lower.match_indices(term).map(|(i, _)| i).collect();
+            if positions.is_empty() {
+                continue;
+            }
+            let mask = MASK_CHAR.to_string().repeat(term.chars().count());
+            for pos in positions.into_iter().rev() {
+                result.replace_range(pos..pos + term.len(), &mask);
+                masked += 1;
+            }
+        }
+
+        IntensityCheck {
+            content: result,
+            masked,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prompt_differs_per_level() {
+        let mild = IntensityDial::build_prompt(ContentIntensity::Mild);
+        let dramatic = IntensityDial::build_prompt(ContentIntensity::Dramatic);
+        assert!(mild.contains("不使用任何粗口"));
+        assert!(dramatic.contains("可以使用粗口"));
+    }
+
+    #[test]
+    fn test_enforce_by_level() {
+        let text = "该死，他妈的又迟到了。Fuck!";
+
+        let mild = IntensityDial::enforce(ContentIntensity::Mild, text);
+        assert_eq!(mild.content, "＊＊，＊＊＊又迟到了。＊＊＊＊!");
+        assert_eq!(mild.masked, 3);
+
+        let normal = IntensityDial::enforce(ContentIntensity::Normal, text);
+        assert_eq!(normal.content, "该死，＊＊＊又迟到了。＊＊＊＊!");
+        assert_eq!(normal.masked, 2);
+
+        let dramatic = IntensityDial::enforce(ContentIntensity::Dramatic, text);
+        assert_eq!(dramatic.content, text);
+        assert_eq!(dramatic.masked, 0);
+    }
+
+    #[test]
+    fn test_enforce_leaves_clean_text_untouched() {
+        let text = "今天在操场上跑了三圈，好累呀。";
+        let check = IntensityDial::enforce(ContentIntensity::Mild, text);
+        assert_eq!(check.content, text);
+        assert_eq!(check.masked, 0);
+    }
+}
@@ -0,0 +1,365 @@
+use std::collections::VecDeque;
+use std::future::Future;
+use std::sync::{Mutex, OnceLock};
+use std::time::Instant;
+
+use tokio::sync::Semaphore;
+
+use super::data_models::{BackgroundJobKind, DeferredJob, DeviceConditions, JobSchedulerStats};
+use super::streaming_handler;
+
+// ═══════════════════════════════════════════════════════════════════
+//  后台任务调度 (Job Scheduler)
+//  ─────────────────────────────────────────────────────────────────
+//  事实提取、记忆摘要、影子评估等后台任务每轮都可能触发，
+//  连续聊天时会堆积起来，在手机上持续占用网络与 CPU、消耗电量。
+//  所有后台任务统一经过这里：
+//    1. 并发上限：非关键任务同时最多 MAX_CONCURRENT_JOBS 个，其余排队
+//    2. 配额：每类任务有滚动一小时内的次数（网络请求）与占用时长预算，
+//       用完后延后
+//    3. 设备压力：宿主 App 上报电量 / 充电 / 省电模式 / 计费网络，
+//       压力下先延后可有可无的任务（影子评估），电量告急时再延后
+//       普通任务（事实提取、摘要）
+//    4. 补跑：被延后的任务按（类型, 对话）去重记下，条件恢复后
+//       由宿主调用 run_deferred_jobs 补跑
+//  关键任务（回复管线内的蒸馏）只记账，不受并发、配额与压力限制。
+// ═══════════════════════════════════════════════════════════════════
+
+/// 非关键任务的全局并发上限
+const MAX_CONCURRENT_JOBS: usize = 2;
+/// 配额统计窗口：1 小时
+const QUOTA_WINDOW_MS: i64 = 60 * 60 * 1000;
+/// 等待补跑的任务上限（超出时丢弃最旧的）
+const MAX_PENDING_JOBS: usize = 50;
+/// 低电量阈值（未充电时）
+const LOW_BATTERY: f64 = 0.3;
+/// 电量告急阈值（未充电时）
+const CRITICAL_BATTERY: f64 = 0.15;
+
+/// 任务优先级
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum JobPriority {
+    /// 可有可无：任何压力下都延后
+    Deferrable,
+    /// 普通：电量告急时延后
+    Normal,
+    /// 关键：从不延后
+    Critical,
+}
+
+/// 设备压力等级
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Pressure {
+    None,
+    /// 低电量未充电 / 省电模式 / 计费网络
+    Moderate,
+    /// 电量告急且未充电
+    Severe,
+}
+
+/// 每类任务的预算（滚动一小时）
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct JobBudget {
+    pub priority: JobPriority,
+    /// 最多执行次数（每次至少一个网络请求）；0 表示不限
+    pub runs_per_hour: u32,
+    /// 最多占用时长（毫秒，按任务实际执行时间计）；0 表示不限
+    pub busy_ms_per_hour: u64,
+}
+
+/// 准入结果
+#[derive(Debug, Clone, PartialEq)]
+pub enum Admission {
+    Run,
+    Defer(String),
+}
+
+struct Usage {
+    kind: BackgroundJobKind,
+    at: i64,
+    busy_ms: u64,
+}
+
+#[derive(Default)]
+struct SchedulerState {
+    conditions: DeviceConditions,
+    usage: VecDeque<Usage>,
+    pending: Vec<DeferredJob>,
+    running: u32,
+    completed: u64,
+    timed_out: u64,
+    deferred_total: u64,
+}
+
+pub struct JobScheduler {
+    permits: Semaphore,
+    state: Mutex<SchedulerState>,
+}
+
+static GLOBAL: OnceLock<JobScheduler> = OnceLock::new();
+
+impl JobScheduler {
+    pub fn new() -> Self {
+        Self {
+            permits: Semaphore::new(MAX_CONCURRENT_JOBS),
+            state: Mutex::new(SchedulerState::default()),
+        }
+    }
+
+    /// 进程内共享的调度器
+    pub fn global() -> &'static JobScheduler {
+        GLOBAL.get_or_init(JobScheduler::new)
+    }
+
+    pub fn budget(kind: BackgroundJobKind) -> JobBudget {
+        match kind {
+            BackgroundJobKind::FactExtraction => JobBudget {
+                priority: JobPriority::Normal,
+                runs_per_hour: 60,
+                busy_ms_per_hour: 10 * 60 * 1000,
+            },
+            BackgroundJobKind::Summarization => JobBudget {
+                priority: JobPriority::Normal,
+                runs_per_hour: 20,
+                busy_ms_per_hour: 10 * 60 * 1000,
+            },
+            BackgroundJobKind::Distillation => JobBudget {
+                priority: JobPriority::Critical,
+                runs_per_hour: 0,
+                busy_ms_per_hour: 0,
+            },
+            BackgroundJobKind::ShadowEval => JobBudget {
+                priority: JobPriority::Deferrable,
+                runs_per_hour: 20,
+                busy_ms_per_hour: 5 * 60 * 1000,
+            },
+        }
+    }
+
+    pub fn pressure(conditions: &DeviceConditions) -> Pressure {
+        let battery = if conditions.is_charging {
+            None
+        } else {
+            conditions.battery_level
+        };
+        match battery {
+            Some(level) if level < CRITICAL_BATTERY => Pressure::Severe,
+            Some(level) if level < LOW_BATTERY => Pressure::Moderate,
+            _ if conditions.low_power_mode || conditions.metered_network => Pressure::Moderate,
+            _ => Pressure::None,
+        }
+    }
+
+    /// 宿主 App 上报最新设备状态
+    pub fn set_conditions(&self, conditions: DeviceConditions) {
+        if let Ok(mut state) = self.state.lock() {
+            state.conditions = conditions;
+        }
+    }
+
+    /// 判断任务当前能否执行（now 为毫秒时间戳）
+    pub fn admit(&self, kind: BackgroundJobKind, now: i64) -> Admission {
+        let budget = Self::budget(kind);
+        if budget.priority == JobPriority::Critical {
+            return Admission::Run;
+        }
+        let Ok(mut state) = self.state.lock() else {
+            return Admission::Run;
+        };
+        let pressure = Self::pressure(&state.conditions);
+        let blocked_at = match budget.priority {
+            JobPriority::Deferrable => Pressure::Moderate,
+            _ => Pressure::Severe,
+        };
+        if pressure >= blocked_at {
+            return Admission::Defer(match pressure {
+                Pressure::Severe => "电量告急".to_string(),
+                _ => "省电 / 低电量 / 计费网络".to_string(),
+            });
+        }
+
+        while state.usage.front().is_some_and(|u| now - u.at > QUOTA_WINDOW_MS) {
+            state.usage.pop_front();
+        }
+        let (runs, busy_ms) = state
+            .usage
+            .iter()
+            .filter(|u| u.kind == kind)
+            .fold((0u32, 0u64), |(n, ms), u| (n + 1, ms + u.busy_ms));
+        if budget.runs_per_hour > 0 && runs >= budget.runs_per_hour {
+            return Admission::Defer(format!("每小时最多执行 {} 次", budget.runs_per_hour));
+        }
+        if budget.busy_ms_per_hour > 0 && busy_ms >= budget.busy_ms_per_hour {
+            return Admission::Defer(format!(
+                "每小时最多占用 {}s",
+                budget.busy_ms_per_hour / 1000
+            ));
+        }
+        Admission::Run
+    }
+
+    /// 在调度下执行任务；被延后时返回 None，并记入待补跑列表
+    pub async fn run<T>(
+        &self,
+        kind: BackgroundJobKind,
+        conversation_id: &str,
+        job: impl Future<Output = T>,
+    ) -> Option<T> {
+        let now = chrono::Utc::now().timestamp_millis();
+        if let Admission::Defer(reason) = self.admit(kind, now) {
+            self.defer(kind, conversation_id, now, reason);
+            return None;
+        }
+
+        let critical = Self::budget(kind).priority == JobPriority::Critical;
+        let _permit = if critical {
+            None
+        } else {
+            self.permits.acquire().await.ok()
+        };
+        self.update(|s| s.running += 1);
+        let started = Instant::now();
+        // 非关键任务中的模型请求让路给交互请求（见 streaming_handler 的请求队列）
+        let result = if critical {
+            job.await
+        } else {
+            streaming_handler::as_background(job).await
+        };
+        let busy_ms = started.elapsed().as_millis() as u64;
+        self.update(|s| {
+            s.running = s.running.saturating_sub(1);
+            s.completed += 1;
+            s.usage.push_back(Usage {
+                kind,
+                at: chrono::Utc::now().timestamp_millis(),
+                busy_ms,
+            });
+        });
+        Some(result)
+    }
+
+    /// 任务自身超时中止时由调用方上报
+    pub fn record_timeout(&self) {
+        self.update(|s| s.timed_out += 1);
+    }
+
+    fn defer(&self, kind: BackgroundJobKind, conversation_id: &str, now: i64, reason: String) {
+        self.update(|s| {
+            s.deferred_total += 1;
+            s.pending
+                .retain(|j| !(j.kind == kind && j.conversation_id == conversation_id));
+            s.pending.push(DeferredJob {
+                kind,
+                conversation_id: conversation_id.to_string(),
+                deferred_at: now,
+                reason,
+            });
+            if s.pending.len() > MAX_PENDING_JOBS {
+                let excess = s.pending.len() - MAX_PENDING_JOBS;
+                s.pending.drain(..excess);
+            }
+        });
+    }
+
+    /// 直接记入待补跑列表（调用方不在运行时中、无法交给后台任务队列时）
+    pub fn queue(&self, kind: BackgroundJobKind, conversation_id: &str, reason: &str) {
+        let now = chrono::Utc::now().timestamp_millis();
+        self.defer(kind, conversation_id, now, reason.to_string());
+    }
+
+    /// 取出当前条件下可以补跑的任务（其余继续等待）
+    pub fn take_runnable(&self, now: i64) -> Vec<DeferredJob> {
+        let pending = match self.state.lock() {
+            Ok(mut state) => std::mem::take(&mut state.pending),
+            Err(_) => return Vec::new(),
+        };
+        let (runnable, waiting): (Vec<_>, Vec<_>) = pending
+            .into_iter()
+            .partition(|j| self.admit(j.kind, now) == Admission::Run);
+        self.update(|s| {
+            let newer = std::mem::take(&mut s.pending);
+            s.pending = waiting;
+            s.pending.extend(newer);
+        });
+        runnable
+    }
+
+    pub fn stats(&self) -> JobSchedulerStats {
+        match self.state.lock() {
+            Ok(state) => JobSchedulerStats {
+                running: state.running,
+                completed: state.completed,
+                timed_out: state.timed_out,
+                deferred_total: state.deferred_total,
+                pending: state.pending.clone(),
+            },
+            Err(_) => JobSchedulerStats::default(),
+        }
+    }
+
+    fn update(&self, f: impl FnOnce(&mut SchedulerState)) {
+        if let Ok(mut state) = self.state.lock() {
+            f(&mut state);
+        }
+    }
+}
+
+impl Default for JobScheduler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pressure_defers_by_priority() {
+        let scheduler = JobScheduler::new();
+        assert_eq!(scheduler.admit(BackgroundJobKind::ShadowEval, 0), Admission::Run);
+
+        scheduler.set_conditions(DeviceConditions {
+            battery_level: Some(0.25),
+            ..Default::default()
+        });
+        assert!(matches!(scheduler.admit(BackgroundJobKind::ShadowEval, 0), Admission::Defer(_)));
+        assert_eq!(scheduler.admit(BackgroundJobKind::FactExtraction, 0), Admission::Run);
+
+        scheduler.set_conditions(DeviceConditions {
+            battery_level: Some(0.1),
+            ..Default::default()
+        });
+        assert!(matches!(scheduler.admit(BackgroundJobKind::Summarization, 0), Admission::Defer(_)));
+        assert_eq!(scheduler.admit(BackgroundJobKind::Distillation, 0), Admission::Run);
+
+        // 充电时不算压力
+        scheduler.set_conditions(DeviceConditions {
+            battery_level: Some(0.1),
+            is_charging: true,
+            ..Default::default()
+        });
+        assert_eq!(scheduler.admit(BackgroundJobKind::ShadowEval, 0), Admission::Run);
+    }
+
+    #[tokio::test]
+    async fn test_quota_defers_and_pending_jobs_run_later() {
+        let scheduler = JobScheduler::new();
+        let quota = JobScheduler::budget(BackgroundJobKind::ShadowEval).runs_per_hour;
+        for _ in 0..quota {
+            assert_eq!(scheduler.run(BackgroundJobKind::ShadowEval, "c1", async { 1 }).await, Some(1));
+        }
+        assert_eq!(scheduler.run(BackgroundJobKind::ShadowEval, "c1", async { 1 }).await, None);
+        assert_eq!(scheduler.run(BackgroundJobKind::ShadowEval, "c1", async { 1 }).await, None);
+
+        let stats = scheduler.stats();
+        assert_eq!(stats.completed, quota as u64);
+        assert_eq!(stats.deferred_total, 2);
+        assert_eq!(stats.pending.len(), 1, "同一对话的同类任务只记一次");
+
+        let now = chrono::Utc::now().timestamp_millis();
+        assert!(scheduler.take_runnable(now).is_empty());
+        assert_eq!(scheduler.take_runnable(now + QUOTA_WINDOW_MS + 1).len(), 1);
+        assert!(scheduler.stats().pending.is_empty());
+    }
+}
@@ -0,0 +1,290 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use super::data_models::{GraphEdge, GraphExportFormat, GraphNode, KnowledgeGraphView};
+use super::knowledge_store::{Fact, KnowledgeStore};
+
+// ═══════════════════════════════════════════════════════════════════
+//  知识图谱 (Knowledge Graph)
+//  ─────────────────────────────────────────────────────────────────
+//  事实内容本身就是「主体→关系→客体」三元组，把主体 / 客体当作节点、
+//  每条事实当作一条有向边，即得到对话的知识图谱：
+//    - 邻居查询：与某个实体相连的一切（「和妹妹有关的都有什么」），可按跳数扩展
+//    - 路径查询：两个实体之间最短的关系链（忽略边的方向）
+//    - 导出：JSON 或 GraphML，供应用内可视化或外部工具打开
+//  实体按归一化名（去空白、小写）合并；不是三段式的事实不进入图谱。
+//
+//  存储结构：无。图谱按需从事实库构建，只存在于内存中
+// ═══════════════════════════════════════════════════════════════════
+
+/// 邻居查询的最大跳数
+const MAX_NEIGHBOR_DEPTH: u32 = 3;
+
+pub struct KnowledgeGraph {
+    /// 按首次出现顺序排列
+    nodes: Vec<GraphNode>,
+    node_index: HashMap<String, usize>,
+    edges: Vec<GraphEdge>,
+    /// 节点 ID → 相连的边（下标），不区分方向
+    adjacency: HashMap<String, Vec<usize>>,
+}
+
+impl KnowledgeGraph {
+    pub fn from_facts(facts: &[Fact]) -> Self {
+        let mut graph = Self {
+            nodes: Vec::new(),
+            node_index: HashMap::new(),
+            edges: Vec::new(),
+            adjacency: HashMap::new(),
+        };
+        for fact in facts {
+            let [subject, relation, object] = match KnowledgeStore::triple_parts(&fact.content) {
+                Some(parts) => parts,
+                None => continue,
+            };
+            let source = graph.intern(&subject);
+            let target = graph.intern(&object);
+            let edge_idx = graph.edges.len();
+            graph.edges.push(GraphEdge {
+                fact_id: fact.id.clone(),
+                source: source.clone(),
+                target: target.clone(),
+                relation,
+                category: KnowledgeStore::category_label(&fact.category).to_string(),
+            });
+            for node in [&source, &target] {
+                graph.nodes[graph.node_index[node]].degree += 1;
+            }
+            graph.adjacency.entry(source.clone()).or_default().push(edge_idx);
+            if target != source {
+                graph.adjacency.entry(target).or_default().push(edge_idx);
+            }
+        }
+        graph
+    }
+
+    /// 登记实体节点，返回节点 ID
+    fn intern(&mut self, label: &str) -> String {
+        let id = KnowledgeStore::normalize_term(label);
+        if !self.node_index.contains_key(&id) {
+            self.node_index.insert(id.clone(), self.nodes.len());
+            self.nodes.push(GraphNode {
+                id: id.clone(),
+                label: label.to_string(),
+                degree: 0,
+            });
+        }
+        id
+    }
+
+    /// 整张图
+    pub fn view(&self) -> KnowledgeGraphView {
+        KnowledgeGraphView {
+            nodes: self.nodes.clone(),
+            edges: self.edges.clone(),
+        }
+    }
+
+    /// 与 entity 相距 depth 跳以内的子图（depth 取 1..=MAX_NEIGHBOR_DEPTH）；实体不存在时为空
+    pub fn neighbors(&self, entity: &str, depth: u32) -> KnowledgeGraphView {
+        let start = KnowledgeStore::normalize_term(entity);
+        if !self.node_index.contains_key(&start) {
+            return KnowledgeGraphView::default();
+        }
+        let depth = depth.clamp(1, MAX_NEIGHBOR_DEPTH);
+
+        let mut visited: HashSet<String> = HashSet::from([start.clone()]);
+        let mut edge_ids: Vec<usize> = Vec::new();
+        let mut frontier = vec![start];
+        for _ in 0..depth {
+            let mut next = Vec::new();
+            for node in &frontier {
+                for &edge_idx in self.adjacency.get(node).into_iter().flatten() {
+                    if !edge_ids.contains(&edge_idx) {
+                        edge_ids.push(edge_idx);
+                    }
+                    let other = self.other_end(edge_idx, node);
+                    if visited.insert(other.to_string()) {
+                        next.push(other.to_string());
+                    }
+                }
+            }
+            frontier = next;
+        }
+
+        edge_ids.sort_unstable();
+        KnowledgeGraphView {
+            nodes: self
+                .nodes
+                .iter()
+                .filter(|n| visited.contains(&n.id))
+                .cloned()
+                .collect(),
+            edges: edge_ids.into_iter().map(|i| self.edges[i].clone()).collect(),
+        }
+    }
+
+    /// from 到 to 的最短关系链（按经过顺序排列的边）；不连通或实体不存在时为空
+    pub fn find_path(&self, from: &str, to: &str) -> Vec<GraphEdge> {
+        let from = KnowledgeStore::normalize_term(from);
+        let to = KnowledgeStore::normalize_term(to);
+        if from == to || !self.node_index.contains_key(&from) || !self.node_index.contains_key(&to)
+        {
+            return Vec::new();
+        }
+
+        // BFS，记录到达每个节点所经过的边
+        let mut came_by: HashMap<String, usize> = HashMap::new();
+        let mut queue = VecDeque::from([from.clone()]);
+        let mut visited: HashSet<String> = HashSet::from([from.clone()]);
+        while let Some(node) = queue.pop_front() {
+            if node == to {
+                break;
+            }
+            for &edge_idx in self.adjacency.get(&node).into_iter().flatten() {
+                let other = self.other_end(edge_idx, &node).to_string();
+                if visited.insert(other.clone()) {
+                    came_by.insert(other.clone(), edge_idx);
+                    queue.push_back(other);
+                }
+            }
+        }
+        if !came_by.contains_key(&to) {
+            return Vec::new();
+        }
+
+        let mut path = Vec::new();
+        let mut node = to;
+        while node != from {
+            let edge_idx = came_by[&node];
+            node = self.other_end(edge_idx, &node).to_string();
+            path.push(self.edges[edge_idx].clone());
+        }
+        path.reverse();
+        path
+    }
+
+    fn other_end(&self, edge_idx: usize, node: &str) -> &str {
+        let edge = &self.edges[edge_idx];
+        if edge.source == node {
+            &edge.target
+        } else {
+            &edge.source
+        }
+    }
+}
+
+/// 把图谱（或子图）导出为指定格式的文本
+pub fn export(view: &KnowledgeGraphView, format: GraphExportFormat) -> String {
+    match format {
+        GraphExportFormat::Json => serde_json::to_string_pretty(view).unwrap_or_default(),
+        GraphExportFormat::GraphMl => to_graphml(view),
+    }
+}
+
+fn to_graphml(view: &KnowledgeGraphView) -> String {
+    let mut out = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n  \
+         <key id=\"label\" for=\"node\" attr.name=\"label\" attr.type=\"string\"/>\n  \
+         <key id=\"relation\" for=\"edge\" attr.name=\"relation\" attr.type=\"string\"/>\n  \
+         <key id=\"category\" for=\"edge\" attr.name=\"category\" attr.type=\"string\"/>\n  \
+         <key id=\"fact_id\" for=\"edge\" attr.name=\"fact_id\" attr.type=\"string\"/>\n  \
+         <graph id=\"knowledge\" edgedefault=\"directed\">\n",
+    );
+    for node in &view.nodes {
+        out.push_str(&format!(
+            "    <node id=\"{}\"><data key=\"label\">{}</data></node>\n",
+            xml_escape(&node.id),
+            xml_escape(&node.label)
+        ));
+    }
+    for (i, edge) in view.edges.iter().enumerate() {
+        out.push_str(&format!(
+            "    <edge id=\"e{}\" source=\"{}\" target=\"{}\">\
+             <data key=\"relation\">{}</data>\
+             <data key=\"category\">{}</data>\
+             <data key=\"fact_id\">{}</data></edge>\n",
+            i,
+            xml_escape(&edge.source),
+            xml_escape(&edge.target),
+            xml_escape(&edge.relation),
+            xml_escape(&edge.category),
+            xml_escape(&edge.fact_id)
+        ));
+    }
+    out.push_str("  </graph>\n</graphml>\n");
+    out
+}
+
+fn xml_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_graph() -> KnowledgeGraph {
+        let facts = KnowledgeStore::parse_extracted_facts(
+            r#"[{"content": "用户→有→妹妹", "category": "relationship"},
+                {"content": "妹妹→就读于→北大", "category": "identity"},
+                {"content": "妹妹→养了→一只猫", "category": "event"},
+                {"content": "北大 → 位于 → 北京", "category": "identity"},
+                {"content": "艾琳→职业是→骑士", "category": "identity"},
+                {"content": "窗外下着雨", "category": "state"}]"#,
+            1,
+        );
+        KnowledgeGraph::from_facts(&facts)
+    }
+
+    #[test]
+    fn test_neighbors_and_path() {
+        let graph = sample_graph();
+        assert_eq!(graph.view().edges.len(), 5);
+
+        let direct = graph.neighbors("妹妹", 1);
+        let labels: Vec<&str> = direct.nodes.iter().map(|n| n.label.as_str()).collect();
+        assert_eq!(labels, vec!["用户", "妹妹", "北大", "一只猫"]);
+        assert_eq!(direct.edges.len(), 3);
+        assert_eq!(graph.neighbors("妹妹", 2).nodes.len(), 5);
+        assert!(graph.neighbors("不存在", 1).nodes.is_empty());
+
+        let path: Vec<String> = graph
+            .find_path("用户", "北京")
+            .into_iter()
+            .map(|e| format!("{}-{}-{}", e.source, e.relation, e.target))
+            .collect();
+        assert_eq!(path, vec!["用户-有-妹妹", "妹妹-就读于-北大", "北大-位于-北京"]);
+        assert!(graph.find_path("用户", "骑士").is_empty());
+    }
+
+    #[test]
+    fn test_graphml_export_escapes_text() {
+        let facts = KnowledgeStore::parse_extracted_facts(
+            r#"[{"content": "A&B→喜欢→<C>", "category": "preference"}]"#,
+            1,
+        );
+        let graphml = export(&KnowledgeGraph::from_facts(&facts).view(), GraphExportFormat::GraphMl);
+        assert!(graphml.contains("<node id=\"a&amp;b\"><data key=\"label\">A&amp;B</data></node>"));
+        assert!(graphml.contains("source=\"a&amp;b\" target=\"&lt;c&gt;\""));
+        assert!(graphml.trim_end().ends_with("</graphml>"));
+
+        let json: KnowledgeGraphView = serde_json::from_str(&export(
+            &KnowledgeGraph::from_facts(&facts).view(),
+            GraphExportFormat::Json,
+        ))
+        .unwrap();
+        assert_eq!(json.edges[0].relation, "喜欢");
+    }
+}
@@ -0,0 +1,560 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use super::at_rest;
+use super::data_models::{KnowledgeBinding, KnowledgeLayer};
+use super::embedding::SemanticQuery;
+use super::error_handler::ChatError;
+use super::group_chat::GroupChatStore;
+use super::knowledge_store::{Fact, FactCategory, FactSearchResult, KnowledgeStore};
+
+// ═══════════════════════════════════════════════════════════════════
+//  知识分层 (Knowledge Layers)
+//  ─────────────────────────────────────────────────────────────────
+//  事实原本只按对话（群聊按「对话@角色」）平铺存放，同一角色开番外对话
+//  要从头认识自己，群聊里一个角色目睹的事件别人都不知道。现在分三层：
+//    - 对话层：{conversation_id}（群聊另有各角色视角 {对话ID}@{角色ID}）
+//    - 角色层：_character_{角色ID}，绑定同一角色的对话共享
+//    - 世界层：_world_{世界ID}，绑定同一世界的对话共享
+//  对话通过 KnowledgeBinding 绑定角色与世界；群聊角色直接以 GroupCharacter::id
+//  作为角色层 ID。各层复用 KnowledgeStore 的事实文件、索引与冲突归档。
+//
+//  晋升规则（写入对话层的新事实按规则复制到上层，原条目保留）：
+//    1. 主体是对话角色本人（名字或「AI角色」）的身份 / 偏好 / 关系 / 承诺 → 角色层
+//    2. 群聊角色视角中的事件 → 该对话的共享层（在场的角色都目睹了）
+//    3. 主体既不是用户也不是出场角色的身份事实（地点、组织、设定），
+//       且置信度不低于 WORLD_PROMOTION_CONFIDENCE → 世界层
+//    其余（状态、共识、关于用户的事实）留在对话层；用户档案另见 KnowledgeStore。
+//    也可以手动把任意事实晋升到指定层（promote_fact）。
+//  检索时从具体到宽泛依次合并：上层与下层重复或矛盾的事实被下层遮蔽
+//  （番外可以改写角色设定而不影响正篇）。
+//
+//  存储结构：
+//    knowledge_base/
+//      _namespaces.json                    — 对话ID → KnowledgeBinding
+//      _character_{id}_facts.json / _index.json / _archived_facts.json
+//      _world_{id}_facts.json / _index.json / _archived_facts.json
+//    以 _ 开头的命名空间不属于任何对话，自动清理时不视为孤立文件
+// ═══════════════════════════════════════════════════════════════════
+
+const BINDINGS_FILE: &str = "_namespaces.json";
+const CHARACTER_SCOPE_PREFIX: &str = "_character_";
+const WORLD_SCOPE_PREFIX: &str = "_world_";
+/// 角色 / 世界 ID 的最大长度（用作文件名的一部分）
+const MAX_LAYER_ID_LEN: usize = 64;
+/// 晋升到世界层的最低置信度：自动提取的默认置信度（0.8）即可，手动调低的不晋升
+const WORLD_PROMOTION_CONFIDENCE: f64 = 0.8;
+/// 事实中指代对话角色本人的主体（事实提取 prompt 把 AI 一方标为「AI角色」）
+const CHARACTER_ALIASES: [&str; 2] = ["ai角色", "角色"];
+/// 事实中指代用户本人的主体
+const USER_SUBJECT: &str = "用户";
+
+/// 以 _ 开头的命名空间属于角色层 / 世界层等共享数据，不属于任何对话
+pub fn is_shared_scope(scope: &str) -> bool {
+    scope.starts_with('_')
+}
+
+pub fn character_scope(character_id: &str) -> String {
+    format!("{}{}", CHARACTER_SCOPE_PREFIX, character_id)
+}
+
+pub fn world_scope(world_id: &str) -> String {
+    format!("{}{}", WORLD_SCOPE_PREFIX, world_id)
+}
+
+/// 某个对话（或群聊角色视角）可见的知识层
+struct Resolved {
+    /// 从具体到宽泛
+    scopes: Vec<String>,
+    /// 群聊角色视角所属对话的共享层（仅群聊角色视角有）
+    scene_scope: Option<String>,
+    character_scope: Option<String>,
+    world_scope: Option<String>,
+    /// 视为角色本人的主体（归一化）
+    character_subjects: Vec<String>,
+    /// 出场角色（归一化）：关于他们的事实不晋升到世界层
+    cast: Vec<String>,
+}
+
+pub struct KnowledgeLayers {
+    base_path: String,
+    store: KnowledgeStore,
+    groups: GroupChatStore,
+}
+
+impl KnowledgeLayers {
+    pub fn new(base_path: &str) -> Self {
+        Self {
+            base_path: base_path.to_string(),
+            store: KnowledgeStore::new(base_path),
+            groups: GroupChatStore::new(base_path),
+        }
+    }
+
+    // ── 绑定 ──
+
+    fn bindings_path(&self) -> PathBuf {
+        PathBuf::from(&self.base_path)
+            .join("knowledge_base")
+            .join(BINDINGS_FILE)
+    }
+
+    fn load_bindings(&self) -> HashMap<String, KnowledgeBinding> {
+        let path = self.bindings_path();
+        if !path.exists() {
+            return HashMap::new();
+        }
+        at_rest::load(&path, "knowledge bindings", |data| {
+            serde_json::from_slice(data).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to parse knowledge bindings: {}", e),
+            })
+        })
+        .unwrap_or_default()
+    }
+
+    fn save_bindings(&self, bindings: &HashMap<String, KnowledgeBinding>) -> Result<(), ChatError> {
+        let path = self.bindings_path();
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to create knowledge directory: {}", e),
+            })?;
+        }
+        let json = serde_json::to_string_pretty(bindings).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to serialize knowledge bindings: {}", e),
+        })?;
+        at_rest::write(&path, json).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to write knowledge bindings: {}", e),
+        })
+    }
+
+    /// 对话的绑定；未绑定时各项为空
+    pub fn binding(&self, conversation_id: &str) -> KnowledgeBinding {
+        self.load_bindings()
+            .remove(conversation_id)
+            .unwrap_or_default()
+    }
+
+    /// 设置对话的绑定（各项均为空时解除绑定）；ID 只能由字母、数字、- 和 _ 组成
+    pub fn set_binding(
+        &self,
+        conversation_id: &str,
+        binding: KnowledgeBinding,
+    ) -> Result<(), ChatError> {
+        if is_shared_scope(conversation_id) {
+            return Err(ChatError::ValidationError {
+                message: format!("'{}' is not a conversation", conversation_id),
+            });
+        }
+        let trimmed = |value: Option<String>| {
+            value
+                .map(|v| v.trim().to_string())
+                .filter(|v| !v.is_empty())
+        };
+        let binding = KnowledgeBinding {
+            character_id: trimmed(binding.character_id),
+            character_name: trimmed(binding.character_name),
+            world_id: trimmed(binding.world_id),
+        };
+        for id in [&binding.character_id, &binding.world_id]
+            .into_iter()
+            .flatten()
+        {
+            Self::validate_layer_id(id)?;
+        }
+
+        let mut bindings = self.load_bindings();
+        if binding == KnowledgeBinding::default() {
+            if bindings.remove(conversation_id).is_none() {
+                return Ok(());
+            }
+        } else {
+            bindings.insert(conversation_id.to_string(), binding);
+        }
+        self.save_bindings(&bindings)
+    }
+
+    /// 删除对话时解除绑定；角色层与世界层的事实保留
+    pub fn remove_binding(&self, conversation_id: &str) -> Result<(), ChatError> {
+        let mut bindings = self.load_bindings();
+        if bindings.remove(conversation_id).is_some() {
+            self.save_bindings(&bindings)?;
+        }
+        Ok(())
+    }
+
+    fn validate_layer_id(id: &str) -> Result<(), ChatError> {
+        if id.len() > MAX_LAYER_ID_LEN
+            || !id
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(ChatError::ValidationError {
+                message: format!("Invalid knowledge layer id '{}'", id),
+            });
+        }
+        Ok(())
+    }
+
+    /// 指定层在该对话下的命名空间；对话未绑定该层时为 None
+    pub fn layer_scope(&self, conversation_id: &str, layer: KnowledgeLayer) -> Option<String> {
+        let resolved = self.resolve(conversation_id);
+        match layer {
+            KnowledgeLayer::Conversation => Some(conversation_id.to_string()),
+            KnowledgeLayer::Character => resolved.character_scope,
+            KnowledgeLayer::World => resolved.world_scope,
+        }
+    }
+
+    fn resolve(&self, scope: &str) -> Resolved {
+        let mut resolved = Resolved {
+            scopes: vec![scope.to_string()],
+            scene_scope: None,
+            character_scope: None,
+            world_scope: None,
+            character_subjects: Vec::new(),
+            cast: Vec::new(),
+        };
+        if is_shared_scope(scope) {
+            return resolved;
+        }
+
+        let (conversation_id, group_character) = match scope.split_once('@') {
+            Some((conversation_id, character_id)) => (conversation_id, Some(character_id)),
+            None => (scope, None),
+        };
+        let binding = self.binding(conversation_id);
+        let group = self.groups.load(conversation_id);
+        let (character_id, character_name) = match (&group, group_character) {
+            (Some(group), Some(character_id)) => (
+                Some(character_id.to_string()),
+                group
+                    .characters
+                    .iter()
+                    .find(|c| c.id == character_id)
+                    .map(|c| c.name.clone()),
+            ),
+            // 群聊本身的共享层不属于任何一个角色
+            (Some(_), None) => (None, None),
+            (None, _) => (binding.character_id, binding.character_name),
+        };
+        if let Some(group) = &group {
+            resolved.cast = group
+                .characters
+                .iter()
+                .map(|c| KnowledgeStore::normalize_term(&c.name))
+                .collect();
+        }
+
+        if group_character.is_some() {
+            let scene = conversation_id.to_string();
+            resolved.scopes.push(scene.clone());
+            resolved.scene_scope = Some(scene);
+        }
+        if let Some(id) = character_id.filter(|id| Self::validate_layer_id(id).is_ok()) {
+            let scope = character_scope(&id);
+            resolved.scopes.push(scope.clone());
+            resolved.character_scope = Some(scope);
+            resolved.character_subjects = CHARACTER_ALIASES.iter().map(|a| a.to_string()).collect();
+            if let Some(name) = character_name {
+                let name = KnowledgeStore::normalize_term(&name);
+                resolved.cast.push(name.clone());
+                resolved.character_subjects.push(name);
+            }
+        }
+        if let Some(id) = binding.world_id {
+            let scope = world_scope(&id);
+            resolved.scopes.push(scope.clone());
+            resolved.world_scope = Some(scope);
+        }
+        resolved
+    }
+
+    // ── 晋升 ──
+
+    /// 按晋升规则把刚写入 scope 的事实复制到上层，返回晋升条数
+    pub fn promote(&self, scope: &str, facts: &[Fact]) -> Result<usize, ChatError> {
+        let resolved = self.resolve(scope);
+        let mut targets: HashMap<String, Vec<Fact>> = HashMap::new();
+        for fact in facts {
+            if let Some(target) = Self::promotion_target(&resolved, fact) {
+                targets.entry(target).or_default().push(fact.clone());
+            }
+        }
+        let mut promoted = 0;
+        for (target, facts) in targets {
+            promoted += facts.len();
+            self.store.add_facts(&target, facts)?;
+        }
+        Ok(promoted)
+    }
+
+    fn promotion_target(resolved: &Resolved, fact: &Fact) -> Option<String> {
+        let [subject, _, _] = KnowledgeStore::triple_parts(&fact.content)?;
+        let subject = KnowledgeStore::normalize_term(&subject);
+        let about_character = resolved.character_subjects.contains(&subject);
+
+        if about_character
+            && matches!(
+                fact.category,
+                FactCategory::Identity
+                    | FactCategory::Preference
+                    | FactCategory::Relationship
+                    | FactCategory::Promise
+            )
+        {
+            return resolved.character_scope.clone();
+        }
+        if fact.category == FactCategory::Event {
+            return resolved.scene_scope.clone();
+        }
+        if fact.category == FactCategory::Identity
+            && fact.confidence >= WORLD_PROMOTION_CONFIDENCE
+            && subject != USER_SUBJECT
+            && !about_character
+            && !CHARACTER_ALIASES.contains(&subject.as_str())
+            && !resolved.cast.contains(&subject)
+        {
+            return resolved.world_scope.clone();
+        }
+        None
+    }
+
+    /// 手动把 scope 中的一条事实晋升到指定层；事实不存在时返回 false，
+    /// 对话未绑定目标层时报错
+    pub fn promote_fact(
+        &self,
+        scope: &str,
+        fact_id: &str,
+        layer: KnowledgeLayer,
+    ) -> Result<bool, ChatError> {
+        let Some(fact) = self
+            .store
+            .load_facts(scope)?
+            .into_iter()
+            .find(|f| f.id == fact_id)
+        else {
+            return Ok(false);
+        };
+        let target = match layer {
+            KnowledgeLayer::Conversation => self.resolve(scope).scene_scope,
+            KnowledgeLayer::Character => self.resolve(scope).character_scope,
+            KnowledgeLayer::World => self.resolve(scope).world_scope,
+        }
+        .ok_or_else(|| ChatError::ValidationError {
+            message: format!("'{}' has no {:?} layer to promote into", scope, layer),
+        })?;
+        self.store.add_facts(&target, vec![fact])?;
+        Ok(true)
+    }
+
+    // ── 分层检索 ──
+
+    /// 上层事实是否被已选中的下层事实遮蔽（同一条、重复或矛盾）
+    fn shadowed(kept: &[Fact], fact: &Fact) -> bool {
+        kept.iter().any(|k| {
+            k.id == fact.id || KnowledgeStore::facts_are_similar(&k.content, &fact.content)
+        }) || KnowledgeStore::find_contradiction(kept, fact).is_some()
+    }
+
+    /// scope 可见的全部事实（从具体到宽泛合并，上层被遮蔽的条目跳过）
+    pub fn all_facts(&self, scope: &str) -> Vec<Fact> {
+        let mut kept: Vec<Fact> = Vec::new();
+        for layer_scope in self.resolve(scope).scopes {
+            for fact in self.store.get_all_facts(&layer_scope) {
+                if !Self::shadowed(&kept, &fact) {
+                    kept.push(fact);
+                }
+            }
+        }
+        kept
+    }
+
+    /// 在 scope 可见的各层中检索，合并后按相关性取前 top_k
+    pub fn search(
+        &self,
+        scope: &str,
+        query: &str,
+        top_k: usize,
+        semantic: Option<&SemanticQuery>,
+    ) -> Vec<FactSearchResult> {
+        let resolved = self.resolve(scope);
+        if resolved.scopes.len() == 1 {
+            return self.store.search_facts(scope, query, top_k, semantic);
+        }
+        // 遮蔽判定要看到下层的全部事实，而不只是命中的那些
+        let mut visible: Vec<Fact> = Vec::new();
+        let mut results: Vec<FactSearchResult> = Vec::new();
+        for layer_scope in &resolved.scopes {
+            let lower = visible.clone();
+            for result in self.store.search_facts(layer_scope, query, top_k, semantic) {
+                if !Self::shadowed(&lower, &result.fact) {
+                    results.push(result);
+                }
+            }
+            for fact in self.store.get_all_facts(layer_scope) {
+                if !Self::shadowed(&lower, &fact) {
+                    visible.push(fact);
+                }
+            }
+        }
+        results.sort_by(|a, b| {
+            b.relevance_score
+                .partial_cmp(&a.relevance_score)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        results.truncate(top_k);
+        results
+    }
+
+    /// 记录检索命中（各层分别更新热度）
+    pub fn record_hits(&self, scope: &str, fact_ids: &[String]) -> Result<(), ChatError> {
+        for layer_scope in self.resolve(scope).scopes {
+            let facts = self.store.get_all_facts(&layer_scope);
+            let hits: Vec<String> = fact_ids
+                .iter()
+                .filter(|id| facts.iter().any(|f| &f.id == *id))
+                .cloned()
+                .collect();
+            if !hits.is_empty() {
+                self.store.record_hits(&layer_scope, &hits)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::data_models::{GroupCharacter, TurnPolicy};
+
+    fn extracted(json: &str, turn: u32) -> Vec<Fact> {
+        KnowledgeStore::parse_extracted_facts(json, turn)
+    }
+
+    #[test]
+    fn test_promotion_and_layered_retrieval() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let base = tmp.path().to_str().unwrap();
+        let layers = KnowledgeLayers::new(base);
+        let store = KnowledgeStore::new(base);
+        let binding = KnowledgeBinding {
+            character_id: Some("erin".to_string()),
+            character_name: Some("艾琳".to_string()),
+            world_id: Some("silvermoon".to_string()),
+        };
+        layers.set_binding("main", binding.clone()).unwrap();
+        layers.set_binding("spinoff", binding).unwrap();
+        assert!(layers
+            .set_binding(
+                "bad",
+                KnowledgeBinding {
+                    world_id: Some("../x".to_string()),
+                    ..Default::default()
+                }
+            )
+            .is_err());
+
+        store
+            .add_facts(
+                "main",
+                extracted(
+                    r#"[{"content": "艾琳→喜欢→剑术", "category": "preference", "entities": ["艾琳"]},
+                        {"content": "王都→位于→北方", "category": "identity", "entities": ["王都"]},
+                        {"content": "用户→是→学者", "category": "identity", "entities": ["用户"]},
+                        {"content": "用户→去了→王都", "category": "event", "entities": ["用户", "王都"]}]"#,
+                    3,
+                ),
+            )
+            .unwrap();
+        let character: Vec<String> = store
+            .get_all_facts(&character_scope("erin"))
+            .into_iter()
+            .map(|f| f.content)
+            .collect();
+        assert_eq!(character, vec!["艾琳→喜欢→剑术".to_string()]);
+        let world: Vec<String> = store
+            .get_all_facts(&world_scope("silvermoon"))
+            .into_iter()
+            .map(|f| f.content)
+            .collect();
+        assert_eq!(world, vec!["王都→位于→北方".to_string()]);
+
+        // 番外对话一开始就知道角色与世界
+        let visible = layers.all_facts("spinoff");
+        assert_eq!(visible.len(), 2);
+        let results = layers.search("spinoff", "艾琳喜欢剑术吗", 5, None);
+        assert_eq!(results[0].fact.content, "艾琳→喜欢→剑术");
+
+        // 番外改写设定：世界层随之更新，正篇对话层的版本仍遮蔽世界层
+        store
+            .add_facts(
+                "spinoff",
+                extracted(
+                    r#"[{"content": "王都→位于→南方", "category": "identity", "entities": ["王都"]}]"#,
+                    1,
+                ),
+            )
+            .unwrap();
+        let main: Vec<String> = layers
+            .all_facts("main")
+            .into_iter()
+            .map(|f| f.content)
+            .collect();
+        assert!(main.contains(&"王都→位于→北方".to_string()));
+        assert!(!main.contains(&"王都→位于→南方".to_string()));
+
+        layers.remove_binding("spinoff").unwrap();
+        assert!(layers
+            .all_facts("spinoff")
+            .iter()
+            .all(|f| !f.content.contains("剑术")));
+    }
+
+    #[test]
+    fn test_group_events_are_shared_with_the_scene() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let base = tmp.path().to_str().unwrap();
+        let group = GroupChatStore::new(base)
+            .save(
+                "g",
+                vec![
+                    GroupCharacter {
+                        id: "kai".to_string(),
+                        name: "凯".to_string(),
+                        system_prompt: String::new(),
+                    },
+                    GroupCharacter {
+                        id: "erin".to_string(),
+                        name: "艾琳".to_string(),
+                        system_prompt: String::new(),
+                    },
+                ],
+                TurnPolicy::RoundRobin,
+            )
+            .unwrap();
+        let store = KnowledgeStore::new(base);
+        let kai = format!("g@{}", group.characters[0].id);
+        let erin = format!("g@{}", group.characters[1].id);
+        store
+            .add_facts(
+                &kai,
+                extracted(
+                    r#"[{"content": "凯→害怕→深水", "category": "preference", "entities": ["凯"]},
+                        {"content": "酒馆→发生了→火灾", "category": "event", "entities": ["酒馆"]}]"#,
+                    2,
+                ),
+            )
+            .unwrap();
+
+        let layers = KnowledgeLayers::new(base);
+        let erin_view: Vec<String> = layers
+            .all_facts(&erin)
+            .into_iter()
+            .map(|f| f.content)
+            .collect();
+        assert_eq!(erin_view, vec!["酒馆→发生了→火灾".to_string()]);
+        // 凯的私人偏好进入他的角色层，跟随他去别的对话
+        assert_eq!(store.get_all_facts(&character_scope("kai")).len(), 1);
+    }
+}
@@ -1,18 +1,34 @@
-use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::fs;
-use std::path::PathBuf;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 use flutter_rust_bridge::frb;
 use serde::{Deserialize, Serialize};
 
+use super::at_rest;
+use super::attachments::AttachmentStore;
+use super::blocking_pool;
+use super::character_relations::CharacterWeb;
 use super::data_models::*;
+use super::embedding::SemanticQuery;
 use super::error_handler::ChatError;
-use super::memory_engine::MemoryEngine;
+use super::fidelity_audit::FidelityAuditor;
+use super::knowledge_graph::KnowledgeGraph;
+use super::knowledge_layers::{self, KnowledgeLayers};
+use super::memory_engine::{self, MemoryEngine};
+use super::schema_migration::{self, SchemaKind};
+use super::store_cache::{self, FileStamp};
 
 const FACT_SIMILARITY_THRESHOLD: f64 = 0.62;
 const CONTEXT_DEDUP_SIMILARITY_THRESHOLD: f64 = 0.88;
 const NON_CRITICAL_UPDATE_FLOOR: f64 = 0.55;
 const MAX_RELATED_FACTS_IN_CONTEXT: usize = 12;
+/// 衰减后置信度低于此值的事实视为已遗忘，不再参与检索（仍保留在事实库中）
+const FORGOTTEN_CONFIDENCE: f64 = 0.2;
+const DAY_MS: f64 = 86_400_000.0;
 
 // ═══════════════════════════════════════════════════════════════════
 //  本地知识库 (Knowledge Store) — 专家系统式事实存储与检索
@@ -26,8 +42,21 @@ const MAX_RELATED_FACTS_IN_CONTEXT: usize = 12;
 //  存储结构：
 //    knowledge_base/
 //      {conversation_id}_facts.json     — 事实库
-//      {conversation_id}_index.json     — 倒排索引
-//      global_facts.json                — 全局共享事实
+//      {conversation_id}_index.json     — 倒排索引（含检索词项与文档统计，增量维护）
+//      {conversation_id}_archived_facts.json — 被新事实取代的旧事实
+//      global_facts.json                — 全局共享事实（用户档案）
+//      _character_{id}_* / _world_{id}_* — 角色层 / 世界层（见 knowledge_layers）
+//    事实文件带 schema_version 信封，旧格式读取时迁移（见 schema_migration）
+//
+//  遗忘曲线：状态、偏好等易变事实的置信度按 R = e^(-t/S) 随距上次确认的天数衰减，
+//  S 为分类基础稳定期，被检索命中越多越稳定；命中或再次提取都会刷新确认时间。
+//  身份、承诺、事件与用户手动维护的事实不衰减。
+//
+//  用户档案：关于用户本人的身份/偏好事实（主体为「用户」）在写入对话知识库时
+//  同步晋升到 global_facts.json，所有对话检索上下文时一并注入，
+//  新对话无需重新认识用户；对话内的事实与档案矛盾时以对话内为准。
+//  档案按人设分区（Fact::persona_id）：只注入与当前对话人设相同的条目，
+//  未选人设的对话共用一个分区。
 // ═══════════════════════════════════════════════════════════════════
 
 /// 事实分类 — 决定事实的存储优先级和检索权重
@@ -71,10 +100,30 @@ pub struct Fact {
     pub hit_count: u32,
     /// 上下文卡片：结构化元信息（参考智谱增强型上下文）
     pub context_snippet: String,
+    /// 描述同一内容的记忆摘要 ID（与 MemorySummary::linked_fact_ids 互为反向链接）
+    #[serde(default)]
+    pub summary_ids: Vec<String>,
+    /// 被哪条新事实取代（仅归档中的事实有值，见 find_contradiction）
+    #[serde(default)]
+    pub superseded_by: Option<String>,
+    /// 被取代的时间
+    #[serde(default)]
+    pub superseded_at: Option<i64>,
+    /// 用户手动添加或修改过：后续自动提取不再改写其内容、分类与置信度，
+    /// 与之矛盾的新提取事实直接丢弃
+    #[serde(default)]
+    pub manual_override: bool,
+    /// 记录时对方使用的人设（见 PersonaStore）；用户档案按人设分区
+    #[serde(default)]
+    pub persona_id: Option<String>,
 }
 
+/// 索引格式/算法版本：关键词提取或索引结构变化时递增，旧索引视为过期
+/// v2：加入检索词项倒排表与文档统计，search_facts 直接使用
+pub const KNOWLEDGE_INDEX_VERSION: u32 = 2;
+
 /// 知识库索引
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct KnowledgeIndex {
     /// 关键词 → 事实ID列表（倒排索引）
     pub keyword_index: HashMap<String, Vec<String>>,
@@ -82,6 +131,31 @@ pub struct KnowledgeIndex {
     pub entity_index: HashMap<String, Vec<String>>,
     /// 分类 → 事实ID列表
     pub category_index: HashMap<String, Vec<String>>,
+    /// 构建该索引时的算法版本（旧文件缺省为 0）
+    #[serde(default)]
+    pub version: u32,
+    /// 检索词项 → 事实ID列表（BM25 的文档频率即列表长度）
+    #[serde(default)]
+    pub term_index: HashMap<String, Vec<String>>,
+    /// 事实ID → 已提取的检索词项
+    #[serde(default)]
+    pub docs: HashMap<String, IndexedDoc>,
+    /// 全部文档的词项总数（平均文档长度 = total_terms / docs.len()）
+    #[serde(default)]
+    pub total_terms: usize,
+    /// 提取词项时的分词方式与停用词指纹，变化后整体重新提取
+    #[serde(default)]
+    pub tokenizer: u64,
+}
+
+/// 索引中的一条事实
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IndexedDoc {
+    /// 关键词、正文与上下文片段的哈希：不变时沿用已提取的词项
+    /// （DefaultHasher 跨工具链版本不保证稳定，变了只是多提取一次）
+    pub hash: u64,
+    /// 去重排序后的检索词项
+    pub terms: Vec<String>,
 }
 
 /// 检索结果
@@ -92,6 +166,7 @@ pub struct FactSearchResult {
 }
 
 #[frb(opaque)]
+#[derive(Clone)]
 pub struct KnowledgeStore {
     base_path: String,
 }
@@ -119,6 +194,16 @@ impl KnowledgeStore {
             .join(format!("{}_facts.json", conversation_id)))
     }
 
+    fn archived_facts_path(&self, conversation_id: &str) -> Result<PathBuf, ChatError> {
+        Ok(self
+            .knowledge_dir()?
+            .join(format!("{}_archived_facts.json", conversation_id)))
+    }
+
+    fn global_facts_path(&self) -> Result<PathBuf, ChatError> {
+        Ok(self.knowledge_dir()?.join("global_facts.json"))
+    }
+
     fn index_path(&self, conversation_id: &str) -> Result<PathBuf, ChatError> {
         Ok(self
             .knowledge_dir()?
@@ -127,42 +212,173 @@ impl KnowledgeStore {
 
     // ── 事实存储 ──
 
+    /// 读取事实文件（任意历史版本，优先走内存缓存）；旧版本迁移后立即按当前版本回写
+    fn read_fact_file(path: &Path, what: &str) -> Result<Vec<Fact>, ChatError> {
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+        if let Some(facts) = store_cache::facts().get(path) {
+            return Ok(facts);
+        }
+        let stamp = FileStamp::of(path);
+        let (facts, upgraded) = at_rest::load(path, what, |data| {
+            schema_migration::decode(SchemaKind::Facts, data)
+        })?;
+        if upgraded {
+            Self::write_fact_file(path, &facts, what)?;
+        } else {
+            store_cache::facts().put(path, stamp, facts.clone());
+        }
+        Ok(facts)
+    }
+
+    fn write_fact_file(path: &Path, facts: &[Fact], what: &str) -> Result<(), ChatError> {
+        let json = schema_migration::encode(SchemaKind::Facts, facts)?;
+        let written = at_rest::write(path, json).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to write {}: {}", what, e),
+        });
+        // 写失败时快照为 None，等同于让缓存失效
+        let stamp = written.as_ref().ok().and_then(|_| FileStamp::of(path));
+        store_cache::facts().put(path, stamp, facts.to_vec());
+        written
+    }
+
     pub fn save_facts(
         &self,
         conversation_id: &str,
         facts: &[Fact],
     ) -> Result<(), ChatError> {
-        let path = self.facts_path(conversation_id)?;
-        let json = serde_json::to_string_pretty(facts).map_err(|e| ChatError::StorageError {
-            message: format!("Failed to serialize facts: {}", e),
-        })?;
-        fs::write(&path, json).map_err(|e| ChatError::StorageError {
-            message: format!("Failed to write facts: {}", e),
-        })
+        Self::write_fact_file(&self.facts_path(conversation_id)?, facts, "facts")
     }
 
     pub fn load_facts(&self, conversation_id: &str) -> Result<Vec<Fact>, ChatError> {
-        let path = self.facts_path(conversation_id)?;
-        if !path.exists() {
-            return Ok(Vec::new());
-        }
-        let json = fs::read_to_string(&path).map_err(|e| ChatError::StorageError {
-            message: format!("Failed to read facts: {}", e),
-        })?;
-        serde_json::from_str(&json).map_err(|e| ChatError::StorageError {
-            message: format!("Failed to parse facts: {}", e),
-        })
+        Self::read_fact_file(&self.facts_path(conversation_id)?, "facts")
+    }
+
+    /// 用户档案（跨对话共享的用户身份/偏好事实）
+    pub fn load_global_facts(&self) -> Result<Vec<Fact>, ChatError> {
+        Self::read_fact_file(&self.global_facts_path()?, "global facts")
     }
 
-    /// 添加新事实（自动去重和更新）
+    pub fn save_global_facts(&self, facts: &[Fact]) -> Result<(), ChatError> {
+        Self::write_fact_file(&self.global_facts_path()?, facts, "global facts")
+    }
+
+    pub fn get_global_facts(&self) -> Vec<Fact> {
+        self.load_global_facts().unwrap_or_default()
+    }
+
+    /// 是否为关于用户本人的身份/偏好事实（可晋升到用户档案）
+    fn is_user_profile_fact(fact: &Fact) -> bool {
+        matches!(fact.category, FactCategory::Identity | FactCategory::Preference)
+            && Self::fact_triple(&fact.content)
+                .is_some_and(|(subject, _, _)| subject == "用户")
+    }
+
+    /// 添加新事实（自动去重和更新）；关于用户本人的身份/偏好事实同步晋升到用户档案，
+    /// 对话中的事实再按规则晋升到角色层 / 世界层（见 knowledge_layers）
     pub fn add_facts(
         &self,
         conversation_id: &str,
         new_facts: Vec<Fact>,
     ) -> Result<(), ChatError> {
+        let profile: Vec<Fact> = new_facts
+            .iter()
+            .filter(|f| Self::is_user_profile_fact(f))
+            .cloned()
+            .collect();
+
+        let promotable = if knowledge_layers::is_shared_scope(conversation_id) {
+            Vec::new()
+        } else {
+            new_facts.clone()
+        };
+
         let mut existing = self.load_facts(conversation_id)?;
+        let superseded = Self::merge_facts(conversation_id, &mut existing, new_facts);
+        if !superseded.is_empty() {
+            self.archive_facts(conversation_id, superseded)?;
+        }
+        self.save_facts(conversation_id, &existing)?;
+        self.rebuild_index(conversation_id, &existing)?;
+
+        if !profile.is_empty() {
+            let mut global = self.load_global_facts()?;
+            let mut personas: Vec<Option<String>> =
+                profile.iter().map(|f| f.persona_id.clone()).collect();
+            personas.sort();
+            personas.dedup();
+            for persona in personas {
+                let (mut partition, rest): (Vec<Fact>, Vec<Fact>) =
+                    global.into_iter().partition(|f| f.persona_id == persona);
+                let incoming: Vec<Fact> = profile
+                    .iter()
+                    .filter(|f| f.persona_id == persona)
+                    .cloned()
+                    .collect();
+                // 档案中被取代的旧事实直接丢弃：冲突记录已保存在来源对话的归档里
+                Self::merge_facts("global", &mut partition, incoming);
+                global = rest;
+                global.extend(partition);
+            }
+            self.save_global_facts(&global)?;
+        }
+        if !promotable.is_empty() {
+            self.layers().promote(conversation_id, &promotable)?;
+        }
+        Ok(())
+    }
+
+    /// 同一数据目录下的知识分层（角色层 / 世界层的绑定、晋升与分层检索）
+    pub(crate) fn layers(&self) -> KnowledgeLayers {
+        KnowledgeLayers::new(&self.base_path)
+    }
+
+    /// 删除某个人设分区下的全部用户档案事实，返回删除条数
+    pub fn remove_persona_profile(&self, persona_id: &str) -> Result<usize, ChatError> {
+        let mut global = self.load_global_facts()?;
+        let before = global.len();
+        global.retain(|f| f.persona_id.as_deref() != Some(persona_id));
+        let removed = before - global.len();
+        if removed > 0 {
+            self.save_global_facts(&global)?;
+        }
+        Ok(removed)
+    }
+
+    /// 把新事实并入 existing（冲突取代 / 相似合并 / 追加），返回被取代的旧事实
+    fn merge_facts(scope: &str, existing: &mut Vec<Fact>, new_facts: Vec<Fact>) -> Vec<Fact> {
+        let mut superseded = Vec::new();
 
         for new_fact in new_facts {
+            if existing
+                .iter()
+                .any(|f| f.manual_override && Self::contradicts(f, &new_fact))
+            {
+                tracing::debug!(scope, new = %new_fact.content, "与手动事实矛盾，忽略提取结果");
+                continue;
+            }
+
+            // 同一主体、同一关系出现不同客体：新事实生效，旧事实归档
+            let mut contradicted = false;
+            while let Some(idx) = Self::find_contradiction(existing, &new_fact) {
+                let mut old = existing.remove(idx);
+                tracing::info!(
+                    scope,
+                    old = %old.content,
+                    new = %new_fact.content,
+                    "事实冲突，旧事实已归档"
+                );
+                old.superseded_by = Some(new_fact.id.clone());
+                old.superseded_at = Some(new_fact.created_at);
+                superseded.push(old);
+                contradicted = true;
+            }
+            if contradicted {
+                existing.push(new_fact);
+                continue;
+            }
+
             // 检查是否已存在相似事实
             let existing_idx = existing.iter().position(|f| {
                 Self::facts_are_similar(&f.content, &new_fact.content)
@@ -172,6 +388,10 @@ impl KnowledgeStore {
             });
 
             if let Some(idx) = existing_idx {
+                if existing[idx].manual_override {
+                    existing[idx].last_confirmed_at = new_fact.last_confirmed_at;
+                    continue;
+                }
                 let similarity = Self::semantic_similarity_score(
                     &existing[idx].content,
                     &new_fact.content,
@@ -196,13 +416,134 @@ impl KnowledgeStore {
             }
         }
 
-        self.save_facts(conversation_id, &existing)?;
-        self.rebuild_index(conversation_id, &existing)?;
-        Ok(())
+        superseded
+    }
+
+    /// 需要随对话注入的用户档案事实：只取对话人设的分区，
+    /// 跳过与对话内事实重复或矛盾的条目（以对话内为准）
+    pub fn profile_facts_for(
+        &self,
+        conversation_facts: &[Fact],
+        persona_id: Option<&str>,
+    ) -> Vec<Fact> {
+        let now = chrono::Utc::now().timestamp_millis();
+        self.get_global_facts()
+            .into_iter()
+            .filter(|g| {
+                g.persona_id.as_deref() == persona_id
+                    && Self::decayed_confidence(g, now) >= FORGOTTEN_CONFIDENCE
+                    && Self::find_contradiction(conversation_facts, g).is_none()
+                    && !conversation_facts
+                        .iter()
+                        .any(|f| Self::facts_are_similar(&f.content, &g.content))
+            })
+            .collect()
+    }
+
+    /// 事实内容按「主体→关系→客体」拆成三段（仅去掉首尾空白）；不是三段式时为 None
+    pub(crate) fn triple_parts(content: &str) -> Option<[String; 3]> {
+        let parts: Vec<String> = content
+            .replace("->", "→")
+            .split('→')
+            .map(|p| p.trim().to_string())
+            .collect();
+        match parts.as_slice() {
+            [s, r, o] if !s.is_empty() && !r.is_empty() && !o.is_empty() => {
+                Some([s.clone(), r.clone(), o.clone()])
+            }
+            _ => None,
+        }
+    }
+
+    /// 三元组的归一化比较键：去掉全部空白并转小写
+    pub(crate) fn normalize_term(term: &str) -> String {
+        term.chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>()
+            .to_lowercase()
+    }
+
+    /// 事实内容的三元组（主体→关系→客体），各部分已归一化；不是三段式时为 None
+    fn fact_triple(content: &str) -> Option<(String, String, String)> {
+        let [s, r, o] = Self::triple_parts(content)?;
+        Some((
+            Self::normalize_term(&s),
+            Self::normalize_term(&r),
+            Self::normalize_term(&o),
+        ))
+    }
+
+    /// 同一主体 + 关系只能有一个客体的分类。偏好、事件、承诺、共识天然可以并存
+    /// （「喜欢→猫」与「喜欢→狗」不矛盾），不做冲突判定
+    fn is_single_valued(category: &FactCategory) -> bool {
+        matches!(
+            category,
+            FactCategory::Identity | FactCategory::Relationship | FactCategory::CurrentState
+        )
+    }
+
+    /// 两条事实是否矛盾：同一主体、同一关系、不同客体
+    fn contradicts(a: &Fact, b: &Fact) -> bool {
+        if !Self::is_single_valued(&a.category) || !Self::is_single_valued(&b.category) {
+            return false;
+        }
+        match (Self::fact_triple(&a.content), Self::fact_triple(&b.content)) {
+            (Some((sa, ra, oa)), Some((sb, rb, ob))) => sa == sb && ra == rb && oa != ob,
+            _ => false,
+        }
+    }
+
+    /// 与新事实矛盾的已有事实
+    pub(crate) fn find_contradiction(existing: &[Fact], new_fact: &Fact) -> Option<usize> {
+        existing.iter().position(|f| Self::contradicts(f, new_fact))
+    }
+
+    /// 被取代的旧事实（按取代时间先后）
+    pub fn load_archived_facts(&self, conversation_id: &str) -> Result<Vec<Fact>, ChatError> {
+        Self::read_fact_file(&self.archived_facts_path(conversation_id)?, "archived facts")
+    }
+
+    fn archive_facts(&self, conversation_id: &str, facts: Vec<Fact>) -> Result<(), ChatError> {
+        let mut archived = self.load_archived_facts(conversation_id)?;
+        archived.extend(facts);
+        Self::write_fact_file(
+            &self.archived_facts_path(conversation_id)?,
+            &archived,
+            "archived facts",
+        )
+    }
+
+    /// 检测到的事实冲突（新的在前）：旧事实及取代它的事实
+    pub fn list_conflicts(&self, conversation_id: &str) -> Vec<FactConflict> {
+        let archived = self.load_archived_facts(conversation_id).unwrap_or_default();
+        let active = self.get_all_facts(conversation_id);
+        let content_of = |id: &str| {
+            active
+                .iter()
+                .chain(archived.iter())
+                .find(|f| f.id == id)
+                .map(|f| f.content.clone())
+                .unwrap_or_default()
+        };
+        let mut conflicts: Vec<FactConflict> = archived
+            .iter()
+            .filter_map(|old| {
+                let active_fact_id = old.superseded_by.clone()?;
+                Some(FactConflict {
+                    superseded_fact_id: old.id.clone(),
+                    superseded_content: old.content.clone(),
+                    active_content: content_of(&active_fact_id),
+                    active_fact_id,
+                    detected_at: old.superseded_at.unwrap_or(0),
+                })
+            })
+            .collect();
+        conflicts.sort_by_key(|c| std::cmp::Reverse(c.detected_at));
+        conflicts
     }
 
     /// 判断两条事实是否语义相似
-    fn facts_are_similar(a: &str, b: &str) -> bool {
+    pub(crate) fn facts_are_similar(a: &str, b: &str) -> bool {
         Self::semantic_similarity_score(a, b) >= FACT_SIMILARITY_THRESHOLD
     }
 
@@ -304,11 +645,155 @@ impl KnowledgeStore {
 
     // ── 倒排索引 ──
 
-    fn rebuild_index(
+    /// 读取倒排索引文件（不存在时返回 None）
+    pub fn load_index(&self, conversation_id: &str) -> Result<Option<KnowledgeIndex>, ChatError> {
+        Ok(self
+            .load_index_shared(conversation_id)?
+            .map(|index| (*index).clone()))
+    }
+
+    /// 读取倒排索引（优先走内存缓存，检索时不复制整份索引）
+    fn load_index_shared(
+        &self,
+        conversation_id: &str,
+    ) -> Result<Option<Arc<KnowledgeIndex>>, ChatError> {
+        let path = self.index_path(conversation_id)?;
+        if !path.exists() {
+            return Ok(None);
+        }
+        if let Some(index) = store_cache::knowledge_indexes().get(&path) {
+            return Ok(Some(index));
+        }
+        let stamp = FileStamp::of(&path);
+        let index: KnowledgeIndex = at_rest::load(&path, "index", |data| {
+            serde_json::from_slice(data).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to parse index: {}", e),
+            })
+        })?;
+        let index = Arc::new(index);
+        store_cache::knowledge_indexes().put(&path, stamp, index.clone());
+        Ok(Some(index))
+    }
+
+    /// 让索引跟上事实集合并保存：只对新增或内容变化的事实重新提取检索词项
+    pub fn rebuild_index(
         &self,
         conversation_id: &str,
         facts: &[Fact],
     ) -> Result<(), ChatError> {
+        let index = self.synced_index(conversation_id, facts);
+        self.write_index(conversation_id, index).map(|_| ())
+    }
+
+    /// 检索用的索引：版本过旧或与事实数不符（绕过索引写入的旧数据）时就地同步
+    fn current_index(&self, conversation_id: &str, facts: &[Fact]) -> Arc<KnowledgeIndex> {
+        if let Ok(Some(index)) = self.load_index_shared(conversation_id) {
+            if Self::index_is_current(&index) && index.docs.len() == facts.len() {
+                return index;
+            }
+        }
+        let index = self.synced_index(conversation_id, facts);
+        match self.write_index(conversation_id, index.clone()) {
+            Ok(index) => index,
+            Err(e) => {
+                tracing::warn!(conversation_id, error = %e, "知识库索引写入失败");
+                Arc::new(index)
+            }
+        }
+    }
+
+    fn synced_index(&self, conversation_id: &str, facts: &[Fact]) -> KnowledgeIndex {
+        let mut index = match self.load_index(conversation_id) {
+            Ok(Some(index)) if Self::index_is_current(&index) => index,
+            _ => KnowledgeIndex {
+                tokenizer: memory_engine::tokenizer_fingerprint(),
+                ..KnowledgeIndex::default()
+            },
+        };
+        Self::sync_index(&mut index, facts);
+        index
+    }
+
+    /// 索引算法、分词方式与停用词都与当前一致时，已提取的词项可以沿用
+    fn index_is_current(index: &KnowledgeIndex) -> bool {
+        index.version == KNOWLEDGE_INDEX_VERSION
+            && index.tokenizer == memory_engine::tokenizer_fingerprint()
+    }
+
+    fn write_index(
+        &self,
+        conversation_id: &str,
+        index: KnowledgeIndex,
+    ) -> Result<Arc<KnowledgeIndex>, ChatError> {
+        let path = self.index_path(conversation_id)?;
+        let json =
+            serde_json::to_string_pretty(&index).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to serialize index: {}", e),
+            })?;
+        let written = at_rest::write(&path, json).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to write index: {}", e),
+        });
+        let index = Arc::new(index);
+        let stamp = written.as_ref().ok().and_then(|_| FileStamp::of(&path));
+        store_cache::knowledge_indexes().put(&path, stamp, index.clone());
+        written.map(|_| index)
+    }
+
+    /// BM25 检索用的文档词项：事实关键词 + 正文与上下文片段的关键词
+    fn doc_terms(fact: &Fact) -> Vec<String> {
+        let mut terms = fact.keywords.clone();
+        terms.extend(MemoryEngine::extract_keywords(&fact.content));
+        terms.extend(MemoryEngine::extract_keywords(&fact.context_snippet));
+        terms.sort();
+        terms.dedup();
+        terms
+    }
+
+    fn doc_hash(fact: &Fact) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        fact.keywords.hash(&mut hasher);
+        fact.content.hash(&mut hasher);
+        fact.context_snippet.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// 增量同步索引：删除消失的事实，只为新增或变化的事实提取词项；
+    /// 关键词 / 实体 / 分类表不涉及分词，按当前事实直接重建
+    fn sync_index(index: &mut KnowledgeIndex, facts: &[Fact]) {
+        let live: HashSet<&str> = facts.iter().map(|f| f.id.as_str()).collect();
+        let removed: Vec<String> = index
+            .docs
+            .keys()
+            .filter(|id| !live.contains(id.as_str()))
+            .cloned()
+            .collect();
+        for id in removed {
+            if let Some(doc) = index.docs.remove(&id) {
+                Self::unpost(&mut index.term_index, &id, &doc.terms);
+                index.total_terms -= doc.terms.len();
+            }
+        }
+        for fact in facts {
+            let hash = Self::doc_hash(fact);
+            if index.docs.get(&fact.id).is_some_and(|doc| doc.hash == hash) {
+                continue;
+            }
+            if let Some(old) = index.docs.remove(&fact.id) {
+                Self::unpost(&mut index.term_index, &fact.id, &old.terms);
+                index.total_terms -= old.terms.len();
+            }
+            let terms = Self::doc_terms(fact);
+            for term in &terms {
+                index
+                    .term_index
+                    .entry(term.clone())
+                    .or_default()
+                    .push(fact.id.clone());
+            }
+            index.total_terms += terms.len();
+            index.docs.insert(fact.id.clone(), IndexedDoc { hash, terms });
+        }
+
         let mut keyword_index: HashMap<String, Vec<String>> = HashMap::new();
         let mut entity_index: HashMap<String, Vec<String>> = HashMap::new();
         let mut category_index: HashMap<String, Vec<String>> = HashMap::new();
@@ -336,95 +821,124 @@ impl KnowledgeStore {
                 .push(fact.id.clone());
         }
 
-        let index = KnowledgeIndex {
-            keyword_index,
-            entity_index,
-            category_index,
-        };
+        index.keyword_index = keyword_index;
+        index.entity_index = entity_index;
+        index.category_index = category_index;
+        index.version = KNOWLEDGE_INDEX_VERSION;
+    }
 
-        let path = self.index_path(conversation_id)?;
-        let json =
-            serde_json::to_string_pretty(&index).map_err(|e| ChatError::StorageError {
-                message: format!("Failed to serialize index: {}", e),
-            })?;
-        fs::write(&path, json).map_err(|e| ChatError::StorageError {
-            message: format!("Failed to write index: {}", e),
-        })
+    fn unpost(term_index: &mut HashMap<String, Vec<String>>, id: &str, terms: &[String]) {
+        for term in terms {
+            if let Some(ids) = term_index.get_mut(term) {
+                ids.retain(|i| i != id);
+                if ids.is_empty() {
+                    term_index.remove(term);
+                }
+            }
+        }
+    }
+
+    /// 用当前算法重新提取所有事实的关键词并重建索引，返回处理的事实数
+    /// 实体来自模型抽取，无法本地重算，保持不变
+    pub fn reindex(&self, conversation_id: &str) -> Result<usize, ChatError> {
+        let mut facts = self.load_facts(conversation_id)?;
+        for fact in facts.iter_mut() {
+            fact.keywords = MemoryEngine::extract_keywords(&fact.content);
+        }
+        self.save_facts(conversation_id, &facts)?;
+        self.rebuild_index(conversation_id, &facts)?;
+        Ok(facts.len())
     }
 
     // ── 事实检索（BM25 + 语义融合）──
 
     /// 根据查询内容检索相关事实
     /// 使用 BM25 + 余弦相似度融合排序
+    ///
+    /// semantic 有值时再融合一路向量余弦（按事实ID取向量）
     pub fn search_facts(
         &self,
         conversation_id: &str,
         query: &str,
         top_k: usize,
+        semantic: Option<&SemanticQuery>,
     ) -> Vec<FactSearchResult> {
-        let facts = match self.load_facts(conversation_id) {
+        let mut facts = match self.load_facts(conversation_id) {
             Ok(f) => f,
             Err(_) => return Vec::new(),
         };
 
+        let query_keywords = MemoryEngine::extract_keywords(query);
+        // 索引按全部事实（含已遗忘的）维护，文档统计直接取自索引
+        let index = (!facts.is_empty() && !query_keywords.is_empty())
+            .then(|| self.current_index(conversation_id, &facts));
+
+        // 已遗忘的事实不参与检索
+        let now = chrono::Utc::now().timestamp_millis();
+        facts.retain(|f| Self::decayed_confidence(f, now) >= FORGOTTEN_CONFIDENCE);
+
         if facts.is_empty() {
             return Vec::new();
         }
 
-        let query_keywords = MemoryEngine::extract_keywords(query);
-        if query_keywords.is_empty() {
+        let Some(index) = index else {
             // 无关键词时，返回高优先级事实
             return Self::get_priority_facts(&facts, top_k);
-        }
-
-        let total_docs = facts.len();
-        let mut doc_freq: HashMap<String, usize> = HashMap::new();
-        let mut all_doc_keywords: Vec<Vec<String>> = Vec::new();
-        let mut total_len = 0usize;
-
-        for fact in &facts {
-            let mut doc_kw = fact.keywords.clone();
-            doc_kw.extend(MemoryEngine::extract_keywords(&fact.content));
-            doc_kw.extend(MemoryEngine::extract_keywords(&fact.context_snippet));
-            doc_kw.sort();
-            doc_kw.dedup();
-
-            for kw in &doc_kw {
-                *doc_freq.entry(kw.clone()).or_insert(0) += 1;
-            }
-            total_len += doc_kw.len();
-            all_doc_keywords.push(doc_kw);
-        }
+        };
 
-        let avg_doc_len = total_len as f64 / total_docs as f64;
+        // 候选：倒排表里至少命中一个查询词的事实（未命中的两路得分都为 0，不参与融合）
+        let position: HashMap<&str, usize> = facts
+            .iter()
+            .enumerate()
+            .map(|(i, f)| (f.id.as_str(), i))
+            .collect();
+        let candidates: BTreeSet<usize> = query_keywords
+            .iter()
+            .filter_map(|term| index.term_index.get(term))
+            .flatten()
+            .filter_map(|id| position.get(id.as_str()).copied())
+            .collect();
+        let doc_freq: HashMap<String, usize> = query_keywords
+            .iter()
+            .map(|term| {
+                let df = index.term_index.get(term).map_or(0, Vec::len);
+                (term.clone(), df)
+            })
+            .collect();
+        let total_docs = index.docs.len().max(1);
+        let avg_doc_len = index.total_terms as f64 / total_docs as f64;
+        let doc_terms = |i: usize| -> &[String] {
+            index
+                .docs
+                .get(&facts[i].id)
+                .map_or(&[], |doc| doc.terms.as_slice())
+        };
 
         // BM25 得分
-        let mut bm25_scores: Vec<(usize, f64)> = all_doc_keywords
+        let mut bm25_scores: Vec<(usize, f64)> = candidates
             .iter()
-            .enumerate()
-            .map(|(i, doc_kw)| {
+            .map(|&i| {
                 let score = MemoryEngine::bm25_score(
                     &query_keywords,
-                    doc_kw,
+                    doc_terms(i),
                     avg_doc_len,
                     total_docs,
                     &doc_freq,
                 );
                 // 高优先级事实加权
                 let category_boost = Self::category_weight(&facts[i].category);
-                // 置信度加权
-                let confidence_boost = 0.5 + facts[i].confidence * 0.5;
+                // 置信度加权（按遗忘曲线衰减后）
+                let confidence_boost = 0.5 + Self::decayed_confidence(&facts[i], now) * 0.5;
                 (i, score * category_boost * confidence_boost)
             })
             .collect();
         bm25_scores.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
 
         // 语义相似度得分
-        let mut semantic_scores: Vec<(usize, f64)> = all_doc_keywords
+        let mut semantic_scores: Vec<(usize, f64)> = candidates
             .iter()
-            .enumerate()
-            .map(|(i, doc_kw)| {
-                let score = MemoryEngine::keyword_cosine_similarity(&query_keywords, doc_kw);
+            .map(|&i| {
+                let score = MemoryEngine::keyword_cosine_similarity(&query_keywords, doc_terms(i));
                 let category_boost = Self::category_weight(&facts[i].category);
                 (i, score * category_boost)
             })
@@ -432,9 +946,31 @@ impl KnowledgeStore {
         semantic_scores
             .sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
 
+        // 向量余弦（同样乘以分类权重）
+        let vector_scores = semantic
+            .and_then(|q| q.rank(facts.iter().map(|f| f.id.as_str())))
+            .map(|mut ranks| {
+                for (i, score) in ranks.iter_mut() {
+                    *score *= Self::category_weight(&facts[*i].category);
+                }
+                ranks.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+                ranks
+            });
+
         // RRF 融合
-        let fused =
-            MemoryEngine::weighted_rrf_fusion(&bm25_scores, &semantic_scores, 0.55, 0.45, 60.0);
+        let fused = match &vector_scores {
+            Some(vector_scores) => MemoryEngine::weighted_rrf_fusion_multi(
+                &[
+                    (&bm25_scores, 0.35),
+                    (&semantic_scores, 0.25),
+                    (vector_scores, 0.4),
+                ],
+                60.0,
+            ),
+            None => {
+                MemoryEngine::weighted_rrf_fusion(&bm25_scores, &semantic_scores, 0.55, 0.45, 60.0)
+            }
+        };
 
         fused
             .into_iter()
@@ -471,11 +1007,155 @@ impl KnowledgeStore {
             .collect()
     }
 
+    /// 分类的基础记忆稳定期（天）；None 表示不随时间衰减
+    fn stability_days(category: &FactCategory) -> Option<f64> {
+        match category {
+            FactCategory::Identity | FactCategory::Promise | FactCategory::Event => None,
+            FactCategory::CurrentState => Some(3.0),
+            FactCategory::Preference => Some(30.0),
+            FactCategory::Consensus => Some(60.0),
+            FactCategory::Relationship => Some(90.0),
+        }
+    }
+
+    /// 按遗忘曲线衰减后的置信度
+    pub fn decayed_confidence(fact: &Fact, now: i64) -> f64 {
+        let base = match Self::stability_days(&fact.category) {
+            Some(days) if !fact.manual_override => days,
+            _ => return fact.confidence,
+        };
+        // 每次命中相当于一次复习，稳定期按命中次数对数增长
+        let stability = base * (1.0 + (1.0 + fact.hit_count as f64).ln());
+        let elapsed_days = (now - fact.last_confirmed_at).max(0) as f64 / DAY_MS;
+        fact.confidence * (-elapsed_days / stability).exp()
+    }
+
     /// 获取全部事实（用于上下文注入）
     pub fn get_all_facts(&self, conversation_id: &str) -> Vec<Fact> {
         self.load_facts(conversation_id).unwrap_or_default()
     }
 
+    // ── 手动维护（scope 为 None 时操作用户档案）──
+
+    fn load_scope(&self, scope: Option<&str>) -> Result<Vec<Fact>, ChatError> {
+        match scope {
+            Some(conversation_id) => self.load_facts(conversation_id),
+            None => self.load_global_facts(),
+        }
+    }
+
+    fn save_scope(&self, scope: Option<&str>, facts: &[Fact]) -> Result<(), ChatError> {
+        match scope {
+            Some(conversation_id) => {
+                self.save_facts(conversation_id, facts)?;
+                self.rebuild_index(conversation_id, facts)
+            }
+            None => self.save_global_facts(facts),
+        }
+    }
+
+    fn validate_manual(content: &str, confidence: f64) -> Result<(), ChatError> {
+        if content.trim().is_empty() {
+            return Err(ChatError::ValidationError {
+                message: "Fact content cannot be empty".to_string(),
+            });
+        }
+        if !(0.0..=1.0).contains(&confidence) {
+            return Err(ChatError::ValidationError {
+                message: format!("Confidence must be within 0.0-1.0, got {}", confidence),
+            });
+        }
+        Ok(())
+    }
+
+    /// 手动添加事实（原样追加，不与已有事实合并）
+    pub fn add_manual_fact(
+        &self,
+        scope: Option<&str>,
+        content: &str,
+        category: FactCategory,
+        confidence: f64,
+    ) -> Result<Fact, ChatError> {
+        Self::validate_manual(content, confidence)?;
+        let mut facts = self.load_scope(scope)?;
+        let mut fact = Self::user_fact(content, 0);
+        fact.category = category;
+        fact.confidence = confidence;
+        fact.manual_override = true;
+        facts.push(fact.clone());
+        self.save_scope(scope, &facts)?;
+        Ok(fact)
+    }
+
+    /// 手动修改事实内容、分类与置信度；事实不存在时返回 false
+    pub fn update_fact(
+        &self,
+        scope: Option<&str>,
+        fact_id: &str,
+        content: &str,
+        category: FactCategory,
+        confidence: f64,
+    ) -> Result<bool, ChatError> {
+        Self::validate_manual(content, confidence)?;
+        let mut facts = self.load_scope(scope)?;
+        let fact = match facts.iter_mut().find(|f| f.id == fact_id) {
+            Some(f) => f,
+            None => return Ok(false),
+        };
+        let content = content.trim();
+        if fact.content != content {
+            fact.content = content.to_string();
+            fact.keywords = MemoryEngine::extract_keywords(content);
+        }
+        fact.category = category;
+        fact.confidence = confidence;
+        fact.last_confirmed_at = chrono::Utc::now().timestamp_millis();
+        fact.manual_override = true;
+        self.save_scope(scope, &facts)?;
+        Ok(true)
+    }
+
+    /// 由事实三元组构建知识图谱（scope 为 None 时为用户档案）
+    pub fn graph(&self, scope: Option<&str>) -> KnowledgeGraph {
+        KnowledgeGraph::from_facts(&self.load_scope(scope).unwrap_or_default())
+    }
+
+    /// 对话中角色之间的关系网（当前事实 + 归档事实中的历史关系）
+    pub fn character_web(&self, conversation_id: &str) -> CharacterWeb {
+        CharacterWeb::from_facts(
+            &self.load_facts(conversation_id).unwrap_or_default(),
+            &self.load_archived_facts(conversation_id).unwrap_or_default(),
+        )
+    }
+
+    /// 删除事实；事实不存在时返回 false
+    pub fn delete_fact(&self, scope: Option<&str>, fact_id: &str) -> Result<bool, ChatError> {
+        let mut facts = self.load_scope(scope)?;
+        let before = facts.len();
+        facts.retain(|f| f.id != fact_id);
+        if facts.len() == before {
+            return Ok(false);
+        }
+        self.save_scope(scope, &facts)?;
+        Ok(true)
+    }
+
+    /// 删除提取自 turn_count 之后轮次的事实（对话截断后那些轮次已不存在），返回删除数
+    pub fn prune_after_turn(
+        &self,
+        conversation_id: &str,
+        turn_count: u32,
+    ) -> Result<usize, ChatError> {
+        let mut facts = self.load_facts(conversation_id)?;
+        let before = facts.len();
+        facts.retain(|f| f.source_turn <= turn_count);
+        let removed = before - facts.len();
+        if removed > 0 {
+            self.save_scope(Some(conversation_id), &facts)?;
+        }
+        Ok(removed)
+    }
+
     /// 分类权重：高优先级事实在检索中获得更高权重
     fn category_weight(category: &FactCategory) -> f64 {
         match category {
@@ -583,11 +1263,39 @@ impl KnowledgeStore {
                     confidence: 0.8,
                     hit_count: 0,
                     context_snippet: context,
+                    summary_ids: Vec::new(),
+                    superseded_by: None,
+                    superseded_at: None,
+                    manual_override: false,
+                    persona_id: None,
                 })
             })
             .collect()
     }
 
+    /// 用户亲自记下的事实（/remember）：不经模型提取，置信度拉满
+    pub fn user_fact(content: &str, turn: u32) -> Fact {
+        let now = chrono::Utc::now().timestamp_millis();
+        Fact {
+            id: uuid::Uuid::new_v4().to_string(),
+            content: content.trim().to_string(),
+            category: FactCategory::Event,
+            source_turn: turn,
+            created_at: now,
+            last_confirmed_at: now,
+            keywords: MemoryEngine::extract_keywords(content),
+            entities: Vec::new(),
+            confidence: 1.0,
+            hit_count: 0,
+            context_snippet: "用户手动记录".to_string(),
+            summary_ids: Vec::new(),
+            superseded_by: None,
+            superseded_at: None,
+            manual_override: false,
+            persona_id: None,
+        }
+    }
+
     /// 构建事实提取 prompt（用于让AI从对话中提取事实）
     pub fn build_fact_extraction_prompt(
         recent_messages: &[Message],
@@ -619,7 +1327,11 @@ impl KnowledgeStore {
                 MessageRole::Assistant => "AI角色",
                 MessageRole::System => continue,
             };
-            prompt.push_str(&format!("{}: {}\n", role, msg.content));
+            prompt.push_str(&format!(
+                "{}: {}\n",
+                role,
+                AttachmentStore::text_with_captions(msg)
+            ));
         }
 
         prompt.push_str(r#"
@@ -636,20 +1348,21 @@ impl KnowledgeStore {
 提取规则：
 1. 只提取确定性事实，不提取推测、氛围描写
 2. 身份信息(identity)：姓名、年龄、职业等不可变属性
-3. 关系(relationship)：人物间的关系定义或变化
+3. 关系(relationship)：人物间的关系定义或变化；配角之间的关系同样提取（艾琳→暗恋→凯），entities 写明双方
 4. 偏好(preference)：喜好、习惯、口癖等
 5. 事件(event)：已确认发生的关键事件
 6. 状态(state)：当前情绪、位置等（会被新状态覆盖）
 7. 承诺(promise)：双方做出的承诺、约定
 8. 共识(consensus)：双方达成的一致看法
 9. 每条事实≤30字，信息密度优先
-10. 如果没有新事实可提取，输出空数组 []
+10. 用户发来的图片以 [图片：描述] 给出，图中能确认的事实（宠物、所在地点、物品等）同样提取
+11. 如果没有新事实可提取，输出空数组 []
 只输出JSON"#);
 
         prompt
     }
 
-    fn category_label(category: &FactCategory) -> &'static str {
+    pub(crate) fn category_label(category: &FactCategory) -> &'static str {
         match category {
             FactCategory::Identity => "身份",
             FactCategory::Relationship => "关系",
@@ -736,17 +1449,16 @@ impl KnowledgeStore {
     pub fn delete_knowledge(&self, conversation_id: &str) -> Result<(), ChatError> {
         let facts_path = self.facts_path(conversation_id)?;
         let index_path = self.index_path(conversation_id)?;
-        if facts_path.exists() {
-            fs::remove_file(&facts_path).map_err(|e| ChatError::StorageError {
-                message: format!("Failed to delete facts: {}", e),
-            })?;
-        }
-        if index_path.exists() {
-            fs::remove_file(&index_path).map_err(|e| ChatError::StorageError {
-                message: format!("Failed to delete index: {}", e),
-            })?;
-        }
-        Ok(())
+        let archived_path = self.archived_facts_path(conversation_id)?;
+        at_rest::remove(&archived_path).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to delete archived facts: {}", e),
+        })?;
+        at_rest::remove(&facts_path).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to delete facts: {}", e),
+        })?;
+        at_rest::remove(&index_path).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to delete index: {}", e),
+        })
     }
 
     /// 更新事实的命中计数
@@ -764,6 +1476,81 @@ impl KnowledgeStore {
         }
         self.save_facts(conversation_id, &facts)
     }
+
+    /// 重建事实 ↔ 摘要的双向链接（写入摘要、分级合并之后调用）
+    ///
+    /// 事实来源轮次落在摘要轮次范围内，且仍能从摘要内容推出时才链接；
+    /// 合并后被压缩掉的事实因此自动断链，两侧始终互为反向。
+    pub fn cross_link(
+        &self,
+        conversation_id: &str,
+        summaries: &mut [MemorySummary],
+    ) -> Result<(), ChatError> {
+        let mut facts = self.load_facts(conversation_id)?;
+        for fact in &mut facts {
+            fact.summary_ids.clear();
+        }
+
+        for summary in summaries.iter_mut() {
+            summary.linked_fact_ids.clear();
+            let corpus = std::iter::once(&summary.summary)
+                .chain(summary.core_facts.iter())
+                .map(|s| s.as_str())
+                .collect::<Vec<&str>>()
+                .join("\n");
+            for fact in &mut facts {
+                let in_range = fact.source_turn >= summary.turn_range_start
+                    && fact.source_turn <= summary.turn_range_end;
+                if !in_range {
+                    continue;
+                }
+                let described = summary
+                    .core_facts
+                    .iter()
+                    .any(|core| Self::facts_are_similar(core, &fact.content))
+                    || FidelityAuditor::is_derivable(&fact.content, &corpus);
+                if described {
+                    summary.linked_fact_ids.push(fact.id.clone());
+                    fact.summary_ids.push(summary.id.clone());
+                }
+            }
+        }
+
+        if facts.is_empty() {
+            return Ok(());
+        }
+        self.save_facts(conversation_id, &facts)
+    }
+
+    /// 摘要链接到的事实（按链接顺序；已被删除的事实跳过）
+    pub fn linked_facts(&self, conversation_id: &str, summary: &MemorySummary) -> Vec<Fact> {
+        let facts = self.get_all_facts(conversation_id);
+        summary
+            .linked_fact_ids
+            .iter()
+            .filter_map(|id| facts.iter().find(|f| &f.id == id).cloned())
+            .collect()
+    }
+}
+
+// ── 异步接口：生成管线里的事实库读写放到 blocking 线程池 ──
+
+impl KnowledgeStore {
+    pub async fn get_all_facts_async(&self, conversation_id: &str) -> Vec<Fact> {
+        let store = self.clone();
+        let id = conversation_id.to_string();
+        blocking_pool::offload("facts_load", move || store.get_all_facts(&id)).await
+    }
+
+    pub async fn add_facts_async(
+        &self,
+        conversation_id: &str,
+        new_facts: Vec<Fact>,
+    ) -> Result<(), ChatError> {
+        let store = self.clone();
+        let id = conversation_id.to_string();
+        blocking_pool::offload("facts_write", move || store.add_facts(&id, new_facts)).await
+    }
 }
 
 #[cfg(test)]
@@ -776,6 +1563,38 @@ mod tests {
                 KnowledgeStore::category_weight(&FactCategory::CurrentState));
     }
 
+    #[test]
+    fn test_forgetting_curve_decay() {
+        let now = 100 * DAY_MS as i64;
+        let mut facts = KnowledgeStore::parse_extracted_facts(
+            r#"[{"content": "用户→心情→低落", "category": "state"},
+                {"content": "用户→职业是→程序员", "category": "identity"}]"#,
+            1,
+        );
+        for f in facts.iter_mut() {
+            f.confidence = 0.9;
+            f.last_confirmed_at = now - 10 * DAY_MS as i64;
+        }
+        let state = KnowledgeStore::decayed_confidence(&facts[0], now);
+        assert!(state < FORGOTTEN_CONFIDENCE);
+        assert_eq!(KnowledgeStore::decayed_confidence(&facts[1], now), 0.9);
+
+        // 常被检索命中的事实衰减更慢
+        facts[0].hit_count = 20;
+        assert!(KnowledgeStore::decayed_confidence(&facts[0], now) > state);
+        facts[0].manual_override = true;
+        assert_eq!(KnowledgeStore::decayed_confidence(&facts[0], now), 0.9);
+
+        // 已遗忘的状态不再被检索到
+        let tmp = tempfile::TempDir::new().unwrap();
+        let store = KnowledgeStore::new(tmp.path().to_str().unwrap());
+        facts[0].hit_count = 0;
+        facts[0].manual_override = false;
+        facts[0].last_confirmed_at = chrono::Utc::now().timestamp_millis() - 30 * DAY_MS as i64;
+        store.save_facts("c1", &facts).unwrap();
+        assert!(store.search_facts("c1", "心情低落", 10, None).is_empty());
+    }
+
     #[test]
     fn test_facts_are_similar() {
         assert!(KnowledgeStore::facts_are_similar(
@@ -833,9 +1652,278 @@ mod tests {
             confidence: 0.9,
             hit_count: 0,
             context_snippet: "用户自我介绍".to_string(),
+            summary_ids: Vec::new(),
+            superseded_by: None,
+            superseded_at: None,
+            manual_override: false,
+            persona_id: None,
         };
         let ctx = KnowledgeStore::build_knowledge_context(&[], &[fact]);
         assert!(ctx.contains("不可变事实"));
         assert!(ctx.contains("程序员"));
     }
+
+    #[test]
+    fn test_cross_link_follows_tiered_merge() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let store = KnowledgeStore::new(tmp.path().to_str().unwrap());
+        let mut facts = KnowledgeStore::parse_extracted_facts(
+            r#"[{"content": "用户→是→程序员", "category": "identity"},
+                {"content": "窗外→下着→小雨", "category": "state"}]"#,
+            3,
+        );
+        facts.extend(KnowledgeStore::parse_extracted_facts(
+            r#"[{"content": "用户→养了→一只猫", "category": "event"}]"#,
+            100,
+        ));
+        store.save_facts("c1", &facts).unwrap();
+
+        let mut summaries: Vec<MemorySummary> = (0..8u32)
+            .map(|i| MemorySummary {
+                id: format!("s{}", i),
+                summary: format!("第{}段闲聊", i),
+                core_facts: if i == 0 {
+                    vec!["[身份] 用户是程序员".to_string(), "窗外下着小雨".to_string()]
+                } else {
+                    vec![]
+                },
+                turn_range_start: i * 5 + 1,
+                turn_range_end: i * 5 + 5,
+                created_at: 0,
+                keywords: vec![],
+                compression_generation: 0,
+                context_card: None,
+                fact_tiers: vec![],
+                linked_fact_ids: vec![],
+                pinned: false,
+                pinned_facts: Vec::new(),
+                stale: false,
+            })
+            .collect();
+        store.cross_link("c1", &mut summaries).unwrap();
+        assert_eq!(summaries[0].linked_fact_ids, vec![facts[0].id.clone(), facts[1].id.clone()]);
+        assert_eq!(store.get_all_facts("c1")[0].summary_ids, vec!["s0".to_string()]);
+        assert!(store.get_all_facts("c1")[2].summary_ids.is_empty());
+
+        // 合并丢弃场景细节：对应事实两侧同时断链，身份事实随合并摘要保留
+        let (mut merged, _) = MemoryEngine::tiered_merge(&summaries);
+        assert_eq!(merged[0].linked_fact_ids.len(), 2);
+        store.cross_link("c1", &mut merged).unwrap();
+        assert_eq!(merged[0].linked_fact_ids, vec![facts[0].id.clone()]);
+        let linked = store.get_all_facts("c1");
+        assert_eq!(linked[0].summary_ids, vec![merged[0].id.clone()]);
+        assert!(linked[1].summary_ids.is_empty());
+        assert_eq!(store.linked_facts("c1", &merged[0])[0].content, "用户→是→程序员");
+    }
+
+    #[test]
+    fn test_contradicting_fact_supersedes_old_one() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let store = KnowledgeStore::new(tmp.path().to_str().unwrap());
+        store
+            .add_facts(
+                "c1",
+                KnowledgeStore::parse_extracted_facts(
+                    r#"[{"content": "用户→住在→北京", "category": "state"},
+                        {"content": "用户→养了→一只猫", "category": "event"}]"#,
+                    3,
+                ),
+            )
+            .unwrap();
+        let new_facts = KnowledgeStore::parse_extracted_facts(
+            r#"[{"content": "用户 → 住在 → 上海", "category": "state"},
+                {"content": "用户→养了→两条金鱼", "category": "event"}]"#,
+            40,
+        );
+        let shanghai_id = new_facts[0].id.clone();
+        store.add_facts("c1", new_facts).unwrap();
+
+        let active: Vec<String> = store.get_all_facts("c1").into_iter().map(|f| f.content).collect();
+        assert!(active.contains(&"用户 → 住在 → 上海".to_string()));
+        assert!(!active.contains(&"用户→住在→北京".to_string()));
+        // 事件类事实可以并存
+        assert!(active.contains(&"用户→养了→一只猫".to_string()));
+        assert!(active.contains(&"用户→养了→两条金鱼".to_string()));
+
+        let conflicts = store.list_conflicts("c1");
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].superseded_content, "用户→住在→北京");
+        assert_eq!(conflicts[0].active_fact_id, shanghai_id);
+        assert_eq!(conflicts[0].active_content, "用户 → 住在 → 上海");
+
+        store.delete_knowledge("c1").unwrap();
+        assert!(store.list_conflicts("c1").is_empty());
+    }
+    #[test]
+    fn test_search_uses_incrementally_maintained_index() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let store = KnowledgeStore::new(tmp.path().to_str().unwrap());
+        store
+            .add_facts(
+                "c1",
+                KnowledgeStore::parse_extracted_facts(
+                    r#"[{"content": "艾琳→擅长→剑术", "category": "identity"},
+                        {"content": "艾琳→讨厌→下雨天", "category": "preference"}]"#,
+                    2,
+                ),
+            )
+            .unwrap();
+        let before = store.load_index("c1").unwrap().unwrap();
+        store
+            .add_facts(
+                "c1",
+                KnowledgeStore::parse_extracted_facts(
+                    r#"[{"content": "小镇→位于→海边", "category": "state"}]"#,
+                    5,
+                ),
+            )
+            .unwrap();
+        let after = store.load_index("c1").unwrap().unwrap();
+        assert_eq!(after.docs.len(), 3);
+        // 已有事实沿用之前提取的词项，统计与全量重建一致
+        for (id, doc) in &before.docs {
+            assert_eq!(after.docs[id].hash, doc.hash);
+        }
+        let mut rebuilt = KnowledgeIndex::default();
+        KnowledgeStore::sync_index(&mut rebuilt, &store.get_all_facts("c1"));
+        assert_eq!(after.total_terms, rebuilt.total_terms);
+        assert_eq!(after.term_index.len(), rebuilt.term_index.len());
+
+        let results = store.search_facts("c1", "海边的小镇", 3, None);
+        assert_eq!(results[0].fact.content, "小镇→位于→海边");
+
+        // 绕过索引写入的事实（旧数据）在检索时补进索引
+        let mut facts = store.get_all_facts("c1");
+        facts.retain(|f| !f.content.contains("小镇"));
+        store.save_facts("c1", &facts).unwrap();
+        assert!(store
+            .search_facts("c1", "海边的小镇", 3, None)
+            .iter()
+            .all(|r| !r.fact.content.contains("小镇")));
+        assert_eq!(store.load_index("c1").unwrap().unwrap().docs.len(), 2);
+    }
+
+    #[test]
+    fn test_user_profile_facts_are_shared_across_conversations() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let store = KnowledgeStore::new(tmp.path().to_str().unwrap());
+        store
+            .add_facts(
+                "c1",
+                KnowledgeStore::parse_extracted_facts(
+                    r#"[{"content": "用户→职业是→程序员", "category": "identity"},
+                        {"content": "用户→喜欢→爵士乐", "category": "preference"},
+                        {"content": "艾琳→职业是→骑士", "category": "identity"}]"#,
+                    3,
+                ),
+            )
+            .unwrap();
+        let global: Vec<String> = store.get_global_facts().into_iter().map(|f| f.content).collect();
+        assert_eq!(global, vec!["用户→职业是→程序员", "用户→喜欢→爵士乐"]);
+
+        // 新对话直接可见；对话内出现矛盾的事实时以对话内为准
+        assert_eq!(store.profile_facts_for(&store.get_all_facts("c2"), None).len(), 2);
+        let local = KnowledgeStore::parse_extracted_facts(
+            r#"[{"content": "用户→职业是→设计师", "category": "identity"}]"#,
+            1,
+        );
+        let injected = store.profile_facts_for(&local, None);
+        assert_eq!(injected.len(), 1);
+        assert_eq!(injected[0].content, "用户→喜欢→爵士乐");
+
+        // 删除对话知识库不影响用户档案
+        store.delete_knowledge("c1").unwrap();
+        assert_eq!(store.get_global_facts().len(), 2);
+    }
+    #[test]
+    fn test_user_profile_is_namespaced_by_persona() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let store = KnowledgeStore::new(tmp.path().to_str().unwrap());
+        let extract = |json: &str, persona: Option<&str>| {
+            let mut facts = KnowledgeStore::parse_extracted_facts(json, 1);
+            for fact in &mut facts {
+                fact.persona_id = persona.map(str::to_string);
+            }
+            facts
+        };
+        store
+            .add_facts("c1", extract(r#"[{"content": "用户→职业是→程序员", "category": "identity"}]"#, None))
+            .unwrap();
+        store
+            .add_facts("c2", extract(r#"[{"content": "用户→职业是→骑士", "category": "identity"}]"#, Some("p1")))
+            .unwrap();
+
+        // 不同人设的同一关系互不取代，各自只注入本分区
+        assert_eq!(store.get_global_facts().len(), 2);
+        let plain = store.profile_facts_for(&[], None);
+        assert_eq!(plain.len(), 1);
+        assert_eq!(plain[0].content, "用户→职业是→程序员");
+        let knight = store.profile_facts_for(&[], Some("p1"));
+        assert_eq!(knight.len(), 1);
+        assert_eq!(knight[0].content, "用户→职业是→骑士");
+
+        assert_eq!(store.remove_persona_profile("p1").unwrap(), 1);
+        assert!(store.profile_facts_for(&[], Some("p1")).is_empty());
+        assert_eq!(store.profile_facts_for(&[], None).len(), 1);
+    }
+    #[test]
+    fn test_manual_facts_survive_extraction() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let store = KnowledgeStore::new(tmp.path().to_str().unwrap());
+        let fact = store
+            .add_manual_fact(Some("c1"), "用户→住在→北京", FactCategory::CurrentState, 0.7)
+            .unwrap();
+        assert!(store
+            .add_manual_fact(Some("c1"), "用户→喜欢→茶", FactCategory::Preference, 1.5)
+            .is_err());
+
+        store
+            .add_facts(
+                "c1",
+                KnowledgeStore::parse_extracted_facts(
+                    r#"[{"content": "用户→住在→上海", "category": "state"}]"#,
+                    5,
+                ),
+            )
+            .unwrap();
+        let facts = store.get_all_facts("c1");
+        assert_eq!(facts.len(), 1);
+        assert_eq!(facts[0].content, "用户→住在→北京");
+        assert_eq!(facts[0].confidence, 0.7);
+
+        assert!(store
+            .update_fact(Some("c1"), &fact.id, "用户→职业是→厨师", FactCategory::Identity, 0.9)
+            .unwrap());
+        let updated = &store.get_all_facts("c1")[0];
+        assert_eq!(updated.category, FactCategory::Identity);
+        assert!(updated.keywords.iter().any(|k| k.contains("厨师")));
+        assert!(!store
+            .update_fact(Some("c1"), "missing", "x", FactCategory::Event, 0.5)
+            .unwrap());
+
+        assert!(store.delete_fact(Some("c1"), &fact.id).unwrap());
+        assert!(!store.delete_fact(Some("c1"), &fact.id).unwrap());
+        assert!(store.get_all_facts("c1").is_empty());
+    }
+
+    #[test]
+    fn test_prune_after_turn_keeps_earlier_facts() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let store = KnowledgeStore::new(tmp.path().to_str().unwrap());
+        let mut facts = KnowledgeStore::parse_extracted_facts(
+            r#"[{"content": "用户→住在→北京", "category": "state"}]"#,
+            2,
+        );
+        facts.extend(KnowledgeStore::parse_extracted_facts(
+            r#"[{"content": "用户→去了→海边", "category": "event"}]"#,
+            5,
+        ));
+        store.add_facts("c1", facts).unwrap();
+
+        assert_eq!(store.prune_after_turn("c1", 3).unwrap(), 1);
+        assert_eq!(store.prune_after_turn("c1", 3).unwrap(), 0);
+        let kept = store.get_all_facts("c1");
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0].source_turn, 2);
+    }
 }
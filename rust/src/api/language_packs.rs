@@ -0,0 +1,521 @@
+use std::borrow::Cow;
+
+// ═══════════════════════════════════════════════════════════════════
+//  语言包 (Language Packs)
+//  ─────────────────────────────────────────────────────────────────
+//  CognitiveEngine 的感知层 / 理解层 / 推理层和短期记忆的情绪扫描
+//  都依赖词表。这里按语言收拢全部词表，分析时按消息的文字自动选包，
+//  使英文、日文对话也能得到与中文同样的 EmotionVector 与意图：
+//    - 中文：原有词表，子串匹配
+//    - 英文：整词匹配、忽略大小写；否定词在关键词之前（"not happy"）
+//    - 日文：子串匹配；否定在词尾，直接收录否定形（「楽しくない」）
+//  语言识别按文字：含假名为日文，含汉字为中文，只有拉丁字母为英文，
+//  其余（纯数字、纯标点、空消息）按中文处理。
+//
+//  存储结构：无。词表为编译期常量
+// ═══════════════════════════════════════════════════════════════════
+
+/// (情感名, 维度索引, [(关键词, 强度)])
+pub(crate) type EmotionLexiconEntry = (&'static str, usize, &'static [(&'static str, f64)]);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Language {
+    Chinese,
+    English,
+    Japanese,
+}
+
+pub(crate) const LANGUAGES: [Language; 3] = [Language::Chinese, Language::English, Language::Japanese];
+
+pub(crate) struct LanguagePack {
+    pub language: Language,
+    /// 以空格分词的语言：关键词按整词匹配并忽略大小写
+    pub word_delimited: bool,
+    /// 「短消息」「长消息」等长度阈值的倍数（以中文字数为基准）
+    pub length_scale: usize,
+    /// 感知层情感词典，维度索引同 EmotionVector 字段顺序
+    pub emotion_lexicon: &'static [EmotionLexiconEntry],
+    /// 关键词前出现这些词时视为被否定
+    pub negation_prefixes: &'static [&'static str],
+    /// 检查否定词时向前看的字节数
+    pub negation_window: usize,
+    pub negation_phrases: &'static [&'static str],
+    pub sarcasm_markers: &'static [(&'static str, f64)],
+    pub hesitation_markers: &'static [&'static str],
+    pub probing_markers: &'static [&'static str],
+    pub coquettish_markers: &'static [&'static str],
+    pub defensive_markers: &'static [&'static str],
+    /// 整条消息只有这些字时视为敷衍（情绪压抑信号）
+    pub flat_replies: &'static [&'static str],
+    pub avoidance_words: &'static [&'static str],
+    pub farewell_words: &'static [&'static str],
+    pub reconcile_words: &'static [&'static str],
+    pub playful_words: &'static [&'static str],
+    pub intimacy_words: &'static [&'static str],
+    pub trust_words: &'static [&'static str],
+    pub conflict_words: &'static [&'static str],
+    /// 情绪转折检测用的粗粒度正 / 负面词
+    pub positive_signals: &'static [&'static str],
+    pub negative_signals: &'static [&'static str],
+    /// 短期记忆快速情绪扫描用的带权正 / 负面词
+    pub quick_positive: &'static [(&'static str, f64)],
+    pub quick_negative: &'static [(&'static str, f64)],
+}
+
+/// 按文字识别语言
+pub(crate) fn detect_language(text: &str) -> Language {
+    let mut has_han = false;
+    let mut has_latin = false;
+    for c in text.chars() {
+        match c {
+            '\u{3040}'..='\u{30ff}' => return Language::Japanese,
+            '\u{4e00}'..='\u{9fff}' | '\u{3400}'..='\u{4dbf}' => has_han = true,
+            c if c.is_ascii_alphabetic() => has_latin = true,
+            _ => {}
+        }
+    }
+    if !has_han && has_latin {
+        Language::English
+    } else {
+        Language::Chinese
+    }
+}
+
+pub(crate) fn pack(language: Language) -> &'static LanguagePack {
+    match language {
+        Language::Chinese => &CHINESE,
+        Language::English => &ENGLISH,
+        Language::Japanese => &JAPANESE,
+    }
+}
+
+/// 按文本内容选包
+pub(crate) fn pack_for(text: &str) -> &'static LanguagePack {
+    pack(detect_language(text))
+}
+
+impl LanguagePack {
+    /// 匹配前的规范化（整词语言统一小写）
+    pub fn normalize<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        if self.word_delimited && text.chars().any(|c| c.is_uppercase()) {
+            Cow::Owned(text.to_lowercase())
+        } else {
+            Cow::Borrowed(text)
+        }
+    }
+
+    /// 在已规范化的文本中查找关键词，返回字节位置
+    pub fn find(&self, text: &str, keyword: &str) -> Option<usize> {
+        if !self.word_delimited {
+            return text.find(keyword);
+        }
+        let is_word_char = |c: char| c.is_alphanumeric() || c == '\'';
+        text.match_indices(keyword).map(|(pos, _)| pos).find(|&pos| {
+            let before = text[..pos].chars().next_back();
+            let after = text[pos + keyword.len()..].chars().next();
+            !before.is_some_and(is_word_char) && !after.is_some_and(is_word_char)
+        })
+    }
+
+    /// 原始文本是否包含关键词
+    pub fn contains(&self, text: &str, keyword: &str) -> bool {
+        self.find(&self.normalize(text), keyword).is_some()
+    }
+
+    /// 已规范化文本中 pos 处的关键词前面是否紧跟否定词
+    pub fn is_negated(&self, text: &str, pos: usize) -> bool {
+        let mut start = pos.saturating_sub(self.negation_window);
+        while !text.is_char_boundary(start) {
+            start += 1;
+        }
+        let prefix = &text[start..pos];
+        self.negation_prefixes.iter().any(|neg| prefix.ends_with(neg))
+    }
+}
+
+// ── 中文 ──
+
+const CHINESE: LanguagePack = LanguagePack {
+    language: Language::Chinese,
+    word_delimited: false,
+    length_scale: 1,
+    emotion_lexicon: &[
+        ("joy", 0, &[
+            ("开心", 0.8), ("高兴", 0.8), ("快乐", 0.9), ("笑", 0.5), ("哈哈", 0.7),
+            ("嘻嘻", 0.6), ("太好了", 0.8), ("喜欢", 0.7), ("爱", 0.9), ("幸福", 0.95),
+            ("温暖", 0.6), ("感谢", 0.5), ("谢谢", 0.4), ("棒", 0.6), ("赞", 0.5),
+            ("耶", 0.7), ("嘿嘿", 0.6), ("甜", 0.7), ("哈哈哈", 0.8), ("噗", 0.5),
+            ("好耶", 0.8), ("绝了", 0.7), ("爽", 0.7), ("舒服", 0.6), ("满足", 0.7),
+            ("开心死了", 1.0), ("乐", 0.6), ("美", 0.5), ("妙", 0.5), ("嘿嘿嘿", 0.7),
+            ("好开心", 0.9), ("超开心", 1.0), ("太棒了", 0.9), ("好喜欢", 0.9),
+            ("心花怒放", 1.0), ("飘了", 0.7), ("上头", 0.6),
+        ]),
+        ("sadness", 1, &[
+            ("难过", 0.8), ("伤心", 0.9), ("痛苦", 1.0), ("哭", 0.8), ("呜呜", 0.7),
+            ("失望", 0.7), ("沮丧", 0.8), ("孤独", 0.8), ("寂寞", 0.7), ("心疼", 0.7),
+            ("遗憾", 0.6), ("可惜", 0.5), ("唉", 0.5), ("叹", 0.4), ("泪", 0.7),
+            ("委屈", 0.8), ("心酸", 0.8), ("难受", 0.8), ("不开心", 0.7), ("丧", 0.6),
+            ("emo", 0.7), ("崩溃", 1.0), ("受不了", 0.9), ("好累", 0.6), ("算了", 0.5),
+            ("无所谓了", 0.6), ("没意思", 0.5), ("心碎", 1.0), ("扎心", 0.8),
+            ("好难过", 0.9), ("想哭", 0.8), ("眼泪", 0.7), ("哭了", 0.9),
+            ("不想说话", 0.7), ("好烦", 0.6), ("活着好累", 1.0),
+        ]),
+        ("anger", 2, &[
+            ("生气", 0.8), ("愤怒", 1.0), ("气死", 0.9), ("混蛋", 0.9), ("可恶", 0.8),
+            ("滚", 1.0), ("烦死", 0.8), ("受够", 0.9), ("讨厌", 0.7), ("烦", 0.6),
+            ("恼", 0.6), ("怒", 0.8), ("闭嘴", 0.9), ("够了", 0.8), ("你行", 0.5),
+            ("随便你", 0.6), ("爱咋咋", 0.7), ("切", 0.4), ("啧", 0.4),
+            ("有病", 0.8), ("神经病", 0.9), ("你够了", 0.8), ("别烦我", 0.8),
+            ("我不想理你", 0.7), ("走开", 0.8), ("少来", 0.6), ("你烦不烦", 0.8),
+        ]),
+        ("fear", 3, &[
+            ("害怕", 0.8), ("恐惧", 1.0), ("担心", 0.6), ("紧张", 0.6), ("不安", 0.7),
+            ("慌", 0.7), ("怕", 0.6), ("焦虑", 0.8), ("忐忑", 0.7), ("心虚", 0.6),
+            ("发抖", 0.8), ("不敢", 0.6), ("完了", 0.7), ("怎么办", 0.6), ("糟了", 0.7),
+            ("慌了", 0.7), ("好怕", 0.8), ("吓死了", 0.8), ("瑟瑟发抖", 0.7),
+            ("心慌", 0.7), ("不会吧", 0.4), ("万一", 0.5),
+        ]),
+        ("surprise", 4, &[
+            ("惊讶", 0.7), ("天哪", 0.8), ("不会吧", 0.6), ("真的吗", 0.5),
+            ("居然", 0.6), ("竟然", 0.6), ("没想到", 0.6), ("啊", 0.3), ("哇", 0.5),
+            ("诶", 0.3), ("卧槽", 0.8), ("我靠", 0.7), ("天呐", 0.8), ("不是吧", 0.6),
+            ("啊？", 0.5), ("嗯？", 0.3), ("等等", 0.4), ("什么鬼", 0.6),
+            ("离谱", 0.6), ("绝了", 0.5), ("震惊", 0.8), ("我的天", 0.8),
+        ]),
+        ("intimacy", 5, &[
+            ("抱", 0.7), ("靠", 0.5), ("牵手", 0.8), ("依偎", 0.9), ("亲", 0.8),
+            ("蹭", 0.7), ("贴", 0.6), ("挽", 0.7), ("搂", 0.8), ("窝", 0.6),
+            ("枕", 0.7), ("偎", 0.8), ("想你", 0.9), ("在吗", 0.4), ("陪我", 0.7),
+            ("别走", 0.8), ("过来", 0.5), ("靠近", 0.6), ("抱抱", 0.8), ("摸摸头", 0.7),
+            ("宝", 0.6), ("亲爱的", 0.8), ("乖", 0.5), ("想见你", 0.9),
+            ("好想你", 1.0), ("不要走", 0.9), ("留下来", 0.8), ("牵", 0.6),
+            ("拉着", 0.5), ("挨着", 0.6), ("暖暖的", 0.6), ("心跳", 0.7),
+        ]),
+        ("trust", 6, &[
+            ("相信", 0.8), ("信任", 0.9), ("放心", 0.7), ("安心", 0.7), ("依赖", 0.7),
+            ("靠谱", 0.6), ("踏实", 0.6), ("陪", 0.5), ("懂", 0.5), ("理解", 0.6),
+            ("知道", 0.3), ("明白", 0.4), ("你说的对", 0.6), ("听你的", 0.7),
+            ("交给你", 0.7), ("有你在", 0.8), ("你在就好", 0.9), ("安全感", 0.9),
+            ("放心吧", 0.6), ("我信你", 0.9),
+        ]),
+        ("anticipation", 7, &[
+            ("期待", 0.8), ("盼", 0.7), ("等", 0.4), ("希望", 0.6), ("要是", 0.5),
+            ("如果能", 0.6), ("好想", 0.7), ("什么时候", 0.5), ("快点", 0.6),
+            ("等不及", 0.8), ("明天", 0.3), ("下次", 0.4), ("以后", 0.3), ("一起", 0.5),
+            ("想要", 0.6), ("能不能", 0.5), ("可以吗", 0.4), ("会不会", 0.4),
+            ("好期待", 0.9), ("迫不及待", 0.9),
+        ]),
+    ],
+    negation_prefixes: &["不", "没", "别", "非", "未", "无", "莫", "勿", "才没", "又不", "并不", "才不"],
+    negation_window: 6,
+    negation_phrases: &[
+        "没事", "不是", "才没有", "没什么", "不要紧", "没关系", "无所谓",
+        "不在乎", "才不是", "才不会", "我没有", "不用了", "不需要",
+        "没有啊", "不是啦", "才没", "我才不", "不用管我",
+    ],
+    sarcasm_markers: &[
+        ("行啊", 0.7), ("厉害了", 0.8), ("随便", 0.5), ("哦", 0.3),
+        ("呵呵", 0.9), ("好的呢", 0.7), ("是是是", 0.8), ("对对对", 0.7),
+        ("你说的都对", 0.9), ("行吧行吧", 0.7), ("嗯嗯嗯", 0.4),
+        ("好好好", 0.3), ("你开心就好", 0.8), ("随你", 0.6),
+        ("爱咋咋地", 0.8), ("你厉害", 0.7), ("了不起", 0.6),
+        ("真棒啊", 0.5), // 需要结合语境判断
+    ],
+    hesitation_markers: &[
+        "我...", "算了", "没什么", "不说了", "还是算了", "其实...",
+        "我想说...", "就是...", "那个...", "嗯...", "唉算了",
+        "不说了不说了", "没事没事", "当我没说",
+    ],
+    probing_markers: &[
+        "你觉得呢", "如果", "假如", "要是", "会不会", "你说",
+        "你想不想", "你愿意吗", "可以吗", "好不好", "行不行",
+        "你介意吗", "你在意吗", "你会怎么", "你喜欢吗",
+    ],
+    coquettish_markers: &[
+        "嘛", "啦", "呀", "哼", "人家", "讨厌", "不嘛", "好不好嘛",
+        "你都不", "都不理我", "哼哼", "呜", "嘤嘤", "QAQ",
+    ],
+    defensive_markers: &[
+        "关你什么事", "我自己可以", "不用你管", "你管得着吗",
+        "跟你没关系", "别管我", "我的事", "你别管",
+        "不需要你", "少管闲事", "我又没", "我哪有",
+    ],
+    flat_replies: &["嗯", "哦", "好", "知道了", "行", "好吧", "嗯嗯"],
+    avoidance_words: &["不说这个了", "换个话题", "别提了", "不想聊", "说点别的"],
+    farewell_words: &["晚安", "拜拜", "再见", "走了", "睡了", "下次见", "明天见", "88", "886"],
+    reconcile_words: &["对不起", "抱歉", "我错了", "是我不好", "原谅我", "别生气了", "我不该"],
+    playful_words: &["哈哈哈", "笑死", "逗你的", "开玩笑", "骗你的", "嘿嘿", "坏蛋", "讨厌啦"],
+    intimacy_words: &[
+        "宝", "亲爱的", "乖", "想你", "抱", "亲", "蹭", "喜欢你",
+        "爱你", "心跳", "脸红", "害羞", "暖", "甜",
+    ],
+    trust_words: &["相信", "信任", "放心", "懂", "理解", "安心", "交给你", "听你的"],
+    conflict_words: &[
+        "生气", "烦", "讨厌", "滚", "够了", "别说了", "不想理你",
+        "随便", "呵呵", "哦", "行吧",
+    ],
+    positive_signals: &[
+        "开心", "高兴", "哈哈", "喜欢", "爱", "棒", "好", "嘿嘿",
+        "耶", "甜", "暖", "幸福", "谢谢",
+    ],
+    negative_signals: &[
+        "难过", "伤心", "生气", "烦", "累", "算了", "唉", "哭",
+        "不开心", "讨厌", "滚", "够了", "无聊", "没意思", "emo",
+    ],
+    quick_positive: &[
+        ("开心", 0.8), ("高兴", 0.8), ("笑", 0.5), ("哈哈", 0.7), ("喜欢", 0.7), ("爱", 0.9),
+        ("甜", 0.7), ("暖", 0.6), ("嘿嘿", 0.6), ("耶", 0.7), ("棒", 0.6),
+    ],
+    quick_negative: &[
+        ("难过", 0.8), ("伤心", 0.9), ("生气", 0.8), ("烦", 0.6), ("哭", 0.8), ("累", 0.5),
+        ("emo", 0.7), ("崩溃", 1.0), ("委屈", 0.8), ("焦虑", 0.7), ("害怕", 0.8),
+    ],
+};
+
+// ── 英文 ──
+
+const ENGLISH: LanguagePack = LanguagePack {
+    language: Language::English,
+    word_delimited: true,
+    length_scale: 3,
+    emotion_lexicon: &[
+        ("joy", 0, &[
+            ("happy", 0.8), ("glad", 0.7), ("joy", 0.9), ("haha", 0.7), ("hahaha", 0.8),
+            ("lol", 0.6), ("lmao", 0.7), ("yay", 0.8), ("love", 0.9), ("awesome", 0.8),
+            ("great", 0.6), ("amazing", 0.8), ("wonderful", 0.8), ("thanks", 0.4),
+            ("thank you", 0.5), ("nice", 0.5), ("cool", 0.4), ("fun", 0.6), ("excited", 0.8),
+            ("delighted", 0.9), ("cheerful", 0.7), ("sweet", 0.6), ("perfect", 0.7),
+            ("so happy", 1.0), ("best day", 0.9), ("hehe", 0.6), ("smile", 0.5), (":)", 0.5),
+        ]),
+        ("sadness", 1, &[
+            ("sad", 0.8), ("unhappy", 0.8), ("upset", 0.7), ("depressed", 1.0), ("cry", 0.8),
+            ("crying", 0.9), ("tears", 0.7), ("lonely", 0.8), ("alone", 0.6), ("hurt", 0.8),
+            ("heartbroken", 1.0), ("miserable", 0.9), ("disappointed", 0.7), ("sigh", 0.5),
+            ("tired", 0.5), ("exhausted", 0.7), ("hopeless", 0.9), ("sorry for myself", 0.7),
+            ("emo", 0.7), ("down", 0.5), ("gloomy", 0.7), ("broken", 0.8), ("can't take it", 0.9),
+            ("whatever", 0.4), ("never mind", 0.5), ("nevermind", 0.5), (":(", 0.6),
+        ]),
+        ("anger", 2, &[
+            ("angry", 0.8), ("mad", 0.7), ("furious", 1.0), ("pissed", 0.9), ("annoyed", 0.6),
+            ("annoying", 0.6), ("hate", 0.8), ("shut up", 0.9), ("go away", 0.8),
+            ("leave me alone", 0.8), ("sick of", 0.8), ("fed up", 0.8), ("enough", 0.6),
+            ("stupid", 0.7), ("idiot", 0.9), ("ugh", 0.5), ("wtf", 0.8), ("damn", 0.6),
+            ("screw you", 1.0), ("get lost", 0.9), ("irritated", 0.6),
+        ]),
+        ("fear", 3, &[
+            ("scared", 0.8), ("afraid", 0.8), ("terrified", 1.0), ("worried", 0.6),
+            ("nervous", 0.6), ("anxious", 0.8), ("panic", 0.8), ("frightened", 0.8),
+            ("uneasy", 0.6), ("what if", 0.5), ("oh no", 0.7), ("what do i do", 0.6),
+            ("freaking out", 0.8), ("shaking", 0.7),
+        ]),
+        ("surprise", 4, &[
+            ("wow", 0.6), ("whoa", 0.7), ("omg", 0.8), ("oh my god", 0.8), ("really", 0.4),
+            ("no way", 0.7), ("seriously", 0.5), ("unbelievable", 0.8), ("what", 0.3),
+            ("surprised", 0.7), ("shocked", 0.8), ("can't believe", 0.7), ("holy", 0.6),
+        ]),
+        ("intimacy", 5, &[
+            ("hug", 0.7), ("hugs", 0.8), ("cuddle", 0.9), ("kiss", 0.8), ("hold me", 0.8),
+            ("hold my hand", 0.8), ("miss you", 0.9), ("i miss you", 1.0), ("stay", 0.5),
+            ("don't go", 0.8), ("come here", 0.5), ("darling", 0.8), ("honey", 0.7),
+            ("babe", 0.7), ("sweetheart", 0.8), ("dear", 0.5), ("love you", 1.0),
+            ("snuggle", 0.8), ("lean on", 0.6), ("close to you", 0.7), ("heartbeat", 0.6),
+        ]),
+        ("trust", 6, &[
+            ("trust", 0.9), ("believe", 0.7), ("rely on", 0.7), ("count on", 0.7),
+            ("safe", 0.6), ("understand", 0.6), ("you're right", 0.6), ("i trust you", 0.9),
+            ("got my back", 0.8), ("i believe you", 0.9), ("reassured", 0.7), ("i know", 0.3),
+        ]),
+        ("anticipation", 7, &[
+            ("can't wait", 0.9), ("looking forward", 0.8), ("hope", 0.6), ("soon", 0.4),
+            ("tomorrow", 0.3), ("next time", 0.4), ("together", 0.5), ("someday", 0.5),
+            ("wish", 0.6), ("want to", 0.5), ("excited for", 0.8), ("when can", 0.5),
+            ("could we", 0.4), ("let's", 0.5),
+        ]),
+    ],
+    negation_prefixes: &[
+        "not ", "not so ", "not really ", "never ", "no ", "don't ", "doesn't ", "didn't ",
+        "isn't ", "wasn't ", "aren't ", "ain't ", "hardly ", "not very ",
+    ],
+    negation_window: 14,
+    negation_phrases: &[
+        "i'm fine", "im fine", "it's fine", "it's nothing", "nothing", "no worries",
+        "doesn't matter", "i don't care", "not really", "i'm okay", "don't need",
+        "i didn't", "no big deal", "forget it",
+    ],
+    sarcasm_markers: &[
+        ("yeah right", 0.9), ("sure", 0.4), ("whatever", 0.6), ("oh great", 0.8),
+        ("how nice", 0.6), ("good for you", 0.8), ("congrats", 0.4), ("wow thanks", 0.7),
+        ("if you say so", 0.8), ("totally", 0.4), ("obviously", 0.5), ("k", 0.4),
+        ("fine by me", 0.6), ("you're so smart", 0.7), ("as if", 0.7),
+    ],
+    hesitation_markers: &[
+        "i...", "never mind", "nevermind", "forget it", "it's nothing", "well...",
+        "um", "umm", "uh", "i mean...", "actually...", "it's just...", "i wanted to say",
+    ],
+    probing_markers: &[
+        "what do you think", "what if", "would you", "do you think", "if i",
+        "do you want", "would you mind", "do you care", "do you like", "is it okay",
+        "can i", "could you", "how would you",
+    ],
+    coquettish_markers: &[
+        "pretty please", "pleeease", "please", "hmph", "meanie", "pout", "aww",
+        "you never", "ignoring me", "notice me", "uwu", ">_<", "qaq",
+    ],
+    defensive_markers: &[
+        "none of your business", "i can handle it", "mind your own", "leave me alone",
+        "i don't need you", "not your problem", "stay out of it", "i didn't do anything",
+        "it's my life", "who asked",
+    ],
+    flat_replies: &["ok", "okay", "k", "fine", "sure", "yeah", "yep", "mhm", "hm", "oh"],
+    avoidance_words: &["change the subject", "let's not", "drop it", "don't want to talk", "something else"],
+    farewell_words: &[
+        "good night", "goodnight", "gn", "bye", "goodbye", "see you", "see ya",
+        "gotta go", "talk later", "ttyl", "going to bed", "night night",
+    ],
+    reconcile_words: &[
+        "sorry", "i apologize", "my bad", "my fault", "forgive me", "i was wrong",
+        "don't be mad", "i shouldn't have",
+    ],
+    playful_words: &["hahaha", "lmao", "just kidding", "jk", "kidding", "gotcha", "teasing", "silly", "dummy"],
+    intimacy_words: &[
+        "babe", "darling", "honey", "sweetheart", "miss you", "hug", "kiss", "cuddle",
+        "love you", "blush", "heartbeat", "warm",
+    ],
+    trust_words: &["trust", "believe", "rely on", "count on", "safe", "understand", "i trust you", "you're right"],
+    conflict_words: &[
+        "angry", "mad", "annoying", "hate", "shut up", "go away", "enough",
+        "whatever", "leave me alone", "ugh", "fine",
+    ],
+    positive_signals: &[
+        "happy", "glad", "haha", "love", "great", "awesome", "nice", "yay",
+        "thanks", "sweet", "warm", "lol",
+    ],
+    negative_signals: &[
+        "sad", "upset", "angry", "annoyed", "tired", "whatever", "sigh", "cry",
+        "hate", "shut up", "enough", "boring", "emo",
+    ],
+    quick_positive: &[
+        ("happy", 0.8), ("glad", 0.7), ("haha", 0.7), ("lol", 0.6), ("love", 0.9),
+        ("sweet", 0.7), ("warm", 0.6), ("yay", 0.7), ("great", 0.6), ("awesome", 0.7),
+    ],
+    quick_negative: &[
+        ("sad", 0.8), ("upset", 0.8), ("angry", 0.8), ("annoyed", 0.6), ("cry", 0.8),
+        ("tired", 0.5), ("emo", 0.7), ("depressed", 1.0), ("hurt", 0.8), ("anxious", 0.7),
+        ("scared", 0.8),
+    ],
+};
+
+// ── 日文 ──
+
+const JAPANESE: LanguagePack = LanguagePack {
+    language: Language::Japanese,
+    word_delimited: false,
+    length_scale: 2,
+    emotion_lexicon: &[
+        ("joy", 0, &[
+            ("嬉しい", 0.8), ("うれしい", 0.8), ("楽しい", 0.8), ("たのしい", 0.8),
+            ("幸せ", 0.95), ("しあわせ", 0.95), ("笑", 0.5), ("ｗｗ", 0.6), ("www", 0.6),
+            ("やった", 0.8), ("最高", 0.9), ("好き", 0.7), ("大好き", 0.9), ("ありがとう", 0.5),
+            ("すごい", 0.6), ("素敵", 0.7), ("よかった", 0.7), ("わーい", 0.8), ("えへへ", 0.6),
+        ]),
+        ("sadness", 1, &[
+            ("悲しい", 0.8), ("かなしい", 0.8), ("寂しい", 0.8), ("さみしい", 0.8),
+            ("辛い", 0.9), ("つらい", 0.9), ("泣", 0.8), ("涙", 0.7), ("落ち込", 0.8),
+            ("しんどい", 0.7), ("疲れた", 0.6), ("残念", 0.6), ("はぁ", 0.5), ("もういい", 0.6),
+            ("楽しくない", 0.7), ("嬉しくない", 0.7), ("ぴえん", 0.6), ("絶望", 1.0),
+        ]),
+        ("anger", 2, &[
+            ("怒", 0.8), ("ムカつく", 0.9), ("むかつく", 0.9), ("うざい", 0.8), ("ウザい", 0.8),
+            ("腹立つ", 0.9), ("ふざけるな", 1.0), ("ふざけんな", 1.0), ("黙れ", 0.9),
+            ("うるさい", 0.8), ("最低", 0.8), ("嫌い", 0.7), ("いい加減にして", 0.9),
+            ("もう知らない", 0.7), ("バカ", 0.6), ("消えて", 1.0),
+        ]),
+        ("fear", 3, &[
+            ("怖い", 0.8), ("こわい", 0.8), ("不安", 0.7), ("心配", 0.6), ("緊張", 0.6),
+            ("やばい", 0.5), ("どうしよう", 0.7), ("恐ろしい", 0.9), ("震え", 0.7),
+        ]),
+        ("surprise", 4, &[
+            ("えっ", 0.6), ("え？", 0.5), ("まさか", 0.7), ("びっくり", 0.8), ("驚", 0.7),
+            ("マジ", 0.6), ("本当に", 0.4), ("うそ", 0.6), ("嘘でしょ", 0.7), ("すげー", 0.6),
+        ]),
+        ("intimacy", 5, &[
+            ("ぎゅ", 0.8), ("抱きし", 0.8), ("会いたい", 0.9), ("そばにいて", 0.9),
+            ("行かないで", 0.8), ("手をつな", 0.8), ("キス", 0.8), ("ちゅ", 0.7),
+            ("大好き", 0.8), ("愛してる", 1.0), ("ドキドキ", 0.7), ("甘え", 0.6),
+        ]),
+        ("trust", 6, &[
+            ("信じ", 0.8), ("信頼", 0.9), ("安心", 0.7), ("任せ", 0.7), ("頼り", 0.7),
+            ("わかってくれ", 0.7), ("あなたがいれば", 0.8),
+        ]),
+        ("anticipation", 7, &[
+            ("楽しみ", 0.8), ("待ちきれない", 0.9), ("期待", 0.8), ("早く", 0.6),
+            ("明日", 0.3), ("今度", 0.4), ("一緒に", 0.5), ("いつか", 0.5), ("したいな", 0.6),
+        ]),
+    ],
+    negation_prefixes: &[],
+    negation_window: 6,
+    negation_phrases: &[
+        "大丈夫", "なんでもない", "何でもない", "別に", "気にしないで", "平気",
+        "関係ない", "いらない", "違う",
+    ],
+    sarcasm_markers: &[
+        ("はいはい", 0.8), ("へー", 0.5), ("ふーん", 0.6), ("よかったね", 0.6),
+        ("さすがですね", 0.7), ("お好きにどうぞ", 0.8), ("勝手にすれば", 0.8),
+    ],
+    hesitation_markers: &[
+        "あの…", "えっと", "なんでもない", "やっぱりいい", "別にいいけど", "その…",
+        "実は…", "言いたいことが",
+    ],
+    probing_markers: &[
+        "どう思う", "もしも", "もし", "してもいい", "いいかな", "嫌じゃない",
+        "好き？", "気になる",
+    ],
+    coquettish_markers: &[
+        "ねぇ", "もう", "ぷん", "むぅ", "構って", "かまって", "ずるい", "ばか",
+        "えー", "やだ",
+    ],
+    defensive_markers: &[
+        "関係ないでしょ", "ほっといて", "放っておいて", "自分でできる", "余計なお世話",
+        "あなたには関係ない",
+    ],
+    flat_replies: &["うん", "そう", "ふーん", "はい", "わかった", "了解", "へー"],
+    avoidance_words: &["話変えよう", "その話はやめて", "話したくない", "別の話"],
+    farewell_words: &["おやすみ", "またね", "バイバイ", "さようなら", "じゃあね", "寝る", "また明日"],
+    reconcile_words: &["ごめん", "すみません", "申し訳", "許して", "私が悪かった", "怒らないで"],
+    playful_words: &["www", "ｗｗ", "冗談", "うそだよ", "からかって", "いじわる"],
+    intimacy_words: &["会いたい", "ぎゅ", "キス", "大好き", "愛してる", "ドキドキ", "照れ", "甘え"],
+    trust_words: &["信じ", "信頼", "安心", "任せ", "頼り"],
+    conflict_words: &["怒", "ムカつく", "うざい", "うるさい", "最低", "嫌い", "もういい", "別に", "はいはい"],
+    positive_signals: &["嬉しい", "楽しい", "好き", "幸せ", "ありがとう", "最高", "よかった", "笑"],
+    negative_signals: &["悲しい", "寂しい", "辛い", "疲れた", "ムカつく", "うざい", "泣", "もういい", "嫌い"],
+    quick_positive: &[
+        ("嬉しい", 0.8), ("楽しい", 0.8), ("好き", 0.7), ("幸せ", 0.9), ("笑", 0.5),
+        ("ありがとう", 0.5), ("最高", 0.8),
+    ],
+    quick_negative: &[
+        ("悲しい", 0.8), ("寂しい", 0.8), ("辛い", 0.9), ("泣", 0.8), ("疲れた", 0.5),
+        ("ムカつく", 0.8), ("怖い", 0.8), ("不安", 0.7),
+    ],
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_language_and_word_matching() {
+        assert_eq!(detect_language("今天好开心"), Language::Chinese);
+        assert_eq!(detect_language("今日はとても楽しい"), Language::Japanese);
+        assert_eq!(detect_language("I'm so happy today"), Language::English);
+        assert_eq!(detect_language("今天emo了"), Language::Chinese);
+        assert_eq!(detect_language("886"), Language::Chinese);
+
+        let english = pack(Language::English);
+        assert!(english.contains("I MISS YOU so much", "miss you"));
+        assert!(!english.contains("he's madder than ever", "mad"));
+        let text = english.normalize("I'm not happy");
+        let pos = english.find(&text, "happy").unwrap();
+        assert!(english.is_negated(&text, pos));
+        assert!(pack(Language::Chinese).is_negated("a不开心", 4));
+    }
+}
@@ -0,0 +1,158 @@
+use super::data_models::{MemorySearchResult, Message, MessageRole, MessageType};
+use super::memory_engine::MemoryEngine;
+use super::text_utils;
+
+// ═══════════════════════════════════════════════════════════════════
+//  离线回声角色 (Local Echo Responder)
+//  ─────────────────────────────────────────────────────────────────
+//  不联网、不需要 API Key 的本地应答器，用于演示模式、集成测试和 UI 开发。
+//  回复完全确定（同样的输入 + 同样的历史 → 同样的回复），组成：
+//    1. 模板：按输入内容的稳定哈希在固定模板里选一条，复述用户原话
+//    2. 检索：从历史用户消息 / 记忆摘要中找关键词重合的一条，引用回来
+//  这样消息存储、轮次计数、记忆索引检索都会被真实走一遍。
+// ═══════════════════════════════════════════════════════════════════
+
+/// 离线回复使用的模型标识（写入消息的 model 字段）
+pub const LOCAL_MODEL_ID: &str = "local-echo";
+
+/// 复述时保留的最大字符数
+const ECHO_MAX_CHARS: usize = 60;
+
+/// 对话（say）模板，{echo} 替换为用户原话
+const SAY_TEMPLATES: &[&str] = &[
+    "你说「{echo}」，我听到啦。",
+    "嗯，「{echo}」——我记下了。",
+    "「{echo}」？再多和我说说吧。",
+    "原来是「{echo}」呀，我在听。",
+];
+
+/// 动作（do）模板
+const DO_TEMPLATES: &[&str] = &[
+    "*看着你* 「{echo}」……我明白了。",
+    "*轻轻点头* 你刚才「{echo}」，我都看到了。",
+    "*歪了歪头* 「{echo}」？",
+];
+
+pub struct LocalResponder;
+
+impl LocalResponder {
+    /// 稳定哈希（FNV-1a），不受进程/平台影响，保证回复确定
+    fn stable_hash(text: &str) -> u64 {
+        text.bytes().fold(0xcbf29ce484222325u64, |hash, b| {
+            (hash ^ b as u64).wrapping_mul(0x100000001b3)
+        })
+    }
+
+    fn excerpt(text: &str) -> String {
+        let trimmed = text.trim().replace('\n', " ");
+        text_utils::ellipsize(&trimmed, ECHO_MAX_CHARS, "…")
+    }
+
+    /// 在更早的用户消息中找关键词重合最多的一条（不含本轮）
+    fn recall_user_message(history: &[Message], user_content: &str) -> Option<String> {
+        let keywords = MemoryEngine::extract_keywords(user_content);
+        if keywords.is_empty() {
+            return None;
+        }
+        let current = user_content.trim();
+        history
+            .iter()
+            .filter(|m| m.role == MessageRole::User && m.content.trim() != current)
+            .map(|m| {
+                let overlap = keywords.iter().filter(|k| m.content.contains(k.as_str())).count();
+                (overlap, m)
+            })
+            .filter(|(overlap, _)| *overlap > 0)
+            // 重合度相同时取最早的一条，保证结果确定
+            .fold(None, |best: Option<(usize, &Message)>, cand| match best {
+                Some(b) if b.0 >= cand.0 => Some(b),
+                _ => Some(cand),
+            })
+            .map(|(_, m)| Self::excerpt(&m.content))
+    }
+
+    /// 生成确定性的离线回复
+    pub fn respond(
+        history: &[Message],
+        user_content: &str,
+        message_type: &MessageType,
+        memories: &[MemorySearchResult],
+    ) -> String {
+        let templates = match message_type {
+            MessageType::Do => DO_TEMPLATES,
+            _ => SAY_TEMPLATES,
+        };
+        let idx = (Self::stable_hash(user_content) % templates.len() as u64) as usize;
+        let mut reply = templates[idx].replace("{echo}", &Self::excerpt(user_content));
+
+        if let Some(memory) = memories.first() {
+            reply.push_str(&format!("\n我还记得：{}", Self::excerpt(&memory.summary)));
+        } else if let Some(earlier) = Self::recall_user_message(history, user_content) {
+            reply.push_str(&format!("\n你之前也说过「{}」。", earlier));
+        }
+
+        reply.push_str("\n（离线演示模式）");
+        reply
+    }
+
+    /// 将回复切成若干片段，模拟流式输出
+    pub fn chunk_reply(reply: &str) -> Vec<String> {
+        let mut chunks = Vec::new();
+        let mut current = String::new();
+        for c in reply.chars() {
+            current.push(c);
+            if matches!(c, '，' | '。' | '？' | '！' | '\n' | '…' | '—') {
+                chunks.push(std::mem::take(&mut current));
+            }
+        }
+        if !current.is_empty() {
+            chunks.push(current);
+        }
+        chunks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_user(content: &str) -> Message {
+        Message {
+            timestamp: 0,
+            ..Message::new(MessageRole::User, content, LOCAL_MODEL_ID)
+        }
+    }
+
+    #[test]
+    fn test_respond_is_deterministic() {
+        let history = vec![make_user("我养了一只猫")];
+        let a = LocalResponder::respond(&history, "今天好累", &MessageType::Say, &[]);
+        let b = LocalResponder::respond(&history, "今天好累", &MessageType::Say, &[]);
+        assert_eq!(a, b);
+        assert!(a.contains("今天好累"));
+    }
+
+    #[test]
+    fn test_respond_recalls_earlier_message_or_memory() {
+        let history = vec![make_user("我养了一只橘猫叫团子"), make_user("团子今天又偷吃了")];
+        let reply =
+            LocalResponder::respond(&history, "团子今天又偷吃了", &MessageType::Say, &[]);
+        assert!(reply.contains("你之前也说过「我养了一只橘猫叫团子」"));
+
+        let memories = vec![MemorySearchResult {
+            summary: "用户养了一只橘猫".to_string(),
+            core_facts: vec![],
+            relevance_score: 1.0,
+        }];
+        let reply = LocalResponder::respond(&history, "团子", &MessageType::Say, &memories);
+        assert!(reply.contains("我还记得：用户养了一只橘猫"));
+    }
+
+    #[test]
+    fn test_chunk_reply_round_trips() {
+        let reply = "你说「好」，我听到啦。\n（离线演示模式）";
+        let chunks = LocalResponder::chunk_reply(reply);
+        assert!(chunks.len() > 1);
+        assert_eq!(chunks.concat(), reply);
+    }
+}
@@ -0,0 +1,243 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::{Mutex, OnceLock};
+
+use tracing::field::{Field, Visit};
+use tracing::{span, Event, Level, Subscriber};
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
+
+// ═══════════════════════════════════════════════════════════════════
+//  结构化日志 (Log Store)
+//  ─────────────────────────────────────────────────────────────────
+//  管线中非致命的失败（摘要、事实提取、决策日志写入、重试……）不再静默吞掉，
+//  而是通过 tracing 记录，并带上所在 span（对话 ID、模型等字段）。
+//  本模块把事件收进内存环形缓冲区，用户反馈问题时由 chat_api::export_logs
+//  导出为纯文本附在报告里。
+//    - 本 crate 的事件记录 DEBUG 及以上，依赖库只记录 WARN 及以上
+//    - 最多保留 MAX_LOG_ENTRIES 条，进程退出即清空
+// ═══════════════════════════════════════════════════════════════════
+
+/// 保留的最近日志条数
+const MAX_LOG_ENTRIES: usize = 1000;
+
+/// 一条日志
+#[derive(Debug, Clone)]
+struct LogEntry {
+    timestamp: i64,
+    level: Level,
+    target: String,
+    /// 从外到内的 span 链，如 `send_message{conversation_id=..} > stream_chat{model=..}`
+    spans: String,
+    message: String,
+}
+
+impl fmt::Display for LogEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let time = chrono::DateTime::from_timestamp_millis(self.timestamp)
+            .map(|t| t.format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string())
+            .unwrap_or_default();
+        write!(f, "{} {:>5} {}", time, self.level, self.target)?;
+        if !self.spans.is_empty() {
+            write!(f, " [{}]", self.spans)?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+static LOG_BUFFER: OnceLock<Mutex<VecDeque<LogEntry>>> = OnceLock::new();
+
+fn log_buffer() -> &'static Mutex<VecDeque<LogEntry>> {
+    LOG_BUFFER.get_or_init(|| Mutex::new(VecDeque::new()))
+}
+
+/// 安装全局 subscriber（重复调用或已有 subscriber 时忽略）
+pub fn init() {
+    let _ = tracing::subscriber::set_global_default(
+        tracing_subscriber::registry().with(RingBufferLayer),
+    );
+}
+
+/// 导出最近的日志（旧的在前），每行一条；limit 为 0 时导出全部
+pub fn export(limit: usize) -> String {
+    let entries = match log_buffer().lock() {
+        Ok(entries) => entries,
+        Err(_) => return String::new(),
+    };
+    let skip = if limit == 0 {
+        0
+    } else {
+        entries.len().saturating_sub(limit)
+    };
+    entries
+        .iter()
+        .skip(skip)
+        .map(|e| e.to_string())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// 清空日志
+pub fn clear() {
+    if let Ok(mut entries) = log_buffer().lock() {
+        entries.clear();
+    }
+}
+
+fn push(entry: LogEntry) {
+    if let Ok(mut entries) = log_buffer().lock() {
+        entries.push_back(entry);
+        while entries.len() > MAX_LOG_ENTRIES {
+            entries.pop_front();
+        }
+    }
+}
+
+/// 事件 / span 字段收集：message 单独保存，其余格式化为 `key=value`
+#[derive(Default)]
+struct FieldVisitor {
+    message: String,
+    fields: Vec<String>,
+}
+
+impl FieldVisitor {
+    fn joined_fields(&self) -> String {
+        self.fields.join(" ")
+    }
+}
+
+impl Visit for FieldVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = value.to_string();
+        } else {
+            self.fields.push(format!("{}={}", field.name(), value));
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.message = format!("{:?}", value);
+        } else {
+            self.fields.push(format!("{}={:?}", field.name(), value));
+        }
+    }
+}
+
+/// 存在 span extensions 中的已格式化字段
+struct SpanFields(String);
+
+struct RingBufferLayer;
+
+impl RingBufferLayer {
+    fn captures(level: &Level, target: &str) -> bool {
+        if target.starts_with(env!("CARGO_CRATE_NAME")) {
+            *level <= Level::DEBUG
+        } else {
+            *level <= Level::WARN
+        }
+    }
+}
+
+impl<S> Layer<S> for RingBufferLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &span::Attributes<'_>, id: &span::Id, ctx: Context<'_, S>) {
+        let mut visitor = FieldVisitor::default();
+        attrs.record(&mut visitor);
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut()
+                .insert(SpanFields(visitor.joined_fields()));
+        }
+    }
+
+    fn on_record(&self, id: &span::Id, values: &span::Record<'_>, ctx: Context<'_, S>) {
+        let mut visitor = FieldVisitor::default();
+        values.record(&mut visitor);
+        if let Some(span) = ctx.span(id) {
+            let mut extensions = span.extensions_mut();
+            match extensions.get_mut::<SpanFields>() {
+                Some(existing) if !existing.0.is_empty() => {
+                    existing.0.push(' ');
+                    existing.0.push_str(&visitor.joined_fields());
+                }
+                Some(existing) => existing.0 = visitor.joined_fields(),
+                None => extensions.insert(SpanFields(visitor.joined_fields())),
+            }
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        if !Self::captures(metadata.level(), metadata.target()) {
+            return;
+        }
+        let mut visitor = FieldVisitor::default();
+        event.record(&mut visitor);
+        let mut message = visitor.message.clone();
+        if !visitor.fields.is_empty() {
+            if !message.is_empty() {
+                message.push(' ');
+            }
+            message.push_str(&visitor.joined_fields());
+        }
+        let spans = ctx
+            .event_scope(event)
+            .map(|scope| {
+                scope
+                    .from_root()
+                    .map(|span| match span.extensions().get::<SpanFields>() {
+                        Some(fields) if !fields.0.is_empty() => {
+                            format!("{}{{{}}}", span.name(), fields.0)
+                        }
+                        _ => span.name().to_string(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" > ")
+            })
+            .unwrap_or_default();
+        push(LogEntry {
+            timestamp: chrono::Utc::now().timestamp_millis(),
+            level: *metadata.level(),
+            target: metadata.target().to_string(),
+            spans,
+            message,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_events_are_captured_with_span_fields() {
+        let marker = uuid::Uuid::new_v4().to_string();
+        let subscriber = tracing_subscriber::registry().with(RingBufferLayer);
+        tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!("send_message", conversation_id = %marker);
+            let _guard = span.enter();
+            let inner = tracing::debug_span!("stream_chat", model = "glm-4.7");
+            let _inner = inner.enter();
+            tracing::warn!(error = "timeout", "摘要失败 {}", marker);
+            tracing::trace!("trace 级别不记录 {}", marker);
+            tracing::warn!(target: "h2::codec", "依赖库 warn 记录 {}", marker);
+            tracing::info!(target: "h2::codec", "依赖库 info 不记录 {}", marker);
+        });
+
+        let exported: Vec<String> = export(0)
+            .lines()
+            .filter(|l| l.contains(&marker))
+            .map(|l| l.to_string())
+            .collect();
+        assert_eq!(exported.len(), 2);
+        assert!(exported[0].contains("WARN"));
+        assert!(exported[0].contains(&format!(
+            "[send_message{{conversation_id={}}} > stream_chat{{model=glm-4.7}}]",
+            marker
+        )));
+        assert!(exported[0].ends_with(&format!("摘要失败 {} error=timeout", marker)));
+        assert!(exported[1].contains("h2::codec"));
+    }
+}
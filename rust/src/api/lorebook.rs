@@ -0,0 +1,261 @@
+use std::fs;
+use std::path::PathBuf;
+
+use flutter_rust_bridge::frb;
+
+use super::data_models::{LoreEntry, Message, MessageRole};
+use super::error_handler::ChatError;
+
+// ═══════════════════════════════════════════════════════════════════
+//  世界设定 (Lorebook)
+//  ─────────────────────────────────────────────────────────────────
+//  知识库里的事实是从对话中抽取的，世界设定则由用户亲手编写：
+//  地名、组织、人物背景、专有名词……只在被提到时才需要让角色知道。
+//    1. 编写：每个条目带触发词、插入深度与优先级
+//    2. 扫描：每轮检查最近 SCAN_MESSAGES 条消息 + 本轮输入，
+//       命中任一触发词（不区分大小写）即入选
+//    3. 预算：按优先级降序装入，总量不超过 LOREBOOK_TOKEN_BUDGET，
+//       与记忆、历史窗口的预算互不挤占
+//    4. 注入：同一深度的条目合并为一条 system 消息，
+//       插在距历史末尾 insertion_depth 条消息处
+//
+//  存储结构：
+//    lorebook/{conversation_id}.json   对话专属条目
+//    lorebook/_global.json             所有对话共享的条目
+// ═══════════════════════════════════════════════════════════════════
+
+/// 全局设定集的存储ID
+pub const GLOBAL_LOREBOOK_ID: &str = "_global";
+/// 扫描触发词的最近消息条数（不含 system）
+const SCAN_MESSAGES: usize = 6;
+/// 世界设定注入的 token 预算（按字节数/2 估算，与上下文构建一致）
+const LOREBOOK_TOKEN_BUDGET: usize = 1500;
+
+#[frb(opaque)]
+pub struct LorebookStore {
+    base_path: String,
+}
+
+impl LorebookStore {
+    pub fn new(base_path: &str) -> Self {
+        Self {
+            base_path: base_path.to_string(),
+        }
+    }
+
+    fn lorebook_dir(&self) -> Result<PathBuf, ChatError> {
+        let dir = PathBuf::from(&self.base_path).join("lorebook");
+        if !dir.exists() {
+            fs::create_dir_all(&dir).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to create lorebook directory: {}", e),
+            })?;
+        }
+        Ok(dir)
+    }
+
+    fn lorebook_path(&self, book_id: &str) -> Result<PathBuf, ChatError> {
+        Ok(self.lorebook_dir()?.join(format!("{}.json", book_id)))
+    }
+
+    pub fn load(&self, book_id: &str) -> Result<Vec<LoreEntry>, ChatError> {
+        let path = self.lorebook_path(book_id)?;
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+        let json = fs::read_to_string(&path).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to read lorebook: {}", e),
+        })?;
+        serde_json::from_str(&json).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to parse lorebook: {}", e),
+        })
+    }
+
+    fn save(&self, book_id: &str, entries: &[LoreEntry]) -> Result<(), ChatError> {
+        let path = self.lorebook_path(book_id)?;
+        let json = serde_json::to_string_pretty(entries).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to serialize lorebook: {}", e),
+        })?;
+        fs::write(&path, json).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to write lorebook: {}", e),
+        })
+    }
+
+    /// 新增或更新条目（按 id 匹配，id 为空时新建）；返回保存后的条目
+    pub fn upsert(&self, book_id: &str, entry: LoreEntry) -> Result<LoreEntry, ChatError> {
+        let mut entry = entry;
+        entry.keywords = entry
+            .keywords
+            .iter()
+            .map(|k| k.trim().to_string())
+            .filter(|k| !k.is_empty())
+            .collect();
+        if entry.keywords.is_empty() {
+            return Err(ChatError::ValidationError {
+                message: "Lore entry needs at least one trigger keyword".to_string(),
+            });
+        }
+        if entry.content.trim().is_empty() {
+            return Err(ChatError::ValidationError {
+                message: "Lore entry content cannot be empty".to_string(),
+            });
+        }
+
+        let mut entries = self.load(book_id)?;
+        match entries.iter().position(|e| !entry.id.is_empty() && e.id == entry.id) {
+            Some(idx) => entries[idx] = entry.clone(),
+            None => {
+                if entry.id.is_empty() {
+                    entry.id = uuid::Uuid::new_v4().to_string();
+                }
+                entries.push(entry.clone());
+            }
+        }
+        self.save(book_id, &entries)?;
+        Ok(entry)
+    }
+
+    /// 移除一个条目；不存在时返回 false
+    pub fn remove(&self, book_id: &str, entry_id: &str) -> Result<bool, ChatError> {
+        let mut entries = self.load(book_id)?;
+        let before = entries.len();
+        entries.retain(|e| e.id != entry_id);
+        if entries.len() == before {
+            return Ok(false);
+        }
+        self.save(book_id, &entries)?;
+        Ok(true)
+    }
+
+    pub fn delete(&self, book_id: &str) -> Result<(), ChatError> {
+        let path = self.lorebook_path(book_id)?;
+        if path.exists() {
+            fs::remove_file(&path).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to delete lorebook: {}", e),
+            })?;
+        }
+        Ok(())
+    }
+
+    /// 对话可用的全部启用条目（全局 + 对话专属）
+    pub fn entries_for(&self, conversation_id: &str) -> Vec<LoreEntry> {
+        let mut entries = self.load(GLOBAL_LOREBOOK_ID).unwrap_or_default();
+        entries.extend(self.load(conversation_id).unwrap_or_default());
+        entries.retain(|e| e.enabled);
+        entries
+    }
+
+    /// 触发词扫描文本：最近几条非 system 消息 + 本轮输入，统一小写
+    pub fn scan_text(messages: &[Message], user_content: &str) -> String {
+        let recent: Vec<&Message> = messages
+            .iter()
+            .filter(|m| m.role != MessageRole::System)
+            .collect();
+        let start = recent.len().saturating_sub(SCAN_MESSAGES);
+        let mut text: String = recent[start..]
+            .iter()
+            .map(|m| m.content.as_str())
+            .collect::<Vec<_>>()
+            .join("\n");
+        text.push('\n');
+        text.push_str(user_content);
+        text.to_lowercase()
+    }
+
+    /// 选出被触发的条目：优先级降序装入预算，装不下的跳过（更小的条目仍可入选）
+    pub fn select<'a>(entries: &'a [LoreEntry], scan_text: &str) -> Vec<&'a LoreEntry> {
+        let mut triggered: Vec<&LoreEntry> = entries
+            .iter()
+            .filter(|e| e.enabled)
+            .filter(|e| {
+                e.keywords.iter().any(|k| {
+                    let k = k.trim().to_lowercase();
+                    !k.is_empty() && scan_text.contains(&k)
+                })
+            })
+            .collect();
+        triggered.sort_by_key(|e| std::cmp::Reverse(e.priority));
+
+        let mut used = 0usize;
+        let mut selected = Vec::new();
+        for entry in triggered {
+            let cost = Self::estimate_tokens(entry);
+            if used + cost > LOREBOOK_TOKEN_BUDGET {
+                continue;
+            }
+            used += cost;
+            selected.push(entry);
+        }
+        selected
+    }
+
+    pub fn estimate_tokens(entry: &LoreEntry) -> usize {
+        (entry.title.len() + entry.content.len()) / 2
+    }
+
+    /// 同一深度条目的提示块
+    pub fn build_prompt(entries: &[&LoreEntry]) -> String {
+        let mut prompt = String::from("【世界设定】以下是与当前对话相关的设定，回复时不得与之矛盾：\n");
+        for entry in entries {
+            if entry.title.trim().is_empty() {
+                prompt.push_str(&format!("· {}\n", entry.content.trim()));
+            } else {
+                prompt.push_str(&format!("· {}：{}\n", entry.title.trim(), entry.content.trim()));
+            }
+        }
+        prompt
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn entry(title: &str, keywords: &[&str], priority: i32, content_len: usize) -> LoreEntry {
+        LoreEntry {
+            id: String::new(),
+            title: title.to_string(),
+            content: "设".repeat(content_len),
+            keywords: keywords.iter().map(|k| k.to_string()).collect(),
+            insertion_depth: 2,
+            priority,
+            enabled: true,
+        }
+    }
+
+    #[test]
+    fn test_upsert_merges_global_and_conversation_books() {
+        let tmp = TempDir::new().unwrap();
+        let store = LorebookStore::new(tmp.path().to_str().unwrap());
+        assert!(store.upsert("c1", entry("空", &["  "], 0, 10)).is_err());
+
+        let saved = store.upsert("c1", entry("雾港", &["雾港"], 0, 10)).unwrap();
+        assert!(!saved.id.is_empty());
+        let mut edited = saved.clone();
+        edited.enabled = false;
+        store.upsert("c1", edited).unwrap();
+        store.upsert(GLOBAL_LOREBOOK_ID, entry("公会", &["公会"], 0, 10)).unwrap();
+
+        let entries = store.entries_for("c1");
+        assert_eq!(entries.len(), 1, "停用条目不参与匹配");
+        assert_eq!(entries[0].title, "公会");
+        assert!(store.remove("c1", &saved.id).unwrap());
+        assert!(store.load("c1").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_select_respects_keywords_priority_and_budget() {
+        let entries = vec![
+            entry("低优先", &["Harbor"], 1, 30),
+            // 占去预算的绝大部分，只剩不到 30 token
+            entry("高优先", &["harbor"], 9, LOREBOOK_TOKEN_BUDGET * 2 / 3 - 23),
+            entry("未触发", &["公会"], 99, 10),
+            entry("小条目", &["港口"], 0, 5),
+        ];
+        let text = LorebookStore::scan_text(&[], "去 HARBOR 的港口看看");
+        let selected = LorebookStore::select(&entries, &text);
+        let titles: Vec<&str> = selected.iter().map(|e| e.title.as_str()).collect();
+        assert_eq!(titles, vec!["高优先", "小条目"]);
+        assert!(LorebookStore::build_prompt(&selected).contains("· 小条目："));
+    }
+}
@@ -1,13 +1,24 @@
+use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
 use std::fs;
+use std::hash::{Hash, Hasher};
 use std::path::PathBuf;
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
 
 use flutter_rust_bridge::frb;
 
 use serde::{Deserialize, Serialize};
 
+use super::at_rest;
+use super::blocking_pool;
+use super::conversation_store::ConversationStore;
 use super::data_models::*;
+use super::embedding::SemanticQuery;
 use super::error_handler::ChatError;
+use super::language_packs;
+use super::schema_migration::{self, SchemaKind};
+use super::store_cache::{self, FileStamp};
+use super::text_utils;
 
 // ═══════════════════════════════════════════════════════════════════
 //  短期记忆与回复指纹 — 追踪对话实时状态
@@ -76,15 +87,25 @@ pub struct RelevanceScore {
     pub final_score: f64,
 }
 
-const SUMMARIZE_INTERVAL: u32 = 10;
-
 /// 触发分级合并的摘要数量阈值
 const TIERED_MERGE_THRESHOLD: usize = 8;
 
+/// 覆盖了用户收藏消息的摘要在检索融合分上的加权倍数
+const STARRED_BOOST: f64 = 1.5;
+
 const BM25_K1: f64 = 1.2;
 const BM25_B: f64 = 0.75;
 
+/// 手动摘要的草稿（对话 ID → 待确认的摘要）：只存在于内存，
+/// 确认后才写入记忆索引，丢弃或应用重启即消失
+static PENDING_SUMMARIES: OnceLock<Mutex<HashMap<String, MemorySummary>>> = OnceLock::new();
+
+fn pending_summaries() -> &'static Mutex<HashMap<String, MemorySummary>> {
+    PENDING_SUMMARIES.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
 #[frb(opaque)]
+#[derive(Clone)]
 pub struct MemoryEngine {
     base_path: String,
 }
@@ -106,8 +127,9 @@ impl MemoryEngine {
         Ok(dir)
     }
 
-    pub fn should_summarize(turn_count: u32) -> bool {
-        turn_count > 0 && turn_count.is_multiple_of(SUMMARIZE_INTERVAL)
+    /// 每 interval 轮生成一次摘要（interval 见 SummarizationConfig）
+    pub fn should_summarize(turn_count: u32, interval: u32) -> bool {
+        interval > 0 && turn_count > 0 && turn_count.is_multiple_of(interval)
     }
 
     /// 根据压缩代数计算影响等级
@@ -175,23 +197,10 @@ impl MemoryEngine {
     }
 
     pub fn extract_keywords(text: &str) -> Vec<String> {
-        let mut keywords = Vec::new();
-        for word in text.split(|c: char| !c.is_alphanumeric() && c != '-' && c != '_') {
-            let w = word.trim().to_lowercase();
-            if w.len() >= 2 && !is_stop_word(&w) {
-                keywords.push(w);
-            }
-        }
-        let chars: Vec<char> = text
-            .chars()
-            .filter(|c| c.is_alphabetic() || *c > '\u{4e00}')
+        let mut keywords: Vec<String> = segment(text)
+            .into_iter()
+            .filter(|w| w.len() >= 2 && !is_stop_word(w))
             .collect();
-        for window in chars.windows(2) {
-            let bigram: String = window.iter().collect();
-            if bigram.chars().any(|c| c > '\u{4e00}') {
-                keywords.push(bigram);
-            }
-        }
         keywords.sort();
         keywords.dedup();
         keywords
@@ -239,6 +248,9 @@ impl MemoryEngine {
                 MessageType::Say => "[说]",
                 MessageType::Do => "[做]",
                 MessageType::Mixed => "[混合]",
+                MessageType::Document => "[正文]",
+                MessageType::Narration => "[旁白]",
+                MessageType::OutOfCharacter => "[场外]",
             };
             prompt.push_str(&format!("{}{}: {}\n", role, type_tag, msg.content));
         }
@@ -425,16 +437,26 @@ impl MemoryEngine {
         semantic_weight: f64,
         k: f64,
     ) -> Vec<(usize, f64)> {
-        let mut fusion_scores: HashMap<usize, f64> = HashMap::new();
+        Self::weighted_rrf_fusion_multi(
+            &[(bm25_ranks, bm25_weight), (semantic_ranks, semantic_weight)],
+            k,
+        )
+    }
 
-        for (rank, (doc_idx, _score)) in bm25_ranks.iter().enumerate() {
-            let rrf = bm25_weight / (k + rank as f64 + 1.0);
-            *fusion_scores.entry(*doc_idx).or_insert(0.0) += rrf;
-        }
+    /// 多路加权 RRF：每路为 (按得分降序的排名列表, 权重)
+    /// 得分为 0 的条目不占名次，避免某一路完全未命中的文档靠列表顺序白拿分
+    pub fn weighted_rrf_fusion_multi(
+        rankings: &[(&[(usize, f64)], f64)],
+        k: f64,
+    ) -> Vec<(usize, f64)> {
+        let mut fusion_scores: HashMap<usize, f64> = HashMap::new();
 
-        for (rank, (doc_idx, _score)) in semantic_ranks.iter().enumerate() {
-            let rrf = semantic_weight / (k + rank as f64 + 1.0);
-            *fusion_scores.entry(*doc_idx).or_insert(0.0) += rrf;
+        for (ranks, weight) in rankings {
+            let hits = ranks.iter().filter(|(_, score)| *score > 0.0);
+            for (rank, (doc_idx, _score)) in hits.enumerate() {
+                let rrf = weight / (k + rank as f64 + 1.0);
+                *fusion_scores.entry(*doc_idx).or_insert(0.0) += rrf;
+            }
         }
 
         let mut results: Vec<(usize, f64)> = fusion_scores.into_iter().collect();
@@ -532,33 +554,38 @@ impl MemoryEngine {
     }
 
     /// 将文本转换为混合特征向量（字符 unigram + bigram + trigram + 关键词）
-    /// 中文字符使用 unigram 和 bigram，关键词提供语义粒度
+    /// 中文字符使用 unigram 和 bigram，关键词提供语义粒度；
+    /// 启用 jieba 时只用分词结果，避免字符 n-gram 稀释相似度
     fn text_to_hybrid_features(text: &str) -> Vec<String> {
-        let chars: Vec<char> = text
-            .chars()
-            .filter(|c| c.is_alphanumeric() || (*c > '\u{4e00}' && *c < '\u{9fff}'))
-            .collect();
-
         let mut features = Vec::new();
 
-        // 中文字符 unigram
-        for c in &chars {
-            if *c > '\u{4e00}' && *c < '\u{9fff}' {
-                features.push(c.to_string());
+        // 字符 n-gram 只在没有分词器时使用：jieba 切出的词已覆盖语义单位
+        #[cfg(not(feature = "jieba"))]
+        {
+            let chars: Vec<char> = text
+                .chars()
+                .filter(|c| c.is_alphanumeric() || is_cjk(*c))
+                .collect();
+
+            // 中文字符 unigram
+            for c in &chars {
+                if is_cjk(*c) {
+                    features.push(c.to_string());
+                }
             }
-        }
 
-        // 字符 bigram（覆盖中英文）
-        if chars.len() >= 2 {
-            for window in chars.windows(2) {
-                features.push(window.iter().collect::<String>());
+            // 字符 bigram（覆盖中英文）
+            if chars.len() >= 2 {
+                for window in chars.windows(2) {
+                    features.push(window.iter().collect::<String>());
+                }
             }
-        }
 
-        // 字符 trigram（提供更多语境信息）
-        if chars.len() >= 3 {
-            for window in chars.windows(3) {
-                features.push(window.iter().collect::<String>());
+            // 字符 trigram（提供更多语境信息）
+            if chars.len() >= 3 {
+                for window in chars.windows(3) {
+                    features.push(window.iter().collect::<String>());
+                }
             }
         }
 
@@ -600,17 +627,19 @@ impl MemoryEngine {
         let keywords = Self::extract_keywords(text);
         topics.extend(keywords);
 
-        // 提取中文短语（2-4 字组合）作为话题
-        let chars: Vec<char> = text.chars().collect();
-        for window_size in 2..=4 {
-            if chars.len() >= window_size {
-                for window in chars.windows(window_size) {
-                    let phrase: String = window.iter().collect();
-                    // 只保留包含中文字符且不全是停用词的短语
-                    if phrase.chars().any(|c| c > '\u{4e00}' && c < '\u{9fff}')
-                        && !is_stop_word(&phrase)
-                    {
-                        topics.push(phrase);
+        // 没有分词器时提取中文短语（2-4 字组合）作为话题；
+        // jieba 切出的词本身就是话题单位，不再枚举滑窗
+        #[cfg(not(feature = "jieba"))]
+        {
+            let chars: Vec<char> = text.chars().collect();
+            for window_size in 2..=4 {
+                if chars.len() >= window_size {
+                    for window in chars.windows(window_size) {
+                        let phrase: String = window.iter().collect();
+                        // 只保留包含中文字符且不全是停用词的短语
+                        if phrase.chars().any(is_cjk) && !is_stop_word(&phrase) {
+                            topics.push(phrase);
+                        }
                     }
                 }
             }
@@ -663,8 +692,22 @@ impl MemoryEngine {
         active_topics: &[String],
         user_content: &str,
     ) -> f64 {
+        Self::compute_relevance(fact, active_topics, user_content).final_score
+    }
+
+    /// 同 compute_relevance_score，附带各维度得分
+    pub fn compute_relevance(
+        fact: &str,
+        active_topics: &[String],
+        user_content: &str,
+    ) -> RelevanceScore {
         if fact.is_empty() || (active_topics.is_empty() && user_content.is_empty()) {
-            return 0.0;
+            return RelevanceScore {
+                tfidf_score: 0.0,
+                keyword_overlap: 0.0,
+                topic_match: 0.0,
+                final_score: 0.0,
+            };
         }
 
         // 维度1：TF-IDF 余弦相似度（事实 vs 用户消息）
@@ -699,7 +742,12 @@ impl MemoryEngine {
         // 综合评分：TF-IDF 40% + 关键词重叠 40% + 包含检测 20%
         let final_score = tfidf_score * 0.4 + keyword_overlap * 0.4 + containment_score * 0.2;
 
-        final_score.clamp(0.0, 1.0)
+        RelevanceScore {
+            tfidf_score,
+            keyword_overlap,
+            topic_match: containment_score,
+            final_score: final_score.clamp(0.0, 1.0),
+        }
     }
 
     // ═══════════════════════════════════════════════════════════════
@@ -731,7 +779,7 @@ impl MemoryEngine {
 
         // 平均句子长度
         let sentences: Vec<&str> = content
-            .split(|c: char| c == '。' || c == '！' || c == '？' || c == '\n')
+            .split(['。', '！', '？', '\n'])
             .filter(|s| !s.trim().is_empty())
             .collect();
         let avg_sentence_len = if sentences.is_empty() {
@@ -982,10 +1030,10 @@ impl MemoryEngine {
         // 检测未展开的话题线索
         let pending_threads = Self::detect_pending_threads(&non_system);
 
-        // 收集 AI 回复的结构指纹
+        // 收集 AI 回复的结构指纹（用户亲自撰写的回复不代表模型的习惯，不计入）
         let response_fingerprints: Vec<ResponseFingerprint> = non_system
             .iter()
-            .filter(|m| m.role == MessageRole::Assistant)
+            .filter(|m| m.role == MessageRole::Assistant && !ConversationStore::is_user_authored(m))
             .rev()
             .take(5)
             .map(|m| Self::fingerprint_response(&m.content))
@@ -1004,46 +1052,16 @@ impl MemoryEngine {
 
     /// 快速情绪扫描（轻量级，用于短期记忆）
     fn quick_emotion_scan(text: &str) -> (f64, f64, String) {
-        let positive_words = [
-            ("开心", 0.8),
-            ("高兴", 0.8),
-            ("笑", 0.5),
-            ("哈哈", 0.7),
-            ("喜欢", 0.7),
-            ("爱", 0.9),
-            ("甜", 0.7),
-            ("暖", 0.6),
-            ("嘿嘿", 0.6),
-            ("耶", 0.7),
-            ("棒", 0.6),
-        ];
-        let negative_words = [
-            ("难过", 0.8),
-            ("伤心", 0.9),
-            ("生气", 0.8),
-            ("烦", 0.6),
-            ("哭", 0.8),
-            ("累", 0.5),
-            ("emo", 0.7),
-            ("崩溃", 1.0),
-            ("委屈", 0.8),
-            ("焦虑", 0.7),
-            ("害怕", 0.8),
-        ];
-
-        let mut pos_score = 0.0f64;
-        let mut neg_score = 0.0f64;
-
-        for &(word, weight) in &positive_words {
-            if text.contains(word) {
-                pos_score += weight;
-            }
-        }
-        for &(word, weight) in &negative_words {
-            if text.contains(word) {
-                neg_score += weight;
-            }
-        }
+        let pack = language_packs::pack_for(text);
+        let score = |words: &[(&str, f64)]| -> f64 {
+            words
+                .iter()
+                .filter(|(word, _)| pack.contains(text, word))
+                .map(|(_, weight)| weight)
+                .sum()
+        };
+        let pos_score = score(pack.quick_positive);
+        let neg_score = score(pack.quick_negative);
 
         let valence = if pos_score + neg_score > 0.0 {
             (pos_score - neg_score) / (pos_score + neg_score)
@@ -1155,6 +1173,39 @@ impl MemoryEngine {
         query: &str,
         summaries: &[MemorySummary],
         top_k: usize,
+    ) -> Vec<MemorySearchResult> {
+        Self::search_memories_semantic(query, summaries, top_k, None, &[])
+    }
+
+    /// 用户收藏的消息所在的轮次（第 N 条用户消息及其回复算第 N 轮，与 turn_count 计数一致）。
+    /// 早期消息可能已移入冷存储，轮次从 turn_count 倒推（同 ConversationStore::turn_at）
+    pub fn starred_turns(messages: &[Message], turn_count: u32) -> Vec<u32> {
+        let live_turns = messages
+            .iter()
+            .filter(|m| m.role == MessageRole::User)
+            .count() as u32;
+        let mut turn = turn_count.saturating_sub(live_turns);
+        let mut starred = Vec::new();
+        for msg in messages {
+            if msg.role == MessageRole::User {
+                turn += 1;
+            }
+            let turn = turn.max(1);
+            if msg.starred && starred.last() != Some(&turn) {
+                starred.push(turn);
+            }
+        }
+        starred
+    }
+
+    /// 检索记忆：BM25 + 关键词余弦，有向量时再融合一路向量余弦（按摘要ID取向量）；
+    /// 轮次范围覆盖 starred_turns 的摘要按 STARRED_BOOST 加权，用户喜欢的时刻更容易被想起
+    pub fn search_memories_semantic(
+        query: &str,
+        summaries: &[MemorySummary],
+        top_k: usize,
+        semantic: Option<&SemanticQuery>,
+        starred_turns: &[u32],
     ) -> Vec<MemorySearchResult> {
         if summaries.is_empty() {
             return Vec::new();
@@ -1220,7 +1271,32 @@ impl MemoryEngine {
             .collect();
         semantic_scores.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
 
-        let fused = Self::weighted_rrf_fusion(&bm25_scores, &semantic_scores, 0.6, 0.4, 60.0);
+        let vector_scores =
+            semantic.and_then(|q| q.rank(summaries.iter().map(|s| s.id.as_str())));
+        let mut fused = match &vector_scores {
+            // 向量能召回换了说法的旧事，权重与 BM25 相当
+            Some(vector_scores) => Self::weighted_rrf_fusion_multi(
+                &[
+                    (&bm25_scores, 0.4),
+                    (&semantic_scores, 0.2),
+                    (vector_scores, 0.4),
+                ],
+                60.0,
+            ),
+            None => Self::weighted_rrf_fusion(&bm25_scores, &semantic_scores, 0.6, 0.4, 60.0),
+        };
+        if !starred_turns.is_empty() {
+            for (idx, score) in fused.iter_mut() {
+                let s = &summaries[*idx];
+                if starred_turns
+                    .iter()
+                    .any(|t| (s.turn_range_start..=s.turn_range_end).contains(t))
+                {
+                    *score *= STARRED_BOOST;
+                }
+            }
+            fused.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        }
 
         fused
             .into_iter()
@@ -1242,10 +1318,13 @@ impl MemoryEngine {
     ///   1. 对每条核心事实进行排级分类（Identity > CriticalEvent > RelationshipDynamic > CurrentState > SceneDetail）
     ///   2. 按排级从低到高合并：先合并 SceneDetail，再合并 CurrentState，直到数量降到目标值
     ///   3. Identity 和 CriticalEvent 级别的事实永远独立保留，不参与合并
+    ///   4. 用户置顶的摘要整条跳过合并；置顶的核心事实无论排级都原样带入合并结果
     ///
     /// 核心原则：关键信息绝对无损，只压缩低优先级的冗余信息
+    ///
+    /// 只按未置顶的摘要计数：置顶摘要不会被合并，计入阈值会让合并每轮都被触发却什么也不做
     pub fn should_tiered_merge(summaries: &[MemorySummary]) -> bool {
-        summaries.len() >= TIERED_MERGE_THRESHOLD
+        summaries.iter().filter(|s| !s.pinned).count() >= TIERED_MERGE_THRESHOLD
     }
 
     /// 对单条核心事实进行排级分类
@@ -1296,24 +1375,42 @@ impl MemoryEngine {
     /// 执行分级合并：将多条摘要按排级策略合并为更少的条目
     /// 返回合并后的摘要列表 + 用于 LLM 合并的 prompt（如果需要 LLM 辅助）
     pub fn tiered_merge(summaries: &[MemorySummary]) -> (Vec<MemorySummary>, Option<String>) {
-        if summaries.len() < TIERED_MERGE_THRESHOLD {
+        if !Self::should_tiered_merge(summaries) {
             return (summaries.to_vec(), None);
         }
 
-        // 第一步：提取所有核心事实并分级
+        // 置顶摘要不参与合并；最新的摘要保持独立
+        let latest = summaries.last().cloned();
+        let (kept, older): (Vec<&MemorySummary>, Vec<&MemorySummary>) = summaries
+            .iter()
+            .take(summaries.len().saturating_sub(1))
+            .partition(|s| s.pinned);
+
+        if older.is_empty() {
+            return (summaries.to_vec(), None);
+        }
+
+        // 第一步：提取所有核心事实并分级（置顶事实单独保留，不参与去重与取舍）
+        let mut pinned_facts: Vec<(String, MemoryTier)> = Vec::new();
         let mut identity_facts: Vec<String> = Vec::new();
         let mut critical_facts: Vec<String> = Vec::new();
         let mut relationship_facts: Vec<String> = Vec::new();
         let mut state_facts: Vec<String> = Vec::new();
         let mut scene_facts: Vec<String> = Vec::new();
 
-        for summary in summaries {
+        for summary in &older {
             for (i, fact) in summary.core_facts.iter().enumerate() {
                 let tier = if i < summary.fact_tiers.len() {
                     summary.fact_tiers[i].clone()
                 } else {
                     Self::classify_fact_tier(fact)
                 };
+                if summary.pinned_facts.contains(fact) {
+                    if !pinned_facts.iter().any(|(f, _)| f == fact) {
+                        pinned_facts.push((fact.clone(), tier));
+                    }
+                    continue;
+                }
                 match tier {
                     MemoryTier::Identity => identity_facts.push(fact.clone()),
                     MemoryTier::CriticalEvent => critical_facts.push(fact.clone()),
@@ -1339,20 +1436,10 @@ impl MemoryEngine {
         let state_facts = Self::deduplicate_state_facts(&state_facts);
 
         // 第三步：将摘要按时间分组合并
-        // 保留最新的 1 条摘要不动，其余合并为 1-2 条
+        // 保留最新的 1 条摘要与置顶摘要不动，其余合并为一条"历史总览"
         let max_gen = summaries.iter().map(|s| s.compression_generation).max().unwrap_or(0);
         let merge_gen = max_gen + 1;
 
-        // 最新的摘要保持独立
-        let latest = summaries.last().cloned();
-
-        // 其余摘要合并为一条"历史总览"
-        let older: Vec<&MemorySummary> = summaries.iter().take(summaries.len().saturating_sub(1)).collect();
-
-        if older.is_empty() {
-            return (summaries.to_vec(), None);
-        }
-
         // 合并所有旧摘要的 summary 为时间线
         let merged_summary: String = older.iter()
             .map(|s| s.summary.as_str())
@@ -1361,7 +1448,7 @@ impl MemoryEngine {
 
         // 截断合并后的 summary（保持精炼）
         let merged_summary = if merged_summary.chars().count() > 150 {
-            format!("{}...", merged_summary.chars().take(147).collect::<String>())
+            text_utils::ellipsize(&merged_summary, 147, "...")
         } else {
             merged_summary
         };
@@ -1370,6 +1457,10 @@ impl MemoryEngine {
         let mut merged_facts: Vec<String> = Vec::new();
         let mut merged_tiers: Vec<MemoryTier> = Vec::new();
 
+        for (f, tier) in &pinned_facts {
+            merged_facts.push(f.clone());
+            merged_tiers.push(tier.clone());
+        }
         for f in &identity_facts {
             merged_facts.push(f.clone());
             merged_tiers.push(MemoryTier::Identity);
@@ -1398,6 +1489,14 @@ impl MemoryEngine {
         merged_keywords.sort();
         merged_keywords.dedup();
 
+        // 合并事实链接：先全部继承，丢弃与否由 KnowledgeStore::cross_link 按合并后的内容重新判定
+        let mut merged_links: Vec<String> = Vec::new();
+        for id in older.iter().flat_map(|s| s.linked_fact_ids.iter()) {
+            if !merged_links.contains(id) {
+                merged_links.push(id.clone());
+            }
+        }
+
         // 构建合并后的上下文卡片
         let merged_card = Self::build_context_card_from_facts(&merged_facts, turn_start, turn_end);
 
@@ -1412,12 +1511,20 @@ impl MemoryEngine {
             compression_generation: merge_gen,
             context_card: Some(merged_card),
             fact_tiers: merged_tiers,
+            linked_fact_ids: merged_links,
+            pinned: false,
+            pinned_facts: pinned_facts.into_iter().map(|(f, _)| f).collect(),
+            // 过时的内容合并进来后整条仍需重新总结
+            stale: older.iter().any(|s| s.stale),
         };
 
+        // 合并条目与置顶摘要按起始轮次排回时间线（置顶摘要可能早于合并范围）
         let mut result = vec![merged_entry];
+        result.extend(kept.into_iter().cloned());
         if let Some(latest) = latest {
             result.push(latest);
         }
+        result.sort_by_key(|s| s.turn_range_start);
 
         // 如果合并后仍然超过目标，生成 LLM 辅助合并 prompt
         let needs_llm = result.iter()
@@ -1453,6 +1560,7 @@ impl MemoryEngine {
         prompt.push_str("以下记忆需要进一步精炼，但必须遵守排级保护规则：\n\n");
 
         prompt.push_str("■ 绝对保护（不可修改、不可合并、不可省略）：\n");
+        prompt.push_str("  - 所有 📌置顶 的记忆与事实\n");
         prompt.push_str("  - 所有 [身份] 类事实\n");
         prompt.push_str("  - 所有 [事件] 类不可逆转折\n");
         prompt.push_str("  - 所有承诺/约定/金额\n\n");
@@ -1462,9 +1570,12 @@ impl MemoryEngine {
         prompt.push_str("  - [状态] 类事实（只保留当前状态）\n\n");
 
         for (i, s) in summaries.iter().enumerate() {
-            prompt.push_str(&format!("记忆{}. [轮{}-{}] {}\n", i + 1, s.turn_range_start, s.turn_range_end, s.summary));
+            let pin_tag = if s.pinned { "📌置顶 " } else { "" };
+            prompt.push_str(&format!("记忆{}. {}[轮{}-{}] {}\n", i + 1, pin_tag, s.turn_range_start, s.turn_range_end, s.summary));
             for (j, fact) in s.core_facts.iter().enumerate() {
-                let tier_tag = if j < s.fact_tiers.len() {
+                let tier_tag = if s.pinned || s.pinned_facts.contains(fact) {
+                    " 📌置顶"
+                } else if j < s.fact_tiers.len() {
                     match &s.fact_tiers[j] {
                         MemoryTier::Identity => " 🔒身份",
                         MemoryTier::CriticalEvent => " 🔒事件",
@@ -1489,7 +1600,7 @@ impl MemoryEngine {
 }
 
 要求：
-1. 🔒标记的事实必须原样保留，一字不改
+1. 🔒与📌标记的事实必须原样保留，一字不改；📌置顶的记忆整条不参与合并
 2. 🔄标记的事实可以合并同类项，但不可丢弃
 3. ⏳标记的事实只保留最新状态
 4. 💨标记的事实可以省略
@@ -1603,6 +1714,23 @@ impl MemoryEngine {
         text
     }
 
+    /// 摘要的检索关键词：摘要正文 + 核心事实，去重排序
+    pub fn summary_keywords(summary: &str, core_facts: &[String]) -> Vec<String> {
+        let mut keywords = Self::extract_keywords(summary);
+        for fact in core_facts {
+            keywords.extend(Self::extract_keywords(fact));
+        }
+        keywords.sort();
+        keywords.dedup();
+        keywords
+    }
+
+    /// 群聊角色的记忆命名空间：记忆索引、知识库与向量都以它代替对话ID存放，
+    /// 每个角色只记得自己视角下的对话（仍以对话ID开头，沙盒判定照常生效）
+    pub fn character_scope(conversation_id: &str, character_id: &str) -> String {
+        format!("{}@{}", conversation_id, character_id)
+    }
+
     pub fn save_memory_index(
         &self,
         conversation_id: &str,
@@ -1610,13 +1738,13 @@ impl MemoryEngine {
     ) -> Result<(), ChatError> {
         let dir = self.memory_dir()?;
         let path = dir.join(format!("{}.json", conversation_id));
-        let json =
-            serde_json::to_string_pretty(summaries).map_err(|e| ChatError::StorageError {
-                message: format!("Failed to serialize memory index: {}", e),
-            })?;
-        fs::write(&path, json).map_err(|e| ChatError::StorageError {
+        let json = schema_migration::encode(SchemaKind::MemoryIndex, summaries)?;
+        let written = at_rest::write(&path, json).map_err(|e| ChatError::StorageError {
             message: format!("Failed to write memory index: {}", e),
-        })
+        });
+        let stamp = written.as_ref().ok().and_then(|_| FileStamp::of(&path));
+        store_cache::memory_indexes().put(&path, stamp, summaries.to_vec());
+        written
     }
 
     pub fn load_memory_index(
@@ -1628,24 +1756,181 @@ impl MemoryEngine {
         if !path.exists() {
             return Ok(Vec::new());
         }
-        let json = fs::read_to_string(&path).map_err(|e| ChatError::StorageError {
-            message: format!("Failed to read memory index: {}", e),
+        if let Some(summaries) = store_cache::memory_indexes().get(&path) {
+            return Ok(summaries);
+        }
+        let stamp = FileStamp::of(&path);
+        let (summaries, upgraded) = at_rest::load(&path, "memory index", |data| {
+            schema_migration::decode(SchemaKind::MemoryIndex, data)
         })?;
-        serde_json::from_str(&json).map_err(|e| ChatError::StorageError {
-            message: format!("Failed to parse memory index: {}", e),
-        })
+        if upgraded {
+            // 旧格式读取成功后立即按当前版本回写，下次不必再迁移
+            self.save_memory_index(conversation_id, &summaries)?;
+        } else {
+            store_cache::memory_indexes().put(&path, stamp, summaries.clone());
+        }
+        Ok(summaries)
+    }
+
+    /// 置顶 / 取消置顶整条摘要（fact 为 None）或其中一条核心事实，返回更新后的摘要列表；
+    /// 摘要或事实不存在时返回 None
+    pub fn set_pinned(
+        &self,
+        conversation_id: &str,
+        summary_id: &str,
+        fact: Option<&str>,
+        pinned: bool,
+    ) -> Result<Option<Vec<MemorySummary>>, ChatError> {
+        let mut summaries = self.load_memory_index(conversation_id)?;
+        let summary = match summaries.iter_mut().find(|s| s.id == summary_id) {
+            Some(s) => s,
+            None => return Ok(None),
+        };
+        match fact {
+            None => summary.pinned = pinned,
+            Some(fact) => {
+                if !summary.core_facts.iter().any(|f| f == fact) {
+                    return Ok(None);
+                }
+                summary.pinned_facts.retain(|f| f != fact);
+                if pinned {
+                    summary.pinned_facts.push(fact.to_string());
+                }
+            }
+        }
+        self.save_memory_index(conversation_id, &summaries)?;
+        Ok(Some(summaries))
+    }
+
+    /// 用户修改摘要正文与核心事实：关键词与上下文卡片随之重建，
+    /// 未改动的事实保留原排级与置顶，新事实重新排级；摘要不存在时返回 None
+    pub fn edit_summary(
+        &self,
+        conversation_id: &str,
+        summary_id: &str,
+        text: &str,
+        core_facts: &[String],
+    ) -> Result<Option<Vec<MemorySummary>>, ChatError> {
+        let text = text.trim();
+        if text.is_empty() {
+            return Err(ChatError::ValidationError {
+                message: "Memory summary cannot be empty".to_string(),
+            });
+        }
+        let facts: Vec<String> = core_facts
+            .iter()
+            .map(|f| f.trim().to_string())
+            .filter(|f| !f.is_empty())
+            .collect();
+        let mut summaries = self.load_memory_index(conversation_id)?;
+        let summary = match summaries.iter_mut().find(|s| s.id == summary_id) {
+            Some(s) => s,
+            None => return Ok(None),
+        };
+        let tiers: Vec<MemoryTier> = facts
+            .iter()
+            .map(|fact| {
+                summary
+                    .core_facts
+                    .iter()
+                    .position(|f| f == fact)
+                    .and_then(|i| summary.fact_tiers.get(i).cloned())
+                    .unwrap_or_else(|| Self::classify_fact_tier(fact))
+            })
+            .collect();
+        summary.summary = text.to_string();
+        summary.pinned_facts.retain(|f| facts.contains(f));
+        summary.core_facts = facts;
+        summary.fact_tiers = tiers;
+        summary.keywords = Self::summary_keywords(&summary.summary, &summary.core_facts);
+        summary.context_card = Some(Self::build_context_card(summary));
+        self.save_memory_index(conversation_id, &summaries)?;
+        Ok(Some(summaries))
+    }
+
+    /// 删除一条摘要；不存在时返回 None
+    pub fn delete_summary(
+        &self,
+        conversation_id: &str,
+        summary_id: &str,
+    ) -> Result<Option<Vec<MemorySummary>>, ChatError> {
+        let mut summaries = self.load_memory_index(conversation_id)?;
+        let before = summaries.len();
+        summaries.retain(|s| s.id != summary_id);
+        if summaries.len() == before {
+            return Ok(None);
+        }
+        self.save_memory_index(conversation_id, &summaries)?;
+        Ok(Some(summaries))
+    }
+
+    /// 手动调整一条核心事实的排级（决定它在分级合并中是否被压缩）；
+    /// 摘要或事实不存在时返回 None
+    pub fn set_fact_tier(
+        &self,
+        conversation_id: &str,
+        summary_id: &str,
+        fact: &str,
+        tier: MemoryTier,
+    ) -> Result<Option<Vec<MemorySummary>>, ChatError> {
+        let mut summaries = self.load_memory_index(conversation_id)?;
+        let summary = match summaries.iter_mut().find(|s| s.id == summary_id) {
+            Some(s) => s,
+            None => return Ok(None),
+        };
+        let index = match summary.core_facts.iter().position(|f| f == fact) {
+            Some(i) => i,
+            None => return Ok(None),
+        };
+        // 旧数据的排级可能缺失或短于事实列表，先按自动排级补齐
+        while summary.fact_tiers.len() < summary.core_facts.len() {
+            let missing = &summary.core_facts[summary.fact_tiers.len()];
+            summary.fact_tiers.push(Self::classify_fact_tier(missing));
+        }
+        summary.fact_tiers[index] = tier;
+        self.save_memory_index(conversation_id, &summaries)?;
+        Ok(Some(summaries))
+    }
+
+    /// 暂存对话的摘要草稿（替换之前未确认的草稿）
+    pub fn stage_summary_draft(conversation_id: &str, draft: MemorySummary) {
+        if let Ok(mut pending) = pending_summaries().lock() {
+            pending.insert(conversation_id.to_string(), draft);
+        }
+    }
+
+    /// 对话当前待确认的摘要草稿
+    pub fn summary_draft(conversation_id: &str) -> Option<MemorySummary> {
+        pending_summaries().lock().ok()?.get(conversation_id).cloned()
+    }
+
+    /// 取出待确认的草稿；summary_id 与当前草稿不符（已被新草稿替换）时不取出
+    pub fn take_summary_draft(conversation_id: &str, summary_id: &str) -> Option<MemorySummary> {
+        let mut pending = pending_summaries().lock().ok()?;
+        if pending.get(conversation_id)?.id != summary_id {
+            return None;
+        }
+        pending.remove(conversation_id)
+    }
+
+    /// 丢弃待确认的草稿；没有草稿时返回 false
+    pub fn discard_summary_draft(conversation_id: &str) -> bool {
+        pending_summaries()
+            .lock()
+            .map(|mut pending| pending.remove(conversation_id).is_some())
+            .unwrap_or(false)
     }
 
     pub fn delete_memory_index(&self, conversation_id: &str) -> Result<(), ChatError> {
         let dir = self.memory_dir()?;
         let path = dir.join(format!("{}.json", conversation_id));
-        if path.exists() {
-            fs::remove_file(&path).map_err(|e| ChatError::StorageError {
-                message: format!("Failed to delete memory index: {}", e),
-            })?;
-        }
+        at_rest::remove(&path).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to delete memory index: {}", e),
+        })?;
         // 同时清除蒸馏状态（记忆清除后蒸馏缓存已失效）
-        let _ = self.delete_distilled_state(conversation_id);
+        if let Err(e) = self.delete_distilled_state(conversation_id) {
+            tracing::warn!(conversation_id, error = %e, "蒸馏状态清除失败");
+        }
         Ok(())
     }
 
@@ -1660,13 +1945,11 @@ impl MemoryEngine {
         if !path.exists() {
             return Ok(None);
         }
-        let json = fs::read_to_string(&path).map_err(|e| ChatError::StorageError {
-            message: format!("Failed to read distilled state: {}", e),
-        })?;
-        let state: DistilledSystemState =
-            serde_json::from_str(&json).map_err(|e| ChatError::StorageError {
+        let state: DistilledSystemState = at_rest::load(&path, "distilled state", |data| {
+            serde_json::from_slice(data).map_err(|e| ChatError::StorageError {
                 message: format!("Failed to parse distilled state: {}", e),
-            })?;
+            })
+        })?;
         Ok(Some(state))
     }
 
@@ -1682,25 +1965,195 @@ impl MemoryEngine {
             serde_json::to_string_pretty(state).map_err(|e| ChatError::StorageError {
                 message: format!("Failed to serialize distilled state: {}", e),
             })?;
-        fs::write(&path, json).map_err(|e| ChatError::StorageError {
+        at_rest::write(&path, json).map_err(|e| ChatError::StorageError {
             message: format!("Failed to write distilled state: {}", e),
         })
     }
 
+    /// 角色设定（第一条非场外的系统消息）的哈希：设定改动后蒸馏缓存随之过期
+    pub fn character_prompt_hash(messages: &[Message]) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        messages
+            .iter()
+            .find(|m| m.role == MessageRole::System && m.message_type != MessageType::OutOfCharacter)
+            .map(|m| m.content.as_str())
+            .unwrap_or_default()
+            .hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// 由蒸馏结果构造持久化状态（记录蒸馏时的记忆数量、压缩代数与角色设定哈希）
+    pub fn distilled_state(
+        core_prompt: &str,
+        summaries: &[MemorySummary],
+        character_prompt_hash: u64,
+        turn_count: u32,
+    ) -> DistilledSystemState {
+        DistilledSystemState {
+            core_prompt: core_prompt.to_string(),
+            last_memory_count: summaries.len(),
+            last_max_compression_gen: summaries
+                .iter()
+                .map(|s| s.compression_generation)
+                .max()
+                .unwrap_or(0),
+            character_prompt_hash,
+            last_turn_count: turn_count,
+            distilled_at: chrono::Utc::now().timestamp_millis(),
+            core_facts_snapshot: summaries.iter().flat_map(|s| s.core_facts.clone()).collect(),
+        }
+    }
+
+    /// 蒸馏状态是否已过期：角色设定被改动，或记忆摘要的数量 / 最大压缩代数与蒸馏时不同
+    pub fn distilled_state_is_stale(
+        state: &DistilledSystemState,
+        character_prompt_hash: u64,
+        summaries: &[MemorySummary],
+    ) -> bool {
+        let max_gen = summaries
+            .iter()
+            .map(|s| s.compression_generation)
+            .max()
+            .unwrap_or(0);
+        state.character_prompt_hash != character_prompt_hash
+            || state.last_memory_count != summaries.len()
+            || state.last_max_compression_gen != max_gen
+    }
+
+    /// 读取仍然有效的蒸馏状态；已过期的就地删除并返回 None（下次需要时重新蒸馏）
+    pub fn load_fresh_distilled_state(
+        &self,
+        conversation_id: &str,
+        character_prompt_hash: u64,
+        summaries: &[MemorySummary],
+    ) -> Option<DistilledSystemState> {
+        let state = self.load_distilled_state(conversation_id).ok()??;
+        if Self::distilled_state_is_stale(&state, character_prompt_hash, summaries) {
+            if let Err(e) = self.delete_distilled_state(conversation_id) {
+                tracing::debug!(conversation_id, error = %e, "过期蒸馏状态删除失败");
+            }
+            return None;
+        }
+        Some(state)
+    }
+
     /// 删除蒸馏状态文件（重启剧情或清除记忆时调用）
     pub fn delete_distilled_state(&self, conversation_id: &str) -> Result<(), ChatError> {
         let dir = self.memory_dir()?;
         let path = dir.join(format!("{}_distilled.json", conversation_id));
-        if path.exists() {
-            fs::remove_file(&path).map_err(|e| ChatError::StorageError {
-                message: format!("Failed to delete distilled state: {}", e),
-            })?;
+        at_rest::remove(&path).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to delete distilled state: {}", e),
+        })
+    }
+}
+
+// ── 分词 ──
+// 关键词提取（BM25 词项）、TF-IDF 特征与话题提取共用同一套分词：
+//   - 启用 `jieba` feature：jieba 搜索引擎模式切词，长词同时给出其中的短词
+//   - 默认：按非字母数字切分，中文再补充字符 bigram（词表无关，但词项膨胀）
+// 停用词 = 内置词表 + 数据目录 stop_words/ 下各语言的自定义词表（不区分语言合并）。
+// 切换分词方式或停用词后，知识库倒排索引按 tokenizer_fingerprint() 重新提取词项。
+
+/// 当前分词方式的标识
+#[cfg(feature = "jieba")]
+const SEGMENTER: &str = "jieba";
+#[cfg(not(feature = "jieba"))]
+const SEGMENTER: &str = "bigram";
+
+#[derive(Default)]
+struct CustomStopWords {
+    words: std::collections::HashSet<String>,
+    fingerprint: u64,
+}
+
+static CUSTOM_STOP_WORDS: OnceLock<RwLock<Arc<CustomStopWords>>> = OnceLock::new();
+
+fn custom_stop_words() -> Arc<CustomStopWords> {
+    CUSTOM_STOP_WORDS
+        .get_or_init(|| RwLock::new(Arc::new(CustomStopWords::default())))
+        .read()
+        .unwrap()
+        .clone()
+}
+
+/// 替换进程内的自定义停用词（语言代码 → 词表，见 ConfigManager::load_stop_words），
+/// 下一次关键词提取生效
+pub fn install_stop_words(lists: HashMap<String, Vec<String>>) {
+    let mut words: Vec<String> = lists
+        .into_values()
+        .flatten()
+        .map(|w| w.trim().to_lowercase())
+        .filter(|w| !w.is_empty())
+        .collect();
+    words.sort();
+    words.dedup();
+    // 没有自定义停用词时指纹为 0，与未安装时一致，不触发索引重建
+    let fingerprint = if words.is_empty() {
+        0
+    } else {
+        let mut hasher = DefaultHasher::new();
+        words.hash(&mut hasher);
+        hasher.finish()
+    };
+    let custom = CustomStopWords {
+        fingerprint,
+        words: words.into_iter().collect(),
+    };
+    let slot = CUSTOM_STOP_WORDS.get_or_init(|| RwLock::new(Arc::new(CustomStopWords::default())));
+    *slot.write().unwrap() = Arc::new(custom);
+}
+
+/// 分词方式与停用词的指纹：任一变化都意味着同一段文本会提取出不同的词项
+pub fn tokenizer_fingerprint() -> u64 {
+    let mut hasher = DefaultHasher::new();
+    SEGMENTER.hash(&mut hasher);
+    custom_stop_words().fingerprint.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(not(feature = "jieba"))]
+fn is_cjk(c: char) -> bool {
+    c > '\u{4e00}' && c < '\u{9fff}'
+}
+
+/// 切分出的词（小写）；未过滤停用词与长度
+#[cfg(feature = "jieba")]
+fn segment(text: &str) -> Vec<String> {
+    static JIEBA: OnceLock<jieba_rs::Jieba> = OnceLock::new();
+    let jieba = JIEBA.get_or_init(jieba_rs::Jieba::new);
+    jieba
+        .cut_for_search(text, true)
+        .into_iter()
+        .map(|w| w.trim().to_lowercase())
+        .filter(|w| w.chars().any(|c| c.is_alphanumeric()))
+        .collect()
+}
+
+#[cfg(not(feature = "jieba"))]
+fn segment(text: &str) -> Vec<String> {
+    let mut words: Vec<String> = text
+        .split(|c: char| !c.is_alphanumeric() && c != '-' && c != '_')
+        .map(|w| w.trim().to_lowercase())
+        .filter(|w| !w.is_empty())
+        .collect();
+    let chars: Vec<char> = text
+        .chars()
+        .filter(|c| c.is_alphabetic() || *c > '\u{4e00}')
+        .collect();
+    for window in chars.windows(2) {
+        let bigram: String = window.iter().collect();
+        if bigram.chars().any(|c| c > '\u{4e00}') {
+            words.push(bigram);
         }
-        Ok(())
     }
+    words
 }
 
 fn is_stop_word(word: &str) -> bool {
+    is_builtin_stop_word(word) || custom_stop_words().words.contains(word)
+}
+
+fn is_builtin_stop_word(word: &str) -> bool {
     matches!(
         word,
         "the"
@@ -1819,19 +2272,150 @@ fn is_stop_word(word: &str) -> bool {
     )
 }
 
+// ── 对外镜像（data_models 中的稳定版本） ──
+
+impl From<&ResponseFingerprint> for FingerprintInsight {
+    fn from(f: &ResponseFingerprint) -> Self {
+        Self {
+            opening_chars: f.opening_chars.clone(),
+            paragraph_count: f.paragraph_count as u32,
+            avg_sentence_len: f.avg_sentence_len,
+            ending_chars: f.ending_chars.clone(),
+            ends_with_question: f.ends_with_question,
+            total_length: f.total_length as u32,
+            has_action_marker: f.has_action_marker,
+            has_list_format: f.has_list_format,
+            emotional_tone: f.emotional_tone.clone(),
+        }
+    }
+}
+
+impl From<&ShortTermContext> for ShortTermInsight {
+    fn from(ctx: &ShortTermContext) -> Self {
+        Self {
+            active_topics: ctx.active_topics.clone(),
+            emotional_arc: ctx
+                .emotional_arc
+                .iter()
+                .map(|s| EmotionalTurnInsight {
+                    turn: s.turn,
+                    valence: s.valence,
+                    arousal: s.arousal,
+                    dominant_emotion: s.dominant_emotion.clone(),
+                })
+                .collect(),
+            pending_threads: ctx.pending_threads.clone(),
+            response_fingerprints: ctx.response_fingerprints.iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<&RelevanceScore> for RelevanceInsight {
+    fn from(score: &RelevanceScore) -> Self {
+        Self {
+            tfidf_score: score.tfidf_score,
+            keyword_overlap: score.keyword_overlap,
+            topic_match: score.topic_match,
+            final_score: score.final_score,
+        }
+    }
+}
+
+// ── 异步接口：生成管线里的记忆索引读取放到 blocking 线程池 ──
+
+impl MemoryEngine {
+    pub async fn load_memory_index_async(
+        &self,
+        conversation_id: &str,
+    ) -> Result<Vec<MemorySummary>, ChatError> {
+        let engine = self.clone();
+        let id = conversation_id.to_string();
+        blocking_pool::offload("memory_index_load", move || engine.load_memory_index(&id)).await
+    }
+
+    pub async fn load_fresh_distilled_state_async(
+        &self,
+        conversation_id: &str,
+        character_prompt_hash: u64,
+        summaries: &[MemorySummary],
+    ) -> Option<DistilledSystemState> {
+        let engine = self.clone();
+        let id = conversation_id.to_string();
+        let summaries = summaries.to_vec();
+        blocking_pool::offload("distilled_state_load", move || {
+            engine.load_fresh_distilled_state(&id, character_prompt_hash, &summaries)
+        })
+        .await
+    }
+
+    pub async fn save_distilled_state_async(
+        &self,
+        conversation_id: &str,
+        state: &DistilledSystemState,
+    ) -> Result<(), ChatError> {
+        let engine = self.clone();
+        let id = conversation_id.to_string();
+        let state = state.clone();
+        blocking_pool::offload("distilled_state_write", move || {
+            engine.save_distilled_state(&id, &state)
+        })
+        .await
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+
+    #[test]
+    fn test_relevance_breakdown_and_short_term_mirror() {
+        let topics = vec!["猫咪".to_string()];
+        let score = MemoryEngine::compute_relevance("用户养了一只猫咪叫年糕", &topics, "年糕今天又拆家了");
+        assert_eq!(
+            score.final_score,
+            MemoryEngine::compute_relevance_score("用户养了一只猫咪叫年糕", &topics, "年糕今天又拆家了")
+        );
+        assert!(score.final_score > 0.0);
+        let insight = RelevanceInsight::from(&score);
+        assert_eq!(insight.keyword_overlap, score.keyword_overlap);
+        assert_eq!(MemoryEngine::compute_relevance("", &topics, "x").final_score, 0.0);
+
+        let messages = vec![
+            Message {
+                id: "1".to_string(),
+                timestamp: 0,
+                ..Message::new(MessageRole::User, "今天好开心，去看了海", "")
+            },
+            Message {
+                id: "2".to_string(),
+                timestamp: 0,
+                ..Message::new(MessageRole::Assistant, "*眨眨眼* 海边冷不冷？", "")
+            },
+        ];
+        let ctx = MemoryEngine::build_short_term_context(&messages);
+        let mirror = ShortTermInsight::from(&ctx);
+        assert_eq!(mirror.active_topics, ctx.active_topics);
+        assert_eq!(mirror.emotional_arc.len(), ctx.emotional_arc.len());
+        assert_eq!(mirror.response_fingerprints.len(), 1);
+        assert!(mirror.response_fingerprints[0].has_action_marker);
+    }
+
     #[test]
     fn test_should_summarize() {
-        assert!(!MemoryEngine::should_summarize(0));
-        assert!(!MemoryEngine::should_summarize(5));
-        assert!(!MemoryEngine::should_summarize(8));
-        assert!(!MemoryEngine::should_summarize(15));
-        assert!(MemoryEngine::should_summarize(10));
-        assert!(MemoryEngine::should_summarize(20));
-        assert!(MemoryEngine::should_summarize(30));
+        let interval = SummarizationConfig::default().interval_turns;
+        assert!(!MemoryEngine::should_summarize(0, interval));
+        assert!(!MemoryEngine::should_summarize(5, interval));
+        assert!(!MemoryEngine::should_summarize(8, interval));
+        assert!(!MemoryEngine::should_summarize(15, interval));
+        assert!(MemoryEngine::should_summarize(10, interval));
+        assert!(MemoryEngine::should_summarize(20, interval));
+        assert!(MemoryEngine::should_summarize(30, interval));
+        // 重度角色扮演每 30 轮、轻量闲聊每 5 轮
+        assert!(!MemoryEngine::should_summarize(20, 30));
+        assert!(MemoryEngine::should_summarize(30, 30));
+        assert!(MemoryEngine::should_summarize(15, 5));
+        assert!(!MemoryEngine::should_summarize(10, 0));
     }
 
     #[test]
@@ -1850,6 +2434,49 @@ mod tests {
         assert!(!kw.is_empty());
     }
 
+    #[test]
+    fn test_custom_stop_words_are_filtered() {
+        let text = "Zorblax 喵呜喵呜 lighthouse";
+        let before = MemoryEngine::extract_keywords(text);
+        assert!(before.contains(&"zorblax".to_string()));
+        let fingerprint = tokenizer_fingerprint();
+
+        let mut lists = HashMap::new();
+        lists.insert("en".to_string(), vec!["ZORBLAX".to_string()]);
+        lists.insert("zh".to_string(), vec!["喵呜".to_string()]);
+        install_stop_words(lists);
+        let after = MemoryEngine::extract_keywords(text);
+        let changed = tokenizer_fingerprint();
+        install_stop_words(HashMap::new());
+
+        assert!(!after.contains(&"zorblax".to_string()));
+        assert!(!after.contains(&"喵呜".to_string()));
+        assert!(after.contains(&"lighthouse".to_string()));
+        assert_ne!(changed, fingerprint);
+        assert_eq!(tokenizer_fingerprint(), fingerprint);
+    }
+
+    #[cfg(feature = "jieba")]
+    #[test]
+    fn test_jieba_segmentation_is_shared() {
+        let text = "我们明天去北京大学图书馆看书";
+        let kw = MemoryEngine::extract_keywords(text);
+        // 搜索引擎模式：长词与其中的短词都保留
+        assert!(kw.contains(&"北京大学图书馆".to_string()));
+        assert!(kw.contains(&"图书馆".to_string()));
+        assert!(kw.contains(&"北京".to_string()));
+        // 不再产生跨词的字符 bigram
+        assert!(!kw.contains(&"天去".to_string()));
+        assert!(!kw.contains(&"学图".to_string()));
+
+        let topics = MemoryEngine::extract_active_topics_from_text(text);
+        assert!(topics.contains(&"图书馆".to_string()));
+        assert!(!topics.iter().any(|t| t == "去北京"));
+
+        let features = MemoryEngine::text_to_hybrid_features(text);
+        assert!(features.iter().all(|f| kw.contains(f)));
+    }
+
     #[test]
     fn test_bm25_score_basic() {
         let query = vec!["hello".to_string(), "world".to_string()];
@@ -1906,6 +2533,10 @@ mod tests {
                 compression_generation: 0,
                 context_card: None,
                 fact_tiers: vec![MemoryTier::Identity],
+                linked_fact_ids: vec![],
+                pinned: false,
+                pinned_facts: Vec::new(),
+                stale: false,
             },
             MemorySummary {
                 id: "2".to_string(),
@@ -1918,6 +2549,10 @@ mod tests {
                 compression_generation: 0,
                 context_card: None,
                 fact_tiers: vec![MemoryTier::CurrentState],
+                linked_fact_ids: vec![],
+                pinned: false,
+                pinned_facts: Vec::new(),
+                stale: false,
             },
         ];
 
@@ -1925,4 +2560,250 @@ mod tests {
         assert!(!results.is_empty());
         assert!(results[0].summary.contains("编程"));
     }
+    #[test]
+    fn test_search_memories_semantic_recalls_paraphrase() {
+        let make = |id: &str, summary: &str, keyword: &str| MemorySummary {
+            id: id.to_string(),
+            summary: summary.to_string(),
+            core_facts: vec![],
+            turn_range_start: 1,
+            turn_range_end: 10,
+            created_at: 0,
+            keywords: vec![keyword.to_string()],
+            compression_generation: 0,
+            context_card: None,
+            fact_tiers: vec![],
+            linked_fact_ids: vec![],
+            pinned: false,
+            pinned_facts: Vec::new(),
+            stale: false,
+        };
+        let summaries = vec![
+            make("a", "两人讨论了编程", "编程"),
+            make("b", "用户对猫毛过敏", "过敏"),
+        ];
+
+        // 字面上都不命中
+        let lexical = MemoryEngine::search_memories("一抱小动物就打喷嚏", &summaries, 1);
+        assert!(lexical.is_empty());
+
+        let mut vectors = HashMap::new();
+        vectors.insert("a".to_string(), vec![0.0, 1.0]);
+        vectors.insert("b".to_string(), vec![0.9, 0.1]);
+        let semantic = SemanticQuery {
+            query: vec![1.0, 0.0],
+            vectors,
+        };
+        let results = MemoryEngine::search_memories_semantic(
+            "一抱小动物就打喷嚏",
+            &summaries,
+            1,
+            Some(&semantic),
+            &[],
+        );
+        assert_eq!(results.len(), 1);
+        assert!(results[0].summary.contains("猫毛"));
+    }
+
+    #[test]
+    fn test_starred_moments_rank_higher() {
+        let make = |id: &str, turn: u32, keywords: &[&str]| MemorySummary {
+            id: id.to_string(),
+            summary: format!("第{}轮一起看了海", turn),
+            core_facts: vec![],
+            turn_range_start: turn,
+            turn_range_end: turn + 4,
+            created_at: 0,
+            keywords: keywords.iter().map(|k| k.to_string()).collect(),
+            compression_generation: 0,
+            context_card: None,
+            fact_tiers: vec![],
+            linked_fact_ids: vec![],
+            pinned: false,
+            pinned_facts: Vec::new(),
+            stale: false,
+        };
+        // b 的关键词更杂，未加权时排在 a 后面
+        let summaries = vec![make("a", 1, &["看海"]), make("b", 6, &["看海", "散步", "晚饭"])];
+        let plain = MemoryEngine::search_memories("看海", &summaries, 1);
+        assert!(plain[0].summary.contains("第1轮"));
+
+        let message = |role: MessageRole, starred: bool| Message {
+            id: String::new(),
+            timestamp: 0,
+            starred,
+            ..Message::new(role, "", "")
+        };
+        let mut messages = Vec::new();
+        for turn in 1..=7 {
+            messages.push(message(MessageRole::User, false));
+            messages.push(message(MessageRole::Assistant, turn == 7));
+        }
+        let starred = MemoryEngine::starred_turns(&messages, 7);
+        assert_eq!(starred, vec![7]);
+        // 前几轮移入冷存储后轮次不变
+        assert_eq!(MemoryEngine::starred_turns(&messages[6..], 7), vec![7]);
+        let boosted = MemoryEngine::search_memories_semantic("看海", &summaries, 1, None, &starred);
+        assert!(boosted[0].summary.contains("第6轮"));
+    }
+
+    #[test]
+    fn test_edit_delete_and_retier_summaries() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let engine = MemoryEngine::new(tmp.path().to_str().unwrap());
+        let summary = |id: &str| MemorySummary {
+            id: id.to_string(),
+            summary: "两人在咖啡馆初遇".to_string(),
+            core_facts: vec!["小林→职业→画家".to_string(), "窗外→正在→下雨".to_string()],
+            turn_range_start: 1,
+            turn_range_end: 10,
+            created_at: 0,
+            keywords: vec![],
+            compression_generation: 0,
+            context_card: None,
+            fact_tiers: vec![MemoryTier::Identity],
+            linked_fact_ids: vec![],
+            pinned: false,
+            pinned_facts: vec!["窗外→正在→下雨".to_string()],
+            stale: false,
+        };
+        engine
+            .save_memory_index("c", &[summary("a"), summary("b")])
+            .unwrap();
+
+        assert!(engine.edit_summary("c", "a", "  ", &[]).is_err());
+        let facts = vec!["小林→职业→画家".to_string(), " 小林→喜欢→看海 ".to_string()];
+        let edited = engine
+            .edit_summary("c", "a", "两人在海边咖啡馆初遇", &facts)
+            .unwrap()
+            .unwrap();
+        assert_eq!(edited[0].core_facts[1], "小林→喜欢→看海");
+        assert_eq!(edited[0].fact_tiers[0], MemoryTier::Identity);
+        assert!(edited[0].pinned_facts.is_empty());
+        assert!(edited[0].keywords.iter().any(|k| k.contains("海")));
+
+        let retiered = engine
+            .set_fact_tier("c", "b", "窗外→正在→下雨", MemoryTier::CriticalEvent)
+            .unwrap()
+            .unwrap();
+        assert_eq!(retiered[1].fact_tiers.len(), 2);
+        assert_eq!(retiered[1].fact_tiers[1], MemoryTier::CriticalEvent);
+        assert!(engine.set_fact_tier("c", "b", "不存在", MemoryTier::Identity).unwrap().is_none());
+
+        let remaining = engine.delete_summary("c", "a").unwrap().unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert!(engine.delete_summary("c", "a").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_distilled_state_expires_on_prompt_or_memory_change() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let engine = MemoryEngine::new(tmp.path().to_str().unwrap());
+        let summary = MemorySummary {
+            id: "s".to_string(),
+            summary: "初遇".to_string(),
+            core_facts: vec![],
+            turn_range_start: 1,
+            turn_range_end: 10,
+            created_at: 0,
+            keywords: vec![],
+            compression_generation: 0,
+            context_card: None,
+            fact_tiers: vec![],
+            linked_fact_ids: vec![],
+            pinned: false,
+            pinned_facts: Vec::new(),
+            stale: false,
+        };
+        let hash = MemoryEngine::character_prompt_hash(&[Message::system_prompt("你是小林")]);
+        let summaries = vec![summary.clone()];
+        let state = MemoryEngine::distilled_state("核心状态", &summaries, hash, 12);
+        engine.save_distilled_state("c", &state).unwrap();
+        assert!(engine.load_fresh_distilled_state("c", hash, &summaries).is_some());
+
+        // 记忆数量变化：过期并被删除
+        let grown = vec![summary.clone(), summary];
+        assert!(engine.load_fresh_distilled_state("c", hash, &grown).is_none());
+        assert!(engine.load_distilled_state("c").unwrap().is_none());
+
+        engine.save_distilled_state("c", &state).unwrap();
+        let edited =
+            MemoryEngine::character_prompt_hash(&[Message::system_prompt("你是小林，喜欢看海")]);
+        assert!(engine.load_fresh_distilled_state("c", edited, &summaries).is_none());
+    }
+
+    #[test]
+    fn test_summary_draft_is_taken_only_by_matching_id() {
+        let draft = |id: &str| MemorySummary {
+            id: id.to_string(),
+            summary: "两人约好周末去看海".to_string(),
+            core_facts: vec![],
+            turn_range_start: 1,
+            turn_range_end: 4,
+            created_at: 0,
+            keywords: vec![],
+            compression_generation: 0,
+            context_card: None,
+            fact_tiers: vec![],
+            linked_fact_ids: vec![],
+            pinned: false,
+            pinned_facts: Vec::new(),
+            stale: false,
+        };
+        let conv_id = "draft-test-conv";
+        MemoryEngine::stage_summary_draft(conv_id, draft("old"));
+        MemoryEngine::stage_summary_draft(conv_id, draft("new"));
+        assert!(MemoryEngine::take_summary_draft(conv_id, "old").is_none());
+        assert_eq!(MemoryEngine::summary_draft(conv_id).unwrap().id, "new");
+        assert_eq!(MemoryEngine::take_summary_draft(conv_id, "new").unwrap().id, "new");
+        assert!(MemoryEngine::summary_draft(conv_id).is_none());
+        assert!(!MemoryEngine::discard_summary_draft(conv_id));
+    }
+
+    #[test]
+    fn test_tiered_merge_keeps_pinned_entries() {
+        let mut summaries: Vec<MemorySummary> = (0..10u32)
+            .map(|i| MemorySummary {
+                id: format!("s{}", i),
+                summary: format!("第{}段闲聊", i),
+                core_facts: vec![format!("窗外→第{}次→下雨", i)],
+                turn_range_start: i * 5 + 1,
+                turn_range_end: i * 5 + 5,
+                created_at: 0,
+                keywords: vec![],
+                compression_generation: 0,
+                context_card: None,
+                fact_tiers: vec![MemoryTier::SceneDetail],
+                linked_fact_ids: vec![],
+                pinned: false,
+                pinned_facts: Vec::new(),
+                stale: false,
+            })
+            .collect();
+        summaries[1].pinned_facts = vec!["窗外→第1次→下雨".to_string()];
+        summaries[0].pinned = true;
+        summaries[3].pinned = true;
+        summaries[2].stale = true;
+
+        let (merged, _) = MemoryEngine::tiered_merge(&summaries);
+        let ids: Vec<&str> = merged.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(merged.len(), 4);
+        // 按起始轮次排列：早于合并范围的置顶摘要排在最前
+        assert_eq!(ids[0], "s0");
+        assert_eq!(&ids[2..], &["s3", "s9"]);
+        assert_eq!(merged[2], summaries[3]);
+        // 场景细节本应丢弃，置顶后原样保留并继续标记为置顶
+        assert_eq!(merged[1].core_facts, vec!["窗外→第1次→下雨".to_string()]);
+        assert_eq!(merged[1].pinned_facts, merged[1].core_facts);
+        // 编辑过时的摘要并入后，合并结果整条等待重新总结
+        assert!(merged[1].stale);
+        assert!(!MemoryEngine::should_tiered_merge(&merged));
+
+        // 旧摘要全部置顶时无可合并，也就不再触发合并
+        for summary in summaries.iter_mut().take(9) {
+            summary.pinned = true;
+        }
+        assert!(!MemoryEngine::should_tiered_merge(&summaries));
+        assert_eq!(MemoryEngine::tiered_merge(&summaries).0, summaries);
+    }
 }
@@ -0,0 +1,199 @@
+use std::collections::VecDeque;
+use std::future::Future;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::Instant;
+
+use super::data_models::{MetricPhase, MetricsSnapshot, PhaseDuration, TurnMetrics};
+
+// ═══════════════════════════════════════════════════════════════════
+//  性能指标 (Metrics)
+//  ─────────────────────────────────────────────────────────────────
+//  供调试面板查看一轮回复慢在哪里：
+//    - 首字延迟：从开始处理到第一个思考 / 正文 delta 推送给 UI
+//    - 分阶段耗时：检索、长上下文蒸馏、推理、对话生成（同一阶段多次执行时累加）
+//    - 重试次数：本轮内 RetryHandler 的重试
+//  一轮回复由 track_turn 包裹，管线各处通过任务局部的记录器上报，
+//  不在任何一轮之内的调用（后台补跑、测试）直接忽略。
+//  最近 MAX_RECENT_TURNS 轮保存在内存中，进程退出即清空。
+// ═══════════════════════════════════════════════════════════════════
+
+/// 保留的最近轮次数
+const MAX_RECENT_TURNS: usize = 100;
+
+struct TurnRecorder {
+    metrics: TurnMetrics,
+    started: Instant,
+}
+
+tokio::task_local! {
+    static CURRENT_TURN: Arc<Mutex<TurnRecorder>>;
+}
+
+static RECENT_TURNS: OnceLock<Mutex<VecDeque<TurnMetrics>>> = OnceLock::new();
+
+fn recent_turns() -> &'static Mutex<VecDeque<TurnMetrics>> {
+    RECENT_TURNS.get_or_init(|| Mutex::new(VecDeque::new()))
+}
+
+fn with_turn(f: impl FnOnce(&mut TurnRecorder)) {
+    let _ = CURRENT_TURN.try_with(|turn| {
+        if let Ok(mut turn) = turn.lock() {
+            f(&mut turn);
+        }
+    });
+}
+
+/// 以一轮回复为单位记录指标：turn 结束后写入最近轮次
+pub async fn track_turn<T>(conversation_id: &str, turn: impl Future<Output = T>) -> T {
+    let recorder = Arc::new(Mutex::new(TurnRecorder {
+        metrics: TurnMetrics {
+            conversation_id: conversation_id.to_string(),
+            started_at: chrono::Utc::now().timestamp_millis(),
+            total_ms: 0,
+            first_token_ms: None,
+            phases: Vec::new(),
+            retries: 0,
+        },
+        started: Instant::now(),
+    }));
+    let result = CURRENT_TURN.scope(recorder.clone(), turn).await;
+    if let Ok(recorder) = recorder.lock() {
+        let mut metrics = recorder.metrics.clone();
+        metrics.total_ms = recorder.started.elapsed().as_millis() as i64;
+        if let Ok(mut turns) = recent_turns().lock() {
+            turns.push_back(metrics);
+            while turns.len() > MAX_RECENT_TURNS {
+                turns.pop_front();
+            }
+        }
+    }
+    result
+}
+
+/// 第一个 delta 推送给 UI 时调用（之后的调用忽略）
+pub fn mark_first_token() {
+    with_turn(|turn| {
+        if turn.metrics.first_token_ms.is_none() {
+            turn.metrics.first_token_ms = Some(turn.started.elapsed().as_millis() as i64);
+        }
+    });
+}
+
+/// RetryHandler 每次重试前调用
+pub fn record_retry() {
+    with_turn(|turn| turn.metrics.retries += 1);
+}
+
+/// 阶段计时：drop 时把耗时累加到本轮的该阶段
+pub struct PhaseTimer {
+    phase: MetricPhase,
+    started: Instant,
+}
+
+impl PhaseTimer {
+    pub fn start(phase: MetricPhase) -> Self {
+        Self {
+            phase,
+            started: Instant::now(),
+        }
+    }
+}
+
+impl Drop for PhaseTimer {
+    fn drop(&mut self) {
+        let elapsed = self.started.elapsed().as_millis() as i64;
+        with_turn(|turn| {
+            match turn.metrics.phases.iter_mut().find(|p| p.phase == self.phase) {
+                Some(existing) => existing.duration_ms += elapsed,
+                None => turn.metrics.phases.push(PhaseDuration {
+                    phase: self.phase,
+                    duration_ms: elapsed,
+                }),
+            }
+        });
+    }
+}
+
+/// 最近轮次（新的在前）与汇总
+pub fn snapshot() -> MetricsSnapshot {
+    let turns: Vec<TurnMetrics> = recent_turns()
+        .lock()
+        .map(|t| t.iter().rev().cloned().collect())
+        .unwrap_or_default();
+    let mean = |values: Vec<i64>| -> Option<i64> {
+        (!values.is_empty()).then(|| values.iter().sum::<i64>() / values.len() as i64)
+    };
+    let avg_first_token_ms = mean(turns.iter().filter_map(|t| t.first_token_ms).collect());
+    let avg_phase_ms = [
+        MetricPhase::Retrieval,
+        MetricPhase::Distillation,
+        MetricPhase::Reasoning,
+        MetricPhase::Chat,
+    ]
+    .into_iter()
+    .filter_map(|phase| {
+        let durations: Vec<i64> = turns
+            .iter()
+            .flat_map(|t| t.phases.iter())
+            .filter(|p| p.phase == phase)
+            .map(|p| p.duration_ms)
+            .collect();
+        mean(durations).map(|duration_ms| PhaseDuration { phase, duration_ms })
+    })
+    .collect();
+    let total_retries = turns.iter().map(|t| t.retries).sum();
+    MetricsSnapshot {
+        turns,
+        avg_first_token_ms,
+        avg_phase_ms,
+        total_retries,
+    }
+}
+
+/// 清空记录
+pub fn clear() {
+    if let Ok(mut turns) = recent_turns().lock() {
+        turns.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_track_turn_records_phases_first_token_and_retries() {
+        // 不在任何一轮之内的上报直接忽略
+        mark_first_token();
+        record_retry();
+
+        let conversation_id = uuid::Uuid::new_v4().to_string();
+        track_turn(&conversation_id, async {
+            for _ in 0..2 {
+                let _timer = PhaseTimer::start(MetricPhase::Retrieval);
+                tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+            }
+            {
+                let _timer = PhaseTimer::start(MetricPhase::Chat);
+                record_retry();
+                tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+                mark_first_token();
+                mark_first_token();
+            }
+        })
+        .await;
+
+        let snapshot = snapshot();
+        let turn = snapshot
+            .turns
+            .iter()
+            .find(|t| t.conversation_id == conversation_id)
+            .unwrap();
+        assert_eq!(turn.retries, 1);
+        assert_eq!(turn.phases.len(), 2, "同一阶段多次执行时累加");
+        assert!(turn.phases[0].duration_ms >= 10);
+        let first_token = turn.first_token_ms.unwrap();
+        assert!(first_token >= 15 && first_token <= turn.total_ms);
+        assert!(snapshot.avg_phase_ms.iter().any(|p| p.phase == MetricPhase::Chat));
+    }
+}
@@ -1,13 +1,65 @@
 pub mod chat_api;
 pub mod data_models;
 
+pub(crate) mod ambient_context;
+pub(crate) mod at_rest;
+pub(crate) mod attachments;
+pub(crate) mod background_tasks;
+pub(crate) mod backup;
+pub(crate) mod blocking_pool;
+pub(crate) mod character_relations;
 pub(crate) mod chat_engine;
+pub(crate) mod chat_provider;
+pub(crate) mod check_ins;
+pub(crate) mod closure;
+pub(crate) mod cloud_sync;
+pub(crate) mod coauthor_engine;
 pub(crate) mod cognitive_engine;
 pub(crate) mod streaming_handler;
+pub(crate) mod topic_blocks;
+pub(crate) mod tokenizer;
+pub(crate) mod tts;
 pub(crate) mod jwt_auth;
+pub(crate) mod context_cache;
 pub(crate) mod conversation_store;
+pub(crate) mod daily_digest;
 pub(crate) mod config_manager;
+pub(crate) mod data_export;
+pub(crate) mod data_layout;
+pub(crate) mod decision_log;
+pub(crate) mod embedding;
+pub(crate) mod group_chat;
+pub(crate) mod housekeeping;
+pub(crate) mod illustration;
 pub(crate) mod error_handler;
+pub(crate) mod fidelity_audit;
+pub(crate) mod integrity_checker;
+pub(crate) mod job_scheduler;
+pub(crate) mod intensity_dial;
+pub(crate) mod knowledge_graph;
+pub(crate) mod knowledge_layers;
 pub(crate) mod knowledge_store;
+pub(crate) mod language_packs;
+pub(crate) mod local_responder;
+pub(crate) mod log_store;
+pub(crate) mod lorebook;
 pub(crate) mod memory_engine;
+pub(crate) mod metrics;
+pub(crate) mod mood;
+pub(crate) mod narration;
+pub(crate) mod prompt_templates;
+pub(crate) mod network_adaptation;
+pub(crate) mod personas;
+pub(crate) mod quick_commands;
+pub(crate) mod reengagement;
+pub(crate) mod reindexer;
+pub(crate) mod safety_filter;
+pub(crate) mod schema_migration;
 pub(crate) mod saydo_detector;
+pub(crate) mod search_index;
+pub(crate) mod store_cache;
+pub(crate) mod shadow_eval;
+pub(crate) mod text_utils;
+pub(crate) mod time_awareness;
+pub(crate) mod timeline;
+pub(crate) mod web_search;
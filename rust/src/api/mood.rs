@@ -0,0 +1,246 @@
+use std::fs;
+use std::path::PathBuf;
+
+use flutter_rust_bridge::frb;
+
+use super::cognitive_engine::{CognitiveAnalysis, DialogueIntent};
+use super::data_models::MoodState;
+use super::error_handler::ChatError;
+
+// ═══════════════════════════════════════════════════════════════════
+//  角色心情 (Mood State)
+//  ─────────────────────────────────────────────────────────────────
+//  CognitiveEngine 每轮从最近消息重新推断情绪，关掉应用再回来角色就
+//  「失忆」了。这里给每个对话持久化一份角色自己的心情：
+//    1. 更新：每轮回复落盘后，按用户这轮的情绪与意图把心情往目标拉一段
+//       （情绪感染 + 意图带来的反应，如被指责会不快、被哄会软化）
+//    2. 衰减：按真实时间向基线回落，半衰期 MOOD_HALF_LIFE_HOURS，
+//       隔天回来仍带着一半的余怒
+//    3. 注入：作为第 3 层（认知快照）的一部分写进上下文
+//
+//  存储结构：
+//    mood/{conversation_id}.json
+// ═══════════════════════════════════════════════════════════════════
+
+/// 心情的基线（没有互动时回落到的状态）：略微积极、平静
+const BASELINE_VALENCE: f64 = 0.1;
+const BASELINE_AROUSAL: f64 = 0.3;
+/// 心情偏离基线的部分每过这么多小时减半
+const MOOD_HALF_LIFE_HOURS: f64 = 24.0;
+/// 每轮互动把心情往目标拉近的比例
+const MOOD_UPDATE_RATE: f64 = 0.4;
+/// 距上次互动超过这么久时，在提示中说明心情是「延续下来的」
+const CARRY_OVER_NOTE_MS: i64 = 60 * 60 * 1000;
+
+#[frb(opaque)]
+pub struct MoodStore {
+    base_path: String,
+}
+
+impl MoodStore {
+    pub fn new(base_path: &str) -> Self {
+        Self {
+            base_path: base_path.to_string(),
+        }
+    }
+
+    fn mood_dir(&self) -> Result<PathBuf, ChatError> {
+        let dir = PathBuf::from(&self.base_path).join("mood");
+        if !dir.exists() {
+            fs::create_dir_all(&dir).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to create mood directory: {}", e),
+            })?;
+        }
+        Ok(dir)
+    }
+
+    fn mood_path(&self, conversation_id: &str) -> Result<PathBuf, ChatError> {
+        Ok(self.mood_dir()?.join(format!("{}.json", conversation_id)))
+    }
+
+    /// 上次保存的心情（未衰减）；从未互动过时为 None
+    pub fn load(&self, conversation_id: &str) -> Result<Option<MoodState>, ChatError> {
+        let path = self.mood_path(conversation_id)?;
+        if !path.exists() {
+            return Ok(None);
+        }
+        let json = fs::read_to_string(&path).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to read mood: {}", e),
+        })?;
+        serde_json::from_str(&json)
+            .map(Some)
+            .map_err(|e| ChatError::StorageError {
+                message: format!("Failed to parse mood: {}", e),
+            })
+    }
+
+    fn save(&self, conversation_id: &str, state: &MoodState) -> Result<(), ChatError> {
+        let path = self.mood_path(conversation_id)?;
+        let json = serde_json::to_string_pretty(state).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to serialize mood: {}", e),
+        })?;
+        fs::write(&path, json).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to write mood: {}", e),
+        })
+    }
+
+    /// 衰减到 now 之后的当前心情
+    pub fn current(&self, conversation_id: &str, now: i64) -> Option<MoodState> {
+        self.load(conversation_id)
+            .ok()
+            .flatten()
+            .map(|state| Self::decay(&state, now))
+    }
+
+    /// 一轮互动结束后更新心情并保存，返回新心情
+    pub fn record_exchange(
+        &self,
+        conversation_id: &str,
+        analysis: &CognitiveAnalysis,
+        now: i64,
+    ) -> Result<MoodState, ChatError> {
+        let (valence, arousal) = match self.current(conversation_id, now) {
+            Some(state) => (state.valence, state.arousal),
+            None => (BASELINE_VALENCE, BASELINE_AROUSAL),
+        };
+        let (target_valence, target_arousal) = Self::reaction_target(analysis);
+        let valence = (valence + (target_valence - valence) * MOOD_UPDATE_RATE).clamp(-1.0, 1.0);
+        let arousal = (arousal + (target_arousal - arousal) * MOOD_UPDATE_RATE).clamp(0.0, 1.0);
+        let state = MoodState {
+            valence,
+            arousal,
+            mood: Self::name_mood(valence, arousal).to_string(),
+            updated_at: now,
+        };
+        self.save(conversation_id, &state)?;
+        Ok(state)
+    }
+
+    pub fn delete(&self, conversation_id: &str) -> Result<(), ChatError> {
+        let path = self.mood_path(conversation_id)?;
+        if path.exists() {
+            fs::remove_file(&path).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to delete mood: {}", e),
+            })?;
+        }
+        Ok(())
+    }
+
+    /// 按经过的真实时间向基线回落（updated_at 不变，仍记录最后一次互动）
+    pub fn decay(state: &MoodState, now: i64) -> MoodState {
+        let hours = (now - state.updated_at).max(0) as f64 / 3_600_000.0;
+        let remain = 0.5f64.powf(hours / MOOD_HALF_LIFE_HOURS);
+        let valence = BASELINE_VALENCE + (state.valence - BASELINE_VALENCE) * remain;
+        let arousal = BASELINE_AROUSAL + (state.arousal - BASELINE_AROUSAL) * remain;
+        MoodState {
+            valence,
+            arousal,
+            mood: Self::name_mood(valence, arousal).to_string(),
+            updated_at: state.updated_at,
+        }
+    }
+
+    /// 角色对这轮互动的情绪反应目标：部分感染用户情绪，再叠加意图带来的反应
+    fn reaction_target(analysis: &CognitiveAnalysis) -> (f64, f64) {
+        let (valence_shift, arousal_shift) = match analysis.intent {
+            DialogueIntent::ExpressingDispleasure => (-0.6, 0.4),
+            DialogueIntent::TestingBoundary => (-0.2, 0.2),
+            DialogueIntent::Withdrawn => (-0.3, -0.1),
+            DialogueIntent::ExpressingAffection => (0.6, 0.2),
+            DialogueIntent::Reconciling => (0.4, -0.1),
+            DialogueIntent::Playful => (0.4, 0.3),
+            DialogueIntent::DeepSharing => (0.2, 0.0),
+            _ => (0.0, 0.0),
+        };
+        let emotion = &analysis.emotion;
+        (
+            (emotion.valence * 0.5 + valence_shift).clamp(-1.0, 1.0),
+            (emotion.arousal * 0.5 + arousal_shift).clamp(0.0, 1.0),
+        )
+    }
+
+    /// 效价 × 唤醒度 四象限命名
+    fn name_mood(valence: f64, arousal: f64) -> &'static str {
+        match (valence, arousal) {
+            (v, a) if v >= 0.4 && a >= 0.5 => "兴奋",
+            (v, _) if v >= 0.3 => "愉快",
+            (v, a) if v <= -0.4 && a >= 0.5 => "恼火",
+            (v, _) if v <= -0.4 => "低落",
+            (v, a) if v <= -0.15 && a >= 0.4 => "不快",
+            (v, _) if v <= -0.15 => "闷闷不乐",
+            (_, a) if a >= 0.6 => "紧绷",
+            _ => "平静",
+        }
+    }
+
+    /// 注入第 3 层的心情提示；心情已回落到平静时不注入
+    pub fn build_prompt(state: &MoodState, now: i64) -> Option<String> {
+        if state.mood == "平静" {
+            return None;
+        }
+        let mut prompt = format!(
+            "【角色当前心情】{}（valence={:.2}, arousal={:.2}）",
+            state.mood, state.valence, state.arousal
+        );
+        let idle = now - state.updated_at;
+        if idle >= CARRY_OVER_NOTE_MS {
+            prompt.push_str(&format!(
+                "\n这份心情延续自约 {} 小时前的上一次互动，已经淡了一些但还没完全消散；\
+                 开场时自然地带着这点余波，不要当作什么都没发生，也不要无故突然转变。",
+                (idle / 3_600_000).max(1)
+            ));
+        } else {
+            prompt.push_str("\n回复时让语气与这份心情一致，情绪变化要有过渡。");
+        }
+        Some(prompt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::cognitive_engine::CognitiveEngine;
+    use crate::api::data_models::{Message, MessageRole};
+
+    fn message(role: MessageRole, content: &str) -> Message {
+        Message {
+            id: String::new(),
+            timestamp: 0,
+            ..Message::new(role, content, "")
+        }
+    }
+
+    #[test]
+    fn test_mood_persists_and_decays_toward_baseline() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let store = MoodStore::new(tmp.path().to_str().unwrap());
+        let history = [
+            message(MessageRole::Assistant, "今天过得怎么样？"),
+            message(MessageRole::User, "你烦不烦啊，气死我了，滚！"),
+        ];
+        let refs: Vec<&Message> = history.iter().collect();
+        let analysis = CognitiveEngine::analyze(&refs);
+
+        let mut state = store.record_exchange("c1", &analysis, 0).unwrap();
+        for _ in 0..3 {
+            state = store.record_exchange("c1", &analysis, 0).unwrap();
+        }
+        assert!(state.valence < -0.4, "valence = {}", state.valence);
+        assert_eq!(state.mood, "恼火");
+
+        // 隔天：偏离基线的部分只剩一半，依然不开心
+        let next_day = 24 * 3_600_000;
+        let woke = store.current("c1", next_day).unwrap();
+        assert!(woke.valence < BASELINE_VALENCE && woke.valence > state.valence);
+        let prompt = MoodStore::build_prompt(&woke, next_day).unwrap();
+        assert!(prompt.contains("24 小时前"));
+
+        // 很久以后回落到平静，不再注入
+        let calm = store.current("c1", 30 * next_day).unwrap();
+        assert_eq!(calm.mood, "平静");
+        assert!(MoodStore::build_prompt(&calm, 30 * next_day).is_none());
+
+        store.delete("c1").unwrap();
+        assert!(store.current("c1", 0).is_none());
+    }
+}
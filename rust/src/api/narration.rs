@@ -0,0 +1,203 @@
+use super::data_models::NarrationPerspective;
+
+// ═══════════════════════════════════════════════════════════════════
+//  叙述视角 (Narration Perspective)
+//  ─────────────────────────────────────────────────────────────────
+//  有人喜欢角色用第一人称说话、动作也写「我」，有人喜欢小说式的第三人称。
+//  按对话设置的视角双重约束：
+//    1. 生成前：注入视角提示块
+//    2. 生成后：检查叙述部分的人称是否漂移
+//       · 第一人称：动作描写（*…* / （…））里出现「她/他」指代自己
+//       · 第三人称：引号对白之外出现「我」
+//       漂移可无歧义修正时直接替换人称后落盘；有歧义时保留原文并提示
+//  Free（默认）不做任何约束。
+// ═══════════════════════════════════════════════════════════════════
+
+/// 第三人称代词（按长度优先匹配）
+const THIRD_PERSON: [&str; 2] = ["她", "他"];
+/// 对白引号（开, 闭）
+const QUOTES: [(char, char); 3] = [('“', '”'), ('「', '」'), ('"', '"')];
+/// 动作描写括号（开, 闭）
+const ACTION_MARKERS: [(char, char); 3] = [('*', '*'), ('（', '）'), ('(', ')')];
+
+/// 生成后检查结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct NarrationCheck {
+    /// 修正后的内容（未修正时为原文）
+    pub content: String,
+    /// 是否检测到视角漂移
+    pub drifted: bool,
+    /// 被替换的人称数；漂移但为 0 表示有歧义、未自动修正
+    pub corrected: usize,
+}
+
+pub struct NarrationGuard;
+
+impl NarrationGuard {
+    /// 生成前的视角提示块；Free 时为 None
+    pub fn build_prompt(perspective: &NarrationPerspective) -> Option<String> {
+        match perspective {
+            NarrationPerspective::Free => None,
+            NarrationPerspective::FirstPerson => Some(
+                "【叙述视角：第一人称】\n\
+                 - 始终以角色本人「我」的口吻说话和描写动作\n\
+                 - 动作、神态描写也用「我」：*我低下头* 而不是 *她低下头*\n\
+                 - 称呼对方用「你」，不要像旁白一样用「她/他」指代自己"
+                    .to_string(),
+            ),
+            NarrationPerspective::ThirdPerson => Some(
+                "【叙述视角：第三人称】\n\
+                 - 以小说旁白的方式叙述，角色用「她/他」或名字指代\n\
+                 - 角色说的话放在引号「」或“”中，只有引号内可以出现「我」\n\
+                 - 叙述部分不要出现「我」，保持旁白的距离感"
+                    .to_string(),
+            ),
+        }
+    }
+
+    /// 生成后检查并尽量修正视角漂移
+    pub fn enforce(perspective: &NarrationPerspective, content: &str) -> NarrationCheck {
+        let unchanged = |drifted: bool| NarrationCheck {
+            content: content.to_string(),
+            drifted,
+            corrected: 0,
+        };
+        match perspective {
+            NarrationPerspective::Free => unchanged(false),
+            NarrationPerspective::FirstPerson => {
+                let segments = Self::segments(content, &ACTION_MARKERS);
+                let action: String = Self::inside(content, &segments).concat();
+                let used: Vec<&str> = THIRD_PERSON.iter().copied().filter(|p| action.contains(p)).collect();
+                if used.is_empty() {
+                    return unchanged(false);
+                }
+                // 动作里既有「我」又有「她/他」，或两种代词混用：指代不明，只提示
+                if used.len() > 1 || action.contains('我') {
+                    return unchanged(true);
+                }
+                Self::replace_in(content, &segments, true, used[0], "我")
+            }
+            NarrationPerspective::ThirdPerson => {
+                let segments = Self::segments(content, &QUOTES);
+                let narration: String = Self::outside(content, &segments).concat();
+                if !narration.contains('我') {
+                    return unchanged(false);
+                }
+                // 用叙述中已在使用的唯一第三人称代词替换「我」
+                let used: Vec<&str> = THIRD_PERSON.iter().copied().filter(|p| narration.contains(p)).collect();
+                if used.len() != 1 {
+                    return unchanged(true);
+                }
+                Self::replace_in(content, &segments, false, "我", used[0])
+            }
+        }
+    }
+
+    /// 成对标记包裹的区段（字节区间，含标记本身）；不成对的标记忽略
+    fn segments(content: &str, pairs: &[(char, char)]) -> Vec<(usize, usize)> {
+        let mut segments = Vec::new();
+        let mut iter = content.char_indices();
+        while let Some((start, c)) = iter.next() {
+            let Some(&(_, close)) = pairs.iter().find(|(open, _)| *open == c) else {
+                continue;
+            };
+            let rest = &content[start + c.len_utf8()..];
+            if let Some(offset) = rest.find(close) {
+                let end = start + c.len_utf8() + offset + close.len_utf8();
+                segments.push((start, end));
+                // 跳到区段之后
+                while iter.offset() < end {
+                    iter.next();
+                }
+            }
+        }
+        segments
+    }
+
+    fn inside<'a>(content: &'a str, segments: &[(usize, usize)]) -> Vec<&'a str> {
+        segments.iter().map(|&(s, e)| &content[s..e]).collect()
+    }
+
+    fn outside<'a>(content: &'a str, segments: &[(usize, usize)]) -> Vec<&'a str> {
+        let mut parts = Vec::new();
+        let mut cursor = 0;
+        for &(s, e) in segments {
+            parts.push(&content[cursor..s]);
+            cursor = e;
+        }
+        parts.push(&content[cursor..]);
+        parts
+    }
+
+    /// 只在区段内（inside=true）或区段外替换人称
+    fn replace_in(
+        content: &str,
+        segments: &[(usize, usize)],
+        inside: bool,
+        from: &str,
+        to: &str,
+    ) -> NarrationCheck {
+        let mut result = String::with_capacity(content.len());
+        let mut corrected = 0;
+        let mut cursor = 0;
+        let mut fix = |part: &str, result: &mut String| {
+            corrected += part.matches(from).count();
+            result.push_str(&part.replace(from, to));
+        };
+        for &(s, e) in segments {
+            let (before, segment) = (&content[cursor..s], &content[s..e]);
+            if inside {
+                result.push_str(before);
+                fix(segment, &mut result);
+            } else {
+                fix(before, &mut result);
+                result.push_str(segment);
+            }
+            cursor = e;
+        }
+        if inside {
+            result.push_str(&content[cursor..]);
+        } else {
+            fix(&content[cursor..], &mut result);
+        }
+        NarrationCheck {
+            content: result,
+            drifted: true,
+            corrected,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_first_person_fixes_third_person_actions() {
+        let p = NarrationPerspective::FirstPerson;
+        let check = NarrationGuard::enforce(&p, "*她低下头，攥紧了她的衣角* 她才不是故意的嘛");
+        assert!(check.drifted);
+        assert_eq!(check.content, "*我低下头，攥紧了我的衣角* 她才不是故意的嘛");
+        assert_eq!(check.corrected, 2);
+
+        let ok = NarrationGuard::enforce(&p, "（我揉了揉你的头）乖啦");
+        assert!(!ok.drifted);
+        let ambiguous = NarrationGuard::enforce(&p, "*我看着他走远*");
+        assert!(ambiguous.drifted && ambiguous.corrected == 0);
+        assert_eq!(ambiguous.content, "*我看着他走远*");
+    }
+
+    #[test]
+    fn test_third_person_keeps_dialogue_untouched() {
+        let p = NarrationPerspective::ThirdPerson;
+        let check = NarrationGuard::enforce(&p, "她笑了笑，我把伞递过去。「我陪你走吧。」");
+        assert!(check.drifted);
+        assert_eq!(check.content, "她笑了笑，她把伞递过去。「我陪你走吧。」");
+
+        assert!(!NarrationGuard::enforce(&p, "他点点头：“我知道。”").drifted);
+        let unknown = NarrationGuard::enforce(&p, "我把伞递过去。");
+        assert!(unknown.drifted && unknown.corrected == 0);
+        assert!(NarrationGuard::build_prompt(&NarrationPerspective::Free).is_none());
+        assert!(!NarrationGuard::enforce(&NarrationPerspective::Free, "*她笑*").drifted);
+    }
+}
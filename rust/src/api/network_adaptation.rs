@@ -0,0 +1,170 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, OnceLock};
+
+use tokio::sync::Notify;
+
+use super::config_manager;
+
+// ═══════════════════════════════════════════════════════════════════
+//  弱网自适应 (Network Adaptation)
+//  ─────────────────────────────────────────────────────────────────
+//  3G / 不稳定 Wi-Fi 下 SSE 长连接频繁卡死，每次都要整轮重试。
+//  这里统计流式请求的成败，在两种传输方式之间自动切换：
+//    1. 降级：连续 DEGRADE_AFTER_FAILURES 次流失败（报错或中途断开），
+//       改用非流式请求（stream=false），整段回复一次性返回
+//    2. 恢复：降级期间连续 UPGRADE_AFTER_SUCCESSES 次请求成功，
+//       视为网络已稳定，自动回到流式
+//  状态为进程级全局，所有对话共享同一份网络判断。
+//
+//  离线检测：请求连不上服务器（连接层失败）即视为离线；之后任何一次拿到
+//  HTTP 响应、探测成功或宿主上报网络可用都视为恢复，并唤醒等待者
+//  （离线期间的发送进入发件箱，恢复后由 chat_api::watch_outbox 重放）。
+// ═══════════════════════════════════════════════════════════════════
+
+/// 连续多少次流失败后降级为非流式
+const DEGRADE_AFTER_FAILURES: u32 = 2;
+/// 降级期间连续多少次成功后恢复流式
+const UPGRADE_AFTER_SUCCESSES: u32 = 3;
+/// 连通性探测的超时
+const PROBE_TIMEOUT_SECS: u64 = 5;
+
+#[derive(Debug, Default, Clone, PartialEq)]
+struct AdaptiveState {
+    degraded: bool,
+    consecutive_failures: u32,
+    stable_successes: u32,
+}
+
+impl AdaptiveState {
+    fn record_failure(&mut self) {
+        if self.degraded {
+            // 降级期间仍失败：网络尚未稳定，重新计数
+            self.stable_successes = 0;
+            return;
+        }
+        self.consecutive_failures += 1;
+        if self.consecutive_failures >= DEGRADE_AFTER_FAILURES {
+            self.degraded = true;
+            self.consecutive_failures = 0;
+            self.stable_successes = 0;
+            tracing::warn!("流式请求连续失败，切换为非流式模式");
+        }
+    }
+
+    fn record_success(&mut self) {
+        if !self.degraded {
+            self.consecutive_failures = 0;
+            return;
+        }
+        self.stable_successes += 1;
+        if self.stable_successes >= UPGRADE_AFTER_SUCCESSES {
+            self.degraded = false;
+            self.stable_successes = 0;
+            tracing::info!("网络已恢复稳定，切换回流式模式");
+        }
+    }
+}
+
+static STATE: OnceLock<Mutex<AdaptiveState>> = OnceLock::new();
+
+fn state() -> &'static Mutex<AdaptiveState> {
+    STATE.get_or_init(|| Mutex::new(AdaptiveState::default()))
+}
+
+/// 当前是否处于非流式（弱网）模式
+pub fn is_degraded() -> bool {
+    state().lock().map(|s| s.degraded).unwrap_or(false)
+}
+
+/// 记录一次失败（流报错 / 中途断开 / 非流式请求失败）
+pub fn record_failure() {
+    if let Ok(mut s) = state().lock() {
+        s.record_failure();
+    }
+}
+
+/// 记录一次完整成功的请求
+pub fn record_success() {
+    if let Ok(mut s) = state().lock() {
+        s.record_success();
+    }
+}
+
+static OFFLINE: AtomicBool = AtomicBool::new(false);
+static ONLINE: OnceLock<Notify> = OnceLock::new();
+
+fn online_notify() -> &'static Notify {
+    ONLINE.get_or_init(Notify::new)
+}
+
+/// 当前是否判定为离线
+pub fn is_offline() -> bool {
+    OFFLINE.load(Ordering::Acquire)
+}
+
+/// 连不上服务器时调用
+pub fn mark_offline() {
+    if !OFFLINE.swap(true, Ordering::AcqRel) {
+        tracing::warn!("无法连接服务器，进入离线模式");
+    }
+}
+
+/// 收到任何 HTTP 响应（或宿主上报网络可用）时调用；从离线恢复时唤醒等待者
+pub fn mark_online() {
+    if OFFLINE.swap(false, Ordering::AcqRel) {
+        tracing::info!("网络已恢复，退出离线模式");
+        online_notify().notify_waiters();
+    }
+}
+
+/// 等待下一次从离线恢复
+pub async fn wait_online() {
+    online_notify().notified().await;
+}
+
+/// 探测 url 是否可达：任何 HTTP 响应（包括 4xx/5xx）都算连通，连通时标记恢复
+pub async fn probe(url: &str) -> bool {
+    let builder = reqwest::Client::builder()
+        .connect_timeout(std::time::Duration::from_secs(PROBE_TIMEOUT_SECS))
+        .timeout(std::time::Duration::from_secs(PROBE_TIMEOUT_SECS));
+    let client = match config_manager::apply_proxy(builder).map(|b| b.build()) {
+        Ok(Ok(client)) => client,
+        _ => return false,
+    };
+    match client.head(url).send().await {
+        Ok(_) => {
+            mark_online();
+            true
+        }
+        Err(e) => {
+            tracing::debug!(url, error = %e, "连通性探测失败");
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_degrades_after_repeated_failures_and_recovers() {
+        let mut s = AdaptiveState::default();
+        s.record_failure();
+        s.record_success();
+        s.record_failure();
+        assert!(!s.degraded, "中间有成功，不应降级");
+        s.record_failure();
+        assert!(s.degraded);
+
+        s.record_success();
+        s.record_success();
+        s.record_failure();
+        assert!(s.degraded, "降级期间失败会重新计数");
+        for _ in 0..UPGRADE_AFTER_SUCCESSES {
+            s.record_success();
+        }
+        assert!(!s.degraded);
+        assert_eq!(s, AdaptiveState::default());
+    }
+}
@@ -0,0 +1,179 @@
+use std::fs;
+use std::path::PathBuf;
+
+use flutter_rust_bridge::frb;
+
+use super::data_models::UserPersona;
+use super::error_handler::ChatError;
+
+// ═══════════════════════════════════════════════════════════════════
+//  用户人设 (User Personas)
+//  ─────────────────────────────────────────────────────────────────
+//  用户可以维护多个自己在对话中扮演的身份（名字、描述、人称代词），
+//  每个对话选用一个（Conversation::persona_id）：
+//    1. 注入：ChatEngine 把人设作为一条系统消息放在角色设定之后，
+//       角色据此称呼、对待对方
+//    2. 用户档案：KnowledgeStore 晋升用户身份/偏好事实时按人设分区，
+//       扮演「骑士」时提到的职业不会出现在以本人身份进行的对话里
+//  删除人设后，引用它的对话按未选人设处理。
+//
+//  存储结构：
+//    personas.json
+// ═══════════════════════════════════════════════════════════════════
+
+const PERSONAS_FILE: &str = "personas.json";
+/// 人设名称的最大字数
+const MAX_NAME_CHARS: usize = 32;
+
+#[frb(opaque)]
+pub struct PersonaStore {
+    base_path: String,
+}
+
+impl PersonaStore {
+    pub fn new(base_path: &str) -> Self {
+        Self {
+            base_path: base_path.to_string(),
+        }
+    }
+
+    fn personas_path(&self) -> PathBuf {
+        PathBuf::from(&self.base_path).join(PERSONAS_FILE)
+    }
+
+    /// 全部人设（按创建时间排序）；文件不存在或无法解析时为空
+    pub fn list(&self) -> Vec<UserPersona> {
+        let mut personas: Vec<UserPersona> = fs::read_to_string(self.personas_path())
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default();
+        personas.sort_by_key(|p| p.created_at);
+        personas
+    }
+
+    pub fn get(&self, persona_id: &str) -> Option<UserPersona> {
+        self.list().into_iter().find(|p| p.id == persona_id)
+    }
+
+    fn write(&self, personas: &[UserPersona]) -> Result<(), ChatError> {
+        let dir = PathBuf::from(&self.base_path);
+        if !dir.exists() {
+            fs::create_dir_all(&dir).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to create data directory: {}", e),
+            })?;
+        }
+        let json = serde_json::to_string_pretty(personas).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to serialize personas: {}", e),
+        })?;
+        fs::write(self.personas_path(), json).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to write personas: {}", e),
+        })
+    }
+
+    /// 新建或更新人设（id 为空时新建），返回保存后的人设
+    pub fn save(&self, persona: UserPersona) -> Result<UserPersona, ChatError> {
+        let name = persona.name.trim().to_string();
+        if name.is_empty() || name.chars().count() > MAX_NAME_CHARS {
+            return Err(ChatError::ValidationError {
+                message: format!("Persona name must be 1-{} characters", MAX_NAME_CHARS),
+            });
+        }
+        let mut personas = self.list();
+        let existing = personas.iter().position(|p| p.id == persona.id);
+        if !persona.id.is_empty() && existing.is_none() {
+            return Err(ChatError::ValidationError {
+                message: format!("Persona '{}' not found", persona.id),
+            });
+        }
+        let saved = UserPersona {
+            id: if persona.id.is_empty() {
+                uuid::Uuid::new_v4().to_string()
+            } else {
+                persona.id
+            },
+            name,
+            description: persona.description.trim().to_string(),
+            pronouns: persona.pronouns.trim().to_string(),
+            created_at: match existing {
+                Some(idx) => personas[idx].created_at,
+                None => chrono::Utc::now().timestamp_millis(),
+            },
+        };
+        match existing {
+            Some(idx) => personas[idx] = saved.clone(),
+            None => personas.push(saved.clone()),
+        }
+        self.write(&personas)?;
+        Ok(saved)
+    }
+
+    /// 删除人设；不存在时返回 false
+    pub fn delete(&self, persona_id: &str) -> Result<bool, ChatError> {
+        let mut personas = self.list();
+        let before = personas.len();
+        personas.retain(|p| p.id != persona_id);
+        if personas.len() == before {
+            return Ok(false);
+        }
+        self.write(&personas)?;
+        Ok(true)
+    }
+
+    /// 注入上下文的人设提示
+    pub fn build_prompt(persona: &UserPersona) -> String {
+        let mut prompt = format!("【对方的人设】和你对话的人是「{}」", persona.name);
+        if !persona.pronouns.is_empty() {
+            prompt.push_str(&format!("（提到对方时用「{}」）", persona.pronouns));
+        }
+        prompt.push('。');
+        if !persona.description.is_empty() {
+            prompt.push('\n');
+            prompt.push_str(&persona.description);
+        }
+        prompt.push_str(
+            "\n按这个身份称呼、对待对方，你们的关系与互动以此为准；\
+             只回应对方的言行，不要替对方决定说什么、做什么。",
+        );
+        prompt
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn persona(id: &str, name: &str) -> UserPersona {
+        UserPersona {
+            id: id.to_string(),
+            name: name.to_string(),
+            description: String::new(),
+            pronouns: String::new(),
+            created_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_persona_crud() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let store = PersonaStore::new(tmp.path().to_str().unwrap());
+        assert!(store.save(persona("", "  ")).is_err());
+        assert!(store.save(persona("missing", "林")).is_err());
+
+        let created = store.save(persona("", " 林晚 ")).unwrap();
+        assert_eq!(created.name, "林晚");
+        assert!(!created.id.is_empty());
+
+        let mut edited = created.clone();
+        edited.pronouns = "她".to_string();
+        edited.description = "刚搬来小镇的画家".to_string();
+        store.save(edited).unwrap();
+        let loaded = store.get(&created.id).unwrap();
+        assert_eq!(loaded.created_at, created.created_at);
+        let prompt = PersonaStore::build_prompt(&loaded);
+        assert!(prompt.contains("「林晚」") && prompt.contains("「她」") && prompt.contains("画家"));
+
+        assert!(store.delete(&created.id).unwrap());
+        assert!(!store.delete(&created.id).unwrap());
+        assert!(store.list().is_empty());
+    }
+}
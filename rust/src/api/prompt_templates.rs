@@ -0,0 +1,409 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Arc, OnceLock, RwLock};
+
+use flutter_rust_bridge::frb;
+
+use super::data_models::{Message, MessageRole, MessageType, PromptTemplateInfo};
+use super::error_handler::ChatError;
+
+// ═══════════════════════════════════════════════════════════════════
+//  提示词模板 (Prompt Templates)
+//  ─────────────────────────────────────────────────────────────────
+//  人格内核、内心推演、长上下文蒸馏与 say/do 风格提示原本都是编译期字面量。
+//  这里允许用户在数据目录下放同名模板文件覆盖任意一段注入提示，无需重新编译：
+//    - 变量：{{char}}（角色名）、{{user}}（对方称呼）、{{mood}}（角色当前心情），
+//      以及各模板自己的变量（见 TEMPLATES）；未知变量渲染为空
+//    - 条件：{{#if 变量}}…{{else}}…{{/if}}，变量非空（去空白后）为真，可嵌套
+//  没有覆盖文件的模板使用内置提示；覆盖文件语法错误时不会被加载。
+//
+//  存储结构：
+//    prompts/{name}.txt
+// ═══════════════════════════════════════════════════════════════════
+
+/// 所有模板都能用的变量
+const COMMON_VARIABLES: [&str; 3] = ["char", "user", "mood"];
+
+/// (模板名, 说明, 专属变量)
+const TEMPLATES: [(&str, &str, &[&str]); 11] = [
+    (
+        "humanization",
+        "人格内核（真人感 + 回复长度/结构约束）",
+        &[
+            "user_message",
+            "rhythm_guide",
+            "structure_guide",
+            "length_rule",
+            "structure_rule",
+            "style_rule",
+        ],
+    ),
+    ("reasoning", "内心推演（基础推理）", &["user_message"]),
+    ("enhanced_reasoning", "内心推演（知识增强推理）", &["user_message", "fact_summary"]),
+    ("distillation", "长上下文蒸馏", &["user_message", "full_memory"]),
+    ("style_say", "Say 模式风格提示", &[]),
+    ("style_do", "Do 模式风格提示", &[]),
+    ("style_mixed", "Say+Do 混合风格提示", &[]),
+    ("style_document", "共写正文风格提示", &[]),
+    ("style_narration", "旁白承接风格提示", &[]),
+    ("style_ooc", "场外模式风格提示", &[]),
+    ("check_in", "角色主动联系的开场提示", &["idle"]),
+];
+
+/// 没有角色名 / 对方称呼时的默认值
+const DEFAULT_CHAR: &str = "角色";
+const DEFAULT_USER: &str = "对方";
+/// 从系统提示里提取角色名时最多取的字数
+const MAX_NAME_CHARS: usize = 12;
+
+static TEMPLATES_SLOT: OnceLock<RwLock<Arc<HashMap<String, String>>>> = OnceLock::new();
+
+fn slot() -> &'static RwLock<Arc<HashMap<String, String>>> {
+    TEMPLATES_SLOT.get_or_init(|| RwLock::new(Arc::new(HashMap::new())))
+}
+
+/// 替换进程内生效的模板覆盖（下一次构建提示时生效）
+pub fn install(templates: HashMap<String, String>) {
+    if let Ok(mut active) = slot().write() {
+        *active = Arc::new(templates);
+    }
+}
+
+/// 有覆盖模板时按变量渲染，否则（含锁已损坏时）使用内置提示
+pub fn render_or(name: &str, vars: &TemplateVars, builtin: impl FnOnce() -> String) -> String {
+    let templates = slot().read().map(|t| t.clone()).unwrap_or_default();
+    render_with(&templates, name, vars, builtin)
+}
+
+fn render_with(
+    templates: &HashMap<String, String>,
+    name: &str,
+    vars: &TemplateVars,
+    builtin: impl FnOnce() -> String,
+) -> String {
+    match templates.get(name).and_then(|src| parse(src).ok()) {
+        Some(nodes) => {
+            let mut out = String::new();
+            render_nodes(&nodes, vars, &mut out);
+            out
+        }
+        None => builtin(),
+    }
+}
+
+/// 各消息类型对应的风格提示模板名
+pub fn style_template_name(message_type: &MessageType) -> &'static str {
+    match message_type {
+        MessageType::Say => "style_say",
+        MessageType::Do => "style_do",
+        MessageType::Mixed => "style_mixed",
+        MessageType::Document => "style_document",
+        MessageType::Narration => "style_narration",
+        MessageType::OutOfCharacter => "style_ooc",
+    }
+}
+
+/// 模板变量表
+#[derive(Debug, Clone, Default)]
+pub struct TemplateVars {
+    values: HashMap<&'static str, String>,
+}
+
+impl TemplateVars {
+    /// 公共变量：角色名取自对话的角色设定，心情由调用方给出
+    pub fn base(messages: &[Message], mood: &str) -> Self {
+        Self::default()
+            .with("char", character_name(messages))
+            .with("user", DEFAULT_USER)
+            .with("mood", mood)
+    }
+
+    pub fn with(mut self, key: &'static str, value: impl Into<String>) -> Self {
+        self.values.insert(key, value.into());
+        self
+    }
+
+    fn get(&self, key: &str) -> &str {
+        self.values.get(key).map(|v| v.as_str()).unwrap_or("")
+    }
+}
+
+/// 从第一条角色设定（系统消息）里找「你是X」「你叫X」「名字是X」中的名字
+fn character_name(messages: &[Message]) -> String {
+    let Some(prompt) = messages
+        .iter()
+        .find(|m| m.role == MessageRole::System && m.message_type != MessageType::OutOfCharacter)
+        .map(|m| m.content.as_str())
+    else {
+        return DEFAULT_CHAR.to_string();
+    };
+    for marker in ["你叫", "名字是", "你是"] {
+        if let Some(pos) = prompt.find(marker) {
+            let name: String = prompt[pos + marker.len()..]
+                .trim_start_matches(['「', '“', '"', ' '])
+                .chars()
+                .take_while(|c| !c.is_whitespace() && !"，,。.！!？?；;：:、」”\"（(".contains(*c))
+                .take(MAX_NAME_CHARS + 1)
+                .collect();
+            if !name.is_empty() && name.chars().count() <= MAX_NAME_CHARS {
+                return name;
+            }
+        }
+    }
+    DEFAULT_CHAR.to_string()
+}
+
+enum Node<'a> {
+    Text(&'a str),
+    Var(&'a str),
+    If {
+        var: &'a str,
+        then: Vec<Node<'a>>,
+        otherwise: Vec<Node<'a>>,
+    },
+}
+
+/// 解析中的一层 if（栈底为顶层，var 为空）
+struct Frame<'a> {
+    var: &'a str,
+    then: Vec<Node<'a>>,
+    otherwise: Vec<Node<'a>>,
+    in_else: bool,
+}
+
+impl<'a> Frame<'a> {
+    fn new(var: &'a str) -> Self {
+        Self {
+            var,
+            then: Vec::new(),
+            otherwise: Vec::new(),
+            in_else: false,
+        }
+    }
+
+    fn push(&mut self, node: Node<'a>) {
+        if self.in_else {
+            self.otherwise.push(node);
+        } else {
+            self.then.push(node);
+        }
+    }
+}
+
+/// 解析模板；标签不闭合、if 不配对时报错
+fn parse(src: &str) -> Result<Vec<Node<'_>>, ChatError> {
+    let invalid = |message: String| ChatError::ValidationError { message };
+    let mut stack = vec![Frame::new("")];
+
+    let mut rest = src;
+    while let Some(open) = rest.find("{{") {
+        if open > 0 {
+            stack.last_mut().unwrap().push(Node::Text(&rest[..open]));
+        }
+        let close = rest[open..].find("}}").ok_or_else(|| {
+            let snippet: String = rest[open..].chars().take(20).collect();
+            invalid(format!("模板标签未闭合：{}", snippet))
+        })?;
+        let tag = rest[open + 2..open + close].trim();
+        rest = &rest[open + close + 2..];
+
+        if let Some(var) = tag.strip_prefix("#if") {
+            let var = var.trim();
+            if var.is_empty() {
+                return Err(invalid("{{#if}} 缺少变量名".to_string()));
+            }
+            stack.push(Frame::new(var));
+        } else if tag == "else" {
+            let depth = stack.len();
+            match stack.last_mut() {
+                Some(frame) if depth > 1 && !frame.in_else => frame.in_else = true,
+                _ => return Err(invalid("{{else}} 不在 {{#if}} 之内".to_string())),
+            }
+        } else if tag == "/if" {
+            if stack.len() < 2 {
+                return Err(invalid("多余的 {{/if}}".to_string()));
+            }
+            let frame = stack.pop().unwrap();
+            stack.last_mut().unwrap().push(Node::If {
+                var: frame.var,
+                then: frame.then,
+                otherwise: frame.otherwise,
+            });
+        } else {
+            stack.last_mut().unwrap().push(Node::Var(tag));
+        }
+    }
+    if !rest.is_empty() {
+        stack.last_mut().unwrap().push(Node::Text(rest));
+    }
+    if stack.len() > 1 {
+        let var = stack.last().unwrap().var;
+        return Err(invalid(format!("{{{{#if {}}}}} 缺少 {{{{/if}}}}", var)));
+    }
+    Ok(stack.pop().unwrap().then)
+}
+
+fn render_nodes(nodes: &[Node], vars: &TemplateVars, out: &mut String) {
+    for node in nodes {
+        match node {
+            Node::Text(text) => out.push_str(text),
+            Node::Var(name) => out.push_str(vars.get(name)),
+            Node::If { var, then, otherwise } => {
+                let branch = if vars.get(var).trim().is_empty() { otherwise } else { then };
+                render_nodes(branch, vars, out);
+            }
+        }
+    }
+}
+
+#[frb(opaque)]
+pub struct PromptTemplateStore {
+    base_path: String,
+}
+
+impl PromptTemplateStore {
+    pub fn new(base_path: &str) -> Self {
+        Self {
+            base_path: base_path.to_string(),
+        }
+    }
+
+    fn prompts_dir(&self) -> Result<PathBuf, ChatError> {
+        let dir = PathBuf::from(&self.base_path).join("prompts");
+        if !dir.exists() {
+            fs::create_dir_all(&dir).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to create prompts directory: {}", e),
+            })?;
+        }
+        Ok(dir)
+    }
+
+    fn template_path(&self, name: &str) -> Result<PathBuf, ChatError> {
+        if !TEMPLATES.iter().any(|(n, _, _)| *n == name) {
+            return Err(ChatError::ValidationError {
+                message: format!("未知的提示词模板：{}", name),
+            });
+        }
+        Ok(self.prompts_dir()?.join(format!("{}.txt", name)))
+    }
+
+    /// 全部可覆盖的模板及其变量、是否已被覆盖
+    pub fn list(&self) -> Vec<PromptTemplateInfo> {
+        TEMPLATES
+            .iter()
+            .map(|(name, description, variables)| PromptTemplateInfo {
+                name: name.to_string(),
+                description: description.to_string(),
+                variables: COMMON_VARIABLES
+                    .iter()
+                    .chain(variables.iter())
+                    .map(|v| v.to_string())
+                    .collect(),
+                customized: self.load(name).ok().flatten().is_some(),
+            })
+            .collect()
+    }
+
+    /// 覆盖模板的原文；未覆盖时为 None
+    pub fn load(&self, name: &str) -> Result<Option<String>, ChatError> {
+        let path = self.template_path(name)?;
+        if !path.exists() {
+            return Ok(None);
+        }
+        fs::read_to_string(&path)
+            .map(Some)
+            .map_err(|e| ChatError::StorageError {
+                message: format!("Failed to read prompt template: {}", e),
+            })
+    }
+
+    /// 全部可用的覆盖模板（语法错误的跳过）
+    pub fn load_all(&self) -> HashMap<String, String> {
+        TEMPLATES
+            .iter()
+            .filter_map(|(name, _, _)| {
+                let src = self.load(name).ok().flatten()?;
+                parse(&src).ok()?;
+                Some((name.to_string(), src))
+            })
+            .collect()
+    }
+
+    /// 保存覆盖模板；语法错误时不落盘。内容为空等同于恢复内置
+    pub fn save(&self, name: &str, content: &str) -> Result<(), ChatError> {
+        if content.trim().is_empty() {
+            return self.reset(name);
+        }
+        let path = self.template_path(name)?;
+        parse(content)?;
+        fs::write(&path, content).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to write prompt template: {}", e),
+        })
+    }
+
+    /// 删除覆盖，恢复内置提示
+    pub fn reset(&self, name: &str) -> Result<(), ChatError> {
+        let path = self.template_path(name)?;
+        if path.exists() {
+            fs::remove_file(&path).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to delete prompt template: {}", e),
+            })?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(src: &str, vars: &TemplateVars) -> String {
+        let mut out = String::new();
+        render_nodes(&parse(src).unwrap(), vars, &mut out);
+        out
+    }
+
+    #[test]
+    fn test_render_variables_and_conditions() {
+        let character = Message::system_prompt("你是「林夏」，一个爱笑的女孩。");
+        let vars = TemplateVars::base(&[character], "愉快").with("fact_summary", "");
+        assert_eq!(
+            render("{{char}}对{{ user }}说话，心情{{mood}}{{unknown}}。", &vars),
+            "林夏对对方说话，心情愉快。"
+        );
+        assert_eq!(
+            render("{{#if fact_summary}}有事实{{else}}{{#if mood}}无事实，{{mood}}{{/if}}{{/if}}", &vars),
+            "无事实，愉快"
+        );
+        assert_eq!(TemplateVars::base(&[], "平静").get("char"), DEFAULT_CHAR);
+
+        assert!(parse("{{#if mood}}没有闭合").is_err());
+        assert!(parse("{{/if}}").is_err());
+        assert!(parse("{{char").is_err());
+    }
+
+    #[test]
+    fn test_store_overrides_and_reset() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let store = PromptTemplateStore::new(tmp.path().to_str().unwrap());
+        assert!(store.save("style_say", "{{#if mood}}坏模板").is_err());
+        assert!(store.save("no_such_template", "x").is_err());
+
+        store.save("style_say", "你是{{char}}，现在{{mood}}。").unwrap();
+        let loaded = store.load_all();
+        assert_eq!(loaded.len(), 1);
+        assert!(store.list().iter().any(|t| t.name == "style_say" && t.customized));
+
+        let vars = TemplateVars::base(&[Message::system_prompt("你叫阿哲。")], "低落");
+        assert_eq!(
+            render_with(&loaded, "style_say", &vars, || "内置".to_string()),
+            "你是阿哲，现在低落。"
+        );
+        assert_eq!(render_with(&loaded, "style_do", &vars, || "内置".to_string()), "内置");
+
+        store.reset("style_say").unwrap();
+        assert!(store.load_all().is_empty());
+        assert!(!store.list().iter().any(|t| t.customized));
+    }
+}
@@ -0,0 +1,204 @@
+use super::cognitive_engine::{CognitiveAnalysis, DialogueIntent};
+use super::data_models::MemorySummary;
+
+// ═══════════════════════════════════════════════════════════════════
+//  快捷命令 (Quick Commands)
+//  ─────────────────────────────────────────────────────────────────
+//  以「/」开头的消息在引擎内部处理，不发给模型、也不写入对话：
+//    /regen           重新生成上一条回复
+//    /continue        让角色接着刚才的话说下去
+//    /recap           回顾已形成的长期记忆
+//    /mood            查看当前的情绪与关系状态
+//    /remember 内容   把一条事实直接记入知识库
+//    /draw [描述]     为当前场景画一张插画（见 illustration）
+//    /ooc 指令        场外导演指令：以 system 消息记入对话（不算一轮、不进入
+//                     记忆与事实提取），在角色下一次回复时注入上下文
+//    /help            列出可用命令
+//  命令名只认 ASCII 字母，「/(ㄒoㄒ)/」之类的颜文字照常作为消息发送。
+// ═══════════════════════════════════════════════════════════════════
+
+/// /recap 最多列出的记忆条数（取最近的）
+const RECAP_MAX_SUMMARIES: usize = 3;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum QuickCommand {
+    Regen,
+    Continue,
+    Recap,
+    Mood,
+    Remember(String),
+    /// 场景插画，参数为可选的画面补充描述
+    Draw(String),
+    /// 场外导演指令
+    Ooc(String),
+    Help,
+    /// 形似命令但无法识别
+    Unknown(String),
+}
+
+impl QuickCommand {
+    /// 解析快捷命令；不是命令时返回 None（按普通消息发送）
+    pub fn parse(content: &str) -> Option<Self> {
+        let rest = content.trim().strip_prefix('/')?;
+        let (name, arg) = match rest.find(char::is_whitespace) {
+            Some(idx) => (&rest[..idx], rest[idx..].trim()),
+            None => (rest, ""),
+        };
+        if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphabetic()) {
+            return None;
+        }
+        Some(match name.to_ascii_lowercase().as_str() {
+            "regen" | "regenerate" => Self::Regen,
+            "continue" | "cont" => Self::Continue,
+            "recap" => Self::Recap,
+            "mood" => Self::Mood,
+            "remember" => Self::Remember(arg.to_string()),
+            "draw" | "illustrate" => Self::Draw(arg.to_string()),
+            "ooc" | "director" => Self::Ooc(arg.to_string()),
+            "help" => Self::Help,
+            other => Self::Unknown(other.to_string()),
+        })
+    }
+
+    pub fn help_text() -> &'static str {
+        "可用命令：\n\
+         /regen — 重新生成上一条回复\n\
+         /continue — 让角色接着说下去\n\
+         /recap — 回顾长期记忆\n\
+         /mood — 查看当前情绪与关系状态\n\
+         /remember 内容 — 直接记住一条事实\n\
+         /draw [描述] — 为当前场景画一张插画\n\
+         /ooc 指令 — 给角色的「导演」下场外指令，下一次回复生效"
+    }
+
+    /// 导演指令提示：指令只约束剧情走向，角色不应察觉
+    pub fn build_directive_prompt(directives: &[&str]) -> String {
+        let lines: Vec<String> = directives.iter().map(|d| format!("- {}", d)).collect();
+        format!(
+            "【导演指令（场外安排，不要在回复中提及或解释）】\n{}\n\
+             在本轮回复中自然地落实这些安排：用角色的动作、对白或场景过渡让它发生，\
+             保持角色身份，不要跳出角色确认收到指令。",
+            lines.join("\n")
+        )
+    }
+
+    /// 长期记忆回顾（只读本地记忆索引，不调用模型）
+    pub fn build_recap(summaries: &[MemorySummary], turn_count: u32) -> String {
+        if summaries.is_empty() {
+            return format!("已聊 {} 轮，还没有形成长期记忆。", turn_count);
+        }
+        let start = summaries.len().saturating_sub(RECAP_MAX_SUMMARIES);
+        let mut recap = format!("已聊 {} 轮，最近的记忆：", turn_count);
+        for summary in &summaries[start..] {
+            recap.push_str(&format!(
+                "\n· 第{}-{}轮：{}",
+                summary.turn_range_start, summary.turn_range_end, summary.summary
+            ));
+            if let Some(fact) = summary.core_facts.first() {
+                recap.push_str(&format!("（{}）", fact));
+            }
+        }
+        recap
+    }
+
+    /// 情绪与关系状态的文字描述
+    pub fn describe_mood(analysis: &CognitiveAnalysis) -> String {
+        let emotion = &analysis.emotion;
+        let mood = if emotion.valence > 0.3 {
+            "心情不错"
+        } else if emotion.valence < -0.3 {
+            "情绪低落"
+        } else {
+            "心情平稳"
+        };
+        let energy = if emotion.arousal > 0.6 { "，有点激动" } else { "" };
+        let intent = match analysis.intent {
+            DialogueIntent::SeekingComfort => "想被安慰",
+            DialogueIntent::ExpressingAffection => "在表达亲近",
+            DialogueIntent::ExpressingDispleasure => "有些不满",
+            DialogueIntent::TestingBoundary => "在试探关系",
+            DialogueIntent::SharingDaily => "在分享日常",
+            DialogueIntent::SeekingResponse => "在等回应",
+            DialogueIntent::EmotionalVenting => "在宣泄情绪",
+            DialogueIntent::Playful => "在玩闹",
+            DialogueIntent::Reconciling => "想要和好",
+            DialogueIntent::Farewell => "准备告别",
+            DialogueIntent::Withdrawn => "有点冷淡",
+            DialogueIntent::DeepSharing => "在认真交心",
+        };
+        let rel = &analysis.relationship;
+        let trend = if rel.trend > 0.1 {
+            "升温"
+        } else if rel.trend < -0.1 {
+            "降温"
+        } else {
+            "平稳"
+        };
+        format!(
+            "当前氛围：{}{}，对方{}。\n亲密 {:.0}% · 信任 {:.0}% · 紧张 {:.0}% · 关系{}",
+            mood,
+            energy,
+            intent,
+            rel.closeness * 100.0,
+            rel.trust_level * 100.0,
+            rel.tension * 100.0,
+            trend
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_commands() {
+        assert_eq!(QuickCommand::parse("/regen"), Some(QuickCommand::Regen));
+        assert_eq!(QuickCommand::parse("  /Continue  "), Some(QuickCommand::Continue));
+        assert_eq!(
+            QuickCommand::parse("/remember 她不吃香菜"),
+            Some(QuickCommand::Remember("她不吃香菜".to_string()))
+        );
+        assert_eq!(
+            QuickCommand::parse("/dance"),
+            Some(QuickCommand::Unknown("dance".to_string()))
+        );
+        assert_eq!(
+            QuickCommand::parse("/draw 黄昏的海边"),
+            Some(QuickCommand::Draw("黄昏的海边".to_string()))
+        );
+        assert_eq!(
+            QuickCommand::parse("/ooc 让场景转到海边"),
+            Some(QuickCommand::Ooc("让场景转到海边".to_string()))
+        );
+        assert_eq!(QuickCommand::parse("/(ㄒoㄒ)/"), None);
+        assert_eq!(QuickCommand::parse("/ 你好"), None);
+        assert_eq!(QuickCommand::parse("今天/明天都行"), None);
+    }
+
+    #[test]
+    fn test_recap_lists_latest_summaries() {
+        assert!(QuickCommand::build_recap(&[], 4).contains("还没有形成长期记忆"));
+        let summaries: Vec<MemorySummary> = (0..5u32)
+            .map(|i| MemorySummary {
+                id: i.to_string(),
+                summary: format!("第{}段", i),
+                core_facts: vec![],
+                turn_range_start: i * 10 + 1,
+                turn_range_end: i * 10 + 10,
+                created_at: 0,
+                keywords: vec![],
+                compression_generation: 0,
+                context_card: None,
+                fact_tiers: vec![],
+                linked_fact_ids: vec![],
+                pinned: false,
+                pinned_facts: Vec::new(),
+                stale: false,
+            })
+            .collect();
+        let recap = QuickCommand::build_recap(&summaries, 50);
+        assert!(!recap.contains("第1段"));
+        assert!(recap.contains("第2段") && recap.contains("第4段"));
+    }
+}
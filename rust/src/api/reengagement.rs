@@ -0,0 +1,328 @@
+use std::fs;
+use std::path::PathBuf;
+
+use super::chat_engine::ChatEngine;
+use super::chat_provider::ChatProvider;
+use super::conversation_store::ConversationStore;
+use super::data_models::*;
+use super::error_handler::ChatError;
+use super::memory_engine::MemoryEngine;
+use super::streaming_handler::StreamingHandler;
+use super::text_utils;
+use super::topic_blocks::BlockedTopicStore;
+
+// ═══════════════════════════════════════════════════════════════════
+//  久别重逢 (Re-engagement)
+//  ─────────────────────────────────────────────────────────────────
+//  隔了几周再打开一个对话，用户往往已经忘了上次聊到哪，
+//  角色却像什么都没发生一样接着说。打开沉寂超过 STALE_AFTER_MS 的对话时：
+//    1. 前情提要：最近的长期记忆摘要 + 最后几句对话，本地拼接，不调用模型
+//    2. 未完线索：短期记忆中「提到但没聊开」的话题
+//    3. 开场建议：快速模型以角色口吻写一句开场白，提到分别了多久、
+//       顺带问起没聊完的事；模型不可用或超时时用本地模板兜底
+//  结果按对话缓存，直到对话出现新消息（下一轮）才重新生成，
+//  反复打开同一个对话不会重复花费。
+//
+//  存储结构：
+//    reengagement/{conversation_id}.json
+// ═══════════════════════════════════════════════════════════════════
+
+/// 沉寂多久算「久别」：7 天
+const STALE_AFTER_MS: i64 = 7 * 24 * 60 * 60 * 1000;
+const DAY_MS: i64 = 24 * 60 * 60 * 1000;
+/// 前情提要带入的记忆摘要条数（取最近的）
+const RECAP_SUMMARIES: usize = 2;
+/// 前情提要带入的最后几句对话
+const RECAP_MESSAGES: usize = 4;
+/// 单句对话在提要中的截断长度（字符）
+const RECAP_MESSAGE_CHARS: usize = 60;
+/// 开场白带入的线索数
+const OPENER_THREADS: usize = 2;
+/// 开场白使用的快速模型
+const OPENER_MODEL: &str = "glm-4.7-flash";
+const OPENER_MAX_TOKENS: u32 = 120;
+const OPENER_TIMEOUT_SECS: u64 = 15;
+/// 开场白最长字符数（模型超长时截断）
+const OPENER_MAX_CHARS: usize = 80;
+
+pub struct ReengagementGenerator<'a> {
+    provider: Option<&'a dyn ChatProvider>,
+    base_path: String,
+    conversation_store: ConversationStore,
+    memory_engine: MemoryEngine,
+    blocked_topics: BlockedTopicStore,
+}
+
+impl<'a> ReengagementGenerator<'a> {
+    /// provider 为 None（离线模式）时开场白只用本地模板
+    pub fn new(provider: Option<&'a dyn ChatProvider>, base_path: &str) -> Self {
+        Self {
+            provider,
+            base_path: base_path.to_string(),
+            conversation_store: ConversationStore::new(base_path),
+            memory_engine: MemoryEngine::new(base_path),
+            blocked_topics: BlockedTopicStore::new(base_path),
+        }
+    }
+
+    fn cache_dir(&self) -> Result<PathBuf, ChatError> {
+        let dir = PathBuf::from(&self.base_path).join("reengagement");
+        if !dir.exists() {
+            fs::create_dir_all(&dir).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to create reengagement directory: {}", e),
+            })?;
+        }
+        Ok(dir)
+    }
+
+    fn cache_path(&self, conversation_id: &str) -> Result<PathBuf, ChatError> {
+        Ok(self.cache_dir()?.join(format!("{}.json", conversation_id)))
+    }
+
+    fn load_cached(&self, conversation_id: &str) -> Option<ReengagementBrief> {
+        let json = fs::read_to_string(self.cache_path(conversation_id).ok()?).ok()?;
+        serde_json::from_str(&json).ok()
+    }
+
+    fn save_cached(&self, brief: &ReengagementBrief) -> Result<(), ChatError> {
+        let json = serde_json::to_string_pretty(brief).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to serialize reengagement brief: {}", e),
+        })?;
+        fs::write(self.cache_path(&brief.conversation_id)?, json).map_err(|e| {
+            ChatError::StorageError {
+                message: format!("Failed to write reengagement brief: {}", e),
+            }
+        })
+    }
+
+    pub fn delete(&self, conversation_id: &str) -> Result<(), ChatError> {
+        let path = self.cache_path(conversation_id)?;
+        if path.exists() {
+            fs::remove_file(&path).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to delete reengagement brief: {}", e),
+            })?;
+        }
+        Ok(())
+    }
+
+    /// 对话沉寂超过阈值时返回重逢提要（now 为毫秒时间戳）；
+    /// 最近还在聊、共写模式或空对话返回 None
+    pub async fn prepare(
+        &self,
+        conversation_id: &str,
+        now: i64,
+    ) -> Result<Option<ReengagementBrief>, ChatError> {
+        let conv = self.conversation_store.load_active_branch(conversation_id)?;
+        if conv.mode != ConversationMode::Chat {
+            return Ok(None);
+        }
+        let Some(last) = conv.messages.iter().rev().find(|m| m.role != MessageRole::System) else {
+            return Ok(None);
+        };
+        let last_active = if last.timestamp > 0 { last.timestamp } else { conv.updated_at };
+        if now - last_active < STALE_AFTER_MS {
+            return Ok(None);
+        }
+        if let Some(cached) = self.load_cached(conversation_id) {
+            if cached.last_message_id == last.id {
+                return Ok(Some(cached));
+            }
+        }
+
+        let elapsed_days = ((now - last_active) / DAY_MS) as u32;
+        let summaries = self
+            .memory_engine
+            .load_memory_index(conversation_id)
+            .unwrap_or_default();
+        let recap = Self::build_recap(&summaries, &conv.messages);
+        let pending_threads = MemoryEngine::build_short_term_context(&conv.messages).pending_threads;
+
+        let opener = match self.request_opener(&conv, &recap, elapsed_days, &pending_threads).await {
+            Some(opener) => opener,
+            None => Self::fallback_opener(elapsed_days, &pending_threads),
+        };
+
+        let brief = ReengagementBrief {
+            conversation_id: conversation_id.to_string(),
+            elapsed_days,
+            recap,
+            opener,
+            pending_threads,
+            generated_at: now,
+            last_message_id: last.id.clone(),
+        };
+        self.save_cached(&brief)?;
+        Ok(Some(brief))
+    }
+
+    /// 前情提要：最近的记忆摘要 + 最后几句对话
+    pub fn build_recap(summaries: &[MemorySummary], messages: &[Message]) -> String {
+        let mut recap = String::from("前情提要：");
+        let start = summaries.len().saturating_sub(RECAP_SUMMARIES);
+        for summary in &summaries[start..] {
+            recap.push_str(&format!("\n· {}", summary.summary.trim()));
+        }
+        let recent: Vec<&Message> = messages
+            .iter()
+            .filter(|m| m.role != MessageRole::System)
+            .collect();
+        if !recent.is_empty() {
+            recap.push_str("\n上次聊到：");
+            for msg in &recent[recent.len().saturating_sub(RECAP_MESSAGES)..] {
+                let speaker = if msg.role == MessageRole::User { "你" } else { "TA" };
+                recap.push_str(&format!(
+                    "\n{}：{}",
+                    speaker,
+                    text_utils::ellipsize(msg.content.trim(), RECAP_MESSAGE_CHARS, "…")
+                ));
+            }
+        }
+        recap
+    }
+
+    /// 分别时长的口语描述
+    pub fn describe_elapsed(days: u32) -> String {
+        match days {
+            0..=13 => format!("{}天", days),
+            14..=59 => format!("{}周", days / 7),
+            60..=364 => format!("{}个月", days / 30),
+            _ => "一年多".to_string(),
+        }
+    }
+
+    /// 模型不可用时的开场白模板
+    pub fn fallback_opener(elapsed_days: u32, pending_threads: &[String]) -> String {
+        let elapsed = Self::describe_elapsed(elapsed_days);
+        match pending_threads.first() {
+            Some(thread) => format!("好久不见呀，都{}没聊了。上次你说到{}，后来怎么样了？", elapsed, thread),
+            None => format!("好久不见呀，都{}没聊了，最近过得好吗？", elapsed),
+        }
+    }
+
+    async fn request_opener(
+        &self,
+        conv: &Conversation,
+        recap: &str,
+        elapsed_days: u32,
+        pending_threads: &[String],
+    ) -> Option<String> {
+        let provider = self.provider?;
+        let messages = self.build_opener_messages(conv, recap, elapsed_days, pending_threads);
+        let mut body = ChatEngine::build_request_body(&messages, OPENER_MODEL, false);
+        body["max_tokens"] = serde_json::json!(OPENER_MAX_TOKENS);
+        let text = tokio::time::timeout(
+            std::time::Duration::from_secs(OPENER_TIMEOUT_SECS),
+            StreamingHandler::complete_silently(provider, body),
+        )
+        .await
+        .ok()?
+        .ok()?;
+        let line = text.lines().map(str::trim).find(|l| !l.is_empty())?;
+        Some(text_utils::ellipsize(line, OPENER_MAX_CHARS, "…"))
+    }
+
+    /// 角色设定 + 回避话题 + 前情提要 + 开场指令
+    fn build_opener_messages(
+        &self,
+        conv: &Conversation,
+        recap: &str,
+        elapsed_days: u32,
+        pending_threads: &[String],
+    ) -> Vec<Message> {
+        let make = |role: MessageRole, content: String| Message {
+            id: String::new(),
+            timestamp: 0,
+            ..Message::new(role, content, OPENER_MODEL)
+        };
+
+        let mut system = conv
+            .messages
+            .iter()
+            .find(|m| m.role == MessageRole::System)
+            .map(|m| m.content.clone())
+            .unwrap_or_default();
+        if let Some(avoid) = BlockedTopicStore::build_prompt(&self.blocked_topics.active(&conv.id)) {
+            system.push_str("\n\n");
+            system.push_str(&avoid);
+        }
+
+        let threads = if pending_threads.is_empty() {
+            String::new()
+        } else {
+            let picked: Vec<&str> = pending_threads
+                .iter()
+                .take(OPENER_THREADS)
+                .map(String::as_str)
+                .collect();
+            format!("上次没聊完的话题：{}。可以自然地问起其中一个。\n", picked.join("、"))
+        };
+
+        let mut messages = Vec::new();
+        if !system.trim().is_empty() {
+            messages.push(Message::system_prompt(system));
+        }
+        messages.push(make(
+            MessageRole::User,
+            format!(
+                "（旁白）你们已经{}没有说话了，对方刚刚重新打开了聊天。\n{}\n{}\
+                 请保持你的角色身份和说话方式，写一句主动打招呼的开场白：\
+                 要体现出分别了这么久，不要假装刚刚还在聊。\
+                 不超过 50 字，只输出这句话本身。",
+                Self::describe_elapsed(elapsed_days),
+                recap,
+                threads
+            ),
+        ));
+        messages
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn msg(role: MessageRole, content: &str, timestamp: i64) -> Message {
+        Message {
+            timestamp,
+            ..Message::new(role, content, "test")
+        }
+    }
+
+    #[tokio::test]
+    async fn test_brief_only_for_stale_conversations_and_cached_until_next_turn() {
+        let tmp = TempDir::new().unwrap();
+        let path = tmp.path().to_str().unwrap();
+        let store = ConversationStore::new(path);
+        let conv = store.create_conversation();
+        store.save_conversation(&conv).unwrap();
+        store.add_message(&conv.id, msg(MessageRole::User, "周末去爬山了", 1_000)).unwrap();
+        store.add_message(&conv.id, msg(MessageRole::Assistant, "累不累呀", 2_000)).unwrap();
+
+        let generator = ReengagementGenerator::new(None, path);
+        assert!(generator.prepare(&conv.id, 2_000 + DAY_MS).await.unwrap().is_none());
+
+        let now = 2_000 + 21 * DAY_MS;
+        let brief = generator.prepare(&conv.id, now).await.unwrap().unwrap();
+        assert_eq!(brief.elapsed_days, 21);
+        assert!(brief.recap.contains("你：周末去爬山了"));
+        assert!(brief.opener.contains("3周"));
+
+        // 没有新消息：直接取缓存
+        let again = generator.prepare(&conv.id, now + DAY_MS).await.unwrap().unwrap();
+        assert_eq!(again.generated_at, now);
+
+        store.add_message(&conv.id, msg(MessageRole::User, "在吗", 3_000)).unwrap();
+        let renewed = generator.prepare(&conv.id, now + DAY_MS).await.unwrap().unwrap();
+        assert_eq!(renewed.generated_at, now + DAY_MS);
+    }
+
+    #[test]
+    fn test_fallback_opener_mentions_elapsed_time_and_thread() {
+        assert_eq!(ReengagementGenerator::describe_elapsed(9), "9天");
+        assert_eq!(ReengagementGenerator::describe_elapsed(90), "3个月");
+        let opener = ReengagementGenerator::fallback_opener(30, &["面试".to_string()]);
+        assert!(opener.contains("4周") && opener.contains("面试"));
+        assert!(!ReengagementGenerator::fallback_opener(400, &[]).contains("上次"));
+    }
+}
@@ -0,0 +1,269 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+use flutter_rust_bridge::frb;
+use serde::{Deserialize, Serialize};
+
+use super::blocking_pool;
+use super::conversation_store::ConversationStore;
+use super::data_models::{IndexScope, ReindexProgress};
+use super::error_handler::ChatError;
+use super::knowledge_store::{KnowledgeStore, KNOWLEDGE_INDEX_VERSION};
+use super::memory_engine::MemoryEngine;
+
+// ═══════════════════════════════════════════════════════════════════
+//  索引批量重建 (Reindexer)
+//  ─────────────────────────────────────────────────────────────────
+//  检索算法升级（换关键词提取器、调整索引结构）后，磁盘上的旧索引
+//  仍按旧算法构建，检索质量会悄悄下降。这里为每类索引记录版本号：
+//    - 知识库：事实关键词 + 倒排索引（KNOWLEDGE_INDEX_VERSION）
+//    - 记忆：摘要关键词（MEMORY_KEYWORD_VERSION）
+//  版本记录在 data_path/index_versions.json，重建逐对话进行并立即记账，
+//  中途退出后再次运行只处理剩余的过期对话。
+// ═══════════════════════════════════════════════════════════════════
+
+/// 记忆摘要关键词算法版本
+pub const MEMORY_KEYWORD_VERSION: u32 = 1;
+
+const VERSIONS_FILE: &str = "index_versions.json";
+
+/// 单个对话各类索引的构建版本
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct IndexVersions {
+    #[serde(default)]
+    pub knowledge: u32,
+    #[serde(default)]
+    pub memory: u32,
+}
+
+#[frb(opaque)]
+pub struct Reindexer {
+    base_path: String,
+    conversation_store: ConversationStore,
+    knowledge_store: KnowledgeStore,
+    memory_engine: MemoryEngine,
+}
+
+impl Reindexer {
+    pub fn new(base_path: &str) -> Self {
+        Self {
+            base_path: base_path.to_string(),
+            conversation_store: ConversationStore::new(base_path),
+            knowledge_store: KnowledgeStore::new(base_path),
+            memory_engine: MemoryEngine::new(base_path),
+        }
+    }
+
+    fn versions_path(&self) -> PathBuf {
+        PathBuf::from(&self.base_path).join(VERSIONS_FILE)
+    }
+
+    pub fn load_versions(&self) -> BTreeMap<String, IndexVersions> {
+        fs::read_to_string(self.versions_path())
+            .ok()
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default()
+    }
+
+    fn save_versions(&self, versions: &BTreeMap<String, IndexVersions>) -> Result<(), ChatError> {
+        let json = serde_json::to_string_pretty(versions).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to serialize index versions: {}", e),
+        })?;
+        fs::write(self.versions_path(), json).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to write index versions: {}", e),
+        })
+    }
+
+    fn needs_knowledge(scope: IndexScope, v: &IndexVersions) -> bool {
+        scope != IndexScope::Memory && v.knowledge < KNOWLEDGE_INDEX_VERSION
+    }
+
+    fn needs_memory(scope: IndexScope, v: &IndexVersions) -> bool {
+        scope != IndexScope::Knowledge && v.memory < MEMORY_KEYWORD_VERSION
+    }
+
+    /// 重新计算单个对话的记忆摘要关键词（索引文件与对话内副本一并更新），返回摘要数
+    fn reindex_memory(&self, conversation_id: &str) -> Result<usize, ChatError> {
+        let mut summaries = self.memory_engine.load_memory_index(conversation_id)?;
+        for summary in summaries.iter_mut() {
+            summary.keywords = MemoryEngine::summary_keywords(&summary.summary, &summary.core_facts);
+        }
+        self.memory_engine
+            .save_memory_index(conversation_id, &summaries)?;
+
+        // 对话内的摘要副本同步更新；不动 updated_at，避免重建打乱对话列表排序
+        let mut conv = self.conversation_store.load_conversation(conversation_id)?;
+        for summary in conv.memory_summaries.iter_mut() {
+            summary.keywords = MemoryEngine::summary_keywords(&summary.summary, &summary.core_facts);
+        }
+        self.conversation_store.save_conversation(&conv)?;
+        Ok(summaries.len())
+    }
+
+    /// 按范围重建单个对话中已过期的索引，返回重建的条目数
+    pub fn reindex_conversation(
+        &self,
+        conversation_id: &str,
+        scope: IndexScope,
+    ) -> Result<usize, ChatError> {
+        let mut versions = self.load_versions();
+        let mut current = versions.get(conversation_id).copied().unwrap_or_default();
+        let mut rebuilt = 0;
+
+        if Self::needs_knowledge(scope, &current) {
+            rebuilt += self.knowledge_store.reindex(conversation_id)?;
+            current.knowledge = KNOWLEDGE_INDEX_VERSION;
+        }
+        if Self::needs_memory(scope, &current) {
+            rebuilt += self.reindex_memory(conversation_id)?;
+            current.memory = MEMORY_KEYWORD_VERSION;
+        }
+
+        versions.insert(conversation_id.to_string(), current);
+        self.save_versions(&versions)?;
+        Ok(rebuilt)
+    }
+
+    /// 后台批量重建所有对话的过期索引，每处理完一个对话发送一次进度
+    pub async fn reindex_all(&self, scope: IndexScope, on_progress: impl Fn(ReindexProgress)) {
+        let ids: Vec<String> = self
+            .conversation_store
+            .list_conversations()
+            .into_iter()
+            .map(|s| s.id)
+            .collect();
+        let total = ids.len() as u32;
+
+        for (i, id) in ids.into_iter().enumerate() {
+            let base_path = self.base_path.clone();
+            let conv_id = id.clone();
+            let result = blocking_pool::offload("reindex", move || {
+                Reindexer::new(&base_path).reindex_conversation(&conv_id, scope)
+            })
+            .await;
+            let (rebuilt_entries, error) = match result {
+                Ok(n) => (n as u32, None),
+                Err(e) => (0, Some(e.to_string())),
+            };
+            on_progress(ReindexProgress {
+                conversation_id: id,
+                completed: i as u32 + 1,
+                total,
+                rebuilt_entries,
+                error,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::data_models::MemorySummary;
+    use crate::api::knowledge_store::{Fact, FactCategory};
+    use tempfile::TempDir;
+
+    fn make_fact(content: &str) -> Fact {
+        Fact {
+            id: uuid::Uuid::new_v4().to_string(),
+            content: content.to_string(),
+            category: FactCategory::Preference,
+            source_turn: 1,
+            created_at: 0,
+            last_confirmed_at: 0,
+            keywords: vec!["过期关键词".to_string()],
+            entities: vec![],
+            confidence: 0.8,
+            hit_count: 0,
+            context_snippet: String::new(),
+            summary_ids: Vec::new(),
+            superseded_by: None,
+            superseded_at: None,
+            manual_override: false,
+            persona_id: None,
+        }
+    }
+
+    fn setup(base: &str) -> String {
+        let store = ConversationStore::new(base);
+        let mut conv = store.create_conversation();
+        let summary = MemorySummary {
+            id: "m1".to_string(),
+            summary: "用户喜欢在雨天听爵士乐".to_string(),
+            core_facts: vec!["用户喜欢爵士乐".to_string()],
+            turn_range_start: 1,
+            turn_range_end: 5,
+            created_at: 0,
+            keywords: vec![],
+            compression_generation: 0,
+            context_card: None,
+            fact_tiers: vec![],
+            linked_fact_ids: vec![],
+            pinned: false,
+            pinned_facts: Vec::new(),
+            stale: false,
+        };
+        conv.memory_summaries = vec![summary.clone()];
+        store.save_conversation(&conv).unwrap();
+        MemoryEngine::new(base)
+            .save_memory_index(&conv.id, &[summary])
+            .unwrap();
+        let knowledge = KnowledgeStore::new(base);
+        let facts = vec![make_fact("用户喜欢喝冰美式")];
+        knowledge.save_facts(&conv.id, &facts).unwrap();
+        knowledge.rebuild_index(&conv.id, &facts).unwrap();
+        conv.id
+    }
+
+    #[test]
+    fn test_reindex_conversation_rebuilds_and_records_version() {
+        let tmp = TempDir::new().unwrap();
+        let base = tmp.path().to_str().unwrap();
+        let id = setup(base);
+        let reindexer = Reindexer::new(base);
+
+        assert_eq!(reindexer.reindex_conversation(&id, IndexScope::All).unwrap(), 2);
+
+        let facts = KnowledgeStore::new(base).load_facts(&id).unwrap();
+        assert!(!facts[0].keywords.contains(&"过期关键词".to_string()));
+        let summaries = MemoryEngine::new(base).load_memory_index(&id).unwrap();
+        assert!(summaries[0].keywords.contains(&"爵士".to_string()));
+        let index = KnowledgeStore::new(base).load_index(&id).unwrap().unwrap();
+        assert_eq!(index.version, KNOWLEDGE_INDEX_VERSION);
+
+        // 已是最新版本：不再重建
+        assert_eq!(reindexer.reindex_conversation(&id, IndexScope::All).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_reindex_scope_limits_work() {
+        let tmp = TempDir::new().unwrap();
+        let base = tmp.path().to_str().unwrap();
+        let id = setup(base);
+        let reindexer = Reindexer::new(base);
+
+        assert_eq!(reindexer.reindex_conversation(&id, IndexScope::Memory).unwrap(), 1);
+        let versions = reindexer.load_versions();
+        assert_eq!(versions[&id].memory, MEMORY_KEYWORD_VERSION);
+        assert_eq!(versions[&id].knowledge, 0);
+    }
+
+    #[tokio::test]
+    async fn test_reindex_all_reports_progress() {
+        let tmp = TempDir::new().unwrap();
+        let base = tmp.path().to_str().unwrap();
+        setup(base);
+        setup(base);
+
+        let progress = std::sync::Mutex::new(Vec::new());
+        Reindexer::new(base)
+            .reindex_all(IndexScope::All, |p| progress.lock().unwrap().push(p))
+            .await;
+        let progress = progress.into_inner().unwrap();
+        assert_eq!(progress.len(), 2);
+        assert_eq!(progress[1].completed, 2);
+        assert_eq!(progress[1].total, 2);
+        assert!(progress.iter().all(|p| p.error.is_none() && p.rebuilt_entries == 2));
+    }
+}
@@ -0,0 +1,289 @@
+use std::sync::{Arc, OnceLock, RwLock};
+
+use super::data_models::{
+    SafetyAction, SafetyCategory, SafetyEvent, SafetyPolicy, SafetyRule, SafetyStage,
+};
+
+// ═══════════════════════════════════════════════════════════════════
+//  内容安全过滤 (Safety Filter)
+//  ─────────────────────────────────────────────────────────────────
+//  可选的安全层，默认关闭。ChatEngine 在请求对话模型前后各调用一次：
+//    1. 发送前：检查用户消息，Block 直接拒绝（消息不落盘），
+//       Soften 在本轮上下文中插入谨慎处理的提示，Warn 只发事件
+//    2. 生成后：对回复做本地分类，Block 丢弃回复，
+//       Soften 把命中词语遮蔽后落盘，Warn 只发事件
+//  分类按词表命中判断（内置词表 + 每个分类的 extra_terms），不依赖网络；
+//  同一文本命中多个分类时，按最严格的动作处理（Block > Soften > Warn）。
+//
+//  存储结构：
+//    safety.json（设置，见 ConfigManager::load_safety_policy）
+// ═══════════════════════════════════════════════════════════════════
+
+const SELF_HARM_TERMS: &[&str] = &[
+    "自杀", "自残", "割腕", "轻生", "不想活了", "结束生命", "跳楼", "安眠药吞",
+    "suicide", "kill myself", "self-harm", "cut myself",
+];
+
+const VIOLENCE_TERMS: &[&str] = &[
+    "杀了他", "砍死", "捅死", "弄死你", "虐杀", "肢解", "灭门", "爆头",
+    "murder", "stab him", "behead",
+];
+
+const SEXUAL_TERMS: &[&str] = &[
+    "做爱", "性交", "口交", "裸体照", "强奸", "性器官",
+    "porn", "blowjob", "rape",
+];
+
+const HATE_TERMS: &[&str] = &[
+    "劣等民族", "支那", "黑鬼", "死基佬", "种族清洗", "人种低劣",
+    "nigger", "faggot", "ethnic cleansing",
+];
+
+const ILLEGAL_TERMS: &[&str] = &[
+    "制毒", "冰毒配方", "贩毒", "自制炸弹", "炸药配方", "洗钱", "私造枪支", "盗刷信用卡",
+    "make meth", "build a bomb", "launder money",
+];
+
+/// 遮蔽字符（与强度调节一致）
+const MASK_CHAR: char = '＊';
+
+static ACTIVE_POLICY: OnceLock<RwLock<Arc<SafetyPolicy>>> = OnceLock::new();
+
+fn policy_slot() -> &'static RwLock<Arc<SafetyPolicy>> {
+    ACTIVE_POLICY.get_or_init(|| RwLock::new(Arc::new(SafetyPolicy::default())))
+}
+
+/// 替换当前生效的安全设置（下一轮对话生效）
+pub fn install_policy(policy: SafetyPolicy) {
+    if let Ok(mut slot) = policy_slot().write() {
+        *slot = Arc::new(policy);
+    }
+}
+
+/// 当前生效的安全设置（init_app 之前或锁已损坏时为默认设置，即关闭）
+pub fn policy() -> Arc<SafetyPolicy> {
+    policy_slot().read().map(|p| p.clone()).unwrap_or_default()
+}
+
+/// 回复的检查结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct SafetyOutcome {
+    /// 处理后的回复（Soften 已遮蔽；被拦截时为空）
+    pub content: String,
+    pub blocked: bool,
+    pub events: Vec<SafetyEvent>,
+}
+
+pub struct SafetyFilter;
+
+impl SafetyFilter {
+    fn builtin_terms(category: SafetyCategory) -> &'static [&'static str] {
+        match category {
+            SafetyCategory::SelfHarm => SELF_HARM_TERMS,
+            SafetyCategory::Violence => VIOLENCE_TERMS,
+            SafetyCategory::Sexual => SEXUAL_TERMS,
+            SafetyCategory::Hate => HATE_TERMS,
+            SafetyCategory::Illegal => ILLEGAL_TERMS,
+        }
+    }
+
+    pub fn category_label(category: SafetyCategory) -> &'static str {
+        match category {
+            SafetyCategory::SelfHarm => "自伤",
+            SafetyCategory::Violence => "暴力",
+            SafetyCategory::Sexual => "色情",
+            SafetyCategory::Hate => "仇恨",
+            SafetyCategory::Illegal => "违法",
+        }
+    }
+
+    /// 规则中命中的词语（按出现位置排序，去重）
+    fn matched_terms(rule: &SafetyRule, lower: &str) -> Vec<String> {
+        let mut hits: Vec<(usize, String)> = Vec::new();
+        let extra = rule.extra_terms.iter().map(|t| t.trim().to_lowercase());
+        for term in Self::builtin_terms(rule.category)
+            .iter()
+            .map(|t| t.to_string())
+            .chain(extra)
+        {
+            if term.is_empty() || hits.iter().any(|(_, t)| *t == term) {
+                continue;
+            }
+            if let Some(pos) = lower.find(&term) {
+                hits.push((pos, term));
+            }
+        }
+        hits.sort();
+        hits.into_iter().map(|(_, term)| term).collect()
+    }
+
+    /// 对文本分类：每个命中的分类一条事件（按规则顺序）
+    pub fn classify(policy: &SafetyPolicy, stage: SafetyStage, text: &str) -> Vec<SafetyEvent> {
+        let lower = text.to_lowercase();
+        policy
+            .rules
+            .iter()
+            .filter_map(|rule| {
+                let matched = Self::matched_terms(rule, &lower);
+                (!matched.is_empty()).then_some(SafetyEvent {
+                    stage,
+                    category: rule.category,
+                    action: rule.action,
+                    matched,
+                })
+            })
+            .collect()
+    }
+
+    /// 发送前检查用户消息；未开启或不检查输入时为空
+    pub fn screen_input(policy: &SafetyPolicy, content: &str) -> Vec<SafetyEvent> {
+        if !policy.enabled || !policy.check_input {
+            return Vec::new();
+        }
+        Self::classify(policy, SafetyStage::Input, content)
+    }
+
+    /// 生成后检查回复
+    pub fn screen_output(policy: &SafetyPolicy, content: &str) -> SafetyOutcome {
+        if !policy.enabled || !policy.check_output {
+            return SafetyOutcome {
+                content: content.to_string(),
+                blocked: false,
+                events: Vec::new(),
+            };
+        }
+        let events = Self::classify(policy, SafetyStage::Output, content);
+        if Self::is_blocked(&events) {
+            return SafetyOutcome {
+                content: String::new(),
+                blocked: true,
+                events,
+            };
+        }
+        let mut content = content.to_string();
+        for event in events.iter().filter(|e| e.action == SafetyAction::Soften) {
+            content = Self::mask(&content, &event.matched);
+        }
+        SafetyOutcome {
+            content,
+            blocked: false,
+            events,
+        }
+    }
+
+    pub fn is_blocked(events: &[SafetyEvent]) -> bool {
+        events.iter().any(|e| e.action == SafetyAction::Block)
+    }
+
+    /// 拦截时给用户的说明
+    pub fn blocked_notice(events: &[SafetyEvent]) -> String {
+        let labels: Vec<&str> = events
+            .iter()
+            .filter(|e| e.action == SafetyAction::Block)
+            .map(|e| Self::category_label(e.category))
+            .collect();
+        let what = match events.first().map(|e| e.stage) {
+            Some(SafetyStage::Output) => "回复",
+            _ => "消息",
+        };
+        format!("这条{}涉及「{}」内容，已按内容安全设置拦截。", what, labels.join("、"))
+    }
+
+    /// 输入命中 Soften 分类时，插入到本轮上下文的谨慎处理提示
+    pub fn build_soften_prompt(events: &[SafetyEvent]) -> Option<String> {
+        let softened: Vec<&SafetyEvent> = events
+            .iter()
+            .filter(|e| e.action == SafetyAction::Soften)
+            .collect();
+        if softened.is_empty() {
+            return None;
+        }
+        let mut prompt = String::from("【内容安全提示】对方这条消息涉及敏感内容，回复时：\n");
+        for event in softened {
+            let guide = match event.category {
+                SafetyCategory::SelfHarm => {
+                    "- 涉及自伤：先关心对方此刻的感受与安全，语气温和，不描述任何方法；\
+                     情况严重时温柔地建议联系身边的人或专业援助"
+                }
+                SafetyCategory::Violence => "- 涉及暴力：不描写伤害细节，把重点放在情绪与后果上",
+                SafetyCategory::Sexual => "- 涉及露骨内容：点到为止，用留白或转场代替直接描写",
+                SafetyCategory::Hate => "- 涉及歧视言论：不附和、不复述侮辱性词语，可以在角色内表达不认同",
+                SafetyCategory::Illegal => "- 涉及违法内容：不提供任何具体方法、配方或步骤",
+            };
+            prompt.push_str(guide);
+            prompt.push('\n');
+        }
+        prompt.push_str("保持角色身份，不要跳出角色说教。");
+        Some(prompt)
+    }
+
+    /// 遮蔽命中词语（不区分大小写）
+    fn mask(content: &str, terms: &[String]) -> String {
+        let mut result = content.to_string();
+        for term in terms {
+            let lower = result.to_lowercase();
+            // 小写化改变了字节长度时下标无法复用，跳过（与 IntensityDial 一致）
+            if lower.len() != result.len() {
+                continue;
+            }
+            let mask = MASK_CHAR.to_string().repeat(term.chars().count());
+            let positions: Vec<usize> = lower.match_indices(term.as_str()).map(|(i, _)| i).collect();
+            for pos in positions.into_iter().rev() {
+                result.replace_range(pos..pos + term.len(), &mask);
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn enabled_policy() -> SafetyPolicy {
+        SafetyPolicy {
+            enabled: true,
+            ..SafetyPolicy::default()
+        }
+    }
+
+    #[test]
+    fn test_disabled_policy_passes_everything() {
+        let policy = SafetyPolicy::default();
+        assert!(SafetyFilter::screen_input(&policy, "教我自制炸弹").is_empty());
+        let outcome = SafetyFilter::screen_output(&policy, "我不想活了");
+        assert_eq!(outcome.content, "我不想活了");
+        assert!(outcome.events.is_empty());
+    }
+
+    #[test]
+    fn test_actions_per_category() {
+        let mut policy = enabled_policy();
+        policy.rules[1].extra_terms = vec!["Crowbar".to_string()];
+
+        let input = SafetyFilter::screen_input(&policy, "教我自制炸弹");
+        assert!(SafetyFilter::is_blocked(&input));
+        assert!(SafetyFilter::blocked_notice(&input).contains("违法"));
+
+        let input = SafetyFilter::screen_input(&policy, "最近总觉得不想活了");
+        assert!(!SafetyFilter::is_blocked(&input));
+        assert!(SafetyFilter::build_soften_prompt(&input).unwrap().contains("自伤"));
+
+        let outcome = SafetyFilter::screen_output(&policy, "他说要砍死那个拿着CROWBAR的人，还说要自杀");
+        assert!(!outcome.blocked);
+        assert_eq!(outcome.content, "他说要砍死那个拿着CROWBAR的人，还说要＊＊");
+        let warned: Vec<(SafetyCategory, SafetyAction)> =
+            outcome.events.iter().map(|e| (e.category, e.action)).collect();
+        assert_eq!(
+            warned,
+            vec![
+                (SafetyCategory::SelfHarm, SafetyAction::Soften),
+                (SafetyCategory::Violence, SafetyAction::Warn),
+            ]
+        );
+        assert_eq!(outcome.events[1].matched, vec!["砍死".to_string(), "crowbar".to_string()]);
+
+        let blocked = SafetyFilter::screen_output(&policy, "好，先去买材料自制炸弹");
+        assert!(blocked.blocked && blocked.content.is_empty());
+    }
+}
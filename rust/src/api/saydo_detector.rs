@@ -1,4 +1,33 @@
-use super::data_models::MessageType;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use flutter_rust_bridge::frb;
+use regex::Regex;
+
+use super::data_models::{MessageType, SayDoMark, SayDoRule, SayDoRuleSet};
+use super::error_handler::ChatError;
+use super::store_cache::{self, FileStamp};
+use super::text_utils;
+
+/// 用户输入超过该字数（且为 Do/Mixed）才规划回复骨架
+const SKELETON_MIN_INPUT_CHARS: usize = 60;
+/// 骨架 prompt 中带入的上一条回复长度（字符）
+const SKELETON_CONTEXT_CHARS: usize = 200;
+/// 单个节拍的最大字数（超长说明模型在写正文而非骨架）
+const SKELETON_BEAT_MAX_CHARS: usize = 40;
+/// 骨架节拍标签：反应 → 动作 → 钩子
+const SKELETON_BEATS: [&str; 3] = ["反应", "动作", "钩子"];
+/// 每个对话最多的自定义规则数
+const MAX_CUSTOM_RULES: usize = 20;
+/// 以这些标记开头的消息是场外交流（比较时忽略大小写）
+const OOC_PREFIXES: [&str; 4] = ["ooc:", "ooc：", "((", "（（"];
+/// 消息中任意位置出现这些标记即含场外交流
+const OOC_BLOCK_MARKERS: [&str; 4] = ["(ooc", "（ooc", "[ooc", "【ooc"];
+/// 以这些标记开头的消息是旁白
+const NARRATION_PREFIXES: [&str; 8] = [
+    "旁白：", "旁白:", "【旁白】", "[旁白]", "（旁白）", "narrator:", "[narrator]", "[narration]",
+];
 
 pub struct SayDoDetector;
 
@@ -8,6 +37,9 @@ impl SayDoDetector {
         if trimmed.is_empty() {
             return MessageType::Say;
         }
+        if let Some(message_type) = Self::detect_frame(trimmed) {
+            return message_type;
+        }
 
         let has_do = Self::has_do_markers(trimmed);
         let has_say = Self::has_say_content(trimmed);
@@ -20,6 +52,97 @@ impl SayDoDetector {
         }
     }
 
+    /// 按对话的自定义规则识别；没有规则时等同于 detect
+    ///
+    /// 规则按优先级从高到低认领文本片段，剩下的文字按 rules.unmatched 计，
+    /// 未指定时交给内置识别
+    pub fn detect_with_rules(content: &str, rules: &CompiledSayDoRules) -> MessageType {
+        if rules.rules.is_empty() && rules.unmatched.is_none() {
+            return Self::detect(content);
+        }
+        let trimmed = content.trim();
+        if trimmed.is_empty() {
+            return MessageType::Say;
+        }
+        if let Some(message_type) = Self::detect_frame(trimmed) {
+            return message_type;
+        }
+
+        let mut claimed = vec![false; trimmed.len()];
+        let (mut has_say, mut has_do) = (false, false);
+        for (regex, mark) in &rules.rules {
+            for found in regex.find_iter(trimmed) {
+                let span = found.start()..found.end();
+                if span.is_empty() || claimed[span.clone()].iter().any(|&c| c) {
+                    continue;
+                }
+                claimed[span].iter_mut().for_each(|c| *c = true);
+                match mark {
+                    SayDoMark::Say => has_say = true,
+                    SayDoMark::Do => has_do = true,
+                }
+            }
+        }
+
+        // 被认领的片段换成空格，避免前后文字粘连
+        let rest: String = trimmed
+            .char_indices()
+            .map(|(i, c)| if claimed[i] { ' ' } else { c })
+            .collect();
+        match rules.unmatched {
+            Some(mark) if rest.chars().any(char::is_alphanumeric) => match mark {
+                SayDoMark::Say => has_say = true,
+                SayDoMark::Do => has_do = true,
+            },
+            Some(_) => {}
+            None if !rest.trim().is_empty() => {
+                has_do |= Self::has_do_markers(&rest);
+                has_say |= Self::has_say_content(&rest);
+            }
+            None => {}
+        }
+
+        match (has_do, has_say) {
+            (true, false) => MessageType::Do,
+            (true, true) => MessageType::Mixed,
+            _ => MessageType::Say,
+        }
+    }
+
+    /// 跳出对白 / 动作框架的消息：场外交流优先于旁白
+    fn detect_frame(text: &str) -> Option<MessageType> {
+        let lower = text.to_lowercase();
+        if OOC_PREFIXES.iter().any(|p| lower.starts_with(p))
+            || OOC_BLOCK_MARKERS.iter().any(|m| lower.contains(m))
+        {
+            return Some(MessageType::OutOfCharacter);
+        }
+        if NARRATION_PREFIXES.iter().any(|p| lower.starts_with(p)) {
+            return Some(MessageType::Narration);
+        }
+        None
+    }
+
+    /// 校验自定义规则（正则可编译、数量不超上限）
+    pub fn validate_rules(rules: &SayDoRuleSet) -> Result<(), ChatError> {
+        if rules.rules.len() > MAX_CUSTOM_RULES {
+            return Err(ChatError::ValidationError {
+                message: format!("At most {} say/do rules are allowed", MAX_CUSTOM_RULES),
+            });
+        }
+        for rule in &rules.rules {
+            if rule.pattern.trim().is_empty() {
+                return Err(ChatError::ValidationError {
+                    message: "Say/do rule pattern must not be empty".to_string(),
+                });
+            }
+            Regex::new(&rule.pattern).map_err(|e| ChatError::ValidationError {
+                message: format!("Invalid say/do rule pattern '{}': {}", rule.pattern, e),
+            })?;
+        }
+        Ok(())
+    }
+
     fn has_do_markers(text: &str) -> bool {
         Self::has_bracket_action(text, '(', ')', 2)
             || Self::has_bracket_action(text, '（', '）', 1)
@@ -171,8 +294,204 @@ impl SayDoDetector {
                  ═══ 禁止 ═══\n\
                  超过6个动作、条目式列举、使用「」引号"
             }
+            MessageType::Document => {
+                "【回复规则·正文模式】\n\
+                 以叙事正文写作，成段推进，不用聊天口吻。\n\
+                 保持既有视角与文风，不加前言后记。"
+            }
+            MessageType::Narration => {
+                "【回复规则·旁白承接】\n\
+                 对方用旁白推进了场景，旁白描述的变化视为已经发生。\n\
+                 以角色身份身处新场景，用动作与对白对这一变化做出反应。\n\n\
+                 ═══ 禁止 ═══\n\
+                 复述或改写旁白、替对方的角色行动、自己也写成旁白"
+            }
+            MessageType::OutOfCharacter => {
+                "【回复规则·场外模式】\n\
+                 对方暂时跳出了角色，以玩家身份和你场外（OOC）交流。\n\
+                 这一条请你同样跳出角色，作为协作写故事的伙伴直接回答：\n\
+                 - 回复用「(OOC: ...)」包裹，语气平实，不带角色口癖\n\
+                 - 回答对方的问题或确认对方的要求，简短具体\n\
+                 - 不推进剧情、不写动作和对白，下一条回复再回到角色中"
+            }
         }
     }
+
+    /// 长动作场景容易写散：Do/Mixed 且输入较长时先规划三拍骨架
+    pub fn needs_skeleton(message_type: &MessageType, content: &str) -> bool {
+        matches!(message_type, MessageType::Do | MessageType::Mixed)
+            && content.trim().chars().count() >= SKELETON_MIN_INPUT_CHARS
+    }
+
+    /// 骨架规划 prompt：只要三行要点，不写正文
+    pub fn build_skeleton_prompt(content: &str, last_reply: Option<&str>) -> String {
+        let mut prompt = String::from("为角色的下一条回复规划骨架。\n");
+        if let Some(reply) = last_reply.filter(|r| !r.trim().is_empty()) {
+            let reply = text_utils::truncate_chars(reply, SKELETON_CONTEXT_CHARS);
+            prompt.push_str(&format!("角色上一条回复：{}\n", reply));
+        }
+        prompt.push_str(&format!("对方刚才：{}\n\n", content.trim()));
+        prompt.push_str(
+            "只输出三行，每行不超过 20 字：\n\
+             反应：角色对这一幕的第一反应\n\
+             动作：角色接下来做的一件事\n\
+             钩子：留给对方接的话头或悬念",
+        );
+        prompt
+    }
+
+    /// 解析三拍骨架；行数不足或某拍过长视为规划失败
+    pub fn parse_skeleton(text: &str) -> Option<Vec<String>> {
+        let beats: Vec<String> = text
+            .lines()
+            .map(|line| {
+                let line = line
+                    .trim()
+                    .trim_start_matches(|c: char| c.is_ascii_digit() || "-*.、) ".contains(c));
+                let line = SKELETON_BEATS
+                    .iter()
+                    .find_map(|label| line.strip_prefix(label))
+                    .unwrap_or(line);
+                line.trim_start_matches([':', '：', ' ']).trim().to_string()
+            })
+            .filter(|line| !line.is_empty())
+            .take(SKELETON_BEATS.len())
+            .collect();
+        if beats.len() < SKELETON_BEATS.len()
+            || beats.iter().any(|b| b.chars().count() > SKELETON_BEAT_MAX_CHARS)
+        {
+            return None;
+        }
+        Some(beats)
+    }
+
+    /// 隐藏引导：只约束结构，不要求照抄
+    pub fn build_skeleton_guidance(beats: &[String]) -> String {
+        let lines: Vec<String> = SKELETON_BEATS
+            .iter()
+            .zip(beats)
+            .enumerate()
+            .map(|(i, (label, beat))| format!("{}. {}：{}", i + 1, label, beat))
+            .collect();
+        format!(
+            "【本轮回复骨架（内部参考，不要照抄或提及）】\n{}\n\
+             按这三拍组织回复，每拍一两句即可，写完钩子就收住，不要继续铺陈。",
+            lines.join("\n")
+        )
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════
+//  自定义 Say/Do 规则 (SayDo Rules)
+//  ─────────────────────────────────────────────────────────────────
+//  内置识别把括号 / 星号当动作、其余当对白，但并非所有人都这样写
+//  （有人用「」写对白、不加标记写动作）。每个对话可以配置自己的
+//  正则规则，未配置时沿用内置识别。
+//
+//  存储结构：
+//    saydo_rules/{conversation_id}.json
+// ═══════════════════════════════════════════════════════════════════
+
+/// 编译好的规则集：正则只在加载 / 保存时编译一次，按优先级从高到低排好
+#[derive(Debug, Clone, Default)]
+pub struct CompiledSayDoRules {
+    rules: Vec<(Regex, SayDoMark)>,
+    unmatched: Option<SayDoMark>,
+}
+
+impl CompiledSayDoRules {
+    /// 编译规则集；无法编译的正则（旧版本保存的文件）直接跳过
+    pub fn compile(rules: &SayDoRuleSet) -> Self {
+        let mut ordered: Vec<&SayDoRule> = rules.rules.iter().collect();
+        ordered.sort_by_key(|rule| std::cmp::Reverse(rule.priority));
+        Self {
+            rules: ordered
+                .into_iter()
+                .filter_map(|rule| Some((Regex::new(&rule.pattern).ok()?, rule.mark)))
+                .collect(),
+            unmatched: rules.unmatched,
+        }
+    }
+}
+
+#[frb(opaque)]
+pub struct SayDoRuleStore {
+    base_path: String,
+}
+
+impl SayDoRuleStore {
+    pub fn new(base_path: &str) -> Self {
+        Self {
+            base_path: base_path.to_string(),
+        }
+    }
+
+    fn rules_dir(&self) -> Result<PathBuf, ChatError> {
+        let dir = PathBuf::from(&self.base_path).join("saydo_rules");
+        if !dir.exists() {
+            fs::create_dir_all(&dir).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to create say/do rules directory: {}", e),
+            })?;
+        }
+        Ok(dir)
+    }
+
+    fn rules_path(&self, conversation_id: &str) -> Result<PathBuf, ChatError> {
+        Ok(self.rules_dir()?.join(format!("{}.json", conversation_id)))
+    }
+
+    /// 对话的自定义规则；未配置或读取失败时为空（即内置识别）
+    pub fn load(&self, conversation_id: &str) -> SayDoRuleSet {
+        self.rules_path(conversation_id)
+            .ok()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default()
+    }
+
+    /// 编译好的规则，优先走内存缓存；规则文件被改写后重新读取并编译
+    pub fn load_compiled(&self, conversation_id: &str) -> Arc<CompiledSayDoRules> {
+        let Ok(path) = self.rules_path(conversation_id) else {
+            return Arc::default();
+        };
+        if let Some(compiled) = store_cache::saydo_rules().get(&path) {
+            return compiled;
+        }
+        let stamp = FileStamp::of(&path);
+        let compiled = Arc::new(CompiledSayDoRules::compile(&self.load(conversation_id)));
+        store_cache::saydo_rules().put(&path, stamp, compiled.clone());
+        compiled
+    }
+
+    /// 保存规则；规则为空时删除文件，恢复内置识别
+    pub fn save(&self, conversation_id: &str, rules: &SayDoRuleSet) -> Result<(), ChatError> {
+        SayDoDetector::validate_rules(rules)?;
+        if rules.rules.is_empty() && rules.unmatched.is_none() {
+            return self.delete(conversation_id);
+        }
+        let path = self.rules_path(conversation_id)?;
+        let json = serde_json::to_string_pretty(rules).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to serialize say/do rules: {}", e),
+        })?;
+        let written = fs::write(&path, json).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to write say/do rules: {}", e),
+        });
+        // 写失败时快照为 None，等同于让缓存失效
+        let stamp = written.as_ref().ok().and_then(|_| FileStamp::of(&path));
+        let compiled = Arc::new(CompiledSayDoRules::compile(rules));
+        store_cache::saydo_rules().put(&path, stamp, compiled);
+        written
+    }
+
+    pub fn delete(&self, conversation_id: &str) -> Result<(), ChatError> {
+        let path = self.rules_path(conversation_id)?;
+        if path.exists() {
+            fs::remove_file(&path).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to delete say/do rules: {}", e),
+            })?;
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -235,6 +554,114 @@ mod tests {
         assert_eq!(SayDoDetector::detect("你好 :)"), MessageType::Say);
     }
 
+    #[test]
+    fn test_skeleton_only_for_long_action_scenes() {
+        let long = "（推开门，雨水顺着伞尖滴在玄关，看到你蜷在沙发上睡着了，电视还亮着，茶几上的泡面已经凉透，我把湿外套挂好，蹲下来看了你好一会儿）";
+        assert!(SayDoDetector::needs_skeleton(&MessageType::Do, long));
+        assert!(!SayDoDetector::needs_skeleton(&MessageType::Say, long));
+        assert!(!SayDoDetector::needs_skeleton(&MessageType::Mixed, "（摸摸头）乖"));
+
+        let beats = SayDoDetector::parse_skeleton(
+            "1. 反应：愣了一下，心软\n2. 动作：轻手关掉电视\n3. 钩子：「又没吃晚饭？」",
+        )
+        .unwrap();
+        assert_eq!(beats, vec!["愣了一下，心软", "轻手关掉电视", "「又没吃晚饭？」"]);
+        assert!(SayDoDetector::build_skeleton_guidance(&beats).contains("2. 动作：轻手关掉电视"));
+        assert!(SayDoDetector::parse_skeleton("反应：愣住").is_none());
+    }
+
+    #[test]
+    fn test_detect_narration_and_ooc() {
+        assert_eq!(
+            SayDoDetector::detect("(OOC: 这段剧情是不是推进得太快了？)"),
+            MessageType::OutOfCharacter
+        );
+        assert_eq!(
+            SayDoDetector::detect("你好呀（OOC：我明天要出差，先暂停一下）"),
+            MessageType::OutOfCharacter
+        );
+        assert_eq!(SayDoDetector::detect("((brb))"), MessageType::OutOfCharacter);
+        assert_eq!(
+            SayDoDetector::detect("【旁白】夜幕降临，雨越下越大。"),
+            MessageType::Narration
+        );
+        assert_eq!(
+            SayDoDetector::detect("Narrator: The storm rolls in."),
+            MessageType::Narration
+        );
+        assert!(SayDoDetector::build_style_prompt(&MessageType::OutOfCharacter).contains("OOC"));
+    }
+
+    #[test]
+    fn test_custom_rules_take_precedence() {
+        // 「」写对白、不加标记写动作
+        let rules = SayDoRuleSet {
+            rules: vec![SayDoRule {
+                pattern: "「[^」]*」".to_string(),
+                mark: SayDoMark::Say,
+                priority: 0,
+            }],
+            unmatched: Some(SayDoMark::Do),
+        };
+        let compiled = CompiledSayDoRules::compile(&rules);
+        assert_eq!(
+            SayDoDetector::detect_with_rules("走到窗边「今天天气真好」", &compiled),
+            MessageType::Mixed
+        );
+        assert_eq!(SayDoDetector::detect_with_rules("「早上好」", &compiled), MessageType::Say);
+        assert_eq!(
+            SayDoDetector::detect_with_rules("推开门走了进来。", &compiled),
+            MessageType::Do
+        );
+
+        // 高优先级先认领：[[...]] 是对白，覆盖低优先级的「方括号是动作」
+        let rules = SayDoRuleSet {
+            rules: vec![
+                SayDoRule {
+                    pattern: r"\[[^\]]+\]".to_string(),
+                    mark: SayDoMark::Do,
+                    priority: 0,
+                },
+                SayDoRule {
+                    pattern: r"\[\[[^\]]+\]\]".to_string(),
+                    mark: SayDoMark::Say,
+                    priority: 10,
+                },
+            ],
+            unmatched: None,
+        };
+        let compiled = CompiledSayDoRules::compile(&rules);
+        assert_eq!(SayDoDetector::detect_with_rules("[[你好]]", &compiled), MessageType::Say);
+        assert_eq!(SayDoDetector::detect_with_rules("[挥手]", &compiled), MessageType::Do);
+        // 未命中的部分交给内置识别
+        assert_eq!(SayDoDetector::detect_with_rules("[挥手] 你好", &compiled), MessageType::Mixed);
+        assert_eq!(
+            SayDoDetector::detect_with_rules("(走过来)", &CompiledSayDoRules::default()),
+            MessageType::Do
+        );
+
+        let tmp = tempfile::TempDir::new().unwrap();
+        let store = SayDoRuleStore::new(tmp.path().to_str().unwrap());
+        let invalid = SayDoRuleSet {
+            rules: vec![SayDoRule {
+                pattern: "(".to_string(),
+                mark: SayDoMark::Do,
+                priority: 0,
+            }],
+            unmatched: None,
+        };
+        assert!(store.save("c1", &invalid).is_err());
+        store.save("c1", &rules).unwrap();
+        assert_eq!(store.load("c1"), rules);
+        let cached = store.load_compiled("c1");
+        assert_eq!(SayDoDetector::detect_with_rules("[挥手]", &cached), MessageType::Do);
+        store.save("c1", &SayDoRuleSet::default()).unwrap();
+        assert_eq!(store.load("c1"), SayDoRuleSet::default());
+        // 保存时换掉缓存的编译结果
+        let cached = store.load_compiled("c1");
+        assert_eq!(SayDoDetector::detect_with_rules("[挥手]", &cached), MessageType::Say);
+    }
+
     #[test]
     fn test_build_style_prompt() {
         let prompt = SayDoDetector::build_style_prompt(&MessageType::Say);
@@ -0,0 +1,234 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+
+use super::error_handler::ChatError;
+use super::memory_engine::MemoryEngine;
+
+// ═══════════════════════════════════════════════════════════════════
+//  落盘格式版本与迁移 (Schema Migration)
+//  ─────────────────────────────────────────────────────────────────
+//  MemorySummary 与 Fact 不断增加字段，旧文件缺少必填字段时整个文件
+//  解析失败，读取方退回空列表，数据就此丢失。现在记忆索引与事实文件
+//  统一写成带版本号的信封：
+//    {"schema_version": N, "items": [...]}
+//  读取时按版本逐级执行迁移（每步只改 JSON，不依赖旧结构体），
+//  升级过的文件由读取方立即按当前版本回写。
+//
+//  历史版本：
+//    v0：无信封的裸数组（引入版本号之前写下的全部文件）；
+//        早期版本的条目可能缺少 id、keywords 等后来才加入的必填字段
+//    v1：信封格式，条目字段齐全
+//  新增必填字段时：在 MIGRATIONS 末尾追加一步，版本号随之加一。
+//  比当前版本新的文件（高版本 App 写下的）拒绝读取，避免回写时丢字段。
+// ═══════════════════════════════════════════════════════════════════
+
+/// 版本化的文件种类：各自维护迁移步骤
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchemaKind {
+    /// memory_index/{scope}.json（MemorySummary 列表）
+    MemoryIndex,
+    /// knowledge_base 下的事实、归档事实与用户档案（Fact 列表）
+    Facts,
+}
+
+/// 单步迁移：把一个条目从版本 i 升到 i + 1
+type Migration = fn(&mut Map<String, Value>);
+
+const MEMORY_INDEX_MIGRATIONS: [Migration; 1] = [memory_summary_v0_to_v1];
+const FACT_MIGRATIONS: [Migration; 1] = [fact_v0_to_v1];
+
+impl SchemaKind {
+    fn migrations(self) -> &'static [Migration] {
+        match self {
+            SchemaKind::MemoryIndex => &MEMORY_INDEX_MIGRATIONS,
+            SchemaKind::Facts => &FACT_MIGRATIONS,
+        }
+    }
+
+    /// 当前写入的版本
+    pub fn current_version(self) -> u32 {
+        self.migrations().len() as u32
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            SchemaKind::MemoryIndex => "memory index",
+            SchemaKind::Facts => "facts",
+        }
+    }
+}
+
+/// 缺失或为 null 时按 default 补上
+fn fill(
+    item: &mut Map<String, Value>,
+    key: &str,
+    default: impl FnOnce(&Map<String, Value>) -> Value,
+) {
+    if item.get(key).is_none_or(Value::is_null) {
+        let value = default(item);
+        item.insert(key.to_string(), value);
+    }
+}
+
+fn keywords_of(item: &Map<String, Value>, text_key: &str) -> Value {
+    let text = item
+        .get(text_key)
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    json!(MemoryEngine::extract_keywords(text))
+}
+
+fn memory_summary_v0_to_v1(item: &mut Map<String, Value>) {
+    fill(item, "id", |_| json!(uuid::Uuid::new_v4().to_string()));
+    fill(item, "summary", |_| json!(""));
+    fill(item, "core_facts", |_| json!([]));
+    fill(item, "turn_range_start", |_| json!(0));
+    fill(item, "turn_range_end", |i| {
+        i.get("turn_range_start").cloned().unwrap_or(json!(0))
+    });
+    fill(item, "created_at", |_| json!(0));
+    fill(item, "keywords", |i| keywords_of(i, "summary"));
+}
+
+fn fact_v0_to_v1(item: &mut Map<String, Value>) {
+    fill(item, "id", |_| json!(uuid::Uuid::new_v4().to_string()));
+    // 分类缺失时按「当前状态」处理：优先级最低，可被新事实覆盖
+    fill(item, "category", |_| json!("CurrentState"));
+    fill(item, "source_turn", |_| json!(0));
+    fill(item, "created_at", |_| json!(0));
+    fill(item, "last_confirmed_at", |i| {
+        i.get("created_at").cloned().unwrap_or(json!(0))
+    });
+    fill(item, "keywords", |i| keywords_of(i, "content"));
+    fill(item, "entities", |_| json!([]));
+    fill(item, "confidence", |_| json!(0.5));
+    fill(item, "hit_count", |_| json!(0));
+    fill(item, "context_snippet", |_| json!(""));
+}
+
+/// 解析任意版本的文件，返回（当前版本的条目, 是否做过迁移）
+pub fn decode<T: DeserializeOwned>(
+    kind: SchemaKind,
+    json: impl AsRef<[u8]>,
+) -> Result<(Vec<T>, bool), ChatError> {
+    let parse_err = |e: String| ChatError::StorageError {
+        message: format!("Failed to parse {}: {}", kind.label(), e),
+    };
+    let root: Value = serde_json::from_slice(json.as_ref()).map_err(|e| parse_err(e.to_string()))?;
+    let (version, items) = match root {
+        Value::Array(items) => (0, items),
+        Value::Object(mut envelope) => {
+            let version = envelope
+                .get("schema_version")
+                .and_then(Value::as_u64)
+                .ok_or_else(|| parse_err("missing schema_version".to_string()))?
+                as u32;
+            match envelope.remove("items") {
+                Some(Value::Array(items)) => (version, items),
+                _ => return Err(parse_err("missing items".to_string())),
+            }
+        }
+        _ => return Err(parse_err("unexpected top-level value".to_string())),
+    };
+
+    let current = kind.current_version();
+    if version > current {
+        return Err(parse_err(format!(
+            "schema version {} is newer than supported {}",
+            version, current
+        )));
+    }
+    let migrations = &kind.migrations()[version as usize..];
+    let mut parsed = Vec::with_capacity(items.len());
+    for (index, mut item) in items.into_iter().enumerate() {
+        if let Value::Object(map) = &mut item {
+            for migrate in migrations {
+                migrate(map);
+            }
+        }
+        parsed.push(
+            serde_json::from_value(item)
+                .map_err(|e| parse_err(format!("item {}: {}", index, e)))?,
+        );
+    }
+    Ok((parsed, version < current))
+}
+
+/// 按当前版本写成信封
+pub fn encode<T: Serialize>(kind: SchemaKind, items: &[T]) -> Result<String, ChatError> {
+    serde_json::to_string_pretty(&json!({
+        "schema_version": kind.current_version(),
+        "items": items,
+    }))
+    .map_err(|e| ChatError::StorageError {
+        message: format!("Failed to serialize {}: {}", kind.label(), e),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::data_models::MemorySummary;
+    use crate::api::knowledge_store::{Fact, FactCategory};
+
+    #[test]
+    fn test_memory_index_historical_formats() {
+        // v0：最早的格式，缺少 id 与 keywords
+        let early = r#"[{"summary":"我们在海边散步","core_facts":["小雨怕水"],
+            "turn_range_start":1,"turn_range_end":10,"created_at":5}]"#;
+        let (summaries, upgraded) =
+            decode::<MemorySummary>(SchemaKind::MemoryIndex, early).unwrap();
+        assert!(upgraded);
+        assert!(!summaries[0].id.is_empty());
+        assert!(!summaries[0].keywords.is_empty());
+        assert_eq!(summaries[0].core_facts, vec!["小雨怕水".to_string()]);
+
+        // v0：字段齐全的裸数组（分级、卡片等可选字段缺省）
+        let bare = r#"[{"id":"s1","summary":"s","core_facts":[],"turn_range_start":1,
+            "turn_range_end":2,"created_at":0,"keywords":["海边"],"compression_generation":2}]"#;
+        let (summaries, upgraded) = decode::<MemorySummary>(SchemaKind::MemoryIndex, bare).unwrap();
+        assert!(upgraded);
+        assert_eq!(summaries[0].keywords, vec!["海边".to_string()]);
+        assert_eq!(summaries[0].compression_generation, 2);
+
+        // v1：当前格式原样读取，不需要回写
+        let encoded = encode(SchemaKind::MemoryIndex, &summaries).unwrap();
+        let (roundtrip, upgraded) =
+            decode::<MemorySummary>(SchemaKind::MemoryIndex, &encoded).unwrap();
+        assert!(!upgraded);
+        assert_eq!(roundtrip, summaries);
+
+        let future = r#"{"schema_version":99,"items":[]}"#;
+        assert!(decode::<MemorySummary>(SchemaKind::MemoryIndex, future).is_err());
+    }
+
+    #[test]
+    fn test_fact_historical_formats() {
+        // v0：早期事实没有置信度、命中次数、实体与确认时间
+        let early = r#"[{"id":"f1","content":"小雨喜欢猫","category":"Preference",
+            "source_turn":3,"created_at":100}]"#;
+        let (facts, upgraded) = decode::<Fact>(SchemaKind::Facts, early).unwrap();
+        assert!(upgraded);
+        assert_eq!(facts[0].category, FactCategory::Preference);
+        assert_eq!(facts[0].last_confirmed_at, 100);
+        assert_eq!(facts[0].confidence, 0.5);
+        assert!(!facts[0].keywords.is_empty());
+
+        // v0：基线版本的完整字段（无取代 / 人设等后加的可选字段）
+        let baseline = r#"[{"id":"f2","content":"c","category":"Event","source_turn":1,
+            "created_at":0,"last_confirmed_at":0,"keywords":[],"entities":["小雨"],
+            "confidence":0.9,"hit_count":4,"context_snippet":""}]"#;
+        let (facts, _) = decode::<Fact>(SchemaKind::Facts, baseline).unwrap();
+        assert_eq!(facts[0].hit_count, 4);
+        assert!(facts[0].superseded_by.is_none());
+
+        let encoded = encode(SchemaKind::Facts, &facts).unwrap();
+        let (roundtrip, upgraded) = decode::<Fact>(SchemaKind::Facts, &encoded).unwrap();
+        assert!(!upgraded);
+        assert_eq!(roundtrip[0].entities, vec!["小雨".to_string()]);
+
+        // 无法补全的条目（没有正文）报错而不是悄悄丢弃
+        assert!(decode::<Fact>(SchemaKind::Facts, r#"[{"id":"x"}]"#).is_err());
+    }
+}
@@ -0,0 +1,384 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+
+use super::at_rest;
+use super::conversation_store::ConversationStore;
+use super::data_models::{MessageRole, SearchHit};
+use super::error_handler::ChatError;
+
+// ═══════════════════════════════════════════════════════════════════
+//  全文搜索 (Full-text Search)
+//  ─────────────────────────────────────────────────────────────────
+//  跨全部对话按内容查找消息（「那次聊海边旅行是哪天」）：
+//    - 倒排索引：词项 → 消息。中日韩文字按单字 + 相邻二字切分，
+//      其余文字按连续字母数字切成小写单词
+//    - 增量更新：每次搜索前比对各对话的 updated_at，只重建变动过的对话；
+//      被删除 / 重建的对话留下空位，空位过半时整体重建
+//    - 查询：各词项的倒排表求交得到候选，再按原文逐段（空白分隔）
+//      连续匹配确认，命中次数多的在前，同分按时间从新到旧
+//  索引只是缓存：读取失败或版本不符时直接重建。内容派生的词项同样受
+//  静态加密保护（见 at_rest）。
+//
+//  存储结构：
+//    search_index/index.bin
+// ═══════════════════════════════════════════════════════════════════
+
+const INDEX_VERSION: u32 = 1;
+/// 单次搜索最多返回的命中数
+pub const MAX_SEARCH_HITS: usize = 50;
+/// 片段中命中词之前 / 之后保留的字符数
+const SNIPPET_BEFORE: usize = 20;
+const SNIPPET_AFTER: usize = 40;
+
+/// 同一时间只允许一个搜索读写索引文件
+static INDEX_LOCK: Mutex<()> = Mutex::new(());
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct DocRef {
+    conversation_id: String,
+    message_id: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct IndexData {
+    version: u32,
+    /// 对话ID → 建索引时的 updated_at
+    indexed_at: HashMap<String, i64>,
+    /// 文档即一条消息；失效的位置为 None
+    docs: Vec<Option<DocRef>>,
+    /// 词项 → 文档下标（升序）
+    postings: HashMap<String, Vec<u32>>,
+}
+
+impl IndexData {
+    fn live_docs(&self) -> usize {
+        self.docs.iter().filter(|d| d.is_some()).count()
+    }
+
+    fn remove_conversation(&mut self, conversation_id: &str) {
+        self.indexed_at.remove(conversation_id);
+        for doc in &mut self.docs {
+            if doc.as_ref().is_some_and(|d| d.conversation_id == conversation_id) {
+                *doc = None;
+            }
+        }
+    }
+
+    fn add_conversation(&mut self, store: &ConversationStore, conversation_id: &str, updated_at: i64) {
+        self.remove_conversation(conversation_id);
+        let Ok(conv) = store.load_conversation(conversation_id) else {
+            return;
+        };
+        for message in conv.messages.iter().filter(|m| m.role != MessageRole::System) {
+            let doc = self.docs.len() as u32;
+            self.docs.push(Some(DocRef {
+                conversation_id: conversation_id.to_string(),
+                message_id: message.id.clone(),
+            }));
+            for term in terms(&message.content) {
+                self.postings.entry(term).or_default().push(doc);
+            }
+        }
+        self.indexed_at.insert(conversation_id.to_string(), updated_at);
+    }
+}
+
+fn is_cjk(c: char) -> bool {
+    matches!(c,
+        '\u{3040}'..='\u{30ff}'   // 假名
+        | '\u{3400}'..='\u{4dbf}' // 扩展 A
+        | '\u{4e00}'..='\u{9fff}' // 基本汉字
+        | '\u{ac00}'..='\u{d7af}' // 谚文
+        | '\u{f900}'..='\u{faff}')
+}
+
+/// 单字符小写化（保持字符数不变，原文与折叠后的下标一一对应）
+fn fold(text: &str) -> Vec<char> {
+    text.chars()
+        .map(|c| c.to_lowercase().next().unwrap_or(c))
+        .collect()
+}
+
+/// 切分词项：中日韩文字取单字与相邻二字，其余取连续字母数字组成的单词
+fn terms(text: &str) -> HashSet<String> {
+    let chars = fold(text);
+    let mut out = HashSet::new();
+    let mut word = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if is_cjk(c) {
+            out.insert(c.to_string());
+            if let Some(&next) = chars.get(i + 1).filter(|n| is_cjk(**n)) {
+                out.insert([c, next].iter().collect());
+            }
+        }
+        if c.is_alphanumeric() && !is_cjk(c) {
+            word.push(c);
+        } else if !word.is_empty() {
+            out.insert(std::mem::take(&mut word));
+        }
+    }
+    if !word.is_empty() {
+        out.insert(word);
+    }
+    out
+}
+
+/// needle 在 haystack 中的全部起点
+fn find_all(haystack: &[char], needle: &[char]) -> Vec<usize> {
+    if needle.is_empty() || needle.len() > haystack.len() {
+        return Vec::new();
+    }
+    haystack
+        .windows(needle.len())
+        .enumerate()
+        .filter(|(_, w)| *w == needle)
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// 命中处前后的片段，返回 (片段, 命中词在片段中的字符区间)
+fn snippet(content: &[char], start: usize, len: usize) -> (String, u32, u32) {
+    let from = start.saturating_sub(SNIPPET_BEFORE);
+    let to = (start + len + SNIPPET_AFTER).min(content.len());
+    let mut out = String::new();
+    let mut offset = 0;
+    if from > 0 {
+        out.push('…');
+        offset = 1;
+    }
+    out.extend(content[from..to].iter().map(|c| if *c == '\n' { ' ' } else { *c }));
+    if to < content.len() {
+        out.push('…');
+    }
+    let highlight_start = (offset + start - from) as u32;
+    (out, highlight_start, highlight_start + len as u32)
+}
+
+pub struct SearchIndex {
+    base_path: String,
+}
+
+impl SearchIndex {
+    pub fn new(base_path: &str) -> Self {
+        Self {
+            base_path: base_path.to_string(),
+        }
+    }
+
+    fn index_path(&self) -> Result<PathBuf, ChatError> {
+        let dir = PathBuf::from(&self.base_path).join("search_index");
+        if !dir.exists() {
+            fs::create_dir_all(&dir).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to create search index directory: {}", e),
+            })?;
+        }
+        Ok(dir.join("index.bin"))
+    }
+
+    fn load(&self) -> IndexData {
+        self.index_path()
+            .ok()
+            .and_then(|path| {
+                at_rest::load(path, "search index", |data| {
+                    bincode::deserialize::<IndexData>(data).map_err(|e| ChatError::StorageError {
+                        message: format!("Failed to parse search index: {}", e),
+                    })
+                })
+                .ok()
+            })
+            .filter(|index| index.version == INDEX_VERSION)
+            .unwrap_or_else(|| IndexData {
+                version: INDEX_VERSION,
+                ..IndexData::default()
+            })
+    }
+
+    fn save(&self, index: &IndexData) -> Result<(), ChatError> {
+        let data = bincode::serialize(index).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to serialize search index: {}", e),
+        })?;
+        at_rest::write(self.index_path()?, data).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to write search index: {}", e),
+        })
+    }
+
+    /// 让索引跟上对话的最新内容，返回是否有改动
+    fn refresh(&self, index: &mut IndexData, store: &ConversationStore) -> bool {
+        let current: HashMap<String, i64> = store
+            .list_conversations()
+            .into_iter()
+            .map(|s| (s.id, s.updated_at))
+            .collect();
+        let removed: Vec<String> = index
+            .indexed_at
+            .keys()
+            .filter(|id| !current.contains_key(*id))
+            .cloned()
+            .collect();
+        let stale: Vec<(&String, &i64)> = current
+            .iter()
+            .filter(|(id, updated_at)| index.indexed_at.get(*id) != Some(updated_at))
+            .collect();
+        if removed.is_empty() && stale.is_empty() {
+            return false;
+        }
+        for id in &removed {
+            index.remove_conversation(id);
+        }
+        for (id, updated_at) in stale {
+            index.add_conversation(store, id, *updated_at);
+        }
+        if index.live_docs() * 2 < index.docs.len() {
+            // 空位过半：整体重建，回收下标
+            *index = IndexData {
+                version: INDEX_VERSION,
+                ..IndexData::default()
+            };
+            for (id, updated_at) in &current {
+                index.add_conversation(store, id, *updated_at);
+            }
+        }
+        true
+    }
+
+    /// 按内容搜索全部对话；空查询返回空
+    pub fn search(&self, store: &ConversationStore, query: &str) -> Vec<SearchHit> {
+        let segments: Vec<Vec<char>> = query.split_whitespace().map(fold).collect();
+        let query_terms = terms(query);
+        if segments.is_empty() || query_terms.is_empty() {
+            return Vec::new();
+        }
+
+        let _guard = INDEX_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let mut index = self.load();
+        if self.refresh(&mut index, store) {
+            if let Err(e) = self.save(&index) {
+                tracing::warn!(error = %e, "搜索索引写入失败");
+            }
+        }
+
+        // 倒排表求交（从最短的开始）
+        let mut lists: Vec<&Vec<u32>> = Vec::new();
+        for term in &query_terms {
+            match index.postings.get(term) {
+                Some(list) => lists.push(list),
+                None => return Vec::new(),
+            }
+        }
+        lists.sort_by_key(|l| l.len());
+        let mut candidates: Vec<u32> = lists[0].clone();
+        for list in &lists[1..] {
+            let set: HashSet<&u32> = list.iter().collect();
+            candidates.retain(|d| set.contains(d));
+        }
+
+        // 按对话分组确认，每个对话只加载一次
+        let mut by_conversation: HashMap<&str, HashSet<&str>> = HashMap::new();
+        for doc in candidates.iter().filter_map(|d| index.docs[*d as usize].as_ref()) {
+            by_conversation
+                .entry(doc.conversation_id.as_str())
+                .or_default()
+                .insert(doc.message_id.as_str());
+        }
+        let mut scored: Vec<(usize, SearchHit)> = Vec::new();
+        for (conversation_id, message_ids) in by_conversation {
+            let Ok(conv) = store.load_conversation(conversation_id) else {
+                continue;
+            };
+            for message in conv.messages.iter().filter(|m| message_ids.contains(m.id.as_str())) {
+                let content = fold(&message.content);
+                let matches: Vec<Vec<usize>> =
+                    segments.iter().map(|s| find_all(&content, s)).collect();
+                if matches.iter().any(|m| m.is_empty()) {
+                    continue;
+                }
+                let (first, len) = matches
+                    .iter()
+                    .zip(&segments)
+                    .map(|(m, s)| (m[0], s.len()))
+                    .min()
+                    .unwrap_or((0, 0));
+                let original: Vec<char> = message.content.chars().collect();
+                let (snippet, highlight_start, highlight_end) = snippet(&original, first, len);
+                scored.push((
+                    matches.iter().map(|m| m.len()).sum(),
+                    SearchHit {
+                        conversation_id: conv.id.clone(),
+                        conversation_title: conv.title.clone(),
+                        message_id: message.id.clone(),
+                        role: message.role.clone(),
+                        snippet,
+                        highlight_start,
+                        highlight_end,
+                        timestamp: message.timestamp,
+                    },
+                ));
+            }
+        }
+        scored.sort_by(|(sa, a), (sb, b)| sb.cmp(sa).then(b.timestamp.cmp(&a.timestamp)));
+        scored
+            .into_iter()
+            .take(MAX_SEARCH_HITS)
+            .map(|(_, hit)| hit)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::data_models::Message;
+
+    fn msg(role: MessageRole, content: &str, timestamp: i64) -> Message {
+        Message {
+            timestamp,
+            ..Message::new(role, content, "glm-4.7")
+        }
+    }
+
+    #[test]
+    fn test_search_finds_cjk_and_words_and_follows_updates() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let base = tmp.path().to_str().unwrap();
+        let store = ConversationStore::new(base);
+        let index = SearchIndex::new(base);
+
+        let mut conv = store.create_conversation();
+        conv.title = "夏天".to_string();
+        conv.messages = vec![
+            msg(MessageRole::System, "海边旅行的设定", 0),
+            msg(MessageRole::User, "还记得我们那次海边旅行吗？", 1),
+            msg(MessageRole::Assistant, "当然，the Beach trip 我一直记得", 2),
+            msg(MessageRole::User, "边走边旅行", 3),
+        ];
+        store.save_conversation(&conv).unwrap();
+
+        let hits = index.search(&store, "海边旅行");
+        assert_eq!(hits.len(), 1, "system 消息不入索引，「边走边旅行」不是连续匹配");
+        assert_eq!(hits[0].conversation_title, "夏天");
+        let snippet: Vec<char> = hits[0].snippet.chars().collect();
+        let highlighted: String = snippet
+            [hits[0].highlight_start as usize..hits[0].highlight_end as usize]
+            .iter()
+            .collect();
+        assert_eq!(highlighted, "海边旅行");
+
+        let hits = index.search(&store, "beach TRIP");
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].role, MessageRole::Assistant);
+        assert!(index.search(&store, "bea").is_empty(), "英文按整词索引");
+        assert!(index.search(&store, "   ").is_empty());
+
+        conv.messages.push(msg(MessageRole::User, "下次去山里吧", 4));
+        conv.updated_at += 1;
+        store.save_conversation(&conv).unwrap();
+        assert_eq!(index.search(&store, "山里").len(), 1);
+
+        store.delete_conversation(&conv.id).unwrap();
+        assert!(index.search(&store, "山里").is_empty());
+    }
+}
@@ -0,0 +1,241 @@
+use std::collections::hash_map::DefaultHasher;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
+
+use flutter_rust_bridge::frb;
+
+use super::data_models::{ReplySignals, ShadowEvalRecord, ShadowEvalSummary};
+use super::error_handler::ChatError;
+use super::fidelity_audit::FidelityAuditor;
+use super::memory_engine::MemoryEngine;
+
+// ═══════════════════════════════════════════════════════════════════
+//  知识注入影子评估 (Shadow Evaluation)
+//  ─────────────────────────────────────────────────────────────────
+//  记忆摘要、知识检索、世界设定层层注入，是否真的让回复更好一直缺少数据。
+//  按 AppSettings.shadow_eval_rate 抽样的轮次，在正式回复落盘之后：
+//    1. 影子上下文：同一段历史，但不注入记忆 / 知识 / 世界设定
+//    2. 影子回复：廉价模型 SHADOW_MODEL，非流式、不推送事件、不写入对话
+//    3. 对比信号（两条回复分别计算）：
+//       · grounded_facts  能推出的本轮注入事实数
+//       · user_overlap    对用户本轮输入的关键词回应程度
+//       · repetition      与上一条 AI 回复的相似度
+//    4. 落盘记录，汇总后给出注入带来的平均差值
+//  影子回复与正式回复的模型不同，差值包含模型差异，应结合样本量看趋势。
+//
+//  存储结构：
+//    shadow_eval/{conversation_id}.json
+// ═══════════════════════════════════════════════════════════════════
+
+/// 影子回复使用的廉价模型
+pub const SHADOW_MODEL: &str = "glm-4.7-flash";
+/// 影子回复的输出上限
+pub const SHADOW_MAX_TOKENS: u32 = 600;
+/// 影子请求的超时（后台执行，超时即放弃本次抽样）
+pub const SHADOW_TIMEOUT_SECS: u64 = 30;
+/// 每个对话最多保留的评估记录数
+const MAX_RECORDS: usize = 200;
+
+#[frb(opaque)]
+#[derive(Clone)]
+pub struct ShadowEvalStore {
+    base_path: String,
+}
+
+impl ShadowEvalStore {
+    pub fn new(base_path: &str) -> Self {
+        Self {
+            base_path: base_path.to_string(),
+        }
+    }
+
+    fn eval_dir(&self) -> Result<PathBuf, ChatError> {
+        let dir = PathBuf::from(&self.base_path).join("shadow_eval");
+        if !dir.exists() {
+            fs::create_dir_all(&dir).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to create shadow eval directory: {}", e),
+            })?;
+        }
+        Ok(dir)
+    }
+
+    fn eval_path(&self, conversation_id: &str) -> Result<PathBuf, ChatError> {
+        Ok(self.eval_dir()?.join(format!("{}.json", conversation_id)))
+    }
+
+    pub fn load(&self, conversation_id: &str) -> Result<Vec<ShadowEvalRecord>, ChatError> {
+        let path = self.eval_path(conversation_id)?;
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+        let json = fs::read_to_string(&path).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to read shadow eval records: {}", e),
+        })?;
+        serde_json::from_str(&json).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to parse shadow eval records: {}", e),
+        })
+    }
+
+    /// 追加一条记录（超出上限时丢弃最旧的）
+    pub fn append(&self, conversation_id: &str, record: ShadowEvalRecord) -> Result<(), ChatError> {
+        let mut records = self.load(conversation_id)?;
+        records.push(record);
+        if records.len() > MAX_RECORDS {
+            let excess = records.len() - MAX_RECORDS;
+            records.drain(..excess);
+        }
+        let json = serde_json::to_string_pretty(&records).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to serialize shadow eval records: {}", e),
+        })?;
+        fs::write(self.eval_path(conversation_id)?, json).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to write shadow eval records: {}", e),
+        })
+    }
+
+    pub fn delete(&self, conversation_id: &str) -> Result<(), ChatError> {
+        let path = self.eval_path(conversation_id)?;
+        if path.exists() {
+            fs::remove_file(&path).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to delete shadow eval records: {}", e),
+            })?;
+        }
+        Ok(())
+    }
+
+    /// 本轮是否抽中：按 (对话, 轮次) 哈希取样，同一轮重试结果一致
+    pub fn is_sampled(conversation_id: &str, turn: u32, rate: f64) -> bool {
+        if rate <= 0.0 {
+            return false;
+        }
+        if rate >= 1.0 {
+            return true;
+        }
+        let mut hasher = DefaultHasher::new();
+        conversation_id.hash(&mut hasher);
+        turn.hash(&mut hasher);
+        (hasher.finish() % 10_000) as f64 / 10_000.0 < rate
+    }
+
+    /// 计算一条回复的质量信号
+    pub fn measure(
+        reply: &str,
+        user_content: &str,
+        previous_reply: Option<&str>,
+        injected_facts: &[String],
+    ) -> ReplySignals {
+        let grounded_facts = injected_facts
+            .iter()
+            .filter(|f| FidelityAuditor::is_derivable(f, reply))
+            .count() as u32;
+
+        let reply_lower = reply.to_lowercase();
+        let keywords = MemoryEngine::extract_keywords(user_content);
+        let user_overlap = if keywords.is_empty() {
+            0.0
+        } else {
+            keywords
+                .iter()
+                .filter(|k| reply_lower.contains(&k.to_lowercase()))
+                .count() as f64
+                / keywords.len() as f64
+        };
+
+        let repetition = previous_reply
+            .filter(|p| !p.trim().is_empty())
+            .map(|p| MemoryEngine::tfidf_cosine_similarity(reply, p))
+            .unwrap_or(0.0);
+
+        ReplySignals {
+            length_chars: reply.chars().count() as u32,
+            grounded_facts,
+            user_overlap,
+            repetition,
+        }
+    }
+
+    pub fn summarize(records: &[ShadowEvalRecord]) -> ShadowEvalSummary {
+        if records.is_empty() {
+            return ShadowEvalSummary::default();
+        }
+        let n = records.len() as f64;
+        let mean = |f: &dyn Fn(&ShadowEvalRecord) -> f64| records.iter().map(f).sum::<f64>() / n;
+        ShadowEvalSummary {
+            samples: records.len() as u32,
+            avg_grounded_gain: mean(&|r| r.main.grounded_facts as f64 - r.shadow.grounded_facts as f64),
+            avg_overlap_gain: mean(&|r| r.main.user_overlap - r.shadow.user_overlap),
+            avg_repetition_delta: mean(&|r| r.main.repetition - r.shadow.repetition),
+            grounded_win_rate: mean(&|r| {
+                if r.main.grounded_facts > r.shadow.grounded_facts {
+                    1.0
+                } else {
+                    0.0
+                }
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn record(main_grounded: u32, shadow_grounded: u32) -> ShadowEvalRecord {
+        ShadowEvalRecord {
+            turn: 1,
+            injected_facts: 3,
+            main_model: "glm-4.7".to_string(),
+            shadow_model: SHADOW_MODEL.to_string(),
+            main: ReplySignals {
+                grounded_facts: main_grounded,
+                user_overlap: 0.5,
+                ..Default::default()
+            },
+            shadow: ReplySignals {
+                grounded_facts: shadow_grounded,
+                ..Default::default()
+            },
+            created_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_measure_counts_grounded_facts_and_overlap() {
+        let facts = vec!["团子是一只橘猫".to_string(), "用户在杭州工作".to_string()];
+        let signals = ShadowEvalStore::measure(
+            "团子这只橘猫又胖了吧？",
+            "我家团子最近好能吃",
+            Some("团子这只橘猫又胖了吧？"),
+            &facts,
+        );
+        assert_eq!(signals.grounded_facts, 1);
+        assert!(signals.user_overlap > 0.0);
+        assert!(signals.repetition > 0.9);
+        assert_eq!(ShadowEvalStore::measure("嗯", "", None, &facts).repetition, 0.0);
+    }
+
+    #[test]
+    fn test_records_are_capped_and_summarized() {
+        let tmp = TempDir::new().unwrap();
+        let store = ShadowEvalStore::new(tmp.path().to_str().unwrap());
+        for _ in 0..MAX_RECORDS {
+            store.append("c1", record(1, 1)).unwrap();
+        }
+        store.append("c1", record(3, 1)).unwrap();
+        store.append("c1", record(2, 1)).unwrap();
+        let records = store.load("c1").unwrap();
+        assert_eq!(records.len(), MAX_RECORDS);
+
+        let summary = ShadowEvalStore::summarize(&records[records.len() - 2..]);
+        assert_eq!(summary.samples, 2);
+        assert!((summary.avg_grounded_gain - 1.5).abs() < 1e-9);
+        assert!((summary.avg_overlap_gain - 0.5).abs() < 1e-9);
+        assert_eq!(summary.grounded_win_rate, 1.0);
+
+        assert!(!ShadowEvalStore::is_sampled("c1", 7, 0.0));
+        assert!(ShadowEvalStore::is_sampled("c1", 7, 1.0));
+        let hits = (0..1000).filter(|t| ShadowEvalStore::is_sampled("c1", *t, 0.2)).count();
+        assert!((100..300).contains(&hits), "抽样比例应接近设置值: {}", hits);
+    }
+}
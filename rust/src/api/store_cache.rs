@@ -0,0 +1,189 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::SystemTime;
+
+use super::data_models::MemorySummary;
+use super::knowledge_store::{Fact, KnowledgeIndex};
+use super::saydo_detector::CompiledSayDoRules;
+
+// ═══════════════════════════════════════════════════════════════════
+//  存储读缓存 (Store Cache)
+//  ─────────────────────────────────────────────────────────────────
+//  一轮对话里检索、评估、事实提取会反复读取同一份事实库、知识库索引与记忆索引，
+//  每次都要读盘、解密、解析 JSON。这里按文件路径缓存解析后的结果：
+//    - LRU：超过容量时淘汰最久未用的文件
+//    - 写穿：经由 KnowledgeStore / MemoryEngine / SayDoRuleStore 写入时直接换成新内容
+//    - 校验：命中前比对文件长度与修改时间（读取前取的快照），
+//      同步拉取、数据导入等绕过存储层的改写会让缓存自然失效
+//  仅在内存中缓存；重启后首次读取时重新加载。
+// ═══════════════════════════════════════════════════════════════════
+
+/// 每类缓存最多保留的文件数
+const CAPACITY: usize = 32;
+
+/// 文件快照：长度与修改时间都不变才认为内容没变
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileStamp {
+    len: u64,
+    modified: Option<SystemTime>,
+}
+
+impl FileStamp {
+    /// 文件当前的快照；文件不存在时为 None
+    pub fn of(path: &Path) -> Option<FileStamp> {
+        let meta = fs::metadata(path).ok()?;
+        Some(FileStamp {
+            len: meta.len(),
+            modified: meta.modified().ok(),
+        })
+    }
+}
+
+struct Entry<V> {
+    value: V,
+    stamp: FileStamp,
+    last_used: u64,
+}
+
+struct Inner<V> {
+    entries: HashMap<PathBuf, Entry<V>>,
+    clock: u64,
+}
+
+pub struct FileCache<V> {
+    capacity: usize,
+    inner: Mutex<Inner<V>>,
+}
+
+impl<V: Clone> FileCache<V> {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            inner: Mutex::new(Inner {
+                entries: HashMap::new(),
+                clock: 0,
+            }),
+        }
+    }
+
+    /// 缓存的内容；文件已被改写（快照不符）时丢弃并返回 None
+    pub fn get(&self, path: &Path) -> Option<V> {
+        let stamp = FileStamp::of(path)?;
+        let mut inner = self.inner.lock().ok()?;
+        inner.clock += 1;
+        let clock = inner.clock;
+        match inner.entries.get_mut(path) {
+            Some(entry) if entry.stamp == stamp => {
+                entry.last_used = clock;
+                Some(entry.value.clone())
+            }
+            Some(_) => {
+                inner.entries.remove(path);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// 记录 path 在快照 stamp 时的内容（stamp 应在读取或写入文件之前 / 之后立即获取）
+    pub fn put(&self, path: &Path, stamp: Option<FileStamp>, value: V) {
+        let Ok(mut inner) = self.inner.lock() else {
+            return;
+        };
+        let Some(stamp) = stamp else {
+            inner.entries.remove(path);
+            return;
+        };
+        inner.clock += 1;
+        let clock = inner.clock;
+        if inner.entries.len() >= self.capacity && !inner.entries.contains_key(path) {
+            if let Some(oldest) = inner
+                .entries
+                .iter()
+                .min_by_key(|(_, e)| e.last_used)
+                .map(|(p, _)| p.clone())
+            {
+                inner.entries.remove(&oldest);
+            }
+        }
+        inner.entries.insert(
+            path.to_path_buf(),
+            Entry {
+                value,
+                stamp,
+                last_used: clock,
+            },
+        );
+    }
+
+    pub fn clear(&self) {
+        if let Ok(mut inner) = self.inner.lock() {
+            inner.entries.clear();
+        }
+    }
+}
+
+static FACTS: OnceLock<FileCache<Vec<Fact>>> = OnceLock::new();
+static MEMORY_INDEXES: OnceLock<FileCache<Vec<MemorySummary>>> = OnceLock::new();
+static KNOWLEDGE_INDEXES: OnceLock<FileCache<Arc<KnowledgeIndex>>> = OnceLock::new();
+static SAYDO_RULES: OnceLock<FileCache<Arc<CompiledSayDoRules>>> = OnceLock::new();
+
+/// 事实文件（对话事实库、归档事实、用户档案）
+pub fn facts() -> &'static FileCache<Vec<Fact>> {
+    FACTS.get_or_init(|| FileCache::new(CAPACITY))
+}
+
+/// 记忆索引文件
+pub fn memory_indexes() -> &'static FileCache<Vec<MemorySummary>> {
+    MEMORY_INDEXES.get_or_init(|| FileCache::new(CAPACITY))
+}
+
+/// 知识库倒排索引（共享只读，检索时不复制）
+pub fn knowledge_indexes() -> &'static FileCache<Arc<KnowledgeIndex>> {
+    KNOWLEDGE_INDEXES.get_or_init(|| FileCache::new(CAPACITY))
+}
+
+/// 对话的 Say/Do 规则（已编译的正则）
+pub fn saydo_rules() -> &'static FileCache<Arc<CompiledSayDoRules>> {
+    SAYDO_RULES.get_or_init(|| FileCache::new(CAPACITY))
+}
+
+/// 整体替换数据目录后（导入、同步拉取）清空全部缓存
+pub fn clear_all() {
+    facts().clear();
+    memory_indexes().clear();
+    knowledge_indexes().clear();
+    saydo_rules().clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lru_eviction_and_stamp_invalidation() {
+        let tmp = tempfile::TempDir::new().unwrap();
+        let paths: Vec<PathBuf> = (0..3)
+            .map(|i| tmp.path().join(format!("{}.json", i)))
+            .collect();
+        for path in &paths {
+            fs::write(path, "[]").unwrap();
+        }
+        let cache: FileCache<u32> = FileCache::new(2);
+        cache.put(&paths[0], FileStamp::of(&paths[0]), 0);
+        cache.put(&paths[1], FileStamp::of(&paths[1]), 1);
+        assert_eq!(cache.get(&paths[0]), Some(0));
+        // 容量满：淘汰最久未用的 1
+        cache.put(&paths[2], FileStamp::of(&paths[2]), 2);
+        assert_eq!(cache.get(&paths[1]), None);
+        assert_eq!(cache.get(&paths[0]), Some(0));
+
+        // 绕过缓存的改写让条目失效
+        fs::write(&paths[2], "[1,2,3]").unwrap();
+        assert_eq!(cache.get(&paths[2]), None);
+        fs::remove_file(&paths[0]).unwrap();
+        assert_eq!(cache.get(&paths[0]), None);
+    }
+}
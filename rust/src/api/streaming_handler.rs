@@ -1,7 +1,23 @@
-use super::data_models::ChatStreamEvent;
+use super::chat_provider::{chat_completions_url, ChatProvider, ModelCapabilities};
+use super::config_manager::{self, ModelRegistry};
+use super::data_models::{ChatStreamEvent, GenerationMetadata};
 use super::error_handler::{ChatError, RetryHandler};
+use super::network_adaptation;
+use super::text_utils;
 use flutter_rust_bridge::frb;
 use futures::StreamExt;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+use tokio::sync::Notify;
+
+/// 本地推理服务默认地址（Ollama 的 OpenAI 兼容端点；llama.cpp server 需改为其端口）
+const LOCAL_LLM_DEFAULT_BASE_URL: &str = "http://localhost:11434/v1";
+/// 本地模型的保守上下文窗口（需与 Ollama num_ctx / llama.cpp -c 保持一致）
+const LOCAL_LLM_CONTEXT_WINDOW: usize = 8192;
+/// 本地模型单次输出上限
+const LOCAL_LLM_MAX_OUTPUT_TOKENS: u32 = 2048;
 
 /// 流式请求的超时配置（按模型角色分级）
 struct StreamTimeoutConfig {
@@ -14,33 +30,240 @@ struct StreamTimeoutConfig {
 }
 
 impl StreamTimeoutConfig {
-    /// 根据模型选择合适的超时配置
-    /// 推理模型（glm-4-air）需要更长的首 token 等待时间
-    /// 长上下文模型（glm-4-long）处理大量输入需要更多时间
+    /// 根据模型选择合适的超时配置（数据块等待见 ModelRegistry 的声明）
+    /// 推理模型（glm-4-air）与长上下文模型（glm-4-long）首 token 等待更长
     fn for_model(model: &str) -> Self {
-        match model {
-            "glm-4-air" => Self {
-                connect_timeout_secs: 30,
-                first_chunk_timeout_secs: 300,     // 推理模型首 token 最长等 5 分钟
-                subsequent_chunk_timeout_secs: 120, // 推理链中间段可能有长停顿
-                tcp_keepalive_secs: 15,
-            },
-            "glm-4-long" => Self {
-                connect_timeout_secs: 30,
-                first_chunk_timeout_secs: 300,     // 长上下文处理预热长
-                subsequent_chunk_timeout_secs: 120,
-                tcp_keepalive_secs: 15,
-            },
-            _ => Self {
-                connect_timeout_secs: 30,
-                first_chunk_timeout_secs: 180,     // 标准模型首 token 最长 3 分钟
-                subsequent_chunk_timeout_secs: 90,  // 正常对话块间不应超过 90 秒
-                tcp_keepalive_secs: 15,
-            },
+        let (first_chunk_timeout_secs, subsequent_chunk_timeout_secs) =
+            ModelRegistry::global().chunk_timeouts(model);
+        Self {
+            connect_timeout_secs: 30,
+            first_chunk_timeout_secs,
+            subsequent_chunk_timeout_secs,
+            tcp_keepalive_secs: 15,
         }
     }
 }
 
+// ── 请求队列（客户端限流）──
+// 记忆摘要、事实提取与新一轮回复同时发起时容易触发服务端限流。
+// 所有对话模型请求先在这里按模型排队：
+//   1. 并发：同一模型同时进行的请求不超过 max_concurrent_requests
+//   2. 频率：同一模型相邻两次发起间隔不小于 1 / requests_per_second
+//   3. 优先级：后台任务（JobScheduler 中的非关键任务）为交互请求预留一个并发名额，
+//      且有交互请求在排队时不发起
+// 限额来自 ModelRegistry 的声明（models.json 可覆盖）。
+
+/// 请求优先级
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RequestPriority {
+    /// 用户正在等待的请求（对话回复及其管线）
+    Interactive,
+    /// 后台任务，让路给交互请求
+    Background,
+}
+
+tokio::task_local! {
+    static PRIORITY: RequestPriority;
+}
+
+/// 以后台优先级执行：其中发起的模型请求排在交互请求之后
+pub async fn as_background<T>(job: impl Future<Output = T>) -> T {
+    PRIORITY.scope(RequestPriority::Background, job).await
+}
+
+/// 当前任务的请求优先级（未标记的一律视为交互请求）
+pub fn current_priority() -> RequestPriority {
+    PRIORITY.try_with(|p| *p).unwrap_or(RequestPriority::Interactive)
+}
+
+#[derive(Debug, Default)]
+struct ModelLane {
+    active: u32,
+    /// 正在排队的交互请求数
+    interactive_waiting: u32,
+    last_started: Option<Instant>,
+}
+
+/// 按模型限流的全局请求队列
+pub struct RequestQueue {
+    lanes: Mutex<HashMap<String, ModelLane>>,
+    /// 名额归还或交互请求出队时唤醒排队者
+    changed: Notify,
+}
+
+/// 请求名额，drop 时归还
+pub struct RequestPermit<'a> {
+    queue: &'a RequestQueue,
+    model: String,
+}
+
+impl Drop for RequestPermit<'_> {
+    fn drop(&mut self) {
+        self.queue.update(&self.model, |lane| lane.active = lane.active.saturating_sub(1));
+    }
+}
+
+/// 排队中的交互请求登记，出队（或请求被取消）时撤销
+struct InteractiveWaiting<'a> {
+    queue: &'a RequestQueue,
+    model: &'a str,
+}
+
+impl Drop for InteractiveWaiting<'_> {
+    fn drop(&mut self) {
+        self.queue.update(self.model, |lane| {
+            lane.interactive_waiting = lane.interactive_waiting.saturating_sub(1)
+        });
+    }
+}
+
+static REQUEST_QUEUE: OnceLock<RequestQueue> = OnceLock::new();
+
+impl RequestQueue {
+    pub fn new() -> Self {
+        Self {
+            lanes: Mutex::new(HashMap::new()),
+            changed: Notify::new(),
+        }
+    }
+
+    /// 进程内共享的请求队列
+    pub fn global() -> &'static RequestQueue {
+        REQUEST_QUEUE.get_or_init(RequestQueue::new)
+    }
+
+    /// 按模型限额排队，返回的名额在请求结束（drop）时归还
+    pub async fn acquire(&self, model: &str, priority: RequestPriority) -> RequestPermit<'_> {
+        let limits = ModelRegistry::global().request_limits(model);
+        let _waiting = (priority == RequestPriority::Interactive).then(|| {
+            self.update(model, |lane| lane.interactive_waiting += 1);
+            InteractiveWaiting { queue: self, model }
+        });
+        loop {
+            // 先登记唤醒再检查，避免检查与等待之间的归还被错过
+            let changed = self.changed.notified();
+            match self.try_start(model, priority, limits, Instant::now()) {
+                Ok(()) => {
+                    return RequestPermit {
+                        queue: self,
+                        model: model.to_string(),
+                    }
+                }
+                Err(None) => changed.await,
+                Err(Some(delay)) => {
+                    let _ = tokio::time::timeout(delay, changed).await;
+                }
+            }
+        }
+    }
+
+    /// 能发起时占用名额；否则返回需要等待的时长（None 表示等名额归还）
+    fn try_start(
+        &self,
+        model: &str,
+        priority: RequestPriority,
+        (max_concurrent, requests_per_second): (u32, f64),
+        now: Instant,
+    ) -> Result<(), Option<Duration>> {
+        let mut lanes = self.lanes.lock().unwrap();
+        let lane = lanes.entry(model.to_string()).or_default();
+        let limit = match priority {
+            RequestPriority::Interactive => max_concurrent,
+            RequestPriority::Background => {
+                if lane.interactive_waiting > 0 {
+                    return Err(None);
+                }
+                if max_concurrent > 1 {
+                    max_concurrent - 1
+                } else {
+                    max_concurrent
+                }
+            }
+        };
+        if limit > 0 && lane.active >= limit {
+            return Err(None);
+        }
+        if requests_per_second > 0.0 {
+            if let Some(last) = lane.last_started {
+                let interval = Duration::from_secs_f64(1.0 / requests_per_second);
+                let elapsed = now.saturating_duration_since(last);
+                if elapsed < interval {
+                    return Err(Some(interval - elapsed));
+                }
+            }
+        }
+        lane.active += 1;
+        lane.last_started = Some(now);
+        Ok(())
+    }
+
+    fn update(&self, model: &str, f: impl FnOnce(&mut ModelLane)) {
+        if let Ok(mut lanes) = self.lanes.lock() {
+            f(lanes.entry(model.to_string()).or_default());
+        }
+        self.changed.notify_waiters();
+    }
+}
+
+impl Default for RequestQueue {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// SSE 字节流的增量 UTF-8 解码器
+///
+/// 网络分块与字符边界无关：一个汉字或 emoji 的字节可能分散在两个 chunk 里，
+/// 逐块 `from_utf8_lossy` 会把被截断的半个字符变成两个 �。
+/// 这里把不完整的尾部字节留到下一块再拼接解码，只有真正非法的字节才替换为 �。
+#[derive(Debug, Default)]
+pub(crate) struct Utf8StreamDecoder {
+    pending: Vec<u8>,
+}
+
+impl Utf8StreamDecoder {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// 追加一块字节，返回其中已能完整解码的文本
+    pub(crate) fn push(&mut self, bytes: &[u8]) -> String {
+        self.pending.extend_from_slice(bytes);
+        let mut out = String::new();
+        loop {
+            match std::str::from_utf8(&self.pending) {
+                Ok(text) => {
+                    out.push_str(text);
+                    self.pending.clear();
+                    return out;
+                }
+                Err(e) => {
+                    let valid = e.valid_up_to();
+                    out.push_str(std::str::from_utf8(&self.pending[..valid]).unwrap_or_default());
+                    match e.error_len() {
+                        // 尾部是不完整的多字节序列：留待下一块
+                        None => {
+                            self.pending.drain(..valid);
+                            return out;
+                        }
+                        Some(bad) => {
+                            out.push(char::REPLACEMENT_CHARACTER);
+                            self.pending.drain(..valid + bad);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// 流结束：残留的不完整字节按替换字符输出
+    pub(crate) fn finish(&mut self) -> String {
+        let rest = String::from_utf8_lossy(&self.pending).into_owned();
+        self.pending.clear();
+        rest
+    }
+}
+
 #[frb(opaque)]
 pub struct StreamingHandler {}
 
@@ -53,15 +276,266 @@ impl StreamingHandler {
     /// 3. 连接级重试（3次）+ 数据块超时容忍
     /// 4. TCP keepalive防止NAT/代理断开空闲连接
     /// 5. 更细粒度的错误分类，便于上层决策
+    ///
+    /// 请求体按 GLM 格式传入，由 provider 改写协议、提供鉴权头并解析 SSE 行。
+    ///
+    /// 弱网自适应：流式连续失败后自动改走非流式请求（见 network_adaptation），
+    /// 此时思考与正文各以单个 delta 一次性下发，网络稳定后自动恢复流式。
     pub async fn stream_chat(
-        url: &str,
-        token: &str,
+        provider: &dyn ChatProvider,
         request_body: serde_json::Value,
         on_event: impl Fn(ChatStreamEvent),
     ) -> Result<(String, String), ChatError> {
-        let retry_handler = RetryHandler::new(3, 1000);  // 重试间隔从800ms提升到1000ms
-        let url_owned = url.to_string();
-        let token_owned = token.to_string();
+        Self::stream_chat_with_metadata(provider, request_body, on_event)
+            .await
+            .map(|(content, thinking, _)| (content, thinking))
+    }
+
+    /// 同 stream_chat，额外返回本次请求的生成信息（模型、结束原因、用量、传输方式）
+    #[tracing::instrument(skip_all, fields(model = request_body.get("model").and_then(|v| v.as_str())))]
+    pub async fn stream_chat_with_metadata(
+        provider: &dyn ChatProvider,
+        request_body: serde_json::Value,
+        on_event: impl Fn(ChatStreamEvent),
+    ) -> Result<(String, String, GenerationMetadata), ChatError> {
+        let mut meta = GenerationMetadata {
+            model: request_body
+                .get("model")
+                .and_then(|v| v.as_str())
+                .unwrap_or("unknown")
+                .to_string(),
+            ..GenerationMetadata::default()
+        };
+        let _permit = RequestQueue::global()
+            .acquire(&meta.model, current_priority())
+            .await;
+
+        if network_adaptation::is_degraded() {
+            let result = Self::complete_chat(provider, request_body, &on_event, &mut meta).await;
+            match &result {
+                Ok(_) => network_adaptation::record_success(),
+                Err(e) if Self::is_transport_error(e) => network_adaptation::record_failure(),
+                Err(e) => tracing::warn!(model = %meta.model, error = %e, "非流式请求失败"),
+            }
+            return result.map(|(content, thinking)| (content, thinking, meta));
+        }
+
+        meta.streamed = true;
+        let mut interrupted = false;
+        let result =
+            Self::stream_sse(provider, request_body, &on_event, &mut interrupted, &mut meta).await;
+        match &result {
+            Ok(_) if interrupted => network_adaptation::record_failure(),
+            Ok(_) => network_adaptation::record_success(),
+            Err(e) if Self::is_transport_error(e) => network_adaptation::record_failure(),
+            Err(e) => tracing::warn!(model = %meta.model, error = %e, "流式请求失败"),
+        }
+        meta.interrupted = interrupted;
+        result.map(|(content, thinking)| (content, thinking, meta))
+    }
+
+    /// 只有网络/传输层错误计入弱网判断（限流、鉴权、参数错误与网络无关）
+    fn is_transport_error(err: &ChatError) -> bool {
+        matches!(err, ChatError::NetworkError { .. } | ChatError::StreamError { .. })
+    }
+
+    /// 非流式请求：stream=false，整段回复一次返回，以单个 ContentDelta 下发
+    /// 非流式单次请求，不推送任何事件（后台评估等不展示给用户的请求）
+    #[tracing::instrument(skip_all, fields(model = request_body.get("model").and_then(|v| v.as_str())))]
+    pub async fn complete_silently(
+        provider: &dyn ChatProvider,
+        request_body: serde_json::Value,
+    ) -> Result<String, ChatError> {
+        let model = request_body
+            .get("model")
+            .and_then(|v| v.as_str())
+            .unwrap_or("unknown")
+            .to_string();
+        let _permit = RequestQueue::global()
+            .acquire(&model, current_priority())
+            .await;
+        let mut meta = GenerationMetadata::default();
+        let silent_event = |_event: ChatStreamEvent| {};
+        Self::complete_chat(provider, request_body, &silent_event, &mut meta)
+            .await
+            .map(|(content, _)| content)
+    }
+
+    async fn complete_chat(
+        provider: &dyn ChatProvider,
+        request_body: serde_json::Value,
+        on_event: &impl Fn(ChatStreamEvent),
+        meta: &mut GenerationMetadata,
+    ) -> Result<(String, String), ChatError> {
+        let retry_handler = RetryHandler::with_policy(config_manager::retry_policy());
+        let mut request_body = provider.build_request(request_body);
+        request_body["stream"] = serde_json::json!(false);
+        let url_owned = provider.endpoint();
+        let headers_owned = provider.auth_headers();
+        let model_name = request_body
+            .get("model")
+            .and_then(|v| v.as_str())
+            .unwrap_or("unknown")
+            .to_string();
+
+        // 非流式没有逐块超时可用，整体超时沿用首个数据块的等待上限
+        let timeout_config = StreamTimeoutConfig::for_model(&model_name);
+        let builder = reqwest::Client::builder()
+            .connect_timeout(std::time::Duration::from_secs(timeout_config.connect_timeout_secs))
+            .timeout(std::time::Duration::from_secs(timeout_config.first_chunk_timeout_secs))
+            .tcp_keepalive(std::time::Duration::from_secs(timeout_config.tcp_keepalive_secs));
+        let client = config_manager::apply_proxy(builder)?
+            .build()
+            .map_err(|e| ChatError::NetworkError {
+                message: e.to_string(),
+            })?;
+
+        let json = retry_handler
+            .execute_with_retry(|| {
+                let client = client.clone();
+                let u = url_owned.clone();
+                let h = headers_owned.clone();
+                let b = request_body.clone();
+                async move {
+                    let mut req = client.post(&u);
+                    for (name, value) in &h {
+                        req = req.header(name.as_str(), value.as_str());
+                    }
+                    let resp = req
+                        .header("Content-Type", "application/json")
+                        .json(&b)
+                        .send()
+                        .await
+                        .map_err(|e| {
+                            if e.is_connect() {
+                                network_adaptation::mark_offline();
+                            }
+                            ChatError::NetworkError {
+                                message: format!("网络请求失败: {}", e),
+                            }
+                        })?;
+                    network_adaptation::mark_online();
+
+                    let status = resp.status();
+                    let retry_after = resp
+                        .headers()
+                        .get("retry-after")
+                        .and_then(|v| v.to_str().ok())
+                        .map(|v| v.to_string());
+                    let body_text = resp.text().await.map_err(|e| ChatError::NetworkError {
+                        message: format!("读取响应失败: {}", e),
+                    })?;
+                    if !status.is_success() {
+                        return Err(ChatError::from_glm_response(status.as_u16(), &body_text)
+                            .with_retry_after(retry_after.as_deref()));
+                    }
+                    serde_json::from_str::<serde_json::Value>(&body_text).map_err(|e| {
+                        ChatError::StreamError {
+                            message: format!("响应格式错误: {}", e),
+                        }
+                    })
+                }
+            })
+            .await
+            .map_err(|e| {
+                on_event(ChatStreamEvent::Error(format!("[{}] 请求失败: {}", model_name, e)));
+                e
+            })?;
+
+        provider.read_generation_metadata(&json, meta);
+        let (content, thinking) = provider.parse_completion(&json).unwrap_or_default();
+        if !thinking.is_empty() {
+            on_event(ChatStreamEvent::ThinkingDelta(thinking.clone()));
+        }
+        if !content.is_empty() {
+            on_event(ChatStreamEvent::ContentDelta(content.clone()));
+        } else if thinking.is_empty() {
+            let preview = json.to_string();
+            let preview = text_utils::truncate_chars(&preview, 500);
+            on_event(ChatStreamEvent::Error(format!(
+                "[{}] API 返回了数据但未包含有效内容。\n响应预览: {}",
+                model_name, preview
+            )));
+        }
+        Ok((content, thinking))
+    }
+
+    /// 非流式响应的默认解析（OpenAI 兼容）：返回 (正文, 思考)
+    pub fn parse_completion(json: &serde_json::Value) -> Option<(String, String)> {
+        let message = json.get("choices")?.get(0)?.get("message")?;
+        let text = |key: &str| {
+            message
+                .get(key)
+                .and_then(|v| v.as_str())
+                .unwrap_or("")
+                .to_string()
+        };
+        Some((text("content"), text("reasoning_content")))
+    }
+
+    /// 从响应 JSON 读取模型、结束原因与用量的默认实现（OpenAI 兼容）。
+    /// 流式响应的结束原因与用量通常只出现在最后一个数据块，逐块调用即可
+    pub fn read_generation_metadata(json: &serde_json::Value, meta: &mut GenerationMetadata) {
+        if let Some(model) = json.get("model").and_then(|v| v.as_str()).filter(|m| !m.is_empty()) {
+            meta.model = model.to_string();
+        }
+        if let Some(reason) = json
+            .get("choices")
+            .and_then(|c| c.get(0))
+            .and_then(|c| c.get("finish_reason"))
+            .and_then(|v| v.as_str())
+        {
+            meta.finish_reason = Some(reason.to_string());
+        }
+        if let Some(usage) = json.get("usage") {
+            Self::apply_usage(
+                meta,
+                usage.get("prompt_tokens").and_then(|v| v.as_u64()),
+                usage.get("completion_tokens").and_then(|v| v.as_u64()),
+                usage.get("total_tokens").and_then(|v| v.as_u64()),
+            );
+            // 智谱 / OpenAI 兼容端点的隐式上下文缓存
+            if let Some(cached) = usage
+                .get("prompt_tokens_details")
+                .and_then(|d| d.get("cached_tokens"))
+                .and_then(|v| v.as_u64())
+            {
+                meta.cached_tokens = cached as u32;
+            }
+        }
+    }
+
+    /// 写入用量：只覆盖响应中给出的字段，未给出合计时按输入 + 输出补齐
+    pub fn apply_usage(
+        meta: &mut GenerationMetadata,
+        prompt: Option<u64>,
+        completion: Option<u64>,
+        total: Option<u64>,
+    ) {
+        if let Some(prompt) = prompt {
+            meta.prompt_tokens = prompt as u32;
+        }
+        if let Some(completion) = completion {
+            meta.completion_tokens = completion as u32;
+        }
+        meta.total_tokens = match total {
+            Some(total) => total as u32,
+            None => meta.prompt_tokens + meta.completion_tokens,
+        };
+    }
+
+    /// SSE 流式请求；中途断开但保留了部分内容时置 interrupted
+    async fn stream_sse(
+        provider: &dyn ChatProvider,
+        request_body: serde_json::Value,
+        on_event: &impl Fn(ChatStreamEvent),
+        interrupted: &mut bool,
+        meta: &mut GenerationMetadata,
+    ) -> Result<(String, String), ChatError> {
+        let retry_handler = RetryHandler::with_policy(config_manager::retry_policy());
+        let request_body = provider.build_request(request_body);
+        let url_owned = provider.endpoint();
+        let headers_owned = provider.auth_headers();
         let body_clone = request_body.clone();
 
         // 记录请求模型和 token 预算，便于调试
@@ -79,13 +553,15 @@ impl StreamingHandler {
         // read_timeout 会在 SSE 流中模型推理间歇（两个 chunk 之间）误杀连接，
         // 这是「AI 响应中断」的主要原因。改用 tokio::time::timeout 对每个 chunk
         // 单独计时，首 chunk 允许更长等待（模型预热），后续 chunk 更短。
-        let client = reqwest::Client::builder()
+        // 代理（config_manager::apply_proxy）：国内外网络环境都可能需要经代理访问
+        let builder = reqwest::Client::builder()
             .connect_timeout(std::time::Duration::from_secs(timeout_config.connect_timeout_secs))
             // 不设 read_timeout — 由下方 per-chunk tokio::time::timeout 接管
             // 不设 timeout — 对 SSE 流式响应，总超时会误杀正常传输
             .tcp_keepalive(std::time::Duration::from_secs(timeout_config.tcp_keepalive_secs))
             .pool_idle_timeout(std::time::Duration::from_secs(90))
-            .pool_max_idle_per_host(4)
+            .pool_max_idle_per_host(4);
+        let client = config_manager::apply_proxy(builder)?
             .build()
             .map_err(|e| ChatError::NetworkError {
                 message: e.to_string(),
@@ -95,12 +571,14 @@ impl StreamingHandler {
             .execute_with_retry(|| {
                 let client = client.clone();
                 let u = url_owned.clone();
-                let t = token_owned.clone();
+                let h = headers_owned.clone();
                 let b = body_clone.clone();
                 async move {
-                    let resp = client
-                        .post(&u)
-                        .header("Authorization", format!("Bearer {}", &t))
+                    let mut req = client.post(&u);
+                    for (name, value) in &h {
+                        req = req.header(name.as_str(), value.as_str());
+                    }
+                    let resp = req
                         .header("Content-Type", "application/json")
                         // 显式请求 SSE 流
                         .header("Accept", "text/event-stream")
@@ -114,6 +592,7 @@ impl StreamingHandler {
                                     message: format!("连接超时，请检查网络后重试: {}", e),
                                 }
                             } else if e.is_connect() {
+                                network_adaptation::mark_offline();
                                 ChatError::NetworkError {
                                     message: format!("无法连接到 AI 服务器，请检查网络: {}", e),
                                 }
@@ -123,33 +602,24 @@ impl StreamingHandler {
                                 }
                             }
                         })?;
+                    network_adaptation::mark_online();
 
                     let status = resp.status();
                     if !status.is_success() {
                         let status_code = status.as_u16();
-                        // 先尝试读取 retry-after 头（429 专用）
+                        // 先读取 retry-after 头（429 专用，秒数或 HTTP 日期）
                         let retry_after_header = resp
                             .headers()
                             .get("retry-after")
                             .and_then(|v| v.to_str().ok())
-                            .and_then(|v| v.parse::<u64>().ok());
+                            .map(|v| v.to_string());
 
                         let body_text = resp.text().await.unwrap_or_default();
 
-                        // 使用 GLM 错误码精确分类
+                        // 使用 GLM 错误码精确分类，HTTP 头中的 retry-after 优先于业务码的默认等待
                         // 参考: https://docs.bigmodel.cn/cn/api/api-code
-                        let mut err = ChatError::from_glm_response(status_code, &body_text);
-
-                        // 如果 HTTP 头中有 retry-after，优先使用头部指定的等待时间
-                        if let Some(retry_secs) = retry_after_header {
-                            if matches!(err, ChatError::RateLimitError { .. }) {
-                                err = ChatError::RateLimitError {
-                                    retry_after_secs: retry_secs,
-                                };
-                            }
-                        }
-
-                        return Err(err);
+                        return Err(ChatError::from_glm_response(status_code, &body_text)
+                            .with_retry_after(retry_after_header.as_deref()));
                     }
 
                     Ok(resp)
@@ -163,6 +633,7 @@ impl StreamingHandler {
             })?;
 
         let mut stream = response.bytes_stream();
+        let mut decoder = Utf8StreamDecoder::new();
         let mut buffer = String::new();
         let mut full_content = String::new();
         let mut full_thinking = String::new();
@@ -193,7 +664,8 @@ impl StreamingHandler {
                             model_name, chunk_timeout.as_secs(),
                             full_content.len() + full_thinking.len()
                         );
-                        eprintln!("{}", warn_msg);
+                        tracing::warn!("{}", warn_msg);
+                        *interrupted = true;
                         return Ok((full_content, full_thinking));
                     }
                     let err_msg = if chunk_count == 0 {
@@ -222,8 +694,9 @@ impl StreamingHandler {
                             model_name,
                             full_content.len() + full_thinking.len()
                         );
-                        eprintln!("{}", warn_msg);
+                        tracing::warn!("{}", warn_msg);
                         // 直接返回已收到的内容（partial recovery）
+                        *interrupted = true;
                         return Ok((full_content, full_thinking));
                     }
 
@@ -243,7 +716,7 @@ impl StreamingHandler {
                 }
             };
 
-            let text = String::from_utf8_lossy(&chunk);
+            let text = decoder.push(&chunk);
             chunk_count += 1;
 
             if raw_response_preview.len() < 2000 {
@@ -260,7 +733,10 @@ impl StreamingHandler {
                     continue;
                 }
 
-                if let Some(event) = Self::parse_sse_line(&line) {
+                if let Some(json) = Self::sse_json(&line) {
+                    provider.read_generation_metadata(&json, meta);
+                }
+                if let Some(event) = provider.parse_stream_line(&line) {
                     match &event {
                         ChatStreamEvent::ContentDelta(delta) => {
                             full_content.push_str(delta);
@@ -276,18 +752,35 @@ impl StreamingHandler {
                         ChatStreamEvent::Error(_) => {
                             on_event(event);
                         }
+                        // SSE 流本身不会产生设置变更/即时反应/系统提示/插画/语音/统计/发件箱/安全/主动联系事件
+                        ChatStreamEvent::ConfigChanged
+                        | ChatStreamEvent::Reaction(_)
+                        | ChatStreamEvent::SystemNotice(_)
+                        | ChatStreamEvent::Illustration(_)
+                        | ChatStreamEvent::AudioReady(_)
+                        | ChatStreamEvent::Completed(_)
+                        | ChatStreamEvent::Outbox(_)
+                        | ChatStreamEvent::Safety(_)
+                        | ChatStreamEvent::CheckIn(_)
+                        | ChatStreamEvent::SummaryPreview(_)
+                        | ChatStreamEvent::DraftDelta(_)
+                        | ChatStreamEvent::Refined(_) => {}
                     }
                 }
             }
         }
 
+        buffer.push_str(&decoder.finish());
         if !buffer.trim().is_empty() {
             for line in buffer.lines() {
                 let line = line.trim();
                 if line.is_empty() {
                     continue;
                 }
-                if let Some(event) = Self::parse_sse_line(line) {
+                if let Some(json) = Self::sse_json(line) {
+                    provider.read_generation_metadata(&json, meta);
+                }
+                if let Some(event) = provider.parse_stream_line(line) {
                     match &event {
                         ChatStreamEvent::ContentDelta(delta) => {
                             full_content.push_str(delta);
@@ -303,6 +796,19 @@ impl StreamingHandler {
                         ChatStreamEvent::Error(_) => {
                             on_event(event);
                         }
+                        // SSE 流本身不会产生设置变更/即时反应/系统提示/插画/语音/统计/发件箱/安全/主动联系事件
+                        ChatStreamEvent::ConfigChanged
+                        | ChatStreamEvent::Reaction(_)
+                        | ChatStreamEvent::SystemNotice(_)
+                        | ChatStreamEvent::Illustration(_)
+                        | ChatStreamEvent::AudioReady(_)
+                        | ChatStreamEvent::Completed(_)
+                        | ChatStreamEvent::Outbox(_)
+                        | ChatStreamEvent::Safety(_)
+                        | ChatStreamEvent::CheckIn(_)
+                        | ChatStreamEvent::SummaryPreview(_)
+                        | ChatStreamEvent::DraftDelta(_)
+                        | ChatStreamEvent::Refined(_) => {}
                     }
                 }
             }
@@ -314,7 +820,7 @@ impl StreamingHandler {
                 model_name,
                 chunk_count,
                 max_tokens,
-                raw_response_preview.chars().take(500).collect::<String>()
+                text_utils::truncate_chars(&raw_response_preview, 500)
             );
             on_event(ChatStreamEvent::Error(debug_msg));
         }
@@ -331,6 +837,12 @@ impl StreamingHandler {
         Ok((full_content, full_thinking))
     }
 
+    /// SSE 数据行的 JSON 负载（[DONE] 与非 data 行返回 None）
+    fn sse_json(line: &str) -> Option<serde_json::Value> {
+        let data = line.trim().strip_prefix("data:")?.trim();
+        serde_json::from_str(data).ok()
+    }
+
     pub fn parse_sse_line(line: &str) -> Option<ChatStreamEvent> {
         let trimmed = line.trim();
 
@@ -340,13 +852,9 @@ impl StreamingHandler {
         }
 
         if trimmed.starts_with("data: ") || trimmed.starts_with("data:") {
-            let data = if let Some(stripped) = trimmed.strip_prefix("data: ") {
-                stripped
-            } else if let Some(stripped) = trimmed.strip_prefix("data:") {
-                stripped
-            } else {
-                return None;
-            };
+            let data = trimmed
+                .strip_prefix("data: ")
+                .or_else(|| trimmed.strip_prefix("data:"))?;
 
             let data = data.trim();
 
@@ -451,10 +959,115 @@ impl StreamingHandler {
     }
 }
 
+/// 本地大模型（Ollama / llama.cpp 的 OpenAI 兼容服务）
+///
+/// 无需鉴权；本地模型普遍不支持思考模式、上下文较小，
+/// 通过 capabilities 告知管线降级，请求体中的 max_tokens 也按本地上限收紧。
+pub struct LocalLlmProvider {
+    url: String,
+    model: String,
+}
+
+impl LocalLlmProvider {
+    pub fn new(base_url: Option<&str>, model: &str) -> Self {
+        Self {
+            url: chat_completions_url(base_url.unwrap_or(LOCAL_LLM_DEFAULT_BASE_URL)),
+            model: model.to_string(),
+        }
+    }
+}
+
+impl ChatProvider for LocalLlmProvider {
+    fn endpoint(&self) -> String {
+        self.url.clone()
+    }
+
+    fn auth_headers(&self) -> Vec<(String, String)> {
+        Vec::new()
+    }
+
+    fn build_request(&self, mut body: serde_json::Value) -> serde_json::Value {
+        if let Some(obj) = body.as_object_mut() {
+            obj.insert("model".to_string(), serde_json::json!(self.model));
+            obj.remove("thinking");
+            obj.remove("tools");
+            let max_tokens = obj
+                .get("max_tokens")
+                .and_then(|v| v.as_u64())
+                .unwrap_or(LOCAL_LLM_MAX_OUTPUT_TOKENS as u64)
+                .min(LOCAL_LLM_MAX_OUTPUT_TOKENS as u64);
+            obj.insert("max_tokens".to_string(), serde_json::json!(max_tokens));
+        }
+        body
+    }
+
+    fn capabilities(&self) -> ModelCapabilities {
+        ModelCapabilities {
+            supports_thinking: false,
+            context_window: Some(LOCAL_LLM_CONTEXT_WINDOW),
+            max_output_tokens: Some(LOCAL_LLM_MAX_OUTPUT_TOKENS),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_request_queue_limits_and_background_yields() {
+        let queue = RequestQueue::new();
+        let start = Instant::now();
+        let unlimited_rate = (2, 0.0);
+
+        // 后台任务为交互请求预留一个名额
+        assert!(queue.try_start("m", RequestPriority::Background, unlimited_rate, start).is_ok());
+        assert_eq!(queue.try_start("m", RequestPriority::Background, unlimited_rate, start), Err(None));
+        assert!(queue.try_start("m", RequestPriority::Interactive, unlimited_rate, start).is_ok());
+        assert_eq!(queue.try_start("m", RequestPriority::Interactive, unlimited_rate, start), Err(None));
+
+        // 名额归还后，有交互请求排队时后台任务仍要让路
+        drop(RequestPermit { queue: &queue, model: "m".to_string() });
+        drop(RequestPermit { queue: &queue, model: "m".to_string() });
+        let waiting = InteractiveWaiting { queue: &queue, model: "m" };
+        queue.update("m", |lane| lane.interactive_waiting += 1);
+        assert_eq!(queue.try_start("m", RequestPriority::Background, unlimited_rate, start), Err(None));
+        drop(waiting);
+        assert!(queue.try_start("m", RequestPriority::Background, unlimited_rate, start).is_ok());
+
+        // 频率限制：每秒 2 次即相邻间隔 500ms
+        let rate = (0, 2.0);
+        assert!(queue.try_start("n", RequestPriority::Interactive, rate, start).is_ok());
+        let later = start + Duration::from_millis(200);
+        assert_eq!(
+            queue.try_start("n", RequestPriority::Interactive, rate, later),
+            Err(Some(Duration::from_millis(300)))
+        );
+        assert!(queue
+            .try_start("n", RequestPriority::Interactive, rate, start + Duration::from_millis(500))
+            .is_ok());
+    }
+
+    #[test]
+    fn test_utf8_decoder_reassembles_split_characters() {
+        let text = "早安☀️👨\u{200D}👩\u{200D}👧";
+        let bytes = text.as_bytes();
+        let mut decoder = Utf8StreamDecoder::new();
+        let mut out = String::new();
+        // 逐字节送入：每个多字节字符都会被拆散在多个 chunk 中
+        for b in bytes {
+            out.push_str(&decoder.push(std::slice::from_ref(b)));
+        }
+        out.push_str(&decoder.finish());
+        assert_eq!(out, text);
+
+        let mut decoder = Utf8StreamDecoder::new();
+        assert_eq!(decoder.push(&[b'a', 0xFF, 0xE4, 0xBD]), "a\u{FFFD}");
+        assert_eq!(decoder.push(&[0xA0]), "你");
+        assert_eq!(decoder.push(&[0xE5]), "");
+        assert_eq!(decoder.finish(), "\u{FFFD}");
+    }
+
     #[test]
     fn test_parse_content_delta() {
         let line = r#"data: {"id":"xxx","choices":[{"index":0,"delta":{"role":"assistant","content":"你"},"finish_reason":null}]}"#;
@@ -671,4 +1284,30 @@ mod tests {
             other => panic!("Expected ContentDelta, got {:?}", other),
         }
     }
+
+    #[test]
+    fn test_local_llm_provider_defaults() {
+        let provider = LocalLlmProvider::new(None, "qwen2.5:7b");
+        assert_eq!(provider.endpoint(), "http://localhost:11434/v1/chat/completions");
+        assert!(provider.auth_headers().is_empty());
+        let caps = provider.capabilities();
+        assert!(!caps.supports_thinking);
+        assert_eq!(caps.context_window, Some(LOCAL_LLM_CONTEXT_WINDOW));
+    }
+
+    #[test]
+    fn test_local_llm_request_drops_glm_fields_and_clamps_output() {
+        let provider = LocalLlmProvider::new(Some("http://127.0.0.1:8080/v1"), "llama3");
+        let body = provider.build_request(serde_json::json!({
+            "model": "glm-4.7",
+            "messages": [{"role": "user", "content": "hi"}],
+            "stream": true,
+            "max_tokens": 65536,
+            "thinking": {"type": "disabled"},
+        }));
+        assert_eq!(body["model"], "llama3");
+        assert_eq!(body["max_tokens"], LOCAL_LLM_MAX_OUTPUT_TOKENS);
+        assert!(body.get("thinking").is_none());
+        assert_eq!(provider.endpoint(), "http://127.0.0.1:8080/v1/chat/completions");
+    }
 }
@@ -0,0 +1,122 @@
+// ═══════════════════════════════════════════════════════════════════
+//  文本安全截断 (Text Utilities)
+//  ─────────────────────────────────────────────────────────────────
+//  按字节下标切片 `&s[..n]` 落在多字节字符中间会直接 panic；
+//  按字符截断虽然安全，却可能把 emoji 组合序列拆开：
+//    👨‍👩‍👧 = 👨 ZWJ 👩 ZWJ 👧，👍🏽 = 👍 + 肤色修饰符，❤️ = ❤ + VS16
+//  拆开后界面上会出现孤立的人像或方块。这里的截断函数：
+//    1. 始终落在字符边界上
+//    2. 不在 ZWJ / 变体选择符 / 肤色修饰符 / 组合附加符号前后断开，
+//       整个序列要么完整保留、要么整体舍去
+//  所有需要截断展示文本、预览、提示片段的地方都应走这里。
+// ═══════════════════════════════════════════════════════════════════
+
+const ZWJ: char = '\u{200D}';
+
+/// 必须附着在前一个字符上的延续字符（不能作为截断后的第一个字符）
+fn is_continuation(c: char) -> bool {
+    matches!(c,
+        ZWJ
+        | '\u{FE00}'..='\u{FE0F}'      // 变体选择符（VS16 = emoji 样式）
+        | '\u{1F3FB}'..='\u{1F3FF}'    // 肤色修饰符
+        | '\u{0300}'..='\u{036F}'      // 组合附加符号
+        | '\u{20E3}'                   // 组合键帽 (1️⃣)
+        | '\u{E0020}'..='\u{E007F}'    // 旗帜标签序列
+    )
+}
+
+/// 把字节下标调整到不拆分字符与 emoji 序列的位置（只向前退）
+pub fn floor_boundary(s: &str, index: usize) -> usize {
+    if index >= s.len() {
+        return s.len();
+    }
+    let mut idx = index;
+    while !s.is_char_boundary(idx) {
+        idx -= 1;
+    }
+    // 断点后紧跟延续字符，或断点前是 ZWJ：退到整个序列之前
+    loop {
+        let next_attached = s[idx..].chars().next().is_some_and(is_continuation);
+        let prev_joiner = s[..idx].ends_with(ZWJ);
+        if idx == 0 || !(next_attached || prev_joiner) {
+            return idx;
+        }
+        idx -= s[..idx].chars().next_back().map_or(1, char::len_utf8);
+    }
+}
+
+/// 把字节下标调整到不拆分字符与 emoji 序列的位置（只向后进）
+pub fn ceil_boundary(s: &str, index: usize) -> usize {
+    let mut idx = index.min(s.len());
+    while !s.is_char_boundary(idx) {
+        idx += 1;
+    }
+    while idx < s.len() {
+        let next_attached = s[idx..].chars().next().is_some_and(is_continuation);
+        let prev_joiner = s[..idx].ends_with(ZWJ);
+        if !(next_attached || prev_joiner) {
+            break;
+        }
+        idx += s[idx..].chars().next().map_or(1, char::len_utf8);
+    }
+    idx
+}
+
+/// 保留开头至多 max_chars 个字符（不拆分 emoji 序列）
+pub fn truncate_chars(s: &str, max_chars: usize) -> &str {
+    match s.char_indices().nth(max_chars) {
+        Some((idx, _)) => &s[..floor_boundary(s, idx)],
+        None => s,
+    }
+}
+
+/// 保留结尾至多 max_chars 个字符（不拆分 emoji 序列）
+pub fn tail_chars(s: &str, max_chars: usize) -> &str {
+    let total = s.chars().count();
+    if total <= max_chars {
+        return s;
+    }
+    let idx = s.char_indices().nth(total - max_chars).map_or(s.len(), |(i, _)| i);
+    &s[ceil_boundary(s, idx)..]
+}
+
+/// 超长时截断并追加省略号
+pub fn ellipsize(s: &str, max_chars: usize, ellipsis: &str) -> String {
+    let head = truncate_chars(s, max_chars);
+    if head.len() == s.len() {
+        s.to_string()
+    } else {
+        format!("{}{}", head, ellipsis)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncation_keeps_emoji_sequences_whole() {
+        let family = "👨\u{200D}👩\u{200D}👧";
+        let text = format!("你好{}呀", family);
+        assert_eq!(truncate_chars(&text, 2), "你好");
+        // 截在家庭序列中间：整体舍去
+        assert_eq!(truncate_chars(&text, 4), "你好");
+        assert_eq!(truncate_chars(&text, 7), text.trim_end_matches('呀'));
+        assert_eq!(truncate_chars("点赞👍🏽", 3), "点赞");
+        assert_eq!(truncate_chars("短", 10), "短");
+
+        assert_eq!(tail_chars(&text, 1), "呀");
+        assert_eq!(tail_chars(&text, 3), "呀", "不以孤立的 ZWJ 后半段开头");
+        assert_eq!(tail_chars("❤\u{FE0F}好", 2), "好", "不以孤立的变体选择符开头");
+        assert_eq!(ellipsize("一二三四", 2, "…"), "一二…");
+        assert_eq!(ellipsize("一二", 2, "…"), "一二");
+    }
+
+    #[test]
+    fn test_boundaries_never_land_inside_a_char() {
+        let text = "a中b";
+        assert_eq!(floor_boundary(text, 2), 1);
+        assert_eq!(ceil_boundary(text, 2), 4);
+        assert_eq!(floor_boundary(text, 99), text.len());
+    }
+}
@@ -0,0 +1,122 @@
+use chrono::{Datelike, NaiveDateTime, Timelike, Weekday};
+
+use super::data_models::{Message, MessageRole};
+
+// ═══════════════════════════════════════════════════════════════════
+//  现实时间感知 (Time Awareness)
+//  ─────────────────────────────────────────────────────────────────
+//  模型不知道现在几点，凌晨三点的消息和下午三点的回得一模一样，
+//  隔了一周回来也像刚聊完。每轮把现实时间整理成一段提示注入上下文：
+//    1. 当前本地时间：日期、星期、时段（凌晨/上午/…）与时刻
+//    2. 距上一条消息：本轮用户消息之前的最后一句对话到现在过了多久
+//    3. 提醒：深夜、久别时给一句自然带出的建议，其余时候只提供信息
+//  开关为 AppSettings::time_awareness（默认开启，见 ConfigManager）。
+// ═══════════════════════════════════════════════════════════════════
+
+const MINUTE_MS: i64 = 60 * 1000;
+const HOUR_MS: i64 = 60 * MINUTE_MS;
+const DAY_MS: i64 = 24 * HOUR_MS;
+/// 间隔短于此不提「距上一条消息」（连续聊天时没有意义）
+const MENTION_GAP_MS: i64 = 30 * MINUTE_MS;
+/// 间隔超过此视为久别
+const LONG_GAP_MS: i64 = 3 * DAY_MS;
+
+pub struct TimeAwareness;
+
+impl TimeAwareness {
+    /// 本轮之前的最后一句对话的时间：最后一条是用户消息（本轮输入）时取它之前的一条
+    pub fn previous_message_at(messages: &[Message]) -> Option<i64> {
+        let mut history = messages.iter().rev().filter(|m| m.role != MessageRole::System);
+        let last = history.next()?;
+        if last.role == MessageRole::User {
+            history.next().map(|m| m.timestamp)
+        } else {
+            Some(last.timestamp)
+        }
+    }
+
+    fn weekday_label(weekday: Weekday) -> &'static str {
+        match weekday {
+            Weekday::Mon => "星期一",
+            Weekday::Tue => "星期二",
+            Weekday::Wed => "星期三",
+            Weekday::Thu => "星期四",
+            Weekday::Fri => "星期五",
+            Weekday::Sat => "星期六",
+            Weekday::Sun => "星期日",
+        }
+    }
+
+    fn period_label(hour: u32) -> &'static str {
+        match hour {
+            0..=4 => "凌晨",
+            5..=7 => "清晨",
+            8..=10 => "上午",
+            11..=12 => "中午",
+            13..=16 => "下午",
+            17..=18 => "傍晚",
+            _ => "晚上",
+        }
+    }
+
+    /// 「40 分钟」「5 小时」「3 天」「2 周」
+    pub fn describe_elapsed(elapsed_ms: i64) -> String {
+        match elapsed_ms {
+            ms if ms < HOUR_MS => format!("{} 分钟", (ms / MINUTE_MS).max(1)),
+            ms if ms < DAY_MS => format!("{} 小时", ms / HOUR_MS),
+            ms if ms < 14 * DAY_MS => format!("{} 天", ms / DAY_MS),
+            ms => format!("{} 周", ms / (7 * DAY_MS)),
+        }
+    }
+
+    /// 注入上下文的时间提示；elapsed_ms 为距上一条消息的时长（没有上一条时为 None）
+    pub fn build_prompt(local_now: NaiveDateTime, elapsed_ms: Option<i64>) -> String {
+        let hour = local_now.hour();
+        let mut prompt = format!(
+            "【现实时间】现在是{}月{}日 {} {}{}:{:02}。",
+            local_now.month(),
+            local_now.day(),
+            Self::weekday_label(local_now.weekday()),
+            Self::period_label(hour),
+            hour,
+            local_now.minute()
+        );
+        let gap = elapsed_ms.filter(|ms| *ms >= MENTION_GAP_MS);
+        if let Some(ms) = gap {
+            prompt.push_str(&format!("距离上一条消息已经过去约 {}。", Self::describe_elapsed(ms)));
+        }
+        if hour <= 4 {
+            prompt.push_str("\n已经是深夜了，可以自然地留意到对方这么晚还没睡。");
+        }
+        if gap.is_some_and(|ms| ms >= LONG_GAP_MS) {
+            prompt.push_str("\n你们有一阵子没聊了，开口时自然地带出久别的感觉，不要当作刚聊完。");
+        }
+        prompt.push_str("\n只在合适的时候自然提到时间，不要刻意报时。");
+        prompt
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn test_time_prompt_notices_late_night_and_long_gap() {
+        // 2024-03-05 是星期二
+        let late = NaiveDate::from_ymd_opt(2024, 3, 5)
+            .unwrap()
+            .and_hms_opt(3, 7, 0)
+            .unwrap();
+        let prompt = TimeAwareness::build_prompt(late, Some(7 * DAY_MS + HOUR_MS));
+        assert!(prompt.contains("3月5日 星期二 凌晨3:07"));
+        assert!(prompt.contains("约 7 天"));
+        assert!(prompt.contains("深夜") && prompt.contains("久别"));
+
+        let afternoon = late.with_hour(15).unwrap();
+        let prompt = TimeAwareness::build_prompt(afternoon, Some(5 * MINUTE_MS));
+        assert!(prompt.contains("下午15:07"));
+        assert!(!prompt.contains("距离上一条消息"));
+        assert!(!prompt.contains("深夜") && !prompt.contains("久别"));
+    }
+}
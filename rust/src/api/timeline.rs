@@ -0,0 +1,142 @@
+use super::data_models::*;
+use super::knowledge_store::{Fact, FactCategory};
+
+// ═══════════════════════════════════════════════════════════════════
+//  故事时间线 (Story Timeline)
+//  ─────────────────────────────────────────────────────────────────
+//  把两路长期记忆汇成一条按时间排列的「前情提要」：
+//    - 章节：MemoryEngine 的记忆摘要，每条覆盖一段轮次
+//    - 事件：KnowledgeStore 中 Event 分类的事实，落在提取时的轮次上
+//  轮次本身没有时间，按当前时间线上第 N 条用户消息的时间换算；
+//  换算不到（消息已被截断、手动添加的事实等）时用条目自身的创建时间。
+//
+//  存储结构：无。每次查询时从记忆索引与事实库现算
+// ═══════════════════════════════════════════════════════════════════
+
+pub struct StoryTimeline;
+
+impl StoryTimeline {
+    /// messages 为当前时间线上的消息（按时间先后），cold_turns 为已移入冷存储的轮数
+    /// （messages 中第一条用户消息是第 cold_turns + 1 轮）。
+    /// 结果按时间从早到晚排列；同一时间时章节排在事件之前
+    pub fn build(
+        messages: &[Message],
+        cold_turns: u32,
+        summaries: &[MemorySummary],
+        facts: &[Fact],
+    ) -> Vec<TimelineEntry> {
+        let turn_stamps: Vec<i64> = messages
+            .iter()
+            .filter(|m| m.role == MessageRole::User)
+            .map(|m| m.timestamp)
+            .collect();
+        let stamp_of = |turn: u32, fallback: i64| {
+            turn.checked_sub(cold_turns + 1)
+                .and_then(|i| turn_stamps.get(i as usize))
+                .copied()
+                .unwrap_or(fallback)
+        };
+
+        let mut entries: Vec<TimelineEntry> = summaries
+            .iter()
+            .map(|s| TimelineEntry {
+                id: s.id.clone(),
+                kind: TimelineEntryKind::Chapter,
+                text: s.summary.clone(),
+                turn_start: s.turn_range_start,
+                turn_end: s.turn_range_end,
+                timestamp: stamp_of(s.turn_range_start, s.created_at),
+                end_timestamp: stamp_of(s.turn_range_end, s.created_at),
+            })
+            .collect();
+        entries.extend(
+            facts
+                .iter()
+                .filter(|f| f.category == FactCategory::Event)
+                .map(|f| {
+                    let timestamp = stamp_of(f.source_turn, f.created_at);
+                    TimelineEntry {
+                        id: f.id.clone(),
+                        kind: TimelineEntryKind::Event,
+                        text: f.content.clone(),
+                        turn_start: f.source_turn,
+                        turn_end: f.source_turn,
+                        timestamp,
+                        end_timestamp: timestamp,
+                    }
+                }),
+        );
+
+        entries.sort_by_key(|e| {
+            (
+                e.timestamp,
+                e.turn_start,
+                matches!(e.kind, TimelineEntryKind::Event),
+            )
+        });
+        entries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::knowledge_store::KnowledgeStore;
+
+    fn message(role: MessageRole, timestamp: i64) -> Message {
+        Message {
+            timestamp,
+            ..Message::new(role, "……", "glm-4.7")
+        }
+    }
+
+    #[test]
+    fn test_timeline_maps_turns_to_timestamps() {
+        let messages: Vec<Message> = (1..=4i64)
+            .flat_map(|t| {
+                vec![
+                    message(MessageRole::User, t * 1000),
+                    message(MessageRole::Assistant, t * 1000 + 500),
+                ]
+            })
+            .collect();
+        let summary = MemorySummary {
+            id: "s1".to_string(),
+            summary: "初次见面，约好去海边".to_string(),
+            core_facts: vec![],
+            turn_range_start: 1,
+            turn_range_end: 3,
+            created_at: 9_000,
+            keywords: vec![],
+            compression_generation: 0,
+            context_card: None,
+            fact_tiers: vec![],
+            linked_fact_ids: vec![],
+            pinned: false,
+            pinned_facts: Vec::new(),
+            stale: false,
+        };
+        let mut facts = KnowledgeStore::parse_extracted_facts(
+            r#"[{"content": "用户→去了→海边", "category": "event"},
+                {"content": "用户→喜欢→海", "category": "preference"}]"#,
+            4,
+        );
+        let mut manual = KnowledgeStore::user_fact("用户→捡到→贝壳", 0);
+        manual.created_at = 2_500;
+        facts.push(manual);
+
+        let timeline = StoryTimeline::build(&messages, 0, &[summary], &facts);
+        let brief: Vec<(TimelineEntryKind, i64)> =
+            timeline.iter().map(|e| (e.kind, e.timestamp)).collect();
+        assert_eq!(
+            brief,
+            vec![
+                (TimelineEntryKind::Chapter, 1000),
+                (TimelineEntryKind::Event, 2500),
+                (TimelineEntryKind::Event, 4000),
+            ]
+        );
+        assert_eq!(timeline[0].end_timestamp, 3000);
+        assert_eq!(timeline[2].text, "用户→去了→海边");
+    }
+}
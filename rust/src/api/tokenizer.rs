@@ -0,0 +1,296 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
+
+use super::data_models::Message;
+use super::error_handler::ChatError;
+
+// ═══════════════════════════════════════════════════════════════════
+//  Token 计数 (Token Counting)
+//  ─────────────────────────────────────────────────────────────────
+//  上下文评估（assess_context_needs）、max_tokens 计算与上下文截断都
+//  通过 TokenCounter 计数，避免粗估偏差导致过早压缩或请求超长被拒。
+//    · BpeTokenCounter：读取 HuggingFace tokenizer.json（字节级 BPE，
+//      GLM-4 / tiktoken 系词表均可导出为此格式），按合并规则真实切分
+//    · HeuristicTokenCounter：没有词表时的按字符估算（原有算法）
+//  数据目录下存在 tokenizer.json 时 init_app 自动加载，否则用估算。
+//  只计数、不编码，词表中不存在的符号按 1 个 token 计。
+// ═══════════════════════════════════════════════════════════════════
+
+/// 词表文件名（位于数据目录）
+pub const TOKENIZER_FILE: &str = "tokenizer.json";
+/// 每条消息的格式开销（role 标记等）
+pub const MESSAGE_OVERHEAD_TOKENS: usize = 4;
+/// 切分缓存上限，超出后整体清空
+const PIECE_CACHE_LIMIT: usize = 50_000;
+/// 数字按最多 3 位一组切分（与 GLM-4 / cl100k 预切分一致）
+const MAX_DIGIT_RUN: usize = 3;
+
+static GLOBAL: OnceLock<RwLock<Arc<dyn TokenCounter>>> = OnceLock::new();
+
+/// Token 计数器
+pub trait TokenCounter: Send + Sync {
+    fn count_text(&self, text: &str) -> usize;
+
+    fn count_messages(&self, messages: &[Message]) -> usize {
+        messages
+            .iter()
+            .map(|m| self.count_text(&m.content) + MESSAGE_OVERHEAD_TOKENS)
+            .sum()
+    }
+}
+
+fn global_slot() -> &'static RwLock<Arc<dyn TokenCounter>> {
+    GLOBAL.get_or_init(|| RwLock::new(Arc::new(HeuristicTokenCounter)))
+}
+
+/// 进程内共享的计数器（默认为估算）
+pub fn global() -> Arc<dyn TokenCounter> {
+    global_slot().read().unwrap().clone()
+}
+
+/// 替换进程内共享的计数器
+pub fn install(counter: Arc<dyn TokenCounter>) {
+    *global_slot().write().unwrap() = counter;
+}
+
+/// 数据目录下有词表时加载并安装，返回是否已切换为 BPE 计数
+pub fn load_from_dir(data_path: &str) -> bool {
+    let path = Path::new(data_path).join(TOKENIZER_FILE);
+    if !path.exists() {
+        return false;
+    }
+    match BpeTokenCounter::load(&path) {
+        Ok(counter) => {
+            install(Arc::new(counter));
+            true
+        }
+        Err(_) => false,
+    }
+}
+
+/// 按字符估算：中文 1 字 ≈ 1.5 token，英文 1 词 ≈ 1 token，其他 1 字符 ≈ 1 token
+pub struct HeuristicTokenCounter;
+
+impl TokenCounter for HeuristicTokenCounter {
+    fn count_text(&self, text: &str) -> usize {
+        let char_count = text.chars().count();
+        let cjk_chars = text
+            .chars()
+            .filter(|c| *c > '\u{4e00}' && *c < '\u{9fff}')
+            .count();
+        let ascii_words = text.split_whitespace().filter(|w| w.is_ascii()).count();
+        (cjk_chars as f64 * 1.5) as usize + ascii_words + char_count.saturating_sub(cjk_chars + ascii_words)
+    }
+}
+
+/// 字节级 BPE 计数（HuggingFace tokenizer.json）
+pub struct BpeTokenCounter {
+    /// 合并规则 → 优先级（越小越先合并）
+    merges: HashMap<(String, String), usize>,
+    /// 字节 → 可见字符（GPT-2 字节映射）
+    byte_chars: [char; 256],
+    /// 预切分片段 → token 数
+    cache: Mutex<HashMap<String, usize>>,
+}
+
+impl BpeTokenCounter {
+    pub fn load(path: &Path) -> Result<Self, ChatError> {
+        let json = fs::read_to_string(path).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to read tokenizer: {}", e),
+        })?;
+        Self::from_tokenizer_json(&json)
+    }
+
+    /// 解析 tokenizer.json 的 model.merges（"a b" 或 ["a", "b"] 两种写法）
+    pub fn from_tokenizer_json(json: &str) -> Result<Self, ChatError> {
+        let invalid = |message: &str| ChatError::ValidationError {
+            message: format!("Invalid tokenizer: {}", message),
+        };
+        let value: serde_json::Value =
+            serde_json::from_str(json).map_err(|e| invalid(&e.to_string()))?;
+        let model = value.get("model").ok_or_else(|| invalid("missing model"))?;
+        if model.get("type").and_then(|t| t.as_str()).is_some_and(|t| t != "BPE") {
+            return Err(invalid("only BPE models are supported"));
+        }
+        let entries = model
+            .get("merges")
+            .and_then(|m| m.as_array())
+            .ok_or_else(|| invalid("missing merges"))?;
+        let mut merges = HashMap::with_capacity(entries.len());
+        for (rank, entry) in entries.iter().enumerate() {
+            let pair = match entry {
+                serde_json::Value::String(s) => s
+                    .split_once(' ')
+                    .map(|(a, b)| (a.to_string(), b.to_string())),
+                serde_json::Value::Array(parts) => match (
+                    parts.first().and_then(|p| p.as_str()),
+                    parts.get(1).and_then(|p| p.as_str()),
+                ) {
+                    (Some(a), Some(b)) => Some((a.to_string(), b.to_string())),
+                    _ => None,
+                },
+                _ => None,
+            };
+            if let Some(pair) = pair {
+                merges.entry(pair).or_insert(rank);
+            }
+        }
+        if merges.is_empty() {
+            return Err(invalid("empty merges"));
+        }
+        Ok(Self {
+            merges,
+            byte_chars: byte_chars(),
+            cache: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// 单个预切分片段的 token 数
+    fn count_piece(&self, piece: &str) -> usize {
+        if let Some(&n) = self.cache.lock().unwrap().get(piece) {
+            return n;
+        }
+        let mut symbols: Vec<String> = piece
+            .bytes()
+            .map(|b| self.byte_chars[b as usize].to_string())
+            .collect();
+        while symbols.len() > 1 {
+            let best = symbols
+                .windows(2)
+                .enumerate()
+                .filter_map(|(i, w)| {
+                    self.merges
+                        .get(&(w[0].clone(), w[1].clone()))
+                        .map(|&rank| (rank, i))
+                })
+                .min();
+            let Some((_, i)) = best else {
+                break;
+            };
+            let right = symbols.remove(i + 1);
+            symbols[i].push_str(&right);
+        }
+        let n = symbols.len();
+        let mut cache = self.cache.lock().unwrap();
+        if cache.len() >= PIECE_CACHE_LIMIT {
+            cache.clear();
+        }
+        cache.insert(piece.to_string(), n);
+        n
+    }
+}
+
+impl TokenCounter for BpeTokenCounter {
+    fn count_text(&self, text: &str) -> usize {
+        pre_tokenize(text).iter().map(|p| self.count_piece(p)).sum()
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum CharClass {
+    Letter,
+    Digit,
+    Space,
+    Other,
+}
+
+fn classify(c: char) -> CharClass {
+    if c.is_alphabetic() {
+        CharClass::Letter
+    } else if c.is_numeric() {
+        CharClass::Digit
+    } else if c.is_whitespace() {
+        CharClass::Space
+    } else {
+        CharClass::Other
+    }
+}
+
+/// 预切分：同类字符成段，数字最多 3 位一段，
+/// 字母段 / 符号段吸收前面紧邻的单个空格（近似 GLM-4 / cl100k 的切分正则）
+pub fn pre_tokenize(text: &str) -> Vec<&str> {
+    let mut pieces = Vec::new();
+    let chars: Vec<(usize, char)> = text.char_indices().collect();
+    let mut i = 0;
+    while i < chars.len() {
+        let start = chars[i].0;
+        let mut class = classify(chars[i].1);
+        let mut j = i + 1;
+        if chars[i].1 == ' ' {
+            if let Some(&(_, next)) = chars.get(j) {
+                if matches!(classify(next), CharClass::Letter | CharClass::Other) {
+                    class = classify(next);
+                    j += 1;
+                }
+            }
+        }
+        let mut run = j - i;
+        while j < chars.len() && classify(chars[j].1) == class {
+            if class == CharClass::Digit && run >= MAX_DIGIT_RUN {
+                break;
+            }
+            // 空白段不吞掉下一段要吸收的空格
+            if class == CharClass::Space
+                && chars[j].1 == ' '
+                && chars
+                    .get(j + 1)
+                    .is_some_and(|&(_, c)| matches!(classify(c), CharClass::Letter | CharClass::Other))
+            {
+                break;
+            }
+            j += 1;
+            run += 1;
+        }
+        let end = chars.get(j).map(|&(idx, _)| idx).unwrap_or(text.len());
+        pieces.push(&text[start..end]);
+        i = j;
+    }
+    pieces
+}
+
+/// GPT-2 字节映射：可打印字节映射为自身，其余映射到 U+0100 之后
+fn byte_chars() -> [char; 256] {
+    let mut table = ['\0'; 256];
+    let mut next = 0u32;
+    for b in 0..=255u8 {
+        let printable = matches!(b, b'!'..=b'~' | 0xA1..=0xAC | 0xAE..=0xFF);
+        table[b as usize] = if printable {
+            b as char
+        } else {
+            let c = char::from_u32(256 + next).unwrap_or('\u{FFFD}');
+            next += 1;
+            c
+        };
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pre_tokenize_groups_classes() {
+        assert_eq!(
+            pre_tokenize("Hello world 12345，你好"),
+            vec!["Hello", " world", " ", "123", "45", "，", "你好"]
+        );
+        assert_eq!(pre_tokenize("a  b\n"), vec!["a", " ", " b", "\n"]);
+        assert!(pre_tokenize("").is_empty());
+    }
+
+    #[test]
+    fn test_bpe_counts_by_merges() {
+        let json = r#"{"model": {"type": "BPE", "vocab": {},
+            "merges": ["l o", ["Ġ", "lo"], "Ġlo w", "lo w"]}}"#;
+        let counter = BpeTokenCounter::from_tokenizer_json(json).unwrap();
+        assert_eq!(counter.count_text("low"), 1);
+        assert_eq!(counter.count_text("low low"), 2);
+        assert_eq!(counter.count_text("lowly"), 3);
+        // 中文每字 3 字节，无合并时按字节计
+        assert_eq!(counter.count_text("你"), 3);
+        assert!(BpeTokenCounter::from_tokenizer_json(r#"{"model": {"type": "Unigram"}}"#).is_err());
+    }
+}
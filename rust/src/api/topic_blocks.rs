@@ -0,0 +1,247 @@
+use std::fs;
+use std::path::PathBuf;
+
+use flutter_rust_bridge::frb;
+
+use super::cognitive_engine::TopicBlockSignal;
+use super::data_models::BlockedTopic;
+use super::error_handler::ChatError;
+
+// ═══════════════════════════════════════════════════════════════════
+//  回避话题 (Blocked Topics)
+//  ─────────────────────────────────────────────────────────────────
+//  用户说「别聊这个」或对同一个问题一再敷衍，说明角色踩了雷。
+//  这里把这类纠正学下来，之后每轮提醒角色不要主动提起：
+//    1. 识别：CognitiveEngine::detect_topic_block 给出回避信号
+//    2. 归并：关键词大部分重合的信号视为同一话题，累计次数与原话
+//    3. 生效：明确叫停一次即生效，敷衍累计 DEFLECTION_THRESHOLD 次生效
+//    4. 管理：用户可查看学到的话题及依据，并随时移除
+//
+//  存储结构：
+//    blocked_topics/{conversation_id}.json
+// ═══════════════════════════════════════════════════════════════════
+
+/// 每个对话最多保留的话题数（超出时丢弃最久未触发的）
+const MAX_TOPICS: usize = 30;
+/// 每个话题保留的原话条数
+const MAX_EVIDENCE: usize = 5;
+/// 敷衍累计多少次后生效
+const DEFLECTION_THRESHOLD: u32 = 2;
+/// 关键词重合比例（相对较少的一方）达到该值视为同一话题
+const TOPIC_MERGE_OVERLAP: f64 = 0.5;
+
+#[frb(opaque)]
+pub struct BlockedTopicStore {
+    base_path: String,
+}
+
+impl BlockedTopicStore {
+    pub fn new(base_path: &str) -> Self {
+        Self {
+            base_path: base_path.to_string(),
+        }
+    }
+
+    fn topics_dir(&self) -> Result<PathBuf, ChatError> {
+        let dir = PathBuf::from(&self.base_path).join("blocked_topics");
+        if !dir.exists() {
+            fs::create_dir_all(&dir).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to create blocked topics directory: {}", e),
+            })?;
+        }
+        Ok(dir)
+    }
+
+    fn topics_path(&self, conversation_id: &str) -> Result<PathBuf, ChatError> {
+        Ok(self.topics_dir()?.join(format!("{}.json", conversation_id)))
+    }
+
+    pub fn load(&self, conversation_id: &str) -> Result<Vec<BlockedTopic>, ChatError> {
+        let path = self.topics_path(conversation_id)?;
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+        let json = fs::read_to_string(&path).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to read blocked topics: {}", e),
+        })?;
+        serde_json::from_str(&json).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to parse blocked topics: {}", e),
+        })
+    }
+
+    fn save(&self, conversation_id: &str, topics: &[BlockedTopic]) -> Result<(), ChatError> {
+        let path = self.topics_path(conversation_id)?;
+        let json = serde_json::to_string_pretty(topics).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to serialize blocked topics: {}", e),
+        })?;
+        fs::write(&path, json).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to write blocked topics: {}", e),
+        })
+    }
+
+    /// 已生效的话题
+    pub fn active(&self, conversation_id: &str) -> Vec<BlockedTopic> {
+        self.load(conversation_id)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|t| t.active)
+            .collect()
+    }
+
+    /// 记录一次回避信号，归并到已有话题或新建；返回更新后的话题
+    pub fn record(
+        &self,
+        conversation_id: &str,
+        signal: &TopicBlockSignal,
+    ) -> Result<BlockedTopic, ChatError> {
+        let mut topics = self.load(conversation_id).unwrap_or_default();
+        let now = chrono::Utc::now().timestamp_millis();
+
+        let idx = match topics.iter().position(|t| Self::same_topic(t, signal)) {
+            Some(idx) => idx,
+            None => {
+                topics.push(BlockedTopic {
+                    id: uuid::Uuid::new_v4().to_string(),
+                    topic: signal.topic.clone(),
+                    keywords: Vec::new(),
+                    evidence: Vec::new(),
+                    occurrences: 0,
+                    explicit: false,
+                    active: false,
+                    first_seen: now,
+                    last_seen: now,
+                });
+                topics.len() - 1
+            }
+        };
+
+        let topic = &mut topics[idx];
+        // 用户点名的话题比被回避的问句更贴切
+        if signal.explicit && !topic.explicit {
+            topic.topic = signal.topic.clone();
+        }
+        for kw in &signal.keywords {
+            if !topic.keywords.contains(kw) {
+                topic.keywords.push(kw.clone());
+            }
+        }
+        topic.evidence.push(signal.evidence.clone());
+        if topic.evidence.len() > MAX_EVIDENCE {
+            let overflow = topic.evidence.len() - MAX_EVIDENCE;
+            topic.evidence.drain(..overflow);
+        }
+        topic.occurrences += 1;
+        topic.explicit |= signal.explicit;
+        topic.active = topic.explicit || topic.occurrences >= DEFLECTION_THRESHOLD;
+        topic.last_seen = now;
+        let updated = topic.clone();
+
+        if topics.len() > MAX_TOPICS {
+            topics.sort_by_key(|t| std::cmp::Reverse(t.last_seen));
+            topics.truncate(MAX_TOPICS);
+        }
+        self.save(conversation_id, &topics)?;
+        Ok(updated)
+    }
+
+    /// 移除一个学到的话题；不存在时返回 false
+    pub fn remove(&self, conversation_id: &str, topic_id: &str) -> Result<bool, ChatError> {
+        let mut topics = self.load(conversation_id)?;
+        let before = topics.len();
+        topics.retain(|t| t.id != topic_id);
+        if topics.len() == before {
+            return Ok(false);
+        }
+        self.save(conversation_id, &topics)?;
+        Ok(true)
+    }
+
+    pub fn delete(&self, conversation_id: &str) -> Result<(), ChatError> {
+        let path = self.topics_path(conversation_id)?;
+        if path.exists() {
+            fs::remove_file(&path).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to delete blocked topics: {}", e),
+            })?;
+        }
+        Ok(())
+    }
+
+    fn same_topic(topic: &BlockedTopic, signal: &TopicBlockSignal) -> bool {
+        if topic.topic == signal.topic {
+            return true;
+        }
+        let smaller = topic.keywords.len().min(signal.keywords.len());
+        if smaller == 0 {
+            return false;
+        }
+        let shared = signal
+            .keywords
+            .iter()
+            .filter(|k| topic.keywords.contains(k))
+            .count();
+        shared as f64 / smaller as f64 >= TOPIC_MERGE_OVERLAP
+    }
+
+    /// 回避提示块；没有生效话题时返回 None
+    pub fn build_prompt(topics: &[BlockedTopic]) -> Option<String> {
+        if topics.is_empty() {
+            return None;
+        }
+        let list: Vec<String> = topics.iter().map(|t| format!("- {}", t.topic)).collect();
+        Some(format!(
+            "【回避话题】用户之前明确或多次表示不想聊以下话题：\n{}\n\
+             不要主动提起、追问或拿来开玩笑；如果用户自己重新提起，自然回应即可，不要深挖。",
+            list.join("\n")
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn signal(topic: &str, keywords: &[&str], explicit: bool) -> TopicBlockSignal {
+        TopicBlockSignal {
+            topic: topic.to_string(),
+            keywords: keywords.iter().map(|k| k.to_string()).collect(),
+            evidence: "嗯".to_string(),
+            explicit,
+        }
+    }
+
+    #[test]
+    fn test_deflections_activate_after_threshold() {
+        let tmp = TempDir::new().unwrap();
+        let store = BlockedTopicStore::new(tmp.path().to_str().unwrap());
+        let first = store
+            .record("c1", &signal("你和前任还有联系吗", &["前任", "联系"], false))
+            .unwrap();
+        assert!(!first.active);
+        assert!(store.active("c1").is_empty());
+
+        let second = store
+            .record("c1", &signal("前任后来怎么样了", &["前任", "后来"], false))
+            .unwrap();
+        assert_eq!(second.id, first.id);
+        assert_eq!(second.occurrences, 2);
+        assert!(second.active);
+        assert!(BlockedTopicStore::build_prompt(&store.active("c1"))
+            .unwrap()
+            .contains("你和前任还有联系吗"));
+    }
+
+    #[test]
+    fn test_explicit_block_is_immediate_and_removable() {
+        let tmp = TempDir::new().unwrap();
+        let store = BlockedTopicStore::new(tmp.path().to_str().unwrap());
+        let topic = store.record("c1", &signal("我的工作", &["工作"], true)).unwrap();
+        assert!(topic.active);
+        assert_eq!(store.active("c1").len(), 1);
+
+        assert!(store.remove("c1", &topic.id).unwrap());
+        assert!(!store.remove("c1", &topic.id).unwrap());
+        assert!(store.active("c1").is_empty());
+        assert!(BlockedTopicStore::build_prompt(&[]).is_none());
+    }
+}
@@ -0,0 +1,235 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use serde_json::{json, Value};
+
+use super::config_manager;
+use super::data_models::{AppSettings, ProviderKind};
+use super::error_handler::ChatError;
+use super::jwt_auth::JwtAuth;
+use super::text_utils;
+
+// ═══════════════════════════════════════════════════════════════════
+//  语音回复 (Text-to-Speech)
+//  ─────────────────────────────────────────────────────────────────
+//  开启 AppSettings.enable_tts 后，每条回复落盘之后：
+//    1. 朗读文本：去掉 *动作* /（动作）描写，只读说出口的话
+//    2. 音色：按角色取（群聊为角色ID，单聊为对话ID，见 ConfigManager 的
+//       voices.json），未配置时用 DEFAULT_VOICE
+//    3. 合成：智谱 CogTTS（audio/speech），音频存为本地文件，
+//       路径写回 Message.audio_path
+//    4. 推送：ChatStreamEvent::AudioReady
+//  合成失败不影响回复本身，只是这条消息没有语音。只有智谱提供方可用。
+//
+//  存储结构：
+//    audio/{conversation_id}__{message_id}.wav
+// ═══════════════════════════════════════════════════════════════════
+
+const COGTTS_URL: &str = "https://open.bigmodel.cn/api/paas/v4/audio/speech";
+const COGTTS_MODEL: &str = "cogtts";
+/// 未配置角色音色时的默认音色
+pub const DEFAULT_VOICE: &str = "tongtong";
+const AUDIO_FORMAT: &str = "wav";
+/// 合成请求超时
+const TTS_TIMEOUT_SECS: u64 = 30;
+/// 单次朗读的最长字符数（超出部分不读）
+const MAX_SPEECH_CHARS: usize = 500;
+/// 文件名中对话ID与消息ID的分隔符
+const NAME_SEPARATOR: &str = "__";
+
+/// 智谱 CogTTS 语音合成
+pub struct TtsClient {
+    jwt_auth: Mutex<JwtAuth>,
+    /// 角色 → 音色（见 ConfigManager::load_voices）
+    voices: HashMap<String, String>,
+}
+
+impl TtsClient {
+    pub fn new(api_key: &str, voices: HashMap<String, String>) -> Result<Self, String> {
+        Ok(Self {
+            jwt_auth: Mutex::new(JwtAuth::new(api_key)?),
+            voices,
+        })
+    }
+
+    /// 按设置创建：开启了语音回复、智谱提供方且配置了 API Key 时可用
+    pub fn from_settings(settings: &AppSettings, voices: HashMap<String, String>) -> Option<Self> {
+        if !settings.enable_tts || settings.provider != ProviderKind::Zhipu {
+            return None;
+        }
+        let key = settings.api_key.as_deref().map(str::trim).filter(|k| !k.is_empty())?;
+        Self::new(key, voices).ok()
+    }
+
+    /// 角色音色：群聊角色优先，其次对话，最后默认音色
+    pub fn voice_for(&self, conversation_id: &str, character_id: Option<&str>) -> &str {
+        character_id
+            .and_then(|id| self.voices.get(id))
+            .or_else(|| self.voices.get(conversation_id))
+            .map(String::as_str)
+            .unwrap_or(DEFAULT_VOICE)
+    }
+
+    pub fn build_request(text: &str, voice: &str) -> Value {
+        json!({
+            "model": COGTTS_MODEL,
+            "input": text,
+            "voice": voice,
+            "response_format": AUDIO_FORMAT,
+        })
+    }
+
+    /// 合成一段语音，返回音频字节
+    pub async fn synthesize(&self, text: &str, voice: &str) -> Result<Vec<u8>, ChatError> {
+        let client = config_manager::apply_proxy(
+            reqwest::Client::builder().timeout(std::time::Duration::from_secs(TTS_TIMEOUT_SECS)),
+        )?
+        .build()
+        .map_err(|e| ChatError::NetworkError {
+            message: e.to_string(),
+        })?;
+        let token = {
+            let mut auth = self.jwt_auth.lock().unwrap();
+            auth.get_token()
+        };
+        let resp = client
+            .post(COGTTS_URL)
+            .header("Authorization", format!("Bearer {}", token))
+            .json(&Self::build_request(text, voice))
+            .send()
+            .await
+            .map_err(|e| ChatError::NetworkError {
+                message: format!("语音合成请求失败: {}", e),
+            })?;
+        let status = resp.status();
+        if !status.is_success() {
+            return Err(ChatError::ApiError {
+                status: status.as_u16(),
+                message: resp.text().await.unwrap_or_default(),
+            });
+        }
+        let bytes = resp.bytes().await.map_err(|e| ChatError::StreamError {
+            message: format!("Failed to read speech audio: {}", e),
+        })?;
+        Ok(bytes.to_vec())
+    }
+
+    /// 朗读文本：去掉 *动作* 与（动作）描写，合并空白；没有可读内容时返回 None
+    pub fn speakable_text(content: &str) -> Option<String> {
+        let mut text = String::with_capacity(content.len());
+        let mut closing: Option<char> = None;
+        for c in content.chars() {
+            match closing {
+                Some(close) if c == close => closing = None,
+                Some(_) => {}
+                None => match c {
+                    '*' => closing = Some('*'),
+                    '（' => closing = Some('）'),
+                    '(' => closing = Some(')'),
+                    _ => text.push(c),
+                },
+            }
+        }
+        let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+        let text = text.trim_matches(|c: char| c.is_whitespace() || "，。、".contains(c));
+        if text.is_empty() {
+            return None;
+        }
+        Some(text_utils::truncate_chars(text, MAX_SPEECH_CHARS).to_string())
+    }
+}
+
+/// 语音文件存储
+pub struct AudioStore {
+    base_path: String,
+}
+
+impl AudioStore {
+    pub fn new(base_path: &str) -> Self {
+        Self {
+            base_path: base_path.to_string(),
+        }
+    }
+
+    fn audio_dir(&self) -> Result<PathBuf, ChatError> {
+        let dir = PathBuf::from(&self.base_path).join("audio");
+        if !dir.exists() {
+            fs::create_dir_all(&dir).map_err(|e| ChatError::StorageError {
+                message: format!("Failed to create audio directory: {}", e),
+            })?;
+        }
+        Ok(dir)
+    }
+
+    /// 保存一条消息的语音，返回文件路径
+    pub fn save(&self, conversation_id: &str, message_id: &str, audio: &[u8]) -> Result<String, ChatError> {
+        let path = self.audio_dir()?.join(format!(
+            "{}{}{}.{}",
+            conversation_id, NAME_SEPARATOR, message_id, AUDIO_FORMAT
+        ));
+        fs::write(&path, audio).map_err(|e| ChatError::StorageError {
+            message: format!("Failed to write audio: {}", e),
+        })?;
+        Ok(path.to_string_lossy().to_string())
+    }
+
+    /// 删除对话的全部语音
+    pub fn delete(&self, conversation_id: &str) -> Result<(), ChatError> {
+        let prefix = format!("{}{}", conversation_id, NAME_SEPARATOR);
+        let entries = match fs::read_dir(self.audio_dir()?) {
+            Ok(entries) => entries,
+            Err(_) => return Ok(()),
+        };
+        for entry in entries.filter_map(|e| e.ok()) {
+            let is_ours = entry
+                .file_name()
+                .to_str()
+                .is_some_and(|name| name.starts_with(&prefix));
+            if is_ours {
+                fs::remove_file(entry.path()).map_err(|e| ChatError::StorageError {
+                    message: format!("Failed to delete audio: {}", e),
+                })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_speakable_text_skips_actions() {
+        assert_eq!(
+            TtsClient::speakable_text("*揉了揉眼睛* 你回来啦（小声）。今天好冷").as_deref(),
+            Some("你回来啦。今天好冷")
+        );
+        assert_eq!(TtsClient::speakable_text("*点点头*"), None);
+        assert_eq!(TtsClient::speakable_text("（沉默）  "), None);
+    }
+
+    #[test]
+    fn test_voice_lookup_and_audio_files() {
+        let voices = HashMap::from([
+            ("c1".to_string(), "xiaochen".to_string()),
+            ("char-a".to_string(), "jam".to_string()),
+        ]);
+        let client = TtsClient::new("id.secret", voices).unwrap();
+        assert_eq!(client.voice_for("c1", Some("char-a")), "jam");
+        assert_eq!(client.voice_for("c1", Some("char-b")), "xiaochen");
+        assert_eq!(client.voice_for("c2", None), DEFAULT_VOICE);
+
+        let tmp = TempDir::new().unwrap();
+        let store = AudioStore::new(tmp.path().to_str().unwrap());
+        let path = store.save("c1", "m1", b"RIFF").unwrap();
+        store.save("c10", "m1", b"RIFF").unwrap();
+        assert!(path.ends_with("c1__m1.wav"));
+        store.delete("c1").unwrap();
+        assert!(!std::path::Path::new(&path).exists());
+        assert_eq!(fs::read_dir(tmp.path().join("audio")).unwrap().count(), 1);
+    }
+}
@@ -0,0 +1,107 @@
+// ═══════════════════════════════════════════════════════════════════
+//  联网搜索 (Web Search)
+//  ─────────────────────────────────────────────────────────────────
+//  用户问到现实中的时事、行情、赛果时，角色只能凭训练数据「编」，
+//  容易一本正经地胡说。开启后由闸门判断本轮是否为信息性提问，
+//  命中时为请求挂上智谱 web_search 工具：
+//    1. 闸门：时效词 / 现实信息词 + 提问语气，闲聊与扮演动作不触发
+//    2. 工具：由服务端检索，结果经 search_prompt 包装后交给模型
+//    3. 包装：结果明确标注为「参考资料」，只用于核对事实，
+//       回复仍保持角色口吻，不照念、不贴链接
+// ═══════════════════════════════════════════════════════════════════
+
+/// 时效性词汇：问的是「现在」的现实
+const RECENCY_KEYWORDS: &[&str] = &[
+    "最新", "最近", "今天", "今年", "昨天", "刚刚", "现在", "目前", "本周", "这周",
+    "latest", "today", "recent", "this week", "right now",
+];
+
+/// 现实信息类词汇：新闻、行情、赛事、发布等
+const REAL_WORLD_KEYWORDS: &[&str] = &[
+    "新闻", "消息", "发生了什么", "股价", "汇率", "比分", "赛果", "冠军", "发布", "上映",
+    "天气", "选举", "政策", "价格", "多少钱", "排名", "票房",
+    "news", "stock", "price", "score", "election", "release", "weather",
+];
+
+/// 提问语气标记
+const QUESTION_MARKERS: &[&str] = &[
+    "?", "？", "吗", "呢", "什么", "怎么", "多少", "哪", "谁", "是否", "有没有",
+    "what", "who", "when", "how", "which",
+];
+
+/// 过长的输入多半是剧情/创作，不走检索
+const MAX_QUERY_CHARS: usize = 120;
+
+/// 检索结果注入模板（{search_result} 由服务端替换为检索结果）
+const SEARCH_PROMPT_TEMPLATE: &str = "以下是联网检索到的参考资料，仅用于核对现实信息：\n\
+     【参考资料·开始】\n{search_result}\n【参考资料·结束】\n\
+     使用要求：\n\
+     - 只采信资料中明确写到的事实；资料没有覆盖的部分坦白说不确定，不要编造\n\
+     - 回复保持你的角色身份与说话方式，把事实自然地融进对话，不要照念资料\n\
+     - 不要输出链接、来源编号或「根据搜索结果」之类的字样";
+
+pub struct WebSearchGate;
+
+impl WebSearchGate {
+    /// 判断本轮输入是否为需要联网核实的现实信息提问
+    pub fn is_informational(content: &str) -> bool {
+        let trimmed = content.trim();
+        if trimmed.is_empty() || trimmed.chars().count() > MAX_QUERY_CHARS {
+            return false;
+        }
+        // 扮演动作（*动作* / （动作））不是在提问现实
+        if trimmed.starts_with('*') || trimmed.starts_with('（') || trimmed.starts_with('(') {
+            return false;
+        }
+
+        let lower = trimmed.to_lowercase();
+        let has = |keywords: &[&str]| keywords.iter().any(|k| lower.contains(k));
+        let asks = has(QUESTION_MARKERS);
+        let real_world = has(REAL_WORLD_KEYWORDS);
+        let recent = has(RECENCY_KEYWORDS);
+
+        // 现实信息词 + 提问；或时效词 + 现实信息词（「今天的新闻」这类祈使句）
+        real_world && (asks || recent)
+    }
+
+    /// 智谱 web_search 工具定义
+    pub fn build_tool() -> serde_json::Value {
+        serde_json::json!({
+            "type": "web_search",
+            "web_search": {
+                "enable": true,
+                "search_result": true,
+                "search_prompt": SEARCH_PROMPT_TEMPLATE,
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gate_detects_informational_questions() {
+        assert!(WebSearchGate::is_informational("今天有什么新闻？"));
+        assert!(WebSearchGate::is_informational("英伟达现在股价多少"));
+        assert!(WebSearchGate::is_informational("What's the latest news on the election?"));
+    }
+
+    #[test]
+    fn test_gate_ignores_chat_and_roleplay() {
+        assert!(!WebSearchGate::is_informational("你今天过得怎么样？"));
+        assert!(!WebSearchGate::is_informational("*轻轻拍了拍你的头* 最近新闻看多了吧"));
+        assert!(!WebSearchGate::is_informational("我好想你"));
+        assert!(!WebSearchGate::is_informational(""));
+    }
+
+    #[test]
+    fn test_build_tool_marks_reference_material() {
+        let tool = WebSearchGate::build_tool();
+        assert_eq!(tool["type"], "web_search");
+        let prompt = tool["web_search"]["search_prompt"].as_str().unwrap();
+        assert!(prompt.contains("{search_result}"));
+        assert!(prompt.contains("参考资料"));
+    }
+}
@@ -37,7 +37,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -21643838;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = -1263351065;
 
 // Section: executor
 
@@ -45,6 +45,39 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
+fn wire__crate__api__data_models__action_frequency_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "action_frequency_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::data_models::ActionFrequency::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
 fn wire__crate__api__chat_api__add_assistant_message_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -83,7 +116,7 @@ fn wire__crate__api__chat_api__add_assistant_message_impl(
         },
     )
 }
-fn wire__crate__api__chat_api__add_system_message_impl(
+fn wire__crate__api__chat_api__add_manual_fact_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -91,7 +124,7 @@ fn wire__crate__api__chat_api__add_system_message_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "add_system_message",
+            debug_name: "add_manual_fact",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -105,14 +138,19 @@ fn wire__crate__api__chat_api__add_system_message_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_conversation_id = <Option<String>>::sse_decode(&mut deserializer);
             let api_content = <String>::sse_decode(&mut deserializer);
+            let api_category =
+                <crate::api::knowledge_store::FactCategory>::sse_decode(&mut deserializer);
+            let api_confidence = <Option<f64>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::add_system_message(
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::add_manual_fact(
                         api_conversation_id,
                         api_content,
+                        api_category,
+                        api_confidence,
                     ))?;
                     Ok(output_ok)
                 })())
@@ -120,7 +158,7 @@ fn wire__crate__api__chat_api__add_system_message_impl(
         },
     )
 }
-fn wire__crate__api__data_models__app_settings_default_impl(
+fn wire__crate__api__chat_api__add_system_message_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -128,7 +166,7 @@ fn wire__crate__api__data_models__app_settings_default_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "app_settings_default",
+            debug_name: "add_system_message",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -142,18 +180,22 @@ fn wire__crate__api__data_models__app_settings_default_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_content = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::data_models::AppSettings::default())?;
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::add_system_message(
+                        api_conversation_id,
+                        api_content,
+                    ))?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__chat_api__create_conversation_impl(
+fn wire__crate__api__chat_api__add_user_authored_reply_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -161,7 +203,7 @@ fn wire__crate__api__chat_api__create_conversation_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "create_conversation",
+            debug_name: "add_user_authored_reply",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -175,18 +217,23 @@ fn wire__crate__api__chat_api__create_conversation_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_content = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
                     let output_ok =
-                        Result::<_, ()>::Ok(crate::api::chat_api::create_conversation())?;
+                        Result::<_, ()>::Ok(crate::api::chat_api::add_user_authored_reply(
+                            api_conversation_id,
+                            api_content,
+                        ))?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__chat_api__delete_conversation_impl(
+fn wire__crate__api__data_models__ambient_context_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -194,7 +241,7 @@ fn wire__crate__api__chat_api__delete_conversation_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "delete_conversation",
+            debug_name: "ambient_context_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -208,19 +255,18 @@ fn wire__crate__api__chat_api__delete_conversation_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
                     let output_ok =
-                        Result::<_, ()>::Ok(crate::api::chat_api::delete_conversation(api_id))?;
+                        Result::<_, ()>::Ok(crate::api::data_models::AmbientContext::default())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__chat_api__delete_message_impl(
+fn wire__crate__api__chat_api__analyze_conversation_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -228,7 +274,7 @@ fn wire__crate__api__chat_api__delete_message_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "delete_message",
+            debug_name: "analyze_conversation",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -243,21 +289,19 @@ fn wire__crate__api__chat_api__delete_message_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_conversation_id = <String>::sse_decode(&mut deserializer);
-            let api_message_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::delete_message(
-                        api_conversation_id,
-                        api_message_id,
-                    ))?;
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::chat_api::analyze_conversation(api_conversation_id),
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__chat_api__detect_message_type_impl(
+fn wire__crate__api__data_models__app_settings_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -265,7 +309,7 @@ fn wire__crate__api__chat_api__detect_message_type_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "detect_message_type",
+            debug_name: "app_settings_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -279,28 +323,26 @@ fn wire__crate__api__chat_api__detect_message_type_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_content = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::chat_api::detect_message_type(api_content),
-                    )?;
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::data_models::AppSettings::default())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__data_models__dialogue_style_default_impl(
+fn wire__crate__api__chat_api__approve_memory_summary_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "dialogue_style_default",
+            debug_name: "approve_memory_summary",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -314,18 +356,26 @@ fn wire__crate__api__data_models__dialogue_style_default_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_summary_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::data_models::DialogueStyle::default())?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::chat_api::approve_memory_summary(
+                            api_conversation_id,
+                            api_summary_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__chat_api__edit_message_impl(
+fn wire__crate__api__chat_api__audit_all_memory_fidelity_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -333,7 +383,7 @@ fn wire__crate__api__chat_api__edit_message_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "edit_message",
+            debug_name: "audit_all_memory_fidelity",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -347,24 +397,18 @@ fn wire__crate__api__chat_api__edit_message_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_conversation_id = <String>::sse_decode(&mut deserializer);
-            let api_message_id = <String>::sse_decode(&mut deserializer);
-            let api_new_content = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::edit_message(
-                        api_conversation_id,
-                        api_message_id,
-                        api_new_content,
-                    ))?;
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::audit_all_memory_fidelity())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__chat_api__get_available_models_impl(
+fn wire__crate__api__chat_api__audit_memory_fidelity_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -372,7 +416,7 @@ fn wire__crate__api__chat_api__get_available_models_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_available_models",
+            debug_name: "audit_memory_fidelity",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -386,18 +430,20 @@ fn wire__crate__api__chat_api__get_available_models_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::chat_api::get_available_models())?;
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::chat_api::audit_memory_fidelity(api_conversation_id),
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__chat_api__get_conversation_impl(
+fn wire__crate__api__data_models__backoff_strategy_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -405,7 +451,7 @@ fn wire__crate__api__chat_api__get_conversation_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_conversation",
+            debug_name: "backoff_strategy_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -419,19 +465,18 @@ fn wire__crate__api__chat_api__get_conversation_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
                     let output_ok =
-                        Result::<_, ()>::Ok(crate::api::chat_api::get_conversation(api_id))?;
+                        Result::<_, ()>::Ok(crate::api::data_models::BackoffStrategy::default())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__chat_api__get_conversation_list_impl(
+fn wire__crate__api__data_models__backup_retention_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -439,7 +484,7 @@ fn wire__crate__api__chat_api__get_conversation_list_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_conversation_list",
+            debug_name: "backup_retention_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -457,14 +502,14 @@ fn wire__crate__api__chat_api__get_conversation_list_impl(
             move |context| {
                 transform_result_sse::<_, ()>((move || {
                     let output_ok =
-                        Result::<_, ()>::Ok(crate::api::chat_api::get_conversation_list())?;
+                        Result::<_, ()>::Ok(crate::api::data_models::BackupRetention::default())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__chat_api__get_settings_impl(
+fn wire__crate__api__data_models__backup_scope_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -472,7 +517,7 @@ fn wire__crate__api__chat_api__get_settings_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_settings",
+            debug_name: "backup_scope_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -489,14 +534,15 @@ fn wire__crate__api__chat_api__get_settings_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::get_settings())?;
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::data_models::BackupScope::default())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__chat_api__get_turn_count_impl(
+fn wire__crate__api__data_models__blocking_stats_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -504,7 +550,7 @@ fn wire__crate__api__chat_api__get_turn_count_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_turn_count",
+            debug_name: "blocking_stats_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -518,20 +564,18 @@ fn wire__crate__api__chat_api__get_turn_count_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_conversation_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::get_turn_count(
-                        api_conversation_id,
-                    ))?;
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::data_models::BlockingStats::default())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__chat_api__init_app_impl(
+fn wire__crate__api__chat_api__clear_logs_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -539,7 +583,7 @@ fn wire__crate__api__chat_api__init_app_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
+            debug_name: "clear_logs",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -553,12 +597,11 @@ fn wire__crate__api__chat_api__init_app_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_data_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
                     let output_ok = Result::<_, ()>::Ok({
-                        crate::api::chat_api::init_app(api_data_path);
+                        crate::api::chat_api::clear_logs();
                     })?;
                     Ok(output_ok)
                 })())
@@ -566,7 +609,7 @@ fn wire__crate__api__chat_api__init_app_impl(
         },
     )
 }
-fn wire__crate__api__data_models__message_type_default_impl(
+fn wire__crate__api__chat_api__clear_metrics_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -574,7 +617,7 @@ fn wire__crate__api__data_models__message_type_default_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "message_type_default",
+            debug_name: "clear_metrics",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -591,15 +634,16 @@ fn wire__crate__api__data_models__message_type_default_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::data_models::MessageType::default())?;
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::chat_api::clear_metrics();
+                    })?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__chat_api__regenerate_response_impl(
+fn wire__crate__api__chat_api__close_conversation_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -607,7 +651,7 @@ fn wire__crate__api__chat_api__regenerate_response_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "regenerate_response",
+            debug_name: "close_conversation",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -623,24 +667,15 @@ fn wire__crate__api__chat_api__regenerate_response_impl(
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_conversation_id = <String>::sse_decode(&mut deserializer);
             let api_model = <String>::sse_decode(&mut deserializer);
-            let api_enable_thinking = <bool>::sse_decode(&mut deserializer);
-            let api_sink = <StreamSink<
-                crate::api::data_models::ChatStreamEvent,
-                flutter_rust_bridge::for_generated::SseCodec,
-            >>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, ()>(
+                transform_result_sse::<_, String>(
                     (move || async move {
-                        let output_ok = Result::<_, ()>::Ok({
-                            crate::api::chat_api::regenerate_response(
-                                api_conversation_id,
-                                api_model,
-                                api_enable_thinking,
-                                api_sink,
-                            )
-                            .await;
-                        })?;
+                        let output_ok = crate::api::chat_api::close_conversation(
+                            api_conversation_id,
+                            api_model,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -649,7 +684,7 @@ fn wire__crate__api__chat_api__regenerate_response_impl(
         },
     )
 }
-fn wire__crate__api__chat_api__restart_story_impl(
+fn wire__crate__api__chat_api__close_sandbox_conversation_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -657,7 +692,7 @@ fn wire__crate__api__chat_api__restart_story_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "restart_story",
+            debug_name: "close_sandbox_conversation",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -671,20 +706,20 @@ fn wire__crate__api__chat_api__restart_story_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::restart_story(
-                        api_conversation_id,
-                    ))?;
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::chat_api::close_sandbox_conversation(api_id),
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__chat_api__rollback_to_message_impl(
+fn wire__crate__api__data_models__content_intensity_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -692,7 +727,7 @@ fn wire__crate__api__chat_api__rollback_to_message_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "rollback_to_message",
+            debug_name: "content_intensity_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -706,23 +741,18 @@ fn wire__crate__api__chat_api__rollback_to_message_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_conversation_id = <String>::sse_decode(&mut deserializer);
-            let api_message_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
                     let output_ok =
-                        Result::<_, ()>::Ok(crate::api::chat_api::rollback_to_message(
-                            api_conversation_id,
-                            api_message_id,
-                        ))?;
+                        Result::<_, ()>::Ok(crate::api::data_models::ContentIntensity::default())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__chat_api__save_settings_impl(
+fn wire__crate__api__data_models__context_layers_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -730,7 +760,7 @@ fn wire__crate__api__chat_api__save_settings_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "save_settings",
+            debug_name: "context_layers_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -744,20 +774,18 @@ fn wire__crate__api__chat_api__save_settings_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_settings =
-                <crate::api::data_models::AppSettings>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
                     let output_ok =
-                        Result::<_, ()>::Ok(crate::api::chat_api::save_settings(api_settings))?;
+                        Result::<_, ()>::Ok(crate::api::data_models::ContextLayers::default())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__chat_api__search_memories_impl(
+fn wire__crate__api__data_models__conversation_filter_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -765,7 +793,7 @@ fn wire__crate__api__chat_api__search_memories_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "search_memories",
+            debug_name: "conversation_filter_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -779,32 +807,27 @@ fn wire__crate__api__chat_api__search_memories_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_conversation_id = <String>::sse_decode(&mut deserializer);
-            let api_query = <String>::sse_decode(&mut deserializer);
-            let api_top_k = <usize>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::search_memories(
-                        api_conversation_id,
-                        api_query,
-                        api_top_k,
-                    ))?;
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::data_models::ConversationFilter::default(),
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__chat_api__send_message_impl(
+fn wire__crate__api__data_models__conversation_mode_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "send_message",
+            debug_name: "conversation_mode_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -818,37 +841,18 @@ fn wire__crate__api__chat_api__send_message_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_conversation_id = <String>::sse_decode(&mut deserializer);
-            let api_content = <String>::sse_decode(&mut deserializer);
-            let api_model = <String>::sse_decode(&mut deserializer);
-            let api_enable_thinking = <bool>::sse_decode(&mut deserializer);
-            let api_sink = <StreamSink<
-                crate::api::data_models::ChatStreamEvent,
-                flutter_rust_bridge::for_generated::SseCodec,
-            >>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, ()>(
-                    (move || async move {
-                        let output_ok = Result::<_, ()>::Ok({
-                            crate::api::chat_api::send_message(
-                                api_conversation_id,
-                                api_content,
-                                api_model,
-                                api_enable_thinking,
-                                api_sink,
-                            )
-                            .await;
-                        })?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::data_models::ConversationMode::default())?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__chat_api__set_api_key_impl(
+fn wire__crate__api__data_models__conversation_organization_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -856,7 +860,7 @@ fn wire__crate__api__chat_api__set_api_key_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_api_key",
+            debug_name: "conversation_organization_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -870,18 +874,19 @@ fn wire__crate__api__chat_api__set_api_key_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_api_key = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
-                transform_result_sse::<_, String>((move || {
-                    let output_ok = crate::api::chat_api::set_api_key(api_api_key)?;
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::data_models::ConversationOrganization::default(),
+                    )?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__chat_api__set_dialogue_style_impl(
+fn wire__crate__api__chat_api__create_backup_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -889,7 +894,7 @@ fn wire__crate__api__chat_api__set_dialogue_style_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "set_dialogue_style",
+            debug_name: "create_backup",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -903,22 +908,19 @@ fn wire__crate__api__chat_api__set_dialogue_style_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_conversation_id = <String>::sse_decode(&mut deserializer);
-            let api_style = <crate::api::data_models::DialogueStyle>::sse_decode(&mut deserializer);
+            let api_archive_path = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::set_dialogue_style(
-                        api_conversation_id,
-                        api_style,
-                    ))?;
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::create_backup(api_archive_path))?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__chat_api__should_summarize_memory_impl(
+fn wire__crate__api__chat_api__create_branch_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -926,7 +928,7 @@ fn wire__crate__api__chat_api__should_summarize_memory_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "should_summarize_memory",
+            debug_name: "create_branch",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -941,27 +943,31 @@ fn wire__crate__api__chat_api__should_summarize_memory_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_from_message_id = <String>::sse_decode(&mut deserializer);
+            let api_name = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok(
-                        crate::api::chat_api::should_summarize_memory(api_conversation_id),
-                    )?;
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::create_branch(
+                        api_conversation_id,
+                        api_from_message_id,
+                        api_name,
+                    ))?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__chat_api__trigger_memory_summarize_impl(
+fn wire__crate__api__chat_api__create_conversation_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "trigger_memory_summarize",
+            debug_name: "create_conversation",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -975,31 +981,53 @@ fn wire__crate__api__chat_api__trigger_memory_summarize_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_conversation_id = <String>::sse_decode(&mut deserializer);
-            let api_sink = <StreamSink<
-                crate::api::data_models::ChatStreamEvent,
-                flutter_rust_bridge::for_generated::SseCodec,
-            >>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, ()>(
-                    (move || async move {
-                        let output_ok = Result::<_, ()>::Ok({
-                            crate::api::chat_api::trigger_memory_summarize(
-                                api_conversation_id,
-                                api_sink,
-                            )
-                            .await;
-                        })?;
-                        Ok(output_ok)
-                    })()
-                    .await,
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::create_conversation())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__create_sandbox_conversation_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "create_sandbox_conversation",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
                 )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_card = <crate::api::data_models::CharacterCard>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::chat_api::create_sandbox_conversation(api_card),
+                    )?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__chat_api__validate_api_key_impl(
+fn wire__crate__api__chat_api__delete_conversation_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -1007,7 +1035,7 @@ fn wire__crate__api__chat_api__validate_api_key_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "validate_api_key",
+            debug_name: "delete_conversation",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -1021,1030 +1049,16571 @@ fn wire__crate__api__chat_api__validate_api_key_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_api_key = <String>::sse_decode(&mut deserializer);
+            let api_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
                     let output_ok =
-                        Result::<_, ()>::Ok(crate::api::chat_api::validate_api_key(api_api_key))?;
+                        Result::<_, ()>::Ok(crate::api::chat_api::delete_conversation(api_id))?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-
-// Section: dart2rust
-
-impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
-    }
-}
-
-impl SseDecode
-    for StreamSink<
-        crate::api::data_models::ChatStreamEvent,
-        flutter_rust_bridge::for_generated::SseCodec,
-    >
-{
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <String>::sse_decode(deserializer);
-        return StreamSink::deserialize(inner);
-    }
-}
-
-impl SseDecode for String {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <Vec<u8>>::sse_decode(deserializer);
-        return String::from_utf8(inner).unwrap();
-    }
-}
-
-impl SseDecode for crate::api::data_models::AppSettings {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_apiKey = <Option<String>>::sse_decode(deserializer);
-        let mut var_defaultModel = <String>::sse_decode(deserializer);
-        let mut var_enableThinkingByDefault = <bool>::sse_decode(deserializer);
-        let mut var_chatModel = <String>::sse_decode(deserializer);
-        let mut var_thinkingModel = <String>::sse_decode(deserializer);
-        return crate::api::data_models::AppSettings {
-            api_key: var_apiKey,
-            default_model: var_defaultModel,
-            enable_thinking_by_default: var_enableThinkingByDefault,
-            chat_model: var_chatModel,
-            thinking_model: var_thinkingModel,
-        };
-    }
-}
-
-impl SseDecode for bool {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap() != 0
-    }
-}
-
-impl SseDecode for crate::api::data_models::ChatStreamEvent {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut tag_ = <i32>::sse_decode(deserializer);
-        match tag_ {
-            0 => {
-                let mut var_field0 = <String>::sse_decode(deserializer);
-                return crate::api::data_models::ChatStreamEvent::ContentDelta(var_field0);
-            }
-            1 => {
-                let mut var_field0 = <String>::sse_decode(deserializer);
-                return crate::api::data_models::ChatStreamEvent::ThinkingDelta(var_field0);
-            }
-            2 => {
-                return crate::api::data_models::ChatStreamEvent::Done;
+fn wire__crate__api__chat_api__delete_fact_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "delete_fact",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_fact_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::delete_fact(
+                        api_conversation_id,
+                        api_fact_id,
+                    ))?;
+                    Ok(output_ok)
+                })())
             }
-            3 => {
-                let mut var_field0 = <String>::sse_decode(deserializer);
-                return crate::api::data_models::ChatStreamEvent::Error(var_field0);
+        },
+    )
+}
+fn wire__crate__api__chat_api__delete_memory_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "delete_memory",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_summary_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::delete_memory(
+                        api_conversation_id,
+                        api_summary_id,
+                    ))?;
+                    Ok(output_ok)
+                })())
             }
-            _ => {
-                unimplemented!("");
+        },
+    )
+}
+fn wire__crate__api__chat_api__delete_message_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "delete_message",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::delete_message(
+                        api_conversation_id,
+                        api_message_id,
+                    ))?;
+                    Ok(output_ok)
+                })())
             }
-        }
-    }
+        },
+    )
 }
-
-impl SseDecode for crate::api::data_models::Conversation {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <String>::sse_decode(deserializer);
-        let mut var_title = <String>::sse_decode(deserializer);
-        let mut var_messages = <Vec<crate::api::data_models::Message>>::sse_decode(deserializer);
-        let mut var_model = <String>::sse_decode(deserializer);
-        let mut var_createdAt = <i64>::sse_decode(deserializer);
-        let mut var_updatedAt = <i64>::sse_decode(deserializer);
-        let mut var_dialogueStyle =
-            <crate::api::data_models::DialogueStyle>::sse_decode(deserializer);
-        let mut var_turnCount = <u32>::sse_decode(deserializer);
-        let mut var_memorySummaries =
-            <Vec<crate::api::data_models::MemorySummary>>::sse_decode(deserializer);
-        return crate::api::data_models::Conversation {
-            id: var_id,
-            title: var_title,
+fn wire__crate__api__chat_api__delete_persona_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "delete_persona",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_persona_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::chat_api::delete_persona(api_persona_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__detect_message_type_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "detect_message_type",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_content = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::chat_api::detect_message_type(api_content),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__detect_message_type_in_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "detect_message_type_in",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_content = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::detect_message_type_in(
+                            api_conversation_id,
+                            api_content,
+                        ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__data_models__device_conditions_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "device_conditions_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::data_models::DeviceConditions::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__data_models__dialogue_style_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dialogue_style_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::data_models::DialogueStyle::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__disable_encryption_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "disable_encryption",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_secret =
+                <crate::api::data_models::EncryptionSecret>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::chat_api::disable_encryption(api_secret)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__discard_memory_summary_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "discard_memory_summary",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::chat_api::discard_memory_summary(api_conversation_id),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__discard_outbox_entry_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "discard_outbox_entry",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_entry_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::chat_api::discard_outbox_entry(api_entry_id),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__dissolve_group_chat_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "dissolve_group_chat",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::chat_api::dissolve_group_chat(api_conversation_id),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__edit_memory_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "edit_memory",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_summary_id = <String>::sse_decode(&mut deserializer);
+            let api_summary = <String>::sse_decode(&mut deserializer);
+            let api_core_facts = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::edit_memory(
+                        api_conversation_id,
+                        api_summary_id,
+                        api_summary,
+                        api_core_facts,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__edit_message_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "edit_message",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
+            let api_new_content = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::edit_message(
+                        api_conversation_id,
+                        api_message_id,
+                        api_new_content,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__data_models__emoji_usage_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "emoji_usage_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::data_models::EmojiUsage::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__enable_encryption_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "enable_encryption",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_secret =
+                <crate::api::data_models::EncryptionSecret>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::chat_api::enable_encryption(api_secret)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__explain_memory_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "explain_memory",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_summary_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::explain_memory(
+                        api_conversation_id,
+                        api_summary_id,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__explain_relevance_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "explain_relevance",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_fact = <String>::sse_decode(&mut deserializer);
+            let api_user_content = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::explain_relevance(
+                        api_conversation_id,
+                        api_fact,
+                        api_user_content,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__export_all_data_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "export_all_data",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_target_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::chat_api::export_all_data(api_target_path).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__export_knowledge_graph_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "export_knowledge_graph",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_format =
+                <crate::api::data_models::GraphExportFormat>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::export_knowledge_graph(
+                            api_conversation_id,
+                            api_format,
+                        ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__export_logs_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "export_logs",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_limit = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::export_logs(api_limit))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__find_graph_path_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "find_graph_path",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_from = <String>::sse_decode(&mut deserializer);
+            let api_to = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::find_graph_path(
+                        api_conversation_id,
+                        api_from,
+                        api_to,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__data_models__formality_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "formality_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::data_models::Formality::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__generate_daily_digest_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "generate_daily_digest",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::chat_api::generate_daily_digest().await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__data_models__generation_metadata_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "generation_metadata_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::data_models::GenerationMetadata::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__get_available_models_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_available_models",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::get_available_models())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__get_blocked_topics_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_blocked_topics",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::get_blocked_topics(
+                        api_conversation_id,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__get_blocking_stats_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_blocking_stats",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::get_blocking_stats())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__get_character_mood_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_character_mood",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::get_character_mood(
+                        api_conversation_id,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__get_character_relations_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_character_relations",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::chat_api::get_character_relations(api_conversation_id),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__get_character_voices_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_character_voices",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::get_character_voices())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__get_check_in_schedule_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_check_in_schedule",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::chat_api::get_check_in_schedule(api_conversation_id),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__get_conversation_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_conversation",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::get_conversation(api_id))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__get_conversation_list_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_conversation_list",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::get_conversation_list())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__get_degradation_history_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_degradation_history",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::chat_api::get_degradation_history(api_conversation_id),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__get_emotion_lexicon_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_emotion_lexicon",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::get_emotion_lexicon())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__get_encryption_status_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_encryption_status",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::get_encryption_status())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__get_fact_conflicts_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_fact_conflicts",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::get_fact_conflicts(
+                        api_conversation_id,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__get_filtered_conversation_list_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_filtered_conversation_list",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_filter =
+                <crate::api::data_models::ConversationFilter>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::chat_api::get_filtered_conversation_list(api_filter),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__get_generation_metadata_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_generation_metadata",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::get_generation_metadata(
+                            api_conversation_id,
+                            api_message_id,
+                        ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__get_group_chat_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_group_chat",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::get_group_chat(
+                        api_conversation_id,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__get_job_scheduler_stats_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_job_scheduler_stats",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::get_job_scheduler_stats())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__get_knowledge_binding_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_knowledge_binding",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::chat_api::get_knowledge_binding(api_conversation_id),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__get_knowledge_graph_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_knowledge_graph",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::chat_api::get_knowledge_graph(api_conversation_id),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__get_lore_entries_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_lore_entries",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::get_lore_entries(
+                        api_conversation_id,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__get_memory_fidelity_history_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_memory_fidelity_history",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::chat_api::get_memory_fidelity_history(api_conversation_id),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__get_metrics_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_metrics",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::get_metrics())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__get_outbox_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_outbox",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::get_outbox())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__get_pending_memory_summary_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_pending_memory_summary",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::chat_api::get_pending_memory_summary(api_conversation_id),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__get_prompt_template_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_prompt_template",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::chat_api::get_prompt_template(api_name)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__get_retention_policy_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_retention_policy",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::get_retention_policy())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__get_safety_policy_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_safety_policy",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::get_safety_policy())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__get_saydo_rules_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_saydo_rules",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::get_saydo_rules(
+                        api_conversation_id,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__get_settings_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_settings",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::get_settings())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__get_shadow_eval_records_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_shadow_eval_records",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::chat_api::get_shadow_eval_records(api_conversation_id),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__get_shadow_eval_summary_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_shadow_eval_summary",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::chat_api::get_shadow_eval_summary(api_conversation_id),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__get_story_timeline_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_story_timeline",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::get_story_timeline(
+                        api_conversation_id,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__get_summarization_config_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_summarization_config",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::chat_api::get_summarization_config(api_conversation_id),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__get_sync_settings_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_sync_settings",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::get_sync_settings())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__get_turn_count_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "get_turn_count",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::get_turn_count(
+                        api_conversation_id,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__data_models__housekeeping_report_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "housekeeping_report_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::data_models::HousekeepingReport::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__illustrate_scene_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "illustrate_scene",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_hint = <Option<String>>::sse_decode(&mut deserializer);
+            let api_model = <String>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::data_models::ChatStreamEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok({
+                            crate::api::chat_api::illustrate_scene(
+                                api_conversation_id,
+                                api_hint,
+                                api_model,
+                                api_sink,
+                            )
+                            .await;
+                        })?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__import_all_data_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "import_all_data",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_archive_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::chat_api::import_all_data(api_archive_path).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__data_models__index_scope_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "index_scope_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::data_models::IndexScope::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__init_app_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_app",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_data_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::chat_api::init_app(api_data_path);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__invalidate_distilled_state_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "invalidate_distilled_state",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::chat_api::invalidate_distilled_state(api_conversation_id),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__is_streaming_degraded_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_streaming_degraded",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::is_streaming_degraded())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__data_models__job_scheduler_stats_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "job_scheduler_stats_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::data_models::JobSchedulerStats::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__data_models__knowledge_binding_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "knowledge_binding_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::data_models::KnowledgeBinding::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__data_models__knowledge_graph_view_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "knowledge_graph_view_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::data_models::KnowledgeGraphView::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__list_background_tasks_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_background_tasks",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::list_background_tasks())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__list_backups_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_backups",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_archive_path = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::list_backups(api_archive_path))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__list_branches_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_branches",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::list_branches(
+                        api_conversation_id,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__list_conversation_folders_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_conversation_folders",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::list_conversation_folders())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__list_conversation_tags_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_conversation_tags",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::list_conversation_tags())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__list_facts_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_facts",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::list_facts(api_conversation_id))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__list_layer_facts_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_layer_facts",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_layer =
+                <crate::api::data_models::KnowledgeLayer>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::list_layer_facts(
+                        api_conversation_id,
+                        api_layer,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__list_memories_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_memories",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::list_memories(
+                        api_conversation_id,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__list_personas_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_personas",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::list_personas())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__list_prompt_templates_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_prompt_templates",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::list_prompt_templates())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__list_starred_messages_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_starred_messages",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::chat_api::list_starred_messages(api_conversation_id),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__load_cold_storage_messages_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "load_cold_storage_messages",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::chat_api::load_cold_storage_messages(api_conversation_id)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__data_models__message_type_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "message_type_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::data_models::MessageType::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__data_models__metrics_snapshot_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "metrics_snapshot_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::data_models::MetricsSnapshot::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__migrate_data_layout_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "migrate_data_layout",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_target_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::chat_api::migrate_data_layout(api_target_path),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__data_models__narration_perspective_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "narration_perspective_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::data_models::NarrationPerspective::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__data_models__outbox_status_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "outbox_status_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::data_models::OutboxStatus::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__pin_memory_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "pin_memory",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_summary_id = <String>::sse_decode(&mut deserializer);
+            let api_fact = <Option<String>>::sse_decode(&mut deserializer);
+            let api_pinned = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::pin_memory(
+                        api_conversation_id,
+                        api_summary_id,
+                        api_fact,
+                        api_pinned,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__preflight_message_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "preflight_message",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_draft = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::preflight_message(
+                        api_conversation_id,
+                        api_draft,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__prepare_reengagement_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "prepare_reengagement",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::chat_api::prepare_reengagement(api_conversation_id).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__preview_memory_summary_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "preview_memory_summary",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::data_models::ChatStreamEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok({
+                            crate::api::chat_api::preview_memory_summary(
+                                api_conversation_id,
+                                api_sink,
+                            )
+                            .await;
+                        })?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__promote_fact_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "promote_fact",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_fact_id = <String>::sse_decode(&mut deserializer);
+            let api_layer =
+                <crate::api::data_models::KnowledgeLayer>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::chat_api::promote_fact(
+                        api_conversation_id,
+                        api_fact_id,
+                        api_layer,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__data_models__provider_kind_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "provider_kind_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::data_models::ProviderKind::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__prune_backups_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "prune_backups",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_archive_path = <Option<String>>::sse_decode(&mut deserializer);
+            let api_retention =
+                <crate::api::data_models::BackupRetention>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::prune_backups(
+                        api_archive_path,
+                        api_retention,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__query_character_relation_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "query_character_relation",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_a = <String>::sse_decode(&mut deserializer);
+            let api_b = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::query_character_relation(
+                            api_conversation_id,
+                            api_a,
+                            api_b,
+                        ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__query_graph_neighbors_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "query_graph_neighbors",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_entity = <String>::sse_decode(&mut deserializer);
+            let api_depth = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::query_graph_neighbors(
+                            api_conversation_id,
+                            api_entity,
+                            api_depth,
+                        ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__refresh_distilled_state_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "refresh_distilled_state",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::chat_api::refresh_distilled_state(api_conversation_id)
+                                .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__regenerate_from_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "regenerate_from",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
+            let api_as_branch = <bool>::sse_decode(&mut deserializer);
+            let api_model = <String>::sse_decode(&mut deserializer);
+            let api_enable_thinking = <bool>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::data_models::ChatStreamEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok({
+                            crate::api::chat_api::regenerate_from(
+                                api_conversation_id,
+                                api_message_id,
+                                api_as_branch,
+                                api_model,
+                                api_enable_thinking,
+                                api_sink,
+                            )
+                            .await;
+                        })?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__regenerate_response_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "regenerate_response",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_model = <String>::sse_decode(&mut deserializer);
+            let api_enable_thinking = <bool>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::data_models::ChatStreamEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok({
+                            crate::api::chat_api::regenerate_response(
+                                api_conversation_id,
+                                api_model,
+                                api_enable_thinking,
+                                api_sink,
+                            )
+                            .await;
+                        })?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__reindex_all_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reindex_all",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_scope = <crate::api::data_models::IndexScope>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::data_models::ReindexProgress,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok({
+                            crate::api::chat_api::reindex_all(api_scope, api_sink).await;
+                        })?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__reload_prompt_templates_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reload_prompt_templates",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::reload_prompt_templates())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__remove_blocked_topic_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_blocked_topic",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_topic_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::remove_blocked_topic(
+                            api_conversation_id,
+                            api_topic_id,
+                        ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__remove_lore_entry_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "remove_lore_entry",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_entry_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::remove_lore_entry(
+                        api_conversation_id,
+                        api_entry_id,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__repair_integrity_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "repair_integrity",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::repair_integrity(
+                        api_conversation_id,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__data_models__reply_length_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reply_length_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::data_models::ReplyLength::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__data_models__reply_signals_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reply_signals_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::data_models::ReplySignals::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__reset_prompt_template_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reset_prompt_template",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::chat_api::reset_prompt_template(api_name)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__data_models__response_style_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "response_style_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::data_models::ResponseStyle::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__restart_story_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "restart_story",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::restart_story(
+                        api_conversation_id,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__restore_backup_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "restore_backup",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_archive_path = <Option<String>>::sse_decode(&mut deserializer);
+            let api_snapshot_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_scope = <crate::api::data_models::BackupScope>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::chat_api::restore_backup(
+                        api_archive_path,
+                        api_snapshot_id,
+                        api_scope,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__data_models__retention_policy_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "retention_policy_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::data_models::RetentionPolicy::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__data_models__retry_policy_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "retry_policy_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::data_models::RetryPolicy::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__rewrite_reply_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "rewrite_reply",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
+            let api_new_content = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::rewrite_reply(
+                        api_conversation_id,
+                        api_message_id,
+                        api_new_content,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__rollback_to_message_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "rollback_to_message",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::rollback_to_message(
+                            api_conversation_id,
+                            api_message_id,
+                        ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__run_deferred_jobs_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "run_deferred_jobs",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok =
+                            Result::<_, ()>::Ok(crate::api::chat_api::run_deferred_jobs().await)?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__run_housekeeping_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "run_housekeeping",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::chat_api::run_housekeeping().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__run_thinking_retention_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "run_thinking_retention",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::run_thinking_retention())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__data_models__safety_policy_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "safety_policy_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::data_models::SafetyPolicy::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__save_emotion_lexicon_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "save_emotion_lexicon",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_entries =
+                <Vec<crate::api::data_models::LexiconEntry>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::chat_api::save_emotion_lexicon(api_entries)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__save_lore_entry_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "save_lore_entry",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_entry = <crate::api::data_models::LoreEntry>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::save_lore_entry(
+                        api_conversation_id,
+                        api_entry,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__save_persona_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "save_persona",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_persona = <crate::api::data_models::UserPersona>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::chat_api::save_persona(api_persona)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__save_prompt_template_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "save_prompt_template",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_content = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::chat_api::save_prompt_template(api_name, api_content)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__save_settings_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "save_settings",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_settings =
+                <crate::api::data_models::AppSettings>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::save_settings(api_settings))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__data_models__say_do_rule_set_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "say_do_rule_set_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::data_models::SayDoRuleSet::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__search_conversations_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_conversations",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::search_conversations(api_query))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__search_memories_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "search_memories",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_query = <String>::sse_decode(&mut deserializer);
+            let api_top_k = <usize>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::search_memories(
+                        api_conversation_id,
+                        api_query,
+                        api_top_k,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__select_alternative_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "select_alternative",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
+            let api_index = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::select_alternative(
+                        api_conversation_id,
+                        api_message_id,
+                        api_index,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__send_message_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_message",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_content = <String>::sse_decode(&mut deserializer);
+            let api_model = <String>::sse_decode(&mut deserializer);
+            let api_enable_thinking = <bool>::sse_decode(&mut deserializer);
+            let api_ambient =
+                <Option<crate::api::data_models::AmbientContext>>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::data_models::ChatStreamEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok({
+                            crate::api::chat_api::send_message(
+                                api_conversation_id,
+                                api_content,
+                                api_model,
+                                api_enable_thinking,
+                                api_ambient,
+                                api_sink,
+                            )
+                            .await;
+                        })?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__send_message_with_images_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_message_with_images",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_content = <String>::sse_decode(&mut deserializer);
+            let api_images =
+                <Vec<crate::api::data_models::MessageAttachment>>::sse_decode(&mut deserializer);
+            let api_model = <String>::sse_decode(&mut deserializer);
+            let api_enable_thinking = <bool>::sse_decode(&mut deserializer);
+            let api_ambient =
+                <Option<crate::api::data_models::AmbientContext>>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::data_models::ChatStreamEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok({
+                            crate::api::chat_api::send_message_with_images(
+                                api_conversation_id,
+                                api_content,
+                                api_images,
+                                api_model,
+                                api_enable_thinking,
+                                api_ambient,
+                                api_sink,
+                            )
+                            .await;
+                        })?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__set_api_key_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_api_key",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_api_key = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::chat_api::set_api_key(api_api_key)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__set_character_voice_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_character_voice",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_character_key = <String>::sse_decode(&mut deserializer);
+            let api_voice = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::chat_api::set_character_voice(api_character_key, api_voice),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__set_chat_provider_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_chat_provider",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_provider =
+                <crate::api::data_models::ProviderKind>::sse_decode(&mut deserializer);
+            let api_base_url = <Option<String>>::sse_decode(&mut deserializer);
+            let api_model = <Option<String>>::sse_decode(&mut deserializer);
+            let api_api_key = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::chat_api::set_chat_provider(
+                        api_provider,
+                        api_base_url,
+                        api_model,
+                        api_api_key,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__set_check_in_schedule_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_check_in_schedule",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_schedule =
+                <crate::api::data_models::CheckInSchedule>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::chat_api::set_check_in_schedule(api_schedule),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__set_context_layers_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_context_layers",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_layers =
+                <crate::api::data_models::ContextLayers>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::set_context_layers(
+                        api_conversation_id,
+                        api_layers,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__set_conversation_archived_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_conversation_archived",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_archived = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::set_conversation_archived(
+                            api_conversation_id,
+                            api_archived,
+                        ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__set_conversation_mode_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_conversation_mode",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_mode =
+                <crate::api::data_models::ConversationMode>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::chat_api::set_conversation_mode(api_conversation_id, api_mode),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__set_conversation_organization_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_conversation_organization",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_organization =
+                <crate::api::data_models::ConversationOrganization>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::set_conversation_organization(
+                            api_conversation_id,
+                            api_organization,
+                        ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__set_conversation_persona_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_conversation_persona",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_persona_id = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::set_conversation_persona(
+                            api_conversation_id,
+                            api_persona_id,
+                        ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__set_conversation_pinned_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_conversation_pinned",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_pinned = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::set_conversation_pinned(
+                            api_conversation_id,
+                            api_pinned,
+                        ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__set_device_conditions_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_device_conditions",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conditions =
+                <crate::api::data_models::DeviceConditions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::chat_api::set_device_conditions(api_conditions);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__set_dialogue_style_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_dialogue_style",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_style = <crate::api::data_models::DialogueStyle>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::set_dialogue_style(
+                        api_conversation_id,
+                        api_style,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__set_fast_draft_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_fast_draft",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::chat_api::set_fast_draft(api_enabled)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__set_group_chat_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_group_chat",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_characters =
+                <Vec<crate::api::data_models::GroupCharacter>>::sse_decode(&mut deserializer);
+            let api_turn_policy =
+                <crate::api::data_models::TurnPolicy>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::set_group_chat(
+                        api_conversation_id,
+                        api_characters,
+                        api_turn_policy,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__set_knowledge_binding_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_knowledge_binding",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_binding =
+                <crate::api::data_models::KnowledgeBinding>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::chat_api::set_knowledge_binding(
+                        api_conversation_id,
+                        api_binding,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__set_memory_fact_tier_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_memory_fact_tier",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_summary_id = <String>::sse_decode(&mut deserializer);
+            let api_fact = <String>::sse_decode(&mut deserializer);
+            let api_tier = <crate::api::data_models::MemoryTier>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::set_memory_fact_tier(
+                            api_conversation_id,
+                            api_summary_id,
+                            api_fact,
+                            api_tier,
+                        ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__set_message_reactions_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_message_reactions",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
+            let api_reactions = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::chat_api::set_message_reactions(
+                        api_conversation_id,
+                        api_message_id,
+                        api_reactions,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__set_message_starred_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_message_starred",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
+            let api_starred = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::set_message_starred(
+                            api_conversation_id,
+                            api_message_id,
+                            api_starred,
+                        ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__set_narration_perspective_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_narration_perspective",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_perspective =
+                <crate::api::data_models::NarrationPerspective>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::set_narration_perspective(
+                            api_conversation_id,
+                            api_perspective,
+                        ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__set_network_available_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_network_available",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_available = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::chat_api::set_network_available(api_available);
+                    })?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__set_proxy_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_proxy",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_proxy =
+                <Option<crate::api::data_models::ProxySettings>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::chat_api::set_proxy(api_proxy)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__set_response_style_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_response_style",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_style = <crate::api::data_models::ResponseStyle>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::set_response_style(
+                        api_conversation_id,
+                        api_style,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__set_retention_policy_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_retention_policy",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_policy =
+                <crate::api::data_models::RetentionPolicy>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::chat_api::set_retention_policy(api_policy)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__set_retry_policy_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_retry_policy",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_policy = <crate::api::data_models::RetryPolicy>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::chat_api::set_retry_policy(api_policy)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__set_safety_policy_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_safety_policy",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_policy = <crate::api::data_models::SafetyPolicy>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::chat_api::set_safety_policy(api_policy)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__set_saydo_rules_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_saydo_rules",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_rules = <crate::api::data_models::SayDoRuleSet>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok =
+                        crate::api::chat_api::set_saydo_rules(api_conversation_id, api_rules)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__set_summarization_config_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_summarization_config",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_config = <Option<crate::api::data_models::SummarizationConfig>>::sse_decode(
+                &mut deserializer,
+            );
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::chat_api::set_summarization_config(
+                        api_conversation_id,
+                        api_config,
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__set_sync_settings_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_sync_settings",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_settings =
+                <crate::api::data_models::SyncSettings>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::chat_api::set_sync_settings(api_settings)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__set_thinking_retention_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_thinking_retention",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_policy =
+                <crate::api::data_models::ThinkingRetention>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::set_thinking_retention(
+                            api_conversation_id,
+                            api_policy,
+                        ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__set_time_awareness_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "set_time_awareness",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_enabled = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::chat_api::set_time_awareness(api_enabled)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__data_models__shadow_eval_summary_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "shadow_eval_summary_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::data_models::ShadowEvalSummary::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__should_summarize_memory_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "should_summarize_memory",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::chat_api::should_summarize_memory(api_conversation_id),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__data_models__summarization_config_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "summarization_config_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::data_models::SummarizationConfig::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__switch_branch_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "switch_branch",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_branch_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::switch_branch(
+                        api_conversation_id,
+                        api_branch_id,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__data_models__sync_backend_kind_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "sync_backend_kind_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::data_models::SyncBackendKind::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__sync_now_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "sync_now",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::chat_api::sync_now().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__data_models__sync_report_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "sync_report_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::data_models::SyncReport::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__data_models__sync_settings_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "sync_settings_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::data_models::SyncSettings::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__data_models__thinking_retention_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "thinking_retention_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::data_models::ThinkingRetention::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__trigger_memory_summarize_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "trigger_memory_summarize",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::data_models::ChatStreamEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok({
+                            crate::api::chat_api::trigger_memory_summarize(
+                                api_conversation_id,
+                                api_sink,
+                            )
+                            .await;
+                        })?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__data_models__turn_policy_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "turn_policy_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::data_models::TurnPolicy::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__undo_message_edit_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "undo_message_edit",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            let api_message_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::undo_message_edit(
+                        api_conversation_id,
+                        api_message_id,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__unlock_encryption_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "unlock_encryption",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_secret =
+                <crate::api::data_models::EncryptionSecret>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, String>((move || {
+                    let output_ok = crate::api::chat_api::unlock_encryption(api_secret)?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__update_fact_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "update_fact",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <Option<String>>::sse_decode(&mut deserializer);
+            let api_fact_id = <String>::sse_decode(&mut deserializer);
+            let api_content = <String>::sse_decode(&mut deserializer);
+            let api_category =
+                <crate::api::knowledge_store::FactCategory>::sse_decode(&mut deserializer);
+            let api_confidence = <f64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::update_fact(
+                        api_conversation_id,
+                        api_fact_id,
+                        api_content,
+                        api_category,
+                        api_confidence,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__upgrade_data_layout_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "upgrade_data_layout",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::upgrade_data_layout())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__validate_api_key_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "validate_api_key",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_api_key = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::validate_api_key(api_api_key))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__verify_data_layout_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "verify_data_layout",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::chat_api::verify_data_layout())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__verify_integrity_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "verify_integrity",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(crate::api::chat_api::verify_integrity(
+                        api_conversation_id,
+                    ))?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__watch_background_tasks_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "watch_background_tasks",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<
+                crate::api::data_models::BackgroundTaskEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok({
+                            crate::api::chat_api::watch_background_tasks(api_sink).await;
+                        })?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__watch_check_ins_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "watch_check_ins",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<
+                crate::api::data_models::ChatStreamEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok({
+                            crate::api::chat_api::watch_check_ins(api_sink).await;
+                        })?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__chat_api__watch_outbox_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "watch_outbox",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_sink = <StreamSink<
+                crate::api::data_models::ChatStreamEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok({
+                            crate::api::chat_api::watch_outbox(api_sink).await;
+                        })?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+
+// Section: dart2rust
+
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
+impl SseDecode for std::collections::HashMap<String, String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<(String, String)>>::sse_decode(deserializer);
+        return inner.into_iter().collect();
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::data_models::BackgroundTaskEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::data_models::ChatStreamEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode
+    for StreamSink<
+        crate::api::data_models::ReindexProgress,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
+impl SseDecode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <Vec<u8>>::sse_decode(deserializer);
+        return String::from_utf8(inner).unwrap();
+    }
+}
+
+impl SseDecode for crate::api::data_models::ActionFrequency {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::data_models::ActionFrequency::Auto,
+            1 => crate::api::data_models::ActionFrequency::Never,
+            2 => crate::api::data_models::ActionFrequency::Occasional,
+            3 => crate::api::data_models::ActionFrequency::Frequent,
+            _ => unreachable!("Invalid variant for ActionFrequency: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::AmbientContext {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_weather = <Option<String>>::sse_decode(deserializer);
+        let mut var_stepCount = <Option<u32>>::sse_decode(deserializer);
+        let mut var_nowPlaying = <Option<String>>::sse_decode(deserializer);
+        let mut var_calendarBusy = <Option<bool>>::sse_decode(deserializer);
+        return crate::api::data_models::AmbientContext {
+            weather: var_weather,
+            step_count: var_stepCount,
+            now_playing: var_nowPlaying,
+            calendar_busy: var_calendarBusy,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::AppSettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_apiKey = <Option<String>>::sse_decode(deserializer);
+        let mut var_defaultModel = <String>::sse_decode(deserializer);
+        let mut var_enableThinkingByDefault = <bool>::sse_decode(deserializer);
+        let mut var_chatModel = <String>::sse_decode(deserializer);
+        let mut var_thinkingModel = <String>::sse_decode(deserializer);
+        let mut var_enableWebSearch = <bool>::sse_decode(deserializer);
+        let mut var_contentIntensity =
+            <crate::api::data_models::ContentIntensity>::sse_decode(deserializer);
+        let mut var_provider = <crate::api::data_models::ProviderKind>::sse_decode(deserializer);
+        let mut var_providerBaseUrl = <Option<String>>::sse_decode(deserializer);
+        let mut var_providerModel = <Option<String>>::sse_decode(deserializer);
+        let mut var_providerApiKey = <Option<String>>::sse_decode(deserializer);
+        let mut var_shadowEvalRate = <f64>::sse_decode(deserializer);
+        let mut var_enableTts = <bool>::sse_decode(deserializer);
+        let mut var_proxy =
+            <Option<crate::api::data_models::ProxySettings>>::sse_decode(deserializer);
+        let mut var_retry = <crate::api::data_models::RetryPolicy>::sse_decode(deserializer);
+        let mut var_timeAwareness = <bool>::sse_decode(deserializer);
+        let mut var_fastDraft = <bool>::sse_decode(deserializer);
+        return crate::api::data_models::AppSettings {
+            api_key: var_apiKey,
+            default_model: var_defaultModel,
+            enable_thinking_by_default: var_enableThinkingByDefault,
+            chat_model: var_chatModel,
+            thinking_model: var_thinkingModel,
+            enable_web_search: var_enableWebSearch,
+            content_intensity: var_contentIntensity,
+            provider: var_provider,
+            provider_base_url: var_providerBaseUrl,
+            provider_model: var_providerModel,
+            provider_api_key: var_providerApiKey,
+            shadow_eval_rate: var_shadowEvalRate,
+            enable_tts: var_enableTts,
+            proxy: var_proxy,
+            retry: var_retry,
+            time_awareness: var_timeAwareness,
+            fast_draft: var_fastDraft,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::AudioReadyEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_messageId = <String>::sse_decode(deserializer);
+        let mut var_audioPath = <String>::sse_decode(deserializer);
+        return crate::api::data_models::AudioReadyEvent {
+            message_id: var_messageId,
+            audio_path: var_audioPath,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::BackgroundJobKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::data_models::BackgroundJobKind::FactExtraction,
+            1 => crate::api::data_models::BackgroundJobKind::Summarization,
+            2 => crate::api::data_models::BackgroundJobKind::Distillation,
+            3 => crate::api::data_models::BackgroundJobKind::ShadowEval,
+            _ => unreachable!("Invalid variant for BackgroundJobKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::BackgroundTaskEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_taskId = <String>::sse_decode(deserializer);
+        let mut var_kind = <crate::api::data_models::BackgroundJobKind>::sse_decode(deserializer);
+        let mut var_conversationId = <String>::sse_decode(deserializer);
+        let mut var_status =
+            <crate::api::data_models::BackgroundTaskStatus>::sse_decode(deserializer);
+        let mut var_updatedAt = <i64>::sse_decode(deserializer);
+        return crate::api::data_models::BackgroundTaskEvent {
+            task_id: var_taskId,
+            kind: var_kind,
+            conversation_id: var_conversationId,
+            status: var_status,
+            updated_at: var_updatedAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::BackgroundTaskStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::data_models::BackgroundTaskStatus::Queued,
+            1 => crate::api::data_models::BackgroundTaskStatus::Running,
+            2 => crate::api::data_models::BackgroundTaskStatus::Completed,
+            3 => crate::api::data_models::BackgroundTaskStatus::Failed,
+            _ => unreachable!("Invalid variant for BackgroundTaskStatus: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::BackoffStrategy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::data_models::BackoffStrategy::Fixed,
+            1 => crate::api::data_models::BackoffStrategy::Exponential,
+            2 => crate::api::data_models::BackoffStrategy::ExponentialJitter,
+            _ => unreachable!("Invalid variant for BackoffStrategy: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::BackupRetention {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_keepLast = <u32>::sse_decode(deserializer);
+        let mut var_keepDaily = <u32>::sse_decode(deserializer);
+        return crate::api::data_models::BackupRetention {
+            keep_last: var_keepLast,
+            keep_daily: var_keepDaily,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::BackupScope {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::data_models::BackupScope::All,
+            1 => crate::api::data_models::BackupScope::Conversations,
+            2 => crate::api::data_models::BackupScope::Memory,
+            3 => crate::api::data_models::BackupScope::Knowledge,
+            4 => crate::api::data_models::BackupScope::Config,
+            _ => unreachable!("Invalid variant for BackupScope: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::BackupSnapshotInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_createdAt = <i64>::sse_decode(deserializer);
+        let mut var_fileCount = <u32>::sse_decode(deserializer);
+        let mut var_totalBytes = <u64>::sse_decode(deserializer);
+        let mut var_addedBytes = <u64>::sse_decode(deserializer);
+        return crate::api::data_models::BackupSnapshotInfo {
+            id: var_id,
+            created_at: var_createdAt,
+            file_count: var_fileCount,
+            total_bytes: var_totalBytes,
+            added_bytes: var_addedBytes,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::BlockedTopic {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_topic = <String>::sse_decode(deserializer);
+        let mut var_keywords = <Vec<String>>::sse_decode(deserializer);
+        let mut var_evidence = <Vec<String>>::sse_decode(deserializer);
+        let mut var_occurrences = <u32>::sse_decode(deserializer);
+        let mut var_explicit = <bool>::sse_decode(deserializer);
+        let mut var_active = <bool>::sse_decode(deserializer);
+        let mut var_firstSeen = <i64>::sse_decode(deserializer);
+        let mut var_lastSeen = <i64>::sse_decode(deserializer);
+        return crate::api::data_models::BlockedTopic {
+            id: var_id,
+            topic: var_topic,
+            keywords: var_keywords,
+            evidence: var_evidence,
+            occurrences: var_occurrences,
+            explicit: var_explicit,
+            active: var_active,
+            first_seen: var_firstSeen,
+            last_seen: var_lastSeen,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::BlockingStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_tasks = <u64>::sse_decode(deserializer);
+        let mut var_totalRunMs = <u64>::sse_decode(deserializer);
+        let mut var_maxRunMs = <u64>::sse_decode(deserializer);
+        let mut var_maxQueueMs = <u64>::sse_decode(deserializer);
+        let mut var_slowestLabel = <String>::sse_decode(deserializer);
+        return crate::api::data_models::BlockingStats {
+            tasks: var_tasks,
+            total_run_ms: var_totalRunMs,
+            max_run_ms: var_maxRunMs,
+            max_queue_ms: var_maxQueueMs,
+            slowest_label: var_slowestLabel,
+        };
+    }
+}
+
+impl SseDecode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap() != 0
+    }
+}
+
+impl SseDecode for crate::api::data_models::BranchInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_parentMessageId = <Option<String>>::sse_decode(deserializer);
+        let mut var_messageCount = <u32>::sse_decode(deserializer);
+        let mut var_lastMessagePreview = <String>::sse_decode(deserializer);
+        let mut var_createdAt = <i64>::sse_decode(deserializer);
+        let mut var_isActive = <bool>::sse_decode(deserializer);
+        return crate::api::data_models::BranchInfo {
+            id: var_id,
+            name: var_name,
+            parent_message_id: var_parentMessageId,
+            message_count: var_messageCount,
+            last_message_preview: var_lastMessagePreview,
+            created_at: var_createdAt,
+            is_active: var_isActive,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::CharacterCard {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_systemPrompt = <String>::sse_decode(deserializer);
+        let mut var_greeting = <String>::sse_decode(deserializer);
+        return crate::api::data_models::CharacterCard {
+            name: var_name,
+            system_prompt: var_systemPrompt,
+            greeting: var_greeting,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::CharacterRelation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_from = <String>::sse_decode(deserializer);
+        let mut var_to = <String>::sse_decode(deserializer);
+        let mut var_relation = <String>::sse_decode(deserializer);
+        let mut var_sentiment =
+            <crate::api::data_models::RelationSentiment>::sse_decode(deserializer);
+        let mut var_factId = <String>::sse_decode(deserializer);
+        let mut var_sourceTurn = <u32>::sse_decode(deserializer);
+        let mut var_history =
+            <Vec<crate::api::data_models::RelationChange>>::sse_decode(deserializer);
+        return crate::api::data_models::CharacterRelation {
+            from: var_from,
+            to: var_to,
+            relation: var_relation,
+            sentiment: var_sentiment,
+            fact_id: var_factId,
+            source_turn: var_sourceTurn,
+            history: var_history,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::ChatStreamEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::data_models::ChatStreamEvent::ContentDelta(var_field0);
+            }
+            1 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::data_models::ChatStreamEvent::ThinkingDelta(var_field0);
+            }
+            2 => {
+                return crate::api::data_models::ChatStreamEvent::Done;
+            }
+            3 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::data_models::ChatStreamEvent::Error(var_field0);
+            }
+            4 => {
+                return crate::api::data_models::ChatStreamEvent::ConfigChanged;
+            }
+            5 => {
+                let mut var_field0 =
+                    <crate::api::data_models::ReactionEvent>::sse_decode(deserializer);
+                return crate::api::data_models::ChatStreamEvent::Reaction(var_field0);
+            }
+            6 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::data_models::ChatStreamEvent::SystemNotice(var_field0);
+            }
+            7 => {
+                let mut var_field0 = <crate::api::data_models::Message>::sse_decode(deserializer);
+                return crate::api::data_models::ChatStreamEvent::Illustration(var_field0);
+            }
+            8 => {
+                let mut var_field0 =
+                    <crate::api::data_models::AudioReadyEvent>::sse_decode(deserializer);
+                return crate::api::data_models::ChatStreamEvent::AudioReady(var_field0);
+            }
+            9 => {
+                let mut var_field0 =
+                    <crate::api::data_models::GenerationCompletedEvent>::sse_decode(deserializer);
+                return crate::api::data_models::ChatStreamEvent::Completed(var_field0);
+            }
+            10 => {
+                let mut var_field0 =
+                    <crate::api::data_models::OutboxEntry>::sse_decode(deserializer);
+                return crate::api::data_models::ChatStreamEvent::Outbox(var_field0);
+            }
+            11 => {
+                let mut var_field0 =
+                    <crate::api::data_models::SafetyEvent>::sse_decode(deserializer);
+                return crate::api::data_models::ChatStreamEvent::Safety(var_field0);
+            }
+            12 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::data_models::ChatStreamEvent::CheckIn(var_field0);
+            }
+            13 => {
+                let mut var_field0 =
+                    <crate::api::data_models::MemorySummary>::sse_decode(deserializer);
+                return crate::api::data_models::ChatStreamEvent::SummaryPreview(var_field0);
+            }
+            14 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::data_models::ChatStreamEvent::DraftDelta(var_field0);
+            }
+            15 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::data_models::ChatStreamEvent::Refined(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::data_models::CheckInSchedule {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_conversationId = <String>::sse_decode(deserializer);
+        let mut var_enabled = <bool>::sse_decode(deserializer);
+        let mut var_silenceHours = <u32>::sse_decode(deserializer);
+        let mut var_lastSentAt = <Option<i64>>::sse_decode(deserializer);
+        return crate::api::data_models::CheckInSchedule {
+            conversation_id: var_conversationId,
+            enabled: var_enabled,
+            silence_hours: var_silenceHours,
+            last_sent_at: var_lastSentAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::CognitiveInsight {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_emotion = <crate::api::data_models::EmotionInsight>::sse_decode(deserializer);
+        let mut var_intent = <crate::api::data_models::IntentKind>::sse_decode(deserializer);
+        let mut var_relationship =
+            <crate::api::data_models::RelationshipInsight>::sse_decode(deserializer);
+        let mut var_empathyStrategy =
+            <crate::api::data_models::EmpathyKind>::sse_decode(deserializer);
+        let mut var_detectedPatterns =
+            <Vec<crate::api::data_models::LanguagePatternKind>>::sse_decode(deserializer);
+        let mut var_cognitivePrompt = <String>::sse_decode(deserializer);
+        return crate::api::data_models::CognitiveInsight {
+            emotion: var_emotion,
+            intent: var_intent,
+            relationship: var_relationship,
+            empathy_strategy: var_empathyStrategy,
+            detected_patterns: var_detectedPatterns,
+            cognitive_prompt: var_cognitivePrompt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::ContentIntensity {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::data_models::ContentIntensity::Mild,
+            1 => crate::api::data_models::ContentIntensity::Normal,
+            2 => crate::api::data_models::ContentIntensity::Dramatic,
+            _ => unreachable!("Invalid variant for ContentIntensity: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::ContextLayers {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_shortTermMemory = <bool>::sse_decode(deserializer);
+        let mut var_cognitiveSnapshot = <bool>::sse_decode(deserializer);
+        let mut var_diversityHint = <bool>::sse_decode(deserializer);
+        let mut var_humanizationHint = <bool>::sse_decode(deserializer);
+        let mut var_knowledgeInjection = <bool>::sse_decode(deserializer);
+        return crate::api::data_models::ContextLayers {
+            short_term_memory: var_shortTermMemory,
+            cognitive_snapshot: var_cognitiveSnapshot,
+            diversity_hint: var_diversityHint,
+            humanization_hint: var_humanizationHint,
+            knowledge_injection: var_knowledgeInjection,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::Conversation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_messages = <Vec<crate::api::data_models::Message>>::sse_decode(deserializer);
+        let mut var_model = <String>::sse_decode(deserializer);
+        let mut var_createdAt = <i64>::sse_decode(deserializer);
+        let mut var_updatedAt = <i64>::sse_decode(deserializer);
+        let mut var_dialogueStyle =
+            <crate::api::data_models::DialogueStyle>::sse_decode(deserializer);
+        let mut var_turnCount = <u32>::sse_decode(deserializer);
+        let mut var_memorySummaries =
+            <Vec<crate::api::data_models::MemorySummary>>::sse_decode(deserializer);
+        let mut var_mode = <crate::api::data_models::ConversationMode>::sse_decode(deserializer);
+        let mut var_thinkingRetention =
+            <crate::api::data_models::ThinkingRetention>::sse_decode(deserializer);
+        let mut var_branchId = <String>::sse_decode(deserializer);
+        let mut var_parentMessageId = <Option<String>>::sse_decode(deserializer);
+        let mut var_branches =
+            <Vec<crate::api::data_models::ConversationBranch>>::sse_decode(deserializer);
+        let mut var_narration =
+            <crate::api::data_models::NarrationPerspective>::sse_decode(deserializer);
+        let mut var_contextLayers =
+            <crate::api::data_models::ContextLayers>::sse_decode(deserializer);
+        let mut var_closedAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_responseStyle =
+            <crate::api::data_models::ResponseStyle>::sse_decode(deserializer);
+        let mut var_personaId = <Option<String>>::sse_decode(deserializer);
+        let mut var_organization =
+            <crate::api::data_models::ConversationOrganization>::sse_decode(deserializer);
+        let mut var_coldTurns = <u32>::sse_decode(deserializer);
+        return crate::api::data_models::Conversation {
+            id: var_id,
+            title: var_title,
+            messages: var_messages,
+            model: var_model,
+            created_at: var_createdAt,
+            updated_at: var_updatedAt,
+            dialogue_style: var_dialogueStyle,
+            turn_count: var_turnCount,
+            memory_summaries: var_memorySummaries,
+            mode: var_mode,
+            thinking_retention: var_thinkingRetention,
+            branch_id: var_branchId,
+            parent_message_id: var_parentMessageId,
+            branches: var_branches,
+            narration: var_narration,
+            context_layers: var_contextLayers,
+            closed_at: var_closedAt,
+            response_style: var_responseStyle,
+            persona_id: var_personaId,
+            organization: var_organization,
+            cold_turns: var_coldTurns,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::ConversationBranch {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_parentMessageId = <Option<String>>::sse_decode(deserializer);
+        let mut var_messages = <Vec<crate::api::data_models::Message>>::sse_decode(deserializer);
+        let mut var_turnCount = <u32>::sse_decode(deserializer);
+        let mut var_memorySummaries =
+            <Vec<crate::api::data_models::MemorySummary>>::sse_decode(deserializer);
+        let mut var_createdAt = <i64>::sse_decode(deserializer);
+        let mut var_coldTurns = <u32>::sse_decode(deserializer);
+        return crate::api::data_models::ConversationBranch {
+            id: var_id,
+            name: var_name,
+            parent_message_id: var_parentMessageId,
             messages: var_messages,
+            turn_count: var_turnCount,
+            memory_summaries: var_memorySummaries,
+            created_at: var_createdAt,
+            cold_turns: var_coldTurns,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::ConversationClosure {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_epilogue = <String>::sse_decode(deserializer);
+        let mut var_recap = <String>::sse_decode(deserializer);
+        let mut var_archivePath = <String>::sse_decode(deserializer);
+        let mut var_closedAt = <i64>::sse_decode(deserializer);
+        return crate::api::data_models::ConversationClosure {
+            epilogue: var_epilogue,
+            recap: var_recap,
+            archive_path: var_archivePath,
+            closed_at: var_closedAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::ConversationFilter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_folder = <Option<String>>::sse_decode(deserializer);
+        let mut var_tags = <Vec<String>>::sse_decode(deserializer);
+        let mut var_archived = <bool>::sse_decode(deserializer);
+        let mut var_query = <String>::sse_decode(deserializer);
+        return crate::api::data_models::ConversationFilter {
+            folder: var_folder,
+            tags: var_tags,
+            archived: var_archived,
+            query: var_query,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::ConversationInsights {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_cognitive =
+            <Option<crate::api::data_models::CognitiveInsight>>::sse_decode(deserializer);
+        let mut var_shortTerm =
+            <crate::api::data_models::ShortTermInsight>::sse_decode(deserializer);
+        return crate::api::data_models::ConversationInsights {
+            cognitive: var_cognitive,
+            short_term: var_shortTerm,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::ConversationMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::data_models::ConversationMode::Chat,
+            1 => crate::api::data_models::ConversationMode::CoAuthor,
+            _ => unreachable!("Invalid variant for ConversationMode: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::ConversationOrganization {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_tags = <Vec<String>>::sse_decode(deserializer);
+        let mut var_folder = <Option<String>>::sse_decode(deserializer);
+        let mut var_pinned = <bool>::sse_decode(deserializer);
+        let mut var_archived = <bool>::sse_decode(deserializer);
+        return crate::api::data_models::ConversationOrganization {
+            tags: var_tags,
+            folder: var_folder,
+            pinned: var_pinned,
+            archived: var_archived,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::ConversationSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_lastMessagePreview = <String>::sse_decode(deserializer);
+        let mut var_model = <String>::sse_decode(deserializer);
+        let mut var_updatedAt = <i64>::sse_decode(deserializer);
+        let mut var_organization =
+            <crate::api::data_models::ConversationOrganization>::sse_decode(deserializer);
+        return crate::api::data_models::ConversationSummary {
+            id: var_id,
+            title: var_title,
+            last_message_preview: var_lastMessagePreview,
+            model: var_model,
+            updated_at: var_updatedAt,
+            organization: var_organization,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::DailyDigest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_generatedAt = <i64>::sse_decode(deserializer);
+        let mut var_entries = <Vec<crate::api::data_models::DigestEntry>>::sse_decode(deserializer);
+        let mut var_skipped = <u32>::sse_decode(deserializer);
+        let mut var_estimatedTokens = <u32>::sse_decode(deserializer);
+        return crate::api::data_models::DailyDigest {
+            generated_at: var_generatedAt,
+            entries: var_entries,
+            skipped: var_skipped,
+            estimated_tokens: var_estimatedTokens,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::DataExportInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_formatVersion = <u32>::sse_decode(deserializer);
+        let mut var_layoutVersion = <u32>::sse_decode(deserializer);
+        let mut var_createdAt = <i64>::sse_decode(deserializer);
+        let mut var_fileCount = <u32>::sse_decode(deserializer);
+        let mut var_totalBytes = <u64>::sse_decode(deserializer);
+        return crate::api::data_models::DataExportInfo {
+            format_version: var_formatVersion,
+            layout_version: var_layoutVersion,
+            created_at: var_createdAt,
+            file_count: var_fileCount,
+            total_bytes: var_totalBytes,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::DeferredJob {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <crate::api::data_models::BackgroundJobKind>::sse_decode(deserializer);
+        let mut var_conversationId = <String>::sse_decode(deserializer);
+        let mut var_deferredAt = <i64>::sse_decode(deserializer);
+        let mut var_reason = <String>::sse_decode(deserializer);
+        return crate::api::data_models::DeferredJob {
+            kind: var_kind,
+            conversation_id: var_conversationId,
+            deferred_at: var_deferredAt,
+            reason: var_reason,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::DegradationKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::data_models::DegradationKind::ThinkingDropped,
+            1 => crate::api::data_models::DegradationKind::CompactRetry,
+            2 => crate::api::data_models::DegradationKind::ModelFallback,
+            3 => crate::api::data_models::DegradationKind::ReasoningTimeout,
+            4 => crate::api::data_models::DegradationKind::DistillationTimeout,
+            5 => crate::api::data_models::DegradationKind::ContextTruncated,
+            6 => crate::api::data_models::DegradationKind::FactExtractionTimeout,
+            _ => unreachable!("Invalid variant for DegradationKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::DegradationRecord {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_turn = <u32>::sse_decode(deserializer);
+        let mut var_kind = <crate::api::data_models::DegradationKind>::sse_decode(deserializer);
+        let mut var_model = <String>::sse_decode(deserializer);
+        let mut var_detail = <String>::sse_decode(deserializer);
+        let mut var_timestamp = <i64>::sse_decode(deserializer);
+        return crate::api::data_models::DegradationRecord {
+            turn: var_turn,
+            kind: var_kind,
+            model: var_model,
+            detail: var_detail,
+            timestamp: var_timestamp,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::DeviceConditions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_batteryLevel = <Option<f64>>::sse_decode(deserializer);
+        let mut var_isCharging = <bool>::sse_decode(deserializer);
+        let mut var_lowPowerMode = <bool>::sse_decode(deserializer);
+        let mut var_meteredNetwork = <bool>::sse_decode(deserializer);
+        return crate::api::data_models::DeviceConditions {
+            battery_level: var_batteryLevel,
+            is_charging: var_isCharging,
+            low_power_mode: var_lowPowerMode,
+            metered_network: var_meteredNetwork,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::DialogueStyle {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::data_models::DialogueStyle::Free,
+            1 => crate::api::data_models::DialogueStyle::SayOnly,
+            2 => crate::api::data_models::DialogueStyle::DoOnly,
+            3 => crate::api::data_models::DialogueStyle::Mixed,
+            _ => unreachable!("Invalid variant for DialogueStyle: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::DigestEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_conversationId = <String>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_note = <String>::sse_decode(deserializer);
+        let mut var_lastActiveAt = <i64>::sse_decode(deserializer);
+        return crate::api::data_models::DigestEntry {
+            conversation_id: var_conversationId,
+            title: var_title,
+            note: var_note,
+            last_active_at: var_lastActiveAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::DistilledSystemState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_corePrompt = <String>::sse_decode(deserializer);
+        let mut var_lastMemoryCount = <usize>::sse_decode(deserializer);
+        let mut var_lastMaxCompressionGen = <u32>::sse_decode(deserializer);
+        let mut var_characterPromptHash = <u64>::sse_decode(deserializer);
+        let mut var_lastTurnCount = <u32>::sse_decode(deserializer);
+        let mut var_distilledAt = <i64>::sse_decode(deserializer);
+        let mut var_coreFactsSnapshot = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::data_models::DistilledSystemState {
+            core_prompt: var_corePrompt,
+            last_memory_count: var_lastMemoryCount,
+            last_max_compression_gen: var_lastMaxCompressionGen,
+            character_prompt_hash: var_characterPromptHash,
+            last_turn_count: var_lastTurnCount,
+            distilled_at: var_distilledAt,
+            core_facts_snapshot: var_coreFactsSnapshot,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::DraftTone {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::data_models::DraftTone::Calm,
+            1 => crate::api::data_models::DraftTone::Warm,
+            2 => crate::api::data_models::DraftTone::Playful,
+            3 => crate::api::data_models::DraftTone::Sad,
+            4 => crate::api::data_models::DraftTone::Anxious,
+            5 => crate::api::data_models::DraftTone::Sarcastic,
+            6 => crate::api::data_models::DraftTone::Hostile,
+            _ => unreachable!("Invalid variant for DraftTone: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::EmojiUsage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::data_models::EmojiUsage::Auto,
+            1 => crate::api::data_models::EmojiUsage::Never,
+            2 => crate::api::data_models::EmojiUsage::Occasional,
+            3 => crate::api::data_models::EmojiUsage::Frequent,
+            _ => unreachable!("Invalid variant for EmojiUsage: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::EmotionDimension {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::data_models::EmotionDimension::Joy,
+            1 => crate::api::data_models::EmotionDimension::Sadness,
+            2 => crate::api::data_models::EmotionDimension::Anger,
+            3 => crate::api::data_models::EmotionDimension::Fear,
+            4 => crate::api::data_models::EmotionDimension::Surprise,
+            5 => crate::api::data_models::EmotionDimension::Intimacy,
+            6 => crate::api::data_models::EmotionDimension::Trust,
+            7 => crate::api::data_models::EmotionDimension::Anticipation,
+            _ => unreachable!("Invalid variant for EmotionDimension: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::EmotionInsight {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_joy = <f64>::sse_decode(deserializer);
+        let mut var_sadness = <f64>::sse_decode(deserializer);
+        let mut var_anger = <f64>::sse_decode(deserializer);
+        let mut var_fear = <f64>::sse_decode(deserializer);
+        let mut var_surprise = <f64>::sse_decode(deserializer);
+        let mut var_intimacy = <f64>::sse_decode(deserializer);
+        let mut var_trust = <f64>::sse_decode(deserializer);
+        let mut var_anticipation = <f64>::sse_decode(deserializer);
+        let mut var_valence = <f64>::sse_decode(deserializer);
+        let mut var_arousal = <f64>::sse_decode(deserializer);
+        return crate::api::data_models::EmotionInsight {
+            joy: var_joy,
+            sadness: var_sadness,
+            anger: var_anger,
+            fear: var_fear,
+            surprise: var_surprise,
+            intimacy: var_intimacy,
+            trust: var_trust,
+            anticipation: var_anticipation,
+            valence: var_valence,
+            arousal: var_arousal,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::EmotionalTurnInsight {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_turn = <u32>::sse_decode(deserializer);
+        let mut var_valence = <f64>::sse_decode(deserializer);
+        let mut var_arousal = <f64>::sse_decode(deserializer);
+        let mut var_dominantEmotion = <String>::sse_decode(deserializer);
+        return crate::api::data_models::EmotionalTurnInsight {
+            turn: var_turn,
+            valence: var_valence,
+            arousal: var_arousal,
+            dominant_emotion: var_dominantEmotion,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::EmpathyKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::data_models::EmpathyKind::Mirror,
+            1 => crate::api::data_models::EmpathyKind::Accompany,
+            2 => crate::api::data_models::EmpathyKind::Distract,
+            3 => crate::api::data_models::EmpathyKind::Responsive,
+            4 => crate::api::data_models::EmpathyKind::PlayfulCounter,
+            5 => crate::api::data_models::EmpathyKind::GentleFirm,
+            6 => crate::api::data_models::EmpathyKind::ProactiveCare,
+            7 => crate::api::data_models::EmpathyKind::NaturalFlow,
+            8 => crate::api::data_models::EmpathyKind::GiveSpace,
+            9 => crate::api::data_models::EmpathyKind::Escalate,
+            _ => unreachable!("Invalid variant for EmpathyKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::EncryptionKeySource {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::data_models::EncryptionKeySource::Passphrase,
+            1 => crate::api::data_models::EncryptionKeySource::Keystore,
+            _ => unreachable!("Invalid variant for EncryptionKeySource: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::EncryptionSecret {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::api::data_models::EncryptionSecret::Passphrase(var_field0);
+            }
+            1 => {
+                let mut var_field0 = <Vec<u8>>::sse_decode(deserializer);
+                return crate::api::data_models::EncryptionSecret::Keystore(var_field0);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::data_models::EncryptionStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_enabled = <bool>::sse_decode(deserializer);
+        let mut var_unlocked = <bool>::sse_decode(deserializer);
+        let mut var_source =
+            <Option<crate::api::data_models::EncryptionKeySource>>::sse_decode(deserializer);
+        return crate::api::data_models::EncryptionStatus {
+            enabled: var_enabled,
+            unlocked: var_unlocked,
+            source: var_source,
+        };
+    }
+}
+
+impl SseDecode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::knowledge_store::Fact {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_content = <String>::sse_decode(deserializer);
+        let mut var_category =
+            <crate::api::knowledge_store::FactCategory>::sse_decode(deserializer);
+        let mut var_sourceTurn = <u32>::sse_decode(deserializer);
+        let mut var_createdAt = <i64>::sse_decode(deserializer);
+        let mut var_lastConfirmedAt = <i64>::sse_decode(deserializer);
+        let mut var_keywords = <Vec<String>>::sse_decode(deserializer);
+        let mut var_entities = <Vec<String>>::sse_decode(deserializer);
+        let mut var_confidence = <f64>::sse_decode(deserializer);
+        let mut var_hitCount = <u32>::sse_decode(deserializer);
+        let mut var_contextSnippet = <String>::sse_decode(deserializer);
+        let mut var_summaryIds = <Vec<String>>::sse_decode(deserializer);
+        let mut var_supersededBy = <Option<String>>::sse_decode(deserializer);
+        let mut var_supersededAt = <Option<i64>>::sse_decode(deserializer);
+        let mut var_manualOverride = <bool>::sse_decode(deserializer);
+        let mut var_personaId = <Option<String>>::sse_decode(deserializer);
+        return crate::api::knowledge_store::Fact {
+            id: var_id,
+            content: var_content,
+            category: var_category,
+            source_turn: var_sourceTurn,
+            created_at: var_createdAt,
+            last_confirmed_at: var_lastConfirmedAt,
+            keywords: var_keywords,
+            entities: var_entities,
+            confidence: var_confidence,
+            hit_count: var_hitCount,
+            context_snippet: var_contextSnippet,
+            summary_ids: var_summaryIds,
+            superseded_by: var_supersededBy,
+            superseded_at: var_supersededAt,
+            manual_override: var_manualOverride,
+            persona_id: var_personaId,
+        };
+    }
+}
+
+impl SseDecode for crate::api::knowledge_store::FactCategory {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::knowledge_store::FactCategory::Identity,
+            1 => crate::api::knowledge_store::FactCategory::Relationship,
+            2 => crate::api::knowledge_store::FactCategory::Preference,
+            3 => crate::api::knowledge_store::FactCategory::Event,
+            4 => crate::api::knowledge_store::FactCategory::CurrentState,
+            5 => crate::api::knowledge_store::FactCategory::Promise,
+            6 => crate::api::knowledge_store::FactCategory::Consensus,
+            _ => unreachable!("Invalid variant for FactCategory: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::FactConflict {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_supersededFactId = <String>::sse_decode(deserializer);
+        let mut var_supersededContent = <String>::sse_decode(deserializer);
+        let mut var_activeFactId = <String>::sse_decode(deserializer);
+        let mut var_activeContent = <String>::sse_decode(deserializer);
+        let mut var_detectedAt = <i64>::sse_decode(deserializer);
+        return crate::api::data_models::FactConflict {
+            superseded_fact_id: var_supersededFactId,
+            superseded_content: var_supersededContent,
+            active_fact_id: var_activeFactId,
+            active_content: var_activeContent,
+            detected_at: var_detectedAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::FingerprintInsight {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_openingChars = <String>::sse_decode(deserializer);
+        let mut var_paragraphCount = <u32>::sse_decode(deserializer);
+        let mut var_avgSentenceLen = <f64>::sse_decode(deserializer);
+        let mut var_endingChars = <String>::sse_decode(deserializer);
+        let mut var_endsWithQuestion = <bool>::sse_decode(deserializer);
+        let mut var_totalLength = <u32>::sse_decode(deserializer);
+        let mut var_hasActionMarker = <bool>::sse_decode(deserializer);
+        let mut var_hasListFormat = <bool>::sse_decode(deserializer);
+        let mut var_emotionalTone = <String>::sse_decode(deserializer);
+        return crate::api::data_models::FingerprintInsight {
+            opening_chars: var_openingChars,
+            paragraph_count: var_paragraphCount,
+            avg_sentence_len: var_avgSentenceLen,
+            ending_chars: var_endingChars,
+            ends_with_question: var_endsWithQuestion,
+            total_length: var_totalLength,
+            has_action_marker: var_hasActionMarker,
+            has_list_format: var_hasListFormat,
+            emotional_tone: var_emotionalTone,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::Formality {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::data_models::Formality::Auto,
+            1 => crate::api::data_models::Formality::Casual,
+            2 => crate::api::data_models::Formality::Neutral,
+            3 => crate::api::data_models::Formality::Formal,
+            _ => unreachable!("Invalid variant for Formality: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::GenerationCompletedEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_messageId = <String>::sse_decode(deserializer);
+        let mut var_metadata =
+            <crate::api::data_models::GenerationMetadata>::sse_decode(deserializer);
+        return crate::api::data_models::GenerationCompletedEvent {
+            message_id: var_messageId,
+            metadata: var_metadata,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::GenerationMetadata {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_model = <String>::sse_decode(deserializer);
+        let mut var_finishReason = <Option<String>>::sse_decode(deserializer);
+        let mut var_promptTokens = <u32>::sse_decode(deserializer);
+        let mut var_completionTokens = <u32>::sse_decode(deserializer);
+        let mut var_totalTokens = <u32>::sse_decode(deserializer);
+        let mut var_cachedTokens = <u32>::sse_decode(deserializer);
+        let mut var_streamed = <bool>::sse_decode(deserializer);
+        let mut var_interrupted = <bool>::sse_decode(deserializer);
+        let mut var_fallbacks = <Vec<String>>::sse_decode(deserializer);
+        let mut var_latencyMs = <i64>::sse_decode(deserializer);
+        return crate::api::data_models::GenerationMetadata {
+            model: var_model,
+            finish_reason: var_finishReason,
+            prompt_tokens: var_promptTokens,
+            completion_tokens: var_completionTokens,
+            total_tokens: var_totalTokens,
+            cached_tokens: var_cachedTokens,
+            streamed: var_streamed,
+            interrupted: var_interrupted,
+            fallbacks: var_fallbacks,
+            latency_ms: var_latencyMs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::GraphEdge {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_factId = <String>::sse_decode(deserializer);
+        let mut var_source = <String>::sse_decode(deserializer);
+        let mut var_target = <String>::sse_decode(deserializer);
+        let mut var_relation = <String>::sse_decode(deserializer);
+        let mut var_category = <String>::sse_decode(deserializer);
+        return crate::api::data_models::GraphEdge {
+            fact_id: var_factId,
+            source: var_source,
+            target: var_target,
+            relation: var_relation,
+            category: var_category,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::GraphExportFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::data_models::GraphExportFormat::Json,
+            1 => crate::api::data_models::GraphExportFormat::GraphMl,
+            _ => unreachable!("Invalid variant for GraphExportFormat: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::GraphNode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_label = <String>::sse_decode(deserializer);
+        let mut var_degree = <u32>::sse_decode(deserializer);
+        return crate::api::data_models::GraphNode {
+            id: var_id,
+            label: var_label,
+            degree: var_degree,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::GroupCharacter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_systemPrompt = <String>::sse_decode(deserializer);
+        return crate::api::data_models::GroupCharacter {
+            id: var_id,
+            name: var_name,
+            system_prompt: var_systemPrompt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::GroupChat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_conversationId = <String>::sse_decode(deserializer);
+        let mut var_characters =
+            <Vec<crate::api::data_models::GroupCharacter>>::sse_decode(deserializer);
+        let mut var_turnPolicy = <crate::api::data_models::TurnPolicy>::sse_decode(deserializer);
+        return crate::api::data_models::GroupChat {
+            conversation_id: var_conversationId,
+            characters: var_characters,
+            turn_policy: var_turnPolicy,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::HousekeepingReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_archived = <Vec<String>>::sse_decode(deserializer);
+        let mut var_rolledOverMessages = <u32>::sse_decode(deserializer);
+        let mut var_purgedFiles = <u32>::sse_decode(deserializer);
+        return crate::api::data_models::HousekeepingReport {
+            archived: var_archived,
+            rolled_over_messages: var_rolledOverMessages,
+            purged_files: var_purgedFiles,
+        };
+    }
+}
+
+impl SseDecode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for crate::api::data_models::IndexScope {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::data_models::IndexScope::All,
+            1 => crate::api::data_models::IndexScope::Knowledge,
+            2 => crate::api::data_models::IndexScope::Memory,
+            _ => unreachable!("Invalid variant for IndexScope: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::IntegrityIssue {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <crate::api::data_models::IntegrityIssueKind>::sse_decode(deserializer);
+        let mut var_detail = <String>::sse_decode(deserializer);
+        let mut var_repaired = <bool>::sse_decode(deserializer);
+        return crate::api::data_models::IntegrityIssue {
+            kind: var_kind,
+            detail: var_detail,
+            repaired: var_repaired,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::IntegrityIssueKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::data_models::IntegrityIssueKind::TurnCountMismatch,
+            1 => crate::api::data_models::IntegrityIssueKind::SummaryRangeInvalid,
+            2 => crate::api::data_models::IntegrityIssueKind::SummaryIndexDesync,
+            3 => crate::api::data_models::IntegrityIssueKind::OrphanFact,
+            4 => crate::api::data_models::IntegrityIssueKind::DanglingIndexEntry,
+            _ => unreachable!("Invalid variant for IntegrityIssueKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::IntegrityReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_conversationId = <String>::sse_decode(deserializer);
+        let mut var_actualTurns = <u32>::sse_decode(deserializer);
+        let mut var_recordedTurnCount = <u32>::sse_decode(deserializer);
+        let mut var_issues =
+            <Vec<crate::api::data_models::IntegrityIssue>>::sse_decode(deserializer);
+        let mut var_checkedAt = <i64>::sse_decode(deserializer);
+        return crate::api::data_models::IntegrityReport {
+            conversation_id: var_conversationId,
+            actual_turns: var_actualTurns,
+            recorded_turn_count: var_recordedTurnCount,
+            issues: var_issues,
+            checked_at: var_checkedAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::IntentKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::data_models::IntentKind::SeekingComfort,
+            1 => crate::api::data_models::IntentKind::ExpressingAffection,
+            2 => crate::api::data_models::IntentKind::ExpressingDispleasure,
+            3 => crate::api::data_models::IntentKind::TestingBoundary,
+            4 => crate::api::data_models::IntentKind::SharingDaily,
+            5 => crate::api::data_models::IntentKind::SeekingResponse,
+            6 => crate::api::data_models::IntentKind::EmotionalVenting,
+            7 => crate::api::data_models::IntentKind::Playful,
+            8 => crate::api::data_models::IntentKind::Reconciling,
+            9 => crate::api::data_models::IntentKind::Farewell,
+            10 => crate::api::data_models::IntentKind::Withdrawn,
+            11 => crate::api::data_models::IntentKind::DeepSharing,
+            _ => unreachable!("Invalid variant for IntentKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::JobSchedulerStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_running = <u32>::sse_decode(deserializer);
+        let mut var_completed = <u64>::sse_decode(deserializer);
+        let mut var_timedOut = <u64>::sse_decode(deserializer);
+        let mut var_deferredTotal = <u64>::sse_decode(deserializer);
+        let mut var_pending = <Vec<crate::api::data_models::DeferredJob>>::sse_decode(deserializer);
+        return crate::api::data_models::JobSchedulerStats {
+            running: var_running,
+            completed: var_completed,
+            timed_out: var_timedOut,
+            deferred_total: var_deferredTotal,
+            pending: var_pending,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::KnowledgeBinding {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_characterId = <Option<String>>::sse_decode(deserializer);
+        let mut var_characterName = <Option<String>>::sse_decode(deserializer);
+        let mut var_worldId = <Option<String>>::sse_decode(deserializer);
+        return crate::api::data_models::KnowledgeBinding {
+            character_id: var_characterId,
+            character_name: var_characterName,
+            world_id: var_worldId,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::KnowledgeGraphView {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_nodes = <Vec<crate::api::data_models::GraphNode>>::sse_decode(deserializer);
+        let mut var_edges = <Vec<crate::api::data_models::GraphEdge>>::sse_decode(deserializer);
+        return crate::api::data_models::KnowledgeGraphView {
+            nodes: var_nodes,
+            edges: var_edges,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::KnowledgeLayer {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::data_models::KnowledgeLayer::Conversation,
+            1 => crate::api::data_models::KnowledgeLayer::Character,
+            2 => crate::api::data_models::KnowledgeLayer::World,
+            _ => unreachable!("Invalid variant for KnowledgeLayer: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::LanguagePatternKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::data_models::LanguagePatternKind::Negation,
+            1 => crate::api::data_models::LanguagePatternKind::Sarcasm,
+            2 => crate::api::data_models::LanguagePatternKind::Hesitation,
+            3 => crate::api::data_models::LanguagePatternKind::Repetition,
+            4 => crate::api::data_models::LanguagePatternKind::Urgent,
+            5 => crate::api::data_models::LanguagePatternKind::Dragging,
+            6 => crate::api::data_models::LanguagePatternKind::Contradictory,
+            7 => crate::api::data_models::LanguagePatternKind::Probing,
+            8 => crate::api::data_models::LanguagePatternKind::Coquettish,
+            9 => crate::api::data_models::LanguagePatternKind::Defensive,
+            10 => crate::api::data_models::LanguagePatternKind::Suppressed,
+            11 => crate::api::data_models::LanguagePatternKind::TopicAvoidance,
+            _ => unreachable!("Invalid variant for LanguagePatternKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::LayoutVerification {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_version = <u32>::sse_decode(deserializer);
+        let mut var_checkedFiles = <u32>::sse_decode(deserializer);
+        let mut var_missing = <Vec<String>>::sse_decode(deserializer);
+        let mut var_corrupted = <Vec<String>>::sse_decode(deserializer);
+        let mut var_unexpected = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::data_models::LayoutVerification {
+            version: var_version,
+            checked_files: var_checkedFiles,
+            missing: var_missing,
+            corrupted: var_corrupted,
+            unexpected: var_unexpected,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::LexiconEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_word = <String>::sse_decode(deserializer);
+        let mut var_dimension =
+            <crate::api::data_models::EmotionDimension>::sse_decode(deserializer);
+        let mut var_weight = <f64>::sse_decode(deserializer);
+        return crate::api::data_models::LexiconEntry {
+            word: var_word,
+            dimension: var_dimension,
+            weight: var_weight,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::LinkedFact {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_factId = <String>::sse_decode(deserializer);
+        let mut var_content = <String>::sse_decode(deserializer);
+        let mut var_sourceTurn = <u32>::sse_decode(deserializer);
+        let mut var_summaryIds = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::data_models::LinkedFact {
+            fact_id: var_factId,
+            content: var_content,
+            source_turn: var_sourceTurn,
+            summary_ids: var_summaryIds,
+        };
+    }
+}
+
+impl SseDecode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<String>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::data_models::BackgroundTaskEvent> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::data_models::BackgroundTaskEvent>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::data_models::BackupSnapshotInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::data_models::BackupSnapshotInfo>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::data_models::BlockedTopic> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::data_models::BlockedTopic>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::data_models::BranchInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::data_models::BranchInfo>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::data_models::CharacterRelation> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::data_models::CharacterRelation>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::data_models::ConversationBranch> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::data_models::ConversationBranch>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::data_models::ConversationSummary> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::data_models::ConversationSummary>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::data_models::DeferredJob> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::data_models::DeferredJob>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::data_models::DegradationRecord> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::data_models::DegradationRecord>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::data_models::DigestEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::data_models::DigestEntry>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::data_models::EmotionalTurnInsight> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::data_models::EmotionalTurnInsight>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::knowledge_store::Fact> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::knowledge_store::Fact>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::data_models::FactConflict> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::data_models::FactConflict>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::data_models::FingerprintInsight> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::data_models::FingerprintInsight>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::data_models::GraphEdge> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::data_models::GraphEdge>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::data_models::GraphNode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::data_models::GraphNode>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::data_models::GroupCharacter> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::data_models::GroupCharacter>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::data_models::IntegrityIssue> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::data_models::IntegrityIssue>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::data_models::LanguagePatternKind> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::data_models::LanguagePatternKind>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::data_models::LexiconEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::data_models::LexiconEntry>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::data_models::LinkedFact> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::data_models::LinkedFact>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::data_models::LoreEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::data_models::LoreEntry>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::data_models::MemoryFidelityReport> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::data_models::MemoryFidelityReport>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::data_models::MemorySearchResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::data_models::MemorySearchResult>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::data_models::MemorySummary> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::data_models::MemorySummary>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::data_models::MemoryTier> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::data_models::MemoryTier>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::data_models::Message> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::data_models::Message>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::data_models::MessageAlternative> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::data_models::MessageAlternative>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::data_models::MessageAttachment> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::data_models::MessageAttachment>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::data_models::MessageEdit> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::data_models::MessageEdit>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::data_models::ModelInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::data_models::ModelInfo>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::data_models::OutboxEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::data_models::OutboxEntry>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::data_models::PhaseDuration> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::data_models::PhaseDuration>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<u8> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<u8>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::data_models::PromptTemplateInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::data_models::PromptTemplateInfo>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<(String, String)> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<(String, String)>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::data_models::RelationChange> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::data_models::RelationChange>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::data_models::SafetyRule> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::data_models::SafetyRule>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::data_models::SayDoRule> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::data_models::SayDoRule>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::data_models::SearchHit> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::data_models::SearchHit>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::data_models::ShadowEvalRecord> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::data_models::ShadowEvalRecord>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::data_models::TimelineEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::data_models::TimelineEntry>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::data_models::TurnMetrics> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::data_models::TurnMetrics>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::data_models::UserPersona> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::data_models::UserPersona>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::api::data_models::LoreEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_title = <String>::sse_decode(deserializer);
+        let mut var_content = <String>::sse_decode(deserializer);
+        let mut var_keywords = <Vec<String>>::sse_decode(deserializer);
+        let mut var_insertionDepth = <u32>::sse_decode(deserializer);
+        let mut var_priority = <i32>::sse_decode(deserializer);
+        let mut var_enabled = <bool>::sse_decode(deserializer);
+        return crate::api::data_models::LoreEntry {
+            id: var_id,
+            title: var_title,
+            content: var_content,
+            keywords: var_keywords,
+            insertion_depth: var_insertionDepth,
+            priority: var_priority,
+            enabled: var_enabled,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::MemoryContextCard {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_sourceRange = <String>::sse_decode(deserializer);
+        let mut var_topicTags = <Vec<String>>::sse_decode(deserializer);
+        let mut var_keyEntities = <Vec<String>>::sse_decode(deserializer);
+        let mut var_emotionalTone = <String>::sse_decode(deserializer);
+        let mut var_causalLinks = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::data_models::MemoryContextCard {
+            source_range: var_sourceRange,
+            topic_tags: var_topicTags,
+            key_entities: var_keyEntities,
+            emotional_tone: var_emotionalTone,
+            causal_links: var_causalLinks,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::MemoryExplanation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_summaryId = <String>::sse_decode(deserializer);
+        let mut var_summary = <String>::sse_decode(deserializer);
+        let mut var_turnRangeStart = <u32>::sse_decode(deserializer);
+        let mut var_turnRangeEnd = <u32>::sse_decode(deserializer);
+        let mut var_compressionGeneration = <u32>::sse_decode(deserializer);
+        let mut var_linkedFacts =
+            <Vec<crate::api::data_models::LinkedFact>>::sse_decode(deserializer);
+        return crate::api::data_models::MemoryExplanation {
+            summary_id: var_summaryId,
+            summary: var_summary,
+            turn_range_start: var_turnRangeStart,
+            turn_range_end: var_turnRangeEnd,
+            compression_generation: var_compressionGeneration,
+            linked_facts: var_linkedFacts,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::MemoryFidelityReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_conversationId = <String>::sse_decode(deserializer);
+        let mut var_score = <f64>::sse_decode(deserializer);
+        let mut var_sampledFacts = <u32>::sse_decode(deserializer);
+        let mut var_lostFacts = <Vec<String>>::sse_decode(deserializer);
+        let mut var_previousScore = <Option<f64>>::sse_decode(deserializer);
+        let mut var_alert = <bool>::sse_decode(deserializer);
+        let mut var_auditedAt = <i64>::sse_decode(deserializer);
+        return crate::api::data_models::MemoryFidelityReport {
+            conversation_id: var_conversationId,
+            score: var_score,
+            sampled_facts: var_sampledFacts,
+            lost_facts: var_lostFacts,
+            previous_score: var_previousScore,
+            alert: var_alert,
+            audited_at: var_auditedAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::MemorySearchResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_summary = <String>::sse_decode(deserializer);
+        let mut var_coreFacts = <Vec<String>>::sse_decode(deserializer);
+        let mut var_relevanceScore = <f64>::sse_decode(deserializer);
+        return crate::api::data_models::MemorySearchResult {
+            summary: var_summary,
+            core_facts: var_coreFacts,
+            relevance_score: var_relevanceScore,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::MemorySummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_summary = <String>::sse_decode(deserializer);
+        let mut var_coreFacts = <Vec<String>>::sse_decode(deserializer);
+        let mut var_turnRangeStart = <u32>::sse_decode(deserializer);
+        let mut var_turnRangeEnd = <u32>::sse_decode(deserializer);
+        let mut var_createdAt = <i64>::sse_decode(deserializer);
+        let mut var_keywords = <Vec<String>>::sse_decode(deserializer);
+        let mut var_compressionGeneration = <u32>::sse_decode(deserializer);
+        let mut var_contextCard =
+            <Option<crate::api::data_models::MemoryContextCard>>::sse_decode(deserializer);
+        let mut var_factTiers =
+            <Vec<crate::api::data_models::MemoryTier>>::sse_decode(deserializer);
+        let mut var_linkedFactIds = <Vec<String>>::sse_decode(deserializer);
+        let mut var_pinned = <bool>::sse_decode(deserializer);
+        let mut var_pinnedFacts = <Vec<String>>::sse_decode(deserializer);
+        let mut var_stale = <bool>::sse_decode(deserializer);
+        return crate::api::data_models::MemorySummary {
+            id: var_id,
+            summary: var_summary,
+            core_facts: var_coreFacts,
+            turn_range_start: var_turnRangeStart,
+            turn_range_end: var_turnRangeEnd,
+            created_at: var_createdAt,
+            keywords: var_keywords,
+            compression_generation: var_compressionGeneration,
+            context_card: var_contextCard,
+            fact_tiers: var_factTiers,
+            linked_fact_ids: var_linkedFactIds,
+            pinned: var_pinned,
+            pinned_facts: var_pinnedFacts,
+            stale: var_stale,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::MemoryTier {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::data_models::MemoryTier::Identity,
+            1 => crate::api::data_models::MemoryTier::CriticalEvent,
+            2 => crate::api::data_models::MemoryTier::RelationshipDynamic,
+            3 => crate::api::data_models::MemoryTier::CurrentState,
+            4 => crate::api::data_models::MemoryTier::SceneDetail,
+            _ => unreachable!("Invalid variant for MemoryTier: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::Message {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_role = <crate::api::data_models::MessageRole>::sse_decode(deserializer);
+        let mut var_content = <String>::sse_decode(deserializer);
+        let mut var_thinkingContent = <Option<String>>::sse_decode(deserializer);
+        let mut var_model = <String>::sse_decode(deserializer);
+        let mut var_timestamp = <i64>::sse_decode(deserializer);
+        let mut var_messageType = <crate::api::data_models::MessageType>::sse_decode(deserializer);
+        let mut var_generationMetadata =
+            <Option<crate::api::data_models::GenerationMetadata>>::sse_decode(deserializer);
+        let mut var_characterId = <Option<String>>::sse_decode(deserializer);
+        let mut var_attachments =
+            <Vec<crate::api::data_models::MessageAttachment>>::sse_decode(deserializer);
+        let mut var_audioPath = <Option<String>>::sse_decode(deserializer);
+        let mut var_alternatives =
+            <Vec<crate::api::data_models::MessageAlternative>>::sse_decode(deserializer);
+        let mut var_selectedAlternative = <u32>::sse_decode(deserializer);
+        let mut var_starred = <bool>::sse_decode(deserializer);
+        let mut var_reactions = <Vec<String>>::sse_decode(deserializer);
+        let mut var_editHistory =
+            <Vec<crate::api::data_models::MessageEdit>>::sse_decode(deserializer);
+        return crate::api::data_models::Message {
+            id: var_id,
+            role: var_role,
+            content: var_content,
+            thinking_content: var_thinkingContent,
+            model: var_model,
+            timestamp: var_timestamp,
+            message_type: var_messageType,
+            generation_metadata: var_generationMetadata,
+            character_id: var_characterId,
+            attachments: var_attachments,
+            audio_path: var_audioPath,
+            alternatives: var_alternatives,
+            selected_alternative: var_selectedAlternative,
+            starred: var_starred,
+            reactions: var_reactions,
+            edit_history: var_editHistory,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::MessageAlternative {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_content = <String>::sse_decode(deserializer);
+        let mut var_thinkingContent = <Option<String>>::sse_decode(deserializer);
+        let mut var_model = <String>::sse_decode(deserializer);
+        let mut var_timestamp = <i64>::sse_decode(deserializer);
+        let mut var_generationMetadata =
+            <Option<crate::api::data_models::GenerationMetadata>>::sse_decode(deserializer);
+        let mut var_audioPath = <Option<String>>::sse_decode(deserializer);
+        return crate::api::data_models::MessageAlternative {
+            content: var_content,
+            thinking_content: var_thinkingContent,
+            model: var_model,
+            timestamp: var_timestamp,
+            generation_metadata: var_generationMetadata,
+            audio_path: var_audioPath,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::MessageAttachment {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_mimeType = <String>::sse_decode(deserializer);
+        let mut var_url = <Option<String>>::sse_decode(deserializer);
+        let mut var_caption = <Option<String>>::sse_decode(deserializer);
+        return crate::api::data_models::MessageAttachment {
+            id: var_id,
+            mime_type: var_mimeType,
+            url: var_url,
+            caption: var_caption,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::MessageEdit {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_content = <String>::sse_decode(deserializer);
+        let mut var_timestamp = <i64>::sse_decode(deserializer);
+        let mut var_editedAt = <i64>::sse_decode(deserializer);
+        return crate::api::data_models::MessageEdit {
+            content: var_content,
+            timestamp: var_timestamp,
+            edited_at: var_editedAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::MessageRole {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::data_models::MessageRole::User,
+            1 => crate::api::data_models::MessageRole::Assistant,
+            2 => crate::api::data_models::MessageRole::System,
+            _ => unreachable!("Invalid variant for MessageRole: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::MessageType {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::data_models::MessageType::Say,
+            1 => crate::api::data_models::MessageType::Do,
+            2 => crate::api::data_models::MessageType::Mixed,
+            3 => crate::api::data_models::MessageType::Document,
+            4 => crate::api::data_models::MessageType::Narration,
+            5 => crate::api::data_models::MessageType::OutOfCharacter,
+            _ => unreachable!("Invalid variant for MessageType: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::MetricPhase {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::data_models::MetricPhase::Retrieval,
+            1 => crate::api::data_models::MetricPhase::Distillation,
+            2 => crate::api::data_models::MetricPhase::Reasoning,
+            3 => crate::api::data_models::MetricPhase::Chat,
+            _ => unreachable!("Invalid variant for MetricPhase: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::MetricsSnapshot {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_turns = <Vec<crate::api::data_models::TurnMetrics>>::sse_decode(deserializer);
+        let mut var_avgFirstTokenMs = <Option<i64>>::sse_decode(deserializer);
+        let mut var_avgPhaseMs =
+            <Vec<crate::api::data_models::PhaseDuration>>::sse_decode(deserializer);
+        let mut var_totalRetries = <u32>::sse_decode(deserializer);
+        return crate::api::data_models::MetricsSnapshot {
+            turns: var_turns,
+            avg_first_token_ms: var_avgFirstTokenMs,
+            avg_phase_ms: var_avgPhaseMs,
+            total_retries: var_totalRetries,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::ModelInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_contextTokens = <usize>::sse_decode(deserializer);
+        let mut var_maxOutputTokens = <usize>::sse_decode(deserializer);
+        let mut var_supportsThinking = <bool>::sse_decode(deserializer);
+        return crate::api::data_models::ModelInfo {
+            id: var_id,
+            name: var_name,
+            context_tokens: var_contextTokens,
+            max_output_tokens: var_maxOutputTokens,
+            supports_thinking: var_supportsThinking,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::MoodState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_valence = <f64>::sse_decode(deserializer);
+        let mut var_arousal = <f64>::sse_decode(deserializer);
+        let mut var_mood = <String>::sse_decode(deserializer);
+        let mut var_updatedAt = <i64>::sse_decode(deserializer);
+        return crate::api::data_models::MoodState {
+            valence: var_valence,
+            arousal: var_arousal,
+            mood: var_mood,
+            updated_at: var_updatedAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::NarrationPerspective {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::data_models::NarrationPerspective::Free,
+            1 => crate::api::data_models::NarrationPerspective::FirstPerson,
+            2 => crate::api::data_models::NarrationPerspective::ThirdPerson,
+            _ => unreachable!("Invalid variant for NarrationPerspective: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<String>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::data_models::AmbientContext> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::data_models::AmbientContext>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::data_models::BackupSnapshotInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::data_models::BackupSnapshotInfo>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<bool>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::data_models::CheckInSchedule> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::data_models::CheckInSchedule>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::data_models::CognitiveInsight> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::data_models::CognitiveInsight>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::data_models::Conversation> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::data_models::Conversation>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::data_models::ConversationInsights> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::data_models::ConversationInsights>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::data_models::ConversationOrganization> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <crate::api::data_models::ConversationOrganization>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::data_models::DailyDigest> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::data_models::DailyDigest>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::data_models::DistilledSystemState> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::data_models::DistilledSystemState>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::data_models::EncryptionKeySource> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::data_models::EncryptionKeySource>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<f64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::knowledge_store::Fact> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::knowledge_store::Fact>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::data_models::GenerationMetadata> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::data_models::GenerationMetadata>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::data_models::GroupChat> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::data_models::GroupChat>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<i64>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::data_models::IntegrityReport> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::data_models::IntegrityReport>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::data_models::LayoutVerification> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::data_models::LayoutVerification>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::data_models::LoreEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::data_models::LoreEntry>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::data_models::MemoryContextCard> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::data_models::MemoryContextCard>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::data_models::MemoryExplanation> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::data_models::MemoryExplanation>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::data_models::MemoryFidelityReport> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::data_models::MemoryFidelityReport>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::data_models::MemorySummary> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::data_models::MemorySummary>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::data_models::Message> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::data_models::Message>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::data_models::MoodState> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::data_models::MoodState>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::data_models::PreflightAdvisory> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::data_models::PreflightAdvisory>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::data_models::ProxySettings> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::data_models::ProxySettings>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::data_models::ReengagementBrief> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::data_models::ReengagementBrief>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::data_models::RelevanceInsight> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::data_models::RelevanceInsight>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::data_models::SayDoMark> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::data_models::SayDoMark>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::data_models::SummarizationConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<crate::api::data_models::SummarizationConfig>::sse_decode(
+                deserializer,
+            ));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for crate::api::data_models::OutboxEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_conversationId = <String>::sse_decode(deserializer);
+        let mut var_content = <String>::sse_decode(deserializer);
+        let mut var_attachments =
+            <Vec<crate::api::data_models::MessageAttachment>>::sse_decode(deserializer);
+        let mut var_model = <String>::sse_decode(deserializer);
+        let mut var_enableThinking = <bool>::sse_decode(deserializer);
+        let mut var_status = <crate::api::data_models::OutboxStatus>::sse_decode(deserializer);
+        let mut var_queuedAt = <i64>::sse_decode(deserializer);
+        let mut var_lastError = <Option<String>>::sse_decode(deserializer);
+        return crate::api::data_models::OutboxEntry {
+            id: var_id,
+            conversation_id: var_conversationId,
+            content: var_content,
+            attachments: var_attachments,
             model: var_model,
+            enable_thinking: var_enableThinking,
+            status: var_status,
+            queued_at: var_queuedAt,
+            last_error: var_lastError,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::OutboxStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::data_models::OutboxStatus::Queued,
+            1 => crate::api::data_models::OutboxStatus::Sending,
+            2 => crate::api::data_models::OutboxStatus::Failed,
+            _ => unreachable!("Invalid variant for OutboxStatus: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::PhaseDuration {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_phase = <crate::api::data_models::MetricPhase>::sse_decode(deserializer);
+        let mut var_durationMs = <i64>::sse_decode(deserializer);
+        return crate::api::data_models::PhaseDuration {
+            phase: var_phase,
+            duration_ms: var_durationMs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::PreflightAdvisory {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_tone = <crate::api::data_models::DraftTone>::sse_decode(deserializer);
+        let mut var_regretRisk = <f64>::sse_decode(deserializer);
+        let mut var_tensionDelta = <f64>::sse_decode(deserializer);
+        let mut var_closenessDelta = <f64>::sse_decode(deserializer);
+        let mut var_lateNight = <bool>::sse_decode(deserializer);
+        let mut var_suggestPause = <bool>::sse_decode(deserializer);
+        let mut var_notes = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::data_models::PreflightAdvisory {
+            tone: var_tone,
+            regret_risk: var_regretRisk,
+            tension_delta: var_tensionDelta,
+            closeness_delta: var_closenessDelta,
+            late_night: var_lateNight,
+            suggest_pause: var_suggestPause,
+            notes: var_notes,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::PromptTemplateInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_description = <String>::sse_decode(deserializer);
+        let mut var_variables = <Vec<String>>::sse_decode(deserializer);
+        let mut var_customized = <bool>::sse_decode(deserializer);
+        return crate::api::data_models::PromptTemplateInfo {
+            name: var_name,
+            description: var_description,
+            variables: var_variables,
+            customized: var_customized,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::ProviderKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::data_models::ProviderKind::Zhipu,
+            1 => crate::api::data_models::ProviderKind::LocalEcho,
+            2 => crate::api::data_models::ProviderKind::OpenAiCompatible,
+            3 => crate::api::data_models::ProviderKind::Anthropic,
+            4 => crate::api::data_models::ProviderKind::LocalLlm,
+            _ => unreachable!("Invalid variant for ProviderKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::ProxySettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_url = <String>::sse_decode(deserializer);
+        let mut var_username = <Option<String>>::sse_decode(deserializer);
+        let mut var_password = <Option<String>>::sse_decode(deserializer);
+        let mut var_bypass = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::data_models::ProxySettings {
+            url: var_url,
+            username: var_username,
+            password: var_password,
+            bypass: var_bypass,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::ReactionEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_emoji = <String>::sse_decode(deserializer);
+        let mut var_label = <String>::sse_decode(deserializer);
+        return crate::api::data_models::ReactionEvent {
+            emoji: var_emoji,
+            label: var_label,
+        };
+    }
+}
+
+impl SseDecode for (String, String) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_field0 = <String>::sse_decode(deserializer);
+        let mut var_field1 = <String>::sse_decode(deserializer);
+        return (var_field0, var_field1);
+    }
+}
+
+impl SseDecode for crate::api::data_models::ReengagementBrief {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_conversationId = <String>::sse_decode(deserializer);
+        let mut var_elapsedDays = <u32>::sse_decode(deserializer);
+        let mut var_recap = <String>::sse_decode(deserializer);
+        let mut var_opener = <String>::sse_decode(deserializer);
+        let mut var_pendingThreads = <Vec<String>>::sse_decode(deserializer);
+        let mut var_generatedAt = <i64>::sse_decode(deserializer);
+        let mut var_lastMessageId = <String>::sse_decode(deserializer);
+        return crate::api::data_models::ReengagementBrief {
+            conversation_id: var_conversationId,
+            elapsed_days: var_elapsedDays,
+            recap: var_recap,
+            opener: var_opener,
+            pending_threads: var_pendingThreads,
+            generated_at: var_generatedAt,
+            last_message_id: var_lastMessageId,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::ReindexProgress {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_conversationId = <String>::sse_decode(deserializer);
+        let mut var_completed = <u32>::sse_decode(deserializer);
+        let mut var_total = <u32>::sse_decode(deserializer);
+        let mut var_rebuiltEntries = <u32>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        return crate::api::data_models::ReindexProgress {
+            conversation_id: var_conversationId,
+            completed: var_completed,
+            total: var_total,
+            rebuilt_entries: var_rebuiltEntries,
+            error: var_error,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::RelationChange {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_relation = <String>::sse_decode(deserializer);
+        let mut var_factId = <String>::sse_decode(deserializer);
+        let mut var_sourceTurn = <u32>::sse_decode(deserializer);
+        return crate::api::data_models::RelationChange {
+            relation: var_relation,
+            fact_id: var_factId,
+            source_turn: var_sourceTurn,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::RelationSentiment {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::data_models::RelationSentiment::Positive,
+            1 => crate::api::data_models::RelationSentiment::Negative,
+            2 => crate::api::data_models::RelationSentiment::Neutral,
+            _ => unreachable!("Invalid variant for RelationSentiment: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::RelationshipInsight {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_closeness = <f64>::sse_decode(deserializer);
+        let mut var_trustLevel = <f64>::sse_decode(deserializer);
+        let mut var_tension = <f64>::sse_decode(deserializer);
+        let mut var_powerBalance = <f64>::sse_decode(deserializer);
+        let mut var_trend = <f64>::sse_decode(deserializer);
+        return crate::api::data_models::RelationshipInsight {
+            closeness: var_closeness,
+            trust_level: var_trustLevel,
+            tension: var_tension,
+            power_balance: var_powerBalance,
+            trend: var_trend,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::RelevanceInsight {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_tfidfScore = <f64>::sse_decode(deserializer);
+        let mut var_keywordOverlap = <f64>::sse_decode(deserializer);
+        let mut var_topicMatch = <f64>::sse_decode(deserializer);
+        let mut var_finalScore = <f64>::sse_decode(deserializer);
+        return crate::api::data_models::RelevanceInsight {
+            tfidf_score: var_tfidfScore,
+            keyword_overlap: var_keywordOverlap,
+            topic_match: var_topicMatch,
+            final_score: var_finalScore,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::ReplyLength {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::data_models::ReplyLength::Auto,
+            1 => crate::api::data_models::ReplyLength::Short,
+            2 => crate::api::data_models::ReplyLength::Medium,
+            3 => crate::api::data_models::ReplyLength::Long,
+            _ => unreachable!("Invalid variant for ReplyLength: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::ReplySignals {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_lengthChars = <u32>::sse_decode(deserializer);
+        let mut var_groundedFacts = <u32>::sse_decode(deserializer);
+        let mut var_userOverlap = <f64>::sse_decode(deserializer);
+        let mut var_repetition = <f64>::sse_decode(deserializer);
+        return crate::api::data_models::ReplySignals {
+            length_chars: var_lengthChars,
+            grounded_facts: var_groundedFacts,
+            user_overlap: var_userOverlap,
+            repetition: var_repetition,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::ResponseStyle {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_replyLength = <crate::api::data_models::ReplyLength>::sse_decode(deserializer);
+        let mut var_formality = <crate::api::data_models::Formality>::sse_decode(deserializer);
+        let mut var_emojiUsage = <crate::api::data_models::EmojiUsage>::sse_decode(deserializer);
+        let mut var_actionFrequency =
+            <crate::api::data_models::ActionFrequency>::sse_decode(deserializer);
+        return crate::api::data_models::ResponseStyle {
+            reply_length: var_replyLength,
+            formality: var_formality,
+            emoji_usage: var_emojiUsage,
+            action_frequency: var_actionFrequency,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::RetentionPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_archiveAfterIdleDays = <u32>::sse_decode(deserializer);
+        let mut var_maxMessagesPerConversation = <u32>::sse_decode(deserializer);
+        let mut var_purgeOrphans = <bool>::sse_decode(deserializer);
+        return crate::api::data_models::RetentionPolicy {
+            archive_after_idle_days: var_archiveAfterIdleDays,
+            max_messages_per_conversation: var_maxMessagesPerConversation,
+            purge_orphans: var_purgeOrphans,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::RetryPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_maxRetries = <u32>::sse_decode(deserializer);
+        let mut var_initialDelayMs = <u64>::sse_decode(deserializer);
+        let mut var_maxDelayMs = <u64>::sse_decode(deserializer);
+        let mut var_backoff = <crate::api::data_models::BackoffStrategy>::sse_decode(deserializer);
+        let mut var_retryNetworkErrors = <bool>::sse_decode(deserializer);
+        let mut var_retryStreamErrors = <bool>::sse_decode(deserializer);
+        let mut var_retryServerErrors = <bool>::sse_decode(deserializer);
+        let mut var_retryRateLimited = <bool>::sse_decode(deserializer);
+        let mut var_maxRetryAfterSecs = <u64>::sse_decode(deserializer);
+        return crate::api::data_models::RetryPolicy {
+            max_retries: var_maxRetries,
+            initial_delay_ms: var_initialDelayMs,
+            max_delay_ms: var_maxDelayMs,
+            backoff: var_backoff,
+            retry_network_errors: var_retryNetworkErrors,
+            retry_stream_errors: var_retryStreamErrors,
+            retry_server_errors: var_retryServerErrors,
+            retry_rate_limited: var_retryRateLimited,
+            max_retry_after_secs: var_maxRetryAfterSecs,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::SafetyAction {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::data_models::SafetyAction::Block,
+            1 => crate::api::data_models::SafetyAction::Soften,
+            2 => crate::api::data_models::SafetyAction::Warn,
+            _ => unreachable!("Invalid variant for SafetyAction: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::SafetyCategory {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::data_models::SafetyCategory::SelfHarm,
+            1 => crate::api::data_models::SafetyCategory::Violence,
+            2 => crate::api::data_models::SafetyCategory::Sexual,
+            3 => crate::api::data_models::SafetyCategory::Hate,
+            4 => crate::api::data_models::SafetyCategory::Illegal,
+            _ => unreachable!("Invalid variant for SafetyCategory: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::SafetyEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_stage = <crate::api::data_models::SafetyStage>::sse_decode(deserializer);
+        let mut var_category = <crate::api::data_models::SafetyCategory>::sse_decode(deserializer);
+        let mut var_action = <crate::api::data_models::SafetyAction>::sse_decode(deserializer);
+        let mut var_matched = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::data_models::SafetyEvent {
+            stage: var_stage,
+            category: var_category,
+            action: var_action,
+            matched: var_matched,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::SafetyPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_enabled = <bool>::sse_decode(deserializer);
+        let mut var_checkInput = <bool>::sse_decode(deserializer);
+        let mut var_checkOutput = <bool>::sse_decode(deserializer);
+        let mut var_rules = <Vec<crate::api::data_models::SafetyRule>>::sse_decode(deserializer);
+        return crate::api::data_models::SafetyPolicy {
+            enabled: var_enabled,
+            check_input: var_checkInput,
+            check_output: var_checkOutput,
+            rules: var_rules,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::SafetyRule {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_category = <crate::api::data_models::SafetyCategory>::sse_decode(deserializer);
+        let mut var_action = <crate::api::data_models::SafetyAction>::sse_decode(deserializer);
+        let mut var_extraTerms = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::data_models::SafetyRule {
+            category: var_category,
+            action: var_action,
+            extra_terms: var_extraTerms,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::SafetyStage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::data_models::SafetyStage::Input,
+            1 => crate::api::data_models::SafetyStage::Output,
+            _ => unreachable!("Invalid variant for SafetyStage: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::SayDoMark {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::data_models::SayDoMark::Say,
+            1 => crate::api::data_models::SayDoMark::Do,
+            _ => unreachable!("Invalid variant for SayDoMark: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::SayDoRule {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_pattern = <String>::sse_decode(deserializer);
+        let mut var_mark = <crate::api::data_models::SayDoMark>::sse_decode(deserializer);
+        let mut var_priority = <i32>::sse_decode(deserializer);
+        return crate::api::data_models::SayDoRule {
+            pattern: var_pattern,
+            mark: var_mark,
+            priority: var_priority,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::SayDoRuleSet {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_rules = <Vec<crate::api::data_models::SayDoRule>>::sse_decode(deserializer);
+        let mut var_unmatched =
+            <Option<crate::api::data_models::SayDoMark>>::sse_decode(deserializer);
+        return crate::api::data_models::SayDoRuleSet {
+            rules: var_rules,
+            unmatched: var_unmatched,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::SearchHit {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_conversationId = <String>::sse_decode(deserializer);
+        let mut var_conversationTitle = <String>::sse_decode(deserializer);
+        let mut var_messageId = <String>::sse_decode(deserializer);
+        let mut var_role = <crate::api::data_models::MessageRole>::sse_decode(deserializer);
+        let mut var_snippet = <String>::sse_decode(deserializer);
+        let mut var_highlightStart = <u32>::sse_decode(deserializer);
+        let mut var_highlightEnd = <u32>::sse_decode(deserializer);
+        let mut var_timestamp = <i64>::sse_decode(deserializer);
+        return crate::api::data_models::SearchHit {
+            conversation_id: var_conversationId,
+            conversation_title: var_conversationTitle,
+            message_id: var_messageId,
+            role: var_role,
+            snippet: var_snippet,
+            highlight_start: var_highlightStart,
+            highlight_end: var_highlightEnd,
+            timestamp: var_timestamp,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::ShadowEvalRecord {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_turn = <u32>::sse_decode(deserializer);
+        let mut var_injectedFacts = <u32>::sse_decode(deserializer);
+        let mut var_mainModel = <String>::sse_decode(deserializer);
+        let mut var_shadowModel = <String>::sse_decode(deserializer);
+        let mut var_main = <crate::api::data_models::ReplySignals>::sse_decode(deserializer);
+        let mut var_shadow = <crate::api::data_models::ReplySignals>::sse_decode(deserializer);
+        let mut var_createdAt = <i64>::sse_decode(deserializer);
+        return crate::api::data_models::ShadowEvalRecord {
+            turn: var_turn,
+            injected_facts: var_injectedFacts,
+            main_model: var_mainModel,
+            shadow_model: var_shadowModel,
+            main: var_main,
+            shadow: var_shadow,
+            created_at: var_createdAt,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::ShadowEvalSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_samples = <u32>::sse_decode(deserializer);
+        let mut var_avgGroundedGain = <f64>::sse_decode(deserializer);
+        let mut var_avgOverlapGain = <f64>::sse_decode(deserializer);
+        let mut var_avgRepetitionDelta = <f64>::sse_decode(deserializer);
+        let mut var_groundedWinRate = <f64>::sse_decode(deserializer);
+        return crate::api::data_models::ShadowEvalSummary {
+            samples: var_samples,
+            avg_grounded_gain: var_avgGroundedGain,
+            avg_overlap_gain: var_avgOverlapGain,
+            avg_repetition_delta: var_avgRepetitionDelta,
+            grounded_win_rate: var_groundedWinRate,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::ShortTermInsight {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_activeTopics = <Vec<String>>::sse_decode(deserializer);
+        let mut var_emotionalArc =
+            <Vec<crate::api::data_models::EmotionalTurnInsight>>::sse_decode(deserializer);
+        let mut var_pendingThreads = <Vec<String>>::sse_decode(deserializer);
+        let mut var_responseFingerprints =
+            <Vec<crate::api::data_models::FingerprintInsight>>::sse_decode(deserializer);
+        return crate::api::data_models::ShortTermInsight {
+            active_topics: var_activeTopics,
+            emotional_arc: var_emotionalArc,
+            pending_threads: var_pendingThreads,
+            response_fingerprints: var_responseFingerprints,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::SummarizationConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_intervalTurns = <u32>::sse_decode(deserializer);
+        let mut var_windowMessages = <u32>::sse_decode(deserializer);
+        return crate::api::data_models::SummarizationConfig {
+            interval_turns: var_intervalTurns,
+            window_messages: var_windowMessages,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::SyncBackendKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::data_models::SyncBackendKind::WebDav,
+            1 => crate::api::data_models::SyncBackendKind::S3,
+            _ => unreachable!("Invalid variant for SyncBackendKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::SyncReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_pushed = <u32>::sse_decode(deserializer);
+        let mut var_pulled = <u32>::sse_decode(deserializer);
+        let mut var_deleted = <u32>::sse_decode(deserializer);
+        let mut var_conflicts = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::data_models::SyncReport {
+            pushed: var_pushed,
+            pulled: var_pulled,
+            deleted: var_deleted,
+            conflicts: var_conflicts,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::SyncSettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_backend = <crate::api::data_models::SyncBackendKind>::sse_decode(deserializer);
+        let mut var_endpoint = <String>::sse_decode(deserializer);
+        let mut var_username = <String>::sse_decode(deserializer);
+        let mut var_password = <String>::sse_decode(deserializer);
+        let mut var_bucket = <String>::sse_decode(deserializer);
+        let mut var_region = <String>::sse_decode(deserializer);
+        let mut var_remoteRoot = <String>::sse_decode(deserializer);
+        return crate::api::data_models::SyncSettings {
+            backend: var_backend,
+            endpoint: var_endpoint,
+            username: var_username,
+            password: var_password,
+            bucket: var_bucket,
+            region: var_region,
+            remote_root: var_remoteRoot,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::ThinkingRetention {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::data_models::ThinkingRetention::KeepAll;
+            }
+            1 => {
+                let mut var_field0 = <u32>::sse_decode(deserializer);
+                return crate::api::data_models::ThinkingRetention::KeepLastTurns(var_field0);
+            }
+            2 => {
+                return crate::api::data_models::ThinkingRetention::DiscardAfterSummary;
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::data_models::TimelineEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_kind = <crate::api::data_models::TimelineEntryKind>::sse_decode(deserializer);
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_turnStart = <u32>::sse_decode(deserializer);
+        let mut var_turnEnd = <u32>::sse_decode(deserializer);
+        let mut var_timestamp = <i64>::sse_decode(deserializer);
+        let mut var_endTimestamp = <i64>::sse_decode(deserializer);
+        return crate::api::data_models::TimelineEntry {
+            id: var_id,
+            kind: var_kind,
+            text: var_text,
+            turn_start: var_turnStart,
+            turn_end: var_turnEnd,
+            timestamp: var_timestamp,
+            end_timestamp: var_endTimestamp,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::TimelineEntryKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::data_models::TimelineEntryKind::Chapter,
+            1 => crate::api::data_models::TimelineEntryKind::Event,
+            _ => unreachable!("Invalid variant for TimelineEntryKind: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::TurnMetrics {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_conversationId = <String>::sse_decode(deserializer);
+        let mut var_startedAt = <i64>::sse_decode(deserializer);
+        let mut var_totalMs = <i64>::sse_decode(deserializer);
+        let mut var_firstTokenMs = <Option<i64>>::sse_decode(deserializer);
+        let mut var_phases =
+            <Vec<crate::api::data_models::PhaseDuration>>::sse_decode(deserializer);
+        let mut var_retries = <u32>::sse_decode(deserializer);
+        return crate::api::data_models::TurnMetrics {
+            conversation_id: var_conversationId,
+            started_at: var_startedAt,
+            total_ms: var_totalMs,
+            first_token_ms: var_firstTokenMs,
+            phases: var_phases,
+            retries: var_retries,
+        };
+    }
+}
+
+impl SseDecode for crate::api::data_models::TurnPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::data_models::TurnPolicy::RoundRobin,
+            1 => crate::api::data_models::TurnPolicy::ModelChosen,
+            _ => unreachable!("Invalid variant for TurnPolicy: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap()
+    }
+}
+
+impl SseDecode for u8 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u8().unwrap()
+    }
+}
+
+impl SseDecode for () {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+}
+
+impl SseDecode for crate::api::data_models::UserPersona {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_id = <String>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_description = <String>::sse_decode(deserializer);
+        let mut var_pronouns = <String>::sse_decode(deserializer);
+        let mut var_createdAt = <i64>::sse_decode(deserializer);
+        return crate::api::data_models::UserPersona {
+            id: var_id,
+            name: var_name,
+            description: var_description,
+            pronouns: var_pronouns,
             created_at: var_createdAt,
-            updated_at: var_updatedAt,
-            dialogue_style: var_dialogueStyle,
-            turn_count: var_turnCount,
-            memory_summaries: var_memorySummaries,
         };
     }
 }
 
-impl SseDecode for crate::api::data_models::ConversationSummary {
+impl SseDecode for usize {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    }
+}
+
+fn pde_ffi_dispatcher_primary_impl(
+    func_id: i32,
+    port: flutter_rust_bridge::for_generated::MessagePort,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        1 => wire__crate__api__data_models__action_frequency_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        2 => wire__crate__api__chat_api__add_assistant_message_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        3 => wire__crate__api__chat_api__add_manual_fact_impl(port, ptr, rust_vec_len, data_len),
+        4 => wire__crate__api__chat_api__add_system_message_impl(port, ptr, rust_vec_len, data_len),
+        5 => wire__crate__api__chat_api__add_user_authored_reply_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        6 => wire__crate__api__data_models__ambient_context_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        7 => {
+            wire__crate__api__chat_api__analyze_conversation_impl(port, ptr, rust_vec_len, data_len)
+        }
+        8 => wire__crate__api__data_models__app_settings_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        9 => wire__crate__api__chat_api__approve_memory_summary_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        10 => wire__crate__api__chat_api__audit_all_memory_fidelity_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        11 => wire__crate__api__chat_api__audit_memory_fidelity_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        12 => wire__crate__api__data_models__backoff_strategy_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        13 => wire__crate__api__data_models__backup_retention_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        14 => wire__crate__api__data_models__backup_scope_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        15 => wire__crate__api__data_models__blocking_stats_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        16 => wire__crate__api__chat_api__clear_logs_impl(port, ptr, rust_vec_len, data_len),
+        17 => wire__crate__api__chat_api__clear_metrics_impl(port, ptr, rust_vec_len, data_len),
+        18 => {
+            wire__crate__api__chat_api__close_conversation_impl(port, ptr, rust_vec_len, data_len)
+        }
+        19 => wire__crate__api__chat_api__close_sandbox_conversation_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        20 => wire__crate__api__data_models__content_intensity_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        21 => wire__crate__api__data_models__context_layers_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        22 => wire__crate__api__data_models__conversation_filter_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        23 => wire__crate__api__data_models__conversation_mode_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        24 => wire__crate__api__data_models__conversation_organization_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        25 => wire__crate__api__chat_api__create_backup_impl(port, ptr, rust_vec_len, data_len),
+        26 => wire__crate__api__chat_api__create_branch_impl(port, ptr, rust_vec_len, data_len),
+        27 => {
+            wire__crate__api__chat_api__create_conversation_impl(port, ptr, rust_vec_len, data_len)
+        }
+        28 => wire__crate__api__chat_api__create_sandbox_conversation_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        29 => {
+            wire__crate__api__chat_api__delete_conversation_impl(port, ptr, rust_vec_len, data_len)
+        }
+        30 => wire__crate__api__chat_api__delete_fact_impl(port, ptr, rust_vec_len, data_len),
+        31 => wire__crate__api__chat_api__delete_memory_impl(port, ptr, rust_vec_len, data_len),
+        32 => wire__crate__api__chat_api__delete_message_impl(port, ptr, rust_vec_len, data_len),
+        33 => wire__crate__api__chat_api__delete_persona_impl(port, ptr, rust_vec_len, data_len),
+        34 => {
+            wire__crate__api__chat_api__detect_message_type_impl(port, ptr, rust_vec_len, data_len)
+        }
+        35 => wire__crate__api__chat_api__detect_message_type_in_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        36 => wire__crate__api__data_models__device_conditions_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        37 => wire__crate__api__data_models__dialogue_style_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        38 => {
+            wire__crate__api__chat_api__disable_encryption_impl(port, ptr, rust_vec_len, data_len)
+        }
+        39 => wire__crate__api__chat_api__discard_memory_summary_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        40 => {
+            wire__crate__api__chat_api__discard_outbox_entry_impl(port, ptr, rust_vec_len, data_len)
+        }
+        41 => {
+            wire__crate__api__chat_api__dissolve_group_chat_impl(port, ptr, rust_vec_len, data_len)
+        }
+        42 => wire__crate__api__chat_api__edit_memory_impl(port, ptr, rust_vec_len, data_len),
+        43 => wire__crate__api__chat_api__edit_message_impl(port, ptr, rust_vec_len, data_len),
+        44 => wire__crate__api__data_models__emoji_usage_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        45 => wire__crate__api__chat_api__enable_encryption_impl(port, ptr, rust_vec_len, data_len),
+        46 => wire__crate__api__chat_api__explain_memory_impl(port, ptr, rust_vec_len, data_len),
+        47 => wire__crate__api__chat_api__explain_relevance_impl(port, ptr, rust_vec_len, data_len),
+        48 => wire__crate__api__chat_api__export_all_data_impl(port, ptr, rust_vec_len, data_len),
+        49 => wire__crate__api__chat_api__export_knowledge_graph_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        50 => wire__crate__api__chat_api__export_logs_impl(port, ptr, rust_vec_len, data_len),
+        51 => wire__crate__api__chat_api__find_graph_path_impl(port, ptr, rust_vec_len, data_len),
+        52 => {
+            wire__crate__api__data_models__formality_default_impl(port, ptr, rust_vec_len, data_len)
+        }
+        53 => wire__crate__api__chat_api__generate_daily_digest_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        54 => wire__crate__api__data_models__generation_metadata_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        55 => {
+            wire__crate__api__chat_api__get_available_models_impl(port, ptr, rust_vec_len, data_len)
+        }
+        56 => {
+            wire__crate__api__chat_api__get_blocked_topics_impl(port, ptr, rust_vec_len, data_len)
+        }
+        57 => {
+            wire__crate__api__chat_api__get_blocking_stats_impl(port, ptr, rust_vec_len, data_len)
+        }
+        58 => {
+            wire__crate__api__chat_api__get_character_mood_impl(port, ptr, rust_vec_len, data_len)
+        }
+        59 => wire__crate__api__chat_api__get_character_relations_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        60 => {
+            wire__crate__api__chat_api__get_character_voices_impl(port, ptr, rust_vec_len, data_len)
+        }
+        61 => wire__crate__api__chat_api__get_check_in_schedule_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        62 => wire__crate__api__chat_api__get_conversation_impl(port, ptr, rust_vec_len, data_len),
+        63 => wire__crate__api__chat_api__get_conversation_list_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        64 => wire__crate__api__chat_api__get_degradation_history_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        65 => {
+            wire__crate__api__chat_api__get_emotion_lexicon_impl(port, ptr, rust_vec_len, data_len)
+        }
+        66 => wire__crate__api__chat_api__get_encryption_status_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        67 => {
+            wire__crate__api__chat_api__get_fact_conflicts_impl(port, ptr, rust_vec_len, data_len)
+        }
+        68 => wire__crate__api__chat_api__get_filtered_conversation_list_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        69 => wire__crate__api__chat_api__get_generation_metadata_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        70 => wire__crate__api__chat_api__get_group_chat_impl(port, ptr, rust_vec_len, data_len),
+        71 => wire__crate__api__chat_api__get_job_scheduler_stats_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        72 => wire__crate__api__chat_api__get_knowledge_binding_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        73 => {
+            wire__crate__api__chat_api__get_knowledge_graph_impl(port, ptr, rust_vec_len, data_len)
+        }
+        74 => wire__crate__api__chat_api__get_lore_entries_impl(port, ptr, rust_vec_len, data_len),
+        75 => wire__crate__api__chat_api__get_memory_fidelity_history_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        76 => wire__crate__api__chat_api__get_metrics_impl(port, ptr, rust_vec_len, data_len),
+        77 => wire__crate__api__chat_api__get_outbox_impl(port, ptr, rust_vec_len, data_len),
+        78 => wire__crate__api__chat_api__get_pending_memory_summary_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        79 => {
+            wire__crate__api__chat_api__get_prompt_template_impl(port, ptr, rust_vec_len, data_len)
+        }
+        80 => {
+            wire__crate__api__chat_api__get_retention_policy_impl(port, ptr, rust_vec_len, data_len)
+        }
+        81 => wire__crate__api__chat_api__get_safety_policy_impl(port, ptr, rust_vec_len, data_len),
+        82 => wire__crate__api__chat_api__get_saydo_rules_impl(port, ptr, rust_vec_len, data_len),
+        83 => wire__crate__api__chat_api__get_settings_impl(port, ptr, rust_vec_len, data_len),
+        84 => wire__crate__api__chat_api__get_shadow_eval_records_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        85 => wire__crate__api__chat_api__get_shadow_eval_summary_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        86 => {
+            wire__crate__api__chat_api__get_story_timeline_impl(port, ptr, rust_vec_len, data_len)
+        }
+        87 => wire__crate__api__chat_api__get_summarization_config_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        88 => wire__crate__api__chat_api__get_sync_settings_impl(port, ptr, rust_vec_len, data_len),
+        89 => wire__crate__api__chat_api__get_turn_count_impl(port, ptr, rust_vec_len, data_len),
+        90 => wire__crate__api__data_models__housekeeping_report_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        91 => wire__crate__api__chat_api__illustrate_scene_impl(port, ptr, rust_vec_len, data_len),
+        92 => wire__crate__api__chat_api__import_all_data_impl(port, ptr, rust_vec_len, data_len),
+        93 => wire__crate__api__data_models__index_scope_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        94 => wire__crate__api__chat_api__init_app_impl(port, ptr, rust_vec_len, data_len),
+        95 => wire__crate__api__chat_api__invalidate_distilled_state_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        96 => wire__crate__api__chat_api__is_streaming_degraded_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        97 => wire__crate__api__data_models__job_scheduler_stats_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        98 => wire__crate__api__data_models__knowledge_binding_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        99 => wire__crate__api__data_models__knowledge_graph_view_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        100 => wire__crate__api__chat_api__list_background_tasks_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        101 => wire__crate__api__chat_api__list_backups_impl(port, ptr, rust_vec_len, data_len),
+        102 => wire__crate__api__chat_api__list_branches_impl(port, ptr, rust_vec_len, data_len),
+        103 => wire__crate__api__chat_api__list_conversation_folders_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        104 => wire__crate__api__chat_api__list_conversation_tags_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        105 => wire__crate__api__chat_api__list_facts_impl(port, ptr, rust_vec_len, data_len),
+        106 => wire__crate__api__chat_api__list_layer_facts_impl(port, ptr, rust_vec_len, data_len),
+        107 => wire__crate__api__chat_api__list_memories_impl(port, ptr, rust_vec_len, data_len),
+        108 => wire__crate__api__chat_api__list_personas_impl(port, ptr, rust_vec_len, data_len),
+        109 => wire__crate__api__chat_api__list_prompt_templates_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        110 => wire__crate__api__chat_api__list_starred_messages_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        111 => wire__crate__api__chat_api__load_cold_storage_messages_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        112 => wire__crate__api__data_models__message_type_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        113 => wire__crate__api__data_models__metrics_snapshot_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        114 => {
+            wire__crate__api__chat_api__migrate_data_layout_impl(port, ptr, rust_vec_len, data_len)
+        }
+        115 => wire__crate__api__data_models__narration_perspective_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        116 => wire__crate__api__data_models__outbox_status_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        117 => wire__crate__api__chat_api__pin_memory_impl(port, ptr, rust_vec_len, data_len),
+        118 => {
+            wire__crate__api__chat_api__preflight_message_impl(port, ptr, rust_vec_len, data_len)
+        }
+        119 => {
+            wire__crate__api__chat_api__prepare_reengagement_impl(port, ptr, rust_vec_len, data_len)
+        }
+        120 => wire__crate__api__chat_api__preview_memory_summary_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        121 => wire__crate__api__chat_api__promote_fact_impl(port, ptr, rust_vec_len, data_len),
+        122 => wire__crate__api__data_models__provider_kind_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        123 => wire__crate__api__chat_api__prune_backups_impl(port, ptr, rust_vec_len, data_len),
+        124 => wire__crate__api__chat_api__query_character_relation_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        125 => wire__crate__api__chat_api__query_graph_neighbors_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        126 => wire__crate__api__chat_api__refresh_distilled_state_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        127 => wire__crate__api__chat_api__regenerate_from_impl(port, ptr, rust_vec_len, data_len),
+        128 => {
+            wire__crate__api__chat_api__regenerate_response_impl(port, ptr, rust_vec_len, data_len)
+        }
+        129 => wire__crate__api__chat_api__reindex_all_impl(port, ptr, rust_vec_len, data_len),
+        130 => wire__crate__api__chat_api__reload_prompt_templates_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        131 => {
+            wire__crate__api__chat_api__remove_blocked_topic_impl(port, ptr, rust_vec_len, data_len)
+        }
+        132 => {
+            wire__crate__api__chat_api__remove_lore_entry_impl(port, ptr, rust_vec_len, data_len)
+        }
+        133 => wire__crate__api__chat_api__repair_integrity_impl(port, ptr, rust_vec_len, data_len),
+        134 => wire__crate__api__data_models__reply_length_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        135 => wire__crate__api__data_models__reply_signals_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        136 => wire__crate__api__chat_api__reset_prompt_template_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        137 => wire__crate__api__data_models__response_style_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        138 => wire__crate__api__chat_api__restart_story_impl(port, ptr, rust_vec_len, data_len),
+        139 => wire__crate__api__chat_api__restore_backup_impl(port, ptr, rust_vec_len, data_len),
+        140 => wire__crate__api__data_models__retention_policy_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        141 => wire__crate__api__data_models__retry_policy_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        142 => wire__crate__api__chat_api__rewrite_reply_impl(port, ptr, rust_vec_len, data_len),
+        143 => {
+            wire__crate__api__chat_api__rollback_to_message_impl(port, ptr, rust_vec_len, data_len)
+        }
+        144 => {
+            wire__crate__api__chat_api__run_deferred_jobs_impl(port, ptr, rust_vec_len, data_len)
+        }
+        145 => wire__crate__api__chat_api__run_housekeeping_impl(port, ptr, rust_vec_len, data_len),
+        146 => wire__crate__api__chat_api__run_thinking_retention_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        147 => wire__crate__api__data_models__safety_policy_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        148 => {
+            wire__crate__api__chat_api__save_emotion_lexicon_impl(port, ptr, rust_vec_len, data_len)
+        }
+        149 => wire__crate__api__chat_api__save_lore_entry_impl(port, ptr, rust_vec_len, data_len),
+        150 => wire__crate__api__chat_api__save_persona_impl(port, ptr, rust_vec_len, data_len),
+        151 => {
+            wire__crate__api__chat_api__save_prompt_template_impl(port, ptr, rust_vec_len, data_len)
+        }
+        152 => wire__crate__api__chat_api__save_settings_impl(port, ptr, rust_vec_len, data_len),
+        153 => wire__crate__api__data_models__say_do_rule_set_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        154 => {
+            wire__crate__api__chat_api__search_conversations_impl(port, ptr, rust_vec_len, data_len)
+        }
+        155 => wire__crate__api__chat_api__search_memories_impl(port, ptr, rust_vec_len, data_len),
+        156 => {
+            wire__crate__api__chat_api__select_alternative_impl(port, ptr, rust_vec_len, data_len)
+        }
+        157 => wire__crate__api__chat_api__send_message_impl(port, ptr, rust_vec_len, data_len),
+        158 => wire__crate__api__chat_api__send_message_with_images_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        159 => wire__crate__api__chat_api__set_api_key_impl(port, ptr, rust_vec_len, data_len),
+        160 => {
+            wire__crate__api__chat_api__set_character_voice_impl(port, ptr, rust_vec_len, data_len)
+        }
+        161 => {
+            wire__crate__api__chat_api__set_chat_provider_impl(port, ptr, rust_vec_len, data_len)
+        }
+        162 => wire__crate__api__chat_api__set_check_in_schedule_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        163 => {
+            wire__crate__api__chat_api__set_context_layers_impl(port, ptr, rust_vec_len, data_len)
+        }
+        164 => wire__crate__api__chat_api__set_conversation_archived_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        165 => wire__crate__api__chat_api__set_conversation_mode_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        166 => wire__crate__api__chat_api__set_conversation_organization_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        167 => wire__crate__api__chat_api__set_conversation_persona_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        168 => wire__crate__api__chat_api__set_conversation_pinned_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        169 => wire__crate__api__chat_api__set_device_conditions_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        170 => {
+            wire__crate__api__chat_api__set_dialogue_style_impl(port, ptr, rust_vec_len, data_len)
+        }
+        171 => wire__crate__api__chat_api__set_fast_draft_impl(port, ptr, rust_vec_len, data_len),
+        172 => wire__crate__api__chat_api__set_group_chat_impl(port, ptr, rust_vec_len, data_len),
+        173 => wire__crate__api__chat_api__set_knowledge_binding_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        174 => {
+            wire__crate__api__chat_api__set_memory_fact_tier_impl(port, ptr, rust_vec_len, data_len)
+        }
+        175 => wire__crate__api__chat_api__set_message_reactions_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        176 => {
+            wire__crate__api__chat_api__set_message_starred_impl(port, ptr, rust_vec_len, data_len)
+        }
+        177 => wire__crate__api__chat_api__set_narration_perspective_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        178 => wire__crate__api__chat_api__set_network_available_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        179 => wire__crate__api__chat_api__set_proxy_impl(port, ptr, rust_vec_len, data_len),
+        180 => {
+            wire__crate__api__chat_api__set_response_style_impl(port, ptr, rust_vec_len, data_len)
+        }
+        181 => {
+            wire__crate__api__chat_api__set_retention_policy_impl(port, ptr, rust_vec_len, data_len)
+        }
+        182 => wire__crate__api__chat_api__set_retry_policy_impl(port, ptr, rust_vec_len, data_len),
+        183 => {
+            wire__crate__api__chat_api__set_safety_policy_impl(port, ptr, rust_vec_len, data_len)
+        }
+        184 => wire__crate__api__chat_api__set_saydo_rules_impl(port, ptr, rust_vec_len, data_len),
+        185 => wire__crate__api__chat_api__set_summarization_config_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        186 => {
+            wire__crate__api__chat_api__set_sync_settings_impl(port, ptr, rust_vec_len, data_len)
+        }
+        187 => wire__crate__api__chat_api__set_thinking_retention_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        188 => {
+            wire__crate__api__chat_api__set_time_awareness_impl(port, ptr, rust_vec_len, data_len)
+        }
+        189 => wire__crate__api__data_models__shadow_eval_summary_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        190 => wire__crate__api__chat_api__should_summarize_memory_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        191 => wire__crate__api__data_models__summarization_config_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        192 => wire__crate__api__chat_api__switch_branch_impl(port, ptr, rust_vec_len, data_len),
+        193 => wire__crate__api__data_models__sync_backend_kind_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        194 => wire__crate__api__chat_api__sync_now_impl(port, ptr, rust_vec_len, data_len),
+        195 => wire__crate__api__data_models__sync_report_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        196 => wire__crate__api__data_models__sync_settings_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        197 => wire__crate__api__data_models__thinking_retention_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        198 => wire__crate__api__chat_api__trigger_memory_summarize_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        199 => wire__crate__api__data_models__turn_policy_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        200 => {
+            wire__crate__api__chat_api__undo_message_edit_impl(port, ptr, rust_vec_len, data_len)
+        }
+        201 => {
+            wire__crate__api__chat_api__unlock_encryption_impl(port, ptr, rust_vec_len, data_len)
+        }
+        202 => wire__crate__api__chat_api__update_fact_impl(port, ptr, rust_vec_len, data_len),
+        203 => {
+            wire__crate__api__chat_api__upgrade_data_layout_impl(port, ptr, rust_vec_len, data_len)
+        }
+        204 => wire__crate__api__chat_api__validate_api_key_impl(port, ptr, rust_vec_len, data_len),
+        205 => {
+            wire__crate__api__chat_api__verify_data_layout_impl(port, ptr, rust_vec_len, data_len)
+        }
+        206 => wire__crate__api__chat_api__verify_integrity_impl(port, ptr, rust_vec_len, data_len),
+        207 => wire__crate__api__chat_api__watch_background_tasks_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        208 => wire__crate__api__chat_api__watch_check_ins_impl(port, ptr, rust_vec_len, data_len),
+        209 => wire__crate__api__chat_api__watch_outbox_impl(port, ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+fn pde_ffi_dispatcher_sync_impl(
+    func_id: i32,
+    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len: i32,
+    data_len: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
+    match func_id {
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::ActionFrequency {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Auto => 0.into_dart(),
+            Self::Never => 1.into_dart(),
+            Self::Occasional => 2.into_dart(),
+            Self::Frequent => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::ActionFrequency
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::ActionFrequency>
+    for crate::api::data_models::ActionFrequency
+{
+    fn into_into_dart(self) -> crate::api::data_models::ActionFrequency {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::AmbientContext {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.weather.into_into_dart().into_dart(),
+            self.step_count.into_into_dart().into_dart(),
+            self.now_playing.into_into_dart().into_dart(),
+            self.calendar_busy.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::AmbientContext
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::AmbientContext>
+    for crate::api::data_models::AmbientContext
+{
+    fn into_into_dart(self) -> crate::api::data_models::AmbientContext {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::AppSettings {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.api_key.into_into_dart().into_dart(),
+            self.default_model.into_into_dart().into_dart(),
+            self.enable_thinking_by_default.into_into_dart().into_dart(),
+            self.chat_model.into_into_dart().into_dart(),
+            self.thinking_model.into_into_dart().into_dart(),
+            self.enable_web_search.into_into_dart().into_dart(),
+            self.content_intensity.into_into_dart().into_dart(),
+            self.provider.into_into_dart().into_dart(),
+            self.provider_base_url.into_into_dart().into_dart(),
+            self.provider_model.into_into_dart().into_dart(),
+            self.provider_api_key.into_into_dart().into_dart(),
+            self.shadow_eval_rate.into_into_dart().into_dart(),
+            self.enable_tts.into_into_dart().into_dart(),
+            self.proxy.into_into_dart().into_dart(),
+            self.retry.into_into_dart().into_dart(),
+            self.time_awareness.into_into_dart().into_dart(),
+            self.fast_draft.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::AppSettings
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::AppSettings>
+    for crate::api::data_models::AppSettings
+{
+    fn into_into_dart(self) -> crate::api::data_models::AppSettings {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::AudioReadyEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.message_id.into_into_dart().into_dart(),
+            self.audio_path.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::AudioReadyEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::AudioReadyEvent>
+    for crate::api::data_models::AudioReadyEvent
+{
+    fn into_into_dart(self) -> crate::api::data_models::AudioReadyEvent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::BackgroundJobKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::FactExtraction => 0.into_dart(),
+            Self::Summarization => 1.into_dart(),
+            Self::Distillation => 2.into_dart(),
+            Self::ShadowEval => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::BackgroundJobKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::BackgroundJobKind>
+    for crate::api::data_models::BackgroundJobKind
+{
+    fn into_into_dart(self) -> crate::api::data_models::BackgroundJobKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::BackgroundTaskEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.task_id.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.conversation_id.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+            self.updated_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::BackgroundTaskEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::BackgroundTaskEvent>
+    for crate::api::data_models::BackgroundTaskEvent
+{
+    fn into_into_dart(self) -> crate::api::data_models::BackgroundTaskEvent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::BackgroundTaskStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Queued => 0.into_dart(),
+            Self::Running => 1.into_dart(),
+            Self::Completed => 2.into_dart(),
+            Self::Failed => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::BackgroundTaskStatus
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::BackgroundTaskStatus>
+    for crate::api::data_models::BackgroundTaskStatus
+{
+    fn into_into_dart(self) -> crate::api::data_models::BackgroundTaskStatus {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::BackoffStrategy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Fixed => 0.into_dart(),
+            Self::Exponential => 1.into_dart(),
+            Self::ExponentialJitter => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::BackoffStrategy
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::BackoffStrategy>
+    for crate::api::data_models::BackoffStrategy
+{
+    fn into_into_dart(self) -> crate::api::data_models::BackoffStrategy {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::BackupRetention {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.keep_last.into_into_dart().into_dart(),
+            self.keep_daily.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::BackupRetention
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::BackupRetention>
+    for crate::api::data_models::BackupRetention
+{
+    fn into_into_dart(self) -> crate::api::data_models::BackupRetention {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::BackupScope {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::All => 0.into_dart(),
+            Self::Conversations => 1.into_dart(),
+            Self::Memory => 2.into_dart(),
+            Self::Knowledge => 3.into_dart(),
+            Self::Config => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::BackupScope
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::BackupScope>
+    for crate::api::data_models::BackupScope
+{
+    fn into_into_dart(self) -> crate::api::data_models::BackupScope {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::BackupSnapshotInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.file_count.into_into_dart().into_dart(),
+            self.total_bytes.into_into_dart().into_dart(),
+            self.added_bytes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::BackupSnapshotInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::BackupSnapshotInfo>
+    for crate::api::data_models::BackupSnapshotInfo
+{
+    fn into_into_dart(self) -> crate::api::data_models::BackupSnapshotInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::BlockedTopic {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.topic.into_into_dart().into_dart(),
+            self.keywords.into_into_dart().into_dart(),
+            self.evidence.into_into_dart().into_dart(),
+            self.occurrences.into_into_dart().into_dart(),
+            self.explicit.into_into_dart().into_dart(),
+            self.active.into_into_dart().into_dart(),
+            self.first_seen.into_into_dart().into_dart(),
+            self.last_seen.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::BlockedTopic
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::BlockedTopic>
+    for crate::api::data_models::BlockedTopic
+{
+    fn into_into_dart(self) -> crate::api::data_models::BlockedTopic {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::BlockingStats {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.tasks.into_into_dart().into_dart(),
+            self.total_run_ms.into_into_dart().into_dart(),
+            self.max_run_ms.into_into_dart().into_dart(),
+            self.max_queue_ms.into_into_dart().into_dart(),
+            self.slowest_label.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::BlockingStats
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::BlockingStats>
+    for crate::api::data_models::BlockingStats
+{
+    fn into_into_dart(self) -> crate::api::data_models::BlockingStats {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::BranchInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.parent_message_id.into_into_dart().into_dart(),
+            self.message_count.into_into_dart().into_dart(),
+            self.last_message_preview.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.is_active.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::BranchInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::BranchInfo>
+    for crate::api::data_models::BranchInfo
+{
+    fn into_into_dart(self) -> crate::api::data_models::BranchInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::CharacterCard {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.system_prompt.into_into_dart().into_dart(),
+            self.greeting.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::CharacterCard
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::CharacterCard>
+    for crate::api::data_models::CharacterCard
+{
+    fn into_into_dart(self) -> crate::api::data_models::CharacterCard {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::CharacterRelation {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.from.into_into_dart().into_dart(),
+            self.to.into_into_dart().into_dart(),
+            self.relation.into_into_dart().into_dart(),
+            self.sentiment.into_into_dart().into_dart(),
+            self.fact_id.into_into_dart().into_dart(),
+            self.source_turn.into_into_dart().into_dart(),
+            self.history.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::CharacterRelation
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::CharacterRelation>
+    for crate::api::data_models::CharacterRelation
+{
+    fn into_into_dart(self) -> crate::api::data_models::CharacterRelation {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::ChatStreamEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::data_models::ChatStreamEvent::ContentDelta(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::data_models::ChatStreamEvent::ThinkingDelta(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::data_models::ChatStreamEvent::Done => [2.into_dart()].into_dart(),
+            crate::api::data_models::ChatStreamEvent::Error(field0) => {
+                [3.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::data_models::ChatStreamEvent::ConfigChanged => [4.into_dart()].into_dart(),
+            crate::api::data_models::ChatStreamEvent::Reaction(field0) => {
+                [5.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::data_models::ChatStreamEvent::SystemNotice(field0) => {
+                [6.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::data_models::ChatStreamEvent::Illustration(field0) => {
+                [7.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::data_models::ChatStreamEvent::AudioReady(field0) => {
+                [8.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::data_models::ChatStreamEvent::Completed(field0) => {
+                [9.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::data_models::ChatStreamEvent::Outbox(field0) => {
+                [10.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::data_models::ChatStreamEvent::Safety(field0) => {
+                [11.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::data_models::ChatStreamEvent::CheckIn(field0) => {
+                [12.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::data_models::ChatStreamEvent::SummaryPreview(field0) => {
+                [13.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::data_models::ChatStreamEvent::DraftDelta(field0) => {
+                [14.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::data_models::ChatStreamEvent::Refined(field0) => {
+                [15.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::ChatStreamEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::ChatStreamEvent>
+    for crate::api::data_models::ChatStreamEvent
+{
+    fn into_into_dart(self) -> crate::api::data_models::ChatStreamEvent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::CheckInSchedule {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.conversation_id.into_into_dart().into_dart(),
+            self.enabled.into_into_dart().into_dart(),
+            self.silence_hours.into_into_dart().into_dart(),
+            self.last_sent_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::CheckInSchedule
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::CheckInSchedule>
+    for crate::api::data_models::CheckInSchedule
+{
+    fn into_into_dart(self) -> crate::api::data_models::CheckInSchedule {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::CognitiveInsight {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.emotion.into_into_dart().into_dart(),
+            self.intent.into_into_dart().into_dart(),
+            self.relationship.into_into_dart().into_dart(),
+            self.empathy_strategy.into_into_dart().into_dart(),
+            self.detected_patterns.into_into_dart().into_dart(),
+            self.cognitive_prompt.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::CognitiveInsight
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::CognitiveInsight>
+    for crate::api::data_models::CognitiveInsight
+{
+    fn into_into_dart(self) -> crate::api::data_models::CognitiveInsight {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::ContentIntensity {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Mild => 0.into_dart(),
+            Self::Normal => 1.into_dart(),
+            Self::Dramatic => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::ContentIntensity
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::ContentIntensity>
+    for crate::api::data_models::ContentIntensity
+{
+    fn into_into_dart(self) -> crate::api::data_models::ContentIntensity {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::ContextLayers {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.short_term_memory.into_into_dart().into_dart(),
+            self.cognitive_snapshot.into_into_dart().into_dart(),
+            self.diversity_hint.into_into_dart().into_dart(),
+            self.humanization_hint.into_into_dart().into_dart(),
+            self.knowledge_injection.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::ContextLayers
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::ContextLayers>
+    for crate::api::data_models::ContextLayers
+{
+    fn into_into_dart(self) -> crate::api::data_models::ContextLayers {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::Conversation {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.messages.into_into_dart().into_dart(),
+            self.model.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.updated_at.into_into_dart().into_dart(),
+            self.dialogue_style.into_into_dart().into_dart(),
+            self.turn_count.into_into_dart().into_dart(),
+            self.memory_summaries.into_into_dart().into_dart(),
+            self.mode.into_into_dart().into_dart(),
+            self.thinking_retention.into_into_dart().into_dart(),
+            self.branch_id.into_into_dart().into_dart(),
+            self.parent_message_id.into_into_dart().into_dart(),
+            self.branches.into_into_dart().into_dart(),
+            self.narration.into_into_dart().into_dart(),
+            self.context_layers.into_into_dart().into_dart(),
+            self.closed_at.into_into_dart().into_dart(),
+            self.response_style.into_into_dart().into_dart(),
+            self.persona_id.into_into_dart().into_dart(),
+            self.organization.into_into_dart().into_dart(),
+            self.cold_turns.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::Conversation
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::Conversation>
+    for crate::api::data_models::Conversation
+{
+    fn into_into_dart(self) -> crate::api::data_models::Conversation {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::ConversationBranch {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.parent_message_id.into_into_dart().into_dart(),
+            self.messages.into_into_dart().into_dart(),
+            self.turn_count.into_into_dart().into_dart(),
+            self.memory_summaries.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.cold_turns.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::ConversationBranch
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::ConversationBranch>
+    for crate::api::data_models::ConversationBranch
+{
+    fn into_into_dart(self) -> crate::api::data_models::ConversationBranch {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::ConversationClosure {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.epilogue.into_into_dart().into_dart(),
+            self.recap.into_into_dart().into_dart(),
+            self.archive_path.into_into_dart().into_dart(),
+            self.closed_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::ConversationClosure
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::ConversationClosure>
+    for crate::api::data_models::ConversationClosure
+{
+    fn into_into_dart(self) -> crate::api::data_models::ConversationClosure {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::ConversationFilter {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.folder.into_into_dart().into_dart(),
+            self.tags.into_into_dart().into_dart(),
+            self.archived.into_into_dart().into_dart(),
+            self.query.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::ConversationFilter
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::ConversationFilter>
+    for crate::api::data_models::ConversationFilter
+{
+    fn into_into_dart(self) -> crate::api::data_models::ConversationFilter {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::ConversationInsights {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.cognitive.into_into_dart().into_dart(),
+            self.short_term.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::ConversationInsights
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::ConversationInsights>
+    for crate::api::data_models::ConversationInsights
+{
+    fn into_into_dart(self) -> crate::api::data_models::ConversationInsights {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::ConversationMode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Chat => 0.into_dart(),
+            Self::CoAuthor => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::ConversationMode
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::ConversationMode>
+    for crate::api::data_models::ConversationMode
+{
+    fn into_into_dart(self) -> crate::api::data_models::ConversationMode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::ConversationOrganization {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.tags.into_into_dart().into_dart(),
+            self.folder.into_into_dart().into_dart(),
+            self.pinned.into_into_dart().into_dart(),
+            self.archived.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::ConversationOrganization
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::ConversationOrganization>
+    for crate::api::data_models::ConversationOrganization
+{
+    fn into_into_dart(self) -> crate::api::data_models::ConversationOrganization {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::ConversationSummary {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.last_message_preview.into_into_dart().into_dart(),
+            self.model.into_into_dart().into_dart(),
+            self.updated_at.into_into_dart().into_dart(),
+            self.organization.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::ConversationSummary
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::ConversationSummary>
+    for crate::api::data_models::ConversationSummary
+{
+    fn into_into_dart(self) -> crate::api::data_models::ConversationSummary {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::DailyDigest {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.generated_at.into_into_dart().into_dart(),
+            self.entries.into_into_dart().into_dart(),
+            self.skipped.into_into_dart().into_dart(),
+            self.estimated_tokens.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::DailyDigest
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::DailyDigest>
+    for crate::api::data_models::DailyDigest
+{
+    fn into_into_dart(self) -> crate::api::data_models::DailyDigest {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::DataExportInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.format_version.into_into_dart().into_dart(),
+            self.layout_version.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.file_count.into_into_dart().into_dart(),
+            self.total_bytes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::DataExportInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::DataExportInfo>
+    for crate::api::data_models::DataExportInfo
+{
+    fn into_into_dart(self) -> crate::api::data_models::DataExportInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::DeferredJob {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.conversation_id.into_into_dart().into_dart(),
+            self.deferred_at.into_into_dart().into_dart(),
+            self.reason.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::DeferredJob
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::DeferredJob>
+    for crate::api::data_models::DeferredJob
+{
+    fn into_into_dart(self) -> crate::api::data_models::DeferredJob {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::DegradationKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::ThinkingDropped => 0.into_dart(),
+            Self::CompactRetry => 1.into_dart(),
+            Self::ModelFallback => 2.into_dart(),
+            Self::ReasoningTimeout => 3.into_dart(),
+            Self::DistillationTimeout => 4.into_dart(),
+            Self::ContextTruncated => 5.into_dart(),
+            Self::FactExtractionTimeout => 6.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::DegradationKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::DegradationKind>
+    for crate::api::data_models::DegradationKind
+{
+    fn into_into_dart(self) -> crate::api::data_models::DegradationKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::DegradationRecord {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.turn.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.model.into_into_dart().into_dart(),
+            self.detail.into_into_dart().into_dart(),
+            self.timestamp.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::DegradationRecord
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::DegradationRecord>
+    for crate::api::data_models::DegradationRecord
+{
+    fn into_into_dart(self) -> crate::api::data_models::DegradationRecord {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::DeviceConditions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.battery_level.into_into_dart().into_dart(),
+            self.is_charging.into_into_dart().into_dart(),
+            self.low_power_mode.into_into_dart().into_dart(),
+            self.metered_network.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::DeviceConditions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::DeviceConditions>
+    for crate::api::data_models::DeviceConditions
+{
+    fn into_into_dart(self) -> crate::api::data_models::DeviceConditions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::DialogueStyle {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Free => 0.into_dart(),
+            Self::SayOnly => 1.into_dart(),
+            Self::DoOnly => 2.into_dart(),
+            Self::Mixed => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::DialogueStyle
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::DialogueStyle>
+    for crate::api::data_models::DialogueStyle
+{
+    fn into_into_dart(self) -> crate::api::data_models::DialogueStyle {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::DigestEntry {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.conversation_id.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.note.into_into_dart().into_dart(),
+            self.last_active_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::DigestEntry
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::DigestEntry>
+    for crate::api::data_models::DigestEntry
+{
+    fn into_into_dart(self) -> crate::api::data_models::DigestEntry {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::DistilledSystemState {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.core_prompt.into_into_dart().into_dart(),
+            self.last_memory_count.into_into_dart().into_dart(),
+            self.last_max_compression_gen.into_into_dart().into_dart(),
+            self.character_prompt_hash.into_into_dart().into_dart(),
+            self.last_turn_count.into_into_dart().into_dart(),
+            self.distilled_at.into_into_dart().into_dart(),
+            self.core_facts_snapshot.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::DistilledSystemState
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::DistilledSystemState>
+    for crate::api::data_models::DistilledSystemState
+{
+    fn into_into_dart(self) -> crate::api::data_models::DistilledSystemState {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::DraftTone {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Calm => 0.into_dart(),
+            Self::Warm => 1.into_dart(),
+            Self::Playful => 2.into_dart(),
+            Self::Sad => 3.into_dart(),
+            Self::Anxious => 4.into_dart(),
+            Self::Sarcastic => 5.into_dart(),
+            Self::Hostile => 6.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::DraftTone
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::DraftTone>
+    for crate::api::data_models::DraftTone
+{
+    fn into_into_dart(self) -> crate::api::data_models::DraftTone {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::EmojiUsage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Auto => 0.into_dart(),
+            Self::Never => 1.into_dart(),
+            Self::Occasional => 2.into_dart(),
+            Self::Frequent => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::EmojiUsage
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::EmojiUsage>
+    for crate::api::data_models::EmojiUsage
+{
+    fn into_into_dart(self) -> crate::api::data_models::EmojiUsage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::EmotionDimension {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Joy => 0.into_dart(),
+            Self::Sadness => 1.into_dart(),
+            Self::Anger => 2.into_dart(),
+            Self::Fear => 3.into_dart(),
+            Self::Surprise => 4.into_dart(),
+            Self::Intimacy => 5.into_dart(),
+            Self::Trust => 6.into_dart(),
+            Self::Anticipation => 7.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::EmotionDimension
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::EmotionDimension>
+    for crate::api::data_models::EmotionDimension
+{
+    fn into_into_dart(self) -> crate::api::data_models::EmotionDimension {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::EmotionInsight {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.joy.into_into_dart().into_dart(),
+            self.sadness.into_into_dart().into_dart(),
+            self.anger.into_into_dart().into_dart(),
+            self.fear.into_into_dart().into_dart(),
+            self.surprise.into_into_dart().into_dart(),
+            self.intimacy.into_into_dart().into_dart(),
+            self.trust.into_into_dart().into_dart(),
+            self.anticipation.into_into_dart().into_dart(),
+            self.valence.into_into_dart().into_dart(),
+            self.arousal.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::EmotionInsight
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::EmotionInsight>
+    for crate::api::data_models::EmotionInsight
+{
+    fn into_into_dart(self) -> crate::api::data_models::EmotionInsight {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::EmotionalTurnInsight {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.turn.into_into_dart().into_dart(),
+            self.valence.into_into_dart().into_dart(),
+            self.arousal.into_into_dart().into_dart(),
+            self.dominant_emotion.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::EmotionalTurnInsight
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::EmotionalTurnInsight>
+    for crate::api::data_models::EmotionalTurnInsight
+{
+    fn into_into_dart(self) -> crate::api::data_models::EmotionalTurnInsight {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::EmpathyKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Mirror => 0.into_dart(),
+            Self::Accompany => 1.into_dart(),
+            Self::Distract => 2.into_dart(),
+            Self::Responsive => 3.into_dart(),
+            Self::PlayfulCounter => 4.into_dart(),
+            Self::GentleFirm => 5.into_dart(),
+            Self::ProactiveCare => 6.into_dart(),
+            Self::NaturalFlow => 7.into_dart(),
+            Self::GiveSpace => 8.into_dart(),
+            Self::Escalate => 9.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::EmpathyKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::EmpathyKind>
+    for crate::api::data_models::EmpathyKind
+{
+    fn into_into_dart(self) -> crate::api::data_models::EmpathyKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::EncryptionKeySource {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Passphrase => 0.into_dart(),
+            Self::Keystore => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::EncryptionKeySource
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::EncryptionKeySource>
+    for crate::api::data_models::EncryptionKeySource
+{
+    fn into_into_dart(self) -> crate::api::data_models::EncryptionKeySource {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::EncryptionSecret {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::data_models::EncryptionSecret::Passphrase(field0) => {
+                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::data_models::EncryptionSecret::Keystore(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::EncryptionSecret
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::EncryptionSecret>
+    for crate::api::data_models::EncryptionSecret
+{
+    fn into_into_dart(self) -> crate::api::data_models::EncryptionSecret {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::EncryptionStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.enabled.into_into_dart().into_dart(),
+            self.unlocked.into_into_dart().into_dart(),
+            self.source.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::EncryptionStatus
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::EncryptionStatus>
+    for crate::api::data_models::EncryptionStatus
+{
+    fn into_into_dart(self) -> crate::api::data_models::EncryptionStatus {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::knowledge_store::Fact {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.content.into_into_dart().into_dart(),
+            self.category.into_into_dart().into_dart(),
+            self.source_turn.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.last_confirmed_at.into_into_dart().into_dart(),
+            self.keywords.into_into_dart().into_dart(),
+            self.entities.into_into_dart().into_dart(),
+            self.confidence.into_into_dart().into_dart(),
+            self.hit_count.into_into_dart().into_dart(),
+            self.context_snippet.into_into_dart().into_dart(),
+            self.summary_ids.into_into_dart().into_dart(),
+            self.superseded_by.into_into_dart().into_dart(),
+            self.superseded_at.into_into_dart().into_dart(),
+            self.manual_override.into_into_dart().into_dart(),
+            self.persona_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::knowledge_store::Fact
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::knowledge_store::Fact>
+    for crate::api::knowledge_store::Fact
+{
+    fn into_into_dart(self) -> crate::api::knowledge_store::Fact {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::knowledge_store::FactCategory {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Identity => 0.into_dart(),
+            Self::Relationship => 1.into_dart(),
+            Self::Preference => 2.into_dart(),
+            Self::Event => 3.into_dart(),
+            Self::CurrentState => 4.into_dart(),
+            Self::Promise => 5.into_dart(),
+            Self::Consensus => 6.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::knowledge_store::FactCategory
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::knowledge_store::FactCategory>
+    for crate::api::knowledge_store::FactCategory
+{
+    fn into_into_dart(self) -> crate::api::knowledge_store::FactCategory {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::FactConflict {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.superseded_fact_id.into_into_dart().into_dart(),
+            self.superseded_content.into_into_dart().into_dart(),
+            self.active_fact_id.into_into_dart().into_dart(),
+            self.active_content.into_into_dart().into_dart(),
+            self.detected_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::FactConflict
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::FactConflict>
+    for crate::api::data_models::FactConflict
+{
+    fn into_into_dart(self) -> crate::api::data_models::FactConflict {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::FingerprintInsight {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.opening_chars.into_into_dart().into_dart(),
+            self.paragraph_count.into_into_dart().into_dart(),
+            self.avg_sentence_len.into_into_dart().into_dart(),
+            self.ending_chars.into_into_dart().into_dart(),
+            self.ends_with_question.into_into_dart().into_dart(),
+            self.total_length.into_into_dart().into_dart(),
+            self.has_action_marker.into_into_dart().into_dart(),
+            self.has_list_format.into_into_dart().into_dart(),
+            self.emotional_tone.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::FingerprintInsight
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::FingerprintInsight>
+    for crate::api::data_models::FingerprintInsight
+{
+    fn into_into_dart(self) -> crate::api::data_models::FingerprintInsight {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::Formality {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Auto => 0.into_dart(),
+            Self::Casual => 1.into_dart(),
+            Self::Neutral => 2.into_dart(),
+            Self::Formal => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::Formality
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::Formality>
+    for crate::api::data_models::Formality
+{
+    fn into_into_dart(self) -> crate::api::data_models::Formality {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::GenerationCompletedEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.message_id.into_into_dart().into_dart(),
+            self.metadata.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::GenerationCompletedEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::GenerationCompletedEvent>
+    for crate::api::data_models::GenerationCompletedEvent
+{
+    fn into_into_dart(self) -> crate::api::data_models::GenerationCompletedEvent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::GenerationMetadata {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.model.into_into_dart().into_dart(),
+            self.finish_reason.into_into_dart().into_dart(),
+            self.prompt_tokens.into_into_dart().into_dart(),
+            self.completion_tokens.into_into_dart().into_dart(),
+            self.total_tokens.into_into_dart().into_dart(),
+            self.cached_tokens.into_into_dart().into_dart(),
+            self.streamed.into_into_dart().into_dart(),
+            self.interrupted.into_into_dart().into_dart(),
+            self.fallbacks.into_into_dart().into_dart(),
+            self.latency_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::GenerationMetadata
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::GenerationMetadata>
+    for crate::api::data_models::GenerationMetadata
+{
+    fn into_into_dart(self) -> crate::api::data_models::GenerationMetadata {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::GraphEdge {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.fact_id.into_into_dart().into_dart(),
+            self.source.into_into_dart().into_dart(),
+            self.target.into_into_dart().into_dart(),
+            self.relation.into_into_dart().into_dart(),
+            self.category.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::GraphEdge
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::GraphEdge>
+    for crate::api::data_models::GraphEdge
+{
+    fn into_into_dart(self) -> crate::api::data_models::GraphEdge {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::GraphExportFormat {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Json => 0.into_dart(),
+            Self::GraphMl => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::GraphExportFormat
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::GraphExportFormat>
+    for crate::api::data_models::GraphExportFormat
+{
+    fn into_into_dart(self) -> crate::api::data_models::GraphExportFormat {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::GraphNode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.label.into_into_dart().into_dart(),
+            self.degree.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::GraphNode
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::GraphNode>
+    for crate::api::data_models::GraphNode
+{
+    fn into_into_dart(self) -> crate::api::data_models::GraphNode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::GroupCharacter {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.system_prompt.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::GroupCharacter
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::GroupCharacter>
+    for crate::api::data_models::GroupCharacter
+{
+    fn into_into_dart(self) -> crate::api::data_models::GroupCharacter {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::GroupChat {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.conversation_id.into_into_dart().into_dart(),
+            self.characters.into_into_dart().into_dart(),
+            self.turn_policy.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::GroupChat
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::GroupChat>
+    for crate::api::data_models::GroupChat
+{
+    fn into_into_dart(self) -> crate::api::data_models::GroupChat {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::HousekeepingReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.archived.into_into_dart().into_dart(),
+            self.rolled_over_messages.into_into_dart().into_dart(),
+            self.purged_files.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::HousekeepingReport
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::HousekeepingReport>
+    for crate::api::data_models::HousekeepingReport
+{
+    fn into_into_dart(self) -> crate::api::data_models::HousekeepingReport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::IndexScope {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::All => 0.into_dart(),
+            Self::Knowledge => 1.into_dart(),
+            Self::Memory => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::IndexScope
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::IndexScope>
+    for crate::api::data_models::IndexScope
+{
+    fn into_into_dart(self) -> crate::api::data_models::IndexScope {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::IntegrityIssue {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.detail.into_into_dart().into_dart(),
+            self.repaired.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::IntegrityIssue
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::IntegrityIssue>
+    for crate::api::data_models::IntegrityIssue
+{
+    fn into_into_dart(self) -> crate::api::data_models::IntegrityIssue {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::IntegrityIssueKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::TurnCountMismatch => 0.into_dart(),
+            Self::SummaryRangeInvalid => 1.into_dart(),
+            Self::SummaryIndexDesync => 2.into_dart(),
+            Self::OrphanFact => 3.into_dart(),
+            Self::DanglingIndexEntry => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::IntegrityIssueKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::IntegrityIssueKind>
+    for crate::api::data_models::IntegrityIssueKind
+{
+    fn into_into_dart(self) -> crate::api::data_models::IntegrityIssueKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::IntegrityReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.conversation_id.into_into_dart().into_dart(),
+            self.actual_turns.into_into_dart().into_dart(),
+            self.recorded_turn_count.into_into_dart().into_dart(),
+            self.issues.into_into_dart().into_dart(),
+            self.checked_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::IntegrityReport
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::IntegrityReport>
+    for crate::api::data_models::IntegrityReport
+{
+    fn into_into_dart(self) -> crate::api::data_models::IntegrityReport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::IntentKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::SeekingComfort => 0.into_dart(),
+            Self::ExpressingAffection => 1.into_dart(),
+            Self::ExpressingDispleasure => 2.into_dart(),
+            Self::TestingBoundary => 3.into_dart(),
+            Self::SharingDaily => 4.into_dart(),
+            Self::SeekingResponse => 5.into_dart(),
+            Self::EmotionalVenting => 6.into_dart(),
+            Self::Playful => 7.into_dart(),
+            Self::Reconciling => 8.into_dart(),
+            Self::Farewell => 9.into_dart(),
+            Self::Withdrawn => 10.into_dart(),
+            Self::DeepSharing => 11.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::IntentKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::IntentKind>
+    for crate::api::data_models::IntentKind
+{
+    fn into_into_dart(self) -> crate::api::data_models::IntentKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::JobSchedulerStats {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.running.into_into_dart().into_dart(),
+            self.completed.into_into_dart().into_dart(),
+            self.timed_out.into_into_dart().into_dart(),
+            self.deferred_total.into_into_dart().into_dart(),
+            self.pending.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::JobSchedulerStats
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::JobSchedulerStats>
+    for crate::api::data_models::JobSchedulerStats
+{
+    fn into_into_dart(self) -> crate::api::data_models::JobSchedulerStats {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::KnowledgeBinding {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.character_id.into_into_dart().into_dart(),
+            self.character_name.into_into_dart().into_dart(),
+            self.world_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::KnowledgeBinding
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::KnowledgeBinding>
+    for crate::api::data_models::KnowledgeBinding
+{
+    fn into_into_dart(self) -> crate::api::data_models::KnowledgeBinding {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::KnowledgeGraphView {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.nodes.into_into_dart().into_dart(),
+            self.edges.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::KnowledgeGraphView
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::KnowledgeGraphView>
+    for crate::api::data_models::KnowledgeGraphView
+{
+    fn into_into_dart(self) -> crate::api::data_models::KnowledgeGraphView {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::KnowledgeLayer {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Conversation => 0.into_dart(),
+            Self::Character => 1.into_dart(),
+            Self::World => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::KnowledgeLayer
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::KnowledgeLayer>
+    for crate::api::data_models::KnowledgeLayer
+{
+    fn into_into_dart(self) -> crate::api::data_models::KnowledgeLayer {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::LanguagePatternKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Negation => 0.into_dart(),
+            Self::Sarcasm => 1.into_dart(),
+            Self::Hesitation => 2.into_dart(),
+            Self::Repetition => 3.into_dart(),
+            Self::Urgent => 4.into_dart(),
+            Self::Dragging => 5.into_dart(),
+            Self::Contradictory => 6.into_dart(),
+            Self::Probing => 7.into_dart(),
+            Self::Coquettish => 8.into_dart(),
+            Self::Defensive => 9.into_dart(),
+            Self::Suppressed => 10.into_dart(),
+            Self::TopicAvoidance => 11.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::LanguagePatternKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::LanguagePatternKind>
+    for crate::api::data_models::LanguagePatternKind
+{
+    fn into_into_dart(self) -> crate::api::data_models::LanguagePatternKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::LayoutVerification {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.version.into_into_dart().into_dart(),
+            self.checked_files.into_into_dart().into_dart(),
+            self.missing.into_into_dart().into_dart(),
+            self.corrupted.into_into_dart().into_dart(),
+            self.unexpected.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::LayoutVerification
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::LayoutVerification>
+    for crate::api::data_models::LayoutVerification
+{
+    fn into_into_dart(self) -> crate::api::data_models::LayoutVerification {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::LexiconEntry {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.word.into_into_dart().into_dart(),
+            self.dimension.into_into_dart().into_dart(),
+            self.weight.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::LexiconEntry
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::LexiconEntry>
+    for crate::api::data_models::LexiconEntry
+{
+    fn into_into_dart(self) -> crate::api::data_models::LexiconEntry {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::LinkedFact {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.fact_id.into_into_dart().into_dart(),
+            self.content.into_into_dart().into_dart(),
+            self.source_turn.into_into_dart().into_dart(),
+            self.summary_ids.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::LinkedFact
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::LinkedFact>
+    for crate::api::data_models::LinkedFact
+{
+    fn into_into_dart(self) -> crate::api::data_models::LinkedFact {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::LoreEntry {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.title.into_into_dart().into_dart(),
+            self.content.into_into_dart().into_dart(),
+            self.keywords.into_into_dart().into_dart(),
+            self.insertion_depth.into_into_dart().into_dart(),
+            self.priority.into_into_dart().into_dart(),
+            self.enabled.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::LoreEntry
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::LoreEntry>
+    for crate::api::data_models::LoreEntry
+{
+    fn into_into_dart(self) -> crate::api::data_models::LoreEntry {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::MemoryContextCard {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.source_range.into_into_dart().into_dart(),
+            self.topic_tags.into_into_dart().into_dart(),
+            self.key_entities.into_into_dart().into_dart(),
+            self.emotional_tone.into_into_dart().into_dart(),
+            self.causal_links.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::MemoryContextCard
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::MemoryContextCard>
+    for crate::api::data_models::MemoryContextCard
+{
+    fn into_into_dart(self) -> crate::api::data_models::MemoryContextCard {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::MemoryExplanation {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.summary_id.into_into_dart().into_dart(),
+            self.summary.into_into_dart().into_dart(),
+            self.turn_range_start.into_into_dart().into_dart(),
+            self.turn_range_end.into_into_dart().into_dart(),
+            self.compression_generation.into_into_dart().into_dart(),
+            self.linked_facts.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::MemoryExplanation
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::MemoryExplanation>
+    for crate::api::data_models::MemoryExplanation
+{
+    fn into_into_dart(self) -> crate::api::data_models::MemoryExplanation {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::MemoryFidelityReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.conversation_id.into_into_dart().into_dart(),
+            self.score.into_into_dart().into_dart(),
+            self.sampled_facts.into_into_dart().into_dart(),
+            self.lost_facts.into_into_dart().into_dart(),
+            self.previous_score.into_into_dart().into_dart(),
+            self.alert.into_into_dart().into_dart(),
+            self.audited_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::MemoryFidelityReport
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::MemoryFidelityReport>
+    for crate::api::data_models::MemoryFidelityReport
+{
+    fn into_into_dart(self) -> crate::api::data_models::MemoryFidelityReport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::MemorySearchResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.summary.into_into_dart().into_dart(),
+            self.core_facts.into_into_dart().into_dart(),
+            self.relevance_score.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::MemorySearchResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::MemorySearchResult>
+    for crate::api::data_models::MemorySearchResult
+{
+    fn into_into_dart(self) -> crate::api::data_models::MemorySearchResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::MemorySummary {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.summary.into_into_dart().into_dart(),
+            self.core_facts.into_into_dart().into_dart(),
+            self.turn_range_start.into_into_dart().into_dart(),
+            self.turn_range_end.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+            self.keywords.into_into_dart().into_dart(),
+            self.compression_generation.into_into_dart().into_dart(),
+            self.context_card.into_into_dart().into_dart(),
+            self.fact_tiers.into_into_dart().into_dart(),
+            self.linked_fact_ids.into_into_dart().into_dart(),
+            self.pinned.into_into_dart().into_dart(),
+            self.pinned_facts.into_into_dart().into_dart(),
+            self.stale.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::MemorySummary
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::MemorySummary>
+    for crate::api::data_models::MemorySummary
+{
+    fn into_into_dart(self) -> crate::api::data_models::MemorySummary {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::MemoryTier {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Identity => 0.into_dart(),
+            Self::CriticalEvent => 1.into_dart(),
+            Self::RelationshipDynamic => 2.into_dart(),
+            Self::CurrentState => 3.into_dart(),
+            Self::SceneDetail => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::MemoryTier
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::MemoryTier>
+    for crate::api::data_models::MemoryTier
+{
+    fn into_into_dart(self) -> crate::api::data_models::MemoryTier {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::Message {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.role.into_into_dart().into_dart(),
+            self.content.into_into_dart().into_dart(),
+            self.thinking_content.into_into_dart().into_dart(),
+            self.model.into_into_dart().into_dart(),
+            self.timestamp.into_into_dart().into_dart(),
+            self.message_type.into_into_dart().into_dart(),
+            self.generation_metadata.into_into_dart().into_dart(),
+            self.character_id.into_into_dart().into_dart(),
+            self.attachments.into_into_dart().into_dart(),
+            self.audio_path.into_into_dart().into_dart(),
+            self.alternatives.into_into_dart().into_dart(),
+            self.selected_alternative.into_into_dart().into_dart(),
+            self.starred.into_into_dart().into_dart(),
+            self.reactions.into_into_dart().into_dart(),
+            self.edit_history.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::Message
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::Message>
+    for crate::api::data_models::Message
+{
+    fn into_into_dart(self) -> crate::api::data_models::Message {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::MessageAlternative {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.content.into_into_dart().into_dart(),
+            self.thinking_content.into_into_dart().into_dart(),
+            self.model.into_into_dart().into_dart(),
+            self.timestamp.into_into_dart().into_dart(),
+            self.generation_metadata.into_into_dart().into_dart(),
+            self.audio_path.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::MessageAlternative
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::MessageAlternative>
+    for crate::api::data_models::MessageAlternative
+{
+    fn into_into_dart(self) -> crate::api::data_models::MessageAlternative {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::MessageAttachment {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.mime_type.into_into_dart().into_dart(),
+            self.url.into_into_dart().into_dart(),
+            self.caption.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::MessageAttachment
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::MessageAttachment>
+    for crate::api::data_models::MessageAttachment
+{
+    fn into_into_dart(self) -> crate::api::data_models::MessageAttachment {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::MessageEdit {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.content.into_into_dart().into_dart(),
+            self.timestamp.into_into_dart().into_dart(),
+            self.edited_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::MessageEdit
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::MessageEdit>
+    for crate::api::data_models::MessageEdit
+{
+    fn into_into_dart(self) -> crate::api::data_models::MessageEdit {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::MessageRole {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::User => 0.into_dart(),
+            Self::Assistant => 1.into_dart(),
+            Self::System => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::MessageRole
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::MessageRole>
+    for crate::api::data_models::MessageRole
+{
+    fn into_into_dart(self) -> crate::api::data_models::MessageRole {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::MessageType {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Say => 0.into_dart(),
+            Self::Do => 1.into_dart(),
+            Self::Mixed => 2.into_dart(),
+            Self::Document => 3.into_dart(),
+            Self::Narration => 4.into_dart(),
+            Self::OutOfCharacter => 5.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::MessageType
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::MessageType>
+    for crate::api::data_models::MessageType
+{
+    fn into_into_dart(self) -> crate::api::data_models::MessageType {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::MetricPhase {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Retrieval => 0.into_dart(),
+            Self::Distillation => 1.into_dart(),
+            Self::Reasoning => 2.into_dart(),
+            Self::Chat => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::MetricPhase
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::MetricPhase>
+    for crate::api::data_models::MetricPhase
+{
+    fn into_into_dart(self) -> crate::api::data_models::MetricPhase {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::MetricsSnapshot {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.turns.into_into_dart().into_dart(),
+            self.avg_first_token_ms.into_into_dart().into_dart(),
+            self.avg_phase_ms.into_into_dart().into_dart(),
+            self.total_retries.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::MetricsSnapshot
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::MetricsSnapshot>
+    for crate::api::data_models::MetricsSnapshot
+{
+    fn into_into_dart(self) -> crate::api::data_models::MetricsSnapshot {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::ModelInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.context_tokens.into_into_dart().into_dart(),
+            self.max_output_tokens.into_into_dart().into_dart(),
+            self.supports_thinking.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::ModelInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::ModelInfo>
+    for crate::api::data_models::ModelInfo
+{
+    fn into_into_dart(self) -> crate::api::data_models::ModelInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::MoodState {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.valence.into_into_dart().into_dart(),
+            self.arousal.into_into_dart().into_dart(),
+            self.mood.into_into_dart().into_dart(),
+            self.updated_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::MoodState
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::MoodState>
+    for crate::api::data_models::MoodState
+{
+    fn into_into_dart(self) -> crate::api::data_models::MoodState {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::NarrationPerspective {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Free => 0.into_dart(),
+            Self::FirstPerson => 1.into_dart(),
+            Self::ThirdPerson => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::NarrationPerspective
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::NarrationPerspective>
+    for crate::api::data_models::NarrationPerspective
+{
+    fn into_into_dart(self) -> crate::api::data_models::NarrationPerspective {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::OutboxEntry {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.conversation_id.into_into_dart().into_dart(),
+            self.content.into_into_dart().into_dart(),
+            self.attachments.into_into_dart().into_dart(),
+            self.model.into_into_dart().into_dart(),
+            self.enable_thinking.into_into_dart().into_dart(),
+            self.status.into_into_dart().into_dart(),
+            self.queued_at.into_into_dart().into_dart(),
+            self.last_error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::OutboxEntry
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::OutboxEntry>
+    for crate::api::data_models::OutboxEntry
+{
+    fn into_into_dart(self) -> crate::api::data_models::OutboxEntry {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::OutboxStatus {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Queued => 0.into_dart(),
+            Self::Sending => 1.into_dart(),
+            Self::Failed => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::OutboxStatus
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::OutboxStatus>
+    for crate::api::data_models::OutboxStatus
+{
+    fn into_into_dart(self) -> crate::api::data_models::OutboxStatus {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::PhaseDuration {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.phase.into_into_dart().into_dart(),
+            self.duration_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::PhaseDuration
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::PhaseDuration>
+    for crate::api::data_models::PhaseDuration
+{
+    fn into_into_dart(self) -> crate::api::data_models::PhaseDuration {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::PreflightAdvisory {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.tone.into_into_dart().into_dart(),
+            self.regret_risk.into_into_dart().into_dart(),
+            self.tension_delta.into_into_dart().into_dart(),
+            self.closeness_delta.into_into_dart().into_dart(),
+            self.late_night.into_into_dart().into_dart(),
+            self.suggest_pause.into_into_dart().into_dart(),
+            self.notes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::PreflightAdvisory
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::PreflightAdvisory>
+    for crate::api::data_models::PreflightAdvisory
+{
+    fn into_into_dart(self) -> crate::api::data_models::PreflightAdvisory {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::PromptTemplateInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
+            self.variables.into_into_dart().into_dart(),
+            self.customized.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::PromptTemplateInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::PromptTemplateInfo>
+    for crate::api::data_models::PromptTemplateInfo
+{
+    fn into_into_dart(self) -> crate::api::data_models::PromptTemplateInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::ProviderKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Zhipu => 0.into_dart(),
+            Self::LocalEcho => 1.into_dart(),
+            Self::OpenAiCompatible => 2.into_dart(),
+            Self::Anthropic => 3.into_dart(),
+            Self::LocalLlm => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::ProviderKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::ProviderKind>
+    for crate::api::data_models::ProviderKind
+{
+    fn into_into_dart(self) -> crate::api::data_models::ProviderKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::ProxySettings {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.url.into_into_dart().into_dart(),
+            self.username.into_into_dart().into_dart(),
+            self.password.into_into_dart().into_dart(),
+            self.bypass.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::ProxySettings
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::ProxySettings>
+    for crate::api::data_models::ProxySettings
+{
+    fn into_into_dart(self) -> crate::api::data_models::ProxySettings {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::ReactionEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.emoji.into_into_dart().into_dart(),
+            self.label.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::ReactionEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::ReactionEvent>
+    for crate::api::data_models::ReactionEvent
+{
+    fn into_into_dart(self) -> crate::api::data_models::ReactionEvent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::ReengagementBrief {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.conversation_id.into_into_dart().into_dart(),
+            self.elapsed_days.into_into_dart().into_dart(),
+            self.recap.into_into_dart().into_dart(),
+            self.opener.into_into_dart().into_dart(),
+            self.pending_threads.into_into_dart().into_dart(),
+            self.generated_at.into_into_dart().into_dart(),
+            self.last_message_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::ReengagementBrief
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::ReengagementBrief>
+    for crate::api::data_models::ReengagementBrief
+{
+    fn into_into_dart(self) -> crate::api::data_models::ReengagementBrief {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::ReindexProgress {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.conversation_id.into_into_dart().into_dart(),
+            self.completed.into_into_dart().into_dart(),
+            self.total.into_into_dart().into_dart(),
+            self.rebuilt_entries.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::ReindexProgress
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::ReindexProgress>
+    for crate::api::data_models::ReindexProgress
+{
+    fn into_into_dart(self) -> crate::api::data_models::ReindexProgress {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::RelationChange {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.relation.into_into_dart().into_dart(),
+            self.fact_id.into_into_dart().into_dart(),
+            self.source_turn.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::RelationChange
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::RelationChange>
+    for crate::api::data_models::RelationChange
+{
+    fn into_into_dart(self) -> crate::api::data_models::RelationChange {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::RelationSentiment {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Positive => 0.into_dart(),
+            Self::Negative => 1.into_dart(),
+            Self::Neutral => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::RelationSentiment
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::RelationSentiment>
+    for crate::api::data_models::RelationSentiment
+{
+    fn into_into_dart(self) -> crate::api::data_models::RelationSentiment {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::RelationshipInsight {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.closeness.into_into_dart().into_dart(),
+            self.trust_level.into_into_dart().into_dart(),
+            self.tension.into_into_dart().into_dart(),
+            self.power_balance.into_into_dart().into_dart(),
+            self.trend.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::RelationshipInsight
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::RelationshipInsight>
+    for crate::api::data_models::RelationshipInsight
+{
+    fn into_into_dart(self) -> crate::api::data_models::RelationshipInsight {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::RelevanceInsight {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.tfidf_score.into_into_dart().into_dart(),
+            self.keyword_overlap.into_into_dart().into_dart(),
+            self.topic_match.into_into_dart().into_dart(),
+            self.final_score.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::RelevanceInsight
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::RelevanceInsight>
+    for crate::api::data_models::RelevanceInsight
+{
+    fn into_into_dart(self) -> crate::api::data_models::RelevanceInsight {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::ReplyLength {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Auto => 0.into_dart(),
+            Self::Short => 1.into_dart(),
+            Self::Medium => 2.into_dart(),
+            Self::Long => 3.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::ReplyLength
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::ReplyLength>
+    for crate::api::data_models::ReplyLength
+{
+    fn into_into_dart(self) -> crate::api::data_models::ReplyLength {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::ReplySignals {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.length_chars.into_into_dart().into_dart(),
+            self.grounded_facts.into_into_dart().into_dart(),
+            self.user_overlap.into_into_dart().into_dart(),
+            self.repetition.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::ReplySignals
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::ReplySignals>
+    for crate::api::data_models::ReplySignals
+{
+    fn into_into_dart(self) -> crate::api::data_models::ReplySignals {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::ResponseStyle {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.reply_length.into_into_dart().into_dart(),
+            self.formality.into_into_dart().into_dart(),
+            self.emoji_usage.into_into_dart().into_dart(),
+            self.action_frequency.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::ResponseStyle
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::ResponseStyle>
+    for crate::api::data_models::ResponseStyle
+{
+    fn into_into_dart(self) -> crate::api::data_models::ResponseStyle {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::RetentionPolicy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.archive_after_idle_days.into_into_dart().into_dart(),
+            self.max_messages_per_conversation
+                .into_into_dart()
+                .into_dart(),
+            self.purge_orphans.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::RetentionPolicy
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::RetentionPolicy>
+    for crate::api::data_models::RetentionPolicy
+{
+    fn into_into_dart(self) -> crate::api::data_models::RetentionPolicy {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::RetryPolicy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.max_retries.into_into_dart().into_dart(),
+            self.initial_delay_ms.into_into_dart().into_dart(),
+            self.max_delay_ms.into_into_dart().into_dart(),
+            self.backoff.into_into_dart().into_dart(),
+            self.retry_network_errors.into_into_dart().into_dart(),
+            self.retry_stream_errors.into_into_dart().into_dart(),
+            self.retry_server_errors.into_into_dart().into_dart(),
+            self.retry_rate_limited.into_into_dart().into_dart(),
+            self.max_retry_after_secs.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::RetryPolicy
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::RetryPolicy>
+    for crate::api::data_models::RetryPolicy
+{
+    fn into_into_dart(self) -> crate::api::data_models::RetryPolicy {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::SafetyAction {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Block => 0.into_dart(),
+            Self::Soften => 1.into_dart(),
+            Self::Warn => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::SafetyAction
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::SafetyAction>
+    for crate::api::data_models::SafetyAction
+{
+    fn into_into_dart(self) -> crate::api::data_models::SafetyAction {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::SafetyCategory {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::SelfHarm => 0.into_dart(),
+            Self::Violence => 1.into_dart(),
+            Self::Sexual => 2.into_dart(),
+            Self::Hate => 3.into_dart(),
+            Self::Illegal => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::SafetyCategory
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::SafetyCategory>
+    for crate::api::data_models::SafetyCategory
+{
+    fn into_into_dart(self) -> crate::api::data_models::SafetyCategory {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::SafetyEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.stage.into_into_dart().into_dart(),
+            self.category.into_into_dart().into_dart(),
+            self.action.into_into_dart().into_dart(),
+            self.matched.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::SafetyEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::SafetyEvent>
+    for crate::api::data_models::SafetyEvent
+{
+    fn into_into_dart(self) -> crate::api::data_models::SafetyEvent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::SafetyPolicy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.enabled.into_into_dart().into_dart(),
+            self.check_input.into_into_dart().into_dart(),
+            self.check_output.into_into_dart().into_dart(),
+            self.rules.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::SafetyPolicy
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::SafetyPolicy>
+    for crate::api::data_models::SafetyPolicy
+{
+    fn into_into_dart(self) -> crate::api::data_models::SafetyPolicy {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::SafetyRule {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.category.into_into_dart().into_dart(),
+            self.action.into_into_dart().into_dart(),
+            self.extra_terms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::SafetyRule
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::SafetyRule>
+    for crate::api::data_models::SafetyRule
+{
+    fn into_into_dart(self) -> crate::api::data_models::SafetyRule {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::SafetyStage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Input => 0.into_dart(),
+            Self::Output => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::SafetyStage
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::SafetyStage>
+    for crate::api::data_models::SafetyStage
+{
+    fn into_into_dart(self) -> crate::api::data_models::SafetyStage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::SayDoMark {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Say => 0.into_dart(),
+            Self::Do => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::SayDoMark
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::SayDoMark>
+    for crate::api::data_models::SayDoMark
+{
+    fn into_into_dart(self) -> crate::api::data_models::SayDoMark {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::SayDoRule {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.pattern.into_into_dart().into_dart(),
+            self.mark.into_into_dart().into_dart(),
+            self.priority.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::SayDoRule
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::SayDoRule>
+    for crate::api::data_models::SayDoRule
+{
+    fn into_into_dart(self) -> crate::api::data_models::SayDoRule {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::SayDoRuleSet {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.rules.into_into_dart().into_dart(),
+            self.unmatched.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::SayDoRuleSet
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::SayDoRuleSet>
+    for crate::api::data_models::SayDoRuleSet
+{
+    fn into_into_dart(self) -> crate::api::data_models::SayDoRuleSet {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::SearchHit {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.conversation_id.into_into_dart().into_dart(),
+            self.conversation_title.into_into_dart().into_dart(),
+            self.message_id.into_into_dart().into_dart(),
+            self.role.into_into_dart().into_dart(),
+            self.snippet.into_into_dart().into_dart(),
+            self.highlight_start.into_into_dart().into_dart(),
+            self.highlight_end.into_into_dart().into_dart(),
+            self.timestamp.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::SearchHit
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::SearchHit>
+    for crate::api::data_models::SearchHit
+{
+    fn into_into_dart(self) -> crate::api::data_models::SearchHit {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::ShadowEvalRecord {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.turn.into_into_dart().into_dart(),
+            self.injected_facts.into_into_dart().into_dart(),
+            self.main_model.into_into_dart().into_dart(),
+            self.shadow_model.into_into_dart().into_dart(),
+            self.main.into_into_dart().into_dart(),
+            self.shadow.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::ShadowEvalRecord
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::ShadowEvalRecord>
+    for crate::api::data_models::ShadowEvalRecord
+{
+    fn into_into_dart(self) -> crate::api::data_models::ShadowEvalRecord {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::ShadowEvalSummary {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.samples.into_into_dart().into_dart(),
+            self.avg_grounded_gain.into_into_dart().into_dart(),
+            self.avg_overlap_gain.into_into_dart().into_dart(),
+            self.avg_repetition_delta.into_into_dart().into_dart(),
+            self.grounded_win_rate.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::ShadowEvalSummary
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::ShadowEvalSummary>
+    for crate::api::data_models::ShadowEvalSummary
+{
+    fn into_into_dart(self) -> crate::api::data_models::ShadowEvalSummary {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::ShortTermInsight {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.active_topics.into_into_dart().into_dart(),
+            self.emotional_arc.into_into_dart().into_dart(),
+            self.pending_threads.into_into_dart().into_dart(),
+            self.response_fingerprints.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::ShortTermInsight
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::ShortTermInsight>
+    for crate::api::data_models::ShortTermInsight
+{
+    fn into_into_dart(self) -> crate::api::data_models::ShortTermInsight {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::SummarizationConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.interval_turns.into_into_dart().into_dart(),
+            self.window_messages.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::SummarizationConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::SummarizationConfig>
+    for crate::api::data_models::SummarizationConfig
+{
+    fn into_into_dart(self) -> crate::api::data_models::SummarizationConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::SyncBackendKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::WebDav => 0.into_dart(),
+            Self::S3 => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::SyncBackendKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::SyncBackendKind>
+    for crate::api::data_models::SyncBackendKind
+{
+    fn into_into_dart(self) -> crate::api::data_models::SyncBackendKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::SyncReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.pushed.into_into_dart().into_dart(),
+            self.pulled.into_into_dart().into_dart(),
+            self.deleted.into_into_dart().into_dart(),
+            self.conflicts.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::SyncReport
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::SyncReport>
+    for crate::api::data_models::SyncReport
+{
+    fn into_into_dart(self) -> crate::api::data_models::SyncReport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::SyncSettings {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.backend.into_into_dart().into_dart(),
+            self.endpoint.into_into_dart().into_dart(),
+            self.username.into_into_dart().into_dart(),
+            self.password.into_into_dart().into_dart(),
+            self.bucket.into_into_dart().into_dart(),
+            self.region.into_into_dart().into_dart(),
+            self.remote_root.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::SyncSettings
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::SyncSettings>
+    for crate::api::data_models::SyncSettings
+{
+    fn into_into_dart(self) -> crate::api::data_models::SyncSettings {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::ThinkingRetention {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::data_models::ThinkingRetention::KeepAll => [0.into_dart()].into_dart(),
+            crate::api::data_models::ThinkingRetention::KeepLastTurns(field0) => {
+                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::data_models::ThinkingRetention::DiscardAfterSummary => {
+                [2.into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::ThinkingRetention
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::ThinkingRetention>
+    for crate::api::data_models::ThinkingRetention
+{
+    fn into_into_dart(self) -> crate::api::data_models::ThinkingRetention {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::TimelineEntry {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.text.into_into_dart().into_dart(),
+            self.turn_start.into_into_dart().into_dart(),
+            self.turn_end.into_into_dart().into_dart(),
+            self.timestamp.into_into_dart().into_dart(),
+            self.end_timestamp.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::TimelineEntry
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::TimelineEntry>
+    for crate::api::data_models::TimelineEntry
+{
+    fn into_into_dart(self) -> crate::api::data_models::TimelineEntry {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::TimelineEntryKind {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Chapter => 0.into_dart(),
+            Self::Event => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::TimelineEntryKind
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::TimelineEntryKind>
+    for crate::api::data_models::TimelineEntryKind
+{
+    fn into_into_dart(self) -> crate::api::data_models::TimelineEntryKind {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::TurnMetrics {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.conversation_id.into_into_dart().into_dart(),
+            self.started_at.into_into_dart().into_dart(),
+            self.total_ms.into_into_dart().into_dart(),
+            self.first_token_ms.into_into_dart().into_dart(),
+            self.phases.into_into_dart().into_dart(),
+            self.retries.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::TurnMetrics
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::TurnMetrics>
+    for crate::api::data_models::TurnMetrics
+{
+    fn into_into_dart(self) -> crate::api::data_models::TurnMetrics {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::TurnPolicy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::RoundRobin => 0.into_dart(),
+            Self::ModelChosen => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::TurnPolicy
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::TurnPolicy>
+    for crate::api::data_models::TurnPolicy
+{
+    fn into_into_dart(self) -> crate::api::data_models::TurnPolicy {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::data_models::UserPersona {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.id.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
+            self.pronouns.into_into_dart().into_dart(),
+            self.created_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::data_models::UserPersona
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::UserPersona>
+    for crate::api::data_models::UserPersona
+{
+    fn into_into_dart(self) -> crate::api::data_models::UserPersona {
+        self
+    }
+}
+
+impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(format!("{:?}", self), serializer);
+    }
+}
+
+impl SseEncode for std::collections::HashMap<String, String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<(String, String)>>::sse_encode(self.into_iter().collect(), serializer);
+    }
+}
+
+impl SseEncode
+    for StreamSink<
+        crate::api::data_models::BackgroundTaskEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<
+        crate::api::data_models::ChatStreamEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode
+    for StreamSink<
+        crate::api::data_models::ReindexProgress,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
+impl SseEncode for String {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::ActionFrequency {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::data_models::ActionFrequency::Auto => 0,
+                crate::api::data_models::ActionFrequency::Never => 1,
+                crate::api::data_models::ActionFrequency::Occasional => 2,
+                crate::api::data_models::ActionFrequency::Frequent => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::data_models::AmbientContext {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.weather, serializer);
+        <Option<u32>>::sse_encode(self.step_count, serializer);
+        <Option<String>>::sse_encode(self.now_playing, serializer);
+        <Option<bool>>::sse_encode(self.calendar_busy, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::AppSettings {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.api_key, serializer);
+        <String>::sse_encode(self.default_model, serializer);
+        <bool>::sse_encode(self.enable_thinking_by_default, serializer);
+        <String>::sse_encode(self.chat_model, serializer);
+        <String>::sse_encode(self.thinking_model, serializer);
+        <bool>::sse_encode(self.enable_web_search, serializer);
+        <crate::api::data_models::ContentIntensity>::sse_encode(self.content_intensity, serializer);
+        <crate::api::data_models::ProviderKind>::sse_encode(self.provider, serializer);
+        <Option<String>>::sse_encode(self.provider_base_url, serializer);
+        <Option<String>>::sse_encode(self.provider_model, serializer);
+        <Option<String>>::sse_encode(self.provider_api_key, serializer);
+        <f64>::sse_encode(self.shadow_eval_rate, serializer);
+        <bool>::sse_encode(self.enable_tts, serializer);
+        <Option<crate::api::data_models::ProxySettings>>::sse_encode(self.proxy, serializer);
+        <crate::api::data_models::RetryPolicy>::sse_encode(self.retry, serializer);
+        <bool>::sse_encode(self.time_awareness, serializer);
+        <bool>::sse_encode(self.fast_draft, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::AudioReadyEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.message_id, serializer);
+        <String>::sse_encode(self.audio_path, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::BackgroundJobKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::data_models::BackgroundJobKind::FactExtraction => 0,
+                crate::api::data_models::BackgroundJobKind::Summarization => 1,
+                crate::api::data_models::BackgroundJobKind::Distillation => 2,
+                crate::api::data_models::BackgroundJobKind::ShadowEval => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::data_models::BackgroundTaskEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.task_id, serializer);
+        <crate::api::data_models::BackgroundJobKind>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.conversation_id, serializer);
+        <crate::api::data_models::BackgroundTaskStatus>::sse_encode(self.status, serializer);
+        <i64>::sse_encode(self.updated_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::BackgroundTaskStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::data_models::BackgroundTaskStatus::Queued => 0,
+                crate::api::data_models::BackgroundTaskStatus::Running => 1,
+                crate::api::data_models::BackgroundTaskStatus::Completed => 2,
+                crate::api::data_models::BackgroundTaskStatus::Failed => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::data_models::BackoffStrategy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::data_models::BackoffStrategy::Fixed => 0,
+                crate::api::data_models::BackoffStrategy::Exponential => 1,
+                crate::api::data_models::BackoffStrategy::ExponentialJitter => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::data_models::BackupRetention {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.keep_last, serializer);
+        <u32>::sse_encode(self.keep_daily, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::BackupScope {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::data_models::BackupScope::All => 0,
+                crate::api::data_models::BackupScope::Conversations => 1,
+                crate::api::data_models::BackupScope::Memory => 2,
+                crate::api::data_models::BackupScope::Knowledge => 3,
+                crate::api::data_models::BackupScope::Config => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::data_models::BackupSnapshotInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <i64>::sse_encode(self.created_at, serializer);
+        <u32>::sse_encode(self.file_count, serializer);
+        <u64>::sse_encode(self.total_bytes, serializer);
+        <u64>::sse_encode(self.added_bytes, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::BlockedTopic {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.topic, serializer);
+        <Vec<String>>::sse_encode(self.keywords, serializer);
+        <Vec<String>>::sse_encode(self.evidence, serializer);
+        <u32>::sse_encode(self.occurrences, serializer);
+        <bool>::sse_encode(self.explicit, serializer);
+        <bool>::sse_encode(self.active, serializer);
+        <i64>::sse_encode(self.first_seen, serializer);
+        <i64>::sse_encode(self.last_seen, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::BlockingStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.tasks, serializer);
+        <u64>::sse_encode(self.total_run_ms, serializer);
+        <u64>::sse_encode(self.max_run_ms, serializer);
+        <u64>::sse_encode(self.max_queue_ms, serializer);
+        <String>::sse_encode(self.slowest_label, serializer);
+    }
+}
+
+impl SseEncode for bool {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u8(self as _).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::data_models::BranchInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <Option<String>>::sse_encode(self.parent_message_id, serializer);
+        <u32>::sse_encode(self.message_count, serializer);
+        <String>::sse_encode(self.last_message_preview, serializer);
+        <i64>::sse_encode(self.created_at, serializer);
+        <bool>::sse_encode(self.is_active, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::CharacterCard {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.system_prompt, serializer);
+        <String>::sse_encode(self.greeting, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::CharacterRelation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.from, serializer);
+        <String>::sse_encode(self.to, serializer);
+        <String>::sse_encode(self.relation, serializer);
+        <crate::api::data_models::RelationSentiment>::sse_encode(self.sentiment, serializer);
+        <String>::sse_encode(self.fact_id, serializer);
+        <u32>::sse_encode(self.source_turn, serializer);
+        <Vec<crate::api::data_models::RelationChange>>::sse_encode(self.history, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::ChatStreamEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::data_models::ChatStreamEvent::ContentDelta(field0) => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::data_models::ChatStreamEvent::ThinkingDelta(field0) => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::data_models::ChatStreamEvent::Done => {
+                <i32>::sse_encode(2, serializer);
+            }
+            crate::api::data_models::ChatStreamEvent::Error(field0) => {
+                <i32>::sse_encode(3, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::data_models::ChatStreamEvent::ConfigChanged => {
+                <i32>::sse_encode(4, serializer);
+            }
+            crate::api::data_models::ChatStreamEvent::Reaction(field0) => {
+                <i32>::sse_encode(5, serializer);
+                <crate::api::data_models::ReactionEvent>::sse_encode(field0, serializer);
+            }
+            crate::api::data_models::ChatStreamEvent::SystemNotice(field0) => {
+                <i32>::sse_encode(6, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::data_models::ChatStreamEvent::Illustration(field0) => {
+                <i32>::sse_encode(7, serializer);
+                <crate::api::data_models::Message>::sse_encode(field0, serializer);
+            }
+            crate::api::data_models::ChatStreamEvent::AudioReady(field0) => {
+                <i32>::sse_encode(8, serializer);
+                <crate::api::data_models::AudioReadyEvent>::sse_encode(field0, serializer);
+            }
+            crate::api::data_models::ChatStreamEvent::Completed(field0) => {
+                <i32>::sse_encode(9, serializer);
+                <crate::api::data_models::GenerationCompletedEvent>::sse_encode(field0, serializer);
+            }
+            crate::api::data_models::ChatStreamEvent::Outbox(field0) => {
+                <i32>::sse_encode(10, serializer);
+                <crate::api::data_models::OutboxEntry>::sse_encode(field0, serializer);
+            }
+            crate::api::data_models::ChatStreamEvent::Safety(field0) => {
+                <i32>::sse_encode(11, serializer);
+                <crate::api::data_models::SafetyEvent>::sse_encode(field0, serializer);
+            }
+            crate::api::data_models::ChatStreamEvent::CheckIn(field0) => {
+                <i32>::sse_encode(12, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::data_models::ChatStreamEvent::SummaryPreview(field0) => {
+                <i32>::sse_encode(13, serializer);
+                <crate::api::data_models::MemorySummary>::sse_encode(field0, serializer);
+            }
+            crate::api::data_models::ChatStreamEvent::DraftDelta(field0) => {
+                <i32>::sse_encode(14, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::data_models::ChatStreamEvent::Refined(field0) => {
+                <i32>::sse_encode(15, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::data_models::CheckInSchedule {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.conversation_id, serializer);
+        <bool>::sse_encode(self.enabled, serializer);
+        <u32>::sse_encode(self.silence_hours, serializer);
+        <Option<i64>>::sse_encode(self.last_sent_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::CognitiveInsight {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::data_models::EmotionInsight>::sse_encode(self.emotion, serializer);
+        <crate::api::data_models::IntentKind>::sse_encode(self.intent, serializer);
+        <crate::api::data_models::RelationshipInsight>::sse_encode(self.relationship, serializer);
+        <crate::api::data_models::EmpathyKind>::sse_encode(self.empathy_strategy, serializer);
+        <Vec<crate::api::data_models::LanguagePatternKind>>::sse_encode(
+            self.detected_patterns,
+            serializer,
+        );
+        <String>::sse_encode(self.cognitive_prompt, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::ContentIntensity {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::data_models::ContentIntensity::Mild => 0,
+                crate::api::data_models::ContentIntensity::Normal => 1,
+                crate::api::data_models::ContentIntensity::Dramatic => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::data_models::ContextLayers {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.short_term_memory, serializer);
+        <bool>::sse_encode(self.cognitive_snapshot, serializer);
+        <bool>::sse_encode(self.diversity_hint, serializer);
+        <bool>::sse_encode(self.humanization_hint, serializer);
+        <bool>::sse_encode(self.knowledge_injection, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::Conversation {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.title, serializer);
+        <Vec<crate::api::data_models::Message>>::sse_encode(self.messages, serializer);
+        <String>::sse_encode(self.model, serializer);
+        <i64>::sse_encode(self.created_at, serializer);
+        <i64>::sse_encode(self.updated_at, serializer);
+        <crate::api::data_models::DialogueStyle>::sse_encode(self.dialogue_style, serializer);
+        <u32>::sse_encode(self.turn_count, serializer);
+        <Vec<crate::api::data_models::MemorySummary>>::sse_encode(
+            self.memory_summaries,
+            serializer,
+        );
+        <crate::api::data_models::ConversationMode>::sse_encode(self.mode, serializer);
+        <crate::api::data_models::ThinkingRetention>::sse_encode(
+            self.thinking_retention,
+            serializer,
+        );
+        <String>::sse_encode(self.branch_id, serializer);
+        <Option<String>>::sse_encode(self.parent_message_id, serializer);
+        <Vec<crate::api::data_models::ConversationBranch>>::sse_encode(self.branches, serializer);
+        <crate::api::data_models::NarrationPerspective>::sse_encode(self.narration, serializer);
+        <crate::api::data_models::ContextLayers>::sse_encode(self.context_layers, serializer);
+        <Option<i64>>::sse_encode(self.closed_at, serializer);
+        <crate::api::data_models::ResponseStyle>::sse_encode(self.response_style, serializer);
+        <Option<String>>::sse_encode(self.persona_id, serializer);
+        <crate::api::data_models::ConversationOrganization>::sse_encode(
+            self.organization,
+            serializer,
+        );
+        <u32>::sse_encode(self.cold_turns, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::ConversationBranch {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <Option<String>>::sse_encode(self.parent_message_id, serializer);
+        <Vec<crate::api::data_models::Message>>::sse_encode(self.messages, serializer);
+        <u32>::sse_encode(self.turn_count, serializer);
+        <Vec<crate::api::data_models::MemorySummary>>::sse_encode(
+            self.memory_summaries,
+            serializer,
+        );
+        <i64>::sse_encode(self.created_at, serializer);
+        <u32>::sse_encode(self.cold_turns, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::ConversationClosure {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.epilogue, serializer);
+        <String>::sse_encode(self.recap, serializer);
+        <String>::sse_encode(self.archive_path, serializer);
+        <i64>::sse_encode(self.closed_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::ConversationFilter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.folder, serializer);
+        <Vec<String>>::sse_encode(self.tags, serializer);
+        <bool>::sse_encode(self.archived, serializer);
+        <String>::sse_encode(self.query, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::ConversationInsights {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<crate::api::data_models::CognitiveInsight>>::sse_encode(self.cognitive, serializer);
+        <crate::api::data_models::ShortTermInsight>::sse_encode(self.short_term, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::ConversationMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::data_models::ConversationMode::Chat => 0,
+                crate::api::data_models::ConversationMode::CoAuthor => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::data_models::ConversationOrganization {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<String>>::sse_encode(self.tags, serializer);
+        <Option<String>>::sse_encode(self.folder, serializer);
+        <bool>::sse_encode(self.pinned, serializer);
+        <bool>::sse_encode(self.archived, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::ConversationSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.title, serializer);
+        <String>::sse_encode(self.last_message_preview, serializer);
+        <String>::sse_encode(self.model, serializer);
+        <i64>::sse_encode(self.updated_at, serializer);
+        <crate::api::data_models::ConversationOrganization>::sse_encode(
+            self.organization,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::data_models::DailyDigest {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i64>::sse_encode(self.generated_at, serializer);
+        <Vec<crate::api::data_models::DigestEntry>>::sse_encode(self.entries, serializer);
+        <u32>::sse_encode(self.skipped, serializer);
+        <u32>::sse_encode(self.estimated_tokens, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::DataExportInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.format_version, serializer);
+        <u32>::sse_encode(self.layout_version, serializer);
+        <i64>::sse_encode(self.created_at, serializer);
+        <u32>::sse_encode(self.file_count, serializer);
+        <u64>::sse_encode(self.total_bytes, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::DeferredJob {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::data_models::BackgroundJobKind>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.conversation_id, serializer);
+        <i64>::sse_encode(self.deferred_at, serializer);
+        <String>::sse_encode(self.reason, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::DegradationKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::data_models::DegradationKind::ThinkingDropped => 0,
+                crate::api::data_models::DegradationKind::CompactRetry => 1,
+                crate::api::data_models::DegradationKind::ModelFallback => 2,
+                crate::api::data_models::DegradationKind::ReasoningTimeout => 3,
+                crate::api::data_models::DegradationKind::DistillationTimeout => 4,
+                crate::api::data_models::DegradationKind::ContextTruncated => 5,
+                crate::api::data_models::DegradationKind::FactExtractionTimeout => 6,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::data_models::DegradationRecord {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.turn, serializer);
+        <crate::api::data_models::DegradationKind>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.model, serializer);
+        <String>::sse_encode(self.detail, serializer);
+        <i64>::sse_encode(self.timestamp, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::DeviceConditions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<f64>>::sse_encode(self.battery_level, serializer);
+        <bool>::sse_encode(self.is_charging, serializer);
+        <bool>::sse_encode(self.low_power_mode, serializer);
+        <bool>::sse_encode(self.metered_network, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::DialogueStyle {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::data_models::DialogueStyle::Free => 0,
+                crate::api::data_models::DialogueStyle::SayOnly => 1,
+                crate::api::data_models::DialogueStyle::DoOnly => 2,
+                crate::api::data_models::DialogueStyle::Mixed => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::data_models::DigestEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.conversation_id, serializer);
+        <String>::sse_encode(self.title, serializer);
+        <String>::sse_encode(self.note, serializer);
+        <i64>::sse_encode(self.last_active_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::DistilledSystemState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.core_prompt, serializer);
+        <usize>::sse_encode(self.last_memory_count, serializer);
+        <u32>::sse_encode(self.last_max_compression_gen, serializer);
+        <u64>::sse_encode(self.character_prompt_hash, serializer);
+        <u32>::sse_encode(self.last_turn_count, serializer);
+        <i64>::sse_encode(self.distilled_at, serializer);
+        <Vec<String>>::sse_encode(self.core_facts_snapshot, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::DraftTone {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::data_models::DraftTone::Calm => 0,
+                crate::api::data_models::DraftTone::Warm => 1,
+                crate::api::data_models::DraftTone::Playful => 2,
+                crate::api::data_models::DraftTone::Sad => 3,
+                crate::api::data_models::DraftTone::Anxious => 4,
+                crate::api::data_models::DraftTone::Sarcastic => 5,
+                crate::api::data_models::DraftTone::Hostile => 6,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::data_models::EmojiUsage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::data_models::EmojiUsage::Auto => 0,
+                crate::api::data_models::EmojiUsage::Never => 1,
+                crate::api::data_models::EmojiUsage::Occasional => 2,
+                crate::api::data_models::EmojiUsage::Frequent => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::data_models::EmotionDimension {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::data_models::EmotionDimension::Joy => 0,
+                crate::api::data_models::EmotionDimension::Sadness => 1,
+                crate::api::data_models::EmotionDimension::Anger => 2,
+                crate::api::data_models::EmotionDimension::Fear => 3,
+                crate::api::data_models::EmotionDimension::Surprise => 4,
+                crate::api::data_models::EmotionDimension::Intimacy => 5,
+                crate::api::data_models::EmotionDimension::Trust => 6,
+                crate::api::data_models::EmotionDimension::Anticipation => 7,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::data_models::EmotionInsight {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f64>::sse_encode(self.joy, serializer);
+        <f64>::sse_encode(self.sadness, serializer);
+        <f64>::sse_encode(self.anger, serializer);
+        <f64>::sse_encode(self.fear, serializer);
+        <f64>::sse_encode(self.surprise, serializer);
+        <f64>::sse_encode(self.intimacy, serializer);
+        <f64>::sse_encode(self.trust, serializer);
+        <f64>::sse_encode(self.anticipation, serializer);
+        <f64>::sse_encode(self.valence, serializer);
+        <f64>::sse_encode(self.arousal, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::EmotionalTurnInsight {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.turn, serializer);
+        <f64>::sse_encode(self.valence, serializer);
+        <f64>::sse_encode(self.arousal, serializer);
+        <String>::sse_encode(self.dominant_emotion, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::EmpathyKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::data_models::EmpathyKind::Mirror => 0,
+                crate::api::data_models::EmpathyKind::Accompany => 1,
+                crate::api::data_models::EmpathyKind::Distract => 2,
+                crate::api::data_models::EmpathyKind::Responsive => 3,
+                crate::api::data_models::EmpathyKind::PlayfulCounter => 4,
+                crate::api::data_models::EmpathyKind::GentleFirm => 5,
+                crate::api::data_models::EmpathyKind::ProactiveCare => 6,
+                crate::api::data_models::EmpathyKind::NaturalFlow => 7,
+                crate::api::data_models::EmpathyKind::GiveSpace => 8,
+                crate::api::data_models::EmpathyKind::Escalate => 9,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::data_models::EncryptionKeySource {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::data_models::EncryptionKeySource::Passphrase => 0,
+                crate::api::data_models::EncryptionKeySource::Keystore => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::data_models::EncryptionSecret {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::data_models::EncryptionSecret::Passphrase(field0) => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::api::data_models::EncryptionSecret::Keystore(field0) => {
+                <i32>::sse_encode(1, serializer);
+                <Vec<u8>>::sse_encode(field0, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::data_models::EncryptionStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.enabled, serializer);
+        <bool>::sse_encode(self.unlocked, serializer);
+        <Option<crate::api::data_models::EncryptionKeySource>>::sse_encode(self.source, serializer);
+    }
+}
+
+impl SseEncode for f64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::knowledge_store::Fact {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.content, serializer);
+        <crate::api::knowledge_store::FactCategory>::sse_encode(self.category, serializer);
+        <u32>::sse_encode(self.source_turn, serializer);
+        <i64>::sse_encode(self.created_at, serializer);
+        <i64>::sse_encode(self.last_confirmed_at, serializer);
+        <Vec<String>>::sse_encode(self.keywords, serializer);
+        <Vec<String>>::sse_encode(self.entities, serializer);
+        <f64>::sse_encode(self.confidence, serializer);
+        <u32>::sse_encode(self.hit_count, serializer);
+        <String>::sse_encode(self.context_snippet, serializer);
+        <Vec<String>>::sse_encode(self.summary_ids, serializer);
+        <Option<String>>::sse_encode(self.superseded_by, serializer);
+        <Option<i64>>::sse_encode(self.superseded_at, serializer);
+        <bool>::sse_encode(self.manual_override, serializer);
+        <Option<String>>::sse_encode(self.persona_id, serializer);
+    }
+}
+
+impl SseEncode for crate::api::knowledge_store::FactCategory {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::knowledge_store::FactCategory::Identity => 0,
+                crate::api::knowledge_store::FactCategory::Relationship => 1,
+                crate::api::knowledge_store::FactCategory::Preference => 2,
+                crate::api::knowledge_store::FactCategory::Event => 3,
+                crate::api::knowledge_store::FactCategory::CurrentState => 4,
+                crate::api::knowledge_store::FactCategory::Promise => 5,
+                crate::api::knowledge_store::FactCategory::Consensus => 6,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::data_models::FactConflict {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.superseded_fact_id, serializer);
+        <String>::sse_encode(self.superseded_content, serializer);
+        <String>::sse_encode(self.active_fact_id, serializer);
+        <String>::sse_encode(self.active_content, serializer);
+        <i64>::sse_encode(self.detected_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::FingerprintInsight {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.opening_chars, serializer);
+        <u32>::sse_encode(self.paragraph_count, serializer);
+        <f64>::sse_encode(self.avg_sentence_len, serializer);
+        <String>::sse_encode(self.ending_chars, serializer);
+        <bool>::sse_encode(self.ends_with_question, serializer);
+        <u32>::sse_encode(self.total_length, serializer);
+        <bool>::sse_encode(self.has_action_marker, serializer);
+        <bool>::sse_encode(self.has_list_format, serializer);
+        <String>::sse_encode(self.emotional_tone, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::Formality {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::data_models::Formality::Auto => 0,
+                crate::api::data_models::Formality::Casual => 1,
+                crate::api::data_models::Formality::Neutral => 2,
+                crate::api::data_models::Formality::Formal => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::data_models::GenerationCompletedEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.message_id, serializer);
+        <crate::api::data_models::GenerationMetadata>::sse_encode(self.metadata, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::GenerationMetadata {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.model, serializer);
+        <Option<String>>::sse_encode(self.finish_reason, serializer);
+        <u32>::sse_encode(self.prompt_tokens, serializer);
+        <u32>::sse_encode(self.completion_tokens, serializer);
+        <u32>::sse_encode(self.total_tokens, serializer);
+        <u32>::sse_encode(self.cached_tokens, serializer);
+        <bool>::sse_encode(self.streamed, serializer);
+        <bool>::sse_encode(self.interrupted, serializer);
+        <Vec<String>>::sse_encode(self.fallbacks, serializer);
+        <i64>::sse_encode(self.latency_ms, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::GraphEdge {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.fact_id, serializer);
+        <String>::sse_encode(self.source, serializer);
+        <String>::sse_encode(self.target, serializer);
+        <String>::sse_encode(self.relation, serializer);
+        <String>::sse_encode(self.category, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::GraphExportFormat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::data_models::GraphExportFormat::Json => 0,
+                crate::api::data_models::GraphExportFormat::GraphMl => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::data_models::GraphNode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.label, serializer);
+        <u32>::sse_encode(self.degree, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::GroupCharacter {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.system_prompt, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::GroupChat {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.conversation_id, serializer);
+        <Vec<crate::api::data_models::GroupCharacter>>::sse_encode(self.characters, serializer);
+        <crate::api::data_models::TurnPolicy>::sse_encode(self.turn_policy, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::HousekeepingReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<String>>::sse_encode(self.archived, serializer);
+        <u32>::sse_encode(self.rolled_over_messages, serializer);
+        <u32>::sse_encode(self.purged_files, serializer);
+    }
+}
+
+impl SseEncode for i32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for i64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
+    }
+}
+
+impl SseEncode for crate::api::data_models::IndexScope {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::data_models::IndexScope::All => 0,
+                crate::api::data_models::IndexScope::Knowledge => 1,
+                crate::api::data_models::IndexScope::Memory => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::data_models::IntegrityIssue {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::data_models::IntegrityIssueKind>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.detail, serializer);
+        <bool>::sse_encode(self.repaired, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::IntegrityIssueKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::data_models::IntegrityIssueKind::TurnCountMismatch => 0,
+                crate::api::data_models::IntegrityIssueKind::SummaryRangeInvalid => 1,
+                crate::api::data_models::IntegrityIssueKind::SummaryIndexDesync => 2,
+                crate::api::data_models::IntegrityIssueKind::OrphanFact => 3,
+                crate::api::data_models::IntegrityIssueKind::DanglingIndexEntry => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::data_models::IntegrityReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.conversation_id, serializer);
+        <u32>::sse_encode(self.actual_turns, serializer);
+        <u32>::sse_encode(self.recorded_turn_count, serializer);
+        <Vec<crate::api::data_models::IntegrityIssue>>::sse_encode(self.issues, serializer);
+        <i64>::sse_encode(self.checked_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::IntentKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::data_models::IntentKind::SeekingComfort => 0,
+                crate::api::data_models::IntentKind::ExpressingAffection => 1,
+                crate::api::data_models::IntentKind::ExpressingDispleasure => 2,
+                crate::api::data_models::IntentKind::TestingBoundary => 3,
+                crate::api::data_models::IntentKind::SharingDaily => 4,
+                crate::api::data_models::IntentKind::SeekingResponse => 5,
+                crate::api::data_models::IntentKind::EmotionalVenting => 6,
+                crate::api::data_models::IntentKind::Playful => 7,
+                crate::api::data_models::IntentKind::Reconciling => 8,
+                crate::api::data_models::IntentKind::Farewell => 9,
+                crate::api::data_models::IntentKind::Withdrawn => 10,
+                crate::api::data_models::IntentKind::DeepSharing => 11,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::data_models::JobSchedulerStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.running, serializer);
+        <u64>::sse_encode(self.completed, serializer);
+        <u64>::sse_encode(self.timed_out, serializer);
+        <u64>::sse_encode(self.deferred_total, serializer);
+        <Vec<crate::api::data_models::DeferredJob>>::sse_encode(self.pending, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::KnowledgeBinding {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<String>>::sse_encode(self.character_id, serializer);
+        <Option<String>>::sse_encode(self.character_name, serializer);
+        <Option<String>>::sse_encode(self.world_id, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::KnowledgeGraphView {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::data_models::GraphNode>>::sse_encode(self.nodes, serializer);
+        <Vec<crate::api::data_models::GraphEdge>>::sse_encode(self.edges, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::KnowledgeLayer {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::data_models::KnowledgeLayer::Conversation => 0,
+                crate::api::data_models::KnowledgeLayer::Character => 1,
+                crate::api::data_models::KnowledgeLayer::World => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::data_models::LanguagePatternKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::data_models::LanguagePatternKind::Negation => 0,
+                crate::api::data_models::LanguagePatternKind::Sarcasm => 1,
+                crate::api::data_models::LanguagePatternKind::Hesitation => 2,
+                crate::api::data_models::LanguagePatternKind::Repetition => 3,
+                crate::api::data_models::LanguagePatternKind::Urgent => 4,
+                crate::api::data_models::LanguagePatternKind::Dragging => 5,
+                crate::api::data_models::LanguagePatternKind::Contradictory => 6,
+                crate::api::data_models::LanguagePatternKind::Probing => 7,
+                crate::api::data_models::LanguagePatternKind::Coquettish => 8,
+                crate::api::data_models::LanguagePatternKind::Defensive => 9,
+                crate::api::data_models::LanguagePatternKind::Suppressed => 10,
+                crate::api::data_models::LanguagePatternKind::TopicAvoidance => 11,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::data_models::LayoutVerification {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.version, serializer);
+        <u32>::sse_encode(self.checked_files, serializer);
+        <Vec<String>>::sse_encode(self.missing, serializer);
+        <Vec<String>>::sse_encode(self.corrupted, serializer);
+        <Vec<String>>::sse_encode(self.unexpected, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::LexiconEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.word, serializer);
+        <crate::api::data_models::EmotionDimension>::sse_encode(self.dimension, serializer);
+        <f64>::sse_encode(self.weight, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::LinkedFact {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.fact_id, serializer);
+        <String>::sse_encode(self.content, serializer);
+        <u32>::sse_encode(self.source_turn, serializer);
+        <Vec<String>>::sse_encode(self.summary_ids, serializer);
+    }
+}
+
+impl SseEncode for Vec<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <String>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::data_models::BackgroundTaskEvent> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::data_models::BackgroundTaskEvent>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::data_models::BackupSnapshotInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::data_models::BackupSnapshotInfo>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::data_models::BlockedTopic> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::data_models::BlockedTopic>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::data_models::BranchInfo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <String>::sse_decode(deserializer);
-        let mut var_title = <String>::sse_decode(deserializer);
-        let mut var_lastMessagePreview = <String>::sse_decode(deserializer);
-        let mut var_model = <String>::sse_decode(deserializer);
-        let mut var_updatedAt = <i64>::sse_decode(deserializer);
-        return crate::api::data_models::ConversationSummary {
-            id: var_id,
-            title: var_title,
-            last_message_preview: var_lastMessagePreview,
-            model: var_model,
-            updated_at: var_updatedAt,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::data_models::BranchInfo>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::data_models::CharacterRelation> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::data_models::CharacterRelation>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::data_models::ConversationBranch> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::data_models::ConversationBranch>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::data_models::ConversationSummary> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::data_models::ConversationSummary>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::data_models::DeferredJob> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::data_models::DeferredJob>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::data_models::DegradationRecord> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::data_models::DegradationRecord>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::data_models::DigestEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::data_models::DigestEntry>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::data_models::EmotionalTurnInsight> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::data_models::EmotionalTurnInsight>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::knowledge_store::Fact> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::knowledge_store::Fact>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::data_models::FactConflict> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::data_models::FactConflict>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::data_models::FingerprintInsight> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::data_models::FingerprintInsight>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::data_models::GraphEdge> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::data_models::GraphEdge>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::data_models::GraphNode> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::data_models::GraphNode>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::data_models::GroupCharacter> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::data_models::GroupCharacter>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::data_models::IntegrityIssue> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::data_models::IntegrityIssue>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::data_models::LanguagePatternKind> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::data_models::LanguagePatternKind>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::data_models::LexiconEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::data_models::LexiconEntry>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::data_models::LinkedFact> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::data_models::LinkedFact>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::data_models::LoreEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::data_models::LoreEntry>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::data_models::MemoryFidelityReport> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::data_models::MemoryFidelityReport>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::data_models::MemorySearchResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::data_models::MemorySearchResult>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::data_models::MemorySummary> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::data_models::MemorySummary>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::data_models::MemoryTier> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::data_models::MemoryTier>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::data_models::Message> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::data_models::Message>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::data_models::MessageAlternative> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::data_models::MessageAlternative>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::data_models::MessageAttachment> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::data_models::MessageAttachment>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::data_models::MessageEdit> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::data_models::MessageEdit>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::data_models::ModelInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::data_models::ModelInfo>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::data_models::OutboxEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::data_models::OutboxEntry>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for crate::api::data_models::DialogueStyle {
+impl SseEncode for Vec<crate::api::data_models::PhaseDuration> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::api::data_models::DialogueStyle::Free,
-            1 => crate::api::data_models::DialogueStyle::SayOnly,
-            2 => crate::api::data_models::DialogueStyle::DoOnly,
-            3 => crate::api::data_models::DialogueStyle::Mixed,
-            _ => unreachable!("Invalid variant for DialogueStyle: {}", inner),
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::data_models::PhaseDuration>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for f64 {
+impl SseEncode for Vec<u8> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_f64::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <u8>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for i32 {
+impl SseEncode for Vec<crate::api::data_models::PromptTemplateInfo> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::data_models::PromptTemplateInfo>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for i64 {
+impl SseEncode for Vec<(String, String)> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_i64::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <(String, String)>::sse_encode(item, serializer);
+        }
     }
 }
 
-impl SseDecode for Vec<String> {
+impl SseEncode for Vec<crate::api::data_models::RelationChange> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<String>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::data_models::RelationChange>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::data_models::ConversationSummary> {
+impl SseEncode for Vec<crate::api::data_models::SafetyRule> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::data_models::ConversationSummary>::sse_decode(
-                deserializer,
-            ));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::data_models::SafetyRule>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::data_models::MemorySearchResult> {
+impl SseEncode for Vec<crate::api::data_models::SayDoRule> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::data_models::MemorySearchResult>::sse_decode(
-                deserializer,
-            ));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::data_models::SayDoRule>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::data_models::MemorySummary> {
+impl SseEncode for Vec<crate::api::data_models::SearchHit> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::data_models::MemorySummary>::sse_decode(
-                deserializer,
-            ));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::data_models::SearchHit>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::data_models::MemoryTier> {
+impl SseEncode for Vec<crate::api::data_models::ShadowEvalRecord> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::data_models::MemoryTier>::sse_decode(
-                deserializer,
-            ));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::data_models::ShadowEvalRecord>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::data_models::Message> {
+impl SseEncode for Vec<crate::api::data_models::TimelineEntry> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::data_models::Message>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::data_models::TimelineEntry>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<crate::api::data_models::ModelInfo> {
+impl SseEncode for Vec<crate::api::data_models::TurnMetrics> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<crate::api::data_models::ModelInfo>::sse_decode(
-                deserializer,
-            ));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::data_models::TurnMetrics>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for Vec<u8> {
+impl SseEncode for Vec<crate::api::data_models::UserPersona> {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut len_ = <i32>::sse_decode(deserializer);
-        let mut ans_ = vec![];
-        for idx_ in 0..len_ {
-            ans_.push(<u8>::sse_decode(deserializer));
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::data_models::UserPersona>::sse_encode(item, serializer);
         }
-        return ans_;
     }
 }
 
-impl SseDecode for crate::api::data_models::MemoryContextCard {
+impl SseEncode for crate::api::data_models::LoreEntry {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_sourceRange = <String>::sse_decode(deserializer);
-        let mut var_topicTags = <Vec<String>>::sse_decode(deserializer);
-        let mut var_keyEntities = <Vec<String>>::sse_decode(deserializer);
-        let mut var_emotionalTone = <String>::sse_decode(deserializer);
-        let mut var_causalLinks = <Vec<String>>::sse_decode(deserializer);
-        return crate::api::data_models::MemoryContextCard {
-            source_range: var_sourceRange,
-            topic_tags: var_topicTags,
-            key_entities: var_keyEntities,
-            emotional_tone: var_emotionalTone,
-            causal_links: var_causalLinks,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.title, serializer);
+        <String>::sse_encode(self.content, serializer);
+        <Vec<String>>::sse_encode(self.keywords, serializer);
+        <u32>::sse_encode(self.insertion_depth, serializer);
+        <i32>::sse_encode(self.priority, serializer);
+        <bool>::sse_encode(self.enabled, serializer);
     }
 }
 
-impl SseDecode for crate::api::data_models::MemorySearchResult {
+impl SseEncode for crate::api::data_models::MemoryContextCard {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_summary = <String>::sse_decode(deserializer);
-        let mut var_coreFacts = <Vec<String>>::sse_decode(deserializer);
-        let mut var_relevanceScore = <f64>::sse_decode(deserializer);
-        return crate::api::data_models::MemorySearchResult {
-            summary: var_summary,
-            core_facts: var_coreFacts,
-            relevance_score: var_relevanceScore,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.source_range, serializer);
+        <Vec<String>>::sse_encode(self.topic_tags, serializer);
+        <Vec<String>>::sse_encode(self.key_entities, serializer);
+        <String>::sse_encode(self.emotional_tone, serializer);
+        <Vec<String>>::sse_encode(self.causal_links, serializer);
     }
 }
 
-impl SseDecode for crate::api::data_models::MemorySummary {
+impl SseEncode for crate::api::data_models::MemoryExplanation {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <String>::sse_decode(deserializer);
-        let mut var_summary = <String>::sse_decode(deserializer);
-        let mut var_coreFacts = <Vec<String>>::sse_decode(deserializer);
-        let mut var_turnRangeStart = <u32>::sse_decode(deserializer);
-        let mut var_turnRangeEnd = <u32>::sse_decode(deserializer);
-        let mut var_createdAt = <i64>::sse_decode(deserializer);
-        let mut var_keywords = <Vec<String>>::sse_decode(deserializer);
-        let mut var_compressionGeneration = <u32>::sse_decode(deserializer);
-        let mut var_contextCard =
-            <Option<crate::api::data_models::MemoryContextCard>>::sse_decode(deserializer);
-        let mut var_factTiers =
-            <Vec<crate::api::data_models::MemoryTier>>::sse_decode(deserializer);
-        return crate::api::data_models::MemorySummary {
-            id: var_id,
-            summary: var_summary,
-            core_facts: var_coreFacts,
-            turn_range_start: var_turnRangeStart,
-            turn_range_end: var_turnRangeEnd,
-            created_at: var_createdAt,
-            keywords: var_keywords,
-            compression_generation: var_compressionGeneration,
-            context_card: var_contextCard,
-            fact_tiers: var_factTiers,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.summary_id, serializer);
+        <String>::sse_encode(self.summary, serializer);
+        <u32>::sse_encode(self.turn_range_start, serializer);
+        <u32>::sse_encode(self.turn_range_end, serializer);
+        <u32>::sse_encode(self.compression_generation, serializer);
+        <Vec<crate::api::data_models::LinkedFact>>::sse_encode(self.linked_facts, serializer);
     }
 }
 
-impl SseDecode for crate::api::data_models::MemoryTier {
+impl SseEncode for crate::api::data_models::MemoryFidelityReport {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::api::data_models::MemoryTier::Identity,
-            1 => crate::api::data_models::MemoryTier::CriticalEvent,
-            2 => crate::api::data_models::MemoryTier::RelationshipDynamic,
-            3 => crate::api::data_models::MemoryTier::CurrentState,
-            4 => crate::api::data_models::MemoryTier::SceneDetail,
-            _ => unreachable!("Invalid variant for MemoryTier: {}", inner),
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.conversation_id, serializer);
+        <f64>::sse_encode(self.score, serializer);
+        <u32>::sse_encode(self.sampled_facts, serializer);
+        <Vec<String>>::sse_encode(self.lost_facts, serializer);
+        <Option<f64>>::sse_encode(self.previous_score, serializer);
+        <bool>::sse_encode(self.alert, serializer);
+        <i64>::sse_encode(self.audited_at, serializer);
     }
 }
 
-impl SseDecode for crate::api::data_models::Message {
+impl SseEncode for crate::api::data_models::MemorySearchResult {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <String>::sse_decode(deserializer);
-        let mut var_role = <crate::api::data_models::MessageRole>::sse_decode(deserializer);
-        let mut var_content = <String>::sse_decode(deserializer);
-        let mut var_thinkingContent = <Option<String>>::sse_decode(deserializer);
-        let mut var_model = <String>::sse_decode(deserializer);
-        let mut var_timestamp = <i64>::sse_decode(deserializer);
-        let mut var_messageType = <crate::api::data_models::MessageType>::sse_decode(deserializer);
-        return crate::api::data_models::Message {
-            id: var_id,
-            role: var_role,
-            content: var_content,
-            thinking_content: var_thinkingContent,
-            model: var_model,
-            timestamp: var_timestamp,
-            message_type: var_messageType,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.summary, serializer);
+        <Vec<String>>::sse_encode(self.core_facts, serializer);
+        <f64>::sse_encode(self.relevance_score, serializer);
     }
 }
 
-impl SseDecode for crate::api::data_models::MessageRole {
+impl SseEncode for crate::api::data_models::MemorySummary {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::api::data_models::MessageRole::User,
-            1 => crate::api::data_models::MessageRole::Assistant,
-            2 => crate::api::data_models::MessageRole::System,
-            _ => unreachable!("Invalid variant for MessageRole: {}", inner),
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.summary, serializer);
+        <Vec<String>>::sse_encode(self.core_facts, serializer);
+        <u32>::sse_encode(self.turn_range_start, serializer);
+        <u32>::sse_encode(self.turn_range_end, serializer);
+        <i64>::sse_encode(self.created_at, serializer);
+        <Vec<String>>::sse_encode(self.keywords, serializer);
+        <u32>::sse_encode(self.compression_generation, serializer);
+        <Option<crate::api::data_models::MemoryContextCard>>::sse_encode(
+            self.context_card,
+            serializer,
+        );
+        <Vec<crate::api::data_models::MemoryTier>>::sse_encode(self.fact_tiers, serializer);
+        <Vec<String>>::sse_encode(self.linked_fact_ids, serializer);
+        <bool>::sse_encode(self.pinned, serializer);
+        <Vec<String>>::sse_encode(self.pinned_facts, serializer);
+        <bool>::sse_encode(self.stale, serializer);
     }
 }
 
-impl SseDecode for crate::api::data_models::MessageType {
+impl SseEncode for crate::api::data_models::MemoryTier {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut inner = <i32>::sse_decode(deserializer);
-        return match inner {
-            0 => crate::api::data_models::MessageType::Say,
-            1 => crate::api::data_models::MessageType::Do,
-            2 => crate::api::data_models::MessageType::Mixed,
-            _ => unreachable!("Invalid variant for MessageType: {}", inner),
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::data_models::MemoryTier::Identity => 0,
+                crate::api::data_models::MemoryTier::CriticalEvent => 1,
+                crate::api::data_models::MemoryTier::RelationshipDynamic => 2,
+                crate::api::data_models::MemoryTier::CurrentState => 3,
+                crate::api::data_models::MemoryTier::SceneDetail => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseDecode for crate::api::data_models::ModelInfo {
+impl SseEncode for crate::api::data_models::Message {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        let mut var_id = <String>::sse_decode(deserializer);
-        let mut var_name = <String>::sse_decode(deserializer);
-        let mut var_contextTokens = <usize>::sse_decode(deserializer);
-        let mut var_maxOutputTokens = <usize>::sse_decode(deserializer);
-        let mut var_supportsThinking = <bool>::sse_decode(deserializer);
-        return crate::api::data_models::ModelInfo {
-            id: var_id,
-            name: var_name,
-            context_tokens: var_contextTokens,
-            max_output_tokens: var_maxOutputTokens,
-            supports_thinking: var_supportsThinking,
-        };
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <crate::api::data_models::MessageRole>::sse_encode(self.role, serializer);
+        <String>::sse_encode(self.content, serializer);
+        <Option<String>>::sse_encode(self.thinking_content, serializer);
+        <String>::sse_encode(self.model, serializer);
+        <i64>::sse_encode(self.timestamp, serializer);
+        <crate::api::data_models::MessageType>::sse_encode(self.message_type, serializer);
+        <Option<crate::api::data_models::GenerationMetadata>>::sse_encode(
+            self.generation_metadata,
+            serializer,
+        );
+        <Option<String>>::sse_encode(self.character_id, serializer);
+        <Vec<crate::api::data_models::MessageAttachment>>::sse_encode(self.attachments, serializer);
+        <Option<String>>::sse_encode(self.audio_path, serializer);
+        <Vec<crate::api::data_models::MessageAlternative>>::sse_encode(
+            self.alternatives,
+            serializer,
+        );
+        <u32>::sse_encode(self.selected_alternative, serializer);
+        <bool>::sse_encode(self.starred, serializer);
+        <Vec<String>>::sse_encode(self.reactions, serializer);
+        <Vec<crate::api::data_models::MessageEdit>>::sse_encode(self.edit_history, serializer);
     }
 }
 
-impl SseDecode for Option<String> {
+impl SseEncode for crate::api::data_models::MessageAlternative {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<String>::sse_decode(deserializer));
-        } else {
-            return None;
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.content, serializer);
+        <Option<String>>::sse_encode(self.thinking_content, serializer);
+        <String>::sse_encode(self.model, serializer);
+        <i64>::sse_encode(self.timestamp, serializer);
+        <Option<crate::api::data_models::GenerationMetadata>>::sse_encode(
+            self.generation_metadata,
+            serializer,
+        );
+        <Option<String>>::sse_encode(self.audio_path, serializer);
     }
 }
 
-impl SseDecode for Option<crate::api::data_models::Conversation> {
+impl SseEncode for crate::api::data_models::MessageAttachment {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::data_models::Conversation>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.mime_type, serializer);
+        <Option<String>>::sse_encode(self.url, serializer);
+        <Option<String>>::sse_encode(self.caption, serializer);
     }
 }
 
-impl SseDecode for Option<crate::api::data_models::MemoryContextCard> {
+impl SseEncode for crate::api::data_models::MessageEdit {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        if (<bool>::sse_decode(deserializer)) {
-            return Some(<crate::api::data_models::MemoryContextCard>::sse_decode(
-                deserializer,
-            ));
-        } else {
-            return None;
-        }
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.content, serializer);
+        <i64>::sse_encode(self.timestamp, serializer);
+        <i64>::sse_encode(self.edited_at, serializer);
     }
 }
 
-impl SseDecode for u32 {
+impl SseEncode for crate::api::data_models::MessageRole {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::data_models::MessageRole::User => 0,
+                crate::api::data_models::MessageRole::Assistant => 1,
+                crate::api::data_models::MessageRole::System => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseDecode for u8 {
+impl SseEncode for crate::api::data_models::MessageType {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u8().unwrap()
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::data_models::MessageType::Say => 0,
+                crate::api::data_models::MessageType::Do => 1,
+                crate::api::data_models::MessageType::Mixed => 2,
+                crate::api::data_models::MessageType::Document => 3,
+                crate::api::data_models::MessageType::Narration => 4,
+                crate::api::data_models::MessageType::OutOfCharacter => 5,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseDecode for () {
+impl SseEncode for crate::api::data_models::MetricPhase {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {}
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::data_models::MetricPhase::Retrieval => 0,
+                crate::api::data_models::MetricPhase::Distillation => 1,
+                crate::api::data_models::MetricPhase::Reasoning => 2,
+                crate::api::data_models::MetricPhase::Chat => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
 }
 
-impl SseDecode for usize {
+impl SseEncode for crate::api::data_models::MetricsSnapshot {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
-        deserializer.cursor.read_u64::<NativeEndian>().unwrap() as _
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::data_models::TurnMetrics>>::sse_encode(self.turns, serializer);
+        <Option<i64>>::sse_encode(self.avg_first_token_ms, serializer);
+        <Vec<crate::api::data_models::PhaseDuration>>::sse_encode(self.avg_phase_ms, serializer);
+        <u32>::sse_encode(self.total_retries, serializer);
     }
 }
 
-fn pde_ffi_dispatcher_primary_impl(
-    func_id: i32,
-    port: flutter_rust_bridge::for_generated::MessagePort,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        1 => wire__crate__api__chat_api__add_assistant_message_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        2 => wire__crate__api__chat_api__add_system_message_impl(port, ptr, rust_vec_len, data_len),
-        3 => wire__crate__api__data_models__app_settings_default_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        4 => {
-            wire__crate__api__chat_api__create_conversation_impl(port, ptr, rust_vec_len, data_len)
-        }
-        5 => {
-            wire__crate__api__chat_api__delete_conversation_impl(port, ptr, rust_vec_len, data_len)
-        }
-        6 => wire__crate__api__chat_api__delete_message_impl(port, ptr, rust_vec_len, data_len),
-        7 => {
-            wire__crate__api__chat_api__detect_message_type_impl(port, ptr, rust_vec_len, data_len)
-        }
-        8 => wire__crate__api__data_models__dialogue_style_default_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        9 => wire__crate__api__chat_api__edit_message_impl(port, ptr, rust_vec_len, data_len),
-        10 => {
-            wire__crate__api__chat_api__get_available_models_impl(port, ptr, rust_vec_len, data_len)
-        }
-        11 => wire__crate__api__chat_api__get_conversation_impl(port, ptr, rust_vec_len, data_len),
-        12 => wire__crate__api__chat_api__get_conversation_list_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        13 => wire__crate__api__chat_api__get_settings_impl(port, ptr, rust_vec_len, data_len),
-        14 => wire__crate__api__chat_api__get_turn_count_impl(port, ptr, rust_vec_len, data_len),
-        15 => wire__crate__api__chat_api__init_app_impl(port, ptr, rust_vec_len, data_len),
-        16 => wire__crate__api__data_models__message_type_default_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        17 => {
-            wire__crate__api__chat_api__regenerate_response_impl(port, ptr, rust_vec_len, data_len)
-        }
-        18 => wire__crate__api__chat_api__restart_story_impl(port, ptr, rust_vec_len, data_len),
-        19 => {
-            wire__crate__api__chat_api__rollback_to_message_impl(port, ptr, rust_vec_len, data_len)
-        }
-        20 => wire__crate__api__chat_api__save_settings_impl(port, ptr, rust_vec_len, data_len),
-        21 => wire__crate__api__chat_api__search_memories_impl(port, ptr, rust_vec_len, data_len),
-        22 => wire__crate__api__chat_api__send_message_impl(port, ptr, rust_vec_len, data_len),
-        23 => wire__crate__api__chat_api__set_api_key_impl(port, ptr, rust_vec_len, data_len),
-        24 => {
-            wire__crate__api__chat_api__set_dialogue_style_impl(port, ptr, rust_vec_len, data_len)
-        }
-        25 => wire__crate__api__chat_api__should_summarize_memory_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        26 => wire__crate__api__chat_api__trigger_memory_summarize_impl(
-            port,
-            ptr,
-            rust_vec_len,
-            data_len,
-        ),
-        27 => wire__crate__api__chat_api__validate_api_key_impl(port, ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+impl SseEncode for crate::api::data_models::ModelInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <usize>::sse_encode(self.context_tokens, serializer);
+        <usize>::sse_encode(self.max_output_tokens, serializer);
+        <bool>::sse_encode(self.supports_thinking, serializer);
     }
 }
 
-fn pde_ffi_dispatcher_sync_impl(
-    func_id: i32,
-    ptr: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len: i32,
-    data_len: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    // Codec=Pde (Serialization + dispatch), see doc to use other codecs
-    match func_id {
-        _ => unreachable!(),
+impl SseEncode for crate::api::data_models::MoodState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f64>::sse_encode(self.valence, serializer);
+        <f64>::sse_encode(self.arousal, serializer);
+        <String>::sse_encode(self.mood, serializer);
+        <i64>::sse_encode(self.updated_at, serializer);
     }
 }
 
-// Section: rust2dart
-
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::data_models::AppSettings {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.api_key.into_into_dart().into_dart(),
-            self.default_model.into_into_dart().into_dart(),
-            self.enable_thinking_by_default.into_into_dart().into_dart(),
-            self.chat_model.into_into_dart().into_dart(),
-            self.thinking_model.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+impl SseEncode for crate::api::data_models::NarrationPerspective {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::data_models::NarrationPerspective::Free => 0,
+                crate::api::data_models::NarrationPerspective::FirstPerson => 1,
+                crate::api::data_models::NarrationPerspective::ThirdPerson => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::data_models::AppSettings
-{
-}
-impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::AppSettings>
-    for crate::api::data_models::AppSettings
-{
-    fn into_into_dart(self) -> crate::api::data_models::AppSettings {
-        self
+
+impl SseEncode for Option<String> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <String>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::data_models::ChatStreamEvent {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            crate::api::data_models::ChatStreamEvent::ContentDelta(field0) => {
-                [0.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::data_models::ChatStreamEvent::ThinkingDelta(field0) => {
-                [1.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            crate::api::data_models::ChatStreamEvent::Done => [2.into_dart()].into_dart(),
-            crate::api::data_models::ChatStreamEvent::Error(field0) => {
-                [3.into_dart(), field0.into_into_dart().into_dart()].into_dart()
-            }
-            _ => {
-                unimplemented!("");
-            }
+
+impl SseEncode for Option<crate::api::data_models::AmbientContext> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::data_models::AmbientContext>::sse_encode(value, serializer);
         }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::data_models::ChatStreamEvent
-{
+
+impl SseEncode for Option<crate::api::data_models::BackupSnapshotInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::data_models::BackupSnapshotInfo>::sse_encode(value, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::ChatStreamEvent>
-    for crate::api::data_models::ChatStreamEvent
-{
-    fn into_into_dart(self) -> crate::api::data_models::ChatStreamEvent {
-        self
+
+impl SseEncode for Option<bool> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <bool>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::data_models::Conversation {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.title.into_into_dart().into_dart(),
-            self.messages.into_into_dart().into_dart(),
-            self.model.into_into_dart().into_dart(),
-            self.created_at.into_into_dart().into_dart(),
-            self.updated_at.into_into_dart().into_dart(),
-            self.dialogue_style.into_into_dart().into_dart(),
-            self.turn_count.into_into_dart().into_dart(),
-            self.memory_summaries.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Option<crate::api::data_models::CheckInSchedule> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::data_models::CheckInSchedule>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::data_models::Conversation
-{
+
+impl SseEncode for Option<crate::api::data_models::CognitiveInsight> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::data_models::CognitiveInsight>::sse_encode(value, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::Conversation>
-    for crate::api::data_models::Conversation
-{
-    fn into_into_dart(self) -> crate::api::data_models::Conversation {
-        self
+
+impl SseEncode for Option<crate::api::data_models::Conversation> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::data_models::Conversation>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::data_models::ConversationSummary {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.title.into_into_dart().into_dart(),
-            self.last_message_preview.into_into_dart().into_dart(),
-            self.model.into_into_dart().into_dart(),
-            self.updated_at.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Option<crate::api::data_models::ConversationInsights> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::data_models::ConversationInsights>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::data_models::ConversationSummary
-{
+
+impl SseEncode for Option<crate::api::data_models::ConversationOrganization> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::data_models::ConversationOrganization>::sse_encode(value, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::ConversationSummary>
-    for crate::api::data_models::ConversationSummary
-{
-    fn into_into_dart(self) -> crate::api::data_models::ConversationSummary {
-        self
+
+impl SseEncode for Option<crate::api::data_models::DailyDigest> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::data_models::DailyDigest>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::data_models::DialogueStyle {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            Self::Free => 0.into_dart(),
-            Self::SayOnly => 1.into_dart(),
-            Self::DoOnly => 2.into_dart(),
-            Self::Mixed => 3.into_dart(),
-            _ => unreachable!(),
+
+impl SseEncode for Option<crate::api::data_models::DistilledSystemState> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::data_models::DistilledSystemState>::sse_encode(value, serializer);
         }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::data_models::DialogueStyle
-{
+
+impl SseEncode for Option<crate::api::data_models::EncryptionKeySource> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::data_models::EncryptionKeySource>::sse_encode(value, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::DialogueStyle>
-    for crate::api::data_models::DialogueStyle
-{
-    fn into_into_dart(self) -> crate::api::data_models::DialogueStyle {
-        self
+
+impl SseEncode for Option<f64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <f64>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::data_models::MemoryContextCard {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.source_range.into_into_dart().into_dart(),
-            self.topic_tags.into_into_dart().into_dart(),
-            self.key_entities.into_into_dart().into_dart(),
-            self.emotional_tone.into_into_dart().into_dart(),
-            self.causal_links.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Option<crate::api::knowledge_store::Fact> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::knowledge_store::Fact>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::data_models::MemoryContextCard
-{
+
+impl SseEncode for Option<crate::api::data_models::GenerationMetadata> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::data_models::GenerationMetadata>::sse_encode(value, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::MemoryContextCard>
-    for crate::api::data_models::MemoryContextCard
-{
-    fn into_into_dart(self) -> crate::api::data_models::MemoryContextCard {
-        self
+
+impl SseEncode for Option<crate::api::data_models::GroupChat> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::data_models::GroupChat>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::data_models::MemorySearchResult {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.summary.into_into_dart().into_dart(),
-            self.core_facts.into_into_dart().into_dart(),
-            self.relevance_score.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Option<i64> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <i64>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::data_models::MemorySearchResult
-{
+
+impl SseEncode for Option<crate::api::data_models::IntegrityReport> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::data_models::IntegrityReport>::sse_encode(value, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::MemorySearchResult>
-    for crate::api::data_models::MemorySearchResult
-{
-    fn into_into_dart(self) -> crate::api::data_models::MemorySearchResult {
-        self
+
+impl SseEncode for Option<crate::api::data_models::LayoutVerification> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::data_models::LayoutVerification>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::data_models::MemorySummary {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.summary.into_into_dart().into_dart(),
-            self.core_facts.into_into_dart().into_dart(),
-            self.turn_range_start.into_into_dart().into_dart(),
-            self.turn_range_end.into_into_dart().into_dart(),
-            self.created_at.into_into_dart().into_dart(),
-            self.keywords.into_into_dart().into_dart(),
-            self.compression_generation.into_into_dart().into_dart(),
-            self.context_card.into_into_dart().into_dart(),
-            self.fact_tiers.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Option<crate::api::data_models::LoreEntry> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::data_models::LoreEntry>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::data_models::MemorySummary
-{
+
+impl SseEncode for Option<crate::api::data_models::MemoryContextCard> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::data_models::MemoryContextCard>::sse_encode(value, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::MemorySummary>
-    for crate::api::data_models::MemorySummary
-{
-    fn into_into_dart(self) -> crate::api::data_models::MemorySummary {
-        self
+
+impl SseEncode for Option<crate::api::data_models::MemoryExplanation> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::data_models::MemoryExplanation>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::data_models::MemoryTier {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            Self::Identity => 0.into_dart(),
-            Self::CriticalEvent => 1.into_dart(),
-            Self::RelationshipDynamic => 2.into_dart(),
-            Self::CurrentState => 3.into_dart(),
-            Self::SceneDetail => 4.into_dart(),
-            _ => unreachable!(),
+
+impl SseEncode for Option<crate::api::data_models::MemoryFidelityReport> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::data_models::MemoryFidelityReport>::sse_encode(value, serializer);
         }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::data_models::MemoryTier
-{
+
+impl SseEncode for Option<crate::api::data_models::MemorySummary> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::data_models::MemorySummary>::sse_encode(value, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::MemoryTier>
-    for crate::api::data_models::MemoryTier
-{
-    fn into_into_dart(self) -> crate::api::data_models::MemoryTier {
-        self
+
+impl SseEncode for Option<crate::api::data_models::Message> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::data_models::Message>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::data_models::Message {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.role.into_into_dart().into_dart(),
-            self.content.into_into_dart().into_dart(),
-            self.thinking_content.into_into_dart().into_dart(),
-            self.model.into_into_dart().into_dart(),
-            self.timestamp.into_into_dart().into_dart(),
-            self.message_type.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for Option<crate::api::data_models::MoodState> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::data_models::MoodState>::sse_encode(value, serializer);
+        }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::data_models::Message
-{
+
+impl SseEncode for Option<crate::api::data_models::PreflightAdvisory> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::data_models::PreflightAdvisory>::sse_encode(value, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::Message>
-    for crate::api::data_models::Message
-{
-    fn into_into_dart(self) -> crate::api::data_models::Message {
-        self
+
+impl SseEncode for Option<crate::api::data_models::ProxySettings> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::data_models::ProxySettings>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::data_models::MessageRole {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            Self::User => 0.into_dart(),
-            Self::Assistant => 1.into_dart(),
-            Self::System => 2.into_dart(),
-            _ => unreachable!(),
+
+impl SseEncode for Option<crate::api::data_models::ReengagementBrief> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::data_models::ReengagementBrief>::sse_encode(value, serializer);
         }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::data_models::MessageRole
-{
+
+impl SseEncode for Option<crate::api::data_models::RelevanceInsight> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::data_models::RelevanceInsight>::sse_encode(value, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::MessageRole>
-    for crate::api::data_models::MessageRole
-{
-    fn into_into_dart(self) -> crate::api::data_models::MessageRole {
-        self
+
+impl SseEncode for Option<crate::api::data_models::SayDoMark> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::data_models::SayDoMark>::sse_encode(value, serializer);
+        }
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::data_models::MessageType {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        match self {
-            Self::Say => 0.into_dart(),
-            Self::Do => 1.into_dart(),
-            Self::Mixed => 2.into_dart(),
-            _ => unreachable!(),
+
+impl SseEncode for Option<crate::api::data_models::SummarizationConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::data_models::SummarizationConfig>::sse_encode(value, serializer);
         }
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::data_models::MessageType
-{
+
+impl SseEncode for Option<u32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u32>::sse_encode(value, serializer);
+        }
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::MessageType>
-    for crate::api::data_models::MessageType
-{
-    fn into_into_dart(self) -> crate::api::data_models::MessageType {
-        self
+
+impl SseEncode for crate::api::data_models::OutboxEntry {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.conversation_id, serializer);
+        <String>::sse_encode(self.content, serializer);
+        <Vec<crate::api::data_models::MessageAttachment>>::sse_encode(self.attachments, serializer);
+        <String>::sse_encode(self.model, serializer);
+        <bool>::sse_encode(self.enable_thinking, serializer);
+        <crate::api::data_models::OutboxStatus>::sse_encode(self.status, serializer);
+        <i64>::sse_encode(self.queued_at, serializer);
+        <Option<String>>::sse_encode(self.last_error, serializer);
     }
 }
-// Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::data_models::ModelInfo {
-    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
-        [
-            self.id.into_into_dart().into_dart(),
-            self.name.into_into_dart().into_dart(),
-            self.context_tokens.into_into_dart().into_dart(),
-            self.max_output_tokens.into_into_dart().into_dart(),
-            self.supports_thinking.into_into_dart().into_dart(),
-        ]
-        .into_dart()
+
+impl SseEncode for crate::api::data_models::OutboxStatus {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::data_models::OutboxStatus::Queued => 0,
+                crate::api::data_models::OutboxStatus::Sending => 1,
+                crate::api::data_models::OutboxStatus::Failed => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
-impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::data_models::ModelInfo
-{
+
+impl SseEncode for crate::api::data_models::PhaseDuration {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::data_models::MetricPhase>::sse_encode(self.phase, serializer);
+        <i64>::sse_encode(self.duration_ms, serializer);
+    }
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::data_models::ModelInfo>
-    for crate::api::data_models::ModelInfo
-{
-    fn into_into_dart(self) -> crate::api::data_models::ModelInfo {
-        self
+
+impl SseEncode for crate::api::data_models::PreflightAdvisory {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::data_models::DraftTone>::sse_encode(self.tone, serializer);
+        <f64>::sse_encode(self.regret_risk, serializer);
+        <f64>::sse_encode(self.tension_delta, serializer);
+        <f64>::sse_encode(self.closeness_delta, serializer);
+        <bool>::sse_encode(self.late_night, serializer);
+        <bool>::sse_encode(self.suggest_pause, serializer);
+        <Vec<String>>::sse_encode(self.notes, serializer);
     }
 }
 
-impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+impl SseEncode for crate::api::data_models::PromptTemplateInfo {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(format!("{:?}", self), serializer);
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.description, serializer);
+        <Vec<String>>::sse_encode(self.variables, serializer);
+        <bool>::sse_encode(self.customized, serializer);
     }
 }
 
-impl SseEncode
-    for StreamSink<
-        crate::api::data_models::ChatStreamEvent,
-        flutter_rust_bridge::for_generated::SseCodec,
-    >
-{
+impl SseEncode for crate::api::data_models::ProviderKind {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        unimplemented!("")
+        <i32>::sse_encode(
+            match self {
+                crate::api::data_models::ProviderKind::Zhipu => 0,
+                crate::api::data_models::ProviderKind::LocalEcho => 1,
+                crate::api::data_models::ProviderKind::OpenAiCompatible => 2,
+                crate::api::data_models::ProviderKind::Anthropic => 3,
+                crate::api::data_models::ProviderKind::LocalLlm => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for String {
+impl SseEncode for crate::api::data_models::ProxySettings {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Vec<u8>>::sse_encode(self.into_bytes(), serializer);
+        <String>::sse_encode(self.url, serializer);
+        <Option<String>>::sse_encode(self.username, serializer);
+        <Option<String>>::sse_encode(self.password, serializer);
+        <Vec<String>>::sse_encode(self.bypass, serializer);
     }
 }
 
-impl SseEncode for crate::api::data_models::AppSettings {
+impl SseEncode for crate::api::data_models::ReactionEvent {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <Option<String>>::sse_encode(self.api_key, serializer);
-        <String>::sse_encode(self.default_model, serializer);
-        <bool>::sse_encode(self.enable_thinking_by_default, serializer);
-        <String>::sse_encode(self.chat_model, serializer);
-        <String>::sse_encode(self.thinking_model, serializer);
+        <String>::sse_encode(self.emoji, serializer);
+        <String>::sse_encode(self.label, serializer);
     }
 }
 
-impl SseEncode for bool {
+impl SseEncode for (String, String) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_u8(self as _).unwrap();
+        <String>::sse_encode(self.0, serializer);
+        <String>::sse_encode(self.1, serializer);
     }
 }
 
-impl SseEncode for crate::api::data_models::ChatStreamEvent {
+impl SseEncode for crate::api::data_models::ReengagementBrief {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        match self {
-            crate::api::data_models::ChatStreamEvent::ContentDelta(field0) => {
-                <i32>::sse_encode(0, serializer);
-                <String>::sse_encode(field0, serializer);
-            }
-            crate::api::data_models::ChatStreamEvent::ThinkingDelta(field0) => {
-                <i32>::sse_encode(1, serializer);
-                <String>::sse_encode(field0, serializer);
-            }
-            crate::api::data_models::ChatStreamEvent::Done => {
-                <i32>::sse_encode(2, serializer);
-            }
-            crate::api::data_models::ChatStreamEvent::Error(field0) => {
-                <i32>::sse_encode(3, serializer);
-                <String>::sse_encode(field0, serializer);
-            }
-            _ => {
-                unimplemented!("");
-            }
-        }
+        <String>::sse_encode(self.conversation_id, serializer);
+        <u32>::sse_encode(self.elapsed_days, serializer);
+        <String>::sse_encode(self.recap, serializer);
+        <String>::sse_encode(self.opener, serializer);
+        <Vec<String>>::sse_encode(self.pending_threads, serializer);
+        <i64>::sse_encode(self.generated_at, serializer);
+        <String>::sse_encode(self.last_message_id, serializer);
     }
 }
 
-impl SseEncode for crate::api::data_models::Conversation {
+impl SseEncode for crate::api::data_models::ReindexProgress {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.id, serializer);
-        <String>::sse_encode(self.title, serializer);
-        <Vec<crate::api::data_models::Message>>::sse_encode(self.messages, serializer);
-        <String>::sse_encode(self.model, serializer);
-        <i64>::sse_encode(self.created_at, serializer);
-        <i64>::sse_encode(self.updated_at, serializer);
-        <crate::api::data_models::DialogueStyle>::sse_encode(self.dialogue_style, serializer);
-        <u32>::sse_encode(self.turn_count, serializer);
-        <Vec<crate::api::data_models::MemorySummary>>::sse_encode(
-            self.memory_summaries,
-            serializer,
-        );
+        <String>::sse_encode(self.conversation_id, serializer);
+        <u32>::sse_encode(self.completed, serializer);
+        <u32>::sse_encode(self.total, serializer);
+        <u32>::sse_encode(self.rebuilt_entries, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
     }
 }
 
-impl SseEncode for crate::api::data_models::ConversationSummary {
+impl SseEncode for crate::api::data_models::RelationChange {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.id, serializer);
-        <String>::sse_encode(self.title, serializer);
-        <String>::sse_encode(self.last_message_preview, serializer);
-        <String>::sse_encode(self.model, serializer);
-        <i64>::sse_encode(self.updated_at, serializer);
+        <String>::sse_encode(self.relation, serializer);
+        <String>::sse_encode(self.fact_id, serializer);
+        <u32>::sse_encode(self.source_turn, serializer);
     }
 }
 
-impl SseEncode for crate::api::data_models::DialogueStyle {
+impl SseEncode for crate::api::data_models::RelationSentiment {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(
             match self {
-                crate::api::data_models::DialogueStyle::Free => 0,
-                crate::api::data_models::DialogueStyle::SayOnly => 1,
-                crate::api::data_models::DialogueStyle::DoOnly => 2,
-                crate::api::data_models::DialogueStyle::Mixed => 3,
+                crate::api::data_models::RelationSentiment::Positive => 0,
+                crate::api::data_models::RelationSentiment::Negative => 1,
+                crate::api::data_models::RelationSentiment::Neutral => 2,
                 _ => {
                     unimplemented!("");
                 }
@@ -2054,156 +17623,177 @@ impl SseEncode for crate::api::data_models::DialogueStyle {
     }
 }
 
-impl SseEncode for f64 {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_f64::<NativeEndian>(self).unwrap();
-    }
-}
-
-impl SseEncode for i32 {
+impl SseEncode for crate::api::data_models::RelationshipInsight {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i32::<NativeEndian>(self).unwrap();
+        <f64>::sse_encode(self.closeness, serializer);
+        <f64>::sse_encode(self.trust_level, serializer);
+        <f64>::sse_encode(self.tension, serializer);
+        <f64>::sse_encode(self.power_balance, serializer);
+        <f64>::sse_encode(self.trend, serializer);
     }
 }
 
-impl SseEncode for i64 {
+impl SseEncode for crate::api::data_models::RelevanceInsight {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        serializer.cursor.write_i64::<NativeEndian>(self).unwrap();
+        <f64>::sse_encode(self.tfidf_score, serializer);
+        <f64>::sse_encode(self.keyword_overlap, serializer);
+        <f64>::sse_encode(self.topic_match, serializer);
+        <f64>::sse_encode(self.final_score, serializer);
     }
 }
 
-impl SseEncode for Vec<String> {
+impl SseEncode for crate::api::data_models::ReplyLength {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <String>::sse_encode(item, serializer);
-        }
+        <i32>::sse_encode(
+            match self {
+                crate::api::data_models::ReplyLength::Auto => 0,
+                crate::api::data_models::ReplyLength::Short => 1,
+                crate::api::data_models::ReplyLength::Medium => 2,
+                crate::api::data_models::ReplyLength::Long => 3,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for Vec<crate::api::data_models::ConversationSummary> {
+impl SseEncode for crate::api::data_models::ReplySignals {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::data_models::ConversationSummary>::sse_encode(item, serializer);
-        }
+        <u32>::sse_encode(self.length_chars, serializer);
+        <u32>::sse_encode(self.grounded_facts, serializer);
+        <f64>::sse_encode(self.user_overlap, serializer);
+        <f64>::sse_encode(self.repetition, serializer);
     }
 }
 
-impl SseEncode for Vec<crate::api::data_models::MemorySearchResult> {
+impl SseEncode for crate::api::data_models::ResponseStyle {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::data_models::MemorySearchResult>::sse_encode(item, serializer);
-        }
+        <crate::api::data_models::ReplyLength>::sse_encode(self.reply_length, serializer);
+        <crate::api::data_models::Formality>::sse_encode(self.formality, serializer);
+        <crate::api::data_models::EmojiUsage>::sse_encode(self.emoji_usage, serializer);
+        <crate::api::data_models::ActionFrequency>::sse_encode(self.action_frequency, serializer);
     }
 }
 
-impl SseEncode for Vec<crate::api::data_models::MemorySummary> {
+impl SseEncode for crate::api::data_models::RetentionPolicy {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::data_models::MemorySummary>::sse_encode(item, serializer);
-        }
+        <u32>::sse_encode(self.archive_after_idle_days, serializer);
+        <u32>::sse_encode(self.max_messages_per_conversation, serializer);
+        <bool>::sse_encode(self.purge_orphans, serializer);
     }
 }
 
-impl SseEncode for Vec<crate::api::data_models::MemoryTier> {
+impl SseEncode for crate::api::data_models::RetryPolicy {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::data_models::MemoryTier>::sse_encode(item, serializer);
-        }
+        <u32>::sse_encode(self.max_retries, serializer);
+        <u64>::sse_encode(self.initial_delay_ms, serializer);
+        <u64>::sse_encode(self.max_delay_ms, serializer);
+        <crate::api::data_models::BackoffStrategy>::sse_encode(self.backoff, serializer);
+        <bool>::sse_encode(self.retry_network_errors, serializer);
+        <bool>::sse_encode(self.retry_stream_errors, serializer);
+        <bool>::sse_encode(self.retry_server_errors, serializer);
+        <bool>::sse_encode(self.retry_rate_limited, serializer);
+        <u64>::sse_encode(self.max_retry_after_secs, serializer);
     }
 }
 
-impl SseEncode for Vec<crate::api::data_models::Message> {
+impl SseEncode for crate::api::data_models::SafetyAction {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::data_models::Message>::sse_encode(item, serializer);
-        }
+        <i32>::sse_encode(
+            match self {
+                crate::api::data_models::SafetyAction::Block => 0,
+                crate::api::data_models::SafetyAction::Soften => 1,
+                crate::api::data_models::SafetyAction::Warn => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for Vec<crate::api::data_models::ModelInfo> {
+impl SseEncode for crate::api::data_models::SafetyCategory {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <crate::api::data_models::ModelInfo>::sse_encode(item, serializer);
-        }
+        <i32>::sse_encode(
+            match self {
+                crate::api::data_models::SafetyCategory::SelfHarm => 0,
+                crate::api::data_models::SafetyCategory::Violence => 1,
+                crate::api::data_models::SafetyCategory::Sexual => 2,
+                crate::api::data_models::SafetyCategory::Hate => 3,
+                crate::api::data_models::SafetyCategory::Illegal => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
-impl SseEncode for Vec<u8> {
+impl SseEncode for crate::api::data_models::SafetyEvent {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(self.len() as _, serializer);
-        for item in self {
-            <u8>::sse_encode(item, serializer);
-        }
+        <crate::api::data_models::SafetyStage>::sse_encode(self.stage, serializer);
+        <crate::api::data_models::SafetyCategory>::sse_encode(self.category, serializer);
+        <crate::api::data_models::SafetyAction>::sse_encode(self.action, serializer);
+        <Vec<String>>::sse_encode(self.matched, serializer);
     }
 }
 
-impl SseEncode for crate::api::data_models::MemoryContextCard {
+impl SseEncode for crate::api::data_models::SafetyPolicy {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.source_range, serializer);
-        <Vec<String>>::sse_encode(self.topic_tags, serializer);
-        <Vec<String>>::sse_encode(self.key_entities, serializer);
-        <String>::sse_encode(self.emotional_tone, serializer);
-        <Vec<String>>::sse_encode(self.causal_links, serializer);
+        <bool>::sse_encode(self.enabled, serializer);
+        <bool>::sse_encode(self.check_input, serializer);
+        <bool>::sse_encode(self.check_output, serializer);
+        <Vec<crate::api::data_models::SafetyRule>>::sse_encode(self.rules, serializer);
     }
 }
 
-impl SseEncode for crate::api::data_models::MemorySearchResult {
+impl SseEncode for crate::api::data_models::SafetyRule {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.summary, serializer);
-        <Vec<String>>::sse_encode(self.core_facts, serializer);
-        <f64>::sse_encode(self.relevance_score, serializer);
+        <crate::api::data_models::SafetyCategory>::sse_encode(self.category, serializer);
+        <crate::api::data_models::SafetyAction>::sse_encode(self.action, serializer);
+        <Vec<String>>::sse_encode(self.extra_terms, serializer);
     }
 }
 
-impl SseEncode for crate::api::data_models::MemorySummary {
+impl SseEncode for crate::api::data_models::SafetyStage {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.id, serializer);
-        <String>::sse_encode(self.summary, serializer);
-        <Vec<String>>::sse_encode(self.core_facts, serializer);
-        <u32>::sse_encode(self.turn_range_start, serializer);
-        <u32>::sse_encode(self.turn_range_end, serializer);
-        <i64>::sse_encode(self.created_at, serializer);
-        <Vec<String>>::sse_encode(self.keywords, serializer);
-        <u32>::sse_encode(self.compression_generation, serializer);
-        <Option<crate::api::data_models::MemoryContextCard>>::sse_encode(
-            self.context_card,
+        <i32>::sse_encode(
+            match self {
+                crate::api::data_models::SafetyStage::Input => 0,
+                crate::api::data_models::SafetyStage::Output => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
             serializer,
         );
-        <Vec<crate::api::data_models::MemoryTier>>::sse_encode(self.fact_tiers, serializer);
     }
 }
 
-impl SseEncode for crate::api::data_models::MemoryTier {
+impl SseEncode for crate::api::data_models::SayDoMark {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(
             match self {
-                crate::api::data_models::MemoryTier::Identity => 0,
-                crate::api::data_models::MemoryTier::CriticalEvent => 1,
-                crate::api::data_models::MemoryTier::RelationshipDynamic => 2,
-                crate::api::data_models::MemoryTier::CurrentState => 3,
-                crate::api::data_models::MemoryTier::SceneDetail => 4,
+                crate::api::data_models::SayDoMark::Say => 0,
+                crate::api::data_models::SayDoMark::Do => 1,
                 _ => {
                     unimplemented!("");
                 }
@@ -2213,44 +17803,92 @@ impl SseEncode for crate::api::data_models::MemoryTier {
     }
 }
 
-impl SseEncode for crate::api::data_models::Message {
+impl SseEncode for crate::api::data_models::SayDoRule {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.pattern, serializer);
+        <crate::api::data_models::SayDoMark>::sse_encode(self.mark, serializer);
+        <i32>::sse_encode(self.priority, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::SayDoRuleSet {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<crate::api::data_models::SayDoRule>>::sse_encode(self.rules, serializer);
+        <Option<crate::api::data_models::SayDoMark>>::sse_encode(self.unmatched, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::SearchHit {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.conversation_id, serializer);
+        <String>::sse_encode(self.conversation_title, serializer);
+        <String>::sse_encode(self.message_id, serializer);
         <crate::api::data_models::MessageRole>::sse_encode(self.role, serializer);
-        <String>::sse_encode(self.content, serializer);
-        <Option<String>>::sse_encode(self.thinking_content, serializer);
-        <String>::sse_encode(self.model, serializer);
+        <String>::sse_encode(self.snippet, serializer);
+        <u32>::sse_encode(self.highlight_start, serializer);
+        <u32>::sse_encode(self.highlight_end, serializer);
         <i64>::sse_encode(self.timestamp, serializer);
-        <crate::api::data_models::MessageType>::sse_encode(self.message_type, serializer);
     }
 }
 
-impl SseEncode for crate::api::data_models::MessageRole {
+impl SseEncode for crate::api::data_models::ShadowEvalRecord {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <i32>::sse_encode(
-            match self {
-                crate::api::data_models::MessageRole::User => 0,
-                crate::api::data_models::MessageRole::Assistant => 1,
-                crate::api::data_models::MessageRole::System => 2,
-                _ => {
-                    unimplemented!("");
-                }
-            },
+        <u32>::sse_encode(self.turn, serializer);
+        <u32>::sse_encode(self.injected_facts, serializer);
+        <String>::sse_encode(self.main_model, serializer);
+        <String>::sse_encode(self.shadow_model, serializer);
+        <crate::api::data_models::ReplySignals>::sse_encode(self.main, serializer);
+        <crate::api::data_models::ReplySignals>::sse_encode(self.shadow, serializer);
+        <i64>::sse_encode(self.created_at, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::ShadowEvalSummary {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.samples, serializer);
+        <f64>::sse_encode(self.avg_grounded_gain, serializer);
+        <f64>::sse_encode(self.avg_overlap_gain, serializer);
+        <f64>::sse_encode(self.avg_repetition_delta, serializer);
+        <f64>::sse_encode(self.grounded_win_rate, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::ShortTermInsight {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<String>>::sse_encode(self.active_topics, serializer);
+        <Vec<crate::api::data_models::EmotionalTurnInsight>>::sse_encode(
+            self.emotional_arc,
+            serializer,
+        );
+        <Vec<String>>::sse_encode(self.pending_threads, serializer);
+        <Vec<crate::api::data_models::FingerprintInsight>>::sse_encode(
+            self.response_fingerprints,
             serializer,
         );
     }
 }
 
-impl SseEncode for crate::api::data_models::MessageType {
+impl SseEncode for crate::api::data_models::SummarizationConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u32>::sse_encode(self.interval_turns, serializer);
+        <u32>::sse_encode(self.window_messages, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::SyncBackendKind {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <i32>::sse_encode(
             match self {
-                crate::api::data_models::MessageType::Say => 0,
-                crate::api::data_models::MessageType::Do => 1,
-                crate::api::data_models::MessageType::Mixed => 2,
+                crate::api::data_models::SyncBackendKind::WebDav => 0,
+                crate::api::data_models::SyncBackendKind::S3 => 1,
                 _ => {
                     unimplemented!("");
                 }
@@ -2260,44 +17898,104 @@ impl SseEncode for crate::api::data_models::MessageType {
     }
 }
 
-impl SseEncode for crate::api::data_models::ModelInfo {
+impl SseEncode for crate::api::data_models::SyncReport {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <String>::sse_encode(self.id, serializer);
-        <String>::sse_encode(self.name, serializer);
-        <usize>::sse_encode(self.context_tokens, serializer);
-        <usize>::sse_encode(self.max_output_tokens, serializer);
-        <bool>::sse_encode(self.supports_thinking, serializer);
+        <u32>::sse_encode(self.pushed, serializer);
+        <u32>::sse_encode(self.pulled, serializer);
+        <u32>::sse_encode(self.deleted, serializer);
+        <Vec<String>>::sse_encode(self.conflicts, serializer);
     }
 }
 
-impl SseEncode for Option<String> {
+impl SseEncode for crate::api::data_models::SyncSettings {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <String>::sse_encode(value, serializer);
-        }
+        <crate::api::data_models::SyncBackendKind>::sse_encode(self.backend, serializer);
+        <String>::sse_encode(self.endpoint, serializer);
+        <String>::sse_encode(self.username, serializer);
+        <String>::sse_encode(self.password, serializer);
+        <String>::sse_encode(self.bucket, serializer);
+        <String>::sse_encode(self.region, serializer);
+        <String>::sse_encode(self.remote_root, serializer);
     }
 }
 
-impl SseEncode for Option<crate::api::data_models::Conversation> {
+impl SseEncode for crate::api::data_models::ThinkingRetention {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::data_models::Conversation>::sse_encode(value, serializer);
+        match self {
+            crate::api::data_models::ThinkingRetention::KeepAll => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::data_models::ThinkingRetention::KeepLastTurns(field0) => {
+                <i32>::sse_encode(1, serializer);
+                <u32>::sse_encode(field0, serializer);
+            }
+            crate::api::data_models::ThinkingRetention::DiscardAfterSummary => {
+                <i32>::sse_encode(2, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
         }
     }
 }
 
-impl SseEncode for Option<crate::api::data_models::MemoryContextCard> {
+impl SseEncode for crate::api::data_models::TimelineEntry {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
-        <bool>::sse_encode(self.is_some(), serializer);
-        if let Some(value) = self {
-            <crate::api::data_models::MemoryContextCard>::sse_encode(value, serializer);
-        }
+        <String>::sse_encode(self.id, serializer);
+        <crate::api::data_models::TimelineEntryKind>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.text, serializer);
+        <u32>::sse_encode(self.turn_start, serializer);
+        <u32>::sse_encode(self.turn_end, serializer);
+        <i64>::sse_encode(self.timestamp, serializer);
+        <i64>::sse_encode(self.end_timestamp, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::TimelineEntryKind {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::data_models::TimelineEntryKind::Chapter => 0,
+                crate::api::data_models::TimelineEntryKind::Event => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::data_models::TurnMetrics {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.conversation_id, serializer);
+        <i64>::sse_encode(self.started_at, serializer);
+        <i64>::sse_encode(self.total_ms, serializer);
+        <Option<i64>>::sse_encode(self.first_token_ms, serializer);
+        <Vec<crate::api::data_models::PhaseDuration>>::sse_encode(self.phases, serializer);
+        <u32>::sse_encode(self.retries, serializer);
+    }
+}
+
+impl SseEncode for crate::api::data_models::TurnPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::data_models::TurnPolicy::RoundRobin => 0,
+                crate::api::data_models::TurnPolicy::ModelChosen => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
     }
 }
 
@@ -2308,6 +18006,13 @@ impl SseEncode for u32 {
     }
 }
 
+impl SseEncode for u64 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u64::<NativeEndian>(self).unwrap();
+    }
+}
+
 impl SseEncode for u8 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -2320,6 +18025,17 @@ impl SseEncode for () {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {}
 }
 
+impl SseEncode for crate::api::data_models::UserPersona {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.id, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <String>::sse_encode(self.description, serializer);
+        <String>::sse_encode(self.pronouns, serializer);
+        <i64>::sse_encode(self.created_at, serializer);
+    }
+}
+
 impl SseEncode for usize {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {